@@ -0,0 +1,45 @@
+//! Criterion benchmarks for the discretized heat exchanger scenarios.
+//!
+//! Run with `cargo bench --bench discretized_hx`. Add
+//! `--features coolprop-dylib` (or `coolprop-static`) to include the
+//! real-fluid CO₂ scenarios.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use twine_models::support::testing::{HX_SEGMENTS, RecuperatorScenario};
+
+fn perfect_gas(c: &mut Criterion) {
+    let mut group = c.benchmark_group("recuperator_given_ua/perfect_gas_air");
+    for segments in HX_SEGMENTS {
+        let scenario = RecuperatorScenario::perfect_gas(segments).expect("valid scenario");
+        group.bench_with_input(
+            BenchmarkId::from_parameter(segments),
+            &scenario,
+            |b, scenario| b.iter(|| black_box(scenario).run().expect("scenario solves")),
+        );
+    }
+    group.finish();
+}
+
+#[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
+fn coolprop(c: &mut Criterion) {
+    let mut group = c.benchmark_group("recuperator_given_ua/coolprop_co2");
+    for segments in HX_SEGMENTS {
+        let scenario = RecuperatorScenario::coolprop_co2(segments).expect("valid scenario");
+        group.bench_with_input(
+            BenchmarkId::from_parameter(segments),
+            &scenario,
+            |b, scenario| b.iter(|| black_box(scenario).run().expect("scenario solves")),
+        );
+    }
+    group.finish();
+}
+
+#[cfg(not(any(feature = "coolprop-static", feature = "coolprop-dylib")))]
+criterion_group!(benches, perfect_gas);
+
+#[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
+criterion_group!(benches, perfect_gas, coolprop);
+
+criterion_main!(benches);
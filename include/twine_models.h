@@ -0,0 +1,148 @@
+/*
+ * C API for twine-models, built with the `ffi` feature.
+ *
+ * Keep in sync with `src/ffi.rs` and its submodules.
+ *
+ * Quantities are in SI base units (K, Pa, kg/m^3, kg/s, W, W/K, J/kg).
+ * Fallible calls return a TwineStatus and write through their out-pointer
+ * only on TWINE_STATUS_OK. twine_last_error_message() describes the most
+ * recent failure on the calling thread.
+ */
+
+#ifndef TWINE_MODELS_H
+#define TWINE_MODELS_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Outcome of a fallible call. */
+typedef enum TwineStatus {
+    TWINE_STATUS_OK = 0,
+    TWINE_STATUS_NULL_POINTER = 1,
+    TWINE_STATUS_INVALID_ARGUMENT = 2,
+    TWINE_STATUS_PROPERTY_FAILED = 3,
+    TWINE_STATUS_SOLVER_FAILED = 4,
+    TWINE_STATUS_PANIC = 5,
+} TwineStatus;
+
+/* Message for the most recent failed call on this thread, or NULL.
+ * Owned by the library; valid until the next failing call on this thread. */
+const char *twine_last_error_message(void);
+
+/* ---- Thermo models ---------------------------------------------------- */
+
+/* Opaque thermo model handle. */
+typedef struct TwineThermo TwineThermo;
+
+/* A thermodynamic state. */
+typedef struct TwineState {
+    double temperature; /* K */
+    double density;     /* kg/m^3 */
+} TwineState;
+
+/* Perfect gas model of "air" or "carbon_dioxide". NULL on failure. */
+TwineThermo *twine_thermo_perfect_gas(const char *fluid);
+
+/* Incompressible model of "water". NULL on failure. */
+TwineThermo *twine_thermo_incompressible(const char *fluid);
+
+/* Releases a thermo model. NULL is a no-op. */
+void twine_thermo_free(TwineThermo *thermo);
+
+/* State constructors and pressure; perfect gas models only. */
+TwineStatus twine_thermo_state_from_tp(const TwineThermo *thermo, double temperature,
+                                       double pressure, TwineState *out);
+TwineStatus twine_thermo_state_from_ph(const TwineThermo *thermo, double pressure,
+                                       double enthalpy, TwineState *out);
+TwineStatus twine_thermo_pressure(const TwineThermo *thermo, TwineState state, double *out);
+
+/* Properties at a state. */
+TwineStatus twine_thermo_internal_energy(const TwineThermo *thermo, TwineState state,
+                                         double *out); /* J/kg */
+TwineStatus twine_thermo_enthalpy(const TwineThermo *thermo, TwineState state,
+                                  double *out); /* J/kg */
+TwineStatus twine_thermo_entropy(const TwineThermo *thermo, TwineState state,
+                                 double *out); /* J/(kg K) */
+TwineStatus twine_thermo_cp(const TwineThermo *thermo, TwineState state,
+                            double *out); /* J/(kg K) */
+TwineStatus twine_thermo_cv(const TwineThermo *thermo, TwineState state,
+                            double *out); /* J/(kg K) */
+
+/* ---- Heat exchangers -------------------------------------------------- */
+
+typedef enum TwineArrangementKind {
+    TWINE_ARRANGEMENT_COUNTER_FLOW = 0,
+    TWINE_ARRANGEMENT_PARALLEL_FLOW = 1,
+    TWINE_ARRANGEMENT_CROSS_FLOW_MIXED_MIXED = 2,
+    TWINE_ARRANGEMENT_CROSS_FLOW_MIXED_UNMIXED = 3,
+    TWINE_ARRANGEMENT_CROSS_FLOW_UNMIXED_MIXED = 4,
+    TWINE_ARRANGEMENT_CROSS_FLOW_UNMIXED_UNMIXED = 5,
+    TWINE_ARRANGEMENT_SHELL_AND_TUBE = 6,
+} TwineArrangementKind;
+
+/* Pass counts are only read for TWINE_ARRANGEMENT_SHELL_AND_TUBE. */
+typedef struct TwineArrangement {
+    TwineArrangementKind kind;
+    uint16_t shell_passes; /* 1 to 4 */
+    uint16_t tube_passes;  /* even multiple of shell_passes */
+} TwineArrangement;
+
+typedef struct TwineStreamInlet {
+    double capacitance_rate; /* W/K */
+    double temperature;      /* K */
+} TwineStreamInlet;
+
+typedef struct TwineStream {
+    double capacitance_rate;   /* W/K */
+    double inlet_temperature;  /* K */
+    double outlet_temperature; /* K */
+    double heat_flow;          /* W, positive when the stream is heated */
+} TwineStream;
+
+typedef struct TwineKnownConductanceResult {
+    TwineStream streams[2]; /* in the order of the inlets */
+    double effectiveness;
+} TwineKnownConductanceResult;
+
+typedef struct TwineDiscretizedInputs {
+    TwineState top_inlet;
+    TwineState bottom_inlet;
+    double top_mass_flow;        /* kg/s */
+    double bottom_mass_flow;     /* kg/s */
+    double top_pressure_drop;    /* Pa */
+    double bottom_pressure_drop; /* Pa */
+} TwineDiscretizedInputs;
+
+typedef struct TwineDiscretizedOutputs {
+    TwineState top_outlet;
+    TwineState bottom_outlet;
+    double q_dot;            /* W, positive from the top to the bottom stream */
+    double ua;               /* W/K */
+    double min_delta_t;      /* K */
+    size_t min_delta_t_node;
+    size_t iterations;
+} TwineDiscretizedOutputs;
+
+/* Effectiveness-NTU: resolves both streams given the conductance ua (W/K). */
+TwineStatus twine_hx_known_conductance_and_inlets(TwineArrangement arrangement, double ua,
+                                                  TwineStreamInlet first,
+                                                  TwineStreamInlet second,
+                                                  TwineKnownConductanceResult *out);
+
+/* Discretized exchanger solved for the target conductance ua (W/K).
+ * thermo must be a perfect gas model, the arrangement counter or parallel
+ * flow, and segments one of 1, 5, 10, 20, 50, or 100. */
+TwineStatus twine_hx_discretized_given_ua(const TwineThermo *thermo,
+                                          TwineArrangement arrangement, size_t segments,
+                                          TwineDiscretizedInputs inputs, double ua,
+                                          TwineDiscretizedOutputs *out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* TWINE_MODELS_H */
@@ -0,0 +1,904 @@
+//! Crate-level error taxonomy.
+//!
+//! Each model reports failures through its own error type, which keeps the
+//! variants specific and matchable. Compositions that drive several models
+//! usually only need to know what *kind* of failure occurred: bad inputs,
+//! non-physical results, a failing property backend, or a solver that did
+//! not converge. [`ModelError`] captures that category along with the model
+//! that failed, and keeps the original error as its [`source`] so callers
+//! can still downcast to the specific type when they need to.
+//!
+//! Every public model error converts into [`ModelError`] with `?` or
+//! [`From`]:
+//!
+//! ```
+//! use twine_models::{
+//!     ModelError,
+//!     models::thermal::battery::lumped::{BatteryPack, BatteryPackError, BatteryPackParameters},
+//! };
+//! use uom::si::{
+//!     electrical_resistance::ohm,
+//!     f64::{ElectricalResistance, Mass, SpecificHeatCapacity, ThermalConductance},
+//!     mass::kilogram,
+//!     specific_heat_capacity::joule_per_kilogram_kelvin,
+//!     thermal_conductance::watt_per_kelvin,
+//! };
+//!
+//! fn build_pack(mass: f64) -> Result<BatteryPack, ModelError> {
+//!     Ok(BatteryPack::new(BatteryPackParameters {
+//!         mass: Mass::new::<kilogram>(mass),
+//!         specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(1000.0),
+//!         internal_resistance: ElectricalResistance::new::<ohm>(0.05),
+//!         coolant_ua: ThermalConductance::new::<watt_per_kelvin>(200.0),
+//!         ambient_ua: ThermalConductance::new::<watt_per_kelvin>(5.0),
+//!     })?)
+//! }
+//!
+//! let error = build_pack(-1.0).unwrap_err();
+//! assert!(matches!(error, ModelError::InvalidInput { model: "BatteryPack", .. }));
+//! assert!(matches!(
+//!     error.downcast_ref::<BatteryPackError>(),
+//!     Some(BatteryPackError::NonPositiveMass(_))
+//! ));
+//! ```
+//!
+//! [`source`]: std::error::Error::source
+
+use std::{error::Error as StdError, fmt::Debug};
+
+use thiserror::Error;
+
+use crate::{
+    models::{
+        boundary::schedule::ScheduleError,
+        cycling::CyclingError,
+        flowsheet::FlowsheetError,
+        hydraulic::{
+            check_valve::CheckValveError, duct_network::DuctNetworkError,
+            expansion_tank::ExpansionTankError, valve::ValveError,
+        },
+        monte_carlo::{DistributionError, MonteCarloError},
+        simulation::QuasiSteadyError,
+        thermal::{
+            battery::lumped::BatteryPackError,
+            chiller::adsorption::AdsorptionChillerError,
+            district_heating::substation::SubstationError,
+            electronics::{cold_plate::ColdPlateError, liquid_loop::LiquidCoolingLoopError},
+            engine::stirling::StirlingError,
+            ground::{borefield::BorefieldError, horizontal::HorizontalLoopError},
+            hx::{
+                air_cooled::AirCooledCondenserError,
+                discretized::{
+                    ConjugateError, DiscretizedExchangerError, GivenUaError, KnownBuildError,
+                    RecuperatorError, RecuperatorGivenOutletError, RecuperatorGivenUaError,
+                    SolveError,
+                },
+                drain_water::DrainWaterError,
+                erv::EnergyRecoveryVentilatorError,
+                manifold::ManifoldError,
+            },
+            pipe::{freeze::PipeFreezeError, heat_trace::HeatTraceError},
+            pool::swimming::SwimmingPoolError,
+            refrigeration::{
+                cascade::CascadeError, charge::ChargeVesselError, defrost::DefrostControllerError,
+                desuperheater::DesuperheaterError, frost::FrostedCoilError,
+                oil::OilCirculationError, suction_line::SuctionLineError,
+            },
+            solar::{dhw::SolarDhwError, field::SolarFieldError, thermosiphon::ThermosiphonError},
+            steam::{attemperator::AttemperatorError, prv::PressureReducingValveError},
+            tank::{stratified::StratifiedTankError, two_tank::TwoTankError},
+            vessel::{control_volume::ControlVolumeError, drum::DrumError},
+        },
+    },
+    support::turbomachinery::{compressor::CompressionError, turbine::ExpansionError},
+};
+
+/// Boxed error stored as the [`source`](StdError::source) of a [`ModelError`].
+pub type BoxError = Box<dyn StdError + Send + Sync>;
+
+/// A model failure, categorized by kind.
+///
+/// Every variant names the failing `model` and carries the model-specific
+/// error as its `source`.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ModelError {
+    /// A parameter or runtime input is outside the model's valid domain.
+    #[error("invalid input to {model}")]
+    InvalidInput {
+        /// Name of the model that rejected the input.
+        model: &'static str,
+
+        /// Model-specific error.
+        #[source]
+        source: BoxError,
+    },
+
+    /// The model produced, or was asked for, a non-physical result.
+    #[error("physics violation in {model}")]
+    PhysicsViolation {
+        /// Name of the model that detected the violation.
+        model: &'static str,
+
+        /// Model-specific error.
+        #[source]
+        source: BoxError,
+    },
+
+    /// A thermodynamic property evaluation or state construction failed.
+    #[error("property backend failed in {model}")]
+    PropertyBackend {
+        /// Name of the model whose property call failed.
+        model: &'static str,
+
+        /// Model-specific error.
+        #[source]
+        source: BoxError,
+    },
+
+    /// An iterative solver did not converge.
+    #[error("{model} failed to converge")]
+    ConvergenceFailure {
+        /// Name of the model whose solver failed.
+        model: &'static str,
+
+        /// Iteration count when available.
+        iterations: Option<usize>,
+
+        /// Model-specific error.
+        #[source]
+        source: BoxError,
+    },
+}
+
+impl ModelError {
+    /// Returns the name of the model that failed.
+    #[must_use]
+    pub fn model(&self) -> &'static str {
+        match self {
+            Self::InvalidInput { model, .. }
+            | Self::PhysicsViolation { model, .. }
+            | Self::PropertyBackend { model, .. }
+            | Self::ConvergenceFailure { model, .. } => model,
+        }
+    }
+
+    /// Returns the model-specific error if it is of type `E`.
+    #[must_use]
+    pub fn downcast_ref<E: StdError + 'static>(&self) -> Option<&E> {
+        let (Self::InvalidInput { source, .. }
+        | Self::PhysicsViolation { source, .. }
+        | Self::PropertyBackend { source, .. }
+        | Self::ConvergenceFailure { source, .. }) = self;
+        source.downcast_ref()
+    }
+
+    fn invalid_input(model: &'static str, source: impl Into<BoxError>) -> Self {
+        Self::InvalidInput {
+            model,
+            source: source.into(),
+        }
+    }
+
+    fn physics(model: &'static str, source: impl Into<BoxError>) -> Self {
+        Self::PhysicsViolation {
+            model,
+            source: source.into(),
+        }
+    }
+
+    fn property(model: &'static str, source: impl Into<BoxError>) -> Self {
+        Self::PropertyBackend {
+            model,
+            source: source.into(),
+        }
+    }
+
+    fn convergence(
+        model: &'static str,
+        iterations: Option<usize>,
+        source: impl Into<BoxError>,
+    ) -> Self {
+        Self::ConvergenceFailure {
+            model,
+            iterations,
+            source: source.into(),
+        }
+    }
+}
+
+/// Implements `From<$error> for ModelError` for errors whose variants all
+/// describe invalid inputs.
+macro_rules! invalid_input_from {
+    ($($error:ty => $model:literal),* $(,)?) => {
+        $(
+            impl From<$error> for ModelError {
+                fn from(error: $error) -> Self {
+                    Self::invalid_input($model, error)
+                }
+            }
+        )*
+    };
+}
+
+invalid_input_from! {
+    BatteryPackError => "BatteryPack",
+    DistributionError => "Distribution",
+    ScheduleError => "Schedule",
+    StratifiedTankError => "StratifiedTank",
+    SwimmingPoolError => "SwimmingPool",
+}
+
+impl From<ControlVolumeError> for ModelError {
+    fn from(error: ControlVolumeError) -> Self {
+        const MODEL: &str = "ControlVolume";
+        match error {
+            ControlVolumeError::NonPositiveVolume(_) | ControlVolumeError::NonPositiveMass(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+            ControlVolumeError::ThermoModelFailed { .. } => Self::property(MODEL, error),
+        }
+    }
+}
+
+impl From<DrumError> for ModelError {
+    fn from(error: DrumError) -> Self {
+        const MODEL: &str = "Drum";
+        match error {
+            DrumError::InvalidGeometry(_)
+            | DrumError::NonPositiveMass(_)
+            | DrumError::InvalidInput(_) => Self::invalid_input(MODEL, error),
+            DrumError::NotSaturated { .. } => Self::physics(MODEL, error),
+            DrumError::LevelNotConverged { iterations } => {
+                Self::convergence(MODEL, Some(iterations), error)
+            }
+            DrumError::ThermoModelFailed { .. } => Self::property(MODEL, error),
+        }
+    }
+}
+
+impl From<AttemperatorError> for ModelError {
+    fn from(error: AttemperatorError) -> Self {
+        const MODEL: &str = "Attemperator";
+        match error {
+            AttemperatorError::InvalidParameter(_) | AttemperatorError::InvalidInput(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+            AttemperatorError::InsufficientSuperheat { .. } => Self::physics(MODEL, error),
+            AttemperatorError::ThermoModelFailed { .. } => Self::property(MODEL, error),
+        }
+    }
+}
+
+impl From<PressureReducingValveError> for ModelError {
+    fn from(error: PressureReducingValveError) -> Self {
+        const MODEL: &str = "PressureReducingValve";
+        match error {
+            PressureReducingValveError::InvalidInput(_) => Self::invalid_input(MODEL, error),
+            PressureReducingValveError::Desuperheater(error) => error.into(),
+            PressureReducingValveError::ThermoModelFailed { .. } => Self::property(MODEL, error),
+        }
+    }
+}
+
+impl From<DuctNetworkError> for ModelError {
+    fn from(error: DuctNetworkError) -> Self {
+        const MODEL: &str = "DuctNetwork";
+        match error {
+            DuctNetworkError::InvalidParameter(_) | DuctNetworkError::InvalidInput(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+            DuctNetworkError::Singular => Self::convergence(MODEL, None, error),
+            DuctNetworkError::MaxIters { iters, .. } => {
+                Self::convergence(MODEL, Some(iters), error)
+            }
+        }
+    }
+}
+
+impl From<ExpansionTankError> for ModelError {
+    fn from(error: ExpansionTankError) -> Self {
+        const MODEL: &str = "ExpansionTank";
+        match error {
+            ExpansionTankError::InvalidParameter(_) => Self::invalid_input(MODEL, error),
+            ExpansionTankError::Drained { .. } | ExpansionTankError::Waterlogged { .. } => {
+                Self::physics(MODEL, error)
+            }
+            ExpansionTankError::ThermoModelFailed { .. } => Self::property(MODEL, error),
+        }
+    }
+}
+
+impl From<ValveError> for ModelError {
+    fn from(error: ValveError) -> Self {
+        const MODEL: &str = "Valve";
+        match error {
+            ValveError::InvalidParameter(_) | ValveError::NegativePressureDrop(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+            ValveError::ThermoModelFailed { .. } => Self::property(MODEL, error),
+        }
+    }
+}
+
+impl From<CheckValveError> for ModelError {
+    fn from(error: CheckValveError) -> Self {
+        const MODEL: &str = "CheckValve";
+        match error {
+            CheckValveError::InvalidParameter(_)
+            | CheckValveError::Valve(
+                ValveError::InvalidParameter(_) | ValveError::NegativePressureDrop(_),
+            ) => Self::invalid_input(MODEL, error),
+            CheckValveError::Valve(ValveError::ThermoModelFailed { .. }) => {
+                Self::property(MODEL, error)
+            }
+        }
+    }
+}
+
+impl From<SubstationError> for ModelError {
+    fn from(error: SubstationError) -> Self {
+        const MODEL: &str = "Substation";
+        match error {
+            SubstationError::InvalidParameter(_)
+            | SubstationError::NonPositiveSecondaryFlow(_)
+            | SubstationError::NegativeDifferentialPressure(_) => Self::invalid_input(MODEL, error),
+            SubstationError::HeatExchanger(_) => Self::physics(MODEL, error),
+            SubstationError::Convergence { iterations, .. } => {
+                Self::convergence(MODEL, iterations, error)
+            }
+        }
+    }
+}
+
+impl From<ColdPlateError> for ModelError {
+    fn from(error: ColdPlateError) -> Self {
+        const MODEL: &str = "ColdPlate";
+        match error {
+            ColdPlateError::InvalidParameter(_) | ColdPlateError::InvalidInput(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+        }
+    }
+}
+
+impl From<LiquidCoolingLoopError> for ModelError {
+    fn from(error: LiquidCoolingLoopError) -> Self {
+        const MODEL: &str = "LiquidCoolingLoop";
+        match error {
+            LiquidCoolingLoopError::InvalidParameter(_)
+            | LiquidCoolingLoopError::InvalidInput(_)
+            | LiquidCoolingLoopError::ColdPlate(_) => Self::invalid_input(MODEL, error),
+        }
+    }
+}
+
+impl From<StirlingError> for ModelError {
+    fn from(error: StirlingError) -> Self {
+        const MODEL: &str = "StirlingEngine";
+        match error {
+            StirlingError::InvalidParameter(_) | StirlingError::InvalidInput(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+        }
+    }
+}
+
+impl From<AdsorptionChillerError> for ModelError {
+    fn from(error: AdsorptionChillerError) -> Self {
+        const MODEL: &str = "AdsorptionChiller";
+        match error {
+            AdsorptionChillerError::InvalidParameter(_)
+            | AdsorptionChillerError::InvalidInput(_) => Self::invalid_input(MODEL, error),
+        }
+    }
+}
+
+impl From<BorefieldError> for ModelError {
+    fn from(error: BorefieldError) -> Self {
+        const MODEL: &str = "Borefield";
+        match error {
+            BorefieldError::InvalidParameter(_) | BorefieldError::InvalidInput(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+        }
+    }
+}
+
+impl From<HorizontalLoopError> for ModelError {
+    fn from(error: HorizontalLoopError) -> Self {
+        const MODEL: &str = "HorizontalLoop";
+        match error {
+            HorizontalLoopError::InvalidParameter(_) | HorizontalLoopError::InvalidInput(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+        }
+    }
+}
+
+impl From<PipeFreezeError> for ModelError {
+    fn from(error: PipeFreezeError) -> Self {
+        const MODEL: &str = "PipeFreeze";
+        match error {
+            PipeFreezeError::InvalidParameter(_) | PipeFreezeError::InvalidInput(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+        }
+    }
+}
+
+impl From<HeatTraceError> for ModelError {
+    fn from(error: HeatTraceError) -> Self {
+        const MODEL: &str = "HeatTrace";
+        match error {
+            HeatTraceError::InvalidParameter(_)
+            | HeatTraceError::InvalidInput(_)
+            | HeatTraceError::Pipe(_) => Self::invalid_input(MODEL, error),
+        }
+    }
+}
+
+impl From<CascadeError> for ModelError {
+    fn from(error: CascadeError) -> Self {
+        const MODEL: &str = "CascadeCycle";
+        match error {
+            CascadeError::InvalidParameter(_) | CascadeError::InvalidInput(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+            CascadeError::Stage { .. } | CascadeError::CascadeCondenser(_) => {
+                Self::property(MODEL, error)
+            }
+            CascadeError::Pinch(_) | CascadeError::Undersized { .. } => Self::physics(MODEL, error),
+            CascadeError::Convergence(_) => Self::convergence(MODEL, None, error),
+        }
+    }
+}
+
+impl From<ChargeVesselError> for ModelError {
+    fn from(error: ChargeVesselError) -> Self {
+        const MODEL: &str = "ChargeVessel";
+        match error {
+            ChargeVesselError::InvalidParameter(_) | ChargeVesselError::InvalidInput(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+            ChargeVesselError::Flooded { .. } | ChargeVesselError::Drained { .. } => {
+                Self::physics(MODEL, error)
+            }
+            ChargeVesselError::ThermoModelFailed { .. } => Self::property(MODEL, error),
+        }
+    }
+}
+
+impl From<DesuperheaterError> for ModelError {
+    fn from(error: DesuperheaterError) -> Self {
+        const MODEL: &str = "Desuperheater";
+        match error {
+            DesuperheaterError::InvalidParameter(_)
+            | DesuperheaterError::InvalidInput(_)
+            | DesuperheaterError::NotSuperheated { .. } => Self::invalid_input(MODEL, error),
+            DesuperheaterError::Pinch => Self::physics(MODEL, error),
+            DesuperheaterError::Exchanger(_) | DesuperheaterError::ThermoModelFailed { .. } => {
+                Self::property(MODEL, error)
+            }
+            DesuperheaterError::Convergence(_) => Self::convergence(MODEL, None, error),
+        }
+    }
+}
+
+impl From<OilCirculationError> for ModelError {
+    fn from(error: OilCirculationError) -> Self {
+        Self::invalid_input("OilCirculation", error)
+    }
+}
+
+impl From<SuctionLineError> for ModelError {
+    fn from(error: SuctionLineError) -> Self {
+        const MODEL: &str = "SuctionLineHx";
+        match error {
+            SuctionLineError::InvalidConductance(_)
+            | SuctionLineError::NonPositiveMassFlow(_)
+            | SuctionLineError::Exchanger(RecuperatorGivenUaError::UnsupportedSegments(_)) => {
+                Self::invalid_input(MODEL, error)
+            }
+            SuctionLineError::Exchanger(RecuperatorGivenUaError::Convergence {
+                iterations,
+                ..
+            }) => Self::convergence(MODEL, iterations, error),
+            SuctionLineError::Exchanger(_) => Self::physics(MODEL, error),
+            SuctionLineError::ThermoModelFailed { .. } => Self::property(MODEL, error),
+        }
+    }
+}
+
+impl From<FrostedCoilError> for ModelError {
+    fn from(error: FrostedCoilError) -> Self {
+        const MODEL: &str = "FrostedCoil";
+        match error {
+            FrostedCoilError::InvalidParameter(_) | FrostedCoilError::Psychrometric(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+            FrostedCoilError::Blocked { .. } => Self::physics(MODEL, error),
+        }
+    }
+}
+
+/// Forwards coil failures, so they are reported against the coil.
+impl From<DefrostControllerError> for ModelError {
+    fn from(error: DefrostControllerError) -> Self {
+        match error {
+            DefrostControllerError::Coil(source) => source.into(),
+            DefrostControllerError::InvalidParameter(_) => {
+                Self::invalid_input("DefrostController", error)
+            }
+        }
+    }
+}
+
+impl From<SolarDhwError> for ModelError {
+    fn from(error: SolarDhwError) -> Self {
+        const MODEL: &str = "SolarDhwSystem";
+        match error {
+            SolarDhwError::InvalidParameter(_)
+            | SolarDhwError::InvalidInput { .. }
+            | SolarDhwError::Tank(_) => Self::invalid_input(MODEL, error),
+            SolarDhwError::Convergence { .. } => Self::convergence(MODEL, None, error),
+        }
+    }
+}
+
+impl From<SolarFieldError> for ModelError {
+    fn from(error: SolarFieldError) -> Self {
+        const MODEL: &str = "SolarField";
+        match error {
+            SolarFieldError::InvalidParameter(_) | SolarFieldError::InvalidInput(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+            SolarFieldError::ThermoModelFailed { .. } => Self::property(MODEL, error),
+        }
+    }
+}
+
+impl From<TwoTankError> for ModelError {
+    fn from(error: TwoTankError) -> Self {
+        const MODEL: &str = "TwoTankStorage";
+        match error {
+            TwoTankError::InvalidParameter(_)
+            | TwoTankError::InvalidInput(_)
+            | TwoTankError::NonPositiveMass(_) => Self::invalid_input(MODEL, error),
+            TwoTankError::ThermoModelFailed { .. } => Self::property(MODEL, error),
+        }
+    }
+}
+
+impl From<ThermosiphonError> for ModelError {
+    fn from(error: ThermosiphonError) -> Self {
+        const MODEL: &str = "Thermosiphon";
+        match error {
+            ThermosiphonError::InvalidParameter(_) => Self::invalid_input(MODEL, error),
+            ThermosiphonError::ThermoModelFailed { .. } => Self::property(MODEL, error),
+            ThermosiphonError::CheckValve(error) => error.into(),
+            ThermosiphonError::Convergence { iterations, .. } => {
+                Self::convergence(MODEL, iterations, error)
+            }
+        }
+    }
+}
+
+impl From<FlowsheetError> for ModelError {
+    fn from(error: FlowsheetError) -> Self {
+        const MODEL: &str = "Flowsheet";
+        match error {
+            FlowsheetError::Convergence { iterations, .. } => {
+                Self::convergence(MODEL, Some(iterations), error)
+            }
+            FlowsheetError::NonFinite { .. } | FlowsheetError::SingularJacobian => {
+                Self::convergence(MODEL, None, error)
+            }
+            FlowsheetError::Component { .. } | FlowsheetError::OutletCount { .. } => {
+                Self::physics(MODEL, error)
+            }
+            _ => Self::invalid_input(MODEL, error),
+        }
+    }
+}
+
+/// Forwards the wrapped model's error, so a failing sample is reported
+/// against the model that failed.
+impl<E> From<MonteCarloError<E>> for ModelError
+where
+    E: Into<ModelError> + StdError + Send + Sync + 'static,
+{
+    fn from(error: MonteCarloError<E>) -> Self {
+        const MODEL: &str = "MonteCarlo";
+        match error {
+            MonteCarloError::Model { source, .. } => source.into(),
+            MonteCarloError::NonFinite { .. } => Self::physics(MODEL, error),
+            MonteCarloError::InvalidConfig(_) => Self::invalid_input(MODEL, error),
+        }
+    }
+}
+
+/// Forwards the wrapped model's error.
+impl<E> From<CyclingError<E>> for ModelError
+where
+    E: Into<ModelError> + StdError + Send + Sync + 'static,
+{
+    fn from(error: CyclingError<E>) -> Self {
+        match error {
+            CyclingError::Model(source) => source.into(),
+            CyclingError::InvalidInput(_) => Self::invalid_input("CyclingDegradation", error),
+        }
+    }
+}
+
+impl<E> From<QuasiSteadyError<E>> for ModelError
+where
+    E: Into<ModelError> + StdError + Send + Sync + 'static,
+{
+    fn from(error: QuasiSteadyError<E>) -> Self {
+        const MODEL: &str = "QuasiSteadyDriver";
+        match error {
+            QuasiSteadyError::Plant { source, .. } => source.into(),
+            QuasiSteadyError::StepSizeUnderflow { .. } => Self::convergence(MODEL, None, error),
+            QuasiSteadyError::InvalidConfig(_) => Self::invalid_input(MODEL, error),
+        }
+    }
+}
+
+impl From<KnownBuildError> for ModelError {
+    fn from(error: KnownBuildError) -> Self {
+        const MODEL: &str = "Known";
+        match error {
+            KnownBuildError::Property { .. } => Self::property(MODEL, error),
+            _ => Self::invalid_input(MODEL, error),
+        }
+    }
+}
+
+impl From<DiscretizedExchangerError> for ModelError {
+    fn from(error: DiscretizedExchangerError) -> Self {
+        const MODEL: &str = "DiscretizedExchanger";
+        match error {
+            DiscretizedExchangerError::UnsupportedSegments(_)
+            | DiscretizedExchangerError::GivenUa(
+                GivenUaError::NegativeUa(_)
+                | GivenUaError::InvalidInletTemperature { .. }
+                | GivenUaError::EqualInletTemperatures
+                | GivenUaError::InvalidWallConductance(_)
+                | GivenUaError::WallConductionRequiresCounterflow,
+            )
+            | DiscretizedExchangerError::Conjugate(
+                ConjugateError::InvalidWall(_)
+                | ConjugateError::InvalidFilm { .. }
+                | ConjugateError::InvalidInletTemperature { .. }
+                | ConjugateError::EqualInletTemperatures,
+            ) => Self::invalid_input(MODEL, error),
+            DiscretizedExchangerError::Solve(SolveError::ThermoModelFailed { .. })
+            | DiscretizedExchangerError::GivenUa(GivenUaError::Solve(
+                SolveError::ThermoModelFailed { .. },
+            ))
+            | DiscretizedExchangerError::Conjugate(ConjugateError::Solve(
+                SolveError::ThermoModelFailed { .. },
+            )) => Self::property(MODEL, error),
+            DiscretizedExchangerError::Solve(SolveError::SecondLawViolation { .. })
+            | DiscretizedExchangerError::GivenUa(
+                GivenUaError::Solve(SolveError::SecondLawViolation { .. })
+                | GivenUaError::ExcessiveWallConduction(_),
+            )
+            | DiscretizedExchangerError::Conjugate(ConjugateError::Solve(
+                SolveError::SecondLawViolation { .. },
+            )) => Self::physics(MODEL, error),
+            DiscretizedExchangerError::GivenUa(GivenUaError::MaxIters { iters, .. })
+            | DiscretizedExchangerError::Conjugate(ConjugateError::MaxIters { iters, .. }) => {
+                Self::convergence(MODEL, Some(iters), error)
+            }
+            DiscretizedExchangerError::GivenUa(GivenUaError::Bisection(_))
+            | DiscretizedExchangerError::Conjugate(ConjugateError::Bisection(_)) => {
+                Self::convergence(MODEL, None, error)
+            }
+        }
+    }
+}
+
+impl From<AirCooledCondenserError> for ModelError {
+    fn from(error: AirCooledCondenserError) -> Self {
+        const MODEL: &str = "AirCooledCondenser";
+        match error {
+            AirCooledCondenserError::InvalidParameter(_)
+            | AirCooledCondenserError::InvalidInput(_)
+            | AirCooledCondenserError::Psychrometric(_) => Self::invalid_input(MODEL, error),
+        }
+    }
+}
+
+impl From<EnergyRecoveryVentilatorError> for ModelError {
+    fn from(error: EnergyRecoveryVentilatorError) -> Self {
+        const MODEL: &str = "EnergyRecoveryVentilator";
+        match error {
+            EnergyRecoveryVentilatorError::InvalidParameter(_)
+            | EnergyRecoveryVentilatorError::InvalidInput(_)
+            | EnergyRecoveryVentilatorError::Psychrometric(_) => Self::invalid_input(MODEL, error),
+        }
+    }
+}
+
+impl From<DrainWaterError> for ModelError {
+    fn from(error: DrainWaterError) -> Self {
+        const MODEL: &str = "DrainWaterHeatRecovery";
+        match error {
+            DrainWaterError::InvalidParameter(_) | DrainWaterError::InvalidInput(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+            DrainWaterError::NotConverged { iterations } => {
+                Self::convergence(MODEL, Some(iterations), error)
+            }
+        }
+    }
+}
+
+impl From<ManifoldError> for ModelError {
+    fn from(error: ManifoldError) -> Self {
+        const MODEL: &str = "Manifold";
+        match error {
+            ManifoldError::InvalidParameter(_) | ManifoldError::InvalidInput(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+            ManifoldError::Convergence { iterations, .. } => {
+                Self::convergence(MODEL, iterations, error)
+            }
+        }
+    }
+}
+
+impl From<RecuperatorGivenUaError> for ModelError {
+    fn from(error: RecuperatorGivenUaError) -> Self {
+        const MODEL: &str = "RecuperatorGivenUa";
+        match error {
+            RecuperatorGivenUaError::UnsupportedSegments(_)
+            | RecuperatorGivenUaError::InvalidConfig { .. }
+            | RecuperatorGivenUaError::EqualInletTemperatures
+            | RecuperatorGivenUaError::NegativeUa(_)
+            | RecuperatorGivenUaError::InvalidInletTemperature { .. } => {
+                Self::invalid_input(MODEL, error)
+            }
+            RecuperatorGivenUaError::Convergence { iterations, .. } => {
+                Self::convergence(MODEL, iterations, error)
+            }
+            RecuperatorGivenUaError::ExcessiveWallConduction(_) => Self::physics(MODEL, error),
+            RecuperatorGivenUaError::ThermoModelFailed { .. } => Self::property(MODEL, error),
+        }
+    }
+}
+
+impl From<RecuperatorGivenOutletError> for ModelError {
+    fn from(error: RecuperatorGivenOutletError) -> Self {
+        const MODEL: &str = "RecuperatorGivenOutlet";
+        match error {
+            RecuperatorGivenOutletError::UnsupportedSegments(_) => {
+                Self::invalid_input(MODEL, error)
+            }
+            RecuperatorGivenOutletError::SecondLawViolation { .. } => Self::physics(MODEL, error),
+            RecuperatorGivenOutletError::ThermoModelFailed { .. } => Self::property(MODEL, error),
+        }
+    }
+}
+
+impl From<RecuperatorError> for ModelError {
+    fn from(error: RecuperatorError) -> Self {
+        match error {
+            RecuperatorError::GivenUa(error) => error.into(),
+            RecuperatorError::GivenOutlet(error) => error.into(),
+        }
+    }
+}
+
+impl<Fluid> From<ExpansionError<Fluid>> for ModelError
+where
+    Fluid: Debug + Send + Sync + 'static,
+{
+    fn from(error: ExpansionError<Fluid>) -> Self {
+        const MODEL: &str = "turbine";
+        match error {
+            ExpansionError::OutletPressureGreaterThanInlet { .. } => {
+                Self::invalid_input(MODEL, error)
+            }
+            ExpansionError::NonPhysicalWork { .. } => Self::physics(MODEL, error),
+            ExpansionError::ThermodynamicModelFailed { .. } => Self::property(MODEL, error),
+        }
+    }
+}
+
+impl<Fluid> From<CompressionError<Fluid>> for ModelError
+where
+    Fluid: Debug + Send + Sync + 'static,
+{
+    fn from(error: CompressionError<Fluid>) -> Self {
+        const MODEL: &str = "compressor";
+        match error {
+            CompressionError::OutletPressureLessThanInlet { .. }
+            | CompressionError::InvalidDisplacement(_) => Self::invalid_input(MODEL, error),
+            CompressionError::NonPhysicalWork { .. }
+            | CompressionError::ZeroVolumetricEfficiency { .. } => Self::physics(MODEL, error),
+            CompressionError::ThermodynamicModelFailed { .. } => Self::property(MODEL, error),
+        }
+    }
+}
+
+/// Forwards the wrapped model error, so a factory failure is reported
+/// against the model it was building.
+#[cfg(feature = "serde")]
+impl From<crate::models::factory::FactoryError> for ModelError {
+    fn from(error: crate::models::factory::FactoryError) -> Self {
+        use crate::models::factory::FactoryError;
+
+        match error {
+            FactoryError::BatteryPack(error) => error.into(),
+            FactoryError::SwimmingPool(error) => error.into(),
+            FactoryError::Schedule(error) => error.into(),
+            FactoryError::ControlVolume(error) => error.into(),
+            FactoryError::Recuperator(error) => error.into(),
+            _ => Self::invalid_input("factory", error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uom::si::{f64::Pressure, pressure::kilopascal};
+
+    use crate::support::thermo::PropertyError;
+
+    #[test]
+    fn categorizes_by_variant() {
+        let error = ModelError::from(RecuperatorGivenUaError::Convergence {
+            message: "bracket exhausted".into(),
+            iterations: Some(42),
+        });
+        assert!(matches!(
+            error,
+            ModelError::ConvergenceFailure {
+                model: "RecuperatorGivenUa",
+                iterations: Some(42),
+                ..
+            }
+        ));
+
+        let error = ModelError::from(RecuperatorGivenOutletError::SecondLawViolation {
+            message: "outlet hotter than hot inlet".into(),
+        });
+        assert!(matches!(error, ModelError::PhysicsViolation { .. }));
+
+        let error = ModelError::from(ControlVolumeError::ThermoModelFailed {
+            context: "pressure".into(),
+            source: Box::new(PropertyError::Calculation {
+                context: "test".into(),
+            }),
+        });
+        assert!(matches!(error, ModelError::PropertyBackend { .. }));
+        assert_eq!(error.model(), "ControlVolume");
+    }
+
+    #[test]
+    fn keeps_specific_error_as_source() {
+        let error = ModelError::from(ExpansionError::<()>::OutletPressureGreaterThanInlet {
+            p_in: Pressure::new::<kilopascal>(100.0),
+            p_out: Pressure::new::<kilopascal>(200.0),
+        });
+
+        assert_eq!(error.to_string(), "invalid input to turbine");
+        assert!(
+            error
+                .source()
+                .unwrap()
+                .to_string()
+                .starts_with("outlet pressure must not be greater than inlet")
+        );
+        assert!(matches!(
+            error.downcast_ref::<ExpansionError<()>>(),
+            Some(ExpansionError::OutletPressureGreaterThanInlet { .. })
+        ));
+        assert!(error.downcast_ref::<ScheduleError>().is_none());
+    }
+}
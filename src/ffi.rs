@@ -0,0 +1,196 @@
+//! C-compatible API for property and heat exchanger calls.
+//!
+//! A flat `extern "C"` surface for plant codes written in C, C++, or Fortran
+//! (through `iso_c_binding`). The declarations live in
+//! `include/twine_models.h`.
+//!
+//! # Conventions
+//!
+//! - Thermo models are opaque [`TwineThermo`] handles created by a
+//!   `twine_thermo_*` constructor and released with [`twine_thermo_free`].
+//! - Everything else is a plain `#[repr(C)]` struct of doubles passed by
+//!   value or through caller-owned pointers.
+//! - Quantities are in SI base units (K, Pa, kg/m³, kg/s, W, W/K, J/kg).
+//! - Fallible calls return a [`TwineStatus`] and write results through an
+//!   out-pointer only on [`TwineStatus::Ok`]. The message for the most recent
+//!   failure on the calling thread is available from
+//!   [`twine_last_error_message`].
+//!
+//! Panics never cross the boundary; they are reported as
+//! [`TwineStatus::Panic`].
+//!
+//! Requires the `ffi` feature. Build a shared library to link against with
+//! `cargo rustc --release --features ffi --crate-type cdylib`.
+
+mod hx;
+mod thermo;
+
+use std::{
+    cell::RefCell,
+    error::Error as StdError,
+    ffi::{CString, c_char},
+    fmt::Write,
+    panic::{AssertUnwindSafe, catch_unwind},
+    ptr,
+};
+
+pub use hx::{
+    TwineArrangement, TwineArrangementKind, TwineDiscretizedInputs, TwineDiscretizedOutputs,
+    TwineKnownConductanceResult, TwineStream, TwineStreamInlet, twine_hx_discretized_given_ua,
+    twine_hx_known_conductance_and_inlets,
+};
+pub use thermo::{
+    TwineState, TwineThermo, twine_thermo_cp, twine_thermo_cv, twine_thermo_enthalpy,
+    twine_thermo_entropy, twine_thermo_free, twine_thermo_incompressible,
+    twine_thermo_internal_energy, twine_thermo_perfect_gas, twine_thermo_pressure,
+    twine_thermo_state_from_ph, twine_thermo_state_from_tp,
+};
+
+/// Outcome of a fallible FFI call.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwineStatus {
+    /// The call succeeded and its outputs were written.
+    Ok = 0,
+
+    /// A required pointer argument was null.
+    NullPointer = 1,
+
+    /// An argument is outside the valid domain.
+    InvalidArgument = 2,
+
+    /// A thermodynamic property evaluation or state construction failed.
+    PropertyFailed = 3,
+
+    /// An iterative solver did not converge or produced a non-physical result.
+    SolverFailed = 4,
+
+    /// The call panicked. This indicates a bug in `twine-models`.
+    Panic = 5,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Returns the message for the most recent failed call on this thread.
+///
+/// Returns null if no call has failed yet. The string is owned by the
+/// library and stays valid until the next failing call on the same thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn twine_last_error_message() -> *const c_char {
+    LAST_ERROR.with_borrow(|message| message.as_ref().map_or(ptr::null(), |m| m.as_ptr()))
+}
+
+/// A failed call: its status and the message to report.
+struct Failure {
+    status: TwineStatus,
+    message: String,
+}
+
+impl Failure {
+    fn new(status: TwineStatus, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+
+    fn invalid(error: &(dyn StdError + 'static)) -> Self {
+        Self::new(TwineStatus::InvalidArgument, error_chain(error))
+    }
+
+    fn property(error: &(dyn StdError + 'static)) -> Self {
+        Self::new(TwineStatus::PropertyFailed, error_chain(error))
+    }
+
+    fn solver(error: &(dyn StdError + 'static)) -> Self {
+        Self::new(TwineStatus::SolverFailed, error_chain(error))
+    }
+
+    fn null(argument: &str) -> Self {
+        Self::new(TwineStatus::NullPointer, format!("`{argument}` is null"))
+    }
+}
+
+/// Runs `call`, writing its value to `out` on success.
+///
+/// Failures and panics are recorded for [`twine_last_error_message`] and
+/// reported as a status.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of `T`.
+unsafe fn run<T>(out: *mut T, call: impl FnOnce() -> Result<T, Failure>) -> TwineStatus {
+    if out.is_null() {
+        return fail(&Failure::null("out"));
+    }
+
+    match catch_unwind(AssertUnwindSafe(call)) {
+        Ok(Ok(value)) => {
+            // SAFETY: `out` is non-null and the caller guarantees it is valid.
+            unsafe { out.write(value) };
+            TwineStatus::Ok
+        }
+        Ok(Err(failure)) => fail(&failure),
+        Err(_) => fail(&Failure::new(TwineStatus::Panic, "twine-models panicked")),
+    }
+}
+
+fn fail(failure: &Failure) -> TwineStatus {
+    // Interior nul bytes can't appear in a C string; drop them.
+    let message = CString::new(failure.message.replace('\0', "")).expect("nul bytes were removed");
+    LAST_ERROR.with_borrow_mut(|last| *last = Some(message));
+    failure.status
+}
+
+/// Joins an error and its sources into one message.
+fn error_chain(error: &(dyn StdError + 'static)) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        let _ = write!(message, ": {cause}");
+        source = cause.source();
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::ffi::CStr;
+
+    fn last_error() -> String {
+        let message = twine_last_error_message();
+        assert!(!message.is_null());
+        // SAFETY: non-null messages are valid C strings owned by the library.
+        unsafe { CStr::from_ptr(message) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn records_failures_and_catches_panics() {
+        let mut out = 0.0_f64;
+
+        // SAFETY: `out` is a valid `f64`.
+        let status = unsafe { run(&raw mut out, || Err(Failure::null("thermo"))) };
+        assert_eq!(status, TwineStatus::NullPointer);
+        assert_eq!(last_error(), "`thermo` is null");
+
+        // SAFETY: `out` is a valid `f64`.
+        let status = unsafe { run(&raw mut out, || panic!("bug")) };
+        assert_eq!(status, TwineStatus::Panic);
+        assert_eq!(last_error(), "twine-models panicked");
+
+        // SAFETY: `out` is a valid `f64`.
+        let status = unsafe { run(&raw mut out, || Ok(1.5)) };
+        assert_eq!(status, TwineStatus::Ok);
+        assert!((out - 1.5).abs() < f64::EPSILON);
+
+        // SAFETY: a null `out` is rejected before any write.
+        let status = unsafe { run(ptr::null_mut::<f64>(), || Ok(1.0)) };
+        assert_eq!(status, TwineStatus::NullPointer);
+    }
+}
@@ -0,0 +1,444 @@
+use uom::si::{
+    f64::{MassRate, Pressure, ThermalConductance, ThermodynamicTemperature},
+    mass_rate::kilogram_per_second,
+    power::watt,
+    pressure::pascal,
+    ratio::ratio,
+    temperature_interval::kelvin as delta_kelvin,
+    thermal_conductance::watt_per_kelvin,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::{
+    models::{
+        factory::{AnyArrangement, ArrangementConfig, Mixing, with_gas},
+        thermal::hx::discretized::core::{
+            DiscretizedArrangement, DiscretizedHx, DiscretizedHxThermoModel, GivenUaConfig,
+            GivenUaError, Known,
+        },
+    },
+    support::hx::{CapacitanceRate, CounterFlow, ParallelFlow, Stream, StreamInlet, functional},
+};
+
+use super::{
+    Failure, TwineStatus, run,
+    thermo::{TwineState, TwineThermo, handle, requires_gas},
+};
+
+/// Heat exchanger flow arrangement.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwineArrangementKind {
+    /// Counterflow.
+    CounterFlow = 0,
+
+    /// Parallel flow.
+    ParallelFlow = 1,
+
+    /// Cross flow with both streams mixed.
+    CrossFlowMixedMixed = 2,
+
+    /// Cross flow with the first stream mixed and the second unmixed.
+    CrossFlowMixedUnmixed = 3,
+
+    /// Cross flow with the first stream unmixed and the second mixed.
+    CrossFlowUnmixedMixed = 4,
+
+    /// Cross flow with both streams unmixed.
+    CrossFlowUnmixedUnmixed = 5,
+
+    /// Shell-and-tube, with pass counts in [`TwineArrangement`].
+    ShellAndTube = 6,
+}
+
+/// A heat exchanger flow arrangement.
+///
+/// The pass counts are only read for [`TwineArrangementKind::ShellAndTube`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TwineArrangement {
+    /// Arrangement kind.
+    pub kind: TwineArrangementKind,
+
+    /// Number of shell passes (1 to 4).
+    pub shell_passes: u16,
+
+    /// Number of tube passes, an even multiple of the shell passes.
+    pub tube_passes: u16,
+}
+
+impl TwineArrangement {
+    fn build(self) -> Result<AnyArrangement, Failure> {
+        use TwineArrangementKind as Kind;
+
+        let cross_flow = |first, second| ArrangementConfig::CrossFlow { first, second };
+        let config = match self.kind {
+            Kind::CounterFlow => ArrangementConfig::CounterFlow,
+            Kind::ParallelFlow => ArrangementConfig::ParallelFlow,
+            Kind::CrossFlowMixedMixed => cross_flow(Mixing::Mixed, Mixing::Mixed),
+            Kind::CrossFlowMixedUnmixed => cross_flow(Mixing::Mixed, Mixing::Unmixed),
+            Kind::CrossFlowUnmixedMixed => cross_flow(Mixing::Unmixed, Mixing::Mixed),
+            Kind::CrossFlowUnmixedUnmixed => cross_flow(Mixing::Unmixed, Mixing::Unmixed),
+            Kind::ShellAndTube => ArrangementConfig::ShellAndTube {
+                shell_passes: self.shell_passes,
+                tube_passes: self.tube_passes,
+            },
+        };
+        config.build().map_err(|error| Failure::invalid(&error))
+    }
+}
+
+/// A stream entering an effectiveness-NTU exchanger.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwineStreamInlet {
+    /// Capacitance rate in W/K.
+    pub capacitance_rate: f64,
+
+    /// Inlet temperature in K.
+    pub temperature: f64,
+}
+
+/// A fully resolved effectiveness-NTU stream.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwineStream {
+    /// Capacitance rate in W/K.
+    pub capacitance_rate: f64,
+
+    /// Inlet temperature in K.
+    pub inlet_temperature: f64,
+
+    /// Outlet temperature in K.
+    pub outlet_temperature: f64,
+
+    /// Heat flow in W, positive when the stream is heated.
+    pub heat_flow: f64,
+}
+
+impl From<Stream> for TwineStream {
+    fn from(stream: Stream) -> Self {
+        Self {
+            capacitance_rate: stream.capacitance_rate.get::<watt_per_kelvin>(),
+            inlet_temperature: stream.inlet_temperature.get::<kelvin>(),
+            outlet_temperature: stream.outlet_temperature.get::<kelvin>(),
+            heat_flow: stream.heat_flow.signed().get::<watt>(),
+        }
+    }
+}
+
+/// Result of [`twine_hx_known_conductance_and_inlets`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwineKnownConductanceResult {
+    /// Resolved streams, in the order of the inlets.
+    pub streams: [TwineStream; 2],
+
+    /// Overall effectiveness.
+    pub effectiveness: f64,
+}
+
+/// Known inputs for a discretized heat exchanger.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwineDiscretizedInputs {
+    /// Top stream inlet state.
+    pub top_inlet: TwineState,
+
+    /// Bottom stream inlet state.
+    pub bottom_inlet: TwineState,
+
+    /// Top stream mass flow rate in kg/s.
+    pub top_mass_flow: f64,
+
+    /// Bottom stream mass flow rate in kg/s.
+    pub bottom_mass_flow: f64,
+
+    /// Top stream pressure drop in Pa.
+    pub top_pressure_drop: f64,
+
+    /// Bottom stream pressure drop in Pa.
+    pub bottom_pressure_drop: f64,
+}
+
+/// Outlet states and performance of a discretized heat exchanger.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwineDiscretizedOutputs {
+    /// Top stream outlet state.
+    pub top_outlet: TwineState,
+
+    /// Bottom stream outlet state.
+    pub bottom_outlet: TwineState,
+
+    /// Heat transfer rate in W, positive from the top to the bottom stream.
+    pub q_dot: f64,
+
+    /// Achieved conductance in W/K.
+    pub ua: f64,
+
+    /// Minimum hot-to-cold temperature difference in K.
+    pub min_delta_t: f64,
+
+    /// Node index of the minimum temperature difference.
+    pub min_delta_t_node: usize,
+
+    /// Number of solver iterations.
+    pub iterations: usize,
+}
+
+/// Resolves both streams of an exchanger with conductance `ua` (W/K).
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn twine_hx_known_conductance_and_inlets(
+    arrangement: TwineArrangement,
+    ua: f64,
+    first: TwineStreamInlet,
+    second: TwineStreamInlet,
+    out: *mut TwineKnownConductanceResult,
+) -> TwineStatus {
+    // SAFETY: forwarded from the caller.
+    unsafe {
+        run(out, || {
+            let arrangement = arrangement.build()?;
+            let result = functional::known_conductance_and_inlets(
+                &arrangement,
+                ThermalConductance::new::<watt_per_kelvin>(ua),
+                [stream_inlet(first)?, stream_inlet(second)?],
+            )
+            .map_err(|error| Failure::invalid(&error))?;
+
+            Ok(TwineKnownConductanceResult {
+                streams: result.streams.map(TwineStream::from),
+                effectiveness: result.effectiveness.get::<ratio>(),
+            })
+        })
+    }
+}
+
+/// Solves a discretized heat exchanger for the target conductance `ua` (W/K).
+///
+/// Both streams use `thermo`, which must be a perfect gas model. The
+/// arrangement must be counterflow or parallel flow, and `segments` one of
+/// 1, 5, 10, 20, 50, or 100. The solver uses its default tolerances.
+///
+/// # Safety
+///
+/// `thermo` must be null or a live handle, and `out` must be null or valid
+/// for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn twine_hx_discretized_given_ua(
+    thermo: *const TwineThermo,
+    arrangement: TwineArrangement,
+    segments: usize,
+    inputs: TwineDiscretizedInputs,
+    ua: f64,
+    out: *mut TwineDiscretizedOutputs,
+) -> TwineStatus {
+    let ua = ThermalConductance::new::<watt_per_kelvin>(ua);
+
+    // SAFETY: forwarded from the caller.
+    unsafe {
+        run(out, || {
+            let thermo = handle(thermo)?;
+            with_gas!(&thermo.0, |model, fluid| {
+                let known = Known::builder()
+                    .top_inlet(inputs.top_inlet.to_state(fluid))
+                    .bottom_inlet(inputs.bottom_inlet.to_state(fluid))
+                    .mass_flows(
+                        MassRate::new::<kilogram_per_second>(inputs.top_mass_flow),
+                        MassRate::new::<kilogram_per_second>(inputs.bottom_mass_flow),
+                    )
+                    .top_pressure_drop(Pressure::new::<pascal>(inputs.top_pressure_drop))
+                    .bottom_pressure_drop(Pressure::new::<pascal>(inputs.bottom_pressure_drop))
+                    .build_checked(model, model)
+                    .map_err(|error| Failure::invalid(&error))?;
+
+                match arrangement.build()?.config() {
+                    ArrangementConfig::CounterFlow => {
+                        given_ua_with::<CounterFlow, _, _>(&known, ua, segments, model)
+                    }
+                    ArrangementConfig::ParallelFlow => {
+                        given_ua_with::<ParallelFlow, _, _>(&known, ua, segments, model)
+                    }
+                    _ => Err(Failure::new(
+                        TwineStatus::InvalidArgument,
+                        "discretized heat exchangers support counter flow and parallel flow",
+                    )),
+                }
+            }, else Err(requires_gas("twine_hx_discretized_given_ua")))
+        })
+    }
+}
+
+/// Selects the node count for `segments`.
+///
+/// Segment counts match those supported by the recuperator models.
+fn given_ua_with<Arrangement, Fluid, Model>(
+    known: &Known<Fluid, Fluid>,
+    ua: ThermalConductance,
+    segments: usize,
+    thermo: &Model,
+) -> Result<TwineDiscretizedOutputs, Failure>
+where
+    Arrangement: DiscretizedArrangement + Default,
+    Fluid: Clone,
+    Model: DiscretizedHxThermoModel<Fluid>,
+{
+    match segments {
+        1 => given_ua::<Arrangement, _, _, 2>(known, ua, thermo),
+        5 => given_ua::<Arrangement, _, _, 6>(known, ua, thermo),
+        10 => given_ua::<Arrangement, _, _, 11>(known, ua, thermo),
+        20 => given_ua::<Arrangement, _, _, 21>(known, ua, thermo),
+        50 => given_ua::<Arrangement, _, _, 51>(known, ua, thermo),
+        100 => given_ua::<Arrangement, _, _, 101>(known, ua, thermo),
+        _ => Err(Failure::new(
+            TwineStatus::InvalidArgument,
+            format!(
+                "unsupported segment count {segments}; supported values are 1, 5, 10, 20, 50, 100"
+            ),
+        )),
+    }
+}
+
+fn given_ua<Arrangement, Fluid, Model, const N: usize>(
+    known: &Known<Fluid, Fluid>,
+    ua: ThermalConductance,
+    thermo: &Model,
+) -> Result<TwineDiscretizedOutputs, Failure>
+where
+    Arrangement: DiscretizedArrangement + Default,
+    Fluid: Clone,
+    Model: DiscretizedHxThermoModel<Fluid>,
+{
+    let solution =
+        DiscretizedHx::<Arrangement, N>::given_ua_same(known, ua, GivenUaConfig::default(), thermo)
+            .map_err(|error| match error {
+                GivenUaError::NegativeUa(_)
+                | GivenUaError::InvalidInletTemperature { .. }
+                | GivenUaError::EqualInletTemperatures => Failure::invalid(&error),
+                _ => Failure::solver(&error),
+            })?;
+    let results = &solution.results;
+
+    // The bottom stream leaves at node 0 in counterflow and node N-1 in
+    // parallel flow.
+    let bottom_outlet = Arrangement::bottom_select(&results.bottom[N - 1], &results.bottom[0]);
+
+    Ok(TwineDiscretizedOutputs {
+        top_outlet: TwineState::from_state(&results.top[N - 1]),
+        bottom_outlet: TwineState::from_state(bottom_outlet),
+        q_dot: results.q_dot.signed_top_to_bottom().get::<watt>(),
+        ua: results.ua.get::<watt_per_kelvin>(),
+        min_delta_t: results.min_delta_t.value.get::<delta_kelvin>(),
+        min_delta_t_node: results.min_delta_t.node,
+        iterations: solution.iterations,
+    })
+}
+
+fn stream_inlet(inlet: TwineStreamInlet) -> Result<StreamInlet, Failure> {
+    Ok(StreamInlet::new(
+        CapacitanceRate::new::<watt_per_kelvin>(inlet.capacitance_rate)
+            .map_err(|error| Failure::invalid(&error))?,
+        ThermodynamicTemperature::new::<kelvin>(inlet.temperature),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use crate::ffi::{twine_thermo_free, twine_thermo_perfect_gas, twine_thermo_state_from_tp};
+
+    const COUNTER_FLOW: TwineArrangement = TwineArrangement {
+        kind: TwineArrangementKind::CounterFlow,
+        shell_passes: 0,
+        tube_passes: 0,
+    };
+
+    #[test]
+    fn known_conductance_conserves_energy() {
+        let mut out = TwineKnownConductanceResult {
+            streams: [TwineStream {
+                capacitance_rate: 0.0,
+                inlet_temperature: 0.0,
+                outlet_temperature: 0.0,
+                heat_flow: 0.0,
+            }; 2],
+            effectiveness: 0.0,
+        };
+
+        // SAFETY: `out` is valid for writes.
+        let status = unsafe {
+            twine_hx_known_conductance_and_inlets(
+                COUNTER_FLOW,
+                3000.0,
+                TwineStreamInlet {
+                    capacitance_rate: 3000.0,
+                    temperature: 323.15,
+                },
+                TwineStreamInlet {
+                    capacitance_rate: 6000.0,
+                    temperature: 353.15,
+                },
+                &raw mut out,
+            )
+        };
+
+        assert_eq!(status, TwineStatus::Ok);
+        assert!(out.effectiveness > 0.0 && out.effectiveness < 1.0);
+        assert_relative_eq!(out.streams[0].heat_flow, -out.streams[1].heat_flow);
+    }
+
+    #[test]
+    fn given_ua_reports_outlets() {
+        // SAFETY: the handle is live until freed and all out-pointers are valid.
+        unsafe {
+            let air = twine_thermo_perfect_gas(c"air".as_ptr());
+            let mut cold = TwineState {
+                temperature: 0.0,
+                density: 0.0,
+            };
+            let mut hot = cold;
+            twine_thermo_state_from_tp(air, 400.0, 200e3, &raw mut cold);
+            twine_thermo_state_from_tp(air, 600.0, 100e3, &raw mut hot);
+
+            let inputs = TwineDiscretizedInputs {
+                top_inlet: cold,
+                bottom_inlet: hot,
+                top_mass_flow: 1.0,
+                bottom_mass_flow: 1.0,
+                top_pressure_drop: 0.0,
+                bottom_pressure_drop: 0.0,
+            };
+            let mut out = TwineDiscretizedOutputs {
+                top_outlet: cold,
+                bottom_outlet: hot,
+                q_dot: 0.0,
+                ua: 0.0,
+                min_delta_t: 0.0,
+                min_delta_t_node: 0,
+                iterations: 0,
+            };
+
+            let status =
+                twine_hx_discretized_given_ua(air, COUNTER_FLOW, 10, inputs, 500.0, &raw mut out);
+            assert_eq!(status, TwineStatus::Ok);
+            assert_relative_eq!(out.ua, 500.0, max_relative = 1e-9);
+            assert!(out.top_outlet.temperature > 400.0);
+            assert!(out.bottom_outlet.temperature < 600.0);
+            assert!(out.iterations > 0);
+
+            let status =
+                twine_hx_discretized_given_ua(air, COUNTER_FLOW, 7, inputs, 500.0, &raw mut out);
+            assert_eq!(status, TwineStatus::InvalidArgument);
+
+            twine_thermo_free(air);
+        }
+    }
+}
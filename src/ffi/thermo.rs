@@ -0,0 +1,372 @@
+use std::ffi::{CStr, c_char};
+
+use serde::{Deserialize, de::IntoDeserializer};
+use uom::si::{
+    available_energy::joule_per_kilogram,
+    f64::{MassDensity, Pressure, ThermodynamicTemperature},
+    mass_density::kilogram_per_cubic_meter,
+    pressure::pascal,
+    specific_heat_capacity::joule_per_kilogram_kelvin,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::{
+    models::factory::{AnyThermo, Gas, Liquid, ThermoConfig, with_gas, with_thermo},
+    support::{
+        thermo::{
+            State,
+            capability::{
+                HasCp, HasCv, HasEnthalpy, HasEntropy, HasInternalEnergy, HasPressure, StateFrom,
+            },
+        },
+        units::SpecificEnthalpy,
+    },
+};
+
+use super::{Failure, TwineStatus, fail, run};
+
+/// Opaque handle to a thermo model.
+///
+/// Create one with [`twine_thermo_perfect_gas`] or
+/// [`twine_thermo_incompressible`] and release it with [`twine_thermo_free`].
+#[derive(Debug)]
+pub struct TwineThermo(pub(super) AnyThermo);
+
+/// A thermodynamic state.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwineState {
+    /// Temperature in K.
+    pub temperature: f64,
+
+    /// Density in kg/m³.
+    pub density: f64,
+}
+
+impl TwineState {
+    pub(super) fn from_state<Fluid>(state: &State<Fluid>) -> Self {
+        Self {
+            temperature: state.temperature.get::<kelvin>(),
+            density: state.density.get::<kilogram_per_cubic_meter>(),
+        }
+    }
+
+    pub(super) fn to_state<Fluid>(self, fluid: Fluid) -> State<Fluid> {
+        State::new(
+            ThermodynamicTemperature::new::<kelvin>(self.temperature),
+            MassDensity::new::<kilogram_per_cubic_meter>(self.density),
+            fluid,
+        )
+    }
+}
+
+/// Creates a perfect gas model of `fluid` (`"air"` or `"carbon_dioxide"`).
+///
+/// Returns null on failure; see [`twine_last_error_message`].
+///
+/// # Safety
+///
+/// `fluid` must be null or a valid nul-terminated string.
+///
+/// [`twine_last_error_message`]: super::twine_last_error_message
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn twine_thermo_perfect_gas(fluid: *const c_char) -> *mut TwineThermo {
+    // SAFETY: forwarded from the caller.
+    unsafe {
+        create(fluid, |name| {
+            Gas::deserialize(name).map(|fluid| ThermoConfig::PerfectGas { fluid })
+        })
+    }
+}
+
+/// Creates an incompressible model of `fluid` (`"water"`).
+///
+/// Returns null on failure; see [`twine_last_error_message`].
+///
+/// # Safety
+///
+/// `fluid` must be null or a valid nul-terminated string.
+///
+/// [`twine_last_error_message`]: super::twine_last_error_message
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn twine_thermo_incompressible(fluid: *const c_char) -> *mut TwineThermo {
+    // SAFETY: forwarded from the caller.
+    unsafe {
+        create(fluid, |name| {
+            Liquid::deserialize(name).map(|fluid| ThermoConfig::Incompressible { fluid })
+        })
+    }
+}
+
+/// Releases a thermo model. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `thermo` must be null or a handle returned by a `twine_thermo_*`
+/// constructor that has not already been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn twine_thermo_free(thermo: *mut TwineThermo) {
+    if !thermo.is_null() {
+        // SAFETY: the handle came from `Box::into_raw` in `create`.
+        drop(unsafe { Box::from_raw(thermo) });
+    }
+}
+
+/// Writes the state at `temperature` (K) and `pressure` (Pa) to `out`.
+///
+/// Requires a perfect gas model.
+///
+/// # Safety
+///
+/// `thermo` must be null or a live handle, and `out` must be null or valid
+/// for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn twine_thermo_state_from_tp(
+    thermo: *const TwineThermo,
+    temperature: f64,
+    pressure: f64,
+    out: *mut TwineState,
+) -> TwineStatus {
+    let t = ThermodynamicTemperature::new::<kelvin>(temperature);
+    let p = Pressure::new::<pascal>(pressure);
+    // SAFETY: forwarded from the caller.
+    unsafe {
+        run(out, || {
+            with_gas!(&handle(thermo)?.0, |model, fluid| model
+                .state_from((fluid, t, p))
+                .map(|state| TwineState::from_state(&state))
+                .map_err(|error| Failure::property(&error)),
+                else Err(requires_gas("twine_thermo_state_from_tp")))
+        })
+    }
+}
+
+/// Writes the state at `pressure` (Pa) and specific `enthalpy` (J/kg) to `out`.
+///
+/// Requires a perfect gas model.
+///
+/// # Safety
+///
+/// `thermo` must be null or a live handle, and `out` must be null or valid
+/// for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn twine_thermo_state_from_ph(
+    thermo: *const TwineThermo,
+    pressure: f64,
+    enthalpy: f64,
+    out: *mut TwineState,
+) -> TwineStatus {
+    let p = Pressure::new::<pascal>(pressure);
+    let h = SpecificEnthalpy::new::<joule_per_kilogram>(enthalpy);
+    // SAFETY: forwarded from the caller.
+    unsafe {
+        run(out, || {
+            with_gas!(&handle(thermo)?.0, |model, fluid| model
+                .state_from((fluid, p, h))
+                .map(|state| TwineState::from_state(&state))
+                .map_err(|error| Failure::property(&error)),
+                else Err(requires_gas("twine_thermo_state_from_ph")))
+        })
+    }
+}
+
+/// Writes the pressure (Pa) at `state` to `out`.
+///
+/// Requires a perfect gas model.
+///
+/// # Safety
+///
+/// `thermo` must be null or a live handle, and `out` must be null or valid
+/// for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn twine_thermo_pressure(
+    thermo: *const TwineThermo,
+    state: TwineState,
+    out: *mut f64,
+) -> TwineStatus {
+    // SAFETY: forwarded from the caller.
+    unsafe {
+        run(out, || {
+            with_gas!(&handle(thermo)?.0, |model, fluid| model
+                .pressure(&state.to_state(fluid))
+                .map(|p| p.get::<pascal>())
+                .map_err(|error| Failure::property(&error)),
+                else Err(requires_gas("twine_thermo_pressure")))
+        })
+    }
+}
+
+/// Defines an FFI property getter that writes `$method` in `$unit` to `out`.
+macro_rules! property {
+    ($(#[$doc:meta])* $name:ident => $method:ident in $unit:ty) => {
+        $(#[$doc])*
+        ///
+        /// # Safety
+        ///
+        /// `thermo` must be null or a live handle, and `out` must be null or
+        /// valid for writes.
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn $name(
+            thermo: *const TwineThermo,
+            state: TwineState,
+            out: *mut f64,
+        ) -> TwineStatus {
+            // SAFETY: forwarded from the caller.
+            unsafe {
+                run(out, || {
+                    with_thermo!(&handle(thermo)?.0, |model, fluid| model
+                        .$method(&state.to_state(fluid))
+                        .map(|value| value.get::<$unit>())
+                        .map_err(|error| Failure::property(&error)))
+                })
+            }
+        }
+    };
+}
+
+property! {
+    /// Writes the specific internal energy (J/kg) at `state` to `out`.
+    twine_thermo_internal_energy => internal_energy in joule_per_kilogram
+}
+
+property! {
+    /// Writes the specific enthalpy (J/kg) at `state` to `out`.
+    twine_thermo_enthalpy => enthalpy in joule_per_kilogram
+}
+
+property! {
+    /// Writes the specific entropy (J/(kg·K)) at `state` to `out`.
+    twine_thermo_entropy => entropy in joule_per_kilogram_kelvin
+}
+
+property! {
+    /// Writes the isobaric specific heat (J/(kg·K)) at `state` to `out`.
+    twine_thermo_cp => cp in joule_per_kilogram_kelvin
+}
+
+property! {
+    /// Writes the isochoric specific heat (J/(kg·K)) at `state` to `out`.
+    twine_thermo_cv => cv in joule_per_kilogram_kelvin
+}
+
+/// Borrows the model behind a handle.
+///
+/// # Safety
+///
+/// `thermo` must be null or a live handle.
+pub(super) unsafe fn handle<'a>(thermo: *const TwineThermo) -> Result<&'a TwineThermo, Failure> {
+    // SAFETY: the caller guarantees a non-null handle is live.
+    unsafe { thermo.as_ref() }.ok_or_else(|| Failure::null("thermo"))
+}
+
+pub(super) fn requires_gas(function: &str) -> Failure {
+    Failure::new(
+        TwineStatus::InvalidArgument,
+        format!("{function} requires a perfect gas model"),
+    )
+}
+
+/// Parses `fluid` with `config` and boxes the built model.
+///
+/// # Safety
+///
+/// `fluid` must be null or a valid nul-terminated string.
+unsafe fn create(
+    fluid: *const c_char,
+    config: impl FnOnce(
+        serde::de::value::StrDeserializer<'_, serde::de::value::Error>,
+    ) -> Result<ThermoConfig, serde::de::value::Error>,
+) -> *mut TwineThermo {
+    if fluid.is_null() {
+        fail(&Failure::null("fluid"));
+        return std::ptr::null_mut();
+    }
+
+    // SAFETY: the caller guarantees a non-null `fluid` is a valid C string.
+    let name = unsafe { CStr::from_ptr(fluid) }.to_string_lossy();
+    let built = config(name.as_ref().into_deserializer())
+        .map_err(|error| Failure::invalid(&error))
+        .and_then(|config| config.build().map_err(|error| Failure::invalid(&error)));
+
+    match built {
+        Ok(thermo) => Box::into_raw(Box::new(TwineThermo(thermo))),
+        Err(failure) => {
+            fail(&failure);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use crate::ffi::twine_last_error_message;
+
+    #[test]
+    fn property_calls_round_trip() {
+        // SAFETY: the fluid name is a valid C string and the handle is freed once.
+        unsafe {
+            let air = twine_thermo_perfect_gas(c"air".as_ptr());
+            assert!(!air.is_null());
+
+            let mut state = TwineState {
+                temperature: 0.0,
+                density: 0.0,
+            };
+            let status = twine_thermo_state_from_tp(air, 300.0, 101_325.0, &raw mut state);
+            assert_eq!(status, TwineStatus::Ok);
+
+            let mut p = 0.0;
+            assert_eq!(
+                twine_thermo_pressure(air, state, &raw mut p),
+                TwineStatus::Ok
+            );
+            assert_relative_eq!(p, 101_325.0, epsilon = 1e-6);
+
+            let mut h = 0.0;
+            assert_eq!(
+                twine_thermo_enthalpy(air, state, &raw mut h),
+                TwineStatus::Ok
+            );
+            let mut back = state;
+            assert_eq!(
+                twine_thermo_state_from_ph(air, 101_325.0, h, &raw mut back),
+                TwineStatus::Ok
+            );
+            assert_relative_eq!(back.temperature, 300.0, epsilon = 1e-9);
+
+            twine_thermo_free(air);
+        }
+    }
+
+    #[test]
+    fn reports_invalid_handles_and_fluids() {
+        // SAFETY: the fluid names are valid C strings and the handle is freed once.
+        unsafe {
+            assert!(twine_thermo_perfect_gas(c"steam".as_ptr()).is_null());
+            assert!(!twine_last_error_message().is_null());
+
+            let state = TwineState {
+                temperature: 330.0,
+                density: 985.0,
+            };
+            let mut out = 0.0;
+            assert_eq!(
+                twine_thermo_cp(std::ptr::null(), state, &raw mut out),
+                TwineStatus::NullPointer
+            );
+
+            let water = twine_thermo_incompressible(c"water".as_ptr());
+            assert_eq!(twine_thermo_cp(water, state, &raw mut out), TwineStatus::Ok);
+            assert!(out > 4000.0);
+            assert_eq!(
+                twine_thermo_pressure(water, state, &raw mut out),
+                TwineStatus::InvalidArgument
+            );
+            twine_thermo_free(water);
+        }
+    }
+}
@@ -10,6 +10,7 @@
 //!
 //! - [`models`]: Domain-specific [`twine_core::Model`] implementations.
 //! - [`support`]: Supporting utilities used by models.
+//! - [`ModelError`]: Common error categories that model errors convert into.
 //!
 //! ## Utility code lifecycle
 //!
@@ -28,6 +29,38 @@
 //!
 //! Note: Only utilities at the crate-level (in [`support`]) are part of the public API.
 //! Model-specific and domain-specific utility code remains private.
+//!
+//! ## `no_std`
+//!
+//! The `std` feature is enabled by default. Without it, the crate builds as
+//! `no_std` (with `alloc`) and provides only the pieces that make sense on an
+//! embedded target: [`support::constraint`], [`support::units`], the
+//! effectiveness-NTU toolkit in [`support::hx`], and the analytic thermo models
+//! in [`support::thermo`] (`PerfectGas` and `Incompressible`). Enable the `libm` feature for the floating point math
+//! those models need:
+//!
+//! ```toml
+//! twine-models = { version = "0.2", default-features = false, features = ["libm"] }
+//! ```
+//!
+//! Models, solvers, `CoolProp`, and everything that touches the file system
+//! require `std`.
+//!
+//! ## Python
+//!
+//! The `python` feature builds a `twine_models` Python extension module
+//! exposing the thermo capability calls, the effectiveness-NTU functional
+//! solvers, and the discretized heat exchanger solvers. See the README for
+//! build instructions.
+//!
+//! The `ffi` feature exports a flat C API for the same calls; see the `ffi` module.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("twine-models requires either the `std` or the `libm` feature for float math");
 
 #[cfg(all(feature = "coolprop-dylib", feature = "coolprop-static"))]
 compile_error!(
@@ -35,5 +68,15 @@ compile_error!(
      enable one or the other, not both"
 );
 
+#[cfg(feature = "std")]
+pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "std")]
 pub mod models;
+#[cfg(feature = "python")]
+mod python;
 pub mod support;
+
+#[cfg(feature = "std")]
+pub use error::ModelError;
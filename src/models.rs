@@ -20,4 +20,12 @@
 //! through multiple adapters (e.g., different wrapper types implementing
 //! [`twine_core::Model`]).
 
+pub mod boundary;
+pub mod cycling;
+#[cfg(feature = "serde")]
+pub mod factory;
+pub mod flowsheet;
+pub mod hydraulic;
+pub mod monte_carlo;
+pub mod simulation;
 pub mod thermal;
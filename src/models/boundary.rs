@@ -0,0 +1,11 @@
+//! Boundary condition models.
+//!
+//! ## Available models
+//!
+//! - **Schedules** ([`schedule`]) — [`Schedule`]: maps simulation time to a
+//!   value by step or linear interpolation, optionally repeating as a daily or
+//!   weekly profile, for loads, setpoints, and occupancy.
+//!
+//! [`Schedule`]: schedule::Schedule
+
+pub mod schedule;
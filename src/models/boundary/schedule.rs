@@ -0,0 +1,46 @@
+//! Time-series schedule boundary model.
+//!
+//! A [`Schedule`] maps simulation time to a value. Points are interpolated as
+//! steps (each value holds until the next point) or linearly, and a schedule
+//! can repeat with a fixed period to describe daily or weekly profiles.
+//! Values are plain `f64` in whatever unit the caller chooses.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_core::Model;
+//! use twine_models::models::boundary::schedule::{Interpolation, Schedule};
+//! use uom::si::{f64::Time, time::hour};
+//!
+//! // Occupancy: 1.0 from 08:00 to 18:00, otherwise 0.0, every day.
+//! let occupancy = Schedule::new(
+//!     [(0.0, 0.0), (8.0, 1.0), (18.0, 0.0)].map(|(h, v)| (Time::new::<hour>(h), v)),
+//!     Interpolation::Step,
+//! )?
+//! .repeating(Time::new::<hour>(24.0))?;
+//!
+//! assert_eq!(occupancy.call(&Time::new::<hour>(12.0))?, 1.0);
+//! assert_eq!(occupancy.call(&Time::new::<hour>(24.0 * 3.0 + 20.0))?, 0.0);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Schedules can also be loaded from CSV with [`Schedule::from_csv`].
+
+mod core;
+
+use std::convert::Infallible;
+
+use twine_core::Model;
+use uom::si::f64::Time;
+
+pub use core::{Interpolation, Schedule, ScheduleError};
+
+impl Model for Schedule {
+    type Input = Time;
+    type Output = f64;
+    type Error = Infallible;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        Ok(self.value_at(*input))
+    }
+}
@@ -0,0 +1,324 @@
+mod csv;
+
+use thiserror::Error;
+use uom::si::{f64::Time, time::second};
+
+/// How a [`Schedule`] fills in values between its points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Interpolation {
+    /// Each value holds until the next point.
+    #[default]
+    Step,
+
+    /// Values vary linearly between points.
+    Linear,
+}
+
+/// Errors that can occur when building a [`Schedule`].
+#[derive(Debug, Clone, PartialEq, Error)]
+#[non_exhaustive]
+pub enum ScheduleError {
+    /// The schedule has no points.
+    #[error("schedule must have at least one point")]
+    Empty,
+
+    /// A time or value is NaN or infinite.
+    #[error("point {index} is not finite")]
+    NonFinite { index: usize },
+
+    /// Point times are not strictly increasing.
+    #[error("point {index} is not later than the point before it")]
+    NotIncreasing { index: usize },
+
+    /// The repeat period is not strictly positive and finite.
+    #[error("repeat period must be strictly positive and finite")]
+    InvalidPeriod,
+
+    /// A point lies outside `[0, period)` of a repeating schedule.
+    #[error("point {index} is outside the repeat period")]
+    OutsidePeriod { index: usize },
+
+    /// A CSV line could not be parsed.
+    #[error("CSV line {line}: {message}")]
+    Csv { line: usize, message: String },
+}
+
+/// A time-to-value boundary condition.
+///
+/// Before the first point the schedule holds the first value, and after the
+/// last point it holds the last value. A repeating schedule instead wraps
+/// time into `[0, period)`, interpolating from the last point back to the
+/// first across the period boundary.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schedule {
+    /// Point times in seconds, strictly increasing.
+    times: Vec<f64>,
+    values: Vec<f64>,
+    interpolation: Interpolation,
+    /// Repeat period in seconds.
+    period: Option<f64>,
+}
+
+impl Schedule {
+    /// Creates a schedule from `(time, value)` points in increasing time order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no points, if any time or value is not
+    /// finite, or if the times are not strictly increasing.
+    pub fn new(
+        points: impl IntoIterator<Item = (Time, f64)>,
+        interpolation: Interpolation,
+    ) -> Result<Self, ScheduleError> {
+        let (times, values): (Vec<f64>, Vec<f64>) = points
+            .into_iter()
+            .map(|(time, value)| (time.get::<second>(), value))
+            .unzip();
+
+        if times.is_empty() {
+            return Err(ScheduleError::Empty);
+        }
+        if let Some(index) =
+            (0..times.len()).find(|&i| !times[i].is_finite() || !values[i].is_finite())
+        {
+            return Err(ScheduleError::NonFinite { index });
+        }
+        if let Some(index) = times.windows(2).position(|pair| pair[1] <= pair[0]) {
+            return Err(ScheduleError::NotIncreasing { index: index + 1 });
+        }
+
+        Ok(Self {
+            times,
+            values,
+            interpolation,
+            period: None,
+        })
+    }
+
+    /// Creates a constant schedule.
+    #[must_use]
+    pub fn constant(value: f64) -> Self {
+        Self {
+            times: vec![0.0],
+            values: vec![value],
+            interpolation: Interpolation::Step,
+            period: None,
+        }
+    }
+
+    /// Creates a daily profile from 24 hourly values, starting at midnight.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any value is not finite.
+    pub fn daily_hourly(
+        values: [f64; 24],
+        interpolation: Interpolation,
+    ) -> Result<Self, ScheduleError> {
+        let hour = 3600.0;
+        let points = (0_u32..)
+            .zip(values)
+            .map(|(h, value)| (Time::new::<second>(f64::from(h) * hour), value));
+        Self::new(points, interpolation)?.repeating(Time::new::<second>(24.0 * hour))
+    }
+
+    /// Makes the schedule repeat with the given period.
+    ///
+    /// Use a period of one day for daily profiles or one week for weekly
+    /// profiles. Point times are measured from the start of each period.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the period is not strictly positive and finite, or
+    /// if any point lies outside `[0, period)`.
+    pub fn repeating(self, period: Time) -> Result<Self, ScheduleError> {
+        let period = period.get::<second>();
+        if !(period.is_finite() && period > 0.0) {
+            return Err(ScheduleError::InvalidPeriod);
+        }
+        if let Some(index) = self.times.iter().position(|&t| !(0.0..period).contains(&t)) {
+            return Err(ScheduleError::OutsidePeriod { index });
+        }
+        Ok(Self {
+            period: Some(period),
+            ..self
+        })
+    }
+
+    /// Returns the interpolation mode.
+    #[must_use]
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Returns the repeat period, if the schedule repeats.
+    #[must_use]
+    pub fn period(&self) -> Option<Time> {
+        self.period.map(Time::new::<second>)
+    }
+
+    /// Returns the scheduled value at `time`.
+    ///
+    /// A NaN `time` yields NaN.
+    #[must_use]
+    pub fn value_at(&self, time: Time) -> f64 {
+        let t = time.get::<second>();
+        if t.is_nan() {
+            return f64::NAN;
+        }
+        match self.period {
+            None => self.value_within(t),
+            Some(period) => self.value_wrapped(t.rem_euclid(period), period),
+        }
+    }
+
+    /// Evaluates a non-repeating schedule, holding the end values.
+    fn value_within(&self, t: f64) -> f64 {
+        let last = self.times.len() - 1;
+        let next = self.times.partition_point(|&ti| ti <= t);
+        if next == 0 {
+            return self.values[0];
+        }
+        if next > last {
+            return self.values[last];
+        }
+        self.between(next - 1, next, t, self.times[next])
+    }
+
+    /// Evaluates a repeating schedule at `t` in `[0, period)`.
+    fn value_wrapped(&self, t: f64, period: f64) -> f64 {
+        let last = self.times.len() - 1;
+        let next = self.times.partition_point(|&ti| ti <= t);
+        match next {
+            // Before the first point: wrap back to the last point of the
+            // previous period.
+            0 => self.wrap_segment(t + period, period),
+            n if n > last => self.wrap_segment(t, period),
+            n => self.between(n - 1, n, t, self.times[n]),
+        }
+    }
+
+    /// Interpolates across the period boundary, from the last point to the
+    /// first point of the next period, at `t` in `[t_last, t_first + period)`.
+    fn wrap_segment(&self, t: f64, period: f64) -> f64 {
+        let last = self.times.len() - 1;
+        self.between(last, 0, t, self.times[0] + period)
+    }
+
+    /// Interpolates from point `from` (at its own time) to point `to`
+    /// (treated as occurring at `to_time`).
+    fn between(&self, from: usize, to: usize, t: f64, to_time: f64) -> f64 {
+        match self.interpolation {
+            Interpolation::Step => self.values[from],
+            Interpolation::Linear => {
+                let fraction = (t - self.times[from]) / (to_time - self.times[from]);
+                self.values[from] + fraction * (self.values[to] - self.values[from])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::time::{day, hour};
+
+    fn hours(h: f64) -> Time {
+        Time::new::<hour>(h)
+    }
+
+    fn points(points: &[(f64, f64)]) -> Vec<(Time, f64)> {
+        points.iter().map(|&(h, v)| (hours(h), v)).collect()
+    }
+
+    #[test]
+    fn step_schedule_holds_values() {
+        let schedule =
+            Schedule::new(points(&[(1.0, 10.0), (2.0, 20.0)]), Interpolation::Step).unwrap();
+
+        assert_relative_eq!(schedule.value_at(hours(0.0)), 10.0);
+        assert_relative_eq!(schedule.value_at(hours(1.5)), 10.0);
+        assert_relative_eq!(schedule.value_at(hours(2.0)), 20.0);
+        assert_relative_eq!(schedule.value_at(hours(9.0)), 20.0);
+    }
+
+    #[test]
+    fn linear_schedule_interpolates() {
+        let schedule =
+            Schedule::new(points(&[(0.0, 0.0), (2.0, 10.0)]), Interpolation::Linear).unwrap();
+
+        assert_relative_eq!(schedule.value_at(hours(0.5)), 2.5);
+        assert_relative_eq!(schedule.value_at(hours(-1.0)), 0.0);
+        assert_relative_eq!(schedule.value_at(hours(5.0)), 10.0);
+    }
+
+    #[test]
+    fn repeating_linear_schedule_wraps_across_period() {
+        // 10 at 06:00 and 20 at 18:00; the overnight segment runs from 20
+        // back to 10 over 12 hours.
+        let schedule = Schedule::new(points(&[(6.0, 10.0), (18.0, 20.0)]), Interpolation::Linear)
+            .unwrap()
+            .repeating(Time::new::<day>(1.0))
+            .unwrap();
+
+        assert_relative_eq!(schedule.value_at(hours(12.0)), 15.0, epsilon = 1e-12);
+        assert_relative_eq!(schedule.value_at(hours(24.0)), 15.0, epsilon = 1e-12);
+        assert_relative_eq!(schedule.value_at(hours(21.0)), 17.5, epsilon = 1e-12);
+        assert_relative_eq!(schedule.value_at(hours(3.0 + 48.0)), 12.5, epsilon = 1e-12);
+        assert_relative_eq!(schedule.value_at(hours(-12.0)), 15.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn repeating_step_schedule_wraps_to_last_value() {
+        let schedule = Schedule::new(points(&[(8.0, 1.0), (18.0, 0.5)]), Interpolation::Step)
+            .unwrap()
+            .repeating(Time::new::<day>(1.0))
+            .unwrap();
+
+        assert_relative_eq!(schedule.value_at(hours(2.0)), 0.5);
+        assert_relative_eq!(schedule.value_at(hours(8.0)), 1.0);
+        assert_relative_eq!(schedule.value_at(hours(24.0 + 20.0)), 0.5);
+    }
+
+    #[test]
+    fn daily_hourly_profile() {
+        let mut values = [0.0; 24];
+        values[7] = 3.0;
+        let schedule = Schedule::daily_hourly(values, Interpolation::Step).unwrap();
+
+        assert_relative_eq!(schedule.value_at(hours(7.5)), 3.0);
+        assert_relative_eq!(schedule.value_at(hours(24.0 * 10.0 + 7.2)), 3.0);
+        assert_relative_eq!(schedule.value_at(hours(8.0)), 0.0);
+        assert_eq!(schedule.period(), Some(Time::new::<day>(1.0)));
+    }
+
+    #[test]
+    fn rejects_invalid_points() {
+        assert_eq!(
+            Schedule::new(Vec::new(), Interpolation::Step),
+            Err(ScheduleError::Empty)
+        );
+        assert_eq!(
+            Schedule::new(points(&[(0.0, 1.0), (0.0, 2.0)]), Interpolation::Step),
+            Err(ScheduleError::NotIncreasing { index: 1 })
+        );
+        assert_eq!(
+            Schedule::new(points(&[(0.0, f64::NAN)]), Interpolation::Step),
+            Err(ScheduleError::NonFinite { index: 0 })
+        );
+        assert_eq!(
+            Schedule::new(points(&[(0.0, 1.0), (30.0, 2.0)]), Interpolation::Step)
+                .unwrap()
+                .repeating(Time::new::<day>(1.0)),
+            Err(ScheduleError::OutsidePeriod { index: 1 })
+        );
+        assert_eq!(
+            Schedule::constant(1.0).repeating(hours(0.0)),
+            Err(ScheduleError::InvalidPeriod)
+        );
+    }
+}
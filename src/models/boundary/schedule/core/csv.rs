@@ -0,0 +1,137 @@
+use std::io::BufRead;
+
+use uom::si::f64::Time;
+
+use super::{Interpolation, Schedule, ScheduleError};
+
+impl Schedule {
+    /// Reads a schedule from two-column CSV data: time, then value.
+    ///
+    /// Times are multiples of `time_unit`, so pass one hour to read a column
+    /// of hours. Blank lines and lines starting with `#` are skipped, and the
+    /// first remaining line is treated as a header if its time column is not
+    /// a number.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScheduleError::Csv`] if the data can't be read or a line
+    /// doesn't hold exactly two numeric columns, or any error from
+    /// [`Schedule::new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use twine_models::models::boundary::schedule::{Interpolation, Schedule};
+    /// use uom::si::{f64::Time, time::hour};
+    ///
+    /// let data = "\
+    /// hour,load_kw
+    /// 0,2.0
+    /// 6,5.5
+    /// 22,2.0
+    /// ";
+    ///
+    /// let load = Schedule::from_csv(data.as_bytes(), Time::new::<hour>(1.0), Interpolation::Linear)?
+    ///     .repeating(Time::new::<hour>(24.0))?;
+    /// assert!((load.value_at(Time::new::<hour>(3.0)) - 3.75).abs() < 1e-12);
+    /// # Ok::<(), twine_models::models::boundary::schedule::ScheduleError>(())
+    /// ```
+    pub fn from_csv(
+        reader: impl BufRead,
+        time_unit: Time,
+        interpolation: Interpolation,
+    ) -> Result<Self, ScheduleError> {
+        let mut points = Vec::new();
+        let mut seen_data = false;
+
+        for (index, line) in reader.lines().enumerate() {
+            let line_number = index + 1;
+            let error = |message: String| ScheduleError::Csv {
+                line: line_number,
+                message,
+            };
+
+            let line = line.map_err(|err| error(err.to_string()))?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let columns: Vec<&str> = line.split(',').map(str::trim).collect();
+            let [time, value] = columns[..] else {
+                return Err(error(format!(
+                    "expected 2 columns, found {}",
+                    columns.len()
+                )));
+            };
+
+            let Ok(time) = time.parse::<f64>() else {
+                if seen_data {
+                    return Err(error(format!("invalid time `{time}`")));
+                }
+                // Header line.
+                seen_data = true;
+                continue;
+            };
+            seen_data = true;
+
+            let value = value
+                .parse::<f64>()
+                .map_err(|_| error(format!("invalid value `{value}`")))?;
+            points.push((time_unit * time, value));
+        }
+
+        Self::new(points, interpolation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::time::{minute, second};
+
+    #[test]
+    fn reads_data_without_header() {
+        let data = "# setpoint schedule\n0, 20\n\n30, 22.5\n";
+        let schedule = Schedule::from_csv(
+            data.as_bytes(),
+            Time::new::<minute>(1.0),
+            Interpolation::Step,
+        )
+        .unwrap();
+
+        assert_relative_eq!(schedule.value_at(Time::new::<second>(1700.0)), 20.0);
+        assert_relative_eq!(schedule.value_at(Time::new::<second>(1800.0)), 22.5);
+    }
+
+    #[test]
+    fn reports_bad_lines() {
+        let unit = Time::new::<second>(1.0);
+        let parse = |data: &str| Schedule::from_csv(data.as_bytes(), unit, Interpolation::Step);
+
+        assert_eq!(
+            parse("t,v\n0,1\nx,2\n"),
+            Err(ScheduleError::Csv {
+                line: 3,
+                message: "invalid time `x`".into()
+            })
+        );
+        assert_eq!(
+            parse("0,1,2\n"),
+            Err(ScheduleError::Csv {
+                line: 1,
+                message: "expected 2 columns, found 3".into()
+            })
+        );
+        assert_eq!(
+            parse("0,abc\n"),
+            Err(ScheduleError::Csv {
+                line: 1,
+                message: "invalid value `abc`".into()
+            })
+        );
+        assert_eq!(parse("time,value\n"), Err(ScheduleError::Empty));
+    }
+}
@@ -0,0 +1,75 @@
+//! Part-load cycling degradation for any capacity model.
+//!
+//! Steady-state maps of heat pumps, chillers, and boilers give their
+//! performance while running continuously. Below full load these units
+//! cycle on and off, and every start wastes energy rebuilding pressures
+//! and temperatures. [`CyclingDegradation`] wraps such a model and reports
+//! the average capacity and power over a simulation step, applying a
+//! degradation coefficient and honoring minimum on and off times.
+//!
+//! ## Example
+//!
+//! ```
+//! use std::convert::Infallible;
+//!
+//! use twine_core::Model;
+//! use twine_models::{
+//!     models::cycling::{CyclingConfig, CyclingDegradation, CyclingInput, CyclingState},
+//!     support::metrics::OperatingPoint,
+//! };
+//! use uom::si::{
+//!     f64::{Power, ThermodynamicTemperature, Time},
+//!     power::kilowatt,
+//!     ratio::ratio,
+//!     thermodynamic_temperature::degree_celsius,
+//!     time::minute,
+//! };
+//!
+//! /// A heat pump whose capacity and COP fall with outdoor temperature.
+//! struct HeatPump;
+//!
+//! impl Model for HeatPump {
+//!     type Input = ThermodynamicTemperature;
+//!     type Output = OperatingPoint;
+//!     type Error = Infallible;
+//!
+//!     fn call(&self, outdoor: &ThermodynamicTemperature) -> Result<OperatingPoint, Infallible> {
+//!         let t = outdoor.get::<degree_celsius>();
+//!         let capacity = Power::new::<kilowatt>(8.0 + 0.2 * t);
+//!         Ok(OperatingPoint::new(capacity, capacity / (3.0 + 0.05 * t)))
+//!     }
+//! }
+//!
+//! let cycling = CyclingDegradation::new(HeatPump, CyclingConfig::default(), |out| *out);
+//!
+//! let output = cycling.call(&CyclingInput {
+//!     model: ThermodynamicTemperature::new::<degree_celsius>(7.0),
+//!     load: Power::new::<kilowatt>(3.0),
+//!     time_step: Time::new::<minute>(10.0),
+//!     state: CyclingState::default(),
+//! })?;
+//!
+//! // The load is met, but at a lower COP than the steady-state 3.35.
+//! assert!((output.delivered - Power::new::<kilowatt>(3.0)).abs() < Power::new::<kilowatt>(1e-9));
+//! assert!((output.delivered / output.consumed).get::<ratio>() < 3.35);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    CyclingConfig, CyclingDegradation, CyclingError, CyclingInput, CyclingOutput, CyclingState,
+};
+
+/// Averages the wrapped model's performance over one step.
+impl<M: Model> Model for CyclingDegradation<M> {
+    type Input = CyclingInput<M::Input>;
+    type Output = CyclingOutput<M::Output>;
+    type Error = CyclingError<M::Error>;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.step(input)
+    }
+}
@@ -0,0 +1,474 @@
+use std::fmt;
+
+use thiserror::Error;
+use twine_core::Model;
+use uom::{
+    ConstZero,
+    si::{
+        f64::{Power, Ratio, Time},
+        ratio::ratio,
+        time::second,
+    },
+};
+
+use crate::support::{control::SwitchState, metrics::OperatingPoint};
+
+type Extractor<O> = Box<dyn Fn(&O) -> OperatingPoint + Send + Sync>;
+
+/// Cycling settings for a [`CyclingDegradation`] wrapper.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CyclingConfig {
+    /// Degradation coefficient `C_d` in `PLF = 1 − C_d·(1 − PLR)`.
+    ///
+    /// Zero means cycling costs nothing; values near 0.25 are typical of
+    /// air-source heat pumps and the AHRI 210/240 default.
+    pub degradation_coefficient: Ratio,
+
+    /// Shortest time the unit runs once started.
+    pub min_on_time: Time,
+
+    /// Shortest time the unit stays off once stopped.
+    pub min_off_time: Time,
+}
+
+impl Default for CyclingConfig {
+    /// `C_d = 0.25` with no minimum on or off times.
+    fn default() -> Self {
+        Self {
+            degradation_coefficient: Ratio::new::<ratio>(0.25),
+            min_on_time: Time::ZERO,
+            min_off_time: Time::ZERO,
+        }
+    }
+}
+
+/// Errors from a [`CyclingDegradation`] step.
+#[derive(Debug, Error)]
+pub enum CyclingError<E> {
+    /// The configuration or step input is out of range.
+    #[error("invalid cycling input: {0}")]
+    InvalidInput(&'static str),
+
+    /// The wrapped model failed.
+    #[error("model failed")]
+    Model(#[source] E),
+}
+
+/// Whether the unit is running and for how long, carried between steps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CyclingState {
+    /// Whether the unit is running.
+    pub switch: SwitchState,
+
+    /// Time since the unit last switched.
+    ///
+    /// Starts long enough that no minimum time holds the unit.
+    pub elapsed: Time,
+}
+
+impl Default for CyclingState {
+    /// Off, and free to start.
+    fn default() -> Self {
+        Self {
+            switch: SwitchState::Off,
+            elapsed: Time::new::<second>(f64::INFINITY),
+        }
+    }
+}
+
+/// Input to one [`CyclingDegradation`] step.
+#[derive(Debug, Clone)]
+pub struct CyclingInput<I> {
+    /// Input to the wrapped model at full-capacity steady state.
+    pub model: I,
+
+    /// Load the unit should meet on average over the step.
+    pub load: Power,
+
+    /// Step length.
+    pub time_step: Time,
+
+    /// Unit state at the start of the step.
+    pub state: CyclingState,
+}
+
+/// Output from one [`CyclingDegradation`] step.
+#[derive(Debug, Clone)]
+pub struct CyclingOutput<O> {
+    /// Output of the wrapped model at full-capacity steady state.
+    pub steady: O,
+
+    /// Fraction of the step the unit ran.
+    pub runtime_fraction: Ratio,
+
+    /// Delivered capacity over steady-state capacity.
+    pub part_load_ratio: Ratio,
+
+    /// Part-load factor, the efficiency penalty for cycling.
+    pub part_load_factor: Ratio,
+
+    /// Capacity delivered, averaged over the step.
+    pub delivered: Power,
+
+    /// Power consumed, averaged over the step.
+    pub consumed: Power,
+
+    /// Load not met, averaged over the step.
+    ///
+    /// Negative when a minimum on time forces the unit to overshoot.
+    pub unmet: Power,
+
+    /// Unit state at the end of the step.
+    pub state: CyclingState,
+}
+
+/// Turns a capacity model's steady-state performance into its average
+/// performance over a step while cycling to meet a smaller load.
+///
+/// The wrapped model is evaluated once per step at full capacity, and
+/// `performance` extracts its steady capacity and power. Cycling follows
+/// the part-load factor `PLF = 1 − C_d·(1 − PLR)`: to deliver a part-load
+/// ratio `PLR` the unit runs for a fraction `PLR / PLF` of the step at full
+/// power, so consumption rises as the load falls.
+///
+/// Minimum on and off times are enforced across steps through the
+/// [`CyclingState`]. The unit switches at most once within a step: it
+/// either finishes a run and stops, or waits out its off time and starts,
+/// so steps should be no longer than the minimum times they resolve.
+pub struct CyclingDegradation<M: Model> {
+    model: M,
+    config: CyclingConfig,
+    performance: Extractor<M::Output>,
+}
+
+impl<M: Model> CyclingDegradation<M> {
+    /// Wraps `model`, reading its steady-state capacity and power with
+    /// `performance`.
+    pub fn new(
+        model: M,
+        config: CyclingConfig,
+        performance: impl Fn(&M::Output) -> OperatingPoint + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            model,
+            config,
+            performance: Box::new(performance),
+        }
+    }
+
+    /// Returns the wrapped model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Returns the cycling settings.
+    pub fn config(&self) -> &CyclingConfig {
+        &self.config
+    }
+
+    /// Evaluates the wrapped model and averages its performance over one
+    /// step.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CyclingError::InvalidInput`] if the degradation coefficient
+    /// lies outside `[0, 1]`, a minimum time or the load is negative, or the
+    /// time step is not strictly positive, and [`CyclingError::Model`] if
+    /// the wrapped model fails.
+    pub fn step(
+        &self,
+        input: &CyclingInput<M::Input>,
+    ) -> Result<CyclingOutput<M::Output>, CyclingError<M::Error>> {
+        self.validate(input)?;
+        let CyclingInput {
+            load,
+            time_step,
+            state,
+            ..
+        } = *input;
+
+        let steady = self.model.call(&input.model).map_err(CyclingError::Model)?;
+        let OperatingPoint {
+            delivered: capacity,
+            consumed: power,
+        } = (self.performance)(&steady);
+
+        // Runtime needed to meet the load, with the cycling penalty.
+        let cd = self.config.degradation_coefficient.get::<ratio>();
+        let required = if capacity > Power::ZERO {
+            let plr = (load / capacity).get::<ratio>().clamp(0.0, 1.0);
+            plr / (1.0 - cd * (1.0 - plr))
+        } else {
+            0.0
+        };
+
+        // Minimum times can hold the unit off or keep it running.
+        let dt = time_step;
+        let on_time = match state.switch {
+            SwitchState::Off => {
+                let locked = (self.config.min_off_time - state.elapsed).max(Time::ZERO);
+                (required * dt).min((dt - locked).max(Time::ZERO))
+            }
+            SwitchState::On => {
+                let held = (self.config.min_on_time - state.elapsed).max(Time::ZERO);
+                (required * dt).max(held).min(dt)
+            }
+        };
+
+        let rtf = (on_time / dt).get::<ratio>();
+        let plr = rtf * (1.0 - cd) / (1.0 - cd * rtf);
+        let plf = if rtf > 0.0 { plr / rtf } else { 1.0 - cd };
+        let delivered = capacity * plr;
+
+        Ok(CyclingOutput {
+            steady,
+            runtime_fraction: Ratio::new::<ratio>(rtf),
+            part_load_ratio: Ratio::new::<ratio>(plr),
+            part_load_factor: Ratio::new::<ratio>(plf),
+            delivered,
+            consumed: power * rtf,
+            unmet: load - delivered,
+            state: next_state(state, on_time, dt),
+        })
+    }
+
+    fn validate(&self, input: &CyclingInput<M::Input>) -> Result<(), CyclingError<M::Error>> {
+        let CyclingConfig {
+            degradation_coefficient,
+            min_on_time,
+            min_off_time,
+        } = self.config;
+
+        if !(0.0..=1.0).contains(&degradation_coefficient.get::<ratio>()) {
+            return Err(CyclingError::InvalidInput(
+                "degradation coefficient must be in [0, 1]",
+            ));
+        }
+        if min_on_time.is_nan() || min_on_time < Time::ZERO {
+            return Err(CyclingError::InvalidInput(
+                "minimum on time must be non-negative",
+            ));
+        }
+        if min_off_time.is_nan() || min_off_time < Time::ZERO {
+            return Err(CyclingError::InvalidInput(
+                "minimum off time must be non-negative",
+            ));
+        }
+        if input.load.is_nan() || input.load < Power::ZERO {
+            return Err(CyclingError::InvalidInput("load must be non-negative"));
+        }
+        if input.time_step.is_nan() || input.time_step <= Time::ZERO {
+            return Err(CyclingError::InvalidInput(
+                "time step must be strictly positive",
+            ));
+        }
+        if input.state.elapsed.is_nan() || input.state.elapsed < Time::ZERO {
+            return Err(CyclingError::InvalidInput(
+                "elapsed time must be non-negative",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// State at the end of a step that ran for `on_time` of `dt`.
+///
+/// A running unit runs first and then stops; a stopped unit waits and then
+/// starts.
+fn next_state(state: CyclingState, on_time: Time, dt: Time) -> CyclingState {
+    match state.switch {
+        _ if on_time >= dt => CyclingState {
+            switch: SwitchState::On,
+            elapsed: match state.switch {
+                SwitchState::On => state.elapsed + dt,
+                SwitchState::Off => dt,
+            },
+        },
+        _ if on_time <= Time::ZERO => CyclingState {
+            switch: SwitchState::Off,
+            elapsed: match state.switch {
+                SwitchState::Off => state.elapsed + dt,
+                SwitchState::On => dt,
+            },
+        },
+        SwitchState::On => CyclingState {
+            switch: SwitchState::Off,
+            elapsed: dt - on_time,
+        },
+        SwitchState::Off => CyclingState {
+            switch: SwitchState::On,
+            elapsed: on_time,
+        },
+    }
+}
+
+impl<M: Model + fmt::Debug> fmt::Debug for CyclingDegradation<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CyclingDegradation")
+            .field("model", &self.model)
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use uom::si::{power::kilowatt, time::minute};
+
+    /// A unit with fixed 10 kW capacity at 2.5 kW input.
+    #[derive(Debug)]
+    struct Unit;
+
+    impl Model for Unit {
+        type Input = ();
+        type Output = OperatingPoint;
+        type Error = Infallible;
+
+        fn call(&self, (): &()) -> Result<OperatingPoint, Infallible> {
+            Ok(OperatingPoint::new(kw(10.0), kw(2.5)))
+        }
+    }
+
+    fn kw(value: f64) -> Power {
+        Power::new::<kilowatt>(value)
+    }
+
+    fn minutes(value: f64) -> Time {
+        Time::new::<minute>(value)
+    }
+
+    fn wrap(config: CyclingConfig) -> CyclingDegradation<Unit> {
+        CyclingDegradation::new(Unit, config, |out| *out)
+    }
+
+    fn input(load: f64, state: CyclingState) -> CyclingInput<()> {
+        CyclingInput {
+            model: (),
+            load: kw(load),
+            time_step: minutes(10.0),
+            state,
+        }
+    }
+
+    #[test]
+    fn part_load_follows_degradation_curve() {
+        let unit = wrap(CyclingConfig::default());
+        let out = unit.step(&input(5.0, CyclingState::default())).unwrap();
+
+        // PLF = 1 − 0.25·0.5 = 0.875, RTF = 0.5 / 0.875.
+        assert_relative_eq!(
+            out.part_load_ratio.get::<ratio>(),
+            0.5,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            out.part_load_factor.get::<ratio>(),
+            0.875,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            out.runtime_fraction.get::<ratio>(),
+            0.5 / 0.875,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(out.delivered.get::<kilowatt>(), 5.0, max_relative = 1e-12);
+        assert_relative_eq!(
+            out.consumed.get::<kilowatt>(),
+            2.5 * 0.5 / 0.875,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(out.unmet.get::<kilowatt>(), 0.0, epsilon = 1e-12);
+        assert_eq!(out.state.switch, SwitchState::On);
+    }
+
+    #[test]
+    fn full_load_runs_continuously_and_reports_unmet() {
+        let unit = wrap(CyclingConfig::default());
+        let out = unit.step(&input(12.0, CyclingState::default())).unwrap();
+
+        assert_relative_eq!(out.runtime_fraction.get::<ratio>(), 1.0);
+        assert_relative_eq!(out.part_load_factor.get::<ratio>(), 1.0);
+        assert_relative_eq!(out.unmet.get::<kilowatt>(), 2.0, max_relative = 1e-12);
+        assert_eq!(out.state.switch, SwitchState::On);
+        assert_eq!(out.state.elapsed, minutes(10.0));
+    }
+
+    #[test]
+    fn minimum_off_time_holds_the_unit_off() {
+        let unit = wrap(CyclingConfig {
+            min_off_time: minutes(15.0),
+            ..CyclingConfig::default()
+        });
+        let just_stopped = CyclingState {
+            switch: SwitchState::Off,
+            elapsed: minutes(8.0),
+        };
+        let out = unit.step(&input(9.0, just_stopped)).unwrap();
+
+        // Locked out for 7 of the 10 minutes.
+        assert_relative_eq!(
+            out.runtime_fraction.get::<ratio>(),
+            0.3,
+            max_relative = 1e-12
+        );
+        assert!(out.unmet > Power::ZERO);
+        assert_eq!(out.state.switch, SwitchState::On);
+        assert_relative_eq!(out.state.elapsed.get::<minute>(), 3.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn minimum_on_time_forces_overshoot() {
+        let unit = wrap(CyclingConfig {
+            min_on_time: minutes(10.0),
+            ..CyclingConfig::default()
+        });
+        let just_started = CyclingState {
+            switch: SwitchState::On,
+            elapsed: minutes(2.0),
+        };
+        let out = unit.step(&input(1.0, just_started)).unwrap();
+
+        assert_relative_eq!(
+            out.runtime_fraction.get::<ratio>(),
+            0.8,
+            max_relative = 1e-12
+        );
+        assert!(out.unmet < Power::ZERO);
+        assert_eq!(out.state.switch, SwitchState::Off);
+        assert_relative_eq!(out.state.elapsed.get::<minute>(), 2.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn no_load_keeps_the_unit_off() {
+        let unit = wrap(CyclingConfig::default());
+        let out = unit.step(&input(0.0, CyclingState::default())).unwrap();
+
+        assert_eq!(out.delivered, Power::ZERO);
+        assert_eq!(out.consumed, Power::ZERO);
+        assert_eq!(out.state.switch, SwitchState::Off);
+    }
+
+    #[test]
+    fn rejects_invalid_inputs() {
+        let unit = wrap(CyclingConfig {
+            degradation_coefficient: Ratio::new::<ratio>(1.5),
+            ..CyclingConfig::default()
+        });
+        assert!(matches!(
+            unit.step(&input(5.0, CyclingState::default())),
+            Err(CyclingError::InvalidInput(_))
+        ));
+
+        let unit = wrap(CyclingConfig::default());
+        assert!(matches!(
+            unit.step(&input(-1.0, CyclingState::default())),
+            Err(CyclingError::InvalidInput(_))
+        ));
+    }
+}
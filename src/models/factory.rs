@@ -0,0 +1,325 @@
+//! Build models from declarative configuration.
+//!
+//! Models in this crate are strongly typed: the fluid, thermo model, and flow
+//! arrangement are generic parameters fixed at compile time. Applications
+//! that let end users describe a plant in a config file have to make those
+//! choices at runtime instead. This module provides serde descriptions of
+//! models ([`ModelConfig`]), thermo models ([`ThermoConfig`]), and flow
+//! arrangements ([`ArrangementConfig`]), and builds them into runtime-dispatch
+//! types ([`AnyModel`], [`AnyThermo`], [`AnyArrangement`]) that hold the
+//! concrete, validated model.
+//!
+//! Any self-describing serde format works; the examples use JSON, and the
+//! same structure reads from TOML. Quantities are plain numbers in SI base
+//! units (kelvin, pascal, watt per kelvin, cubic meter, second).
+//!
+//! Requires the `serde` feature.
+//!
+//! ## Example
+//!
+//! ```
+//! use twine_models::models::factory::{AnyModel, ModelConfig};
+//!
+//! let config: ModelConfig = serde_json::from_str(r#"{
+//!     "model": "battery_pack",
+//!     "mass": 300.0,
+//!     "specific_heat": 1000.0,
+//!     "internal_resistance": 0.05,
+//!     "coolant_ua": 200.0,
+//!     "ambient_ua": 5.0
+//! }"#)?;
+//!
+//! let AnyModel::BatteryPack(pack) = config.build()? else {
+//!     unreachable!("the config describes a battery pack");
+//! };
+//! assert!((pack.heat_capacity().value - 300_000.0).abs() < 1e-9);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod arrangement;
+mod thermo;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uom::si::f64::{Time, Volume};
+
+use crate::{
+    models::{
+        boundary::schedule::{Interpolation, Schedule, ScheduleError},
+        thermal::{
+            battery::lumped::{BatteryPack, BatteryPackError, BatteryPackParameters},
+            hx::discretized::{
+                RecuperatorGivenUa, RecuperatorGivenUaConfig, RecuperatorGivenUaError,
+            },
+            pool::swimming::{SwimmingPool, SwimmingPoolError, SwimmingPoolParameters},
+            vessel::control_volume::{ControlVolume, ControlVolumeError},
+        },
+    },
+    support::{
+        hx::arrangement::ShellAndTubeConfigError,
+        thermo::{
+            fluid::{Air, CarbonDioxide},
+            model::{
+                PerfectGas, incompressible::IncompressibleParametersError,
+                perfect_gas::PerfectGasParametersError,
+            },
+        },
+    },
+};
+
+pub use arrangement::{AnyArrangement, ArrangementConfig, Mixing};
+pub use thermo::{AnyThermo, Gas, Liquid, ThermoConfig};
+#[cfg(any(feature = "python", feature = "ffi"))]
+pub(crate) use thermo::{with_gas, with_thermo};
+
+/// Errors that can occur when building from a configuration.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum FactoryError {
+    /// The perfect gas constants for the selected fluid are invalid.
+    #[error(transparent)]
+    PerfectGas(#[from] PerfectGasParametersError),
+
+    /// The incompressible constants for the selected fluid are invalid.
+    #[error(transparent)]
+    Incompressible(#[from] IncompressibleParametersError),
+
+    /// The shell-and-tube pass counts are invalid.
+    #[error(transparent)]
+    ShellAndTube(#[from] ShellAndTubeConfigError),
+
+    /// The shell-and-tube arrangement has more shell passes than are supported.
+    #[error("shell-and-tube arrangements support 1 to 4 shell passes, got {0}")]
+    UnsupportedShellPasses(u16),
+
+    /// The battery pack parameters are invalid.
+    #[error(transparent)]
+    BatteryPack(#[from] BatteryPackError),
+
+    /// The swimming pool parameters are invalid.
+    #[error(transparent)]
+    SwimmingPool(#[from] SwimmingPoolError),
+
+    /// The schedule points or period are invalid.
+    #[error(transparent)]
+    Schedule(#[from] ScheduleError),
+
+    /// The control volume parameters are invalid.
+    #[error(transparent)]
+    ControlVolume(#[from] ControlVolumeError),
+
+    /// The recuperator configuration is invalid.
+    #[error(transparent)]
+    Recuperator(#[from] RecuperatorGivenUaError),
+}
+
+/// Declarative description of a model.
+///
+/// The `model` field selects the variant; the remaining fields are the
+/// parameters of that model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "model", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ModelConfig {
+    /// A [`BatteryPack`].
+    BatteryPack(BatteryPackParameters),
+
+    /// A [`SwimmingPool`].
+    SwimmingPool(SwimmingPoolParameters),
+
+    /// A [`Schedule`].
+    Schedule(ScheduleConfig),
+
+    /// A [`ControlVolume`] holding a perfect gas.
+    ControlVolume(ControlVolumeConfig),
+
+    /// A perfect-gas [`RecuperatorGivenUa`].
+    RecuperatorGivenUa(RecuperatorConfig),
+}
+
+/// Configuration for a [`Schedule`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleConfig {
+    /// `[time, value]` points in increasing time order, with time in seconds.
+    pub points: Vec<(Time, f64)>,
+
+    /// How values are filled in between points.
+    #[serde(default)]
+    pub interpolation: Interpolation,
+
+    /// Repeat period, or `None` for a one-shot schedule.
+    #[serde(default)]
+    pub period: Option<Time>,
+}
+
+/// Configuration for a perfect-gas [`ControlVolume`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ControlVolumeConfig {
+    /// Gas held in the volume.
+    pub gas: Gas,
+
+    /// Vessel volume.
+    pub volume: Volume,
+}
+
+/// Configuration for a perfect-gas [`RecuperatorGivenUa`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RecuperatorConfig {
+    /// Working fluid on both sides.
+    pub gas: Gas,
+
+    /// Number of discretization segments.
+    pub segments: usize,
+
+    /// Solver tolerances and iteration limits.
+    #[serde(default)]
+    pub solver: RecuperatorGivenUaConfig,
+}
+
+/// A model built from a [`ModelConfig`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum AnyModel {
+    /// A lumped battery pack.
+    BatteryPack(BatteryPack),
+
+    /// A swimming pool.
+    SwimmingPool(SwimmingPool),
+
+    /// A time schedule.
+    Schedule(Schedule),
+
+    /// A control volume holding air.
+    AirControlVolume(ControlVolume<Air, PerfectGas<Air>>),
+
+    /// A control volume holding carbon dioxide.
+    CarbonDioxideControlVolume(ControlVolume<CarbonDioxide, PerfectGas<CarbonDioxide>>),
+
+    /// A recuperator with air on both sides.
+    AirRecuperatorGivenUa(RecuperatorGivenUa<Air, PerfectGas<Air>>),
+
+    /// A recuperator with carbon dioxide on both sides.
+    CarbonDioxideRecuperatorGivenUa(RecuperatorGivenUa<CarbonDioxide, PerfectGas<CarbonDioxide>>),
+}
+
+impl ModelConfig {
+    /// Builds the described model.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FactoryError`] if the model or its thermo model rejects
+    /// the configured parameters.
+    pub fn build(&self) -> Result<AnyModel, FactoryError> {
+        Ok(match self {
+            Self::BatteryPack(parameters) => AnyModel::BatteryPack(BatteryPack::new(*parameters)?),
+            Self::SwimmingPool(parameters) => {
+                AnyModel::SwimmingPool(SwimmingPool::new(*parameters)?)
+            }
+            Self::Schedule(config) => {
+                let schedule = Schedule::new(config.points.iter().copied(), config.interpolation)?;
+                AnyModel::Schedule(match config.period {
+                    Some(period) => schedule.repeating(period)?,
+                    None => schedule,
+                })
+            }
+            Self::ControlVolume(ControlVolumeConfig { gas, volume }) => match gas {
+                Gas::Air => {
+                    AnyModel::AirControlVolume(ControlVolume::new(PerfectGas::new()?, *volume)?)
+                }
+                Gas::CarbonDioxide => AnyModel::CarbonDioxideControlVolume(ControlVolume::new(
+                    PerfectGas::new()?,
+                    *volume,
+                )?),
+            },
+            Self::RecuperatorGivenUa(RecuperatorConfig {
+                gas,
+                segments,
+                solver,
+            }) => match gas {
+                Gas::Air => AnyModel::AirRecuperatorGivenUa(RecuperatorGivenUa::new(
+                    PerfectGas::new()?,
+                    *segments,
+                    *solver,
+                )?),
+                Gas::CarbonDioxide => AnyModel::CarbonDioxideRecuperatorGivenUa(
+                    RecuperatorGivenUa::new(PerfectGas::new()?, *segments, *solver)?,
+                ),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use twine_core::Model;
+    use uom::si::{time::hour, volume::cubic_meter};
+
+    fn build(json: &str) -> Result<AnyModel, FactoryError> {
+        serde_json::from_str::<ModelConfig>(json).unwrap().build()
+    }
+
+    #[test]
+    fn builds_repeating_schedule() {
+        let model = build(
+            r#"{
+                "model": "schedule",
+                "points": [[0, 1.0], [43200, 2.0]],
+                "interpolation": "linear",
+                "period": 86400
+            }"#,
+        )
+        .unwrap();
+
+        let AnyModel::Schedule(schedule) = model else {
+            panic!("expected a schedule, got {model:?}");
+        };
+        assert_eq!(schedule.interpolation(), Interpolation::Linear);
+        assert_relative_eq!(schedule.call(&Time::new::<hour>(30.0)).unwrap(), 1.5);
+    }
+
+    #[test]
+    fn selects_gas_at_runtime() {
+        let model =
+            build(r#"{ "model": "control_volume", "gas": "carbon_dioxide", "volume": 0.25 }"#)
+                .unwrap();
+        let AnyModel::CarbonDioxideControlVolume(cv) = model else {
+            panic!("expected a CO2 control volume, got {model:?}");
+        };
+        assert_relative_eq!(cv.volume().get::<cubic_meter>(), 0.25);
+
+        let model =
+            build(r#"{ "model": "recuperator_given_ua", "gas": "air", "segments": 10 }"#).unwrap();
+        assert!(matches!(model, AnyModel::AirRecuperatorGivenUa(_)));
+    }
+
+    #[test]
+    fn reports_invalid_parameters() {
+        let error =
+            build(r#"{ "model": "control_volume", "gas": "air", "volume": -1.0 }"#).unwrap_err();
+        assert!(matches!(
+            error,
+            FactoryError::ControlVolume(ControlVolumeError::NonPositiveVolume(_))
+        ));
+
+        let error = build(r#"{ "model": "recuperator_given_ua", "gas": "air", "segments": 7 }"#)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            FactoryError::Recuperator(RecuperatorGivenUaError::UnsupportedSegments(7))
+        ));
+
+        let error = build(r#"{ "model": "schedule", "points": [] }"#).unwrap_err();
+        assert!(matches!(
+            error,
+            FactoryError::Schedule(ScheduleError::Empty)
+        ));
+    }
+
+    #[test]
+    fn rejects_unknown_models() {
+        assert!(serde_json::from_str::<ModelConfig>(r#"{ "model": "flux_capacitor" }"#).is_err());
+    }
+}
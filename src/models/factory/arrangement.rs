@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+
+use crate::support::hx::{
+    CapacitanceRate, Effectiveness, EffectivenessRelation, Ntu,
+    arrangement::{
+        CounterFlow, CrossFlow, Mixed, ParallelFlow, ShellAndTube, ShellAndTubeConfigError, Unmixed,
+    },
+};
+
+use super::FactoryError;
+
+/// Whether a cross-flow stream is mixed across the flow channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Mixing {
+    /// The stream is mixed.
+    Mixed,
+
+    /// The stream is unmixed.
+    Unmixed,
+}
+
+/// Declarative description of a heat exchanger flow arrangement.
+///
+/// ```
+/// use twine_models::models::factory::ArrangementConfig;
+///
+/// let config: ArrangementConfig = serde_json::from_str(
+///     r#"{ "type": "shell_and_tube", "shell_passes": 2, "tube_passes": 4 }"#,
+/// )?;
+/// let arrangement = config.build()?;
+/// assert_eq!(arrangement.config(), config);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ArrangementConfig {
+    /// Counterflow.
+    CounterFlow,
+
+    /// Parallel flow.
+    ParallelFlow,
+
+    /// Cross flow, with the mixing of each stream in inlet order.
+    CrossFlow {
+        /// Mixing of the first stream.
+        first: Mixing,
+
+        /// Mixing of the second stream.
+        second: Mixing,
+    },
+
+    /// Shell-and-tube.
+    ShellAndTube {
+        /// Number of shell passes (1 to 4).
+        shell_passes: u16,
+
+        /// Number of tube passes, an even multiple of the shell passes.
+        tube_passes: u16,
+    },
+}
+
+/// A validated flow arrangement chosen at runtime.
+///
+/// Implements [`EffectivenessRelation`] by dispatching to the matching
+/// arrangement type, so it can be passed to the functional heat exchanger
+/// API in place of a compile-time arrangement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnyArrangement {
+    config: ArrangementConfig,
+}
+
+impl ArrangementConfig {
+    /// Validates the described arrangement.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FactoryError`] if the shell-and-tube pass counts are
+    /// invalid or have more than four shell passes.
+    pub fn build(&self) -> Result<AnyArrangement, FactoryError> {
+        if let Self::ShellAndTube {
+            shell_passes,
+            tube_passes,
+        } = *self
+        {
+            if shell_passes == 0 {
+                return Err(ShellAndTubeConfigError::ZeroShellPasses.into());
+            }
+            if shell_passes > 4 {
+                return Err(FactoryError::UnsupportedShellPasses(shell_passes));
+            }
+            if tube_passes < 2 * shell_passes {
+                return Err(ShellAndTubeConfigError::InsufficientTubePasses.into());
+            }
+            if !tube_passes.is_multiple_of(2 * shell_passes) {
+                return Err(ShellAndTubeConfigError::TubePassesNotMultiple.into());
+            }
+        }
+
+        Ok(AnyArrangement { config: *self })
+    }
+}
+
+impl AnyArrangement {
+    /// Returns the configuration this arrangement was built from.
+    #[must_use]
+    pub fn config(&self) -> ArrangementConfig {
+        self.config
+    }
+}
+
+impl EffectivenessRelation for AnyArrangement {
+    fn effectiveness(&self, ntu: Ntu, capacitance_rates: [CapacitanceRate; 2]) -> Effectiveness {
+        match self.config {
+            ArrangementConfig::CounterFlow => CounterFlow.effectiveness(ntu, capacitance_rates),
+            ArrangementConfig::ParallelFlow => ParallelFlow.effectiveness(ntu, capacitance_rates),
+            ArrangementConfig::CrossFlow { first, second } => match (first, second) {
+                (Mixing::Mixed, Mixing::Mixed) => {
+                    CrossFlow::<Mixed, Mixed>::new().effectiveness(ntu, capacitance_rates)
+                }
+                (Mixing::Mixed, Mixing::Unmixed) => {
+                    CrossFlow::<Mixed, Unmixed>::new().effectiveness(ntu, capacitance_rates)
+                }
+                (Mixing::Unmixed, Mixing::Mixed) => {
+                    CrossFlow::<Unmixed, Mixed>::new().effectiveness(ntu, capacitance_rates)
+                }
+                (Mixing::Unmixed, Mixing::Unmixed) => {
+                    CrossFlow::<Unmixed, Unmixed>::new().effectiveness(ntu, capacitance_rates)
+                }
+            },
+            // The effectiveness depends only on the shell pass count, which
+            // `build` has limited to 1..=4.
+            ArrangementConfig::ShellAndTube { shell_passes, .. } => match shell_passes {
+                1 => shell_and_tube::<1, 2>().effectiveness(ntu, capacitance_rates),
+                2 => shell_and_tube::<2, 4>().effectiveness(ntu, capacitance_rates),
+                3 => shell_and_tube::<3, 6>().effectiveness(ntu, capacitance_rates),
+                4 => shell_and_tube::<4, 8>().effectiveness(ntu, capacitance_rates),
+                _ => unreachable!("validated at build"),
+            },
+        }
+    }
+}
+
+fn shell_and_tube<const S: u16, const T: u16>() -> ShellAndTube<S, T> {
+    ShellAndTube::new().expect("pass counts are valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{f64::ThermalConductance, ratio::ratio, thermal_conductance::watt_per_kelvin};
+
+    fn rates(a: f64, b: f64) -> [CapacitanceRate; 2] {
+        [
+            CapacitanceRate::new::<watt_per_kelvin>(a).unwrap(),
+            CapacitanceRate::new::<watt_per_kelvin>(b).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn dispatches_to_compile_time_arrangements() {
+        let capacitance_rates = rates(1000.0, 600.0);
+        let ntu = Ntu::from_conductance_and_capacitance_rates(
+            ThermalConductance::new::<watt_per_kelvin>(900.0),
+            capacitance_rates,
+        )
+        .unwrap();
+
+        let runtime = |config: ArrangementConfig| {
+            config
+                .build()
+                .unwrap()
+                .effectiveness(ntu, capacitance_rates)
+                .get::<ratio>()
+        };
+
+        assert_relative_eq!(
+            runtime(ArrangementConfig::CounterFlow),
+            CounterFlow
+                .effectiveness(ntu, capacitance_rates)
+                .get::<ratio>()
+        );
+        assert_relative_eq!(
+            runtime(ArrangementConfig::CrossFlow {
+                first: Mixing::Unmixed,
+                second: Mixing::Mixed,
+            }),
+            CrossFlow::<Unmixed, Mixed>::new()
+                .effectiveness(ntu, capacitance_rates)
+                .get::<ratio>()
+        );
+        assert_relative_eq!(
+            runtime(ArrangementConfig::ShellAndTube {
+                shell_passes: 2,
+                tube_passes: 8,
+            }),
+            ShellAndTube::<2, 8>::new()
+                .unwrap()
+                .effectiveness(ntu, capacitance_rates)
+                .get::<ratio>()
+        );
+    }
+
+    #[test]
+    fn validates_shell_and_tube_passes() {
+        let build = |shell_passes, tube_passes| {
+            ArrangementConfig::ShellAndTube {
+                shell_passes,
+                tube_passes,
+            }
+            .build()
+        };
+
+        assert!(matches!(
+            build(0, 2),
+            Err(FactoryError::ShellAndTube(
+                ShellAndTubeConfigError::ZeroShellPasses
+            ))
+        ));
+        assert!(matches!(
+            build(2, 6),
+            Err(FactoryError::ShellAndTube(
+                ShellAndTubeConfigError::TubePassesNotMultiple
+            ))
+        ));
+        assert!(matches!(
+            build(5, 10),
+            Err(FactoryError::UnsupportedShellPasses(5))
+        ));
+    }
+}
@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+
+use crate::support::thermo::{
+    fluid::{Air, CarbonDioxide, Water},
+    model::{Incompressible, PerfectGas},
+};
+
+use super::FactoryError;
+
+/// A gas with built-in perfect gas constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Gas {
+    /// Dry air.
+    Air,
+
+    /// Carbon dioxide.
+    CarbonDioxide,
+}
+
+/// A liquid with built-in incompressible constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Liquid {
+    /// Liquid water.
+    Water,
+}
+
+/// Declarative description of a thermo model.
+///
+/// ```
+/// use twine_models::models::factory::{AnyThermo, ThermoConfig};
+///
+/// let config: ThermoConfig =
+///     serde_json::from_str(r#"{ "model": "perfect_gas", "fluid": "air" }"#)?;
+/// assert!(matches!(config.build()?, AnyThermo::PerfectGasAir(_)));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "model", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ThermoConfig {
+    /// A [`PerfectGas`] model.
+    PerfectGas {
+        /// Gas to model.
+        fluid: Gas,
+    },
+
+    /// An [`Incompressible`] model.
+    Incompressible {
+        /// Liquid to model.
+        fluid: Liquid,
+    },
+}
+
+/// A thermo model built from a [`ThermoConfig`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum AnyThermo {
+    /// Perfect gas model of air.
+    PerfectGasAir(PerfectGas<Air>),
+
+    /// Perfect gas model of carbon dioxide.
+    PerfectGasCarbonDioxide(PerfectGas<CarbonDioxide>),
+
+    /// Incompressible model of water.
+    IncompressibleWater(Incompressible<Water>),
+}
+
+/// Evaluates `$body` with the concrete model held by an [`AnyThermo`] bound
+/// to `$model` and its fluid marker bound to `$fluid`.
+#[cfg(any(feature = "python", feature = "ffi"))]
+macro_rules! with_thermo {
+    ($thermo:expr, |$model:ident, $fluid:ident| $body:expr) => {
+        match $thermo {
+            $crate::models::factory::AnyThermo::PerfectGasAir($model) => {
+                let $fluid = $crate::support::thermo::fluid::Air;
+                $body
+            }
+            $crate::models::factory::AnyThermo::PerfectGasCarbonDioxide($model) => {
+                let $fluid = $crate::support::thermo::fluid::CarbonDioxide;
+                $body
+            }
+            $crate::models::factory::AnyThermo::IncompressibleWater($model) => {
+                let $fluid = $crate::support::thermo::fluid::Water;
+                $body
+            }
+        }
+    };
+}
+
+/// Like [`with_thermo!`], but only for perfect gas models, which have a
+/// pressure relation. Evaluates `$otherwise` for any other model.
+#[cfg(any(feature = "python", feature = "ffi"))]
+macro_rules! with_gas {
+    ($thermo:expr, |$model:ident, $fluid:ident| $body:expr, else $otherwise:expr) => {
+        match $thermo {
+            $crate::models::factory::AnyThermo::PerfectGasAir($model) => {
+                let $fluid = $crate::support::thermo::fluid::Air;
+                $body
+            }
+            $crate::models::factory::AnyThermo::PerfectGasCarbonDioxide($model) => {
+                let $fluid = $crate::support::thermo::fluid::CarbonDioxide;
+                $body
+            }
+            $crate::models::factory::AnyThermo::IncompressibleWater(_) => $otherwise,
+        }
+    };
+}
+
+#[cfg(any(feature = "python", feature = "ffi"))]
+pub(crate) use {with_gas, with_thermo};
+
+impl ThermoConfig {
+    /// Builds the described thermo model.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FactoryError`] if the fluid's built-in constants are invalid.
+    pub fn build(&self) -> Result<AnyThermo, FactoryError> {
+        Ok(match self {
+            Self::PerfectGas { fluid: Gas::Air } => AnyThermo::PerfectGasAir(PerfectGas::new()?),
+            Self::PerfectGas {
+                fluid: Gas::CarbonDioxide,
+            } => AnyThermo::PerfectGasCarbonDioxide(PerfectGas::new()?),
+            Self::Incompressible {
+                fluid: Liquid::Water,
+            } => AnyThermo::IncompressibleWater(Incompressible::new()?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_each_thermo_model() {
+        let cases = [
+            r#"{ "model": "perfect_gas", "fluid": "carbon_dioxide" }"#,
+            r#"{ "model": "incompressible", "fluid": "water" }"#,
+        ];
+        let built: Vec<_> = cases
+            .iter()
+            .map(|json| {
+                serde_json::from_str::<ThermoConfig>(json)
+                    .unwrap()
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+
+        assert!(matches!(built[0], AnyThermo::PerfectGasCarbonDioxide(_)));
+        assert!(matches!(built[1], AnyThermo::IncompressibleWater(_)));
+    }
+
+    #[test]
+    fn rejects_mismatched_fluid() {
+        let json = r#"{ "model": "incompressible", "fluid": "air" }"#;
+        assert!(serde_json::from_str::<ThermoConfig>(json).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+//! Steady-state flowsheets.
+//!
+//! A [`Flowsheet`] is a network of [`Component`]s whose inlet and outlet
+//! ports are joined by material [`Stream`]s, the crate's shared
+//! [`FluidPort`](crate::support::port::FluidPort) connector. Instead of each
+//! cycle model hand-writing its own convergence loop, the network is declared
+//! once and solved by a shared driver:
+//!
+//! 1. Components are ordered so each one runs after the components that feed
+//!    it.
+//! 2. Recycle loops are broken at tear streams. Any stream given a
+//!    [`guess`](FlowsheetBuilder::guess) is torn; a loop without one is
+//!    reported at build time, naming the stream that closes it.
+//! 3. An outer loop iterates on the tear streams' temperature, density, and
+//!    mass flow until they reproduce themselves, using Wegstein acceleration
+//!    by default or Newton's method (see [`TearMethod`]).
+//!
+//! [`Mixer`] and [`Splitter`] cover the usual junctions, and [`FnComponent`]
+//! wraps a closure for one-off unit operations. Any other model joins a
+//! flowsheet by implementing [`Component`].
+//!
+//! ## Quick start
+//!
+//! A heater in a recycle loop: 60% of the heated stream returns to the
+//! mixer.
+//!
+//! ```
+//! use twine_models::{
+//!     models::flowsheet::{FnComponent, Flowsheet, Mixer, Splitter, Stream},
+//!     support::thermo::{
+//!         capability::{HasEnthalpy, HasPressure, StateFrom},
+//!         fluid::Air,
+//!         model::PerfectGas,
+//!     },
+//! };
+//! use uom::si::{
+//!     f64::{MassRate, Power, Pressure, ThermodynamicTemperature},
+//!     mass_rate::kilogram_per_second,
+//!     power::kilowatt,
+//!     pressure::kilopascal,
+//!     thermodynamic_temperature::kelvin,
+//! };
+//!
+//! let air = PerfectGas::<Air>::new()?;
+//! let inlet = air.state_from((
+//!     Air,
+//!     ThermodynamicTemperature::new::<kelvin>(300.0),
+//!     Pressure::new::<kilopascal>(200.0),
+//! ))?;
+//! let feed_stream = Stream::new(inlet, MassRate::new::<kilogram_per_second>(1.0));
+//!
+//! let heater = FnComponent::new(1, 1, move |inlets: &[Stream<Air>]| {
+//!     let inlet = &inlets[0];
+//!     let p = air.pressure(&inlet.state)?;
+//!     let h = air.enthalpy(&inlet.state)? + Power::new::<kilowatt>(10.0) / inlet.mass_flow;
+//!     Ok(vec![Stream::new(air.state_from((Air, p, h))?, inlet.mass_flow)])
+//! });
+//!
+//! let mut sheet = Flowsheet::builder();
+//! let feed = sheet.feed("feed");
+//! let mixer = sheet.add("mixer", Mixer::new(air));
+//! let heater = sheet.add("heater", heater);
+//! let splitter = sheet.add("splitter", Splitter::new(vec![0.4, 0.6])?);
+//!
+//! let product = sheet.outlet(splitter, 0)?;
+//! let recycle = sheet.outlet(splitter, 1)?;
+//! sheet.connect(feed, mixer, 0)?;
+//! sheet.connect(recycle, mixer, 1)?;
+//! sheet.connect(sheet.outlet(mixer, 0)?, heater, 0)?;
+//! sheet.connect(sheet.outlet(heater, 0)?, splitter, 0)?;
+//! sheet.guess(recycle, feed_stream.clone());
+//!
+//! let sheet = sheet.build()?;
+//! let solution = sheet.solve(&[feed_stream])?;
+//!
+//! // 10 kW into 1 kg/s of air with cp ≈ 1 kJ/(kg·K) raises it about 10 K.
+//! let product = solution.stream(product);
+//! assert!((product.mass_flow.get::<kilogram_per_second>() - 1.0).abs() < 1e-9);
+//! assert!((product.state.temperature.get::<kelvin>() - 310.0).abs() < 0.1);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    Component, ComponentId, Flowsheet, FlowsheetBuilder, FlowsheetError, FlowsheetSolution,
+    FnComponent, Mixer, MixerError, Splitter, SplitterError, Stream, StreamId, TearConfig,
+    TearMethod,
+};
+
+/// Solves the flowsheet for the feed streams, in the order they were added.
+impl<Fluid: Clone> Model for Flowsheet<Fluid> {
+    type Input = Vec<Stream<Fluid>>;
+    type Output = FlowsheetSolution<Fluid>;
+    type Error = FlowsheetError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.solve(input)
+    }
+}
@@ -0,0 +1,822 @@
+mod components;
+mod graph;
+mod tear;
+
+use std::fmt;
+
+use thiserror::Error;
+use uom::si::{
+    f64::{MassDensity, MassRate, ThermodynamicTemperature},
+    mass_density::kilogram_per_cubic_meter,
+    mass_rate::kilogram_per_second,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::{
+    error::BoxError,
+    support::{port::FluidPort, thermo::State},
+};
+
+pub use components::{FnComponent, Mixer, MixerError, Splitter, SplitterError};
+pub use tear::{TearConfig, TearMethod};
+
+use graph::Edge;
+
+/// A material stream: a fluid state and its mass flow rate.
+///
+/// Streams are the crate's [`FluidPort`] connector, so models that already
+/// take or return fluid ports can be wrapped as components directly.
+pub type Stream<Fluid> = FluidPort<Fluid>;
+
+/// A unit operation in a flowsheet.
+///
+/// A component has a fixed number of inlet and outlet ports and computes its
+/// outlet streams from its inlet streams. Anything with internal iteration,
+/// such as a heat exchanger solve, does it inside [`Component::evaluate`];
+/// the flowsheet only iterates on recycle loops.
+pub trait Component<Fluid> {
+    /// Number of inlet ports.
+    fn inlets(&self) -> usize;
+
+    /// Number of outlet ports.
+    fn outlets(&self) -> usize;
+
+    /// Computes the outlet streams, in port order, from the inlet streams.
+    ///
+    /// # Errors
+    ///
+    /// Returns the component's own error, boxed.
+    fn evaluate(&self, inlets: &[Stream<Fluid>]) -> Result<Vec<Stream<Fluid>>, BoxError>;
+}
+
+/// Identifies a component added with [`FlowsheetBuilder::add`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ComponentId(usize);
+
+/// Identifies a feed or a component outlet stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StreamId(usize);
+
+/// Errors from building or solving a flowsheet.
+#[derive(Debug, Error)]
+pub enum FlowsheetError {
+    /// A connection names an inlet port the component does not have.
+    #[error("{component} has {inlets} inlet(s), so inlet {port} does not exist")]
+    NoSuchInlet {
+        component: String,
+        port: usize,
+        inlets: usize,
+    },
+
+    /// An outlet was requested from a port the component does not have.
+    #[error("{component} has {outlets} outlet(s), so outlet {port} does not exist")]
+    NoSuchOutlet {
+        component: String,
+        port: usize,
+        outlets: usize,
+    },
+
+    /// A connection targets an inlet that is already connected.
+    #[error("{component} inlet {port} is already connected")]
+    InletConnected { component: String, port: usize },
+
+    /// A stream is connected to more than one inlet.
+    #[error("{stream} already feeds another inlet; split it with a `Splitter`")]
+    StreamConnected { stream: String },
+
+    /// An inlet was never connected.
+    #[error("{component} inlet {port} is not connected")]
+    UnconnectedInlet { component: String, port: usize },
+
+    /// A guess was given for a stream that does not run between components.
+    #[error("{stream} is not a connection between components and cannot be torn")]
+    NotInternal { stream: String },
+
+    /// A stream that closes a recycle loop has no initial guess.
+    #[error("{stream} closes a recycle loop; give it an initial guess")]
+    MissingGuess { stream: String },
+
+    /// A tear loop setting is invalid.
+    #[error("invalid tear configuration: {0}")]
+    InvalidConfig(&'static str),
+
+    /// The number of feed streams passed to a solve does not match the
+    /// flowsheet.
+    #[error("expected {expected} feed stream(s), got {actual}")]
+    FeedCount { expected: usize, actual: usize },
+
+    /// A component returned the wrong number of outlet streams.
+    #[error("{component} returned {actual} outlet stream(s), expected {expected}")]
+    OutletCount {
+        component: String,
+        expected: usize,
+        actual: usize,
+    },
+
+    /// A component failed to evaluate.
+    #[error("{component} failed")]
+    Component {
+        component: String,
+        #[source]
+        source: BoxError,
+    },
+
+    /// A tear stream was driven to a non-finite value.
+    #[error("tear stream {stream} is not finite")]
+    NonFinite { stream: String },
+
+    /// The Newton Jacobian of the tear loop is singular.
+    #[error("tear stream Jacobian is singular")]
+    SingularJacobian,
+
+    /// The tear loop hit its iteration limit.
+    #[error("tear streams failed to converge in {iterations} iterations (residual={residual})")]
+    Convergence { iterations: usize, residual: f64 },
+}
+
+/// Where a stream comes from.
+#[derive(Debug, Clone, Copy)]
+enum Source {
+    Feed,
+    Outlet { component: usize },
+}
+
+#[derive(Debug, Clone)]
+struct StreamInfo {
+    name: String,
+    source: Source,
+    sink: Option<(usize, usize)>,
+}
+
+struct Node<Fluid> {
+    name: String,
+    component: Box<dyn Component<Fluid>>,
+    inlets: Vec<Option<StreamId>>,
+    outlets: Vec<StreamId>,
+}
+
+/// Assembles a [`Flowsheet`] from feeds, components, and connections.
+///
+/// See the [module documentation](super) for an example.
+pub struct FlowsheetBuilder<Fluid> {
+    nodes: Vec<Node<Fluid>>,
+    streams: Vec<StreamInfo>,
+    feeds: Vec<StreamId>,
+    guesses: Vec<(StreamId, Stream<Fluid>)>,
+    config: TearConfig,
+}
+
+impl<Fluid> Default for FlowsheetBuilder<Fluid> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            streams: Vec::new(),
+            feeds: Vec::new(),
+            guesses: Vec::new(),
+            config: TearConfig::default(),
+        }
+    }
+}
+
+impl<Fluid> FlowsheetBuilder<Fluid> {
+    /// Adds a feed stream, supplied when the flowsheet is solved.
+    ///
+    /// Feeds are passed to [`Flowsheet::solve`] in the order they are added.
+    pub fn feed(&mut self, name: impl Into<String>) -> StreamId {
+        let id = StreamId(self.streams.len());
+        self.streams.push(StreamInfo {
+            name: name.into(),
+            source: Source::Feed,
+            sink: None,
+        });
+        self.feeds.push(id);
+        id
+    }
+
+    /// Adds a component, creating a stream for each of its outlets.
+    pub fn add(
+        &mut self,
+        name: impl Into<String>,
+        component: impl Component<Fluid> + 'static,
+    ) -> ComponentId {
+        let name = name.into();
+        let index = self.nodes.len();
+
+        let outlets = (0..component.outlets())
+            .map(|port| {
+                let id = StreamId(self.streams.len());
+                self.streams.push(StreamInfo {
+                    name: format!("{name} outlet {port}"),
+                    source: Source::Outlet { component: index },
+                    sink: None,
+                });
+                id
+            })
+            .collect();
+
+        self.nodes.push(Node {
+            inlets: vec![None; component.inlets()],
+            component: Box::new(component),
+            name,
+            outlets,
+        });
+        ComponentId(index)
+    }
+
+    /// Returns the stream leaving `component` through outlet `port`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FlowsheetError::NoSuchOutlet`] if the component has no such
+    /// outlet.
+    pub fn outlet(&self, component: ComponentId, port: usize) -> Result<StreamId, FlowsheetError> {
+        let node = &self.nodes[component.0];
+        node.outlets
+            .get(port)
+            .copied()
+            .ok_or_else(|| FlowsheetError::NoSuchOutlet {
+                component: node.name.clone(),
+                port,
+                outlets: node.outlets.len(),
+            })
+    }
+
+    /// Connects `stream` to inlet `port` of `component`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FlowsheetError`] if the inlet does not exist or is already
+    /// connected, or if `stream` already feeds another inlet.
+    pub fn connect(
+        &mut self,
+        stream: StreamId,
+        component: ComponentId,
+        port: usize,
+    ) -> Result<(), FlowsheetError> {
+        let node = &mut self.nodes[component.0];
+        let info = &mut self.streams[stream.0];
+
+        let Some(inlet) = node.inlets.get_mut(port) else {
+            return Err(FlowsheetError::NoSuchInlet {
+                component: node.name.clone(),
+                port,
+                inlets: node.inlets.len(),
+            });
+        };
+        if inlet.is_some() {
+            return Err(FlowsheetError::InletConnected {
+                component: node.name.clone(),
+                port,
+            });
+        }
+        if info.sink.is_some() {
+            return Err(FlowsheetError::StreamConnected {
+                stream: info.name.clone(),
+            });
+        }
+
+        *inlet = Some(stream);
+        info.sink = Some((component.0, port));
+        Ok(())
+    }
+
+    /// Sets the initial value of a connection and marks it as a tear stream.
+    ///
+    /// Any loop without a guessed stream is torn automatically, and building
+    /// fails with [`FlowsheetError::MissingGuess`] naming the stream that
+    /// needs one. The torn stream carries the `Fluid` value of its guess.
+    pub fn guess(&mut self, stream: StreamId, value: Stream<Fluid>) {
+        match self.guesses.iter_mut().find(|(id, _)| *id == stream) {
+            Some((_, existing)) => *existing = value,
+            None => self.guesses.push((stream, value)),
+        }
+    }
+
+    /// Sets the tear loop method and convergence settings.
+    pub fn config(&mut self, config: TearConfig) {
+        self.config = config;
+    }
+
+    /// Checks the connections and plans the evaluation order.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FlowsheetError`] if an inlet is unconnected, a guess is
+    /// for a feed or an unconnected outlet, a recycle loop has no guessed
+    /// stream, or the tear configuration is invalid.
+    pub fn build(self) -> Result<Flowsheet<Fluid>, FlowsheetError> {
+        self.config.validate()?;
+
+        for node in &self.nodes {
+            if let Some(port) = node.inlets.iter().position(Option::is_none) {
+                return Err(FlowsheetError::UnconnectedInlet {
+                    component: node.name.clone(),
+                    port,
+                });
+            }
+        }
+
+        let edges: Vec<Edge> = self
+            .streams
+            .iter()
+            .enumerate()
+            .filter_map(|(stream, info)| match (info.source, info.sink) {
+                (Source::Outlet { component }, Some((to, _))) => Some(Edge {
+                    stream,
+                    from: component,
+                    to,
+                }),
+                _ => None,
+            })
+            .collect();
+
+        for (id, _) in &self.guesses {
+            if !edges.iter().any(|edge| edge.stream == id.0) {
+                return Err(FlowsheetError::NotInternal {
+                    stream: self.streams[id.0].name.clone(),
+                });
+            }
+        }
+
+        let plan = graph::plan(self.nodes.len(), &edges, |stream| {
+            self.guesses.iter().any(|(id, _)| id.0 == stream)
+        });
+        if let Some(&stream) = plan.back_edges.first() {
+            return Err(FlowsheetError::MissingGuess {
+                stream: self.streams[stream].name.clone(),
+            });
+        }
+
+        Ok(Flowsheet {
+            nodes: self.nodes,
+            streams: self.streams,
+            feeds: self.feeds,
+            tears: self.guesses,
+            order: plan.order,
+            config: self.config,
+        })
+    }
+}
+
+/// A steady-state network of components connected by material streams.
+///
+/// Built with [`Flowsheet::builder`]. Components are evaluated in sequence,
+/// and recycle loops are closed by iterating on tear streams with the
+/// configured [`TearMethod`].
+pub struct Flowsheet<Fluid> {
+    nodes: Vec<Node<Fluid>>,
+    streams: Vec<StreamInfo>,
+    feeds: Vec<StreamId>,
+    tears: Vec<(StreamId, Stream<Fluid>)>,
+    order: Vec<usize>,
+    config: TearConfig,
+}
+
+impl<Fluid> fmt::Debug for Flowsheet<Fluid> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = |ids: &mut dyn Iterator<Item = usize>| {
+            ids.map(|i| self.nodes[i].name.as_str()).collect::<Vec<_>>()
+        };
+        f.debug_struct("Flowsheet")
+            .field("order", &names(&mut self.order.iter().copied()))
+            .field("tears", &self.tears().collect::<Vec<_>>())
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Every stream of a solved flowsheet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowsheetSolution<Fluid> {
+    streams: Vec<Stream<Fluid>>,
+
+    /// Outer iterations used to converge the tear streams.
+    ///
+    /// A flowsheet without recycles is solved in one pass.
+    pub iterations: usize,
+
+    /// Final scaled tear residual (see [`TearConfig`]).
+    pub residual: f64,
+}
+
+impl<Fluid> FlowsheetSolution<Fluid> {
+    /// Returns a stream by id.
+    #[must_use]
+    pub fn stream(&self, id: StreamId) -> &Stream<Fluid> {
+        &self.streams[id.0]
+    }
+}
+
+impl<Fluid> Flowsheet<Fluid> {
+    /// Starts an empty flowsheet.
+    #[must_use]
+    pub fn builder() -> FlowsheetBuilder<Fluid> {
+        FlowsheetBuilder::default()
+    }
+
+    /// Returns the name of a stream.
+    #[must_use]
+    pub fn stream_name(&self, id: StreamId) -> &str {
+        &self.streams[id.0].name
+    }
+
+    /// Returns the names of the tear streams.
+    pub fn tears(&self) -> impl Iterator<Item = &str> {
+        self.tears.iter().map(|(id, _)| self.stream_name(*id))
+    }
+}
+
+impl<Fluid: Clone> Flowsheet<Fluid> {
+    /// Solves the flowsheet for the given feed streams.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FlowsheetError`] if the feed count is wrong, a component
+    /// fails, or the tear streams do not converge.
+    pub fn solve(
+        &self,
+        feeds: &[Stream<Fluid>],
+    ) -> Result<FlowsheetSolution<Fluid>, FlowsheetError> {
+        let x0 = self
+            .tears
+            .iter()
+            .flat_map(|(_, guess)| pack(guess))
+            .collect();
+        self.solve_from(feeds, x0)
+    }
+
+    /// Solves the flowsheet, starting the tear streams from a previous solution.
+    ///
+    /// A time-stepping simulation re-solves the same flowsheet with slowly
+    /// changing feeds. Seeding the tear loop with the last converged streams
+    /// instead of the build-time guesses usually converges in a few
+    /// iterations.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`solve`](Self::solve).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `previous` was not produced by this flowsheet.
+    pub fn solve_warm(
+        &self,
+        feeds: &[Stream<Fluid>],
+        previous: &FlowsheetSolution<Fluid>,
+    ) -> Result<FlowsheetSolution<Fluid>, FlowsheetError> {
+        assert_eq!(
+            previous.streams.len(),
+            self.streams.len(),
+            "previous solution is from a different flowsheet"
+        );
+        let x0 = self
+            .tears
+            .iter()
+            .flat_map(|(id, _)| pack(previous.stream(*id)))
+            .collect();
+        self.solve_from(feeds, x0)
+    }
+
+    fn solve_from(
+        &self,
+        feeds: &[Stream<Fluid>],
+        x0: Vec<f64>,
+    ) -> Result<FlowsheetSolution<Fluid>, FlowsheetError> {
+        if feeds.len() != self.feeds.len() {
+            return Err(FlowsheetError::FeedCount {
+                expected: self.feeds.len(),
+                actual: feeds.len(),
+            });
+        }
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("flowsheet", tears = self.tears.len()).entered();
+
+        let converged = tear::converge(|x| self.pass(feeds, x), x0, &self.config)?;
+        Ok(FlowsheetSolution {
+            streams: converged.payload,
+            iterations: converged.iterations,
+            residual: converged.residual,
+        })
+    }
+
+    /// Evaluates every component once with the tear streams set from `x`.
+    ///
+    /// Returns the recomputed tear variables and all streams.
+    fn pass(
+        &self,
+        feeds: &[Stream<Fluid>],
+        x: &[f64],
+    ) -> Result<(Vec<f64>, Vec<Stream<Fluid>>), FlowsheetError> {
+        let mut streams: Vec<Option<Stream<Fluid>>> = vec![None; self.streams.len()];
+        let mut inputs: Vec<Option<Stream<Fluid>>> = vec![None; self.streams.len()];
+
+        for (id, feed) in self.feeds.iter().zip(feeds) {
+            streams[id.0] = Some(feed.clone());
+            inputs[id.0] = Some(feed.clone());
+        }
+        for ((id, guess), values) in self.tears.iter().zip(x.chunks_exact(3)) {
+            let stream = unpack(values, guess).ok_or_else(|| FlowsheetError::NonFinite {
+                stream: self.stream_name(*id).to_owned(),
+            })?;
+            inputs[id.0] = Some(stream);
+        }
+
+        for &index in &self.order {
+            let node = &self.nodes[index];
+            let inlets: Vec<Stream<Fluid>> = node
+                .inlets
+                .iter()
+                .map(|id| {
+                    let id = id.expect("build checks that every inlet is connected");
+                    inputs[id.0]
+                        .clone()
+                        .expect("evaluation order puts producers first")
+                })
+                .collect();
+
+            let outlets =
+                node.component
+                    .evaluate(&inlets)
+                    .map_err(|source| FlowsheetError::Component {
+                        component: node.name.clone(),
+                        source,
+                    })?;
+            if outlets.len() != node.outlets.len() {
+                return Err(FlowsheetError::OutletCount {
+                    component: node.name.clone(),
+                    expected: node.outlets.len(),
+                    actual: outlets.len(),
+                });
+            }
+
+            for (id, outlet) in node.outlets.iter().zip(outlets) {
+                if inputs[id.0].is_none() {
+                    inputs[id.0] = Some(outlet.clone());
+                }
+                streams[id.0] = Some(outlet);
+            }
+        }
+
+        let streams: Vec<Stream<Fluid>> = streams
+            .into_iter()
+            .map(|stream| stream.expect("every stream has a feed or a producer"))
+            .collect();
+        let gx = self
+            .tears
+            .iter()
+            .flat_map(|(id, _)| pack(&streams[id.0]))
+            .collect();
+        Ok((gx, streams))
+    }
+}
+
+/// Tear variables of a stream: temperature (K), density (kg/m³), and mass
+/// flow rate (kg/s).
+fn pack<Fluid>(stream: &Stream<Fluid>) -> [f64; 3] {
+    [
+        stream.state.temperature.get::<kelvin>(),
+        stream.state.density.get::<kilogram_per_cubic_meter>(),
+        stream.mass_flow.get::<kilogram_per_second>(),
+    ]
+}
+
+/// Rebuilds a tear stream from its variables, taking the fluid and any
+/// pressure reference from `guess`.
+fn unpack<Fluid: Clone>(values: &[f64], guess: &Stream<Fluid>) -> Option<Stream<Fluid>> {
+    if !values.iter().all(|v| v.is_finite()) {
+        return None;
+    }
+    Some(Stream {
+        state: State::new(
+            ThermodynamicTemperature::new::<kelvin>(values[0]),
+            MassDensity::new::<kilogram_per_cubic_meter>(values[1]),
+            guess.state.fluid.clone(),
+        ),
+        mass_flow: MassRate::new::<kilogram_per_second>(values[2]),
+        pressure: guess.pressure,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        available_energy::joule_per_kilogram,
+        f64::{Power, Pressure},
+        power::kilowatt,
+        pressure::kilopascal,
+    };
+
+    use crate::support::{
+        thermo::{
+            capability::{HasEnthalpy, HasPressure, StateFrom},
+            fluid::Air,
+            model::PerfectGas,
+        },
+        units::SpecificEnthalpy,
+    };
+
+    fn air() -> PerfectGas<Air> {
+        PerfectGas::new().unwrap()
+    }
+
+    fn stream(t: f64, m_dot: f64) -> Stream<Air> {
+        let state = air()
+            .state_from((
+                Air,
+                ThermodynamicTemperature::new::<kelvin>(t),
+                Pressure::new::<kilopascal>(200.0),
+            ))
+            .unwrap();
+        Stream::new(state, MassRate::new::<kilogram_per_second>(m_dot))
+    }
+
+    /// Adds `q_dot` at constant pressure.
+    fn heater(q_dot: Power) -> impl Component<Air> {
+        FnComponent::new(1, 1, move |inlets: &[Stream<Air>]| {
+            let thermo = air();
+            let inlet = &inlets[0];
+            let p = thermo.pressure(&inlet.state)?;
+            let h = thermo.enthalpy(&inlet.state)? + q_dot / inlet.mass_flow;
+            let state = thermo.state_from((Air, p, h))?;
+            Ok(vec![Stream::new(state, inlet.mass_flow)])
+        })
+    }
+
+    /// feed → mixer → heater → splitter → (product, recycle → mixer)
+    fn recycle_loop(
+        config: TearConfig,
+        recycle_fraction: f64,
+    ) -> (Flowsheet<Air>, StreamId, StreamId) {
+        let mut sheet = Flowsheet::builder();
+        let feed = sheet.feed("feed");
+        let mixer = sheet.add("mixer", Mixer::new(air()));
+        let heater = sheet.add("heater", heater(Power::new::<kilowatt>(10.0)));
+        let splitter = sheet.add(
+            "splitter",
+            Splitter::new(vec![1.0 - recycle_fraction, recycle_fraction]).unwrap(),
+        );
+
+        let product = sheet.outlet(splitter, 0).unwrap();
+        let recycle = sheet.outlet(splitter, 1).unwrap();
+        sheet.connect(feed, mixer, 0).unwrap();
+        sheet.connect(recycle, mixer, 1).unwrap();
+        sheet
+            .connect(sheet.outlet(mixer, 0).unwrap(), heater, 0)
+            .unwrap();
+        sheet
+            .connect(sheet.outlet(heater, 0).unwrap(), splitter, 0)
+            .unwrap();
+        sheet.guess(recycle, stream(300.0, 0.5));
+        sheet.config(config);
+
+        (sheet.build().unwrap(), feed, product)
+    }
+
+    #[test]
+    fn recycle_loop_closes_the_overall_energy_balance() {
+        for method in [
+            TearMethod::Substitution { damping: 1.0 },
+            TearMethod::default(),
+            TearMethod::Newton {
+                relative_step: 1e-7,
+            },
+        ] {
+            let config = TearConfig {
+                method,
+                ..TearConfig::default()
+            };
+            let (sheet, _, product) = recycle_loop(config, 0.6);
+            let feed = stream(300.0, 1.0);
+            let solution = sheet.solve(std::slice::from_ref(&feed)).unwrap();
+
+            // Overall: ṁ·h_product = ṁ·h_feed + Q̇ with ṁ = 1 kg/s.
+            let product = solution.stream(product);
+            assert_relative_eq!(
+                product.mass_flow.get::<kilogram_per_second>(),
+                1.0,
+                max_relative = 1e-9
+            );
+            let expected: SpecificEnthalpy = air().enthalpy(&feed.state).unwrap()
+                + Power::new::<kilowatt>(10.0) / feed.mass_flow;
+            assert_relative_eq!(
+                air()
+                    .enthalpy(&product.state)
+                    .unwrap()
+                    .get::<joule_per_kilogram>(),
+                expected.get::<joule_per_kilogram>(),
+                max_relative = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn wegstein_beats_substitution_on_a_heavy_recycle() {
+        let iterations = |method| {
+            let config = TearConfig {
+                method,
+                max_iters: 500,
+                ..TearConfig::default()
+            };
+            let (sheet, _, _) = recycle_loop(config, 0.9);
+            sheet.solve(&[stream(300.0, 1.0)]).unwrap().iterations
+        };
+
+        let substitution = iterations(TearMethod::Substitution { damping: 1.0 });
+        let wegstein = iterations(TearMethod::default());
+        assert!(
+            wegstein * 5 < substitution,
+            "wegstein {wegstein}, substitution {substitution}"
+        );
+    }
+
+    #[test]
+    fn warm_start_from_a_nearby_solution_converges_faster() {
+        let (sheet, _, product) = recycle_loop(TearConfig::default(), 0.9);
+        let previous = sheet.solve(&[stream(300.0, 1.0)]).unwrap();
+
+        let feeds = [stream(301.0, 1.0)];
+        let cold = sheet.solve(&feeds).unwrap();
+        let warm = sheet.solve_warm(&feeds, &previous).unwrap();
+
+        assert!(
+            warm.iterations < cold.iterations,
+            "warm {}, cold {}",
+            warm.iterations,
+            cold.iterations
+        );
+        assert_relative_eq!(
+            warm.stream(product).state.temperature.get::<kelvin>(),
+            cold.stream(product).state.temperature.get::<kelvin>(),
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn identifies_unguessed_recycles_and_unconnected_ports() {
+        let mut sheet = Flowsheet::builder();
+        let feed = sheet.feed("feed");
+        let mixer = sheet.add("mixer", Mixer::new(air()));
+        let splitter = sheet.add("splitter", Splitter::new(vec![0.5, 0.5]).unwrap());
+        sheet.connect(feed, mixer, 0).unwrap();
+        sheet
+            .connect(sheet.outlet(mixer, 0).unwrap(), splitter, 0)
+            .unwrap();
+
+        assert!(matches!(
+            sheet.connect(feed, splitter, 0),
+            Err(FlowsheetError::InletConnected { .. })
+        ));
+        assert!(matches!(
+            sheet.connect(feed, mixer, 2),
+            Err(FlowsheetError::NoSuchInlet { inlets: 2, .. })
+        ));
+        assert!(matches!(
+            sheet.outlet(splitter, 2),
+            Err(FlowsheetError::NoSuchOutlet { outlets: 2, .. })
+        ));
+
+        sheet
+            .connect(sheet.outlet(splitter, 1).unwrap(), mixer, 1)
+            .unwrap();
+        let error = sheet.build().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "splitter outlet 1 closes a recycle loop; give it an initial guess"
+        );
+    }
+
+    #[test]
+    fn solves_open_flowsheets_in_one_pass() {
+        let mut sheet = Flowsheet::builder();
+        let hot = sheet.feed("hot");
+        let cold = sheet.feed("cold");
+        let mixer = sheet.add("mixer", Mixer::new(air()));
+        sheet.connect(hot, mixer, 0).unwrap();
+        sheet.connect(cold, mixer, 1).unwrap();
+        let product = sheet.outlet(mixer, 0).unwrap();
+        let sheet = sheet.build().unwrap();
+        assert_eq!(sheet.tears().count(), 0);
+
+        let solution = sheet
+            .solve(&[stream(400.0, 1.0), stream(300.0, 1.0)])
+            .unwrap();
+        assert_eq!(solution.iterations, 1);
+        assert_relative_eq!(
+            solution.stream(product).state.temperature.get::<kelvin>(),
+            350.0,
+            max_relative = 1e-12
+        );
+
+        assert!(matches!(
+            sheet.solve(&[stream(400.0, 1.0)]),
+            Err(FlowsheetError::FeedCount {
+                expected: 2,
+                actual: 1
+            })
+        ));
+    }
+}
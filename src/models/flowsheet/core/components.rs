@@ -0,0 +1,210 @@
+use std::fmt;
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::f64::{MassRate, Power, Pressure},
+};
+
+use crate::{
+    error::BoxError,
+    support::{
+        thermo::capability::{HasEnthalpy, HasPressure, StateFrom, ThermoModel},
+        units::SpecificEnthalpy,
+    },
+};
+
+use super::{Component, Stream};
+
+/// A component defined by a closure.
+///
+/// Handy for small unit operations, such as a fixed heat input or a pressure
+/// drop, that don't warrant their own type.
+pub struct FnComponent<F> {
+    inlets: usize,
+    outlets: usize,
+    evaluate: F,
+}
+
+impl<F> FnComponent<F> {
+    /// Wraps `evaluate`, which maps `inlets` streams to `outlets` streams.
+    pub fn new(inlets: usize, outlets: usize, evaluate: F) -> Self {
+        Self {
+            inlets,
+            outlets,
+            evaluate,
+        }
+    }
+}
+
+impl<F> fmt::Debug for FnComponent<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnComponent")
+            .field("inlets", &self.inlets)
+            .field("outlets", &self.outlets)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Fluid, F> Component<Fluid> for FnComponent<F>
+where
+    F: Fn(&[Stream<Fluid>]) -> Result<Vec<Stream<Fluid>>, BoxError>,
+{
+    fn inlets(&self) -> usize {
+        self.inlets
+    }
+
+    fn outlets(&self) -> usize {
+        self.outlets
+    }
+
+    fn evaluate(&self, inlets: &[Stream<Fluid>]) -> Result<Vec<Stream<Fluid>>, BoxError> {
+        (self.evaluate)(inlets)
+    }
+}
+
+/// Errors from a [`Mixer`].
+#[derive(Debug, Error)]
+pub enum MixerError {
+    /// The inlets carry no net flow, so the mixed enthalpy is undefined.
+    #[error("mixer inlets must carry a positive total mass flow, got {0:?}")]
+    NoFlow(MassRate),
+
+    /// A thermodynamic model operation failed.
+    #[error("thermodynamic model failed: {context}")]
+    ThermoModelFailed {
+        /// Operation context for the thermodynamic model failure.
+        context: String,
+
+        /// Underlying thermodynamic model error.
+        #[source]
+        source: BoxError,
+    },
+}
+
+impl MixerError {
+    fn thermo(context: &str, source: impl Into<BoxError>) -> Self {
+        Self::ThermoModelFailed {
+            context: context.to_owned(),
+            source: source.into(),
+        }
+    }
+}
+
+/// Adiabatic mixing of two streams.
+///
+/// The outlet carries the combined mass flow at the flow-weighted mean
+/// enthalpy and the lower of the inlet pressures. It takes the fluid of
+/// inlet 0.
+#[derive(Debug, Clone)]
+pub struct Mixer<Thermo> {
+    thermo: Thermo,
+}
+
+impl<Thermo> Mixer<Thermo> {
+    /// Creates a two-inlet mixer.
+    pub fn new(thermo: Thermo) -> Self {
+        Self { thermo }
+    }
+}
+
+impl<Fluid, Thermo> Component<Fluid> for Mixer<Thermo>
+where
+    Fluid: Clone,
+    Thermo: ThermoModel<Fluid = Fluid>
+        + HasPressure
+        + HasEnthalpy
+        + StateFrom<(Fluid, Pressure, SpecificEnthalpy)>,
+{
+    fn inlets(&self) -> usize {
+        2
+    }
+
+    fn outlets(&self) -> usize {
+        1
+    }
+
+    fn evaluate(&self, inlets: &[Stream<Fluid>]) -> Result<Vec<Stream<Fluid>>, BoxError> {
+        let mass_flow: MassRate = inlets.iter().map(|inlet| inlet.mass_flow).sum();
+        if mass_flow <= MassRate::ZERO {
+            return Err(MixerError::NoFlow(mass_flow).into());
+        }
+
+        let mut pressure = None;
+        let mut energy = Power::ZERO;
+        for inlet in inlets {
+            let p = self
+                .thermo
+                .pressure(&inlet.state)
+                .map_err(|error| MixerError::thermo("inlet pressure", error))?;
+            let h = self
+                .thermo
+                .enthalpy(&inlet.state)
+                .map_err(|error| MixerError::thermo("inlet enthalpy", error))?;
+            pressure = Some(pressure.map_or(p, |min: Pressure| min.min(p)));
+            energy += inlet.mass_flow * h;
+        }
+        let pressure = pressure.expect("a mixer has inlets");
+
+        let state = self
+            .thermo
+            .state_from((inlets[0].state.fluid.clone(), pressure, energy / mass_flow))
+            .map_err(|error| MixerError::thermo("outlet state", error))?;
+        Ok(vec![Stream::new(state, mass_flow)])
+    }
+}
+
+/// Errors from creating a [`Splitter`].
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum SplitterError {
+    /// The fractions are empty, negative, or do not sum to one.
+    #[error("split fractions must be non-negative and sum to 1, got {0:?}")]
+    InvalidFractions(Vec<f64>),
+}
+
+/// Divides one stream into several at the same state and pressure reference.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Splitter {
+    fractions: Vec<f64>,
+}
+
+impl Splitter {
+    /// Creates a splitter with one outlet per fraction of the inlet flow.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SplitterError::InvalidFractions`] unless the fractions are
+    /// non-empty, non-negative, and sum to one within `1e-12`.
+    pub fn new(fractions: Vec<f64>) -> Result<Self, SplitterError> {
+        let valid = !fractions.is_empty()
+            && fractions.iter().all(|f| f.is_finite() && *f >= 0.0)
+            && (fractions.iter().sum::<f64>() - 1.0).abs() <= 1e-12;
+        if valid {
+            Ok(Self { fractions })
+        } else {
+            Err(SplitterError::InvalidFractions(fractions))
+        }
+    }
+}
+
+impl<Fluid: Clone> Component<Fluid> for Splitter {
+    fn inlets(&self) -> usize {
+        1
+    }
+
+    fn outlets(&self) -> usize {
+        self.fractions.len()
+    }
+
+    fn evaluate(&self, inlets: &[Stream<Fluid>]) -> Result<Vec<Stream<Fluid>>, BoxError> {
+        let inlet = &inlets[0];
+        Ok(self
+            .fractions
+            .iter()
+            .map(|fraction| Stream {
+                mass_flow: inlet.mass_flow * *fraction,
+                ..inlet.clone()
+            })
+            .collect())
+    }
+}
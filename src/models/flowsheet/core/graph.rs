@@ -0,0 +1,135 @@
+/// A stream connecting an outlet of one component to an inlet of another.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Edge {
+    pub(super) stream: usize,
+    pub(super) from: usize,
+    pub(super) to: usize,
+}
+
+/// Evaluation order and the streams that must be torn to reach it.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Plan {
+    /// Component indices in evaluation order.
+    pub(super) order: Vec<usize>,
+
+    /// Streams that close a loop and were not already cut.
+    pub(super) back_edges: Vec<usize>,
+}
+
+/// Orders `components` so every uncut edge runs forward.
+///
+/// Edges for which `cut` returns true are removed first. Any loop that
+/// remains is broken at the edge that closes it during a depth-first walk
+/// over the components in index order; those edges are reported as
+/// [`Plan::back_edges`]. The order is the lowest-index-first topological
+/// sort of what is left, so unrelated components keep insertion order.
+pub(super) fn plan(components: usize, edges: &[Edge], cut: impl Fn(usize) -> bool) -> Plan {
+    let mut successors = vec![Vec::new(); components];
+    for edge in edges.iter().filter(|edge| !cut(edge.stream)) {
+        successors[edge.from].push(*edge);
+    }
+
+    let back_edges = find_back_edges(&successors);
+
+    let mut in_degree = vec![0_usize; components];
+    for edge in successors.iter().flatten() {
+        if !back_edges.contains(&edge.stream) {
+            in_degree[edge.to] += 1;
+        }
+    }
+
+    let mut order = Vec::with_capacity(components);
+    let mut done = vec![false; components];
+    while let Some(next) = (0..components).find(|&i| !done[i] && in_degree[i] == 0) {
+        done[next] = true;
+        order.push(next);
+        for edge in &successors[next] {
+            if !back_edges.contains(&edge.stream) {
+                in_degree[edge.to] -= 1;
+            }
+        }
+    }
+    debug_assert_eq!(order.len(), components, "back edges break every loop");
+
+    Plan { order, back_edges }
+}
+
+fn find_back_edges(successors: &[Vec<Edge>]) -> Vec<usize> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mark {
+        New,
+        Active,
+        Done,
+    }
+
+    let mut marks = vec![Mark::New; successors.len()];
+    let mut back_edges = Vec::new();
+
+    for root in 0..successors.len() {
+        if marks[root] != Mark::New {
+            continue;
+        }
+        marks[root] = Mark::Active;
+        let mut stack = vec![(root, 0)];
+
+        while let Some((node, next)) = stack.last_mut() {
+            let Some(edge) = successors[*node].get(*next) else {
+                marks[*node] = Mark::Done;
+                stack.pop();
+                continue;
+            };
+            *next += 1;
+
+            match marks[edge.to] {
+                Mark::New => {
+                    marks[edge.to] = Mark::Active;
+                    stack.push((edge.to, 0));
+                }
+                Mark::Active => back_edges.push(edge.stream),
+                Mark::Done => {}
+            }
+        }
+    }
+
+    back_edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge(stream: usize, from: usize, to: usize) -> Edge {
+        Edge { stream, from, to }
+    }
+
+    #[test]
+    fn orders_a_chain_without_tears() {
+        let edges = [edge(0, 2, 0), edge(1, 0, 1)];
+        let plan = plan(3, &edges, |_| false);
+        assert_eq!(plan.order, [2, 0, 1]);
+        assert!(plan.back_edges.is_empty());
+    }
+
+    #[test]
+    fn tears_the_stream_that_closes_a_recycle() {
+        // mixer (0) -> heater (1) -> splitter (2) -> mixer (0)
+        let edges = [edge(0, 0, 1), edge(1, 1, 2), edge(2, 2, 0)];
+
+        let plan_auto = plan(3, &edges, |_| false);
+        assert_eq!(plan_auto.back_edges, [2]);
+        assert_eq!(plan_auto.order, [0, 1, 2]);
+
+        let plan_cut = plan(3, &edges, |stream| stream == 1);
+        assert!(plan_cut.back_edges.is_empty());
+        assert_eq!(plan_cut.order, [2, 0, 1]);
+    }
+
+    #[test]
+    fn tears_every_independent_loop() {
+        // Two loops sharing component 1: 0 <-> 1 and 1 <-> 2.
+        let edges = [edge(0, 0, 1), edge(1, 1, 0), edge(2, 1, 2), edge(3, 2, 1)];
+        let plan = plan(3, &edges, |_| false);
+        assert_eq!(plan.back_edges, [1, 3]);
+        assert_eq!(plan.order, [0, 1, 2]);
+    }
+}
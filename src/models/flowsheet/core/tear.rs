@@ -0,0 +1,310 @@
+use crate::support::numerics::linear::solve_linear;
+
+use super::FlowsheetError;
+
+/// Outer-loop method used to converge tear streams.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TearMethod {
+    /// Damped successive substitution: `x ← x + damping·(g(x) − x)`.
+    Substitution {
+        /// Step fraction in `(0, 1]`.
+        damping: f64,
+    },
+
+    /// Wegstein acceleration, applied to each tear variable independently.
+    ///
+    /// The acceleration factor `q = s / (s − 1)`, where `s` is the secant
+    /// slope of `g` from the previous iteration, is clamped to
+    /// `[q_min, q_max]`, and the update is `x ← q·x + (1 − q)·g(x)`.
+    /// `q = 0` is plain substitution; negative values accelerate.
+    Wegstein {
+        /// Lower bound on `q`.
+        q_min: f64,
+
+        /// Upper bound on `q`, below 1.
+        q_max: f64,
+    },
+
+    /// Newton's method on `g(x) − x` with a forward-difference Jacobian.
+    ///
+    /// Each iteration costs one flowsheet pass per tear variable plus one.
+    /// Worth it for tightly coupled recycles where Wegstein stalls.
+    Newton {
+        /// Perturbation as a fraction of `max(|x|, 1)`.
+        relative_step: f64,
+    },
+}
+
+impl Default for TearMethod {
+    /// Wegstein with `q` bounded to `[-5, 0]`.
+    fn default() -> Self {
+        Self::Wegstein {
+            q_min: -5.0,
+            q_max: 0.0,
+        }
+    }
+}
+
+/// Convergence settings for the tear stream loop.
+///
+/// The loop converges when every tear variable satisfies
+/// `|g(x) − x| ≤ tolerance · max(|x|, 1)`. Tear variables are temperature
+/// in K, density in kg/m³, and mass flow rate in kg/s.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TearConfig {
+    /// Outer-loop method.
+    pub method: TearMethod,
+
+    /// Maximum number of outer iterations.
+    pub max_iters: usize,
+
+    /// Scaled convergence tolerance.
+    pub tolerance: f64,
+}
+
+impl Default for TearConfig {
+    fn default() -> Self {
+        Self {
+            method: TearMethod::default(),
+            max_iters: 100,
+            tolerance: 1e-10,
+        }
+    }
+}
+
+impl TearConfig {
+    pub(super) fn validate(&self) -> Result<(), FlowsheetError> {
+        if self.max_iters == 0 {
+            return Err(FlowsheetError::InvalidConfig(
+                "max_iters must be at least 1",
+            ));
+        }
+        if !(self.tolerance.is_finite() && self.tolerance > 0.0) {
+            return Err(FlowsheetError::InvalidConfig(
+                "tolerance must be finite and positive",
+            ));
+        }
+        match self.method {
+            TearMethod::Substitution { damping } if !(damping > 0.0 && damping <= 1.0) => {
+                Err(FlowsheetError::InvalidConfig("damping must be in (0, 1]"))
+            }
+            TearMethod::Wegstein { q_min, q_max }
+                if !(q_min.is_finite() && q_min <= q_max && q_max < 1.0) =>
+            {
+                Err(FlowsheetError::InvalidConfig(
+                    "Wegstein bounds must satisfy q_min <= q_max < 1",
+                ))
+            }
+            TearMethod::Newton { relative_step }
+                if !(relative_step.is_finite() && relative_step > 0.0) =>
+            {
+                Err(FlowsheetError::InvalidConfig(
+                    "relative_step must be finite and positive",
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A converged tear loop.
+pub(super) struct Converged<T> {
+    /// What `g` returned alongside the final tear values.
+    pub(super) payload: T,
+    pub(super) iterations: usize,
+    pub(super) residual: f64,
+}
+
+/// Solves `x = g(x)` for the tear variables.
+///
+/// `g` returns the recomputed tear variables and a payload; the payload of
+/// the converged evaluation is returned.
+pub(super) fn converge<T>(
+    mut g: impl FnMut(&[f64]) -> Result<(Vec<f64>, T), FlowsheetError>,
+    mut x: Vec<f64>,
+    config: &TearConfig,
+) -> Result<Converged<T>, FlowsheetError> {
+    let mut previous: Option<(Vec<f64>, Vec<f64>)> = None;
+    let mut residual = f64::INFINITY;
+
+    for iteration in 1..=config.max_iters {
+        let (gx, payload) = g(&x)?;
+        residual = scaled_residual(&x, &gx);
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(iteration, residual);
+
+        if residual <= config.tolerance {
+            return Ok(Converged {
+                payload,
+                iterations: iteration,
+                residual,
+            });
+        }
+
+        let next = match config.method {
+            TearMethod::Substitution { damping } => x
+                .iter()
+                .zip(&gx)
+                .map(|(x, gx)| x + damping * (gx - x))
+                .collect(),
+            TearMethod::Wegstein { q_min, q_max } => {
+                wegstein(&x, &gx, previous.as_ref(), q_min, q_max)
+            }
+            TearMethod::Newton { relative_step } => newton(&mut g, &x, &gx, relative_step)?,
+        };
+        previous = Some((x, gx));
+        x = next;
+    }
+
+    Err(FlowsheetError::Convergence {
+        iterations: config.max_iters,
+        residual,
+    })
+}
+
+fn scaled_residual(x: &[f64], gx: &[f64]) -> f64 {
+    x.iter()
+        .zip(gx)
+        .map(|(x, gx)| (gx - x).abs() / x.abs().max(1.0))
+        .fold(0.0, f64::max)
+}
+
+fn wegstein(
+    x: &[f64],
+    gx: &[f64],
+    previous: Option<&(Vec<f64>, Vec<f64>)>,
+    q_min: f64,
+    q_max: f64,
+) -> Vec<f64> {
+    (0..x.len())
+        .map(|i| {
+            let q = previous.map_or(0.0, |(x_prev, gx_prev)| {
+                let dx = x[i] - x_prev[i];
+                if dx == 0.0 {
+                    return 0.0;
+                }
+                let slope = (gx[i] - gx_prev[i]) / dx;
+                // A unit slope means no fixed point along this variable;
+                // clamping handles the infinite `q`.
+                (slope / (slope - 1.0)).clamp(q_min, q_max)
+            });
+            let q = if q.is_finite() { q } else { q_min };
+            q * x[i] + (1.0 - q) * gx[i]
+        })
+        .collect()
+}
+
+fn newton<T>(
+    g: &mut impl FnMut(&[f64]) -> Result<(Vec<f64>, T), FlowsheetError>,
+    x: &[f64],
+    gx: &[f64],
+    relative_step: f64,
+) -> Result<Vec<f64>, FlowsheetError> {
+    let n = x.len();
+    let f: Vec<f64> = gx.iter().zip(x).map(|(gx, x)| gx - x).collect();
+
+    let mut jacobian = vec![vec![0.0; n]; n];
+    for j in 0..n {
+        let step = relative_step * x[j].abs().max(1.0);
+        let mut perturbed = x.to_vec();
+        perturbed[j] += step;
+        let (g_perturbed, _) = g(&perturbed)?;
+        for i in 0..n {
+            let f_perturbed = g_perturbed[i] - perturbed[i];
+            jacobian[i][j] = (f_perturbed - f[i]) / step;
+        }
+    }
+
+    let rhs = f.iter().map(|f| -f).collect();
+    let dx = solve_linear(jacobian, rhs).ok_or(FlowsheetError::SingularJacobian)?;
+    Ok(x.iter().zip(dx).map(|(x, dx)| x + dx).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    /// `g(x) = [0.5·cos(y) + 1, 0.9·x − 0.2·y]`: a coupled contraction.
+    #[allow(clippy::unnecessary_wraps)]
+    fn g(x: &[f64]) -> Result<(Vec<f64>, ()), FlowsheetError> {
+        Ok((vec![0.5 * x[1].cos() + 1.0, 0.9 * x[0] - 0.2 * x[1]], ()))
+    }
+
+    fn solve(method: TearMethod) -> Converged<()> {
+        let config = TearConfig {
+            method,
+            ..TearConfig::default()
+        };
+        converge(g, vec![0.0, 0.0], &config).unwrap()
+    }
+
+    #[test]
+    fn every_method_reaches_the_same_fixed_point() {
+        let substitution = solve(TearMethod::Substitution { damping: 1.0 });
+        let wegstein = solve(TearMethod::default());
+        let newton = solve(TearMethod::Newton {
+            relative_step: 1e-7,
+        });
+
+        assert!(newton.iterations < substitution.iterations);
+        for converged in [substitution, wegstein, newton] {
+            assert!(converged.residual <= 1e-10);
+        }
+    }
+
+    #[test]
+    fn wegstein_accelerates_a_slow_linear_contraction() {
+        // g(x) = 0.95·x + 1 has slope 0.95, so substitution needs hundreds of
+        // iterations while Wegstein's secant finds the fixed point at once.
+        let g = |x: &[f64]| Ok((vec![0.95 * x[0] + 1.0], x[0]));
+        let config = TearConfig {
+            method: TearMethod::Wegstein {
+                q_min: -50.0,
+                q_max: 0.0,
+            },
+            ..TearConfig::default()
+        };
+
+        let converged = converge(g, vec![0.0], &config).unwrap();
+        assert!(converged.iterations <= 4);
+        assert_relative_eq!(converged.payload, 20.0, max_relative = 1e-9);
+
+        let substitution = TearConfig {
+            method: TearMethod::Substitution { damping: 1.0 },
+            ..config
+        };
+        assert!(matches!(
+            converge(g, vec![0.0], &substitution),
+            Err(FlowsheetError::Convergence {
+                iterations: 100,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        for method in [
+            TearMethod::Substitution { damping: 0.0 },
+            TearMethod::Wegstein {
+                q_min: 0.0,
+                q_max: 1.0,
+            },
+            TearMethod::Newton {
+                relative_step: f64::NAN,
+            },
+        ] {
+            let config = TearConfig {
+                method,
+                ..TearConfig::default()
+            };
+            assert!(matches!(
+                config.validate(),
+                Err(FlowsheetError::InvalidConfig(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Hydraulic component models.
+//!
+//! These models relate pressure and flow in fluid networks.
+//!
+//! ## Available models
+//!
+//! - **Check valves** ([`check_valve`]) — [`CheckValve`]: passes flow in
+//!   one direction above a cracking pressure and blocks reverse flow.
+//!
+//! - **Duct networks** ([`duct_network`]) — [`DuctNetwork`]: supply fan
+//!   pushing air through branching ducts, fittings, and dampers to zone
+//!   outlets.
+//!
+//! - **Expansion tanks** ([`expansion_tank`]) — [`ExpansionTank`]:
+//!   diaphragm tank that sets closed-loop pressure from the thermal
+//!   expansion of the loop liquid against a pre-charged gas cushion.
+//!
+//! - **Valves** ([`valve`]) — [`Valve`]: throttling valve rated by a `Kv` or
+//!   `Cv` flow coefficient, with an opening-dependent characteristic and a
+//!   choked-flow limit for gases.
+//!
+//! [`CheckValve`]: check_valve::CheckValve
+//! [`DuctNetwork`]: duct_network::DuctNetwork
+//! [`ExpansionTank`]: expansion_tank::ExpansionTank
+//! [`Valve`]: valve::Valve
+
+pub mod check_valve;
+pub mod duct_network;
+pub mod expansion_tank;
+pub mod valve;
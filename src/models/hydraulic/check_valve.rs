@@ -0,0 +1,72 @@
+//! Spring-loaded check valve.
+//!
+//! A [`CheckValve`] passes flow in one direction once the forward pressure
+//! difference exceeds its cracking pressure, and blocks reverse flow. The
+//! open valve follows the same flow-coefficient rating and choked-flow limit
+//! as [`Valve`](super::valve::Valve).
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::hydraulic::{
+//!         check_valve::{CheckValve, CheckValveInput, CheckValveParameters},
+//!         valve::FlowCoefficient,
+//!     },
+//!     support::thermo::{capability::StateFrom, fluid::Water, model::Incompressible},
+//! };
+//! use uom::si::{
+//!     f64::{Pressure, ThermodynamicTemperature, VolumeRate},
+//!     pressure::pascal,
+//!     thermodynamic_temperature::degree_celsius,
+//!     volume_rate::cubic_meter_per_hour,
+//! };
+//!
+//! let valve = CheckValve::new(
+//!     CheckValveParameters {
+//!         flow_coefficient: FlowCoefficient::Kv(VolumeRate::new::<cubic_meter_per_hour>(8.0)),
+//!         cracking_pressure: Pressure::new::<pascal>(500.0),
+//!     },
+//!     Incompressible::<Water>::new().unwrap(),
+//! )
+//! .unwrap();
+//!
+//! let inlet = valve
+//!     .thermo()
+//!     .state_from((Water, ThermodynamicTemperature::new::<degree_celsius>(20.0)))
+//!     .unwrap();
+//!
+//! // A cooling collector at night drives the loop backward; the valve holds.
+//! let output = valve
+//!     .evaluate(&CheckValveInput {
+//!         inlet,
+//!         pressure_difference: Pressure::new::<pascal>(-150.0),
+//!     })
+//!     .unwrap();
+//!
+//! assert!(!output.open);
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+use super::valve::ValveThermoModel;
+
+pub use core::{
+    CheckValve, CheckValveError, CheckValveInput, CheckValveOutput, CheckValveParameters,
+};
+
+impl<Fluid, Thermo> Model for CheckValve<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: ValveThermoModel<Fluid>,
+{
+    type Input = CheckValveInput<Fluid>;
+    type Output = CheckValveOutput;
+    type Error = CheckValveError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
@@ -0,0 +1,255 @@
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::f64::{MassRate, Pressure, VolumeRate},
+};
+
+use crate::{
+    models::hydraulic::valve::{
+        FlowCoefficient, Valve, ValveCharacteristic, ValveError, ValveInput, ValveParameters,
+        ValveThermoModel,
+    },
+    support::{
+        constraint::{Constraint, NonNegative, UnitInterval},
+        thermo::State,
+    },
+};
+
+/// Fixed design parameters of a [`CheckValve`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CheckValveParameters {
+    /// Flow coefficient of the fully open valve.
+    pub flow_coefficient: FlowCoefficient,
+
+    /// Forward pressure difference needed to lift the disc off its seat.
+    pub cracking_pressure: Pressure,
+}
+
+/// Errors that can occur when creating or evaluating a [`CheckValve`].
+#[derive(Debug, Error)]
+pub enum CheckValveError {
+    /// A design parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// The open valve failed to evaluate.
+    #[error("open valve evaluation failed")]
+    Valve(#[from] ValveError),
+}
+
+/// Input to the check valve model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckValveInput<Fluid> {
+    /// State of the fluid at the upstream port of the permitted direction.
+    pub inlet: State<Fluid>,
+
+    /// Pressure at the upstream port minus pressure at the downstream port.
+    ///
+    /// Negative when the loop pushes fluid against the permitted direction.
+    pub pressure_difference: Pressure,
+}
+
+/// Output from the check valve model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CheckValveOutput {
+    /// Mass flow in the permitted direction; zero while the valve is shut.
+    pub mass_flow: MassRate,
+
+    /// Volumetric flow at inlet density.
+    pub volume_flow: VolumeRate,
+
+    /// Whether the disc is off its seat.
+    pub open: bool,
+
+    /// Whether the flow is limited by choking.
+    pub choked: bool,
+}
+
+/// A spring-loaded check valve that passes flow in one direction only.
+///
+/// The disc stays seated until the forward pressure difference exceeds the
+/// cracking pressure, so reverse flow and small forward differences are
+/// blocked. Once open, the spring holds back the cracking pressure and the
+/// remainder drives flow through the valve's flow coefficient as in a fully
+/// open [`Valve`]:
+///
+/// ```text
+/// ṁ = 0                        for ΔP ≤ ΔP_crack
+/// ṁ = ṁ_valve(ΔP − ΔP_crack)   for ΔP > ΔP_crack
+/// ```
+///
+/// Flow rises continuously from zero at the cracking pressure, which keeps
+/// loop solvers well behaved as the valve opens.
+#[derive(Debug, Clone)]
+pub struct CheckValve<Fluid, Thermo> {
+    valve: Valve<Fluid, Thermo>,
+    cracking_pressure: Pressure,
+}
+
+impl<Fluid, Thermo> CheckValve<Fluid, Thermo> {
+    /// Creates a check valve from its design parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckValveError::InvalidParameter`] if the cracking pressure
+    /// is negative, or [`CheckValveError::Valve`] if the flow coefficient is
+    /// invalid.
+    pub fn new(parameters: CheckValveParameters, thermo: Thermo) -> Result<Self, CheckValveError> {
+        let CheckValveParameters {
+            flow_coefficient,
+            cracking_pressure,
+        } = parameters;
+
+        if NonNegative::check(&cracking_pressure).is_err() {
+            return Err(CheckValveError::InvalidParameter(
+                "cracking pressure must be non-negative",
+            ));
+        }
+
+        let valve = Valve::new(
+            ValveParameters {
+                flow_coefficient,
+                characteristic: ValveCharacteristic::Linear,
+            },
+            thermo,
+        )?;
+
+        Ok(Self {
+            valve,
+            cracking_pressure,
+        })
+    }
+
+    /// Returns the cracking pressure.
+    #[must_use]
+    pub fn cracking_pressure(&self) -> Pressure {
+        self.cracking_pressure
+    }
+
+    /// Returns the thermo model.
+    #[must_use]
+    pub fn thermo(&self) -> &Thermo {
+        self.valve.thermo()
+    }
+
+    /// Evaluates the flow through the check valve.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the thermo model cannot report the properties
+    /// the open valve needs.
+    pub fn evaluate(
+        &self,
+        input: &CheckValveInput<Fluid>,
+    ) -> Result<CheckValveOutput, CheckValveError>
+    where
+        Fluid: Clone,
+        Thermo: ValveThermoModel<Fluid>,
+    {
+        let driving = input.pressure_difference - self.cracking_pressure;
+        if driving.is_nan() || driving <= Pressure::ZERO {
+            return Ok(CheckValveOutput {
+                mass_flow: MassRate::ZERO,
+                volume_flow: VolumeRate::ZERO,
+                open: false,
+                choked: false,
+            });
+        }
+
+        let output = self.valve.evaluate(&ValveInput {
+            inlet: input.inlet.clone(),
+            pressure_drop: driving,
+            opening: UnitInterval::one(),
+        })?;
+
+        Ok(CheckValveOutput {
+            mass_flow: output.mass_flow,
+            volume_flow: output.volume_flow,
+            open: true,
+            choked: output.choked,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::ThermodynamicTemperature, pressure::kilopascal,
+        thermodynamic_temperature::degree_celsius, volume_rate::cubic_meter_per_hour,
+    };
+
+    use crate::support::thermo::{capability::StateFrom, fluid::Water, model::Incompressible};
+
+    fn check_valve() -> CheckValve<Water, Incompressible<Water>> {
+        CheckValve::new(
+            CheckValveParameters {
+                flow_coefficient: FlowCoefficient::Kv(VolumeRate::new::<cubic_meter_per_hour>(5.0)),
+                cracking_pressure: Pressure::new::<kilopascal>(2.0),
+            },
+            Incompressible::<Water>::new().unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn input(
+        valve: &CheckValve<Water, Incompressible<Water>>,
+        pressure_difference: f64,
+    ) -> CheckValveInput<Water> {
+        CheckValveInput {
+            inlet: valve
+                .thermo()
+                .state_from((Water, ThermodynamicTemperature::new::<degree_celsius>(40.0)))
+                .unwrap(),
+            pressure_difference: Pressure::new::<kilopascal>(pressure_difference),
+        }
+    }
+
+    #[test]
+    fn blocks_reverse_flow() {
+        let valve = check_valve();
+        let output = valve.evaluate(&input(&valve, -50.0)).unwrap();
+
+        assert!(!output.open);
+        assert_eq!(output.mass_flow, MassRate::ZERO);
+    }
+
+    #[test]
+    fn stays_shut_below_cracking_pressure() {
+        let valve = check_valve();
+        for dp in [0.0, 1.0, 2.0] {
+            let output = valve.evaluate(&input(&valve, dp)).unwrap();
+            assert!(!output.open, "open at {dp} kPa");
+            assert_eq!(output.mass_flow, MassRate::ZERO);
+        }
+    }
+
+    #[test]
+    fn open_valve_passes_kv_flow_beyond_cracking_pressure() {
+        let valve = check_valve();
+        let output = valve.evaluate(&input(&valve, 102.0)).unwrap();
+
+        // The 100 kPa beyond cracking is the 1 bar rating drop.
+        let rho = valve.thermo().reference_density().value;
+        assert!(output.open);
+        assert_relative_eq!(
+            output.volume_flow.get::<cubic_meter_per_hour>(),
+            5.0 * (1000.0 / rho).sqrt(),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn rejects_negative_cracking_pressure() {
+        let result = CheckValve::<Water, _>::new(
+            CheckValveParameters {
+                flow_coefficient: FlowCoefficient::Kv(VolumeRate::new::<cubic_meter_per_hour>(5.0)),
+                cracking_pressure: Pressure::new::<kilopascal>(-1.0),
+            },
+            Incompressible::<Water>::new().unwrap(),
+        );
+        assert!(matches!(result, Err(CheckValveError::InvalidParameter(_))));
+    }
+}
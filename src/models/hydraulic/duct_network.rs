@@ -0,0 +1,100 @@
+//! Supply air duct networks.
+//!
+//! A [`DuctNetwork`] distributes the air delivered by a supply fan through
+//! branching round and rectangular ducts, fittings, and dampers to the
+//! outlets of a building's zones. It finds the operating point where the
+//! fan curve meets the resistance of the network, the flow delivered to
+//! every outlet, and the static pressure at every junction, so fan
+//! selection, damper balancing, and zone airflow can be simulated alongside
+//! the coils and spaces the air serves.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::hydraulic::duct_network::{
+//!         Damper, Duct, DuctEnd, DuctNetwork, DuctNetworkInput, DuctNetworkParameters,
+//!         DuctSection, SupplyFan,
+//!     },
+//!     support::psychrometrics::MoistAir,
+//! };
+//! use uom::si::{
+//!     f64::{Length, Pressure, Ratio, ThermodynamicTemperature, VolumeRate},
+//!     length::{meter, millimeter},
+//!     pressure::{kilopascal, pascal},
+//!     ratio::{percent, ratio},
+//!     thermodynamic_temperature::degree_celsius,
+//!     volume_rate::cubic_meter_per_second,
+//! };
+//!
+//! let branch = |length: f64| Duct {
+//!     from: 1,
+//!     to: DuctEnd::Outlet,
+//!     section: DuctSection::Round {
+//!         diameter: Length::new::<millimeter>(250.0),
+//!     },
+//!     length: Length::new::<meter>(length),
+//!     fitting_loss: Ratio::new::<ratio>(2.0),
+//!     damper: Some(Damper {
+//!         open_loss: Ratio::new::<ratio>(0.5),
+//!     }),
+//! };
+//!
+//! let network = DuctNetwork::new(DuctNetworkParameters {
+//!     fan: SupplyFan {
+//!         shutoff_pressure: Pressure::new::<pascal>(500.0),
+//!         free_delivery: VolumeRate::new::<cubic_meter_per_second>(1.0),
+//!         efficiency: Ratio::new::<percent>(60.0),
+//!     },
+//!     junctions: 2,
+//!     ducts: vec![
+//!         Duct {
+//!             from: 0,
+//!             to: DuctEnd::Junction(1),
+//!             section: DuctSection::Rectangular {
+//!                 width: Length::new::<millimeter>(500.0),
+//!                 height: Length::new::<millimeter>(300.0),
+//!             },
+//!             length: Length::new::<meter>(10.0),
+//!             fitting_loss: Ratio::new::<ratio>(0.3),
+//!             damper: None,
+//!         },
+//!         branch(3.0),
+//!         branch(20.0),
+//!     ],
+//!     roughness: Length::new::<millimeter>(0.09),
+//! })?;
+//!
+//! // Throttle the near branch to push air to the far one.
+//! let output = network.evaluate(&DuctNetworkInput {
+//!     air: MoistAir::from_relative_humidity(
+//!         ThermodynamicTemperature::new::<degree_celsius>(20.0),
+//!         Ratio::new::<percent>(50.0),
+//!         Pressure::new::<kilopascal>(101.325),
+//!     )?,
+//!     fan_speed: Ratio::new::<ratio>(1.0),
+//!     damper_openings: vec![Ratio::new::<percent>(40.0), Ratio::new::<ratio>(1.0)],
+//! })?;
+//!
+//! assert!(output.ducts[2].volume_flow > output.ducts[1].volume_flow);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    Damper, Duct, DuctEnd, DuctFlow, DuctNetwork, DuctNetworkError, DuctNetworkInput,
+    DuctNetworkOutput, DuctNetworkParameters, DuctSection, SupplyFan,
+};
+
+impl Model for DuctNetwork {
+    type Input = DuctNetworkInput;
+    type Output = DuctNetworkOutput;
+    type Error = DuctNetworkError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
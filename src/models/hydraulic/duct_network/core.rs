@@ -0,0 +1,949 @@
+use std::{collections::VecDeque, f64::consts::PI};
+
+use thiserror::Error;
+use uom::si::{
+    f64::{Area, Length, MassDensity, MassRate, Power, Pressure, Ratio, Velocity, VolumeRate},
+    length::meter,
+    mass_density::kilogram_per_cubic_meter,
+    mass_rate::kilogram_per_second,
+    pressure::pascal,
+    ratio::ratio,
+    thermodynamic_temperature::kelvin,
+    velocity::meter_per_second,
+    volume_rate::cubic_meter_per_second,
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive, UnitInterval},
+    convection::FilmProperties,
+    friction::churchill,
+    numerics::linear::solve_linear,
+    psychrometrics::MoistAir,
+};
+
+/// Largest scaled residual accepted as converged.
+const TOLERANCE: f64 = 1e-10;
+
+/// Newton iterations allowed before giving up.
+const MAX_ITERS: usize = 100;
+
+/// Step halvings tried in the line search before accepting a step anyway.
+const MAX_HALVINGS: usize = 20;
+
+/// Pressure at which [`FilmProperties::air`] evaluates its density, in Pa.
+const FILM_PRESSURE: f64 = 101_325.0;
+
+/// Gas constant of dry air, in J/kg·K.
+const DRY_AIR_GAS_CONSTANT: f64 = 287.05;
+
+/// The supply fan feeding junction 0.
+///
+/// The fan curve is the parabola `ΔP = P₀·s² − P₀·(Q / Q₀)²` between the
+/// shutoff pressure `P₀` and the free delivery `Q₀`, scaled to speed `s` by
+/// the affinity laws.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SupplyFan {
+    /// Total pressure rise at zero flow and full speed.
+    pub shutoff_pressure: Pressure,
+
+    /// Volume flow at zero pressure rise and full speed.
+    pub free_delivery: VolumeRate,
+
+    /// Combined fan, drive, and motor efficiency.
+    pub efficiency: Ratio,
+}
+
+/// Cross-section of a duct.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DuctSection {
+    /// Round duct.
+    Round {
+        /// Inside diameter.
+        diameter: Length,
+    },
+
+    /// Rectangular duct, with friction based on its hydraulic diameter.
+    Rectangular {
+        /// Inside width.
+        width: Length,
+
+        /// Inside height.
+        height: Length,
+    },
+}
+
+impl DuctSection {
+    /// Returns the flow area.
+    #[must_use]
+    pub fn area(&self) -> Area {
+        match *self {
+            Self::Round { diameter } => PI / 4.0 * diameter * diameter,
+            Self::Rectangular { width, height } => width * height,
+        }
+    }
+
+    /// Returns the hydraulic diameter `4·A / perimeter`.
+    #[must_use]
+    pub fn hydraulic_diameter(&self) -> Length {
+        match *self {
+            Self::Round { diameter } => diameter,
+            Self::Rectangular { width, height } => 2.0 * width * height / (width + height),
+        }
+    }
+}
+
+/// Where a duct discharges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DuctEnd {
+    /// Into another junction of the network.
+    Junction(usize),
+
+    /// Through a terminal into the conditioned space.
+    Outlet,
+}
+
+/// A balancing or zone damper.
+///
+/// The loss coefficient is `K_open / θ²` at opening `θ`, the loss of an
+/// orifice whose free area shrinks in proportion to the opening.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Damper {
+    /// Loss coefficient of the fully open damper.
+    pub open_loss: Ratio,
+}
+
+/// A duct run between two points of the network.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Duct {
+    /// Upstream junction.
+    pub from: usize,
+
+    /// Downstream junction or outlet.
+    pub to: DuctEnd,
+
+    /// Cross-section.
+    pub section: DuctSection,
+
+    /// Length of the run.
+    pub length: Length,
+
+    /// Sum of the velocity-pressure loss coefficients of the fittings along
+    /// the run: elbows, the branch leg of a tee, transitions, and, at an
+    /// outlet, the diffuser and exit loss.
+    pub fitting_loss: Ratio,
+
+    /// Damper in the run, if any.
+    pub damper: Option<Damper>,
+}
+
+/// Fixed parameters of a [`DuctNetwork`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DuctNetworkParameters {
+    /// Supply fan discharging into junction 0.
+    pub fan: SupplyFan,
+
+    /// Number of junctions, numbered from 0.
+    pub junctions: usize,
+
+    /// Duct runs joining the junctions and outlets.
+    pub ducts: Vec<Duct>,
+
+    /// Absolute roughness of the duct walls.
+    pub roughness: Length,
+}
+
+/// Errors from [`DuctNetwork`] construction and evaluation.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum DuctNetworkError {
+    /// A network parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating input is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// The linearized flow equations could not be solved.
+    #[error("flow equations are singular")]
+    Singular,
+
+    /// The flows did not converge within the iteration limit.
+    #[error("no convergence after {iters} iterations (scaled residual {residual:?})")]
+    MaxIters {
+        /// Largest scaled residual at the last iterate.
+        residual: Ratio,
+
+        /// Iterations performed.
+        iters: usize,
+    },
+}
+
+/// A supply air distribution network.
+///
+/// A fan draws air from the conditioned space and pushes it through a
+/// network of ducts to outlets that discharge back into the same space.
+/// Each run loses `(f·L/D + ΣK)·ρ·v²/2` with the Darcy friction factor from
+/// the Churchill correlation, so branch flows follow from balancing the fan
+/// curve against the resistance of every path.
+///
+/// The flow equations are solved by Newton's method in the mass flow of
+/// every duct and the static pressure of every junction. Air is treated as
+/// lightly compressible: the density in each duct follows the ideal gas law
+/// at its mean absolute pressure, while temperature and humidity ratio are
+/// those of the space air throughout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuctNetwork {
+    parameters: DuctNetworkParameters,
+    dampers: usize,
+}
+
+/// Input to the duct network model.
+#[derive(Debug, Clone)]
+pub struct DuctNetworkInput {
+    /// Air in the conditioned space, drawn in by the fan.
+    ///
+    /// Its pressure is the pressure the outlets discharge into.
+    pub air: MoistAir,
+
+    /// Fan speed as a fraction of full speed.
+    pub fan_speed: Ratio,
+
+    /// Opening of each damper, in the order the dampers appear among the
+    /// ducts.
+    pub damper_openings: Vec<Ratio>,
+}
+
+/// Flow through one duct run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DuctFlow {
+    /// Dry-air mass flow, negative if the air runs from `to` back to `from`.
+    pub dry_air_flow: MassRate,
+
+    /// Volume flow at the duct's density.
+    pub volume_flow: VolumeRate,
+
+    /// Mean velocity.
+    pub velocity: Velocity,
+
+    /// Static pressure drop from `from` to `to`.
+    pub pressure_drop: Pressure,
+}
+
+/// Output from the duct network model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuctNetworkOutput {
+    /// Volume flow drawn in by the fan.
+    pub fan_flow: VolumeRate,
+
+    /// Pressure rise across the fan.
+    pub fan_pressure_rise: Pressure,
+
+    /// Electrical power drawn by the fan.
+    pub fan_power: Power,
+
+    /// Static pressure of each junction above the space.
+    pub junction_pressures: Vec<Pressure>,
+
+    /// Flow through each duct, in parameter order.
+    pub ducts: Vec<DuctFlow>,
+
+    /// Newton iterations performed.
+    pub iterations: usize,
+}
+
+/// A duct reduced to plain numbers for the solve.
+struct Element {
+    from: usize,
+    to: Option<usize>,
+    area: f64,
+    diameter: f64,
+    length: f64,
+    relative_roughness: f64,
+    loss: f64,
+}
+
+impl Element {
+    /// Pressure drop in Pa for a mass flow in kg/s.
+    fn pressure_drop(&self, mass_flow: f64, density: f64, viscosity: f64) -> f64 {
+        if mass_flow == 0.0 {
+            return 0.0;
+        }
+        let reynolds = mass_flow.abs() * self.diameter / (self.area * viscosity);
+        let f = churchill(reynolds, self.relative_roughness);
+        (f * self.length / self.diameter + self.loss) * mass_flow * mass_flow.abs()
+            / (2.0 * density * self.area * self.area)
+    }
+}
+
+/// The operating point shared by every residual evaluation.
+struct Operating {
+    elements: Vec<Element>,
+    junctions: usize,
+    space_pressure: f64,
+    space_density: f64,
+    viscosity: f64,
+    shutoff: f64,
+    free_delivery: f64,
+    speed: f64,
+}
+
+impl Operating {
+    /// Density in kg/m³ at a static pressure above the space, in Pa.
+    fn density(&self, pressure: f64) -> f64 {
+        self.space_density * (self.space_pressure + pressure) / self.space_pressure
+    }
+
+    /// Pressure of a duct end, zero at an outlet.
+    fn pressure(&self, x: &[f64], junction: Option<usize>) -> f64 {
+        junction.map_or(0.0, |j| x[1 + self.elements.len() + j])
+    }
+
+    fn duct_density(&self, x: &[f64], element: &Element) -> f64 {
+        let mean = 0.5 * (self.pressure(x, Some(element.from)) + self.pressure(x, element.to));
+        self.density(mean)
+    }
+
+    /// Fan pressure rise in Pa at a fan mass flow in kg/s.
+    fn fan_rise(&self, mass_flow: f64) -> f64 {
+        let q = mass_flow / self.space_density;
+        self.shutoff * (self.speed * self.speed - q * q.abs() / self.free_delivery.powi(2))
+    }
+
+    /// Residuals ordered as the unknowns: fan, ducts, then junctions.
+    ///
+    /// Pressure rows are in Pa and mass rows in kg/s.
+    fn residuals(&self, x: &[f64]) -> Vec<f64> {
+        let ducts = self.elements.len();
+        let mut r = vec![0.0; 1 + ducts + self.junctions];
+
+        r[0] = self.pressure(x, Some(0)) - self.fan_rise(x[0]);
+        r[1 + ducts] += x[0];
+        for (l, element) in self.elements.iter().enumerate() {
+            let flow = x[1 + l];
+            let drop = element.pressure_drop(flow, self.duct_density(x, element), self.viscosity);
+            r[1 + l] = self.pressure(x, Some(element.from)) - self.pressure(x, element.to) - drop;
+            r[1 + ducts + element.from] -= flow;
+            if let Some(to) = element.to {
+                r[1 + ducts + to] += flow;
+            }
+        }
+        r
+    }
+
+    /// Jacobian of [`residuals`](Self::residuals), holding the densities at
+    /// their current values.
+    fn jacobian(&self, x: &[f64], flow_scale: f64) -> Vec<Vec<f64>> {
+        let ducts = self.elements.len();
+        let size = 1 + ducts + self.junctions;
+        let mut jac = vec![vec![0.0; size]; size];
+        let p = |j: usize| 1 + ducts + j;
+
+        let fan_flow = x[0] / self.space_density;
+        jac[0][p(0)] = 1.0;
+        jac[0][0] =
+            2.0 * self.shutoff * fan_flow.abs() / (self.free_delivery.powi(2) * self.space_density);
+        jac[p(0)][0] = 1.0;
+
+        for (l, element) in self.elements.iter().enumerate() {
+            let flow = x[1 + l];
+            let density = self.duct_density(x, element);
+            let step = 1e-6 * flow.abs().max(flow_scale);
+            let slope = (element.pressure_drop(flow + step, density, self.viscosity)
+                - element.pressure_drop(flow - step, density, self.viscosity))
+                / (2.0 * step);
+
+            jac[1 + l][1 + l] = -slope;
+            jac[1 + l][p(element.from)] = 1.0;
+            jac[p(element.from)][1 + l] = -1.0;
+            if let Some(to) = element.to {
+                jac[1 + l][p(to)] = -1.0;
+                jac[p(to)][1 + l] = 1.0;
+            }
+        }
+        jac
+    }
+}
+
+impl DuctNetwork {
+    /// Creates a duct network from its parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DuctNetworkError::InvalidParameter`] if a fan, duct, or
+    /// damper value is out of range, a duct refers to a junction that does
+    /// not exist or starts and ends at the same junction, no duct reaches an
+    /// outlet, or some junction is not connected to the fan.
+    pub fn new(parameters: DuctNetworkParameters) -> Result<Self, DuctNetworkError> {
+        let DuctNetworkParameters {
+            fan,
+            junctions,
+            ref ducts,
+            roughness,
+        } = parameters;
+
+        if StrictlyPositive::check(&fan.shutoff_pressure).is_err()
+            || !fan.shutoff_pressure.is_finite()
+        {
+            return Err(DuctNetworkError::InvalidParameter(
+                "fan shutoff pressure must be strictly positive and finite",
+            ));
+        }
+        if StrictlyPositive::check(&fan.free_delivery).is_err() || !fan.free_delivery.is_finite() {
+            return Err(DuctNetworkError::InvalidParameter(
+                "fan free delivery must be strictly positive and finite",
+            ));
+        }
+        if StrictlyPositive::check(&fan.efficiency).is_err()
+            || UnitInterval::check(&fan.efficiency).is_err()
+        {
+            return Err(DuctNetworkError::InvalidParameter(
+                "fan efficiency must be in (0, 1]",
+            ));
+        }
+        if NonNegative::check(&roughness).is_err() || !roughness.is_finite() {
+            return Err(DuctNetworkError::InvalidParameter(
+                "roughness must be non-negative and finite",
+            ));
+        }
+        if junctions == 0 {
+            return Err(DuctNetworkError::InvalidParameter(
+                "network must have at least one junction",
+            ));
+        }
+
+        for duct in ducts {
+            check_duct(duct, junctions)?;
+        }
+        check_connected(junctions, ducts)?;
+
+        let dampers = ducts.iter().filter(|duct| duct.damper.is_some()).count();
+        Ok(Self {
+            parameters,
+            dampers,
+        })
+    }
+
+    /// Returns the network parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &DuctNetworkParameters {
+        &self.parameters
+    }
+
+    /// Solves the flow through every duct at a fan speed and set of damper
+    /// openings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DuctNetworkError::InvalidInput`] if the fan speed or a
+    /// damper opening is not in (0, 1] or the number of openings does not
+    /// match the number of dampers, [`DuctNetworkError::Singular`] if a
+    /// Newton step cannot be formed, or [`DuctNetworkError::MaxIters`] if the
+    /// flows do not converge.
+    pub fn evaluate(
+        &self,
+        input: &DuctNetworkInput,
+    ) -> Result<DuctNetworkOutput, DuctNetworkError> {
+        let DuctNetworkInput {
+            air,
+            fan_speed,
+            ref damper_openings,
+        } = *input;
+
+        if StrictlyPositive::check(&fan_speed).is_err() || UnitInterval::check(&fan_speed).is_err()
+        {
+            return Err(DuctNetworkError::InvalidInput(
+                "fan speed must be in (0, 1]",
+            ));
+        }
+        if damper_openings.len() != self.dampers {
+            return Err(DuctNetworkError::InvalidInput(
+                "one opening is required for each damper",
+            ));
+        }
+        if damper_openings
+            .iter()
+            .any(|o| StrictlyPositive::check(o).is_err() || UnitInterval::check(o).is_err())
+        {
+            return Err(DuctNetworkError::InvalidInput(
+                "damper openings must be in (0, 1]",
+            ));
+        }
+
+        let operating = self.operating(&air, fan_speed, damper_openings);
+        let (x, iterations) = solve(&operating)?;
+
+        let ducts = operating.elements.len();
+        let moist_per_dry = 1.0 + air.humidity_ratio().get::<ratio>();
+        let fan_flow = x[0] / operating.space_density;
+        let fan_rise = operating.fan_rise(x[0]);
+        let flows = operating
+            .elements
+            .iter()
+            .enumerate()
+            .map(|(l, element)| {
+                let flow = x[1 + l];
+                let density = operating.duct_density(&x, element);
+                DuctFlow {
+                    dry_air_flow: MassRate::new::<kilogram_per_second>(flow / moist_per_dry),
+                    volume_flow: VolumeRate::new::<cubic_meter_per_second>(flow / density),
+                    velocity: Velocity::new::<meter_per_second>(flow / (density * element.area)),
+                    pressure_drop: Pressure::new::<pascal>(element.pressure_drop(
+                        flow,
+                        density,
+                        operating.viscosity,
+                    )),
+                }
+            })
+            .collect();
+
+        Ok(DuctNetworkOutput {
+            fan_flow: VolumeRate::new::<cubic_meter_per_second>(fan_flow),
+            fan_pressure_rise: Pressure::new::<pascal>(fan_rise),
+            fan_power: VolumeRate::new::<cubic_meter_per_second>(fan_flow)
+                * Pressure::new::<pascal>(fan_rise)
+                / self.parameters.fan.efficiency,
+            junction_pressures: x[1 + ducts..]
+                .iter()
+                .map(|&p| Pressure::new::<pascal>(p))
+                .collect(),
+            ducts: flows,
+            iterations,
+        })
+    }
+
+    /// Reduces the network and input to plain numbers for the solve.
+    fn operating(&self, air: &MoistAir, fan_speed: Ratio, damper_openings: &[Ratio]) -> Operating {
+        let DuctNetworkParameters {
+            fan,
+            junctions,
+            ref ducts,
+            roughness,
+        } = self.parameters;
+
+        let mut openings = damper_openings.iter();
+        let elements = ducts
+            .iter()
+            .map(|duct| {
+                let diameter = duct.section.hydraulic_diameter().get::<meter>();
+                let damper_loss = duct.damper.map_or(0.0, |damper| {
+                    let opening = openings.next().map_or(1.0, Ratio::get::<ratio>);
+                    damper.open_loss.get::<ratio>() / (opening * opening)
+                });
+                Element {
+                    from: duct.from,
+                    to: match duct.to {
+                        DuctEnd::Junction(to) => Some(to),
+                        DuctEnd::Outlet => None,
+                    },
+                    area: duct.section.area().value,
+                    diameter,
+                    length: duct.length.get::<meter>(),
+                    relative_roughness: roughness.get::<meter>() / diameter,
+                    loss: duct.fitting_loss.get::<ratio>() + damper_loss,
+                }
+            })
+            .collect();
+
+        // FilmProperties reports the kinematic viscosity at one atmosphere.
+        let temperature = air.temperature();
+        let film = FilmProperties::air(temperature);
+        let film_density = MassDensity::new::<kilogram_per_cubic_meter>(
+            FILM_PRESSURE / (DRY_AIR_GAS_CONSTANT * temperature.get::<kelvin>()),
+        );
+        let space_density: MassDensity =
+            (1.0 + air.humidity_ratio().get::<ratio>()) / air.specific_volume();
+
+        Operating {
+            elements,
+            junctions,
+            space_pressure: air.pressure().get::<pascal>(),
+            space_density: space_density.get::<kilogram_per_cubic_meter>(),
+            viscosity: (film.kinematic_viscosity * film_density).value,
+            shutoff: fan.shutoff_pressure.get::<pascal>(),
+            free_delivery: fan.free_delivery.get::<cubic_meter_per_second>(),
+            speed: fan_speed.get::<ratio>(),
+        }
+    }
+}
+
+/// Checks one duct's dimensions, losses, and end junctions.
+fn check_duct(duct: &Duct, junctions: usize) -> Result<(), DuctNetworkError> {
+    let dimensions = match duct.section {
+        DuctSection::Round { diameter } => [diameter, diameter],
+        DuctSection::Rectangular { width, height } => [width, height],
+    };
+    if dimensions
+        .iter()
+        .any(|d| StrictlyPositive::check(d).is_err() || !d.is_finite())
+    {
+        return Err(DuctNetworkError::InvalidParameter(
+            "duct dimensions must be strictly positive and finite",
+        ));
+    }
+    if StrictlyPositive::check(&duct.length).is_err() || !duct.length.is_finite() {
+        return Err(DuctNetworkError::InvalidParameter(
+            "duct length must be strictly positive and finite",
+        ));
+    }
+    if NonNegative::check(&duct.fitting_loss).is_err() || !duct.fitting_loss.is_finite() {
+        return Err(DuctNetworkError::InvalidParameter(
+            "fitting loss must be non-negative and finite",
+        ));
+    }
+    if let Some(damper) = duct.damper
+        && (NonNegative::check(&damper.open_loss).is_err() || !damper.open_loss.is_finite())
+    {
+        return Err(DuctNetworkError::InvalidParameter(
+            "damper loss must be non-negative and finite",
+        ));
+    }
+    let valid_end = match duct.to {
+        DuctEnd::Junction(to) => to < junctions && to != duct.from,
+        DuctEnd::Outlet => true,
+    };
+    if duct.from >= junctions || !valid_end {
+        return Err(DuctNetworkError::InvalidParameter(
+            "ducts must join two distinct existing junctions or a junction and an outlet",
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that the network has an outlet and every junction reaches the fan.
+fn check_connected(junctions: usize, ducts: &[Duct]) -> Result<(), DuctNetworkError> {
+    if !ducts.iter().any(|duct| duct.to == DuctEnd::Outlet) {
+        return Err(DuctNetworkError::InvalidParameter(
+            "network must have at least one outlet",
+        ));
+    }
+
+    // Every junction must be reachable from the fan.
+    let mut reached = vec![false; junctions];
+    let mut queue = VecDeque::from([0]);
+    reached[0] = true;
+    while let Some(j) = queue.pop_front() {
+        for duct in ducts {
+            let DuctEnd::Junction(to) = duct.to else {
+                continue;
+            };
+            let next = if duct.from == j {
+                to
+            } else if to == j {
+                duct.from
+            } else {
+                continue;
+            };
+            if !reached[next] {
+                reached[next] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+    if reached.contains(&false) {
+        return Err(DuctNetworkError::InvalidParameter(
+            "every junction must be connected to the fan",
+        ));
+    }
+    Ok(())
+}
+
+/// Solves the flow equations by damped Newton iteration.
+///
+/// Returns the unknowns and the iteration count.
+fn solve(operating: &Operating) -> Result<(Vec<f64>, usize), DuctNetworkError> {
+    let ducts = operating.elements.len();
+    let pressure_scale = operating.shutoff * operating.speed * operating.speed;
+    let flow_scale = operating.space_density * operating.free_delivery * operating.speed;
+    let outlets = operating
+        .elements
+        .iter()
+        .filter(|element| element.to.is_none())
+        .count();
+
+    let scaled_norm = |r: &[f64]| {
+        r.iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let scale = if i <= ducts {
+                    pressure_scale
+                } else {
+                    flow_scale
+                };
+                (v / scale).abs()
+            })
+            .fold(0.0, f64::max)
+    };
+
+    // Start from half the free delivery shared among the outlets.
+    let mut x = vec![0.5 * pressure_scale; 1 + ducts + operating.junctions];
+    x[0] = 0.5 * flow_scale;
+    // Outlet counts are far below 2^52, so the cast is exact.
+    #[allow(clippy::cast_precision_loss)]
+    let per_outlet = 0.5 * flow_scale / outlets as f64;
+    for value in &mut x[1..=ducts] {
+        *value = per_outlet;
+    }
+
+    let mut r = operating.residuals(&x);
+    let mut norm = scaled_norm(&r);
+    for iter in 1..=MAX_ITERS {
+        let jac = operating.jacobian(&x, flow_scale);
+        let step =
+            solve_linear(jac, r.iter().map(|v| -v).collect()).ok_or(DuctNetworkError::Singular)?;
+
+        let mut fraction = 1.0;
+        let mut trial;
+        let mut trial_r;
+        let mut trial_norm;
+        let mut halvings = 0;
+        loop {
+            trial = x
+                .iter()
+                .zip(&step)
+                .map(|(x, dx)| x + fraction * dx)
+                .collect::<Vec<_>>();
+            trial_r = operating.residuals(&trial);
+            trial_norm = scaled_norm(&trial_r);
+            if trial_norm < norm || halvings == MAX_HALVINGS {
+                break;
+            }
+            fraction *= 0.5;
+            halvings += 1;
+        }
+
+        x = trial;
+        r = trial_r;
+        norm = trial_norm;
+        if norm < TOLERANCE {
+            return Ok((x, iter));
+        }
+    }
+
+    Err(DuctNetworkError::MaxIters {
+        residual: Ratio::new::<ratio>(norm),
+        iters: MAX_ITERS,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::ThermodynamicTemperature, length::millimeter, pressure::kilopascal, ratio::percent,
+        thermodynamic_temperature::degree_celsius,
+    };
+
+    fn space() -> MoistAir {
+        MoistAir::from_relative_humidity(
+            ThermodynamicTemperature::new::<degree_celsius>(20.0),
+            Ratio::new::<percent>(50.0),
+            Pressure::new::<kilopascal>(101.325),
+        )
+        .unwrap()
+    }
+
+    fn round(diameter_mm: f64) -> DuctSection {
+        DuctSection::Round {
+            diameter: Length::new::<millimeter>(diameter_mm),
+        }
+    }
+
+    fn duct(from: usize, to: DuctEnd, section: DuctSection, length: f64, loss: f64) -> Duct {
+        Duct {
+            from,
+            to,
+            section,
+            length: Length::new::<meter>(length),
+            fitting_loss: Ratio::new::<ratio>(loss),
+            damper: None,
+        }
+    }
+
+    fn fan() -> SupplyFan {
+        SupplyFan {
+            shutoff_pressure: Pressure::new::<pascal>(500.0),
+            free_delivery: VolumeRate::new::<cubic_meter_per_second>(1.0),
+            efficiency: Ratio::new::<percent>(60.0),
+        }
+    }
+
+    /// A trunk feeding a near and a far branch, each with a damper.
+    fn branched() -> DuctNetwork {
+        let damped = |duct: Duct| Duct {
+            damper: Some(Damper {
+                open_loss: Ratio::new::<ratio>(0.5),
+            }),
+            ..duct
+        };
+        DuctNetwork::new(DuctNetworkParameters {
+            fan: fan(),
+            junctions: 2,
+            ducts: vec![
+                duct(
+                    0,
+                    DuctEnd::Junction(1),
+                    DuctSection::Rectangular {
+                        width: Length::new::<millimeter>(500.0),
+                        height: Length::new::<millimeter>(300.0),
+                    },
+                    10.0,
+                    0.3,
+                ),
+                damped(duct(1, DuctEnd::Outlet, round(250.0), 3.0, 1.5)),
+                damped(duct(1, DuctEnd::Outlet, round(250.0), 20.0, 2.5)),
+            ],
+            roughness: Length::new::<millimeter>(0.09),
+        })
+        .unwrap()
+    }
+
+    fn input(speed: f64, openings: [f64; 2]) -> DuctNetworkInput {
+        DuctNetworkInput {
+            air: space(),
+            fan_speed: Ratio::new::<ratio>(speed),
+            damper_openings: openings.map(Ratio::new::<ratio>).to_vec(),
+        }
+    }
+
+    #[test]
+    fn single_duct_balances_the_fan_curve() {
+        let network = DuctNetwork::new(DuctNetworkParameters {
+            fan: fan(),
+            junctions: 1,
+            ducts: vec![duct(0, DuctEnd::Outlet, round(400.0), 15.0, 1.0)],
+            roughness: Length::new::<millimeter>(0.09),
+        })
+        .unwrap();
+        let output = network
+            .evaluate(&DuctNetworkInput {
+                damper_openings: vec![],
+                ..input(1.0, [1.0; 2])
+            })
+            .unwrap();
+
+        // The fan rise equals the drop through the only duct.
+        let duct = output.ducts[0];
+        assert_relative_eq!(
+            output.fan_pressure_rise.get::<pascal>(),
+            duct.pressure_drop.get::<pascal>(),
+            max_relative = 1e-8
+        );
+        assert_relative_eq!(
+            output.fan_pressure_rise.get::<pascal>(),
+            500.0 * (1.0 - output.fan_flow.get::<cubic_meter_per_second>().powi(2)),
+            max_relative = 1e-9
+        );
+        assert_eq!(output.junction_pressures.len(), 1);
+
+        // Turbulent flow in the duct.
+        assert!(duct.velocity.get::<meter_per_second>() > 3.0);
+        let friction = duct.pressure_drop.get::<pascal>();
+        let density = 1.2;
+        let velocity_pressure = 0.5 * density * duct.velocity.get::<meter_per_second>().powi(2);
+        let f = (friction / velocity_pressure - 1.0) * 0.4 / 15.0;
+        assert!((0.015..0.025).contains(&f), "friction factor {f}");
+    }
+
+    #[test]
+    fn branch_flows_balance_at_the_junction() {
+        let output = branched().evaluate(&input(1.0, [1.0, 1.0])).unwrap();
+
+        let trunk = output.ducts[0].dry_air_flow;
+        let branches = output.ducts[1].dry_air_flow + output.ducts[2].dry_air_flow;
+        assert_relative_eq!(
+            trunk.get::<kilogram_per_second>(),
+            branches.get::<kilogram_per_second>(),
+            max_relative = 1e-9
+        );
+
+        // Both branches see the same junction pressure, so the shorter one
+        // with fewer fittings carries more air.
+        assert!(output.ducts[1].dry_air_flow > output.ducts[2].dry_air_flow);
+        assert_relative_eq!(
+            output.ducts[1].pressure_drop.get::<pascal>(),
+            output.junction_pressures[1].get::<pascal>(),
+            max_relative = 1e-8
+        );
+        assert_relative_eq!(
+            output.ducts[2].pressure_drop.get::<pascal>(),
+            output.junction_pressures[1].get::<pascal>(),
+            max_relative = 1e-8
+        );
+    }
+
+    #[test]
+    fn closing_a_damper_shifts_air_to_the_other_branch() {
+        let open = branched().evaluate(&input(1.0, [1.0, 1.0])).unwrap();
+        let throttled = branched().evaluate(&input(1.0, [0.3, 1.0])).unwrap();
+
+        assert!(throttled.ducts[1].dry_air_flow < open.ducts[1].dry_air_flow);
+        assert!(throttled.ducts[2].dry_air_flow > open.ducts[2].dry_air_flow);
+        assert!(throttled.fan_flow < open.fan_flow);
+        assert!(throttled.fan_pressure_rise > open.fan_pressure_rise);
+    }
+
+    #[test]
+    fn fan_speed_follows_affinity_laws() {
+        let full = branched().evaluate(&input(1.0, [1.0, 1.0])).unwrap();
+        let half = branched().evaluate(&input(0.5, [1.0, 1.0])).unwrap();
+
+        // Nearly quadratic resistance keeps the operating point on a
+        // system curve through the origin.
+        let flow_ratio = half.fan_flow / full.fan_flow;
+        assert_relative_eq!(flow_ratio.get::<ratio>(), 0.5, max_relative = 0.02);
+        assert_relative_eq!(
+            (half.fan_power / full.fan_power).get::<ratio>(),
+            0.125,
+            max_relative = 0.05
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_networks_and_inputs() {
+        let parameters = branched().parameters().clone();
+        let mut dangling = parameters.clone();
+        dangling.ducts[0].to = DuctEnd::Junction(2);
+        assert!(matches!(
+            DuctNetwork::new(dangling),
+            Err(DuctNetworkError::InvalidParameter(_))
+        ));
+
+        let mut isolated = parameters.clone();
+        isolated.junctions = 3;
+        assert!(matches!(
+            DuctNetwork::new(isolated),
+            Err(DuctNetworkError::InvalidParameter(_))
+        ));
+
+        let mut closed = parameters;
+        closed.ducts.retain(|duct| duct.to != DuctEnd::Outlet);
+        assert!(matches!(
+            DuctNetwork::new(closed),
+            Err(DuctNetworkError::InvalidParameter(_))
+        ));
+
+        assert!(matches!(
+            branched().evaluate(&input(1.0, [0.0, 1.0])),
+            Err(DuctNetworkError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            branched().evaluate(&DuctNetworkInput {
+                damper_openings: vec![],
+                ..input(1.0, [1.0, 1.0])
+            }),
+            Err(DuctNetworkError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            branched().evaluate(&input(0.0, [1.0, 1.0])),
+            Err(DuctNetworkError::InvalidInput(_))
+        ));
+    }
+}
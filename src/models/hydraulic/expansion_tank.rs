@@ -0,0 +1,85 @@
+//! Diaphragm expansion tank for closed liquid loops.
+//!
+//! An [`ExpansionTank`] sets the pressure of a closed hydronic loop from the
+//! thermal expansion of its liquid. The tank's gas cushion is pre-charged
+//! before the loop is filled, and the liquid mass locked in at fill is
+//! tracked as the loop temperature changes, so any thermo model
+//! implementing `StateFrom<(Fluid, ThermodynamicTemperature, Pressure)>`
+//! with a temperature-dependent density gives a consistent loop pressure.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::models::hydraulic::expansion_tank::{
+//!     ExpansionTank, ExpansionTankInput, ExpansionTankParameters,
+//! };
+//! # use std::convert::Infallible;
+//! # use twine_models::support::thermo::{State, capability::{StateFrom, ThermoModel}};
+//! # use uom::si::{f64::MassDensity, mass_density::kilogram_per_cubic_meter};
+//! use uom::si::{
+//!     f64::{Pressure, ThermodynamicTemperature, Volume},
+//!     pressure::kilopascal,
+//!     thermodynamic_temperature::degree_celsius,
+//!     volume::liter,
+//! };
+//! #
+//! # #[derive(Debug, Clone, Copy)]
+//! # struct Glycol;
+//! # struct GlycolModel;
+//! # impl ThermoModel for GlycolModel { type Fluid = Glycol; }
+//! # impl StateFrom<(Glycol, ThermodynamicTemperature, Pressure)> for GlycolModel {
+//! #     type Error = Infallible;
+//! #     fn state_from(
+//! #         &self,
+//! #         (fluid, t, _): (Glycol, ThermodynamicTemperature, Pressure),
+//! #     ) -> Result<State<Glycol>, Infallible> {
+//! #         let rho = 1050.0 - 0.6 * t.get::<degree_celsius>();
+//! #         Ok(State::new(t, MassDensity::new::<kilogram_per_cubic_meter>(rho), fluid))
+//! #     }
+//! # }
+//!
+//! let tank = ExpansionTank::new(
+//!     ExpansionTankParameters {
+//!         tank_volume: Volume::new::<liter>(50.0),
+//!         precharge_pressure: Pressure::new::<kilopascal>(250.0),
+//!         system_volume: Volume::new::<liter>(400.0),
+//!         fill_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.0),
+//!         fill_pressure: Pressure::new::<kilopascal>(270.0),
+//!     },
+//!     GlycolModel,
+//! )
+//! .unwrap();
+//!
+//! let output = tank
+//!     .evaluate(&ExpansionTankInput {
+//!         fluid: Glycol,
+//!         temperature: ThermodynamicTemperature::new::<degree_celsius>(60.0),
+//!     })
+//!     .unwrap();
+//!
+//! // The warm loop pushes liquid into the tank and raises the pressure.
+//! assert!(output.pressure > Pressure::new::<kilopascal>(270.0));
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    ExpansionTank, ExpansionTankError, ExpansionTankInput, ExpansionTankOutput,
+    ExpansionTankParameters, ExpansionTankThermoModel,
+};
+
+impl<Fluid, Thermo> Model for ExpansionTank<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: ExpansionTankThermoModel<Fluid>,
+{
+    type Input = ExpansionTankInput<Fluid>;
+    type Output = ExpansionTankOutput;
+    type Error = ExpansionTankError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
@@ -0,0 +1,446 @@
+use std::{error::Error as StdError, marker::PhantomData};
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::f64::{MassDensity, Pressure, ThermodynamicTemperature, Volume},
+};
+
+use crate::support::{
+    constraint::{Constraint, StrictlyPositive},
+    thermo::capability::{StateFrom, ThermoModel},
+    units::display::pretty,
+};
+
+/// Required thermo model bounds for an [`ExpansionTank`].
+#[doc(hidden)]
+pub trait ExpansionTankThermoModel<Fluid>:
+    ThermoModel<Fluid = Fluid> + StateFrom<(Fluid, ThermodynamicTemperature, Pressure)>
+{
+}
+
+impl<Fluid, T> ExpansionTankThermoModel<Fluid> for T where
+    T: ThermoModel<Fluid = Fluid> + StateFrom<(Fluid, ThermodynamicTemperature, Pressure)>
+{
+}
+
+/// Fixed design parameters of an [`ExpansionTank`] and the loop it serves.
+///
+/// All pressures are absolute and referred to the tank connection.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExpansionTankParameters {
+    /// Total internal volume of the tank, gas and liquid sides together.
+    pub tank_volume: Volume,
+
+    /// Gas-side pressure of the empty tank.
+    pub precharge_pressure: Pressure,
+
+    /// Liquid volume of the loop outside the tank.
+    pub system_volume: Volume,
+
+    /// Loop temperature when the loop was filled.
+    pub fill_temperature: ThermodynamicTemperature,
+
+    /// Loop pressure when the loop was filled.
+    ///
+    /// Must be at least the precharge pressure, so the tank accepts liquid
+    /// at fill.
+    pub fill_pressure: Pressure,
+}
+
+/// Errors that can occur when creating or evaluating an [`ExpansionTank`].
+#[derive(Debug, Error)]
+pub enum ExpansionTankError {
+    /// A design parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// The loop contracted by more than the liquid the tank held at fill.
+    ///
+    /// The diaphragm bottoms out and the loop pressure falls below the
+    /// precharge, so it is no longer set by the tank.
+    #[error("tank drained: loop liquid short by {}", pretty(*.shortfall))]
+    Drained {
+        /// Liquid volume missing from the loop.
+        shortfall: Volume,
+    },
+
+    /// The loop expanded by more than the tank's gas volume.
+    #[error("tank waterlogged: loop liquid exceeds tank capacity by {}", pretty(*.excess))]
+    Waterlogged {
+        /// Liquid volume the tank cannot accept.
+        excess: Volume,
+    },
+
+    /// A thermodynamic model operation failed.
+    #[error("thermodynamic model failed: {context}")]
+    ThermoModelFailed {
+        /// Operation context for the thermodynamic model failure.
+        context: String,
+
+        /// Underlying thermodynamic model error.
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+}
+
+impl ExpansionTankError {
+    fn thermo(context: &str, source: impl StdError + Send + Sync + 'static) -> Self {
+        Self::ThermoModelFailed {
+            context: context.to_owned(),
+            source: Box::new(source),
+        }
+    }
+}
+
+/// Input to the expansion tank model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpansionTankInput<Fluid> {
+    /// Fluid-specific data used to build the states.
+    pub fluid: Fluid,
+
+    /// Mean temperature of the loop liquid.
+    pub temperature: ThermodynamicTemperature,
+}
+
+/// Output from the expansion tank model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExpansionTankOutput {
+    /// Loop pressure at the tank connection.
+    pub pressure: Pressure,
+
+    /// Liquid volume held in the tank.
+    pub acceptance_volume: Volume,
+
+    /// Gas volume remaining in the tank.
+    pub gas_volume: Volume,
+
+    /// Change in loop liquid volume since fill.
+    ///
+    /// Negative when the loop is colder, and so denser, than at fill.
+    pub expansion_volume: Volume,
+}
+
+/// A diaphragm expansion tank pressurizing a closed liquid loop.
+///
+/// The loop is filled at a known temperature and pressure, which fixes the
+/// liquid mass `m = ρ_fill·(V_sys + V_t·(1 − p_pre/p_fill))`. At any other
+/// temperature the liquid that does not fit in the rigid loop volume sits
+/// in the tank, and the gas cushion compresses isothermally:
+///
+/// ```text
+/// V_liq = m/ρ(T) − V_sys
+/// p     = p_pre·V_t / (V_t − V_liq)
+/// ```
+///
+/// Densities are evaluated at the fill pressure, since the liquid's own
+/// compressibility is negligible next to the gas cushion.
+#[derive(Debug, Clone)]
+pub struct ExpansionTank<Fluid, Thermo> {
+    parameters: ExpansionTankParameters,
+    thermo: Thermo,
+    _fluid: PhantomData<Fluid>,
+}
+
+impl<Fluid, Thermo> ExpansionTank<Fluid, Thermo> {
+    /// Creates an expansion tank from its design parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpansionTankError::InvalidParameter`] if a volume or the
+    /// precharge is not strictly positive, or the fill pressure is below the
+    /// precharge.
+    pub fn new(
+        parameters: ExpansionTankParameters,
+        thermo: Thermo,
+    ) -> Result<Self, ExpansionTankError> {
+        let ExpansionTankParameters {
+            tank_volume,
+            precharge_pressure,
+            system_volume,
+            fill_pressure,
+            ..
+        } = parameters;
+
+        if StrictlyPositive::check(&tank_volume).is_err() {
+            return Err(ExpansionTankError::InvalidParameter(
+                "tank volume must be strictly positive",
+            ));
+        }
+        if StrictlyPositive::check(&system_volume).is_err() {
+            return Err(ExpansionTankError::InvalidParameter(
+                "system volume must be strictly positive",
+            ));
+        }
+        if StrictlyPositive::check(&precharge_pressure).is_err() {
+            return Err(ExpansionTankError::InvalidParameter(
+                "precharge pressure must be strictly positive",
+            ));
+        }
+        if fill_pressure.is_nan() || fill_pressure < precharge_pressure {
+            return Err(ExpansionTankError::InvalidParameter(
+                "fill pressure must be at least the precharge pressure",
+            ));
+        }
+
+        Ok(Self {
+            parameters,
+            thermo,
+            _fluid: PhantomData,
+        })
+    }
+
+    /// Returns the design parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &ExpansionTankParameters {
+        &self.parameters
+    }
+
+    /// Returns the thermo model.
+    #[must_use]
+    pub fn thermo(&self) -> &Thermo {
+        &self.thermo
+    }
+
+    /// Liquid volume held in the tank at fill.
+    #[must_use]
+    pub fn fill_acceptance_volume(&self) -> Volume {
+        let ExpansionTankParameters {
+            tank_volume,
+            precharge_pressure,
+            fill_pressure,
+            ..
+        } = self.parameters;
+        tank_volume * (1.0 - (precharge_pressure / fill_pressure).value)
+    }
+
+    /// Evaluates the loop pressure at the given liquid temperature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the liquid no longer fits the tank's range or if
+    /// the thermo model cannot build a state.
+    pub fn evaluate(
+        &self,
+        input: &ExpansionTankInput<Fluid>,
+    ) -> Result<ExpansionTankOutput, ExpansionTankError>
+    where
+        Fluid: Clone,
+        Thermo: ExpansionTankThermoModel<Fluid>,
+    {
+        let ExpansionTankParameters {
+            tank_volume,
+            precharge_pressure,
+            system_volume,
+            fill_temperature,
+            fill_pressure,
+        } = self.parameters;
+
+        let density = |temperature, context| -> Result<MassDensity, ExpansionTankError> {
+            self.thermo
+                .state_from((input.fluid.clone(), temperature, fill_pressure))
+                .map(|state| state.density)
+                .map_err(|error| ExpansionTankError::thermo(context, error))
+        };
+        let fill_density = density(fill_temperature, "fill state from (T, p)")?;
+        let operating_density = density(input.temperature, "operating state from (T, p)")?;
+
+        let fill_liquid = system_volume + self.fill_acceptance_volume();
+        let liquid = fill_liquid * (fill_density / operating_density).value;
+        let acceptance_volume = liquid - system_volume;
+
+        if acceptance_volume < Volume::ZERO {
+            return Err(ExpansionTankError::Drained {
+                shortfall: -acceptance_volume,
+            });
+        }
+        if acceptance_volume >= tank_volume {
+            return Err(ExpansionTankError::Waterlogged {
+                excess: acceptance_volume - tank_volume,
+            });
+        }
+
+        let gas_volume = tank_volume - acceptance_volume;
+
+        Ok(ExpansionTankOutput {
+            pressure: precharge_pressure * (tank_volume / gas_volume).value,
+            acceptance_volume,
+            gas_volume,
+            expansion_volume: liquid - fill_liquid,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        mass_density::kilogram_per_cubic_meter, pressure::kilopascal,
+        thermodynamic_temperature::degree_celsius, volume::liter,
+    };
+
+    use crate::support::thermo::State;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Liquid;
+
+    /// Liquid whose density falls linearly by 0.05 % per kelvin above 10 °C.
+    #[derive(Debug, Clone, Copy)]
+    struct ExpandingLiquid;
+
+    impl ThermoModel for ExpandingLiquid {
+        type Fluid = Liquid;
+    }
+
+    impl StateFrom<(Liquid, ThermodynamicTemperature, Pressure)> for ExpandingLiquid {
+        type Error = Infallible;
+
+        fn state_from(
+            &self,
+            (fluid, temperature, _pressure): (Liquid, ThermodynamicTemperature, Pressure),
+        ) -> Result<State<Liquid>, Self::Error> {
+            let rise = temperature.get::<degree_celsius>() - 10.0;
+            let density =
+                MassDensity::new::<kilogram_per_cubic_meter>(1000.0 * (1.0 - 5e-4 * rise));
+            Ok(State::new(temperature, density, fluid))
+        }
+    }
+
+    fn tank() -> ExpansionTank<Liquid, ExpandingLiquid> {
+        ExpansionTank::new(
+            ExpansionTankParameters {
+                tank_volume: Volume::new::<liter>(20.0),
+                precharge_pressure: Pressure::new::<kilopascal>(200.0),
+                system_volume: Volume::new::<liter>(200.0),
+                fill_temperature: ThermodynamicTemperature::new::<degree_celsius>(10.0),
+                fill_pressure: Pressure::new::<kilopascal>(250.0),
+            },
+            ExpandingLiquid,
+        )
+        .unwrap()
+    }
+
+    fn at(temperature: f64) -> ExpansionTankInput<Liquid> {
+        ExpansionTankInput {
+            fluid: Liquid,
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(temperature),
+        }
+    }
+
+    #[test]
+    fn fill_temperature_recovers_fill_pressure() {
+        let tank = tank();
+        let output = tank.evaluate(&at(10.0)).unwrap();
+
+        assert_relative_eq!(
+            output.pressure.get::<kilopascal>(),
+            250.0,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            output.acceptance_volume.get::<liter>(),
+            4.0,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(output.expansion_volume.get::<liter>(), 0.0);
+    }
+
+    #[test]
+    fn heating_compresses_gas_cushion() {
+        let tank = tank();
+        let output = tank.evaluate(&at(70.0)).unwrap();
+
+        // 204 L at fill grows by 1/0.97, pushing 6.309 L more into the tank.
+        let expansion = 204.0 / 0.97 - 204.0;
+        assert_relative_eq!(
+            output.expansion_volume.get::<liter>(),
+            expansion,
+            epsilon = 1e-9
+        );
+        let gas = 16.0 - expansion;
+        assert_relative_eq!(output.gas_volume.get::<liter>(), gas, epsilon = 1e-9);
+        assert_relative_eq!(
+            output.pressure.get::<kilopascal>(),
+            200.0 * 20.0 / gas,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn cooling_below_fill_lowers_pressure_toward_precharge() {
+        let tank = tank();
+        let warm = tank.evaluate(&at(10.0)).unwrap();
+        let cold = tank.evaluate(&at(0.0)).unwrap();
+
+        assert!(cold.pressure < warm.pressure);
+        assert!(cold.pressure > tank.parameters().precharge_pressure);
+        assert!(cold.expansion_volume < Volume::ZERO);
+    }
+
+    #[test]
+    fn out_of_range_temperatures_are_reported() {
+        let tank = tank();
+        assert!(matches!(
+            tank.evaluate(&at(-40.0)),
+            Err(ExpansionTankError::Drained { .. })
+        ));
+        assert!(matches!(
+            tank.evaluate(&at(200.0)),
+            Err(ExpansionTankError::Waterlogged { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_fill_below_precharge() {
+        let parameters = ExpansionTankParameters {
+            fill_pressure: Pressure::new::<kilopascal>(150.0),
+            ..*tank().parameters()
+        };
+        assert!(matches!(
+            ExpansionTank::<Liquid, _>::new(parameters, ExpandingLiquid),
+            Err(ExpansionTankError::InvalidParameter(_))
+        ));
+    }
+
+    #[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
+    mod coolprop_tests {
+        use super::*;
+
+        use uom::si::{
+            pressure::kilopascal, thermodynamic_temperature::degree_celsius, volume::liter,
+        };
+
+        use crate::support::thermo::{fluid::Water, model::CoolProp};
+
+        #[test]
+        fn water_loop_heated_to_80_c_stays_within_tank_range() {
+            let tank = ExpansionTank::new(
+                ExpansionTankParameters {
+                    tank_volume: Volume::new::<liter>(35.0),
+                    precharge_pressure: Pressure::new::<kilopascal>(200.0),
+                    system_volume: Volume::new::<liter>(500.0),
+                    fill_temperature: ThermodynamicTemperature::new::<degree_celsius>(10.0),
+                    fill_pressure: Pressure::new::<kilopascal>(220.0),
+                },
+                CoolProp::<Water>::new().unwrap(),
+            )
+            .unwrap();
+
+            let output = tank
+                .evaluate(&ExpansionTankInput {
+                    fluid: Water,
+                    temperature: ThermodynamicTemperature::new::<degree_celsius>(80.0),
+                })
+                .unwrap();
+
+            // Water expands about 2.9 % between 10 °C and 80 °C.
+            let expansion = output.expansion_volume.get::<liter>();
+            assert!((14.0..15.0).contains(&expansion), "expansion = {expansion}");
+            assert!(output.pressure > Pressure::new::<kilopascal>(300.0));
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Throttling valve rated by a flow coefficient.
+//!
+//! A [`Valve`] computes the mass flow through a valve from the pressure drop
+//! across it, its rated `Kv` or `Cv`, and an opening fraction in the unit
+//! interval. Gas flow is capped at the choked limit derived from the inlet
+//! speed of sound, so any thermo model implementing `HasCp`, `HasCv`, and
+//! `HasSpeedOfSound` can be used.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::hydraulic::valve::{
+//!         FlowCoefficient, Valve, ValveCharacteristic, ValveInput, ValveParameters,
+//!     },
+//!     support::{
+//!         constraint::Constrained,
+//!         thermo::{capability::StateFrom, fluid::Air, model::PerfectGas},
+//!     },
+//! };
+//! use uom::si::{
+//!     f64::{Pressure, Ratio, ThermodynamicTemperature, VolumeRate},
+//!     pressure::kilopascal,
+//!     ratio::percent,
+//!     thermodynamic_temperature::degree_celsius,
+//!     volume_rate::gallon_per_minute,
+//! };
+//!
+//! let valve = Valve::new(
+//!     ValveParameters {
+//!         flow_coefficient: FlowCoefficient::Cv(VolumeRate::new::<gallon_per_minute>(2.0)),
+//!         characteristic: ValveCharacteristic::EqualPercentage { rangeability: 50.0 },
+//!     },
+//!     PerfectGas::<Air>::new().unwrap(),
+//! )
+//! .unwrap();
+//!
+//! let inlet = valve
+//!     .thermo()
+//!     .state_from((
+//!         Air,
+//!         ThermodynamicTemperature::new::<degree_celsius>(20.0),
+//!         Pressure::new::<kilopascal>(600.0),
+//!     ))
+//!     .unwrap();
+//!
+//! // Venting to atmosphere chokes the flow.
+//! let output = valve
+//!     .evaluate(&ValveInput {
+//!         inlet,
+//!         pressure_drop: Pressure::new::<kilopascal>(500.0),
+//!         opening: Constrained::new(Ratio::new::<percent>(60.0)).unwrap(),
+//!     })
+//!     .unwrap();
+//!
+//! assert!(output.choked);
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    FlowCoefficient, Valve, ValveCharacteristic, ValveError, ValveInput, ValveOutput,
+    ValveParameters, ValveThermoModel,
+};
+
+impl<Fluid, Thermo> Model for Valve<Fluid, Thermo>
+where
+    Thermo: ValveThermoModel<Fluid>,
+{
+    type Input = ValveInput<Fluid>;
+    type Output = ValveOutput;
+    type Error = ValveError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
@@ -0,0 +1,511 @@
+use std::{error::Error as StdError, marker::PhantomData};
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::{
+        f64::{Area, MassDensity, MassRate, Pressure, Ratio, VolumeRate},
+        mass_density::kilogram_per_cubic_meter,
+        pressure::{bar, psi},
+        ratio::ratio,
+    },
+};
+
+use crate::support::{
+    constraint::{Constrained, Constraint, StrictlyPositive, UnitInterval},
+    thermo::{
+        State,
+        capability::{HasCp, HasCv, HasSpeedOfSound, ThermoModel},
+    },
+};
+
+/// Density of the water used to rate flow coefficients.
+const REFERENCE_DENSITY_KG_PER_M3: f64 = 1000.0;
+
+/// Required thermo model bounds for a [`Valve`].
+#[doc(hidden)]
+pub trait ValveThermoModel<Fluid>:
+    ThermoModel<Fluid = Fluid> + HasCp + HasCv + HasSpeedOfSound
+{
+}
+
+impl<Fluid, T> ValveThermoModel<Fluid> for T where
+    T: ThermoModel<Fluid = Fluid> + HasCp + HasCv + HasSpeedOfSound
+{
+}
+
+/// Rated flow coefficient of a fully open valve.
+///
+/// Both conventions give the volumetric flow of water through the valve at a
+/// reference pressure drop, and differ only in that reference.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FlowCoefficient {
+    /// Flow at a 1 bar pressure drop, usually quoted in m³/h.
+    Kv(VolumeRate),
+
+    /// Flow at a 1 psi pressure drop, usually quoted in US gal/min.
+    Cv(VolumeRate),
+}
+
+impl FlowCoefficient {
+    /// Returns the equivalent `Kv`.
+    ///
+    /// A `Cv` is rescaled to the 1 bar reference drop, which recovers the
+    /// familiar `Kv ≈ 0.865·Cv` when both are in their customary units.
+    #[must_use]
+    pub fn kv(self) -> VolumeRate {
+        match self {
+            Self::Kv(kv) => kv,
+            Self::Cv(cv) => {
+                let scale = (Pressure::new::<bar>(1.0) / Pressure::new::<psi>(1.0)).get::<ratio>();
+                cv * scale.sqrt()
+            }
+        }
+    }
+}
+
+/// Inherent flow characteristic relating opening to flow coefficient.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ValveCharacteristic {
+    /// Flow coefficient proportional to opening.
+    #[default]
+    Linear,
+
+    /// Each increment of opening multiplies the flow coefficient by the same
+    /// factor, `Kv/Kv_max = R^(x − 1)`.
+    EqualPercentage {
+        /// Ratio of the largest to the smallest controllable flow
+        /// coefficient; must be greater than one.
+        rangeability: f64,
+    },
+}
+
+impl ValveCharacteristic {
+    /// Fraction of the rated flow coefficient available at `opening`.
+    ///
+    /// A fully closed valve passes no flow regardless of characteristic.
+    #[must_use]
+    pub fn relative_flow(self, opening: Constrained<Ratio, UnitInterval>) -> f64 {
+        let x = opening.into_inner().get::<ratio>();
+        if x == 0.0 {
+            return 0.0;
+        }
+        match self {
+            Self::Linear => x,
+            Self::EqualPercentage { rangeability } => rangeability.powf(x - 1.0),
+        }
+    }
+}
+
+/// Fixed design parameters of a [`Valve`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ValveParameters {
+    /// Flow coefficient of the fully open valve.
+    pub flow_coefficient: FlowCoefficient,
+
+    /// Inherent flow characteristic.
+    pub characteristic: ValveCharacteristic,
+}
+
+/// Errors that can occur when creating or evaluating a [`Valve`].
+#[derive(Debug, Error)]
+pub enum ValveError {
+    /// A design parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// The pressure drop across the valve is negative.
+    #[error("pressure drop must be non-negative, got {0:?}")]
+    NegativePressureDrop(Pressure),
+
+    /// A thermodynamic model operation failed.
+    #[error("thermodynamic model failed: {context}")]
+    ThermoModelFailed {
+        /// Operation context for the thermodynamic model failure.
+        context: String,
+
+        /// Underlying thermodynamic model error.
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+}
+
+impl ValveError {
+    fn thermo(context: &str, source: impl StdError + Send + Sync + 'static) -> Self {
+        Self::ThermoModelFailed {
+            context: context.to_owned(),
+            source: Box::new(source),
+        }
+    }
+}
+
+/// Input to the valve model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValveInput<Fluid> {
+    /// State of the fluid entering the valve.
+    pub inlet: State<Fluid>,
+
+    /// Static pressure drop from inlet to outlet.
+    pub pressure_drop: Pressure,
+
+    /// Valve opening, from closed (zero) to fully open (one).
+    pub opening: Constrained<Ratio, UnitInterval>,
+}
+
+/// Output from the valve model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ValveOutput {
+    /// Mass flow through the valve.
+    pub mass_flow: MassRate,
+
+    /// Volumetric flow at inlet density.
+    pub volume_flow: VolumeRate,
+
+    /// Effective `Kv` at the given opening.
+    pub kv: VolumeRate,
+
+    /// Whether the flow is limited by choking.
+    pub choked: bool,
+}
+
+/// A throttling valve rated by a flow coefficient.
+///
+/// The flow through a partially open valve follows the rating equation with
+/// inlet density `ρ`,
+///
+/// ```text
+/// ṁ = Kv·√(ρ·ρ_w·ΔP / 1 bar)
+/// ```
+///
+/// where `ρ_w` is the 1000 kg/m³ rating density and `Kv` is scaled by the
+/// characteristic. The same `Kv` defines an effective flow area
+/// `A = Kv·√(ρ_w / (2·1 bar))`, and compressible flow cannot exceed the
+/// critical mass flux through that area,
+///
+/// ```text
+/// ṁ* = A·ρ·a·(2 / (γ + 1))^((γ + 1) / (2·(γ − 1)))
+/// ```
+///
+/// with speed of sound `a` and `γ = cp⁄cv` at the inlet. An incompressible
+/// thermo model reports an infinite speed of sound, so liquids never choke.
+#[derive(Debug, Clone)]
+pub struct Valve<Fluid, Thermo> {
+    parameters: ValveParameters,
+    kv: VolumeRate,
+    thermo: Thermo,
+    _fluid: PhantomData<Fluid>,
+}
+
+impl<Fluid, Thermo> Valve<Fluid, Thermo> {
+    /// Creates a valve from its design parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValveError::InvalidParameter`] if the flow coefficient is not
+    /// strictly positive or an equal-percentage rangeability is not greater
+    /// than one.
+    pub fn new(parameters: ValveParameters, thermo: Thermo) -> Result<Self, ValveError> {
+        let kv = parameters.flow_coefficient.kv();
+        if StrictlyPositive::check(&kv).is_err() || !kv.is_finite() {
+            return Err(ValveError::InvalidParameter(
+                "flow coefficient must be strictly positive and finite",
+            ));
+        }
+        if let ValveCharacteristic::EqualPercentage { rangeability } = parameters.characteristic
+            && !(rangeability > 1.0 && rangeability.is_finite())
+        {
+            return Err(ValveError::InvalidParameter(
+                "rangeability must be finite and greater than one",
+            ));
+        }
+
+        Ok(Self {
+            parameters,
+            kv,
+            thermo,
+            _fluid: PhantomData,
+        })
+    }
+
+    /// Returns the design parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &ValveParameters {
+        &self.parameters
+    }
+
+    /// Returns the thermo model.
+    #[must_use]
+    pub fn thermo(&self) -> &Thermo {
+        &self.thermo
+    }
+
+    /// Evaluates the flow through the valve.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pressure drop is negative or the thermo model
+    /// cannot report the inlet speed of sound or heat capacities.
+    pub fn evaluate(&self, input: &ValveInput<Fluid>) -> Result<ValveOutput, ValveError>
+    where
+        Thermo: ValveThermoModel<Fluid>,
+    {
+        let ValveInput {
+            inlet,
+            pressure_drop,
+            opening,
+        } = input;
+
+        if *pressure_drop < Pressure::ZERO {
+            return Err(ValveError::NegativePressureDrop(*pressure_drop));
+        }
+
+        let kv = self.kv * self.parameters.characteristic.relative_flow(*opening);
+        let rho = inlet.density;
+        let rho_w = MassDensity::new::<kilogram_per_cubic_meter>(REFERENCE_DENSITY_KG_PER_M3);
+        let reference_drop = Pressure::new::<bar>(1.0);
+
+        let scale = (*pressure_drop / reference_drop).get::<ratio>().sqrt();
+        let rated_flow: MassRate = kv * (rho * rho_w).sqrt() * scale;
+
+        let a = self
+            .thermo
+            .speed_of_sound(inlet)
+            .map_err(|error| ValveError::thermo("inlet speed of sound", error))?;
+        let choked_flow = if a.is_finite() {
+            let cp = self
+                .thermo
+                .cp(inlet)
+                .map_err(|error| ValveError::thermo("inlet cp", error))?;
+            let cv = self
+                .thermo
+                .cv(inlet)
+                .map_err(|error| ValveError::thermo("inlet cv", error))?;
+            let gamma = (cp / cv).get::<ratio>();
+
+            let area: Area = kv * (rho_w / (2.0 * reference_drop)).sqrt();
+            Some(area * rho * a * critical_flux_factor(gamma))
+        } else {
+            None
+        };
+
+        let (mass_flow, choked) = match choked_flow {
+            Some(limit) if limit < rated_flow => (limit, true),
+            _ => (rated_flow, false),
+        };
+
+        Ok(ValveOutput {
+            mass_flow,
+            volume_flow: mass_flow / rho,
+            kv,
+            choked,
+        })
+    }
+}
+
+/// Critical mass flux `ṁ*/(A·ρ·a)` of an ideal gas expanding from rest.
+///
+/// Approaches `e^(−1/2)` as `γ → 1`.
+fn critical_flux_factor(gamma: f64) -> f64 {
+    if gamma - 1.0 < 1e-9 {
+        return (-0.5_f64).exp();
+    }
+    (2.0 / (gamma + 1.0)).powf((gamma + 1.0) / (2.0 * (gamma - 1.0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::ThermodynamicTemperature, mass_rate::kilogram_per_second, pressure::kilopascal,
+        thermodynamic_temperature::degree_celsius, volume_rate::cubic_meter_per_hour,
+        volume_rate::gallon_per_minute,
+    };
+
+    use crate::support::thermo::{
+        capability::StateFrom,
+        fluid::{Air, Water},
+        model::{Incompressible, PerfectGas},
+    };
+
+    fn opening(x: f64) -> Constrained<Ratio, UnitInterval> {
+        Constrained::new(Ratio::new::<ratio>(x)).unwrap()
+    }
+
+    fn water_valve(characteristic: ValveCharacteristic) -> Valve<Water, Incompressible<Water>> {
+        Valve::new(
+            ValveParameters {
+                flow_coefficient: FlowCoefficient::Kv(VolumeRate::new::<cubic_meter_per_hour>(
+                    10.0,
+                )),
+                characteristic,
+            },
+            Incompressible::<Water>::new().unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn water_input(
+        valve: &Valve<Water, Incompressible<Water>>,
+        pressure_drop: f64,
+        x: f64,
+    ) -> ValveInput<Water> {
+        let inlet = valve
+            .thermo()
+            .state_from((Water, ThermodynamicTemperature::new::<degree_celsius>(20.0)))
+            .unwrap();
+        ValveInput {
+            inlet,
+            pressure_drop: Pressure::new::<kilopascal>(pressure_drop),
+            opening: opening(x),
+        }
+    }
+
+    fn air_valve() -> Valve<Air, PerfectGas<Air>> {
+        Valve::new(
+            ValveParameters {
+                flow_coefficient: FlowCoefficient::Kv(VolumeRate::new::<cubic_meter_per_hour>(1.0)),
+                characteristic: ValveCharacteristic::Linear,
+            },
+            PerfectGas::<Air>::new().unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn air_input(pressure_drop: f64) -> ValveInput<Air> {
+        let inlet = PerfectGas::<Air>::new()
+            .unwrap()
+            .state_from((
+                Air,
+                ThermodynamicTemperature::new::<degree_celsius>(20.0),
+                Pressure::new::<kilopascal>(500.0),
+            ))
+            .unwrap();
+        ValveInput {
+            inlet,
+            pressure_drop: Pressure::new::<kilopascal>(pressure_drop),
+            opening: opening(1.0),
+        }
+    }
+
+    #[test]
+    fn cv_converts_to_kv() {
+        let kv = FlowCoefficient::Cv(VolumeRate::new::<gallon_per_minute>(1.0)).kv();
+        assert_relative_eq!(kv.get::<cubic_meter_per_hour>(), 0.865, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn open_valve_passes_kv_at_one_bar() {
+        let valve = water_valve(ValveCharacteristic::Linear);
+        let output = valve.evaluate(&water_input(&valve, 100.0, 1.0)).unwrap();
+
+        // The water model's density is close to, but not exactly, the rating density.
+        let rho = valve.thermo().reference_density();
+        let expected = 10.0 * (REFERENCE_DENSITY_KG_PER_M3 / rho.value).sqrt();
+        assert_relative_eq!(
+            output.volume_flow.get::<cubic_meter_per_hour>(),
+            expected,
+            max_relative = 1e-12
+        );
+        assert!(!output.choked);
+    }
+
+    #[test]
+    fn flow_scales_with_square_root_of_pressure_drop() {
+        let valve = water_valve(ValveCharacteristic::Linear);
+        let low = valve.evaluate(&water_input(&valve, 25.0, 1.0)).unwrap();
+        let high = valve.evaluate(&water_input(&valve, 100.0, 1.0)).unwrap();
+
+        assert_relative_eq!(
+            (high.mass_flow / low.mass_flow).get::<ratio>(),
+            2.0,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn characteristic_scales_effective_kv() {
+        let linear = water_valve(ValveCharacteristic::Linear);
+        let output = linear.evaluate(&water_input(&linear, 50.0, 0.4)).unwrap();
+        assert_relative_eq!(output.kv.get::<cubic_meter_per_hour>(), 4.0);
+
+        let equal_percentage =
+            water_valve(ValveCharacteristic::EqualPercentage { rangeability: 50.0 });
+        let output = equal_percentage
+            .evaluate(&water_input(&equal_percentage, 50.0, 0.5))
+            .unwrap();
+        assert_relative_eq!(
+            output.kv.get::<cubic_meter_per_hour>(),
+            10.0 / 50.0_f64.sqrt()
+        );
+    }
+
+    #[test]
+    fn closed_valve_passes_no_flow() {
+        let valve = water_valve(ValveCharacteristic::EqualPercentage { rangeability: 30.0 });
+        let output = valve.evaluate(&water_input(&valve, 200.0, 0.0)).unwrap();
+        assert_eq!(output.mass_flow, MassRate::ZERO);
+    }
+
+    #[test]
+    fn small_gas_drop_is_unchoked() {
+        let valve = air_valve();
+        let input = air_input(5.0);
+        let output = valve.evaluate(&input).unwrap();
+
+        let rho = input.inlet.density.get::<kilogram_per_cubic_meter>();
+        let expected = (1.0 / 3600.0) * (rho * REFERENCE_DENSITY_KG_PER_M3 * 0.05).sqrt();
+        assert_relative_eq!(
+            output.mass_flow.get::<kilogram_per_second>(),
+            expected,
+            max_relative = 1e-12
+        );
+        assert!(!output.choked);
+    }
+
+    #[test]
+    fn large_gas_drop_is_choked() {
+        let valve = air_valve();
+        let moderate = valve.evaluate(&air_input(400.0)).unwrap();
+        let large = valve.evaluate(&air_input(490.0)).unwrap();
+
+        assert!(moderate.choked);
+        assert!(large.choked);
+        assert_eq!(moderate.mass_flow, large.mass_flow);
+    }
+
+    #[test]
+    fn critical_flux_factor_matches_air() {
+        assert_relative_eq!(critical_flux_factor(1.4), 0.5787, epsilon = 1e-4);
+        assert_relative_eq!(critical_flux_factor(1.0), (-0.5_f64).exp());
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_inputs() {
+        let thermo = Incompressible::<Water>::new().unwrap();
+        let zero_kv = ValveParameters {
+            flow_coefficient: FlowCoefficient::Kv(VolumeRate::ZERO),
+            characteristic: ValveCharacteristic::Linear,
+        };
+        assert!(matches!(
+            Valve::<Water, _>::new(zero_kv, thermo),
+            Err(ValveError::InvalidParameter(_))
+        ));
+
+        let flat = ValveParameters {
+            flow_coefficient: FlowCoefficient::Kv(VolumeRate::new::<cubic_meter_per_hour>(1.0)),
+            characteristic: ValveCharacteristic::EqualPercentage { rangeability: 1.0 },
+        };
+        assert!(matches!(
+            Valve::<Water, _>::new(flat, thermo),
+            Err(ValveError::InvalidParameter(_))
+        ));
+
+        let valve = water_valve(ValveCharacteristic::Linear);
+        assert!(matches!(
+            valve.evaluate(&water_input(&valve, -1.0, 1.0)),
+            Err(ValveError::NegativePressureDrop(_))
+        ));
+    }
+}
@@ -0,0 +1,91 @@
+//! Monte Carlo uncertainty propagation for any model.
+//!
+//! [`MonteCarlo`] wraps a [`twine_core::Model`] and runs it many times with
+//! selected input fields drawn from [`Distribution`]s, then reports
+//! [`Statistics`] (mean, standard deviation, extremes, and quantiles) for
+//! chosen outputs. Design-margin questions, such as how much capacity a
+//! heat exchanger keeps if its UA comes in 15% low, become a few lines
+//! instead of a script around each model.
+//!
+//! Draws are seeded per sample, so a run is reproducible from
+//! [`MonteCarloConfig::seed`] and gives identical results on any number of
+//! [`threads`](MonteCarloConfig::threads).
+//!
+//! ## Example
+//!
+//! ```
+//! use std::convert::Infallible;
+//!
+//! use twine_core::Model;
+//! use twine_models::models::monte_carlo::{Distribution, MonteCarlo, MonteCarloConfig};
+//! use uom::si::{
+//!     f64::{Power, TemperatureInterval, ThermalConductance},
+//!     power::watt,
+//!     temperature_interval::kelvin,
+//!     thermal_conductance::watt_per_kelvin,
+//! };
+//!
+//! /// Heat flow through a wall: `Q = UA·ΔT`.
+//! struct Wall;
+//!
+//! #[derive(Clone)]
+//! struct WallInput {
+//!     ua: ThermalConductance,
+//!     delta_t: TemperatureInterval,
+//! }
+//!
+//! impl Model for Wall {
+//!     type Input = WallInput;
+//!     type Output = Power;
+//!     type Error = Infallible;
+//!
+//!     fn call(&self, input: &WallInput) -> Result<Power, Infallible> {
+//!         Ok(input.ua * input.delta_t)
+//!     }
+//! }
+//!
+//! // UA is known to ±10 W/K; the temperature difference is most likely 20 K.
+//! let study = MonteCarlo::new(Wall, MonteCarloConfig::default())
+//!     .vary("ua", Distribution::uniform(90.0, 110.0)?, |input: &mut WallInput, ua| {
+//!         input.ua = ThermalConductance::new::<watt_per_kelvin>(ua);
+//!     })
+//!     .vary("delta_t", Distribution::triangular(15.0, 20.0, 25.0)?, |input, dt| {
+//!         input.delta_t = TemperatureInterval::new::<kelvin>(dt);
+//!     })
+//!     .observe("q_watts", |q: &Power| q.get::<watt>());
+//!
+//! let base = WallInput {
+//!     ua: ThermalConductance::new::<watt_per_kelvin>(100.0),
+//!     delta_t: TemperatureInterval::new::<kelvin>(20.0),
+//! };
+//! let results = study.call(&base)?;
+//!
+//! let q = results.get("q_watts").unwrap();
+//! assert!((q.mean - 2000.0).abs() < 30.0);
+//! assert!(q.quantile(0.05) > 1500.0 && q.quantile(0.95) < 2500.0);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    Distribution, DistributionError, MonteCarlo, MonteCarloConfig, MonteCarloError,
+    MonteCarloResults, Statistics,
+};
+
+/// Runs every sample from the given base input.
+impl<M> Model for MonteCarlo<M>
+where
+    M: Model + Sync,
+    M::Input: Clone + Sync,
+{
+    type Input = M::Input;
+    type Output = MonteCarloResults;
+    type Error = MonteCarloError<M::Error>;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.run(input)
+    }
+}
@@ -0,0 +1,513 @@
+mod distribution;
+mod rng;
+
+use std::{fmt, ops::Range, panic, thread};
+
+use thiserror::Error;
+use twine_core::Model;
+
+pub use distribution::{Distribution, DistributionError};
+
+use rng::Rng;
+
+type Setter<I> = Box<dyn Fn(&mut I, f64) + Send + Sync>;
+type Extractor<O> = Box<dyn Fn(&O) -> f64 + Send + Sync>;
+
+struct Parameter<I> {
+    name: String,
+    distribution: Distribution,
+    set: Setter<I>,
+}
+
+struct Observable<O> {
+    name: String,
+    extract: Extractor<O>,
+}
+
+/// Sampling settings for a [`MonteCarlo`] run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonteCarloConfig {
+    /// Number of model evaluations.
+    pub samples: usize,
+
+    /// Seed for the random draws.
+    ///
+    /// A run is reproducible from its seed, whatever the thread count.
+    pub seed: u64,
+
+    /// Number of worker threads; 1 runs on the calling thread.
+    pub threads: usize,
+}
+
+impl Default for MonteCarloConfig {
+    /// 1000 samples with seed 0 on one thread.
+    fn default() -> Self {
+        Self {
+            samples: 1000,
+            seed: 0,
+            threads: 1,
+        }
+    }
+}
+
+/// Errors from a [`MonteCarlo`] run.
+#[derive(Debug, Error)]
+pub enum MonteCarloError<E> {
+    /// The configuration cannot produce statistics.
+    #[error("invalid Monte Carlo configuration: {0}")]
+    InvalidConfig(&'static str),
+
+    /// The wrapped model failed.
+    ///
+    /// [`MonteCarlo::input`] rebuilds the input that caused it.
+    #[error("model failed on sample {sample}")]
+    Model {
+        /// Index of the failing sample.
+        sample: usize,
+
+        /// The wrapped model's error.
+        #[source]
+        source: E,
+    },
+
+    /// An observed output was NaN or infinite.
+    #[error("output `{output}` is not finite on sample {sample}")]
+    NonFinite {
+        /// Name of the output.
+        output: String,
+
+        /// Index of the offending sample.
+        sample: usize,
+    },
+}
+
+/// Summary of one observed output across all samples.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statistics {
+    /// Sample mean.
+    pub mean: f64,
+
+    /// Sample standard deviation (zero for a single sample).
+    pub std_dev: f64,
+
+    /// Smallest value.
+    pub min: f64,
+
+    /// Largest value.
+    pub max: f64,
+
+    sorted: Vec<f64>,
+}
+
+impl Statistics {
+    fn new(mut values: Vec<f64>) -> Self {
+        values.sort_by(f64::total_cmp);
+        // Sample counts are far below 2^52, so the casts are exact.
+        #[allow(clippy::cast_precision_loss)]
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let variance = if values.len() > 1 {
+            values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+        Self {
+            mean,
+            std_dev: variance.sqrt(),
+            min: values[0],
+            max: values[values.len() - 1],
+            sorted: values,
+        }
+    }
+
+    /// Returns the `q` quantile, interpolating linearly between samples.
+    ///
+    /// `q` is clamped to `[0, 1]`, so `quantile(0.95)` is the 95th
+    /// percentile.
+    #[must_use]
+    // Sample counts are far below 2^52, so the casts are exact.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn quantile(&self, q: f64) -> f64 {
+        let position = q.clamp(0.0, 1.0) * (self.sorted.len() - 1) as f64;
+        // `position` is non-negative and within the sample count.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let below = position.floor() as usize;
+        let above = (below + 1).min(self.sorted.len() - 1);
+        let fraction = position - below as f64;
+        self.sorted[below] + fraction * (self.sorted[above] - self.sorted[below])
+    }
+
+    /// Returns every sampled value, sorted ascending.
+    #[must_use]
+    pub fn values(&self) -> &[f64] {
+        &self.sorted
+    }
+}
+
+/// Output statistics from a [`MonteCarlo`] run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonteCarloResults {
+    outputs: Vec<(String, Statistics)>,
+}
+
+impl MonteCarloResults {
+    /// Returns the statistics of the output observed under `name`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Statistics> {
+        self.outputs
+            .iter()
+            .find(|(output, _)| output == name)
+            .map(|(_, statistics)| statistics)
+    }
+
+    /// Iterates over the observed outputs in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Statistics)> {
+        self.outputs
+            .iter()
+            .map(|(name, statistics)| (name.as_str(), statistics))
+    }
+}
+
+/// Runs a model over sampled inputs and summarizes its outputs.
+///
+/// Each sample starts from a base input, overwrites the
+/// [`vary`](Self::vary)ed fields with random draws, calls the wrapped model,
+/// and records each [`observe`](Self::observe)d output.
+pub struct MonteCarlo<M: Model> {
+    model: M,
+    config: MonteCarloConfig,
+    parameters: Vec<Parameter<M::Input>>,
+    observables: Vec<Observable<M::Output>>,
+}
+
+impl<M: Model> MonteCarlo<M> {
+    /// Wraps `model` with no uncertain inputs or observed outputs yet.
+    pub fn new(model: M, config: MonteCarloConfig) -> Self {
+        Self {
+            model,
+            config,
+            parameters: Vec::new(),
+            observables: Vec::new(),
+        }
+    }
+
+    /// Adds an uncertain input.
+    ///
+    /// `set` writes a drawn value into the input, attaching units as needed.
+    /// Inputs are drawn in the order they were added.
+    #[must_use]
+    pub fn vary(
+        mut self,
+        name: impl Into<String>,
+        distribution: Distribution,
+        set: impl Fn(&mut M::Input, f64) + Send + Sync + 'static,
+    ) -> Self {
+        self.parameters.push(Parameter {
+            name: name.into(),
+            distribution,
+            set: Box::new(set),
+        });
+        self
+    }
+
+    /// Adds an output to summarize, extracted from each model output as a
+    /// number.
+    #[must_use]
+    pub fn observe(
+        mut self,
+        name: impl Into<String>,
+        extract: impl Fn(&M::Output) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        self.observables.push(Observable {
+            name: name.into(),
+            extract: Box::new(extract),
+        });
+        self
+    }
+
+    /// Returns the wrapped model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Returns the sampling settings.
+    pub fn config(&self) -> &MonteCarloConfig {
+        &self.config
+    }
+}
+
+impl<M> MonteCarlo<M>
+where
+    M: Model,
+    M::Input: Clone,
+{
+    /// Returns the input used for `sample`, built from `base`.
+    ///
+    /// Useful for reproducing a sample reported in a
+    /// [`MonteCarloError`].
+    pub fn input(&self, base: &M::Input, sample: usize) -> M::Input {
+        let mut rng = Rng::for_sample(self.config.seed, sample);
+        let mut input = base.clone();
+        for parameter in &self.parameters {
+            (parameter.set)(&mut input, parameter.distribution.sample(&mut rng));
+        }
+        input
+    }
+
+    fn evaluate(
+        &self,
+        base: &M::Input,
+        sample: usize,
+    ) -> Result<Vec<f64>, MonteCarloError<M::Error>> {
+        let output = self
+            .model
+            .call(&self.input(base, sample))
+            .map_err(|source| MonteCarloError::Model { sample, source })?;
+
+        self.observables
+            .iter()
+            .map(|observable| {
+                let value = (observable.extract)(&output);
+                if value.is_finite() {
+                    Ok(value)
+                } else {
+                    Err(MonteCarloError::NonFinite {
+                        output: observable.name.clone(),
+                        sample,
+                    })
+                }
+            })
+            .collect()
+    }
+
+    fn evaluate_range(
+        &self,
+        base: &M::Input,
+        samples: Range<usize>,
+    ) -> Result<Vec<Vec<f64>>, MonteCarloError<M::Error>> {
+        samples.map(|sample| self.evaluate(base, sample)).collect()
+    }
+}
+
+impl<M> MonteCarlo<M>
+where
+    M: Model + Sync,
+    M::Input: Clone + Sync,
+{
+    /// Runs every sample from `base` and summarizes the observed outputs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MonteCarloError::InvalidConfig`] if there are no samples,
+    /// threads, or observed outputs. Otherwise returns the error from the
+    /// lowest-numbered failing sample.
+    ///
+    /// # Panics
+    ///
+    /// Re-raises a panic from the wrapped model on any worker thread.
+    pub fn run(&self, base: &M::Input) -> Result<MonteCarloResults, MonteCarloError<M::Error>> {
+        let MonteCarloConfig {
+            samples, threads, ..
+        } = self.config;
+        if samples == 0 {
+            return Err(MonteCarloError::InvalidConfig("samples must be at least 1"));
+        }
+        if threads == 0 {
+            return Err(MonteCarloError::InvalidConfig("threads must be at least 1"));
+        }
+        if self.observables.is_empty() {
+            return Err(MonteCarloError::InvalidConfig("no outputs are observed"));
+        }
+
+        let rows = if threads == 1 {
+            self.evaluate_range(base, 0..samples)?
+        } else {
+            let chunk = samples.div_ceil(threads);
+            let chunks: Vec<_> = thread::scope(|scope| {
+                let handles: Vec<_> = (0..samples)
+                    .step_by(chunk)
+                    .map(|start| {
+                        let end = (start + chunk).min(samples);
+                        scope.spawn(move || self.evaluate_range(base, start..end))
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().unwrap_or_else(|p| panic::resume_unwind(p)))
+                    .collect()
+            });
+            let mut rows = Vec::with_capacity(samples);
+            for chunk in chunks {
+                rows.extend(chunk?);
+            }
+            rows
+        };
+
+        let outputs = self
+            .observables
+            .iter()
+            .enumerate()
+            .map(|(index, observable)| {
+                let values = rows.iter().map(|row| row[index]).collect();
+                (observable.name.clone(), Statistics::new(values))
+            })
+            .collect();
+        Ok(MonteCarloResults { outputs })
+    }
+}
+
+impl<M: Model + fmt::Debug> fmt::Debug for MonteCarlo<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parameters: Vec<_> = self
+            .parameters
+            .iter()
+            .map(|parameter| (&parameter.name, &parameter.distribution))
+            .collect();
+        let observables: Vec<_> = self
+            .observables
+            .iter()
+            .map(|observable| &observable.name)
+            .collect();
+        f.debug_struct("MonteCarlo")
+            .field("model", &self.model)
+            .field("config", &self.config)
+            .field("parameters", &parameters)
+            .field("observables", &observables)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    /// `y = a·x + b`, failing for negative `x`.
+    #[derive(Debug)]
+    struct Line;
+
+    #[derive(Debug, Clone)]
+    struct LineInput {
+        a: f64,
+        b: f64,
+        x: f64,
+    }
+
+    #[derive(Debug, Error)]
+    #[error("x must be non-negative")]
+    struct NegativeX;
+
+    impl Model for Line {
+        type Input = LineInput;
+        type Output = f64;
+        type Error = NegativeX;
+
+        fn call(&self, input: &Self::Input) -> Result<f64, NegativeX> {
+            if input.x < 0.0 {
+                return Err(NegativeX);
+            }
+            Ok(input.a * input.x + input.b)
+        }
+    }
+
+    fn base() -> LineInput {
+        LineInput {
+            a: 2.0,
+            b: 1.0,
+            x: 3.0,
+        }
+    }
+
+    fn wrapper(config: MonteCarloConfig) -> MonteCarlo<Line> {
+        MonteCarlo::new(Line, config)
+            .vary("a", Distribution::normal(2.0, 0.1).unwrap(), |input, a| {
+                input.a = a;
+            })
+            .vary("b", Distribution::uniform(0.0, 2.0).unwrap(), |input, b| {
+                input.b = b;
+            })
+            .observe("y", |y| *y)
+    }
+
+    #[test]
+    fn statistics_follow_the_input_distributions() {
+        let config = MonteCarloConfig {
+            samples: 20_000,
+            ..MonteCarloConfig::default()
+        };
+        let results = wrapper(config).run(&base()).unwrap();
+        let y = results.get("y").unwrap();
+
+        // y = 3a + b: mean 7, variance 9·0.01 + 4/12.
+        assert_relative_eq!(y.mean, 7.0, max_relative = 1e-3);
+        assert_relative_eq!(
+            y.std_dev,
+            (0.09 + 1.0 / 3.0_f64).sqrt(),
+            max_relative = 0.02
+        );
+        assert!(y.min < y.quantile(0.05) && y.quantile(0.95) < y.max);
+        assert_relative_eq!(y.quantile(0.5), 7.0, max_relative = 0.01);
+        assert_eq!(y.values().len(), 20_000);
+        assert!(results.get("z").is_none());
+    }
+
+    #[test]
+    fn threads_do_not_change_the_results() {
+        let serial = MonteCarloConfig {
+            samples: 101,
+            seed: 9,
+            threads: 1,
+        };
+        let parallel = MonteCarloConfig {
+            threads: 4,
+            ..serial
+        };
+        assert_eq!(
+            wrapper(serial).run(&base()).unwrap(),
+            wrapper(parallel).run(&base()).unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_the_first_failing_sample() {
+        let config = MonteCarloConfig {
+            samples: 200,
+            threads: 3,
+            ..MonteCarloConfig::default()
+        };
+        let monte_carlo = wrapper(config).vary(
+            "x",
+            Distribution::uniform(-1.0, 10.0).unwrap(),
+            |input, x| input.x = x,
+        );
+
+        let Err(MonteCarloError::Model { sample, .. }) = monte_carlo.run(&base()) else {
+            panic!("expected a model failure");
+        };
+        assert!(monte_carlo.input(&base(), sample).x < 0.0);
+        assert!((0..sample).all(|earlier| monte_carlo.input(&base(), earlier).x >= 0.0));
+    }
+
+    #[test]
+    fn rejects_unusable_configurations() {
+        let no_samples = MonteCarloConfig {
+            samples: 0,
+            ..MonteCarloConfig::default()
+        };
+        assert!(matches!(
+            wrapper(no_samples).run(&base()),
+            Err(MonteCarloError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            MonteCarlo::new(Line, MonteCarloConfig::default()).run(&base()),
+            Err(MonteCarloError::InvalidConfig(_))
+        ));
+
+        let nan = MonteCarlo::new(Line, MonteCarloConfig::default()).observe("nan", |_| f64::NAN);
+        assert!(matches!(
+            nan.run(&base()),
+            Err(MonteCarloError::NonFinite { sample: 0, .. })
+        ));
+    }
+}
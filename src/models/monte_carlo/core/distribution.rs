@@ -0,0 +1,187 @@
+use std::f64::consts::TAU;
+
+use thiserror::Error;
+
+use super::rng::Rng;
+
+/// Probability distribution of an uncertain input.
+///
+/// Values are plain `f64`s; the setter passed to
+/// [`MonteCarlo::vary`](super::MonteCarlo::vary) attaches the units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Distribution {
+    /// Equally likely anywhere in `[low, high]`.
+    Uniform { low: f64, high: f64 },
+
+    /// Gaussian with the given mean and standard deviation.
+    Normal { mean: f64, std_dev: f64 },
+
+    /// Triangular on `[low, high]`, peaking at `mode`.
+    ///
+    /// A common choice when only a best estimate and bounds are known.
+    Triangular { low: f64, mode: f64, high: f64 },
+}
+
+/// Errors from creating a [`Distribution`].
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum DistributionError {
+    /// A parameter is NaN or infinite.
+    #[error("distribution parameters must be finite")]
+    NonFinite,
+
+    /// The bounds are reversed, or the mode lies outside them.
+    #[error("distribution bounds must satisfy low <= mode <= high, got {low}, {mode}, {high}")]
+    Bounds { low: f64, mode: f64, high: f64 },
+
+    /// The standard deviation is negative.
+    #[error("standard deviation must be non-negative, got {0}")]
+    NegativeStdDev(f64),
+}
+
+impl Distribution {
+    /// Creates a uniform distribution on `[low, high]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DistributionError`] if a bound is not finite or
+    /// `low > high`.
+    pub fn uniform(low: f64, high: f64) -> Result<Self, DistributionError> {
+        check_bounds(low, low, high)?;
+        Ok(Self::Uniform { low, high })
+    }
+
+    /// Creates a normal distribution.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DistributionError`] if a parameter is not finite or
+    /// `std_dev` is negative.
+    pub fn normal(mean: f64, std_dev: f64) -> Result<Self, DistributionError> {
+        if !(mean.is_finite() && std_dev.is_finite()) {
+            return Err(DistributionError::NonFinite);
+        }
+        if std_dev < 0.0 {
+            return Err(DistributionError::NegativeStdDev(std_dev));
+        }
+        Ok(Self::Normal { mean, std_dev })
+    }
+
+    /// Creates a triangular distribution on `[low, high]` with peak `mode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DistributionError`] if a parameter is not finite or
+    /// `low <= mode <= high` does not hold.
+    pub fn triangular(low: f64, mode: f64, high: f64) -> Result<Self, DistributionError> {
+        check_bounds(low, mode, high)?;
+        Ok(Self::Triangular { low, mode, high })
+    }
+
+    /// Returns the distribution's mean.
+    #[must_use]
+    pub fn mean(&self) -> f64 {
+        match *self {
+            Self::Uniform { low, high } => 0.5 * (low + high),
+            Self::Normal { mean, .. } => mean,
+            Self::Triangular { low, mode, high } => (low + mode + high) / 3.0,
+        }
+    }
+
+    pub(super) fn sample(&self, rng: &mut Rng) -> f64 {
+        match *self {
+            Self::Uniform { low, high } => low + rng.next_f64() * (high - low),
+            Self::Normal { mean, std_dev } => {
+                // Box–Muller; `1 - u` keeps the logarithm's argument in (0, 1].
+                let radius = (-2.0 * (1.0 - rng.next_f64()).ln()).sqrt();
+                mean + std_dev * radius * (TAU * rng.next_f64()).cos()
+            }
+            Self::Triangular { low, mode, high } => {
+                let u = rng.next_f64();
+                let width = high - low;
+                if width == 0.0 {
+                    return low;
+                }
+                let split = (mode - low) / width;
+                if u < split {
+                    low + (u * width * (mode - low)).sqrt()
+                } else {
+                    high - ((1.0 - u) * width * (high - mode)).sqrt()
+                }
+            }
+        }
+    }
+}
+
+fn check_bounds(low: f64, mode: f64, high: f64) -> Result<(), DistributionError> {
+    if !(low.is_finite() && mode.is_finite() && high.is_finite()) {
+        return Err(DistributionError::NonFinite);
+    }
+    if !(low <= mode && mode <= high) {
+        return Err(DistributionError::Bounds { low, mode, high });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    fn draws(distribution: Distribution) -> Vec<f64> {
+        let mut rng = Rng::for_sample(42, 0);
+        (0..100_000)
+            .map(|_| distribution.sample(&mut rng))
+            .collect()
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn mean_and_std_dev(values: &[f64]) -> (f64, f64) {
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        (mean, variance.sqrt())
+    }
+
+    #[test]
+    fn samples_match_their_moments() {
+        let uniform = Distribution::uniform(2.0, 6.0).unwrap();
+        let values = draws(uniform);
+        assert!(values.iter().all(|v| (2.0..6.0).contains(v)));
+        let (mean, std_dev) = mean_and_std_dev(&values);
+        assert_relative_eq!(mean, uniform.mean(), max_relative = 0.01);
+        assert_relative_eq!(std_dev, 4.0 / 12.0_f64.sqrt(), max_relative = 0.01);
+
+        let normal = Distribution::normal(10.0, 0.5).unwrap();
+        let (mean, std_dev) = mean_and_std_dev(&draws(normal));
+        assert_relative_eq!(mean, 10.0, max_relative = 0.001);
+        assert_relative_eq!(std_dev, 0.5, max_relative = 0.01);
+
+        let triangular = Distribution::triangular(0.0, 1.0, 4.0).unwrap();
+        let values = draws(triangular);
+        assert!(values.iter().all(|v| (0.0..=4.0).contains(v)));
+        let (mean, _) = mean_and_std_dev(&values);
+        assert_relative_eq!(mean, triangular.mean(), max_relative = 0.01);
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        assert_eq!(
+            Distribution::uniform(1.0, 0.0),
+            Err(DistributionError::Bounds {
+                low: 1.0,
+                mode: 1.0,
+                high: 0.0
+            })
+        );
+        assert_eq!(
+            Distribution::normal(0.0, -1.0),
+            Err(DistributionError::NegativeStdDev(-1.0))
+        );
+        assert_eq!(
+            Distribution::triangular(0.0, f64::NAN, 1.0),
+            Err(DistributionError::NonFinite)
+        );
+        assert!(Distribution::triangular(0.0, 2.0, 1.0).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+//! Quasi-steady plant simulation.
+//!
+//! Many plants mix components with very different response times: a tank
+//! takes hours to heat up, while its heat exchanger and pump settle in
+//! seconds. A [`QuasiSteadyDriver`] treats the slow parts as integrated
+//! storage and the fast parts as steady, so each time step solves the fast
+//! components for the current storage, then advances the storage with the
+//! rates that solution implies.
+//!
+//! A plant describes itself by implementing [`QuasiSteadyPlant`]. The driver
+//! owns the time loop:
+//!
+//! - [`TimeStep::Fixed`] takes equal steps and shortens the last one to land
+//!   on the end time.
+//! - [`TimeStep::Adaptive`] compares each step with two half steps, retrying
+//!   it shorter if they disagree and lengthening the next one if they agree
+//!   closely.
+//! - A [`WarmStart`](QuasiSteadyPlant::WarmStart) value is carried from one
+//!   steady solve to the next, so iterative components can start from the
+//!   last converged answer (see [`RecuperatorGivenUa::call_warm`] and
+//!   [`Flowsheet::solve_warm`]).
+//!
+//! [`RecuperatorGivenUa::call_warm`]: crate::models::thermal::hx::discretized::RecuperatorGivenUa::call_warm
+//! [`Flowsheet::solve_warm`]: crate::models::flowsheet::Flowsheet::solve_warm
+//!
+//! ## Example
+//!
+//! A 500 kg water tank heated through a coil with `UA = 2 kW/K` from a
+//! 350 K source.
+//!
+//! ```
+//! use std::convert::Infallible;
+//!
+//! use twine_core::StepIntegrable;
+//! use twine_models::{
+//!     models::simulation::{QuasiSteadyDriver, QuasiSteadyInput, QuasiSteadyPlant, TimeStep},
+//!     support::units::{TemperatureDifference, TemperatureRate},
+//! };
+//! use uom::si::{
+//!     f64::{HeatCapacity, Power, ThermalConductance, ThermodynamicTemperature, Time},
+//!     heat_capacity::kilojoule_per_kelvin,
+//!     thermal_conductance::kilowatt_per_kelvin,
+//!     thermodynamic_temperature::kelvin,
+//!     time::{hour, minute},
+//! };
+//!
+//! #[derive(Clone)]
+//! struct Tank(ThermodynamicTemperature);
+//!
+//! impl StepIntegrable<Time> for Tank {
+//!     type Derivative = TemperatureRate;
+//!
+//!     fn step(&self, rate: TemperatureRate, dt: Time) -> Self {
+//!         Tank(self.0 + rate * dt)
+//!     }
+//! }
+//!
+//! struct Coil {
+//!     source: ThermodynamicTemperature,
+//!     ua: ThermalConductance,
+//!     capacity: HeatCapacity,
+//! }
+//!
+//! impl QuasiSteadyPlant for Coil {
+//!     type Storage = Tank;
+//!     type Solution = Power;
+//!     type WarmStart = ();
+//!     type Error = Infallible;
+//!
+//!     fn solve(&self, _t: Time, tank: &Tank, _: &mut ()) -> Result<Power, Infallible> {
+//!         Ok(self.ua * self.source.minus(tank.0))
+//!     }
+//!
+//!     fn derivative(&self, _t: Time, _: &Tank, q: &Power) -> Result<TemperatureRate, Infallible> {
+//!         Ok((*q / self.capacity).into())
+//!     }
+//!
+//!     fn step_error(&self, _: &Tank, _: &Tank) -> f64 {
+//!         0.0
+//!     }
+//! }
+//!
+//! let coil = Coil {
+//!     source: ThermodynamicTemperature::new::<kelvin>(350.0),
+//!     ua: ThermalConductance::new::<kilowatt_per_kelvin>(2.0),
+//!     capacity: HeatCapacity::new::<kilojoule_per_kelvin>(500.0 * 4.18),
+//! };
+//! let driver = QuasiSteadyDriver::new(coil, TimeStep::Fixed(Time::new::<minute>(1.0)));
+//!
+//! let trajectory = driver.run(&QuasiSteadyInput {
+//!     start: Time::new::<hour>(0.0),
+//!     end: Time::new::<hour>(2.0),
+//!     storage: Tank(ThermodynamicTemperature::new::<kelvin>(290.0)),
+//! })?;
+//!
+//! // The time constant is about 17 minutes, so two hours nearly settles it.
+//! let end = trajectory.last();
+//! assert_eq!(trajectory.points.len(), 121);
+//! assert!((end.storage.0.get::<kelvin>() - 350.0).abs() < 0.1);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    AdaptiveStep, QuasiSteadyDriver, QuasiSteadyError, QuasiSteadyInput, QuasiSteadyPlant,
+    TimeStep, Trajectory, TrajectoryPoint,
+};
+
+/// Runs the plant over the input's time span.
+impl<P: QuasiSteadyPlant> Model for QuasiSteadyDriver<P> {
+    type Input = QuasiSteadyInput<P::Storage>;
+    type Output = Trajectory<P::Storage, P::Solution>;
+    type Error = QuasiSteadyError<P::Error>;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.run(input)
+    }
+}
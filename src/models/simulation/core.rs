@@ -0,0 +1,606 @@
+use thiserror::Error;
+use twine_core::{DerivativeOf, StepIntegrable};
+use uom::{ConstZero, si::f64::Time};
+
+use crate::support::units::display::pretty;
+
+/// A plant that a [`QuasiSteadyDriver`] can step through time.
+///
+/// The plant splits into two parts. Storage, such as tank temperatures or a
+/// vessel's mass and energy, changes slowly and is integrated. Everything
+/// else, such as heat exchangers, pumps, or a whole
+/// [`Flowsheet`](crate::models::flowsheet::Flowsheet), responds quickly and
+/// is solved at steady state for the storage held fixed.
+pub trait QuasiSteadyPlant {
+    /// Integrated storage states.
+    type Storage: StepIntegrable<Time, Derivative: Clone> + Clone;
+
+    /// Steady solution of the fast components at one instant.
+    type Solution: Clone;
+
+    /// Hints carried from one steady solve to the next.
+    ///
+    /// A fresh value is created at the start of each run and passed to every
+    /// [`solve`](Self::solve), which can read the last converged values and
+    /// overwrite them, for example to seed
+    /// [`RecuperatorGivenUa::call_warm`] or [`Flowsheet::solve_warm`].
+    /// Use `()` if no component benefits from a warm start.
+    ///
+    /// [`RecuperatorGivenUa::call_warm`]: crate::models::thermal::hx::discretized::RecuperatorGivenUa::call_warm
+    /// [`Flowsheet::solve_warm`]: crate::models::flowsheet::Flowsheet::solve_warm
+    type WarmStart: Default;
+
+    /// Error returned by the steady solve or the storage derivative.
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Solves the fast components at `time` with the storage held fixed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the plant's error if a component fails.
+    fn solve(
+        &self,
+        time: Time,
+        storage: &Self::Storage,
+        warm_start: &mut Self::WarmStart,
+    ) -> Result<Self::Solution, Self::Error>;
+
+    /// Returns the rate of change of the storage implied by a steady solution.
+    ///
+    /// # Errors
+    ///
+    /// Returns the plant's error if the derivative cannot be computed.
+    fn derivative(
+        &self,
+        time: Time,
+        storage: &Self::Storage,
+        solution: &Self::Solution,
+    ) -> Result<DerivativeOf<Self::Storage, Time>, Self::Error>;
+
+    /// Returns the scaled difference between two estimates of one step.
+    ///
+    /// [`TimeStep::Adaptive`] compares a full step (`coarse`) with two half
+    /// steps (`fine`) and accepts the step when this returns at most 1.
+    /// Scale each storage state by its tolerance, for example
+    /// `|ΔT| / 0.01 K`, and return the largest. Never called with
+    /// [`TimeStep::Fixed`], where returning zero is fine.
+    fn step_error(&self, coarse: &Self::Storage, fine: &Self::Storage) -> f64;
+}
+
+/// How a [`QuasiSteadyDriver`] chooses its time steps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeStep {
+    /// Every step has the same length, except a shorter final step to land
+    /// on the end time.
+    Fixed(Time),
+
+    /// Step lengths follow the plant's [`step_error`](QuasiSteadyPlant::step_error).
+    Adaptive(AdaptiveStep),
+}
+
+/// Step length limits for [`TimeStep::Adaptive`].
+///
+/// Each step is taken once in full and once as two halves. The halves are
+/// kept if the two results agree within tolerance; otherwise the step is
+/// retried shorter. The next step grows or shrinks with the observed error,
+/// by at most a factor of five either way.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveStep {
+    /// Length of the first attempted step.
+    pub initial: Time,
+
+    /// Shortest step; a step this short that still misses tolerance fails
+    /// the run.
+    pub min: Time,
+
+    /// Longest step.
+    pub max: Time,
+}
+
+/// Start time, end time, and initial storage for a run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuasiSteadyInput<Storage> {
+    /// Simulation start time.
+    pub start: Time,
+
+    /// Simulation end time.
+    pub end: Time,
+
+    /// Storage states at `start`.
+    pub storage: Storage,
+}
+
+/// One accepted point of a run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrajectoryPoint<Storage, Solution> {
+    /// Simulation time.
+    pub time: Time,
+
+    /// Storage states at `time`.
+    pub storage: Storage,
+
+    /// Steady solution at `time`.
+    pub solution: Solution,
+}
+
+/// Every accepted point of a run, starting with the initial state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trajectory<Storage, Solution> {
+    /// Accepted points in time order; the last is at the end time.
+    pub points: Vec<TrajectoryPoint<Storage, Solution>>,
+
+    /// Adaptive steps that missed tolerance and were retried shorter.
+    pub rejected_steps: usize,
+}
+
+impl<Storage, Solution> Trajectory<Storage, Solution> {
+    /// Returns the point at the end time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` has been emptied by the caller.
+    #[must_use]
+    pub fn last(&self) -> &TrajectoryPoint<Storage, Solution> {
+        self.points
+            .last()
+            .expect("a trajectory always holds the initial point")
+    }
+}
+
+/// Errors from a [`QuasiSteadyDriver`] run.
+#[derive(Debug, Error)]
+pub enum QuasiSteadyError<E> {
+    /// The time span or step settings are invalid.
+    #[error("invalid quasi-steady configuration: {0}")]
+    InvalidConfig(&'static str),
+
+    /// The plant failed to solve or to compute its storage derivative.
+    #[error("plant failed at t = {}", pretty(*.time))]
+    Plant {
+        /// Simulation time of the failure.
+        time: Time,
+
+        /// The plant's error.
+        #[source]
+        source: E,
+    },
+
+    /// An adaptive step at the minimum length still missed tolerance.
+    #[error("step error {error} at t = {} exceeds tolerance at the minimum step", pretty(*.time))]
+    StepSizeUnderflow {
+        /// Start of the step that failed.
+        time: Time,
+
+        /// Scaled error of the minimum-length step.
+        error: f64,
+    },
+}
+
+type PlantTrajectory<P> =
+    Trajectory<<P as QuasiSteadyPlant>::Storage, <P as QuasiSteadyPlant>::Solution>;
+
+/// Steps a [`QuasiSteadyPlant`] through time.
+///
+/// At each accepted time the driver solves the plant's fast components at
+/// steady state, takes the storage derivative from that solution, and
+/// advances the storage with an explicit Euler step. See the
+/// [module documentation](super) for an example.
+#[derive(Debug, Clone)]
+pub struct QuasiSteadyDriver<P> {
+    plant: P,
+    step: TimeStep,
+}
+
+impl<P: QuasiSteadyPlant> QuasiSteadyDriver<P> {
+    /// Creates a driver for `plant` with the given step control.
+    pub fn new(plant: P, step: TimeStep) -> Self {
+        Self { plant, step }
+    }
+
+    /// Returns the plant.
+    pub fn plant(&self) -> &P {
+        &self.plant
+    }
+
+    /// Runs the plant from `input.start` to `input.end`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`QuasiSteadyError`] if the configuration is invalid, the
+    /// plant fails, or an adaptive step cannot meet tolerance.
+    pub fn run(
+        &self,
+        input: &QuasiSteadyInput<P::Storage>,
+    ) -> Result<PlantTrajectory<P>, QuasiSteadyError<P::Error>> {
+        self.validate(input)?;
+
+        let mut warm_start = P::WarmStart::default();
+        let mut time = input.start;
+        let mut storage = input.storage.clone();
+        let mut solution = self.solve(time, &storage, &mut warm_start)?;
+
+        let mut points = vec![TrajectoryPoint {
+            time,
+            storage: storage.clone(),
+            solution: solution.clone(),
+        }];
+        let mut rejected_steps = 0;
+        let mut dt = match self.step {
+            TimeStep::Fixed(dt) => dt,
+            TimeStep::Adaptive(adaptive) => adaptive.initial,
+        };
+
+        while time < input.end {
+            let remaining = input.end - time;
+            let h = if dt < remaining { dt } else { remaining };
+            let derivative = self.derivative(time, &storage, &solution)?;
+
+            let next = match self.step {
+                TimeStep::Fixed(_) => storage.step(derivative, h),
+                TimeStep::Adaptive(adaptive) => {
+                    let coarse = storage.step(derivative.clone(), h);
+                    let half = h * 0.5;
+                    let mid = storage.step(derivative, half);
+                    let mid_solution = self.solve(time + half, &mid, &mut warm_start)?;
+                    let mid_derivative = self.derivative(time + half, &mid, &mid_solution)?;
+                    let fine = mid.step(mid_derivative, half);
+
+                    let error = self.plant.step_error(&coarse, &fine);
+                    let resized = (h * step_factor(error)).min(adaptive.max);
+                    if error.is_nan() || error > 1.0 {
+                        if h <= adaptive.min {
+                            return Err(QuasiSteadyError::StepSizeUnderflow { time, error });
+                        }
+                        rejected_steps += 1;
+                        dt = resized.max(adaptive.min);
+                        continue;
+                    }
+                    // A step shortened to land on the end time says nothing
+                    // about how long the next one could be.
+                    if h == dt {
+                        dt = resized.max(adaptive.min);
+                    }
+                    fine
+                }
+            };
+
+            time = if h == remaining { input.end } else { time + h };
+            solution = self.solve(time, &next, &mut warm_start)?;
+            storage = next;
+            points.push(TrajectoryPoint {
+                time,
+                storage: storage.clone(),
+                solution: solution.clone(),
+            });
+        }
+
+        Ok(Trajectory {
+            points,
+            rejected_steps,
+        })
+    }
+
+    fn validate(
+        &self,
+        input: &QuasiSteadyInput<P::Storage>,
+    ) -> Result<(), QuasiSteadyError<P::Error>> {
+        if !(input.start.is_finite() && input.end.is_finite()) {
+            return Err(QuasiSteadyError::InvalidConfig(
+                "start and end times must be finite",
+            ));
+        }
+        if input.end < input.start {
+            return Err(QuasiSteadyError::InvalidConfig(
+                "end time must not precede start time",
+            ));
+        }
+        let positive = |dt: Time| dt > Time::ZERO && dt.is_finite();
+        match self.step {
+            TimeStep::Fixed(dt) if !positive(dt) => Err(QuasiSteadyError::InvalidConfig(
+                "fixed step must be positive and finite",
+            )),
+            TimeStep::Adaptive(AdaptiveStep { initial, min, max })
+                if !(positive(min) && min <= initial && initial <= max && max.is_finite()) =>
+            {
+                Err(QuasiSteadyError::InvalidConfig(
+                    "adaptive steps must satisfy 0 < min ≤ initial ≤ max < ∞",
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn solve(
+        &self,
+        time: Time,
+        storage: &P::Storage,
+        warm_start: &mut P::WarmStart,
+    ) -> Result<P::Solution, QuasiSteadyError<P::Error>> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            time = time.get::<uom::si::time::second>(),
+            "quasi-steady solve"
+        );
+
+        self.plant
+            .solve(time, storage, warm_start)
+            .map_err(|source| QuasiSteadyError::Plant { time, source })
+    }
+
+    fn derivative(
+        &self,
+        time: Time,
+        storage: &P::Storage,
+        solution: &P::Solution,
+    ) -> Result<DerivativeOf<P::Storage, Time>, QuasiSteadyError<P::Error>> {
+        self.plant
+            .derivative(time, storage, solution)
+            .map_err(|source| QuasiSteadyError::Plant { time, source })
+    }
+}
+
+/// Returns the factor applied to a step length after observing `error`.
+///
+/// Euler's local error scales with the square of the step, so the step that
+/// would just meet tolerance is `h / √error`. A 0.9 safety factor and limits
+/// of 0.2 and 5 keep the step from oscillating.
+fn step_factor(error: f64) -> f64 {
+    if error.is_nan() {
+        return 0.2;
+    }
+    (0.9 / error.sqrt()).clamp(0.2, 5.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{cell::Cell, convert::Infallible};
+
+    use approx::assert_relative_eq;
+    use thiserror::Error;
+    use uom::si::{
+        f64::{TemperatureInterval, ThermodynamicTemperature},
+        temperature_interval::kelvin as delta_kelvin,
+        thermodynamic_temperature::kelvin,
+        time::second,
+    };
+
+    use crate::support::units::{TemperatureDifference, TemperatureRate};
+
+    /// A tank relaxing toward a source temperature with time constant `tau`.
+    struct Tank {
+        source: ThermodynamicTemperature,
+        tau: Time,
+        tolerance: TemperatureInterval,
+        warm_hits: Cell<usize>,
+    }
+
+    impl Tank {
+        fn new() -> Self {
+            Self {
+                source: ThermodynamicTemperature::new::<kelvin>(350.0),
+                tau: Time::new::<second>(100.0),
+                tolerance: TemperatureInterval::new::<delta_kelvin>(1e-2),
+                warm_hits: Cell::new(0),
+            }
+        }
+
+        fn exact(&self, t: f64) -> f64 {
+            350.0 - 50.0 * (-t / self.tau.get::<second>()).exp()
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct TankState(ThermodynamicTemperature);
+
+    impl StepIntegrable<Time> for TankState {
+        type Derivative = TemperatureRate;
+
+        fn step(&self, derivative: TemperatureRate, delta: Time) -> Self {
+            Self(self.0 + derivative * delta)
+        }
+    }
+
+    impl QuasiSteadyPlant for Tank {
+        type Storage = TankState;
+        type Solution = TemperatureInterval;
+        type WarmStart = Option<TemperatureInterval>;
+        type Error = Infallible;
+
+        fn solve(
+            &self,
+            _time: Time,
+            storage: &TankState,
+            warm_start: &mut Option<TemperatureInterval>,
+        ) -> Result<TemperatureInterval, Infallible> {
+            if warm_start.is_some() {
+                self.warm_hits.set(self.warm_hits.get() + 1);
+            }
+            let approach = self.source.minus(storage.0);
+            *warm_start = Some(approach);
+            Ok(approach)
+        }
+
+        fn derivative(
+            &self,
+            _time: Time,
+            _storage: &TankState,
+            approach: &TemperatureInterval,
+        ) -> Result<TemperatureRate, Infallible> {
+            Ok(*approach / self.tau)
+        }
+
+        fn step_error(&self, coarse: &TankState, fine: &TankState) -> f64 {
+            (coarse.0.minus(fine.0) / self.tolerance).value.abs()
+        }
+    }
+
+    fn input(end: f64) -> QuasiSteadyInput<TankState> {
+        QuasiSteadyInput {
+            start: Time::ZERO,
+            end: Time::new::<second>(end),
+            storage: TankState(ThermodynamicTemperature::new::<kelvin>(300.0)),
+        }
+    }
+
+    #[test]
+    fn fixed_steps_land_on_the_end_time() {
+        let driver = QuasiSteadyDriver::new(Tank::new(), TimeStep::Fixed(Time::new::<second>(1.0)));
+        let trajectory = driver.run(&input(100.5)).unwrap();
+
+        assert_eq!(trajectory.points.len(), 102);
+        assert_eq!(trajectory.last().time, Time::new::<second>(100.5));
+        assert_eq!(trajectory.rejected_steps, 0);
+
+        // Explicit Euler at dt = τ/100 is within half a percent of the decay.
+        let tank = driver.plant();
+        assert_relative_eq!(
+            trajectory.last().storage.0.get::<kelvin>(),
+            tank.exact(100.5),
+            max_relative = 5e-4
+        );
+
+        // Every solve after the first sees the previous solution.
+        assert_eq!(tank.warm_hits.get(), 101);
+    }
+
+    #[test]
+    fn adaptive_steps_meet_tolerance_with_fewer_points() {
+        let step = TimeStep::Adaptive(AdaptiveStep {
+            initial: Time::new::<second>(1.0),
+            min: Time::new::<second>(1e-3),
+            max: Time::new::<second>(50.0),
+        });
+        let driver = QuasiSteadyDriver::new(Tank::new(), step);
+        let trajectory = driver.run(&input(500.0)).unwrap();
+
+        assert_eq!(trajectory.last().time, Time::new::<second>(500.0));
+        assert!(
+            trajectory.points.len() < 100,
+            "{} points",
+            trajectory.points.len()
+        );
+
+        // Steps grow as the tank settles.
+        let steps: Vec<f64> = trajectory
+            .points
+            .windows(2)
+            .map(|pair| (pair[1].time - pair[0].time).get::<second>())
+            .collect();
+        assert!(steps[steps.len() - 2] > 5.0 * steps[0]);
+
+        // Each step's own error is near tolerance, and they accumulate over
+        // the run to a fraction of a kelvin out of a 50 K swing.
+        let tank = driver.plant();
+        let worst = trajectory
+            .points
+            .iter()
+            .map(|point| {
+                (point.storage.0.get::<kelvin>() - tank.exact(point.time.get::<second>())).abs()
+            })
+            .fold(0.0, f64::max);
+        assert!(worst < 0.3, "worst error {worst} K");
+    }
+
+    #[test]
+    fn adaptive_steps_fail_below_the_minimum() {
+        let step = TimeStep::Adaptive(AdaptiveStep {
+            initial: Time::new::<second>(10.0),
+            min: Time::new::<second>(5.0),
+            max: Time::new::<second>(10.0),
+        });
+        let driver = QuasiSteadyDriver::new(Tank::new(), step);
+
+        assert!(matches!(
+            driver.run(&input(100.0)),
+            Err(QuasiSteadyError::StepSizeUnderflow { time, .. }) if time == Time::ZERO
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_configuration() {
+        let fixed =
+            |dt| QuasiSteadyDriver::new(Tank::new(), TimeStep::Fixed(Time::new::<second>(dt)));
+        assert!(matches!(
+            fixed(0.0).run(&input(10.0)),
+            Err(QuasiSteadyError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            fixed(1.0).run(&input(-10.0)),
+            Err(QuasiSteadyError::InvalidConfig(_))
+        ));
+
+        let adaptive = QuasiSteadyDriver::new(
+            Tank::new(),
+            TimeStep::Adaptive(AdaptiveStep {
+                initial: Time::new::<second>(100.0),
+                min: Time::new::<second>(1.0),
+                max: Time::new::<second>(10.0),
+            }),
+        );
+        assert!(matches!(
+            adaptive.run(&input(10.0)),
+            Err(QuasiSteadyError::InvalidConfig(_))
+        ));
+    }
+
+    #[derive(Debug, Error)]
+    #[error("source dried up")]
+    struct DriedUp;
+
+    /// Fails once the simulation passes `limit`.
+    struct Failing {
+        limit: Time,
+    }
+
+    impl QuasiSteadyPlant for Failing {
+        type Storage = TankState;
+        type Solution = ();
+        type WarmStart = ();
+        type Error = DriedUp;
+
+        fn solve(
+            &self,
+            time: Time,
+            _storage: &TankState,
+            _warm_start: &mut (),
+        ) -> Result<(), DriedUp> {
+            if time > self.limit {
+                Err(DriedUp)
+            } else {
+                Ok(())
+            }
+        }
+
+        fn derivative(
+            &self,
+            _time: Time,
+            _storage: &TankState,
+            _solution: &(),
+        ) -> Result<TemperatureRate, DriedUp> {
+            Ok(TemperatureInterval::new::<delta_kelvin>(1.0) / Time::new::<second>(1.0))
+        }
+
+        fn step_error(&self, _coarse: &TankState, _fine: &TankState) -> f64 {
+            0.0
+        }
+    }
+
+    #[test]
+    fn plant_errors_report_the_time() {
+        let driver = QuasiSteadyDriver::new(
+            Failing {
+                limit: Time::new::<second>(2.5),
+            },
+            TimeStep::Fixed(Time::new::<second>(1.0)),
+        );
+
+        match driver.run(&input(10.0)) {
+            Err(QuasiSteadyError::Plant { time, .. }) => {
+                assert_eq!(time, Time::new::<second>(3.0));
+            }
+            other => panic!("expected a plant error, got {other:?}"),
+        }
+    }
+}
@@ -5,21 +5,159 @@
 //!
 //! ## Available models
 //!
-//! - **Heat exchangers** ([`hx`]) — counterflow heat recovery between two
-//!   streams of the same working fluid, discretized into segments for
-//!   real-fluid accuracy:
+//! - **Batteries** ([`battery`]) — [`BatteryPack`]: lumped-capacitance pack
+//!   with ohmic heat generation and cooling via a UA to a coolant stream.
+//!
+//! - **Chillers** ([`chiller`]) — [`AdsorptionChiller`]: two adsorbent beds
+//!   cycled between hot and cooling water, with lumped bed dynamics and
+//!   cycle-averaged cooling capacity and COP.
+//!
+//! - **District heating** ([`district_heating`]) — [`Substation`]: plate
+//!   heat exchanger, primary control valve, and secondary pump that hold a
+//!   secondary supply setpoint within network ΔP limits.
+//!
+//! - **Electronics** ([`electronics`]) — liquid cooling of electronics:
+//!   - [`ColdPlate`]: junction-to-coolant resistance network of a
+//!     liquid-cooled cold plate.
+//!   - [`LiquidCoolingLoop`]: pump, cold plate, and radiator composed into a
+//!     closed loop, solved in steady state.
+//!
+//! - **Engines** ([`engine`]) — [`StirlingEngine`]: isothermal Schmidt
+//!   analysis of a Stirling engine with an imperfect regenerator.
+//!
+//! - **Ground heat exchangers** ([`ground`]) — [`Borefield`]: vertical
+//!   boreholes whose finite line source responses are superposed across
+//!   the field, with load aggregation for multi-year simulations.
+//!   [`HorizontalLoop`]: straight trench pipes or a slinky coil in shallow
+//!   ground whose undisturbed temperature swings with the seasons.
+//!
+//! - **Heat exchangers** ([`hx`]) — heat recovery between two streams,
+//!   discretized into segments for real-fluid accuracy, header-fed
+//!   parallel circuits, and heat rejection to ambient air:
 //!   - [`RecuperatorGivenUa`]: given a target UA, find outlet states
 //!     (iterative).
 //!   - [`RecuperatorGivenOutlet`]: given an outlet temperature, compute UA
 //!     (direct).
+//!   - [`Recuperator`]: either of the above, chosen per call.
+//!   - [`DiscretizedExchanger`]: counterflow or parallel flow and segment
+//!     count chosen at runtime.
+//!   - [`Manifold`]: flow split among parallel circuits by header friction,
+//!     with the resulting effectiveness penalty.
+//!   - [`AirCooledCondenser`]: fan-driven finned-tube condenser or dry
+//!     cooler rejecting heat to ambient air.
+//!   - [`EnergyRecoveryVentilator`]: membrane core exchanging heat and
+//!     moisture between ventilation supply and exhaust air.
+//!   - [`DrainWaterHeatRecovery`]: falling-film drain stack preheating
+//!     mains water from shower drain water over a draw profile.
+//!
+//! - **Pipes** ([`pipe`]) — freezing and freeze protection of pipes:
+//!   - [`PipeFreeze`]: stagnant fluid and pipe wall cooling, freezing
+//!     solid, and thawing by the enthalpy method, with time-to-freeze under
+//!     a cold ambient.
+//!   - [`HeatTrace`]: thermostatically switched constant-wattage or
+//!     self-regulating heating cable holding a pipe above freezing, with
+//!     its energy use.
+//!
+//! - **Pools** ([`pool`]) — [`SwimmingPool`]: well-mixed open water mass
+//!   with evaporation, convection, radiation, and ground losses plus solar
+//!   and heater gains.
+//!
+//! - **Refrigeration** ([`refrigeration`]) — vapor-compression cycles and
+//!   their components:
+//!   - [`CascadeCycle`]: two loops coupled through a discretized cascade
+//!     condenser, solved for the intermediate temperature.
+//!   - [`DefrostController`]: timed or demand defrost of a frosting coil,
+//!     metering the defrost energy.
+//!   - [`Desuperheater`]: recovers discharge superheat into a water stream
+//!     without condensing the refrigerant.
+//!   - [`FrostedCoil`]: frost growth on an air-source evaporator, with the
+//!     resulting UA and pressure-drop penalties and defrost energy.
+//!   - [`LiquidReceiver`]: vessel feeding saturated liquid to the expansion
+//!     valve, reporting the charge it holds and can still take.
+//!   - [`OilCirculation`]: compressor oil carried with the refrigerant,
+//!     degrading film coefficients and evaporator capacity.
+//!   - [`SuctionAccumulator`]: vessel feeding saturated vapor to the
+//!     compressor, reporting the charge it holds and can still take.
+//!   - [`SuctionLineHx`]: liquid-line to suction-line heat exchanger, sized
+//!     by effectiveness or conductance.
+//!
+//! - **Solar** ([`solar`]) — solar thermal systems:
+//!   - [`SolarDhwSystem`]: collector array, pipes, external heat exchanger,
+//!     stratified tank, pump controller, and backup element composed into a
+//!     domestic hot water system.
+//!   - [`SolarField`]: rows of tracking collectors for concentrating solar
+//!     power, with row-to-row shading, header losses, and defocus control.
+//!   - [`Thermosiphon`]: natural-circulation collector loop, solved for the
+//!     flow at which buoyancy balances friction.
+//!
+//! - **Steam** ([`steam`]) — steam and condensate distribution stations:
+//!   - [`Attemperator`]: spray desuperheating station finding the spray
+//!     water flow that holds a steam line at a target temperature.
+//!   - [`PressureReducingValve`]: isenthalpic pressure reduction with the
+//!     flash fraction, optionally followed by a desuperheater.
+//!
+//! - **Tanks** ([`tank`]) — thermal storage tanks:
+//!   - [`StratifiedTank`]: vertical thermal storage tank discretized into
+//!     fully mixed nodes, with port pairs, auxiliary heat sources, buoyancy
+//!     mixing, and conduction.
+//!   - [`TwoTankStorage`]: hot and cold tanks of a sensible storage medium,
+//!     charged and discharged through an exchanger, with tank losses and
+//!     state of charge.
 //!
-//! - **Tanks** ([`tank`]) — [`StratifiedTank`]: vertical thermal storage tank
-//!   discretized into fully mixed nodes, with port pairs, auxiliary heat
-//!   sources, buoyancy mixing, and conduction.
+//! - **Vessels** ([`vessel`]) — rigid vessels holding a fluid inventory:
+//!   - [`ControlVolume`]: rigid, well-mixed fluid volume whose mass and
+//!     internal energy change with inlet and outlet flows and heat input.
+//!   - [`Drum`]: saturated steam drum with feedwater, steam draw, continuous
+//!     blowdown, and liquid level, heated directly or by a gas-side tube bank.
 //!
+//! [`BatteryPack`]: battery::lumped::BatteryPack
+//! [`AdsorptionChiller`]: chiller::adsorption::AdsorptionChiller
+//! [`Substation`]: district_heating::substation::Substation
+//! [`ColdPlate`]: electronics::cold_plate::ColdPlate
+//! [`LiquidCoolingLoop`]: electronics::liquid_loop::LiquidCoolingLoop
+//! [`StirlingEngine`]: engine::stirling::StirlingEngine
+//! [`Borefield`]: ground::borefield::Borefield
+//! [`HorizontalLoop`]: ground::horizontal::HorizontalLoop
 //! [`RecuperatorGivenUa`]: hx::discretized::RecuperatorGivenUa
 //! [`RecuperatorGivenOutlet`]: hx::discretized::RecuperatorGivenOutlet
+//! [`Recuperator`]: hx::discretized::Recuperator
+//! [`DiscretizedExchanger`]: hx::discretized::DiscretizedExchanger
+//! [`Manifold`]: hx::manifold::Manifold
+//! [`AirCooledCondenser`]: hx::air_cooled::AirCooledCondenser
+//! [`EnergyRecoveryVentilator`]: hx::erv::EnergyRecoveryVentilator
+//! [`DrainWaterHeatRecovery`]: hx::drain_water::DrainWaterHeatRecovery
+//! [`PipeFreeze`]: pipe::freeze::PipeFreeze
+//! [`HeatTrace`]: pipe::heat_trace::HeatTrace
+//! [`SwimmingPool`]: pool::swimming::SwimmingPool
+//! [`CascadeCycle`]: refrigeration::cascade::CascadeCycle
+//! [`DefrostController`]: refrigeration::defrost::DefrostController
+//! [`Desuperheater`]: refrigeration::desuperheater::Desuperheater
+//! [`FrostedCoil`]: refrigeration::frost::FrostedCoil
+//! [`LiquidReceiver`]: refrigeration::receiver::LiquidReceiver
+//! [`OilCirculation`]: refrigeration::oil::OilCirculation
+//! [`SuctionAccumulator`]: refrigeration::accumulator::SuctionAccumulator
+//! [`SuctionLineHx`]: refrigeration::suction_line::SuctionLineHx
+//! [`SolarDhwSystem`]: solar::dhw::SolarDhwSystem
+//! [`SolarField`]: solar::field::SolarField
+//! [`Thermosiphon`]: solar::thermosiphon::Thermosiphon
+//! [`Attemperator`]: steam::attemperator::Attemperator
+//! [`PressureReducingValve`]: steam::prv::PressureReducingValve
 //! [`StratifiedTank`]: tank::stratified::StratifiedTank
+//! [`TwoTankStorage`]: tank::two_tank::TwoTankStorage
+//! [`ControlVolume`]: vessel::control_volume::ControlVolume
+//! [`Drum`]: vessel::drum::Drum
 
+pub mod battery;
+pub mod chiller;
+pub mod district_heating;
+pub mod electronics;
+pub mod engine;
+pub mod ground;
 pub mod hx;
+pub mod pipe;
+pub mod pool;
+pub mod refrigeration;
+pub mod solar;
+pub mod steam;
 pub mod tank;
+pub mod vessel;
@@ -0,0 +1,3 @@
+//! Battery pack thermal models.
+
+pub mod lumped;
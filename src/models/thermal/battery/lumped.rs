@@ -0,0 +1,274 @@
+//! Lumped-capacitance battery pack thermal model.
+//!
+//! A [`BatteryPack`] treats the pack as a single isothermal mass that
+//! generates ohmic heat (`I²R`) and exchanges heat with a coolant stream and
+//! its surroundings. The pack temperature is the integrable state.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::battery::lumped::{
+//!         BatteryPack, BatteryPackInput, BatteryPackParameters,
+//!     },
+//!     support::hx::{CapacitanceRate, StreamInlet},
+//! };
+//! use uom::si::{
+//!     electric_current::ampere,
+//!     electrical_resistance::milliohm,
+//!     f64::{ElectricCurrent, ElectricalResistance, Mass, SpecificHeatCapacity,
+//!           ThermalConductance, ThermodynamicTemperature},
+//!     mass::kilogram,
+//!     specific_heat_capacity::joule_per_kilogram_kelvin,
+//!     thermal_conductance::watt_per_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//! };
+//!
+//! let pack = BatteryPack::new(BatteryPackParameters {
+//!     mass: Mass::new::<kilogram>(300.0),
+//!     specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(1000.0),
+//!     internal_resistance: ElectricalResistance::new::<milliohm>(50.0),
+//!     coolant_ua: ThermalConductance::new::<watt_per_kelvin>(200.0),
+//!     ambient_ua: ThermalConductance::new::<watt_per_kelvin>(5.0),
+//! }).expect("valid parameters");
+//!
+//! let t_coolant = ThermodynamicTemperature::new::<degree_celsius>(25.0);
+//! let coolant = StreamInlet::new(
+//!     CapacitanceRate::new::<watt_per_kelvin>(400.0).unwrap(),
+//!     t_coolant,
+//! );
+//!
+//! let output = pack.evaluate(&BatteryPackInput {
+//!     temperature: ThermodynamicTemperature::new::<degree_celsius>(35.0),
+//!     current: ElectricCurrent::new::<ampere>(150.0),
+//!     coolant: Some(coolant),
+//!     ambient_temperature: ThermodynamicTemperature::new::<degree_celsius>(30.0),
+//! });
+//!
+//! // The coolant picks up heat from the warmer pack.
+//! assert!(output.coolant_outlet_temperature.unwrap() > t_coolant);
+//! ```
+
+mod core;
+
+use std::convert::Infallible;
+
+use twine_core::{DerivativeOf, Model, OdeProblem, StepIntegrable};
+use uom::si::f64::{ThermodynamicTemperature, Time};
+
+use crate::support::units::TemperatureRate;
+
+pub use core::{
+    BatteryPack, BatteryPackError, BatteryPackInput, BatteryPackOutput, BatteryPackParameters,
+};
+
+impl Model for BatteryPack {
+    type Input = BatteryPackInput;
+    type Output = BatteryPackOutput;
+    type Error = Infallible;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        Ok(self.evaluate(input))
+    }
+}
+
+/// Pack temperature, used as the ODE state for time integration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BatteryState {
+    /// Lumped pack temperature.
+    pub temperature: ThermodynamicTemperature,
+}
+
+impl StepIntegrable<Time> for BatteryState {
+    type Derivative = TemperatureRate;
+
+    fn step(&self, derivative: TemperatureRate, delta: Time) -> Self {
+        BatteryState {
+            temperature: self.temperature + derivative * delta,
+        }
+    }
+}
+
+/// Adapts a [`BatteryPack`] for use with `twine_solvers::transient::euler::solve`.
+///
+/// The state is the pack temperature. Current, coolant, and ambient
+/// conditions are carried forward from the base input on each step.
+///
+/// # Example
+///
+/// ```
+/// use twine_models::models::thermal::battery::lumped::{
+///     BatteryOdeProblem, BatteryPack, BatteryPackInput, BatteryPackParameters,
+/// };
+/// use twine_solvers::transient::euler;
+/// use uom::si::{
+///     electric_current::ampere,
+///     electrical_resistance::milliohm,
+///     f64::{ElectricCurrent, ElectricalResistance, Mass, SpecificHeatCapacity,
+///           ThermalConductance, ThermodynamicTemperature, Time},
+///     mass::kilogram,
+///     specific_heat_capacity::joule_per_kilogram_kelvin,
+///     thermal_conductance::watt_per_kelvin,
+///     thermodynamic_temperature::degree_celsius,
+///     time::second,
+/// };
+///
+/// let pack = BatteryPack::new(BatteryPackParameters {
+///     mass: Mass::new::<kilogram>(300.0),
+///     specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(1000.0),
+///     internal_resistance: ElectricalResistance::new::<milliohm>(50.0),
+///     coolant_ua: ThermalConductance::new::<watt_per_kelvin>(200.0),
+///     ambient_ua: ThermalConductance::new::<watt_per_kelvin>(5.0),
+/// }).unwrap();
+///
+/// let t_init = ThermodynamicTemperature::new::<degree_celsius>(25.0);
+/// let initial = BatteryPackInput {
+///     temperature: t_init,
+///     current: ElectricCurrent::new::<ampere>(200.0),
+///     coolant: None,
+///     ambient_temperature: t_init,
+/// };
+///
+/// let solution = euler::solve_unobserved(
+///     &pack,
+///     &BatteryOdeProblem,
+///     initial,
+///     Time::new::<second>(10.0),
+///     60,
+/// ).unwrap();
+/// assert_eq!(solution.steps, 60);
+/// ```
+pub struct BatteryOdeProblem;
+
+impl OdeProblem for BatteryOdeProblem {
+    type Input = BatteryPackInput;
+    type Output = BatteryPackOutput;
+    type Delta = Time;
+    type State = BatteryState;
+    type Error = Infallible;
+
+    fn state(&self, input: &Self::Input) -> Result<BatteryState, Infallible> {
+        Ok(BatteryState {
+            temperature: input.temperature,
+        })
+    }
+
+    fn derivative(
+        &self,
+        _input: &Self::Input,
+        output: &Self::Output,
+    ) -> Result<DerivativeOf<BatteryState, Time>, Infallible> {
+        Ok(output.derivative)
+    }
+
+    fn build_input(
+        &self,
+        base: &Self::Input,
+        state: &Self::State,
+        _delta: &Time,
+    ) -> Result<Self::Input, Infallible> {
+        Ok(BatteryPackInput {
+            temperature: state.temperature,
+            ..*base
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use twine_solvers::transient::euler;
+    use uom::{
+        ConstZero,
+        si::{
+            electric_current::ampere,
+            electrical_resistance::milliohm,
+            f64::{
+                ElectricCurrent, ElectricalResistance, Mass, SpecificHeatCapacity,
+                ThermalConductance,
+            },
+            mass::kilogram,
+            specific_heat_capacity::joule_per_kilogram_kelvin,
+            thermal_conductance::watt_per_kelvin,
+            thermodynamic_temperature::degree_celsius,
+            time::second,
+        },
+    };
+
+    use crate::support::hx::{CapacitanceRate, StreamInlet};
+
+    fn test_pack() -> BatteryPack {
+        BatteryPack::new(BatteryPackParameters {
+            mass: Mass::new::<kilogram>(100.0),
+            specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(1000.0),
+            internal_resistance: ElectricalResistance::new::<milliohm>(10.0),
+            coolant_ua: ThermalConductance::new::<watt_per_kelvin>(20.0),
+            ambient_ua: ThermalConductance::ZERO,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn euler_step_matches_derivative() {
+        let pack = test_pack();
+        let t_init = ThermodynamicTemperature::new::<degree_celsius>(25.0);
+        let initial = BatteryPackInput {
+            temperature: t_init,
+            current: ElectricCurrent::new::<ampere>(100.0),
+            coolant: None,
+            ambient_temperature: t_init,
+        };
+
+        let solution = euler::solve_unobserved(
+            &pack,
+            &BatteryOdeProblem,
+            initial,
+            Time::new::<second>(100.0),
+            1,
+        )
+        .unwrap();
+
+        // 100 W into 100 kJ/K for 100 s raises the pack by 0.1 K.
+        let last = solution.history.last().unwrap();
+        assert_relative_eq!(
+            last.input.temperature.get::<degree_celsius>(),
+            25.1,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn cooled_pack_approaches_steady_state() {
+        let pack = test_pack();
+        let coolant_temp = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+        let initial = BatteryPackInput {
+            temperature: coolant_temp,
+            current: ElectricCurrent::new::<ampere>(100.0),
+            coolant: Some(StreamInlet::new(
+                CapacitanceRate::new::<watt_per_kelvin>(1.0e6).unwrap(),
+                coolant_temp,
+            )),
+            ambient_temperature: coolant_temp,
+        };
+
+        let solution = euler::solve_unobserved(
+            &pack,
+            &BatteryOdeProblem,
+            initial,
+            Time::new::<second>(10.0),
+            5_000,
+        )
+        .unwrap();
+
+        // With a near-infinite coolant capacitance rate the effective
+        // conductance is UA, so steady state is ΔT = Q / UA = 100 / 20 = 5 K.
+        let last = solution.history.last().unwrap();
+        assert_relative_eq!(
+            last.input.temperature.get::<degree_celsius>(),
+            25.0,
+            epsilon = 1e-3
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::{
+        f64::{
+            ElectricCurrent, ElectricalResistance, HeatCapacity, Mass, Power, SpecificHeatCapacity,
+            ThermalConductance, ThermodynamicTemperature,
+        },
+        ratio::ratio,
+    },
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive},
+    hx::StreamInlet,
+    units::{TemperatureDifference, TemperatureRate},
+};
+
+/// Fixed physical parameters of a lumped battery pack.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatteryPackParameters {
+    /// Total thermal mass of the pack (cells, busbars, and enclosure).
+    pub mass: Mass,
+
+    /// Mass-averaged specific heat capacity of the pack.
+    pub specific_heat: SpecificHeatCapacity,
+
+    /// Lumped internal (ohmic) resistance seen by the pack current.
+    pub internal_resistance: ElectricalResistance,
+
+    /// Thermal conductance between the cells and the coolant stream.
+    pub coolant_ua: ThermalConductance,
+
+    /// Thermal conductance between the pack and its surroundings.
+    pub ambient_ua: ThermalConductance,
+}
+
+/// Errors that can occur when creating a [`BatteryPack`].
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+pub enum BatteryPackError {
+    /// The pack mass is not strictly positive.
+    #[error("mass must be strictly positive, got {0:?}")]
+    NonPositiveMass(Mass),
+
+    /// The specific heat capacity is not strictly positive.
+    #[error("specific heat must be strictly positive, got {0:?}")]
+    NonPositiveSpecificHeat(SpecificHeatCapacity),
+
+    /// The internal resistance is negative or NaN.
+    #[error("internal resistance must be non-negative, got {0:?}")]
+    NegativeResistance(ElectricalResistance),
+
+    /// The coolant conductance is negative or NaN.
+    #[error("coolant UA must be non-negative, got {0:?}")]
+    NegativeCoolantUa(ThermalConductance),
+
+    /// The ambient conductance is negative or NaN.
+    #[error("ambient UA must be non-negative, got {0:?}")]
+    NegativeAmbientUa(ThermalConductance),
+}
+
+/// A lumped-capacitance battery pack thermal model.
+///
+/// The pack is a single isothermal mass that generates heat from ohmic
+/// losses (`I²R`) and rejects heat to a coolant stream and to its
+/// surroundings.
+///
+/// The coolant sees the pack as an isothermal wall, so its effectiveness is
+/// `ε = 1 - exp(-UA / C)`, where `C` is the coolant capacitance rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BatteryPack {
+    heat_capacity: HeatCapacity,
+    internal_resistance: ElectricalResistance,
+    coolant_ua: ThermalConductance,
+    ambient_ua: ThermalConductance,
+}
+
+/// Input to the battery pack model.
+#[derive(Debug, Clone, Copy)]
+pub struct BatteryPackInput {
+    /// Current pack temperature.
+    pub temperature: ThermodynamicTemperature,
+
+    /// Pack current.
+    ///
+    /// The sign does not matter; charge and discharge both generate heat.
+    pub current: ElectricCurrent,
+
+    /// Coolant stream entering the pack, or `None` when the coolant is off.
+    pub coolant: Option<StreamInlet>,
+
+    /// Temperature of the surroundings.
+    pub ambient_temperature: ThermodynamicTemperature,
+}
+
+/// Output from the battery pack model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BatteryPackOutput {
+    /// Time derivative of the pack temperature.
+    pub derivative: TemperatureRate,
+
+    /// Ohmic heat generation within the pack.
+    pub heat_generation: Power,
+
+    /// Heat transferred from the pack to the coolant.
+    ///
+    /// Negative when the coolant is warmer than the pack.
+    pub coolant_heat_rate: Power,
+
+    /// Heat transferred from the pack to its surroundings.
+    ///
+    /// Negative when the surroundings are warmer than the pack.
+    pub ambient_heat_rate: Power,
+
+    /// Coolant outlet temperature, or `None` when the coolant is off.
+    pub coolant_outlet_temperature: Option<ThermodynamicTemperature>,
+}
+
+impl BatteryPack {
+    /// Creates a battery pack from its physical parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BatteryPackError`] if the mass or specific heat is not
+    /// strictly positive, or if the resistance or either conductance is
+    /// negative.
+    pub fn new(parameters: BatteryPackParameters) -> Result<Self, BatteryPackError> {
+        let BatteryPackParameters {
+            mass,
+            specific_heat,
+            internal_resistance,
+            coolant_ua,
+            ambient_ua,
+        } = parameters;
+
+        if StrictlyPositive::check(&mass).is_err() {
+            return Err(BatteryPackError::NonPositiveMass(mass));
+        }
+        if StrictlyPositive::check(&specific_heat).is_err() {
+            return Err(BatteryPackError::NonPositiveSpecificHeat(specific_heat));
+        }
+        if NonNegative::check(&internal_resistance).is_err() {
+            return Err(BatteryPackError::NegativeResistance(internal_resistance));
+        }
+        if NonNegative::check(&coolant_ua).is_err() {
+            return Err(BatteryPackError::NegativeCoolantUa(coolant_ua));
+        }
+        if NonNegative::check(&ambient_ua).is_err() {
+            return Err(BatteryPackError::NegativeAmbientUa(ambient_ua));
+        }
+
+        Ok(Self {
+            heat_capacity: mass * specific_heat,
+            internal_resistance,
+            coolant_ua,
+            ambient_ua,
+        })
+    }
+
+    /// Total heat capacity of the pack.
+    #[must_use]
+    pub fn heat_capacity(&self) -> HeatCapacity {
+        self.heat_capacity
+    }
+
+    /// Evaluates the pack's thermal response at a single point in time.
+    #[must_use]
+    pub fn evaluate(&self, input: &BatteryPackInput) -> BatteryPackOutput {
+        let BatteryPackInput {
+            temperature,
+            current,
+            coolant,
+            ambient_temperature,
+        } = *input;
+
+        let heat_generation: Power = current * current * self.internal_resistance;
+
+        let (coolant_heat_rate, coolant_outlet_temperature) = match coolant {
+            Some(inlet) => {
+                let c_dot = *inlet.capacitance_rate;
+                let ntu = (self.coolant_ua / c_dot).get::<ratio>();
+                let effectiveness = -(-ntu).exp_m1();
+                let q_dot = effectiveness * c_dot * temperature.minus(inlet.temperature);
+                (q_dot, Some(inlet.temperature + q_dot / c_dot))
+            }
+            None => (Power::ZERO, None),
+        };
+
+        let ambient_heat_rate = self.ambient_ua * temperature.minus(ambient_temperature);
+
+        let net_heat_rate = heat_generation - coolant_heat_rate - ambient_heat_rate;
+
+        BatteryPackOutput {
+            derivative: net_heat_rate / self.heat_capacity,
+            heat_generation,
+            coolant_heat_rate,
+            ambient_heat_rate,
+            coolant_outlet_temperature,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        electric_current::ampere,
+        electrical_resistance::milliohm,
+        f64::{TemperatureInterval, Time},
+        mass::kilogram,
+        power::watt,
+        specific_heat_capacity::joule_per_kilogram_kelvin,
+        temperature_interval::kelvin as delta_kelvin,
+        thermal_conductance::watt_per_kelvin,
+        thermodynamic_temperature::degree_celsius,
+        time::second,
+    };
+
+    use crate::support::{hx::CapacitanceRate, units::test_utils::celsius};
+
+    fn parameters() -> BatteryPackParameters {
+        BatteryPackParameters {
+            mass: Mass::new::<kilogram>(100.0),
+            specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(1000.0),
+            internal_resistance: ElectricalResistance::new::<milliohm>(10.0),
+            coolant_ua: ThermalConductance::new::<watt_per_kelvin>(50.0),
+            ambient_ua: ThermalConductance::new::<watt_per_kelvin>(5.0),
+        }
+    }
+
+    fn k_per_s(rate: TemperatureRate) -> f64 {
+        let delta: TemperatureInterval = rate * Time::new::<second>(1.0);
+        delta.get::<delta_kelvin>()
+    }
+
+    #[test]
+    fn ohmic_heating_without_cooling() {
+        let pack = BatteryPack::new(parameters()).unwrap();
+
+        let out = pack.evaluate(&BatteryPackInput {
+            temperature: celsius(25.0),
+            current: ElectricCurrent::new::<ampere>(-100.0),
+            coolant: None,
+            ambient_temperature: celsius(25.0),
+        });
+
+        // Q = I²R = 100² * 0.01 = 100 W
+        // dT/dt = Q / (m * cp) = 100 / 100,000 = 0.001 K/s
+        assert_relative_eq!(out.heat_generation.get::<watt>(), 100.0);
+        assert_relative_eq!(out.coolant_heat_rate.get::<watt>(), 0.0);
+        assert_relative_eq!(out.ambient_heat_rate.get::<watt>(), 0.0);
+        assert_relative_eq!(k_per_s(out.derivative), 0.001);
+        assert!(out.coolant_outlet_temperature.is_none());
+    }
+
+    #[test]
+    fn coolant_removes_heat_with_isothermal_wall_effectiveness() {
+        let pack = BatteryPack::new(parameters()).unwrap();
+        let c_dot = CapacitanceRate::new::<watt_per_kelvin>(50.0).unwrap();
+
+        let out = pack.evaluate(&BatteryPackInput {
+            temperature: celsius(40.0),
+            current: ElectricCurrent::new::<ampere>(0.0),
+            coolant: Some(StreamInlet::new(c_dot, celsius(20.0))),
+            ambient_temperature: celsius(40.0),
+        });
+
+        // NTU = 50 / 50 = 1, ε = 1 - e⁻¹
+        // Q = ε * C * (40 - 20)
+        let effectiveness = 1.0 - (-1.0_f64).exp();
+        let q_expected = effectiveness * 50.0 * 20.0;
+
+        assert_relative_eq!(out.coolant_heat_rate.get::<watt>(), q_expected);
+        assert_relative_eq!(
+            out.coolant_outlet_temperature
+                .unwrap()
+                .get::<degree_celsius>(),
+            20.0 + q_expected / 50.0,
+        );
+        assert_relative_eq!(k_per_s(out.derivative), -q_expected / 100_000.0);
+    }
+
+    #[test]
+    fn ambient_gain_when_surroundings_are_warmer() {
+        let pack = BatteryPack::new(parameters()).unwrap();
+
+        let out = pack.evaluate(&BatteryPackInput {
+            temperature: celsius(20.0),
+            current: ElectricCurrent::new::<ampere>(0.0),
+            coolant: None,
+            ambient_temperature: celsius(30.0),
+        });
+
+        assert_relative_eq!(out.ambient_heat_rate.get::<watt>(), -50.0);
+        assert_relative_eq!(k_per_s(out.derivative), 50.0 / 100_000.0);
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        let zero_mass = BatteryPackParameters {
+            mass: Mass::ZERO,
+            ..parameters()
+        };
+        assert!(matches!(
+            BatteryPack::new(zero_mass),
+            Err(BatteryPackError::NonPositiveMass(_))
+        ));
+
+        let negative_resistance = BatteryPackParameters {
+            internal_resistance: ElectricalResistance::new::<milliohm>(-1.0),
+            ..parameters()
+        };
+        assert!(matches!(
+            BatteryPack::new(negative_resistance),
+            Err(BatteryPackError::NegativeResistance(_))
+        ));
+
+        let nan_ua = BatteryPackParameters {
+            coolant_ua: ThermalConductance::new::<watt_per_kelvin>(f64::NAN),
+            ..parameters()
+        };
+        assert!(matches!(
+            BatteryPack::new(nan_ua),
+            Err(BatteryPackError::NegativeCoolantUa(_))
+        ));
+    }
+}
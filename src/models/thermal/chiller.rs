@@ -0,0 +1,3 @@
+//! Heat-driven chillers.
+
+pub mod adsorption;
@@ -0,0 +1,73 @@
+//! Two-bed adsorption chiller.
+//!
+//! An [`AdsorptionChiller`] turns low-grade heat into cooling by cycling two
+//! adsorbent beds between a hot water and a cooling water circuit. Given the
+//! bed design, the water inlets, and the evaporator and condenser
+//! temperatures, it steps the lumped bed dynamics over several cycles and
+//! reports the cycle-averaged cooling capacity, heat input, and COP.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::chiller::adsorption::{
+//!         Adsorbent, AdsorptionChiller, AdsorptionChillerInput, AdsorptionChillerParameters,
+//!     },
+//!     support::hx::{CapacitanceRate, StreamInlet},
+//! };
+//! use uom::si::{
+//!     f64::{HeatCapacity, Mass, ThermalConductance, ThermodynamicTemperature, Time},
+//!     heat_capacity::kilojoule_per_kelvin,
+//!     mass::kilogram,
+//!     power::kilowatt,
+//!     thermal_conductance::{kilowatt_per_kelvin, watt_per_kelvin},
+//!     thermodynamic_temperature::degree_celsius,
+//!     time::second,
+//! };
+//!
+//! let chiller = AdsorptionChiller::new(AdsorptionChillerParameters {
+//!     adsorbent: Adsorbent::silica_gel(),
+//!     adsorbent_mass: Mass::new::<kilogram>(20.0),
+//!     bed_heat_capacity: HeatCapacity::new::<kilojoule_per_kelvin>(20.0),
+//!     bed_ua: ThermalConductance::new::<kilowatt_per_kelvin>(1.5),
+//! })?;
+//!
+//! let output = chiller.simulate(&AdsorptionChillerInput {
+//!     hot_water: StreamInlet::new(
+//!         CapacitanceRate::new::<watt_per_kelvin>(1250.0)?,
+//!         ThermodynamicTemperature::new::<degree_celsius>(85.0),
+//!     ),
+//!     cooling_water: StreamInlet::new(
+//!         CapacitanceRate::new::<watt_per_kelvin>(2100.0)?,
+//!         ThermodynamicTemperature::new::<degree_celsius>(30.0),
+//!     ),
+//!     evaporator_temperature: ThermodynamicTemperature::new::<degree_celsius>(12.0),
+//!     condenser_temperature: ThermodynamicTemperature::new::<degree_celsius>(32.0),
+//!     half_cycle: Time::new::<second>(300.0),
+//!     time_step: Time::new::<second>(1.0),
+//!     cycles: 5,
+//! })?;
+//!
+//! assert!(output.cooling_capacity.get::<kilowatt>() > 1.0);
+//! assert!(output.cop.value > 0.3 && output.cop.value < 0.8);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    Adsorbent, AdsorptionChiller, AdsorptionChillerError, AdsorptionChillerInput,
+    AdsorptionChillerOutput, AdsorptionChillerParameters, AdsorptionCycle, BedState,
+};
+
+impl Model for AdsorptionChiller {
+    type Input = AdsorptionChillerInput;
+    type Output = AdsorptionChillerOutput;
+    type Error = AdsorptionChillerError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.simulate(input)
+    }
+}
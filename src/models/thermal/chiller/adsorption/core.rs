@@ -0,0 +1,642 @@
+use thiserror::Error;
+use uom::si::{
+    available_energy::joule_per_kilogram,
+    diffusion_coefficient::square_meter_per_second,
+    energy::joule,
+    f64::{
+        DiffusionCoefficient, Energy, Frequency, HeatCapacity, Length, Mass, Power, Ratio,
+        SpecificHeatCapacity, TemperatureInterval, ThermalConductance, ThermodynamicTemperature,
+        Time,
+    },
+    frequency::hertz,
+    heat_capacity::joule_per_kelvin,
+    length::meter,
+    mass::kilogram,
+    power::watt,
+    pressure::pascal,
+    ratio::ratio,
+    specific_heat_capacity::joule_per_kilogram_kelvin,
+    temperature_interval,
+    thermal_conductance::watt_per_kelvin,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    time::second,
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive},
+    hx::StreamInlet,
+    psychrometrics::saturation_pressure,
+    units::SpecificEnthalpy,
+};
+
+/// Specific heat of liquid and adsorbed water, J/(kg·K).
+const WATER_SPECIFIC_HEAT: f64 = 4186.0;
+
+/// Adsorbent and its equilibrium and kinetic constants with water vapor.
+///
+/// The equilibrium uptake follows a Freundlich-type isotherm in the ratio of
+/// saturation pressures, `q* = q₀·(Pₛ(T_v) / Pₛ(T_b))^(1/n)`, where `T_v` is
+/// the saturation temperature of the vapor the bed is open to and `T_b` is
+/// the bed temperature. Uptake approaches equilibrium by the linear driving
+/// force model, `dq/dt = k·(q* − q)`, with the rate constant
+/// `k = 15·D₀·exp(−Eₐ/(R·T_b)) / R_p²`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Adsorbent {
+    /// Uptake at saturation, in kilograms of water per kilogram of dry
+    /// adsorbent.
+    pub max_uptake: Ratio,
+
+    /// Heterogeneity exponent `n` of the isotherm.
+    pub heterogeneity: Ratio,
+
+    /// Heat released per kilogram of water adsorbed.
+    pub isosteric_heat: SpecificEnthalpy,
+
+    /// Pre-exponential surface diffusivity `D₀`.
+    pub diffusivity: DiffusionCoefficient,
+
+    /// Activation energy over the gas constant, `Eₐ/R`.
+    pub activation_temperature: TemperatureInterval,
+
+    /// Mean radius of the adsorbent particles.
+    pub particle_radius: Length,
+
+    /// Specific heat of the dry adsorbent.
+    pub specific_heat: SpecificHeatCapacity,
+}
+
+impl Adsorbent {
+    /// Type RD silica gel with water, using the isotherm of Chihara and
+    /// Suzuki and the kinetics of Sakoda and Suzuki.
+    #[must_use]
+    pub fn silica_gel() -> Self {
+        Self {
+            max_uptake: Ratio::new::<ratio>(0.346),
+            heterogeneity: Ratio::new::<ratio>(1.6),
+            isosteric_heat: SpecificEnthalpy::new::<joule_per_kilogram>(2.51e6),
+            diffusivity: DiffusionCoefficient::new::<square_meter_per_second>(2.54e-4),
+            activation_temperature: TemperatureInterval::new::<temperature_interval::kelvin>(
+                5049.0,
+            ),
+            particle_radius: Length::new::<meter>(0.17e-3),
+            specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(924.0),
+        }
+    }
+
+    /// Returns the equilibrium uptake of a bed at `bed_temperature` open to
+    /// vapor saturated at `vapor_temperature`.
+    #[must_use]
+    pub fn equilibrium_uptake(
+        &self,
+        vapor_temperature: ThermodynamicTemperature,
+        bed_temperature: ThermodynamicTemperature,
+    ) -> Ratio {
+        let pressure_ratio = saturation_pressure(vapor_temperature).get::<pascal>()
+            / saturation_pressure(bed_temperature).get::<pascal>();
+        self.max_uptake * pressure_ratio.powf(1.0 / self.heterogeneity.get::<ratio>())
+    }
+
+    /// Returns the linear driving force rate constant at `bed_temperature`.
+    #[must_use]
+    pub fn rate_constant(&self, bed_temperature: ThermodynamicTemperature) -> Frequency {
+        let arrhenius = (-self
+            .activation_temperature
+            .get::<temperature_interval::kelvin>()
+            / bed_temperature.get::<kelvin>())
+        .exp();
+        let rp = self.particle_radius.get::<meter>();
+        Frequency::new::<hertz>(
+            15.0 * self.diffusivity.get::<square_meter_per_second>() * arrhenius / (rp * rp),
+        )
+    }
+}
+
+/// Beds of an [`AdsorptionChiller`].
+///
+/// The chiller has two identical beds, each a finned heat exchanger packed
+/// with adsorbent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdsorptionChillerParameters {
+    /// Adsorbent packed in the beds.
+    pub adsorbent: Adsorbent,
+
+    /// Dry adsorbent mass in each bed.
+    pub adsorbent_mass: Mass,
+
+    /// Heat capacity of each bed's metal heat exchanger.
+    pub bed_heat_capacity: HeatCapacity,
+
+    /// Conductance between each bed and the water flowing through it.
+    pub bed_ua: ThermalConductance,
+}
+
+/// Errors from [`AdsorptionChiller`] construction and simulation.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum AdsorptionChillerError {
+    /// A chiller parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating input is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+}
+
+/// A two-bed adsorption chiller driven by low-grade heat.
+///
+/// Each half-cycle, one bed is heated by the hot water and desorbs vapor to
+/// the condenser while the other is cooled by the cooling water and adsorbs
+/// vapor from the evaporator, producing the cooling effect. The beds then
+/// swap roles. Each bed is lumped to a single temperature and uptake:
+///
+/// ```text
+/// (M·(c_s + c_w·q) + C_hx)·dT/dt = ε·C·(T_in − T) + M·Q_st·dq/dt
+/// ```
+///
+/// where `ε = 1 − exp(−UA / C)` is the effectiveness of the water passing
+/// through a bed at uniform temperature. Vapor only flows from the
+/// evaporator into a cooled bed and from a heated bed into the condenser,
+/// as through check valves, so a bed just switched is pre-heated or
+/// pre-cooled at constant uptake until its pressure crosses that of the
+/// vessel it opens to.
+///
+/// The evaporator and condenser are held at given saturation temperatures.
+/// The evaporator absorbs the latent heat of the vapor less the sensible
+/// heat of the condensate returning from the condenser, and the condenser
+/// rejects the latent heat of the vapor it receives. The sensible heat of
+/// the vapor between the vessels is neglected, so the cycle energy balance
+/// closes to within about one percent.
+///
+/// Simulation starts with the first bed at the cooling water temperature,
+/// loaded to equilibrium with the evaporator, and the second at the hot
+/// water temperature, dried to equilibrium with the condenser. Outputs are
+/// averaged over the last cycle, which approaches the periodic steady state
+/// after a few cycles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdsorptionChiller {
+    parameters: AdsorptionChillerParameters,
+}
+
+/// Input to the adsorption chiller model.
+#[derive(Debug, Clone, Copy)]
+pub struct AdsorptionChillerInput {
+    /// Hot water driving desorption.
+    pub hot_water: StreamInlet,
+
+    /// Cooling water for the adsorbing bed.
+    pub cooling_water: StreamInlet,
+
+    /// Saturation temperature in the evaporator.
+    pub evaporator_temperature: ThermodynamicTemperature,
+
+    /// Saturation temperature in the condenser.
+    pub condenser_temperature: ThermodynamicTemperature,
+
+    /// Duration of each half-cycle, after which the beds swap roles.
+    pub half_cycle: Time,
+
+    /// Integration step, rounded so a whole number of steps fills each
+    /// half-cycle.
+    pub time_step: Time,
+
+    /// Number of full cycles to simulate.
+    pub cycles: usize,
+}
+
+/// Temperature and uptake of one bed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BedState {
+    /// Bed temperature.
+    pub temperature: ThermodynamicTemperature,
+
+    /// Water held per kilogram of dry adsorbent.
+    pub uptake: Ratio,
+}
+
+/// Energy totals over one full cycle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdsorptionCycle {
+    /// Heat absorbed by the evaporator.
+    pub cooling: Energy,
+
+    /// Heat supplied by the hot water.
+    pub heat_input: Energy,
+
+    /// Heat rejected by the cooled bed and the condenser.
+    pub heat_rejected: Energy,
+
+    /// Cooling over heat input.
+    pub cop: Ratio,
+}
+
+/// Output from the adsorption chiller model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdsorptionChillerOutput {
+    /// Mean evaporator heat absorption over the last cycle.
+    pub cooling_capacity: Power,
+
+    /// Mean heat supplied by the hot water over the last cycle.
+    pub heat_input: Power,
+
+    /// Mean heat rejected over the last cycle.
+    pub heat_rejected: Power,
+
+    /// Coefficient of performance over the last cycle.
+    pub cop: Ratio,
+
+    /// Mean hot water return temperature over the last cycle.
+    pub hot_water_return: ThermodynamicTemperature,
+
+    /// Difference between the highest and lowest uptake of a bed over the
+    /// last cycle.
+    pub uptake_swing: Ratio,
+
+    /// Energy totals for each simulated cycle, in order.
+    pub cycles: Vec<AdsorptionCycle>,
+
+    /// Bed states at the end of the simulation.
+    pub beds: [BedState; 2],
+}
+
+impl AdsorptionChiller {
+    /// Creates an adsorption chiller from its parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AdsorptionChillerError::InvalidParameter`] if an adsorbent
+    /// constant, the adsorbent mass, or the bed UA is not strictly positive,
+    /// or the bed heat capacity is negative.
+    pub fn new(parameters: AdsorptionChillerParameters) -> Result<Self, AdsorptionChillerError> {
+        let AdsorptionChillerParameters {
+            adsorbent: a,
+            adsorbent_mass,
+            bed_heat_capacity,
+            bed_ua,
+        } = parameters;
+
+        let positive = |v: f64| v > 0.0 && v.is_finite();
+        if !(positive(a.max_uptake.get::<ratio>())
+            && positive(a.heterogeneity.get::<ratio>())
+            && positive(a.isosteric_heat.get::<joule_per_kilogram>())
+            && positive(a.diffusivity.get::<square_meter_per_second>())
+            && positive(a.particle_radius.get::<meter>())
+            && positive(a.specific_heat.get::<joule_per_kilogram_kelvin>()))
+        {
+            return Err(AdsorptionChillerError::InvalidParameter(
+                "adsorbent constants must be strictly positive and finite",
+            ));
+        }
+        if NonNegative::check(&a.activation_temperature).is_err()
+            || !a.activation_temperature.is_finite()
+        {
+            return Err(AdsorptionChillerError::InvalidParameter(
+                "activation temperature must be non-negative and finite",
+            ));
+        }
+        if StrictlyPositive::check(&adsorbent_mass).is_err() || !adsorbent_mass.is_finite() {
+            return Err(AdsorptionChillerError::InvalidParameter(
+                "adsorbent mass must be strictly positive and finite",
+            ));
+        }
+        if NonNegative::check(&bed_heat_capacity).is_err() || !bed_heat_capacity.is_finite() {
+            return Err(AdsorptionChillerError::InvalidParameter(
+                "bed heat capacity must be non-negative and finite",
+            ));
+        }
+        if StrictlyPositive::check(&bed_ua).is_err() || !bed_ua.is_finite() {
+            return Err(AdsorptionChillerError::InvalidParameter(
+                "bed UA must be strictly positive and finite",
+            ));
+        }
+
+        Ok(Self { parameters })
+    }
+
+    /// Returns the chiller parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &AdsorptionChillerParameters {
+        &self.parameters
+    }
+
+    /// Simulates the chiller over the given number of cycles.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AdsorptionChillerError::InvalidInput`] if the time step or
+    /// half-cycle is not strictly positive, the half-cycle is shorter than
+    /// the time step, no cycles are requested, or the temperatures do not
+    /// satisfy `0 °C < evaporator < condenser` and
+    /// `cooling water < hot water ≤ 200 °C`.
+    pub fn simulate(
+        &self,
+        input: &AdsorptionChillerInput,
+    ) -> Result<AdsorptionChillerOutput, AdsorptionChillerError> {
+        let AdsorptionChillerInput {
+            hot_water,
+            cooling_water,
+            evaporator_temperature,
+            condenser_temperature,
+            half_cycle,
+            time_step,
+            cycles,
+        } = *input;
+
+        check_input(input)?;
+        let t_e = evaporator_temperature.get::<degree_celsius>();
+        let t_c = condenser_temperature.get::<degree_celsius>();
+
+        let adsorbent = self.parameters.adsorbent;
+        let m = self.parameters.adsorbent_mass.get::<kilogram>();
+        let c_s = adsorbent.specific_heat.get::<joule_per_kilogram_kelvin>();
+        let c_hx = self.parameters.bed_heat_capacity.get::<joule_per_kelvin>();
+        let q_st = adsorbent.isosteric_heat.get::<joule_per_kilogram>();
+        let ua = self.parameters.bed_ua.get::<watt_per_kelvin>();
+
+        let (hot_conductance, hot_inlet) = water_side(hot_water, ua);
+        let (cool_conductance, cool_inlet) = water_side(cooling_water, ua);
+
+        // Vapor leaves the evaporator and returns as condensate.
+        let cooling_per_kg = latent_heat(t_e) - WATER_SPECIFIC_HEAT * (t_c - t_e);
+        let condensing_per_kg = latent_heat(t_c);
+
+        let steps = (half_cycle / time_step).get::<ratio>().round().max(1.0);
+        let dt = half_cycle.get::<second>() / steps;
+        // Bounded by the check that the half-cycle is at least one step.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let steps = steps as usize;
+
+        let mut beds = initial_beds(&adsorbent, input);
+        let mut summaries = Vec::with_capacity(cycles);
+        let mut uptake_range = (f64::INFINITY, f64::NEG_INFINITY);
+
+        for cycle in 0..cycles {
+            let mut cooling = 0.0;
+            let mut heat_input = 0.0;
+            let mut heat_rejected = 0.0;
+
+            for half in 0..2 {
+                // The first bed desorbs in the first half of each cycle.
+                let desorbing = half;
+                for _ in 0..steps {
+                    for (i, bed) in beds.iter_mut().enumerate() {
+                        let t = bed.temperature.get::<kelvin>();
+                        let q = bed.uptake.get::<ratio>();
+                        let heating = i == desorbing;
+                        let (vapor, conductance, inlet) = if heating {
+                            (condenser_temperature, hot_conductance, hot_inlet)
+                        } else {
+                            (evaporator_temperature, cool_conductance, cool_inlet)
+                        };
+
+                        let driving = adsorbent
+                            .equilibrium_uptake(vapor, bed.temperature)
+                            .get::<ratio>()
+                            - q;
+                        let rate = adsorbent.rate_constant(bed.temperature).get::<hertz>()
+                            * if heating {
+                                driving.min(0.0)
+                            } else {
+                                driving.max(0.0)
+                            };
+                        let water_heat = conductance * (inlet - t);
+                        let thermal_mass = m * (c_s + WATER_SPECIFIC_HEAT * q) + c_hx;
+                        let dt_dt = (water_heat + m * q_st * rate) / thermal_mass;
+
+                        if heating {
+                            heat_input += water_heat * dt;
+                            heat_rejected -= m * rate * condensing_per_kg * dt;
+                        } else {
+                            heat_rejected -= water_heat * dt;
+                            cooling += m * rate * cooling_per_kg * dt;
+                        }
+
+                        bed.temperature = ThermodynamicTemperature::new::<kelvin>(t + dt_dt * dt);
+                        bed.uptake = Ratio::new::<ratio>(q + rate * dt);
+                    }
+
+                    if cycle + 1 == cycles {
+                        let q = beds[0].uptake.get::<ratio>();
+                        uptake_range = (uptake_range.0.min(q), uptake_range.1.max(q));
+                    }
+                }
+            }
+
+            summaries.push(AdsorptionCycle {
+                cooling: Energy::new::<joule>(cooling),
+                heat_input: Energy::new::<joule>(heat_input),
+                heat_rejected: Energy::new::<joule>(heat_rejected),
+                cop: Ratio::new::<ratio>(cooling / heat_input),
+            });
+        }
+
+        let last = summaries[cycles - 1];
+        let period = 2.0 * half_cycle;
+        let heat_input = last.heat_input / period;
+        Ok(AdsorptionChillerOutput {
+            cooling_capacity: last.cooling / period,
+            heat_input,
+            heat_rejected: last.heat_rejected / period,
+            cop: last.cop,
+            hot_water_return: ThermodynamicTemperature::new::<kelvin>(
+                hot_inlet
+                    - heat_input.get::<watt>()
+                        / hot_water.capacitance_rate.get::<watt_per_kelvin>(),
+            ),
+            uptake_swing: Ratio::new::<ratio>(uptake_range.1 - uptake_range.0),
+            cycles: summaries,
+            beds,
+        })
+    }
+}
+
+fn check_input(input: &AdsorptionChillerInput) -> Result<(), AdsorptionChillerError> {
+    if StrictlyPositive::check(&input.time_step).is_err() || !input.time_step.is_finite() {
+        return Err(AdsorptionChillerError::InvalidInput(
+            "time step must be strictly positive and finite",
+        ));
+    }
+    if !input.half_cycle.is_finite() || input.half_cycle < input.time_step {
+        return Err(AdsorptionChillerError::InvalidInput(
+            "half-cycle must be finite and at least one time step",
+        ));
+    }
+    if input.cycles == 0 {
+        return Err(AdsorptionChillerError::InvalidInput(
+            "at least one cycle must be simulated",
+        ));
+    }
+    let t_e = input.evaporator_temperature.get::<degree_celsius>();
+    let t_c = input.condenser_temperature.get::<degree_celsius>();
+    if !(t_e > 0.0 && t_c > t_e && t_c.is_finite()) {
+        return Err(AdsorptionChillerError::InvalidInput(
+            "temperatures must satisfy 0 °C < evaporator < condenser",
+        ));
+    }
+    let t_hot = input.hot_water.temperature.get::<degree_celsius>();
+    let t_cool = input.cooling_water.temperature.get::<degree_celsius>();
+    if !(t_cool > 0.0 && t_hot > t_cool && t_hot <= 200.0) {
+        return Err(AdsorptionChillerError::InvalidInput(
+            "water temperatures must satisfy 0 °C < cooling < hot ≤ 200 °C",
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the conductance, W/K, and inlet temperature, K, of a water stream
+/// passing a bed at uniform temperature.
+fn water_side(stream: StreamInlet, ua: f64) -> (f64, f64) {
+    let c = stream.capacitance_rate.get::<watt_per_kelvin>();
+    (
+        c * (1.0 - (-ua / c).exp()),
+        stream.temperature.get::<kelvin>(),
+    )
+}
+
+/// Returns the beds at equilibrium with the cooling water and the hot water.
+fn initial_beds(adsorbent: &Adsorbent, input: &AdsorptionChillerInput) -> [BedState; 2] {
+    let AdsorptionChillerInput {
+        hot_water,
+        cooling_water,
+        evaporator_temperature,
+        condenser_temperature,
+        ..
+    } = *input;
+    [
+        BedState {
+            temperature: cooling_water.temperature,
+            uptake: adsorbent.equilibrium_uptake(evaporator_temperature, cooling_water.temperature),
+        },
+        BedState {
+            temperature: hot_water.temperature,
+            uptake: adsorbent.equilibrium_uptake(condenser_temperature, hot_water.temperature),
+        },
+    ]
+}
+
+/// Latent heat of vaporization of water at `t_celsius`, J/kg.
+fn latent_heat(t_celsius: f64) -> f64 {
+    2.501e6 - 2370.0 * t_celsius
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{heat_capacity::kilojoule_per_kelvin, power::kilowatt};
+
+    use crate::support::{hx::CapacitanceRate, units::test_utils::celsius};
+
+    fn chiller() -> AdsorptionChiller {
+        AdsorptionChiller::new(AdsorptionChillerParameters {
+            adsorbent: Adsorbent::silica_gel(),
+            adsorbent_mass: Mass::new::<kilogram>(20.0),
+            bed_heat_capacity: HeatCapacity::new::<kilojoule_per_kelvin>(20.0),
+            bed_ua: ThermalConductance::new::<watt_per_kelvin>(1500.0),
+        })
+        .unwrap()
+    }
+
+    fn input(hot: f64) -> AdsorptionChillerInput {
+        AdsorptionChillerInput {
+            hot_water: StreamInlet::new(
+                CapacitanceRate::new::<watt_per_kelvin>(1250.0).unwrap(),
+                celsius(hot),
+            ),
+            cooling_water: StreamInlet::new(
+                CapacitanceRate::new::<watt_per_kelvin>(2100.0).unwrap(),
+                celsius(30.0),
+            ),
+            evaporator_temperature: celsius(12.0),
+            condenser_temperature: celsius(32.0),
+            half_cycle: Time::new::<second>(300.0),
+            time_step: Time::new::<second>(1.0),
+            cycles: 6,
+        }
+    }
+
+    #[test]
+    fn silica_gel_isotherm_and_kinetics() {
+        let gel = Adsorbent::silica_gel();
+
+        // A bed at its vapor's saturation temperature holds the full uptake.
+        assert_relative_eq!(
+            gel.equilibrium_uptake(celsius(30.0), celsius(30.0))
+                .get::<ratio>(),
+            0.346,
+            epsilon = 1e-12,
+        );
+        let loaded = gel.equilibrium_uptake(celsius(12.0), celsius(30.0));
+        let dried = gel.equilibrium_uptake(celsius(32.0), celsius(80.0));
+        assert!(dried < loaded);
+        assert!(gel.rate_constant(celsius(80.0)) > 10.0 * gel.rate_constant(celsius(30.0)));
+    }
+
+    #[test]
+    fn settles_to_a_periodic_cycle() {
+        let output = chiller().simulate(&input(85.0)).unwrap();
+        let [.., previous, last] = output.cycles[..] else {
+            panic!("at least two cycles");
+        };
+
+        assert_relative_eq!(
+            last.cop.get::<ratio>(),
+            previous.cop.get::<ratio>(),
+            max_relative = 1e-3,
+        );
+        assert!(output.cop.get::<ratio>() > 0.4 && output.cop.get::<ratio>() < 0.6);
+        assert!(output.cooling_capacity.get::<kilowatt>() > 5.0);
+        assert!(output.uptake_swing.get::<ratio>() > 0.02);
+        assert!(output.hot_water_return < celsius(85.0));
+    }
+
+    #[test]
+    fn cycle_energy_balance_closes() {
+        let output = chiller().simulate(&input(85.0)).unwrap();
+
+        // Only the vapor's sensible heat between the vessels is unaccounted.
+        assert_relative_eq!(
+            (output.heat_input + output.cooling_capacity).get::<kilowatt>(),
+            output.heat_rejected.get::<kilowatt>(),
+            max_relative = 0.02,
+        );
+    }
+
+    #[test]
+    fn hotter_driving_water_raises_capacity() {
+        let warm = chiller().simulate(&input(70.0)).unwrap();
+        let hot = chiller().simulate(&input(85.0)).unwrap();
+
+        assert!(hot.cooling_capacity > warm.cooling_capacity);
+        assert!(hot.uptake_swing > warm.uptake_swing);
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_inputs() {
+        let mut parameters = *chiller().parameters();
+        parameters.bed_ua = ThermalConductance::new::<watt_per_kelvin>(0.0);
+        assert!(matches!(
+            AdsorptionChiller::new(parameters),
+            Err(AdsorptionChillerError::InvalidParameter(_))
+        ));
+
+        let chiller = chiller();
+        let mut swapped = input(85.0);
+        swapped.evaporator_temperature = celsius(35.0);
+        let mut cold_drive = input(85.0);
+        cold_drive.hot_water.temperature = celsius(25.0);
+        let mut short = input(85.0);
+        short.half_cycle = Time::new::<second>(0.5);
+        let mut none = input(85.0);
+        none.cycles = 0;
+
+        for bad in [swapped, cold_drive, short, none] {
+            assert!(matches!(
+                chiller.simulate(&bad),
+                Err(AdsorptionChillerError::InvalidInput(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,3 @@
+//! District heating components.
+
+pub mod substation;
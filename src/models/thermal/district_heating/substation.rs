@@ -0,0 +1,74 @@
+//! District heating substation model.
+//!
+//! A [`Substation`] couples a district heating network (primary side) to a
+//! building circuit (secondary side) through a counterflow plate heat
+//! exchanger. A primary control valve modulates network flow to hold the
+//! secondary supply temperature at its setpoint, limited by the flow the
+//! valve can pass at the available differential pressure.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::models::thermal::district_heating::substation::{
+//!     Fluid, Substation, SubstationConfig, SubstationInput, SubstationParameters,
+//! };
+//! use uom::si::{
+//!     f64::{MassDensity, MassRate, Pressure, Ratio, SpecificHeatCapacity,
+//!           ThermalConductance, ThermodynamicTemperature, VolumeRate},
+//!     mass_density::kilogram_per_cubic_meter,
+//!     mass_rate::kilogram_per_second,
+//!     pressure::kilopascal,
+//!     ratio::ratio,
+//!     specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+//!     thermal_conductance::kilowatt_per_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//!     volume_rate::cubic_meter_per_hour,
+//! };
+//!
+//! let water = Fluid {
+//!     density: MassDensity::new::<kilogram_per_cubic_meter>(980.0),
+//!     specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(4.19),
+//! };
+//!
+//! let substation = Substation::new(
+//!     SubstationParameters {
+//!         ua: ThermalConductance::new::<kilowatt_per_kelvin>(8.0),
+//!         primary: water,
+//!         secondary: water,
+//!         valve_kv: VolumeRate::new::<cubic_meter_per_hour>(4.0),
+//!         pump_pressure_rise: Pressure::new::<kilopascal>(40.0),
+//!         pump_efficiency: Ratio::new::<ratio>(0.4),
+//!     },
+//!     SubstationConfig::default(),
+//! ).expect("valid parameters");
+//!
+//! let output = substation.evaluate(&SubstationInput {
+//!     primary_supply_temperature: ThermodynamicTemperature::new::<degree_celsius>(85.0),
+//!     primary_differential_pressure: Pressure::new::<kilopascal>(80.0),
+//!     secondary_return_temperature: ThermodynamicTemperature::new::<degree_celsius>(45.0),
+//!     secondary_mass_flow: MassRate::new::<kilogram_per_second>(0.6),
+//!     secondary_supply_setpoint: ThermodynamicTemperature::new::<degree_celsius>(65.0),
+//! }).unwrap();
+//!
+//! assert!(output.setpoint_met);
+//! assert!(output.primary_return_temperature < ThermodynamicTemperature::new::<degree_celsius>(85.0));
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    Fluid, Substation, SubstationConfig, SubstationError, SubstationInput, SubstationOutput,
+    SubstationParameters,
+};
+
+impl Model for Substation {
+    type Input = SubstationInput;
+    type Output = SubstationOutput;
+    type Error = SubstationError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
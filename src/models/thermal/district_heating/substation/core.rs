@@ -0,0 +1,539 @@
+mod problem;
+
+use thiserror::Error;
+use twine_core::Model;
+use twine_solvers::equation::{
+    bisection,
+    bracket::{Bracket, Sign},
+};
+use uom::{
+    ConstZero,
+    si::{
+        f64::{
+            MassDensity, MassRate, Power, Pressure, Ratio, SpecificHeatCapacity,
+            TemperatureInterval, ThermalConductance, ThermodynamicTemperature, VolumeRate,
+        },
+        mass_rate::kilogram_per_second,
+        pressure::bar,
+        ratio::ratio,
+        temperature_interval::kelvin as delta_kelvin,
+    },
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive, UnitIntervalLowerOpen},
+    hx::{CapacitanceRate, StreamInlet, functional::KnownConductanceResult},
+    units::display::pretty,
+};
+
+use problem::{PlateHxModel, SetpointProblem};
+
+/// Constant properties for one side of the substation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fluid {
+    /// Mass density, used for valve and pump volumetric flows.
+    pub density: MassDensity,
+
+    /// Specific heat capacity at constant pressure.
+    pub specific_heat: SpecificHeatCapacity,
+}
+
+/// Fixed design parameters of a [`Substation`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SubstationParameters {
+    /// Overall thermal conductance of the counterflow plate heat exchanger.
+    pub ua: ThermalConductance,
+
+    /// Primary (network) side fluid properties.
+    pub primary: Fluid,
+
+    /// Secondary (building) side fluid properties.
+    pub secondary: Fluid,
+
+    /// Flow coefficient of the fully open primary control valve.
+    ///
+    /// This is the volumetric flow through the valve at a 1 bar pressure
+    /// drop (the conventional `Kv`, usually quoted in m³/h).
+    pub valve_kv: VolumeRate,
+
+    /// Pressure rise delivered by the secondary circulation pump.
+    pub pump_pressure_rise: Pressure,
+
+    /// Overall (wire-to-water) efficiency of the secondary pump.
+    pub pump_efficiency: Ratio,
+}
+
+/// Solver configuration for [`Substation`].
+#[derive(Debug, Clone, Copy)]
+pub struct SubstationConfig {
+    /// Absolute tolerance on the secondary supply temperature.
+    pub temp_abs_tol: TemperatureInterval,
+
+    /// Maximum number of solver iterations.
+    pub max_iters: usize,
+}
+
+impl Default for SubstationConfig {
+    fn default() -> Self {
+        Self {
+            temp_abs_tol: TemperatureInterval::new::<delta_kelvin>(1e-9),
+            max_iters: 100,
+        }
+    }
+}
+
+/// Errors from [`Substation`] construction and evaluation.
+#[derive(Debug, Error)]
+pub enum SubstationError {
+    /// A design parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// The secondary mass flow is not strictly positive.
+    #[error("secondary mass flow must be strictly positive, got {0:?}")]
+    NonPositiveSecondaryFlow(MassRate),
+
+    /// The available primary differential pressure is negative.
+    #[error("primary differential pressure must be non-negative, got {}", pretty(*.0))]
+    NegativeDifferentialPressure(Pressure),
+
+    /// A heat exchanger quantity violated its constraints.
+    #[error("heat exchanger evaluation failed: {0}")]
+    HeatExchanger(String),
+
+    /// The solver failed to converge.
+    #[error("substation solver failed to converge: {message}")]
+    Convergence {
+        /// Details about the convergence failure.
+        message: String,
+
+        /// Iteration count when available.
+        iterations: Option<usize>,
+    },
+}
+
+/// A district heating substation.
+///
+/// Combines a counterflow plate heat exchanger, a primary control valve, and
+/// a secondary circulation pump. The valve modulates primary flow so the
+/// secondary supply reaches its setpoint, limited by the flow the valve can
+/// pass at the available network differential pressure.
+#[derive(Debug, Clone, Copy)]
+pub struct Substation {
+    parameters: SubstationParameters,
+    config: SubstationConfig,
+}
+
+/// Input to the substation model.
+#[derive(Debug, Clone, Copy)]
+pub struct SubstationInput {
+    /// Network supply temperature entering the primary side.
+    pub primary_supply_temperature: ThermodynamicTemperature,
+
+    /// Differential pressure available across the primary control valve.
+    pub primary_differential_pressure: Pressure,
+
+    /// Building return temperature entering the secondary side.
+    pub secondary_return_temperature: ThermodynamicTemperature,
+
+    /// Secondary circulation mass flow (set by the pump).
+    pub secondary_mass_flow: MassRate,
+
+    /// Target secondary supply temperature.
+    pub secondary_supply_setpoint: ThermodynamicTemperature,
+}
+
+/// Output from the substation model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SubstationOutput {
+    /// Achieved secondary supply temperature.
+    pub secondary_supply_temperature: ThermodynamicTemperature,
+
+    /// Primary return temperature leaving the heat exchanger.
+    pub primary_return_temperature: ThermodynamicTemperature,
+
+    /// Primary mass flow through the control valve.
+    pub primary_mass_flow: MassRate,
+
+    /// Valve opening as a fraction of its maximum flow (linear characteristic).
+    pub valve_opening: Ratio,
+
+    /// Heat delivered from the primary to the secondary side.
+    pub heat_rate: Power,
+
+    /// Electrical power drawn by the secondary pump.
+    pub pump_power: Power,
+
+    /// Whether the setpoint was met.
+    ///
+    /// `false` means the secondary supply falls short of the setpoint: either
+    /// the valve is fully open, or the network is no warmer than the
+    /// secondary return and the valve is closed.
+    pub setpoint_met: bool,
+
+    /// Number of solver iterations.
+    pub iterations: usize,
+}
+
+impl Substation {
+    /// Creates a substation from its design parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SubstationError::InvalidParameter`] if a property, the valve
+    /// coefficient, or the pump efficiency is non-physical.
+    pub fn new(
+        parameters: SubstationParameters,
+        config: SubstationConfig,
+    ) -> Result<Self, SubstationError> {
+        let SubstationParameters {
+            ua,
+            primary,
+            secondary,
+            valve_kv,
+            pump_pressure_rise,
+            pump_efficiency,
+        } = parameters;
+
+        if NonNegative::check(&ua).is_err() {
+            return Err(SubstationError::InvalidParameter("UA must be non-negative"));
+        }
+        if !fluid_is_valid(primary) {
+            return Err(SubstationError::InvalidParameter(
+                "primary fluid properties must be strictly positive",
+            ));
+        }
+        if !fluid_is_valid(secondary) {
+            return Err(SubstationError::InvalidParameter(
+                "secondary fluid properties must be strictly positive",
+            ));
+        }
+        if StrictlyPositive::check(&valve_kv).is_err() {
+            return Err(SubstationError::InvalidParameter(
+                "valve Kv must be strictly positive",
+            ));
+        }
+        if NonNegative::check(&pump_pressure_rise).is_err() {
+            return Err(SubstationError::InvalidParameter(
+                "pump pressure rise must be non-negative",
+            ));
+        }
+        if UnitIntervalLowerOpen::check(&pump_efficiency).is_err() {
+            return Err(SubstationError::InvalidParameter(
+                "pump efficiency must be in (0, 1]",
+            ));
+        }
+
+        Ok(Self { parameters, config })
+    }
+
+    /// Maximum primary mass flow the valve passes at `dp`.
+    ///
+    /// Uses the `Kv` definition `V̇ = Kv·√(ΔP / 1 bar)`.
+    fn max_primary_flow(&self, dp: Pressure) -> MassRate {
+        let scale = (dp / Pressure::new::<bar>(1.0)).get::<ratio>().sqrt();
+        self.parameters.valve_kv * scale * self.parameters.primary.density
+    }
+
+    /// Evaluates the substation at a single operating point.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SubstationError`] if the inputs are invalid or the
+    /// setpoint search fails to converge.
+    pub fn evaluate(&self, input: &SubstationInput) -> Result<SubstationOutput, SubstationError> {
+        let SubstationInput {
+            primary_supply_temperature,
+            primary_differential_pressure,
+            secondary_return_temperature,
+            secondary_mass_flow,
+            secondary_supply_setpoint,
+        } = *input;
+
+        if StrictlyPositive::check(&secondary_mass_flow).is_err() {
+            return Err(SubstationError::NonPositiveSecondaryFlow(
+                secondary_mass_flow,
+            ));
+        }
+        if NonNegative::check(&primary_differential_pressure).is_err() {
+            return Err(SubstationError::NegativeDifferentialPressure(
+                primary_differential_pressure,
+            ));
+        }
+
+        let secondary_inlet = StreamInlet::new(
+            CapacitanceRate::from_mass_rate_and_specific_heat(
+                secondary_mass_flow,
+                self.parameters.secondary.specific_heat,
+            )
+            .map_err(|e| SubstationError::HeatExchanger(e.to_string()))?,
+            secondary_return_temperature,
+        );
+
+        let model = PlateHxModel {
+            ua: self.parameters.ua,
+            primary_cp: self.parameters.primary.specific_heat,
+            primary_supply_temperature,
+            secondary_inlet,
+        };
+
+        let m_max = self.max_primary_flow(primary_differential_pressure);
+        let no_heat_needed = secondary_supply_setpoint <= secondary_return_temperature;
+
+        // Network water no warmer than the secondary return can only take
+        // heat out of the building loop, so the valve stays shut.
+        let network_too_cold = primary_supply_temperature <= secondary_return_temperature;
+
+        if no_heat_needed || network_too_cold || m_max == MassRate::ZERO {
+            return Ok(self.closed_valve_output(input, no_heat_needed));
+        }
+
+        // At the fully open valve the exchanger delivers its maximum heat.
+        let full_open = model
+            .call(&m_max)
+            .map_err(|e| SubstationError::HeatExchanger(e.to_string()))?;
+        let t_out_max = full_open.streams[1].outlet_temperature;
+        if t_out_max <= secondary_supply_setpoint + self.config.temp_abs_tol {
+            let setpoint_met = t_out_max >= secondary_supply_setpoint - self.config.temp_abs_tol;
+            return Ok(self.resolved_output(input, m_max, m_max, &full_open, setpoint_met, 0));
+        }
+
+        // The bracket is known from physics without evaluation:
+        // - At zero primary flow there is no heat transfer, so the secondary
+        //   leaves at its return temperature, below the setpoint.
+        // - At the fully open valve the secondary overshoots (checked above).
+        let bracket = Bracket::new(
+            (0.0, Sign::Negative),
+            (m_max.get::<kilogram_per_second>(), Sign::Positive),
+        )
+        .map_err(|error| SubstationError::Convergence {
+            message: error.to_string(),
+            iterations: None,
+        })?;
+
+        let problem = SetpointProblem {
+            setpoint: secondary_supply_setpoint,
+        };
+        let config = bisection::Config {
+            max_iters: self.config.max_iters,
+            x_abs_tol: 0.0,
+            x_rel_tol: 0.0,
+            residual_tol: self.config.temp_abs_tol.get::<delta_kelvin>(),
+        };
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "substation",
+            setpoint =
+                secondary_supply_setpoint.get::<uom::si::thermodynamic_temperature::kelvin>(),
+        )
+        .entered();
+
+        let solution = bisection::solve_from_bracket(
+            &model,
+            &problem,
+            bracket,
+            &config,
+            |#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+             event: &bisection::Event<'_, _, _>| {
+                #[cfg(feature = "tracing")]
+                crate::support::numerics::solve::trace_bisection(event);
+                None
+            },
+        )
+        .map_err(|error| SubstationError::Convergence {
+            message: error.to_string(),
+            iterations: None,
+        })?;
+
+        if solution.status != bisection::Status::Converged {
+            return Err(SubstationError::Convergence {
+                message: "iteration limit reached".to_owned(),
+                iterations: Some(solution.iters),
+            });
+        }
+
+        Ok(self.resolved_output(
+            input,
+            solution.snapshot.input,
+            m_max,
+            &solution.snapshot.output,
+            true,
+            solution.iters,
+        ))
+    }
+
+    fn pump_power(&self, secondary_mass_flow: MassRate) -> Power {
+        let volume_rate: VolumeRate = secondary_mass_flow / self.parameters.secondary.density;
+        volume_rate * self.parameters.pump_pressure_rise / self.parameters.pump_efficiency
+    }
+
+    fn closed_valve_output(&self, input: &SubstationInput, setpoint_met: bool) -> SubstationOutput {
+        SubstationOutput {
+            secondary_supply_temperature: input.secondary_return_temperature,
+            primary_return_temperature: input.primary_supply_temperature,
+            primary_mass_flow: MassRate::ZERO,
+            valve_opening: Ratio::ZERO,
+            heat_rate: Power::ZERO,
+            pump_power: self.pump_power(input.secondary_mass_flow),
+            setpoint_met,
+            iterations: 0,
+        }
+    }
+
+    fn resolved_output(
+        &self,
+        input: &SubstationInput,
+        primary_mass_flow: MassRate,
+        max_primary_flow: MassRate,
+        result: &KnownConductanceResult,
+        setpoint_met: bool,
+        iterations: usize,
+    ) -> SubstationOutput {
+        let [primary, secondary] = result.streams;
+        SubstationOutput {
+            secondary_supply_temperature: secondary.outlet_temperature,
+            primary_return_temperature: primary.outlet_temperature,
+            primary_mass_flow,
+            valve_opening: primary_mass_flow / max_primary_flow,
+            heat_rate: secondary.heat_flow.signed(),
+            pump_power: self.pump_power(input.secondary_mass_flow),
+            setpoint_met,
+            iterations,
+        }
+    }
+}
+
+fn fluid_is_valid(fluid: Fluid) -> bool {
+    StrictlyPositive::check(&fluid.density).is_ok()
+        && StrictlyPositive::check(&fluid.specific_heat).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        mass_density::kilogram_per_cubic_meter, power::watt, pressure::kilopascal,
+        specific_heat_capacity::joule_per_kilogram_kelvin,
+        thermal_conductance::kilowatt_per_kelvin, thermodynamic_temperature::degree_celsius,
+        volume_rate::cubic_meter_per_hour,
+    };
+
+    use crate::support::units::{TemperatureDifference, test_utils::celsius};
+
+    fn water() -> Fluid {
+        Fluid {
+            density: MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+            specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(4000.0),
+        }
+    }
+
+    fn substation() -> Substation {
+        Substation::new(
+            SubstationParameters {
+                ua: ThermalConductance::new::<kilowatt_per_kelvin>(5.0),
+                primary: water(),
+                secondary: water(),
+                valve_kv: VolumeRate::new::<cubic_meter_per_hour>(3.6),
+                pump_pressure_rise: Pressure::new::<kilopascal>(50.0),
+                pump_efficiency: Ratio::new::<ratio>(0.5),
+            },
+            SubstationConfig::default(),
+        )
+        .unwrap()
+    }
+
+    fn input(dp_kpa: f64, setpoint: f64) -> SubstationInput {
+        SubstationInput {
+            primary_supply_temperature: celsius(80.0),
+            primary_differential_pressure: Pressure::new::<kilopascal>(dp_kpa),
+            secondary_return_temperature: celsius(40.0),
+            secondary_mass_flow: MassRate::new::<kilogram_per_second>(0.5),
+            secondary_supply_setpoint: celsius(setpoint),
+        }
+    }
+
+    #[test]
+    fn meets_setpoint_and_balances_energy() {
+        let out = substation().evaluate(&input(100.0, 60.0)).unwrap();
+
+        assert!(out.setpoint_met);
+        assert_relative_eq!(
+            out.secondary_supply_temperature.get::<degree_celsius>(),
+            60.0,
+            epsilon = 1e-6
+        );
+
+        // Secondary: Q = 0.5 kg/s * 4000 J/(kg·K) * 20 K = 40 kW.
+        assert_relative_eq!(out.heat_rate.get::<watt>(), 40_000.0, epsilon = 1e-2);
+
+        // Primary: same heat leaves the network water.
+        let primary_drop = celsius(80.0).minus(out.primary_return_temperature);
+        let q_primary = out.primary_mass_flow.get::<kilogram_per_second>()
+            * 4000.0
+            * primary_drop.get::<delta_kelvin>();
+        assert_relative_eq!(q_primary, 40_000.0, epsilon = 1e-2);
+
+        assert!(out.valve_opening.get::<ratio>() > 0.0);
+        assert!(out.valve_opening.get::<ratio>() < 1.0);
+    }
+
+    #[test]
+    fn valve_limited_when_differential_pressure_is_low() {
+        // Kv = 3.6 m³/h at 1 kPa passes 0.1 kg/s, not enough for 75 °C.
+        let out = substation().evaluate(&input(1.0, 75.0)).unwrap();
+
+        assert!(!out.setpoint_met);
+        assert_relative_eq!(out.valve_opening.get::<ratio>(), 1.0);
+        assert_relative_eq!(
+            out.primary_mass_flow.get::<kilogram_per_second>(),
+            0.1,
+            epsilon = 1e-12
+        );
+        assert!(out.secondary_supply_temperature < celsius(75.0));
+    }
+
+    #[test]
+    fn closes_valve_when_no_heat_is_needed() {
+        let out = substation().evaluate(&input(100.0, 35.0)).unwrap();
+
+        assert!(out.setpoint_met);
+        assert_eq!(out.primary_mass_flow, MassRate::ZERO);
+        assert_eq!(out.heat_rate, Power::ZERO);
+        assert_eq!(out.secondary_supply_temperature, celsius(40.0));
+
+        // Pump power = V̇ * Δp / η = 0.0005 m³/s * 50 kPa / 0.5 = 50 W.
+        assert_relative_eq!(out.pump_power.get::<watt>(), 50.0);
+    }
+
+    #[test]
+    fn closes_valve_when_network_is_colder_than_return() {
+        let mut cold_network = input(100.0, 60.0);
+        cold_network.primary_supply_temperature = celsius(35.0);
+
+        let out = substation().evaluate(&cold_network).unwrap();
+
+        assert!(!out.setpoint_met);
+        assert_eq!(out.valve_opening, Ratio::ZERO);
+        assert_eq!(out.primary_mass_flow, MassRate::ZERO);
+        assert_eq!(out.heat_rate, Power::ZERO);
+        assert_eq!(out.secondary_supply_temperature, celsius(40.0));
+    }
+
+    #[test]
+    fn rejects_invalid_inputs() {
+        let mut bad_flow = input(100.0, 60.0);
+        bad_flow.secondary_mass_flow = MassRate::ZERO;
+        assert!(matches!(
+            substation().evaluate(&bad_flow),
+            Err(SubstationError::NonPositiveSecondaryFlow(_))
+        ));
+
+        assert!(matches!(
+            substation().evaluate(&input(-1.0, 60.0)),
+            Err(SubstationError::NegativeDifferentialPressure(_))
+        ));
+    }
+}
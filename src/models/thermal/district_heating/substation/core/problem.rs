@@ -0,0 +1,71 @@
+//! Problem formulation for matching the secondary supply setpoint.
+
+use std::convert::Infallible;
+
+use twine_core::{EquationProblem, Model};
+use uom::si::{
+    f64::{MassRate, SpecificHeatCapacity, ThermalConductance, ThermodynamicTemperature},
+    mass_rate::kilogram_per_second,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::support::{
+    constraint::ConstraintError,
+    hx::{
+        CapacitanceRate, StreamInlet,
+        arrangement::CounterFlow,
+        functional::{self, KnownConductanceResult},
+    },
+};
+
+/// Plate heat exchanger evaluated at a candidate primary mass flow.
+///
+/// The secondary inlet is fixed; only the primary flow varies.
+pub(super) struct PlateHxModel {
+    pub(super) ua: ThermalConductance,
+    pub(super) primary_cp: SpecificHeatCapacity,
+    pub(super) primary_supply_temperature: ThermodynamicTemperature,
+    pub(super) secondary_inlet: StreamInlet,
+}
+
+impl Model for PlateHxModel {
+    type Input = MassRate;
+    type Output = KnownConductanceResult;
+    type Error = ConstraintError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        let primary_inlet = StreamInlet::new(
+            CapacitanceRate::from_mass_rate_and_specific_heat(*input, self.primary_cp)?,
+            self.primary_supply_temperature,
+        );
+        functional::known_conductance_and_inlets(
+            &CounterFlow,
+            self.ua,
+            [primary_inlet, self.secondary_inlet],
+        )
+    }
+}
+
+/// Residual is `secondary_outlet - setpoint` in kelvin.
+pub(super) struct SetpointProblem {
+    pub(super) setpoint: ThermodynamicTemperature,
+}
+
+impl EquationProblem<1> for SetpointProblem {
+    type Input = MassRate;
+    type Output = KnownConductanceResult;
+    type Error = Infallible;
+
+    fn input(&self, x: &[f64; 1]) -> Result<Self::Input, Self::Error> {
+        Ok(MassRate::new::<kilogram_per_second>(x[0]))
+    }
+
+    fn residuals(
+        &self,
+        _input: &Self::Input,
+        output: &Self::Output,
+    ) -> Result<[f64; 1], Self::Error> {
+        let t_out = output.streams[1].outlet_temperature.get::<kelvin>();
+        Ok([t_out - self.setpoint.get::<kelvin>()])
+    }
+}
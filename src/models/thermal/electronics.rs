@@ -0,0 +1,12 @@
+//! Liquid cooling of electronics.
+//!
+//! This module provides models for cooling heat-dissipating devices, such
+//! as processors, power modules, and battery cells, with a pumped liquid:
+//!
+//! - [`cold_plate`]: thermal resistance network from a device junction to
+//!   the coolant flowing through its cold plate.
+//! - [`liquid_loop`]: a closed loop template with a pump, a cold plate, and
+//!   a radiator, solved in steady state.
+
+pub mod cold_plate;
+pub mod liquid_loop;
@@ -0,0 +1,68 @@
+//! Liquid-cooled cold plate.
+//!
+//! A [`ColdPlate`] carries the heat dissipated by a device through its case
+//! and a thermal interface into a plate cooled by a liquid stream. Given
+//! the dissipated heat, coolant flow, and coolant inlet temperature, it
+//! reports the junction, case, and plate temperatures, the coolant outlet
+//! temperature, and the coolant pressure drop.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::models::thermal::electronics::cold_plate::{
+//!     ColdPlate, ColdPlateInput, ColdPlateParameters, Coolant,
+//! };
+//! use uom::si::{
+//!     f64::{
+//!         MassDensity, MassRate, Power, Pressure, SpecificHeatCapacity, ThermalConductance,
+//!         ThermodynamicTemperature,
+//!     },
+//!     mass_density::kilogram_per_cubic_meter,
+//!     mass_rate::kilogram_per_second,
+//!     power::watt,
+//!     pressure::kilopascal,
+//!     specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+//!     thermal_conductance::watt_per_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//! };
+//!
+//! let plate = ColdPlate::new(ColdPlateParameters {
+//!     junction_to_case: ThermalConductance::new::<watt_per_kelvin>(25.0),
+//!     interface: ThermalConductance::new::<watt_per_kelvin>(50.0),
+//!     rated_convective: ThermalConductance::new::<watt_per_kelvin>(40.0),
+//!     rated_flow: MassRate::new::<kilogram_per_second>(0.02),
+//!     rated_pressure_drop: Pressure::new::<kilopascal>(20.0),
+//!     coolant: Coolant {
+//!         density: MassDensity::new::<kilogram_per_cubic_meter>(1040.0),
+//!         specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(3.6),
+//!     },
+//! })?;
+//!
+//! // A 300 W processor on 25 % propylene glycol at 30 °C.
+//! let output = plate.evaluate(&ColdPlateInput {
+//!     heat: Power::new::<watt>(300.0),
+//!     coolant_flow: MassRate::new::<kilogram_per_second>(0.02),
+//!     inlet_temperature: ThermodynamicTemperature::new::<degree_celsius>(30.0),
+//! })?;
+//!
+//! assert!(output.junction_temperature.get::<degree_celsius>() < 85.0);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    ColdPlate, ColdPlateError, ColdPlateInput, ColdPlateOutput, ColdPlateParameters, Coolant,
+};
+
+impl Model for ColdPlate {
+    type Input = ColdPlateInput;
+    type Output = ColdPlateOutput;
+    type Error = ColdPlateError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
@@ -0,0 +1,366 @@
+use thiserror::Error;
+use uom::si::{
+    f64::{
+        MassDensity, MassRate, Power, Pressure, Ratio, SpecificHeatCapacity, ThermalConductance,
+        ThermodynamicTemperature,
+    },
+    ratio::ratio,
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive},
+    units::TemperatureDifference,
+};
+
+/// Exponent on coolant flow in the convective conductance, from turbulent
+/// channel flow (`Nu ∝ Re^0.8`).
+const CONVECTIVE_FLOW_EXPONENT: f64 = 0.8;
+
+/// Constant properties of a liquid coolant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coolant {
+    /// Mass density, used for pump volumetric flows.
+    pub density: MassDensity,
+
+    /// Specific heat capacity at constant pressure.
+    pub specific_heat: SpecificHeatCapacity,
+}
+
+/// Fixed parameters of a [`ColdPlate`].
+///
+/// Each conductance is the inverse of one resistance in the series path
+/// from the device junction to the coolant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColdPlateParameters {
+    /// Conductance from the device junction to its case, from the device
+    /// datasheet (the inverse of `R_jc`).
+    pub junction_to_case: ThermalConductance,
+
+    /// Conductance of the thermal interface material between the case and
+    /// the plate.
+    pub interface: ThermalConductance,
+
+    /// Conductance from the plate base to the coolant at `rated_flow`,
+    /// including conduction through the base and fins.
+    pub rated_convective: ThermalConductance,
+
+    /// Coolant flow at which `rated_convective` and `rated_pressure_drop`
+    /// apply.
+    pub rated_flow: MassRate,
+
+    /// Coolant pressure drop across the plate at `rated_flow`.
+    pub rated_pressure_drop: Pressure,
+
+    /// Coolant properties.
+    pub coolant: Coolant,
+}
+
+/// Errors from [`ColdPlate`] construction and evaluation.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ColdPlateError {
+    /// A cold plate parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating input is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+}
+
+/// A liquid-cooled cold plate carrying heat from a device to a coolant.
+///
+/// The heat crosses three resistances in series: junction to case, the
+/// thermal interface, and the plate to the coolant. The plate base is
+/// taken as isothermal, so the coolant warms toward it with effectiveness
+/// `1 − exp(−UA_conv / ṁcp)`, and in steady state the coolant carries away
+/// all the dissipated heat:
+///
+/// ```text
+/// T_base = T_in + Q / (ε·ṁcp)
+/// T_case = T_base + Q / UA_tim
+/// T_j    = T_case + Q / UA_jc
+/// ```
+///
+/// The convective conductance scales with coolant flow to the 0.8 power and
+/// the pressure drop with its square.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColdPlate {
+    parameters: ColdPlateParameters,
+}
+
+/// Input to the cold plate model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColdPlateInput {
+    /// Heat dissipated by the device.
+    pub heat: Power,
+
+    /// Coolant mass flow through the plate.
+    pub coolant_flow: MassRate,
+
+    /// Coolant temperature entering the plate.
+    pub inlet_temperature: ThermodynamicTemperature,
+}
+
+/// Output from the cold plate model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColdPlateOutput {
+    /// Device junction temperature.
+    pub junction_temperature: ThermodynamicTemperature,
+
+    /// Device case temperature.
+    pub case_temperature: ThermodynamicTemperature,
+
+    /// Plate base temperature under the interface.
+    pub base_temperature: ThermodynamicTemperature,
+
+    /// Coolant temperature leaving the plate.
+    pub outlet_temperature: ThermodynamicTemperature,
+
+    /// Conductance from the plate base to the coolant at this flow.
+    pub convective: ThermalConductance,
+
+    /// Heat over the junction-to-inlet temperature difference, the inverse
+    /// of the overall thermal resistance.
+    pub overall: ThermalConductance,
+
+    /// Coolant pressure drop across the plate.
+    pub pressure_drop: Pressure,
+}
+
+impl ColdPlate {
+    /// Creates a cold plate from its parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ColdPlateError::InvalidParameter`] if a conductance, the
+    /// rated flow, or a coolant property is not strictly positive, or the
+    /// rated pressure drop is negative.
+    pub fn new(parameters: ColdPlateParameters) -> Result<Self, ColdPlateError> {
+        let ColdPlateParameters {
+            junction_to_case,
+            interface,
+            rated_convective,
+            rated_flow,
+            rated_pressure_drop,
+            coolant,
+        } = parameters;
+
+        if [junction_to_case, interface, rated_convective]
+            .iter()
+            .any(|ua| StrictlyPositive::check(ua).is_err() || !ua.is_finite())
+        {
+            return Err(ColdPlateError::InvalidParameter(
+                "conductances must be strictly positive and finite",
+            ));
+        }
+        if StrictlyPositive::check(&rated_flow).is_err() || !rated_flow.is_finite() {
+            return Err(ColdPlateError::InvalidParameter(
+                "rated flow must be strictly positive and finite",
+            ));
+        }
+        if NonNegative::check(&rated_pressure_drop).is_err() || !rated_pressure_drop.is_finite() {
+            return Err(ColdPlateError::InvalidParameter(
+                "rated pressure drop must be non-negative and finite",
+            ));
+        }
+        if StrictlyPositive::check(&coolant.density).is_err()
+            || StrictlyPositive::check(&coolant.specific_heat).is_err()
+        {
+            return Err(ColdPlateError::InvalidParameter(
+                "coolant properties must be strictly positive",
+            ));
+        }
+
+        Ok(Self { parameters })
+    }
+
+    /// Returns the cold plate parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &ColdPlateParameters {
+        &self.parameters
+    }
+
+    /// Returns the coolant pressure drop across the plate at `flow`.
+    #[must_use]
+    pub fn pressure_drop(&self, flow: MassRate) -> Pressure {
+        let flow_ratio = (flow / self.parameters.rated_flow).get::<ratio>();
+        self.parameters.rated_pressure_drop * flow_ratio * flow_ratio
+    }
+
+    /// Evaluates the temperatures through the plate at a single operating
+    /// point.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ColdPlateError::InvalidInput`] if the heat is negative or
+    /// the coolant flow is not strictly positive.
+    pub fn evaluate(&self, input: &ColdPlateInput) -> Result<ColdPlateOutput, ColdPlateError> {
+        let ColdPlateInput {
+            heat,
+            coolant_flow,
+            inlet_temperature,
+        } = *input;
+        let p = &self.parameters;
+
+        if NonNegative::check(&heat).is_err() || !heat.is_finite() {
+            return Err(ColdPlateError::InvalidInput(
+                "heat must be non-negative and finite",
+            ));
+        }
+        if StrictlyPositive::check(&coolant_flow).is_err() || !coolant_flow.is_finite() {
+            return Err(ColdPlateError::InvalidInput(
+                "coolant flow must be strictly positive and finite",
+            ));
+        }
+
+        let flow_ratio: Ratio = coolant_flow / p.rated_flow;
+        let convective =
+            p.rated_convective * flow_ratio.get::<ratio>().powf(CONVECTIVE_FLOW_EXPONENT);
+        let capacitance_rate = coolant_flow * p.coolant.specific_heat;
+        let effectiveness = -(-(convective / capacitance_rate).get::<ratio>()).exp_m1();
+
+        let outlet_temperature = inlet_temperature.plus(heat / capacitance_rate);
+        let base_temperature = inlet_temperature.plus(heat / (capacitance_rate * effectiveness));
+        let case_temperature = base_temperature.plus(heat / p.interface);
+        let junction_temperature = case_temperature.plus(heat / p.junction_to_case);
+
+        // The series path, with the coolant side as its equivalent resistance.
+        let overall = 1.0
+            / (1.0 / (capacitance_rate * effectiveness)
+                + 1.0 / p.interface
+                + 1.0 / p.junction_to_case);
+
+        Ok(ColdPlateOutput {
+            junction_temperature,
+            case_temperature,
+            base_temperature,
+            outlet_temperature,
+            convective,
+            overall,
+            pressure_drop: self.pressure_drop(coolant_flow),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        mass_density::kilogram_per_cubic_meter, mass_rate::kilogram_per_second, power::watt,
+        pressure::kilopascal, specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+        thermal_conductance::watt_per_kelvin, thermodynamic_temperature::degree_celsius,
+    };
+
+    use crate::support::units::test_utils::celsius;
+
+    fn plate() -> ColdPlate {
+        ColdPlate::new(ColdPlateParameters {
+            junction_to_case: ThermalConductance::new::<watt_per_kelvin>(25.0),
+            interface: ThermalConductance::new::<watt_per_kelvin>(50.0),
+            rated_convective: ThermalConductance::new::<watt_per_kelvin>(40.0),
+            rated_flow: MassRate::new::<kilogram_per_second>(0.02),
+            rated_pressure_drop: Pressure::new::<kilopascal>(20.0),
+            coolant: Coolant {
+                density: MassDensity::new::<kilogram_per_cubic_meter>(1040.0),
+                specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(3.6),
+            },
+        })
+        .unwrap()
+    }
+
+    fn input(heat: f64) -> ColdPlateInput {
+        ColdPlateInput {
+            heat: Power::new::<watt>(heat),
+            coolant_flow: MassRate::new::<kilogram_per_second>(0.02),
+            inlet_temperature: celsius(30.0),
+        }
+    }
+
+    #[test]
+    fn temperatures_step_up_through_the_resistances() {
+        let output = plate().evaluate(&input(300.0)).unwrap();
+
+        // 300 W into 72 W/K of coolant warms it by 4.17 K.
+        assert_relative_eq!(
+            output.outlet_temperature.get::<degree_celsius>(),
+            30.0 + 300.0 / 72.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            output
+                .junction_temperature
+                .minus(output.case_temperature)
+                .value,
+            300.0 / 25.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            output.case_temperature.minus(output.base_temperature).value,
+            300.0 / 50.0,
+            epsilon = 1e-9
+        );
+        assert!(output.base_temperature > output.outlet_temperature);
+        assert_relative_eq!(
+            (Power::new::<watt>(300.0) / output.overall).value,
+            output.junction_temperature.minus(celsius(30.0)).value,
+            epsilon = 1e-9
+        );
+        assert_eq!(output.pressure_drop, Pressure::new::<kilopascal>(20.0));
+    }
+
+    #[test]
+    fn more_flow_cools_the_junction_at_a_pressure_cost() {
+        let rated = plate().evaluate(&input(300.0)).unwrap();
+        let doubled = plate()
+            .evaluate(&ColdPlateInput {
+                coolant_flow: MassRate::new::<kilogram_per_second>(0.04),
+                ..input(300.0)
+            })
+            .unwrap();
+
+        assert!(doubled.junction_temperature < rated.junction_temperature);
+        assert_relative_eq!(
+            doubled.convective.get::<watt_per_kelvin>(),
+            40.0 * 2.0_f64.powf(0.8),
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            doubled.pressure_drop.get::<kilopascal>(),
+            80.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn no_heat_leaves_everything_at_inlet_temperature() {
+        let output = plate().evaluate(&input(0.0)).unwrap();
+        assert_eq!(output.junction_temperature, celsius(30.0));
+        assert_eq!(output.outlet_temperature, celsius(30.0));
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_inputs() {
+        assert!(matches!(
+            ColdPlate::new(ColdPlateParameters {
+                interface: ThermalConductance::new::<watt_per_kelvin>(0.0),
+                ..*plate().parameters()
+            }),
+            Err(ColdPlateError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            plate().evaluate(&input(-1.0)),
+            Err(ColdPlateError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            plate().evaluate(&ColdPlateInput {
+                coolant_flow: MassRate::new::<kilogram_per_second>(0.0),
+                ..input(300.0)
+            }),
+            Err(ColdPlateError::InvalidInput(_))
+        ));
+    }
+}
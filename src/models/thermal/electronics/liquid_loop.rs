@@ -0,0 +1,94 @@
+//! Closed liquid cooling loop template.
+//!
+//! A [`LiquidCoolingLoop`] composes a circulation pump, a
+//! [`ColdPlate`](super::cold_plate::ColdPlate), and a radiator into one
+//! steady-state model, as in a direct-to-chip loop in a data-center rack or
+//! the cooling loop of a power converter. Give it the device heat and the
+//! secondary stream through the radiator, such as fan air or facility
+//! water, and it reports the loop flow, pump power, coolant temperatures,
+//! and device junction temperature.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::electronics::{
+//!         cold_plate::{ColdPlateParameters, Coolant},
+//!         liquid_loop::{
+//!             LiquidCoolingLoop, LiquidCoolingLoopInput, LiquidCoolingLoopParameters, Pump,
+//!             Radiator,
+//!         },
+//!     },
+//!     support::hx::{CapacitanceRate, StreamInlet},
+//! };
+//! use uom::si::{
+//!     f64::{
+//!         MassDensity, MassRate, Power, Pressure, Ratio, SpecificHeatCapacity,
+//!         ThermalConductance, ThermodynamicTemperature, VolumeRate,
+//!     },
+//!     mass_density::kilogram_per_cubic_meter,
+//!     mass_rate::kilogram_per_second,
+//!     power::watt,
+//!     pressure::kilopascal,
+//!     ratio::percent,
+//!     specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+//!     thermal_conductance::watt_per_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//!     volume_rate::liter_per_minute,
+//! };
+//!
+//! let cooling_loop = LiquidCoolingLoop::new(LiquidCoolingLoopParameters {
+//!     cold_plate: ColdPlateParameters {
+//!         junction_to_case: ThermalConductance::new::<watt_per_kelvin>(25.0),
+//!         interface: ThermalConductance::new::<watt_per_kelvin>(50.0),
+//!         rated_convective: ThermalConductance::new::<watt_per_kelvin>(40.0),
+//!         rated_flow: MassRate::new::<kilogram_per_second>(0.02),
+//!         rated_pressure_drop: Pressure::new::<kilopascal>(20.0),
+//!         coolant: Coolant {
+//!             density: MassDensity::new::<kilogram_per_cubic_meter>(1040.0),
+//!             specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(3.6),
+//!         },
+//!     },
+//!     pump: Pump {
+//!         shutoff_pressure: Pressure::new::<kilopascal>(60.0),
+//!         max_flow: VolumeRate::new::<liter_per_minute>(3.0),
+//!         efficiency: Ratio::new::<percent>(20.0),
+//!     },
+//!     radiator: Radiator {
+//!         ua: ThermalConductance::new::<watt_per_kelvin>(60.0),
+//!         pressure_drop: Pressure::new::<kilopascal>(10.0),
+//!     },
+//! })?;
+//!
+//! // A 300 W processor, with the radiator fans pushing 35 °C room air.
+//! let output = cooling_loop.evaluate(&LiquidCoolingLoopInput {
+//!     heat: Power::new::<watt>(300.0),
+//!     secondary: StreamInlet::new(
+//!         CapacitanceRate::new::<watt_per_kelvin>(150.0)?,
+//!         ThermodynamicTemperature::new::<degree_celsius>(35.0),
+//!     ),
+//! })?;
+//!
+//! assert!(output.cold_plate.junction_temperature.get::<degree_celsius>() < 95.0);
+//! assert!(output.heat_rejected > Power::new::<watt>(300.0));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    LiquidCoolingLoop, LiquidCoolingLoopError, LiquidCoolingLoopInput, LiquidCoolingLoopOutput,
+    LiquidCoolingLoopParameters, Pump, Radiator,
+};
+
+impl Model for LiquidCoolingLoop {
+    type Input = LiquidCoolingLoopInput;
+    type Output = LiquidCoolingLoopOutput;
+    type Error = LiquidCoolingLoopError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
@@ -0,0 +1,457 @@
+use thiserror::Error;
+use uom::si::{
+    f64::{
+        MassRate, Power, Pressure, Ratio, ThermalConductance, ThermodynamicTemperature, VolumeRate,
+    },
+    ratio::ratio,
+};
+
+use crate::{
+    models::thermal::electronics::cold_plate::{
+        ColdPlate, ColdPlateError, ColdPlateInput, ColdPlateOutput, ColdPlateParameters,
+    },
+    support::{
+        constraint::{Constraint, NonNegative, StrictlyPositive, UnitInterval},
+        hx::{CapacitanceRate, CrossFlow, EffectivenessRelation, Ntu, StreamInlet, Unmixed},
+        units::TemperatureDifference,
+    },
+};
+
+/// Circulation pump with a quadratic head curve.
+///
+/// The pressure rise falls from `shutoff_pressure` at zero flow to zero at
+/// `max_flow`, as `Δp = Δp₀·(1 − (V̇ / V̇_max)²)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pump {
+    /// Pressure rise at zero flow.
+    pub shutoff_pressure: Pressure,
+
+    /// Flow at which the pressure rise reaches zero.
+    pub max_flow: VolumeRate,
+
+    /// Overall (wire-to-water) efficiency.
+    ///
+    /// All the electrical input ends up as heat in the coolant.
+    pub efficiency: Ratio,
+}
+
+/// Liquid-to-air or liquid-to-liquid radiator rejecting the loop heat.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Radiator {
+    /// Overall conductance between the coolant and the secondary stream.
+    pub ua: ThermalConductance,
+
+    /// Coolant pressure drop through the radiator and the loop piping at
+    /// the cold plate's rated flow.
+    pub pressure_drop: Pressure,
+}
+
+/// Fixed parameters of a [`LiquidCoolingLoop`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidCoolingLoopParameters {
+    /// Cold plate under the device, which also sets the coolant.
+    pub cold_plate: ColdPlateParameters,
+
+    /// Circulation pump.
+    pub pump: Pump,
+
+    /// Heat rejection exchanger.
+    pub radiator: Radiator,
+}
+
+/// Errors from [`LiquidCoolingLoop`] construction and evaluation.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum LiquidCoolingLoopError {
+    /// A loop parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating input is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// The cold plate rejected its parameters or operating point.
+    #[error("cold plate: {0}")]
+    ColdPlate(#[from] ColdPlateError),
+}
+
+/// A closed liquid cooling loop for electronics.
+///
+/// A pump drives coolant through a cold plate under the device and a
+/// radiator that rejects the heat to a secondary stream, such as fan air
+/// or facility water. The loop is evaluated in steady state:
+///
+/// - The flow is where the pump curve meets the loop's quadratic system
+///   curve.
+/// - The radiator rejects the device heat plus the pump's electrical
+///   input, and its crossflow effectiveness (both streams unmixed) fixes
+///   how far above the secondary inlet the coolant must run to do so.
+/// - The pump heats the coolant on its way from the radiator to the cold
+///   plate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LiquidCoolingLoop {
+    parameters: LiquidCoolingLoopParameters,
+    cold_plate: ColdPlate,
+}
+
+/// Input to the liquid cooling loop model.
+#[derive(Debug, Clone, Copy)]
+pub struct LiquidCoolingLoopInput {
+    /// Heat dissipated by the device.
+    pub heat: Power,
+
+    /// Secondary stream entering the radiator.
+    pub secondary: StreamInlet,
+}
+
+/// Output from the liquid cooling loop model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LiquidCoolingLoopOutput {
+    /// Coolant mass flow around the loop.
+    pub coolant_flow: MassRate,
+
+    /// Pressure rise across the pump, equal to the loop pressure drop.
+    pub pump_pressure_rise: Pressure,
+
+    /// Electrical power drawn by the pump.
+    pub pump_power: Power,
+
+    /// Heat rejected by the radiator, the device heat plus the pump power.
+    pub heat_rejected: Power,
+
+    /// Radiator effectiveness.
+    pub radiator_effectiveness: Ratio,
+
+    /// Coolant temperature entering the radiator, the hottest in the loop.
+    pub radiator_inlet_temperature: ThermodynamicTemperature,
+
+    /// Coolant temperature leaving the radiator, the coldest in the loop.
+    pub radiator_outlet_temperature: ThermodynamicTemperature,
+
+    /// Secondary stream temperature leaving the radiator.
+    pub secondary_outlet_temperature: ThermodynamicTemperature,
+
+    /// Temperatures and pressure drop at the cold plate.
+    pub cold_plate: ColdPlateOutput,
+}
+
+impl LiquidCoolingLoop {
+    /// Creates a liquid cooling loop from its parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LiquidCoolingLoopError::ColdPlate`] if the cold plate
+    /// parameters are invalid, or
+    /// [`LiquidCoolingLoopError::InvalidParameter`] if the pump shutoff
+    /// pressure or maximum flow or the radiator conductance is not strictly
+    /// positive, the radiator pressure drop is negative, or the pump
+    /// efficiency is not in (0, 1].
+    pub fn new(parameters: LiquidCoolingLoopParameters) -> Result<Self, LiquidCoolingLoopError> {
+        let cold_plate = ColdPlate::new(parameters.cold_plate)?;
+        let LiquidCoolingLoopParameters { pump, radiator, .. } = parameters;
+
+        if StrictlyPositive::check(&pump.shutoff_pressure).is_err()
+            || !pump.shutoff_pressure.is_finite()
+        {
+            return Err(LiquidCoolingLoopError::InvalidParameter(
+                "pump shutoff pressure must be strictly positive and finite",
+            ));
+        }
+        if StrictlyPositive::check(&pump.max_flow).is_err() || !pump.max_flow.is_finite() {
+            return Err(LiquidCoolingLoopError::InvalidParameter(
+                "pump maximum flow must be strictly positive and finite",
+            ));
+        }
+        if StrictlyPositive::check(&pump.efficiency).is_err()
+            || UnitInterval::check(&pump.efficiency).is_err()
+        {
+            return Err(LiquidCoolingLoopError::InvalidParameter(
+                "pump efficiency must be in (0, 1]",
+            ));
+        }
+        if StrictlyPositive::check(&radiator.ua).is_err() || !radiator.ua.is_finite() {
+            return Err(LiquidCoolingLoopError::InvalidParameter(
+                "radiator UA must be strictly positive and finite",
+            ));
+        }
+        if NonNegative::check(&radiator.pressure_drop).is_err()
+            || !radiator.pressure_drop.is_finite()
+        {
+            return Err(LiquidCoolingLoopError::InvalidParameter(
+                "radiator pressure drop must be non-negative and finite",
+            ));
+        }
+
+        Ok(Self {
+            parameters,
+            cold_plate,
+        })
+    }
+
+    /// Returns the loop parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &LiquidCoolingLoopParameters {
+        &self.parameters
+    }
+
+    /// Returns the coolant flow and pump pressure rise at the intersection
+    /// of the pump and system curves.
+    ///
+    /// Both curves are quadratic in flow, so the operating point is closed
+    /// form.
+    #[must_use]
+    pub fn operating_point(&self) -> (MassRate, Pressure) {
+        let LiquidCoolingLoopParameters {
+            cold_plate,
+            pump,
+            radiator,
+        } = self.parameters;
+
+        // System curve Δp = k·V̇², pump curve Δp = Δp₀·(1 − (V̇ / V̇_max)²).
+        let rated_volume_flow: VolumeRate = cold_plate.rated_flow / cold_plate.coolant.density;
+        let rated_drop = cold_plate.rated_pressure_drop + radiator.pressure_drop;
+        let system = (rated_drop / pump.shutoff_pressure).get::<ratio>()
+            / (rated_volume_flow / pump.max_flow).get::<ratio>().powi(2);
+        let flow_fraction = (1.0 / (1.0 + system)).sqrt();
+
+        let volume_flow = pump.max_flow * flow_fraction;
+        let pressure_rise = pump.shutoff_pressure * (1.0 - flow_fraction * flow_fraction);
+        (volume_flow * cold_plate.coolant.density, pressure_rise)
+    }
+
+    /// Evaluates the loop in steady state.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LiquidCoolingLoopError::InvalidInput`] if the heat is
+    /// negative or not finite, or [`LiquidCoolingLoopError::ColdPlate`] if
+    /// the cold plate cannot be evaluated.
+    pub fn evaluate(
+        &self,
+        input: &LiquidCoolingLoopInput,
+    ) -> Result<LiquidCoolingLoopOutput, LiquidCoolingLoopError> {
+        let LiquidCoolingLoopInput { heat, secondary } = *input;
+        if NonNegative::check(&heat).is_err() || !heat.is_finite() {
+            return Err(LiquidCoolingLoopError::InvalidInput(
+                "heat must be non-negative and finite",
+            ));
+        }
+
+        let LiquidCoolingLoopParameters {
+            cold_plate,
+            pump,
+            radiator,
+        } = self.parameters;
+        let (coolant_flow, pump_pressure_rise) = self.operating_point();
+        let pump_power =
+            coolant_flow / cold_plate.coolant.density * pump_pressure_rise / pump.efficiency;
+        let heat_rejected = heat + pump_power;
+
+        // The radiator must pass the whole load from coolant to secondary.
+        let coolant =
+            CapacitanceRate::from_quantity(coolant_flow * cold_plate.coolant.specific_heat)
+                .map_err(|_| {
+                    LiquidCoolingLoopError::InvalidInput(
+                        "coolant capacitance rate must be positive",
+                    )
+                })?;
+        let rates = [coolant, secondary.capacitance_rate];
+        let minimum = (*coolant).min(*secondary.capacitance_rate);
+        let effectiveness = CrossFlow::<Unmixed, Unmixed>::new().effectiveness(
+            Ntu::from_conductance_and_capacitance_rates(radiator.ua, rates).map_err(|_| {
+                LiquidCoolingLoopError::InvalidInput("radiator NTU must be non-negative")
+            })?,
+            rates,
+        );
+
+        let radiator_inlet_temperature = secondary
+            .temperature
+            .plus(heat_rejected / (minimum * effectiveness.get::<ratio>()));
+        let radiator_outlet_temperature =
+            radiator_inlet_temperature.plus(-(heat_rejected / *coolant));
+        let cold_plate_output = self.cold_plate.evaluate(&ColdPlateInput {
+            heat,
+            coolant_flow,
+            inlet_temperature: radiator_outlet_temperature.plus(pump_power / *coolant),
+        })?;
+
+        Ok(LiquidCoolingLoopOutput {
+            coolant_flow,
+            pump_pressure_rise,
+            pump_power,
+            heat_rejected,
+            radiator_effectiveness: *effectiveness,
+            radiator_inlet_temperature,
+            radiator_outlet_temperature,
+            secondary_outlet_temperature: secondary
+                .temperature
+                .plus(heat_rejected / *secondary.capacitance_rate),
+            cold_plate: cold_plate_output,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::{MassDensity, SpecificHeatCapacity},
+        mass_density::kilogram_per_cubic_meter,
+        mass_rate::kilogram_per_second,
+        power::watt,
+        pressure::kilopascal,
+        specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+        thermal_conductance::watt_per_kelvin,
+        thermodynamic_temperature::degree_celsius,
+        volume_rate::liter_per_minute,
+    };
+
+    use crate::{
+        models::thermal::electronics::cold_plate::Coolant, support::units::test_utils::celsius,
+    };
+
+    fn parameters() -> LiquidCoolingLoopParameters {
+        LiquidCoolingLoopParameters {
+            cold_plate: ColdPlateParameters {
+                junction_to_case: ThermalConductance::new::<watt_per_kelvin>(25.0),
+                interface: ThermalConductance::new::<watt_per_kelvin>(50.0),
+                rated_convective: ThermalConductance::new::<watt_per_kelvin>(40.0),
+                rated_flow: MassRate::new::<kilogram_per_second>(0.02),
+                rated_pressure_drop: Pressure::new::<kilopascal>(20.0),
+                coolant: Coolant {
+                    density: MassDensity::new::<kilogram_per_cubic_meter>(1040.0),
+                    specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(3.6),
+                },
+            },
+            pump: Pump {
+                shutoff_pressure: Pressure::new::<kilopascal>(60.0),
+                max_flow: VolumeRate::new::<liter_per_minute>(3.0),
+                efficiency: Ratio::new::<ratio>(0.2),
+            },
+            radiator: Radiator {
+                ua: ThermalConductance::new::<watt_per_kelvin>(60.0),
+                pressure_drop: Pressure::new::<kilopascal>(10.0),
+            },
+        }
+    }
+
+    fn input(heat: f64) -> LiquidCoolingLoopInput {
+        LiquidCoolingLoopInput {
+            heat: Power::new::<watt>(heat),
+            secondary: StreamInlet::new(
+                CapacitanceRate::new::<watt_per_kelvin>(150.0).unwrap(),
+                celsius(25.0),
+            ),
+        }
+    }
+
+    #[test]
+    fn operating_point_sits_on_both_curves() {
+        let cooling_loop = LiquidCoolingLoop::new(parameters()).unwrap();
+        let (flow, pressure_rise) = cooling_loop.operating_point();
+
+        let p = parameters();
+        let flow_ratio = (flow / p.cold_plate.rated_flow).get::<ratio>();
+        let system =
+            (p.cold_plate.rated_pressure_drop + p.radiator.pressure_drop) * flow_ratio * flow_ratio;
+        assert_relative_eq!(
+            pressure_rise.get::<kilopascal>(),
+            system.get::<kilopascal>(),
+            max_relative = 1e-12
+        );
+
+        let volume_fraction =
+            (flow / p.cold_plate.coolant.density / p.pump.max_flow).get::<ratio>();
+        assert_relative_eq!(
+            pressure_rise.get::<kilopascal>(),
+            60.0 * (1.0 - volume_fraction * volume_fraction),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn loop_closes_its_energy_balance() {
+        let output = LiquidCoolingLoop::new(parameters())
+            .unwrap()
+            .evaluate(&input(300.0))
+            .unwrap();
+
+        // The cold plate returns coolant at the radiator inlet temperature.
+        assert_relative_eq!(
+            output.cold_plate.outlet_temperature.get::<degree_celsius>(),
+            output.radiator_inlet_temperature.get::<degree_celsius>(),
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            output.heat_rejected.get::<watt>(),
+            300.0 + output.pump_power.get::<watt>(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            output.heat_rejected.get::<watt>(),
+            150.0
+                * output
+                    .secondary_outlet_temperature
+                    .minus(celsius(25.0))
+                    .value,
+            max_relative = 1e-9
+        );
+        assert!(output.radiator_outlet_temperature > celsius(25.0));
+        assert!(output.cold_plate.junction_temperature > output.radiator_inlet_temperature);
+    }
+
+    #[test]
+    fn warmer_secondary_raises_junction_one_for_one() {
+        let cooling_loop = LiquidCoolingLoop::new(parameters()).unwrap();
+        let cool = cooling_loop.evaluate(&input(300.0)).unwrap();
+        let warm = cooling_loop
+            .evaluate(&LiquidCoolingLoopInput {
+                secondary: StreamInlet::new(
+                    CapacitanceRate::new::<watt_per_kelvin>(150.0).unwrap(),
+                    celsius(35.0),
+                ),
+                ..input(300.0)
+            })
+            .unwrap();
+
+        assert_relative_eq!(
+            warm.cold_plate
+                .junction_temperature
+                .minus(cool.cold_plate.junction_temperature)
+                .value,
+            10.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_inputs() {
+        let mut bad = parameters();
+        bad.pump.efficiency = Ratio::new::<ratio>(0.0);
+        assert!(matches!(
+            LiquidCoolingLoop::new(bad),
+            Err(LiquidCoolingLoopError::InvalidParameter(_))
+        ));
+
+        let mut bad = parameters();
+        bad.cold_plate.rated_flow = MassRate::new::<kilogram_per_second>(0.0);
+        assert!(matches!(
+            LiquidCoolingLoop::new(bad),
+            Err(LiquidCoolingLoopError::ColdPlate(
+                ColdPlateError::InvalidParameter(_)
+            ))
+        ));
+
+        assert!(matches!(
+            LiquidCoolingLoop::new(parameters())
+                .unwrap()
+                .evaluate(&input(-5.0)),
+            Err(LiquidCoolingLoopError::InvalidInput(_))
+        ));
+    }
+}
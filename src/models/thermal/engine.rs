@@ -0,0 +1,3 @@
+//! Heat engines.
+
+pub mod stirling;
@@ -0,0 +1,67 @@
+//! Stirling engine cycle.
+//!
+//! A [`StirlingEngine`] is analyzed with the isothermal Schmidt model:
+//! given the engine geometry, working gas, mean pressure, heater and cooler
+//! gas temperatures, and speed, it reports the indicated power, the heat
+//! supplied and rejected, and the efficiency with the regenerator's
+//! shortfall included.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::models::thermal::engine::stirling::{
+//!     StirlingEngine, StirlingInput, StirlingParameters, WorkingGas,
+//! };
+//! use uom::si::{
+//!     angle::degree,
+//!     f64::{Angle, Frequency, Pressure, Ratio, ThermodynamicTemperature, Volume},
+//!     frequency::hertz,
+//!     power::kilowatt,
+//!     pressure::megapascal,
+//!     ratio::ratio,
+//!     thermodynamic_temperature::degree_celsius,
+//!     volume::cubic_centimeter,
+//! };
+//!
+//! let engine = StirlingEngine::new(StirlingParameters {
+//!     expansion_swept: Volume::new::<cubic_centimeter>(120.0),
+//!     compression_swept: Volume::new::<cubic_centimeter>(110.0),
+//!     expansion_clearance: Volume::new::<cubic_centimeter>(30.0),
+//!     compression_clearance: Volume::new::<cubic_centimeter>(28.0),
+//!     heater_volume: Volume::new::<cubic_centimeter>(70.0),
+//!     regenerator_volume: Volume::new::<cubic_centimeter>(50.0),
+//!     cooler_volume: Volume::new::<cubic_centimeter>(15.0),
+//!     phase_angle: Angle::new::<degree>(90.0),
+//!     regenerator_ntu: Ratio::new::<ratio>(20.0),
+//!     gas: WorkingGas::helium(),
+//! })?;
+//!
+//! let output = engine.evaluate(&StirlingInput {
+//!     mean_pressure: Pressure::new::<megapascal>(4.0),
+//!     hot_temperature: ThermodynamicTemperature::new::<degree_celsius>(650.0),
+//!     cold_temperature: ThermodynamicTemperature::new::<degree_celsius>(60.0),
+//!     frequency: Frequency::new::<hertz>(25.0),
+//! })?;
+//!
+//! assert!(output.indicated_power.get::<kilowatt>() > 2.0);
+//! assert!(output.efficiency < output.carnot_efficiency);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    StirlingEngine, StirlingError, StirlingInput, StirlingOutput, StirlingParameters, WorkingGas,
+};
+
+impl Model for StirlingEngine {
+    type Input = StirlingInput;
+    type Output = StirlingOutput;
+    type Error = StirlingError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
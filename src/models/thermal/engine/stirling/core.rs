@@ -0,0 +1,555 @@
+use std::f64::consts::PI;
+
+use thiserror::Error;
+use uom::si::{
+    angle::radian,
+    energy::joule,
+    f64::{
+        Angle, Energy, Frequency, Mass, Power, Pressure, Ratio, SpecificHeatCapacity,
+        ThermodynamicTemperature, Volume,
+    },
+    mass::kilogram,
+    pressure::pascal,
+    ratio::ratio,
+    specific_heat_capacity::joule_per_kilogram_kelvin,
+    thermal_conductance::watt_per_kelvin,
+    thermodynamic_temperature::kelvin,
+    volume::cubic_meter,
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive},
+    hx::{CapacitanceRate, CounterFlow, EffectivenessRelation, Ntu},
+    units::TemperatureDifference,
+};
+
+/// Ideal working gas of a Stirling engine.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorkingGas {
+    /// Specific gas constant.
+    pub gas_constant: SpecificHeatCapacity,
+
+    /// Specific heat at constant volume.
+    pub cv: SpecificHeatCapacity,
+}
+
+impl WorkingGas {
+    /// Helium, the usual working gas of high-performance engines.
+    #[must_use]
+    pub fn helium() -> Self {
+        Self {
+            gas_constant: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(2077.1),
+            cv: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(3115.6),
+        }
+    }
+
+    /// Hydrogen, with the lowest flow losses but demanding seals.
+    #[must_use]
+    pub fn hydrogen() -> Self {
+        Self {
+            gas_constant: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(4124.2),
+            cv: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(10_183.0),
+        }
+    }
+
+    /// Air, as in low-pressure demonstration engines.
+    #[must_use]
+    pub fn air() -> Self {
+        Self {
+            gas_constant: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(287.05),
+            cv: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(717.6),
+        }
+    }
+}
+
+/// Geometry and working gas of a [`StirlingEngine`].
+///
+/// Swept volumes vary sinusoidally with crank angle, the expansion space
+/// leading the compression space by `phase_angle`. This covers alpha
+/// engines directly and beta and gamma engines through their equivalent
+/// swept volumes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StirlingParameters {
+    /// Swept volume of the hot expansion space.
+    pub expansion_swept: Volume,
+
+    /// Swept volume of the cold compression space.
+    pub compression_swept: Volume,
+
+    /// Clearance volume of the expansion space.
+    pub expansion_clearance: Volume,
+
+    /// Clearance volume of the compression space.
+    pub compression_clearance: Volume,
+
+    /// Void volume of the heater, at the hot temperature.
+    pub heater_volume: Volume,
+
+    /// Void volume of the regenerator matrix.
+    pub regenerator_volume: Volume,
+
+    /// Void volume of the cooler, at the cold temperature.
+    pub cooler_volume: Volume,
+
+    /// Angle by which the expansion volume leads the compression volume,
+    /// typically about 90°.
+    pub phase_angle: Angle,
+
+    /// Number of transfer units of the regenerator, its matrix conductance
+    /// over the capacitance rate of the gas blowing through it.
+    pub regenerator_ntu: Ratio,
+
+    /// Working gas.
+    pub gas: WorkingGas,
+}
+
+/// Errors from [`StirlingEngine`] construction and evaluation.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum StirlingError {
+    /// An engine parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating input is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+}
+
+/// A Stirling engine evaluated by the isothermal Schmidt analysis.
+///
+/// Every space holds gas at a fixed temperature: the expansion space and
+/// heater at the hot temperature, the compression space and cooler at the
+/// cold temperature, and the regenerator at the log mean of the two. With
+/// sinusoidal volume variations the pressure has a closed form over the
+/// cycle, and so do the work of each space, following Urieli and
+/// Berchowitz.
+///
+/// In the isothermal engine the expansion space absorbs all the heater heat
+/// and the compression space rejects all the cooler heat, giving Carnot
+/// efficiency. A real regenerator returns only a fraction `ε` of the heat
+/// the gas leaves in it. The shortfall `(1 − ε)·M·cv·(T_h − T_k)` per cycle
+/// must come from the heater and leaves through the cooler, which lowers
+/// the efficiency without changing the work. The regenerator is treated as
+/// a balanced counterflow exchanger between the hot and cold blows, so
+/// `ε = NTU / (1 + NTU)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StirlingEngine {
+    parameters: StirlingParameters,
+}
+
+/// Input to the Stirling engine model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StirlingInput {
+    /// Cycle-mean working gas pressure.
+    pub mean_pressure: Pressure,
+
+    /// Gas temperature in the heater and expansion space.
+    pub hot_temperature: ThermodynamicTemperature,
+
+    /// Gas temperature in the cooler and compression space.
+    pub cold_temperature: ThermodynamicTemperature,
+
+    /// Crank speed, in cycles per unit time.
+    pub frequency: Frequency,
+}
+
+/// Output from the Stirling engine model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StirlingOutput {
+    /// Mass of working gas that gives the mean pressure.
+    pub gas_mass: Mass,
+
+    /// Lowest pressure over the cycle.
+    pub min_pressure: Pressure,
+
+    /// Highest pressure over the cycle.
+    pub max_pressure: Pressure,
+
+    /// Work done by the gas in the expansion space per cycle, which equals
+    /// the heat it absorbs there.
+    pub expansion_work: Energy,
+
+    /// Work done by the gas in the compression space per cycle, which is
+    /// negative and equals the heat it gives up there.
+    pub compression_work: Energy,
+
+    /// Net indicated work per cycle.
+    pub indicated_work: Energy,
+
+    /// Net indicated power.
+    pub indicated_power: Power,
+
+    /// Regenerator effectiveness.
+    pub regenerator_effectiveness: Ratio,
+
+    /// Heat the regenerator fails to recover, made up by the heater.
+    pub regenerator_loss: Power,
+
+    /// Heat supplied by the heater.
+    pub heat_input: Power,
+
+    /// Heat rejected by the cooler.
+    pub heat_rejected: Power,
+
+    /// Indicated power over heater heat.
+    pub efficiency: Ratio,
+
+    /// Carnot efficiency between the hot and cold temperatures.
+    pub carnot_efficiency: Ratio,
+}
+
+impl StirlingEngine {
+    /// Creates a Stirling engine from its parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StirlingError::InvalidParameter`] if a swept volume or gas
+    /// property is not strictly positive, or a dead volume or the
+    /// regenerator NTU is negative.
+    pub fn new(parameters: StirlingParameters) -> Result<Self, StirlingError> {
+        let StirlingParameters {
+            expansion_swept,
+            compression_swept,
+            expansion_clearance,
+            compression_clearance,
+            heater_volume,
+            regenerator_volume,
+            cooler_volume,
+            phase_angle,
+            regenerator_ntu,
+            gas,
+        } = parameters;
+
+        if [expansion_swept, compression_swept]
+            .iter()
+            .any(|v| StrictlyPositive::check(v).is_err() || !v.is_finite())
+        {
+            return Err(StirlingError::InvalidParameter(
+                "swept volumes must be strictly positive and finite",
+            ));
+        }
+        if [
+            expansion_clearance,
+            compression_clearance,
+            heater_volume,
+            regenerator_volume,
+            cooler_volume,
+        ]
+        .iter()
+        .any(|v| NonNegative::check(v).is_err() || !v.is_finite())
+        {
+            return Err(StirlingError::InvalidParameter(
+                "dead volumes must be non-negative and finite",
+            ));
+        }
+        if !phase_angle.is_finite() {
+            return Err(StirlingError::InvalidParameter(
+                "phase angle must be finite",
+            ));
+        }
+        if NonNegative::check(&regenerator_ntu).is_err() || !regenerator_ntu.is_finite() {
+            return Err(StirlingError::InvalidParameter(
+                "regenerator NTU must be non-negative and finite",
+            ));
+        }
+        if StrictlyPositive::check(&gas.gas_constant).is_err()
+            || StrictlyPositive::check(&gas.cv).is_err()
+        {
+            return Err(StirlingError::InvalidParameter(
+                "gas properties must be strictly positive",
+            ));
+        }
+
+        Ok(Self { parameters })
+    }
+
+    /// Returns the engine parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &StirlingParameters {
+        &self.parameters
+    }
+
+    /// Returns the regenerator effectiveness, from the balanced counterflow
+    /// relation at the regenerator NTU.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the regenerator NTU is negative, which
+    /// [`StirlingEngine::new`] rules out.
+    #[must_use]
+    pub fn regenerator_effectiveness(&self) -> Ratio {
+        // Any equal pair of capacitance rates gives the balanced exchanger.
+        let blow = CapacitanceRate::new::<watt_per_kelvin>(1.0)
+            .expect("unit capacitance rate is strictly positive");
+        let ntu = Ntu::from_quantity(self.parameters.regenerator_ntu)
+            .expect("regenerator NTU is validated as non-negative");
+        *CounterFlow.effectiveness(ntu, [blow, blow])
+    }
+
+    /// Evaluates the cycle at a single operating point.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StirlingError::InvalidInput`] if the mean pressure or
+    /// frequency is not strictly positive, the cold temperature is not
+    /// strictly positive, or the hot temperature is not above the cold.
+    pub fn evaluate(&self, input: &StirlingInput) -> Result<StirlingOutput, StirlingError> {
+        let StirlingInput {
+            mean_pressure,
+            hot_temperature,
+            cold_temperature,
+            frequency,
+        } = *input;
+        let p = &self.parameters;
+
+        if StrictlyPositive::check(&mean_pressure).is_err() || !mean_pressure.is_finite() {
+            return Err(StirlingError::InvalidInput(
+                "mean pressure must be strictly positive and finite",
+            ));
+        }
+        if StrictlyPositive::check(&frequency).is_err() || !frequency.is_finite() {
+            return Err(StirlingError::InvalidInput(
+                "frequency must be strictly positive and finite",
+            ));
+        }
+        let t_h = hot_temperature.get::<kelvin>();
+        let t_k = cold_temperature.get::<kelvin>();
+        if !(t_k > 0.0 && t_h > t_k && t_h.is_finite()) {
+            return Err(StirlingError::InvalidInput(
+                "temperatures must satisfy 0 < cold < hot",
+            ));
+        }
+
+        // Reduced volumes V/T, in m³/K, of each space.
+        let reduced = |volume: Volume, t: f64| volume.get::<cubic_meter>() / t;
+        let expansion = reduced(p.expansion_swept, t_h);
+        let compression = reduced(p.compression_swept, t_k);
+        let regenerator_temperature = (t_h - t_k) / (t_h / t_k).ln();
+        let s = compression / 2.0
+            + reduced(p.compression_clearance + p.cooler_volume, t_k)
+            + reduced(p.regenerator_volume, regenerator_temperature)
+            + reduced(p.heater_volume + p.expansion_clearance, t_h)
+            + expansion / 2.0;
+
+        // Σ V/T = s·(1 + b·cos(θ + β)) over crank angle θ.
+        let alpha = p.phase_angle.get::<radian>();
+        let beta = (expansion * alpha.sin()).atan2(expansion * alpha.cos() + compression);
+        let c = 0.5
+            * (expansion * expansion
+                + 2.0 * expansion * compression * alpha.cos()
+                + compression * compression)
+                .sqrt();
+        let b = c / s;
+        let root = (1.0 - b * b).sqrt();
+
+        // The gas mass follows from the cycle-mean pressure, p̄ = MR / (s·√(1 − b²)).
+        let mr = mean_pressure.get::<pascal>() * s * root;
+        let gas_mass =
+            Mass::new::<kilogram>(mr / p.gas.gas_constant.get::<joule_per_kilogram_kelvin>());
+
+        // Work of each space per cycle, ∮ p dV.
+        let factor = if b > 0.0 {
+            PI * mean_pressure.get::<pascal>() * (root - 1.0) / b
+        } else {
+            0.0
+        };
+        let compression_work =
+            Energy::new::<joule>(p.compression_swept.get::<cubic_meter>() * factor * beta.sin());
+        let expansion_work = Energy::new::<joule>(
+            p.expansion_swept.get::<cubic_meter>() * factor * (beta - alpha).sin(),
+        );
+        let indicated_work = expansion_work + compression_work;
+
+        let regenerator_effectiveness = self.regenerator_effectiveness();
+        let regenerator_heat = gas_mass * p.gas.cv * (hot_temperature.minus(cold_temperature));
+        let regenerator_loss =
+            regenerator_heat * (1.0 - regenerator_effectiveness.get::<ratio>()) * frequency;
+
+        let indicated_power: Power = indicated_work * frequency;
+        let heat_input = expansion_work * frequency + regenerator_loss;
+        let heat_rejected = -compression_work * frequency + regenerator_loss;
+
+        Ok(StirlingOutput {
+            gas_mass,
+            min_pressure: mean_pressure * root / (1.0 + b),
+            max_pressure: mean_pressure * root / (1.0 - b),
+            expansion_work,
+            compression_work,
+            indicated_work,
+            indicated_power,
+            regenerator_effectiveness,
+            regenerator_loss,
+            heat_input,
+            heat_rejected,
+            efficiency: indicated_power / heat_input,
+            carnot_efficiency: Ratio::new::<ratio>(1.0 - t_k / t_h),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        angle::degree, frequency::hertz, power::kilowatt, pressure::megapascal,
+        thermodynamic_temperature::degree_celsius, volume::cubic_centimeter,
+    };
+
+    fn cc(v: f64) -> Volume {
+        Volume::new::<cubic_centimeter>(v)
+    }
+
+    fn parameters() -> StirlingParameters {
+        StirlingParameters {
+            expansion_swept: cc(120.0),
+            compression_swept: cc(110.0),
+            expansion_clearance: cc(30.0),
+            compression_clearance: cc(28.0),
+            heater_volume: cc(70.0),
+            regenerator_volume: cc(50.0),
+            cooler_volume: cc(15.0),
+            phase_angle: Angle::new::<degree>(90.0),
+            regenerator_ntu: Ratio::new::<ratio>(20.0),
+            gas: WorkingGas::helium(),
+        }
+    }
+
+    fn input() -> StirlingInput {
+        StirlingInput {
+            mean_pressure: Pressure::new::<megapascal>(4.0),
+            hot_temperature: ThermodynamicTemperature::new::<degree_celsius>(650.0),
+            cold_temperature: ThermodynamicTemperature::new::<degree_celsius>(60.0),
+            frequency: Frequency::new::<hertz>(25.0),
+        }
+    }
+
+    #[test]
+    fn closed_form_work_matches_integrated_pressure() {
+        let p = parameters();
+        let output = StirlingEngine::new(p).unwrap().evaluate(&input()).unwrap();
+
+        // Integrate p·dV around the crank with the gas mass from the model.
+        let t_h = input().hot_temperature.get::<kelvin>();
+        let t_k = input().cold_temperature.get::<kelvin>();
+        let t_r = (t_h - t_k) / (t_h / t_k).ln();
+        let m3 = |v: Volume| v.get::<cubic_meter>();
+        let mr = output.gas_mass.get::<kilogram>() * p.gas.gas_constant.value;
+        let alpha = p.phase_angle.get::<radian>();
+        let volumes = |theta: f64| {
+            let v_c =
+                m3(p.compression_clearance) + m3(p.compression_swept) * (1.0 + theta.cos()) / 2.0;
+            let v_e = m3(p.expansion_clearance)
+                + m3(p.expansion_swept) * (1.0 + (theta + alpha).cos()) / 2.0;
+            (v_c, v_e)
+        };
+        let pressure = |theta: f64| {
+            let (v_c, v_e) = volumes(theta);
+            mr / ((v_c + m3(p.cooler_volume)) / t_k
+                + m3(p.regenerator_volume) / t_r
+                + (v_e + m3(p.heater_volume)) / t_h)
+        };
+
+        let steps = 20_000;
+        let d_theta = 2.0 * PI / f64::from(steps);
+        let (mut w_c, mut w_e, mut p_sum) = (0.0, 0.0, 0.0);
+        for i in 0..steps {
+            let theta = (f64::from(i) + 0.5) * d_theta;
+            let (c0, e0) = volumes(theta - d_theta / 2.0);
+            let (c1, e1) = volumes(theta + d_theta / 2.0);
+            w_c += pressure(theta) * (c1 - c0);
+            w_e += pressure(theta) * (e1 - e0);
+            p_sum += pressure(theta);
+        }
+
+        assert_relative_eq!(
+            output.compression_work.get::<joule>(),
+            w_c,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(
+            output.expansion_work.get::<joule>(),
+            w_e,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(
+            p_sum / f64::from(steps),
+            input().mean_pressure.get::<pascal>(),
+            max_relative = 1e-9
+        );
+        assert!(output.compression_work < Energy::new::<joule>(0.0));
+    }
+
+    #[test]
+    fn perfect_regenerator_reaches_carnot() {
+        let output = StirlingEngine::new(StirlingParameters {
+            regenerator_ntu: Ratio::new::<ratio>(f64::MAX),
+            ..parameters()
+        })
+        .unwrap()
+        .evaluate(&input())
+        .unwrap();
+
+        assert_relative_eq!(output.regenerator_effectiveness.get::<ratio>(), 1.0);
+        assert_relative_eq!(
+            output.efficiency.get::<ratio>(),
+            output.carnot_efficiency.get::<ratio>(),
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn leaky_regenerator_costs_efficiency_not_power() {
+        let engine = StirlingEngine::new(parameters()).unwrap();
+        let output = engine.evaluate(&input()).unwrap();
+
+        // NTU 20 recovers 20/21 of the regenerator heat.
+        assert_relative_eq!(
+            output.regenerator_effectiveness.get::<ratio>(),
+            20.0 / 21.0,
+            max_relative = 1e-12
+        );
+        assert!(output.efficiency < output.carnot_efficiency);
+        assert_relative_eq!(
+            (output.heat_input - output.heat_rejected).get::<kilowatt>(),
+            output.indicated_power.get::<kilowatt>(),
+            max_relative = 1e-9
+        );
+
+        // A few kilowatts, in line with engines of this size.
+        let power = output.indicated_power.get::<kilowatt>();
+        assert!(power > 2.0 && power < 10.0, "power = {power}");
+        assert!(output.min_pressure < input().mean_pressure);
+        assert!(output.max_pressure > input().mean_pressure);
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_inputs() {
+        assert!(matches!(
+            StirlingEngine::new(StirlingParameters {
+                cooler_volume: cc(-1.0),
+                ..parameters()
+            }),
+            Err(StirlingError::InvalidParameter(_))
+        ));
+
+        let engine = StirlingEngine::new(parameters()).unwrap();
+        assert!(matches!(
+            engine.evaluate(&StirlingInput {
+                hot_temperature: input().cold_temperature,
+                ..input()
+            }),
+            Err(StirlingError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            engine.evaluate(&StirlingInput {
+                frequency: Frequency::new::<hertz>(0.0),
+                ..input()
+            }),
+            Err(StirlingError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,6 @@
+//! Ground heat exchangers.
+
+pub mod borefield;
+pub mod horizontal;
+
+mod response;
@@ -0,0 +1,91 @@
+//! Vertical borehole fields for ground-source heat pumps.
+//!
+//! A [`Borefield`] exchanges heat between a circulating fluid and the
+//! ground through a field of vertical boreholes. Its response to a load
+//! comes from a g-function that superposes the finite line source of every
+//! borehole on every other, so a dense field responds more strongly than the
+//! same boreholes spread apart, and heat rejected in excess of heat
+//! extracted warms the ground a little more each year.
+//!
+//! [`Borefield::simulate`] runs a whole load profile, aggregating older
+//! loads so that hourly simulations spanning decades stay fast. To couple
+//! the field with a heat pump one step at a time, start a [`LoadHistory`]
+//! with [`Borefield::history`] and advance it with [`Borefield::step`].
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::ground::borefield::{
+//!         Borefield, BorefieldInput, BorefieldLayout, BorefieldParameters, Borehole, Ground,
+//!     },
+//!     support::units::{LinearThermalConductance, ThermalDiffusivity},
+//! };
+//! use uom::si::{
+//!     diffusion_coefficient::square_meter_per_second,
+//!     f64::{
+//!         Length, Power, ThermalConductance, ThermalConductivity, ThermodynamicTemperature, Time,
+//!     },
+//!     length::{meter, millimeter},
+//!     power::kilowatt,
+//!     thermal_conductance::kilowatt_per_kelvin,
+//!     thermal_conductivity::watt_per_meter_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//!     time::day,
+//! };
+//!
+//! let field = Borefield::new(BorefieldParameters {
+//!     layout: BorefieldLayout::Rectangle {
+//!         rows: 2,
+//!         columns: 3,
+//!         spacing: Length::new::<meter>(6.0),
+//!     },
+//!     borehole: Borehole {
+//!         length: Length::new::<meter>(120.0),
+//!         buried_depth: Length::new::<meter>(2.0),
+//!         radius: Length::new::<millimeter>(75.0),
+//!         conductance: LinearThermalConductance::new::<watt_per_meter_kelvin>(10.0),
+//!     },
+//!     ground: Ground {
+//!         conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(2.5),
+//!         diffusivity: ThermalDiffusivity::new::<square_meter_per_second>(1e-6),
+//!         undisturbed_temperature: ThermodynamicTemperature::new::<degree_celsius>(12.0),
+//!     },
+//! })?;
+//!
+//! // Two years of daily steps rejecting more heat than is extracted.
+//! let heat_rates = (0..730)
+//!     .map(|d| {
+//!         let season = (2.0 * std::f64::consts::PI * f64::from(d) / 365.0).cos();
+//!         Power::new::<kilowatt>(5.0 - 20.0 * season)
+//!     })
+//!     .collect();
+//! let output = field.simulate(&BorefieldInput {
+//!     heat_rates,
+//!     time_step: Time::new::<day>(1.0),
+//!     fluid_capacitance_rate: ThermalConductance::new::<kilowatt_per_kelvin>(4.0),
+//! })?;
+//!
+//! assert!(output.ground_drift.value > 0.0);
+//! assert!(output.min_outlet_temperature.get::<degree_celsius>() > 0.0);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    Borefield, BorefieldError, BorefieldInput, BorefieldLayout, BorefieldOutput,
+    BorefieldParameters, BorefieldStep, Borehole, Ground, LoadHistory,
+};
+
+impl Model for Borefield {
+    type Input = BorefieldInput;
+    type Output = BorefieldOutput;
+    type Error = BorefieldError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.simulate(input)
+    }
+}
@@ -0,0 +1,700 @@
+mod g_function;
+
+use std::f64::consts::PI;
+
+use thiserror::Error;
+use uom::si::{
+    f64::{
+        Length, Power, Ratio, TemperatureInterval, ThermalConductance, ThermalConductivity,
+        ThermodynamicTemperature, Time,
+    },
+    length::meter,
+    ratio::ratio,
+    temperature_interval::kelvin as delta_kelvin,
+    thermal_conductivity::watt_per_meter_kelvin,
+    time::{second, year},
+};
+
+use crate::{
+    models::thermal::ground::response::ResponseTable,
+    support::{
+        constraint::{Constraint, NonNegative, StrictlyPositive},
+        units::{LinearThermalConductance, TemperatureDifference, ThermalDiffusivity},
+    },
+};
+
+pub use crate::models::thermal::ground::response::LoadHistory;
+
+use g_function::{Geometry, field_response};
+
+/// Arrangement of the boreholes at the surface.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BorefieldLayout {
+    /// Boreholes in a single evenly spaced row.
+    Line {
+        /// Number of boreholes.
+        count: usize,
+
+        /// Distance between neighbors.
+        spacing: Length,
+    },
+
+    /// Boreholes on a square grid.
+    Rectangle {
+        /// Number of rows.
+        rows: usize,
+
+        /// Number of boreholes in each row.
+        columns: usize,
+
+        /// Distance between neighbors along rows and columns.
+        spacing: Length,
+    },
+
+    /// Boreholes at arbitrary surface positions `[x, y]`.
+    Custom(Vec<[Length; 2]>),
+}
+
+impl BorefieldLayout {
+    /// Returns the surface position `[x, y]` of every borehole.
+    #[must_use]
+    // Row and column indices are far below 2^52, so the casts are exact.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn positions(&self) -> Vec<[Length; 2]> {
+        match *self {
+            Self::Line { count, spacing } => (0..count)
+                .map(|i| [spacing * i as f64, Length::new::<meter>(0.0)])
+                .collect(),
+            Self::Rectangle {
+                rows,
+                columns,
+                spacing,
+            } => (0..rows)
+                .flat_map(|row| {
+                    (0..columns).map(move |column| [spacing * column as f64, spacing * row as f64])
+                })
+                .collect(),
+            Self::Custom(ref positions) => positions.clone(),
+        }
+    }
+}
+
+/// Geometry and thermal resistance of each borehole.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Borehole {
+    /// Active length exchanging heat with the ground.
+    pub length: Length,
+
+    /// Depth of the top of the active length below the surface.
+    pub buried_depth: Length,
+
+    /// Borehole radius.
+    pub radius: Length,
+
+    /// Conductance per length between the mean fluid temperature and the
+    /// borehole wall, the inverse of the effective borehole thermal
+    /// resistance `R_b`.
+    pub conductance: LinearThermalConductance,
+}
+
+/// Thermal properties of the ground.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ground {
+    /// Thermal conductivity.
+    pub conductivity: ThermalConductivity,
+
+    /// Thermal diffusivity.
+    pub diffusivity: ThermalDiffusivity,
+
+    /// Undisturbed temperature of the ground over the borehole depth.
+    pub undisturbed_temperature: ThermodynamicTemperature,
+}
+
+/// Fixed parameters of a [`Borefield`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BorefieldParameters {
+    /// Surface arrangement of the boreholes.
+    pub layout: BorefieldLayout,
+
+    /// Each borehole, all identical.
+    pub borehole: Borehole,
+
+    /// Surrounding ground.
+    pub ground: Ground,
+}
+
+/// Errors from [`Borefield`] construction and evaluation.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum BorefieldError {
+    /// A borefield parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating input is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+}
+
+/// A field of vertical ground heat exchanger boreholes.
+///
+/// The ground responds to the field's heat load through a g-function built
+/// by spatial superposition: the finite line source response of every
+/// borehole is added onto every other, so neighboring boreholes warm or cool
+/// each other and the field's long-term temperature drift grows with its
+/// size and density. Each borehole carries the same heat rate per length,
+/// and the reported wall temperature is the field mean.
+///
+/// Loads are applied in fixed time steps and superposed in time through a
+/// [`LoadHistory`], which aggregates older loads so that multi-year hourly
+/// simulations stay fast. The circulating fluid's mean temperature sits
+/// above the wall by the heat rate per length over the borehole
+/// conductance, and the inlet and outlet temperatures straddle it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Borefield {
+    parameters: BorefieldParameters,
+    boreholes: usize,
+    g: ResponseTable,
+}
+
+/// Input to the borefield model: a load profile in fixed time steps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BorefieldInput {
+    /// Heat rejected to the ground in each time step.
+    ///
+    /// Negative while heat is extracted.
+    pub heat_rates: Vec<Power>,
+
+    /// Length of each time step.
+    pub time_step: Time,
+
+    /// Heat capacity rate of the fluid circulating through the field.
+    pub fluid_capacitance_rate: ThermalConductance,
+}
+
+/// Temperatures at the end of one time step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BorefieldStep {
+    /// Mean borehole wall temperature.
+    pub wall_temperature: ThermodynamicTemperature,
+
+    /// Mean temperature of the circulating fluid.
+    pub fluid_temperature: ThermodynamicTemperature,
+
+    /// Fluid temperature entering the field.
+    pub inlet_temperature: ThermodynamicTemperature,
+
+    /// Fluid temperature leaving the field.
+    pub outlet_temperature: ThermodynamicTemperature,
+}
+
+/// Output from the borefield model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BorefieldOutput {
+    /// Temperatures at the end of each time step.
+    pub steps: Vec<BorefieldStep>,
+
+    /// Rise of the mean wall temperature above the undisturbed ground,
+    /// averaged over the final year of the profile, or over the whole
+    /// profile if it is shorter.
+    ///
+    /// Averaging over a year cancels the seasonal swing, leaving the drift
+    /// that an imbalance between heat rejected and extracted accumulates
+    /// year after year.
+    pub ground_drift: TemperatureInterval,
+
+    /// Lowest fluid temperature leaving the field.
+    pub min_outlet_temperature: ThermodynamicTemperature,
+
+    /// Highest fluid temperature leaving the field.
+    pub max_outlet_temperature: ThermodynamicTemperature,
+}
+
+impl Borefield {
+    /// Creates a borefield and computes its g-function.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BorefieldError::InvalidParameter`] if the layout has no
+    /// boreholes or places two closer than a borehole diameter, or if any
+    /// dimension or property is out of range.
+    pub fn new(parameters: BorefieldParameters) -> Result<Self, BorefieldError> {
+        let BorefieldParameters {
+            ref layout,
+            borehole,
+            ground,
+        } = parameters;
+
+        if StrictlyPositive::check(&borehole.length).is_err() || !borehole.length.is_finite() {
+            return Err(BorefieldError::InvalidParameter(
+                "borehole length must be strictly positive and finite",
+            ));
+        }
+        if NonNegative::check(&borehole.buried_depth).is_err() || !borehole.buried_depth.is_finite()
+        {
+            return Err(BorefieldError::InvalidParameter(
+                "buried depth must be non-negative and finite",
+            ));
+        }
+        if StrictlyPositive::check(&borehole.radius).is_err() || borehole.radius >= borehole.length
+        {
+            return Err(BorefieldError::InvalidParameter(
+                "borehole radius must be strictly positive and less than its length",
+            ));
+        }
+        if StrictlyPositive::check(&borehole.conductance).is_err()
+            || !borehole.conductance.is_finite()
+        {
+            return Err(BorefieldError::InvalidParameter(
+                "borehole conductance must be strictly positive and finite",
+            ));
+        }
+        if StrictlyPositive::check(&ground.conductivity).is_err()
+            || !ground.conductivity.is_finite()
+        {
+            return Err(BorefieldError::InvalidParameter(
+                "ground conductivity must be strictly positive and finite",
+            ));
+        }
+        if StrictlyPositive::check(&ground.diffusivity).is_err() || !ground.diffusivity.is_finite()
+        {
+            return Err(BorefieldError::InvalidParameter(
+                "ground diffusivity must be strictly positive and finite",
+            ));
+        }
+        if !ground.undisturbed_temperature.is_finite() {
+            return Err(BorefieldError::InvalidParameter(
+                "undisturbed ground temperature must be finite",
+            ));
+        }
+
+        let positions: Vec<[f64; 2]> = layout
+            .positions()
+            .iter()
+            .map(|[x, y]| [x.get::<meter>(), y.get::<meter>()])
+            .collect();
+        if positions.is_empty() {
+            return Err(BorefieldError::InvalidParameter(
+                "layout must have at least one borehole",
+            ));
+        }
+        if positions.iter().flatten().any(|v| !v.is_finite()) {
+            return Err(BorefieldError::InvalidParameter(
+                "borehole positions must be finite",
+            ));
+        }
+        let diameter = 2.0 * borehole.radius.get::<meter>();
+        for (i, a) in positions.iter().enumerate() {
+            if positions[i + 1..]
+                .iter()
+                .any(|b| (a[0] - b[0]).hypot(a[1] - b[1]) < diameter)
+            {
+                return Err(BorefieldError::InvalidParameter(
+                    "boreholes must be at least a diameter apart",
+                ));
+            }
+        }
+
+        let g = field_response(
+            &positions,
+            Geometry {
+                length: borehole.length.get::<meter>(),
+                buried_depth: borehole.buried_depth.get::<meter>(),
+                radius: borehole.radius.get::<meter>(),
+            },
+            ground.diffusivity.value,
+        );
+
+        Ok(Self {
+            boreholes: positions.len(),
+            parameters,
+            g,
+        })
+    }
+
+    /// Returns the borefield parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &BorefieldParameters {
+        &self.parameters
+    }
+
+    /// Returns the number of boreholes.
+    #[must_use]
+    pub fn boreholes(&self) -> usize {
+        self.boreholes
+    }
+
+    /// Returns the field g-function at `time` after a step in load, the
+    /// mean wall temperature rise scaled by `2πk / q′`.
+    #[must_use]
+    pub fn g_function(&self, time: Time) -> Ratio {
+        Ratio::new::<ratio>(self.g.eval(time.get::<second>()))
+    }
+
+    /// Starts an empty load history for stepping the field one time step at
+    /// a time.
+    ///
+    /// The history remembers loads at least `horizon` into the past; set it
+    /// to the length of the simulation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BorefieldError::InvalidInput`] if the time step is not
+    /// strictly positive and finite or the horizon is negative.
+    pub fn history(&self, time_step: Time, horizon: Time) -> Result<LoadHistory, BorefieldError> {
+        if StrictlyPositive::check(&time_step).is_err() || !time_step.is_finite() {
+            return Err(BorefieldError::InvalidInput(
+                "time step must be strictly positive and finite",
+            ));
+        }
+        if NonNegative::check(&horizon).is_err() || !horizon.is_finite() {
+            return Err(BorefieldError::InvalidInput(
+                "horizon must be non-negative and finite",
+            ));
+        }
+
+        // Both times were checked finite and non-negative above.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let horizon_steps = (horizon / time_step).get::<ratio>().ceil() as usize;
+        Ok(LoadHistory::new(
+            &self.g,
+            time_step,
+            horizon_steps,
+            2.0 * PI
+                * self
+                    .parameters
+                    .ground
+                    .conductivity
+                    .get::<watt_per_meter_kelvin>(),
+        ))
+    }
+
+    /// Applies `heat_rate` for the next time step of `history` and returns
+    /// the temperatures at the end of it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BorefieldError::InvalidInput`] if the heat rate is not
+    /// finite or the fluid capacitance rate is not strictly positive and
+    /// finite.
+    pub fn step(
+        &self,
+        history: &mut LoadHistory,
+        heat_rate: Power,
+        fluid_capacitance_rate: ThermalConductance,
+    ) -> Result<BorefieldStep, BorefieldError> {
+        if !heat_rate.is_finite() {
+            return Err(BorefieldError::InvalidInput("heat rate must be finite"));
+        }
+        if StrictlyPositive::check(&fluid_capacitance_rate).is_err()
+            || !fluid_capacitance_rate.is_finite()
+        {
+            return Err(BorefieldError::InvalidInput(
+                "fluid capacitance rate must be strictly positive and finite",
+            ));
+        }
+
+        let BorefieldParameters {
+            borehole, ground, ..
+        } = self.parameters;
+        // Boreholes number far below 2^52, so the cast is exact.
+        #[allow(clippy::cast_precision_loss)]
+        let heat_per_length = heat_rate / (borehole.length * self.boreholes as f64);
+        history.push(heat_per_length.value);
+
+        let wall_temperature = ground
+            .undisturbed_temperature
+            .plus(TemperatureInterval::new::<delta_kelvin>(
+                history.temperature_change(),
+            ));
+        let fluid_temperature = wall_temperature.plus(heat_per_length / borehole.conductance);
+        let half_rise: TemperatureInterval = 0.5 * heat_rate / fluid_capacitance_rate;
+        Ok(BorefieldStep {
+            wall_temperature,
+            fluid_temperature,
+            inlet_temperature: fluid_temperature.plus(half_rise),
+            outlet_temperature: fluid_temperature.plus(-half_rise),
+        })
+    }
+
+    /// Simulates the field through a load profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BorefieldError::InvalidInput`] if the profile is empty, or
+    /// any error from [`history`](Self::history) or [`step`](Self::step).
+    // Step counts are far below 2^52, and the time step was checked
+    // strictly positive and finite by `history`.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn simulate(&self, input: &BorefieldInput) -> Result<BorefieldOutput, BorefieldError> {
+        let BorefieldInput {
+            ref heat_rates,
+            time_step,
+            fluid_capacitance_rate,
+        } = *input;
+        if heat_rates.is_empty() {
+            return Err(BorefieldError::InvalidInput(
+                "load profile must have at least one step",
+            ));
+        }
+
+        let mut history = self.history(time_step, time_step * heat_rates.len() as f64)?;
+        let steps = heat_rates
+            .iter()
+            .map(|&heat_rate| self.step(&mut history, heat_rate, fluid_capacitance_rate))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let outlets = steps.iter().map(|step| step.outlet_temperature);
+        let min_outlet_temperature = outlets.clone().reduce(ThermodynamicTemperature::min);
+        let max_outlet_temperature = outlets.reduce(ThermodynamicTemperature::max);
+        let year_steps = (Time::new::<year>(1.0) / time_step).get::<ratio>().round() as usize;
+        let final_year = &steps[steps.len().saturating_sub(year_steps.max(1))..];
+        let undisturbed = self.parameters.ground.undisturbed_temperature;
+        let ground_drift = final_year
+            .iter()
+            .map(|step| step.wall_temperature.minus(undisturbed))
+            .sum::<TemperatureInterval>()
+            / final_year.len() as f64;
+
+        let last = steps[steps.len() - 1];
+        Ok(BorefieldOutput {
+            ground_drift,
+            min_outlet_temperature: min_outlet_temperature.unwrap_or(last.outlet_temperature),
+            max_outlet_temperature: max_outlet_temperature.unwrap_or(last.outlet_temperature),
+            steps,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        diffusion_coefficient::square_meter_per_second, length::millimeter, power::kilowatt,
+        thermal_conductance::kilowatt_per_kelvin, thermodynamic_temperature::degree_celsius,
+        time::hour,
+    };
+
+    const HOURS_PER_YEAR: usize = 8760;
+
+    fn parameters(layout: BorefieldLayout) -> BorefieldParameters {
+        BorefieldParameters {
+            layout,
+            borehole: Borehole {
+                length: Length::new::<meter>(150.0),
+                buried_depth: Length::new::<meter>(2.0),
+                radius: Length::new::<millimeter>(75.0),
+                conductance: LinearThermalConductance::new::<watt_per_meter_kelvin>(10.0),
+            },
+            ground: Ground {
+                conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(2.0),
+                diffusivity: ThermalDiffusivity::new::<square_meter_per_second>(1e-6),
+                undisturbed_temperature: ThermodynamicTemperature::new::<degree_celsius>(10.0),
+            },
+        }
+    }
+
+    fn grid(side: usize) -> Borefield {
+        Borefield::new(parameters(BorefieldLayout::Rectangle {
+            rows: side,
+            columns: side,
+            spacing: Length::new::<meter>(6.0),
+        }))
+        .unwrap()
+    }
+
+    fn fluid() -> ThermalConductance {
+        ThermalConductance::new::<kilowatt_per_kelvin>(10.0)
+    }
+
+    /// An hourly load swinging between summer rejection and winter
+    /// extraction, offset by `imbalance` kW.
+    #[allow(clippy::cast_precision_loss)]
+    fn seasonal(years: usize, imbalance: f64) -> Vec<Power> {
+        (0..years * HOURS_PER_YEAR)
+            .map(|h| {
+                let phase = 2.0 * PI * h as f64 / HOURS_PER_YEAR as f64;
+                Power::new::<kilowatt>(imbalance - 40.0 * phase.cos())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn neighbors_raise_the_long_term_response() {
+        let single = grid(1);
+        let field = grid(3);
+        let one_year = Time::new::<year>(1.0);
+
+        // Within a day the boreholes do not yet see each other.
+        let day = Time::new::<hour>(24.0);
+        assert_relative_eq!(
+            field.g_function(day).get::<ratio>(),
+            single.g_function(day).get::<ratio>(),
+            max_relative = 1e-6
+        );
+        assert!(field.g_function(one_year) > single.g_function(one_year) * 1.3);
+        assert!(field.g_function(one_year * 20.0) > single.g_function(one_year * 20.0) * 2.0);
+    }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn constant_load_follows_the_g_function() {
+        let field = grid(2);
+        let load = Power::new::<kilowatt>(20.0);
+        let hours = 2000;
+        let output = field
+            .simulate(&BorefieldInput {
+                heat_rates: vec![load; hours],
+                time_step: Time::new::<hour>(1.0),
+                fluid_capacitance_rate: fluid(),
+            })
+            .unwrap();
+
+        let q = load.get::<uom::si::power::watt>() / (150.0 * 4.0);
+        let expected = q * field
+            .g_function(Time::new::<hour>(hours as f64))
+            .get::<ratio>()
+            / (2.0 * PI * 2.0);
+        let last = output.steps[hours - 1];
+        assert_relative_eq!(
+            last.wall_temperature
+                .minus(field.parameters().ground.undisturbed_temperature)
+                .get::<delta_kelvin>(),
+            expected,
+            max_relative = 0.02
+        );
+        assert_relative_eq!(
+            last.fluid_temperature
+                .minus(last.wall_temperature)
+                .get::<delta_kelvin>(),
+            q / 10.0,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            last.inlet_temperature
+                .minus(last.outlet_temperature)
+                .get::<delta_kelvin>(),
+            2.0,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn aggregation_tracks_direct_superposition() {
+        let field = grid(2);
+        let loads = seasonal(1, 5.0);
+        let step = Time::new::<hour>(1.0);
+        let output = field
+            .simulate(&BorefieldInput {
+                heat_rates: loads.clone(),
+                time_step: step,
+                fluid_capacitance_rate: fluid(),
+            })
+            .unwrap();
+
+        // Superpose every hourly load step directly at a few instants.
+        let per_length = |p: Power| p.get::<uom::si::power::watt>() / (150.0 * 4.0);
+        for n in [500, 4000, HOURS_PER_YEAR - 1] {
+            let mut rise = 0.0;
+            for (i, load) in loads[..=n].iter().enumerate() {
+                let previous = if i == 0 {
+                    0.0
+                } else {
+                    per_length(loads[i - 1])
+                };
+                let age = step * (n + 1 - i) as f64;
+                rise += (per_length(*load) - previous) * field.g_function(age).get::<ratio>();
+            }
+            rise /= 2.0 * PI * 2.0;
+            let aggregated = output.steps[n]
+                .wall_temperature
+                .minus(field.parameters().ground.undisturbed_temperature)
+                .get::<delta_kelvin>();
+            assert!(
+                (aggregated - rise).abs() < 0.01 * rise.abs().max(1.0),
+                "hour {n}: aggregated {aggregated}, direct {rise}"
+            );
+        }
+    }
+
+    #[test]
+    fn imbalanced_loads_drift_year_over_year() {
+        let field = grid(3);
+        let years = 5;
+        let simulate = |imbalance| {
+            field
+                .simulate(&BorefieldInput {
+                    heat_rates: seasonal(years, imbalance),
+                    time_step: Time::new::<hour>(1.0),
+                    fluid_capacitance_rate: fluid(),
+                })
+                .unwrap()
+        };
+
+        // The wall temperature at the same point of each year.
+        let yearly = |output: &BorefieldOutput| {
+            (1..=years)
+                .map(|y| {
+                    output.steps[y * HOURS_PER_YEAR - 1]
+                        .wall_temperature
+                        .get::<degree_celsius>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let balanced = yearly(&simulate(0.0));
+        let warming = yearly(&simulate(10.0));
+        assert!((balanced[years - 1] - balanced[0]).abs() < 0.1);
+        assert!(warming.windows(2).all(|w| w[1] > w[0]));
+        assert!(warming[years - 1] - warming[0] > 0.5);
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_inputs() {
+        assert!(matches!(
+            Borefield::new(parameters(BorefieldLayout::Line {
+                count: 0,
+                spacing: Length::new::<meter>(6.0),
+            })),
+            Err(BorefieldError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            Borefield::new(parameters(BorefieldLayout::Custom(vec![
+                [Length::new::<meter>(0.0), Length::new::<meter>(0.0)],
+                [Length::new::<meter>(0.1), Length::new::<meter>(0.0)],
+            ]))),
+            Err(BorefieldError::InvalidParameter(_))
+        ));
+
+        let field = grid(1);
+        assert!(matches!(
+            field.simulate(&BorefieldInput {
+                heat_rates: vec![],
+                time_step: Time::new::<hour>(1.0),
+                fluid_capacitance_rate: fluid(),
+            }),
+            Err(BorefieldError::InvalidInput(_))
+        ));
+        let mut history = field
+            .history(Time::new::<hour>(1.0), Time::new::<hour>(10.0))
+            .unwrap();
+        assert!(matches!(
+            field.step(
+                &mut history,
+                Power::new::<kilowatt>(1.0),
+                ThermalConductance::new::<kilowatt_per_kelvin>(0.0)
+            ),
+            Err(BorefieldError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,149 @@
+//! Finite line source g-functions.
+
+use std::f64::consts::PI;
+
+use crate::{models::thermal::ground::response::ResponseTable, support::numerics::special::erf};
+
+/// Subintervals of the Simpson's rule integration in `ln s`.
+const INTERVALS: usize = 400;
+
+/// Value of `d²·s²` beyond which the integrand is negligible.
+const CUTOFF: f64 = 40.0;
+
+/// Range and spacing of the tabulated `ln(t / tₛ)`.
+const LN_MIN: f64 = -20.0;
+const LN_MAX: f64 = 3.0;
+const LN_STEP: f64 = 0.25;
+
+/// Borehole geometry, in meters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct Geometry {
+    pub(super) length: f64,
+    pub(super) buried_depth: f64,
+    pub(super) radius: f64,
+}
+
+/// Superposes the finite line source response of every borehole on every
+/// other, with the same heat rate per length in each.
+///
+/// The result is the mean wall temperature response of the field, so
+/// `ΔT = q′·g / (2πk)`.
+pub(super) fn field_response(
+    positions: &[[f64; 2]],
+    geometry: Geometry,
+    diffusivity: f64,
+) -> ResponseTable {
+    // Group the pair distances so each is integrated once.
+    let mut distances = Vec::new();
+    for (i, a) in positions.iter().enumerate() {
+        for b in &positions[i + 1..] {
+            distances.push((a[0] - b[0]).hypot(a[1] - b[1]));
+        }
+    }
+    distances.sort_by(f64::total_cmp);
+    let mut pairs: Vec<(f64, f64)> = Vec::new();
+    for d in distances {
+        match pairs.last_mut() {
+            Some((last, count)) if d - *last <= 1e-9 * d => *count += 2.0,
+            _ => pairs.push((d, 2.0)),
+        }
+    }
+    // Boreholes number far below 2^52, so the cast is exact.
+    #[allow(clippy::cast_precision_loss)]
+    let count = positions.len() as f64;
+    pairs.push((geometry.radius, count));
+
+    let ln_characteristic = (geometry.length * geometry.length / (9.0 * diffusivity)).ln();
+    // The table spans a fixed, positive range of `ln t`.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let points = ((LN_MAX - LN_MIN) / LN_STEP).round() as usize + 1;
+    ResponseTable::tabulate(ln_characteristic + LN_MIN, LN_STEP, points, |time| {
+        pairs
+            .iter()
+            .map(|&(d, n)| n * finite_line_source(geometry, d, diffusivity * time))
+            .sum::<f64>()
+            / count
+    })
+}
+
+/// Mean temperature response along one borehole to a unit line load on
+/// another `distance` away, after the ground has diffused for `alpha_t`
+/// (diffusivity times time, m²).
+///
+/// Uses the integral form of Claesson and Javed (2011), with the mirror
+/// source above the ground surface that holds it at the undisturbed
+/// temperature. Pass the borehole radius as `distance` for the response of a
+/// borehole to itself.
+pub(super) fn finite_line_source(geometry: Geometry, distance: f64, alpha_t: f64) -> f64 {
+    let lower = -0.5 * (4.0 * alpha_t).ln();
+    let upper = (CUTOFF.sqrt() / distance).ln();
+    if lower >= upper {
+        return 0.0;
+    }
+
+    let Geometry {
+        length,
+        buried_depth,
+        ..
+    } = geometry;
+    let integrand = |u: f64| {
+        let s = u.exp();
+        let h = length * s;
+        let d = buried_depth * s;
+        let y = 2.0 * ierf(h) + 2.0 * ierf(h + 2.0 * d) - ierf(2.0 * h + 2.0 * d) - ierf(2.0 * d);
+        0.5 * (-(distance * s).powi(2)).exp() * y / h
+    };
+
+    // Simpson's rule uses a handful of intervals, so the casts are exact.
+    #[allow(clippy::cast_precision_loss)]
+    let width = (upper - lower) / INTERVALS as f64;
+    let interior: f64 = (1..INTERVALS)
+        .map(|i| {
+            let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+            #[allow(clippy::cast_precision_loss)]
+            let position = lower + i as f64 * width;
+            weight * integrand(position)
+        })
+        .sum();
+    (integrand(lower) + interior + integrand(upper)) * width / 3.0
+}
+
+/// Integral of the error function, `x·erf(x) − (1 − exp(−x²)) / √π`.
+fn ierf(x: f64) -> f64 {
+    x * erf(x) + (-x * x).exp_m1() / PI.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    const GEOMETRY: Geometry = Geometry {
+        length: 150.0,
+        buried_depth: 2.0,
+        radius: 0.075,
+    };
+
+    #[test]
+    fn short_times_approach_the_infinite_line_source() {
+        // At r²/4αt = 0.01 the infinite line source gives
+        // g = E₁(0.01)/2 = 4.037_93/2. The ends of the finite borehole
+        // respond a little less.
+        let alpha_t = GEOMETRY.radius.powi(2) / 0.04;
+        assert_relative_eq!(
+            finite_line_source(GEOMETRY, GEOMETRY.radius, alpha_t),
+            4.037_93 / 2.0,
+            max_relative = 2e-3
+        );
+    }
+
+    #[test]
+    fn single_borehole_settles_near_its_steady_value() {
+        // The steady finite line source is close to ln(H / 2r_b).
+        let g = field_response(&[[0.0, 0.0]], GEOMETRY, 1e-6);
+        let steady = (GEOMETRY.length / (2.0 * GEOMETRY.radius)).ln();
+        assert_relative_eq!(g.eval(1e14), steady, max_relative = 0.05);
+        assert!(g.eval(1e9) < g.eval(1e10));
+    }
+}
@@ -0,0 +1,97 @@
+//! Horizontal ground loops for ground-source heat pumps.
+//!
+//! A [`HorizontalLoop`] exchanges heat between a circulating fluid and the
+//! shallow ground through pipe buried a meter or two down, either as
+//! straight runs along a trench or as a slinky coil of overlapping loops.
+//! Shallow ground is cheap to reach but far from steady: its undisturbed
+//! temperature swings through the year, following the Kusuda profile of a
+//! [`SeasonalGround`], so the loop is coldest in late winter just when the
+//! heat pump draws hardest on it.
+//!
+//! The pipe's response superposes line sources and their images above the
+//! surface, so pipes sharing a trench and the crowded loops of a slinky
+//! interfere with each other. [`HorizontalLoop::simulate`] runs a whole load
+//! profile; to couple the loop with a heat pump one step at a time, start a
+//! [`LoadHistory`] with [`HorizontalLoop::history`] and advance it with
+//! [`HorizontalLoop::step`].
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::ground::horizontal::{
+//!         GroundPipe, HorizontalLayout, HorizontalLoop, HorizontalLoopInput,
+//!         HorizontalLoopParameters, SeasonalGround, SlinkyOrientation,
+//!     },
+//!     support::units::{LinearThermalConductance, ThermalDiffusivity},
+//! };
+//! use uom::si::{
+//!     diffusion_coefficient::square_meter_per_second,
+//!     f64::{
+//!         Length, Power, TemperatureInterval, ThermalConductance, ThermalConductivity,
+//!         ThermodynamicTemperature, Time,
+//!     },
+//!     length::{meter, millimeter},
+//!     power::kilowatt,
+//!     temperature_interval::kelvin,
+//!     thermal_conductance::kilowatt_per_kelvin,
+//!     thermal_conductivity::watt_per_meter_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//!     time::day,
+//! };
+//!
+//! let ground_loop = HorizontalLoop::new(HorizontalLoopParameters {
+//!     layout: HorizontalLayout::Slinky {
+//!         loops: 60,
+//!         diameter: Length::new::<meter>(0.9),
+//!         pitch: Length::new::<meter>(0.45),
+//!         depth: Length::new::<meter>(1.8),
+//!         orientation: SlinkyOrientation::Flat,
+//!     },
+//!     pipe: GroundPipe {
+//!         radius: Length::new::<millimeter>(16.0),
+//!         conductance: LinearThermalConductance::new::<watt_per_meter_kelvin>(5.0),
+//!     },
+//!     ground: SeasonalGround {
+//!         conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(1.5),
+//!         diffusivity: ThermalDiffusivity::new::<square_meter_per_second>(6e-7),
+//!         mean_temperature: ThermodynamicTemperature::new::<degree_celsius>(10.0),
+//!         amplitude: TemperatureInterval::new::<kelvin>(10.0),
+//!         coldest_time: Time::new::<day>(35.0),
+//!     },
+//! })?;
+//!
+//! // A winter of daily heat extraction starting in November.
+//! let output = ground_loop.simulate(&HorizontalLoopInput {
+//!     heat_rates: vec![Power::new::<kilowatt>(-0.6); 120],
+//!     time_step: Time::new::<day>(1.0),
+//!     start: Time::new::<day>(305.0),
+//!     fluid_capacitance_rate: ThermalConductance::new::<kilowatt_per_kelvin>(1.0),
+//! })?;
+//!
+//! // The fluid leaves colder than the ground it passed through.
+//! let last = output.steps[119];
+//! assert!(last.outlet_temperature < last.undisturbed_temperature);
+//! assert!(output.min_outlet_temperature.get::<degree_celsius>() > -5.0);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    GroundPipe, HorizontalLayout, HorizontalLoop, HorizontalLoopError, HorizontalLoopInput,
+    HorizontalLoopOutput, HorizontalLoopParameters, HorizontalLoopStep, LoadHistory,
+    SeasonalGround, SlinkyOrientation,
+};
+
+impl Model for HorizontalLoop {
+    type Input = HorizontalLoopInput;
+    type Output = HorizontalLoopOutput;
+    type Error = HorizontalLoopError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.simulate(input)
+    }
+}
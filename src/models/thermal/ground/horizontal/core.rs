@@ -0,0 +1,820 @@
+mod source;
+
+use std::f64::consts::PI;
+
+use thiserror::Error;
+use uom::si::{
+    f64::{
+        Length, Power, Ratio, TemperatureInterval, ThermalConductance, ThermalConductivity,
+        ThermodynamicTemperature, Time,
+    },
+    length::meter,
+    ratio::ratio,
+    temperature_interval::kelvin as delta_kelvin,
+    thermal_conductivity::watt_per_meter_kelvin,
+    time::{second, year},
+};
+
+use crate::{
+    models::thermal::ground::response::ResponseTable,
+    support::{
+        constraint::{Constraint, NonNegative, StrictlyPositive},
+        units::{LinearThermalConductance, TemperatureDifference, ThermalDiffusivity},
+    },
+};
+
+pub use crate::models::thermal::ground::response::LoadHistory;
+
+use source::Sources;
+
+/// How a slinky coil's loops sit in the trench.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SlinkyOrientation {
+    /// Loops lying flat on the floor of a wide trench.
+    Flat,
+
+    /// Loops standing on edge in a narrow trench.
+    Upright,
+}
+
+/// Arrangement of the buried pipe.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HorizontalLayout {
+    /// Straight pipes running the length of a trench.
+    Trench {
+        /// Length of the trench, and of each pipe in it.
+        length: Length,
+
+        /// Position `[offset, depth]` of each pipe across the trench, with
+        /// the offset measured horizontally and the depth below the surface.
+        pipes: Vec<[Length; 2]>,
+    },
+
+    /// A slinky coil of overlapping circular loops stretched along a
+    /// trench.
+    Slinky {
+        /// Number of loops.
+        loops: usize,
+
+        /// Diameter of each loop.
+        diameter: Length,
+
+        /// Distance between the centers of neighboring loops, less than the
+        /// diameter when the loops overlap.
+        pitch: Length,
+
+        /// Depth of the loop centers below the surface.
+        depth: Length,
+
+        /// Whether the loops lie flat or stand upright.
+        orientation: SlinkyOrientation,
+    },
+}
+
+impl HorizontalLayout {
+    /// Returns the total length of buried pipe.
+    #[must_use]
+    // Pipe and loop counts are far below 2^52, so the casts are exact.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn pipe_length(&self) -> Length {
+        match *self {
+            Self::Trench { length, ref pipes } => length * pipes.len() as f64,
+            Self::Slinky {
+                loops, diameter, ..
+            } => PI * diameter * loops as f64,
+        }
+    }
+}
+
+/// Geometry and thermal resistance of the buried pipe.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroundPipe {
+    /// Outer radius of the pipe.
+    pub radius: Length,
+
+    /// Conductance per length between the mean fluid temperature and the
+    /// pipe's outer wall.
+    pub conductance: LinearThermalConductance,
+}
+
+/// Thermal properties of shallow ground and its seasonal temperature swing.
+///
+/// The undisturbed temperature follows the Kusuda and Achenbach (1965)
+/// profile: a sinusoidal annual swing at the surface that is damped and
+/// delayed as it diffuses downward.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeasonalGround {
+    /// Thermal conductivity.
+    pub conductivity: ThermalConductivity,
+
+    /// Thermal diffusivity.
+    pub diffusivity: ThermalDiffusivity,
+
+    /// Annual mean surface temperature.
+    pub mean_temperature: ThermodynamicTemperature,
+
+    /// Amplitude of the annual swing in surface temperature.
+    pub amplitude: TemperatureInterval,
+
+    /// Time of year, from the start of January, when the surface is
+    /// coldest.
+    pub coldest_time: Time,
+}
+
+impl SeasonalGround {
+    /// Returns the undisturbed ground temperature at `depth` below the
+    /// surface and `time` from the start of the year.
+    ///
+    /// The swing decays as `exp(−z/d)` and lags by `z/d` radians, where
+    /// `d = √(α·P/π)` is the damping depth of the annual period `P`.
+    #[must_use]
+    pub fn temperature(&self, depth: Length, time: Time) -> ThermodynamicTemperature {
+        let period = Time::new::<year>(1.0).get::<second>();
+        let damping = (self.diffusivity.value * period / PI).sqrt();
+        let z = depth.get::<meter>() / damping;
+        let phase = 2.0 * PI * (time - self.coldest_time).get::<second>() / period - z;
+        self.mean_temperature
+            .plus(-self.amplitude * (-z).exp() * phase.cos())
+    }
+}
+
+/// Fixed parameters of a [`HorizontalLoop`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HorizontalLoopParameters {
+    /// Arrangement of the buried pipe.
+    pub layout: HorizontalLayout,
+
+    /// The pipe itself.
+    pub pipe: GroundPipe,
+
+    /// Surrounding ground.
+    pub ground: SeasonalGround,
+}
+
+/// Errors from [`HorizontalLoop`] construction and evaluation.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum HorizontalLoopError {
+    /// A ground loop parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating input is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+}
+
+/// A horizontal ground loop of straight trench pipes or a slinky coil.
+///
+/// The pipe is built from straight line sources, each paired with a mirror
+/// image above the surface, and their point source responses are superposed
+/// at the pipe wall. Pipes sharing a trench, or the overlapping loops of a
+/// slinky, therefore warm or cool each other, and the surface caps how far
+/// the disturbance can grow. Every part of the pipe carries the same heat
+/// rate per length, and the reported wall temperature is the mean.
+///
+/// The disturbance rides on the undisturbed ground temperature at the pipe
+/// depth, which swings through the year, so the loop's outlet temperature
+/// follows the seasons even at constant load. Loads are applied in fixed
+/// time steps and superposed in time through a [`LoadHistory`], just as for
+/// a vertical [`Borefield`](super::super::borefield::Borefield).
+#[derive(Debug, Clone, PartialEq)]
+pub struct HorizontalLoop {
+    parameters: HorizontalLoopParameters,
+    depths: Vec<Length>,
+    g: ResponseTable,
+}
+
+/// Input to the horizontal loop model: a load profile in fixed time steps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HorizontalLoopInput {
+    /// Heat rejected to the ground in each time step.
+    ///
+    /// Negative while heat is extracted.
+    pub heat_rates: Vec<Power>,
+
+    /// Length of each time step.
+    pub time_step: Time,
+
+    /// Time of year, from the start of January, when the profile begins.
+    pub start: Time,
+
+    /// Heat capacity rate of the fluid circulating through the loop.
+    pub fluid_capacitance_rate: ThermalConductance,
+}
+
+/// Temperatures at the end of one time step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HorizontalLoopStep {
+    /// Undisturbed ground temperature, averaged over the pipe.
+    pub undisturbed_temperature: ThermodynamicTemperature,
+
+    /// Mean pipe wall temperature.
+    pub wall_temperature: ThermodynamicTemperature,
+
+    /// Mean temperature of the circulating fluid.
+    pub fluid_temperature: ThermodynamicTemperature,
+
+    /// Fluid temperature entering the loop.
+    pub inlet_temperature: ThermodynamicTemperature,
+
+    /// Fluid temperature leaving the loop.
+    pub outlet_temperature: ThermodynamicTemperature,
+}
+
+/// Output from the horizontal loop model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HorizontalLoopOutput {
+    /// Temperatures at the end of each time step.
+    pub steps: Vec<HorizontalLoopStep>,
+
+    /// Lowest fluid temperature leaving the loop.
+    pub min_outlet_temperature: ThermodynamicTemperature,
+
+    /// Highest fluid temperature leaving the loop.
+    pub max_outlet_temperature: ThermodynamicTemperature,
+}
+
+impl HorizontalLoop {
+    /// Creates a horizontal loop and computes its response.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HorizontalLoopError::InvalidParameter`] if the layout has
+    /// no pipe, places pipes closer than a diameter apart or out of the
+    /// ground, or if any dimension or property is out of range.
+    pub fn new(parameters: HorizontalLoopParameters) -> Result<Self, HorizontalLoopError> {
+        let HorizontalLoopParameters {
+            ref layout,
+            pipe,
+            ground,
+        } = parameters;
+
+        validate_pipe_and_ground(&pipe, &ground)?;
+
+        let radius = pipe.radius.get::<meter>();
+        let sources = match *layout {
+            HorizontalLayout::Trench { length, ref pipes } => {
+                trench_sources(length, pipes, radius)?
+            }
+            HorizontalLayout::Slinky {
+                loops,
+                diameter,
+                pitch,
+                depth,
+                orientation,
+            } => slinky_sources(loops, diameter, pitch, depth, orientation, radius)?,
+        };
+
+        Ok(Self {
+            depths: sources
+                .targets
+                .iter()
+                .map(|target| Length::new::<meter>(target[2]))
+                .collect(),
+            g: sources.response(ground.diffusivity.value),
+            parameters,
+        })
+    }
+
+    /// Returns the horizontal loop parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &HorizontalLoopParameters {
+        &self.parameters
+    }
+
+    /// Returns the loop's response at `time` after a step in load, the mean
+    /// wall temperature rise scaled by `2πk / q′`.
+    #[must_use]
+    pub fn g_function(&self, time: Time) -> Ratio {
+        Ratio::new::<ratio>(self.g.eval(time.get::<second>()))
+    }
+
+    /// Returns the undisturbed ground temperature averaged over the pipe at
+    /// `time` from the start of the year.
+    #[must_use]
+    pub fn undisturbed_temperature(&self, time: Time) -> ThermodynamicTemperature {
+        let ground = self.parameters.ground;
+        let swing = self
+            .depths
+            .iter()
+            .map(|&depth| {
+                ground
+                    .temperature(depth, time)
+                    .minus(ground.mean_temperature)
+            })
+            .sum::<TemperatureInterval>();
+        // Each pipe contributes one depth, far below 2^52 of them.
+        #[allow(clippy::cast_precision_loss)]
+        let swing = swing / self.depths.len() as f64;
+        ground.mean_temperature.plus(swing)
+    }
+
+    /// Starts an empty load history for stepping the loop one time step at
+    /// a time.
+    ///
+    /// The history remembers loads at least `horizon` into the past; set it
+    /// to the length of the simulation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HorizontalLoopError::InvalidInput`] if the time step is not
+    /// strictly positive and finite or the horizon is negative.
+    pub fn history(
+        &self,
+        time_step: Time,
+        horizon: Time,
+    ) -> Result<LoadHistory, HorizontalLoopError> {
+        if StrictlyPositive::check(&time_step).is_err() || !time_step.is_finite() {
+            return Err(HorizontalLoopError::InvalidInput(
+                "time step must be strictly positive and finite",
+            ));
+        }
+        if NonNegative::check(&horizon).is_err() || !horizon.is_finite() {
+            return Err(HorizontalLoopError::InvalidInput(
+                "horizon must be non-negative and finite",
+            ));
+        }
+
+        // Both times were checked finite and non-negative above.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let horizon_steps = (horizon / time_step).get::<ratio>().ceil() as usize;
+        Ok(LoadHistory::new(
+            &self.g,
+            time_step,
+            horizon_steps,
+            2.0 * PI
+                * self
+                    .parameters
+                    .ground
+                    .conductivity
+                    .get::<watt_per_meter_kelvin>(),
+        ))
+    }
+
+    /// Applies `heat_rate` for the next time step of `history` and returns
+    /// the temperatures at the end of it, which falls at `time` from the
+    /// start of the year.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HorizontalLoopError::InvalidInput`] if the heat rate or time
+    /// is not finite or the fluid capacitance rate is not strictly positive
+    /// and finite.
+    pub fn step(
+        &self,
+        history: &mut LoadHistory,
+        heat_rate: Power,
+        fluid_capacitance_rate: ThermalConductance,
+        time: Time,
+    ) -> Result<HorizontalLoopStep, HorizontalLoopError> {
+        if !heat_rate.is_finite() {
+            return Err(HorizontalLoopError::InvalidInput(
+                "heat rate must be finite",
+            ));
+        }
+        if !time.is_finite() {
+            return Err(HorizontalLoopError::InvalidInput("time must be finite"));
+        }
+        if StrictlyPositive::check(&fluid_capacitance_rate).is_err()
+            || !fluid_capacitance_rate.is_finite()
+        {
+            return Err(HorizontalLoopError::InvalidInput(
+                "fluid capacitance rate must be strictly positive and finite",
+            ));
+        }
+
+        let HorizontalLoopParameters {
+            ref layout, pipe, ..
+        } = self.parameters;
+        let heat_per_length = heat_rate / layout.pipe_length();
+        history.push(heat_per_length.value);
+
+        let undisturbed_temperature = self.undisturbed_temperature(time);
+        let wall_temperature = undisturbed_temperature.plus(
+            TemperatureInterval::new::<delta_kelvin>(history.temperature_change()),
+        );
+        let fluid_temperature = wall_temperature.plus(heat_per_length / pipe.conductance);
+        let half_rise: TemperatureInterval = 0.5 * heat_rate / fluid_capacitance_rate;
+        Ok(HorizontalLoopStep {
+            undisturbed_temperature,
+            wall_temperature,
+            fluid_temperature,
+            inlet_temperature: fluid_temperature.plus(half_rise),
+            outlet_temperature: fluid_temperature.plus(-half_rise),
+        })
+    }
+
+    /// Simulates the loop through a load profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HorizontalLoopError::InvalidInput`] if the profile is empty,
+    /// or any error from [`history`](Self::history) or [`step`](Self::step).
+    pub fn simulate(
+        &self,
+        input: &HorizontalLoopInput,
+    ) -> Result<HorizontalLoopOutput, HorizontalLoopError> {
+        let HorizontalLoopInput {
+            ref heat_rates,
+            time_step,
+            start,
+            fluid_capacitance_rate,
+        } = *input;
+        if heat_rates.is_empty() {
+            return Err(HorizontalLoopError::InvalidInput(
+                "load profile must have at least one step",
+            ));
+        }
+
+        // Step counts are far below 2^52, so the casts are exact.
+        #[allow(clippy::cast_precision_loss)]
+        let mut history = self.history(time_step, time_step * heat_rates.len() as f64)?;
+        let steps = heat_rates
+            .iter()
+            .enumerate()
+            .map(|(i, &heat_rate)| {
+                #[allow(clippy::cast_precision_loss)]
+                let time = start + time_step * (i + 1) as f64;
+                self.step(&mut history, heat_rate, fluid_capacitance_rate, time)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let outlets = steps.iter().map(|step| step.outlet_temperature);
+        let min_outlet_temperature = outlets.clone().reduce(ThermodynamicTemperature::min);
+        let max_outlet_temperature = outlets.reduce(ThermodynamicTemperature::max);
+
+        let last = steps[steps.len() - 1];
+        Ok(HorizontalLoopOutput {
+            min_outlet_temperature: min_outlet_temperature.unwrap_or(last.outlet_temperature),
+            max_outlet_temperature: max_outlet_temperature.unwrap_or(last.outlet_temperature),
+            steps,
+        })
+    }
+}
+
+/// Checks the pipe and ground properties.
+fn validate_pipe_and_ground(
+    pipe: &GroundPipe,
+    ground: &SeasonalGround,
+) -> Result<(), HorizontalLoopError> {
+    if StrictlyPositive::check(&pipe.radius).is_err() || !pipe.radius.is_finite() {
+        return Err(HorizontalLoopError::InvalidParameter(
+            "pipe radius must be strictly positive and finite",
+        ));
+    }
+    if StrictlyPositive::check(&pipe.conductance).is_err() || !pipe.conductance.is_finite() {
+        return Err(HorizontalLoopError::InvalidParameter(
+            "pipe conductance must be strictly positive and finite",
+        ));
+    }
+    if StrictlyPositive::check(&ground.conductivity).is_err() || !ground.conductivity.is_finite() {
+        return Err(HorizontalLoopError::InvalidParameter(
+            "ground conductivity must be strictly positive and finite",
+        ));
+    }
+    if StrictlyPositive::check(&ground.diffusivity).is_err() || !ground.diffusivity.is_finite() {
+        return Err(HorizontalLoopError::InvalidParameter(
+            "ground diffusivity must be strictly positive and finite",
+        ));
+    }
+    if !ground.mean_temperature.is_finite() {
+        return Err(HorizontalLoopError::InvalidParameter(
+            "mean ground temperature must be finite",
+        ));
+    }
+    if NonNegative::check(&ground.amplitude).is_err() || !ground.amplitude.is_finite() {
+        return Err(HorizontalLoopError::InvalidParameter(
+            "surface temperature amplitude must be non-negative and finite",
+        ));
+    }
+    if !ground.coldest_time.is_finite() {
+        return Err(HorizontalLoopError::InvalidParameter(
+            "coldest time of year must be finite",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks a trench layout and builds its line sources.
+fn trench_sources(
+    length: Length,
+    pipes: &[[Length; 2]],
+    radius: f64,
+) -> Result<Sources, HorizontalLoopError> {
+    if StrictlyPositive::check(&length).is_err() || !length.is_finite() {
+        return Err(HorizontalLoopError::InvalidParameter(
+            "trench length must be strictly positive and finite",
+        ));
+    }
+    let pipes: Vec<[f64; 2]> = pipes
+        .iter()
+        .map(|[y, z]| [y.get::<meter>(), z.get::<meter>()])
+        .collect();
+    if pipes.is_empty() {
+        return Err(HorizontalLoopError::InvalidParameter(
+            "trench must have at least one pipe",
+        ));
+    }
+    if pipes
+        .iter()
+        .any(|&[y, z]| !y.is_finite() || !z.is_finite() || z <= radius)
+    {
+        return Err(HorizontalLoopError::InvalidParameter(
+            "pipes must be finitely placed and fully buried",
+        ));
+    }
+    for (i, a) in pipes.iter().enumerate() {
+        if pipes[i + 1..]
+            .iter()
+            .any(|b| (a[0] - b[0]).hypot(a[1] - b[1]) < 2.0 * radius)
+        {
+            return Err(HorizontalLoopError::InvalidParameter(
+                "pipes must be at least a diameter apart",
+            ));
+        }
+    }
+    Ok(Sources::trench(length.get::<meter>(), &pipes, radius))
+}
+
+/// Checks a slinky layout and builds its ring sources.
+fn slinky_sources(
+    loops: usize,
+    diameter: Length,
+    pitch: Length,
+    depth: Length,
+    orientation: SlinkyOrientation,
+    radius: f64,
+) -> Result<Sources, HorizontalLoopError> {
+    if loops == 0 {
+        return Err(HorizontalLoopError::InvalidParameter(
+            "slinky must have at least one loop",
+        ));
+    }
+    if !diameter.is_finite() || diameter.get::<meter>() <= 4.0 * radius {
+        return Err(HorizontalLoopError::InvalidParameter(
+            "loop diameter must be finite and larger than the pipe",
+        ));
+    }
+    if StrictlyPositive::check(&pitch).is_err() || !pitch.is_finite() {
+        return Err(HorizontalLoopError::InvalidParameter(
+            "loop pitch must be strictly positive and finite",
+        ));
+    }
+    let upright = orientation == SlinkyOrientation::Upright;
+    let top = if upright {
+        0.5 * diameter.get::<meter>()
+    } else {
+        0.0
+    };
+    if !depth.is_finite() || depth.get::<meter>() - top <= radius {
+        return Err(HorizontalLoopError::InvalidParameter(
+            "loops must be finitely placed and fully buried",
+        ));
+    }
+    Ok(Sources::slinky(
+        loops,
+        diameter.get::<meter>(),
+        pitch.get::<meter>(),
+        depth.get::<meter>(),
+        upright,
+        radius,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        diffusion_coefficient::square_meter_per_second,
+        length::millimeter,
+        power::kilowatt,
+        thermal_conductance::kilowatt_per_kelvin,
+        thermodynamic_temperature::degree_celsius,
+        time::{day, hour},
+    };
+
+    fn ground() -> SeasonalGround {
+        SeasonalGround {
+            conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(1.5),
+            diffusivity: ThermalDiffusivity::new::<square_meter_per_second>(6e-7),
+            mean_temperature: ThermodynamicTemperature::new::<degree_celsius>(10.0),
+            amplitude: TemperatureInterval::new::<delta_kelvin>(10.0),
+            coldest_time: Time::new::<day>(35.0),
+        }
+    }
+
+    fn trench(pipes: &[[f64; 2]]) -> HorizontalLoopParameters {
+        HorizontalLoopParameters {
+            layout: HorizontalLayout::Trench {
+                length: Length::new::<meter>(100.0),
+                pipes: pipes
+                    .iter()
+                    .map(|&[y, z]| [Length::new::<meter>(y), Length::new::<meter>(z)])
+                    .collect(),
+            },
+            pipe: GroundPipe {
+                radius: Length::new::<millimeter>(16.0),
+                conductance: LinearThermalConductance::new::<watt_per_meter_kelvin>(5.0),
+            },
+            ground: ground(),
+        }
+    }
+
+    fn fluid() -> ThermalConductance {
+        ThermalConductance::new::<kilowatt_per_kelvin>(1.0)
+    }
+
+    #[test]
+    fn seasonal_swing_damps_and_lags_with_depth() {
+        let ground = ground();
+        let surface = Length::new::<meter>(0.0);
+        assert_relative_eq!(
+            ground
+                .temperature(surface, ground.coldest_time)
+                .get::<degree_celsius>(),
+            0.0,
+            epsilon = 1e-9
+        );
+
+        // One damping depth down, the swing shrinks by e and the coldest
+        // moment comes a radian of the year later.
+        let period = Time::new::<year>(1.0);
+        let damping = Length::new::<meter>((6e-7 * period.get::<second>() / PI).sqrt());
+        let lag = period / (2.0 * PI);
+        assert_relative_eq!(
+            ground
+                .temperature(damping, ground.coldest_time + lag)
+                .get::<degree_celsius>(),
+            10.0 - 10.0 / 1.0_f64.exp(),
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn idle_loop_follows_the_undisturbed_ground() {
+        let shallow = HorizontalLoop::new(trench(&[[0.0, 1.0]])).unwrap();
+        let deep = HorizontalLoop::new(trench(&[[0.0, 2.0]])).unwrap();
+        let simulate = |ground_loop: &HorizontalLoop| {
+            ground_loop
+                .simulate(&HorizontalLoopInput {
+                    heat_rates: vec![Power::new::<kilowatt>(0.0); 365],
+                    time_step: Time::new::<day>(1.0),
+                    start: Time::new::<day>(0.0),
+                    fluid_capacitance_rate: fluid(),
+                })
+                .unwrap()
+        };
+
+        let shallow = simulate(&shallow);
+        let deep = simulate(&deep);
+        for step in &shallow.steps {
+            assert_eq!(step.outlet_temperature, step.undisturbed_temperature);
+        }
+        let range = |output: &HorizontalLoopOutput| {
+            output
+                .max_outlet_temperature
+                .minus(output.min_outlet_temperature)
+        };
+        assert!(range(&deep) < range(&shallow));
+        assert!(range(&shallow).get::<delta_kelvin>() < 20.0);
+    }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn constant_load_follows_the_response() {
+        let ground_loop = HorizontalLoop::new(trench(&[[0.0, 1.5]])).unwrap();
+        let load = Power::new::<kilowatt>(-2.0);
+        let hours = 1000;
+        let output = ground_loop
+            .simulate(&HorizontalLoopInput {
+                heat_rates: vec![load; hours],
+                time_step: Time::new::<hour>(1.0),
+                start: Time::new::<day>(0.0),
+                fluid_capacitance_rate: fluid(),
+            })
+            .unwrap();
+
+        let q = -2000.0 / 100.0;
+        let expected = q * ground_loop
+            .g_function(Time::new::<hour>(hours as f64))
+            .get::<ratio>()
+            / (2.0 * PI * 1.5);
+        let last = output.steps[hours - 1];
+        assert_relative_eq!(
+            last.wall_temperature
+                .minus(last.undisturbed_temperature)
+                .get::<delta_kelvin>(),
+            expected,
+            max_relative = 0.02
+        );
+        assert_relative_eq!(
+            last.fluid_temperature
+                .minus(last.wall_temperature)
+                .get::<delta_kelvin>(),
+            q / 5.0,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            last.outlet_temperature
+                .minus(last.inlet_temperature)
+                .get::<delta_kelvin>(),
+            2.0,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn pipes_sharing_a_trench_interfere() {
+        let single = HorizontalLoop::new(trench(&[[0.0, 1.5]])).unwrap();
+        let pair = HorizontalLoop::new(trench(&[[-0.15, 1.5], [0.15, 1.5]])).unwrap();
+        let early = Time::new::<hour>(2.0);
+        let month = Time::new::<day>(30.0);
+        assert_relative_eq!(
+            pair.g_function(early).get::<ratio>(),
+            single.g_function(early).get::<ratio>(),
+            max_relative = 1e-3
+        );
+        assert!(pair.g_function(month) > single.g_function(month) * 1.2);
+    }
+
+    #[test]
+    fn slinky_packs_more_pipe_into_a_trench() {
+        let parameters = |pitch: f64| HorizontalLoopParameters {
+            layout: HorizontalLayout::Slinky {
+                loops: 20,
+                diameter: Length::new::<meter>(0.9),
+                pitch: Length::new::<meter>(pitch),
+                depth: Length::new::<meter>(1.5),
+                orientation: SlinkyOrientation::Upright,
+            },
+            ..trench(&[])
+        };
+        let compact = HorizontalLoop::new(parameters(0.3)).unwrap();
+        let extended = HorizontalLoop::new(parameters(0.9)).unwrap();
+        assert_relative_eq!(
+            compact.parameters().layout.pipe_length().get::<meter>(),
+            20.0 * PI * 0.9,
+            max_relative = 1e-12
+        );
+
+        // Closer loops interfere more, so each meter of pipe responds more.
+        let month = Time::new::<day>(30.0);
+        assert!(compact.g_function(month) > extended.g_function(month));
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_inputs() {
+        assert!(matches!(
+            HorizontalLoop::new(trench(&[])),
+            Err(HorizontalLoopError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            HorizontalLoop::new(trench(&[[0.0, 1.5], [0.01, 1.5]])),
+            Err(HorizontalLoopError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            HorizontalLoop::new(HorizontalLoopParameters {
+                layout: HorizontalLayout::Slinky {
+                    loops: 10,
+                    diameter: Length::new::<meter>(0.9),
+                    pitch: Length::new::<meter>(0.3),
+                    depth: Length::new::<meter>(0.4),
+                    orientation: SlinkyOrientation::Upright,
+                },
+                ..trench(&[])
+            }),
+            Err(HorizontalLoopError::InvalidParameter(_))
+        ));
+
+        let ground_loop = HorizontalLoop::new(trench(&[[0.0, 1.5]])).unwrap();
+        assert!(matches!(
+            ground_loop.simulate(&HorizontalLoopInput {
+                heat_rates: vec![],
+                time_step: Time::new::<hour>(1.0),
+                start: Time::new::<day>(0.0),
+                fluid_capacitance_rate: fluid(),
+            }),
+            Err(HorizontalLoopError::InvalidInput(_))
+        ));
+        let mut history = ground_loop
+            .history(Time::new::<hour>(1.0), Time::new::<hour>(10.0))
+            .unwrap();
+        assert!(matches!(
+            ground_loop.step(
+                &mut history,
+                Power::new::<kilowatt>(1.0),
+                ThermalConductance::new::<kilowatt_per_kelvin>(0.0),
+                Time::new::<day>(0.0),
+            ),
+            Err(HorizontalLoopError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,240 @@
+//! Buried pipes as chains of straight line sources.
+
+use std::f64::consts::PI;
+
+use crate::{models::thermal::ground::response::ResponseTable, support::numerics::special::erfc};
+
+/// Straight segments approximating each slinky loop.
+const SEGMENTS_PER_LOOP: usize = 16;
+
+/// Points along each straight trench pipe where its wall is sampled.
+const TRENCH_TARGETS: usize = 5;
+
+/// Subintervals of the Simpson's rule integration along each segment.
+const INTERVALS: usize = 32;
+
+/// Value of `r / √(4αt)` beyond which a point source is negligible.
+const CUTOFF: f64 = 6.0;
+
+/// Range and spacing of the tabulated `ln t`, from a minute to a century.
+const LN_MIN: f64 = 4.094;
+const LN_MAX: f64 = 21.87;
+const LN_STEP: f64 = 0.25;
+
+/// Pipes laid out as straight line sources, with the points on their walls
+/// where the temperature response is averaged. Coordinates are in meters,
+/// with `z` the depth below the surface.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Sources {
+    segments: Vec<[[f64; 3]; 2]>,
+    pub(super) targets: Vec<[f64; 3]>,
+    radius: f64,
+}
+
+impl Sources {
+    /// Straight pipes of `length` along a trench, each at `[offset, depth]`
+    /// across it, sampled at a few points along each pipe.
+    // Point, segment, and loop counts are far below 2^52, so the casts are exact.
+    #[allow(clippy::cast_precision_loss)]
+    pub(super) fn trench(length: f64, pipes: &[[f64; 2]], radius: f64) -> Self {
+        let segments = pipes
+            .iter()
+            .map(|&[y, z]| [[0.0, y, z], [length, y, z]])
+            .collect();
+        let targets = pipes
+            .iter()
+            .flat_map(|&[y, z]| {
+                (0..TRENCH_TARGETS).map(move |i| {
+                    let x = length * (i as f64 + 0.5) / TRENCH_TARGETS as f64;
+                    [x, y + radius, z]
+                })
+            })
+            .collect();
+        Self {
+            segments,
+            targets,
+            radius,
+        }
+    }
+
+    /// Overlapping loops of a slinky coil, centered `pitch` apart along the
+    /// trench at `depth`, lying flat or standing upright in the trench.
+    ///
+    /// The wall is sampled around the middle loop, where the interference
+    /// from its neighbors is strongest.
+    // Point, segment, and loop counts are far below 2^52, so the casts are exact.
+    #[allow(clippy::cast_precision_loss)]
+    pub(super) fn slinky(
+        loops: usize,
+        diameter: f64,
+        pitch: f64,
+        depth: f64,
+        upright: bool,
+        radius: f64,
+    ) -> Self {
+        let r = 0.5 * diameter;
+        let point = |center: f64, angle: f64| {
+            if upright {
+                [center + r * angle.cos(), 0.0, depth - r * angle.sin()]
+            } else {
+                [center + r * angle.cos(), r * angle.sin(), depth]
+            }
+        };
+        let angle = |j: usize| 2.0 * PI * j as f64 / SEGMENTS_PER_LOOP as f64;
+
+        let segments = (0..loops)
+            .flat_map(|k| {
+                let center = k as f64 * pitch;
+                (0..SEGMENTS_PER_LOOP)
+                    .map(move |j| [point(center, angle(j)), point(center, angle(j + 1))])
+            })
+            .collect();
+
+        // Offset the midpoint of each segment outward by the pipe radius.
+        let center = (loops / 2) as f64 * pitch;
+        let targets = (0..SEGMENTS_PER_LOOP)
+            .map(|j| {
+                let [a, b] = [point(center, angle(j)), point(center, angle(j + 1))];
+                let mid = [
+                    0.5 * (a[0] + b[0]),
+                    0.5 * (a[1] + b[1]),
+                    0.5 * (a[2] + b[2]),
+                ];
+                let out = [mid[0] - center, mid[1], mid[2] - depth];
+                let norm = (out[0] * out[0] + out[1] * out[1] + out[2] * out[2]).sqrt();
+                [
+                    mid[0] + radius * out[0] / norm,
+                    mid[1] + radius * out[1] / norm,
+                    mid[2] + radius * out[2] / norm,
+                ]
+            })
+            .collect();
+
+        Self {
+            segments,
+            targets,
+            radius,
+        }
+    }
+
+    /// Tabulates the mean wall temperature response, `ΔT = q′·g / (2πk)`,
+    /// to the same heat rate per length in every pipe.
+    pub(super) fn response(&self, diffusivity: f64) -> ResponseTable {
+        // The table spans a fixed, positive range of `ln t`.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let points = ((LN_MAX - LN_MIN) / LN_STEP).round() as usize + 1;
+        ResponseTable::tabulate(LN_MIN, LN_STEP, points, |time| {
+            self.response_at(diffusivity * time)
+        })
+    }
+
+    /// Mean wall temperature response after the ground has diffused for
+    /// `alpha_t` (diffusivity times time, m²).
+    ///
+    /// Each segment is paired with a mirror image above the surface that
+    /// holds the surface at its undisturbed temperature.
+    #[allow(clippy::cast_precision_loss)]
+    pub(super) fn response_at(&self, alpha_t: f64) -> f64 {
+        let spread = (4.0 * alpha_t).sqrt();
+        let total: f64 = self
+            .targets
+            .iter()
+            .map(|&target| {
+                self.segments
+                    .iter()
+                    .map(|&[a, b]| {
+                        let image = [[a[0], a[1], -a[2]], [b[0], b[1], -b[2]]];
+                        segment_source([a, b], target, spread, self.radius)
+                            - segment_source(image, target, spread, self.radius)
+                    })
+                    .sum::<f64>()
+            })
+            .sum();
+        0.5 * total / self.targets.len() as f64
+    }
+}
+
+/// Integral of the point source response `erfc(r / √(4αt)) / r` along a
+/// straight segment, seen from `target`.
+///
+/// Substituting `s = h·sinh u`, with `h` the distance from the target to the
+/// segment's line, turns the integrand into the smooth `erfc(h·cosh u / √(4αt))`,
+/// so a short Simpson's rule stays accurate even right beside the pipe. The
+/// distance is held to at least `radius`, where pipes cross in a flat slinky.
+// Simpson's rule uses a handful of intervals, so the casts are exact.
+#[allow(clippy::cast_precision_loss)]
+fn segment_source(segment: [[f64; 3]; 2], target: [f64; 3], spread: f64, radius: f64) -> f64 {
+    let [a, b] = segment;
+    let axis = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    let length = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+    let w = [target[0] - a[0], target[1] - a[1], target[2] - a[2]];
+    let along = (w[0] * axis[0] + w[1] * axis[1] + w[2] * axis[2]) / length;
+    let h = (w[0] * w[0] + w[1] * w[1] + w[2] * w[2] - along * along)
+        .max(0.0)
+        .sqrt()
+        .max(radius);
+
+    let reach = CUTOFF * spread / h;
+    if reach <= 1.0 {
+        return 0.0;
+    }
+    let limit = reach.acosh();
+    let lower = (-along / h).asinh().max(-limit);
+    let upper = ((length - along) / h).asinh().min(limit);
+    if lower >= upper {
+        return 0.0;
+    }
+
+    let integrand = |u: f64| erfc(h * u.cosh() / spread);
+    let width = (upper - lower) / INTERVALS as f64;
+    let interior: f64 = (1..INTERVALS)
+        .map(|i| {
+            let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+            weight * integrand(lower + i as f64 * width)
+        })
+        .sum();
+    (integrand(lower) + interior + integrand(upper)) * width / 3.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    const RADIUS: f64 = 0.016;
+
+    #[test]
+    fn long_pipe_matches_the_infinite_line_source() {
+        // At r²/4αt = 0.01 the infinite line source gives
+        // g = E₁(0.01)/2 = 4.037_93/2, and the surface is still far away.
+        let pipe = Sources::trench(200.0, &[[0.0, 1.5]], RADIUS);
+        let alpha_t = RADIUS * RADIUS / 0.04;
+        assert_relative_eq!(
+            pipe.response_at(alpha_t),
+            4.037_93 / 2.0,
+            max_relative = 2e-3
+        );
+    }
+
+    #[test]
+    fn long_pipe_settles_at_its_steady_value() {
+        // Beside its surface image, a long pipe at depth z settles at
+        // g = ln(2z / r). Its ends respond a little less.
+        let pipe = Sources::trench(200.0, &[[0.0, 1.5]], RADIUS);
+        let alpha_t = 1e-6 * 3e8;
+        assert_relative_eq!(
+            pipe.response_at(alpha_t),
+            (3.0 / RADIUS).ln(),
+            max_relative = 0.03
+        );
+    }
+
+    #[test]
+    fn slinky_loops_interfere() {
+        let sparse = Sources::slinky(11, 0.9, 5.0, 1.8, false, RADIUS);
+        let dense = Sources::slinky(11, 0.9, 0.3, 1.8, false, RADIUS);
+        let alpha_t = 1e-6 * 3e6;
+        assert!(dense.response_at(alpha_t) > 1.2 * sparse.response_at(alpha_t));
+    }
+}
@@ -0,0 +1,159 @@
+//! Step responses of the ground and load aggregation for long simulations.
+
+use uom::si::{f64::Time, time::second};
+
+/// Cells of each width before the width doubles.
+const CELLS_PER_LEVEL: usize = 10;
+
+/// A dimensionless step response tabulated on a uniform grid in `ln t`.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct ResponseTable {
+    ln_start: f64,
+    ln_step: f64,
+    values: Vec<f64>,
+}
+
+impl ResponseTable {
+    /// Tabulates `response` at `points` times in seconds, starting at
+    /// `exp(ln_start)` and spaced `ln_step` apart in `ln t`.
+    // Tables hold a few hundred points, so the cast is exact.
+    #[allow(clippy::cast_precision_loss)]
+    pub(super) fn tabulate(
+        ln_start: f64,
+        ln_step: f64,
+        points: usize,
+        response: impl Fn(f64) -> f64,
+    ) -> Self {
+        let values = (0..points)
+            .map(|i| response((ln_start + i as f64 * ln_step).exp()))
+            .collect();
+        Self {
+            ln_start,
+            ln_step,
+            values,
+        }
+    }
+
+    /// Evaluates the response at `time` seconds after a step in load.
+    ///
+    /// Interpolates linearly in `ln t`. Before the first tabulated time the
+    /// response falls linearly to zero, and after the last it holds at the
+    /// steady value.
+    pub(super) fn eval(&self, time: f64) -> f64 {
+        if time <= 0.0 {
+            return 0.0;
+        }
+        let position = (time.ln() - self.ln_start) / self.ln_step;
+        if position <= 0.0 {
+            return self.values[0] * time / self.ln_start.exp();
+        }
+        let last = self.values.len() - 1;
+        // `position` is positive here, and its floor indexes into the table.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let index = position.floor() as usize;
+        if index >= last {
+            return self.values[last];
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let fraction = position - index as f64;
+        self.values[index] + fraction * (self.values[index + 1] - self.values[index])
+    }
+}
+
+/// The heat load history of a ground heat exchanger, aggregated into cells
+/// that widen with age.
+///
+/// Follows the scheme of Claesson and Javed (2012). The most recent loads
+/// are kept step by step, while older ones are averaged into cells whose
+/// width doubles every few cells, so each step costs work proportional to
+/// the logarithm of the simulated time rather than to its length. Loads
+/// older than the horizon the history was created for are forgotten.
+///
+/// Create one with [`Borefield::history`](super::borefield::Borefield::history)
+/// or [`HorizontalLoop::history`](super::horizontal::HorizontalLoop::history)
+/// and advance it with the matching `step`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadHistory {
+    time_step: Time,
+    widths: Vec<f64>,
+    increments: Vec<f64>,
+    loads: Vec<f64>,
+    steps: usize,
+}
+
+impl LoadHistory {
+    /// Lays out cells covering at least `horizon_steps` time steps, with the
+    /// response increment across each divided by `2πk`.
+    pub(super) fn new(
+        response: &ResponseTable,
+        time_step: Time,
+        horizon_steps: usize,
+        two_pi_k: f64,
+    ) -> Self {
+        let mut widths = Vec::new();
+        let mut covered = 0;
+        let mut width = 1;
+        while covered < horizon_steps.max(1) {
+            for _ in 0..CELLS_PER_LEVEL {
+                // Cell widths are powers of two well below 2^52.
+                #[allow(clippy::cast_precision_loss)]
+                widths.push(width as f64);
+                covered += width;
+            }
+            width *= 2;
+        }
+
+        let dt = time_step.get::<second>();
+        let mut elapsed = 0.0;
+        let increments = widths
+            .iter()
+            .map(|width| {
+                let before = response.eval(elapsed * dt);
+                elapsed += width;
+                (response.eval(elapsed * dt) - before) / two_pi_k
+            })
+            .collect();
+
+        Self {
+            time_step,
+            loads: vec![0.0; widths.len()],
+            widths,
+            increments,
+            steps: 0,
+        }
+    }
+
+    /// Returns the length of each time step.
+    #[must_use]
+    pub fn time_step(&self) -> Time {
+        self.time_step
+    }
+
+    /// Returns the time simulated so far.
+    #[must_use]
+    // Step counts are far below 2^52, so the cast is exact.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn elapsed(&self) -> Time {
+        self.time_step * self.steps as f64
+    }
+
+    /// Shifts every cell one step older and records the load per length of
+    /// the new step, in W/m.
+    pub(super) fn push(&mut self, load: f64) {
+        for i in (1..self.loads.len()).rev() {
+            self.loads[i] += (self.loads[i - 1] - self.loads[i]) / self.widths[i];
+        }
+        self.loads[0] = load;
+        self.steps += 1;
+    }
+
+    /// Returns the rise in mean wall temperature, in K, at the end of the
+    /// latest step.
+    pub(super) fn temperature_change(&self) -> f64 {
+        self.loads
+            .iter()
+            .zip(&self.increments)
+            .map(|(q, dg)| q * dg)
+            .sum()
+    }
+}
@@ -2,4 +2,8 @@
 //!
 //! This module provides [`twine_core::Model`] implementations for heat exchangers.
 
+pub mod air_cooled;
 pub mod discretized;
+pub mod drain_water;
+pub mod erv;
+pub mod manifold;
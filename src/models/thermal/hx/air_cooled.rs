@@ -0,0 +1,83 @@
+//! Dry air-cooled condensers and coolers.
+//!
+//! An [`AirCooledCondenser`] rejects heat from a process fluid to ambient
+//! air drawn across a finned-tube bundle by fans, with no water consumed.
+//! It serves as the heat-rejection block of power and refrigeration cycles
+//! in dry climates: the steam condenser of a Rankine plant, the gas cooler
+//! of a supercritical CO₂ cycle, or the condenser of an air-cooled chiller.
+//!
+//! Performance depends on the ambient air. The fans move a fixed volume, so
+//! hot air carries less mass, and the process fluid cannot be cooled below
+//! the ambient dry bulb. [`AirCooledCondenser::condensing_temperature`]
+//! gives the condensing temperature a cycle settles at for a given load.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::hx::air_cooled::{
+//!         AirCooledCondenser, AirCooledCondenserInput, AirCooledCondenserParameters,
+//!         AirCooledProcess, Fan,
+//!     },
+//!     support::psychrometrics::MoistAir,
+//! };
+//! use uom::si::{
+//!     f64::{Power, Pressure, Ratio, ThermalConductance, ThermodynamicTemperature, VolumeRate},
+//!     power::megawatt,
+//!     pressure::{kilopascal, pascal},
+//!     ratio::{percent, ratio},
+//!     thermal_conductance::kilowatt_per_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//!     volume_rate::cubic_meter_per_second,
+//! };
+//!
+//! let condenser = AirCooledCondenser::new(AirCooledCondenserParameters {
+//!     rated_ua: ThermalConductance::new::<kilowatt_per_kelvin>(200.0),
+//!     fan: Fan {
+//!         rated_flow: VolumeRate::new::<cubic_meter_per_second>(100.0),
+//!         rated_pressure_rise: Pressure::new::<pascal>(150.0),
+//!         efficiency: Ratio::new::<percent>(60.0),
+//!     },
+//! })?;
+//!
+//! let air = MoistAir::from_relative_humidity(
+//!     ThermodynamicTemperature::new::<degree_celsius>(30.0),
+//!     Ratio::new::<percent>(30.0),
+//!     Pressure::new::<kilopascal>(101.325),
+//! )?;
+//! let full_speed = Ratio::new::<ratio>(1.0);
+//!
+//! // Steam condensing at 50 °C.
+//! let output = condenser.evaluate(&AirCooledCondenserInput {
+//!     air,
+//!     fan_speed: full_speed,
+//!     process: AirCooledProcess::Condensing {
+//!         temperature: ThermodynamicTemperature::new::<degree_celsius>(50.0),
+//!     },
+//! })?;
+//! assert!(output.heat_rate > Power::new::<megawatt>(1.5));
+//!
+//! // The condensing temperature needed to reject 1 MW.
+//! let t_cond = condenser.condensing_temperature(Power::new::<megawatt>(1.0), &air, full_speed)?;
+//! assert!(t_cond.get::<degree_celsius>() < 50.0);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    AirCooledCondenser, AirCooledCondenserError, AirCooledCondenserInput, AirCooledCondenserOutput,
+    AirCooledCondenserParameters, AirCooledProcess, Fan,
+};
+
+impl Model for AirCooledCondenser {
+    type Input = AirCooledCondenserInput;
+    type Output = AirCooledCondenserOutput;
+    type Error = AirCooledCondenserError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
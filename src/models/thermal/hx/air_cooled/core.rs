@@ -0,0 +1,526 @@
+use thiserror::Error;
+use uom::si::{
+    f64::{
+        MassDensity, MassRate, Power, Pressure, Ratio, ThermalConductance,
+        ThermodynamicTemperature, VolumeRate,
+    },
+    mass_density::kilogram_per_cubic_meter,
+    ratio::ratio,
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive, UnitInterval},
+    hx::{CapacitanceRate, CrossFlow, Mixed, StreamInlet, Unmixed, functional},
+    psychrometrics::{MoistAir, PsychrometricError, sensible_heating},
+    units::TemperatureDifference,
+};
+
+/// Density of standard air, at which fan and coil ratings are quoted.
+const STANDARD_AIR_DENSITY: f64 = 1.204;
+
+/// Exponent on air mass flow in the air-side conductance of a finned coil.
+const AIR_SIDE_EXPONENT: f64 = 0.5;
+
+/// Fans that draw ambient air across the coil.
+///
+/// The fans follow the affinity laws: volume flow is proportional to speed,
+/// and pressure rise to speed squared and air density, so the shaft power
+/// goes as speed cubed and in proportion to density.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fan {
+    /// Air volume flow at full speed.
+    pub rated_flow: VolumeRate,
+
+    /// Total pressure rise at full speed in standard air (1.204 kg/m³).
+    pub rated_pressure_rise: Pressure,
+
+    /// Combined fan, drive, and motor efficiency.
+    pub efficiency: Ratio,
+}
+
+impl Fan {
+    /// Returns the air volume flow at `speed`, a fraction of full speed.
+    #[must_use]
+    pub fn flow(&self, speed: Ratio) -> VolumeRate {
+        self.rated_flow * speed
+    }
+
+    /// Returns the electrical power drawn at `speed` in air of `density`.
+    #[must_use]
+    pub fn power(&self, speed: Ratio, density: MassDensity) -> Power {
+        let s = speed.get::<ratio>();
+        let density_ratio = density.get::<kilogram_per_cubic_meter>() / STANDARD_AIR_DENSITY;
+        self.rated_flow * self.rated_pressure_rise / self.efficiency * (s * s * s * density_ratio)
+    }
+}
+
+/// Fixed parameters of an [`AirCooledCondenser`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AirCooledCondenserParameters {
+    /// Overall conductance with the fans at full speed in standard air.
+    pub rated_ua: ThermalConductance,
+
+    /// Fans moving air across the coil.
+    pub fan: Fan,
+}
+
+/// Errors from [`AirCooledCondenser`] construction and evaluation.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum AirCooledCondenserError {
+    /// A condenser parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating input is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// An air state could not be evaluated.
+    #[error(transparent)]
+    Psychrometric(#[from] PsychrometricError),
+}
+
+/// The process fluid inside the coil tubes.
+#[derive(Debug, Clone, Copy)]
+pub enum AirCooledProcess {
+    /// Vapor condensing at a fixed saturation temperature, as in a steam or
+    /// refrigerant condenser.
+    ///
+    /// Desuperheating and subcooling are neglected, so the tube side has an
+    /// unlimited capacitance rate.
+    Condensing {
+        /// Saturation temperature of the condensing vapor.
+        temperature: ThermodynamicTemperature,
+    },
+
+    /// A single-phase gas or liquid being cooled, as in a dry cooler or a
+    /// supercritical CO₂ gas cooler.
+    Cooling(StreamInlet),
+}
+
+/// An air-cooled condenser or dry cooler.
+///
+/// Ambient air crosses a finned-tube bundle in crossflow with the process
+/// fluid. The air side is unmixed across the fins and the tube side mixed.
+/// While condensing, the tube side is isothermal and the effectiveness is
+/// `1 − exp(−UA / C_air)`.
+///
+/// The fans move a volume of air set by their speed, so hot, thin ambient
+/// air carries less mass and less heat. The conductance scales as the
+/// square root of the air mass flow relative to its rating, the usual
+/// dependence of a finned-coil air-side coefficient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AirCooledCondenser {
+    parameters: AirCooledCondenserParameters,
+}
+
+/// Input to the air-cooled condenser model.
+#[derive(Debug, Clone, Copy)]
+pub struct AirCooledCondenserInput {
+    /// Ambient air drawn into the coil.
+    pub air: MoistAir,
+
+    /// Fan speed as a fraction of full speed.
+    pub fan_speed: Ratio,
+
+    /// Process fluid inside the tubes.
+    pub process: AirCooledProcess,
+}
+
+/// Output from the air-cooled condenser model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AirCooledCondenserOutput {
+    /// Heat rejected from the process fluid to the air.
+    pub heat_rate: Power,
+
+    /// Process fluid temperature leaving the coil.
+    ///
+    /// Equal to the saturation temperature while condensing.
+    pub process_outlet_temperature: ThermodynamicTemperature,
+
+    /// Air leaving the coil.
+    pub outlet_air: MoistAir,
+
+    /// Dry-air mass flow across the coil.
+    pub air_flow: MassRate,
+
+    /// Overall conductance at this air flow.
+    pub ua: ThermalConductance,
+
+    /// Heat exchanger effectiveness.
+    pub effectiveness: Ratio,
+
+    /// Electrical power drawn by the fans.
+    pub fan_power: Power,
+}
+
+/// Air-side conditions at a fan speed.
+struct AirSide {
+    dry_air_flow: MassRate,
+    capacitance_rate: ThermalConductance,
+    ua: ThermalConductance,
+    fan_power: Power,
+}
+
+impl AirCooledCondenser {
+    /// Creates an air-cooled condenser from its parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AirCooledCondenserError::InvalidParameter`] if the rated
+    /// conductance or fan flow is not strictly positive, the fan pressure
+    /// rise is negative, or the fan efficiency is not in (0, 1].
+    pub fn new(parameters: AirCooledCondenserParameters) -> Result<Self, AirCooledCondenserError> {
+        let AirCooledCondenserParameters { rated_ua, fan } = parameters;
+
+        if StrictlyPositive::check(&rated_ua).is_err() || !rated_ua.is_finite() {
+            return Err(AirCooledCondenserError::InvalidParameter(
+                "rated UA must be strictly positive and finite",
+            ));
+        }
+        if StrictlyPositive::check(&fan.rated_flow).is_err() || !fan.rated_flow.is_finite() {
+            return Err(AirCooledCondenserError::InvalidParameter(
+                "rated fan flow must be strictly positive and finite",
+            ));
+        }
+        if NonNegative::check(&fan.rated_pressure_rise).is_err()
+            || !fan.rated_pressure_rise.is_finite()
+        {
+            return Err(AirCooledCondenserError::InvalidParameter(
+                "rated fan pressure rise must be non-negative and finite",
+            ));
+        }
+        if StrictlyPositive::check(&fan.efficiency).is_err()
+            || UnitInterval::check(&fan.efficiency).is_err()
+        {
+            return Err(AirCooledCondenserError::InvalidParameter(
+                "fan efficiency must be in (0, 1]",
+            ));
+        }
+
+        Ok(Self { parameters })
+    }
+
+    /// Returns the condenser parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &AirCooledCondenserParameters {
+        &self.parameters
+    }
+
+    /// Evaluates the heat rejected to ambient air at a single operating
+    /// point.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AirCooledCondenserError::InvalidInput`] if the fan speed is
+    /// not in (0, 1] or the process stream is invalid, or
+    /// [`AirCooledCondenserError::Psychrometric`] if the air leaving a coil
+    /// colder than ambient would fall below its dew point.
+    pub fn evaluate(
+        &self,
+        input: &AirCooledCondenserInput,
+    ) -> Result<AirCooledCondenserOutput, AirCooledCondenserError> {
+        let AirCooledCondenserInput {
+            air,
+            fan_speed,
+            process,
+        } = *input;
+        let air_side = self.air_side(&air, fan_speed)?;
+
+        let (heat_rate, process_outlet_temperature, effectiveness) = match process {
+            AirCooledProcess::Condensing { temperature } => {
+                let ntu = (air_side.ua / air_side.capacitance_rate).get::<ratio>();
+                let effectiveness = -(-ntu).exp_m1();
+                let heat_rate = air_side.capacitance_rate
+                    * temperature.minus(air.temperature())
+                    * effectiveness;
+                (heat_rate, temperature, Ratio::new::<ratio>(effectiveness))
+            }
+            AirCooledProcess::Cooling(inlet) => {
+                let air_inlet = StreamInlet::new(
+                    CapacitanceRate::from_quantity(air_side.capacitance_rate).map_err(|_| {
+                        AirCooledCondenserError::InvalidInput(
+                            "air capacitance rate must be strictly positive",
+                        )
+                    })?,
+                    air.temperature(),
+                );
+                let result = functional::known_conductance_and_inlets(
+                    &CrossFlow::<Mixed, Unmixed>::new(),
+                    air_side.ua,
+                    [inlet, air_inlet],
+                )
+                .map_err(|_| {
+                    AirCooledCondenserError::InvalidInput(
+                        "process stream must have a valid capacitance rate",
+                    )
+                })?;
+                (
+                    -result.streams[0].heat_flow.signed(),
+                    result.streams[0].outlet_temperature,
+                    *result.effectiveness,
+                )
+            }
+        };
+
+        Ok(AirCooledCondenserOutput {
+            heat_rate,
+            process_outlet_temperature,
+            outlet_air: sensible_heating(&air, heat_rate, air_side.dry_air_flow)?,
+            air_flow: air_side.dry_air_flow,
+            ua: air_side.ua,
+            effectiveness,
+            fan_power: air_side.fan_power,
+        })
+    }
+
+    /// Returns the condensing temperature at which the condenser rejects
+    /// `heat_rate` to `air` with the fans at `fan_speed`.
+    ///
+    /// This is the inverse of [`evaluate`](Self::evaluate) for a condensing
+    /// process, giving the condensing temperature, and so the back pressure,
+    /// that a cycle rejecting a known load will settle at.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AirCooledCondenserError::InvalidInput`] if the heat rate is
+    /// negative or not finite or the fan speed is not in (0, 1], or
+    /// [`AirCooledCondenserError::Psychrometric`] if the air state cannot be
+    /// evaluated.
+    pub fn condensing_temperature(
+        &self,
+        heat_rate: Power,
+        air: &MoistAir,
+        fan_speed: Ratio,
+    ) -> Result<ThermodynamicTemperature, AirCooledCondenserError> {
+        if NonNegative::check(&heat_rate).is_err() || !heat_rate.is_finite() {
+            return Err(AirCooledCondenserError::InvalidInput(
+                "heat rate must be non-negative and finite",
+            ));
+        }
+        let air_side = self.air_side(air, fan_speed)?;
+
+        let ntu = (air_side.ua / air_side.capacitance_rate).get::<ratio>();
+        let effectiveness = -(-ntu).exp_m1();
+        Ok(air
+            .temperature()
+            .plus(heat_rate / (air_side.capacitance_rate * effectiveness)))
+    }
+
+    /// Evaluates the air flow, conductance, and fan power at a fan speed.
+    fn air_side(
+        &self,
+        air: &MoistAir,
+        fan_speed: Ratio,
+    ) -> Result<AirSide, AirCooledCondenserError> {
+        if StrictlyPositive::check(&fan_speed).is_err() || UnitInterval::check(&fan_speed).is_err()
+        {
+            return Err(AirCooledCondenserError::InvalidInput(
+                "fan speed must be in (0, 1]",
+            ));
+        }
+
+        let AirCooledCondenserParameters { rated_ua, fan } = self.parameters;
+        let specific_volume = air.specific_volume();
+        let density: MassDensity = (1.0 + air.humidity_ratio().get::<ratio>()) / specific_volume;
+        let volume_flow = fan.flow(fan_speed);
+        let dry_air_flow: MassRate = volume_flow / specific_volume;
+
+        let rated_mass_flow =
+            fan.rated_flow * MassDensity::new::<kilogram_per_cubic_meter>(STANDARD_AIR_DENSITY);
+        let flow_ratio = (volume_flow * density / rated_mass_flow).get::<ratio>();
+
+        Ok(AirSide {
+            dry_air_flow,
+            capacitance_rate: dry_air_flow * air.humid_specific_heat(),
+            ua: rated_ua * flow_ratio.powf(AIR_SIDE_EXPONENT),
+            fan_power: fan.power(fan_speed, density),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::SpecificHeatCapacity,
+        mass_rate::kilogram_per_second,
+        power::kilowatt,
+        pressure::{kilopascal, pascal},
+        ratio::percent,
+        specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+        thermal_conductance::kilowatt_per_kelvin,
+        thermodynamic_temperature::degree_celsius,
+        volume_rate::cubic_meter_per_second,
+    };
+
+    use crate::support::units::test_utils::celsius;
+
+    fn condenser() -> AirCooledCondenser {
+        AirCooledCondenser::new(AirCooledCondenserParameters {
+            rated_ua: ThermalConductance::new::<kilowatt_per_kelvin>(200.0),
+            fan: Fan {
+                rated_flow: VolumeRate::new::<cubic_meter_per_second>(100.0),
+                rated_pressure_rise: Pressure::new::<pascal>(150.0),
+                efficiency: Ratio::new::<percent>(60.0),
+            },
+        })
+        .unwrap()
+    }
+
+    fn ambient(t: f64) -> MoistAir {
+        MoistAir::from_relative_humidity(
+            celsius(t),
+            Ratio::new::<percent>(40.0),
+            Pressure::new::<kilopascal>(101.325),
+        )
+        .unwrap()
+    }
+
+    fn condensing(air: MoistAir, temperature: f64) -> AirCooledCondenserInput {
+        AirCooledCondenserInput {
+            air,
+            fan_speed: Ratio::new::<ratio>(1.0),
+            process: AirCooledProcess::Condensing {
+                temperature: celsius(temperature),
+            },
+        }
+    }
+
+    #[test]
+    fn condensing_heat_warms_the_air() {
+        let output = condenser()
+            .evaluate(&condensing(ambient(25.0), 45.0))
+            .unwrap();
+
+        assert_relative_eq!(
+            output.heat_rate.get::<kilowatt>(),
+            (output.air_flow
+                * ambient(25.0).humid_specific_heat()
+                * output.outlet_air.temperature().minus(celsius(25.0)))
+            .get::<kilowatt>(),
+            max_relative = 1e-9
+        );
+        assert!(output.outlet_air.temperature() < celsius(45.0));
+        assert_eq!(output.process_outlet_temperature, celsius(45.0));
+
+        // Full speed in air close to standard density draws close to the
+        // rated 100 m³/s × 150 Pa / 0.6 = 25 kW.
+        assert_relative_eq!(
+            output.fan_power.get::<kilowatt>(),
+            25.0,
+            max_relative = 0.03
+        );
+    }
+
+    #[test]
+    fn hot_ambient_reduces_capacity_and_raises_condensing_temperature() {
+        let mild = condenser()
+            .evaluate(&condensing(ambient(15.0), 45.0))
+            .unwrap();
+        let hot = condenser()
+            .evaluate(&condensing(ambient(35.0), 45.0))
+            .unwrap();
+        assert!(hot.heat_rate < mild.heat_rate);
+        assert!(hot.air_flow < mild.air_flow);
+        assert!(hot.fan_power < mild.fan_power);
+
+        // Rejecting the same load on a hotter day needs a hotter condenser.
+        let load = mild.heat_rate;
+        let speed = Ratio::new::<ratio>(1.0);
+        let t_mild = condenser()
+            .condensing_temperature(load, &ambient(15.0), speed)
+            .unwrap();
+        let t_hot = condenser()
+            .condensing_temperature(load, &ambient(35.0), speed)
+            .unwrap();
+        assert_relative_eq!(t_mild.get::<degree_celsius>(), 45.0, epsilon = 1e-9);
+        assert!(t_hot.minus(t_mild).value > 20.0);
+    }
+
+    #[test]
+    fn fan_power_follows_speed_cubed() {
+        let full = condenser()
+            .evaluate(&condensing(ambient(25.0), 45.0))
+            .unwrap();
+        let half = condenser()
+            .evaluate(&AirCooledCondenserInput {
+                fan_speed: Ratio::new::<ratio>(0.5),
+                ..condensing(ambient(25.0), 45.0)
+            })
+            .unwrap();
+
+        assert_relative_eq!(
+            half.fan_power.get::<kilowatt>(),
+            full.fan_power.get::<kilowatt>() / 8.0,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            half.ua.get::<kilowatt_per_kelvin>(),
+            full.ua.get::<kilowatt_per_kelvin>() * 0.5_f64.sqrt(),
+            max_relative = 1e-12
+        );
+        assert!(half.heat_rate < full.heat_rate);
+        assert!(half.effectiveness > full.effectiveness);
+    }
+
+    #[test]
+    fn dry_cooler_balances_both_streams() {
+        let flow = MassRate::new::<kilogram_per_second>(20.0);
+        let cp = SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(3.6);
+        let output = condenser()
+            .evaluate(&AirCooledCondenserInput {
+                air: ambient(25.0),
+                fan_speed: Ratio::new::<ratio>(1.0),
+                process: AirCooledProcess::Cooling(StreamInlet::new(
+                    CapacitanceRate::from_quantity(flow * cp).unwrap(),
+                    celsius(50.0),
+                )),
+            })
+            .unwrap();
+
+        let process_out = output.process_outlet_temperature;
+        assert!(process_out < celsius(50.0) && process_out > celsius(25.0));
+        assert_relative_eq!(
+            output.heat_rate.get::<kilowatt>(),
+            (flow * cp * celsius(50.0).minus(process_out)).get::<kilowatt>(),
+            max_relative = 1e-9
+        );
+        assert!(output.outlet_air.temperature() > celsius(25.0));
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_inputs() {
+        let parameters = *condenser().parameters();
+        assert!(matches!(
+            AirCooledCondenser::new(AirCooledCondenserParameters {
+                fan: Fan {
+                    efficiency: Ratio::new::<percent>(120.0),
+                    ..parameters.fan
+                },
+                ..parameters
+            }),
+            Err(AirCooledCondenserError::InvalidParameter(_))
+        ));
+
+        assert!(matches!(
+            condenser().evaluate(&AirCooledCondenserInput {
+                fan_speed: Ratio::new::<ratio>(0.0),
+                ..condensing(ambient(25.0), 45.0)
+            }),
+            Err(AirCooledCondenserError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            condenser().condensing_temperature(
+                Power::new::<kilowatt>(-1.0),
+                &ambient(25.0),
+                Ratio::new::<ratio>(1.0)
+            ),
+            Err(AirCooledCondenserError::InvalidInput(_))
+        ));
+    }
+}
@@ -5,12 +5,24 @@
 //! along a linear array of nodes, supporting real-fluid behavior.
 
 pub(crate) mod core;
+pub mod exchanger;
 pub mod recuperator;
 
+pub use exchanger::{
+    DiscretizedExchanger, DiscretizedExchangerError, ExchangerConjugateResults,
+    ExchangerGivenUaResults, ExchangerResults, FlowArrangement, SUPPORTED_SEGMENTS,
+};
+
 pub use recuperator::{
-    OutletTemp, RecuperatorGivenOutlet, RecuperatorGivenOutletError, RecuperatorGivenOutletInput,
-    RecuperatorGivenOutletOutput, RecuperatorGivenUa, RecuperatorGivenUaConfig,
-    RecuperatorGivenUaError, RecuperatorGivenUaInput, RecuperatorGivenUaOutput,
+    OutletTemp, Recuperator, RecuperatorError, RecuperatorGivenOutlet, RecuperatorGivenOutletError,
+    RecuperatorGivenOutletInput, RecuperatorGivenOutletOutput, RecuperatorGivenUa,
+    RecuperatorGivenUaConfig, RecuperatorGivenUaConfigBuilder, RecuperatorGivenUaError,
+    RecuperatorGivenUaInput, RecuperatorGivenUaOutput, RecuperatorInput, RecuperatorOutput,
+    RecuperatorSpec,
 };
 
-pub use core::{HeatTransferRate, Inlets, MassFlows, MinDeltaT, PressureDrops};
+pub use core::{
+    Charge, ChargeError, ConjugateConfig, ConjugateError, ConjugateWall, Given, GivenUaConfig,
+    GivenUaError, GivenUaIteration, HeatTransferRate, Inlets, Known, KnownBuildError, KnownBuilder,
+    MassFlows, MinDeltaT, PressureDrops, SlipCorrelation, SolveError, WallNode, WallSide,
+};
@@ -1,13 +1,20 @@
-//! Discretized counterflow and parallel-flow heat exchanger modeling.
+//! Discretized heat exchanger modeling.
 //!
 //! A discretized heat exchanger divides the flow into a linear series of
 //! constant-property sub-exchangers so thermodynamic properties can vary
 //! along a linear array of nodes, supporting real-fluid behavior.
+//!
+//! Counterflow, parallel-flow, shell-and-tube, and mixed-unmixed crossflow
+//! arrangements are supported. Shell-and-tube and crossflow exchangers are
+//! discretized as counter-current series of segments, so with two nodes they
+//! reduce to their lumped effectiveness-NTU relations.
 
 // This module is internal infrastructure for Model adapters (issue #14).
 // Dead code warnings are expected until adapters consume this API.
 #![allow(dead_code)]
 
+mod charge;
+mod conjugate;
 mod given_ua;
 mod heat_transfer_rate;
 mod input;
@@ -19,20 +26,26 @@ mod traits;
 #[cfg(test)]
 pub(crate) mod test_support;
 
-pub use given_ua::{GivenUaConfig, GivenUaError, GivenUaResults};
+pub use charge::{Charge, ChargeError, SlipCorrelation};
+pub use conjugate::{
+    ConjugateConfig, ConjugateError, ConjugateResults, ConjugateWall, WallNode, WallSide,
+};
+pub use given_ua::{GivenUaConfig, GivenUaError, GivenUaIteration, GivenUaResults};
 pub use heat_transfer_rate::HeatTransferRate;
-pub use input::{Given, Inlets, Known, MassFlows, PressureDrops};
+pub use input::{Given, Inlets, Known, KnownBuildError, KnownBuilder, MassFlows, PressureDrops};
 pub use results::{MinDeltaT, Results};
 pub use solve::SolveError;
-pub(crate) use traits::DiscretizedHxThermoModel;
+pub(crate) use traits::{DiscretizedArrangement, DiscretizedHxThermoModel};
 
 use std::marker::PhantomData;
 
-use uom::si::f64::ThermalConductance;
+use uom::si::f64::{HeatTransfer, ThermalConductance};
+
+use crate::support::thermo::State;
 
+use conjugate::conjugate;
 use given_ua::given_ua;
 use solve::solve;
-use traits::DiscretizedArrangement;
 
 /// Entry point for solving a discretized heat exchanger.
 ///
@@ -119,7 +132,47 @@ impl<Arrangement, const N: usize> DiscretizedHx<Arrangement, N> {
         TopFluid: Clone,
         BottomFluid: Clone,
     {
-        given_ua::<Arrangement, _, _, N>(known, target_ua, config, thermo_top, thermo_bottom)
+        given_ua::<Arrangement, _, _, N>(
+            known,
+            target_ua,
+            config,
+            thermo_top,
+            thermo_bottom,
+            |_| {},
+        )
+    }
+
+    /// Solves a discretized heat exchanger given a target UA, reporting each iteration.
+    ///
+    /// Behaves like [`DiscretizedHx::given_ua`], but calls `observer` with a
+    /// [`GivenUaIteration`] for every candidate outlet temperature the solver
+    /// evaluates, including iterations of a solve that ultimately fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GivenUaError`] on non-physical results, thermodynamic model failures,
+    /// or if the solver fails to converge.
+    pub fn given_ua_observed<TopFluid, BottomFluid>(
+        known: &Known<TopFluid, BottomFluid>,
+        target_ua: ThermalConductance,
+        config: GivenUaConfig,
+        thermo_top: &impl DiscretizedHxThermoModel<TopFluid>,
+        thermo_bottom: &impl DiscretizedHxThermoModel<BottomFluid>,
+        observer: impl FnMut(&GivenUaIteration),
+    ) -> Result<GivenUaResults<TopFluid, BottomFluid, N>, GivenUaError>
+    where
+        Arrangement: DiscretizedArrangement + Default,
+        TopFluid: Clone,
+        BottomFluid: Clone,
+    {
+        given_ua::<Arrangement, _, _, N>(
+            known,
+            target_ua,
+            config,
+            thermo_top,
+            thermo_bottom,
+            observer,
+        )
     }
 
     /// Solves a discretized heat exchanger given a target UA when both streams share the same thermo model.
@@ -141,6 +194,37 @@ impl<Arrangement, const N: usize> DiscretizedHx<Arrangement, N> {
         Fluid: Clone,
         Model: DiscretizedHxThermoModel<Fluid>,
     {
-        given_ua::<Arrangement, _, _, N>(known, target_ua, config, thermo, thermo)
+        given_ua::<Arrangement, _, _, N>(known, target_ua, config, thermo, thermo, |_| {})
+    }
+
+    /// Solves a discretized heat exchanger whose conductance comes from the
+    /// film coefficients on either side of a conjugate wall.
+    ///
+    /// Instead of a combined UA, the films are evaluated from the local
+    /// stream states at every node, so per-side heat transfer coefficients
+    /// from a correlation can be used directly. Iterates on the top outlet
+    /// temperature until the segments use exactly the whole surface, and
+    /// reports the wall surface temperatures at every node.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConjugateError`] on an invalid wall or film coefficient,
+    /// non-physical results, thermodynamic model failures, or if the solver
+    /// fails to converge.
+    pub fn conjugate<TopFluid, BottomFluid, TopFilm, BottomFilm>(
+        known: &Known<TopFluid, BottomFluid>,
+        wall: &ConjugateWall<TopFilm, BottomFilm>,
+        config: ConjugateConfig,
+        thermo_top: &impl DiscretizedHxThermoModel<TopFluid>,
+        thermo_bottom: &impl DiscretizedHxThermoModel<BottomFluid>,
+    ) -> Result<ConjugateResults<TopFluid, BottomFluid, N>, ConjugateError>
+    where
+        Arrangement: DiscretizedArrangement + Default,
+        TopFluid: Clone,
+        BottomFluid: Clone,
+        TopFilm: Fn(&State<TopFluid>) -> HeatTransfer,
+        BottomFilm: Fn(&State<BottomFluid>) -> HeatTransfer,
+    {
+        conjugate::<Arrangement, _, _, _, _, N>(known, wall, config, thermo_top, thermo_bottom)
     }
 }
@@ -0,0 +1,392 @@
+//! Fluid mass inventory of a solved discretized heat exchanger.
+//!
+//! Each stream's internal volume is spread over the segments in proportion to
+//! their share of the total conductance, which assumes the conductance per
+//! unit length is uniform along the exchanger. Each node is then charged with
+//! half of the volume of its neighbouring segments at the node's density.
+//!
+//! Two-phase nodes use a void-fraction density instead of the homogeneous
+//! state density. The vapor phase generally moves faster than the liquid, so
+//! less of the volume is filled with vapor than the quality alone suggests,
+//! and a [`SlipCorrelation`] describes by how much.
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::{
+        f64::{Mass, MassDensity, Ratio, ThermalConductance, Volume},
+        ratio::ratio,
+    },
+};
+
+use crate::support::{
+    thermo::{
+        PropertyError, State,
+        capability::{HasTwoPhase, TwoPhase},
+    },
+    units::display::pretty,
+};
+
+use super::Results;
+
+/// Ratio of vapor to liquid velocity used to compute the void fraction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SlipCorrelation {
+    /// Both phases move at the same velocity.
+    ///
+    /// The void-fraction density equals the homogeneous state density, which
+    /// gives the lowest charge estimate.
+    #[default]
+    Homogeneous,
+
+    /// Zivi (1964): `S = (ρ_l / ρ_v)^(1/3)`.
+    ///
+    /// Derived from minimum entropy production in annular flow.
+    Zivi,
+
+    /// Chisholm (1973): `S = √(1 − x·(1 − ρ_l / ρ_v))`.
+    Chisholm,
+}
+
+impl SlipCorrelation {
+    /// Returns the slip ratio for the given phase split.
+    #[must_use]
+    pub fn slip_ratio(self, two_phase: &TwoPhase) -> f64 {
+        let x = two_phase.quality.get::<ratio>();
+        let density_ratio = (two_phase.liquid_density / two_phase.vapor_density).get::<ratio>();
+
+        match self {
+            Self::Homogeneous => 1.0,
+            Self::Zivi => density_ratio.cbrt(),
+            Self::Chisholm => (1.0 - x * (1.0 - density_ratio)).sqrt(),
+        }
+    }
+
+    /// Returns the vapor volume fraction for the given phase split.
+    ///
+    /// Computes `α = x / (x + (1 − x)·(ρ_v / ρ_l)·S)`, which is zero for
+    /// saturated liquid and one for saturated vapor.
+    #[must_use]
+    pub fn void_fraction(self, two_phase: &TwoPhase) -> Ratio {
+        let x = two_phase.quality.get::<ratio>();
+        let density_ratio = (two_phase.vapor_density / two_phase.liquid_density).get::<ratio>();
+        let slip = self.slip_ratio(two_phase);
+
+        Ratio::new::<ratio>(x / (x + (1.0 - x) * density_ratio * slip))
+    }
+
+    /// Returns the in-situ density of the two-phase mixture.
+    #[must_use]
+    pub fn mixture_density(self, two_phase: &TwoPhase) -> MassDensity {
+        let alpha = self.void_fraction(two_phase);
+        two_phase.vapor_density * alpha
+            + two_phase.liquid_density * (Ratio::new::<ratio>(1.0) - alpha)
+    }
+}
+
+/// Fluid mass held by one stream of a heat exchanger.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Charge<const N: usize> {
+    /// Mass attributed to each node, ordered from left (0) to right (N-1).
+    pub nodes: [Mass; N],
+
+    /// Total mass held by the stream.
+    pub total: Mass,
+}
+
+/// Errors that can occur while estimating a stream's charge.
+#[derive(Debug, Error)]
+pub enum ChargeError {
+    /// The internal volume is negative or not finite.
+    #[error("internal volume must be non-negative and finite, got {}", pretty(*.0))]
+    InvalidVolume(Volume),
+
+    /// The thermo model could not determine the phase split at a node.
+    #[error("two_phase(node {node}) failed")]
+    ThermoModelFailed {
+        /// Node index whose state was queried.
+        node: usize,
+
+        /// Underlying thermodynamic model error.
+        #[source]
+        source: PropertyError,
+    },
+}
+
+impl<TopFluid, BottomFluid, const N: usize> Results<TopFluid, BottomFluid, N> {
+    /// Estimates the fluid mass held by the top stream.
+    ///
+    /// `volume` is the top stream's total internal volume.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ChargeError`] if the volume is invalid or the phase split
+    /// of a node cannot be determined.
+    pub fn top_charge(
+        &self,
+        volume: Volume,
+        slip: SlipCorrelation,
+        thermo: &impl HasTwoPhase<Fluid = TopFluid>,
+    ) -> Result<Charge<N>, ChargeError> {
+        charge(&self.top, &self.cumulative_ua, volume, slip, thermo)
+    }
+
+    /// Estimates the fluid mass held by the bottom stream.
+    ///
+    /// `volume` is the bottom stream's total internal volume.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ChargeError`] if the volume is invalid or the phase split
+    /// of a node cannot be determined.
+    pub fn bottom_charge(
+        &self,
+        volume: Volume,
+        slip: SlipCorrelation,
+        thermo: &impl HasTwoPhase<Fluid = BottomFluid>,
+    ) -> Result<Charge<N>, ChargeError> {
+        charge(&self.bottom, &self.cumulative_ua, volume, slip, thermo)
+    }
+}
+
+/// Charges each node with its share of `volume` at its in-situ density.
+fn charge<Fluid, const N: usize>(
+    states: &[State<Fluid>; N],
+    cumulative_ua: &[ThermalConductance; N],
+    volume: Volume,
+    slip: SlipCorrelation,
+    thermo: &impl HasTwoPhase<Fluid = Fluid>,
+) -> Result<Charge<N>, ChargeError> {
+    if !(volume >= Volume::ZERO && volume.is_finite()) {
+        return Err(ChargeError::InvalidVolume(volume));
+    }
+
+    // Without heat transfer there is no conductance to apportion, so the
+    // segments share the volume equally.
+    let total_ua = cumulative_ua[N - 1];
+    #[allow(clippy::cast_precision_loss)]
+    let segment_volume = |i: usize| {
+        if total_ua > ThermalConductance::ZERO {
+            volume * ((cumulative_ua[i + 1] - cumulative_ua[i]) / total_ua)
+        } else {
+            volume / (N - 1) as f64
+        }
+    };
+
+    let mut nodes = [Mass::ZERO; N];
+    for (node, state) in states.iter().enumerate() {
+        let left = if node > 0 {
+            segment_volume(node - 1)
+        } else {
+            Volume::ZERO
+        };
+        let right = if node < N - 1 {
+            segment_volume(node)
+        } else {
+            Volume::ZERO
+        };
+
+        let density = thermo
+            .two_phase(state)
+            .map_err(|source| ChargeError::ThermoModelFailed { node, source })?
+            .map_or(state.density, |two_phase| slip.mixture_density(&two_phase));
+
+        nodes[node] = density * (left + right) * 0.5;
+    }
+
+    Ok(Charge {
+        nodes,
+        total: nodes.iter().copied().sum(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::{MassRate, Power, TemperatureInterval},
+        mass::kilogram,
+        mass_density::kilogram_per_cubic_meter,
+        mass_rate::kilogram_per_second,
+        power::kilowatt,
+        temperature_interval::kelvin as delta_kelvin,
+        thermal_conductance::watt_per_kelvin,
+        thermodynamic_temperature::kelvin,
+        volume::cubic_meter,
+    };
+
+    use crate::models::thermal::hx::discretized::core::{
+        DiscretizedHx, Given, HeatTransferRate, Inlets, Known, MassFlows, MinDeltaT, PressureDrops,
+        test_support::{TestFluid, TestThermoModel, state},
+    };
+    use crate::support::{hx::arrangement::CounterFlow, thermo::capability::ThermoModel};
+
+    /// Evaporating stream whose quality rises by 0.1 per kelvin above 300 K.
+    struct Evaporating;
+
+    impl ThermoModel for Evaporating {
+        type Fluid = TestFluid;
+    }
+
+    impl HasTwoPhase for Evaporating {
+        fn two_phase(&self, state: &State<TestFluid>) -> Result<Option<TwoPhase>, PropertyError> {
+            let quality = (state.temperature.get::<kelvin>() - 300.0) / 10.0;
+            if !(0.0..=1.0).contains(&quality) {
+                return Ok(None);
+            }
+            Ok(Some(TwoPhase {
+                quality: Ratio::new::<ratio>(quality),
+                liquid_density: MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+                vapor_density: MassDensity::new::<kilogram_per_cubic_meter>(10.0),
+            }))
+        }
+    }
+
+    fn two_phase(quality: f64) -> TwoPhase {
+        TwoPhase {
+            quality: Ratio::new::<ratio>(quality),
+            liquid_density: MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+            vapor_density: MassDensity::new::<kilogram_per_cubic_meter>(10.0),
+        }
+    }
+
+    fn evaporator_results(
+        top_temperatures: [f64; 3],
+        cumulative_ua: [f64; 3],
+    ) -> Results<TestFluid, TestFluid, 3> {
+        let ua = cumulative_ua.map(ThermalConductance::new::<watt_per_kelvin>);
+        Results {
+            top: top_temperatures.map(state),
+            bottom: [state(330.0); 3],
+            q_dot: HeatTransferRate::BottomToTop(Power::new::<kilowatt>(1.0)),
+            ua: ua[2],
+            cumulative_ua: ua,
+            min_delta_t: MinDeltaT {
+                value: TemperatureInterval::new::<delta_kelvin>(20.0),
+                node: 2,
+            },
+        }
+    }
+
+    #[test]
+    fn void_fraction_spans_saturated_liquid_to_vapor() {
+        for slip in [
+            SlipCorrelation::Homogeneous,
+            SlipCorrelation::Zivi,
+            SlipCorrelation::Chisholm,
+        ] {
+            assert_relative_eq!(slip.void_fraction(&two_phase(0.0)).get::<ratio>(), 0.0);
+            assert_relative_eq!(slip.void_fraction(&two_phase(1.0)).get::<ratio>(), 1.0);
+        }
+
+        // Homogeneous: α = 0.5 / (0.5 + 0.5·0.01).
+        let homogeneous = SlipCorrelation::Homogeneous.void_fraction(&two_phase(0.5));
+        assert_relative_eq!(homogeneous.get::<ratio>(), 1.0 / 1.01, epsilon = 1e-12);
+
+        // Zivi: S = 100^(1/3).
+        let zivi = SlipCorrelation::Zivi.void_fraction(&two_phase(0.5));
+        let s = 100.0_f64.cbrt();
+        assert_relative_eq!(zivi.get::<ratio>(), 1.0 / (1.0 + 0.01 * s), epsilon = 1e-12);
+        assert!(zivi < homogeneous);
+    }
+
+    #[test]
+    fn homogeneous_mixture_density_matches_quality_average() {
+        // 1/ρ = x/ρ_v + (1 − x)/ρ_l
+        let density = SlipCorrelation::Homogeneous.mixture_density(&two_phase(0.25));
+        let expected = 1.0 / (0.25 / 10.0 + 0.75 / 1000.0);
+        assert_relative_eq!(
+            density.get::<kilogram_per_cubic_meter>(),
+            expected,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn single_phase_charge_is_density_times_volume() {
+        let model = TestThermoModel::new();
+        let known = Known {
+            inlets: Inlets {
+                top: state(400.0),
+                bottom: state(300.0),
+            },
+            m_dot: MassFlows::new_unchecked(
+                MassRate::new::<kilogram_per_second>(1.0),
+                MassRate::new::<kilogram_per_second>(1.0),
+            ),
+            dp: PressureDrops::default(),
+        };
+        let results = DiscretizedHx::<CounterFlow, 5>::solve_same(
+            &known,
+            Given::HeatTransferRate(HeatTransferRate::TopToBottom(Power::new::<kilowatt>(50.0))),
+            &model,
+        )
+        .expect("solve should succeed");
+
+        let volume = Volume::new::<cubic_meter>(2.0);
+        let charge = results
+            .top_charge(volume, SlipCorrelation::Zivi, &model)
+            .expect("charge should succeed");
+
+        // The test model has a uniform density of 1 kg/m³.
+        assert_relative_eq!(charge.total.get::<kilogram>(), 2.0, epsilon = 1e-12);
+        // Balanced counterflow has a uniform ΔT, so every segment carries the
+        // same conductance and interior nodes hold twice the end nodes.
+        assert_relative_eq!(charge.nodes[0].get::<kilogram>(), 0.25, epsilon = 1e-9);
+        assert_relative_eq!(charge.nodes[2].get::<kilogram>(), 0.5, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn two_phase_nodes_use_void_fraction_density() {
+        // Saturated liquid in, quality 0.5 midway, saturated vapor out.
+        let results = evaporator_results([300.0, 305.0, 310.0], [0.0, 100.0, 400.0]);
+        let volume = Volume::new::<cubic_meter>(0.01);
+
+        let charge = |slip| {
+            results
+                .top_charge(volume, slip, &Evaporating)
+                .expect("charge should succeed")
+        };
+        let homogeneous = charge(SlipCorrelation::Homogeneous);
+        let zivi = charge(SlipCorrelation::Zivi);
+
+        // Segment volumes follow conductance: 1/4 and 3/4 of the total.
+        let node_volumes = [0.125 * 0.01, 0.5 * 0.01, 0.375 * 0.01];
+        let densities = [1000.0, 1.0 / (0.5 / 10.0 + 0.5 / 1000.0), 10.0];
+        for i in 0..3 {
+            assert_relative_eq!(
+                homogeneous.nodes[i].get::<kilogram>(),
+                densities[i] * node_volumes[i],
+                epsilon = 1e-12
+            );
+        }
+
+        // Slip holds more liquid in the two-phase node only.
+        assert_relative_eq!(
+            homogeneous.nodes[0].get::<kilogram>(),
+            zivi.nodes[0].get::<kilogram>()
+        );
+        assert!(zivi.nodes[1] > homogeneous.nodes[1]);
+        assert_relative_eq!(
+            homogeneous.nodes[2].get::<kilogram>(),
+            zivi.nodes[2].get::<kilogram>()
+        );
+        assert!(zivi.total > homogeneous.total);
+    }
+
+    #[test]
+    fn rejects_invalid_volume() {
+        let results = evaporator_results([300.0, 305.0, 310.0], [0.0, 100.0, 400.0]);
+
+        for volume in [-1.0, f64::NAN] {
+            let result = results.top_charge(
+                Volume::new::<cubic_meter>(volume),
+                SlipCorrelation::Homogeneous,
+                &Evaporating,
+            );
+            assert!(matches!(result, Err(ChargeError::InvalidVolume(_))));
+        }
+    }
+}
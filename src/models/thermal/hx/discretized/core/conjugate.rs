@@ -0,0 +1,638 @@
+//! Iterative solver with the separating wall resolved between the streams.
+//!
+//! Instead of a single combined UA, each side of the wall has its own film
+//! coefficient, evaluated from the local fluid state at every node, and the
+//! wall has its own through-thickness conductance. Each segment's
+//! conductance is the series combination of the three, so the exchange
+//! surface is shared among segments according to the local resistances.
+//! The solver varies the top stream outlet temperature until the segments
+//! use exactly the whole surface.
+
+mod problem;
+
+use twine_solvers::equation::{
+    bisection,
+    bracket::{Bracket, Sign},
+};
+use uom::{
+    ConstZero,
+    si::{
+        f64::{
+            Area, HeatTransfer, Ratio, TemperatureInterval, ThermalConductance,
+            ThermodynamicTemperature,
+        },
+        ratio::ratio,
+        temperature_interval::kelvin as delta_kelvin,
+        thermal_conductance::watt_per_kelvin,
+        thermodynamic_temperature::kelvin,
+    },
+};
+
+use crate::support::{
+    constraint::AboveAbsoluteZero,
+    thermo::State,
+    units::{TemperatureDifference, display::pretty},
+};
+
+use super::{
+    Known, Results, SolveError,
+    given_ua::GivenUaModel,
+    traits::{DiscretizedArrangement, DiscretizedHxThermoModel},
+};
+
+use problem::ConjugateProblem;
+
+/// Separating wall and the film coefficients on either side of it.
+///
+/// The film closures return the local heat transfer coefficient for a
+/// stream state, typically from a convection correlation evaluated with
+/// the state's properties. They are called at every node for every
+/// candidate outlet temperature.
+#[derive(Debug, Clone, Copy)]
+pub struct ConjugateWall<TopFilm, BottomFilm> {
+    /// Heat transfer area wetted by the top stream.
+    pub top_area: Area,
+
+    /// Heat transfer area wetted by the bottom stream.
+    pub bottom_area: Area,
+
+    /// Conductance through the wall thickness over the whole exchanger,
+    /// `k·A_m / t` for a plane wall.
+    pub wall_conductance: ThermalConductance,
+
+    /// Top stream film coefficient at a local state.
+    pub top_film: TopFilm,
+
+    /// Bottom stream film coefficient at a local state.
+    pub bottom_film: BottomFilm,
+}
+
+/// Solver configuration for a conjugate wall solve.
+#[derive(Debug, Clone, Copy)]
+pub struct ConjugateConfig {
+    /// Maximum iteration count for the bisection solve.
+    pub max_iters: usize,
+
+    /// Absolute tolerance for the outlet temperature search variable.
+    pub temp_tol: TemperatureInterval,
+
+    /// Absolute tolerance on the share of the surface used by the segments,
+    /// which is one at the solution.
+    pub area_tol: Ratio,
+}
+
+impl Default for ConjugateConfig {
+    fn default() -> Self {
+        Self {
+            max_iters: 100,
+            temp_tol: TemperatureInterval::new::<delta_kelvin>(1e-12),
+            area_tol: Ratio::new::<ratio>(1e-12),
+        }
+    }
+}
+
+impl ConjugateConfig {
+    /// Converts this configuration into a bisection solver configuration.
+    fn bisection(&self) -> bisection::Config {
+        bisection::Config {
+            max_iters: self.max_iters,
+            x_abs_tol: self.temp_tol.get::<delta_kelvin>(),
+            x_rel_tol: 0.0,
+            residual_tol: self.area_tol.get::<ratio>(),
+        }
+    }
+}
+
+/// Which side of the wall a film belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WallSide {
+    /// The side wetted by the top stream.
+    Top,
+
+    /// The side wetted by the bottom stream.
+    Bottom,
+}
+
+/// Errors that can occur while solving with a conjugate wall.
+#[derive(Debug, thiserror::Error)]
+pub enum ConjugateError {
+    /// A wall area or the wall conductance is not strictly positive and finite.
+    #[error("invalid wall: {0}")]
+    InvalidWall(&'static str),
+
+    /// A film closure returned a coefficient that is not strictly positive
+    /// and finite.
+    #[error("invalid {side:?} film coefficient at node {node}")]
+    InvalidFilm {
+        /// Side of the wall.
+        side: WallSide,
+
+        /// Node at which the coefficient was evaluated.
+        node: usize,
+    },
+
+    /// An inlet temperature is not above absolute zero.
+    #[error("{stream} inlet temperature must be above absolute zero, got {}", pretty(*.temperature))]
+    InvalidInletTemperature {
+        /// Stream the inlet belongs to (`"top"` or `"bottom"`).
+        stream: &'static str,
+
+        /// Rejected inlet temperature.
+        temperature: ThermodynamicTemperature,
+    },
+
+    /// The inlet temperatures are equal.
+    #[error("equal inlet temperatures: solver cannot form a search bracket")]
+    EqualInletTemperatures,
+
+    /// A discretized heat exchanger solve failed.
+    #[error("discretized solve failed")]
+    Solve(#[from] SolveError),
+
+    /// The bisection solver encountered an error.
+    #[error("bisection solver error")]
+    Bisection(#[from] bisection::Error),
+
+    /// The solver reached the iteration limit without converging.
+    #[error("solver hit iteration limit: residual={residual:?}")]
+    MaxIters {
+        /// Best residual achieved in the share of the surface used.
+        residual: Ratio,
+
+        /// Iteration count performed by the solver.
+        iters: usize,
+    },
+}
+
+/// Wall surface temperatures and film conductances at a node.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WallNode {
+    /// Wall temperature on the top stream's side.
+    pub top_surface: ThermodynamicTemperature,
+
+    /// Wall temperature on the bottom stream's side.
+    pub bottom_surface: ThermodynamicTemperature,
+
+    /// Top film coefficient times the top area, at this node's state.
+    pub top_film: ThermalConductance,
+
+    /// Bottom film coefficient times the bottom area, at this node's state.
+    pub bottom_film: ThermalConductance,
+}
+
+/// Results from a conjugate wall solve.
+#[derive(Debug, Clone)]
+pub struct ConjugateResults<TopFluid, BottomFluid, const N: usize> {
+    /// Heat exchanger node states and performance metrics.
+    pub results: Results<TopFluid, BottomFluid, N>,
+
+    /// Number of bisection iterations performed.
+    pub iterations: usize,
+
+    /// Wall state at each node, ordered from left (0) to right (N-1).
+    pub wall: [WallNode; N],
+
+    /// Share of the exchange surface accumulated from node 0 to each node.
+    ///
+    /// The first entry is zero and the last is one.
+    pub cumulative_area: [Ratio; N],
+
+    /// Top film conductance accumulated from node 0 to each node.
+    ///
+    /// Differences between neighbouring entries give each segment's top
+    /// film conductance. In each segment the two films and the segment's
+    /// share of the wall conductance combine in series to the segment's
+    /// share of [`Results::cumulative_ua`].
+    pub cumulative_top_ua: [ThermalConductance; N],
+
+    /// Bottom film conductance accumulated from node 0 to each node.
+    pub cumulative_bottom_ua: [ThermalConductance; N],
+}
+
+/// Node film conductances and the share of the surface each segment needs.
+struct Surface<const N: usize> {
+    top_film: [ThermalConductance; N],
+    bottom_film: [ThermalConductance; N],
+    cumulative_area: [f64; N],
+}
+
+impl<TopFilm, BottomFilm> ConjugateWall<TopFilm, BottomFilm> {
+    /// Evaluates the films at every node and the surface share each segment
+    /// needs for its conductance.
+    fn surface<TopFluid, BottomFluid, const N: usize>(
+        &self,
+        results: &Results<TopFluid, BottomFluid, N>,
+    ) -> Result<Surface<N>, ConjugateError>
+    where
+        TopFilm: Fn(&State<TopFluid>) -> HeatTransfer,
+        BottomFilm: Fn(&State<BottomFluid>) -> HeatTransfer,
+    {
+        let film = |h: HeatTransfer, area: Area, side, node| {
+            let g = h * area;
+            if g > ThermalConductance::ZERO && g.is_finite() {
+                Ok(g)
+            } else {
+                Err(ConjugateError::InvalidFilm { side, node })
+            }
+        };
+
+        let mut top_film = [ThermalConductance::ZERO; N];
+        let mut bottom_film = [ThermalConductance::ZERO; N];
+        let mut resistance = [0.0; N];
+        for k in 0..N {
+            top_film[k] = film(
+                (self.top_film)(&results.top[k]),
+                self.top_area,
+                WallSide::Top,
+                k,
+            )?;
+            bottom_film[k] = film(
+                (self.bottom_film)(&results.bottom[k]),
+                self.bottom_area,
+                WallSide::Bottom,
+                k,
+            )?;
+            resistance[k] = [top_film[k], self.wall_conductance, bottom_film[k]]
+                .iter()
+                .map(|g| 1.0 / g.get::<watt_per_kelvin>())
+                .sum();
+        }
+
+        // Each segment's resistance is the mean of its end nodes'.
+        let mut cumulative_area = [0.0; N];
+        for i in 0..(N - 1) {
+            let ua =
+                (results.cumulative_ua[i + 1] - results.cumulative_ua[i]).get::<watt_per_kelvin>();
+            cumulative_area[i + 1] =
+                cumulative_area[i] + ua * (resistance[i] + resistance[i + 1]) / 2.0;
+        }
+
+        Ok(Surface {
+            top_film,
+            bottom_film,
+            cumulative_area,
+        })
+    }
+
+    /// Checks the wall areas and conductance.
+    fn validate(&self) -> Result<(), ConjugateError> {
+        if !(self.top_area > Area::ZERO
+            && self.top_area.is_finite()
+            && self.bottom_area > Area::ZERO
+            && self.bottom_area.is_finite())
+        {
+            return Err(ConjugateError::InvalidWall(
+                "areas must be strictly positive and finite",
+            ));
+        }
+        if !(self.wall_conductance > ThermalConductance::ZERO && self.wall_conductance.is_finite())
+        {
+            return Err(ConjugateError::InvalidWall(
+                "wall conductance must be strictly positive and finite",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Solves a discretized heat exchanger whose conductance comes from the
+/// film coefficients on either side of a conjugate wall.
+///
+/// Uses bisection on the top stream outlet temperature. At each candidate,
+/// the base solver gives every segment's required conductance, and the
+/// local film and wall resistances convert it to the share of the surface
+/// the segment needs. The solution uses the whole surface.
+///
+/// # Errors
+///
+/// Returns [`ConjugateError`] on an invalid wall or film coefficient,
+/// non-physical results, thermodynamic model failures, or if the solver
+/// fails to converge.
+pub(super) fn conjugate<Arrangement, TopFluid, BottomFluid, TopFilm, BottomFilm, const N: usize>(
+    known: &Known<TopFluid, BottomFluid>,
+    wall: &ConjugateWall<TopFilm, BottomFilm>,
+    config: ConjugateConfig,
+    thermo_top: &impl DiscretizedHxThermoModel<TopFluid>,
+    thermo_bottom: &impl DiscretizedHxThermoModel<BottomFluid>,
+) -> Result<ConjugateResults<TopFluid, BottomFluid, N>, ConjugateError>
+where
+    Arrangement: DiscretizedArrangement + Default,
+    TopFluid: Clone,
+    BottomFluid: Clone,
+    TopFilm: Fn(&State<TopFluid>) -> HeatTransfer,
+    BottomFilm: Fn(&State<BottomFluid>) -> HeatTransfer,
+{
+    const {
+        assert!(
+            N >= 2,
+            "discretized heat exchanger requires at least 2 nodes (inlet and outlet)"
+        );
+    };
+
+    wall.validate()?;
+    for (stream, temperature) in [
+        ("top", known.inlets.top.temperature),
+        ("bottom", known.inlets.bottom.temperature),
+    ] {
+        if AboveAbsoluteZero::new(temperature).is_err() {
+            return Err(ConjugateError::InvalidInletTemperature {
+                stream,
+                temperature,
+            });
+        }
+    }
+
+    let t_top_in = known.inlets.top.temperature.get::<kelvin>();
+    let t_bottom_in = known.inlets.bottom.temperature.get::<kelvin>();
+
+    // Exact equality is intentional — with identical inlet temperatures
+    // the bisection bracket collapses to zero width.
+    #[allow(clippy::float_cmp)]
+    if t_top_in == t_bottom_in {
+        return Err(ConjugateError::EqualInletTemperatures);
+    }
+
+    let model = GivenUaModel::<Arrangement, _, _, _, _, N>::new(known, thermo_top, thermo_bottom);
+    let problem = ConjugateProblem::new(wall);
+
+    // As for a given UA: no heat transfer uses none of the surface, and
+    // reaching the other inlet temperature needs an unbounded surface.
+    let ends = if t_top_in < t_bottom_in {
+        ((t_top_in, Sign::Negative), (t_bottom_in, Sign::Positive))
+    } else {
+        ((t_bottom_in, Sign::Positive), (t_top_in, Sign::Negative))
+    };
+    let bracket =
+        Bracket::new(ends.0, ends.1).expect("bracket is valid: endpoints differ and signs oppose");
+
+    let solution = bisection::solve_from_bracket(
+        &model,
+        &problem,
+        bracket,
+        &config.bisection(),
+        |event: &bisection::Event<'_, _, _>| {
+            #[cfg(feature = "tracing")]
+            crate::support::numerics::solve::trace_bisection(event);
+
+            // A second-law violation is an overshoot, as in `given_ua`.
+            if matches!(
+                event,
+                bisection::Event::ModelFailed {
+                    error: SolveError::SecondLawViolation { .. },
+                    ..
+                }
+            ) {
+                return Some(bisection::Action::assume_positive());
+            }
+            None
+        },
+    )
+    .map_err(|error| match error {
+        bisection::Error::Problem(source) => match source.downcast::<ConjugateError>() {
+            Ok(error) => *error,
+            Err(source) => ConjugateError::Bisection(bisection::Error::Problem(source)),
+        },
+        other => ConjugateError::Bisection(other),
+    })?;
+
+    if solution.status != bisection::Status::Converged {
+        return Err(ConjugateError::MaxIters {
+            residual: Ratio::new::<ratio>(solution.residual),
+            iters: solution.iters,
+        });
+    }
+
+    let results = solution.snapshot.output;
+    let surface = wall.surface(&results)?;
+    Ok(finish(results, solution.iters, wall, &surface))
+}
+
+/// Assembles the wall state from a converged solve.
+fn finish<TopFluid, BottomFluid, TopFilm, BottomFilm, const N: usize>(
+    results: Results<TopFluid, BottomFluid, N>,
+    iterations: usize,
+    wall: &ConjugateWall<TopFilm, BottomFilm>,
+    surface: &Surface<N>,
+) -> ConjugateResults<TopFluid, BottomFluid, N> {
+    let Surface {
+        top_film,
+        bottom_film,
+        cumulative_area,
+    } = surface;
+
+    // The local flux times the whole surface, from top to bottom.
+    let wall_nodes = std::array::from_fn(|k| {
+        let resistance = 1.0 / top_film[k].get::<watt_per_kelvin>()
+            + 1.0 / wall.wall_conductance.get::<watt_per_kelvin>()
+            + 1.0 / bottom_film[k].get::<watt_per_kelvin>();
+        let delta_t = results.top[k]
+            .temperature
+            .minus(results.bottom[k].temperature)
+            .get::<delta_kelvin>();
+        let flux = delta_t / resistance;
+        let drop = |g: ThermalConductance| {
+            TemperatureInterval::new::<delta_kelvin>(flux / g.get::<watt_per_kelvin>())
+        };
+        WallNode {
+            top_surface: results.top[k].temperature.plus(-drop(top_film[k])),
+            bottom_surface: results.bottom[k].temperature.plus(drop(bottom_film[k])),
+            top_film: top_film[k],
+            bottom_film: bottom_film[k],
+        }
+    });
+
+    // Each segment's films are its share of the surface over the mean of its
+    // end nodes' film resistances.
+    let cumulative_film = |film: &[ThermalConductance; N]| {
+        let mut cumulative = [ThermalConductance::ZERO; N];
+        for i in 0..(N - 1) {
+            let share = cumulative_area[i + 1] - cumulative_area[i];
+            let resistance = f64::midpoint(
+                1.0 / film[i].get::<watt_per_kelvin>(),
+                1.0 / film[i + 1].get::<watt_per_kelvin>(),
+            );
+            cumulative[i + 1] =
+                cumulative[i] + ThermalConductance::new::<watt_per_kelvin>(share / resistance);
+        }
+        cumulative
+    };
+
+    ConjugateResults {
+        cumulative_top_ua: cumulative_film(top_film),
+        cumulative_bottom_ua: cumulative_film(bottom_film),
+        cumulative_area: cumulative_area.map(Ratio::new::<ratio>),
+        wall: wall_nodes,
+        iterations,
+        results,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        area::square_meter, f64::MassRate, heat_transfer::watt_per_square_meter_kelvin,
+        mass_rate::kilogram_per_second,
+    };
+
+    use crate::{
+        models::thermal::hx::discretized::core::{
+            DiscretizedHx, GivenUaConfig, Inlets, MassFlows, PressureDrops,
+            test_support::{TestFluid, TestThermoModel, state},
+        },
+        support::hx::arrangement::{CounterFlow, ParallelFlow},
+    };
+
+    fn known() -> Known<TestFluid, TestFluid> {
+        Known {
+            inlets: Inlets {
+                top: state(400.0),
+                bottom: state(300.0),
+            },
+            m_dot: MassFlows::new_unchecked(
+                MassRate::new::<kilogram_per_second>(2.0),
+                MassRate::new::<kilogram_per_second>(3.0),
+            ),
+            dp: PressureDrops::default(),
+        }
+    }
+
+    fn h(value: f64) -> HeatTransfer {
+        HeatTransfer::new::<watt_per_square_meter_kelvin>(value)
+    }
+
+    fn wall<TopFilm, BottomFilm>(
+        top_film: TopFilm,
+        bottom_film: BottomFilm,
+    ) -> ConjugateWall<TopFilm, BottomFilm> {
+        ConjugateWall {
+            top_area: Area::new::<square_meter>(2.0),
+            bottom_area: Area::new::<square_meter>(3.0),
+            wall_conductance: ThermalConductance::new::<watt_per_kelvin>(6000.0),
+            top_film,
+            bottom_film,
+        }
+    }
+
+    #[test]
+    fn constant_films_match_the_combined_ua() {
+        let model = TestThermoModel::new();
+
+        // 2000 W/K, 6000 W/K, and 3000 W/K in series make 1000 W/K.
+        let wall = wall(
+            |_: &State<TestFluid>| h(1000.0),
+            |_: &State<TestFluid>| h(1000.0),
+        );
+        let solved = conjugate::<CounterFlow, _, _, _, _, 6>(
+            &known(),
+            &wall,
+            ConjugateConfig::default(),
+            &model,
+            &model,
+        )
+        .unwrap();
+        let combined = DiscretizedHx::<CounterFlow, 6>::given_ua(
+            &known(),
+            ThermalConductance::new::<watt_per_kelvin>(1000.0),
+            GivenUaConfig::default(),
+            &model,
+            &model,
+        )
+        .unwrap();
+
+        assert_relative_eq!(
+            solved.results.top[5].temperature.get::<kelvin>(),
+            combined.results.top[5].temperature.get::<kelvin>(),
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            solved.cumulative_area[5].get::<ratio>(),
+            1.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            solved.cumulative_top_ua[5].get::<watt_per_kelvin>(),
+            2000.0,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            solved.cumulative_bottom_ua[5].get::<watt_per_kelvin>(),
+            3000.0,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn wall_surfaces_lie_between_the_streams() {
+        let model = TestThermoModel::new();
+
+        // A film that strengthens as the fluid warms shifts surface toward
+        // the cold end.
+        let warming = |s: &State<TestFluid>| h(3.0 * s.temperature.get::<kelvin>());
+        let wall = wall(warming, warming);
+        let solved = conjugate::<ParallelFlow, _, _, _, _, 11>(
+            &known(),
+            &wall,
+            ConjugateConfig::default(),
+            &model,
+            &model,
+        )
+        .unwrap();
+
+        for (k, node) in solved.wall.iter().enumerate() {
+            let top = solved.results.top[k].temperature;
+            let bottom = solved.results.bottom[k].temperature;
+            assert!(bottom <= node.bottom_surface);
+            assert!(node.bottom_surface < node.top_surface);
+            assert!(node.top_surface <= top);
+        }
+
+        // Films are weakest at the coldest node, the bottom inlet.
+        let bottom_films = solved.wall.map(|node| node.bottom_film);
+        assert_eq!(
+            bottom_films
+                .iter()
+                .enumerate()
+                .min_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+                .map(|(k, _)| k),
+            Some(0)
+        );
+        assert_relative_eq!(
+            solved.cumulative_area[10].get::<ratio>(),
+            1.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_walls_and_films() {
+        type Film = fn(&State<TestFluid>) -> HeatTransfer;
+
+        let model = TestThermoModel::new();
+        let solve = |wall: &ConjugateWall<_, _>| {
+            conjugate::<CounterFlow, _, _, _, _, 6>(
+                &known(),
+                wall,
+                ConjugateConfig::default(),
+                &model,
+                &model,
+            )
+        };
+
+        let film: Film = |_| h(1000.0);
+        let mut thin = wall(film, film);
+        thin.top_area = Area::ZERO;
+        assert!(matches!(solve(&thin), Err(ConjugateError::InvalidWall(_))));
+
+        let fouled = wall(film, (|_| h(0.0)) as Film);
+        assert!(matches!(
+            solve(&fouled),
+            Err(ConjugateError::InvalidFilm {
+                side: WallSide::Bottom,
+                node: 0,
+            })
+        ));
+    }
+}
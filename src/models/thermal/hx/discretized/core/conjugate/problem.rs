@@ -0,0 +1,57 @@
+//! Problem formulation for conjugate wall solving.
+
+use std::marker::PhantomData;
+
+use twine_core::EquationProblem;
+use uom::si::{
+    f64::{HeatTransfer, ThermodynamicTemperature},
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::{models::thermal::hx::discretized::core::Results, support::thermo::State};
+
+use super::{ConjugateError, ConjugateWall};
+
+/// Equation problem definition for conjugate wall solving.
+///
+/// Computes the residual as the share of the surface the segments need,
+/// less one.
+pub(super) struct ConjugateProblem<'a, TopFluid, BottomFluid, TopFilm, BottomFilm, const N: usize> {
+    wall: &'a ConjugateWall<TopFilm, BottomFilm>,
+    _fluids: PhantomData<(TopFluid, BottomFluid)>,
+}
+
+impl<'a, TopFluid, BottomFluid, TopFilm, BottomFilm, const N: usize>
+    ConjugateProblem<'a, TopFluid, BottomFluid, TopFilm, BottomFilm, N>
+{
+    pub(super) fn new(wall: &'a ConjugateWall<TopFilm, BottomFilm>) -> Self {
+        Self {
+            wall,
+            _fluids: PhantomData,
+        }
+    }
+}
+
+impl<TopFluid, BottomFluid, TopFilm, BottomFilm, const N: usize> EquationProblem<1>
+    for ConjugateProblem<'_, TopFluid, BottomFluid, TopFilm, BottomFilm, N>
+where
+    TopFilm: Fn(&State<TopFluid>) -> HeatTransfer,
+    BottomFilm: Fn(&State<BottomFluid>) -> HeatTransfer,
+{
+    type Input = ThermodynamicTemperature;
+    type Output = Results<TopFluid, BottomFluid, N>;
+    type Error = ConjugateError;
+
+    fn input(&self, x: &[f64; 1]) -> Result<Self::Input, Self::Error> {
+        Ok(ThermodynamicTemperature::new::<kelvin>(x[0]))
+    }
+
+    fn residuals(
+        &self,
+        _input: &Self::Input,
+        output: &Self::Output,
+    ) -> Result<[f64; 1], Self::Error> {
+        let surface = self.wall.surface(output)?;
+        Ok([surface.cumulative_area[N - 1] - 1.0])
+    }
+}
@@ -6,11 +6,16 @@
 
 mod config;
 mod error;
+mod iteration;
 mod problem;
+mod wall;
+mod warm_start;
 
 pub use config::GivenUaConfig;
 pub use error::GivenUaError;
+pub use iteration::GivenUaIteration;
 
+use twine_core::{EquationProblem, Model};
 use twine_solvers::equation::{
     bisection,
     bracket::{Bracket, Sign},
@@ -23,12 +28,15 @@ use uom::{
     },
 };
 
+use crate::support::constraint::AboveAbsoluteZero;
+
 use super::{
     Given, HeatTransferRate, Known, Results, SolveError,
     traits::{DiscretizedArrangement, DiscretizedHxThermoModel},
 };
 
-use problem::{GivenUaModel, GivenUaProblem};
+pub(super) use problem::GivenUaModel;
+use problem::GivenUaProblem;
 
 /// Results from a `given_ua` solve, including the node states and iteration count.
 #[derive(Debug, Clone)]
@@ -37,7 +45,15 @@ pub struct GivenUaResults<TopFluid, BottomFluid, const N: usize> {
     pub results: Results<TopFluid, BottomFluid, N>,
 
     /// Number of bisection iterations performed.
+    ///
+    /// Includes the probes around [`GivenUaConfig::warm_start`], if any.
     pub iterations: usize,
+
+    /// Heat conducted axially through the separating wall.
+    ///
+    /// Already included in `results.q_dot`. [`HeatTransferRate::None`] when
+    /// [`GivenUaConfig::wall_conductance`] is zero.
+    pub wall_q_dot: HeatTransferRate,
 }
 
 /// Solves a discretized heat exchanger given a target conductance (UA).
@@ -46,7 +62,13 @@ pub struct GivenUaResults<TopFluid, BottomFluid, const N: usize> {
 /// the solver is skipped and zero-transfer results are returned immediately.
 ///
 /// Uses bisection to iteratively find the top stream outlet temperature that
-/// achieves the specified thermal conductance.
+/// achieves the specified thermal conductance. `observer` is called with
+/// every candidate evaluated, including the probes around a warm start, so it
+/// sees as many calls as the reported iterations.
+///
+/// With a nonzero [`GivenUaConfig::wall_conductance`], the heat conducted
+/// along the wall is removed from the inlets before matching UA, then added
+/// back to the reported heat transfer rate.
 ///
 /// # Errors
 ///
@@ -58,6 +80,7 @@ pub(super) fn given_ua<Arrangement, TopFluid, BottomFluid, const N: usize>(
     config: GivenUaConfig,
     thermo_top: &impl DiscretizedHxThermoModel<TopFluid>,
     thermo_bottom: &impl DiscretizedHxThermoModel<BottomFluid>,
+    mut observer: impl FnMut(&GivenUaIteration),
 ) -> Result<GivenUaResults<TopFluid, BottomFluid, N>, GivenUaError>
 where
     Arrangement: DiscretizedArrangement + Default,
@@ -71,29 +94,35 @@ where
         );
     };
 
-    if target_ua < ThermalConductance::ZERO {
-        return Err(GivenUaError::NegativeUa(target_ua));
-    }
+    validate(known, target_ua)?;
+
+    let shifted =
+        wall::shift_inlets::<Arrangement, _, _>(known, &config, thermo_top, thermo_bottom)?;
+    let surface_known = shifted.as_ref().map_or(known, |shifted| &shifted.known);
+    let finish = |results, iterations| {
+        finish::<Arrangement, _, _, N>(results, iterations, known, shifted.as_ref())
+    };
 
     if target_ua == ThermalConductance::ZERO {
         let results = super::DiscretizedHx::<Arrangement, N>::solve(
-            known,
+            surface_known,
             Given::HeatTransferRate(HeatTransferRate::None),
             thermo_top,
             thermo_bottom,
         )?;
-        return Ok(GivenUaResults {
-            results,
-            iterations: 0,
-        });
+        return Ok(finish(results, 0));
     }
 
-    let model = GivenUaModel::<Arrangement, _, _, _, _, N>::new(known, thermo_top, thermo_bottom);
+    #[cfg(feature = "tracing")]
+    let _span = enter_span::<N>(target_ua);
+
+    let model =
+        GivenUaModel::<Arrangement, _, _, _, _, N>::new(surface_known, thermo_top, thermo_bottom);
 
     let problem = GivenUaProblem::new(target_ua);
 
-    let t_top_in = known.inlets.top.temperature.get::<kelvin>();
-    let t_bottom_in = known.inlets.bottom.temperature.get::<kelvin>();
+    let t_top_in = surface_known.inlets.top.temperature.get::<kelvin>();
+    let t_bottom_in = surface_known.inlets.bottom.temperature.get::<kelvin>();
 
     // Exact equality is intentional — with identical inlet temperatures
     // the bisection bracket collapses to zero width.
@@ -107,38 +136,49 @@ where
     // - At T_out = T_bottom_in: infinite UA (or second-law violation), residual is positive.
     // Bracket requires left < right, so order by value and assign signs
     // based on which endpoint is which.
-    let bracket = if t_top_in < t_bottom_in {
-        Bracket::new((t_top_in, Sign::Negative), (t_bottom_in, Sign::Positive))
+    let ends = if t_top_in < t_bottom_in {
+        ((t_top_in, Sign::Negative), (t_bottom_in, Sign::Positive))
     } else {
-        Bracket::new((t_bottom_in, Sign::Positive), (t_top_in, Sign::Negative))
-    }
-    .expect("bracket is valid: endpoints differ and signs oppose");
+        ((t_bottom_in, Sign::Positive), (t_top_in, Sign::Negative))
+    };
+
+    // Probes around a warm start are observed and count toward the reported
+    // iterations.
+    let (ends, probes) = match config.warm_start {
+        Some(guess) => warm_start::narrow(
+            &model,
+            target_ua,
+            ends,
+            guess,
+            config.warm_start_window,
+            &mut observer,
+        ),
+        None => (ends, 0),
+    };
+    let bracket =
+        Bracket::new(ends.0, ends.1).expect("bracket is valid: endpoints differ and signs oppose");
 
+    let mut iteration = probes;
     let solution = bisection::solve_from_bracket(
         &model,
         &problem,
         bracket,
         &config.bisection(),
         |event: &bisection::Event<'_, _, _>| {
-            // A second-law violation during midpoint iteration is a
-            // genuine overshoot — the candidate outlet temperature
-            // exceeds physical limits, so UA is overestimated.
-            // Other model errors (thermo backend failures, etc.)
-            // are not recoverable and propagate as solver failures.
-            if matches!(
-                event,
-                bisection::Event::ModelFailed {
-                    error: SolveError::SecondLawViolation { .. },
-                    ..
-                }
-            ) {
-                return Some(bisection::Action::assume_positive());
-            }
-            None
+            iteration += 1;
+            observe(iteration, event, &mut observer)
         },
     )?;
 
-    let iterations = solution.iters;
+    let iterations = solution.iters + probes;
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        iterations,
+        residual = solution.residual,
+        status = ?solution.status,
+        "given_ua finished"
+    );
 
     if solution.status != bisection::Status::Converged {
         return Err(GivenUaError::MaxIters {
@@ -147,10 +187,102 @@ where
         });
     }
 
-    Ok(GivenUaResults {
-        results: solution.snapshot.output,
+    Ok(finish(solution.snapshot.output, iterations))
+}
+
+/// Checks the target conductance and the inlet temperatures.
+fn validate<TopFluid, BottomFluid>(
+    known: &Known<TopFluid, BottomFluid>,
+    target_ua: ThermalConductance,
+) -> Result<(), GivenUaError> {
+    if target_ua < ThermalConductance::ZERO {
+        return Err(GivenUaError::NegativeUa(target_ua));
+    }
+
+    for (stream, temperature) in [
+        ("top", known.inlets.top.temperature),
+        ("bottom", known.inlets.bottom.temperature),
+    ] {
+        if AboveAbsoluteZero::new(temperature).is_err() {
+            return Err(GivenUaError::InvalidInletTemperature {
+                stream,
+                temperature,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds any wall conduction back onto the surface results.
+fn finish<Arrangement, TopFluid, BottomFluid, const N: usize>(
+    mut results: Results<TopFluid, BottomFluid, N>,
+    iterations: usize,
+    known: &Known<TopFluid, BottomFluid>,
+    shifted: Option<&wall::Shifted<TopFluid, BottomFluid>>,
+) -> GivenUaResults<TopFluid, BottomFluid, N>
+where
+    Arrangement: DiscretizedArrangement,
+    TopFluid: Clone,
+    BottomFluid: Clone,
+{
+    let wall_q_dot = match shifted {
+        Some(shifted) => {
+            wall::restore::<Arrangement, _, _, N>(&mut results, known, shifted.q_signed);
+            HeatTransferRate::from_signed_top_to_bottom(shifted.q_signed)
+                .expect("wall heat is a nonzero number")
+        }
+        None => HeatTransferRate::None,
+    };
+    GivenUaResults {
+        results,
         iterations,
-    })
+        wall_q_dot,
+    }
+}
+
+/// Opens the tracing span covering one `given_ua` solve.
+#[cfg(feature = "tracing")]
+fn enter_span<const N: usize>(target_ua: ThermalConductance) -> tracing::span::EnteredSpan {
+    tracing::debug_span!(
+        "given_ua",
+        target_ua = target_ua.get::<watt_per_kelvin>(),
+        nodes = N,
+    )
+    .entered()
+}
+
+/// Reports one bisection event to the observer and picks the solver action.
+///
+/// A second-law violation during midpoint iteration is a genuine overshoot —
+/// the candidate outlet temperature exceeds physical limits, so UA is
+/// overestimated. Other model errors (thermo backend failures, etc.) are not
+/// recoverable and propagate as solver failures.
+fn observe<M, P, TopFluid, BottomFluid, const N: usize>(
+    iteration: usize,
+    event: &bisection::Event<'_, M, P>,
+    observer: &mut impl FnMut(&GivenUaIteration),
+) -> Option<bisection::Action>
+where
+    M: Model<Output = Results<TopFluid, BottomFluid, N>, Error = SolveError>,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+    P::Error: core::fmt::Display,
+{
+    #[cfg(feature = "tracing")]
+    crate::support::numerics::solve::trace_bisection(event);
+
+    observer(&GivenUaIteration::from_event(iteration, event));
+
+    if matches!(
+        event,
+        bisection::Event::ModelFailed {
+            error: SolveError::SecondLawViolation { .. },
+            ..
+        }
+    ) {
+        return Some(bisection::Action::assume_positive());
+    }
+    None
 }
 
 #[cfg(test)]
@@ -159,8 +291,10 @@ mod tests {
 
     use approx::assert_relative_eq;
     use uom::si::{
-        f64::{MassRate, ThermodynamicTemperature},
+        f64::{MassRate, TemperatureInterval, ThermodynamicTemperature},
         mass_rate::kilogram_per_second,
+        power::watt,
+        temperature_interval::kelvin as delta_kelvin,
         thermal_conductance::kilowatt_per_kelvin,
         thermodynamic_temperature::kelvin,
     };
@@ -169,7 +303,7 @@ mod tests {
         DiscretizedHx, Given, HeatTransferRate, Inlets, Known, MassFlows, PressureDrops,
         test_support::{TestThermoModel, state},
     };
-    use crate::support::hx::arrangement::CounterFlow;
+    use crate::support::hx::arrangement::{CounterFlow, ParallelFlow};
 
     #[test]
     fn roundtrip() {
@@ -201,6 +335,7 @@ mod tests {
             GivenUaConfig::default(),
             &model,
             &model,
+            |_| {},
         )
         .expect("ua solve should succeed");
 
@@ -211,6 +346,98 @@ mod tests {
         );
     }
 
+    #[test]
+    fn warm_start_narrows_the_bracket() {
+        let model = TestThermoModel::new();
+
+        let known = Known {
+            inlets: Inlets {
+                top: state(400.0),
+                bottom: state(300.0),
+            },
+            m_dot: MassFlows::new_unchecked(
+                MassRate::new::<kilogram_per_second>(2.0),
+                MassRate::new::<kilogram_per_second>(3.0),
+            ),
+            dp: PressureDrops::default(),
+        };
+        let target_ua = ThermalConductance::new::<kilowatt_per_kelvin>(4.0);
+        let solve = |warm_start: Option<f64>| {
+            let config = GivenUaConfig {
+                temp_tol: TemperatureInterval::new::<delta_kelvin>(1e-9),
+                warm_start: warm_start.map(ThermodynamicTemperature::new::<kelvin>),
+                ..GivenUaConfig::default()
+            };
+            given_ua::<CounterFlow, _, _, 5>(&known, target_ua, config, &model, &model, |_| {})
+                .expect("ua solve should succeed")
+        };
+
+        let cold = solve(None);
+        let t_out = cold.results.top[4].temperature.get::<kelvin>();
+
+        // A nearby guess brackets the answer within the probe window.
+        let warm = solve(Some(t_out + 0.05));
+        assert_relative_eq!(
+            warm.results.top[4].temperature.get::<kelvin>(),
+            t_out,
+            epsilon = 1e-8
+        );
+        assert!(warm.iterations + 5 < cold.iterations);
+
+        // A poor guess only trims the bracket but reaches the same answer.
+        let poor = solve(Some(t_out - 3.0));
+        assert_relative_eq!(
+            poor.results.top[4].temperature.get::<kelvin>(),
+            t_out,
+            epsilon = 1e-8
+        );
+
+        // A guess outside the inlet span is ignored.
+        let ignored = solve(Some(500.0));
+        assert_eq!(ignored.iterations, cold.iterations);
+    }
+
+    #[test]
+    fn observer_sees_every_iteration_of_a_warm_start() {
+        let model = TestThermoModel::new();
+
+        let known = Known {
+            inlets: Inlets {
+                top: state(400.0),
+                bottom: state(300.0),
+            },
+            m_dot: MassFlows::new_unchecked(
+                MassRate::new::<kilogram_per_second>(2.0),
+                MassRate::new::<kilogram_per_second>(3.0),
+            ),
+            dp: PressureDrops::default(),
+        };
+        let target_ua = ThermalConductance::new::<kilowatt_per_kelvin>(4.0);
+        let solve = |warm_start: Option<f64>| {
+            let config = GivenUaConfig {
+                warm_start: warm_start.map(ThermodynamicTemperature::new::<kelvin>),
+                ..GivenUaConfig::default()
+            };
+            let mut observed = Vec::new();
+            let result = given_ua::<CounterFlow, _, _, 5>(
+                &known,
+                target_ua,
+                config,
+                &model,
+                &model,
+                |iteration| observed.push(iteration.iteration),
+            )
+            .expect("ua solve should succeed");
+            (result, observed)
+        };
+
+        let (cold, _) = solve(None);
+        let t_out = cold.results.top[4].temperature.get::<kelvin>();
+
+        let (warm, observed) = solve(Some(t_out + 0.05));
+        assert_eq!(observed, (1..=warm.iterations).collect::<Vec<_>>());
+    }
+
     #[test]
     fn zero_returns_no_heat_transfer() {
         let model = TestThermoModel::new();
@@ -233,6 +460,7 @@ mod tests {
             GivenUaConfig::default(),
             &model,
             &model,
+            |_| {},
         )
         .expect("zero ua solve should succeed");
 
@@ -268,11 +496,48 @@ mod tests {
             GivenUaConfig::default(),
             &model,
             &model,
+            |_| {},
         );
 
         assert!(matches!(result, Err(GivenUaError::NegativeUa(_))));
     }
 
+    #[test]
+    fn inlet_below_absolute_zero_returns_error() {
+        let model = TestThermoModel::new();
+
+        let known = Known {
+            inlets: Inlets {
+                top: state(400.0),
+                bottom: state(-5.0),
+            },
+            m_dot: MassFlows::new_unchecked(
+                MassRate::new::<kilogram_per_second>(2.0),
+                MassRate::new::<kilogram_per_second>(3.0),
+            ),
+            dp: PressureDrops::default(),
+        };
+
+        let result = given_ua::<CounterFlow, _, _, 5>(
+            &known,
+            ThermalConductance::new::<watt_per_kelvin>(100.0),
+            GivenUaConfig::default(),
+            &model,
+            &model,
+            |_| {},
+        );
+
+        let Err(GivenUaError::InvalidInletTemperature {
+            stream,
+            temperature,
+        }) = result
+        else {
+            panic!("expected an invalid inlet temperature");
+        };
+        assert_eq!(stream, "bottom");
+        assert_relative_eq!(temperature.get::<kelvin>(), -5.0);
+    }
+
     #[test]
     fn handles_second_law_violations_during_iteration() {
         let model = TestThermoModel::new();
@@ -292,12 +557,14 @@ mod tests {
             dp: PressureDrops::default(),
         };
 
+        let mut history = Vec::new();
         let result = given_ua::<CounterFlow, _, _, 5>(
             &known,
             ThermalConductance::new::<kilowatt_per_kelvin>(2.0),
             GivenUaConfig::default(),
             &model,
             &model,
+            |iteration| history.push(*iteration),
         )
         .expect("solver should converge despite violations during iteration");
 
@@ -306,5 +573,93 @@ mod tests {
             2.0,
             epsilon = 1e-12
         );
+
+        // Every evaluated candidate is reported, including the violations.
+        assert_eq!(history.len(), result.iterations);
+        assert!(history.iter().any(|step| step.ua.is_none()));
+        assert!(
+            history
+                .iter()
+                .enumerate()
+                .all(|(index, step)| step.iteration == index + 1)
+        );
+
+        let last = history.last().expect("at least one iteration");
+        assert_relative_eq!(
+            last.top_outlet_temp.get::<kelvin>(),
+            result.results.top[4].temperature.get::<kelvin>(),
+            epsilon = 1e-9
+        );
+        let [low, high] = last.bracket;
+        assert!(low <= last.top_outlet_temp && last.top_outlet_temp <= high);
+    }
+
+    #[test]
+    fn wall_conduction_is_reported_and_validated() {
+        let model = TestThermoModel::new();
+
+        let known = Known {
+            inlets: Inlets {
+                top: state(400.0),
+                bottom: state(300.0),
+            },
+            m_dot: MassFlows::new_unchecked(
+                MassRate::new::<kilogram_per_second>(2.0),
+                MassRate::new::<kilogram_per_second>(3.0),
+            ),
+            dp: PressureDrops::default(),
+        };
+        let config = |wall_wpk| GivenUaConfig {
+            wall_conductance: ThermalConductance::new::<watt_per_kelvin>(wall_wpk),
+            ..GivenUaConfig::default()
+        };
+        let ua = ThermalConductance::new::<kilowatt_per_kelvin>(10.0);
+
+        let ideal = given_ua::<CounterFlow, _, _, 5>(
+            &known,
+            ua,
+            GivenUaConfig::default(),
+            &model,
+            &model,
+            |_| {},
+        )
+        .unwrap();
+        assert_eq!(ideal.wall_q_dot, HeatTransferRate::None);
+
+        let result =
+            given_ua::<CounterFlow, _, _, 5>(&known, ua, config(10.0), &model, &model, |_| {})
+                .unwrap();
+        assert_relative_eq!(
+            result.wall_q_dot.signed_top_to_bottom().get::<watt>(),
+            1000.0
+        );
+        assert!(result.results.q_dot.magnitude() < ideal.results.q_dot.magnitude());
+        assert_relative_eq!(result.results.top[0].temperature.get::<kelvin>(), 400.0);
+        assert_relative_eq!(result.results.bottom[4].temperature.get::<kelvin>(), 300.0);
+
+        // With no surface, the wall alone still carries heat.
+        let wall_only = given_ua::<CounterFlow, _, _, 5>(
+            &known,
+            ThermalConductance::ZERO,
+            config(10.0),
+            &model,
+            &model,
+            |_| {},
+        )
+        .unwrap();
+        assert_eq!(wall_only.results.q_dot, wall_only.wall_q_dot);
+
+        assert!(matches!(
+            given_ua::<CounterFlow, _, _, 5>(&known, ua, config(-1.0), &model, &model, |_| {}),
+            Err(GivenUaError::InvalidWallConductance(_))
+        ));
+        assert!(matches!(
+            given_ua::<CounterFlow, _, _, 5>(&known, ua, config(5000.0), &model, &model, |_| {}),
+            Err(GivenUaError::ExcessiveWallConduction(_))
+        ));
+        assert!(matches!(
+            given_ua::<ParallelFlow, _, _, 5>(&known, ua, config(10.0), &model, &model, |_| {}),
+            Err(GivenUaError::WallConductionRequiresCounterflow)
+        ));
     }
 }
@@ -1,11 +1,14 @@
 use twine_solvers::equation::bisection;
-use uom::si::{
-    f64::{TemperatureInterval, ThermalConductance},
-    temperature_interval::kelvin as delta_kelvin,
-    thermal_conductance::watt_per_kelvin,
+use uom::{
+    ConstZero,
+    si::{
+        f64::{TemperatureInterval, ThermalConductance, ThermodynamicTemperature},
+        temperature_interval::kelvin as delta_kelvin,
+        thermal_conductance::watt_per_kelvin,
+    },
 };
 
-/// Solver configuration for iterative UA matching.
+/// Solver configuration and wall conduction for iterative UA matching.
 #[derive(Debug, Clone, Copy)]
 pub struct GivenUaConfig {
     /// Maximum iteration count for the bisection solve.
@@ -16,6 +19,28 @@ pub struct GivenUaConfig {
 
     /// Absolute tolerance for the UA residual (achieved - target).
     pub ua_tol: ThermalConductance,
+
+    /// Axial conductance of the separating wall, `k·A / L`.
+    ///
+    /// `k` is the wall conductivity, `A` its cross-sectional area normal to
+    /// the flow, and `L` the flow length. Heat conducted along the wall
+    /// bypasses the exchange surface, which limits the effectiveness of
+    /// high-NTU counterflow exchangers such as micro-channel and cryogenic
+    /// recuperators. Zero (the default) neglects axial conduction.
+    ///
+    /// Only supported for counterflow arrangements.
+    pub wall_conductance: ThermalConductance,
+
+    /// Top outlet temperature expected from a previous, nearby solve.
+    ///
+    /// The solver probes the UA residual at
+    /// `warm_start ± warm_start_window` and, if the target lies between the
+    /// probes, bisects from that narrow bracket instead of the full span
+    /// between the inlet temperatures. `None` (the default) starts cold.
+    pub warm_start: Option<ThermodynamicTemperature>,
+
+    /// Half-width of the bracket probed around [`warm_start`](Self::warm_start).
+    pub warm_start_window: TemperatureInterval,
 }
 
 impl Default for GivenUaConfig {
@@ -24,6 +49,9 @@ impl Default for GivenUaConfig {
             max_iters: 100,
             temp_tol: TemperatureInterval::new::<delta_kelvin>(1e-12),
             ua_tol: ThermalConductance::new::<watt_per_kelvin>(1e-12),
+            wall_conductance: ThermalConductance::ZERO,
+            warm_start: None,
+            warm_start_window: TemperatureInterval::new::<delta_kelvin>(0.1),
         }
     }
 }
@@ -1,16 +1,26 @@
 use thiserror::Error;
 use twine_solvers::equation::bisection;
-use uom::si::f64::ThermalConductance;
+use uom::si::f64::{Power, ThermalConductance, ThermodynamicTemperature};
 
-use crate::models::thermal::hx::discretized::core::SolveError;
+use crate::{models::thermal::hx::discretized::core::SolveError, support::units::display::pretty};
 
 /// Errors that can occur while solving given a target conductance (UA).
 #[derive(Debug, Error)]
 pub enum GivenUaError {
     /// The target UA is negative.
-    #[error("target UA must be non-negative, got {0:?}")]
+    #[error("target UA must be non-negative, got {}", pretty(*.0))]
     NegativeUa(ThermalConductance),
 
+    /// An inlet temperature is not above absolute zero.
+    #[error("{stream} inlet temperature must be above absolute zero, got {}", pretty(*.temperature))]
+    InvalidInletTemperature {
+        /// Stream the inlet belongs to (`"top"` or `"bottom"`).
+        stream: &'static str,
+
+        /// Rejected inlet temperature.
+        temperature: ThermodynamicTemperature,
+    },
+
     /// The inlet temperatures are equal.
     ///
     /// The solver brackets the outlet temperature between the two inlet
@@ -18,6 +28,22 @@ pub enum GivenUaError {
     #[error("equal inlet temperatures: solver cannot form a search bracket")]
     EqualInletTemperatures,
 
+    /// The wall conductance is negative or not finite.
+    #[error("wall conductance must be non-negative and finite, got {}", pretty(*.0))]
+    InvalidWallConductance(ThermalConductance),
+
+    /// Wall conduction was requested for an arrangement other than counterflow.
+    #[error("axial wall conduction is only supported for counterflow")]
+    WallConductionRequiresCounterflow,
+
+    /// Heat conducted along the wall closes the inlet temperature difference.
+    ///
+    /// The wall is conductive enough that no exchange surface is needed to
+    /// bring the streams together, so the parasitic conduction model no
+    /// longer applies.
+    #[error("axial wall conduction of {} closes the inlet temperature difference", pretty(*.0))]
+    ExcessiveWallConduction(Power),
+
     /// A discretized heat exchanger solve failed.
     #[error("discretized solve failed")]
     Solve(#[from] SolveError),
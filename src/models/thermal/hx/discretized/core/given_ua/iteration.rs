@@ -0,0 +1,93 @@
+use twine_core::{EquationProblem, Model};
+use twine_solvers::equation::{bisection, bracket::Bracket};
+use uom::si::{
+    f64::{ThermalConductance, ThermodynamicTemperature},
+    thermal_conductance::watt_per_kelvin,
+    thermodynamic_temperature::kelvin,
+};
+
+use super::Results;
+
+/// One candidate evaluation of a `given_ua` solve.
+///
+/// Passed to the observer of a UA-matching solve, such as
+/// [`RecuperatorGivenUa::call_observed`], after every candidate evaluation,
+/// including the probes around a warm start, so the full search trajectory
+/// can be recorded even when the solve fails to converge.
+///
+/// [`RecuperatorGivenUa::call_observed`]: crate::models::thermal::hx::discretized::RecuperatorGivenUa::call_observed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GivenUaIteration {
+    /// One-based iteration number.
+    pub iteration: usize,
+
+    /// Candidate top stream outlet temperature.
+    pub top_outlet_temp: ThermodynamicTemperature,
+
+    /// Conductance achieved at the candidate.
+    ///
+    /// `None` if the candidate could not be evaluated, e.g. because it
+    /// violates the second law.
+    pub ua: Option<ThermalConductance>,
+
+    /// UA residual (achieved − target), or `None` if the candidate could not
+    /// be evaluated.
+    pub residual: Option<ThermalConductance>,
+
+    /// Outlet temperature bracket at the time of the evaluation, low end first.
+    pub bracket: [ThermodynamicTemperature; 2],
+}
+
+impl GivenUaIteration {
+    pub(super) fn from_event<M, P, TopFluid, BottomFluid, const N: usize>(
+        iteration: usize,
+        event: &bisection::Event<'_, M, P>,
+    ) -> Self
+    where
+        M: Model<Output = Results<TopFluid, BottomFluid, N>>,
+        P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+    {
+        let (x, evaluated, bracket) = match event {
+            bisection::Event::Evaluated {
+                point,
+                output,
+                bracket,
+                ..
+            } => (point.x, Some((output.ua, point.residual)), bracket),
+            bisection::Event::ModelFailed { x, bracket, .. }
+            | bisection::Event::ProblemFailed { x, bracket, .. } => (*x, None, bracket),
+        };
+
+        Self {
+            iteration,
+            top_outlet_temp: ThermodynamicTemperature::new::<kelvin>(x),
+            ua: evaluated.map(|(ua, _)| ua),
+            residual: evaluated
+                .map(|(_, residual)| ThermalConductance::new::<watt_per_kelvin>(residual)),
+            bracket: temperatures(bracket),
+        }
+    }
+
+    /// Describes a warm-start probe, evaluated before bisection begins.
+    pub(super) fn probe(
+        iteration: usize,
+        x: f64,
+        ua: Option<ThermalConductance>,
+        target_ua: ThermalConductance,
+        bracket: [f64; 2],
+    ) -> Self {
+        Self {
+            iteration,
+            top_outlet_temp: ThermodynamicTemperature::new::<kelvin>(x),
+            ua,
+            residual: ua.map(|ua| ua - target_ua),
+            bracket: bracket.map(ThermodynamicTemperature::new::<kelvin>),
+        }
+    }
+}
+
+fn temperatures(bracket: &Bracket) -> [ThermodynamicTemperature; 2] {
+    bracket
+        .as_array()
+        .map(ThermodynamicTemperature::new::<kelvin>)
+}
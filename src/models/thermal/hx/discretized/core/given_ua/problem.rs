@@ -19,7 +19,7 @@ use crate::models::thermal::hx::discretized::core::{
 ///
 /// Wraps the base discretized solver and exposes the top stream outlet
 /// temperature as the sole input variable to the model.
-pub(super) struct GivenUaModel<
+pub(in crate::models::thermal::hx::discretized::core) struct GivenUaModel<
     'a,
     Arrangement,
     TopFluid,
@@ -37,7 +37,7 @@ pub(super) struct GivenUaModel<
 impl<'a, Arrangement, TopFluid, BottomFluid, TopThermo, BottomThermo, const N: usize>
     GivenUaModel<'a, Arrangement, TopFluid, BottomFluid, TopThermo, BottomThermo, N>
 {
-    pub(super) fn new(
+    pub(in crate::models::thermal::hx::discretized::core) fn new(
         known: &'a Known<TopFluid, BottomFluid>,
         thermo_top: &'a TopThermo,
         thermo_bottom: &'a BottomThermo,
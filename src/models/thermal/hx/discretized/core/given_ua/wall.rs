@@ -0,0 +1,140 @@
+//! Axial conduction through the separating wall.
+//!
+//! The wall is treated as a parasitic conductance `G = k·A / L` between the
+//! two ends of a counterflow exchanger. Heat `G·(T_hot,in − T_cold,in)`
+//! leaves the hot stream at its inlet and enters the cold stream at its
+//! inlet, bypassing the exchange surface. The exchanger then sees inlets
+//! that are closer together, which is what limits high-NTU performance.
+
+use uom::{
+    ConstZero,
+    si::f64::{Power, TemperatureInterval, ThermalConductance},
+};
+
+use crate::{
+    models::thermal::hx::discretized::core::{
+        HeatTransferRate, Known, Results, SolveError,
+        traits::{DiscretizedArrangement, DiscretizedHxThermoModel},
+    },
+    support::units::TemperatureDifference,
+};
+
+use super::{GivenUaConfig, GivenUaError};
+
+/// Inputs seen by the exchange surface once wall conduction is accounted for.
+pub(super) struct Shifted<TopFluid, BottomFluid> {
+    /// Known inputs with the conduction heat removed from the inlets.
+    pub known: Known<TopFluid, BottomFluid>,
+
+    /// Heat conducted through the wall, positive from top to bottom.
+    pub q_signed: Power,
+}
+
+/// Shifts the inlet states by the heat conducted axially through the wall.
+///
+/// Returns `None` when the wall carries no heat.
+///
+/// # Errors
+///
+/// Returns [`GivenUaError`] if the wall conductance is invalid, the
+/// arrangement is not counterflow, a thermo model call fails, or the
+/// conducted heat would bring the shifted inlets to or past each other.
+pub(super) fn shift_inlets<Arrangement, TopFluid, BottomFluid>(
+    known: &Known<TopFluid, BottomFluid>,
+    config: &GivenUaConfig,
+    thermo_top: &impl DiscretizedHxThermoModel<TopFluid>,
+    thermo_bottom: &impl DiscretizedHxThermoModel<BottomFluid>,
+) -> Result<Option<Shifted<TopFluid, BottomFluid>>, GivenUaError>
+where
+    Arrangement: DiscretizedArrangement,
+    TopFluid: Clone,
+    BottomFluid: Clone,
+{
+    let conductance = config.wall_conductance;
+    if !(conductance >= ThermalConductance::ZERO && conductance.is_finite()) {
+        return Err(GivenUaError::InvalidWallConductance(conductance));
+    }
+    if conductance == ThermalConductance::ZERO {
+        return Ok(None);
+    }
+    if Arrangement::BOTTOM_FLOWS_LEFT_TO_RIGHT {
+        return Err(GivenUaError::WallConductionRequiresCounterflow);
+    }
+
+    let top_in = &known.inlets.top;
+    let bottom_in = &known.inlets.bottom;
+    let inlet_delta_t = top_in.temperature.minus(bottom_in.temperature);
+    let q_signed = conductance * inlet_delta_t;
+    if q_signed == Power::ZERO {
+        return Ok(None);
+    }
+
+    let h_top = thermo_top
+        .enthalpy(top_in)
+        .map_err(|err| SolveError::thermo_failed("enthalpy(top inlet)", err))?;
+    let p_top = thermo_top
+        .pressure(top_in)
+        .map_err(|err| SolveError::thermo_failed("pressure(top inlet)", err))?;
+    let h_bottom = thermo_bottom
+        .enthalpy(bottom_in)
+        .map_err(|err| SolveError::thermo_failed("enthalpy(bottom inlet)", err))?;
+    let p_bottom = thermo_bottom
+        .pressure(bottom_in)
+        .map_err(|err| SolveError::thermo_failed("pressure(bottom inlet)", err))?;
+
+    let top = thermo_top
+        .state_from((
+            top_in.fluid.clone(),
+            p_top,
+            h_top - q_signed / known.m_dot.top(),
+        ))
+        .map_err(|err| {
+            SolveError::thermo_failed("state_from(top inlet after wall conduction)", err)
+        })?;
+    let bottom = thermo_bottom
+        .state_from((
+            bottom_in.fluid.clone(),
+            p_bottom,
+            h_bottom + q_signed / known.m_dot.bottom(),
+        ))
+        .map_err(|err| {
+            SolveError::thermo_failed("state_from(bottom inlet after wall conduction)", err)
+        })?;
+
+    // The conducted heat must leave the streams still ordered hot to cold,
+    // otherwise the parasitic model no longer describes the exchanger.
+    let shifted_delta_t = top.temperature.minus(bottom.temperature);
+    if shifted_delta_t.signum() != inlet_delta_t.signum()
+        || shifted_delta_t == TemperatureInterval::ZERO
+    {
+        return Err(GivenUaError::ExcessiveWallConduction(q_signed.abs()));
+    }
+
+    let mut shifted = known.clone();
+    shifted.inlets.top = top;
+    shifted.inlets.bottom = bottom;
+
+    Ok(Some(Shifted {
+        known: shifted,
+        q_signed,
+    }))
+}
+
+/// Restores the true inlet states and adds the conducted heat to the results.
+pub(super) fn restore<Arrangement, TopFluid, BottomFluid, const N: usize>(
+    results: &mut Results<TopFluid, BottomFluid, N>,
+    known: &Known<TopFluid, BottomFluid>,
+    q_signed: Power,
+) where
+    Arrangement: DiscretizedArrangement,
+    TopFluid: Clone,
+    BottomFluid: Clone,
+{
+    results.top[0] = known.inlets.top.clone();
+    results.bottom[Arrangement::bottom_select(0, N - 1)] = known.inlets.bottom.clone();
+
+    results.q_dot = HeatTransferRate::from_signed_top_to_bottom(
+        results.q_dot.signed_top_to_bottom() + q_signed,
+    )
+    .expect("wall conduction adds to surface heat transfer in the same direction");
+}
@@ -0,0 +1,77 @@
+//! Bracket narrowing from a previous solution.
+//!
+//! When consecutive solves differ only slightly, as in a time-stepping
+//! simulation, the previous top outlet temperature is a good estimate of the
+//! next one. Probing just either side of it usually yields a bracket far
+//! narrower than the span between the inlet temperatures.
+
+use twine_core::Model;
+use twine_solvers::equation::bracket::Sign;
+use uom::si::{
+    f64::{TemperatureInterval, ThermalConductance, ThermodynamicTemperature},
+    temperature_interval::kelvin as delta_kelvin,
+    thermal_conductance::watt_per_kelvin,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::models::thermal::hx::discretized::core::{GivenUaIteration, Results, SolveError};
+
+/// A bracket endpoint: outlet temperature in kelvin and its residual sign.
+pub(super) type End = (f64, Sign);
+
+/// Narrows the bracket `[left, right]` by probing either side of `guess`.
+///
+/// Probes outside the bracket, and probes whose solve fails for reasons
+/// other than a second-law violation, are skipped. A second-law violation
+/// counts as overshooting the target UA, as it does during bisection.
+/// `observer` is called with every probe, numbered from one.
+///
+/// Returns the narrowed ends and the number of probes evaluated.
+pub(super) fn narrow<TopFluid, BottomFluid, const N: usize>(
+    model: &impl Model<
+        Input = ThermodynamicTemperature,
+        Output = Results<TopFluid, BottomFluid, N>,
+        Error = SolveError,
+    >,
+    target_ua: ThermalConductance,
+    (left, right): (End, End),
+    guess: ThermodynamicTemperature,
+    window: TemperatureInterval,
+    observer: &mut impl FnMut(&GivenUaIteration),
+) -> ((End, End), usize) {
+    let guess = guess.get::<kelvin>();
+    let window = window.get::<delta_kelvin>().abs();
+    let target = target_ua.get::<watt_per_kelvin>();
+
+    let mut points = vec![left, right];
+    let mut probes = 0;
+    for x in [guess - window, guess + window] {
+        if !(x > left.0 && x < right.0) {
+            continue;
+        }
+        probes += 1;
+        let evaluated = model.call(&ThermodynamicTemperature::new::<kelvin>(x));
+        let ua = evaluated.as_ref().ok().map(|results| results.ua);
+        observer(&GivenUaIteration::probe(
+            probes,
+            x,
+            ua,
+            target_ua,
+            [left.0, right.0],
+        ));
+        let sign = match evaluated {
+            Ok(results) => Sign::of(results.ua.get::<watt_per_kelvin>() - target),
+            Err(SolveError::SecondLawViolation { .. }) => Sign::Positive,
+            Err(_) => continue,
+        };
+        points.push((x, sign));
+    }
+    points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let ends = points
+        .windows(2)
+        .find(|pair| pair[0].1 != pair[1].1)
+        .map_or((left, right), |pair| (pair[0], pair[1]));
+
+    (ends, probes)
+}
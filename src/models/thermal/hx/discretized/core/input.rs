@@ -6,6 +6,6 @@ mod pressure_drops;
 
 pub use given::Given;
 pub use inlets::Inlets;
-pub use known::Known;
+pub use known::{Known, KnownBuildError, KnownBuilder};
 pub use mass_flows::MassFlows;
 pub use pressure_drops::PressureDrops;
@@ -1,9 +1,24 @@
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::f64::{MassRate, Pressure},
+};
+
 use crate::models::thermal::hx::discretized::core::{Inlets, MassFlows, PressureDrops};
+use crate::support::{
+    constraint::{Constrained, ConstraintError, NonNegative, StrictlyPositive},
+    thermo::{PropertyError, State, capability::HasPressure},
+    units::display::pretty,
+};
 
 /// Core inputs for a discretized heat exchanger.
 ///
-/// Combined with a [`Given`] constraint, these inputs define the boundary
-/// problem without requiring thermodynamic property evaluation.
+/// Combined with a `Given` constraint (a target UA or an outlet
+/// temperature), these inputs define the boundary problem without requiring
+/// thermodynamic property evaluation.
+///
+/// Use [`Known::builder`] to assemble the inputs field by field with
+/// validation at [`KnownBuilder::build`].
 #[derive(Debug, Clone)]
 pub struct Known<TopFluid, BottomFluid> {
     /// Inlet states for the two streams.
@@ -15,3 +30,341 @@ pub struct Known<TopFluid, BottomFluid> {
     /// Total pressure drops for the two streams.
     pub dp: PressureDrops,
 }
+
+impl<TopFluid, BottomFluid> Known<TopFluid, BottomFluid> {
+    /// Returns a builder for [`Known`] inputs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use twine_models::{
+    ///     models::thermal::hx::discretized::Known,
+    ///     support::thermo::{State, fluid::Air, model::PerfectGas},
+    /// };
+    /// use uom::si::{
+    ///     f64::{MassDensity, MassRate, Pressure, ThermodynamicTemperature},
+    ///     mass_density::kilogram_per_cubic_meter,
+    ///     mass_rate::kilogram_per_second,
+    ///     pressure::kilopascal,
+    ///     thermodynamic_temperature::kelvin,
+    /// };
+    ///
+    /// let thermo = PerfectGas::<Air>::new().unwrap();
+    /// let state = |t| State::new(
+    ///     ThermodynamicTemperature::new::<kelvin>(t),
+    ///     MassDensity::new::<kilogram_per_cubic_meter>(1.0),
+    ///     Air,
+    /// );
+    ///
+    /// let known = Known::builder()
+    ///     .top_inlet(state(300.0))
+    ///     .bottom_inlet(state(500.0))
+    ///     .mass_flows(
+    ///         MassRate::new::<kilogram_per_second>(1.0),
+    ///         MassRate::new::<kilogram_per_second>(1.2),
+    ///     )
+    ///     .bottom_pressure_drop(Pressure::new::<kilopascal>(2.0))
+    ///     .build_checked(&thermo, &thermo)?;
+    ///
+    /// assert_eq!(known.dp.bottom(), Pressure::new::<kilopascal>(2.0));
+    /// # Ok::<(), twine_models::models::thermal::hx::discretized::KnownBuildError>(())
+    /// ```
+    #[must_use]
+    pub fn builder() -> KnownBuilder<TopFluid, BottomFluid> {
+        KnownBuilder {
+            top_inlet: None,
+            bottom_inlet: None,
+            top_m_dot: None,
+            bottom_m_dot: None,
+            top_dp: Pressure::ZERO,
+            bottom_dp: Pressure::ZERO,
+        }
+    }
+}
+
+/// Builder for [`Known`] inputs.
+///
+/// Both inlet states and both mass flow rates are required. Pressure drops
+/// default to zero.
+#[derive(Debug, Clone)]
+pub struct KnownBuilder<TopFluid, BottomFluid> {
+    top_inlet: Option<State<TopFluid>>,
+    bottom_inlet: Option<State<BottomFluid>>,
+    top_m_dot: Option<MassRate>,
+    bottom_m_dot: Option<MassRate>,
+    top_dp: Pressure,
+    bottom_dp: Pressure,
+}
+
+/// Errors from [`KnownBuilder`].
+#[derive(Debug, Error)]
+pub enum KnownBuildError {
+    /// A required field was not set.
+    #[error("missing {0}")]
+    Missing(&'static str),
+
+    /// A mass flow rate is not strictly positive.
+    #[error("{stream} mass flow rate is invalid")]
+    MassFlow {
+        /// Stream the mass flow belongs to (`"top"` or `"bottom"`).
+        stream: &'static str,
+
+        /// Violated constraint.
+        #[source]
+        source: ConstraintError,
+    },
+
+    /// A pressure drop is negative.
+    #[error("{stream} pressure drop is invalid")]
+    PressureDrop {
+        /// Stream the pressure drop belongs to (`"top"` or `"bottom"`).
+        stream: &'static str,
+
+        /// Violated constraint.
+        #[source]
+        source: ConstraintError,
+    },
+
+    /// A pressure drop is not less than the stream's inlet pressure.
+    #[error(
+        "{stream} pressure drop {} must be less than the inlet pressure {}",
+        pretty(*dp),
+        pretty(*inlet)
+    )]
+    PressureDropExceedsInlet {
+        /// Stream the pressure drop belongs to (`"top"` or `"bottom"`).
+        stream: &'static str,
+
+        /// Requested pressure drop.
+        dp: Pressure,
+
+        /// Inlet pressure of the stream.
+        inlet: Pressure,
+    },
+
+    /// The inlet pressure could not be evaluated.
+    #[error("failed to evaluate {stream} inlet pressure")]
+    Property {
+        /// Stream whose inlet pressure failed (`"top"` or `"bottom"`).
+        stream: &'static str,
+
+        /// Underlying property error.
+        #[source]
+        source: PropertyError,
+    },
+}
+
+impl<TopFluid, BottomFluid> KnownBuilder<TopFluid, BottomFluid> {
+    /// Sets the top stream inlet state.
+    #[must_use]
+    pub fn top_inlet(self, state: State<TopFluid>) -> Self {
+        Self {
+            top_inlet: Some(state),
+            ..self
+        }
+    }
+
+    /// Sets the bottom stream inlet state.
+    #[must_use]
+    pub fn bottom_inlet(self, state: State<BottomFluid>) -> Self {
+        Self {
+            bottom_inlet: Some(state),
+            ..self
+        }
+    }
+
+    /// Sets both mass flow rates.
+    #[must_use]
+    pub fn mass_flows(self, top: MassRate, bottom: MassRate) -> Self {
+        Self {
+            top_m_dot: Some(top),
+            bottom_m_dot: Some(bottom),
+            ..self
+        }
+    }
+
+    /// Sets the top stream pressure drop (`p_inlet - p_outlet`).
+    #[must_use]
+    pub fn top_pressure_drop(self, dp: Pressure) -> Self {
+        Self { top_dp: dp, ..self }
+    }
+
+    /// Sets the bottom stream pressure drop (`p_inlet - p_outlet`).
+    #[must_use]
+    pub fn bottom_pressure_drop(self, dp: Pressure) -> Self {
+        Self {
+            bottom_dp: dp,
+            ..self
+        }
+    }
+
+    /// Validates the fields and builds the inputs.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`KnownBuildError`] if an inlet or mass flow is missing, if
+    /// a mass flow is not strictly positive, or if a pressure drop is negative.
+    pub fn build(self) -> Result<Known<TopFluid, BottomFluid>, KnownBuildError> {
+        let top = self
+            .top_inlet
+            .ok_or(KnownBuildError::Missing("top inlet"))?;
+        let bottom = self
+            .bottom_inlet
+            .ok_or(KnownBuildError::Missing("bottom inlet"))?;
+        let top_m_dot = self
+            .top_m_dot
+            .ok_or(KnownBuildError::Missing("mass flows"))?;
+        let bottom_m_dot = self
+            .bottom_m_dot
+            .ok_or(KnownBuildError::Missing("mass flows"))?;
+
+        let m_dot = MassFlows::from_constrained(
+            positive_flow("top", top_m_dot)?,
+            positive_flow("bottom", bottom_m_dot)?,
+        );
+        let dp = PressureDrops::from_constrained(
+            non_negative_dp("top", self.top_dp)?,
+            non_negative_dp("bottom", self.bottom_dp)?,
+        );
+
+        Ok(Known {
+            inlets: Inlets { top, bottom },
+            m_dot,
+            dp,
+        })
+    }
+
+    /// Builds the inputs and checks each pressure drop against its inlet pressure.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`KnownBuildError`] for any error from [`build`](Self::build),
+    /// if an inlet pressure cannot be evaluated, or if a pressure drop is not
+    /// less than its stream's inlet pressure.
+    pub fn build_checked(
+        self,
+        thermo_top: &impl HasPressure<Fluid = TopFluid>,
+        thermo_bottom: &impl HasPressure<Fluid = BottomFluid>,
+    ) -> Result<Known<TopFluid, BottomFluid>, KnownBuildError> {
+        let known = self.build()?;
+
+        let p_top =
+            thermo_top
+                .pressure(&known.inlets.top)
+                .map_err(|source| KnownBuildError::Property {
+                    stream: "top",
+                    source,
+                })?;
+        check_dp("top", known.dp.top(), p_top)?;
+
+        let p_bottom = thermo_bottom
+            .pressure(&known.inlets.bottom)
+            .map_err(|source| KnownBuildError::Property {
+                stream: "bottom",
+                source,
+            })?;
+        check_dp("bottom", known.dp.bottom(), p_bottom)?;
+
+        Ok(known)
+    }
+}
+
+fn positive_flow(
+    stream: &'static str,
+    m_dot: MassRate,
+) -> Result<Constrained<MassRate, StrictlyPositive>, KnownBuildError> {
+    Constrained::new(m_dot).map_err(|source| KnownBuildError::MassFlow { stream, source })
+}
+
+fn non_negative_dp(
+    stream: &'static str,
+    dp: Pressure,
+) -> Result<Constrained<Pressure, NonNegative>, KnownBuildError> {
+    Constrained::new(dp).map_err(|source| KnownBuildError::PressureDrop { stream, source })
+}
+
+fn check_dp(stream: &'static str, dp: Pressure, inlet: Pressure) -> Result<(), KnownBuildError> {
+    if dp < inlet {
+        Ok(())
+    } else {
+        Err(KnownBuildError::PressureDropExceedsInlet { stream, dp, inlet })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uom::si::{mass_rate::kilogram_per_second, pressure::kilopascal};
+
+    use crate::models::thermal::hx::discretized::core::test_support::{
+        TestFluid, TestThermoModel, state,
+    };
+
+    fn kg_per_s(value: f64) -> MassRate {
+        MassRate::new::<kilogram_per_second>(value)
+    }
+
+    fn builder() -> KnownBuilder<TestFluid, TestFluid> {
+        Known::builder()
+            .top_inlet(state(300.0))
+            .bottom_inlet(state(400.0))
+            .mass_flows(kg_per_s(1.0), kg_per_s(2.0))
+    }
+
+    #[test]
+    fn defaults_pressure_drops_to_zero() {
+        let known = builder().build().unwrap();
+
+        assert_eq!(known.m_dot.bottom(), kg_per_s(2.0));
+        assert_eq!(known.dp.top(), Pressure::ZERO);
+        assert_eq!(known.dp.bottom(), Pressure::ZERO);
+    }
+
+    #[test]
+    fn reports_missing_and_invalid_fields() {
+        assert!(matches!(
+            Known::<TestFluid, TestFluid>::builder()
+                .top_inlet(state(300.0))
+                .build(),
+            Err(KnownBuildError::Missing("bottom inlet"))
+        ));
+        assert!(matches!(
+            builder().mass_flows(kg_per_s(1.0), kg_per_s(0.0)).build(),
+            Err(KnownBuildError::MassFlow {
+                stream: "bottom",
+                ..
+            })
+        ));
+        assert!(matches!(
+            builder()
+                .top_pressure_drop(Pressure::new::<kilopascal>(-1.0))
+                .build(),
+            Err(KnownBuildError::PressureDrop { stream: "top", .. })
+        ));
+    }
+
+    #[test]
+    fn checks_pressure_drop_against_inlet_pressure() {
+        // The test thermo model reports atmospheric pressure for every state.
+        let thermo = TestThermoModel::new();
+
+        let known = builder()
+            .bottom_pressure_drop(Pressure::new::<kilopascal>(100.0))
+            .build_checked(&thermo, &thermo)
+            .unwrap();
+        assert_eq!(known.dp.bottom(), Pressure::new::<kilopascal>(100.0));
+
+        let error = builder()
+            .bottom_pressure_drop(Pressure::new::<kilopascal>(101.325))
+            .build_checked(&thermo, &thermo)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            KnownBuildError::PressureDropExceedsInlet {
+                stream: "bottom",
+                ..
+            }
+        ));
+    }
+}
@@ -1,12 +1,12 @@
 //! Performance metrics for discretized heat exchangers.
 
 use crate::support::{
-    hx::{CapacitanceRate, Stream, StreamInlet, functional},
+    hx::{CapacitanceRate, Effectiveness, Ntu, NtuRelation, Stream, StreamInlet, functional},
     units::TemperatureDifference,
 };
 use uom::{
     ConstZero,
-    si::f64::{MassRate, TemperatureInterval, ThermalConductance},
+    si::f64::{MassRate, Power, TemperatureInterval, ThermalConductance},
     si::temperature_interval::kelvin as delta_kelvin,
 };
 
@@ -16,24 +16,35 @@ use super::{
     traits::DiscretizedArrangement,
 };
 
-/// Computes total UA using a segment-by-segment effectiveness-NTU analysis.
+/// Segment temperature change, in kelvin, below which a stream is treated as
+/// isothermal.
+const ISOTHERMAL_TOL_K: f64 = 1e-6;
+
+/// Computes UA using a segment-by-segment effectiveness-NTU analysis.
+///
+/// Returns the conductance accumulated from node 0 to each node, so the last
+/// entry is the total UA.
 pub(super) fn compute_ua<Arrangement, TopFluid, BottomFluid, const N: usize>(
     arrangement: &Arrangement,
     m_dot_top: MassRate,
     m_dot_bottom: MassRate,
     q_dot: HeatTransferRate,
     nodes: &Nodes<TopFluid, BottomFluid, N>,
-) -> Result<ThermalConductance, SolveError>
+) -> Result<[ThermalConductance; N], SolveError>
 where
     Arrangement: DiscretizedArrangement,
 {
+    let mut cumulative_ua = [ThermalConductance::ZERO; N];
+
     if q_dot == HeatTransferRate::None {
-        return Ok(ThermalConductance::ZERO);
+        return Ok(cumulative_ua);
     }
 
     let bottom_outlet_index = Arrangement::bottom_select(N - 1, 0);
-
-    let mut ua_total = ThermalConductance::ZERO;
+    let segment = Segment {
+        arrangement,
+        segments: N - 1,
+    };
 
     for i in 0..(N - 1) {
         let top_in = &nodes.top[i];
@@ -62,6 +73,23 @@ where
 
         let top_delta_t = top_out.temperature.minus(top_in.temperature);
         let top_delta_h = h_top_out - h_top_in;
+        let bottom_delta_t = bottom_out.temperature.minus(bottom_in.temperature);
+        let bottom_delta_h = h_bottom_out - h_bottom_in;
+
+        let is_isothermal =
+            |delta_t: TemperatureInterval| delta_t.get::<delta_kelvin>().abs() <= ISOTHERMAL_TOL_K;
+        if is_isothermal(top_delta_t) || is_isothermal(bottom_delta_t) {
+            let ua = isothermal_segment_ua(
+                nodes,
+                q_dot,
+                (m_dot_top * top_delta_h).abs(),
+                i,
+                bottom_outlet_index,
+            )?;
+            cumulative_ua[i + 1] = cumulative_ua[i] + ua;
+            continue;
+        }
+
         let c_dot_top = m_dot_top * top_delta_h / top_delta_t;
         let c_dot_top = CapacitanceRate::from_quantity(c_dot_top).map_err(|_| {
             segment_violation_error(
@@ -73,8 +101,6 @@ where
             )
         })?;
 
-        let bottom_delta_t = bottom_out.temperature.minus(bottom_in.temperature);
-        let bottom_delta_h = h_bottom_out - h_bottom_in;
         let c_dot_bottom = m_dot_bottom * bottom_delta_h / bottom_delta_t;
         let c_dot_bottom = CapacitanceRate::from_quantity(c_dot_bottom).map_err(|_| {
             segment_violation_error(
@@ -87,7 +113,7 @@ where
         })?;
 
         let functional::KnownConditionsResult { ua, .. } = functional::known_conditions_and_inlets(
-            arrangement,
+            &segment,
             (
                 StreamInlet::new(c_dot_top, top_in.temperature),
                 Stream::new_from_outlet_temperature(
@@ -107,10 +133,77 @@ where
             )
         })?;
 
-        ua_total += ua;
+        // The segment's effectiveness lies beyond what the arrangement can
+        // reach with any conductance.
+        if !ua.is_finite() {
+            return Err(segment_violation_error(
+                nodes,
+                q_dot,
+                segment_delta_t_hot_cold,
+                i,
+                bottom_outlet_index,
+            ));
+        }
+
+        cumulative_ua[i + 1] = cumulative_ua[i] + ua;
     }
 
-    Ok(ua_total)
+    Ok(cumulative_ua)
+}
+
+/// Computes the conductance of a segment in which one stream is isothermal.
+///
+/// A stream changing phase at constant temperature has no finite
+/// capacitance rate, and property backends flash such states with slight
+/// temperature noise. The segment is treated as the `Cr = 0` limit, where
+/// every arrangement reduces to `UA = q / LMTD`.
+fn isothermal_segment_ua<TopFluid, BottomFluid, const N: usize>(
+    nodes: &Nodes<TopFluid, BottomFluid, N>,
+    q_dot: HeatTransferRate,
+    segment_q_dot: Power,
+    segment_index: usize,
+    bottom_outlet_index: usize,
+) -> Result<ThermalConductance, SolveError> {
+    let hot_minus_cold = |k: usize| {
+        let delta_t = nodes.top[k].temperature.minus(nodes.bottom[k].temperature);
+        match q_dot {
+            HeatTransferRate::BottomToTop(_) => -delta_t,
+            HeatTransferRate::TopToBottom(_) | HeatTransferRate::None => delta_t,
+        }
+    };
+
+    let ends = [
+        hot_minus_cold(segment_index),
+        hot_minus_cold(segment_index + 1),
+    ];
+    if let Some(&end) = ends.iter().find(|end| **end <= TemperatureInterval::ZERO) {
+        return Err(segment_violation_error(
+            nodes,
+            q_dot,
+            end,
+            segment_index,
+            bottom_outlet_index,
+        ));
+    }
+
+    Ok(segment_q_dot / log_mean(ends))
+}
+
+/// One segment of a discretized arrangement, as an NTU relation.
+struct Segment<'a, Arrangement> {
+    arrangement: &'a Arrangement,
+    segments: usize,
+}
+
+impl<Arrangement: DiscretizedArrangement> NtuRelation for Segment<'_, Arrangement> {
+    fn ntu(&self, effectiveness: Effectiveness, capacitance_rates: [CapacitanceRate; 2]) -> Ntu {
+        let ntu = self.arrangement.segment_ntu(
+            self.segments,
+            effectiveness.value,
+            capacitance_rates.map(|rate| rate.value),
+        );
+        Ntu::new(ntu).expect("segment NTU should be non-negative")
+    }
 }
 
 /// Computes the minimum hot-to-cold temperature difference and its node index.
@@ -154,6 +247,15 @@ where
     }
 }
 
+/// Log-mean of two positive temperature differences.
+fn log_mean([a, b]: [TemperatureInterval; 2]) -> TemperatureInterval {
+    let ratio = (a / b).value;
+    if (ratio - 1.0).abs() < 1e-9 {
+        return (a + b) / 2.0;
+    }
+    (a - b) / ratio.ln()
+}
+
 /// Creates a second law violation error for a segment with invalid capacitance rate.
 fn segment_violation_error<TopFluid, BottomFluid, const N: usize>(
     nodes: &Nodes<TopFluid, BottomFluid, N>,
@@ -176,14 +278,24 @@ fn segment_violation_error<TopFluid, BottomFluid, const N: usize>(
 mod tests {
     use super::*;
 
-    use uom::si::{f64::MassRate, mass_rate::kilogram_per_second};
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::{MassRate, Power, Ratio, ThermodynamicTemperature},
+        mass_rate::kilogram_per_second,
+        power::kilowatt,
+        ratio::ratio,
+        thermal_conductance::kilowatt_per_kelvin,
+        thermodynamic_temperature::kelvin,
+    };
 
     use crate::models::thermal::hx::discretized::core::{
-        Given, HeatTransferRate, Inlets, Known, MassFlows, PressureDrops,
+        DiscretizedHx, Given, HeatTransferRate, Inlets, Known, MassFlows, PressureDrops,
         solve::Resolved,
-        test_support::{TestThermoModel, state},
+        test_support::{TestFluid, TestRefrigerant, TestThermoModel, state},
+    };
+    use crate::support::{
+        constraint::Constrained, hx::arrangement::CounterFlow, thermo::capability::StateFrom,
     };
-    use crate::support::hx::arrangement::CounterFlow;
 
     #[test]
     fn ua_is_zero_for_no_heat_transfer() {
@@ -212,7 +324,7 @@ mod tests {
         let nodes = Nodes::<_, _, 2>::new::<CounterFlow>(&resolved, &model, &model)
             .expect("discretization should succeed");
 
-        let ua = compute_ua(
+        let cumulative_ua = compute_ua(
             &CounterFlow,
             resolved.top.m_dot,
             resolved.bottom.m_dot,
@@ -221,6 +333,101 @@ mod tests {
         )
         .expect("metrics should succeed");
 
-        assert_eq!(ua, ThermalConductance::ZERO);
+        assert_eq!(cumulative_ua, [ThermalConductance::ZERO; 2]);
+    }
+
+    #[test]
+    fn condensing_against_evaporating_is_a_uniform_delta_t_exchanger() {
+        let model = TestRefrigerant::ammonia_like();
+        let saturated = |t, x| {
+            model
+                .state_from((
+                    TestFluid,
+                    ThermodynamicTemperature::new::<kelvin>(t),
+                    Constrained::new(Ratio::new::<ratio>(x)).unwrap(),
+                ))
+                .unwrap()
+        };
+
+        // 200 kW keeps both streams inside the dome.
+        let known = Known {
+            inlets: Inlets {
+                top: saturated(300.0, 1.0),
+                bottom: saturated(290.0, 0.2),
+            },
+            m_dot: MassFlows::new_unchecked(
+                MassRate::new::<kilogram_per_second>(1.0),
+                MassRate::new::<kilogram_per_second>(1.0),
+            ),
+            dp: PressureDrops::default(),
+        };
+        let results = DiscretizedHx::<CounterFlow, 5>::solve_same(
+            &known,
+            Given::HeatTransferRate(HeatTransferRate::TopToBottom(Power::new::<kilowatt>(200.0))),
+            &model,
+        )
+        .expect("solve should succeed");
+
+        assert_relative_eq!(
+            results.ua.get::<kilowatt_per_kelvin>(),
+            20.0,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn evaporating_against_liquid_matches_the_cr_zero_limit() {
+        let model = TestRefrigerant::ammonia_like();
+        let saturated = |t, x| {
+            model
+                .state_from((
+                    TestFluid,
+                    ThermodynamicTemperature::new::<kelvin>(t),
+                    Constrained::new(Ratio::new::<ratio>(x)).unwrap(),
+                ))
+                .unwrap()
+        };
+
+        // 22 kW cools the liquid from 300 K to 290 K against a stream boiling
+        // at 280 K, so UA = m * cp * ln(20 / 10) in every segment layout.
+        let known = Known {
+            inlets: Inlets {
+                top: saturated(280.0, 0.2),
+                bottom: saturated(300.0, 0.0),
+            },
+            m_dot: MassFlows::new_unchecked(
+                MassRate::new::<kilogram_per_second>(1.0),
+                MassRate::new::<kilogram_per_second>(1.0),
+            ),
+            dp: PressureDrops::default(),
+        };
+        let results = DiscretizedHx::<CounterFlow, 5>::solve_same(
+            &known,
+            Given::HeatTransferRate(HeatTransferRate::BottomToTop(Power::new::<kilowatt>(22.0))),
+            &model,
+        )
+        .expect("solve should succeed");
+
+        assert_relative_eq!(
+            results.ua.get::<kilowatt_per_kelvin>(),
+            2.2 * 2.0_f64.ln(),
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn log_mean_handles_equal_ends() {
+        let delta_t = |k| TemperatureInterval::new::<delta_kelvin>(k);
+
+        assert_relative_eq!(
+            log_mean([delta_t(20.0), delta_t(10.0)]).get::<delta_kelvin>(),
+            10.0 / 2.0_f64.ln(),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            log_mean([delta_t(10.0), delta_t(10.0)]).get::<delta_kelvin>(),
+            10.0,
+            max_relative = 1e-12
+        );
     }
 }
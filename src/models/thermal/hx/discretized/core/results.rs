@@ -25,6 +25,13 @@ pub struct Results<TopFluid, BottomFluid, const N: usize> {
     /// Total heat exchanger conductance.
     pub ua: ThermalConductance,
 
+    /// Conductance accumulated from node 0 to each node.
+    ///
+    /// The first entry is zero and the last equals [`ua`](Self::ua).
+    /// Differences between neighbouring entries give each segment's share of
+    /// the exchange surface.
+    pub cumulative_ua: [ThermalConductance; N],
+
     /// Minimum hot-to-cold temperature difference and its node.
     pub min_delta_t: MinDeltaT,
 }
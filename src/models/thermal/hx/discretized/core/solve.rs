@@ -47,7 +47,7 @@ where
     let min_delta_t = compute_min_delta_t::<Arrangement, _, _, N>(&nodes);
     SolveError::check_second_law(&resolved, min_delta_t)?;
 
-    let ua = compute_ua(
+    let cumulative_ua = compute_ua(
         &Arrangement::default(),
         resolved.top.m_dot,
         resolved.bottom.m_dot,
@@ -59,7 +59,8 @@ where
         top: nodes.top,
         bottom: nodes.bottom,
         q_dot: resolved.q_dot,
-        ua,
+        ua: cumulative_ua[N - 1],
+        cumulative_ua,
         min_delta_t,
     })
 }
@@ -80,14 +81,40 @@ mod tests {
 
     use crate::models::thermal::hx::discretized::core::{
         HeatTransferRate, Inlets, MassFlows, PressureDrops,
-        test_support::{TestThermoModel, state},
+        test_support::{TestFluid, TestThermoModel, state},
     };
     use crate::support::hx::{
-        CapacitanceRate, Stream, StreamInlet,
-        arrangement::{CounterFlow, ParallelFlow},
+        CapacitanceRate, NtuRelation, Stream, StreamInlet,
+        arrangement::{CounterFlow, CrossFlow, Mixed, ParallelFlow, ShellAndTube, Unmixed},
         functional,
     };
 
+    /// UA the functional solver needs for `q_dot` with constant properties.
+    fn functional_ua(
+        arrangement: &impl NtuRelation,
+        known: &Known<TestFluid, TestFluid>,
+        q_dot: HeatTransferRate,
+        model: &TestThermoModel,
+    ) -> f64 {
+        functional::known_conditions_and_inlets(
+            arrangement,
+            (
+                StreamInlet::new(
+                    CapacitanceRate::from_quantity(known.m_dot.top() * model.cp()).unwrap(),
+                    known.inlets.top.temperature,
+                ),
+                Stream::new_from_heat_flow(
+                    CapacitanceRate::from_quantity(known.m_dot.bottom() * model.cp()).unwrap(),
+                    known.inlets.bottom.temperature,
+                    HeatFlow::outgoing(q_dot.magnitude()).unwrap(),
+                ),
+            ),
+        )
+        .expect("functional solve should succeed")
+        .ua
+        .get::<kilowatt_per_kelvin>()
+    }
+
     #[test]
     fn rejects_second_law_violation() {
         let model = TestThermoModel::new();
@@ -278,4 +305,142 @@ mod tests {
             functional_result.ua.get::<kilowatt_per_kelvin>(),
         );
     }
+
+    #[test]
+    fn shell_and_tube_ua_matches_functional_solver() {
+        let model = TestThermoModel::new();
+
+        let known = Known {
+            inlets: Inlets {
+                top: state(400.0),
+                bottom: state(300.0),
+            },
+            m_dot: MassFlows::new_unchecked(
+                MassRate::new::<kilogram_per_second>(2.0),
+                MassRate::new::<kilogram_per_second>(3.0),
+            ),
+            dp: PressureDrops::default(),
+        };
+
+        let q_dot = HeatTransferRate::TopToBottom(Power::new::<kilowatt>(100.0));
+        let arrangement = ShellAndTube::<2, 4>::default();
+        let expected = functional_ua(&arrangement, &known, q_dot, &model);
+
+        // A single segment is the whole exchanger.
+        let single = solve::<ShellAndTube<2, 4>, _, _, 2>(
+            &known,
+            Given::HeatTransferRate(q_dot),
+            &model,
+            &model,
+        )
+        .expect("discretized solve should succeed");
+        assert_relative_eq!(
+            single.ua.get::<kilowatt_per_kelvin>(),
+            expected,
+            max_relative = 1e-12
+        );
+
+        // Finer segments see slightly different effectiveness along the
+        // exchanger but stay close to the lumped relation.
+        let fine = solve::<ShellAndTube<2, 4>, _, _, 11>(
+            &known,
+            Given::HeatTransferRate(q_dot),
+            &model,
+            &model,
+        )
+        .expect("discretized solve should succeed");
+        assert_relative_eq!(
+            fine.ua.get::<kilowatt_per_kelvin>(),
+            expected,
+            max_relative = 1e-2
+        );
+    }
+
+    #[test]
+    fn cross_flow_ua_matches_functional_solver_for_a_single_pass() {
+        let model = TestThermoModel::new();
+
+        let known = Known {
+            inlets: Inlets {
+                top: state(400.0),
+                bottom: state(300.0),
+            },
+            m_dot: MassFlows::new_unchecked(
+                MassRate::new::<kilogram_per_second>(2.0),
+                MassRate::new::<kilogram_per_second>(3.0),
+            ),
+            dp: PressureDrops::default(),
+        };
+
+        let q_dot = HeatTransferRate::TopToBottom(Power::new::<kilowatt>(60.0));
+        let arrangement = CrossFlow::<Mixed, Unmixed>::new();
+
+        let result = solve::<CrossFlow<Mixed, Unmixed>, _, _, 2>(
+            &known,
+            Given::HeatTransferRate(q_dot),
+            &model,
+            &model,
+        )
+        .expect("discretized solve should succeed");
+
+        assert_relative_eq!(
+            result.ua.get::<kilowatt_per_kelvin>(),
+            functional_ua(&arrangement, &known, q_dot, &model),
+            max_relative = 1e-12
+        );
+
+        // More passes approach counterflow, which needs less conductance.
+        let passes = solve::<CrossFlow<Mixed, Unmixed>, _, _, 6>(
+            &known,
+            Given::HeatTransferRate(q_dot),
+            &model,
+            &model,
+        )
+        .expect("discretized solve should succeed");
+        assert!(passes.ua < result.ua);
+        assert!(
+            passes.ua.get::<kilowatt_per_kelvin>()
+                > functional_ua(&CounterFlow, &known, q_dot, &model)
+        );
+    }
+
+    #[test]
+    fn shell_and_tube_rejects_effectiveness_beyond_its_limit() {
+        let model = TestThermoModel::new();
+
+        let known = Known {
+            inlets: Inlets {
+                top: state(400.0),
+                bottom: state(300.0),
+            },
+            m_dot: MassFlows::new_unchecked(
+                MassRate::new::<kilogram_per_second>(1.0),
+                MassRate::new::<kilogram_per_second>(1.0),
+            ),
+            dp: PressureDrops::default(),
+        };
+
+        // With balanced streams a single 1-2 shell tops out near 59%
+        // effectiveness; counterflow could still deliver 80 kW.
+        let q_dot = HeatTransferRate::TopToBottom(Power::new::<kilowatt>(80.0));
+
+        let result = solve::<ShellAndTube<1, 2>, _, _, 2>(
+            &known,
+            Given::HeatTransferRate(q_dot),
+            &model,
+            &model,
+        );
+        assert!(matches!(
+            result,
+            Err(SolveError::SecondLawViolation {
+                violation_node: Some(0),
+                ..
+            })
+        ));
+
+        assert!(
+            solve::<CounterFlow, _, _, 2>(&known, Given::HeatTransferRate(q_dot), &model, &model)
+                .is_ok()
+        );
+    }
 }
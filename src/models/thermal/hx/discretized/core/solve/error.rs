@@ -54,7 +54,7 @@ pub enum SolveError {
 
 impl SolveError {
     /// Creates a thermo model failure error with context.
-    pub(super) fn thermo_failed(
+    pub(in crate::models::thermal::hx::discretized::core) fn thermo_failed(
         context: impl Into<String>,
         err: impl std::error::Error + Send + Sync + 'static,
     ) -> Self {
@@ -1,18 +1,24 @@
 use std::convert::Infallible;
 
 use crate::support::{
+    constraint::{Constrained, UnitInterval},
     thermo::{
         PropertyError, State,
-        capability::{HasEnthalpy, HasPressure, StateFrom, ThermoModel},
+        capability::{
+            HasEnthalpy, HasEntropy, HasPressure, HasTwoPhase, StateFrom, ThermoModel, TwoPhase,
+        },
     },
-    units::{SpecificEnthalpy, TemperatureDifference},
+    units::{SpecificEnthalpy, SpecificEntropy, TemperatureDifference},
 };
 use uom::si::{
+    available_energy::joule_per_kilogram,
     f64::{
-        MassDensity, Pressure, SpecificHeatCapacity, TemperatureInterval, ThermodynamicTemperature,
+        MassDensity, Pressure, Ratio, SpecificHeatCapacity, TemperatureInterval,
+        ThermodynamicTemperature,
     },
     mass_density::kilogram_per_cubic_meter,
     pressure::pascal,
+    ratio::ratio,
     specific_heat_capacity::joule_per_kilogram_kelvin,
     thermodynamic_temperature::kelvin,
 };
@@ -59,6 +65,12 @@ impl HasEnthalpy for TestThermoModel {
     }
 }
 
+impl HasTwoPhase for TestThermoModel {
+    fn two_phase(&self, _state: &State<Self::Fluid>) -> Result<Option<TwoPhase>, PropertyError> {
+        Ok(None)
+    }
+}
+
 impl StateFrom<(TestFluid, ThermodynamicTemperature, Pressure)> for TestThermoModel {
     type Error = Infallible;
 
@@ -91,3 +103,250 @@ pub(crate) fn state(temp_kelvin: f64) -> State<TestFluid> {
         TestFluid,
     )
 }
+
+/// A pure refrigerant with a Clausius–Clapeyron vapor pressure curve.
+///
+/// The liquid is incompressible, the vapor is an ideal gas, and both phases
+/// share one specific heat so the latent heat is constant. With those
+/// choices the saturated vapor entropy from the ideal gas relation matches
+/// the liquid entropy plus `L/T`, so isentropic and isenthalpic processes
+/// are consistent across the dome. Subcooled liquid reports its saturation
+/// pressure, since an incompressible liquid carries no pressure information.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TestRefrigerant {
+    /// Reference temperature (K), where enthalpy and liquid entropy are zero.
+    t0: f64,
+    /// Saturation pressure at `t0` (Pa).
+    p0: f64,
+    /// Latent heat of vaporization (J/kg).
+    latent: f64,
+    /// Specific gas constant of the vapor (J/kg·K).
+    gas_constant: f64,
+    /// Specific heat of both phases (J/kg·K).
+    cp: f64,
+    /// Liquid density (kg/m³).
+    liquid_density: f64,
+}
+
+impl TestRefrigerant {
+    /// An ammonia-like refrigerant for a high-temperature stage.
+    pub(crate) fn ammonia_like() -> Self {
+        Self {
+            t0: 273.15,
+            p0: 430e3,
+            latent: 1.26e6,
+            gas_constant: 488.0,
+            cp: 2200.0,
+            liquid_density: 640.0,
+        }
+    }
+
+    /// A CO₂-like refrigerant for a low-temperature stage.
+    pub(crate) fn carbon_dioxide_like() -> Self {
+        Self {
+            t0: 273.15,
+            p0: 3.5e6,
+            latent: 300e3,
+            gas_constant: 189.0,
+            cp: 1500.0,
+            liquid_density: 930.0,
+        }
+    }
+
+    /// A water-like fluid for steam systems, boiling at 100 °C and 1 atm.
+    ///
+    /// Both phases share the vapor specific heat, so liquid enthalpies are
+    /// understated.
+    pub(crate) fn water_like() -> Self {
+        Self {
+            t0: 373.15,
+            p0: 101_325.0,
+            latent: 2.257e6,
+            gas_constant: 461.5,
+            cp: 2100.0,
+            liquid_density: 960.0,
+        }
+    }
+
+    fn saturation_pressure(&self, t: f64) -> f64 {
+        self.p0 * (self.latent / self.gas_constant * (1.0 / self.t0 - 1.0 / t)).exp()
+    }
+
+    fn saturation_temperature(&self, p: f64) -> f64 {
+        1.0 / (1.0 / self.t0 - self.gas_constant / self.latent * (p / self.p0).ln())
+    }
+
+    fn vapor_density(&self, t: f64) -> f64 {
+        self.saturation_pressure(t) / (self.gas_constant * t)
+    }
+
+    /// Vapor mass fraction, clamped to zero for liquid and one for vapor.
+    fn quality(&self, state: &State<TestFluid>) -> f64 {
+        let t = state.temperature.get::<kelvin>();
+        let v = 1.0 / state.density.get::<kilogram_per_cubic_meter>();
+        let v_liquid = 1.0 / self.liquid_density;
+        let v_vapor = 1.0 / self.vapor_density(t);
+        ((v - v_liquid) / (v_vapor - v_liquid)).clamp(0.0, 1.0)
+    }
+
+    fn saturated(&self, t: f64, x: f64) -> State<TestFluid> {
+        let v = x / self.vapor_density(t) + (1.0 - x) / self.liquid_density;
+        self.state(t, 1.0 / v)
+    }
+
+    fn liquid(&self, t: f64) -> State<TestFluid> {
+        self.state(t, self.liquid_density)
+    }
+
+    fn vapor(&self, t: f64, p: f64) -> State<TestFluid> {
+        self.state(t, p / (self.gas_constant * t))
+    }
+
+    #[allow(clippy::unused_self)]
+    fn state(&self, t: f64, density: f64) -> State<TestFluid> {
+        State::new(
+            ThermodynamicTemperature::new::<kelvin>(t),
+            MassDensity::new::<kilogram_per_cubic_meter>(density),
+            TestFluid,
+        )
+    }
+}
+
+impl ThermoModel for TestRefrigerant {
+    type Fluid = TestFluid;
+}
+
+impl HasPressure for TestRefrigerant {
+    fn pressure(&self, state: &State<Self::Fluid>) -> Result<Pressure, PropertyError> {
+        let t = state.temperature.get::<kelvin>();
+        let p = if self.quality(state) < 1.0 {
+            self.saturation_pressure(t)
+        } else {
+            state.density.get::<kilogram_per_cubic_meter>() * self.gas_constant * t
+        };
+        Ok(Pressure::new::<pascal>(p))
+    }
+}
+
+impl HasEnthalpy for TestRefrigerant {
+    fn enthalpy(&self, state: &State<Self::Fluid>) -> Result<SpecificEnthalpy, PropertyError> {
+        let t = state.temperature.get::<kelvin>();
+        let h = self.cp * (t - self.t0) + self.quality(state) * self.latent;
+        Ok(SpecificEnthalpy::new::<joule_per_kilogram>(h))
+    }
+}
+
+impl HasEntropy for TestRefrigerant {
+    fn entropy(&self, state: &State<Self::Fluid>) -> Result<SpecificEntropy, PropertyError> {
+        let t = state.temperature.get::<kelvin>();
+        let x = self.quality(state);
+        let s = if x < 1.0 {
+            self.cp * (t / self.t0).ln() + x * self.latent / t
+        } else {
+            let p = self.pressure(state)?.get::<pascal>();
+            self.cp * (t / self.t0).ln() - self.gas_constant * (p / self.p0).ln()
+                + self.latent / self.t0
+        };
+        Ok(SpecificEntropy::new::<joule_per_kilogram_kelvin>(s))
+    }
+}
+
+impl StateFrom<(TestFluid, ThermodynamicTemperature, Pressure)> for TestRefrigerant {
+    type Error = Infallible;
+
+    fn state_from(
+        &self,
+        (_, temperature, pressure): (TestFluid, ThermodynamicTemperature, Pressure),
+    ) -> Result<State<TestFluid>, Self::Error> {
+        let t = temperature.get::<kelvin>();
+        let p = pressure.get::<pascal>();
+        Ok(if t > self.saturation_temperature(p) {
+            self.vapor(t, p)
+        } else {
+            self.liquid(t)
+        })
+    }
+}
+
+impl
+    StateFrom<(
+        TestFluid,
+        ThermodynamicTemperature,
+        Constrained<Ratio, UnitInterval>,
+    )> for TestRefrigerant
+{
+    type Error = Infallible;
+
+    fn state_from(
+        &self,
+        (_, temperature, quality): (
+            TestFluid,
+            ThermodynamicTemperature,
+            Constrained<Ratio, UnitInterval>,
+        ),
+    ) -> Result<State<TestFluid>, Self::Error> {
+        Ok(self.saturated(
+            temperature.get::<kelvin>(),
+            quality.into_inner().get::<ratio>(),
+        ))
+    }
+}
+
+impl StateFrom<(TestFluid, Pressure, Constrained<Ratio, UnitInterval>)> for TestRefrigerant {
+    type Error = Infallible;
+
+    fn state_from(
+        &self,
+        (_, pressure, quality): (TestFluid, Pressure, Constrained<Ratio, UnitInterval>),
+    ) -> Result<State<TestFluid>, Self::Error> {
+        Ok(self.saturated(
+            self.saturation_temperature(pressure.get::<pascal>()),
+            quality.into_inner().get::<ratio>(),
+        ))
+    }
+}
+
+impl StateFrom<(TestFluid, Pressure, SpecificEnthalpy)> for TestRefrigerant {
+    type Error = Infallible;
+
+    fn state_from(
+        &self,
+        (_, pressure, enthalpy): (TestFluid, Pressure, SpecificEnthalpy),
+    ) -> Result<State<TestFluid>, Self::Error> {
+        let p = pressure.get::<pascal>();
+        let h = enthalpy.get::<joule_per_kilogram>();
+        let t_sat = self.saturation_temperature(p);
+        let h_liquid = self.cp * (t_sat - self.t0);
+        Ok(if h < h_liquid {
+            self.liquid(self.t0 + h / self.cp)
+        } else if h > h_liquid + self.latent {
+            self.vapor(self.t0 + (h - self.latent) / self.cp, p)
+        } else {
+            self.saturated(t_sat, (h - h_liquid) / self.latent)
+        })
+    }
+}
+
+impl StateFrom<(TestFluid, Pressure, SpecificEntropy)> for TestRefrigerant {
+    type Error = Infallible;
+
+    fn state_from(
+        &self,
+        (_, pressure, entropy): (TestFluid, Pressure, SpecificEntropy),
+    ) -> Result<State<TestFluid>, Self::Error> {
+        let p = pressure.get::<pascal>();
+        let s = entropy.get::<joule_per_kilogram_kelvin>();
+        let t_sat = self.saturation_temperature(p);
+        let s_liquid = self.cp * (t_sat / self.t0).ln();
+        let s_vapor = s_liquid + self.latent / t_sat;
+        Ok(if s < s_liquid {
+            self.liquid(self.t0 * (s / self.cp).exp())
+        } else if s > s_vapor {
+            let exponent =
+                (s - self.latent / self.t0 + self.gas_constant * (p / self.p0).ln()) / self.cp;
+            self.vapor(self.t0 * exponent.exp(), p)
+        } else {
+            self.saturated(t_sat, (s - s_liquid) * t_sat / self.latent)
+        })
+    }
+}
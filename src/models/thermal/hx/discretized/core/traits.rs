@@ -2,8 +2,8 @@
 
 use crate::support::{
     hx::{
-        NtuRelation,
-        arrangement::{CounterFlow, ParallelFlow},
+        NtuRelation, RawNtuRelation,
+        arrangement::{CounterFlow, CrossFlow, Mixed, ParallelFlow, ShellAndTube, Unmixed},
     },
     thermo::capability::{HasEnthalpy, HasPressure, StateFrom, ThermoModel},
     units::SpecificEnthalpy,
@@ -13,12 +13,20 @@ use uom::si::f64::{Pressure, ThermodynamicTemperature};
 /// Arrangement contract for discretized solvers.
 ///
 /// This trait extends [`NtuRelation`] with the bottom stream flow direction,
-/// which is required to discretize the heat exchanger into nodes.
+/// which is required to discretize the heat exchanger into nodes, and with
+/// the relation each segment between adjacent nodes obeys.
 ///
 /// The "bottom" stream refers to the physical position in the discretized
 /// model, not necessarily the hot or cold stream.
 /// The top stream always flows left to right (node 0 to node N-1).
 /// The bottom stream's direction depends on the arrangement.
+///
+/// Counterflow and parallel flow split into smaller exchangers of the same
+/// kind. Shell-and-tube and crossflow exchangers are laid out as a
+/// counter-current series of segments: a shell-and-tube exchanger as its
+/// shells cut into equal fractions, and a crossflow exchanger as a
+/// cross-counterflow stack of single crossflow passes, so two nodes give the
+/// single-pass exchanger.
 #[doc(hidden)]
 pub trait DiscretizedArrangement: NtuRelation {
     /// True if the bottom stream flows left-to-right (node 0 to N-1).
@@ -45,14 +53,96 @@ pub trait DiscretizedArrangement: NtuRelation {
             reverse
         }
     }
+
+    /// Returns the NTU of one of `segments` segments given its effectiveness
+    /// and the capacitance rates of both streams.
+    ///
+    /// Returns infinity if no finite segment reaches the effectiveness.
+    fn segment_ntu(&self, segments: usize, effectiveness: f64, capacitance_rates: [f64; 2]) -> f64;
 }
 
 impl DiscretizedArrangement for CounterFlow {
     const BOTTOM_FLOWS_LEFT_TO_RIGHT: bool = false;
+
+    fn segment_ntu(
+        &self,
+        _segments: usize,
+        effectiveness: f64,
+        capacitance_rates: [f64; 2],
+    ) -> f64 {
+        reachable(self.ntu_raw(effectiveness, capacitance_rates))
+    }
 }
 
 impl DiscretizedArrangement for ParallelFlow {
     const BOTTOM_FLOWS_LEFT_TO_RIGHT: bool = true;
+
+    fn segment_ntu(
+        &self,
+        _segments: usize,
+        effectiveness: f64,
+        capacitance_rates: [f64; 2],
+    ) -> f64 {
+        reachable(self.ntu_raw(effectiveness, capacitance_rates))
+    }
+}
+
+impl<const S: u16, const T: u16> DiscretizedArrangement for ShellAndTube<S, T> {
+    const BOTTOM_FLOWS_LEFT_TO_RIGHT: bool = false;
+
+    /// Identical segments in counter-current series compose into the whole
+    /// exchanger, so the segment takes an equal share of the NTU the
+    /// composed effectiveness requires.
+    fn segment_ntu(&self, segments: usize, effectiveness: f64, capacitance_rates: [f64; 2]) -> f64 {
+        // Segment counts are far below 2^52, so the cast is exact.
+        #[allow(clippy::cast_precision_loss)]
+        let count = segments as f64;
+        let [first, second] = capacitance_rates;
+        let cr = first.min(second) / first.max(second);
+
+        let composed = if cr < 1.0 {
+            let x = ((1.0 - effectiveness * cr) / (1.0 - effectiveness)).powf(count);
+            (x - 1.0) / (x - cr)
+        } else {
+            // cr == 1
+            let y = count * effectiveness / (1.0 - effectiveness);
+            y / (1.0 + y)
+        };
+
+        reachable(self.ntu_raw(composed, capacitance_rates)) / count
+    }
+}
+
+impl DiscretizedArrangement for CrossFlow<Mixed, Unmixed> {
+    const BOTTOM_FLOWS_LEFT_TO_RIGHT: bool = false;
+
+    fn segment_ntu(
+        &self,
+        _segments: usize,
+        effectiveness: f64,
+        capacitance_rates: [f64; 2],
+    ) -> f64 {
+        reachable(self.ntu_raw(effectiveness, capacitance_rates))
+    }
+}
+
+impl DiscretizedArrangement for CrossFlow<Unmixed, Mixed> {
+    const BOTTOM_FLOWS_LEFT_TO_RIGHT: bool = false;
+
+    fn segment_ntu(
+        &self,
+        _segments: usize,
+        effectiveness: f64,
+        capacitance_rates: [f64; 2],
+    ) -> f64 {
+        reachable(self.ntu_raw(effectiveness, capacitance_rates))
+    }
+}
+
+/// Maps the NaN a relation returns past its maximum effectiveness to an
+/// unbounded NTU.
+fn reachable(ntu: f64) -> f64 {
+    if ntu.is_nan() { f64::INFINITY } else { ntu }
 }
 
 /// Required thermo model bounds for discretized heat exchangers.
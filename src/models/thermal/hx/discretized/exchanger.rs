@@ -0,0 +1,558 @@
+//! Discretized heat exchanger configured at runtime.
+//!
+//! The core discretized solver fixes its flow arrangement and node count as
+//! generic parameters, which suits models that are designed in code. An
+//! application that reads its exchanger layout from a file or a form needs
+//! one concrete type instead. [`DiscretizedExchanger`] stores the
+//! arrangement as a [`FlowArrangement`] and the resolution as a segment
+//! count, and dispatches each solve to the matching generic instance.
+//!
+//! Node states come back in [`ExchangerResults`], which holds them in
+//! vectors sized by the configured segment count.
+//!
+//! # Example
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::hx::discretized::{
+//!         DiscretizedExchanger, DiscretizedExchangerError, FlowArrangement, GivenUaConfig, Inlets,
+//!         Known, MassFlows, PressureDrops,
+//!     },
+//!     support::thermo::{State, fluid::Air, model::PerfectGas},
+//! };
+//! use uom::si::{
+//!     f64::{MassDensity, MassRate, ThermalConductance, ThermodynamicTemperature},
+//!     mass_density::kilogram_per_cubic_meter,
+//!     mass_rate::kilogram_per_second,
+//!     thermal_conductance::watt_per_kelvin,
+//!     thermodynamic_temperature::kelvin,
+//! };
+//!
+//! // Arrangement and resolution chosen by the user at runtime.
+//! let exchanger = DiscretizedExchanger::new(FlowArrangement::ParallelFlow, 20)?;
+//!
+//! let thermo = PerfectGas::<Air>::new().unwrap();
+//! let air = |kelvin_value, density| {
+//!     State::new(
+//!         ThermodynamicTemperature::new::<kelvin>(kelvin_value),
+//!         MassDensity::new::<kilogram_per_cubic_meter>(density),
+//!         Air,
+//!     )
+//! };
+//! let known = Known {
+//!     inlets: Inlets {
+//!         top: air(400.0, 2.0),
+//!         bottom: air(600.0, 1.0),
+//!     },
+//!     m_dot: MassFlows::new_unchecked(
+//!         MassRate::new::<kilogram_per_second>(1.0),
+//!         MassRate::new::<kilogram_per_second>(1.0),
+//!     ),
+//!     dp: PressureDrops::zero(),
+//! };
+//!
+//! let solved = exchanger.given_ua(
+//!     &known,
+//!     ThermalConductance::new::<watt_per_kelvin>(5000.0),
+//!     GivenUaConfig::default(),
+//!     &thermo,
+//!     &thermo,
+//! )?;
+//!
+//! assert_eq!(solved.results.top.len(), 21);
+//! // Parallel flow cannot heat the cold stream past the mixed temperature.
+//! let top_outlet = solved.results.top[20].temperature;
+//! assert!(top_outlet < ThermodynamicTemperature::new::<kelvin>(500.0));
+//! # Ok::<(), DiscretizedExchangerError>(())
+//! ```
+
+use thiserror::Error;
+use uom::si::f64::{HeatTransfer, Ratio, ThermalConductance};
+
+use crate::{
+    models::thermal::hx::discretized::core::{
+        ConjugateConfig, ConjugateError, ConjugateResults, ConjugateWall, DiscretizedHx,
+        DiscretizedHxThermoModel, Given, GivenUaConfig, GivenUaError, GivenUaIteration,
+        GivenUaResults, HeatTransferRate, Known, MinDeltaT, Results, SolveError, WallNode,
+    },
+    support::{
+        hx::arrangement::{CounterFlow, ParallelFlow},
+        thermo::State,
+    },
+};
+
+/// Segment counts a [`DiscretizedExchanger`] can be configured with.
+pub const SUPPORTED_SEGMENTS: [usize; 6] = [1, 5, 10, 20, 50, 100];
+
+/// Flow arrangement of a [`DiscretizedExchanger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FlowArrangement {
+    /// The bottom stream flows opposite the top stream.
+    CounterFlow,
+
+    /// Both streams flow in the same direction.
+    ParallelFlow,
+}
+
+/// Errors from [`DiscretizedExchanger`] construction and solving.
+#[derive(Debug, Error)]
+pub enum DiscretizedExchangerError {
+    /// The segment count is not one of [`SUPPORTED_SEGMENTS`].
+    #[error("unsupported segment count {0}; supported values are 1, 5, 10, 20, 50, 100")]
+    UnsupportedSegments(usize),
+
+    /// A solve with a given outlet temperature or heat transfer rate failed.
+    #[error("discretized solve failed")]
+    Solve(#[from] SolveError),
+
+    /// A solve with a given conductance failed.
+    #[error("discretized solve given UA failed")]
+    GivenUa(#[from] GivenUaError),
+
+    /// A solve with a conjugate wall failed.
+    #[error("discretized solve with a conjugate wall failed")]
+    Conjugate(#[from] ConjugateError),
+}
+
+/// Node states and performance metrics of a [`DiscretizedExchanger`] solve.
+///
+/// Matches the core results, with each node array held in a vector of
+/// `segments + 1` entries ordered from left to right. The top stream always
+/// flows left to right, and the bottom stream flows right to left in
+/// counterflow.
+#[derive(Debug, Clone)]
+pub struct ExchangerResults<TopFluid, BottomFluid> {
+    /// Top stream node states.
+    pub top: Vec<State<TopFluid>>,
+
+    /// Bottom stream node states.
+    pub bottom: Vec<State<BottomFluid>>,
+
+    /// Heat transfer rate.
+    pub q_dot: HeatTransferRate,
+
+    /// Total heat exchanger conductance.
+    pub ua: ThermalConductance,
+
+    /// Conductance accumulated from node 0 to each node.
+    pub cumulative_ua: Vec<ThermalConductance>,
+
+    /// Minimum hot-to-cold temperature difference and its node.
+    pub min_delta_t: MinDeltaT,
+}
+
+impl<TopFluid, BottomFluid, const N: usize> From<Results<TopFluid, BottomFluid, N>>
+    for ExchangerResults<TopFluid, BottomFluid>
+{
+    fn from(results: Results<TopFluid, BottomFluid, N>) -> Self {
+        Self {
+            top: results.top.into(),
+            bottom: results.bottom.into(),
+            q_dot: results.q_dot,
+            ua: results.ua,
+            cumulative_ua: results.cumulative_ua.into(),
+            min_delta_t: results.min_delta_t,
+        }
+    }
+}
+
+/// Results from a [`DiscretizedExchanger::given_ua`] solve.
+#[derive(Debug, Clone)]
+pub struct ExchangerGivenUaResults<TopFluid, BottomFluid> {
+    /// Heat exchanger node states and performance metrics.
+    pub results: ExchangerResults<TopFluid, BottomFluid>,
+
+    /// Number of bisection iterations performed.
+    pub iterations: usize,
+
+    /// Heat conducted axially through the separating wall.
+    ///
+    /// Already included in `results.q_dot`.
+    pub wall_q_dot: HeatTransferRate,
+}
+
+impl<TopFluid, BottomFluid, const N: usize> From<GivenUaResults<TopFluid, BottomFluid, N>>
+    for ExchangerGivenUaResults<TopFluid, BottomFluid>
+{
+    fn from(solved: GivenUaResults<TopFluid, BottomFluid, N>) -> Self {
+        Self {
+            results: solved.results.into(),
+            iterations: solved.iterations,
+            wall_q_dot: solved.wall_q_dot,
+        }
+    }
+}
+
+/// Results from a [`DiscretizedExchanger::conjugate`] solve.
+///
+/// Matches the core conjugate results, with each node array held in a
+/// vector of `segments + 1` entries.
+#[derive(Debug, Clone)]
+pub struct ExchangerConjugateResults<TopFluid, BottomFluid> {
+    /// Heat exchanger node states and performance metrics.
+    pub results: ExchangerResults<TopFluid, BottomFluid>,
+
+    /// Number of bisection iterations performed.
+    pub iterations: usize,
+
+    /// Wall state at each node.
+    pub wall: Vec<WallNode>,
+
+    /// Share of the exchange surface accumulated from node 0 to each node.
+    pub cumulative_area: Vec<Ratio>,
+
+    /// Top film conductance accumulated from node 0 to each node.
+    pub cumulative_top_ua: Vec<ThermalConductance>,
+
+    /// Bottom film conductance accumulated from node 0 to each node.
+    pub cumulative_bottom_ua: Vec<ThermalConductance>,
+}
+
+impl<TopFluid, BottomFluid, const N: usize> From<ConjugateResults<TopFluid, BottomFluid, N>>
+    for ExchangerConjugateResults<TopFluid, BottomFluid>
+{
+    fn from(solved: ConjugateResults<TopFluid, BottomFluid, N>) -> Self {
+        Self {
+            results: solved.results.into(),
+            iterations: solved.iterations,
+            wall: solved.wall.into(),
+            cumulative_area: solved.cumulative_area.into(),
+            cumulative_top_ua: solved.cumulative_top_ua.into(),
+            cumulative_bottom_ua: solved.cumulative_bottom_ua.into(),
+        }
+    }
+}
+
+/// Expands `$body` with `$hx` aliased to the [`DiscretizedHx`] instance for
+/// a runtime arrangement and segment count.
+macro_rules! dispatch {
+    ($arrangement:expr, $segments:expr, $hx:ident => $body:expr) => {
+        match $arrangement {
+            FlowArrangement::CounterFlow => dispatch!(@nodes CounterFlow, $segments, $hx => $body),
+            FlowArrangement::ParallelFlow => dispatch!(@nodes ParallelFlow, $segments, $hx => $body),
+        }
+    };
+    (@nodes $arrangement:ty, $segments:expr, $hx:ident => $body:expr) => {
+        match $segments {
+            1 => {
+                type $hx = DiscretizedHx<$arrangement, 2>;
+                $body
+            }
+            5 => {
+                type $hx = DiscretizedHx<$arrangement, 6>;
+                $body
+            }
+            10 => {
+                type $hx = DiscretizedHx<$arrangement, 11>;
+                $body
+            }
+            20 => {
+                type $hx = DiscretizedHx<$arrangement, 21>;
+                $body
+            }
+            50 => {
+                type $hx = DiscretizedHx<$arrangement, 51>;
+                $body
+            }
+            100 => {
+                type $hx = DiscretizedHx<$arrangement, 101>;
+                $body
+            }
+            _ => unreachable!("validated at construction"),
+        }
+    };
+}
+
+/// A discretized heat exchanger whose arrangement and resolution are chosen
+/// at runtime.
+///
+/// Solves are identical to the generic core solver with the same
+/// arrangement and `segments + 1` nodes. Supported segment counts are
+/// listed in [`SUPPORTED_SEGMENTS`], the same set the recuperator models
+/// accept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiscretizedExchanger {
+    arrangement: FlowArrangement,
+    segments: usize,
+}
+
+impl DiscretizedExchanger {
+    /// Creates an exchanger with the given arrangement and segment count.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DiscretizedExchangerError::UnsupportedSegments`] if
+    /// `segments` is not in [`SUPPORTED_SEGMENTS`].
+    pub fn new(
+        arrangement: FlowArrangement,
+        segments: usize,
+    ) -> Result<Self, DiscretizedExchangerError> {
+        if !SUPPORTED_SEGMENTS.contains(&segments) {
+            return Err(DiscretizedExchangerError::UnsupportedSegments(segments));
+        }
+        Ok(Self {
+            arrangement,
+            segments,
+        })
+    }
+
+    /// Returns the flow arrangement.
+    #[must_use]
+    pub fn arrangement(&self) -> FlowArrangement {
+        self.arrangement
+    }
+
+    /// Returns the number of segments.
+    #[must_use]
+    pub fn segments(&self) -> usize {
+        self.segments
+    }
+
+    /// Solves the exchanger for an outlet temperature or heat transfer rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DiscretizedExchangerError::Solve`] on non-physical results
+    /// or thermodynamic model failures.
+    pub fn solve<TopFluid, BottomFluid>(
+        &self,
+        known: &Known<TopFluid, BottomFluid>,
+        given: Given,
+        thermo_top: &impl DiscretizedHxThermoModel<TopFluid>,
+        thermo_bottom: &impl DiscretizedHxThermoModel<BottomFluid>,
+    ) -> Result<ExchangerResults<TopFluid, BottomFluid>, DiscretizedExchangerError>
+    where
+        TopFluid: Clone,
+        BottomFluid: Clone,
+    {
+        dispatch!(self.arrangement, self.segments, Hx => {
+            Ok(Hx::solve(known, given, thermo_top, thermo_bottom)?.into())
+        })
+    }
+
+    /// Solves the exchanger for a target conductance (UA).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DiscretizedExchangerError::GivenUa`] on non-physical
+    /// results, thermodynamic model failures, or if the solver fails to
+    /// converge.
+    pub fn given_ua<TopFluid, BottomFluid>(
+        &self,
+        known: &Known<TopFluid, BottomFluid>,
+        target_ua: ThermalConductance,
+        config: GivenUaConfig,
+        thermo_top: &impl DiscretizedHxThermoModel<TopFluid>,
+        thermo_bottom: &impl DiscretizedHxThermoModel<BottomFluid>,
+    ) -> Result<ExchangerGivenUaResults<TopFluid, BottomFluid>, DiscretizedExchangerError>
+    where
+        TopFluid: Clone,
+        BottomFluid: Clone,
+    {
+        self.given_ua_observed(known, target_ua, config, thermo_top, thermo_bottom, |_| {})
+    }
+
+    /// Solves the exchanger for a target UA, reporting each iteration.
+    ///
+    /// Behaves like [`given_ua`](Self::given_ua), but calls `observer` with
+    /// every candidate outlet temperature the solver evaluates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DiscretizedExchangerError::GivenUa`] on non-physical
+    /// results, thermodynamic model failures, or if the solver fails to
+    /// converge.
+    pub fn given_ua_observed<TopFluid, BottomFluid>(
+        &self,
+        known: &Known<TopFluid, BottomFluid>,
+        target_ua: ThermalConductance,
+        config: GivenUaConfig,
+        thermo_top: &impl DiscretizedHxThermoModel<TopFluid>,
+        thermo_bottom: &impl DiscretizedHxThermoModel<BottomFluid>,
+        observer: impl FnMut(&GivenUaIteration),
+    ) -> Result<ExchangerGivenUaResults<TopFluid, BottomFluid>, DiscretizedExchangerError>
+    where
+        TopFluid: Clone,
+        BottomFluid: Clone,
+    {
+        dispatch!(self.arrangement, self.segments, Hx => {
+            Ok(Hx::given_ua_observed(
+                known,
+                target_ua,
+                config,
+                thermo_top,
+                thermo_bottom,
+                observer,
+            )?
+            .into())
+        })
+    }
+
+    /// Solves the exchanger with the conductance given by the film
+    /// coefficients on either side of a conjugate wall.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DiscretizedExchangerError::Conjugate`] on an invalid wall
+    /// or film coefficient, non-physical results, thermodynamic model
+    /// failures, or if the solver fails to converge.
+    pub fn conjugate<TopFluid, BottomFluid, TopFilm, BottomFilm>(
+        &self,
+        known: &Known<TopFluid, BottomFluid>,
+        wall: &ConjugateWall<TopFilm, BottomFilm>,
+        config: ConjugateConfig,
+        thermo_top: &impl DiscretizedHxThermoModel<TopFluid>,
+        thermo_bottom: &impl DiscretizedHxThermoModel<BottomFluid>,
+    ) -> Result<ExchangerConjugateResults<TopFluid, BottomFluid>, DiscretizedExchangerError>
+    where
+        TopFluid: Clone,
+        BottomFluid: Clone,
+        TopFilm: Fn(&State<TopFluid>) -> HeatTransfer,
+        BottomFilm: Fn(&State<BottomFluid>) -> HeatTransfer,
+    {
+        dispatch!(self.arrangement, self.segments, Hx => {
+            Ok(Hx::conjugate(known, wall, config, thermo_top, thermo_bottom)?.into())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        area::square_meter,
+        f64::{Area, MassRate, Power},
+        heat_transfer::watt_per_square_meter_kelvin,
+        mass_rate::kilogram_per_second,
+        power::kilowatt,
+        thermal_conductance::kilowatt_per_kelvin,
+        thermodynamic_temperature::kelvin,
+    };
+
+    use crate::models::thermal::hx::discretized::core::{
+        Inlets, MassFlows, PressureDrops,
+        test_support::{TestFluid, TestThermoModel, state},
+    };
+
+    fn known() -> Known<TestFluid, TestFluid> {
+        Known {
+            inlets: Inlets {
+                top: state(300.0),
+                bottom: state(400.0),
+            },
+            m_dot: MassFlows::new_unchecked(
+                MassRate::new::<kilogram_per_second>(1.0),
+                MassRate::new::<kilogram_per_second>(2.0),
+            ),
+            dp: PressureDrops::default(),
+        }
+    }
+
+    #[test]
+    fn matches_the_generic_solver() {
+        let thermo = TestThermoModel::new();
+        let ua = ThermalConductance::new::<kilowatt_per_kelvin>(3.0);
+        let config = GivenUaConfig::default();
+
+        let exchanger = DiscretizedExchanger::new(FlowArrangement::CounterFlow, 10).unwrap();
+        let facade = exchanger
+            .given_ua(&known(), ua, config, &thermo, &thermo)
+            .unwrap();
+        let generic =
+            DiscretizedHx::<CounterFlow, 11>::given_ua(&known(), ua, config, &thermo, &thermo)
+                .unwrap();
+
+        assert_eq!(facade.results.top.len(), 11);
+        assert_eq!(facade.results.cumulative_ua.len(), 11);
+        assert_eq!(facade.iterations, generic.iterations);
+        assert_eq!(
+            facade.results.top[10].temperature,
+            generic.results.top[10].temperature
+        );
+        assert_eq!(
+            facade.results.bottom[0].temperature,
+            generic.results.bottom[0].temperature
+        );
+    }
+
+    #[test]
+    fn arrangement_is_chosen_at_runtime() {
+        let thermo = TestThermoModel::new();
+        let ua = ThermalConductance::new::<kilowatt_per_kelvin>(10.0);
+        let top_outlet = |arrangement| {
+            let solved = DiscretizedExchanger::new(arrangement, 20)
+                .unwrap()
+                .given_ua(&known(), ua, GivenUaConfig::default(), &thermo, &thermo)
+                .unwrap();
+            solved.results.top[20].temperature.get::<kelvin>()
+        };
+
+        // Counterflow heats the top stream further for the same UA.
+        assert!(
+            top_outlet(FlowArrangement::CounterFlow) > top_outlet(FlowArrangement::ParallelFlow)
+        );
+    }
+
+    #[test]
+    fn solves_a_given_heat_transfer_rate() {
+        let thermo = TestThermoModel::new();
+        let q_dot = HeatTransferRate::bottom_to_top(Power::new::<kilowatt>(20.0)).unwrap();
+
+        let results = DiscretizedExchanger::new(FlowArrangement::ParallelFlow, 5)
+            .unwrap()
+            .solve(&known(), Given::HeatTransferRate(q_dot), &thermo, &thermo)
+            .unwrap();
+
+        assert_eq!(results.bottom.len(), 6);
+        assert_relative_eq!(
+            results.cumulative_ua.last().unwrap().value,
+            results.ua.value
+        );
+    }
+
+    #[test]
+    fn solves_with_a_conjugate_wall() {
+        let thermo = TestThermoModel::new();
+        let film = |_: &State<TestFluid>| HeatTransfer::new::<watt_per_square_meter_kelvin>(500.0);
+        let wall = ConjugateWall {
+            top_area: Area::new::<square_meter>(4.0),
+            bottom_area: Area::new::<square_meter>(4.0),
+            wall_conductance: ThermalConductance::new::<kilowatt_per_kelvin>(100.0),
+            top_film: film,
+            bottom_film: film,
+        };
+
+        let solved = DiscretizedExchanger::new(FlowArrangement::CounterFlow, 10)
+            .unwrap()
+            .conjugate(
+                &known(),
+                &wall,
+                ConjugateConfig::default(),
+                &thermo,
+                &thermo,
+            )
+            .unwrap();
+
+        // Two 2 kW/K films and a 100 kW/K wall in series.
+        assert_eq!(solved.wall.len(), 11);
+        assert_relative_eq!(
+            solved.results.ua.get::<kilowatt_per_kelvin>(),
+            1.0 / (0.5 + 0.5 + 0.01),
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_segment_counts() {
+        for segments in [0, 2, 3, 200] {
+            assert!(matches!(
+                DiscretizedExchanger::new(FlowArrangement::CounterFlow, segments),
+                Err(DiscretizedExchangerError::UnsupportedSegments(_))
+            ));
+        }
+    }
+}
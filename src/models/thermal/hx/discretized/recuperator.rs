@@ -6,15 +6,82 @@
 //!   iterates on outlet temperature to find the operating state.
 //! - [`RecuperatorGivenOutlet`]: given an outlet temperature, computes
 //!   the resulting UA directly (no iteration).
+//!
+//! [`Recuperator`] wraps both behind one input and output type, with the
+//! UA or outlet temperature chosen per call.
 
 mod given_outlet;
 mod given_ua;
+mod unified;
+
+use crate::support::{
+    exergy::{DeadState, ExergyBalance, flow_exergy},
+    testing::Balance,
+    thermo::{
+        PropertyError, State,
+        capability::{HasEnthalpy, HasEntropy},
+    },
+};
+
+use super::{HeatTransferRate, Inlets, MassFlows};
 
 pub use given_outlet::{
     OutletTemp, RecuperatorGivenOutlet, RecuperatorGivenOutletError, RecuperatorGivenOutletInput,
     RecuperatorGivenOutletOutput,
 };
 pub use given_ua::{
-    RecuperatorGivenUa, RecuperatorGivenUaConfig, RecuperatorGivenUaError, RecuperatorGivenUaInput,
-    RecuperatorGivenUaOutput,
+    RecuperatorGivenUa, RecuperatorGivenUaConfig, RecuperatorGivenUaConfigBuilder,
+    RecuperatorGivenUaError, RecuperatorGivenUaInput, RecuperatorGivenUaOutput,
+};
+pub use unified::{
+    Recuperator, RecuperatorError, RecuperatorInput, RecuperatorOutput, RecuperatorSpec,
 };
+
+/// Builds the top and bottom stream balances of a solved recuperator.
+///
+/// Each stream gains the heat the other loses, so both balances close when
+/// the outlet enthalpies agree with the reported heat transfer rate.
+fn stream_balances<Fluid>(
+    inlets: &Inlets<Fluid, Fluid>,
+    mass_flows: MassFlows,
+    outlets: [&State<Fluid>; 2],
+    q_dot: HeatTransferRate,
+    thermo: &impl HasEnthalpy<Fluid = Fluid>,
+) -> Result<[Balance; 2], PropertyError> {
+    let [top_outlet, bottom_outlet] = outlets;
+    let q_top_to_bottom = q_dot.signed_top_to_bottom();
+
+    let top = Balance::new("top stream")
+        .inflow(mass_flows.top(), thermo.enthalpy(&inlets.top)?)
+        .outflow(mass_flows.top(), thermo.enthalpy(top_outlet)?)
+        .heat_in(-q_top_to_bottom);
+    let bottom = Balance::new("bottom stream")
+        .inflow(mass_flows.bottom(), thermo.enthalpy(&inlets.bottom)?)
+        .outflow(mass_flows.bottom(), thermo.enthalpy(bottom_outlet)?)
+        .heat_in(q_top_to_bottom);
+
+    Ok([top, bottom])
+}
+
+/// Builds the exergy balance of a solved recuperator.
+///
+/// The hot stream's exergy drop is fuel and the cold stream's exergy rise is
+/// product.
+fn exergy_balance<Fluid>(
+    inlets: &Inlets<Fluid, Fluid>,
+    mass_flows: MassFlows,
+    outlets: [&State<Fluid>; 2],
+    thermo: &(impl HasEnthalpy<Fluid = Fluid> + HasEntropy),
+    dead: &DeadState,
+) -> Result<ExergyBalance, PropertyError> {
+    let [top_outlet, bottom_outlet] = outlets;
+    let psi = |state: &State<Fluid>| flow_exergy(thermo, state, dead);
+
+    Ok(ExergyBalance::new("recuperator", dead.temperature)
+        .stream(mass_flows.top(), psi(&inlets.top)?, psi(top_outlet)?)
+        .stream(
+            mass_flows.bottom(),
+            psi(&inlets.bottom)?,
+            psi(bottom_outlet)?,
+        ))
+}
@@ -9,7 +9,15 @@ use crate::{
         DiscretizedHx, DiscretizedHxThermoModel, Given, HeatTransferRate, Inlets, Known, MassFlows,
         MinDeltaT, PressureDrops, Results, SolveError,
     },
-    support::{hx::arrangement::CounterFlow, thermo::State},
+    support::{
+        exergy::{DeadState, ExergyBalance},
+        hx::arrangement::CounterFlow,
+        testing::Balance,
+        thermo::{
+            PropertyError, State,
+            capability::{HasEnthalpy, HasEntropy},
+        },
+    },
 };
 
 /// A single-fluid counterflow heat exchanger model for heat recovery,
@@ -43,7 +51,7 @@ use crate::{
 /// at the cost of additional computation. Internally, `segments` maps
 /// to a const-generic node count (`N = segments + 1`).
 ///
-/// Supported values: 1, 5, 10, 20, 50. These are a practical subset —
+/// Supported values: 1, 5, 10, 20, 50, 100. These are a practical subset —
 /// additional values can be added with no runtime cost (the tradeoff is
 /// compile time and binary size from const-generic monomorphization).
 ///
@@ -81,6 +89,22 @@ pub struct RecuperatorGivenOutletInput<Fluid> {
     pub outlet_temp: OutletTemp,
 }
 
+impl<Fluid> RecuperatorGivenOutletInput<Fluid> {
+    /// Creates inputs from validated [`Known`] inputs and an outlet temperature.
+    ///
+    /// Use [`Known::builder`] to assemble and validate the inlet states,
+    /// mass flows, and pressure drops.
+    #[must_use]
+    pub fn new(known: Known<Fluid, Fluid>, outlet_temp: OutletTemp) -> Self {
+        Self {
+            inlets: known.inlets,
+            mass_flows: known.m_dot,
+            pressure_drops: known.dp,
+            outlet_temp,
+        }
+    }
+}
+
 /// Outputs from [`RecuperatorGivenOutlet`].
 #[derive(Debug, Clone)]
 pub struct RecuperatorGivenOutletOutput<Fluid> {
@@ -100,11 +124,62 @@ pub struct RecuperatorGivenOutletOutput<Fluid> {
     pub min_delta_t: MinDeltaT,
 }
 
+impl<Fluid> RecuperatorGivenOutletOutput<Fluid> {
+    /// Returns the mass and energy balance of each stream.
+    ///
+    /// Pass the balances to a [`ConservationCheck`] to verify that the
+    /// outlet states are consistent with the reported heat transfer rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PropertyError`] if an enthalpy cannot be evaluated.
+    ///
+    /// [`ConservationCheck`]: crate::support::testing::ConservationCheck
+    pub fn stream_balances(
+        &self,
+        input: &RecuperatorGivenOutletInput<Fluid>,
+        thermo: &impl HasEnthalpy<Fluid = Fluid>,
+    ) -> Result<[Balance; 2], PropertyError> {
+        super::stream_balances(
+            &input.inlets,
+            input.mass_flows,
+            [&self.top_outlet, &self.bottom_outlet],
+            self.q_dot,
+            thermo,
+        )
+    }
+
+    /// Returns the exergy balance of the recuperator.
+    ///
+    /// Add it to an [`ExergyAudit`] alongside the rest of the cycle.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PropertyError`] if an enthalpy or entropy cannot be
+    /// evaluated.
+    ///
+    /// [`ExergyAudit`]: crate::support::exergy::ExergyAudit
+    pub fn exergy_balance(
+        &self,
+        input: &RecuperatorGivenOutletInput<Fluid>,
+        thermo: &(impl HasEnthalpy<Fluid = Fluid> + HasEntropy),
+        dead: &DeadState,
+    ) -> Result<ExergyBalance, PropertyError> {
+        super::exergy_balance(
+            &input.inlets,
+            input.mass_flows,
+            [&self.top_outlet, &self.bottom_outlet],
+            thermo,
+            dead,
+        )
+    }
+}
+
 /// Errors from [`RecuperatorGivenOutlet`] construction and solving.
 #[derive(Debug, Error)]
 pub enum RecuperatorGivenOutletError {
     /// The segment count is not supported.
-    #[error("unsupported segment count {0}; supported values are 1, 5, 10, 20, 50")]
+    #[error("unsupported segment count {0}; supported values are 1, 5, 10, 20, 50, 100")]
     UnsupportedSegments(usize),
 
     /// A thermodynamic model operation failed.
@@ -136,9 +211,9 @@ impl<Fluid, Thermo> RecuperatorGivenOutlet<Fluid, Thermo> {
     /// # Errors
     ///
     /// Returns [`RecuperatorGivenOutletError::UnsupportedSegments`] if
-    /// `segments` is not in `{1, 5, 10, 20, 50}`.
+    /// `segments` is not in `{1, 5, 10, 20, 50, 100}`.
     pub fn new(thermo: Thermo, segments: usize) -> Result<Self, RecuperatorGivenOutletError> {
-        if !matches!(segments, 1 | 5 | 10 | 20 | 50) {
+        if !matches!(segments, 1 | 5 | 10 | 20 | 50 | 100) {
             return Err(RecuperatorGivenOutletError::UnsupportedSegments(segments));
         }
 
@@ -206,6 +281,7 @@ where
             10 => self.solve::<11>(input),
             20 => self.solve::<21>(input),
             50 => self.solve::<51>(input),
+            100 => self.solve::<101>(input),
             _ => unreachable!("validated at construction"),
         }
     }
@@ -288,7 +364,7 @@ mod tests {
 
     #[test]
     fn new_accepts_supported_segment_counts() {
-        for n in [1, 5, 10, 20, 50] {
+        for n in [1, 5, 10, 20, 50, 100] {
             assert!(
                 RecuperatorGivenOutlet::<TestFluid, _>::new(thermo(), n).is_ok(),
                 "segment count {n} should be accepted",
@@ -298,7 +374,7 @@ mod tests {
 
     #[test]
     fn new_rejects_unsupported_segment_counts() {
-        for n in [0, 2, 3, 100] {
+        for n in [0, 2, 3, 200] {
             assert!(
                 matches!(
                     RecuperatorGivenOutlet::<TestFluid, _>::new(thermo(), n),
@@ -422,4 +498,22 @@ mod tests {
             epsilon = 1e-6,
         );
     }
+
+    #[test]
+    fn stream_balances_close() {
+        use crate::support::testing::{BalanceTolerance, ConservationCheck};
+
+        let model = RecuperatorGivenOutlet::new(thermo(), 10).unwrap();
+
+        for input in [
+            input_top(300.0, 400.0, 350.0),
+            input_bottom(300.0, 400.0, 320.0),
+        ] {
+            let output = model.call(&input).unwrap();
+            ConservationCheck::new()
+                .with_all(output.stream_balances(&input, &thermo()).unwrap())
+                .check(BalanceTolerance::default())
+                .unwrap();
+        }
+    }
 }
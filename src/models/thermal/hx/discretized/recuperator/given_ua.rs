@@ -2,14 +2,27 @@ use std::{error::Error as StdError, marker::PhantomData};
 
 use thiserror::Error;
 use twine_core::Model;
-use uom::si::f64::{TemperatureInterval, ThermalConductance};
+use uom::{
+    ConstZero,
+    si::f64::{Power, TemperatureInterval, ThermalConductance, ThermodynamicTemperature},
+};
 
 use crate::{
     models::thermal::hx::discretized::core::{
-        DiscretizedHx, DiscretizedHxThermoModel, GivenUaConfig, GivenUaError, GivenUaResults,
-        HeatTransferRate, Inlets, Known, MassFlows, MinDeltaT, PressureDrops,
+        DiscretizedHx, DiscretizedHxThermoModel, GivenUaConfig, GivenUaError, GivenUaIteration,
+        GivenUaResults, HeatTransferRate, Inlets, Known, MassFlows, MinDeltaT, PressureDrops,
+    },
+    support::{
+        constraint::{And, Constrained, ConstraintError, Finite, NonNegative, StrictlyPositive},
+        exergy::{DeadState, ExergyBalance},
+        hx::arrangement::CounterFlow,
+        testing::Balance,
+        thermo::{
+            PropertyError, State,
+            capability::{HasEnthalpy, HasEntropy},
+        },
+        units::display::pretty,
     },
-    support::{hx::arrangement::CounterFlow, thermo::State},
 };
 
 /// A single-fluid counterflow heat exchanger model for heat recovery,
@@ -55,13 +68,13 @@ use crate::{
 /// at the cost of additional computation. Internally, `segments` maps
 /// to a const-generic node count (`N = segments + 1`).
 ///
-/// Supported values: 1, 5, 10, 20, 50. These are a practical subset —
+/// Supported values: 1, 5, 10, 20, 50, 100. These are a practical subset —
 /// additional values can be added with no runtime cost (the tradeoff is
 /// compile time and binary size from const-generic monomorphization).
 ///
 /// Use 1 segment for quick estimates or analytical verification (reduces to
 /// the classical ε-NTU result). Use 10–20 for typical engineering accuracy.
-/// Use 50 for convergence studies.
+/// Use 50–100 for convergence studies.
 ///
 /// # Example
 ///
@@ -128,7 +141,9 @@ pub struct RecuperatorGivenUa<Fluid, Thermo> {
 }
 
 /// Solver configuration for [`RecuperatorGivenUa`].
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct RecuperatorGivenUaConfig {
     /// Relative tolerance on UA (dimensionless).
     ///
@@ -141,6 +156,13 @@ pub struct RecuperatorGivenUaConfig {
 
     /// Maximum number of solver iterations.
     pub max_iters: usize,
+
+    /// Axial conductance of the separating wall, `k·A / L`.
+    ///
+    /// Heat conducted along the wall from the hot end to the cold end
+    /// bypasses the exchange surface and lowers effectiveness at high NTU.
+    /// Zero (the default) neglects axial conduction.
+    pub wall_conductance: ThermalConductance,
 }
 
 impl Default for RecuperatorGivenUaConfig {
@@ -149,10 +171,98 @@ impl Default for RecuperatorGivenUaConfig {
             ua_rel_tol: 1e-6,
             temp_abs_tol: TemperatureInterval::new::<uom::si::temperature_interval::kelvin>(1e-6),
             max_iters: 100,
+            wall_conductance: ThermalConductance::ZERO,
+        }
+    }
+}
+
+impl RecuperatorGivenUaConfig {
+    /// Returns a builder starting from the default configuration.
+    ///
+    /// ```
+    /// use twine_models::models::thermal::hx::discretized::RecuperatorGivenUaConfig;
+    ///
+    /// let config = RecuperatorGivenUaConfig::builder()
+    ///     .ua_rel_tol(1e-8)
+    ///     .max_iters(200)
+    ///     .build()?;
+    /// assert_eq!(config.max_iters, 200);
+    /// # Ok::<(), twine_models::models::thermal::hx::discretized::RecuperatorGivenUaError>(())
+    /// ```
+    #[must_use]
+    pub fn builder() -> RecuperatorGivenUaConfigBuilder {
+        RecuperatorGivenUaConfigBuilder {
+            config: Self::default(),
+        }
+    }
+}
+
+/// Builder for [`RecuperatorGivenUaConfig`].
+///
+/// Unset fields keep their [`Default`] values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RecuperatorGivenUaConfigBuilder {
+    config: RecuperatorGivenUaConfig,
+}
+
+impl RecuperatorGivenUaConfigBuilder {
+    /// Sets the relative tolerance on UA.
+    #[must_use]
+    pub fn ua_rel_tol(mut self, ua_rel_tol: f64) -> Self {
+        self.config.ua_rel_tol = ua_rel_tol;
+        self
+    }
+
+    /// Sets the absolute tolerance on the temperature search variable.
+    #[must_use]
+    pub fn temp_abs_tol(mut self, temp_abs_tol: TemperatureInterval) -> Self {
+        self.config.temp_abs_tol = temp_abs_tol;
+        self
+    }
+
+    /// Sets the maximum number of solver iterations.
+    #[must_use]
+    pub fn max_iters(mut self, max_iters: usize) -> Self {
+        self.config.max_iters = max_iters;
+        self
+    }
+
+    /// Sets the axial conductance of the separating wall.
+    #[must_use]
+    pub fn wall_conductance(mut self, wall_conductance: ThermalConductance) -> Self {
+        self.config.wall_conductance = wall_conductance;
+        self
+    }
+
+    /// Validates and returns the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RecuperatorGivenUaError::InvalidConfig`] if a tolerance is
+    /// not strictly positive and finite, if `max_iters` is zero, or if the
+    /// wall conductance is negative or not finite.
+    pub fn build(self) -> Result<RecuperatorGivenUaConfig, RecuperatorGivenUaError> {
+        let invalid = |field, source| RecuperatorGivenUaError::InvalidConfig { field, source };
+        let config = self.config;
+
+        Constrained::<f64, And<StrictlyPositive, Finite>>::new(config.ua_rel_tol)
+            .map_err(|source| invalid("ua_rel_tol", source))?;
+        Constrained::<TemperatureInterval, And<StrictlyPositive, Finite>>::new(config.temp_abs_tol)
+            .map_err(|source| invalid("temp_abs_tol", source))?;
+        if config.max_iters == 0 {
+            return Err(invalid("max_iters", ConstraintError::Zero));
         }
+        check_wall_conductance(config.wall_conductance)
+            .map_err(|source| invalid("wall_conductance", source))?;
+
+        Ok(config)
     }
 }
 
+fn check_wall_conductance(wall_conductance: ThermalConductance) -> Result<(), ConstraintError> {
+    Constrained::<ThermalConductance, And<NonNegative, Finite>>::new(wall_conductance).map(|_| ())
+}
+
 /// Inputs for [`RecuperatorGivenUa`].
 #[derive(Debug, Clone)]
 pub struct RecuperatorGivenUaInput<Fluid> {
@@ -169,6 +279,22 @@ pub struct RecuperatorGivenUaInput<Fluid> {
     pub ua: ThermalConductance,
 }
 
+impl<Fluid> RecuperatorGivenUaInput<Fluid> {
+    /// Creates inputs from validated [`Known`] inputs and a target UA.
+    ///
+    /// Use [`Known::builder`] to assemble and validate the inlet states,
+    /// mass flows, and pressure drops.
+    #[must_use]
+    pub fn new(known: Known<Fluid, Fluid>, ua: ThermalConductance) -> Self {
+        Self {
+            inlets: known.inlets,
+            mass_flows: known.m_dot,
+            pressure_drops: known.dp,
+            ua,
+        }
+    }
+}
+
 /// Outputs from [`RecuperatorGivenUa`].
 #[derive(Debug, Clone)]
 pub struct RecuperatorGivenUaOutput<Fluid> {
@@ -191,11 +317,62 @@ pub struct RecuperatorGivenUaOutput<Fluid> {
     pub iterations: usize,
 }
 
+impl<Fluid> RecuperatorGivenUaOutput<Fluid> {
+    /// Returns the mass and energy balance of each stream.
+    ///
+    /// Pass the balances to a [`ConservationCheck`] to verify that the
+    /// outlet states are consistent with the reported heat transfer rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PropertyError`] if an enthalpy cannot be evaluated.
+    ///
+    /// [`ConservationCheck`]: crate::support::testing::ConservationCheck
+    pub fn stream_balances(
+        &self,
+        input: &RecuperatorGivenUaInput<Fluid>,
+        thermo: &impl HasEnthalpy<Fluid = Fluid>,
+    ) -> Result<[Balance; 2], PropertyError> {
+        super::stream_balances(
+            &input.inlets,
+            input.mass_flows,
+            [&self.top_outlet, &self.bottom_outlet],
+            self.q_dot,
+            thermo,
+        )
+    }
+
+    /// Returns the exergy balance of the recuperator.
+    ///
+    /// Add it to an [`ExergyAudit`] alongside the rest of the cycle.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PropertyError`] if an enthalpy or entropy cannot be
+    /// evaluated.
+    ///
+    /// [`ExergyAudit`]: crate::support::exergy::ExergyAudit
+    pub fn exergy_balance(
+        &self,
+        input: &RecuperatorGivenUaInput<Fluid>,
+        thermo: &(impl HasEnthalpy<Fluid = Fluid> + HasEntropy),
+        dead: &DeadState,
+    ) -> Result<ExergyBalance, PropertyError> {
+        super::exergy_balance(
+            &input.inlets,
+            input.mass_flows,
+            [&self.top_outlet, &self.bottom_outlet],
+            thermo,
+            dead,
+        )
+    }
+}
+
 /// Errors from [`RecuperatorGivenUa`] construction and solving.
 #[derive(Debug, Error)]
 pub enum RecuperatorGivenUaError {
     /// The segment count is not supported.
-    #[error("unsupported segment count {0}; supported values are 1, 5, 10, 20, 50")]
+    #[error("unsupported segment count {0}; supported values are 1, 5, 10, 20, 50, 100")]
     UnsupportedSegments(usize),
 
     /// The solver failed to converge.
@@ -216,9 +393,34 @@ pub enum RecuperatorGivenUaError {
     EqualInletTemperatures,
 
     /// The target UA is negative.
-    #[error("target UA must be non-negative, got {0:?}")]
+    #[error("target UA must be non-negative, got {}", pretty(*.0))]
     NegativeUa(ThermalConductance),
 
+    /// A solver configuration field is invalid.
+    #[error("invalid solver configuration: {field}")]
+    InvalidConfig {
+        /// Name of the invalid field.
+        field: &'static str,
+
+        /// Violated constraint.
+        #[source]
+        source: ConstraintError,
+    },
+
+    /// An inlet temperature is not above absolute zero.
+    #[error("{stream} inlet temperature must be above absolute zero, got {}", pretty(*.temperature))]
+    InvalidInletTemperature {
+        /// Stream the inlet belongs to (`"top"` or `"bottom"`).
+        stream: &'static str,
+
+        /// Rejected inlet temperature.
+        temperature: ThermodynamicTemperature,
+    },
+
+    /// Heat conducted along the wall closes the inlet temperature difference.
+    #[error("axial wall conduction of {} closes the inlet temperature difference", pretty(*.0))]
+    ExcessiveWallConduction(Power),
+
     /// A thermodynamic model operation failed.
     ///
     /// This failure can be from property evaluation or state construction.
@@ -244,13 +446,13 @@ impl<Fluid, Thermo> RecuperatorGivenUa<Fluid, Thermo> {
     /// # Errors
     ///
     /// Returns [`RecuperatorGivenUaError::UnsupportedSegments`] if `segments` is not in
-    /// `{1, 5, 10, 20, 50}`.
+    /// `{1, 5, 10, 20, 50, 100}`.
     pub fn new(
         thermo: Thermo,
         segments: usize,
         config: RecuperatorGivenUaConfig,
     ) -> Result<Self, RecuperatorGivenUaError> {
-        if !matches!(segments, 1 | 5 | 10 | 20 | 50) {
+        if !matches!(segments, 1 | 5 | 10 | 20 | 50 | 100) {
             return Err(RecuperatorGivenUaError::UnsupportedSegments(segments));
         }
 
@@ -262,9 +464,75 @@ impl<Fluid, Thermo> RecuperatorGivenUa<Fluid, Thermo> {
         })
     }
 
+    /// Solves like [`Model::call`], reporting each solver iteration to `observer`.
+    ///
+    /// `observer` receives the candidate outlet temperature, achieved UA,
+    /// residual, and bracket of every bisection step, so the trajectory of a
+    /// solve that fails to converge can be inspected or plotted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Model::call`].
+    pub fn call_observed(
+        &self,
+        input: &RecuperatorGivenUaInput<Fluid>,
+        mut observer: impl FnMut(&GivenUaIteration),
+    ) -> Result<RecuperatorGivenUaOutput<Fluid>, RecuperatorGivenUaError>
+    where
+        Fluid: Clone,
+        Thermo: DiscretizedHxThermoModel<Fluid>,
+    {
+        self.dispatch(input, None, &mut observer)
+    }
+
+    /// Solves like [`Model::call`], starting from a nearby solution.
+    ///
+    /// `top_outlet` is the top outlet temperature of a previous solve with
+    /// similar inputs, such as the last time step of a simulation. The solver
+    /// brackets the new outlet temperature close to it, which usually takes
+    /// fewer iterations than a cold start. The result is the same either way.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Model::call`].
+    pub fn call_warm(
+        &self,
+        input: &RecuperatorGivenUaInput<Fluid>,
+        top_outlet: ThermodynamicTemperature,
+    ) -> Result<RecuperatorGivenUaOutput<Fluid>, RecuperatorGivenUaError>
+    where
+        Fluid: Clone,
+        Thermo: DiscretizedHxThermoModel<Fluid>,
+    {
+        self.dispatch(input, Some(top_outlet), &mut |_| {})
+    }
+
+    fn dispatch(
+        &self,
+        input: &RecuperatorGivenUaInput<Fluid>,
+        warm_start: Option<ThermodynamicTemperature>,
+        observer: &mut impl FnMut(&GivenUaIteration),
+    ) -> Result<RecuperatorGivenUaOutput<Fluid>, RecuperatorGivenUaError>
+    where
+        Fluid: Clone,
+        Thermo: DiscretizedHxThermoModel<Fluid>,
+    {
+        match self.segments {
+            1 => self.solve::<2>(input, warm_start, observer),
+            5 => self.solve::<6>(input, warm_start, observer),
+            10 => self.solve::<11>(input, warm_start, observer),
+            20 => self.solve::<21>(input, warm_start, observer),
+            50 => self.solve::<51>(input, warm_start, observer),
+            100 => self.solve::<101>(input, warm_start, observer),
+            _ => unreachable!("validated at construction"),
+        }
+    }
+
     fn solve<const N: usize>(
         &self,
         input: &RecuperatorGivenUaInput<Fluid>,
+        warm_start: Option<ThermodynamicTemperature>,
+        observer: impl FnMut(&GivenUaIteration),
     ) -> Result<RecuperatorGivenUaOutput<Fluid>, RecuperatorGivenUaError>
     where
         Fluid: Clone,
@@ -281,13 +549,18 @@ impl<Fluid, Thermo> RecuperatorGivenUa<Fluid, Thermo> {
             max_iters: self.config.max_iters,
             temp_tol: self.config.temp_abs_tol,
             ua_tol: ua_abs_tol,
+            wall_conductance: self.config.wall_conductance,
+            warm_start,
+            ..GivenUaConfig::default()
         };
 
-        let given_ua_results = DiscretizedHx::<CounterFlow, N>::given_ua_same(
+        let given_ua_results = DiscretizedHx::<CounterFlow, N>::given_ua_observed(
             &known,
             input.ua,
             given_ua_config,
             &self.thermo,
+            &self.thermo,
+            observer,
         )
         .map_err(RecuperatorGivenUaError::from)?;
 
@@ -323,14 +596,7 @@ where
     type Error = RecuperatorGivenUaError;
 
     fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
-        match self.segments {
-            1 => self.solve::<2>(input),
-            5 => self.solve::<6>(input),
-            10 => self.solve::<11>(input),
-            20 => self.solve::<21>(input),
-            50 => self.solve::<51>(input),
-            _ => unreachable!("validated at construction"),
-        }
+        self.call_observed(input, |_| {})
     }
 }
 
@@ -347,6 +613,22 @@ impl From<GivenUaError> for RecuperatorGivenUaError {
                 iterations: None,
             },
             GivenUaError::EqualInletTemperatures => Self::EqualInletTemperatures,
+            GivenUaError::InvalidInletTemperature {
+                stream,
+                temperature,
+            } => Self::InvalidInletTemperature {
+                stream,
+                temperature,
+            },
+            GivenUaError::InvalidWallConductance(wall_conductance) => Self::InvalidConfig {
+                field: "wall_conductance",
+                source: check_wall_conductance(wall_conductance)
+                    .expect_err("the solver only rejects invalid wall conductances"),
+            },
+            GivenUaError::WallConductionRequiresCounterflow => {
+                unreachable!("the recuperator is always counterflow")
+            }
+            GivenUaError::ExcessiveWallConduction(q_dot) => Self::ExcessiveWallConduction(q_dot),
             GivenUaError::MaxIters { iters, .. } => Self::Convergence {
                 message: "iteration limit reached".to_owned(),
                 iterations: Some(iters),
@@ -362,8 +644,8 @@ mod tests {
     use approx::assert_relative_eq;
     use twine_core::Model;
     use uom::si::{
-        f64::MassRate, mass_rate::kilogram_per_second, thermal_conductance::watt_per_kelvin,
-        thermodynamic_temperature::kelvin,
+        f64::MassRate, mass_rate::kilogram_per_second, power::watt,
+        thermal_conductance::watt_per_kelvin, thermodynamic_temperature::kelvin,
     };
 
     use crate::models::thermal::hx::discretized::core::{
@@ -394,9 +676,77 @@ mod tests {
         }
     }
 
+    #[test]
+    fn config_builder_validates_fields() {
+        assert_eq!(
+            RecuperatorGivenUaConfig::builder().build().unwrap(),
+            RecuperatorGivenUaConfig::default()
+        );
+        assert!(matches!(
+            RecuperatorGivenUaConfig::builder().ua_rel_tol(0.0).build(),
+            Err(RecuperatorGivenUaError::InvalidConfig {
+                field: "ua_rel_tol",
+                ..
+            })
+        ));
+        assert!(matches!(
+            RecuperatorGivenUaConfig::builder()
+                .temp_abs_tol(TemperatureInterval::new::<
+                    uom::si::temperature_interval::kelvin,
+                >(f64::NAN))
+                .build(),
+            Err(RecuperatorGivenUaError::InvalidConfig {
+                field: "temp_abs_tol",
+                ..
+            })
+        ));
+        assert!(matches!(
+            RecuperatorGivenUaConfig::builder().max_iters(0).build(),
+            Err(RecuperatorGivenUaError::InvalidConfig {
+                field: "max_iters",
+                source: ConstraintError::Zero,
+            })
+        ));
+        assert!(matches!(
+            RecuperatorGivenUaConfig::builder()
+                .wall_conductance(ThermalConductance::new::<watt_per_kelvin>(-1.0))
+                .build(),
+            Err(RecuperatorGivenUaError::InvalidConfig {
+                field: "wall_conductance",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn input_from_known() {
+        let known = Known::builder()
+            .top_inlet(state(300.0))
+            .bottom_inlet(state(400.0))
+            .mass_flows(
+                MassRate::new::<kilogram_per_second>(1.0),
+                MassRate::new::<kilogram_per_second>(1.0),
+            )
+            .build()
+            .unwrap();
+        let ua = ThermalConductance::new::<watt_per_kelvin>(1000.0);
+
+        let recuperator =
+            RecuperatorGivenUa::new(thermo(), 5, RecuperatorGivenUaConfig::default()).unwrap();
+        let from_known = recuperator
+            .call(&RecuperatorGivenUaInput::new(known, ua))
+            .unwrap();
+        let literal = recuperator.call(&input(300.0, 400.0, 1000.0)).unwrap();
+
+        assert_relative_eq!(
+            from_known.top_outlet.temperature.get::<kelvin>(),
+            literal.top_outlet.temperature.get::<kelvin>()
+        );
+    }
+
     #[test]
     fn new_accepts_supported_segment_counts() {
-        for n in [1, 5, 10, 20, 50] {
+        for n in [1, 5, 10, 20, 50, 100] {
             assert!(
                 RecuperatorGivenUa::<TestFluid, _>::new(
                     thermo(),
@@ -411,7 +761,7 @@ mod tests {
 
     #[test]
     fn new_rejects_unsupported_segment_counts() {
-        for n in [0, 2, 3, 100] {
+        for n in [0, 2, 3, 200] {
             assert!(
                 matches!(
                     RecuperatorGivenUa::<TestFluid, _>::new(
@@ -471,6 +821,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn call_observed_reports_every_iteration() {
+        let inp = input(400.0, 600.0, 500.0);
+        let recuperator =
+            RecuperatorGivenUa::new(thermo(), 10, RecuperatorGivenUaConfig::default()).unwrap();
+
+        let mut history = Vec::new();
+        let out = recuperator
+            .call_observed(&inp, |iteration| history.push(*iteration))
+            .unwrap();
+
+        assert_eq!(history.len(), out.iterations);
+        let last = history.last().unwrap();
+        assert_relative_eq!(
+            last.top_outlet_temp.get::<kelvin>(),
+            out.top_outlet.temperature.get::<kelvin>(),
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            last.ua.unwrap().get::<watt_per_kelvin>(),
+            500.0,
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn call_warm_matches_call_in_fewer_iterations() {
+        // Unbalanced flows keep the UA residual smooth near the root.
+        let inp = RecuperatorGivenUaInput {
+            mass_flows: MassFlows::new_unchecked(
+                MassRate::new::<kilogram_per_second>(1.0),
+                MassRate::new::<kilogram_per_second>(1.5),
+            ),
+            ..input(400.0, 600.0, 500.0)
+        };
+        let recuperator =
+            RecuperatorGivenUa::new(thermo(), 10, RecuperatorGivenUaConfig::default()).unwrap();
+
+        let cold = recuperator.call(&inp).unwrap();
+        let warm = recuperator
+            .call_warm(&inp, cold.top_outlet.temperature)
+            .unwrap();
+
+        assert_relative_eq!(
+            warm.top_outlet.temperature.get::<kelvin>(),
+            cold.top_outlet.temperature.get::<kelvin>(),
+            epsilon = 1e-6
+        );
+        assert!(warm.iterations < cold.iterations);
+    }
+
+    #[test]
+    fn call_observed_reports_iterations_of_failed_solve() {
+        let config = RecuperatorGivenUaConfig::builder()
+            .max_iters(3)
+            .build()
+            .unwrap();
+        let recuperator = RecuperatorGivenUa::new(thermo(), 10, config).unwrap();
+
+        let mut history = Vec::new();
+        let result = recuperator.call_observed(&input(400.0, 600.0, 500.0), |iteration| {
+            history.push(*iteration);
+        });
+
+        assert!(result.is_err());
+        assert_eq!(history.len(), 3);
+        // Each bisection step narrows the bracket.
+        let width = |i: usize| {
+            let [low, high] = history[i].bracket;
+            high.get::<kelvin>() - low.get::<kelvin>()
+        };
+        assert!(width(2) < width(1) && width(1) < width(0));
+    }
+
     #[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
     mod coolprop_tests {
         use super::*;
@@ -639,4 +1063,127 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn matches_golden_sweep() {
+        use crate::support::testing::{GoldenFile, GoldenTable, Tolerance};
+
+        let recuperator =
+            RecuperatorGivenUa::new(thermo(), 20, RecuperatorGivenUaConfig::default()).unwrap();
+        let cases = [
+            (300.0, 500.0),
+            (300.0, 800.0),
+            (450.0, 500.0),
+            (700.0, 350.0),
+        ]
+        .into_iter()
+        .flat_map(|(top, bottom)| [0.0, 100.0, 1000.0, 10_000.0].map(|ua| input(top, bottom, ua)));
+
+        let table = GoldenTable::sweep(
+            &recuperator,
+            cases,
+            [
+                "top_in_k",
+                "bottom_in_k",
+                "target_ua_w_per_k",
+                "top_out_k",
+                "bottom_out_k",
+                "q_dot_w",
+            ],
+            |input, output| {
+                vec![
+                    input.inlets.top.temperature.get::<kelvin>(),
+                    input.inlets.bottom.temperature.get::<kelvin>(),
+                    input.ua.get::<watt_per_kelvin>(),
+                    output.top_outlet.temperature.get::<kelvin>(),
+                    output.bottom_outlet.temperature.get::<kelvin>(),
+                    output.q_dot.signed_top_to_bottom().value,
+                ]
+            },
+        )
+        .unwrap();
+
+        // Outlet temperatures only need to agree to within the solver's
+        // temperature tolerance.
+        GoldenFile::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/golden/recuperator_given_ua.csv"
+        ))
+        .tolerance("top_out_k", Tolerance::absolute(1e-5))
+        .tolerance("bottom_out_k", Tolerance::absolute(1e-5))
+        .tolerance("q_dot_w", Tolerance::absolute(1e-2))
+        .check(&table)
+        .unwrap();
+    }
+
+    #[test]
+    fn stream_balances_close() {
+        use crate::support::testing::{BalanceTolerance, ConservationCheck};
+
+        let recuperator =
+            RecuperatorGivenUa::new(thermo(), 10, RecuperatorGivenUaConfig::default()).unwrap();
+        let input = input(300.0, 500.0, 800.0);
+        let output = recuperator.call(&input).unwrap();
+
+        let [top, bottom] = output.stream_balances(&input, &thermo()).unwrap();
+        ConservationCheck::new()
+            .with(top.clone())
+            .with(bottom)
+            .check(BalanceTolerance::default())
+            .unwrap();
+
+        // Misreporting the heat rate breaks both stream balances.
+        let wrong = RecuperatorGivenUaOutput {
+            q_dot: HeatTransferRate::None,
+            ..output
+        };
+        let error = ConservationCheck::new()
+            .with_all(wrong.stream_balances(&input, &thermo()).unwrap())
+            .check(BalanceTolerance::default())
+            .unwrap_err();
+        assert_eq!(error.imbalances.len(), 2);
+        assert!(!error.imbalances[0].mass_violated);
+        assert_eq!(error.imbalances[0].component, top.component());
+    }
+
+    #[test]
+    fn wall_conduction_lowers_effectiveness() {
+        use crate::support::testing::{BalanceTolerance, ConservationCheck};
+
+        // Balanced flow with C = 1 kW/K and NTU = 20.
+        let input = input(300.0, 500.0, 20_000.0);
+        let ideal = RecuperatorGivenUa::new(thermo(), 1, RecuperatorGivenUaConfig::default())
+            .unwrap()
+            .call(&input)
+            .unwrap();
+
+        // λ = G / C = 0.02.
+        let config = RecuperatorGivenUaConfig::builder()
+            .wall_conductance(ThermalConductance::new::<watt_per_kelvin>(20.0))
+            .build()
+            .unwrap();
+        let recuperator = RecuperatorGivenUa::new(thermo(), 1, config).unwrap();
+        let output = recuperator.call(&input).unwrap();
+
+        // The wall carries λ·C·ΔT from the hot end to the cold end, and the
+        // surface sees inlets 2·λ·ΔT closer together.
+        let effectiveness = |q: HeatTransferRate| q.magnitude().get::<watt>() / 200_000.0;
+        let ntu_effectiveness = 20.0 / 21.0;
+        assert_relative_eq!(
+            effectiveness(ideal.q_dot),
+            ntu_effectiveness,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            effectiveness(output.q_dot),
+            0.02 + ntu_effectiveness * (1.0 - 2.0 * 0.02),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(output.ua.get::<watt_per_kelvin>(), 20_000.0, epsilon = 1e-2);
+
+        ConservationCheck::new()
+            .with_all(output.stream_balances(&input, &thermo()).unwrap())
+            .check(BalanceTolerance::default())
+            .unwrap();
+    }
 }
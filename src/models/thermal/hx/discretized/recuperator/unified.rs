@@ -0,0 +1,391 @@
+use thiserror::Error;
+use twine_core::Model;
+use uom::si::f64::ThermalConductance;
+
+use crate::{
+    models::thermal::hx::discretized::core::{
+        DiscretizedHxThermoModel, HeatTransferRate, Inlets, Known, MassFlows, MinDeltaT,
+        PressureDrops,
+    },
+    support::{
+        exergy::{DeadState, ExergyBalance},
+        testing::Balance,
+        thermo::{
+            PropertyError, State,
+            capability::{HasEnthalpy, HasEntropy},
+        },
+    },
+};
+
+use super::{
+    OutletTemp, RecuperatorGivenOutlet, RecuperatorGivenOutletError, RecuperatorGivenOutletInput,
+    RecuperatorGivenUa, RecuperatorGivenUaConfig, RecuperatorGivenUaError, RecuperatorGivenUaInput,
+};
+
+/// A single-fluid counterflow recuperator sized by either its UA or one
+/// outlet temperature.
+///
+/// `Recuperator` implements [`Model`] with one input and output type for
+/// both specifications, so a cycle assembly can wire it up once and switch
+/// between rating a fixed exchanger and designing one from a target outlet
+/// temperature without changing types.
+/// A [`RecuperatorSpec::Ua`] input solves like [`RecuperatorGivenUa`]; a
+/// [`RecuperatorSpec::OutletTemp`] input solves like
+/// [`RecuperatorGivenOutlet`].
+///
+/// The thermo model is usually borrowed (`&thermo`), which lets one property
+/// model serve every component of a cycle.
+///
+/// Streams and segments follow the same conventions as
+/// [`RecuperatorGivenUa`].
+///
+/// # Example
+///
+/// ```
+/// use twine_core::Model;
+/// use twine_models::{
+///     models::thermal::hx::discretized::{
+///         Inlets, MassFlows, OutletTemp, PressureDrops, Recuperator, RecuperatorGivenUaConfig,
+///         RecuperatorInput, RecuperatorSpec,
+///     },
+///     support::thermo::{State, fluid::Air, model::PerfectGas},
+/// };
+/// use uom::si::{
+///     f64::{MassDensity, MassRate, ThermalConductance, ThermodynamicTemperature},
+///     mass_density::kilogram_per_cubic_meter,
+///     mass_rate::kilogram_per_second,
+///     thermal_conductance::watt_per_kelvin,
+///     thermodynamic_temperature::kelvin,
+/// };
+///
+/// let thermo = PerfectGas::<Air>::new().unwrap();
+/// let recuperator = Recuperator::new(&thermo, 10, RecuperatorGivenUaConfig::default())?;
+///
+/// // Compressor discharge on top, turbine exhaust on the bottom.
+/// let inlets = Inlets {
+///     top: State::new(
+///         ThermodynamicTemperature::new::<kelvin>(400.0),
+///         MassDensity::new::<kilogram_per_cubic_meter>(2.0),
+///         Air,
+///     ),
+///     bottom: State::new(
+///         ThermodynamicTemperature::new::<kelvin>(600.0),
+///         MassDensity::new::<kilogram_per_cubic_meter>(1.0),
+///         Air,
+///     ),
+/// };
+/// let mass_flows = MassFlows::new_unchecked(
+///     MassRate::new::<kilogram_per_second>(1.0),
+///     MassRate::new::<kilogram_per_second>(1.0),
+/// );
+///
+/// // Rate a fixed exchanger...
+/// let rated = recuperator.call(&RecuperatorInput {
+///     inlets: inlets.clone(),
+///     mass_flows,
+///     pressure_drops: PressureDrops::zero(),
+///     spec: RecuperatorSpec::Ua(ThermalConductance::new::<watt_per_kelvin>(2000.0)),
+/// })?;
+///
+/// // ...then size one that delivers the same preheat.
+/// let sized = recuperator.call(&RecuperatorInput {
+///     inlets,
+///     mass_flows,
+///     pressure_drops: PressureDrops::zero(),
+///     spec: RecuperatorSpec::OutletTemp(OutletTemp::Top(rated.top_outlet.temperature)),
+/// })?;
+/// assert!((sized.ua.get::<watt_per_kelvin>() - 2000.0).abs() < 1.0);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Recuperator<Fluid, Thermo> {
+    given_ua: RecuperatorGivenUa<Fluid, Thermo>,
+    given_outlet: RecuperatorGivenOutlet<Fluid, Thermo>,
+}
+
+/// What sizes a [`Recuperator`].
+#[derive(Debug, Clone, Copy)]
+pub enum RecuperatorSpec {
+    /// Overall thermal conductance of the exchanger.
+    Ua(ThermalConductance),
+
+    /// A known outlet temperature and the stream it belongs to.
+    OutletTemp(OutletTemp),
+}
+
+/// Inputs for [`Recuperator`].
+#[derive(Debug, Clone)]
+pub struct RecuperatorInput<Fluid> {
+    /// Inlet states for top and bottom streams.
+    pub inlets: Inlets<Fluid, Fluid>,
+
+    /// Mass flow rates for top and bottom streams (strictly positive).
+    pub mass_flows: MassFlows,
+
+    /// Pressure drops for top and bottom streams (non-negative).
+    pub pressure_drops: PressureDrops,
+
+    /// The UA or outlet temperature that sizes the exchanger.
+    pub spec: RecuperatorSpec,
+}
+
+impl<Fluid> RecuperatorInput<Fluid> {
+    /// Creates inputs from validated [`Known`] inputs and a specification.
+    ///
+    /// Use [`Known::builder`] to assemble and validate the inlet states,
+    /// mass flows, and pressure drops.
+    #[must_use]
+    pub fn new(known: Known<Fluid, Fluid>, spec: RecuperatorSpec) -> Self {
+        Self {
+            inlets: known.inlets,
+            mass_flows: known.m_dot,
+            pressure_drops: known.dp,
+            spec,
+        }
+    }
+}
+
+/// Outputs from [`Recuperator`].
+#[derive(Debug, Clone)]
+pub struct RecuperatorOutput<Fluid> {
+    /// Top stream outlet state.
+    pub top_outlet: State<Fluid>,
+
+    /// Bottom stream outlet state.
+    pub bottom_outlet: State<Fluid>,
+
+    /// Heat transfer rate.
+    pub q_dot: HeatTransferRate,
+
+    /// Overall thermal conductance, achieved or computed.
+    pub ua: ThermalConductance,
+
+    /// Minimum hot-to-cold temperature difference and its location.
+    pub min_delta_t: MinDeltaT,
+}
+
+impl<Fluid> RecuperatorOutput<Fluid> {
+    /// Returns the mass and energy balance of each stream.
+    ///
+    /// Pass the balances to a [`ConservationCheck`] to verify that the
+    /// outlet states are consistent with the reported heat transfer rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PropertyError`] if an enthalpy cannot be evaluated.
+    ///
+    /// [`ConservationCheck`]: crate::support::testing::ConservationCheck
+    pub fn stream_balances(
+        &self,
+        input: &RecuperatorInput<Fluid>,
+        thermo: &impl HasEnthalpy<Fluid = Fluid>,
+    ) -> Result<[Balance; 2], PropertyError> {
+        super::stream_balances(
+            &input.inlets,
+            input.mass_flows,
+            [&self.top_outlet, &self.bottom_outlet],
+            self.q_dot,
+            thermo,
+        )
+    }
+
+    /// Returns the exergy balance of the recuperator.
+    ///
+    /// Add it to an [`ExergyAudit`] alongside the rest of the cycle.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PropertyError`] if an enthalpy or entropy cannot be
+    /// evaluated.
+    ///
+    /// [`ExergyAudit`]: crate::support::exergy::ExergyAudit
+    pub fn exergy_balance(
+        &self,
+        input: &RecuperatorInput<Fluid>,
+        thermo: &(impl HasEnthalpy<Fluid = Fluid> + HasEntropy),
+        dead: &DeadState,
+    ) -> Result<ExergyBalance, PropertyError> {
+        super::exergy_balance(
+            &input.inlets,
+            input.mass_flows,
+            [&self.top_outlet, &self.bottom_outlet],
+            thermo,
+            dead,
+        )
+    }
+}
+
+/// Errors from [`Recuperator`] construction and solving.
+#[derive(Debug, Error)]
+pub enum RecuperatorError {
+    /// Solving for outlet states given a UA failed.
+    #[error(transparent)]
+    GivenUa(#[from] RecuperatorGivenUaError),
+
+    /// Solving for UA given an outlet temperature failed.
+    #[error(transparent)]
+    GivenOutlet(#[from] RecuperatorGivenOutletError),
+}
+
+impl<Fluid, Thermo: Clone> Recuperator<Fluid, Thermo> {
+    /// Creates a discretized counterflow recuperator.
+    ///
+    /// `thermo` provides thermodynamic property evaluation, typically as a
+    /// reference. `segments` controls discretization fidelity, and `config`
+    /// sets the solver tolerances used when the UA is specified.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RecuperatorError::GivenUa`] if `segments` is not in
+    /// `{1, 5, 10, 20, 50, 100}`.
+    pub fn new(
+        thermo: Thermo,
+        segments: usize,
+        config: RecuperatorGivenUaConfig,
+    ) -> Result<Self, RecuperatorError> {
+        Ok(Self {
+            given_ua: RecuperatorGivenUa::new(thermo.clone(), segments, config)?,
+            given_outlet: RecuperatorGivenOutlet::new(thermo, segments)?,
+        })
+    }
+}
+
+impl<Fluid, Thermo> Model for Recuperator<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: DiscretizedHxThermoModel<Fluid>,
+{
+    type Input = RecuperatorInput<Fluid>;
+    type Output = RecuperatorOutput<Fluid>;
+    type Error = RecuperatorError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        let RecuperatorInput {
+            inlets,
+            mass_flows,
+            pressure_drops,
+            spec,
+        } = input.clone();
+
+        match spec {
+            RecuperatorSpec::Ua(ua) => {
+                let output = self.given_ua.call(&RecuperatorGivenUaInput {
+                    inlets,
+                    mass_flows,
+                    pressure_drops,
+                    ua,
+                })?;
+                Ok(RecuperatorOutput {
+                    top_outlet: output.top_outlet,
+                    bottom_outlet: output.bottom_outlet,
+                    q_dot: output.q_dot,
+                    ua: output.ua,
+                    min_delta_t: output.min_delta_t,
+                })
+            }
+            RecuperatorSpec::OutletTemp(outlet_temp) => {
+                let output = self.given_outlet.call(&RecuperatorGivenOutletInput {
+                    inlets,
+                    mass_flows,
+                    pressure_drops,
+                    outlet_temp,
+                })?;
+                Ok(RecuperatorOutput {
+                    top_outlet: output.top_outlet,
+                    bottom_outlet: output.bottom_outlet,
+                    q_dot: output.q_dot,
+                    ua: output.ua,
+                    min_delta_t: output.min_delta_t,
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::{MassRate, ThermodynamicTemperature},
+        mass_rate::kilogram_per_second,
+        thermal_conductance::watt_per_kelvin,
+        thermodynamic_temperature::kelvin,
+    };
+
+    use crate::models::thermal::hx::discretized::core::test_support::{
+        TestFluid, TestThermoModel, state,
+    };
+
+    fn input(spec: RecuperatorSpec) -> RecuperatorInput<TestFluid> {
+        RecuperatorInput {
+            inlets: Inlets {
+                top: state(300.0),
+                bottom: state(400.0),
+            },
+            mass_flows: MassFlows::new_unchecked(
+                MassRate::new::<kilogram_per_second>(1.0),
+                MassRate::new::<kilogram_per_second>(2.0),
+            ),
+            pressure_drops: PressureDrops::zero(),
+            spec,
+        }
+    }
+
+    #[test]
+    fn ua_and_outlet_specs_agree() {
+        let thermo = TestThermoModel::new();
+        let recuperator = Recuperator::new(&thermo, 10, RecuperatorGivenUaConfig::default())
+            .expect("recuperator should be valid");
+
+        let rated = recuperator
+            .call(&input(RecuperatorSpec::Ua(ThermalConductance::new::<
+                watt_per_kelvin,
+            >(1500.0))))
+            .expect("given-UA solve should succeed");
+        assert!(rated.top_outlet.temperature > ThermodynamicTemperature::new::<kelvin>(300.0));
+
+        let sized = recuperator
+            .call(&input(RecuperatorSpec::OutletTemp(OutletTemp::Top(
+                rated.top_outlet.temperature,
+            ))))
+            .expect("given-outlet solve should succeed");
+
+        assert_relative_eq!(
+            sized.ua.get::<watt_per_kelvin>(),
+            1500.0,
+            max_relative = 1e-5
+        );
+        assert_relative_eq!(
+            sized.bottom_outlet.temperature.get::<kelvin>(),
+            rated.bottom_outlet.temperature.get::<kelvin>(),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn surfaces_errors_from_either_solve() {
+        let thermo = TestThermoModel::new();
+        assert!(matches!(
+            Recuperator::<TestFluid, _>::new(&thermo, 7, RecuperatorGivenUaConfig::default()),
+            Err(RecuperatorError::GivenUa(
+                RecuperatorGivenUaError::UnsupportedSegments(7)
+            ))
+        ));
+
+        let recuperator = Recuperator::new(&thermo, 5, RecuperatorGivenUaConfig::default())
+            .expect("recuperator should be valid");
+
+        // Heating the cold top stream above the hot inlet is impossible.
+        let result = recuperator.call(&input(RecuperatorSpec::OutletTemp(OutletTemp::Top(
+            ThermodynamicTemperature::new::<kelvin>(450.0),
+        ))));
+        assert!(matches!(
+            result,
+            Err(RecuperatorError::GivenOutlet(
+                RecuperatorGivenOutletError::SecondLawViolation { .. }
+            ))
+        ));
+    }
+}
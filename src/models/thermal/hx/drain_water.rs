@@ -0,0 +1,83 @@
+//! Drain water heat recovery.
+//!
+//! A [`DrainWaterHeatRecovery`] unit wraps a copper coil around a vertical
+//! drain stack so the warm water running down the stack during a shower
+//! preheats the cold water heading for the water heater or the shower
+//! itself. It is a frequently evaluated retrofit, since it saves water
+//! heating energy with no moving parts.
+//!
+//! The unit is rated by its effectiveness at equal drain and potable flows,
+//! as published under CSA B55.1. Effectiveness is evaluated from the
+//! counterflow effectiveness-NTU relation with a conductance that scales
+//! with flow, so it falls at higher flows and depends on how the preheated
+//! water is piped. [`DrainWaterHeatRecovery::simulate`] runs a hot water
+//! draw profile and reports the heat recovered and the water heater energy
+//! saved.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::models::thermal::hx::drain_water::{
+//!     DrainWaterConnection, DrainWaterDraw, DrainWaterHeatRecovery, DrainWaterInput,
+//!     DrainWaterParameters,
+//! };
+//! use uom::si::{
+//!     f64::{
+//!         MassDensity, Ratio, SpecificHeatCapacity, TemperatureInterval,
+//!         ThermodynamicTemperature, Time, VolumeRate,
+//!     },
+//!     mass_density::kilogram_per_cubic_meter,
+//!     ratio::{percent, ratio},
+//!     specific_heat_capacity::joule_per_kilogram_kelvin,
+//!     temperature_interval::kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//!     time::minute,
+//!     volume_rate::liter_per_minute,
+//! };
+//!
+//! // A unit rated at 50% effectiveness, feeding both the heater and shower.
+//! let unit = DrainWaterHeatRecovery::new(DrainWaterParameters {
+//!     rated_effectiveness: Ratio::new::<percent>(50.0),
+//!     rated_flow: VolumeRate::new::<liter_per_minute>(9.5),
+//!     flow_exponent: 0.4,
+//!     connection: DrainWaterConnection::EqualFlow,
+//!     supply_temperature: ThermodynamicTemperature::new::<degree_celsius>(55.0),
+//!     drain_temperature_drop: TemperatureInterval::new::<kelvin>(2.0),
+//!     water_density: MassDensity::new::<kilogram_per_cubic_meter>(993.0),
+//!     water_specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(4180.0),
+//! })?;
+//!
+//! // An eight-minute shower at 40 °C with 10 °C mains water.
+//! let shower = DrainWaterDraw {
+//!     flow: VolumeRate::new::<liter_per_minute>(9.0),
+//!     fixture_temperature: ThermodynamicTemperature::new::<degree_celsius>(40.0),
+//!     mains_temperature: ThermodynamicTemperature::new::<degree_celsius>(10.0),
+//! };
+//! let output = unit.simulate(&DrainWaterInput {
+//!     draws: vec![shower; 8],
+//!     time_step: Time::new::<minute>(1.0),
+//! })?;
+//!
+//! // Close to half of the shower's water heating is recovered.
+//! assert!(output.savings_fraction > Ratio::new::<ratio>(0.4));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    DrainWaterConnection, DrainWaterDraw, DrainWaterError, DrainWaterHeatRecovery, DrainWaterInput,
+    DrainWaterOutput, DrainWaterParameters, DrainWaterStep,
+};
+
+impl Model for DrainWaterHeatRecovery {
+    type Input = DrainWaterInput;
+    type Output = DrainWaterOutput;
+    type Error = DrainWaterError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.simulate(input)
+    }
+}
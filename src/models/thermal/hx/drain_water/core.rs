@@ -0,0 +1,692 @@
+use std::convert::Infallible;
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::{
+        f64::{
+            Energy, MassDensity, Power, Ratio, SpecificHeatCapacity, TemperatureInterval,
+            ThermalConductance, ThermodynamicTemperature, Time, VolumeRate,
+        },
+        ratio::ratio,
+        thermodynamic_temperature::kelvin,
+    },
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive, UnitInterval},
+    hx::{CounterFlow, RawEffectivenessRelation, RawNtuRelation},
+    numerics::solve::{self, brent},
+    units::TemperatureDifference,
+};
+
+/// How the potable side of a drain water heat recovery unit is piped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DrainWaterConnection {
+    /// Preheated water feeds both the water heater and the cold side of the
+    /// fixture, so the potable flow equals the drain flow.
+    EqualFlow,
+
+    /// Preheated water feeds only the water heater; the fixture draws its
+    /// cold water straight from the mains.
+    HeaterOnly,
+
+    /// Preheated water feeds only the cold side of the fixture; the water
+    /// heater draws straight from the mains.
+    FixtureOnly,
+}
+
+/// Fixed parameters of a [`DrainWaterHeatRecovery`] unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DrainWaterParameters {
+    /// Effectiveness with equal drain and potable flows of `rated_flow`, as
+    /// published under CSA B55.1.
+    pub rated_effectiveness: Ratio,
+
+    /// Flow at which the effectiveness is rated, 9.5 L/min under CSA B55.1.
+    pub rated_flow: VolumeRate,
+
+    /// Exponent `n` in `UA ∝ flow^n`, with the flow on the potable side.
+    ///
+    /// The potable coil's film coefficient rises with flow while the falling
+    /// film barely changes, so values of about 0.3 to 0.5 fit published
+    /// effectiveness curves. Zero gives a flow-independent conductance.
+    pub flow_exponent: f64,
+
+    /// How the preheated water is piped.
+    pub connection: DrainWaterConnection,
+
+    /// Temperature the water heater delivers to the fixture.
+    pub supply_temperature: ThermodynamicTemperature,
+
+    /// How far the mixed fixture water cools before it reaches the drain.
+    pub drain_temperature_drop: TemperatureInterval,
+
+    /// Density of the water.
+    pub water_density: MassDensity,
+
+    /// Specific heat of the water.
+    pub water_specific_heat: SpecificHeatCapacity,
+}
+
+/// Errors from [`DrainWaterHeatRecovery`] construction and evaluation.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum DrainWaterError {
+    /// A unit parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// A draw is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// The preheat temperature of a fixture-only unit did not converge.
+    #[error("preheat temperature did not converge in {iterations} iterations")]
+    NotConverged {
+        /// Iterations performed before giving up.
+        iterations: usize,
+    },
+}
+
+/// A falling-film drain water heat recovery unit.
+///
+/// Warm water leaving a shower runs down the inside wall of a copper drain
+/// stack as a thin film while cold mains water climbs through a coil
+/// wrapped around the outside, so the two exchange heat in counterflow. The
+/// unit only recovers heat while water drains and is drawn at the same time,
+/// which makes showers its target.
+///
+/// The exchange follows the counterflow effectiveness-NTU relation. The
+/// conductance is derived from the rated effectiveness at equal flow and
+/// scales with the potable-side flow as `UA = UA_rated · (V / V_rated)^n`,
+/// so effectiveness falls as the flow rises and rises when the potable flow
+/// is smaller than the drain flow.
+///
+/// For each draw the fixture mixes hot water at the supply temperature with
+/// cold water to reach the fixture temperature. The drain carries the whole
+/// fixture flow, a little cooler than it left the fixture. Depending on the
+/// [`DrainWaterConnection`], the potable side carries the whole flow, only
+/// the hot share bound for the water heater, or only the cold share bound
+/// for the fixture; in the last case the cold share itself depends on the
+/// preheat temperature and is solved for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrainWaterHeatRecovery {
+    parameters: DrainWaterParameters,
+    rated_ua: ThermalConductance,
+}
+
+/// A single draw through the fixture served by the unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DrainWaterDraw {
+    /// Mixed flow leaving the fixture, which is also the drain flow.
+    pub flow: VolumeRate,
+
+    /// Temperature of the mixed water leaving the fixture.
+    pub fixture_temperature: ThermodynamicTemperature,
+
+    /// Cold water temperature entering the building.
+    pub mains_temperature: ThermodynamicTemperature,
+}
+
+/// Input to [`DrainWaterHeatRecovery::simulate`]: a draw profile.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DrainWaterInput {
+    /// Draw in each step; steps without a draw have zero flow.
+    pub draws: Vec<DrainWaterDraw>,
+
+    /// Duration of each step.
+    pub time_step: Time,
+}
+
+/// Operating point of a [`DrainWaterHeatRecovery`] unit for one draw.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrainWaterStep {
+    /// Effectiveness at this draw's flows, zero without a draw.
+    pub effectiveness: Ratio,
+
+    /// Potable water temperature leaving the unit.
+    pub preheat_temperature: ThermodynamicTemperature,
+
+    /// Drain water temperature leaving the unit.
+    pub drain_outlet_temperature: ThermodynamicTemperature,
+
+    /// Hot water drawn from the water heater.
+    pub hot_flow: VolumeRate,
+
+    /// Heat recovered from the drain water.
+    pub recovered: Power,
+
+    /// Heat the water heater adds to reach the supply temperature.
+    pub heater_load: Power,
+
+    /// Heat the water heater would add without the unit.
+    pub baseline_heater_load: Power,
+}
+
+/// Output from [`DrainWaterHeatRecovery::simulate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrainWaterOutput {
+    /// Operating point in each step.
+    pub steps: Vec<DrainWaterStep>,
+
+    /// Heat recovered over the profile.
+    pub recovered: Energy,
+
+    /// Water heater energy over the profile.
+    pub heater_energy: Energy,
+
+    /// Water heater energy over the profile without the unit.
+    pub baseline_heater_energy: Energy,
+
+    /// Share of the baseline water heater energy saved, zero if the profile
+    /// draws no hot water.
+    pub savings_fraction: Ratio,
+}
+
+impl DrainWaterHeatRecovery {
+    /// Creates a drain water heat recovery unit from its parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrainWaterError::InvalidParameter`] if the rated
+    /// effectiveness is not in [0, 1), the flow exponent is not in [0, 1],
+    /// the drain temperature drop is negative, or a flow, temperature, or
+    /// water property is not strictly positive and finite.
+    pub fn new(parameters: DrainWaterParameters) -> Result<Self, DrainWaterError> {
+        let DrainWaterParameters {
+            rated_effectiveness,
+            rated_flow,
+            flow_exponent,
+            connection: _,
+            supply_temperature,
+            drain_temperature_drop,
+            water_density,
+            water_specific_heat,
+        } = parameters;
+        let invalid = |message| Err(DrainWaterError::InvalidParameter(message));
+
+        if UnitInterval::check(&rated_effectiveness).is_err()
+            || rated_effectiveness.get::<ratio>() >= 1.0
+        {
+            return invalid("rated effectiveness must be in [0, 1)");
+        }
+        if StrictlyPositive::check(&rated_flow).is_err() || !rated_flow.is_finite() {
+            return invalid("rated flow must be strictly positive and finite");
+        }
+        if !(0.0..=1.0).contains(&flow_exponent) {
+            return invalid("flow exponent must be in [0, 1]");
+        }
+        if supply_temperature.get::<kelvin>() <= 0.0 || !supply_temperature.is_finite() {
+            return invalid("supply temperature must be strictly positive and finite");
+        }
+        if NonNegative::check(&drain_temperature_drop).is_err()
+            || !drain_temperature_drop.is_finite()
+        {
+            return invalid("drain temperature drop must be non-negative and finite");
+        }
+        if StrictlyPositive::check(&water_density).is_err() || !water_density.is_finite() {
+            return invalid("water density must be strictly positive and finite");
+        }
+        if StrictlyPositive::check(&water_specific_heat).is_err()
+            || !water_specific_heat.is_finite()
+        {
+            return invalid("water specific heat must be strictly positive and finite");
+        }
+
+        let rated_rate: ThermalConductance = rated_flow * water_density * water_specific_heat;
+        let rated_ntu = CounterFlow.ntu_raw(rated_effectiveness.get::<ratio>(), [1.0, 1.0]);
+        Ok(Self {
+            parameters,
+            rated_ua: rated_rate * rated_ntu,
+        })
+    }
+
+    /// Returns the unit parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &DrainWaterParameters {
+        &self.parameters
+    }
+
+    /// Returns the overall conductance at the rated flow.
+    #[must_use]
+    pub fn rated_ua(&self) -> ThermalConductance {
+        self.rated_ua
+    }
+
+    /// Returns the effectiveness at the given drain and potable flows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrainWaterError::InvalidInput`] if either flow is not
+    /// strictly positive and finite.
+    pub fn effectiveness(
+        &self,
+        drain_flow: VolumeRate,
+        potable_flow: VolumeRate,
+    ) -> Result<Ratio, DrainWaterError> {
+        for flow in [drain_flow, potable_flow] {
+            if StrictlyPositive::check(&flow).is_err() || !flow.is_finite() {
+                return Err(DrainWaterError::InvalidInput(
+                    "flows must be strictly positive and finite",
+                ));
+            }
+        }
+        Ok(Ratio::new::<ratio>(
+            self.exchange_effectiveness(drain_flow, potable_flow),
+        ))
+    }
+
+    /// Evaluates the heat recovered during a single draw.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrainWaterError::InvalidInput`] if the flow is negative or
+    /// not finite, or the fixture temperature does not lie between the mains
+    /// and supply temperatures, or [`DrainWaterError::NotConverged`] if the
+    /// preheat temperature of a fixture-only unit cannot be resolved.
+    pub fn evaluate(&self, draw: &DrainWaterDraw) -> Result<DrainWaterStep, DrainWaterError> {
+        let DrainWaterDraw {
+            flow,
+            fixture_temperature,
+            mains_temperature,
+        } = *draw;
+        let DrainWaterParameters {
+            connection,
+            supply_temperature,
+            drain_temperature_drop,
+            ..
+        } = self.parameters;
+
+        if NonNegative::check(&flow).is_err() || !flow.is_finite() {
+            return Err(DrainWaterError::InvalidInput(
+                "flow must be non-negative and finite",
+            ));
+        }
+        if !mains_temperature.is_finite()
+            || mains_temperature.get::<kelvin>() <= 0.0
+            || fixture_temperature <= mains_temperature
+            || fixture_temperature >= supply_temperature
+        {
+            return Err(DrainWaterError::InvalidInput(
+                "fixture temperature must lie between the mains and supply temperatures",
+            ));
+        }
+
+        let drain_temperature = fixture_temperature.plus(-drain_temperature_drop);
+        if flow == VolumeRate::ZERO {
+            return Ok(DrainWaterStep {
+                effectiveness: Ratio::ZERO,
+                preheat_temperature: mains_temperature,
+                drain_outlet_temperature: drain_temperature,
+                hot_flow: VolumeRate::ZERO,
+                recovered: Power::ZERO,
+                heater_load: Power::ZERO,
+                baseline_heater_load: Power::ZERO,
+            });
+        }
+
+        // Share of the fixture flow drawn hot when the cold side is at `cold`.
+        let hot_share = |cold: ThermodynamicTemperature| {
+            fixture_temperature.minus(cold) / supply_temperature.minus(cold)
+        };
+        let rate = |flow: VolumeRate| -> ThermalConductance {
+            flow * self.parameters.water_density * self.parameters.water_specific_heat
+        };
+        // Effectiveness and heat recovered with `potable` through the coil.
+        let exchange = |potable: VolumeRate| {
+            let effectiveness = self.exchange_effectiveness(flow, potable);
+            let recovered = rate(flow).min(rate(potable))
+                * drain_temperature.minus(mains_temperature)
+                * effectiveness;
+            (effectiveness, recovered)
+        };
+
+        let (effectiveness, recovered, preheat_temperature, hot_flow, heater_inlet) =
+            match connection {
+                DrainWaterConnection::EqualFlow => {
+                    let (effectiveness, recovered) = exchange(flow);
+                    let preheat = mains_temperature.plus(recovered / rate(flow));
+                    let hot_flow = flow * hot_share(preheat);
+                    (effectiveness, recovered, preheat, hot_flow, preheat)
+                }
+                DrainWaterConnection::HeaterOnly => {
+                    let hot_flow = flow * hot_share(mains_temperature);
+                    let (effectiveness, recovered) = exchange(hot_flow);
+                    let preheat = mains_temperature.plus(recovered / rate(hot_flow));
+                    (effectiveness, recovered, preheat, hot_flow, preheat)
+                }
+                DrainWaterConnection::FixtureOnly => {
+                    let preheat = self.fixture_preheat(
+                        flow,
+                        mains_temperature,
+                        drain_temperature,
+                        hot_share,
+                    )?;
+                    let cold_flow = flow * (1.0 - hot_share(preheat).get::<ratio>());
+                    let (effectiveness, recovered) = exchange(cold_flow);
+                    let hot_flow = flow - cold_flow;
+                    (
+                        effectiveness,
+                        recovered,
+                        preheat,
+                        hot_flow,
+                        mains_temperature,
+                    )
+                }
+            };
+
+        Ok(DrainWaterStep {
+            effectiveness: Ratio::new::<ratio>(effectiveness),
+            preheat_temperature,
+            drain_outlet_temperature: drain_temperature.plus(-(recovered / rate(flow))),
+            hot_flow,
+            recovered,
+            heater_load: rate(hot_flow) * supply_temperature.minus(heater_inlet),
+            baseline_heater_load: rate(flow) * fixture_temperature.minus(mains_temperature),
+        })
+    }
+
+    /// Runs a draw profile and totals the heat recovered and the water
+    /// heater energy with and without the unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrainWaterError::InvalidInput`] if the time step is not
+    /// strictly positive and finite, or any error from
+    /// [`evaluate`](Self::evaluate) for the first draw that fails.
+    pub fn simulate(&self, input: &DrainWaterInput) -> Result<DrainWaterOutput, DrainWaterError> {
+        let dt = input.time_step;
+        if StrictlyPositive::check(&dt).is_err() || !dt.is_finite() {
+            return Err(DrainWaterError::InvalidInput(
+                "time step must be strictly positive and finite",
+            ));
+        }
+
+        let steps = input
+            .draws
+            .iter()
+            .map(|draw| self.evaluate(draw))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let total = |power: fn(&DrainWaterStep) -> Power| -> Energy {
+            steps.iter().map(|step| power(step) * dt).sum()
+        };
+        let recovered = total(|step| step.recovered);
+        let heater_energy = total(|step| step.heater_load);
+        let baseline_heater_energy = total(|step| step.baseline_heater_load);
+        let savings_fraction = if baseline_heater_energy > Energy::ZERO {
+            (baseline_heater_energy - heater_energy) / baseline_heater_energy
+        } else {
+            Ratio::ZERO
+        };
+
+        Ok(DrainWaterOutput {
+            steps,
+            recovered,
+            heater_energy,
+            baseline_heater_energy,
+            savings_fraction,
+        })
+    }
+
+    /// Counterflow effectiveness with `potable` flowing through the coil.
+    fn exchange_effectiveness(&self, drain: VolumeRate, potable: VolumeRate) -> f64 {
+        let DrainWaterParameters {
+            rated_flow,
+            flow_exponent,
+            water_density,
+            water_specific_heat,
+            ..
+        } = self.parameters;
+
+        let ua = self.rated_ua * (potable / rated_flow).get::<ratio>().powf(flow_exponent);
+        let rates: [ThermalConductance; 2] =
+            [drain, potable].map(|flow| flow * water_density * water_specific_heat);
+        let ntu = (ua / rates[0].min(rates[1])).get::<ratio>();
+        CounterFlow.effectiveness_raw(ntu, rates.map(|rate| rate.value))
+    }
+
+    /// Solves for the preheat temperature of a fixture-only unit, whose
+    /// potable flow is the cold share of a fixture fed at that temperature.
+    fn fixture_preheat(
+        &self,
+        flow: VolumeRate,
+        mains: ThermodynamicTemperature,
+        drain: ThermodynamicTemperature,
+        hot_share: impl Fn(ThermodynamicTemperature) -> Ratio,
+    ) -> Result<ThermodynamicTemperature, DrainWaterError> {
+        if drain == mains {
+            return Ok(mains);
+        }
+
+        let rho_c = self.parameters.water_density * self.parameters.water_specific_heat;
+        let residual = |preheat_kelvin: f64| {
+            let preheat = ThermodynamicTemperature::new::<kelvin>(preheat_kelvin);
+            let cold_flow = flow * (1.0 - hot_share(preheat).get::<ratio>());
+            let effectiveness = self.exchange_effectiveness(flow, cold_flow);
+            let rates: [ThermalConductance; 2] = [flow * rho_c, cold_flow * rho_c];
+            let rise = drain.minus(mains) * effectiveness * (rates[0].min(rates[1]) / rates[1]);
+            Ok::<_, Infallible>(mains.plus(rise).get::<kelvin>() - preheat_kelvin)
+        };
+
+        let [low, high] = [mains.get::<kelvin>(), drain.get::<kelvin>()];
+        let solution = brent(
+            residual,
+            [low.min(high), low.max(high)],
+            &solve::Config {
+                max_iters: 100,
+                x_abs_tol: 1e-9,
+                x_rel_tol: 0.0,
+                residual_tol: 0.0,
+            },
+        )
+        .map_err(|error| DrainWaterError::NotConverged {
+            iterations: match error {
+                solve::SolveError::MaxIters { iters, .. } => iters,
+                _ => 0,
+            },
+        })?;
+
+        Ok(ThermodynamicTemperature::new::<kelvin>(solution.x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        energy::kilowatt_hour, mass_density::kilogram_per_cubic_meter, power::kilowatt,
+        ratio::percent, specific_heat_capacity::joule_per_kilogram_kelvin,
+        temperature_interval::kelvin as delta_kelvin, thermodynamic_temperature::degree_celsius,
+        time::minute, volume_rate::liter_per_minute,
+    };
+
+    use crate::support::units::test_utils::celsius;
+
+    fn lpm(value: f64) -> VolumeRate {
+        VolumeRate::new::<liter_per_minute>(value)
+    }
+
+    fn parameters(connection: DrainWaterConnection) -> DrainWaterParameters {
+        DrainWaterParameters {
+            rated_effectiveness: Ratio::new::<percent>(50.0),
+            rated_flow: lpm(9.5),
+            flow_exponent: 0.4,
+            connection,
+            supply_temperature: celsius(55.0),
+            drain_temperature_drop: TemperatureInterval::new::<delta_kelvin>(2.0),
+            water_density: MassDensity::new::<kilogram_per_cubic_meter>(993.0),
+            water_specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(4180.0),
+        }
+    }
+
+    fn unit(connection: DrainWaterConnection) -> DrainWaterHeatRecovery {
+        DrainWaterHeatRecovery::new(parameters(connection)).unwrap()
+    }
+
+    fn shower(flow: f64) -> DrainWaterDraw {
+        DrainWaterDraw {
+            flow: lpm(flow),
+            fixture_temperature: celsius(40.0),
+            mains_temperature: celsius(10.0),
+        }
+    }
+
+    #[test]
+    fn equal_flow_at_the_rated_flow_reproduces_the_rating() {
+        let unit = unit(DrainWaterConnection::EqualFlow);
+        assert_relative_eq!(
+            unit.effectiveness(lpm(9.5), lpm(9.5))
+                .unwrap()
+                .get::<percent>(),
+            50.0,
+            epsilon = 1e-9
+        );
+
+        // Without a temperature drop the drain enters at 40 °C.
+        let mut parameters = parameters(DrainWaterConnection::EqualFlow);
+        parameters.drain_temperature_drop = TemperatureInterval::ZERO;
+        let step = DrainWaterHeatRecovery::new(parameters)
+            .unwrap()
+            .evaluate(&shower(9.5))
+            .unwrap();
+        assert_relative_eq!(
+            step.preheat_temperature.get::<degree_celsius>(),
+            25.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            step.drain_outlet_temperature.get::<degree_celsius>(),
+            25.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn effectiveness_falls_as_flow_rises() {
+        let unit = unit(DrainWaterConnection::EqualFlow);
+        let at = |flow| unit.effectiveness(lpm(flow), lpm(flow)).unwrap();
+
+        assert!(at(5.0) > at(9.5));
+        assert!(at(9.5) > at(15.0));
+
+        // A smaller potable flow sees the drain as the larger stream.
+        assert!(unit.effectiveness(lpm(9.5), lpm(5.0)).unwrap() > at(5.0));
+    }
+
+    #[test]
+    fn recovered_heat_is_what_the_water_heater_saves() {
+        for connection in [
+            DrainWaterConnection::EqualFlow,
+            DrainWaterConnection::HeaterOnly,
+            DrainWaterConnection::FixtureOnly,
+        ] {
+            let step = unit(connection).evaluate(&shower(9.5)).unwrap();
+
+            assert!(step.recovered > Power::ZERO);
+            assert_relative_eq!(
+                (step.heater_load + step.recovered).get::<kilowatt>(),
+                step.baseline_heater_load.get::<kilowatt>(),
+                max_relative = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn equal_flow_recovers_the_most() {
+        let recovered = |connection| unit(connection).evaluate(&shower(9.5)).unwrap().recovered;
+
+        let equal = recovered(DrainWaterConnection::EqualFlow);
+        assert!(equal > recovered(DrainWaterConnection::HeaterOnly));
+        assert!(equal > recovered(DrainWaterConnection::FixtureOnly));
+    }
+
+    #[test]
+    fn fixture_only_preheat_is_self_consistent() {
+        let step = unit(DrainWaterConnection::FixtureOnly)
+            .evaluate(&shower(9.5))
+            .unwrap();
+
+        // The fixture still delivers 40 °C water from the preheated cold side.
+        let cold_flow = lpm(9.5) - step.hot_flow;
+        let mixed = (step.hot_flow * 55.0
+            + cold_flow * step.preheat_temperature.get::<degree_celsius>())
+            / lpm(9.5);
+        assert_relative_eq!(mixed.get::<ratio>(), 40.0, epsilon = 1e-6);
+        assert!(step.preheat_temperature > celsius(10.0));
+        assert!(step.preheat_temperature < celsius(38.0));
+    }
+
+    #[test]
+    fn simulate_totals_a_draw_profile() {
+        let unit = unit(DrainWaterConnection::EqualFlow);
+        let idle = DrainWaterDraw {
+            flow: VolumeRate::ZERO,
+            ..shower(0.0)
+        };
+        let draws = [vec![idle; 10], vec![shower(9.5); 8], vec![idle; 6]].concat();
+        let output = unit
+            .simulate(&DrainWaterInput {
+                draws,
+                time_step: Time::new::<minute>(1.0),
+            })
+            .unwrap();
+
+        let shower_step = unit.evaluate(&shower(9.5)).unwrap();
+        let minutes = Time::new::<minute>(8.0);
+        assert_relative_eq!(
+            output.recovered.get::<kilowatt_hour>(),
+            (shower_step.recovered * minutes).get::<kilowatt_hour>(),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            output.savings_fraction.get::<ratio>(),
+            (shower_step.recovered / shower_step.baseline_heater_load).get::<ratio>(),
+            max_relative = 1e-12
+        );
+        assert_eq!(output.steps[0].recovered, Power::ZERO);
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_inputs() {
+        let mut bad = parameters(DrainWaterConnection::EqualFlow);
+        bad.rated_effectiveness = Ratio::new::<ratio>(1.0);
+        assert!(matches!(
+            DrainWaterHeatRecovery::new(bad),
+            Err(DrainWaterError::InvalidParameter(_))
+        ));
+
+        let mut bad = parameters(DrainWaterConnection::EqualFlow);
+        bad.flow_exponent = 1.5;
+        assert!(matches!(
+            DrainWaterHeatRecovery::new(bad),
+            Err(DrainWaterError::InvalidParameter(_))
+        ));
+
+        let unit = unit(DrainWaterConnection::EqualFlow);
+        assert!(matches!(
+            unit.evaluate(&shower(-1.0)),
+            Err(DrainWaterError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            unit.evaluate(&DrainWaterDraw {
+                fixture_temperature: celsius(60.0),
+                ..shower(9.5)
+            }),
+            Err(DrainWaterError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            unit.simulate(&DrainWaterInput {
+                draws: vec![shower(9.5)],
+                time_step: Time::ZERO,
+            }),
+            Err(DrainWaterError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,83 @@
+//! Membrane energy recovery ventilators.
+//!
+//! An [`EnergyRecoveryVentilator`] exchanges both heat and moisture between
+//! the outdoor air supplied to a building and the stale air exhausted from
+//! it, across a vapor-permeable membrane core. In winter it warms and
+//! humidifies the incoming air; in summer it cools and dries it, cutting the
+//! load that ventilation places on the heating and cooling plant.
+//!
+//! Sensible and latent transfer each have their own effectiveness. Both are
+//! evaluated from counterflow effectiveness-NTU relations, so they rise as
+//! the flow through the core falls or the two streams become unbalanced.
+//! Parameters can be given as conductances or derived from the balanced-flow
+//! effectivenesses published in a product rating.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::hx::erv::{
+//!         EnergyRecoveryVentilator, EnergyRecoveryVentilatorInput,
+//!         EnergyRecoveryVentilatorParameters,
+//!     },
+//!     support::psychrometrics::MoistAir,
+//! };
+//! use uom::{
+//!     ConstZero,
+//!     si::{
+//!         f64::{MassRate, Power, Pressure, Ratio, ThermodynamicTemperature},
+//!         mass_rate::kilogram_per_second,
+//!         power::kilowatt,
+//!         pressure::kilopascal,
+//!         ratio::percent,
+//!         thermodynamic_temperature::degree_celsius,
+//!     },
+//! };
+//!
+//! let flow = MassRate::new::<kilogram_per_second>(0.5);
+//! let erv = EnergyRecoveryVentilator::new(EnergyRecoveryVentilatorParameters::from_ratings(
+//!     Ratio::new::<percent>(75.0),
+//!     Ratio::new::<percent>(60.0),
+//!     flow,
+//! )?)?;
+//!
+//! let atmosphere = Pressure::new::<kilopascal>(101.325);
+//! let output = erv.evaluate(&EnergyRecoveryVentilatorInput {
+//!     supply: MoistAir::from_relative_humidity(
+//!         ThermodynamicTemperature::new::<degree_celsius>(0.0),
+//!         Ratio::new::<percent>(60.0),
+//!         atmosphere,
+//!     )?,
+//!     supply_flow: flow,
+//!     exhaust: MoistAir::from_relative_humidity(
+//!         ThermodynamicTemperature::new::<degree_celsius>(21.0),
+//!         Ratio::new::<percent>(35.0),
+//!         atmosphere,
+//!     )?,
+//!     exhaust_flow: flow,
+//! })?;
+//!
+//! // Winter: the supply air is warmed and humidified.
+//! assert!(output.sensible_heat > Power::new::<kilowatt>(7.0));
+//! assert!(output.latent_heat > Power::ZERO);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    EnergyRecoveryVentilator, EnergyRecoveryVentilatorError, EnergyRecoveryVentilatorInput,
+    EnergyRecoveryVentilatorOutput, EnergyRecoveryVentilatorParameters,
+};
+
+impl Model for EnergyRecoveryVentilator {
+    type Input = EnergyRecoveryVentilatorInput;
+    type Output = EnergyRecoveryVentilatorOutput;
+    type Error = EnergyRecoveryVentilatorError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
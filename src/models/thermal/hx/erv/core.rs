@@ -0,0 +1,449 @@
+use thiserror::Error;
+use uom::si::{
+    f64::{MassRate, Power, Ratio, SpecificHeatCapacity, ThermalConductance},
+    ratio::ratio,
+    specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive, UnitInterval},
+    hx::{CounterFlow, RawEffectivenessRelation},
+    psychrometrics::{MoistAir, PsychrometricError},
+    units::TemperatureDifference,
+};
+
+/// Specific heat of dry air, used to convert a rated sensible effectiveness
+/// to a conductance.
+const DRY_AIR_SPECIFIC_HEAT: f64 = 1.006;
+
+/// Fixed parameters of an [`EnergyRecoveryVentilator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnergyRecoveryVentilatorParameters {
+    /// Overall thermal conductance between the two airstreams.
+    pub sensible_ua: ThermalConductance,
+
+    /// Overall moisture conductance of the membrane: the water transferred
+    /// per unit difference in humidity ratio between the streams.
+    ///
+    /// Zero gives a sensible-only heat recovery ventilator.
+    pub moisture_conductance: MassRate,
+}
+
+impl EnergyRecoveryVentilatorParameters {
+    /// Derives the conductances from effectivenesses rated at balanced flow,
+    /// as published under AHRI 1060.
+    ///
+    /// Each effectiveness is inverted through the balanced counterflow
+    /// relation `NTU = ε / (1 − ε)`, with the sensible conductance based on
+    /// the specific heat of dry air.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EnergyRecoveryVentilatorError::InvalidParameter`] if either
+    /// effectiveness is not in [0, 1) or the rated flow is not strictly
+    /// positive and finite.
+    pub fn from_ratings(
+        sensible_effectiveness: Ratio,
+        latent_effectiveness: Ratio,
+        rated_flow: MassRate,
+    ) -> Result<Self, EnergyRecoveryVentilatorError> {
+        for effectiveness in [sensible_effectiveness, latent_effectiveness] {
+            if UnitInterval::check(&effectiveness).is_err() || effectiveness.get::<ratio>() >= 1.0 {
+                return Err(EnergyRecoveryVentilatorError::InvalidParameter(
+                    "rated effectivenesses must be in [0, 1)",
+                ));
+            }
+        }
+        if StrictlyPositive::check(&rated_flow).is_err() || !rated_flow.is_finite() {
+            return Err(EnergyRecoveryVentilatorError::InvalidParameter(
+                "rated flow must be strictly positive and finite",
+            ));
+        }
+
+        let ntu = |effectiveness: Ratio| {
+            let e = effectiveness.get::<ratio>();
+            e / (1.0 - e)
+        };
+        let specific_heat =
+            SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(DRY_AIR_SPECIFIC_HEAT);
+        Ok(Self {
+            sensible_ua: rated_flow * specific_heat * ntu(sensible_effectiveness),
+            moisture_conductance: rated_flow * ntu(latent_effectiveness),
+        })
+    }
+}
+
+/// Errors from [`EnergyRecoveryVentilator`] construction and evaluation.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum EnergyRecoveryVentilatorError {
+    /// A ventilator parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating input is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// An outlet air state could not be formed, typically because a stream
+    /// would be cooled below its dew point.
+    #[error(transparent)]
+    Psychrometric(#[from] PsychrometricError),
+}
+
+/// A counter-current enthalpy recovery ventilator.
+///
+/// Outdoor supply air and building exhaust air flow on opposite sides of a
+/// vapor-permeable membrane that passes both heat and water. Heat and
+/// moisture transfer are treated as independent counterflow exchanges: the
+/// sensible effectiveness follows from `UA / C_min` over the humid heat
+/// capacity rates, and the latent effectiveness from the moisture
+/// conductance over the smaller dry-air flow. The latent exchange moves
+/// water down the humidity ratio difference, in whichever direction it
+/// points.
+///
+/// Condensation and frosting are not modeled. A stream cooled below its dew
+/// point is reported as a [`EnergyRecoveryVentilatorError::Psychrometric`]
+/// error.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnergyRecoveryVentilator {
+    parameters: EnergyRecoveryVentilatorParameters,
+}
+
+/// Input to the energy recovery ventilator model.
+#[derive(Debug, Clone, Copy)]
+pub struct EnergyRecoveryVentilatorInput {
+    /// Outdoor air entering the supply side.
+    pub supply: MoistAir,
+
+    /// Dry-air mass flow on the supply side.
+    pub supply_flow: MassRate,
+
+    /// Building air entering the exhaust side.
+    pub exhaust: MoistAir,
+
+    /// Dry-air mass flow on the exhaust side.
+    pub exhaust_flow: MassRate,
+}
+
+/// Output from the energy recovery ventilator model.
+///
+/// Transfer rates are signed into the supply stream: positive when the
+/// supply air is heated or humidified, as in winter, and negative when it is
+/// cooled or dried, as in summer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnergyRecoveryVentilatorOutput {
+    /// Air delivered to the building.
+    pub supply_outlet: MoistAir,
+
+    /// Air discharged outdoors.
+    pub exhaust_outlet: MoistAir,
+
+    /// Sensible effectiveness at these flows.
+    pub sensible_effectiveness: Ratio,
+
+    /// Latent effectiveness at these flows.
+    pub latent_effectiveness: Ratio,
+
+    /// Sensible heat transferred to the supply air.
+    pub sensible_heat: Power,
+
+    /// Latent heat carried into the supply air by the transferred water.
+    pub latent_heat: Power,
+
+    /// Total enthalpy gained by the supply air.
+    pub total_heat: Power,
+
+    /// Water transferred through the membrane to the supply air.
+    pub moisture_transfer: MassRate,
+}
+
+impl EnergyRecoveryVentilator {
+    /// Creates an energy recovery ventilator from its parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EnergyRecoveryVentilatorError::InvalidParameter`] if either
+    /// conductance is negative or not finite.
+    pub fn new(
+        parameters: EnergyRecoveryVentilatorParameters,
+    ) -> Result<Self, EnergyRecoveryVentilatorError> {
+        let EnergyRecoveryVentilatorParameters {
+            sensible_ua,
+            moisture_conductance,
+        } = parameters;
+
+        if NonNegative::check(&sensible_ua).is_err() || !sensible_ua.is_finite() {
+            return Err(EnergyRecoveryVentilatorError::InvalidParameter(
+                "sensible UA must be non-negative and finite",
+            ));
+        }
+        if NonNegative::check(&moisture_conductance).is_err() || !moisture_conductance.is_finite() {
+            return Err(EnergyRecoveryVentilatorError::InvalidParameter(
+                "moisture conductance must be non-negative and finite",
+            ));
+        }
+
+        Ok(Self { parameters })
+    }
+
+    /// Returns the ventilator parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &EnergyRecoveryVentilatorParameters {
+        &self.parameters
+    }
+
+    /// Evaluates the heat and moisture recovered at a single operating point.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EnergyRecoveryVentilatorError::InvalidInput`] if either
+    /// dry-air flow is not strictly positive and finite, or
+    /// [`EnergyRecoveryVentilatorError::Psychrometric`] if an outlet stream
+    /// would be supersaturated.
+    pub fn evaluate(
+        &self,
+        input: &EnergyRecoveryVentilatorInput,
+    ) -> Result<EnergyRecoveryVentilatorOutput, EnergyRecoveryVentilatorError> {
+        let EnergyRecoveryVentilatorInput {
+            supply,
+            supply_flow,
+            exhaust,
+            exhaust_flow,
+        } = *input;
+        for flow in [supply_flow, exhaust_flow] {
+            if StrictlyPositive::check(&flow).is_err() || !flow.is_finite() {
+                return Err(EnergyRecoveryVentilatorError::InvalidInput(
+                    "dry air flows must be strictly positive and finite",
+                ));
+            }
+        }
+        let EnergyRecoveryVentilatorParameters {
+            sensible_ua,
+            moisture_conductance,
+        } = self.parameters;
+
+        // Sensible exchange over the humid heat capacity rates.
+        let supply_rate = supply_flow * supply.humid_specific_heat();
+        let exhaust_rate = exhaust_flow * exhaust.humid_specific_heat();
+        let min_rate = supply_rate.min(exhaust_rate);
+        let sensible_effectiveness = CounterFlow.effectiveness_raw(
+            (sensible_ua / min_rate).get::<ratio>(),
+            [supply_rate.value, exhaust_rate.value],
+        );
+        let sensible_heat =
+            min_rate * exhaust.temperature().minus(supply.temperature()) * sensible_effectiveness;
+
+        // Latent exchange over the dry-air flows, by analogy.
+        let min_flow = supply_flow.min(exhaust_flow);
+        let latent_effectiveness = CounterFlow.effectiveness_raw(
+            (moisture_conductance / min_flow).get::<ratio>(),
+            [supply_flow.value, exhaust_flow.value],
+        );
+        let moisture_transfer =
+            min_flow * (exhaust.humidity_ratio() - supply.humidity_ratio()) * latent_effectiveness;
+
+        let supply_outlet = MoistAir::new(
+            supply.temperature().plus(sensible_heat / supply_rate),
+            supply.humidity_ratio() + moisture_transfer / supply_flow,
+            supply.pressure(),
+        )?;
+        let exhaust_outlet = MoistAir::new(
+            exhaust.temperature().plus(-(sensible_heat / exhaust_rate)),
+            exhaust.humidity_ratio() - moisture_transfer / exhaust_flow,
+            exhaust.pressure(),
+        )?;
+
+        let total_heat = supply_flow * (supply_outlet.enthalpy() - supply.enthalpy());
+        Ok(EnergyRecoveryVentilatorOutput {
+            supply_outlet,
+            exhaust_outlet,
+            sensible_effectiveness: Ratio::new::<ratio>(sensible_effectiveness),
+            latent_effectiveness: Ratio::new::<ratio>(latent_effectiveness),
+            sensible_heat,
+            latent_heat: total_heat - sensible_heat,
+            total_heat,
+            moisture_transfer,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::{
+        ConstZero,
+        si::{
+            f64::{Pressure, ThermodynamicTemperature},
+            mass_rate::kilogram_per_second,
+            power::kilowatt,
+            pressure::kilopascal,
+            ratio::percent,
+            thermodynamic_temperature::degree_celsius,
+        },
+    };
+
+    fn air(t: f64, rh: f64) -> MoistAir {
+        MoistAir::from_relative_humidity(
+            ThermodynamicTemperature::new::<degree_celsius>(t),
+            Ratio::new::<percent>(rh),
+            Pressure::new::<kilopascal>(101.325),
+        )
+        .unwrap()
+    }
+
+    fn flow(value: f64) -> MassRate {
+        MassRate::new::<kilogram_per_second>(value)
+    }
+
+    fn ventilator() -> EnergyRecoveryVentilator {
+        EnergyRecoveryVentilator::new(
+            EnergyRecoveryVentilatorParameters::from_ratings(
+                Ratio::new::<percent>(75.0),
+                Ratio::new::<percent>(60.0),
+                flow(1.0),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn summer(supply_flow: f64, exhaust_flow: f64) -> EnergyRecoveryVentilatorInput {
+        EnergyRecoveryVentilatorInput {
+            supply: air(35.0, 50.0),
+            supply_flow: flow(supply_flow),
+            exhaust: air(24.0, 50.0),
+            exhaust_flow: flow(exhaust_flow),
+        }
+    }
+
+    #[test]
+    fn balanced_flow_reproduces_ratings() {
+        let input = summer(1.0, 1.0);
+        let output = ventilator().evaluate(&input).unwrap();
+
+        // Humid specific heats differ slightly from dry air.
+        assert_relative_eq!(
+            output.sensible_effectiveness.get::<ratio>(),
+            0.75,
+            max_relative = 0.01
+        );
+        assert_relative_eq!(
+            output.latent_effectiveness.get::<ratio>(),
+            0.60,
+            max_relative = 1e-12
+        );
+
+        // Summer: the supply is cooled and dried.
+        assert!(output.sensible_heat < Power::ZERO);
+        assert!(output.latent_heat < Power::ZERO);
+        assert!(output.moisture_transfer < MassRate::ZERO);
+        assert_relative_eq!(
+            output.supply_outlet.humidity_ratio().get::<ratio>(),
+            input.supply.humidity_ratio().get::<ratio>()
+                - 0.6
+                    * (input.supply.humidity_ratio() - input.exhaust.humidity_ratio())
+                        .get::<ratio>(),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn conserves_water_and_energy() {
+        let input = summer(1.2, 0.9);
+        let output = ventilator().evaluate(&input).unwrap();
+
+        let water_in = input.supply_flow * input.supply.humidity_ratio()
+            + input.exhaust_flow * input.exhaust.humidity_ratio();
+        let water_out = input.supply_flow * output.supply_outlet.humidity_ratio()
+            + input.exhaust_flow * output.exhaust_outlet.humidity_ratio();
+        assert_relative_eq!(
+            water_in.get::<kilogram_per_second>(),
+            water_out.get::<kilogram_per_second>(),
+            max_relative = 1e-12
+        );
+
+        let exhaust_gain =
+            input.exhaust_flow * (output.exhaust_outlet.enthalpy() - input.exhaust.enthalpy());
+        assert_relative_eq!(
+            output.total_heat.get::<kilowatt>(),
+            -exhaust_gain.get::<kilowatt>(),
+            max_relative = 0.01
+        );
+    }
+
+    #[test]
+    fn unbalanced_flow_raises_effectiveness() {
+        let balanced = ventilator().evaluate(&summer(1.0, 1.0)).unwrap();
+        let excess_exhaust = ventilator().evaluate(&summer(1.0, 1.5)).unwrap();
+        assert!(excess_exhaust.sensible_effectiveness > balanced.sensible_effectiveness);
+        assert!(excess_exhaust.latent_effectiveness > balanced.latent_effectiveness);
+
+        // Lower flow through the same core also raises the effectiveness.
+        let low_flow = ventilator().evaluate(&summer(0.5, 0.5)).unwrap();
+        assert!(low_flow.sensible_effectiveness > balanced.sensible_effectiveness);
+        assert!(low_flow.total_heat.abs() < balanced.total_heat.abs());
+    }
+
+    #[test]
+    fn heat_recovery_ventilator_moves_no_water() {
+        let hrv = EnergyRecoveryVentilator::new(EnergyRecoveryVentilatorParameters {
+            moisture_conductance: MassRate::ZERO,
+            ..*ventilator().parameters()
+        })
+        .unwrap();
+        let input = EnergyRecoveryVentilatorInput {
+            supply: air(0.0, 80.0),
+            supply_flow: flow(1.0),
+            exhaust: air(21.0, 30.0),
+            exhaust_flow: flow(1.0),
+        };
+        let output = hrv.evaluate(&input).unwrap();
+
+        assert!(output.sensible_heat > Power::ZERO);
+        assert_eq!(output.moisture_transfer, MassRate::ZERO);
+        assert_eq!(
+            output.supply_outlet.humidity_ratio(),
+            input.supply.humidity_ratio()
+        );
+    }
+
+    #[test]
+    fn cold_outdoor_air_condenses_in_the_exhaust() {
+        let input = EnergyRecoveryVentilatorInput {
+            supply: air(-20.0, 80.0),
+            supply_flow: flow(1.0),
+            exhaust: air(22.0, 60.0),
+            exhaust_flow: flow(1.0),
+        };
+        assert!(matches!(
+            ventilator().evaluate(&input),
+            Err(EnergyRecoveryVentilatorError::Psychrometric(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_inputs() {
+        assert!(matches!(
+            EnergyRecoveryVentilatorParameters::from_ratings(
+                Ratio::new::<ratio>(1.0),
+                Ratio::new::<percent>(60.0),
+                flow(1.0),
+            ),
+            Err(EnergyRecoveryVentilatorError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            EnergyRecoveryVentilator::new(EnergyRecoveryVentilatorParameters {
+                sensible_ua: -ventilator().parameters().sensible_ua,
+                ..*ventilator().parameters()
+            }),
+            Err(EnergyRecoveryVentilatorError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            ventilator().evaluate(&summer(0.0, 1.0)),
+            Err(EnergyRecoveryVentilatorError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,76 @@
+//! Flow maldistribution among parallel circuits fed by headers.
+//!
+//! A [`Manifold`] splits a stream among identical parallel circuits, as in
+//! microchannel coils and plate-fin exchangers, solving the flow through
+//! each circuit from the friction in the inlet and outlet headers. Each
+//! circuit is then evaluated as its own effectiveness-NTU exchanger against
+//! an even share of the other stream, and the result is compared with the
+//! evenly distributed exchanger to report the effectiveness penalty.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::hx::manifold::{
+//!         HeaderLayout, Manifold, ManifoldError, ManifoldInput, ManifoldParameters,
+//!     },
+//!     support::hx::{CapacitanceRate, CrossFlow, StreamInlet, Unmixed},
+//! };
+//! use uom::si::{
+//!     f64::{
+//!         MassRate, Pressure, SpecificHeatCapacity, ThermalConductance, ThermodynamicTemperature,
+//!     },
+//!     mass_rate::kilogram_per_second,
+//!     pressure::kilopascal,
+//!     ratio::percent,
+//!     specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+//!     thermal_conductance::kilowatt_per_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//! };
+//!
+//! let coil = Manifold::new(ManifoldParameters {
+//!     arrangement: CrossFlow::<Unmixed, Unmixed>::new(),
+//!     layout: HeaderLayout::U,
+//!     circuits: 30,
+//!     ua: ThermalConductance::new::<kilowatt_per_kelvin>(5.0),
+//!     rated_flow: MassRate::new::<kilogram_per_second>(0.6),
+//!     circuit_pressure_drop: Pressure::new::<kilopascal>(15.0),
+//!     header_pressure_drop: Pressure::new::<kilopascal>(0.1),
+//! })?;
+//!
+//! // Glycol cooled by outdoor air.
+//! let output = coil.evaluate(&ManifoldInput {
+//!     mass_flow: MassRate::new::<kilogram_per_second>(0.6),
+//!     specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(3.6),
+//!     inlet_temperature: ThermodynamicTemperature::new::<degree_celsius>(55.0),
+//!     other: StreamInlet::new(
+//!         CapacitanceRate::new::<kilowatt_per_kelvin>(4.0).unwrap(),
+//!         ThermodynamicTemperature::new::<degree_celsius>(30.0),
+//!     ),
+//! })?;
+//!
+//! // Circuits far from the connections run short of flow.
+//! assert!(output.circuit_flows[29] < output.circuit_flows[0]);
+//! assert!(output.effectiveness_penalty.get::<percent>() > 0.0);
+//! # Ok::<(), ManifoldError>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+use crate::support::hx::EffectivenessRelation;
+
+pub use core::{
+    HeaderLayout, Manifold, ManifoldError, ManifoldInput, ManifoldOutput, ManifoldParameters,
+};
+
+impl<Arrangement: EffectivenessRelation> Model for Manifold<Arrangement> {
+    type Input = ManifoldInput;
+    type Output = ManifoldOutput;
+    type Error = ManifoldError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
@@ -0,0 +1,565 @@
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::{
+        f64::{
+            MassRate, Power, Pressure, Ratio, SpecificHeatCapacity, ThermalConductance,
+            ThermodynamicTemperature,
+        },
+        mass_rate::kilogram_per_second,
+        pressure::pascal,
+        ratio::ratio,
+    },
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive},
+    hx::{
+        CapacitanceRate, EffectivenessRelation, StreamInlet,
+        functional::known_conductance_and_inlets,
+    },
+    numerics::solve::{self, SolveError, brent},
+};
+
+/// Relative tolerance on the first circuit's flow.
+const FLOW_REL_TOL: f64 = 1e-12;
+
+/// Relative mismatch between the circuit flows and the total flow that the
+/// solved distribution may leave.
+const BALANCE_TOL: f64 = 1e-6;
+
+/// Where the outlet header leaves relative to the inlet header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeaderLayout {
+    /// Reverse flow: both headers connect at the same end, so the fluid
+    /// turns back along the outlet header.
+    U,
+
+    /// Parallel flow: the outlet header leaves at the far end, so both
+    /// headers flow in the same direction.
+    Z,
+}
+
+/// Fixed design parameters of a [`Manifold`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ManifoldParameters<Arrangement> {
+    /// Flow arrangement of each circuit against the other stream.
+    pub arrangement: Arrangement,
+
+    /// Header connection layout.
+    pub layout: HeaderLayout,
+
+    /// Number of identical parallel circuits.
+    pub circuits: usize,
+
+    /// Total conductance, shared evenly among the circuits.
+    pub ua: ThermalConductance,
+
+    /// Total flow at which the pressure drops below are rated.
+    pub rated_flow: MassRate,
+
+    /// Pressure drop through one circuit carrying its even share of the
+    /// rated flow.
+    pub circuit_pressure_drop: Pressure,
+
+    /// Pressure drop along one header segment, between adjacent circuits,
+    /// carrying the full rated flow.
+    ///
+    /// The inlet and outlet headers are the same size.
+    pub header_pressure_drop: Pressure,
+}
+
+/// Errors that can occur when creating or evaluating a [`Manifold`].
+#[derive(Debug, Error)]
+pub enum ManifoldError {
+    /// A design parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating condition is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// The flow distribution did not converge.
+    #[error("flow distribution did not converge: {message}")]
+    Convergence {
+        /// Solver diagnostic.
+        message: String,
+
+        /// Iterations performed, when known.
+        iterations: Option<usize>,
+    },
+}
+
+/// Operating conditions of a [`Manifold`].
+#[derive(Debug, Clone, Copy)]
+pub struct ManifoldInput {
+    /// Total flow entering the inlet header.
+    pub mass_flow: MassRate,
+
+    /// Specific heat of the circuit fluid.
+    pub specific_heat: SpecificHeatCapacity,
+
+    /// Temperature entering the inlet header.
+    pub inlet_temperature: ThermodynamicTemperature,
+
+    /// The other stream, spread evenly across all circuits.
+    pub other: StreamInlet,
+}
+
+/// Flow distribution and heat transfer of a [`Manifold`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifoldOutput {
+    /// Flow through each circuit, from the header inlet onward.
+    pub circuit_flows: Vec<MassRate>,
+
+    /// Difference between the highest and lowest circuit flows over the
+    /// mean circuit flow.
+    pub flow_spread: Ratio,
+
+    /// Pressure drop from the inlet header entry to the outlet header exit.
+    pub pressure_drop: Pressure,
+
+    /// Heat into the circuit fluid, negative when it is cooled.
+    pub heat_rate: Power,
+
+    /// Mixed temperature leaving the outlet header.
+    pub outlet_temperature: ThermodynamicTemperature,
+
+    /// Temperature of the other stream after all circuits.
+    pub other_outlet_temperature: ThermodynamicTemperature,
+
+    /// Effectiveness of the exchanger as a whole.
+    pub effectiveness: Ratio,
+
+    /// Effectiveness the exchanger would have with perfectly even flow.
+    pub uniform_effectiveness: Ratio,
+
+    /// Fraction of the evenly distributed heat rate lost to
+    /// maldistribution.
+    pub effectiveness_penalty: Ratio,
+
+    /// Number of distribution iterations.
+    pub iterations: usize,
+}
+
+/// A heat exchanger of parallel circuits fed by inlet and outlet headers.
+///
+/// Friction along the headers leaves each circuit with a different driving
+/// pressure, so the circuits nearest the favorable end of the headers take
+/// more flow. Circuits and header segments follow quadratic laws,
+/// `ΔP = K · ṁ²`, with coefficients taken from the rated pressure drops.
+/// Marching along the headers from a trial flow in the first circuit fixes
+/// every other circuit's flow, and the first circuit's flow is solved with
+/// Brent's method so the circuits carry the total flow.
+///
+/// Each circuit is then a small exchanger with an even share of the
+/// conductance and of the other stream, evaluated with the
+/// effectiveness-NTU relation of `Arrangement`. Starved circuits saturate
+/// while flooded ones are short of conductance, so the sum falls below the
+/// evenly distributed heat rate. Velocity-head recovery in the headers and
+/// variation of the circuit conductance with flow are neglected, and the
+/// circuit fluid is single phase.
+#[derive(Debug, Clone)]
+pub struct Manifold<Arrangement> {
+    parameters: ManifoldParameters<Arrangement>,
+
+    /// Circuit resistance, Pa·s²/kg².
+    circuit_k: f64,
+
+    /// Header segment resistance, Pa·s²/kg².
+    header_k: f64,
+}
+
+fn check_input(input: &ManifoldInput) -> Result<(), ManifoldError> {
+    if StrictlyPositive::check(&input.mass_flow).is_err() || !input.mass_flow.is_finite() {
+        return Err(ManifoldError::InvalidInput(
+            "mass flow must be strictly positive and finite",
+        ));
+    }
+    if StrictlyPositive::check(&input.specific_heat).is_err() || !input.specific_heat.is_finite() {
+        return Err(ManifoldError::InvalidInput(
+            "specific heat must be strictly positive and finite",
+        ));
+    }
+    Ok(())
+}
+
+impl<Arrangement: EffectivenessRelation> Manifold<Arrangement> {
+    /// Creates a manifold from its design parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ManifoldError::InvalidParameter`] if there are no circuits,
+    /// the conductance, rated flow, or circuit pressure drop is not strictly
+    /// positive and finite, or the header pressure drop is negative.
+    pub fn new(parameters: ManifoldParameters<Arrangement>) -> Result<Self, ManifoldError> {
+        if parameters.circuits == 0 {
+            return Err(ManifoldError::InvalidParameter(
+                "at least one circuit is required",
+            ));
+        }
+        if StrictlyPositive::check(&parameters.ua).is_err() || !parameters.ua.is_finite() {
+            return Err(ManifoldError::InvalidParameter(
+                "UA must be strictly positive and finite",
+            ));
+        }
+        if StrictlyPositive::check(&parameters.rated_flow).is_err()
+            || !parameters.rated_flow.is_finite()
+        {
+            return Err(ManifoldError::InvalidParameter(
+                "rated flow must be strictly positive and finite",
+            ));
+        }
+        if StrictlyPositive::check(&parameters.circuit_pressure_drop).is_err()
+            || !parameters.circuit_pressure_drop.is_finite()
+        {
+            return Err(ManifoldError::InvalidParameter(
+                "circuit pressure drop must be strictly positive and finite",
+            ));
+        }
+        if NonNegative::check(&parameters.header_pressure_drop).is_err()
+            || !parameters.header_pressure_drop.is_finite()
+        {
+            return Err(ManifoldError::InvalidParameter(
+                "header pressure drop must be non-negative and finite",
+            ));
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let circuits = parameters.circuits as f64;
+        let rated = parameters.rated_flow.get::<kilogram_per_second>();
+        let circuit_k =
+            parameters.circuit_pressure_drop.get::<pascal>() / (rated / circuits).powi(2);
+        let header_k = parameters.header_pressure_drop.get::<pascal>() / rated.powi(2);
+
+        Ok(Self {
+            parameters,
+            circuit_k,
+            header_k,
+        })
+    }
+
+    /// Returns the design parameters.
+    pub fn parameters(&self) -> &ManifoldParameters<Arrangement> {
+        &self.parameters
+    }
+
+    /// Distributes the flow and evaluates the heat transfer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ManifoldError::InvalidInput`] for a non-positive flow or
+    /// specific heat, or [`ManifoldError::Convergence`] if the distribution
+    /// cannot be solved.
+    pub fn evaluate(&self, input: &ManifoldInput) -> Result<ManifoldOutput, ManifoldError> {
+        let ManifoldInput {
+            mass_flow,
+            specific_heat,
+            inlet_temperature,
+            other,
+        } = *input;
+        check_input(input)?;
+
+        let total = mass_flow.get::<kilogram_per_second>();
+        let solution = brent(
+            |first| Ok::<_, ManifoldError>((self.march(total, first).0 - total) / total),
+            [0.0, total],
+            &solve::Config {
+                max_iters: 100,
+                x_abs_tol: 0.0,
+                x_rel_tol: FLOW_REL_TOL,
+                residual_tol: 0.0,
+            },
+        )
+        .map_err(|error| match error {
+            SolveError::Eval(error) => error,
+            SolveError::MaxIters { iters, .. } => ManifoldError::Convergence {
+                message: error.to_string(),
+                iterations: Some(iters),
+            },
+            _ => ManifoldError::Convergence {
+                message: error.to_string(),
+                iterations: None,
+            },
+        })?;
+
+        // Header losses far above the circuit losses amplify round-off along
+        // the march until no trial flow balances.
+        let (sum, flows, pressure_drop) = self.march(total, solution.x);
+        if ((sum - total) / total).abs() > BALANCE_TOL || flows.iter().any(|&flow| flow < 0.0) {
+            return Err(ManifoldError::Convergence {
+                message: "circuit flows do not balance the total flow".to_owned(),
+                iterations: Some(solution.iters),
+            });
+        }
+        let circuit_flows: Vec<_> = flows
+            .iter()
+            .map(|&flow| MassRate::new::<kilogram_per_second>(flow))
+            .collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let circuits = self.parameters.circuits as f64;
+        let mean = total / circuits;
+        let (low, high) = flows
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), &flow| {
+                (low.min(flow), high.max(flow))
+            });
+
+        let ua = self.parameters.ua / circuits;
+        let other_share = *other.capacitance_rate / circuits;
+        let other_temperature = other.temperature;
+        let circuit_heat = |flow: MassRate| -> Result<(Power, ThermalConductance), ManifoldError> {
+            let capacitance =
+                CapacitanceRate::from_mass_rate_and_specific_heat(flow, specific_heat)
+                    .map_err(|_| ManifoldError::InvalidInput("circuit capacitance rate"))?;
+            let other = CapacitanceRate::from_quantity(other_share)
+                .map_err(|_| ManifoldError::InvalidInput("other stream capacitance rate"))?;
+            let result = known_conductance_and_inlets(
+                &self.parameters.arrangement,
+                ua,
+                [
+                    StreamInlet::new(capacitance, inlet_temperature),
+                    StreamInlet::new(other, other_temperature),
+                ],
+            )
+            .map_err(|_| ManifoldError::InvalidInput("circuit exchanger"))?;
+            let c_min = (*capacitance).min(other_share);
+            Ok((
+                result.streams[0].heat_flow.signed(),
+                *result.effectiveness * c_min,
+            ))
+        };
+
+        let mut heat_rate = Power::ZERO;
+        let mut effective_capacitance = ThermalConductance::ZERO;
+        for &flow in circuit_flows.iter().filter(|&&flow| flow > MassRate::ZERO) {
+            let (heat, capacitance) = circuit_heat(flow)?;
+            heat_rate += heat;
+            effective_capacitance += capacitance;
+        }
+        let (_, uniform_capacitance) = circuit_heat(mass_flow / circuits)?;
+
+        let capacitance = mass_flow * specific_heat;
+        let c_min = capacitance.min(*other.capacitance_rate);
+        let effectiveness = effective_capacitance / c_min;
+        let uniform_effectiveness = uniform_capacitance * circuits / c_min;
+
+        Ok(ManifoldOutput {
+            circuit_flows,
+            flow_spread: Ratio::new::<ratio>((high - low) / mean),
+            pressure_drop: Pressure::new::<pascal>(pressure_drop),
+            heat_rate,
+            outlet_temperature: inlet_temperature + heat_rate / capacitance,
+            other_outlet_temperature: other_temperature - heat_rate / *other.capacitance_rate,
+            effectiveness,
+            uniform_effectiveness,
+            effectiveness_penalty: Ratio::new::<ratio>(1.0) - effectiveness / uniform_effectiveness,
+            iterations: solution.iters,
+        })
+    }
+
+    /// Marches along the headers from a trial flow in the first circuit.
+    ///
+    /// Returns the summed circuit flow, each circuit's flow, and the overall
+    /// pressure drop, all in SI base units. A circuit or header segment
+    /// whose driving pressure is negative carries negative flow, which keeps
+    /// the summed flow rising with the trial flow.
+    fn march(&self, total: f64, first: f64) -> (f64, Vec<f64>, f64) {
+        let signed_drop = |k: f64, flow: f64| k * flow * flow.abs();
+        let flow_for = |drop: f64| (drop.abs() / self.circuit_k).sqrt().copysign(drop);
+
+        let mut flows = Vec::with_capacity(self.parameters.circuits);
+        let mut inlet = 0.0;
+        let mut outlet = -signed_drop(self.circuit_k, first);
+        let exit_outlet = outlet;
+        let mut sum = first;
+        flows.push(first);
+
+        for _ in 1..self.parameters.circuits {
+            inlet -= signed_drop(self.header_k, total - sum);
+            outlet = match self.parameters.layout {
+                HeaderLayout::U => outlet + signed_drop(self.header_k, total - sum),
+                HeaderLayout::Z => outlet - signed_drop(self.header_k, sum),
+            };
+            let flow = flow_for(inlet - outlet);
+            flows.push(flow);
+            sum += flow;
+        }
+
+        let exit = match self.parameters.layout {
+            HeaderLayout::U => exit_outlet,
+            HeaderLayout::Z => outlet,
+        };
+        (sum, flows, -exit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        mass_rate::kilogram_per_second, pressure::kilopascal,
+        specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+        thermal_conductance::kilowatt_per_kelvin, thermodynamic_temperature::degree_celsius,
+    };
+
+    use crate::support::{
+        hx::{CrossFlow, Unmixed},
+        units::TemperatureDifference,
+    };
+
+    type Arrangement = CrossFlow<Unmixed, Unmixed>;
+
+    fn parameters(layout: HeaderLayout, header_kpa: f64) -> ManifoldParameters<Arrangement> {
+        ManifoldParameters {
+            arrangement: CrossFlow::new(),
+            layout,
+            circuits: 20,
+            ua: ThermalConductance::new::<kilowatt_per_kelvin>(4.0),
+            rated_flow: MassRate::new::<kilogram_per_second>(0.5),
+            circuit_pressure_drop: Pressure::new::<kilopascal>(20.0),
+            header_pressure_drop: Pressure::new::<kilopascal>(header_kpa),
+        }
+    }
+
+    fn manifold(layout: HeaderLayout, header_kpa: f64) -> Manifold<Arrangement> {
+        Manifold::new(parameters(layout, header_kpa)).unwrap()
+    }
+
+    fn input() -> ManifoldInput {
+        ManifoldInput {
+            mass_flow: MassRate::new::<kilogram_per_second>(0.5),
+            specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(3.6),
+            inlet_temperature: ThermodynamicTemperature::new::<degree_celsius>(60.0),
+            other: StreamInlet::new(
+                CapacitanceRate::new::<kilowatt_per_kelvin>(3.0).unwrap(),
+                ThermodynamicTemperature::new::<degree_celsius>(25.0),
+            ),
+        }
+    }
+
+    fn kg_per_s(flow: MassRate) -> f64 {
+        flow.get::<kilogram_per_second>()
+    }
+
+    #[test]
+    fn frictionless_headers_split_evenly() {
+        for layout in [HeaderLayout::U, HeaderLayout::Z] {
+            let output = manifold(layout, 0.0).evaluate(&input()).unwrap();
+
+            for &flow in &output.circuit_flows {
+                assert_relative_eq!(kg_per_s(flow), 0.025, max_relative = 1e-9);
+            }
+            assert_relative_eq!(
+                output.pressure_drop.get::<kilopascal>(),
+                20.0,
+                max_relative = 1e-9
+            );
+            assert_relative_eq!(
+                output.effectiveness.get::<ratio>(),
+                output.uniform_effectiveness.get::<ratio>(),
+                max_relative = 1e-9
+            );
+            assert!(output.effectiveness_penalty.get::<ratio>().abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn u_layout_starves_the_far_circuits() {
+        let output = manifold(HeaderLayout::U, 1.0).evaluate(&input()).unwrap();
+        let flows: Vec<_> = output.circuit_flows.iter().map(|&f| kg_per_s(f)).collect();
+
+        assert!(flows.windows(2).all(|pair| pair[1] < pair[0]));
+        assert_relative_eq!(flows.iter().sum::<f64>(), 0.5, max_relative = 1e-12);
+        assert!(output.flow_spread.get::<ratio>() > 0.05);
+
+        // Both headers add loss beyond the circuits on the favored path.
+        let circuit_only = manifold(HeaderLayout::U, 0.0).evaluate(&input()).unwrap();
+        assert!(output.pressure_drop > circuit_only.pressure_drop);
+
+        let penalty = output.effectiveness_penalty.get::<ratio>();
+        assert!(penalty > 0.0 && penalty < 0.05, "penalty = {penalty}");
+        assert!(output.effectiveness < output.uniform_effectiveness);
+    }
+
+    #[test]
+    fn z_layout_starves_the_middle() {
+        let output = manifold(HeaderLayout::Z, 1.0).evaluate(&input()).unwrap();
+        let flows: Vec<_> = output.circuit_flows.iter().map(|&f| kg_per_s(f)).collect();
+
+        // With friction alone the two headers mirror each other.
+        assert_relative_eq!(flows[0], flows[19], max_relative = 1e-9);
+        assert!(flows[9] < flows[0]);
+        assert!(output.effectiveness_penalty.get::<ratio>() > 0.0);
+
+        // Every circuit sees a header path of the same length, so Z spreads
+        // the flow less than U.
+        let u = manifold(HeaderLayout::U, 1.0).evaluate(&input()).unwrap();
+        assert!(output.flow_spread < u.flow_spread);
+    }
+
+    #[test]
+    fn heavier_headers_cost_more() {
+        let light = manifold(HeaderLayout::U, 0.02).evaluate(&input()).unwrap();
+        let heavy = manifold(HeaderLayout::U, 0.2).evaluate(&input()).unwrap();
+
+        assert!(heavy.flow_spread > light.flow_spread);
+        assert!(heavy.effectiveness_penalty > light.effectiveness_penalty);
+        assert!(heavy.heat_rate > light.heat_rate);
+    }
+
+    #[test]
+    fn energy_balances_between_streams() {
+        let output = manifold(HeaderLayout::U, 0.1).evaluate(&input()).unwrap();
+        let input = input();
+
+        assert!(output.heat_rate < Power::ZERO);
+        let circuit = output.outlet_temperature.minus(input.inlet_temperature)
+            * (input.mass_flow * input.specific_heat);
+        let other = output
+            .other_outlet_temperature
+            .minus(input.other.temperature)
+            * *input.other.capacitance_rate;
+        assert_relative_eq!(circuit.value, output.heat_rate.value, max_relative = 1e-9);
+        assert_relative_eq!(other.value, -output.heat_rate.value, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn reports_when_header_loss_swamps_the_circuits() {
+        assert!(matches!(
+            manifold(HeaderLayout::Z, 1000.0).evaluate(&input()),
+            Err(ManifoldError::Convergence { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_inputs() {
+        let no_circuits = ManifoldParameters {
+            circuits: 0,
+            ..parameters(HeaderLayout::U, 0.0)
+        };
+        assert!(matches!(
+            Manifold::new(no_circuits),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+        let negative_header = parameters(HeaderLayout::Z, -1.0);
+        assert!(matches!(
+            Manifold::new(negative_header),
+            Err(ManifoldError::InvalidParameter(_))
+        ));
+
+        let no_flow = ManifoldInput {
+            mass_flow: MassRate::ZERO,
+            ..input()
+        };
+        assert!(matches!(
+            manifold(HeaderLayout::U, 0.0).evaluate(&no_flow),
+            Err(ManifoldError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,4 @@
+//! Pipe thermal models.
+
+pub mod freeze;
+pub mod heat_trace;
@@ -0,0 +1,83 @@
+//! Freezing and thawing of stagnant fluid in a pipe.
+//!
+//! A [`PipeFreeze`] follows a pipe full of standing water, or another
+//! [`FreezingFluid`], through a spell of cold weather: the fluid and wall
+//! give up their sensible heat, ice forms against the wall and grows inward
+//! as the latent heat is drawn off, and eventually the pipe is frozen
+//! solid. Resilience studies ask how long that takes once circulation stops,
+//! which [`PipeFreeze::time_to_freeze`] answers directly.
+//!
+//! [`PipeFreeze::simulate`] runs a whole ambient profile and reports when
+//! ice first forms, when the pipe freezes solid, and when it thaws again; to
+//! couple the pipe with other models one step at a time, start a
+//! [`PipeFreezeState`] with [`PipeFreeze::uniform_state`] and advance it
+//! with [`PipeFreeze::step`].
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::models::thermal::pipe::freeze::{
+//!     FreezingFluid, PipeFreeze, PipeFreezeParameters, PipeInsulation, PipeWall,
+//! };
+//! use uom::si::{
+//!     f64::{
+//!         HeatTransfer, Length, MassDensity, SpecificHeatCapacity, ThermalConductivity,
+//!         ThermodynamicTemperature, Time,
+//!     },
+//!     heat_transfer::watt_per_square_meter_kelvin,
+//!     length::millimeter,
+//!     mass_density::kilogram_per_cubic_meter,
+//!     specific_heat_capacity::joule_per_kilogram_kelvin,
+//!     thermal_conductivity::watt_per_meter_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//!     time::{day, hour},
+//! };
+//!
+//! // A 3/4" copper line with 13 mm of foam insulation.
+//! let pipe = PipeFreeze::new(PipeFreezeParameters {
+//!     wall: PipeWall {
+//!         inner_diameter: Length::new::<millimeter>(19.9),
+//!         thickness: Length::new::<millimeter>(1.1),
+//!         density: MassDensity::new::<kilogram_per_cubic_meter>(8930.0),
+//!         specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(385.0),
+//!         conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(390.0),
+//!     },
+//!     insulation: Some(PipeInsulation {
+//!         thickness: Length::new::<millimeter>(13.0),
+//!         conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(0.035),
+//!     }),
+//!     outer_coefficient: HeatTransfer::new::<watt_per_square_meter_kelvin>(15.0),
+//!     fluid: FreezingFluid::water(),
+//!     shells: 8,
+//! })?;
+//!
+//! // Circulation stops with the water at 10 °C and the air at −20 °C.
+//! let time = pipe
+//!     .time_to_freeze(
+//!         ThermodynamicTemperature::new::<degree_celsius>(10.0),
+//!         ThermodynamicTemperature::new::<degree_celsius>(-20.0),
+//!         Time::new::<day>(2.0),
+//!     )?
+//!     .expect("freezes within two days");
+//! assert!(time > Time::new::<hour>(3.0) && time < Time::new::<hour>(12.0));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    FreezingFluid, PipeFreeze, PipeFreezeError, PipeFreezeInput, PipeFreezeOutput,
+    PipeFreezeParameters, PipeFreezeState, PipeFreezeStep, PipeInsulation, PipeWall,
+};
+
+impl Model for PipeFreeze {
+    type Input = PipeFreezeInput;
+    type Output = PipeFreezeOutput;
+    type Error = PipeFreezeError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.simulate(input)
+    }
+}
@@ -0,0 +1,942 @@
+use std::f64::consts::PI;
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::{
+        available_energy::joule_per_kilogram,
+        f64::{
+            AvailableEnergy, HeatTransfer, Length, LinearPowerDensity, MassDensity, Ratio,
+            SpecificHeatCapacity, ThermalConductivity, ThermodynamicTemperature, Time,
+        },
+        heat_transfer::watt_per_square_meter_kelvin,
+        length::meter,
+        linear_power_density::watt_per_meter,
+        mass_density::kilogram_per_cubic_meter,
+        ratio::ratio,
+        specific_heat_capacity::joule_per_kilogram_kelvin,
+        temperature_interval::kelvin as delta_kelvin,
+        thermal_conductivity::watt_per_meter_kelvin,
+        thermodynamic_temperature::{degree_celsius, kelvin},
+        time::second,
+    },
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive},
+    units::TemperatureDifference,
+};
+
+/// Fraction of the explicit stability limit used for each substep.
+const STABILITY_MARGIN: f64 = 0.5;
+
+/// The pipe wall, which stores heat but does not change phase.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PipeWall {
+    /// Inside diameter, filled by the fluid.
+    pub inner_diameter: Length,
+
+    /// Wall thickness.
+    pub thickness: Length,
+
+    /// Density of the wall material.
+    pub density: MassDensity,
+
+    /// Specific heat of the wall material.
+    pub specific_heat: SpecificHeatCapacity,
+
+    /// Thermal conductivity of the wall material.
+    pub conductivity: ThermalConductivity,
+}
+
+/// Insulation wrapped around the pipe.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PipeInsulation {
+    /// Insulation thickness.
+    pub thickness: Length,
+
+    /// Thermal conductivity of the insulation.
+    pub conductivity: ThermalConductivity,
+}
+
+/// A fluid that freezes at a single temperature.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FreezingFluid {
+    /// Temperature at which the fluid freezes and melts.
+    pub freezing_temperature: ThermodynamicTemperature,
+
+    /// Latent heat of fusion.
+    pub latent_heat: AvailableEnergy,
+
+    /// Density of the liquid.
+    ///
+    /// The mass in the pipe is fixed, so expansion on freezing is ignored.
+    pub density: MassDensity,
+
+    /// Specific heat of the liquid.
+    pub liquid_specific_heat: SpecificHeatCapacity,
+
+    /// Specific heat of the solid.
+    pub solid_specific_heat: SpecificHeatCapacity,
+
+    /// Thermal conductivity of the liquid.
+    pub liquid_conductivity: ThermalConductivity,
+
+    /// Thermal conductivity of the solid.
+    pub solid_conductivity: ThermalConductivity,
+}
+
+impl FreezingFluid {
+    /// Water freezing to ice at 0 °C.
+    #[must_use]
+    pub fn water() -> Self {
+        Self {
+            freezing_temperature: ThermodynamicTemperature::new::<degree_celsius>(0.0),
+            latent_heat: AvailableEnergy::new::<joule_per_kilogram>(333.6e3),
+            density: MassDensity::new::<kilogram_per_cubic_meter>(999.8),
+            liquid_specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(4217.0),
+            solid_specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(2050.0),
+            liquid_conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(0.561),
+            solid_conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(2.22),
+        }
+    }
+}
+
+/// Fixed parameters of a [`PipeFreeze`] model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PipeFreezeParameters {
+    /// The pipe wall.
+    pub wall: PipeWall,
+
+    /// Insulation around the wall, if any.
+    pub insulation: Option<PipeInsulation>,
+
+    /// Convection and radiation coefficient from the outer surface to the
+    /// ambient.
+    pub outer_coefficient: HeatTransfer,
+
+    /// Fluid standing in the pipe.
+    pub fluid: FreezingFluid,
+
+    /// Number of concentric shells the fluid is divided into.
+    ///
+    /// One shell treats the fluid as a single lumped mass; more resolve the
+    /// ice growing inward from the wall.
+    pub shells: usize,
+}
+
+/// Errors from [`PipeFreeze`] construction and evaluation.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum PipeFreezeError {
+    /// A pipe or fluid parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating input is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+}
+
+/// Stagnant fluid in a pipe freezing or thawing as the ambient changes.
+///
+/// The fluid is divided into concentric shells of equal thickness, each
+/// tracking its specific enthalpy rather than its temperature. The enthalpy
+/// method makes the phase change part of the heat balance: a shell between
+/// fully solid and fully liquid sits at the freezing temperature while its
+/// enthalpy carries the latent heat, so the freezing front needs no explicit
+/// tracking. A shell's conductivity blends the liquid and solid values by
+/// its liquid fraction.
+///
+/// The wall is a single node at its mid-thickness, conducting to the outer
+/// shell of fluid and losing heat through the wall, any insulation, and the
+/// outer surface coefficient to the ambient. The fluid does not flow and
+/// heat moves through it by conduction only, so natural convection in the
+/// liquid before it freezes is neglected.
+///
+/// Shells and wall are advanced with explicit Euler substeps, each kept at
+/// half the stability limit of the stiffest node, so any step length may be
+/// requested.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipeFreeze {
+    parameters: PipeFreezeParameters,
+    phase: Phase,
+    masses: Vec<f64>,
+    /// Resistance from each shell's node to its outer face, per unit
+    /// conductivity.
+    outward: Vec<f64>,
+    /// Resistance from each shell's inner face to its node, per unit
+    /// conductivity.
+    inward: Vec<f64>,
+    wall_resistance: f64,
+    wall_capacity: f64,
+    loss_resistance: f64,
+    substep: f64,
+}
+
+/// Temperatures and ice of a [`PipeFreeze`] at one instant.
+///
+/// Start one with [`PipeFreeze::uniform_state`] and advance it with
+/// [`PipeFreeze::step`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipeFreezeState {
+    wall: f64,
+    enthalpies: Vec<f64>,
+}
+
+/// Input to the pipe freeze model: an ambient profile in fixed time steps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipeFreezeInput {
+    /// Uniform temperature of the fluid and wall at the start.
+    ///
+    /// Fluid at or above its freezing temperature starts liquid; below, it
+    /// starts frozen.
+    pub initial_temperature: ThermodynamicTemperature,
+
+    /// Ambient temperature in each time step.
+    pub ambient_temperatures: Vec<ThermodynamicTemperature>,
+
+    /// Length of each time step.
+    pub time_step: Time,
+}
+
+/// Conditions at the end of one time step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PipeFreezeStep {
+    /// Pipe wall temperature.
+    pub wall_temperature: ThermodynamicTemperature,
+
+    /// Mass-averaged fluid temperature.
+    pub fluid_temperature: ThermodynamicTemperature,
+
+    /// Temperature at the center of the pipe.
+    pub center_temperature: ThermodynamicTemperature,
+
+    /// Fraction of the fluid mass that is frozen.
+    pub ice_fraction: Ratio,
+
+    /// Heat lost to the ambient per length of pipe.
+    pub heat_loss: LinearPowerDensity,
+}
+
+/// Output from the pipe freeze model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipeFreezeOutput {
+    /// Conditions at the end of each time step.
+    pub steps: Vec<PipeFreezeStep>,
+
+    /// First time ice is present, zero if the fluid starts frozen.
+    pub ice_onset: Option<Time>,
+
+    /// First time the fluid is frozen solid across the whole pipe.
+    pub frozen_solid: Option<Time>,
+
+    /// First time the last of the ice melts, after ice has formed.
+    pub thawed: Option<Time>,
+}
+
+impl PipeFreeze {
+    /// Creates a pipe freeze model.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PipeFreezeError::InvalidParameter`] if there are no fluid
+    /// shells or any dimension or property is out of range.
+    pub fn new(parameters: PipeFreezeParameters) -> Result<Self, PipeFreezeError> {
+        let PipeFreezeParameters {
+            wall,
+            insulation,
+            outer_coefficient,
+            fluid,
+            shells,
+        } = parameters;
+
+        validate(&parameters)?;
+
+        let phase = Phase::new(&fluid);
+        let density = fluid.density.get::<kilogram_per_cubic_meter>();
+
+        let radius = 0.5 * wall.inner_diameter.get::<meter>();
+        // Shell counts are far below 2^52, so the casts are exact.
+        #[allow(clippy::cast_precision_loss)]
+        let width = radius / shells as f64;
+        let mut masses = Vec::with_capacity(shells);
+        let mut outward = Vec::with_capacity(shells);
+        let mut inward = Vec::with_capacity(shells);
+        for i in 0..shells {
+            #[allow(clippy::cast_precision_loss)]
+            let inner = i as f64 * width;
+            let outer = inner + width;
+            let node = inner + 0.5 * width;
+            masses.push(density * PI * (outer * outer - inner * inner));
+            outward.push((outer / node).ln() / (2.0 * PI));
+            inward.push(if i == 0 {
+                0.0
+            } else {
+                (node / inner).ln() / (2.0 * PI)
+            });
+        }
+
+        let wall_k = wall.conductivity.get::<watt_per_meter_kelvin>();
+        let wall_outer = radius + wall.thickness.get::<meter>();
+        let wall_node = radius + 0.5 * wall.thickness.get::<meter>();
+        let wall_resistance = (wall_node / radius).ln() / (2.0 * PI * wall_k);
+        let wall_capacity = wall.density.get::<kilogram_per_cubic_meter>()
+            * wall.specific_heat.get::<joule_per_kilogram_kelvin>()
+            * PI
+            * (wall_outer * wall_outer - radius * radius);
+
+        let mut loss_resistance = (wall_outer / wall_node).ln() / (2.0 * PI * wall_k);
+        let mut surface = wall_outer;
+        if let Some(insulation) = insulation {
+            let insulated = surface + insulation.thickness.get::<meter>();
+            loss_resistance += (insulated / surface).ln()
+                / (2.0 * PI * insulation.conductivity.get::<watt_per_meter_kelvin>());
+            surface = insulated;
+        }
+        loss_resistance +=
+            1.0 / (2.0 * PI * surface * outer_coefficient.get::<watt_per_square_meter_kelvin>());
+
+        // The stiffest node sets the substep, taking the lower specific heat
+        // and higher conductivity of the two phases.
+        let k_max = phase.liquid_conductivity.max(phase.solid_conductivity);
+        let c_min = phase.liquid_specific_heat.min(phase.solid_specific_heat);
+        let last = shells - 1;
+        let mut limit = f64::INFINITY;
+        for i in 0..shells {
+            let mut conductance = 0.0;
+            if i > 0 {
+                conductance += k_max / (outward[i - 1] + inward[i]);
+            }
+            conductance += if i < last {
+                k_max / (outward[i] + inward[i + 1])
+            } else {
+                1.0 / (outward[i] / k_max + wall_resistance)
+            };
+            limit = limit.min(masses[i] * c_min / conductance);
+        }
+        let wall_conductance =
+            1.0 / (outward[last] / k_max + wall_resistance) + 1.0 / loss_resistance;
+        limit = limit.min(wall_capacity / wall_conductance);
+
+        Ok(Self {
+            parameters,
+            phase,
+            masses,
+            outward,
+            inward,
+            wall_resistance,
+            wall_capacity,
+            loss_resistance,
+            substep: STABILITY_MARGIN * limit,
+        })
+    }
+
+    /// Returns the parameters this model was built from.
+    #[must_use]
+    pub fn parameters(&self) -> &PipeFreezeParameters {
+        &self.parameters
+    }
+
+    /// Returns a state with the fluid and wall at one temperature.
+    ///
+    /// Fluid at or above its freezing temperature is liquid; below, it is
+    /// frozen.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PipeFreezeError::InvalidInput`] if the temperature is not
+    /// finite.
+    pub fn uniform_state(
+        &self,
+        temperature: ThermodynamicTemperature,
+    ) -> Result<PipeFreezeState, PipeFreezeError> {
+        let temperature = check_temperature(temperature, "initial temperature must be finite")?;
+        Ok(PipeFreezeState {
+            wall: temperature,
+            enthalpies: vec![self.phase.enthalpy(temperature); self.masses.len()],
+        })
+    }
+
+    /// Advances `state` through `duration` with the ambient held at
+    /// `ambient_temperature`, and returns the conditions at the end.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PipeFreezeError::InvalidInput`] if the ambient temperature
+    /// is not finite, the duration is negative or not finite, or the state
+    /// belongs to a model with a different number of shells.
+    pub fn step(
+        &self,
+        state: &mut PipeFreezeState,
+        ambient_temperature: ThermodynamicTemperature,
+        duration: Time,
+    ) -> Result<PipeFreezeStep, PipeFreezeError> {
+        self.step_heated(
+            state,
+            ambient_temperature,
+            LinearPowerDensity::ZERO,
+            duration,
+        )
+    }
+
+    /// Advances `state` like [`step`](Self::step) while `heat` per length of
+    /// pipe is delivered to the wall, as by electric heat tracing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PipeFreezeError::InvalidInput`] under the same conditions as
+    /// [`step`](Self::step), or if the heat is not finite.
+    pub fn step_heated(
+        &self,
+        state: &mut PipeFreezeState,
+        ambient_temperature: ThermodynamicTemperature,
+        heat: LinearPowerDensity,
+        duration: Time,
+    ) -> Result<PipeFreezeStep, PipeFreezeError> {
+        let ambient = self.check_step(state, ambient_temperature, duration)?;
+        if !heat.is_finite() {
+            return Err(PipeFreezeError::InvalidInput("heat input must be finite"));
+        }
+        let heat = heat.get::<watt_per_meter>();
+        let (count, dt) = self.substeps(duration);
+        for _ in 0..count {
+            self.advance(state, ambient, heat, dt);
+        }
+        Ok(self.conditions(state, ambient))
+    }
+
+    /// Returns the steady heat loss per length of pipe with the wall held at
+    /// `wall_temperature`.
+    ///
+    /// This is the output heat tracing must supply to hold the pipe at that
+    /// temperature.
+    #[must_use]
+    pub fn heat_loss(
+        &self,
+        wall_temperature: ThermodynamicTemperature,
+        ambient_temperature: ThermodynamicTemperature,
+    ) -> LinearPowerDensity {
+        LinearPowerDensity::new::<watt_per_meter>(
+            wall_temperature
+                .minus(ambient_temperature)
+                .get::<delta_kelvin>()
+                / self.loss_resistance,
+        )
+    }
+
+    /// Returns how long the fluid takes to freeze solid from a uniform
+    /// `initial_temperature` with the ambient held at `ambient_temperature`,
+    /// or `None` if it is still partly liquid after `limit`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PipeFreezeError::InvalidInput`] if either temperature is not
+    /// finite or the limit is negative or not finite.
+    pub fn time_to_freeze(
+        &self,
+        initial_temperature: ThermodynamicTemperature,
+        ambient_temperature: ThermodynamicTemperature,
+        limit: Time,
+    ) -> Result<Option<Time>, PipeFreezeError> {
+        let mut state = self.uniform_state(initial_temperature)?;
+        let ambient = self.check_step(&state, ambient_temperature, limit)?;
+        let (count, dt) = self.substeps(limit);
+        for i in 0..=count {
+            if Self::frozen_solid(&state) {
+                // Substep counts are far below 2^52, so the cast is exact.
+                #[allow(clippy::cast_precision_loss)]
+                return Ok(Some(Time::new::<second>(i as f64 * dt)));
+            }
+            if i < count {
+                self.advance(&mut state, ambient, 0.0, dt);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Runs an ambient profile from a uniform initial temperature.
+    ///
+    /// Freezing and thawing events are resolved to the substep, finer than
+    /// the profile's time step.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PipeFreezeError::InvalidInput`] if the time step is not
+    /// strictly positive and finite or any temperature is not finite.
+    pub fn simulate(&self, input: &PipeFreezeInput) -> Result<PipeFreezeOutput, PipeFreezeError> {
+        if StrictlyPositive::check(&input.time_step).is_err() || !input.time_step.is_finite() {
+            return Err(PipeFreezeError::InvalidInput(
+                "time step must be strictly positive and finite",
+            ));
+        }
+        let mut state = self.uniform_state(input.initial_temperature)?;
+        let (count, dt) = self.substeps(input.time_step);
+
+        let mut ice_onset = self.has_ice(&state).then_some(0.0);
+        let mut frozen_solid = Self::frozen_solid(&state).then_some(0.0);
+        let mut thawed = None;
+        let mut elapsed: u32 = 0;
+        let mut steps = Vec::with_capacity(input.ambient_temperatures.len());
+        for &ambient_temperature in &input.ambient_temperatures {
+            let ambient =
+                check_temperature(ambient_temperature, "ambient temperature must be finite")?;
+            for _ in 0..count {
+                self.advance(&mut state, ambient, 0.0, dt);
+                elapsed += 1;
+                let time = f64::from(elapsed) * dt;
+                if ice_onset.is_none() {
+                    if self.has_ice(&state) {
+                        ice_onset = Some(time);
+                    }
+                } else if thawed.is_none() && !self.has_ice(&state) {
+                    thawed = Some(time);
+                }
+                if frozen_solid.is_none() && Self::frozen_solid(&state) {
+                    frozen_solid = Some(time);
+                }
+            }
+            steps.push(self.conditions(&state, ambient));
+        }
+
+        Ok(PipeFreezeOutput {
+            steps,
+            ice_onset: ice_onset.map(Time::new::<second>),
+            frozen_solid: frozen_solid.map(Time::new::<second>),
+            thawed: thawed.map(Time::new::<second>),
+        })
+    }
+
+    fn check_step(
+        &self,
+        state: &PipeFreezeState,
+        ambient_temperature: ThermodynamicTemperature,
+        duration: Time,
+    ) -> Result<f64, PipeFreezeError> {
+        if state.enthalpies.len() != self.masses.len() {
+            return Err(PipeFreezeError::InvalidInput(
+                "state must have the model's number of shells",
+            ));
+        }
+        if NonNegative::check(&duration).is_err() || !duration.is_finite() {
+            return Err(PipeFreezeError::InvalidInput(
+                "duration must be non-negative and finite",
+            ));
+        }
+        check_temperature(ambient_temperature, "ambient temperature must be finite")
+    }
+
+    /// Splits `duration` into equal substeps no longer than the stable one.
+    // The duration was checked finite and non-negative, and substep counts
+    // are far below 2^52.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn substeps(&self, duration: Time) -> (usize, f64) {
+        let duration = duration.get::<second>();
+        let count = (duration / self.substep).ceil() as usize;
+        if count == 0 {
+            (0, 0.0)
+        } else {
+            (count, duration / count as f64)
+        }
+    }
+
+    /// Takes one explicit Euler substep.
+    fn advance(&self, state: &mut PipeFreezeState, ambient: f64, heat: f64, dt: f64) {
+        let last = self.masses.len() - 1;
+        let temperatures: Vec<f64> = state
+            .enthalpies
+            .iter()
+            .map(|&h| self.phase.temperature(h))
+            .collect();
+        let conductivities: Vec<f64> = state
+            .enthalpies
+            .iter()
+            .map(|&h| self.phase.conductivity(h))
+            .collect();
+
+        // Heat flowing outward across each shell's outer face.
+        let mut flows = Vec::with_capacity(last + 1);
+        for i in 0..last {
+            let resistance =
+                self.outward[i] / conductivities[i] + self.inward[i + 1] / conductivities[i + 1];
+            flows.push((temperatures[i] - temperatures[i + 1]) / resistance);
+        }
+        let to_wall = (temperatures[last] - state.wall)
+            / (self.outward[last] / conductivities[last] + self.wall_resistance);
+        flows.push(to_wall);
+
+        let mut inflow = 0.0;
+        for (i, h) in state.enthalpies.iter_mut().enumerate() {
+            *h += dt * (inflow - flows[i]) / self.masses[i];
+            inflow = flows[i];
+        }
+        let loss = (state.wall - ambient) / self.loss_resistance;
+        state.wall += dt * (to_wall + heat - loss) / self.wall_capacity;
+    }
+
+    fn conditions(&self, state: &PipeFreezeState, ambient: f64) -> PipeFreezeStep {
+        let total: f64 = self.masses.iter().sum();
+        let mut temperature = 0.0;
+        let mut liquid = 0.0;
+        for (&mass, &h) in self.masses.iter().zip(&state.enthalpies) {
+            temperature += mass * self.phase.temperature(h);
+            liquid += mass * self.phase.liquid_fraction(h);
+        }
+        PipeFreezeStep {
+            wall_temperature: ThermodynamicTemperature::new::<kelvin>(state.wall),
+            fluid_temperature: ThermodynamicTemperature::new::<kelvin>(temperature / total),
+            center_temperature: ThermodynamicTemperature::new::<kelvin>(
+                self.phase.temperature(state.enthalpies[0]),
+            ),
+            ice_fraction: Ratio::new::<ratio>(1.0 - liquid / total),
+            heat_loss: LinearPowerDensity::new::<watt_per_meter>(
+                (state.wall - ambient) / self.loss_resistance,
+            ),
+        }
+    }
+
+    fn has_ice(&self, state: &PipeFreezeState) -> bool {
+        state.enthalpies.iter().any(|&h| h < self.phase.latent_heat)
+    }
+
+    fn frozen_solid(state: &PipeFreezeState) -> bool {
+        state.enthalpies.iter().all(|&h| h <= 0.0)
+    }
+}
+
+/// Fluid properties in SI units, with specific enthalpy measured from the
+/// solid at its freezing temperature.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Phase {
+    freezing_temperature: f64,
+    latent_heat: f64,
+    liquid_specific_heat: f64,
+    solid_specific_heat: f64,
+    liquid_conductivity: f64,
+    solid_conductivity: f64,
+}
+
+impl Phase {
+    fn new(fluid: &FreezingFluid) -> Self {
+        Self {
+            freezing_temperature: fluid.freezing_temperature.get::<kelvin>(),
+            latent_heat: fluid.latent_heat.get::<joule_per_kilogram>(),
+            liquid_specific_heat: fluid
+                .liquid_specific_heat
+                .get::<joule_per_kilogram_kelvin>(),
+            solid_specific_heat: fluid.solid_specific_heat.get::<joule_per_kilogram_kelvin>(),
+            liquid_conductivity: fluid.liquid_conductivity.get::<watt_per_meter_kelvin>(),
+            solid_conductivity: fluid.solid_conductivity.get::<watt_per_meter_kelvin>(),
+        }
+    }
+
+    /// Enthalpy of fluid at `temperature`, liquid at the freezing point.
+    fn enthalpy(&self, temperature: f64) -> f64 {
+        let excess = temperature - self.freezing_temperature;
+        if excess < 0.0 {
+            self.solid_specific_heat * excess
+        } else {
+            self.latent_heat + self.liquid_specific_heat * excess
+        }
+    }
+
+    fn temperature(&self, enthalpy: f64) -> f64 {
+        if enthalpy < 0.0 {
+            self.freezing_temperature + enthalpy / self.solid_specific_heat
+        } else if enthalpy > self.latent_heat {
+            self.freezing_temperature + (enthalpy - self.latent_heat) / self.liquid_specific_heat
+        } else {
+            self.freezing_temperature
+        }
+    }
+
+    fn liquid_fraction(&self, enthalpy: f64) -> f64 {
+        (enthalpy / self.latent_heat).clamp(0.0, 1.0)
+    }
+
+    fn conductivity(&self, enthalpy: f64) -> f64 {
+        let liquid = self.liquid_fraction(enthalpy);
+        liquid * self.liquid_conductivity + (1.0 - liquid) * self.solid_conductivity
+    }
+}
+
+/// Checks that a pipe freeze model's parameters are in range.
+fn validate(parameters: &PipeFreezeParameters) -> Result<(), PipeFreezeError> {
+    let PipeFreezeParameters {
+        wall,
+        insulation,
+        outer_coefficient,
+        fluid,
+        shells,
+    } = *parameters;
+
+    if shells == 0 {
+        return Err(PipeFreezeError::InvalidParameter(
+            "fluid must have at least one shell",
+        ));
+    }
+    check_positive(
+        wall.inner_diameter.value,
+        "inner diameter must be strictly positive and finite",
+    )?;
+    check_positive(
+        wall.thickness.value,
+        "wall thickness must be strictly positive and finite",
+    )?;
+    check_positive(
+        wall.density.value,
+        "wall density must be strictly positive and finite",
+    )?;
+    check_positive(
+        wall.specific_heat.value,
+        "wall specific heat must be strictly positive and finite",
+    )?;
+    check_positive(
+        wall.conductivity.value,
+        "wall conductivity must be strictly positive and finite",
+    )?;
+    if let Some(insulation) = insulation {
+        check_positive(
+            insulation.thickness.value,
+            "insulation thickness must be strictly positive and finite",
+        )?;
+        check_positive(
+            insulation.conductivity.value,
+            "insulation conductivity must be strictly positive and finite",
+        )?;
+    }
+    check_positive(
+        outer_coefficient.value,
+        "outer surface coefficient must be strictly positive and finite",
+    )?;
+    if !fluid.freezing_temperature.is_finite() {
+        return Err(PipeFreezeError::InvalidParameter(
+            "freezing temperature must be finite",
+        ));
+    }
+    check_positive(
+        fluid.latent_heat.value,
+        "latent heat must be strictly positive and finite",
+    )?;
+    check_positive(
+        fluid.density.value,
+        "fluid density must be strictly positive and finite",
+    )?;
+    check_positive(
+        fluid.liquid_specific_heat.value,
+        "liquid specific heat must be strictly positive and finite",
+    )?;
+    check_positive(
+        fluid.solid_specific_heat.value,
+        "solid specific heat must be strictly positive and finite",
+    )?;
+    check_positive(
+        fluid.liquid_conductivity.value,
+        "liquid conductivity must be strictly positive and finite",
+    )?;
+    check_positive(
+        fluid.solid_conductivity.value,
+        "solid conductivity must be strictly positive and finite",
+    )?;
+    Ok(())
+}
+
+fn check_positive(value: f64, message: &'static str) -> Result<(), PipeFreezeError> {
+    if StrictlyPositive::check(&value).is_err() || !value.is_finite() {
+        return Err(PipeFreezeError::InvalidParameter(message));
+    }
+    Ok(())
+}
+
+fn check_temperature(
+    temperature: ThermodynamicTemperature,
+    message: &'static str,
+) -> Result<f64, PipeFreezeError> {
+    if !temperature.is_finite() {
+        return Err(PipeFreezeError::InvalidInput(message));
+    }
+    Ok(temperature.get::<kelvin>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        length::millimeter,
+        time::{hour, minute},
+    };
+
+    use crate::support::units::test_utils::celsius;
+
+    fn copper_pipe(shells: usize) -> PipeFreezeParameters {
+        PipeFreezeParameters {
+            wall: PipeWall {
+                inner_diameter: Length::new::<millimeter>(20.0),
+                thickness: Length::new::<millimeter>(1.0),
+                density: MassDensity::new::<kilogram_per_cubic_meter>(8930.0),
+                specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(385.0),
+                conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(390.0),
+            },
+            insulation: None,
+            outer_coefficient: HeatTransfer::new::<watt_per_square_meter_kelvin>(10.0),
+            fluid: FreezingFluid::water(),
+            shells,
+        }
+    }
+
+    #[test]
+    fn lumped_fluid_matches_newtonian_cooling_then_latent_plateau() {
+        // With a highly conductive fluid the pipe cools as one mass through
+        // the surface resistance, exponentially to the freezing point and
+        // then at a steady rate while the latent heat is drawn off.
+        let mut parameters = copper_pipe(1);
+        parameters.fluid.liquid_conductivity =
+            ThermalConductivity::new::<watt_per_meter_kelvin>(1e3);
+        parameters.fluid.solid_conductivity =
+            ThermalConductivity::new::<watt_per_meter_kelvin>(1e3);
+        let pipe = PipeFreeze::new(parameters).unwrap();
+
+        let fluid = FreezingFluid::water();
+        let mass = fluid.density.get::<kilogram_per_cubic_meter>() * PI * 0.01 * 0.01;
+        let wall = 8930.0 * 385.0 * PI * (0.011 * 0.011 - 0.01 * 0.01);
+        let resistance = 1.0 / (2.0 * PI * 0.011 * 10.0);
+        let sensible = (mass * 4217.0 + wall) * resistance * (30.0_f64 / 20.0).ln();
+        let latent = mass * 333.6e3 * resistance / 20.0;
+
+        let time = pipe
+            .time_to_freeze(celsius(10.0), celsius(-20.0), Time::new::<hour>(10.0))
+            .unwrap()
+            .unwrap();
+        assert_relative_eq!(time.get::<second>(), sensible + latent, max_relative = 0.01);
+    }
+
+    #[test]
+    fn ice_grows_inward_from_the_wall() {
+        let pipe = PipeFreeze::new(copper_pipe(8)).unwrap();
+        let mut state = pipe.uniform_state(celsius(5.0)).unwrap();
+
+        let mut step = pipe
+            .step(&mut state, celsius(-20.0), Time::new::<minute>(10.0))
+            .unwrap();
+        while step.ice_fraction.get::<ratio>() < 0.3 {
+            step = pipe
+                .step(&mut state, celsius(-20.0), Time::new::<minute>(10.0))
+                .unwrap();
+        }
+
+        // Ice lines the wall, colder than freezing, around a liquid core.
+        assert!(step.ice_fraction.get::<ratio>() < 0.9);
+        assert!(step.wall_temperature < celsius(0.0));
+        assert!(step.center_temperature >= celsius(0.0));
+        assert!(step.heat_loss.get::<watt_per_meter>() > 0.0);
+    }
+
+    #[test]
+    fn insulation_delays_freezing() {
+        let bare = PipeFreeze::new(copper_pipe(4)).unwrap();
+        let mut parameters = copper_pipe(4);
+        parameters.insulation = Some(PipeInsulation {
+            thickness: Length::new::<millimeter>(13.0),
+            conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(0.035),
+        });
+        let insulated = PipeFreeze::new(parameters).unwrap();
+
+        let limit = Time::new::<hour>(48.0);
+        let bare = bare
+            .time_to_freeze(celsius(10.0), celsius(-20.0), limit)
+            .unwrap()
+            .unwrap();
+        let insulated = insulated
+            .time_to_freeze(celsius(10.0), celsius(-20.0), limit)
+            .unwrap()
+            .unwrap();
+        assert!(insulated > 2.0 * bare);
+
+        // Above freezing the pipe never freezes.
+        let pipe = PipeFreeze::new(copper_pipe(4)).unwrap();
+        assert_eq!(
+            pipe.time_to_freeze(celsius(10.0), celsius(1.0), limit)
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn freezes_solid_then_thaws() {
+        let pipe = PipeFreeze::new(copper_pipe(4)).unwrap();
+        let mut ambient_temperatures = vec![celsius(-20.0); 24];
+        ambient_temperatures.extend([celsius(20.0); 24]);
+
+        let output = pipe
+            .simulate(&PipeFreezeInput {
+                initial_temperature: celsius(10.0),
+                ambient_temperatures,
+                time_step: Time::new::<hour>(1.0),
+            })
+            .unwrap();
+
+        let ice_onset = output.ice_onset.unwrap();
+        let frozen_solid = output.frozen_solid.unwrap();
+        let thawed = output.thawed.unwrap();
+        assert!(ice_onset < frozen_solid);
+        assert!(frozen_solid < Time::new::<hour>(24.0));
+        assert!(thawed > Time::new::<hour>(24.0));
+        assert_relative_eq!(output.steps[23].ice_fraction.get::<ratio>(), 1.0);
+        assert!(output.steps[23].center_temperature < celsius(-10.0));
+        assert_relative_eq!(output.steps[47].ice_fraction.get::<ratio>(), 0.0);
+        assert!(output.steps[47].heat_loss.get::<watt_per_meter>() < 0.0);
+    }
+
+    #[test]
+    fn supplying_the_heat_loss_holds_the_wall_steady() {
+        let pipe = PipeFreeze::new(copper_pipe(4)).unwrap();
+        let heat = pipe.heat_loss(celsius(5.0), celsius(-20.0));
+
+        let mut state = pipe.uniform_state(celsius(5.0)).unwrap();
+        let step = pipe
+            .step_heated(&mut state, celsius(-20.0), heat, Time::new::<hour>(6.0))
+            .unwrap();
+        assert_relative_eq!(
+            step.wall_temperature.get::<degree_celsius>(),
+            5.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            step.heat_loss.get::<watt_per_meter>(),
+            heat.get::<watt_per_meter>()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_states() {
+        assert!(matches!(
+            PipeFreeze::new(copper_pipe(0)),
+            Err(PipeFreezeError::InvalidParameter(_))
+        ));
+        let mut parameters = copper_pipe(4);
+        parameters.fluid.latent_heat = AvailableEnergy::new::<joule_per_kilogram>(0.0);
+        assert!(matches!(
+            PipeFreeze::new(parameters),
+            Err(PipeFreezeError::InvalidParameter(_))
+        ));
+
+        let pipe = PipeFreeze::new(copper_pipe(4)).unwrap();
+        let mut state = PipeFreeze::new(copper_pipe(2))
+            .unwrap()
+            .uniform_state(celsius(5.0))
+            .unwrap();
+        assert!(matches!(
+            pipe.step(&mut state, celsius(-5.0), Time::new::<hour>(1.0)),
+            Err(PipeFreezeError::InvalidInput(_))
+        ));
+    }
+}
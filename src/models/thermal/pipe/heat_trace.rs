@@ -0,0 +1,106 @@
+//! Electric heat tracing for freeze protection.
+//!
+//! A [`HeatTrace`] circuit runs a heating cable along a pipe, switched by a
+//! setpoint thermostat sensing the pipe wall or the ambient air, and
+//! replaces the pipe's losses so the water in it stays liquid. The pipe
+//! itself, and the ice that forms if the tracing falls behind, come from
+//! the [`PipeFreeze`](super::freeze::PipeFreeze) model, and the thermostat
+//! is the [`heating`](crate::support::control::thermostat::setpoint::heating)
+//! setpoint controller.
+//!
+//! The cable is either constant wattage or self-regulating, whose output
+//! falls as the pipe warms. [`HeatTrace::required_output`] gives the
+//! output needed to hold a maintain temperature at the design low ambient,
+//! and [`HeatTrace::simulate`] runs an ambient profile and reports the
+//! energy used and whether the pipe ever froze.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::pipe::{
+//!         freeze::{FreezingFluid, PipeFreezeParameters, PipeInsulation, PipeWall},
+//!         heat_trace::{HeatTrace, HeatTraceInput, HeatTraceParameters, TraceCable, TraceSensing},
+//!     },
+//!     support::control::thermostat::setpoint::Deadband,
+//! };
+//! use uom::si::{
+//!     energy::kilowatt_hour,
+//!     f64::{
+//!         HeatTransfer, Length, LinearPowerDensity, MassDensity, SpecificHeatCapacity,
+//!         TemperatureInterval, ThermalConductivity, ThermodynamicTemperature, Time,
+//!     },
+//!     heat_transfer::watt_per_square_meter_kelvin,
+//!     length::{meter, millimeter},
+//!     linear_power_density::watt_per_meter,
+//!     mass_density::kilogram_per_cubic_meter,
+//!     specific_heat_capacity::joule_per_kilogram_kelvin,
+//!     temperature_interval::kelvin,
+//!     thermal_conductivity::watt_per_meter_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//!     time::minute,
+//! };
+//!
+//! let celsius = ThermodynamicTemperature::new::<degree_celsius>;
+//! let trace = HeatTrace::new(HeatTraceParameters {
+//!     pipe: PipeFreezeParameters {
+//!         wall: PipeWall {
+//!             inner_diameter: Length::new::<millimeter>(26.6),
+//!             thickness: Length::new::<millimeter>(3.9),
+//!             density: MassDensity::new::<kilogram_per_cubic_meter>(7850.0),
+//!             specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(490.0),
+//!             conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(45.0),
+//!         },
+//!         insulation: Some(PipeInsulation {
+//!             thickness: Length::new::<millimeter>(25.0),
+//!             conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(0.04),
+//!         }),
+//!         outer_coefficient: HeatTransfer::new::<watt_per_square_meter_kelvin>(20.0),
+//!         fluid: FreezingFluid::water(),
+//!         shells: 4,
+//!     },
+//!     length: Length::new::<meter>(30.0),
+//!     cable: TraceCable::SelfRegulating {
+//!         rated_output: LinearPowerDensity::new::<watt_per_meter>(10.0),
+//!         rated_temperature: celsius(10.0),
+//!         cutoff_temperature: celsius(65.0),
+//!     },
+//!     sensing: TraceSensing::Pipe,
+//!     setpoint: celsius(5.0),
+//!     deadband: Deadband::new(TemperatureInterval::new::<kelvin>(2.0))?,
+//! })?;
+//!
+//! // The cable covers the loss holding 5 °C at a −25 °C design ambient.
+//! let required = trace.required_output(celsius(5.0), celsius(-25.0));
+//! assert!(trace.parameters().cable.output(celsius(5.0)) > required);
+//!
+//! // A cold night in ten-minute steps keeps the pipe clear of ice.
+//! let output = trace.simulate(&HeatTraceInput {
+//!     initial_temperature: celsius(8.0),
+//!     ambient_temperatures: vec![celsius(-20.0); 72],
+//!     time_step: Time::new::<minute>(10.0),
+//! })?;
+//! assert!(output.first_ice.is_none());
+//! assert!(output.min_wall_temperature > celsius(0.0));
+//! assert!(output.energy.get::<kilowatt_hour>() > 1.0);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    HeatTrace, HeatTraceError, HeatTraceInput, HeatTraceOutput, HeatTraceParameters, HeatTraceStep,
+    TraceCable, TraceSensing,
+};
+
+impl Model for HeatTrace {
+    type Input = HeatTraceInput;
+    type Output = HeatTraceOutput;
+    type Error = HeatTraceError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.simulate(input)
+    }
+}
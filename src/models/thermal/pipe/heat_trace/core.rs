@@ -0,0 +1,510 @@
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::f64::{Energy, Length, LinearPowerDensity, ThermodynamicTemperature, Time},
+};
+
+use crate::{
+    models::thermal::pipe::freeze::{
+        PipeFreeze, PipeFreezeError, PipeFreezeParameters, PipeFreezeStep,
+    },
+    support::{
+        constraint::{Constraint, StrictlyPositive},
+        control::{
+            SwitchState,
+            thermostat::setpoint::{Deadband, SetpointThermostatInput, heating},
+        },
+        units::TemperatureDifference,
+    },
+};
+
+/// How a heating cable's output responds to the pipe temperature.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TraceCable {
+    /// Series resistance cable with the same output at any temperature.
+    ConstantWattage {
+        /// Output per length of cable.
+        output: LinearPowerDensity,
+    },
+
+    /// Self-regulating cable whose conductive core passes less current as
+    /// it warms.
+    ///
+    /// Output falls linearly with pipe temperature through the rating point
+    /// and reaches zero at the cutoff temperature.
+    SelfRegulating {
+        /// Output per length of cable at the rating temperature.
+        rated_output: LinearPowerDensity,
+
+        /// Pipe temperature at which the output is rated, commonly 10 °C.
+        rated_temperature: ThermodynamicTemperature,
+
+        /// Pipe temperature at which the output falls to zero.
+        cutoff_temperature: ThermodynamicTemperature,
+    },
+}
+
+impl TraceCable {
+    /// Returns the cable's output per length against a pipe at
+    /// `pipe_temperature`.
+    #[must_use]
+    pub fn output(&self, pipe_temperature: ThermodynamicTemperature) -> LinearPowerDensity {
+        match *self {
+            Self::ConstantWattage { output } => output,
+            Self::SelfRegulating {
+                rated_output,
+                rated_temperature,
+                cutoff_temperature,
+            } => {
+                let span = cutoff_temperature.minus(rated_temperature);
+                let margin = cutoff_temperature.minus(pipe_temperature);
+                (rated_output * (margin / span)).max(LinearPowerDensity::ZERO)
+            }
+        }
+    }
+}
+
+/// Where the heat trace thermostat senses temperature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceSensing {
+    /// A bulb strapped to the pipe wall.
+    Pipe,
+
+    /// An air-sensing thermostat that energizes every traced circuit when
+    /// the ambient is cold, whatever the pipe temperature.
+    Ambient,
+}
+
+/// Fixed parameters of a [`HeatTrace`] circuit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeatTraceParameters {
+    /// The traced pipe and the fluid in it.
+    pub pipe: PipeFreezeParameters,
+
+    /// Length of traced pipe, run once along it.
+    pub length: Length,
+
+    /// Heating cable.
+    pub cable: TraceCable,
+
+    /// Temperature sensed by the thermostat.
+    pub sensing: TraceSensing,
+
+    /// Temperature the thermostat holds the sensed temperature above.
+    pub setpoint: ThermodynamicTemperature,
+
+    /// Thermostat deadband below the setpoint.
+    pub deadband: Deadband,
+}
+
+/// Errors from [`HeatTrace`] construction and evaluation.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum HeatTraceError {
+    /// A cable or thermostat parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating input is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// The traced pipe could not be built or stepped.
+    #[error(transparent)]
+    Pipe(#[from] PipeFreezeError),
+}
+
+/// Electric heat tracing holding a pipe above freezing.
+///
+/// The cable delivers its output to the pipe wall beneath any insulation,
+/// against the losses of the underlying [`PipeFreeze`] model, which also
+/// tracks the fluid if the tracing falls behind and ice forms. A setpoint
+/// thermostat with a deadband switches the cable, sensing either the pipe
+/// wall or the ambient air, and is evaluated at the start of each time
+/// step. A self-regulating cable's output follows the wall temperature at
+/// the same instant.
+///
+/// Energy use is the cable output summed over the traced length while the
+/// thermostat is on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeatTrace {
+    parameters: HeatTraceParameters,
+    pipe: PipeFreeze,
+}
+
+/// Input to the heat trace model: an ambient profile in fixed time steps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeatTraceInput {
+    /// Uniform temperature of the pipe and fluid at the start.
+    pub initial_temperature: ThermodynamicTemperature,
+
+    /// Ambient temperature in each time step.
+    pub ambient_temperatures: Vec<ThermodynamicTemperature>,
+
+    /// Length of each time step.
+    pub time_step: Time,
+}
+
+/// Conditions over one time step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeatTraceStep {
+    /// Thermostat state during the step.
+    pub switch: SwitchState,
+
+    /// Cable output per length during the step.
+    pub output: LinearPowerDensity,
+
+    /// Pipe conditions at the end of the step.
+    pub pipe: PipeFreezeStep,
+}
+
+/// Output from the heat trace model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeatTraceOutput {
+    /// Conditions over each time step.
+    pub steps: Vec<HeatTraceStep>,
+
+    /// Electric energy used by the whole circuit.
+    pub energy: Energy,
+
+    /// Total time the thermostat was on.
+    pub on_time: Time,
+
+    /// Lowest pipe wall temperature at the end of any step.
+    pub min_wall_temperature: ThermodynamicTemperature,
+
+    /// End of the first step with ice in the pipe, if the tracing fell
+    /// behind.
+    pub first_ice: Option<Time>,
+}
+
+impl HeatTrace {
+    /// Creates a heat trace circuit on a pipe.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeatTraceError::InvalidParameter`] if the length, cable
+    /// output, or setpoint is out of range or a self-regulating cable cuts
+    /// off at or below its rating temperature, and [`HeatTraceError::Pipe`]
+    /// if the pipe parameters are invalid.
+    pub fn new(parameters: HeatTraceParameters) -> Result<Self, HeatTraceError> {
+        if StrictlyPositive::check(&parameters.length).is_err() || !parameters.length.is_finite() {
+            return Err(HeatTraceError::InvalidParameter(
+                "traced length must be strictly positive and finite",
+            ));
+        }
+        match parameters.cable {
+            TraceCable::ConstantWattage { output } => {
+                if StrictlyPositive::check(&output).is_err() || !output.is_finite() {
+                    return Err(HeatTraceError::InvalidParameter(
+                        "cable output must be strictly positive and finite",
+                    ));
+                }
+            }
+            TraceCable::SelfRegulating {
+                rated_output,
+                rated_temperature,
+                cutoff_temperature,
+            } => {
+                if StrictlyPositive::check(&rated_output).is_err() || !rated_output.is_finite() {
+                    return Err(HeatTraceError::InvalidParameter(
+                        "rated cable output must be strictly positive and finite",
+                    ));
+                }
+                if !rated_temperature.is_finite() || !cutoff_temperature.is_finite() {
+                    return Err(HeatTraceError::InvalidParameter(
+                        "cable rating and cutoff temperatures must be finite",
+                    ));
+                }
+                if cutoff_temperature <= rated_temperature {
+                    return Err(HeatTraceError::InvalidParameter(
+                        "cable cutoff temperature must be above its rating temperature",
+                    ));
+                }
+            }
+        }
+        if !parameters.setpoint.is_finite() {
+            return Err(HeatTraceError::InvalidParameter(
+                "thermostat setpoint must be finite",
+            ));
+        }
+
+        let pipe = PipeFreeze::new(parameters.pipe)?;
+        Ok(Self { parameters, pipe })
+    }
+
+    /// Returns the parameters this circuit was built from.
+    #[must_use]
+    pub fn parameters(&self) -> &HeatTraceParameters {
+        &self.parameters
+    }
+
+    /// Returns the traced pipe model.
+    #[must_use]
+    pub fn pipe(&self) -> &PipeFreeze {
+        &self.pipe
+    }
+
+    /// Returns the output per length needed to hold the pipe wall at
+    /// `maintain_temperature` against a steady `ambient_temperature`.
+    ///
+    /// Compare it with the cable's [`output`](TraceCable::output) at the
+    /// same wall temperature when selecting a cable for a design low
+    /// ambient.
+    #[must_use]
+    pub fn required_output(
+        &self,
+        maintain_temperature: ThermodynamicTemperature,
+        ambient_temperature: ThermodynamicTemperature,
+    ) -> LinearPowerDensity {
+        self.pipe
+            .heat_loss(maintain_temperature, ambient_temperature)
+            .max(LinearPowerDensity::ZERO)
+    }
+
+    /// Runs an ambient profile from a uniform initial temperature, starting
+    /// with the thermostat off.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeatTraceError::InvalidInput`] if the time step is not
+    /// strictly positive and finite, and [`HeatTraceError::Pipe`] if a
+    /// temperature is not finite.
+    pub fn simulate(&self, input: &HeatTraceInput) -> Result<HeatTraceOutput, HeatTraceError> {
+        if StrictlyPositive::check(&input.time_step).is_err() || !input.time_step.is_finite() {
+            return Err(HeatTraceError::InvalidInput(
+                "time step must be strictly positive and finite",
+            ));
+        }
+        let HeatTraceParameters {
+            length,
+            cable,
+            sensing,
+            setpoint,
+            deadband,
+            ..
+        } = self.parameters;
+
+        let mut state = self.pipe.uniform_state(input.initial_temperature)?;
+        let mut wall_temperature = input.initial_temperature;
+        let mut switch = SwitchState::Off;
+
+        let mut energy = Energy::ZERO;
+        let mut on_time = Time::ZERO;
+        let mut min_wall_temperature = input.initial_temperature;
+        let mut first_ice = None;
+        let mut steps = Vec::with_capacity(input.ambient_temperatures.len());
+        for (i, &ambient_temperature) in input.ambient_temperatures.iter().enumerate() {
+            let temperature = match sensing {
+                TraceSensing::Pipe => wall_temperature,
+                TraceSensing::Ambient => ambient_temperature,
+            };
+            switch = heating(SetpointThermostatInput {
+                state: switch,
+                temperature,
+                setpoint,
+                deadband,
+            });
+            let output = match switch {
+                SwitchState::On => cable.output(wall_temperature),
+                SwitchState::Off => LinearPowerDensity::ZERO,
+            };
+
+            let pipe =
+                self.pipe
+                    .step_heated(&mut state, ambient_temperature, output, input.time_step)?;
+            wall_temperature = pipe.wall_temperature;
+
+            if switch == SwitchState::On {
+                on_time += input.time_step;
+                energy += output * length * input.time_step;
+            }
+            min_wall_temperature = min_wall_temperature.min(wall_temperature);
+            if first_ice.is_none() && pipe.ice_fraction.value > 0.0 {
+                // Step counts are far below 2^52, so the cast is exact.
+                #[allow(clippy::cast_precision_loss)]
+                let elapsed = input.time_step * (i + 1) as f64;
+                first_ice = Some(elapsed);
+            }
+            steps.push(HeatTraceStep {
+                switch,
+                output,
+                pipe,
+            });
+        }
+
+        Ok(HeatTraceOutput {
+            steps,
+            energy,
+            on_time,
+            min_wall_temperature,
+            first_ice,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        energy::joule,
+        f64::{
+            HeatTransfer, MassDensity, SpecificHeatCapacity, TemperatureInterval,
+            ThermalConductivity,
+        },
+        heat_transfer::watt_per_square_meter_kelvin,
+        length::{meter, millimeter},
+        linear_power_density::watt_per_meter,
+        mass_density::kilogram_per_cubic_meter,
+        specific_heat_capacity::joule_per_kilogram_kelvin,
+        temperature_interval::kelvin,
+        thermal_conductivity::watt_per_meter_kelvin,
+        time::{hour, minute, second},
+    };
+
+    use crate::{
+        models::thermal::pipe::freeze::{FreezingFluid, PipeInsulation, PipeWall},
+        support::units::test_utils::celsius,
+    };
+
+    fn watts(value: f64) -> LinearPowerDensity {
+        LinearPowerDensity::new::<watt_per_meter>(value)
+    }
+
+    fn parameters(cable: TraceCable, sensing: TraceSensing) -> HeatTraceParameters {
+        HeatTraceParameters {
+            pipe: PipeFreezeParameters {
+                wall: PipeWall {
+                    inner_diameter: Length::new::<millimeter>(20.0),
+                    thickness: Length::new::<millimeter>(1.0),
+                    density: MassDensity::new::<kilogram_per_cubic_meter>(8930.0),
+                    specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(385.0),
+                    conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(390.0),
+                },
+                insulation: Some(PipeInsulation {
+                    thickness: Length::new::<millimeter>(13.0),
+                    conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(0.035),
+                }),
+                outer_coefficient: HeatTransfer::new::<watt_per_square_meter_kelvin>(15.0),
+                fluid: FreezingFluid::water(),
+                shells: 3,
+            },
+            length: Length::new::<meter>(10.0),
+            cable,
+            sensing,
+            setpoint: celsius(5.0),
+            deadband: Deadband::new(TemperatureInterval::new::<kelvin>(2.0)).unwrap(),
+        }
+    }
+
+    fn cold_night(trace: &HeatTrace) -> HeatTraceOutput {
+        trace
+            .simulate(&HeatTraceInput {
+                initial_temperature: celsius(10.0),
+                ambient_temperatures: vec![celsius(-20.0); 144],
+                time_step: Time::new::<minute>(5.0),
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn self_regulating_output_falls_with_temperature() {
+        let cable = TraceCable::SelfRegulating {
+            rated_output: watts(10.0),
+            rated_temperature: celsius(10.0),
+            cutoff_temperature: celsius(60.0),
+        };
+        assert_relative_eq!(cable.output(celsius(10.0)).get::<watt_per_meter>(), 10.0);
+        assert_relative_eq!(cable.output(celsius(35.0)).get::<watt_per_meter>(), 5.0);
+        assert_relative_eq!(cable.output(celsius(-15.0)).get::<watt_per_meter>(), 15.0);
+        assert_eq!(cable.output(celsius(80.0)), LinearPowerDensity::ZERO);
+    }
+
+    #[test]
+    fn thermostat_holds_pipe_near_setpoint() {
+        let trace = HeatTrace::new(parameters(
+            TraceCable::ConstantWattage {
+                output: watts(15.0),
+            },
+            TraceSensing::Pipe,
+        ))
+        .unwrap();
+        assert!(trace.required_output(celsius(5.0), celsius(-20.0)) < watts(15.0));
+
+        let output = cold_night(&trace);
+        assert!(output.first_ice.is_none());
+        // The wall overshoots the 3 °C switch-on point by up to a step.
+        assert!(output.min_wall_temperature > celsius(1.0));
+        assert!(
+            output
+                .steps
+                .iter()
+                .any(|step| step.switch == SwitchState::Off)
+        );
+
+        // A constant-wattage cable uses its rating for every hour it is on.
+        assert_relative_eq!(
+            output.energy.get::<joule>(),
+            15.0 * 10.0 * output.on_time.get::<second>(),
+            max_relative = 1e-12
+        );
+        assert!(output.on_time < Time::new::<hour>(12.0));
+    }
+
+    #[test]
+    fn undersized_cable_lets_pipe_freeze() {
+        let trace = HeatTrace::new(parameters(
+            TraceCable::ConstantWattage { output: watts(2.0) },
+            TraceSensing::Pipe,
+        ))
+        .unwrap();
+        assert!(trace.required_output(celsius(5.0), celsius(-20.0)) > watts(2.0));
+
+        let output = cold_night(&trace);
+        assert!(output.first_ice.is_some());
+        assert!(output.min_wall_temperature < celsius(0.0));
+    }
+
+    #[test]
+    fn ambient_sensing_stays_off_in_mild_weather() {
+        let trace = HeatTrace::new(parameters(
+            TraceCable::ConstantWattage {
+                output: watts(15.0),
+            },
+            TraceSensing::Ambient,
+        ))
+        .unwrap();
+        let output = trace
+            .simulate(&HeatTraceInput {
+                initial_temperature: celsius(10.0),
+                ambient_temperatures: vec![celsius(8.0); 12],
+                time_step: Time::new::<minute>(5.0),
+            })
+            .unwrap();
+        assert_eq!(output.energy, Energy::ZERO);
+        assert!(
+            output
+                .steps
+                .iter()
+                .all(|step| step.switch == SwitchState::Off)
+        );
+
+        // In the cold it runs continuously, however warm the pipe.
+        let output = cold_night(&trace);
+        assert_eq!(output.on_time, Time::new::<hour>(12.0));
+    }
+
+    #[test]
+    fn rejects_cutoff_below_rating() {
+        let cable = TraceCable::SelfRegulating {
+            rated_output: watts(10.0),
+            rated_temperature: celsius(10.0),
+            cutoff_temperature: celsius(10.0),
+        };
+        assert!(matches!(
+            HeatTrace::new(parameters(cable, TraceSensing::Pipe)),
+            Err(HeatTraceError::InvalidParameter(_))
+        ));
+    }
+}
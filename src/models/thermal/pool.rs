@@ -0,0 +1,3 @@
+//! Pools and other large open thermal masses.
+
+pub mod swimming;
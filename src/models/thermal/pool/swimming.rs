@@ -0,0 +1,201 @@
+//! Swimming pool thermal model.
+//!
+//! A [`SwimmingPool`] treats the pool as a single well-mixed water mass that
+//! loses heat by evaporation, convection, radiation, and ground conduction,
+//! and gains heat from the sun and a heater. The pool temperature is the
+//! integrable state.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::models::thermal::pool::swimming::{
+//!     SwimmingPool, SwimmingPoolInput, SwimmingPoolParameters, Weather,
+//! };
+//! use uom::si::{
+//!     area::square_meter,
+//!     f64::{Area, HeatFluxDensity, MassDensity, Power, Ratio, SpecificHeatCapacity,
+//!           ThermalConductance, ThermodynamicTemperature, Velocity, Volume},
+//!     heat_flux_density::watt_per_square_meter,
+//!     mass_density::kilogram_per_cubic_meter,
+//!     power::kilowatt,
+//!     ratio::ratio,
+//!     specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+//!     thermal_conductance::watt_per_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//!     velocity::meter_per_second,
+//!     volume::cubic_meter,
+//! };
+//!
+//! let pool = SwimmingPool::new(SwimmingPoolParameters {
+//!     surface_area: Area::new::<square_meter>(40.0),
+//!     volume: Volume::new::<cubic_meter>(60.0),
+//!     density: MassDensity::new::<kilogram_per_cubic_meter>(997.0),
+//!     specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(4.18),
+//!     emissivity: Ratio::new::<ratio>(0.95),
+//!     solar_absorptance: Ratio::new::<ratio>(0.8),
+//!     ground_ua: ThermalConductance::new::<watt_per_kelvin>(50.0),
+//! }).expect("valid parameters");
+//!
+//! let output = pool.evaluate(&SwimmingPoolInput {
+//!     temperature: ThermodynamicTemperature::new::<degree_celsius>(27.0),
+//!     weather: Weather {
+//!         air_temperature: ThermodynamicTemperature::new::<degree_celsius>(22.0),
+//!         relative_humidity: Ratio::new::<ratio>(0.6),
+//!         wind_speed: Velocity::new::<meter_per_second>(2.0),
+//!         sky_temperature: ThermodynamicTemperature::new::<degree_celsius>(10.0),
+//!         solar_irradiance: HeatFluxDensity::new::<watt_per_square_meter>(0.0),
+//!     },
+//!     ground_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.0),
+//!     heater_power: Power::new::<kilowatt>(0.0),
+//! });
+//!
+//! // At night with no heater the pool cools, mostly by evaporation.
+//! assert!(output.derivative.value < 0.0);
+//! assert!(output.evaporation_loss > output.convection_loss);
+//! ```
+
+mod core;
+
+use std::convert::Infallible;
+
+use twine_core::{DerivativeOf, Model, OdeProblem, StepIntegrable};
+use uom::si::f64::{ThermodynamicTemperature, Time};
+
+use crate::support::units::TemperatureRate;
+
+pub use core::{
+    SwimmingPool, SwimmingPoolError, SwimmingPoolInput, SwimmingPoolOutput, SwimmingPoolParameters,
+    Weather,
+};
+
+impl Model for SwimmingPool {
+    type Input = SwimmingPoolInput;
+    type Output = SwimmingPoolOutput;
+    type Error = Infallible;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        Ok(self.evaluate(input))
+    }
+}
+
+/// Pool water temperature, used as the ODE state for time integration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PoolState {
+    /// Well-mixed pool water temperature.
+    pub temperature: ThermodynamicTemperature,
+}
+
+impl StepIntegrable<Time> for PoolState {
+    type Derivative = TemperatureRate;
+
+    fn step(&self, derivative: TemperatureRate, delta: Time) -> Self {
+        PoolState {
+            temperature: self.temperature + derivative * delta,
+        }
+    }
+}
+
+/// Adapts a [`SwimmingPool`] for use with `twine_solvers::transient::euler::solve`.
+///
+/// The state is the pool temperature. Weather, ground, and heater inputs are
+/// carried forward from the base input on each step.
+pub struct PoolOdeProblem;
+
+impl OdeProblem for PoolOdeProblem {
+    type Input = SwimmingPoolInput;
+    type Output = SwimmingPoolOutput;
+    type Delta = Time;
+    type State = PoolState;
+    type Error = Infallible;
+
+    fn state(&self, input: &Self::Input) -> Result<PoolState, Infallible> {
+        Ok(PoolState {
+            temperature: input.temperature,
+        })
+    }
+
+    fn derivative(
+        &self,
+        _input: &Self::Input,
+        output: &Self::Output,
+    ) -> Result<DerivativeOf<PoolState, Time>, Infallible> {
+        Ok(output.derivative)
+    }
+
+    fn build_input(
+        &self,
+        base: &Self::Input,
+        state: &Self::State,
+        _delta: &Time,
+    ) -> Result<Self::Input, Infallible> {
+        Ok(SwimmingPoolInput {
+            temperature: state.temperature,
+            ..*base
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use twine_solvers::transient::euler;
+    use uom::{
+        ConstZero,
+        si::{
+            area::square_meter,
+            f64::{
+                Area, HeatFluxDensity, MassDensity, Power, Ratio, SpecificHeatCapacity,
+                ThermalConductance, Velocity, Volume,
+            },
+            mass_density::kilogram_per_cubic_meter,
+            ratio::ratio,
+            specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+            thermodynamic_temperature::degree_celsius,
+            time::hour,
+            velocity::meter_per_second,
+            volume::cubic_meter,
+        },
+    };
+
+    #[test]
+    fn unheated_pool_cools_toward_ambient_overnight() {
+        let pool = SwimmingPool::new(SwimmingPoolParameters {
+            surface_area: Area::new::<square_meter>(40.0),
+            volume: Volume::new::<cubic_meter>(60.0),
+            density: MassDensity::new::<kilogram_per_cubic_meter>(997.0),
+            specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(4.18),
+            emissivity: Ratio::new::<ratio>(0.95),
+            solar_absorptance: Ratio::new::<ratio>(0.8),
+            ground_ua: ThermalConductance::ZERO,
+        })
+        .unwrap();
+
+        let air = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+        let initial = SwimmingPoolInput {
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(28.0),
+            weather: Weather {
+                air_temperature: air,
+                relative_humidity: Ratio::new::<ratio>(0.5),
+                wind_speed: Velocity::new::<meter_per_second>(1.0),
+                sky_temperature: air,
+                solar_irradiance: HeatFluxDensity::ZERO,
+            },
+            ground_temperature: air,
+            heater_power: Power::ZERO,
+        };
+
+        let solution =
+            euler::solve_unobserved(&pool, &PoolOdeProblem, initial, Time::new::<hour>(0.1), 120)
+                .unwrap();
+
+        let temperatures: Vec<_> = solution
+            .history
+            .iter()
+            .map(|snapshot| snapshot.input.temperature)
+            .collect();
+
+        assert!(temperatures.windows(2).all(|pair| pair[1] < pair[0]));
+        assert!(*temperatures.last().unwrap() > air);
+    }
+}
@@ -0,0 +1,407 @@
+mod evaporation;
+
+use thiserror::Error;
+use uom::si::{
+    f64::{
+        Area, HeatCapacity, HeatFluxDensity, HeatTransfer, MassDensity, MassRate, Power, Ratio,
+        SpecificHeatCapacity, ThermalConductance, ThermodynamicTemperature, Velocity, Volume,
+    },
+    heat_flux_density::watt_per_square_meter,
+    heat_transfer::watt_per_square_meter_kelvin,
+    thermodynamic_temperature::kelvin,
+    velocity::meter_per_second,
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive, UnitInterval},
+    radiation::STEFAN_BOLTZMANN,
+    units::{TemperatureDifference, TemperatureRate, volumetric_heat_capacity},
+};
+
+/// Fixed physical parameters of a [`SwimmingPool`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwimmingPoolParameters {
+    /// Water surface area exposed to the air.
+    pub surface_area: Area,
+
+    /// Water volume.
+    pub volume: Volume,
+
+    /// Water density.
+    pub density: MassDensity,
+
+    /// Water specific heat capacity.
+    pub specific_heat: SpecificHeatCapacity,
+
+    /// Long-wave emissivity of the water surface.
+    pub emissivity: Ratio,
+
+    /// Fraction of incident solar irradiance absorbed by the pool.
+    pub solar_absorptance: Ratio,
+
+    /// Thermal conductance between the water and the ground through the
+    /// pool walls and floor.
+    pub ground_ua: ThermalConductance,
+}
+
+/// Errors that can occur when creating a [`SwimmingPool`].
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+pub enum SwimmingPoolError {
+    /// The surface area is not strictly positive.
+    #[error("surface area must be strictly positive, got {0:?}")]
+    NonPositiveArea(Area),
+
+    /// The volume is not strictly positive.
+    #[error("volume must be strictly positive, got {0:?}")]
+    NonPositiveVolume(Volume),
+
+    /// The density or specific heat is not strictly positive.
+    #[error("water properties must be strictly positive")]
+    NonPositiveProperties,
+
+    /// The emissivity is outside `[0, 1]`.
+    #[error("emissivity must be in [0, 1], got {0:?}")]
+    InvalidEmissivity(Ratio),
+
+    /// The solar absorptance is outside `[0, 1]`.
+    #[error("solar absorptance must be in [0, 1], got {0:?}")]
+    InvalidAbsorptance(Ratio),
+
+    /// The ground conductance is negative or NaN.
+    #[error("ground UA must be non-negative, got {0:?}")]
+    NegativeGroundUa(ThermalConductance),
+}
+
+/// A lumped swimming pool thermal model.
+///
+/// The pool is a single well-mixed water mass. Its surface loses heat by
+/// evaporation (driven by the vapor pressure difference between the water
+/// surface and the air), wind-driven convection, and long-wave radiation to
+/// the sky. The walls and floor conduct heat to the ground. Solar irradiance
+/// and an auxiliary heater add heat.
+///
+/// Correlations:
+/// - Evaporation: ASHRAE Handbook (Applications), "Natatoriums",
+///   `ṁ = A·(p_w − p_a)·(0.089 + 0.0782·V) / h_fg` with pressures in kPa and
+///   `h_fg` in kJ/kg.
+/// - Convection: `h = 3.1 + 4.1·V` W/(m²·K) for a horizontal surface in wind.
+/// - Radiation: `q = ε·σ·A·(T_w⁴ − T_sky⁴)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SwimmingPool {
+    surface_area: Area,
+    thermal_mass: HeatCapacity,
+    emissivity: f64,
+    solar_absorptance: f64,
+    ground_ua: ThermalConductance,
+}
+
+/// Weather conditions above the pool.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Weather {
+    /// Dry-bulb air temperature.
+    pub air_temperature: ThermodynamicTemperature,
+
+    /// Relative humidity of the air, from 0 to 1.
+    pub relative_humidity: Ratio,
+
+    /// Wind speed at the water surface.
+    pub wind_speed: Velocity,
+
+    /// Effective sky temperature for long-wave radiation.
+    pub sky_temperature: ThermodynamicTemperature,
+
+    /// Solar irradiance incident on the water surface.
+    pub solar_irradiance: HeatFluxDensity,
+}
+
+/// Input to the swimming pool model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SwimmingPoolInput {
+    /// Current pool water temperature.
+    pub temperature: ThermodynamicTemperature,
+
+    /// Weather conditions above the pool.
+    pub weather: Weather,
+
+    /// Ground temperature around the pool shell.
+    pub ground_temperature: ThermodynamicTemperature,
+
+    /// Heat delivered by the pool heater (or a solar collector loop).
+    pub heater_power: Power,
+}
+
+/// Output from the swimming pool model.
+///
+/// All loss terms are positive when heat leaves the pool.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SwimmingPoolOutput {
+    /// Time derivative of the pool temperature.
+    pub derivative: TemperatureRate,
+
+    /// Rate of water lost to evaporation.
+    pub evaporation_rate: MassRate,
+
+    /// Latent heat lost through evaporation.
+    pub evaporation_loss: Power,
+
+    /// Heat lost by convection to the air.
+    pub convection_loss: Power,
+
+    /// Heat lost by long-wave radiation to the sky.
+    pub radiation_loss: Power,
+
+    /// Heat lost by conduction to the ground.
+    pub conduction_loss: Power,
+
+    /// Absorbed solar gain.
+    pub solar_gain: Power,
+}
+
+impl SwimmingPool {
+    /// Creates a swimming pool from its physical parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SwimmingPoolError`] if any parameter is non-physical.
+    pub fn new(parameters: SwimmingPoolParameters) -> Result<Self, SwimmingPoolError> {
+        let SwimmingPoolParameters {
+            surface_area,
+            volume,
+            density,
+            specific_heat,
+            emissivity,
+            solar_absorptance,
+            ground_ua,
+        } = parameters;
+
+        if StrictlyPositive::check(&surface_area).is_err() {
+            return Err(SwimmingPoolError::NonPositiveArea(surface_area));
+        }
+        if StrictlyPositive::check(&volume).is_err() {
+            return Err(SwimmingPoolError::NonPositiveVolume(volume));
+        }
+        if StrictlyPositive::check(&density).is_err()
+            || StrictlyPositive::check(&specific_heat).is_err()
+        {
+            return Err(SwimmingPoolError::NonPositiveProperties);
+        }
+        if UnitInterval::check(&emissivity).is_err() {
+            return Err(SwimmingPoolError::InvalidEmissivity(emissivity));
+        }
+        if UnitInterval::check(&solar_absorptance).is_err() {
+            return Err(SwimmingPoolError::InvalidAbsorptance(solar_absorptance));
+        }
+        if NonNegative::check(&ground_ua).is_err() {
+            return Err(SwimmingPoolError::NegativeGroundUa(ground_ua));
+        }
+
+        Ok(Self {
+            surface_area,
+            thermal_mass: volume * volumetric_heat_capacity(density, specific_heat),
+            emissivity: emissivity.value,
+            solar_absorptance: solar_absorptance.value,
+            ground_ua,
+        })
+    }
+
+    /// Evaluates the pool's thermal response at a single point in time.
+    #[must_use]
+    pub fn evaluate(&self, input: &SwimmingPoolInput) -> SwimmingPoolOutput {
+        let SwimmingPoolInput {
+            temperature,
+            weather,
+            ground_temperature,
+            heater_power,
+        } = *input;
+
+        let wind = weather.wind_speed.get::<meter_per_second>().max(0.0);
+
+        let (evaporation_rate, evaporation_loss) = evaporation::loss(
+            self.surface_area,
+            temperature,
+            weather.air_temperature,
+            weather.relative_humidity,
+            wind,
+        );
+
+        let h_conv = HeatTransfer::new::<watt_per_square_meter_kelvin>(3.1 + 4.1 * wind);
+        let convection_loss =
+            h_conv * self.surface_area * temperature.minus(weather.air_temperature);
+
+        let t_w = temperature.get::<kelvin>();
+        let t_sky = weather.sky_temperature.get::<kelvin>();
+        let radiation_loss = self.surface_area
+            * HeatFluxDensity::new::<watt_per_square_meter>(
+                self.emissivity * STEFAN_BOLTZMANN * (t_w.powi(4) - t_sky.powi(4)),
+            );
+
+        let conduction_loss = self.ground_ua * temperature.minus(ground_temperature);
+
+        let solar_gain = self.solar_absorptance * weather.solar_irradiance * self.surface_area;
+
+        let net = heater_power + solar_gain
+            - evaporation_loss
+            - convection_loss
+            - radiation_loss
+            - conduction_loss;
+
+        SwimmingPoolOutput {
+            derivative: net / self.thermal_mass,
+            evaporation_rate,
+            evaporation_loss,
+            convection_loss,
+            radiation_loss,
+            conduction_loss,
+            solar_gain,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::{
+        ConstZero,
+        si::{
+            area::square_meter,
+            f64::{TemperatureInterval, Time},
+            mass_density::kilogram_per_cubic_meter,
+            power::watt,
+            ratio::ratio,
+            specific_heat_capacity::joule_per_kilogram_kelvin,
+            temperature_interval::kelvin as delta_kelvin,
+            thermal_conductance::watt_per_kelvin,
+            time::second,
+            volume::cubic_meter,
+        },
+    };
+
+    use crate::support::units::test_utils::celsius;
+
+    fn pool() -> SwimmingPool {
+        SwimmingPool::new(SwimmingPoolParameters {
+            surface_area: Area::new::<square_meter>(50.0),
+            volume: Volume::new::<cubic_meter>(75.0),
+            density: MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+            specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(4184.0),
+            emissivity: Ratio::new::<ratio>(0.95),
+            solar_absorptance: Ratio::new::<ratio>(0.8),
+            ground_ua: ThermalConductance::new::<watt_per_kelvin>(100.0),
+        })
+        .unwrap()
+    }
+
+    fn k_per_s(rate: TemperatureRate) -> f64 {
+        let delta: TemperatureInterval = rate * Time::new::<second>(1.0);
+        delta.get::<delta_kelvin>()
+    }
+
+    fn calm_weather(t: ThermodynamicTemperature) -> Weather {
+        Weather {
+            air_temperature: t,
+            relative_humidity: Ratio::new::<ratio>(1.0),
+            wind_speed: Velocity::ZERO,
+            sky_temperature: t,
+            solar_irradiance: HeatFluxDensity::ZERO,
+        }
+    }
+
+    #[test]
+    fn no_losses_in_equilibrium_with_saturated_air() {
+        let t = celsius(26.0);
+        let out = pool().evaluate(&SwimmingPoolInput {
+            temperature: t,
+            weather: calm_weather(t),
+            ground_temperature: t,
+            heater_power: Power::ZERO,
+        });
+
+        assert_relative_eq!(out.evaporation_rate.value, 0.0);
+        assert_relative_eq!(out.convection_loss.get::<watt>(), 0.0);
+        assert_relative_eq!(out.radiation_loss.get::<watt>(), 0.0);
+        assert_relative_eq!(out.conduction_loss.get::<watt>(), 0.0);
+        assert_relative_eq!(k_per_s(out.derivative), 0.0);
+    }
+
+    #[test]
+    fn heater_and_solar_raise_temperature() {
+        let t = celsius(26.0);
+        let mut weather = calm_weather(t);
+        weather.solar_irradiance = HeatFluxDensity::new::<watt_per_square_meter>(500.0);
+
+        let out = pool().evaluate(&SwimmingPoolInput {
+            temperature: t,
+            weather,
+            ground_temperature: t,
+            heater_power: Power::new::<watt>(10_000.0),
+        });
+
+        // Solar: 0.8 * 500 W/m² * 50 m² = 20 kW.
+        // dT/dt = 30 kW / (75 m³ * 1000 kg/m³ * 4184 J/(kg·K)).
+        assert_relative_eq!(out.solar_gain.get::<watt>(), 20_000.0);
+        assert_relative_eq!(k_per_s(out.derivative), 30_000.0 / 313_800_000.0);
+    }
+
+    #[test]
+    fn dry_windy_air_drives_evaporation() {
+        let t = celsius(26.0);
+        let input = |wind: f64, rh: f64| SwimmingPoolInput {
+            temperature: t,
+            weather: Weather {
+                relative_humidity: Ratio::new::<ratio>(rh),
+                wind_speed: Velocity::new::<meter_per_second>(wind),
+                ..calm_weather(t)
+            },
+            ground_temperature: t,
+            heater_power: Power::ZERO,
+        };
+
+        let humid = pool().evaluate(&input(0.0, 0.8));
+        let dry = pool().evaluate(&input(0.0, 0.4));
+        let windy = pool().evaluate(&input(3.0, 0.4));
+
+        assert!(humid.evaporation_rate.value > 0.0);
+        assert!(dry.evaporation_rate > humid.evaporation_rate);
+        assert!(windy.evaporation_rate > dry.evaporation_rate);
+        assert!(windy.derivative < dry.derivative);
+    }
+
+    #[test]
+    fn radiation_to_cold_sky() {
+        let t = celsius(26.0);
+        let mut weather = calm_weather(t);
+        weather.sky_temperature = celsius(6.0);
+
+        let out = pool().evaluate(&SwimmingPoolInput {
+            temperature: t,
+            weather,
+            ground_temperature: t,
+            heater_power: Power::ZERO,
+        });
+
+        let t_w: f64 = 299.15;
+        let t_sky: f64 = 279.15;
+        let expected = 0.95 * STEFAN_BOLTZMANN * 50.0 * (t_w.powi(4) - t_sky.powi(4));
+        assert_relative_eq!(out.radiation_loss.get::<watt>(), expected, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        let params = SwimmingPoolParameters {
+            surface_area: Area::new::<square_meter>(50.0),
+            volume: Volume::new::<cubic_meter>(75.0),
+            density: MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+            specific_heat: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(4184.0),
+            emissivity: Ratio::new::<ratio>(1.2),
+            solar_absorptance: Ratio::new::<ratio>(0.8),
+            ground_ua: ThermalConductance::ZERO,
+        };
+        assert!(matches!(
+            SwimmingPool::new(params),
+            Err(SwimmingPoolError::InvalidEmissivity(_))
+        ));
+    }
+}
@@ -0,0 +1,70 @@
+//! Evaporative loss from an open water surface.
+
+use uom::si::{
+    f64::{Area, MassRate, Power, Ratio, ThermodynamicTemperature},
+    mass_rate::kilogram_per_second,
+    power::watt,
+    ratio::ratio,
+    thermodynamic_temperature::degree_celsius,
+};
+
+/// Returns the evaporation rate and the latent heat it carries away.
+///
+/// Uses the ASHRAE natatorium correlation with the vapor pressure at the
+/// water surface taken as saturation at the water temperature. The rate is
+/// negative (condensation) when the air dew point exceeds the water
+/// temperature.
+pub(super) fn loss(
+    area: Area,
+    water_temperature: ThermodynamicTemperature,
+    air_temperature: ThermodynamicTemperature,
+    relative_humidity: Ratio,
+    wind_speed_mps: f64,
+) -> (MassRate, Power) {
+    let t_w = water_temperature.get::<degree_celsius>();
+    let t_a = air_temperature.get::<degree_celsius>();
+    let rh = relative_humidity.get::<ratio>().clamp(0.0, 1.0);
+
+    let p_surface_kpa = saturation_pressure_kpa(t_w);
+    let p_air_kpa = rh * saturation_pressure_kpa(t_a);
+
+    let h_fg_kj_per_kg = latent_heat_kj_per_kg(t_w);
+
+    let m_dot = area.value * (p_surface_kpa - p_air_kpa) * (0.089 + 0.0782 * wind_speed_mps)
+        / h_fg_kj_per_kg;
+
+    (
+        MassRate::new::<kilogram_per_second>(m_dot),
+        Power::new::<watt>(m_dot * h_fg_kj_per_kg * 1000.0),
+    )
+}
+
+/// Saturation vapor pressure over liquid water (Magnus form), kPa.
+fn saturation_pressure_kpa(t_celsius: f64) -> f64 {
+    0.610_94 * (17.625 * t_celsius / (t_celsius + 243.04)).exp()
+}
+
+/// Latent heat of vaporization of water, kJ/kg.
+fn latent_heat_kj_per_kg(t_celsius: f64) -> f64 {
+    2501.0 - 2.37 * t_celsius
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn saturation_pressure_matches_steam_tables() {
+        // Steam tables: 3.169 kPa at 25 °C, 12.35 kPa at 50 °C.
+        assert_relative_eq!(saturation_pressure_kpa(25.0), 3.169, max_relative = 5e-3);
+        assert_relative_eq!(saturation_pressure_kpa(50.0), 12.35, max_relative = 2e-3);
+    }
+
+    #[test]
+    fn latent_heat_matches_steam_tables() {
+        // Steam tables: 2442 kJ/kg at 25 °C.
+        assert_relative_eq!(latent_heat_kj_per_kg(25.0), 2442.0, max_relative = 1e-3);
+    }
+}
@@ -0,0 +1,11 @@
+//! Vapor-compression refrigeration systems.
+
+pub mod accumulator;
+pub mod cascade;
+pub mod charge;
+pub mod defrost;
+pub mod desuperheater;
+pub mod frost;
+pub mod oil;
+pub mod receiver;
+pub mod suction_line;
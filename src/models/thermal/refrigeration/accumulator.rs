@@ -0,0 +1,208 @@
+//! Suction accumulator between the evaporator and the compressor.
+//!
+//! A [`SuctionAccumulator`] catches liquid that the evaporator fails to boil
+//! and passes only vapor to the compressor. Its outlet is saturated vapor at
+//! the suction pressure, so a loop modeled with one has a compressor inlet
+//! that stays put while the evaporator outlet swings between wet and
+//! superheated during a solve.
+//!
+//! See [`super::charge`] for the inputs, outputs, and errors it
+//! shares with the [`LiquidReceiver`](super::receiver::LiquidReceiver).
+
+use std::marker::PhantomData;
+
+use twine_core::Model;
+use uom::si::f64::Volume;
+
+use super::charge::{
+    self, ChargeVesselError, ChargeVesselInput, ChargeVesselOutput, ChargeVesselThermoModel, Port,
+};
+
+/// Suction accumulator of a vapor-compression loop.
+///
+/// The vessel holds saturated liquid under saturated vapor at the pressure
+/// of its inlet, and its outlet draws from the vapor space. Heat moved
+/// between the held liquid and the incoming refrigerant closes the energy
+/// balance: liquid arriving with a wet inlet is boiled off, and superheat
+/// arriving with a dry one is given up.
+#[derive(Debug, Clone)]
+pub struct SuctionAccumulator<Fluid, Thermo> {
+    volume: Volume,
+    thermo: Thermo,
+    _fluid: PhantomData<Fluid>,
+}
+
+impl<Fluid, Thermo> SuctionAccumulator<Fluid, Thermo> {
+    /// Creates an accumulator with the given internal volume.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChargeVesselError::InvalidParameter`] if the volume is not
+    /// strictly positive and finite.
+    pub fn new(volume: Volume, thermo: Thermo) -> Result<Self, ChargeVesselError> {
+        charge::check_volume(volume)?;
+        Ok(Self {
+            volume,
+            thermo,
+            _fluid: PhantomData,
+        })
+    }
+
+    /// Returns the internal volume.
+    pub fn volume(&self) -> Volume {
+        self.volume
+    }
+
+    /// Returns a reference to the thermodynamic model.
+    pub fn thermo(&self) -> &Thermo {
+        &self.thermo
+    }
+}
+
+impl<Fluid, Thermo> SuctionAccumulator<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: ChargeVesselThermoModel<Fluid>,
+{
+    /// Evaluates the saturated vapor outlet and the held charge.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChargeVesselError::Flooded`] if the charge would fill the
+    /// vessel with liquid and spill it into the compressor,
+    /// [`ChargeVesselError::Drained`] if the charge is less than vapor alone
+    /// holds, and other [`ChargeVesselError`] variants for invalid inputs
+    /// or property failures.
+    pub fn evaluate(
+        &self,
+        input: &ChargeVesselInput<Fluid>,
+    ) -> Result<ChargeVesselOutput<Fluid>, ChargeVesselError> {
+        charge::evaluate(&self.thermo, self.volume, Port::SaturatedVapor, input)
+    }
+}
+
+impl<Fluid, Thermo> Model for SuctionAccumulator<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: ChargeVesselThermoModel<Fluid>,
+{
+    type Input = ChargeVesselInput<Fluid>;
+    type Output = ChargeVesselOutput<Fluid>;
+    type Error = ChargeVesselError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::{Mass, MassRate, Ratio, ThermodynamicTemperature},
+        mass::kilogram,
+        mass_rate::kilogram_per_second,
+        power::watt,
+        ratio::ratio,
+        thermodynamic_temperature::degree_celsius,
+        volume::liter,
+    };
+
+    use crate::{
+        models::thermal::{
+            hx::discretized::core::test_support::{TestFluid, TestRefrigerant},
+            refrigeration::charge::test_support::{volume, wet},
+        },
+        support::thermo::{
+            State,
+            capability::{HasEnthalpy, HasPressure, StateFrom},
+        },
+    };
+
+    fn input(inlet: State<TestFluid>, kilograms: f64) -> ChargeVesselInput<TestFluid> {
+        ChargeVesselInput {
+            inlet,
+            mass_flow: MassRate::new::<kilogram_per_second>(0.05),
+            charge: Mass::new::<kilogram>(kilograms),
+        }
+    }
+
+    #[test]
+    fn wet_inlet_leaves_as_saturated_vapor() {
+        let thermo = TestRefrigerant::ammonia_like();
+        let accumulator = SuctionAccumulator::new(volume(), &thermo).unwrap();
+        let input = input(wet(&thermo, -5.0, 0.9), 2.0);
+
+        let output = accumulator.evaluate(&input).unwrap();
+
+        assert_relative_eq!(
+            output.outlet.temperature.get::<degree_celsius>(),
+            -5.0,
+            max_relative = 1e-9
+        );
+        assert_eq!(output.outlet, wet(&thermo, -5.0, 1.0));
+
+        // The unboiled tenth of the flow is evaporated in the vessel.
+        let latent = thermo.enthalpy(&wet(&thermo, -5.0, 1.0)).unwrap()
+            - thermo.enthalpy(&wet(&thermo, -5.0, 0.0)).unwrap();
+        assert_relative_eq!(
+            output.heat_transfer_rate.get::<watt>(),
+            (input.mass_flow * latent * 0.1).get::<watt>(),
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn superheated_inlet_gives_up_its_superheat() {
+        let thermo = TestRefrigerant::ammonia_like();
+        let accumulator = SuctionAccumulator::new(volume(), &thermo).unwrap();
+        let suction_pressure = thermo.pressure(&wet(&thermo, -5.0, 1.0)).unwrap();
+        let superheated = thermo
+            .state_from((
+                TestFluid,
+                ThermodynamicTemperature::new::<degree_celsius>(5.0),
+                suction_pressure,
+            ))
+            .unwrap();
+
+        let output = accumulator.evaluate(&input(superheated, 2.0)).unwrap();
+
+        assert_relative_eq!(
+            output.saturation_temperature.get::<degree_celsius>(),
+            -5.0,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            output.heat_transfer_rate.get::<watt>(),
+            -0.05 * 2200.0 * 10.0,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn liquid_level_rises_with_charge_until_flooded() {
+        let thermo = TestRefrigerant::ammonia_like();
+        let accumulator = SuctionAccumulator::new(volume(), &thermo).unwrap();
+        let level = |kilograms| -> Result<Ratio, ChargeVesselError> {
+            accumulator
+                .evaluate(&input(wet(&thermo, -5.0, 0.95), kilograms))
+                .map(|output| output.charge.liquid_fraction)
+        };
+
+        let low = level(1.0).unwrap().get::<ratio>();
+        let high = level(4.0).unwrap().get::<ratio>();
+        assert!(0.0 < low && low < high && high < 1.0, "{low} {high}");
+        assert!(matches!(level(7.0), Err(ChargeVesselError::Flooded { .. })));
+    }
+
+    #[test]
+    fn rejects_invalid_volume() {
+        let thermo = TestRefrigerant::ammonia_like();
+        assert!(matches!(
+            SuctionAccumulator::<TestFluid, _>::new(Volume::new::<liter>(0.0), &thermo),
+            Err(ChargeVesselError::InvalidParameter(_))
+        ));
+    }
+}
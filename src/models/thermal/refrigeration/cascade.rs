@@ -0,0 +1,38 @@
+//! Two-stage cascade refrigeration cycle.
+//!
+//! A [`CascadeCycle`] couples two vapor-compression loops through a cascade
+//! condenser: the low stage condenses while the high stage evaporates. It is
+//! the usual topology for low-temperature refrigeration, where a single
+//! refrigerant would need an impractical pressure ratio, and pairs such as
+//! CO₂ below ammonia let each loop run in its comfortable range.
+//!
+//! Each loop needs a thermo model that can construct saturated states from
+//! a temperature and vapor quality, such as `CoolProp` with the
+//! [`CarbonDioxide`](crate::support::thermo::fluid::CarbonDioxide) and
+//! [`Ammonia`](crate::support::thermo::fluid::Ammonia) fluids.
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    CascadeConfig, CascadeCycle, CascadeError, CascadeInput, CascadeOutput, CascadeParameters,
+    CascadeThermoModel, Stage, StageOutput,
+};
+
+impl<LowFluid, HighFluid, LowThermo, HighThermo> Model
+    for CascadeCycle<LowFluid, HighFluid, LowThermo, HighThermo>
+where
+    LowFluid: Clone,
+    HighFluid: Clone,
+    LowThermo: CascadeThermoModel<LowFluid>,
+    HighThermo: CascadeThermoModel<HighFluid>,
+{
+    type Input = CascadeInput;
+    type Output = CascadeOutput<LowFluid, HighFluid>;
+    type Error = CascadeError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.solve(input)
+    }
+}
@@ -0,0 +1,800 @@
+mod problem;
+mod stage;
+
+use std::error::Error as StdError;
+
+use thiserror::Error;
+use twine_core::Model;
+use twine_solvers::equation::{
+    bisection,
+    bracket::{Bracket, Sign},
+};
+use uom::si::{
+    f64::{
+        MassRate, Power, Pressure, Ratio, TemperatureInterval, ThermalConductance,
+        ThermodynamicTemperature,
+    },
+    ratio::ratio,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::{
+    models::thermal::{
+        hx::discretized::core::{DiscretizedHxThermoModel, Results},
+        refrigeration::{
+            oil::{OilCirculation, degrade_conductance},
+            suction_line::SuctionLineSizing,
+        },
+    },
+    support::{
+        constraint::{Constrained, Constraint, NonNegative, StrictlyPositive, UnitInterval},
+        thermo::{
+            State,
+            capability::{HasEntropy, StateFrom},
+        },
+        turbomachinery::IsentropicEfficiency,
+        units::SpecificEntropy,
+    },
+};
+
+use problem::{CondenserModel, CondenserProblem, CondenserState};
+use stage::StageCycle;
+
+/// Number of nodes in the cascade condenser discretization (20 segments).
+const NODES: usize = 21;
+
+/// Required thermo model bounds for each loop of a cascade cycle.
+///
+/// Adds entropy and saturation state construction to the discretized heat
+/// exchanger bounds, which the compressor and the saturation temperatures
+/// need.
+#[doc(hidden)]
+pub trait CascadeThermoModel<Fluid>:
+    DiscretizedHxThermoModel<Fluid>
+    + HasEntropy
+    + StateFrom<(Fluid, Pressure, SpecificEntropy)>
+    + StateFrom<(
+        Fluid,
+        ThermodynamicTemperature,
+        Constrained<Ratio, UnitInterval>,
+    )>
+{
+}
+
+impl<Fluid, T> CascadeThermoModel<Fluid> for T where
+    T: DiscretizedHxThermoModel<Fluid>
+        + HasEntropy
+        + StateFrom<(Fluid, Pressure, SpecificEntropy)>
+        + StateFrom<(
+            Fluid,
+            ThermodynamicTemperature,
+            Constrained<Ratio, UnitInterval>,
+        )>
+{
+}
+
+/// Boxed error stored as the source of a [`CascadeError`].
+type BoxError = Box<dyn StdError + Send + Sync>;
+
+/// One vapor-compression loop of the cascade.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stage<Fluid> {
+    /// Refrigerant circulating in the loop.
+    pub fluid: Fluid,
+
+    /// Isentropic efficiency of the compressor.
+    pub compressor_efficiency: IsentropicEfficiency,
+
+    /// Superheat at the evaporator outlet.
+    ///
+    /// Zero leaves the evaporator as saturated vapor.
+    pub superheat: TemperatureInterval,
+
+    /// Subcooling at the condenser outlet.
+    ///
+    /// Zero leaves the condenser as saturated liquid.
+    pub subcooling: TemperatureInterval,
+
+    /// Optional suction-line exchanger between the condenser outlet and the
+    /// expansion valve on one side and the evaporator outlet and the
+    /// compressor on the other.
+    pub suction_line: Option<SuctionLineSizing>,
+
+    /// Optional compressor oil carried around the loop.
+    ///
+    /// Oil held in the evaporator outlet lowers the heat each kilogram of
+    /// refrigerant absorbs, and oil on the tube walls lowers the film
+    /// coefficients in the cascade condenser. `None` is an oil-free loop.
+    pub oil: Option<OilCirculation>,
+}
+
+/// Design parameters of a [`CascadeCycle`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CascadeParameters<LowFluid, HighFluid> {
+    /// Low-temperature loop, which serves the refrigeration load.
+    pub low: Stage<LowFluid>,
+
+    /// High-temperature loop, which rejects heat to the surroundings.
+    pub high: Stage<HighFluid>,
+
+    /// Conductance of the cascade condenser between the two loops.
+    ///
+    /// This is the oil-free value. Oil in either loop lowers the
+    /// conductance used in the solve.
+    pub cascade_ua: ThermalConductance,
+}
+
+/// Solver configuration for [`CascadeCycle`].
+#[derive(Debug, Clone, Copy)]
+pub struct CascadeConfig {
+    /// Relative tolerance on the cascade condenser conductance.
+    pub ua_rel_tol: f64,
+
+    /// Maximum number of intermediate temperature iterations.
+    pub max_iters: usize,
+}
+
+impl Default for CascadeConfig {
+    fn default() -> Self {
+        Self {
+            ua_rel_tol: 1e-6,
+            max_iters: 100,
+        }
+    }
+}
+
+/// Operating conditions of a [`CascadeCycle`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CascadeInput {
+    /// Heat absorbed by the low-stage evaporator.
+    pub load: Power,
+
+    /// Saturation temperature in the low-stage evaporator.
+    pub evaporating: ThermodynamicTemperature,
+
+    /// Saturation temperature of the low stage in the cascade condenser.
+    pub low_condensing: ThermodynamicTemperature,
+
+    /// Saturation temperature in the high-stage condenser.
+    pub condensing: ThermodynamicTemperature,
+}
+
+/// State points and energy flows of one loop.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageOutput<Fluid> {
+    /// Refrigerant mass flow.
+    pub mass_flow: MassRate,
+
+    /// Saturation pressure in the evaporator.
+    pub evaporating_pressure: Pressure,
+
+    /// Saturation pressure in the condenser.
+    pub condensing_pressure: Pressure,
+
+    /// Compressor inlet.
+    ///
+    /// This is the evaporator outlet unless a suction-line exchanger warms
+    /// the vapor first.
+    pub suction: State<Fluid>,
+
+    /// Compressor outlet and condenser inlet.
+    pub discharge: State<Fluid>,
+
+    /// Expansion valve inlet.
+    ///
+    /// This is the condenser outlet unless a suction-line exchanger cools
+    /// the liquid first.
+    pub liquid: State<Fluid>,
+
+    /// Expansion valve outlet and evaporator inlet.
+    pub expanded: State<Fluid>,
+
+    /// Heat absorbed in the evaporator.
+    pub refrigeration: Power,
+
+    /// Heat rejected in the condenser.
+    pub heat_rejection: Power,
+
+    /// Shaft power into the compressor.
+    pub compressor_power: Power,
+
+    /// Heat moved from the liquid line to the suction gas.
+    ///
+    /// Zero without a suction-line exchanger.
+    pub suction_line_heat: Power,
+}
+
+/// Result of a [`CascadeCycle`] solve.
+#[derive(Debug, Clone)]
+pub struct CascadeOutput<LowFluid, HighFluid> {
+    /// Low-temperature loop.
+    pub low: StageOutput<LowFluid>,
+
+    /// High-temperature loop.
+    pub high: StageOutput<HighFluid>,
+
+    /// High-stage evaporating temperature that balances the cascade condenser.
+    pub intermediate: ThermodynamicTemperature,
+
+    /// Heat passed from the low stage to the high stage.
+    pub cascade_duty: Power,
+
+    /// Cascade condenser conductance after any oil degradation.
+    pub cascade_ua: ThermalConductance,
+
+    /// Node states and conductance of the cascade condenser, with the low
+    /// stage on top and the high stage on the bottom.
+    pub cascade_condenser: Results<LowFluid, HighFluid, NODES>,
+
+    /// Refrigeration load over total compressor power.
+    pub cop: Ratio,
+
+    /// Number of intermediate temperature iterations.
+    pub iterations: usize,
+}
+
+/// Errors from [`CascadeCycle`].
+#[derive(Debug, Error)]
+pub enum CascadeError {
+    /// A design parameter is non-physical.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating condition is non-physical.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// A refrigerant state in one loop could not be evaluated.
+    #[error("{stage} stage {context} failed")]
+    Stage {
+        /// `"low"` or `"high"`.
+        stage: &'static str,
+
+        /// The step that failed.
+        context: &'static str,
+
+        /// Underlying error.
+        #[source]
+        source: BoxError,
+    },
+
+    /// The cascade condenser temperatures cross at this intermediate
+    /// temperature.
+    #[error("cascade condenser temperatures cross at intermediate temperature {0:?}")]
+    Pinch(ThermodynamicTemperature),
+
+    /// The cascade condenser could not be evaluated.
+    #[error("cascade condenser failed")]
+    CascadeCondenser(#[source] BoxError),
+
+    /// The cascade condenser cannot carry the duty even with the high stage
+    /// evaporating at the low-stage evaporating temperature.
+    #[error("cascade condenser needs at least {required:?}, has {available:?}")]
+    Undersized {
+        /// Conductance needed at the lowest intermediate temperature.
+        required: ThermalConductance,
+
+        /// Conductance of the cascade condenser after any oil degradation.
+        available: ThermalConductance,
+    },
+
+    /// The intermediate temperature search did not converge.
+    #[error("intermediate temperature did not converge: {0}")]
+    Convergence(String),
+}
+
+/// A two-stage cascade vapor-compression refrigeration cycle.
+///
+/// The low stage absorbs the load at a low evaporating temperature and
+/// condenses in the cascade condenser, where it evaporates the high stage.
+/// The high stage rejects the combined heat at its condensing temperature.
+/// The two loops usually run different refrigerants, such as CO₂ below and
+/// ammonia above.
+///
+/// Given the load and three saturation temperatures, the low stage is fully
+/// determined. The high-stage evaporating (intermediate) temperature is
+/// found by bisection so the discretized counterflow cascade condenser,
+/// carrying the low stage's full condensing duty, needs exactly the design
+/// conductance. Desuperheating, condensing, and subcooling of the low stage
+/// against evaporation and superheating of the high stage are all resolved
+/// along the exchanger.
+///
+/// Pressure drops and heat leaks are neglected.
+#[derive(Debug, Clone)]
+pub struct CascadeCycle<LowFluid, HighFluid, LowThermo, HighThermo> {
+    parameters: CascadeParameters<LowFluid, HighFluid>,
+    config: CascadeConfig,
+    low_thermo: LowThermo,
+    high_thermo: HighThermo,
+}
+
+impl<LowFluid, HighFluid, LowThermo, HighThermo>
+    CascadeCycle<LowFluid, HighFluid, LowThermo, HighThermo>
+where
+    LowFluid: Clone,
+    HighFluid: Clone,
+    LowThermo: CascadeThermoModel<LowFluid>,
+    HighThermo: CascadeThermoModel<HighFluid>,
+{
+    /// Creates a cycle from its design parameters and refrigerant models.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CascadeError::InvalidParameter`] if a superheat or
+    /// subcooling is negative, the cascade condenser conductance is not
+    /// strictly positive, oil would hold all of a loop's refrigerant, or the
+    /// solver configuration is unusable.
+    pub fn new(
+        parameters: CascadeParameters<LowFluid, HighFluid>,
+        config: CascadeConfig,
+        low_thermo: LowThermo,
+        high_thermo: HighThermo,
+    ) -> Result<Self, CascadeError> {
+        for stage in [
+            (parameters.low.superheat, parameters.low.subcooling),
+            (parameters.high.superheat, parameters.high.subcooling),
+        ] {
+            if NonNegative::check(&stage.0).is_err() || NonNegative::check(&stage.1).is_err() {
+                return Err(CascadeError::InvalidParameter(
+                    "superheat and subcooling must be non-negative",
+                ));
+            }
+        }
+        for oil in [parameters.low.oil, parameters.high.oil]
+            .into_iter()
+            .flatten()
+        {
+            if StrictlyPositive::check(&oil.capacity_factor()).is_err() {
+                return Err(CascadeError::InvalidParameter(
+                    "oil circulation leaves no refrigerant to evaporate",
+                ));
+            }
+        }
+        if StrictlyPositive::check(&parameters.cascade_ua).is_err()
+            || !parameters.cascade_ua.is_finite()
+        {
+            return Err(CascadeError::InvalidParameter(
+                "cascade condenser UA must be strictly positive and finite",
+            ));
+        }
+        if !(config.ua_rel_tol.is_finite() && config.ua_rel_tol > 0.0) || config.max_iters == 0 {
+            return Err(CascadeError::InvalidParameter(
+                "UA tolerance must be positive and iterations nonzero",
+            ));
+        }
+
+        Ok(Self {
+            parameters,
+            config,
+            low_thermo,
+            high_thermo,
+        })
+    }
+
+    /// Returns the design parameters.
+    pub fn parameters(&self) -> &CascadeParameters<LowFluid, HighFluid> {
+        &self.parameters
+    }
+
+    /// Solves the cycle at the given operating conditions.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CascadeError`] if the conditions are out of order, a
+    /// refrigerant state cannot be evaluated, the cascade condenser is too
+    /// small to carry the duty, or the search fails to converge.
+    pub fn solve(
+        &self,
+        input: &CascadeInput,
+    ) -> Result<CascadeOutput<LowFluid, HighFluid>, CascadeError> {
+        let CascadeInput {
+            load,
+            evaporating,
+            low_condensing,
+            condensing,
+        } = *input;
+
+        if StrictlyPositive::check(&load).is_err() || !load.is_finite() {
+            return Err(CascadeError::InvalidInput(
+                "load must be strictly positive and finite",
+            ));
+        }
+        if !(evaporating < low_condensing && low_condensing < condensing) {
+            return Err(CascadeError::InvalidInput(
+                "saturation temperatures must rise from evaporating to low condensing to condensing",
+            ));
+        }
+
+        let low_cycle = StageCycle::evaluate(
+            "low",
+            &self.parameters.low,
+            evaporating,
+            low_condensing,
+            load,
+            &self.low_thermo,
+        )?;
+        let duty = low_cycle.heat_rejection();
+
+        let model = CondenserModel {
+            high: &self.parameters.high,
+            condensing,
+            low_cycle: &low_cycle,
+            duty,
+            low_thermo: &self.low_thermo,
+            high_thermo: &self.high_thermo,
+        };
+        let cascade_ua = self.cascade_ua();
+        let problem = CondenserProblem::new(cascade_ua);
+
+        // The widest driving difference puts the high stage at the low-stage
+        // evaporating temperature. If the exchanger is too small there, it
+        // is too small everywhere. At the low-stage condensing temperature the
+        // condensing and evaporating plateaus meet, so the required
+        // conductance is unbounded and the residual is positive.
+        let lowest = model.call(&evaporating)?;
+        if lowest.hx.ua >= cascade_ua {
+            return Err(CascadeError::Undersized {
+                required: lowest.hx.ua,
+                available: cascade_ua,
+            });
+        }
+        let bracket = Bracket::new(
+            (evaporating.get::<kelvin>(), Sign::Negative),
+            (low_condensing.get::<kelvin>(), Sign::Positive),
+        )
+        .map_err(|error| CascadeError::Convergence(error.to_string()))?;
+
+        let config = bisection::Config {
+            max_iters: self.config.max_iters,
+            x_abs_tol: 0.0,
+            x_rel_tol: 0.0,
+            residual_tol: self.config.ua_rel_tol,
+        };
+        let solution = bisection::solve_from_bracket(
+            &model,
+            &problem,
+            bracket,
+            &config,
+            |event: &bisection::Event<'_, _, _>| {
+                #[cfg(feature = "tracing")]
+                crate::support::numerics::solve::trace_bisection(event);
+
+                // Crossing temperatures mean the candidate is too close to
+                // the low-stage condensing temperature for any conductance.
+                if matches!(
+                    event,
+                    bisection::Event::ModelFailed {
+                        error: CascadeError::Pinch(_),
+                        ..
+                    }
+                ) {
+                    return Some(bisection::Action::assume_positive());
+                }
+                None
+            },
+        )
+        .map_err(solver_error)?;
+
+        if solution.status != bisection::Status::Converged {
+            return Err(CascadeError::Convergence(
+                "iteration limit reached".to_owned(),
+            ));
+        }
+
+        let intermediate = solution.snapshot.input;
+        let CondenserState { high_cycle, hx } = solution.snapshot.output;
+        let low = low_cycle.output();
+        let high = high_cycle.output();
+        let cop = load / (low.compressor_power + high.compressor_power);
+
+        Ok(CascadeOutput {
+            low,
+            high,
+            intermediate,
+            cascade_duty: duty,
+            cascade_ua,
+            cascade_condenser: hx,
+            cop,
+            iterations: solution.iters,
+        })
+    }
+
+    /// Cascade condenser conductance with oil on either side.
+    ///
+    /// The condensing and evaporating films are each taken as half of the
+    /// clean resistance.
+    fn cascade_ua(&self) -> ThermalConductance {
+        let half = Ratio::new::<ratio>(0.5);
+        let clean = Ratio::new::<ratio>(1.0);
+        let low = &self.parameters.low.oil;
+        let high = &self.parameters.high.oil;
+        let films = [
+            (half, low.map_or(clean, |oil| oil.condensation_factor())),
+            (half, high.map_or(clean, |oil| oil.evaporation_factor())),
+        ];
+        degrade_conductance(self.parameters.cascade_ua, &films)
+    }
+}
+
+/// Recovers a cascade error raised inside the solver, or reports any other
+/// solver failure as a convergence error.
+fn solver_error(error: bisection::Error) -> CascadeError {
+    match error {
+        bisection::Error::Model(source) => match source.downcast::<CascadeError>() {
+            Ok(error) => *error,
+            Err(source) => CascadeError::Convergence(source.to_string()),
+        },
+        error => CascadeError::Convergence(error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::ConstZero;
+    use uom::si::{
+        power::kilowatt, temperature_interval::kelvin as delta_kelvin,
+        thermal_conductance::kilowatt_per_kelvin, thermodynamic_temperature::degree_celsius,
+    };
+
+    use crate::models::thermal::hx::discretized::core::test_support::{TestFluid, TestRefrigerant};
+
+    type TestCycle = CascadeCycle<TestFluid, TestFluid, TestRefrigerant, TestRefrigerant>;
+
+    fn stage<Fluid>(fluid: Fluid) -> Stage<Fluid> {
+        Stage {
+            fluid,
+            compressor_efficiency: IsentropicEfficiency::new(0.7).unwrap(),
+            superheat: TemperatureInterval::new::<delta_kelvin>(5.0),
+            subcooling: TemperatureInterval::new::<delta_kelvin>(2.0),
+            suction_line: None,
+            oil: None,
+        }
+    }
+
+    fn cycle(ua: f64) -> Result<TestCycle, CascadeError> {
+        CascadeCycle::new(
+            CascadeParameters {
+                low: stage(TestFluid),
+                high: stage(TestFluid),
+                cascade_ua: ThermalConductance::new::<kilowatt_per_kelvin>(ua),
+            },
+            CascadeConfig::default(),
+            TestRefrigerant::carbon_dioxide_like(),
+            TestRefrigerant::ammonia_like(),
+        )
+    }
+
+    fn input() -> CascadeInput {
+        CascadeInput {
+            load: Power::new::<kilowatt>(100.0),
+            evaporating: ThermodynamicTemperature::new::<degree_celsius>(-45.0),
+            low_condensing: ThermodynamicTemperature::new::<degree_celsius>(-5.0),
+            condensing: ThermodynamicTemperature::new::<degree_celsius>(35.0),
+        }
+    }
+
+    #[test]
+    fn cascade_condenser_balances_both_loops() {
+        let output = cycle(25.0).unwrap().solve(&input()).unwrap();
+        let kw = |power: Power| power.get::<kilowatt>();
+
+        let intermediate = output.intermediate;
+        assert!(intermediate > input().evaporating && intermediate < input().low_condensing);
+        assert_relative_eq!(
+            output.cascade_condenser.ua.get::<kilowatt_per_kelvin>(),
+            25.0,
+            max_relative = 1e-5
+        );
+
+        // The low stage's condensing duty is the high stage's load.
+        assert_relative_eq!(kw(output.low.refrigeration), 100.0, max_relative = 1e-12);
+        assert_relative_eq!(kw(output.low.heat_rejection), kw(output.cascade_duty));
+        assert_relative_eq!(
+            kw(output.high.refrigeration),
+            kw(output.cascade_duty),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            kw(output.high.heat_rejection),
+            100.0 + kw(output.low.compressor_power) + kw(output.high.compressor_power),
+            max_relative = 1e-9
+        );
+
+        // Both loops work across their own lift.
+        assert!(output.low.condensing_pressure > output.low.evaporating_pressure);
+        assert!(output.high.condensing_pressure > output.high.evaporating_pressure);
+        assert!(output.cascade_condenser.min_delta_t.value > TemperatureInterval::ZERO);
+        let cop = output.cop.get::<uom::si::ratio::ratio>();
+        assert!(cop > 0.5 && cop < 3.0, "cop = {cop}");
+    }
+
+    #[test]
+    fn larger_cascade_condenser_raises_intermediate_and_cop() {
+        let small = cycle(10.0).unwrap().solve(&input()).unwrap();
+        let large = cycle(50.0).unwrap().solve(&input()).unwrap();
+
+        assert!(large.intermediate > small.intermediate);
+        assert!(large.cop > small.cop);
+        assert!(large.high.compressor_power < small.high.compressor_power);
+        assert_eq!(large.low.compressor_power, small.low.compressor_power);
+    }
+
+    #[test]
+    fn suction_line_exchanger_subcools_the_low_stage() {
+        let plain = cycle(25.0).unwrap();
+        let mut parameters = plain.parameters().clone();
+        parameters.low.suction_line = Some(SuctionLineSizing::Conductance {
+            ua: ThermalConductance::new::<kilowatt_per_kelvin>(0.2),
+            segments: 10,
+        });
+        let fitted = CascadeCycle::new(
+            parameters,
+            CascadeConfig::default(),
+            TestRefrigerant::carbon_dioxide_like(),
+            TestRefrigerant::ammonia_like(),
+        )
+        .unwrap();
+
+        let plain = plain.solve(&input()).unwrap();
+        let fitted = fitted.solve(&input()).unwrap();
+        let kw = |power: Power| power.get::<kilowatt>();
+
+        assert_eq!(plain.low.suction_line_heat, Power::ZERO);
+        assert!(fitted.low.suction_line_heat > Power::ZERO);
+        assert!(fitted.low.suction.temperature > plain.low.suction.temperature);
+        assert!(fitted.low.liquid.temperature < plain.low.liquid.temperature);
+        assert!(fitted.low.mass_flow < plain.low.mass_flow);
+
+        // The exchanger moves heat within the loop, so the load and the
+        // overall energy balance are unchanged.
+        assert_relative_eq!(kw(fitted.low.refrigeration), 100.0, max_relative = 1e-9);
+        assert_relative_eq!(
+            kw(fitted.low.heat_rejection),
+            100.0 + kw(fitted.low.compressor_power),
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(kw(fitted.low.heat_rejection), kw(fitted.cascade_duty));
+    }
+
+    #[test]
+    fn circulating_oil_costs_capacity_and_conductance() {
+        let clean = cycle(25.0).unwrap();
+        let mut parameters = clean.parameters().clone();
+        let oil = OilCirculation::new(Ratio::new::<ratio>(0.05)).unwrap();
+        parameters.low.oil = Some(oil);
+        parameters.high.oil = Some(oil);
+        let oily = CascadeCycle::new(
+            parameters,
+            CascadeConfig::default(),
+            TestRefrigerant::carbon_dioxide_like(),
+            TestRefrigerant::ammonia_like(),
+        )
+        .unwrap();
+
+        let clean = clean.solve(&input()).unwrap();
+        let oily = oily.solve(&input()).unwrap();
+        let kw = |power: Power| power.get::<kilowatt>();
+
+        // More refrigerant circulates to meet the same load.
+        assert_relative_eq!(kw(oily.low.refrigeration), 100.0, max_relative = 1e-12);
+        assert_relative_eq!(
+            (oily.low.mass_flow / clean.low.mass_flow).get::<ratio>(),
+            1.0 / oil.capacity_factor().get::<ratio>(),
+            max_relative = 1e-12
+        );
+        assert!(oily.low.compressor_power > clean.low.compressor_power);
+        assert!(oily.low.discharge.temperature < clean.low.discharge.temperature);
+
+        // Oily films leave a smaller condenser, so the intermediate
+        // temperature drops and the cycle works harder.
+        assert_relative_eq!(
+            clean.cascade_ua.get::<kilowatt_per_kelvin>(),
+            25.0,
+            max_relative = 1e-12
+        );
+        assert!(oily.cascade_ua < clean.cascade_ua);
+        assert_relative_eq!(
+            oily.cascade_condenser.ua.get::<kilowatt_per_kelvin>(),
+            oily.cascade_ua.get::<kilowatt_per_kelvin>(),
+            max_relative = 1e-5
+        );
+        assert!(oily.intermediate < clean.intermediate);
+        assert!(oily.cop < clean.cop);
+
+        // Energy still balances across both loops.
+        assert_relative_eq!(
+            kw(oily.low.heat_rejection),
+            100.0 + kw(oily.low.compressor_power),
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            kw(oily.high.heat_rejection),
+            100.0 + kw(oily.low.compressor_power) + kw(oily.high.compressor_power),
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn tiny_cascade_condenser_is_undersized() {
+        assert!(matches!(
+            cycle(0.5).unwrap().solve(&input()),
+            Err(CascadeError::Undersized { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_inputs() {
+        assert!(matches!(cycle(0.0), Err(CascadeError::InvalidParameter(_))));
+
+        let mut parameters = cycle(25.0).unwrap().parameters().clone();
+        parameters.high.subcooling = TemperatureInterval::new::<delta_kelvin>(-1.0);
+        assert!(matches!(
+            CascadeCycle::new(
+                parameters,
+                CascadeConfig::default(),
+                TestRefrigerant::carbon_dioxide_like(),
+                TestRefrigerant::ammonia_like(),
+            ),
+            Err(CascadeError::InvalidParameter(_))
+        ));
+
+        let cycle = cycle(25.0).unwrap();
+        let swapped = CascadeInput {
+            low_condensing: input().condensing,
+            condensing: input().low_condensing,
+            ..input()
+        };
+        assert!(matches!(
+            cycle.solve(&swapped),
+            Err(CascadeError::InvalidInput(_))
+        ));
+        let no_load = CascadeInput {
+            load: Power::new::<kilowatt>(0.0),
+            ..input()
+        };
+        assert!(matches!(
+            cycle.solve(&no_load),
+            Err(CascadeError::InvalidInput(_))
+        ));
+    }
+
+    #[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
+    mod coolprop_tests {
+        use super::*;
+
+        use crate::support::thermo::{
+            fluid::{Ammonia, CarbonDioxide},
+            model::CoolProp,
+        };
+
+        #[test]
+        fn co2_below_ammonia() {
+            let cycle = CascadeCycle::new(
+                CascadeParameters {
+                    low: stage(CarbonDioxide),
+                    high: stage(Ammonia),
+                    cascade_ua: ThermalConductance::new::<kilowatt_per_kelvin>(25.0),
+                },
+                CascadeConfig::default(),
+                CoolProp::<CarbonDioxide>::new().unwrap(),
+                CoolProp::<Ammonia>::new().unwrap(),
+            )
+            .unwrap();
+
+            let output = cycle.solve(&input()).unwrap();
+
+            assert!(output.intermediate < input().low_condensing);
+            assert!(output.intermediate > input().evaporating);
+            assert_relative_eq!(
+                output.cascade_condenser.ua.get::<kilowatt_per_kelvin>(),
+                25.0,
+                max_relative = 1e-5
+            );
+            let cop = output.cop.get::<uom::si::ratio::ratio>();
+            assert!(cop > 1.0 && cop < 2.5, "cop = {cop}");
+        }
+    }
+}
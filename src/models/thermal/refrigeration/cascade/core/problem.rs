@@ -0,0 +1,133 @@
+//! Problem formulation for the cascade condenser balance.
+
+use std::{convert::Infallible, marker::PhantomData};
+
+use twine_core::{EquationProblem, Model};
+use uom::si::{
+    f64::{Power, ThermalConductance, ThermodynamicTemperature},
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::{
+    models::thermal::hx::discretized::core::{
+        DiscretizedHx, Given, HeatTransferRate, Inlets, Known, MassFlows, PressureDrops, Results,
+        SolveError,
+    },
+    support::hx::arrangement::CounterFlow,
+};
+
+use super::{CascadeError, CascadeThermoModel, NODES, Stage, stage::StageCycle};
+
+/// The high stage and cascade condenser at a candidate intermediate
+/// temperature.
+///
+/// The low stage is fixed by the load and its own saturation temperatures,
+/// so its condensing duty is known before the search starts. Each candidate
+/// sizes the high stage to absorb that duty, then asks the
+/// discretized exchanger what conductance the transfer requires.
+pub(super) struct CondenserModel<'a, LowFluid, HighFluid, LowThermo, HighThermo> {
+    pub(super) high: &'a Stage<HighFluid>,
+    pub(super) condensing: ThermodynamicTemperature,
+    pub(super) low_cycle: &'a StageCycle<LowFluid>,
+    pub(super) duty: Power,
+    pub(super) low_thermo: &'a LowThermo,
+    pub(super) high_thermo: &'a HighThermo,
+}
+
+/// High stage and exchanger results at one intermediate temperature.
+#[derive(Debug, Clone)]
+pub(super) struct CondenserState<LowFluid, HighFluid> {
+    pub(super) high_cycle: StageCycle<HighFluid>,
+    pub(super) hx: Results<LowFluid, HighFluid, NODES>,
+}
+
+impl<LowFluid, HighFluid, LowThermo, HighThermo> Model
+    for CondenserModel<'_, LowFluid, HighFluid, LowThermo, HighThermo>
+where
+    LowFluid: Clone,
+    HighFluid: Clone,
+    LowThermo: CascadeThermoModel<LowFluid>,
+    HighThermo: CascadeThermoModel<HighFluid>,
+{
+    type Input = ThermodynamicTemperature;
+    type Output = CondenserState<LowFluid, HighFluid>;
+    type Error = CascadeError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        let high_cycle = StageCycle::evaluate(
+            "high",
+            self.high,
+            *input,
+            self.condensing,
+            self.duty,
+            self.high_thermo,
+        )?;
+
+        // Low-stage refrigerant condenses along the top; high-stage
+        // refrigerant evaporates along the bottom in counterflow.
+        let known = Known {
+            inlets: Inlets {
+                top: self.low_cycle.discharge().clone(),
+                bottom: high_cycle.expanded().clone(),
+            },
+            m_dot: MassFlows::new(self.low_cycle.mass_flow(), high_cycle.mass_flow()).map_err(
+                |error| CascadeError::Stage {
+                    stage: "high",
+                    context: "mass flow",
+                    source: Box::new(error),
+                },
+            )?,
+            dp: PressureDrops::default(),
+        };
+        let q_dot = HeatTransferRate::top_to_bottom(self.duty)
+            .map_err(|error| CascadeError::CascadeCondenser(Box::new(error)))?;
+        let hx = DiscretizedHx::<CounterFlow, NODES>::solve(
+            &known,
+            Given::HeatTransferRate(q_dot),
+            self.low_thermo,
+            self.high_thermo,
+        )
+        .map_err(|error| match error {
+            SolveError::SecondLawViolation { .. } => CascadeError::Pinch(*input),
+            SolveError::ThermoModelFailed { .. } => CascadeError::CascadeCondenser(error.into()),
+        })?;
+
+        Ok(CondenserState { high_cycle, hx })
+    }
+}
+
+/// Residual is `ln(UA_required / UA)`.
+///
+/// The logarithm keeps the residual scaled to the conductance ratio as the
+/// required conductance grows without bound near the pinch.
+pub(super) struct CondenserProblem<LowFluid, HighFluid> {
+    ua: ThermalConductance,
+    _fluids: PhantomData<(LowFluid, HighFluid)>,
+}
+
+impl<LowFluid, HighFluid> CondenserProblem<LowFluid, HighFluid> {
+    pub(super) fn new(ua: ThermalConductance) -> Self {
+        Self {
+            ua,
+            _fluids: PhantomData,
+        }
+    }
+}
+
+impl<LowFluid, HighFluid> EquationProblem<1> for CondenserProblem<LowFluid, HighFluid> {
+    type Input = ThermodynamicTemperature;
+    type Output = CondenserState<LowFluid, HighFluid>;
+    type Error = Infallible;
+
+    fn input(&self, x: &[f64; 1]) -> Result<Self::Input, Self::Error> {
+        Ok(ThermodynamicTemperature::new::<kelvin>(x[0]))
+    }
+
+    fn residuals(
+        &self,
+        _input: &Self::Input,
+        output: &Self::Output,
+    ) -> Result<[f64; 1], Self::Error> {
+        Ok([(output.hx.ua / self.ua).value.ln()])
+    }
+}
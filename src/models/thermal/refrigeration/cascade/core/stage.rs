@@ -0,0 +1,338 @@
+//! State points of a single vapor-compression loop.
+
+use std::error::Error as StdError;
+
+use uom::{
+    ConstZero,
+    si::{
+        f64::{MassRate, Power, Pressure, Ratio, TemperatureInterval, ThermodynamicTemperature},
+        ratio::ratio,
+    },
+};
+
+use crate::{
+    models::thermal::refrigeration::suction_line::{
+        SuctionLineHx, SuctionLineInput, SuctionLineOutput,
+    },
+    support::{
+        constraint::UnitInterval,
+        thermo::State,
+        turbomachinery::compressor,
+        units::{SpecificEnthalpy, TemperatureDifference},
+    },
+};
+
+use super::{CascadeError, CascadeThermoModel, Stage, StageOutput};
+
+/// Iteration limit for the mass flow of a loop with a suction-line exchanger.
+const MAX_MASS_FLOW_ITERS: usize = 50;
+
+/// Relative change in mass flow that ends the suction-line iteration.
+const MASS_FLOW_REL_TOL: f64 = 1e-10;
+
+/// One loop of the cascade sized to its evaporator duty.
+#[derive(Debug, Clone)]
+pub(super) struct StageCycle<Fluid> {
+    mass_flow: MassRate,
+    evaporating_pressure: Pressure,
+    condensing_pressure: Pressure,
+    suction: State<Fluid>,
+    discharge: State<Fluid>,
+    liquid: State<Fluid>,
+    expanded: State<Fluid>,
+    h_evaporator_outlet: SpecificEnthalpy,
+    h_suction: SpecificEnthalpy,
+    h_discharge: SpecificEnthalpy,
+    h_liquid: SpecificEnthalpy,
+    suction_line_heat: Power,
+
+    /// Heat per unit mass the refrigerant dissolved in the oil did not
+    /// absorb in the evaporator.
+    h_unboiled: SpecificEnthalpy,
+}
+
+impl<Fluid: Clone> StageCycle<Fluid> {
+    /// Evaluates the loop between two saturation temperatures.
+    ///
+    /// The evaporator leaves `superheat` above the evaporating temperature,
+    /// the compressor follows its isentropic efficiency up to the condensing
+    /// pressure, the condenser leaves `subcooling` below the condensing
+    /// temperature, and the expansion valve is isenthalpic. An optional
+    /// suction-line exchanger sits between the condenser and valve on the
+    /// liquid side and between the evaporator and compressor on the vapor
+    /// side.
+    ///
+    /// The mass flow is the one that absorbs `evaporator_duty`. With oil in
+    /// the loop, only part of that flow boils in the evaporator. The rest
+    /// leaves dissolved in the oil and boils off in the compressor shell,
+    /// which cools the discharge gas by the heat it did not absorb.
+    pub(super) fn evaluate(
+        name: &'static str,
+        stage: &Stage<Fluid>,
+        evaporating: ThermodynamicTemperature,
+        condensing: ThermodynamicTemperature,
+        evaporator_duty: Power,
+        thermo: &impl CascadeThermoModel<Fluid>,
+    ) -> Result<Self, CascadeError> {
+        let Outlets {
+            evaporating_pressure,
+            condensing_pressure,
+            evaporator: evaporator_outlet,
+            condenser: condenser_outlet,
+        } = Outlets::evaluate(name, stage, evaporating, condensing, thermo)?;
+
+        let h_evaporator_outlet = thermo
+            .enthalpy(&evaporator_outlet)
+            .map_err(|error| stage_error(name, "evaporator outlet enthalpy", error))?;
+        let h_condenser_outlet = thermo
+            .enthalpy(&condenser_outlet)
+            .map_err(|error| stage_error(name, "condenser outlet enthalpy", error))?;
+
+        let capacity_factor = stage
+            .oil
+            .map_or(Ratio::new::<ratio>(1.0), |oil| oil.capacity_factor());
+        let boiling_duty = evaporator_duty / capacity_factor;
+
+        let (mass_flow, suction, liquid, suction_line_heat) = match stage.suction_line {
+            None => (
+                boiling_duty / (h_evaporator_outlet - h_condenser_outlet),
+                evaporator_outlet,
+                condenser_outlet,
+                Power::ZERO,
+            ),
+            Some(sizing) => {
+                let exchanger = SuctionLineHx::new(sizing, thermo)
+                    .map_err(|error| stage_error(name, "suction line", error))?;
+                let suction_line = SuctionLineLoop {
+                    name,
+                    exchanger: &exchanger,
+                    liquid: &condenser_outlet,
+                    vapor: &evaporator_outlet,
+                    base_effect: h_evaporator_outlet - h_condenser_outlet,
+                };
+                let (mass_flow, exchange) = suction_line.solve(boiling_duty)?;
+                (
+                    mass_flow,
+                    exchange.vapor,
+                    exchange.liquid,
+                    exchange.heat_transfer_rate,
+                )
+            }
+        };
+
+        let h_suction = thermo
+            .enthalpy(&suction)
+            .map_err(|error| stage_error(name, "suction enthalpy", error))?;
+        let h_liquid = thermo
+            .enthalpy(&liquid)
+            .map_err(|error| stage_error(name, "liquid line enthalpy", error))?;
+
+        let compression = compressor::isentropic(
+            &suction,
+            condensing_pressure,
+            stage.compressor_efficiency,
+            thermo,
+        )
+        .map_err(|error| stage_error(name, "compression", error.to_string()))?;
+        let h_unboiled = (1.0 - capacity_factor.get::<ratio>()) * (h_evaporator_outlet - h_liquid);
+        let h_discharge = h_suction + compression.work.quantity() - h_unboiled;
+        let discharge = if h_unboiled > SpecificEnthalpy::ZERO {
+            thermo
+                .state_from((stage.fluid.clone(), condensing_pressure, h_discharge))
+                .map_err(|error| stage_error(name, "discharge state", error))?
+        } else {
+            compression.outlet
+        };
+
+        let expanded = thermo
+            .state_from((stage.fluid.clone(), evaporating_pressure, h_liquid))
+            .map_err(|error| stage_error(name, "expansion", error))?;
+
+        Ok(Self {
+            mass_flow,
+            evaporating_pressure,
+            condensing_pressure,
+            suction,
+            discharge,
+            liquid,
+            expanded,
+            h_evaporator_outlet,
+            h_suction,
+            h_discharge,
+            h_liquid,
+            suction_line_heat,
+            h_unboiled,
+        })
+    }
+
+    /// Refrigerant mass flow.
+    pub(super) fn mass_flow(&self) -> MassRate {
+        self.mass_flow
+    }
+
+    /// Shaft power into the compressor.
+    ///
+    /// Refrigerant boiling out of the oil in the shell lowers the discharge
+    /// enthalpy without changing the work on the suction gas.
+    fn compressor_power(&self) -> Power {
+        self.mass_flow * (self.h_discharge + self.h_unboiled - self.h_suction)
+    }
+
+    /// Heat rejected in the condenser.
+    pub(super) fn heat_rejection(&self) -> Power {
+        self.mass_flow * (self.h_discharge - self.h_liquid) - self.suction_line_heat
+    }
+
+    pub(super) fn discharge(&self) -> &State<Fluid> {
+        &self.discharge
+    }
+
+    pub(super) fn expanded(&self) -> &State<Fluid> {
+        &self.expanded
+    }
+
+    /// Collects the loop's state points and energy flows.
+    pub(super) fn output(self) -> StageOutput<Fluid> {
+        let mass_flow = self.mass_flow;
+        StageOutput {
+            refrigeration: mass_flow * (self.h_evaporator_outlet - self.h_liquid - self.h_unboiled),
+            heat_rejection: self.heat_rejection(),
+            compressor_power: self.compressor_power(),
+            suction_line_heat: self.suction_line_heat,
+            mass_flow,
+            evaporating_pressure: self.evaporating_pressure,
+            condensing_pressure: self.condensing_pressure,
+            suction: self.suction,
+            discharge: self.discharge,
+            liquid: self.liquid,
+            expanded: self.expanded,
+        }
+    }
+}
+
+/// Evaporator and condenser outlets of a loop at its saturation pressures.
+struct Outlets<Fluid> {
+    evaporating_pressure: Pressure,
+    condensing_pressure: Pressure,
+    evaporator: State<Fluid>,
+    condenser: State<Fluid>,
+}
+
+impl<Fluid: Clone> Outlets<Fluid> {
+    /// Evaluates the outlets `superheat` above the evaporating temperature
+    /// and `subcooling` below the condensing temperature.
+    fn evaluate(
+        name: &'static str,
+        stage: &Stage<Fluid>,
+        evaporating: ThermodynamicTemperature,
+        condensing: ThermodynamicTemperature,
+        thermo: &impl CascadeThermoModel<Fluid>,
+    ) -> Result<Self, CascadeError> {
+        let saturated = |temperature, quality| {
+            thermo
+                .state_from((stage.fluid.clone(), temperature, quality))
+                .map_err(|error| stage_error(name, "saturation state", error))
+        };
+
+        let saturated_vapor = saturated(evaporating, UnitInterval::one::<Ratio>())?;
+        let evaporating_pressure = thermo
+            .pressure(&saturated_vapor)
+            .map_err(|error| stage_error(name, "evaporating pressure", error))?;
+        let saturated_liquid = saturated(condensing, UnitInterval::zero::<Ratio>())?;
+        let condensing_pressure = thermo
+            .pressure(&saturated_liquid)
+            .map_err(|error| stage_error(name, "condensing pressure", error))?;
+
+        let evaporator_outlet = if stage.superheat > TemperatureInterval::ZERO {
+            thermo
+                .state_from((
+                    stage.fluid.clone(),
+                    evaporating.plus(stage.superheat),
+                    evaporating_pressure,
+                ))
+                .map_err(|error| stage_error(name, "evaporator outlet state", error))?
+        } else {
+            saturated_vapor
+        };
+        let condenser_outlet = if stage.subcooling > TemperatureInterval::ZERO {
+            thermo
+                .state_from((
+                    stage.fluid.clone(),
+                    condensing - stage.subcooling,
+                    condensing_pressure,
+                ))
+                .map_err(|error| stage_error(name, "condenser outlet state", error))?
+        } else {
+            saturated_liquid
+        };
+
+        Ok(Self {
+            evaporating_pressure,
+            condensing_pressure,
+            evaporator: evaporator_outlet,
+            condenser: condenser_outlet,
+        })
+    }
+}
+
+/// Mass flow search for a loop with a suction-line exchanger.
+///
+/// Subcooling the liquid raises the refrigerating effect, so the loop needs
+/// less flow for the same duty, which in turn changes how much heat a
+/// conductance-sized exchanger moves.
+struct SuctionLineLoop<'a, Fluid, Thermo> {
+    name: &'static str,
+    exchanger: &'a SuctionLineHx<Fluid, Thermo>,
+    liquid: &'a State<Fluid>,
+    vapor: &'a State<Fluid>,
+
+    /// Refrigerating effect without the exchanger.
+    base_effect: SpecificEnthalpy,
+}
+
+impl<Fluid, Thermo> SuctionLineLoop<'_, Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: CascadeThermoModel<Fluid>,
+{
+    /// Returns the mass flow that absorbs `duty` and the exchanger outlets.
+    ///
+    /// Each pass sets the flow from the exchanger's heat per unit mass at
+    /// the previous flow. An effectiveness-sized exchanger moves a fixed
+    /// fraction of its limit per unit mass, so it settles after one pass.
+    fn solve(&self, duty: Power) -> Result<(MassRate, SuctionLineOutput<Fluid>), CascadeError> {
+        let mut mass_flow = duty / self.base_effect;
+        for _ in 0..MAX_MASS_FLOW_ITERS {
+            let exchange = self
+                .exchanger
+                .evaluate(&SuctionLineInput {
+                    liquid: self.liquid.clone(),
+                    vapor: self.vapor.clone(),
+                    mass_flow,
+                })
+                .map_err(|error| stage_error(self.name, "suction line", error))?;
+            let next = duty / (self.base_effect + exchange.heat_transfer_rate / mass_flow);
+            if ((next - mass_flow) / mass_flow).value.abs() <= MASS_FLOW_REL_TOL {
+                return Ok((mass_flow, exchange));
+            }
+            mass_flow = next;
+        }
+        Err(stage_error(
+            self.name,
+            "suction line",
+            "mass flow iteration did not converge",
+        ))
+    }
+}
+
+fn stage_error(
+    stage: &'static str,
+    context: &'static str,
+    error: impl Into<Box<dyn StdError + Send + Sync>>,
+) -> CascadeError {
+    CascadeError::Stage {
+        stage,
+        context,
+        source: error.into(),
+    }
+}
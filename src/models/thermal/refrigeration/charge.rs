@@ -0,0 +1,375 @@
+//! Refrigerant charge held in two-phase vessels.
+//!
+//! A [`SuctionAccumulator`](super::accumulator::SuctionAccumulator) and a
+//! [`LiquidReceiver`](super::receiver::LiquidReceiver) are both vessels in
+//! which liquid and vapor settle at the saturation pressure of the line they
+//! sit in. Each draws from one phase, so its outlet is saturated whatever
+//! arrives at its inlet, and the rest of the loop's charge collects or
+//! drains there. This module holds the pieces they share: the input and
+//! output types, the split of the held charge between the phases, and the
+//! errors raised when a vessel floods or runs dry.
+//!
+//! Evaluating a vessel over a range of charges at fixed operating conditions
+//! shows how much refrigerant the loop can gain or lose before the vessel
+//! stops doing its job, which is the usual question in a charge-sensitivity
+//! study.
+
+use std::error::Error as StdError;
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::f64::{Mass, MassRate, Power, Pressure, Ratio, ThermodynamicTemperature, Volume},
+};
+
+use crate::support::{
+    constraint::{Constrained, Constraint, NonNegative, StrictlyPositive, UnitInterval},
+    thermo::{
+        State,
+        capability::{HasEnthalpy, HasPressure, StateFrom, ThermoModel},
+    },
+};
+
+/// Required thermo model bounds for a charge vessel.
+///
+/// The inlet pressure sets the saturation conditions, and saturated states
+/// are constructed from that pressure and a vapor quality.
+#[doc(hidden)]
+pub trait ChargeVesselThermoModel<Fluid>:
+    ThermoModel<Fluid = Fluid>
+    + HasPressure
+    + HasEnthalpy
+    + StateFrom<(Fluid, Pressure, Constrained<Ratio, UnitInterval>)>
+{
+}
+
+impl<Fluid, T> ChargeVesselThermoModel<Fluid> for T where
+    T: ThermoModel<Fluid = Fluid>
+        + HasPressure
+        + HasEnthalpy
+        + StateFrom<(Fluid, Pressure, Constrained<Ratio, UnitInterval>)>
+{
+}
+
+/// Errors that can occur when creating or evaluating a charge vessel.
+#[derive(Debug, Error)]
+pub enum ChargeVesselError {
+    /// A design parameter is non-physical.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An input value is non-physical.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// The vessel is full of liquid and cannot hold the charge.
+    #[error("charge {charge:?} floods the vessel, which holds at most {capacity:?}")]
+    Flooded {
+        /// Refrigerant charge in the vessel.
+        charge: Mass,
+
+        /// Charge that fills the vessel with saturated liquid.
+        capacity: Mass,
+    },
+
+    /// The charge is less than saturated vapor alone would hold.
+    #[error("charge {charge:?} is below the {minimum:?} that saturated vapor alone holds")]
+    Drained {
+        /// Refrigerant charge in the vessel.
+        charge: Mass,
+
+        /// Charge that fills the vessel with saturated vapor.
+        minimum: Mass,
+    },
+
+    /// A thermodynamic model operation failed.
+    #[error("thermodynamic model failed: {context}")]
+    ThermoModelFailed {
+        /// Operation context for the thermodynamic model failure.
+        context: String,
+
+        /// Underlying thermodynamic model error.
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+}
+
+impl ChargeVesselError {
+    fn thermo(context: &str, source: impl StdError + Send + Sync + 'static) -> Self {
+        Self::ThermoModelFailed {
+            context: context.to_owned(),
+            source: Box::new(source),
+        }
+    }
+}
+
+/// Operating conditions of a charge vessel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChargeVesselInput<Fluid> {
+    /// Refrigerant entering the vessel.
+    ///
+    /// Its pressure is the vessel's saturation pressure.
+    pub inlet: State<Fluid>,
+
+    /// Refrigerant mass flow through the vessel.
+    pub mass_flow: MassRate,
+
+    /// Refrigerant held in the vessel.
+    pub charge: Mass,
+}
+
+/// How the charge held in a vessel splits between the phases.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VesselCharge {
+    /// Mass of saturated liquid.
+    pub liquid_mass: Mass,
+
+    /// Mass of saturated vapor.
+    pub vapor_mass: Mass,
+
+    /// Share of the vessel volume filled with liquid.
+    pub liquid_fraction: Ratio,
+
+    /// Charge the vessel can still take before it fills with liquid.
+    pub headroom: Mass,
+
+    /// Charge the vessel can give up before its liquid is gone.
+    pub reserve: Mass,
+}
+
+/// Outlet conditions and held charge of a charge vessel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChargeVesselOutput<Fluid> {
+    /// Saturated refrigerant leaving the vessel.
+    pub outlet: State<Fluid>,
+
+    /// Saturation pressure in the vessel.
+    pub pressure: Pressure,
+
+    /// Saturation temperature in the vessel.
+    pub saturation_temperature: ThermodynamicTemperature,
+
+    /// Heat into the refrigerant that brings the inlet to the outlet state.
+    ///
+    /// Positive when liquid arriving with the inlet must boil off, and
+    /// negative when vapor must condense or superheat must be given up.
+    pub heat_transfer_rate: Power,
+
+    /// Split of the held charge between liquid and vapor.
+    pub charge: VesselCharge,
+}
+
+/// Phase a vessel draws from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Port {
+    SaturatedLiquid,
+    SaturatedVapor,
+}
+
+/// Checks that a vessel's internal volume is strictly positive and finite.
+pub(super) fn check_volume(volume: Volume) -> Result<(), ChargeVesselError> {
+    if StrictlyPositive::check(&volume).is_err() || !volume.is_finite() {
+        return Err(ChargeVesselError::InvalidParameter(
+            "volume must be positive and finite",
+        ));
+    }
+    Ok(())
+}
+
+/// Evaluates a vessel of `volume` that draws from `port`.
+pub(super) fn evaluate<Fluid, Thermo>(
+    thermo: &Thermo,
+    volume: Volume,
+    port: Port,
+    input: &ChargeVesselInput<Fluid>,
+) -> Result<ChargeVesselOutput<Fluid>, ChargeVesselError>
+where
+    Fluid: Clone,
+    Thermo: ChargeVesselThermoModel<Fluid>,
+{
+    let ChargeVesselInput {
+        inlet,
+        mass_flow,
+        charge,
+    } = input;
+    if NonNegative::check(mass_flow).is_err() || !mass_flow.is_finite() {
+        return Err(ChargeVesselError::InvalidInput(
+            "mass flow must be non-negative and finite",
+        ));
+    }
+    if !charge.is_finite() {
+        return Err(ChargeVesselError::InvalidInput("charge must be finite"));
+    }
+
+    let pressure = thermo
+        .pressure(inlet)
+        .map_err(|error| ChargeVesselError::thermo("inlet pressure", error))?;
+    let saturated = |quality, context| {
+        thermo
+            .state_from((inlet.fluid.clone(), pressure, quality))
+            .map_err(|error| ChargeVesselError::thermo(context, error))
+    };
+    let liquid = saturated(UnitInterval::zero::<Ratio>(), "saturated liquid state")?;
+    let vapor = saturated(UnitInterval::one::<Ratio>(), "saturated vapor state")?;
+
+    let capacity = liquid.density * volume;
+    let minimum = vapor.density * volume;
+    if *charge > capacity {
+        return Err(ChargeVesselError::Flooded {
+            charge: *charge,
+            capacity,
+        });
+    }
+    if *charge < minimum {
+        return Err(ChargeVesselError::Drained {
+            charge: *charge,
+            minimum,
+        });
+    }
+
+    // The liquid share of the volume follows from the mean density.
+    let liquid_fraction = (*charge / volume - vapor.density) / (liquid.density - vapor.density);
+    let liquid_mass = liquid_fraction * liquid.density * volume;
+    let held = VesselCharge {
+        liquid_mass,
+        vapor_mass: *charge - liquid_mass,
+        liquid_fraction,
+        headroom: capacity - *charge,
+        reserve: *charge - minimum,
+    };
+
+    let h_inlet = thermo
+        .enthalpy(inlet)
+        .map_err(|error| ChargeVesselError::thermo("inlet enthalpy", error))?;
+    let outlet = match port {
+        Port::SaturatedLiquid => liquid,
+        Port::SaturatedVapor => vapor,
+    };
+    let h_outlet = thermo
+        .enthalpy(&outlet)
+        .map_err(|error| ChargeVesselError::thermo("outlet enthalpy", error))?;
+    let heat_transfer_rate = if *mass_flow > MassRate::ZERO {
+        *mass_flow * (h_outlet - h_inlet)
+    } else {
+        Power::ZERO
+    };
+
+    Ok(ChargeVesselOutput {
+        saturation_temperature: outlet.temperature,
+        outlet,
+        pressure,
+        heat_transfer_rate,
+        charge: held,
+    })
+}
+
+#[cfg(test)]
+pub(super) mod test_support {
+    use uom::si::{f64::Volume, thermodynamic_temperature::degree_celsius, volume::liter};
+
+    use super::*;
+
+    use crate::models::thermal::hx::discretized::core::test_support::{TestFluid, TestRefrigerant};
+
+    /// Ten liters, a typical vessel for a few kilowatts of capacity.
+    pub(in crate::models::thermal::refrigeration) fn volume() -> Volume {
+        Volume::new::<liter>(10.0)
+    }
+
+    /// Saturated refrigerant at `celsius` with vapor quality `quality`.
+    pub(in crate::models::thermal::refrigeration) fn wet(
+        thermo: &TestRefrigerant,
+        celsius: f64,
+        quality: f64,
+    ) -> State<TestFluid> {
+        use uom::si::ratio::ratio;
+
+        thermo
+            .state_from((
+                TestFluid,
+                ThermodynamicTemperature::new::<degree_celsius>(celsius),
+                UnitInterval::new(Ratio::new::<ratio>(quality)).unwrap(),
+            ))
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        mass::kilogram, mass_rate::kilogram_per_second, ratio::ratio,
+        thermodynamic_temperature::degree_celsius,
+    };
+
+    use crate::models::thermal::hx::discretized::core::test_support::TestRefrigerant;
+
+    use test_support::{volume, wet};
+
+    #[test]
+    fn charge_splits_between_the_phases() {
+        let thermo = TestRefrigerant::ammonia_like();
+        let input = ChargeVesselInput {
+            inlet: wet(&thermo, 40.0, 0.2),
+            mass_flow: MassRate::new::<kilogram_per_second>(0.05),
+            charge: Mass::new::<kilogram>(3.0),
+        };
+
+        let output = evaluate(&thermo, volume(), Port::SaturatedLiquid, &input).unwrap();
+        let held = output.charge;
+
+        assert_relative_eq!(
+            output.saturation_temperature.get::<degree_celsius>(),
+            40.0,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            (held.liquid_mass + held.vapor_mass).get::<kilogram>(),
+            3.0,
+            max_relative = 1e-12
+        );
+        let fraction = held.liquid_fraction.get::<ratio>();
+        assert!(0.0 < fraction && fraction < 1.0, "{fraction}");
+
+        // Headroom and reserve span the whole range between empty and full.
+        let span = (output.outlet.density - wet(&thermo, 40.0, 1.0).density) * volume();
+        assert_relative_eq!(
+            (held.headroom + held.reserve).get::<kilogram>(),
+            span.get::<kilogram>(),
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn rejects_charges_outside_the_vessel_limits() {
+        let thermo = TestRefrigerant::ammonia_like();
+        let input = |kilograms| ChargeVesselInput {
+            inlet: wet(&thermo, 40.0, 0.2),
+            mass_flow: MassRate::new::<kilogram_per_second>(0.05),
+            charge: Mass::new::<kilogram>(kilograms),
+        };
+
+        assert!(matches!(
+            evaluate(&thermo, volume(), Port::SaturatedLiquid, &input(50.0)),
+            Err(ChargeVesselError::Flooded { .. })
+        ));
+        assert!(matches!(
+            evaluate(&thermo, volume(), Port::SaturatedVapor, &input(0.0)),
+            Err(ChargeVesselError::Drained { .. })
+        ));
+        assert!(matches!(
+            evaluate(
+                &thermo,
+                volume(),
+                Port::SaturatedVapor,
+                &ChargeVesselInput {
+                    mass_flow: MassRate::new::<kilogram_per_second>(-1.0),
+                    ..input(3.0)
+                }
+            ),
+            Err(ChargeVesselError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,113 @@
+//! Defrost control for air-source heat pumps.
+//!
+//! A [`DefrostController`] decides when a heat pump stops heating to clear
+//! frost from its outdoor coil, and what that costs. It steps a
+//! [`FrostedCoil`](super::frost::FrostedCoil) forward in time, initiates a
+//! defrost on a fixed schedule or on demand from the coil's degradation,
+//! and meters the defrost energy until the coil is clear.
+//!
+//! The [`DefrostState`] in each output is passed back in as the next
+//! step's input, alongside the updated frost mass.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::refrigeration::{
+//!         defrost::{
+//!             DefrostController, DefrostControllerInput, DefrostControllerParameters,
+//!             DefrostState, DefrostStrategy,
+//!         },
+//!         frost::{FrostedCoil, FrostedCoilInput, FrostedCoilParameters},
+//!     },
+//!     support::psychrometrics::MoistAir,
+//! };
+//! use uom::{
+//!     ConstZero,
+//!     si::{
+//!         area::square_meter,
+//!         energy::kilojoule,
+//!         f64::{
+//!             Area, Energy, HeatCapacity, Length, Mass, MassRate, Power, Pressure, Ratio,
+//!             ThermalConductance, ThermodynamicTemperature, Time,
+//!         },
+//!         heat_capacity::kilojoule_per_kelvin,
+//!         length::millimeter,
+//!         mass_rate::kilogram_per_second,
+//!         power::kilowatt,
+//!         pressure::{kilopascal, pascal},
+//!         ratio::percent,
+//!         thermal_conductance::watt_per_kelvin,
+//!         thermodynamic_temperature::degree_celsius,
+//!         time::minute,
+//!     },
+//! };
+//!
+//! let coil = FrostedCoil::new(FrostedCoilParameters {
+//!     surface_area: Area::new::<square_meter>(20.0),
+//!     fin_gap: Length::new::<millimeter>(2.0),
+//!     clean_ua: ThermalConductance::new::<watt_per_kelvin>(800.0),
+//!     clean_pressure_drop: Pressure::new::<pascal>(40.0),
+//!     rated_air_flow: MassRate::new::<kilogram_per_second>(0.8),
+//!     coil_heat_capacity: HeatCapacity::new::<kilojoule_per_kelvin>(6.0),
+//! })?;
+//!
+//! let controller = DefrostController::new(
+//!     coil,
+//!     DefrostControllerParameters {
+//!         strategy: DefrostStrategy::Timed { interval: Time::new::<minute>(30.0) },
+//!         enable_temperature: ThermodynamicTemperature::new::<degree_celsius>(-1.0),
+//!         termination_temperature: ThermodynamicTemperature::new::<degree_celsius>(10.0),
+//!         defrost_capacity: Power::new::<kilowatt>(5.0),
+//!         max_duration: Time::new::<minute>(10.0),
+//!     },
+//! )?;
+//!
+//! let mut input = DefrostControllerInput {
+//!     state: DefrostState::default(),
+//!     coil: FrostedCoilInput {
+//!         air: MoistAir::from_relative_humidity(
+//!             ThermodynamicTemperature::new::<degree_celsius>(2.0),
+//!             Ratio::new::<percent>(85.0),
+//!             Pressure::new::<kilopascal>(101.325),
+//!         )?,
+//!         air_flow: MassRate::new::<kilogram_per_second>(0.8),
+//!         surface_temperature: ThermodynamicTemperature::new::<degree_celsius>(-8.0),
+//!         frost_mass: Mass::ZERO,
+//!     },
+//!     time_step: Time::new::<minute>(1.0),
+//! };
+//!
+//! // Two hours of operation.
+//! let (mut defrosts, mut penalty) = (0, Energy::ZERO);
+//! for _ in 0..120 {
+//!     let output = controller.step(&input)?;
+//!     defrosts += usize::from(output.initiated);
+//!     penalty += output.defrost_energy;
+//!     input.state = output.state;
+//!     input.coil.frost_mass = output.frost_mass;
+//! }
+//!
+//! assert_eq!(defrosts, 3);
+//! assert!(penalty > Energy::new::<kilojoule>(0.0));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    DefrostController, DefrostControllerError, DefrostControllerInput, DefrostControllerOutput,
+    DefrostControllerParameters, DefrostState, DefrostStrategy,
+};
+
+impl Model for DefrostController {
+    type Input = DefrostControllerInput;
+    type Output = DefrostControllerOutput;
+    type Error = DefrostControllerError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.step(input)
+    }
+}
@@ -0,0 +1,551 @@
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::{
+        f64::{Energy, Mass, Power, Ratio, ThermodynamicTemperature, Time},
+        ratio::ratio,
+    },
+};
+
+use crate::{
+    models::thermal::refrigeration::frost::{
+        FrostedCoil, FrostedCoilError, FrostedCoilInput, FrostedCoilOutput,
+    },
+    support::constraint::{Constraint, StrictlyPositive},
+};
+
+/// When a defrost is initiated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DefrostStrategy {
+    /// Defrost after a fixed heating run time, regardless of frost load.
+    Timed {
+        /// Heating run time between defrosts.
+        interval: Time,
+    },
+
+    /// Defrost once frost has degraded the coil past either threshold.
+    Demand {
+        /// Frosted-to-clean UA ratio at or below which to defrost.
+        min_ua_ratio: Ratio,
+
+        /// Frost blockage of the fin gap at or above which to defrost.
+        max_blockage: Ratio,
+
+        /// Shortest heating run between defrosts.
+        min_interval: Time,
+    },
+}
+
+/// Fixed parameters of a defrost controller.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefrostControllerParameters {
+    /// Initiation strategy.
+    pub strategy: DefrostStrategy,
+
+    /// Coil surface temperature above which a defrost is never initiated.
+    pub enable_temperature: ThermodynamicTemperature,
+
+    /// Coil temperature at which a defrost ends.
+    pub termination_temperature: ThermodynamicTemperature,
+
+    /// Heat delivered to the coil while defrosting, by reverse cycle or
+    /// electric heater.
+    pub defrost_capacity: Power,
+
+    /// Longest a defrost may run before it is ended regardless.
+    pub max_duration: Time,
+}
+
+/// Errors from [`DefrostController`] construction and evaluation.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum DefrostControllerError {
+    /// A parameter or input is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// The frosted coil failed to evaluate.
+    #[error(transparent)]
+    Coil(#[from] FrostedCoilError),
+}
+
+/// Operating mode carried between controller steps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DefrostState {
+    /// The cycle is heating and the coil is collecting frost.
+    Heating {
+        /// Heating run time since the last defrost ended.
+        run_time: Time,
+    },
+
+    /// The cycle is clearing the coil.
+    Defrosting {
+        /// Time since the defrost began.
+        elapsed: Time,
+
+        /// Energy delivered to the coil so far.
+        supplied: Energy,
+
+        /// Energy needed to clear the coil, fixed when the defrost began.
+        required: Energy,
+
+        /// Frost on the coil when the defrost began.
+        initial_frost: Mass,
+    },
+}
+
+impl Default for DefrostState {
+    fn default() -> Self {
+        Self::Heating {
+            run_time: Time::ZERO,
+        }
+    }
+}
+
+/// Input to the defrost controller for one time step.
+#[derive(Debug, Clone, Copy)]
+pub struct DefrostControllerInput {
+    /// Mode at the start of the step.
+    pub state: DefrostState,
+
+    /// Coil conditions at the start of the step, including its frost load.
+    pub coil: FrostedCoilInput,
+
+    /// Step length.
+    pub time_step: Time,
+}
+
+/// Output from the defrost controller for one time step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DefrostControllerOutput {
+    /// Mode at the end of the step.
+    pub state: DefrostState,
+
+    /// Coil performance over the step, or `None` while defrosting, when the
+    /// cycle delivers no heat.
+    pub coil: Option<FrostedCoilOutput>,
+
+    /// Frost on the coil at the end of the step.
+    pub frost_mass: Mass,
+
+    /// Energy delivered to the coil for defrost during the step.
+    pub defrost_energy: Energy,
+
+    /// Whether a defrost began at the end of the step.
+    pub initiated: bool,
+
+    /// Whether a defrost ended during the step.
+    pub terminated: bool,
+}
+
+/// Switches a heat pump between heating and defrost as frost builds up on
+/// its outdoor coil.
+///
+/// The controller steps a [`FrostedCoil`] forward in time. While heating,
+/// frost accumulates at the rate the coil reports and the strategy decides
+/// when to defrost. A defrost needs the energy given by
+/// [`FrostedCoil::defrost_energy`] for the frost load at initiation; it is
+/// delivered at the defrost capacity and ends once that energy is supplied
+/// or the maximum duration elapses. Frost melts in proportion to the energy
+/// supplied, so a defrost cut short leaves some frost behind.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DefrostController {
+    coil: FrostedCoil,
+    parameters: DefrostControllerParameters,
+}
+
+impl DefrostController {
+    /// Creates a defrost controller for `coil`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DefrostControllerError::InvalidParameter`] if an interval,
+    /// the defrost capacity, or the maximum duration is not strictly
+    /// positive, a demand threshold lies outside `[0, 1]`, or the
+    /// termination temperature is below the enable temperature.
+    pub fn new(
+        coil: FrostedCoil,
+        parameters: DefrostControllerParameters,
+    ) -> Result<Self, DefrostControllerError> {
+        match parameters.strategy {
+            DefrostStrategy::Timed { interval } => {
+                if StrictlyPositive::check(&interval).is_err() {
+                    return Err(DefrostControllerError::InvalidParameter(
+                        "defrost interval must be strictly positive",
+                    ));
+                }
+            }
+            DefrostStrategy::Demand {
+                min_ua_ratio,
+                max_blockage,
+                min_interval,
+            } => {
+                let in_unit_interval = |r: Ratio| (0.0..=1.0).contains(&r.get::<ratio>());
+                if !in_unit_interval(min_ua_ratio) || !in_unit_interval(max_blockage) {
+                    return Err(DefrostControllerError::InvalidParameter(
+                        "demand thresholds must be in [0, 1]",
+                    ));
+                }
+                if StrictlyPositive::check(&min_interval).is_err() {
+                    return Err(DefrostControllerError::InvalidParameter(
+                        "minimum defrost interval must be strictly positive",
+                    ));
+                }
+            }
+        }
+        if StrictlyPositive::check(&parameters.defrost_capacity).is_err() {
+            return Err(DefrostControllerError::InvalidParameter(
+                "defrost capacity must be strictly positive",
+            ));
+        }
+        if StrictlyPositive::check(&parameters.max_duration).is_err() {
+            return Err(DefrostControllerError::InvalidParameter(
+                "maximum defrost duration must be strictly positive",
+            ));
+        }
+        if parameters.termination_temperature.is_nan()
+            || parameters.termination_temperature < parameters.enable_temperature
+        {
+            return Err(DefrostControllerError::InvalidParameter(
+                "termination temperature must not be below the enable temperature",
+            ));
+        }
+
+        Ok(Self { coil, parameters })
+    }
+
+    /// Returns the controlled coil.
+    #[must_use]
+    pub fn coil(&self) -> &FrostedCoil {
+        &self.coil
+    }
+
+    /// Returns the controller parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &DefrostControllerParameters {
+        &self.parameters
+    }
+
+    /// Advances the controller and the coil's frost load by one time step.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DefrostControllerError::InvalidParameter`] if the time step
+    /// is not strictly positive, or [`DefrostControllerError::Coil`] if the
+    /// coil fails to evaluate, including when frost blocks it before a
+    /// defrost is called.
+    pub fn step(
+        &self,
+        input: &DefrostControllerInput,
+    ) -> Result<DefrostControllerOutput, DefrostControllerError> {
+        let DefrostControllerInput {
+            state,
+            coil,
+            time_step,
+        } = *input;
+
+        if StrictlyPositive::check(&time_step).is_err() {
+            return Err(DefrostControllerError::InvalidParameter(
+                "time step must be strictly positive",
+            ));
+        }
+
+        match state {
+            DefrostState::Heating { run_time } => self.heat(&coil, run_time, time_step),
+            DefrostState::Defrosting {
+                elapsed,
+                supplied,
+                required,
+                initial_frost,
+            } => Ok(self.defrost(elapsed, supplied, required, initial_frost, time_step)),
+        }
+    }
+
+    /// Runs one heating step and decides whether to defrost.
+    fn heat(
+        &self,
+        input: &FrostedCoilInput,
+        run_time: Time,
+        time_step: Time,
+    ) -> Result<DefrostControllerOutput, DefrostControllerError> {
+        let output = self.coil.evaluate(input)?;
+        let frost_mass = input.frost_mass + output.frost_rate * time_step;
+        let run_time = run_time + time_step;
+
+        let enabled = input.surface_temperature <= self.parameters.enable_temperature;
+        let due = match self.parameters.strategy {
+            DefrostStrategy::Timed { interval } => run_time >= interval,
+            DefrostStrategy::Demand {
+                min_ua_ratio,
+                max_blockage,
+                min_interval,
+            } => {
+                run_time >= min_interval
+                    && (output.ua_ratio <= min_ua_ratio || output.blockage >= max_blockage)
+            }
+        };
+
+        let state = if enabled && due && frost_mass > Mass::ZERO {
+            let required = self
+                .coil
+                .defrost_energy(
+                    frost_mass,
+                    input.surface_temperature,
+                    self.parameters.termination_temperature,
+                )?
+                .total();
+            DefrostState::Defrosting {
+                elapsed: Time::ZERO,
+                supplied: Energy::ZERO,
+                required,
+                initial_frost: frost_mass,
+            }
+        } else {
+            DefrostState::Heating { run_time }
+        };
+
+        Ok(DefrostControllerOutput {
+            state,
+            coil: Some(output),
+            frost_mass,
+            defrost_energy: Energy::ZERO,
+            initiated: matches!(state, DefrostState::Defrosting { .. }),
+            terminated: false,
+        })
+    }
+
+    /// Runs one defrost step and decides whether to end it.
+    fn defrost(
+        &self,
+        elapsed: Time,
+        supplied: Energy,
+        required: Energy,
+        initial_frost: Mass,
+        time_step: Time,
+    ) -> DefrostControllerOutput {
+        let delivered = (self.parameters.defrost_capacity * time_step).min(required - supplied);
+        let supplied = supplied + delivered;
+        let elapsed = elapsed + time_step;
+
+        let melted = if required > Energy::ZERO {
+            (supplied / required).get::<ratio>().min(1.0)
+        } else {
+            1.0
+        };
+        let frost_mass = initial_frost * (1.0 - melted);
+
+        let terminated = supplied >= required || elapsed >= self.parameters.max_duration;
+        let state = if terminated {
+            DefrostState::default()
+        } else {
+            DefrostState::Defrosting {
+                elapsed,
+                supplied,
+                required,
+                initial_frost,
+            }
+        };
+
+        DefrostControllerOutput {
+            state,
+            coil: None,
+            frost_mass,
+            defrost_energy: delivered,
+            initiated: false,
+            terminated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        area::square_meter,
+        energy::kilojoule,
+        f64::{Area, HeatCapacity, Length, MassRate, Pressure, ThermalConductance},
+        heat_capacity::kilojoule_per_kelvin,
+        length::millimeter,
+        mass::kilogram,
+        mass_rate::kilogram_per_second,
+        power::kilowatt,
+        pressure::{kilopascal, pascal},
+        thermal_conductance::watt_per_kelvin,
+        time::minute,
+    };
+
+    use crate::{
+        models::thermal::refrigeration::frost::FrostedCoilParameters,
+        support::{psychrometrics::MoistAir, units::test_utils::celsius},
+    };
+
+    fn minutes(value: f64) -> Time {
+        Time::new::<minute>(value)
+    }
+
+    fn coil() -> FrostedCoil {
+        FrostedCoil::new(FrostedCoilParameters {
+            surface_area: Area::new::<square_meter>(20.0),
+            fin_gap: Length::new::<millimeter>(2.0),
+            clean_ua: ThermalConductance::new::<watt_per_kelvin>(800.0),
+            clean_pressure_drop: Pressure::new::<pascal>(40.0),
+            rated_air_flow: MassRate::new::<kilogram_per_second>(0.8),
+            coil_heat_capacity: HeatCapacity::new::<kilojoule_per_kelvin>(6.0),
+        })
+        .unwrap()
+    }
+
+    fn controller(strategy: DefrostStrategy) -> DefrostController {
+        DefrostController::new(
+            coil(),
+            DefrostControllerParameters {
+                strategy,
+                enable_temperature: celsius(-1.0),
+                termination_temperature: celsius(10.0),
+                defrost_capacity: Power::new::<kilowatt>(5.0),
+                max_duration: minutes(10.0),
+            },
+        )
+        .unwrap()
+    }
+
+    fn coil_input(surface: f64, frost_kg: f64) -> FrostedCoilInput {
+        FrostedCoilInput {
+            air: MoistAir::from_relative_humidity(
+                celsius(2.0),
+                Ratio::new::<ratio>(0.85),
+                Pressure::new::<kilopascal>(101.325),
+            )
+            .unwrap(),
+            air_flow: MassRate::new::<kilogram_per_second>(0.8),
+            surface_temperature: celsius(surface),
+            frost_mass: Mass::new::<kilogram>(frost_kg),
+        }
+    }
+
+    /// Steps the controller until `max_steps`, returning every output.
+    fn run(
+        controller: &DefrostController,
+        mut coil: FrostedCoilInput,
+        max_steps: usize,
+    ) -> Vec<DefrostControllerOutput> {
+        let mut state = DefrostState::default();
+        (0..max_steps)
+            .map(|_| {
+                let out = controller
+                    .step(&DefrostControllerInput {
+                        state,
+                        coil,
+                        time_step: minutes(1.0),
+                    })
+                    .unwrap();
+                state = out.state;
+                coil.frost_mass = out.frost_mass;
+                out
+            })
+            .collect()
+    }
+
+    #[test]
+    fn timed_defrost_cycles_on_schedule() {
+        let timed = controller(DefrostStrategy::Timed {
+            interval: minutes(15.0),
+        });
+        let history = run(&timed, coil_input(-8.0, 0.0), 60);
+
+        let initiations: Vec<_> = history
+            .iter()
+            .enumerate()
+            .filter(|(_, out)| out.initiated)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(initiations[0], 14);
+        assert!(initiations.len() >= 2);
+
+        // The coil is cleared and the full defrost energy is delivered.
+        let first_end = history.iter().position(|out| out.terminated).unwrap();
+        assert_relative_eq!(history[first_end].frost_mass.get::<kilogram>(), 0.0);
+        let DefrostState::Defrosting { required, .. } = history[initiations[0]].state else {
+            panic!("expected a defrost to begin");
+        };
+        let delivered: f64 = history[..=first_end]
+            .iter()
+            .map(|out| out.defrost_energy.get::<kilojoule>())
+            .sum();
+        assert_relative_eq!(delivered, required.get::<kilojoule>(), max_relative = 1e-12);
+        assert!(history[initiations[0] + 1].coil.is_none());
+    }
+
+    #[test]
+    fn demand_defrost_waits_for_degradation() {
+        let demand = controller(DefrostStrategy::Demand {
+            min_ua_ratio: Ratio::new::<ratio>(0.8),
+            max_blockage: Ratio::new::<ratio>(0.5),
+            min_interval: minutes(5.0),
+        });
+        let history = run(&demand, coil_input(-8.0, 0.0), 60);
+
+        let first = history.iter().position(|out| out.initiated).unwrap();
+        let trigger = history[first].coil.unwrap();
+        assert!(trigger.ua_ratio.get::<ratio>() <= 0.8 || trigger.blockage.get::<ratio>() >= 0.5);
+
+        let before = history[first - 1].coil.unwrap();
+        assert!(before.ua_ratio.get::<ratio>() > 0.8 && before.blockage.get::<ratio>() < 0.5);
+    }
+
+    #[test]
+    fn no_defrost_above_enable_temperature() {
+        let timed = controller(DefrostStrategy::Timed {
+            interval: minutes(1.0),
+        });
+        let history = run(&timed, coil_input(0.0, 0.0), 10);
+        assert!(history.iter().all(|out| !out.initiated));
+    }
+
+    #[test]
+    fn max_duration_leaves_residual_frost() {
+        let weak = DefrostController::new(
+            coil(),
+            DefrostControllerParameters {
+                defrost_capacity: Power::new::<kilowatt>(0.5),
+                ..*controller(DefrostStrategy::Timed {
+                    interval: minutes(1.0),
+                })
+                .parameters()
+            },
+        )
+        .unwrap();
+        let history = run(&weak, coil_input(-8.0, 1.0), 12);
+
+        assert!(history[0].initiated);
+        let end = history.iter().position(|out| out.terminated).unwrap();
+        assert_eq!(end, 10);
+        assert!(history[end].frost_mass > Mass::ZERO);
+        assert_eq!(history[end].state, DefrostState::default());
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        let result = DefrostController::new(
+            coil(),
+            DefrostControllerParameters {
+                strategy: DefrostStrategy::Demand {
+                    min_ua_ratio: Ratio::new::<ratio>(1.5),
+                    max_blockage: Ratio::new::<ratio>(0.5),
+                    min_interval: minutes(5.0),
+                },
+                ..*controller(DefrostStrategy::Timed {
+                    interval: minutes(1.0),
+                })
+                .parameters()
+            },
+        );
+        assert!(matches!(
+            result,
+            Err(DefrostControllerError::InvalidParameter(_))
+        ));
+    }
+}
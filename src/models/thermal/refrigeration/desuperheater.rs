@@ -0,0 +1,37 @@
+//! Desuperheater for heat-pump water heating.
+//!
+//! A [`Desuperheater`] recovers the superheat of compressor discharge gas
+//! into a water stream, with the refrigerant held above its dew point so
+//! the condenser downstream still does all of the condensing.
+//!
+//! The refrigerant thermo model needs to construct saturated states from a
+//! pressure and vapor quality, such as `CoolProp`. The water side
+//! uses any model that works with the discretized heat exchangers.
+
+mod core;
+
+use twine_core::Model;
+
+use crate::models::thermal::hx::discretized::core::DiscretizedHxThermoModel;
+
+pub use core::{
+    Desuperheater, DesuperheaterConfig, DesuperheaterError, DesuperheaterInput,
+    DesuperheaterOutput, DesuperheaterParameters, DesuperheaterThermoModel,
+};
+
+impl<Refrigerant, Water, RefrigerantThermo, WaterThermo> Model
+    for Desuperheater<Refrigerant, Water, RefrigerantThermo, WaterThermo>
+where
+    Refrigerant: Clone,
+    Water: Clone,
+    RefrigerantThermo: DesuperheaterThermoModel<Refrigerant>,
+    WaterThermo: DiscretizedHxThermoModel<Water>,
+{
+    type Input = DesuperheaterInput<Refrigerant, Water>;
+    type Output = DesuperheaterOutput<Refrigerant, Water>;
+    type Error = DesuperheaterError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
@@ -0,0 +1,619 @@
+mod problem;
+
+use std::{error::Error as StdError, marker::PhantomData};
+
+use thiserror::Error;
+use twine_core::Model;
+use twine_solvers::equation::{
+    bisection,
+    bracket::{Bracket, Sign},
+};
+use uom::{
+    ConstZero,
+    si::f64::{MassRate, Power, Pressure, Ratio, ThermalConductance, ThermodynamicTemperature},
+};
+
+use crate::{
+    models::thermal::hx::discretized::core::{DiscretizedHxThermoModel, MinDeltaT},
+    support::{
+        constraint::{Constrained, Constraint, StrictlyPositive, UnitInterval},
+        thermo::{State, capability::StateFrom},
+        units::TemperatureDifference,
+    },
+};
+
+use problem::{RecoveryModel, RecoveryProblem};
+
+/// Number of nodes in the exchanger discretization (20 segments).
+const NODES: usize = 21;
+
+/// Required thermo model bounds for the refrigerant side of a
+/// [`Desuperheater`].
+///
+/// Adds saturated state construction from pressure to the discretized heat
+/// exchanger bounds, which locates the dew point at the discharge pressure.
+#[doc(hidden)]
+pub trait DesuperheaterThermoModel<Fluid>:
+    DiscretizedHxThermoModel<Fluid> + StateFrom<(Fluid, Pressure, Constrained<Ratio, UnitInterval>)>
+{
+}
+
+impl<Fluid, T> DesuperheaterThermoModel<Fluid> for T where
+    T: DiscretizedHxThermoModel<Fluid>
+        + StateFrom<(Fluid, Pressure, Constrained<Ratio, UnitInterval>)>
+{
+}
+
+/// Boxed error stored as the source of a [`DesuperheaterError`].
+type BoxError = Box<dyn StdError + Send + Sync>;
+
+/// Design parameters of a [`Desuperheater`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DesuperheaterParameters {
+    /// Conductance of the refrigerant-to-water exchanger.
+    pub ua: ThermalConductance,
+}
+
+/// Solver configuration for [`Desuperheater`].
+#[derive(Debug, Clone, Copy)]
+pub struct DesuperheaterConfig {
+    /// Relative tolerance on the exchanger conductance.
+    pub ua_rel_tol: f64,
+
+    /// Maximum number of heat recovery iterations.
+    pub max_iters: usize,
+}
+
+impl Default for DesuperheaterConfig {
+    fn default() -> Self {
+        Self {
+            ua_rel_tol: 1e-6,
+            max_iters: 100,
+        }
+    }
+}
+
+/// Operating conditions of a [`Desuperheater`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DesuperheaterInput<Refrigerant, Water> {
+    /// Superheated vapor leaving the compressor.
+    pub refrigerant: State<Refrigerant>,
+
+    /// Refrigerant mass flow.
+    pub refrigerant_flow: MassRate,
+
+    /// Water entering the exchanger.
+    pub water: State<Water>,
+
+    /// Water mass flow.
+    pub water_flow: MassRate,
+}
+
+/// Result of a [`Desuperheater`] evaluation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DesuperheaterOutput<Refrigerant, Water> {
+    /// Refrigerant leaving toward the condenser.
+    pub refrigerant: State<Refrigerant>,
+
+    /// Water leaving the exchanger.
+    pub water: State<Water>,
+
+    /// Heat moved from the refrigerant into the water.
+    pub heat_recovered: Power,
+
+    /// Heat released by cooling the refrigerant to its dew point.
+    pub available_superheat: Power,
+
+    /// Whether the recovery stopped at the dew point.
+    ///
+    /// When set, the exchanger could transfer more heat than the superheat
+    /// and the refrigerant leaves as saturated vapor.
+    pub superheat_limited: bool,
+
+    /// Conductance used by the transfer.
+    ///
+    /// Equals the design conductance unless the recovery is superheat
+    /// limited, in which case part of the surface is not needed.
+    pub ua: ThermalConductance,
+
+    /// Minimum refrigerant-to-water temperature difference and its node.
+    pub min_delta_t: MinDeltaT,
+
+    /// Number of heat recovery iterations.
+    pub iterations: usize,
+}
+
+/// Errors from [`Desuperheater`].
+#[derive(Debug, Error)]
+pub enum DesuperheaterError {
+    /// A design parameter or solver setting is non-physical.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating condition is non-physical.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// The refrigerant enters at or below its dew point.
+    #[error("refrigerant enters at {inlet:?}, not above its dew point {dew_point:?}")]
+    NotSuperheated {
+        /// Refrigerant inlet temperature.
+        inlet: ThermodynamicTemperature,
+
+        /// Saturation temperature at the refrigerant pressure.
+        dew_point: ThermodynamicTemperature,
+    },
+
+    /// The exchanger temperatures cross for a candidate heat recovery.
+    #[error("refrigerant and water temperatures cross")]
+    Pinch,
+
+    /// The exchanger could not be evaluated.
+    #[error("desuperheater exchanger failed")]
+    Exchanger(#[source] BoxError),
+
+    /// A thermodynamic model operation failed.
+    #[error("thermodynamic model failed: {context}")]
+    ThermoModelFailed {
+        /// Operation context for the thermodynamic model failure.
+        context: String,
+
+        /// Underlying thermodynamic model error.
+        #[source]
+        source: BoxError,
+    },
+
+    /// The heat recovery search did not converge.
+    #[error("heat recovery did not converge: {0}")]
+    Convergence(String),
+}
+
+impl DesuperheaterError {
+    fn thermo(context: &str, source: impl StdError + Send + Sync + 'static) -> Self {
+        Self::ThermoModelFailed {
+            context: context.to_owned(),
+            source: Box::new(source),
+        }
+    }
+}
+
+/// A refrigerant desuperheater heating a water stream.
+///
+/// Hot discharge gas from a compressor gives heat to water in a discretized
+/// counterflow exchanger before it reaches the condenser, the usual way a
+/// heat pump or air conditioner supplies domestic hot water alongside its
+/// main duty. Only superheat is recovered: the refrigerant never cools below
+/// its dew point at the discharge pressure, so all condensation stays in the
+/// downstream condenser.
+///
+/// The recovered heat is found by bisection so the exchanger needs exactly
+/// the design conductance. When the design conductance could cool the
+/// refrigerant past its dew point, the recovery stops at the available
+/// superheat instead and the output is flagged as superheat limited.
+///
+/// Pressure drops and heat leaks are neglected.
+#[derive(Debug, Clone)]
+pub struct Desuperheater<Refrigerant, Water, RefrigerantThermo, WaterThermo> {
+    parameters: DesuperheaterParameters,
+    config: DesuperheaterConfig,
+    refrigerant_thermo: RefrigerantThermo,
+    water_thermo: WaterThermo,
+    _fluids: PhantomData<(Refrigerant, Water)>,
+}
+
+impl<Refrigerant, Water, RefrigerantThermo, WaterThermo>
+    Desuperheater<Refrigerant, Water, RefrigerantThermo, WaterThermo>
+{
+    /// Creates a desuperheater.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DesuperheaterError::InvalidParameter`] if the conductance is
+    /// negative or not finite, or the solver configuration is unusable.
+    pub fn new(
+        parameters: DesuperheaterParameters,
+        config: DesuperheaterConfig,
+        refrigerant_thermo: RefrigerantThermo,
+        water_thermo: WaterThermo,
+    ) -> Result<Self, DesuperheaterError> {
+        if !(parameters.ua >= ThermalConductance::ZERO && parameters.ua.is_finite()) {
+            return Err(DesuperheaterError::InvalidParameter(
+                "UA must be non-negative and finite",
+            ));
+        }
+        if !(config.ua_rel_tol.is_finite() && config.ua_rel_tol > 0.0) || config.max_iters == 0 {
+            return Err(DesuperheaterError::InvalidParameter(
+                "UA tolerance must be positive and iterations nonzero",
+            ));
+        }
+
+        Ok(Self {
+            parameters,
+            config,
+            refrigerant_thermo,
+            water_thermo,
+            _fluids: PhantomData,
+        })
+    }
+
+    /// Returns the design parameters.
+    pub fn parameters(&self) -> &DesuperheaterParameters {
+        &self.parameters
+    }
+}
+
+fn check_flows<Refrigerant, Water>(
+    input: &DesuperheaterInput<Refrigerant, Water>,
+) -> Result<(), DesuperheaterError> {
+    for flow in [input.refrigerant_flow, input.water_flow] {
+        if StrictlyPositive::check(&flow).is_err() || !flow.is_finite() {
+            return Err(DesuperheaterError::InvalidInput(
+                "mass flows must be strictly positive and finite",
+            ));
+        }
+    }
+    Ok(())
+}
+
+impl<Refrigerant, Water, RefrigerantThermo, WaterThermo>
+    Desuperheater<Refrigerant, Water, RefrigerantThermo, WaterThermo>
+where
+    Refrigerant: Clone,
+    Water: Clone,
+    RefrigerantThermo: DesuperheaterThermoModel<Refrigerant>,
+    WaterThermo: DiscretizedHxThermoModel<Water>,
+{
+    /// Evaluates the outlet states.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DesuperheaterError`] if a flow is not strictly positive,
+    /// the refrigerant is not superheated, a property evaluation fails, or
+    /// the search fails to converge.
+    pub fn evaluate(
+        &self,
+        input: &DesuperheaterInput<Refrigerant, Water>,
+    ) -> Result<DesuperheaterOutput<Refrigerant, Water>, DesuperheaterError> {
+        check_flows(input)?;
+        let thermo = &self.refrigerant_thermo;
+        let available_superheat = self.available_superheat(input)?;
+
+        let no_recovery = || DesuperheaterOutput {
+            refrigerant: input.refrigerant.clone(),
+            water: input.water.clone(),
+            heat_recovered: Power::ZERO,
+            available_superheat,
+            superheat_limited: false,
+            ua: ThermalConductance::ZERO,
+            min_delta_t: MinDeltaT {
+                value: input.refrigerant.temperature.minus(input.water.temperature),
+                node: 0,
+            },
+            iterations: 0,
+        };
+        if self.parameters.ua == ThermalConductance::ZERO
+            || input.water.temperature >= input.refrigerant.temperature
+        {
+            return Ok(no_recovery());
+        }
+
+        let model = RecoveryModel {
+            input,
+            available_superheat,
+            refrigerant_thermo: thermo,
+            water_thermo: &self.water_thermo,
+        };
+        let problem = RecoveryProblem::new(self.parameters.ua);
+
+        // Recovering all of the superheat takes the most surface. If the
+        // design has that much, the recovery is capped at the dew point.
+        match model.call(&1.0) {
+            Ok(full) if full.ua <= self.parameters.ua => {
+                return Ok(DesuperheaterOutput {
+                    refrigerant: full.top[NODES - 1].clone(),
+                    water: full.bottom[0].clone(),
+                    heat_recovered: available_superheat,
+                    available_superheat,
+                    superheat_limited: true,
+                    ua: full.ua,
+                    min_delta_t: full.min_delta_t,
+                    iterations: 0,
+                });
+            }
+            Ok(_) | Err(DesuperheaterError::Pinch) => {}
+            Err(error) => return Err(error),
+        }
+
+        let bracket = Bracket::new((0.0, Sign::Negative), (1.0, Sign::Positive))
+            .map_err(|error| DesuperheaterError::Convergence(error.to_string()))?;
+        let config = bisection::Config {
+            max_iters: self.config.max_iters,
+            x_abs_tol: 0.0,
+            x_rel_tol: 0.0,
+            residual_tol: self.config.ua_rel_tol,
+        };
+        let solution = bisection::solve_from_bracket(
+            &model,
+            &problem,
+            bracket,
+            &config,
+            |event: &bisection::Event<'_, _, _>| {
+                #[cfg(feature = "tracing")]
+                crate::support::numerics::solve::trace_bisection(event);
+
+                // Crossing temperatures mean more heat than any conductance
+                // can move.
+                if matches!(
+                    event,
+                    bisection::Event::ModelFailed {
+                        error: DesuperheaterError::Pinch,
+                        ..
+                    }
+                ) {
+                    return Some(bisection::Action::assume_positive());
+                }
+                None
+            },
+        )
+        .map_err(|error| match error {
+            bisection::Error::Model(source) => match source.downcast::<DesuperheaterError>() {
+                Ok(error) => *error,
+                Err(source) => DesuperheaterError::Convergence(source.to_string()),
+            },
+            error => DesuperheaterError::Convergence(error.to_string()),
+        })?;
+
+        if solution.status != bisection::Status::Converged {
+            return Err(DesuperheaterError::Convergence(
+                "iteration limit reached".to_owned(),
+            ));
+        }
+
+        let hx = solution.snapshot.output;
+        Ok(DesuperheaterOutput {
+            refrigerant: hx.top[NODES - 1].clone(),
+            water: hx.bottom[0].clone(),
+            heat_recovered: solution.snapshot.input * available_superheat,
+            available_superheat,
+            superheat_limited: false,
+            ua: hx.ua,
+            min_delta_t: hx.min_delta_t,
+            iterations: solution.iters,
+        })
+    }
+
+    /// Returns the heat released cooling the refrigerant to its dew point.
+    fn available_superheat(
+        &self,
+        input: &DesuperheaterInput<Refrigerant, Water>,
+    ) -> Result<Power, DesuperheaterError> {
+        let thermo = &self.refrigerant_thermo;
+        let pressure = thermo
+            .pressure(&input.refrigerant)
+            .map_err(|error| DesuperheaterError::thermo("discharge pressure", error))?;
+        let dew = thermo
+            .state_from((
+                input.refrigerant.fluid.clone(),
+                pressure,
+                UnitInterval::one::<Ratio>(),
+            ))
+            .map_err(|error| DesuperheaterError::thermo("dew point", error))?;
+        if input.refrigerant.temperature <= dew.temperature {
+            return Err(DesuperheaterError::NotSuperheated {
+                inlet: input.refrigerant.temperature,
+                dew_point: dew.temperature,
+            });
+        }
+        let h_inlet = thermo
+            .enthalpy(&input.refrigerant)
+            .map_err(|error| DesuperheaterError::thermo("discharge enthalpy", error))?;
+        let h_dew = thermo
+            .enthalpy(&dew)
+            .map_err(|error| DesuperheaterError::thermo("dew point enthalpy", error))?;
+        Ok(input.refrigerant_flow * (h_inlet - h_dew))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::TemperatureInterval, mass_rate::kilogram_per_second, power::kilowatt,
+        thermal_conductance::kilowatt_per_kelvin, thermodynamic_temperature::degree_celsius,
+    };
+
+    use crate::{
+        models::thermal::hx::discretized::core::test_support::{
+            TestFluid, TestRefrigerant, TestThermoModel, state,
+        },
+        support::thermo::capability::{HasEnthalpy, HasPressure},
+    };
+
+    type TestDesuperheater = Desuperheater<TestFluid, TestFluid, TestRefrigerant, TestThermoModel>;
+
+    fn desuperheater(ua: f64) -> TestDesuperheater {
+        Desuperheater::new(
+            DesuperheaterParameters {
+                ua: ThermalConductance::new::<kilowatt_per_kelvin>(ua),
+            },
+            DesuperheaterConfig::default(),
+            TestRefrigerant::ammonia_like(),
+            TestThermoModel::new(),
+        )
+        .unwrap()
+    }
+
+    /// Ammonia-like discharge at 90 °C and 40 °C condensing, water at 15 °C.
+    fn input(discharge: f64) -> DesuperheaterInput<TestFluid, TestFluid> {
+        let thermo = TestRefrigerant::ammonia_like();
+        let saturated = thermo
+            .state_from((
+                TestFluid,
+                ThermodynamicTemperature::new::<degree_celsius>(40.0),
+                UnitInterval::one::<Ratio>(),
+            ))
+            .unwrap();
+        let pressure = thermo.pressure(&saturated).unwrap();
+        DesuperheaterInput {
+            refrigerant: thermo
+                .state_from((
+                    TestFluid,
+                    ThermodynamicTemperature::new::<degree_celsius>(discharge),
+                    pressure,
+                ))
+                .unwrap(),
+            refrigerant_flow: MassRate::new::<kilogram_per_second>(0.1),
+            water: state(288.15),
+            water_flow: MassRate::new::<kilogram_per_second>(0.2),
+        }
+    }
+
+    #[test]
+    fn recovers_part_of_the_superheat() {
+        let input = input(90.0);
+        let output = desuperheater(0.1).evaluate(&input).unwrap();
+        let kw = |power: Power| power.get::<kilowatt>();
+
+        // 0.1 kg/s × 2.2 kJ/(kg·K) × 50 K of superheat.
+        assert_relative_eq!(kw(output.available_superheat), 11.0, max_relative = 1e-9);
+        assert!(!output.superheat_limited);
+        assert!(output.heat_recovered > Power::ZERO);
+        assert!(output.heat_recovered < output.available_superheat);
+        assert_relative_eq!(
+            output.ua.get::<kilowatt_per_kelvin>(),
+            0.1,
+            max_relative = 1e-5
+        );
+        assert!(
+            output.refrigerant.temperature > ThermodynamicTemperature::new::<degree_celsius>(40.0)
+        );
+
+        let water = TestThermoModel::new();
+        let gained = input.water_flow
+            * (water.enthalpy(&output.water).unwrap() - water.enthalpy(&input.water).unwrap());
+        assert_relative_eq!(kw(gained), kw(output.heat_recovered), max_relative = 1e-9);
+    }
+
+    #[test]
+    fn large_exchanger_stops_at_the_dew_point() {
+        let output = desuperheater(10.0).evaluate(&input(90.0)).unwrap();
+
+        assert!(output.superheat_limited);
+        assert_eq!(output.heat_recovered, output.available_superheat);
+        assert!(output.ua < ThermalConductance::new::<kilowatt_per_kelvin>(10.0));
+        assert_relative_eq!(
+            output.refrigerant.temperature.get::<degree_celsius>(),
+            40.0,
+            max_relative = 1e-9
+        );
+        assert!(output.min_delta_t.value > TemperatureInterval::ZERO);
+    }
+
+    #[test]
+    fn more_surface_recovers_more_heat() {
+        let small = desuperheater(0.05).evaluate(&input(90.0)).unwrap();
+        let large = desuperheater(0.2).evaluate(&input(90.0)).unwrap();
+
+        assert!(large.heat_recovered > small.heat_recovered);
+        assert!(large.water.temperature > small.water.temperature);
+    }
+
+    #[test]
+    fn rejects_saturated_discharge_and_invalid_settings() {
+        assert!(matches!(
+            desuperheater(0.1).evaluate(&input(40.0)),
+            Err(DesuperheaterError::NotSuperheated { .. })
+        ));
+        let stopped = DesuperheaterInput {
+            water_flow: MassRate::ZERO,
+            ..input(90.0)
+        };
+        assert!(matches!(
+            desuperheater(0.1).evaluate(&stopped),
+            Err(DesuperheaterError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            Desuperheater::<TestFluid, TestFluid, _, _>::new(
+                DesuperheaterParameters {
+                    ua: ThermalConductance::new::<kilowatt_per_kelvin>(-1.0),
+                },
+                DesuperheaterConfig::default(),
+                TestRefrigerant::ammonia_like(),
+                TestThermoModel::new(),
+            ),
+            Err(DesuperheaterError::InvalidParameter(_))
+        ));
+
+        let none = desuperheater(0.0).evaluate(&input(90.0)).unwrap();
+        assert_eq!(none.heat_recovered, Power::ZERO);
+        assert_eq!(none.water, input(90.0).water);
+    }
+
+    #[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
+    mod coolprop_tests {
+        use super::*;
+
+        use uom::si::pressure::kilopascal;
+
+        use crate::support::thermo::{
+            fluid::{Ammonia, Water},
+            model::CoolProp,
+        };
+
+        #[test]
+        fn ammonia_discharge_heats_water() {
+            let ammonia = CoolProp::<Ammonia>::new().unwrap();
+            let water = CoolProp::<Water>::new().unwrap();
+            let input = DesuperheaterInput {
+                // About 40 °C condensing.
+                refrigerant: ammonia
+                    .state_from((
+                        Ammonia,
+                        ThermodynamicTemperature::new::<degree_celsius>(110.0),
+                        Pressure::new::<kilopascal>(1555.0),
+                    ))
+                    .unwrap(),
+                refrigerant_flow: MassRate::new::<kilogram_per_second>(0.05),
+                water: water
+                    .state_from((
+                        Water,
+                        ThermodynamicTemperature::new::<degree_celsius>(15.0),
+                        Pressure::new::<kilopascal>(300.0),
+                    ))
+                    .unwrap(),
+                water_flow: MassRate::new::<kilogram_per_second>(0.05),
+            };
+            let solve = |ua| {
+                Desuperheater::new(
+                    DesuperheaterParameters {
+                        ua: ThermalConductance::new::<kilowatt_per_kelvin>(ua),
+                    },
+                    DesuperheaterConfig::default(),
+                    &ammonia,
+                    &water,
+                )
+                .unwrap()
+                .evaluate(&input)
+                .unwrap()
+            };
+
+            let partial = solve(0.05);
+            assert!(!partial.superheat_limited);
+            assert!(partial.heat_recovered < partial.available_superheat);
+            assert!(
+                partial.refrigerant.temperature
+                    > ThermodynamicTemperature::new::<degree_celsius>(40.0)
+            );
+
+            let full = solve(5.0);
+            assert!(full.superheat_limited);
+            assert_relative_eq!(
+                full.refrigerant.temperature.get::<degree_celsius>(),
+                40.0,
+                epsilon = 0.2
+            );
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Problem formulation for the heat recovery search.
+
+use std::{convert::Infallible, marker::PhantomData};
+
+use twine_core::{EquationProblem, Model};
+use uom::si::f64::{Power, ThermalConductance};
+
+use crate::{
+    models::thermal::hx::discretized::core::{
+        DiscretizedHx, DiscretizedHxThermoModel, Given, HeatTransferRate, Inlets, Known, MassFlows,
+        PressureDrops, Results, SolveError,
+    },
+    support::hx::arrangement::CounterFlow,
+};
+
+use super::{DesuperheaterError, DesuperheaterInput, DesuperheaterThermoModel, NODES};
+
+/// The exchanger at a candidate fraction of the available superheat.
+///
+/// Refrigerant flows along the top and water along the bottom in
+/// counterflow. Each candidate asks the discretized exchanger what
+/// conductance the transfer requires.
+pub(super) struct RecoveryModel<'a, Refrigerant, Water, RefrigerantThermo, WaterThermo> {
+    pub(super) input: &'a DesuperheaterInput<Refrigerant, Water>,
+    pub(super) available_superheat: Power,
+    pub(super) refrigerant_thermo: &'a RefrigerantThermo,
+    pub(super) water_thermo: &'a WaterThermo,
+}
+
+impl<Refrigerant, Water, RefrigerantThermo, WaterThermo> Model
+    for RecoveryModel<'_, Refrigerant, Water, RefrigerantThermo, WaterThermo>
+where
+    Refrigerant: Clone,
+    Water: Clone,
+    RefrigerantThermo: DesuperheaterThermoModel<Refrigerant>,
+    WaterThermo: DiscretizedHxThermoModel<Water>,
+{
+    type Input = f64;
+    type Output = Results<Refrigerant, Water, NODES>;
+    type Error = DesuperheaterError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        let known = Known {
+            inlets: Inlets {
+                top: self.input.refrigerant.clone(),
+                bottom: self.input.water.clone(),
+            },
+            m_dot: MassFlows::new(self.input.refrigerant_flow, self.input.water_flow)
+                .map_err(|error| DesuperheaterError::Exchanger(Box::new(error)))?,
+            dp: PressureDrops::default(),
+        };
+        let q_dot = HeatTransferRate::top_to_bottom(*input * self.available_superheat)
+            .map_err(|error| DesuperheaterError::Exchanger(Box::new(error)))?;
+
+        DiscretizedHx::<CounterFlow, NODES>::solve(
+            &known,
+            Given::HeatTransferRate(q_dot),
+            self.refrigerant_thermo,
+            self.water_thermo,
+        )
+        .map_err(|error| match error {
+            SolveError::SecondLawViolation { .. } => DesuperheaterError::Pinch,
+            SolveError::ThermoModelFailed { .. } => DesuperheaterError::Exchanger(error.into()),
+        })
+    }
+}
+
+/// Residual is `ln(UA_required / UA)`.
+///
+/// The logarithm keeps the residual scaled to the conductance ratio as the
+/// required conductance grows without bound near the pinch.
+pub(super) struct RecoveryProblem<Refrigerant, Water> {
+    ua: ThermalConductance,
+    _fluids: PhantomData<(Refrigerant, Water)>,
+}
+
+impl<Refrigerant, Water> RecoveryProblem<Refrigerant, Water> {
+    pub(super) fn new(ua: ThermalConductance) -> Self {
+        Self {
+            ua,
+            _fluids: PhantomData,
+        }
+    }
+}
+
+impl<Refrigerant, Water> EquationProblem<1> for RecoveryProblem<Refrigerant, Water> {
+    type Input = f64;
+    type Output = Results<Refrigerant, Water, NODES>;
+    type Error = Infallible;
+
+    fn input(&self, x: &[f64; 1]) -> Result<Self::Input, Self::Error> {
+        Ok(x[0])
+    }
+
+    fn residuals(
+        &self,
+        _input: &Self::Input,
+        output: &Self::Output,
+    ) -> Result<[f64; 1], Self::Error> {
+        Ok([(output.ua / self.ua).value.ln()])
+    }
+}
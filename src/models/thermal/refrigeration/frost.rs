@@ -0,0 +1,265 @@
+//! Frost formation on air-source evaporator coils.
+//!
+//! A [`FrostedCoil`] tracks how frost builds up on an evaporator running
+//! below freezing and what it costs: the frost layer insulates the coil,
+//! narrows the fin passages so the air-side pressure drop climbs, and has
+//! to be melted off again in a defrost. The accumulated frost mass is the
+//! integrable state.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::refrigeration::frost::{
+//!         FrostedCoil, FrostedCoilInput, FrostedCoilParameters,
+//!     },
+//!     support::psychrometrics::MoistAir,
+//! };
+//! use uom::{
+//!     ConstZero,
+//!     si::{
+//!         area::square_meter,
+//!         f64::{
+//!             Area, HeatCapacity, Length, Mass, MassRate, Pressure, Ratio,
+//!             ThermalConductance, ThermodynamicTemperature,
+//!         },
+//!         heat_capacity::kilojoule_per_kelvin,
+//!         length::millimeter,
+//!         mass::kilogram,
+//!         mass_rate::kilogram_per_second,
+//!         pressure::{kilopascal, pascal},
+//!         ratio::percent,
+//!         thermal_conductance::watt_per_kelvin,
+//!         thermodynamic_temperature::degree_celsius,
+//!     },
+//! };
+//!
+//! let coil = FrostedCoil::new(FrostedCoilParameters {
+//!     surface_area: Area::new::<square_meter>(20.0),
+//!     fin_gap: Length::new::<millimeter>(2.0),
+//!     clean_ua: ThermalConductance::new::<watt_per_kelvin>(800.0),
+//!     clean_pressure_drop: Pressure::new::<pascal>(40.0),
+//!     rated_air_flow: MassRate::new::<kilogram_per_second>(0.8),
+//!     coil_heat_capacity: HeatCapacity::new::<kilojoule_per_kelvin>(6.0),
+//! })?;
+//!
+//! let output = coil.evaluate(&FrostedCoilInput {
+//!     air: MoistAir::from_relative_humidity(
+//!         ThermodynamicTemperature::new::<degree_celsius>(2.0),
+//!         Ratio::new::<percent>(85.0),
+//!         Pressure::new::<kilopascal>(101.325),
+//!     )?,
+//!     air_flow: MassRate::new::<kilogram_per_second>(0.8),
+//!     surface_temperature: ThermodynamicTemperature::new::<degree_celsius>(-8.0),
+//!     frost_mass: Mass::new::<kilogram>(1.0),
+//! })?;
+//!
+//! assert!(output.frost_rate > MassRate::ZERO);
+//! assert!(output.ua < ThermalConductance::new::<watt_per_kelvin>(800.0));
+//! assert!(output.pressure_drop > Pressure::new::<pascal>(40.0));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::{DerivativeOf, Model, OdeProblem, StepIntegrable};
+use uom::si::f64::{Mass, MassRate, Time};
+
+pub use core::{
+    DefrostEnergy, FrostedCoil, FrostedCoilError, FrostedCoilInput, FrostedCoilOutput,
+    FrostedCoilParameters,
+};
+
+impl Model for FrostedCoil {
+    type Input = FrostedCoilInput;
+    type Output = FrostedCoilOutput;
+    type Error = FrostedCoilError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
+
+/// Accumulated frost mass, used as the ODE state for time integration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrostState {
+    /// Frost on the coil.
+    pub frost_mass: Mass,
+}
+
+impl StepIntegrable<Time> for FrostState {
+    type Derivative = MassRate;
+
+    fn step(&self, derivative: MassRate, delta: Time) -> Self {
+        FrostState {
+            frost_mass: self.frost_mass + derivative * delta,
+        }
+    }
+}
+
+/// Adapts a [`FrostedCoil`] for use with `twine_solvers::transient::euler::solve`.
+///
+/// The state is the frost mass. Air and surface conditions are carried
+/// forward from the base input on each step.
+///
+/// # Example
+///
+/// ```
+/// use twine_models::{
+///     models::thermal::refrigeration::frost::{
+///         FrostOdeProblem, FrostedCoil, FrostedCoilInput, FrostedCoilParameters,
+///     },
+///     support::psychrometrics::MoistAir,
+/// };
+/// use twine_solvers::transient::euler;
+/// use uom::{
+///     ConstZero,
+///     si::{
+///         area::square_meter,
+///         f64::{
+///             Area, HeatCapacity, Length, Mass, MassRate, Pressure, Ratio,
+///             ThermalConductance, ThermodynamicTemperature, Time,
+///         },
+///         heat_capacity::kilojoule_per_kelvin,
+///         length::millimeter,
+///         mass_rate::kilogram_per_second,
+///         pressure::{kilopascal, pascal},
+///         ratio::percent,
+///         thermal_conductance::watt_per_kelvin,
+///         thermodynamic_temperature::degree_celsius,
+///         time::minute,
+///     },
+/// };
+///
+/// let coil = FrostedCoil::new(FrostedCoilParameters {
+///     surface_area: Area::new::<square_meter>(20.0),
+///     fin_gap: Length::new::<millimeter>(2.0),
+///     clean_ua: ThermalConductance::new::<watt_per_kelvin>(800.0),
+///     clean_pressure_drop: Pressure::new::<pascal>(40.0),
+///     rated_air_flow: MassRate::new::<kilogram_per_second>(0.8),
+///     coil_heat_capacity: HeatCapacity::new::<kilojoule_per_kelvin>(6.0),
+/// }).unwrap();
+///
+/// let initial = FrostedCoilInput {
+///     air: MoistAir::from_relative_humidity(
+///         ThermodynamicTemperature::new::<degree_celsius>(2.0),
+///         Ratio::new::<percent>(85.0),
+///         Pressure::new::<kilopascal>(101.325),
+///     ).unwrap(),
+///     air_flow: MassRate::new::<kilogram_per_second>(0.8),
+///     surface_temperature: ThermodynamicTemperature::new::<degree_celsius>(-8.0),
+///     frost_mass: Mass::ZERO,
+/// };
+///
+/// let solution = euler::solve_unobserved(
+///     &coil,
+///     &FrostOdeProblem,
+///     initial,
+///     Time::new::<minute>(1.0),
+///     30,
+/// ).unwrap();
+/// assert!(solution.history.last().unwrap().input.frost_mass > Mass::ZERO);
+/// ```
+pub struct FrostOdeProblem;
+
+impl OdeProblem for FrostOdeProblem {
+    type Input = FrostedCoilInput;
+    type Output = FrostedCoilOutput;
+    type Delta = Time;
+    type State = FrostState;
+    type Error = FrostedCoilError;
+
+    fn state(&self, input: &Self::Input) -> Result<FrostState, FrostedCoilError> {
+        Ok(FrostState {
+            frost_mass: input.frost_mass,
+        })
+    }
+
+    fn derivative(
+        &self,
+        _input: &Self::Input,
+        output: &Self::Output,
+    ) -> Result<DerivativeOf<FrostState, Time>, FrostedCoilError> {
+        Ok(output.frost_rate)
+    }
+
+    fn build_input(
+        &self,
+        base: &Self::Input,
+        state: &Self::State,
+        _delta: &Time,
+    ) -> Result<Self::Input, FrostedCoilError> {
+        Ok(FrostedCoilInput {
+            frost_mass: state.frost_mass,
+            ..*base
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use twine_solvers::transient::euler;
+    use uom::{
+        ConstZero,
+        si::{
+            area::square_meter,
+            f64::{
+                Area, HeatCapacity, Length, Pressure, Ratio, ThermalConductance,
+                ThermodynamicTemperature,
+            },
+            heat_capacity::kilojoule_per_kelvin,
+            length::millimeter,
+            mass_rate::kilogram_per_second,
+            pressure::{kilopascal, pascal},
+            ratio::ratio,
+            thermal_conductance::watt_per_kelvin,
+            thermodynamic_temperature::degree_celsius,
+            time::minute,
+        },
+    };
+
+    use crate::support::psychrometrics::MoistAir;
+
+    #[test]
+    fn frost_growth_slows_and_degrades_the_coil() {
+        let coil = FrostedCoil::new(FrostedCoilParameters {
+            surface_area: Area::new::<square_meter>(20.0),
+            fin_gap: Length::new::<millimeter>(2.0),
+            clean_ua: ThermalConductance::new::<watt_per_kelvin>(800.0),
+            clean_pressure_drop: Pressure::new::<pascal>(40.0),
+            rated_air_flow: MassRate::new::<kilogram_per_second>(0.8),
+            coil_heat_capacity: HeatCapacity::new::<kilojoule_per_kelvin>(6.0),
+        })
+        .unwrap();
+
+        let initial = FrostedCoilInput {
+            air: MoistAir::from_relative_humidity(
+                ThermodynamicTemperature::new::<degree_celsius>(2.0),
+                Ratio::new::<ratio>(0.85),
+                Pressure::new::<kilopascal>(101.325),
+            )
+            .unwrap(),
+            air_flow: MassRate::new::<kilogram_per_second>(0.8),
+            surface_temperature: ThermodynamicTemperature::new::<degree_celsius>(-8.0),
+            frost_mass: Mass::ZERO,
+        };
+
+        let solution = euler::solve_unobserved(
+            &coil,
+            &FrostOdeProblem,
+            initial,
+            Time::new::<minute>(1.0),
+            20,
+        )
+        .unwrap();
+
+        let first = &solution.history[0].output;
+        let last = &solution.history.last().unwrap().output;
+        assert!(last.frost_thickness > first.frost_thickness);
+        assert!(last.frost_rate < first.frost_rate);
+        assert!(last.ua_ratio < first.ua_ratio);
+        assert!(last.pressure_drop > first.pressure_drop);
+    }
+}
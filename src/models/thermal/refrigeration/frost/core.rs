@@ -0,0 +1,587 @@
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::{
+        available_energy::kilojoule_per_kilogram,
+        f64::{
+            Area, AvailableEnergy, Energy, HeatCapacity, Length, Mass, MassDensity, MassRate,
+            Power, Pressure, Ratio, SpecificHeatCapacity, ThermalConductance, ThermalConductivity,
+            ThermodynamicTemperature,
+        },
+        mass_density::kilogram_per_cubic_meter,
+        ratio::ratio,
+        specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+        thermal_conductivity::watt_per_meter_kelvin,
+        thermodynamic_temperature::{degree_celsius, kelvin},
+    },
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive},
+    psychrometrics::{MoistAir, PsychrometricError},
+    units::TemperatureDifference,
+};
+
+/// Latent heat of sublimation of ice near 0 °C.
+const SUBLIMATION_ENTHALPY: f64 = 2834.0;
+
+/// Latent heat of condensation of water near 0 °C.
+const CONDENSATION_ENTHALPY: f64 = 2501.0;
+
+/// Latent heat of fusion of ice.
+const FUSION_ENTHALPY: f64 = 333.6;
+
+/// Specific heat of ice near 0 °C.
+const ICE_SPECIFIC_HEAT: f64 = 2.05;
+
+/// Coldest surface temperature covered by the frost density correlation, °C.
+const COLDEST_DENSITY_SURFACE: f64 = -25.0;
+
+/// Fixed physical parameters of a frosting evaporator coil.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrostedCoilParameters {
+    /// Air-side surface area of the fins and tubes, where frost deposits.
+    pub surface_area: Area,
+
+    /// Clear spacing between adjacent fins when the coil is clean.
+    ///
+    /// Frost grows from both faces of each passage, so the passage closes
+    /// once the frost is half this thick.
+    pub fin_gap: Length,
+
+    /// Overall conductance between the air and the coil surface when clean.
+    pub clean_ua: ThermalConductance,
+
+    /// Air-side pressure drop of the clean coil at `rated_air_flow`.
+    pub clean_pressure_drop: Pressure,
+
+    /// Dry-air flow at which `clean_pressure_drop` applies.
+    pub rated_air_flow: MassRate,
+
+    /// Heat capacity of the coil metal, warmed during a defrost.
+    pub coil_heat_capacity: HeatCapacity,
+}
+
+/// Errors from [`FrostedCoil`] construction and evaluation.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum FrostedCoilError {
+    /// A parameter or input is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// The frost has closed the fin passages.
+    #[error("frost {thickness:?} thick has blocked the fin passages")]
+    Blocked {
+        /// Frost thickness on each fin face.
+        thickness: Length,
+    },
+
+    /// An air state could not be evaluated.
+    #[error(transparent)]
+    Psychrometric(#[from] PsychrometricError),
+}
+
+/// An air-source evaporator coil that collects frost.
+///
+/// Moisture leaves the air at the same effectiveness as heat, driven by the
+/// difference between the air's humidity ratio and that of saturated air at
+/// the coil surface (a Lewis number of one). Below 0 °C it deposits as
+/// frost; above, it drains as condensate.
+///
+/// Frost density follows Hayashi et al. (1977) and its conductivity Yonko
+/// and Sepsy (1967), both evaluated at the current coil surface
+/// temperature. The frost layer adds a conduction resistance in series
+/// with the clean UA and narrows the fin passages. The air-side pressure
+/// drop is taken as laminar channel flow, linear in flow and scaling with
+/// the inverse cube of the open gap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrostedCoil {
+    parameters: FrostedCoilParameters,
+}
+
+/// Input to the frosted coil model.
+#[derive(Debug, Clone, Copy)]
+pub struct FrostedCoilInput {
+    /// Air entering the coil.
+    pub air: MoistAir,
+
+    /// Dry-air mass flow through the coil.
+    pub air_flow: MassRate,
+
+    /// Coil surface temperature, close to the refrigerant evaporating
+    /// temperature.
+    pub surface_temperature: ThermodynamicTemperature,
+
+    /// Frost accumulated on the coil so far.
+    pub frost_mass: Mass,
+}
+
+/// Output from the frosted coil model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrostedCoilOutput {
+    /// Air leaving the coil.
+    pub outlet_air: MoistAir,
+
+    /// Rate at which frost accumulates, the time derivative of the frost
+    /// mass.
+    pub frost_rate: MassRate,
+
+    /// Rate at which condensate drains from a coil above freezing.
+    pub condensate_rate: MassRate,
+
+    /// Heat removed from the air by cooling it.
+    pub sensible_heat_rate: Power,
+
+    /// Heat released by the moisture deposited as frost or condensate.
+    pub latent_heat_rate: Power,
+
+    /// Frost layer density.
+    pub frost_density: MassDensity,
+
+    /// Frost layer thickness on each fin face.
+    pub frost_thickness: Length,
+
+    /// Fraction of the clean fin gap closed by frost.
+    pub blockage: Ratio,
+
+    /// Overall conductance including the frost layer.
+    pub ua: ThermalConductance,
+
+    /// Ratio of the frosted UA to the clean UA.
+    pub ua_ratio: Ratio,
+
+    /// Air-side pressure drop through the frosted coil.
+    pub pressure_drop: Pressure,
+}
+
+/// Energy needed to clear the coil of frost in a defrost cycle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DefrostEnergy {
+    /// Energy to warm the coil metal to the termination temperature.
+    pub coil: Energy,
+
+    /// Energy to warm the frost to 0 °C.
+    pub frost_warming: Energy,
+
+    /// Energy to melt the frost.
+    pub melting: Energy,
+}
+
+impl DefrostEnergy {
+    /// Returns the total defrost energy.
+    #[must_use]
+    pub fn total(&self) -> Energy {
+        self.coil + self.frost_warming + self.melting
+    }
+}
+
+impl FrostedCoil {
+    /// Creates a frosted coil model from its physical parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FrostedCoilError::InvalidParameter`] if an area, gap,
+    /// conductance, or rated flow is not strictly positive, or the clean
+    /// pressure drop or coil heat capacity is negative.
+    pub fn new(parameters: FrostedCoilParameters) -> Result<Self, FrostedCoilError> {
+        let FrostedCoilParameters {
+            surface_area,
+            fin_gap,
+            clean_ua,
+            clean_pressure_drop,
+            rated_air_flow,
+            coil_heat_capacity,
+        } = parameters;
+
+        if StrictlyPositive::check(&surface_area).is_err() {
+            return Err(FrostedCoilError::InvalidParameter(
+                "surface area must be strictly positive",
+            ));
+        }
+        if StrictlyPositive::check(&fin_gap).is_err() {
+            return Err(FrostedCoilError::InvalidParameter(
+                "fin gap must be strictly positive",
+            ));
+        }
+        if StrictlyPositive::check(&clean_ua).is_err() {
+            return Err(FrostedCoilError::InvalidParameter(
+                "clean UA must be strictly positive",
+            ));
+        }
+        if NonNegative::check(&clean_pressure_drop).is_err() {
+            return Err(FrostedCoilError::InvalidParameter(
+                "clean pressure drop must be non-negative",
+            ));
+        }
+        if StrictlyPositive::check(&rated_air_flow).is_err() {
+            return Err(FrostedCoilError::InvalidParameter(
+                "rated air flow must be strictly positive",
+            ));
+        }
+        if NonNegative::check(&coil_heat_capacity).is_err() {
+            return Err(FrostedCoilError::InvalidParameter(
+                "coil heat capacity must be non-negative",
+            ));
+        }
+
+        Ok(Self { parameters })
+    }
+
+    /// Returns the coil parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &FrostedCoilParameters {
+        &self.parameters
+    }
+
+    /// Evaluates the coil's heat and moisture transfer for its current
+    /// frost load.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FrostedCoilError::InvalidParameter`] if the air flow is not
+    /// strictly positive or the frost mass is negative,
+    /// [`FrostedCoilError::Blocked`] once frost closes the fin passages, or
+    /// [`FrostedCoilError::Psychrometric`] if the surface temperature lies
+    /// outside the psychrometric range.
+    pub fn evaluate(
+        &self,
+        input: &FrostedCoilInput,
+    ) -> Result<FrostedCoilOutput, FrostedCoilError> {
+        let FrostedCoilInput {
+            air,
+            air_flow,
+            surface_temperature,
+            frost_mass,
+        } = *input;
+        let p = &self.parameters;
+
+        if StrictlyPositive::check(&air_flow).is_err() {
+            return Err(FrostedCoilError::InvalidParameter(
+                "air flow must be strictly positive",
+            ));
+        }
+        if NonNegative::check(&frost_mass).is_err() {
+            return Err(FrostedCoilError::InvalidParameter(
+                "frost mass must be non-negative",
+            ));
+        }
+
+        let frost_density = frost_density(surface_temperature);
+        let frost_thickness: Length = frost_mass / (frost_density * p.surface_area);
+        let blockage: Ratio = 2.0 * frost_thickness / p.fin_gap;
+        if blockage.get::<ratio>() >= 1.0 {
+            return Err(FrostedCoilError::Blocked {
+                thickness: frost_thickness,
+            });
+        }
+
+        // The frost conducts in series with the clean coil.
+        let frost_resistance =
+            frost_thickness / (frost_conductivity(frost_density) * p.surface_area);
+        let ua = 1.0 / (1.0 / p.clean_ua + frost_resistance);
+
+        // The surface is isothermal, so heat and, by the Lewis analogy,
+        // moisture transfer share one effectiveness.
+        let ntu = (ua / (air_flow * air.humid_specific_heat())).get::<ratio>();
+        let effectiveness = -(-ntu).exp_m1();
+        let t_in = air.temperature().get::<kelvin>();
+        let t_out = ThermodynamicTemperature::new::<kelvin>(
+            t_in - effectiveness * (t_in - surface_temperature.get::<kelvin>()),
+        );
+
+        let w_in = air.humidity_ratio();
+        let w_surface = MoistAir::saturated(surface_temperature, air.pressure())?.humidity_ratio();
+        let w_out = if w_in > w_surface {
+            // Any fog the straight-line path would leave also deposits.
+            let w_saturated = MoistAir::saturated(t_out, air.pressure())?.humidity_ratio();
+            (w_in - effectiveness * (w_in - w_surface)).min(w_saturated)
+        } else {
+            w_in
+        };
+        let outlet_air = MoistAir::new(t_out, w_out, air.pressure())?;
+
+        let moisture_rate: MassRate = air_flow * (w_in - w_out);
+        let frozen = surface_temperature.get::<degree_celsius>() < 0.0;
+        let (frost_rate, condensate_rate, latent_enthalpy) = if frozen {
+            (moisture_rate, MassRate::ZERO, SUBLIMATION_ENTHALPY)
+        } else {
+            (MassRate::ZERO, moisture_rate, CONDENSATION_ENTHALPY)
+        };
+
+        let sensible_heat_rate =
+            air_flow * air.humid_specific_heat() * air.temperature().minus(t_out);
+        let latent_heat_rate =
+            moisture_rate * AvailableEnergy::new::<kilojoule_per_kilogram>(latent_enthalpy);
+
+        // Laminar passages: ΔP ∝ ṁ / gap³ at fixed passage length.
+        let open: f64 = 1.0 - blockage.get::<ratio>();
+        let pressure_drop =
+            p.clean_pressure_drop * (air_flow / p.rated_air_flow).get::<ratio>() / open.powi(3);
+
+        Ok(FrostedCoilOutput {
+            outlet_air,
+            frost_rate,
+            condensate_rate,
+            sensible_heat_rate,
+            latent_heat_rate,
+            frost_density,
+            frost_thickness,
+            blockage,
+            ua,
+            ua_ratio: ua / p.clean_ua,
+            pressure_drop,
+        })
+    }
+
+    /// Returns the energy a defrost must supply to clear `frost_mass` from
+    /// a coil starting at `surface_temperature` and terminating at
+    /// `termination_temperature`.
+    ///
+    /// The meltwater drains at 0 °C, so it is not warmed further. Losses to
+    /// the surrounding air during the defrost are not included.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FrostedCoilError::InvalidParameter`] if the frost mass is
+    /// negative or the termination temperature is below 0 °C or below the
+    /// surface temperature.
+    pub fn defrost_energy(
+        &self,
+        frost_mass: Mass,
+        surface_temperature: ThermodynamicTemperature,
+        termination_temperature: ThermodynamicTemperature,
+    ) -> Result<DefrostEnergy, FrostedCoilError> {
+        if NonNegative::check(&frost_mass).is_err() {
+            return Err(FrostedCoilError::InvalidParameter(
+                "frost mass must be non-negative",
+            ));
+        }
+        let termination = termination_temperature.get::<degree_celsius>();
+        if termination.is_nan()
+            || termination < 0.0
+            || termination_temperature < surface_temperature
+        {
+            return Err(FrostedCoilError::InvalidParameter(
+                "termination temperature must be above freezing and the surface temperature",
+            ));
+        }
+
+        let frost_temperature = surface_temperature.get::<degree_celsius>().min(0.0);
+        let ice_specific_heat =
+            SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(ICE_SPECIFIC_HEAT);
+        let frost_warming = frost_mass
+            * ice_specific_heat
+            * ThermodynamicTemperature::new::<degree_celsius>(0.0).minus(
+                ThermodynamicTemperature::new::<degree_celsius>(frost_temperature),
+            );
+
+        Ok(DefrostEnergy {
+            coil: self.parameters.coil_heat_capacity
+                * termination_temperature.minus(surface_temperature),
+            frost_warming,
+            melting: frost_mass * AvailableEnergy::new::<kilojoule_per_kilogram>(FUSION_ENTHALPY),
+        })
+    }
+}
+
+/// Frost density from Hayashi et al. (1977), `ρ = 650·exp(0.227·T_s)` with
+/// the surface temperature in °C.
+///
+/// The surface temperature is clamped to the −25 °C to 0 °C range of the
+/// correlation.
+fn frost_density(surface_temperature: ThermodynamicTemperature) -> MassDensity {
+    let t = surface_temperature
+        .get::<degree_celsius>()
+        .clamp(COLDEST_DENSITY_SURFACE, 0.0);
+    MassDensity::new::<kilogram_per_cubic_meter>(650.0 * (0.227 * t).exp())
+}
+
+/// Frost conductivity from Yonko and Sepsy (1967).
+fn frost_conductivity(density: MassDensity) -> ThermalConductivity {
+    let rho = density.get::<kilogram_per_cubic_meter>();
+    ThermalConductivity::new::<watt_per_meter_kelvin>(
+        0.024_22 + 7.214e-4 * rho + 1.1797e-6 * rho * rho,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        area::square_meter,
+        energy::kilojoule,
+        heat_capacity::kilojoule_per_kelvin,
+        length::millimeter,
+        mass::kilogram,
+        mass_rate::kilogram_per_second,
+        power::watt,
+        pressure::{kilopascal, pascal},
+        thermal_conductance::watt_per_kelvin,
+    };
+
+    use crate::support::units::test_utils::celsius;
+
+    fn coil() -> FrostedCoil {
+        FrostedCoil::new(FrostedCoilParameters {
+            surface_area: Area::new::<square_meter>(20.0),
+            fin_gap: Length::new::<millimeter>(2.0),
+            clean_ua: ThermalConductance::new::<watt_per_kelvin>(800.0),
+            clean_pressure_drop: Pressure::new::<pascal>(40.0),
+            rated_air_flow: MassRate::new::<kilogram_per_second>(0.8),
+            coil_heat_capacity: HeatCapacity::new::<kilojoule_per_kelvin>(6.0),
+        })
+        .unwrap()
+    }
+
+    fn input(frost_kg: f64) -> FrostedCoilInput {
+        FrostedCoilInput {
+            air: MoistAir::from_relative_humidity(
+                celsius(2.0),
+                Ratio::new::<ratio>(0.85),
+                Pressure::new::<kilopascal>(101.325),
+            )
+            .unwrap(),
+            air_flow: MassRate::new::<kilogram_per_second>(0.8),
+            surface_temperature: celsius(-8.0),
+            frost_mass: Mass::new::<kilogram>(frost_kg),
+        }
+    }
+
+    #[test]
+    fn clean_coil_collects_frost() {
+        let out = coil().evaluate(&input(0.0)).unwrap();
+
+        assert!(out.frost_rate > MassRate::ZERO);
+        assert_eq!(out.condensate_rate, MassRate::ZERO);
+        assert_relative_eq!(out.ua_ratio.get::<ratio>(), 1.0);
+        assert_relative_eq!(out.pressure_drop.get::<pascal>(), 40.0);
+
+        // The removed moisture balances the frost rate.
+        let removed = input(0.0).air.humidity_ratio() - out.outlet_air.humidity_ratio();
+        assert_relative_eq!(
+            (MassRate::new::<kilogram_per_second>(0.8) * removed).get::<kilogram_per_second>(),
+            out.frost_rate.get::<kilogram_per_second>(),
+            max_relative = 1e-12
+        );
+        assert!(out.outlet_air.temperature() < celsius(2.0));
+        assert!(out.latent_heat_rate.get::<watt>() > 0.0);
+    }
+
+    #[test]
+    fn frost_degrades_ua_and_raises_pressure_drop() {
+        let clean = coil().evaluate(&input(0.0)).unwrap();
+        let frosted = coil().evaluate(&input(2.0)).unwrap();
+
+        assert!(frosted.ua_ratio.get::<ratio>() < 1.0);
+        assert!(frosted.sensible_heat_rate < clean.sensible_heat_rate);
+        assert!(frosted.frost_rate < clean.frost_rate);
+        assert!(frosted.pressure_drop > clean.pressure_drop);
+
+        // Thickness follows from the Hayashi density at −8 °C.
+        let rho = 650.0 * (0.227_f64 * -8.0).exp();
+        assert_relative_eq!(
+            frosted.frost_thickness.get::<millimeter>(),
+            2.0 / (rho * 20.0) * 1000.0,
+            max_relative = 1e-12
+        );
+        let open = 1.0 - frosted.blockage.get::<ratio>();
+        assert_relative_eq!(
+            frosted.pressure_drop.get::<pascal>(),
+            40.0 / open.powi(3),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn blocked_coil_is_an_error() {
+        assert!(matches!(
+            coil().evaluate(&input(10.0)),
+            Err(FrostedCoilError::Blocked { .. })
+        ));
+    }
+
+    #[test]
+    fn warm_coil_drains_condensate() {
+        let warm = FrostedCoilInput {
+            air: MoistAir::from_relative_humidity(
+                celsius(27.0),
+                Ratio::new::<ratio>(0.6),
+                Pressure::new::<kilopascal>(101.325),
+            )
+            .unwrap(),
+            surface_temperature: celsius(8.0),
+            ..input(0.0)
+        };
+        let out = coil().evaluate(&warm).unwrap();
+
+        assert_eq!(out.frost_rate, MassRate::ZERO);
+        assert!(out.condensate_rate > MassRate::ZERO);
+    }
+
+    #[test]
+    fn dry_air_deposits_nothing() {
+        let dry = FrostedCoilInput {
+            air: MoistAir::from_relative_humidity(
+                celsius(2.0),
+                Ratio::new::<ratio>(0.2),
+                Pressure::new::<kilopascal>(101.325),
+            )
+            .unwrap(),
+            ..input(0.0)
+        };
+        let out = coil().evaluate(&dry).unwrap();
+
+        assert_eq!(out.frost_rate, MassRate::ZERO);
+        assert_eq!(out.latent_heat_rate, Power::ZERO);
+        assert!(out.sensible_heat_rate > Power::ZERO);
+    }
+
+    #[test]
+    fn defrost_energy_accounts_for_coil_and_frost() {
+        let energy = coil()
+            .defrost_energy(Mass::new::<kilogram>(2.0), celsius(-8.0), celsius(10.0))
+            .unwrap();
+
+        assert_relative_eq!(
+            energy.coil.get::<kilojoule>(),
+            6.0 * 18.0,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            energy.frost_warming.get::<kilojoule>(),
+            2.0 * 2.05 * 8.0,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            energy.melting.get::<kilojoule>(),
+            2.0 * 333.6,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            energy.total().get::<kilojoule>(),
+            108.0 + 32.8 + 667.2,
+            max_relative = 1e-12
+        );
+
+        assert!(
+            coil()
+                .defrost_energy(Mass::new::<kilogram>(2.0), celsius(-8.0), celsius(-1.0))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        let zero_gap = FrostedCoilParameters {
+            fin_gap: Length::ZERO,
+            ..*coil().parameters()
+        };
+        assert!(matches!(
+            FrostedCoil::new(zero_gap),
+            Err(FrostedCoilError::InvalidParameter(_))
+        ));
+    }
+}
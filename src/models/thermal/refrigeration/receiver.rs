@@ -0,0 +1,180 @@
+//! Liquid receiver between the condenser and the expansion valve.
+//!
+//! A [`LiquidReceiver`] stores the refrigerant the loop does not need at
+//! the current operating point and feeds liquid to the expansion valve. Its
+//! outlet is saturated liquid at the condensing pressure, so a loop modeled
+//! with one has a valve inlet that stays put while the condenser outlet
+//! swings between wet and subcooled during a solve.
+//!
+//! See [`super::charge`] for the inputs, outputs, and errors it
+//! shares with the [`SuctionAccumulator`](super::accumulator::SuctionAccumulator).
+
+use std::marker::PhantomData;
+
+use twine_core::Model;
+use uom::si::f64::Volume;
+
+use super::charge::{
+    self, ChargeVesselError, ChargeVesselInput, ChargeVesselOutput, ChargeVesselThermoModel, Port,
+};
+
+/// Liquid receiver of a vapor-compression loop.
+///
+/// The vessel holds saturated liquid under saturated vapor at the pressure
+/// of its inlet, and its outlet draws from the bottom of the liquid. Heat
+/// moved between the vessel and the incoming refrigerant closes the energy
+/// balance: vapor arriving with a wet inlet is condensed, and subcooled
+/// liquid is brought back to saturation.
+#[derive(Debug, Clone)]
+pub struct LiquidReceiver<Fluid, Thermo> {
+    volume: Volume,
+    thermo: Thermo,
+    _fluid: PhantomData<Fluid>,
+}
+
+impl<Fluid, Thermo> LiquidReceiver<Fluid, Thermo> {
+    /// Creates a receiver with the given internal volume.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChargeVesselError::InvalidParameter`] if the volume is not
+    /// strictly positive and finite.
+    pub fn new(volume: Volume, thermo: Thermo) -> Result<Self, ChargeVesselError> {
+        charge::check_volume(volume)?;
+        Ok(Self {
+            volume,
+            thermo,
+            _fluid: PhantomData,
+        })
+    }
+
+    /// Returns the internal volume.
+    pub fn volume(&self) -> Volume {
+        self.volume
+    }
+
+    /// Returns a reference to the thermodynamic model.
+    pub fn thermo(&self) -> &Thermo {
+        &self.thermo
+    }
+}
+
+impl<Fluid, Thermo> LiquidReceiver<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: ChargeVesselThermoModel<Fluid>,
+{
+    /// Evaluates the saturated liquid outlet and the held charge.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChargeVesselError::Flooded`] if the charge would fill the
+    /// vessel with liquid and back it up into the condenser,
+    /// [`ChargeVesselError::Drained`] if the charge is less than vapor alone
+    /// holds, and other [`ChargeVesselError`] variants for invalid inputs
+    /// or property failures.
+    pub fn evaluate(
+        &self,
+        input: &ChargeVesselInput<Fluid>,
+    ) -> Result<ChargeVesselOutput<Fluid>, ChargeVesselError> {
+        charge::evaluate(&self.thermo, self.volume, Port::SaturatedLiquid, input)
+    }
+}
+
+impl<Fluid, Thermo> Model for LiquidReceiver<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: ChargeVesselThermoModel<Fluid>,
+{
+    type Input = ChargeVesselInput<Fluid>;
+    type Output = ChargeVesselOutput<Fluid>;
+    type Error = ChargeVesselError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::{Mass, MassRate},
+        mass::kilogram,
+        mass_rate::kilogram_per_second,
+        power::watt,
+        thermodynamic_temperature::degree_celsius,
+    };
+
+    use crate::{
+        models::thermal::{
+            hx::discretized::core::test_support::{TestFluid, TestRefrigerant},
+            refrigeration::charge::test_support::{volume, wet},
+        },
+        support::thermo::{State, capability::HasEnthalpy},
+    };
+
+    fn input(inlet: State<TestFluid>, kilograms: f64) -> ChargeVesselInput<TestFluid> {
+        ChargeVesselInput {
+            inlet,
+            mass_flow: MassRate::new::<kilogram_per_second>(0.05),
+            charge: Mass::new::<kilogram>(kilograms),
+        }
+    }
+
+    #[test]
+    fn wet_inlet_leaves_as_saturated_liquid() {
+        let thermo = TestRefrigerant::ammonia_like();
+        let receiver = LiquidReceiver::new(volume(), &thermo).unwrap();
+        let input = input(wet(&thermo, 40.0, 0.05), 3.0);
+
+        let output = receiver.call(&input).unwrap();
+
+        assert_relative_eq!(
+            output.saturation_temperature.get::<degree_celsius>(),
+            40.0,
+            max_relative = 1e-9
+        );
+        assert_eq!(output.outlet, wet(&thermo, 40.0, 0.0));
+
+        // The uncondensed vapor gives up its latent heat in the vessel.
+        let latent = thermo.enthalpy(&wet(&thermo, 40.0, 1.0)).unwrap()
+            - thermo.enthalpy(&wet(&thermo, 40.0, 0.0)).unwrap();
+        assert_relative_eq!(
+            output.heat_transfer_rate.get::<watt>(),
+            -(input.mass_flow * latent * 0.05).get::<watt>(),
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn charge_moves_between_headroom_and_reserve() {
+        let thermo = TestRefrigerant::ammonia_like();
+        let receiver = LiquidReceiver::new(volume(), &thermo).unwrap();
+        let held = |kilograms| {
+            receiver
+                .evaluate(&input(wet(&thermo, 40.0, 0.0), kilograms))
+                .map(|output| output.charge)
+        };
+
+        // Each added kilogram comes out of the headroom and into the reserve.
+        let less = held(2.0).unwrap();
+        let more = held(3.0).unwrap();
+        assert_relative_eq!(
+            (less.headroom - more.headroom).get::<kilogram>(),
+            1.0,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            (more.reserve - less.reserve).get::<kilogram>(),
+            1.0,
+            max_relative = 1e-9
+        );
+        assert!(more.liquid_fraction > less.liquid_fraction);
+
+        assert!(matches!(held(0.01), Err(ChargeVesselError::Drained { .. })));
+        assert!(matches!(held(10.0), Err(ChargeVesselError::Flooded { .. })));
+    }
+}
@@ -0,0 +1,34 @@
+//! Liquid-line to suction-line heat exchanger.
+//!
+//! A [`SuctionLineHx`] (also called a liquid-suction or internal heat
+//! exchanger) subcools the liquid headed for the expansion valve against
+//! the vapor headed for the compressor. It is sized either by a fixed
+//! effectiveness or by a conductance solved with a discretized counterflow
+//! exchanger, and both options use real-fluid enthalpies.
+//!
+//! [`Stage::suction_line`](super::cascade::Stage::suction_line) adds one to
+//! a loop of a [`CascadeCycle`](super::cascade::CascadeCycle).
+
+mod core;
+
+use twine_core::Model;
+
+use crate::models::thermal::hx::discretized::core::DiscretizedHxThermoModel;
+
+pub use core::{
+    SuctionLineError, SuctionLineHx, SuctionLineInput, SuctionLineOutput, SuctionLineSizing,
+};
+
+impl<Fluid, Thermo> Model for SuctionLineHx<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: DiscretizedHxThermoModel<Fluid>,
+{
+    type Input = SuctionLineInput<Fluid>;
+    type Output = SuctionLineOutput<Fluid>;
+    type Error = SuctionLineError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
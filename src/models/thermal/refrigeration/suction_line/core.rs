@@ -0,0 +1,489 @@
+use std::{error::Error as StdError, marker::PhantomData};
+
+use thiserror::Error;
+use twine_core::Model;
+use uom::{
+    ConstZero,
+    si::f64::{MassRate, Power, Ratio, ThermalConductance},
+};
+
+use crate::{
+    models::thermal::hx::discretized::{
+        Inlets, MassFlows, PressureDrops, RecuperatorGivenUa, RecuperatorGivenUaConfig,
+        RecuperatorGivenUaError, RecuperatorGivenUaInput, core::DiscretizedHxThermoModel,
+    },
+    support::{
+        constraint::{Constrained, Constraint, StrictlyPositive, UnitInterval},
+        thermo::State,
+    },
+};
+
+/// How a [`SuctionLineHx`] is sized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SuctionLineSizing {
+    /// Fixed fraction of the largest possible heat transfer.
+    ///
+    /// The limit is whichever stream would first reach the other's inlet
+    /// temperature, evaluated from real-fluid enthalpies, so the result
+    /// holds across the vapor's varying specific heat.
+    Effectiveness(Constrained<Ratio, UnitInterval>),
+
+    /// Discretized counterflow exchanger with a known conductance.
+    Conductance {
+        /// Overall conductance of the exchanger.
+        ua: ThermalConductance,
+
+        /// Number of constant-property segments.
+        ///
+        /// Supported values are those of [`RecuperatorGivenUa`].
+        segments: usize,
+    },
+}
+
+/// Liquid-line to suction-line heat exchanger of a vapor-compression cycle.
+///
+/// Warm liquid leaving the condenser gives heat to the cold vapor leaving
+/// the evaporator. The liquid reaches the expansion valve more subcooled,
+/// which raises the refrigerating effect, while the compressor draws in
+/// warmer, less dense vapor. Both streams carry the same refrigerant at the
+/// same mass flow, and pressure drops are neglected.
+#[derive(Debug, Clone)]
+pub struct SuctionLineHx<Fluid, Thermo> {
+    sizing: SuctionLineSizing,
+    thermo: Thermo,
+    _fluid: PhantomData<Fluid>,
+}
+
+/// Inlet conditions of a [`SuctionLineHx`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuctionLineInput<Fluid> {
+    /// Liquid leaving the condenser.
+    pub liquid: State<Fluid>,
+
+    /// Vapor leaving the evaporator.
+    pub vapor: State<Fluid>,
+
+    /// Refrigerant mass flow through both sides.
+    pub mass_flow: MassRate,
+}
+
+/// Outlet conditions of a [`SuctionLineHx`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuctionLineOutput<Fluid> {
+    /// Liquid entering the expansion valve.
+    pub liquid: State<Fluid>,
+
+    /// Vapor entering the compressor.
+    pub vapor: State<Fluid>,
+
+    /// Heat moved from the liquid to the vapor.
+    pub heat_transfer_rate: Power,
+
+    /// Heat transfer rate over its thermodynamic limit.
+    pub effectiveness: Ratio,
+}
+
+/// Errors from [`SuctionLineHx`].
+#[derive(Debug, Error)]
+pub enum SuctionLineError {
+    /// The conductance is negative or not finite.
+    #[error("suction-line conductance must be non-negative and finite, got {0:?}")]
+    InvalidConductance(ThermalConductance),
+
+    /// The refrigerant mass flow is not strictly positive.
+    #[error("mass flow must be strictly positive, got {0:?}")]
+    NonPositiveMassFlow(MassRate),
+
+    /// The discretized exchanger could not be solved.
+    #[error("suction-line exchanger failed")]
+    Exchanger(#[from] RecuperatorGivenUaError),
+
+    /// A thermodynamic model operation failed.
+    #[error("thermodynamic model failed: {context}")]
+    ThermoModelFailed {
+        /// Operation context for the thermodynamic model failure.
+        context: String,
+
+        /// Underlying thermodynamic model error.
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+}
+
+impl SuctionLineError {
+    fn thermo(context: &str, source: impl StdError + Send + Sync + 'static) -> Self {
+        Self::ThermoModelFailed {
+            context: context.to_owned(),
+            source: Box::new(source),
+        }
+    }
+}
+
+impl<Fluid, Thermo> SuctionLineHx<Fluid, Thermo> {
+    /// Creates a suction-line exchanger.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SuctionLineError::InvalidConductance`] for a negative or
+    /// non-finite conductance, and [`SuctionLineError::Exchanger`] for an
+    /// unsupported segment count.
+    pub fn new(sizing: SuctionLineSizing, thermo: Thermo) -> Result<Self, SuctionLineError> {
+        if let SuctionLineSizing::Conductance { ua, segments } = sizing {
+            if !(ua >= ThermalConductance::ZERO && ua.is_finite()) {
+                return Err(SuctionLineError::InvalidConductance(ua));
+            }
+            RecuperatorGivenUa::<Fluid, _>::new(&thermo, segments, Self::recuperator_config())?;
+        }
+
+        Ok(Self {
+            sizing,
+            thermo,
+            _fluid: PhantomData,
+        })
+    }
+
+    /// Returns how the exchanger is sized.
+    pub fn sizing(&self) -> SuctionLineSizing {
+        self.sizing
+    }
+
+    fn recuperator_config() -> RecuperatorGivenUaConfig {
+        RecuperatorGivenUaConfig::default()
+    }
+}
+
+impl<Fluid, Thermo> SuctionLineHx<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: DiscretizedHxThermoModel<Fluid>,
+{
+    /// Evaluates the outlet states.
+    ///
+    /// Returns the inlets unchanged when the liquid is not warmer than the
+    /// vapor.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SuctionLineError`] if the mass flow is not strictly
+    /// positive, a property evaluation fails, or the discretized exchanger
+    /// cannot be solved.
+    pub fn evaluate(
+        &self,
+        input: &SuctionLineInput<Fluid>,
+    ) -> Result<SuctionLineOutput<Fluid>, SuctionLineError> {
+        let SuctionLineInput {
+            liquid,
+            vapor,
+            mass_flow,
+        } = input;
+        StrictlyPositive::check(mass_flow)
+            .map_err(|_| SuctionLineError::NonPositiveMassFlow(*mass_flow))?;
+
+        let no_transfer = || SuctionLineOutput {
+            liquid: liquid.clone(),
+            vapor: vapor.clone(),
+            heat_transfer_rate: Power::ZERO,
+            effectiveness: Ratio::ZERO,
+        };
+        if liquid.temperature <= vapor.temperature {
+            return Ok(no_transfer());
+        }
+
+        let thermo = &self.thermo;
+        let enthalpy = |state: &State<Fluid>, context| {
+            thermo
+                .enthalpy(state)
+                .map_err(|error| SuctionLineError::thermo(context, error))
+        };
+        let pressure = |state: &State<Fluid>, context| {
+            thermo
+                .pressure(state)
+                .map_err(|error| SuctionLineError::thermo(context, error))
+        };
+        let h_liquid = enthalpy(liquid, "liquid inlet enthalpy")?;
+        let h_vapor = enthalpy(vapor, "vapor inlet enthalpy")?;
+        let p_liquid = pressure(liquid, "liquid pressure")?;
+        let p_vapor = pressure(vapor, "vapor pressure")?;
+
+        // Each stream's limit is reaching the other stream's inlet temperature.
+        let vapor_limit = thermo
+            .state_from((vapor.fluid.clone(), liquid.temperature, p_vapor))
+            .map_err(|error| SuctionLineError::thermo("vapor limit state", error))?;
+        let liquid_limit = thermo
+            .state_from((liquid.fluid.clone(), vapor.temperature, p_liquid))
+            .map_err(|error| SuctionLineError::thermo("liquid limit state", error))?;
+        let q_max = *mass_flow
+            * (enthalpy(&vapor_limit, "vapor limit enthalpy")? - h_vapor)
+                .min(h_liquid - enthalpy(&liquid_limit, "liquid limit enthalpy")?);
+
+        match self.sizing {
+            SuctionLineSizing::Effectiveness(effectiveness) => {
+                let effectiveness = effectiveness.into_inner();
+                let heat_transfer_rate = effectiveness * q_max;
+                let per_mass = heat_transfer_rate / *mass_flow;
+                let liquid_out = thermo
+                    .state_from((liquid.fluid.clone(), p_liquid, h_liquid - per_mass))
+                    .map_err(|error| SuctionLineError::thermo("liquid outlet state", error))?;
+                let vapor_out = thermo
+                    .state_from((vapor.fluid.clone(), p_vapor, h_vapor + per_mass))
+                    .map_err(|error| SuctionLineError::thermo("vapor outlet state", error))?;
+
+                Ok(SuctionLineOutput {
+                    liquid: liquid_out,
+                    vapor: vapor_out,
+                    heat_transfer_rate,
+                    effectiveness,
+                })
+            }
+            SuctionLineSizing::Conductance { ua, segments } => {
+                if ua == ThermalConductance::ZERO {
+                    return Ok(no_transfer());
+                }
+                let recuperator =
+                    RecuperatorGivenUa::new(thermo, segments, Self::recuperator_config())?;
+                let output = recuperator.call(&RecuperatorGivenUaInput {
+                    inlets: Inlets {
+                        top: liquid.clone(),
+                        bottom: vapor.clone(),
+                    },
+                    mass_flows: MassFlows::new_unchecked(*mass_flow, *mass_flow),
+                    pressure_drops: PressureDrops::zero(),
+                    ua,
+                })?;
+                let heat_transfer_rate = output.q_dot.signed_top_to_bottom();
+
+                Ok(SuctionLineOutput {
+                    liquid: output.top_outlet,
+                    vapor: output.bottom_outlet,
+                    heat_transfer_rate,
+                    effectiveness: heat_transfer_rate / q_max,
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::{Pressure, ThermodynamicTemperature},
+        mass_rate::kilogram_per_second,
+        power::kilowatt,
+        ratio::ratio,
+        thermal_conductance::kilowatt_per_kelvin,
+        thermodynamic_temperature::degree_celsius,
+    };
+
+    use crate::{
+        models::thermal::hx::discretized::core::test_support::{TestFluid, TestRefrigerant},
+        support::thermo::capability::{HasEnthalpy, HasPressure, StateFrom},
+    };
+
+    fn saturated_pressure(thermo: &TestRefrigerant, celsius: f64) -> Pressure {
+        let state = thermo
+            .state_from((
+                TestFluid,
+                ThermodynamicTemperature::new::<degree_celsius>(celsius),
+                UnitInterval::one::<Ratio>(),
+            ))
+            .unwrap();
+        thermo.pressure(&state).unwrap()
+    }
+
+    /// Liquid subcooled to 30 °C at 40 °C condensing, vapor superheated to
+    /// 0 °C at −5 °C evaporating.
+    fn input(thermo: &TestRefrigerant) -> SuctionLineInput<TestFluid> {
+        let state = |celsius, saturation| {
+            thermo
+                .state_from((
+                    TestFluid,
+                    ThermodynamicTemperature::new::<degree_celsius>(celsius),
+                    saturated_pressure(thermo, saturation),
+                ))
+                .unwrap()
+        };
+        SuctionLineInput {
+            liquid: state(30.0, 40.0),
+            vapor: state(0.0, -5.0),
+            mass_flow: MassRate::new::<kilogram_per_second>(0.1),
+        }
+    }
+
+    fn effectiveness(value: f64) -> SuctionLineSizing {
+        SuctionLineSizing::Effectiveness(Constrained::new(Ratio::new::<ratio>(value)).unwrap())
+    }
+
+    #[test]
+    fn effectiveness_sizing_balances_both_streams() {
+        let thermo = TestRefrigerant::ammonia_like();
+        let hx = SuctionLineHx::new(effectiveness(0.6), &thermo).unwrap();
+        let input = input(&thermo);
+
+        let output = hx.evaluate(&input).unwrap();
+
+        assert!(output.liquid.temperature < input.liquid.temperature);
+        assert!(output.vapor.temperature > input.vapor.temperature);
+        assert!(output.vapor.temperature < input.liquid.temperature);
+        assert_relative_eq!(output.effectiveness.get::<ratio>(), 0.6);
+
+        let gained = input.mass_flow
+            * (thermo.enthalpy(&output.vapor).unwrap() - thermo.enthalpy(&input.vapor).unwrap());
+        let lost = input.mass_flow
+            * (thermo.enthalpy(&input.liquid).unwrap() - thermo.enthalpy(&output.liquid).unwrap());
+        let q = output.heat_transfer_rate.get::<kilowatt>();
+        assert_relative_eq!(gained.get::<kilowatt>(), q, max_relative = 1e-9);
+        assert_relative_eq!(lost.get::<kilowatt>(), q, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn full_effectiveness_swaps_balanced_inlet_temperatures() {
+        let thermo = TestRefrigerant::ammonia_like();
+        let hx = SuctionLineHx::new(effectiveness(1.0), &thermo).unwrap();
+        let input = input(&thermo);
+
+        let output = hx.evaluate(&input).unwrap();
+
+        // Balanced streams swap inlet temperatures.
+        assert_relative_eq!(
+            output.vapor.temperature.get::<degree_celsius>(),
+            30.0,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            output.liquid.temperature.get::<degree_celsius>(),
+            0.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn conductance_sizing_matches_balanced_counterflow() {
+        let thermo = TestRefrigerant::ammonia_like();
+        let input = input(&thermo);
+        let solve = |ua| {
+            SuctionLineHx::new(
+                SuctionLineSizing::Conductance {
+                    ua: ThermalConductance::new::<kilowatt_per_kelvin>(ua),
+                    segments: 10,
+                },
+                &thermo,
+            )
+            .unwrap()
+            .evaluate(&input)
+            .unwrap()
+        };
+
+        let none = solve(0.0);
+        assert_eq!(none.heat_transfer_rate, Power::ZERO);
+        assert_eq!(none.liquid, input.liquid);
+
+        // Liquid and vapor share one specific heat in the test refrigerant,
+        // so the exchanger is balanced and `ε = NTU / (1 + NTU)`.
+        let capacitance_rate = 0.1 * 2.2;
+        for ua in [0.05, 0.5, 2.0] {
+            let ntu = ua / capacitance_rate;
+            assert_relative_eq!(
+                solve(ua).effectiveness.get::<ratio>(),
+                ntu / (1.0 + ntu),
+                max_relative = 1e-5
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_sizing_and_flow() {
+        let thermo = TestRefrigerant::ammonia_like();
+        let conductance = |ua, segments| {
+            SuctionLineHx::<TestFluid, _>::new(
+                SuctionLineSizing::Conductance {
+                    ua: ThermalConductance::new::<kilowatt_per_kelvin>(ua),
+                    segments,
+                },
+                &thermo,
+            )
+        };
+        assert!(matches!(
+            conductance(-1.0, 10),
+            Err(SuctionLineError::InvalidConductance(_))
+        ));
+        assert!(matches!(
+            conductance(1.0, 7),
+            Err(SuctionLineError::Exchanger(
+                RecuperatorGivenUaError::UnsupportedSegments(7)
+            ))
+        ));
+
+        let hx = SuctionLineHx::new(effectiveness(0.5), &thermo).unwrap();
+        let stopped = SuctionLineInput {
+            mass_flow: MassRate::ZERO,
+            ..input(&thermo)
+        };
+        assert!(matches!(
+            hx.evaluate(&stopped),
+            Err(SuctionLineError::NonPositiveMassFlow(_))
+        ));
+    }
+
+    #[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
+    mod coolprop_tests {
+        use super::*;
+
+        use uom::si::pressure::kilopascal;
+
+        use crate::support::thermo::{fluid::Ammonia, model::CoolProp};
+
+        #[test]
+        fn ammonia_vapor_limits_the_exchange() {
+            let thermo = CoolProp::<Ammonia>::new().unwrap();
+            let state = |celsius, kpa| {
+                thermo
+                    .state_from((
+                        Ammonia,
+                        ThermodynamicTemperature::new::<degree_celsius>(celsius),
+                        Pressure::new::<kilopascal>(kpa),
+                    ))
+                    .unwrap()
+            };
+            // Subcooled liquid at about 40 °C condensing, superheated vapor at
+            // about -5 °C evaporating.
+            let input = SuctionLineInput {
+                liquid: state(30.0, 1555.0),
+                vapor: state(0.0, 355.0),
+                mass_flow: MassRate::new::<kilogram_per_second>(0.1),
+            };
+
+            let full = SuctionLineHx::new(effectiveness(1.0), &thermo)
+                .unwrap()
+                .evaluate(&input)
+                .unwrap();
+
+            // Liquid ammonia has about twice the specific heat of the vapor,
+            // so the vapor reaches the liquid inlet temperature first.
+            assert_relative_eq!(
+                full.vapor.temperature.get::<degree_celsius>(),
+                30.0,
+                max_relative = 1e-6
+            );
+            assert!(full.liquid.temperature.get::<degree_celsius>() > 10.0);
+
+            let sized = SuctionLineHx::new(
+                SuctionLineSizing::Conductance {
+                    ua: ThermalConductance::new::<kilowatt_per_kelvin>(0.5),
+                    segments: 10,
+                },
+                &thermo,
+            )
+            .unwrap()
+            .evaluate(&input)
+            .unwrap();
+            let effectiveness = sized.effectiveness.get::<ratio>();
+            assert!(
+                0.5 < effectiveness && effectiveness < 1.0,
+                "{effectiveness}"
+            );
+        }
+    }
+}
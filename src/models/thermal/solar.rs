@@ -0,0 +1,5 @@
+//! Solar thermal systems.
+
+pub mod dhw;
+pub mod field;
+pub mod thermosiphon;
@@ -0,0 +1,121 @@
+//! Solar domestic hot water system template.
+//!
+//! A [`SolarDhwSystem`] composes a collector array, insulated pipes, an
+//! external heat exchanger, a stratified tank, a differential pump
+//! controller, and an electric backup element into one model. Give it a
+//! series of weather and draw conditions and it reports the solar fraction,
+//! the energy balance of the loop and tank, and the operating point of each
+//! step.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::{
+//!         solar::dhw::{
+//!             AuxiliaryHeater, Collector, SolarDhwConditions, SolarDhwConfig, SolarDhwInput,
+//!             SolarDhwParameters, SolarDhwSystem, SolarLoop, Storage,
+//!         },
+//!         tank::stratified::Fluid,
+//!     },
+//!     support::control::thermostat::{DifferentialBand, Deadband},
+//! };
+//! use uom::si::{
+//!     area::square_meter,
+//!     f64::{
+//!         Area, HeatFluxDensity, HeatTransfer, Length, MassDensity, MassRate, Power, Ratio,
+//!         SpecificHeatCapacity, TemperatureInterval, ThermalConductance, ThermalConductivity,
+//!         ThermodynamicTemperature, Time, Volume, VolumeRate,
+//!     },
+//!     heat_flux_density::watt_per_square_meter,
+//!     heat_transfer::watt_per_square_meter_kelvin,
+//!     length::meter,
+//!     mass_density::kilogram_per_cubic_meter,
+//!     mass_rate::kilogram_per_second,
+//!     power::kilowatt,
+//!     ratio::ratio,
+//!     specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+//!     temperature_interval::degree_celsius as delta_celsius,
+//!     thermal_conductance::watt_per_kelvin,
+//!     thermal_conductivity::watt_per_meter_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//!     time::minute,
+//!     volume::liter,
+//!     volume_rate::liter_per_minute,
+//! };
+//!
+//! let delta = TemperatureInterval::new::<delta_celsius>;
+//! let system = SolarDhwSystem::new(
+//!     SolarDhwParameters {
+//!         collector: Collector {
+//!             area: Area::new::<square_meter>(4.0),
+//!             optical_efficiency: Ratio::new::<ratio>(0.75),
+//!             linear_loss: HeatTransfer::new::<watt_per_square_meter_kelvin>(3.5),
+//!             quadratic_loss: HeatTransfer::new::<watt_per_square_meter_kelvin>(0.015) / delta(1.0),
+//!         },
+//!         solar_loop: SolarLoop {
+//!             specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(3.8),
+//!             mass_flow: MassRate::new::<kilogram_per_second>(0.06),
+//!             pipe_ua: ThermalConductance::new::<watt_per_kelvin>(3.0),
+//!             hx_ua: ThermalConductance::new::<watt_per_kelvin>(400.0),
+//!             tank_flow: VolumeRate::new::<liter_per_minute>(3.5),
+//!         },
+//!         storage: Storage {
+//!             volume: Volume::new::<liter>(300.0),
+//!             height: Length::new::<meter>(1.6),
+//!             u_value: HeatTransfer::new::<watt_per_square_meter_kelvin>(0.6),
+//!             room_temperature: ThermodynamicTemperature::new::<degree_celsius>(20.0),
+//!             water: Fluid {
+//!                 density: MassDensity::new::<kilogram_per_cubic_meter>(990.0),
+//!                 specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(4.18),
+//!                 thermal_conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(0.6),
+//!             },
+//!         },
+//!         auxiliary: AuxiliaryHeater {
+//!             power: Power::new::<kilowatt>(3.0),
+//!             setpoint: ThermodynamicTemperature::new::<degree_celsius>(50.0),
+//!             deadband: Deadband::new(delta(5.0))?,
+//!         },
+//!         pump_control: DifferentialBand::new(delta(7.0), delta(2.0))?,
+//!     },
+//!     SolarDhwConfig::default(),
+//! )?;
+//!
+//! // Two hours of steady midday sun with no draw.
+//! let noon = SolarDhwConditions {
+//!     irradiance: HeatFluxDensity::new::<watt_per_square_meter>(850.0),
+//!     ambient_temperature: ThermodynamicTemperature::new::<degree_celsius>(25.0),
+//!     mains_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.0),
+//!     draw: VolumeRate::new::<liter_per_minute>(0.0),
+//! };
+//! let output = system.simulate(&SolarDhwInput {
+//!     initial_temperature: ThermodynamicTemperature::new::<degree_celsius>(48.0),
+//!     time_step: Time::new::<minute>(1.0),
+//!     conditions: vec![noon; 120],
+//! })?;
+//!
+//! // The sun alone warms the tank; the element never switches on.
+//! assert_eq!(output.solar_fraction, Ratio::new::<ratio>(1.0));
+//! assert!(output.temperatures[9] > ThermodynamicTemperature::new::<degree_celsius>(55.0));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    AuxiliaryHeater, Collector, SolarDhwConditions, SolarDhwConfig, SolarDhwEnergy, SolarDhwError,
+    SolarDhwInput, SolarDhwOutput, SolarDhwParameters, SolarDhwStep, SolarDhwSystem, SolarLoop,
+    Storage,
+};
+
+impl Model for SolarDhwSystem {
+    type Input = SolarDhwInput;
+    type Output = SolarDhwOutput;
+    type Error = SolarDhwError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.simulate(input)
+    }
+}
@@ -0,0 +1,895 @@
+mod collector;
+mod problem;
+
+use thiserror::Error;
+use twine_core::StepIntegrable;
+use twine_solvers::equation::{
+    bisection,
+    bracket::{Bracket, Sign},
+};
+use uom::{
+    ConstZero,
+    si::{
+        f64::{
+            Energy, HeatFluxDensity, HeatTransfer, Length, MassRate, Power, Ratio,
+            SpecificHeatCapacity, TemperatureInterval, ThermalConductance,
+            ThermodynamicTemperature, Time, Volume, VolumeRate,
+        },
+        temperature_interval::kelvin as delta_kelvin,
+        thermodynamic_temperature::kelvin,
+    },
+};
+
+use crate::{
+    models::thermal::tank::stratified::{
+        AuxHeatFlow, Environment, Fluid, Geometry, Insulation, Location, PortFlow, PortLocation,
+        StratifiedTank, StratifiedTankError, StratifiedTankInput, TankDerivative, TankState,
+    },
+    support::{
+        constraint::{Constraint, NonNegative, StrictlyPositive},
+        control::{
+            SwitchState,
+            thermostat::{
+                differential::{DifferentialBand, DifferentialThermostatInput, differential},
+                setpoint::{Deadband, SetpointThermostatInput, heating},
+            },
+        },
+        hx::{CapacitanceRate, StreamInlet},
+        metrics,
+        units::TemperatureDifference,
+    },
+};
+
+pub use collector::Collector;
+
+use problem::{LoopModel, LoopProblem, LoopState};
+
+/// Number of tank nodes.
+const NODES: usize = 10;
+
+/// Node holding the auxiliary element and its thermostat sensor.
+const ELEMENT_NODE: usize = 7;
+
+type Tank = StratifiedTank<NODES, 2, 1>;
+
+/// The collector loop between the array and the external heat exchanger.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarLoop {
+    /// Specific heat of the loop fluid, typically a glycol mixture.
+    pub specific_heat: SpecificHeatCapacity,
+
+    /// Loop mass flow while the pump runs.
+    pub mass_flow: MassRate,
+
+    /// Thermal conductance to ambient of each of the supply and return pipes.
+    pub pipe_ua: ThermalConductance,
+
+    /// Conductance of the counterflow heat exchanger.
+    pub hx_ua: ThermalConductance,
+
+    /// Tank-side flow through the heat exchanger while the pump runs.
+    ///
+    /// Water is drawn from the bottom of the tank and returned to the top.
+    pub tank_flow: VolumeRate,
+}
+
+/// The stratified storage tank.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Storage {
+    /// Water volume.
+    pub volume: Volume,
+
+    /// Height of the vertical cylindrical tank.
+    pub height: Length,
+
+    /// Heat transfer coefficient of the insulation on every face.
+    pub u_value: HeatTransfer,
+
+    /// Temperature of the room around the tank.
+    pub room_temperature: ThermodynamicTemperature,
+
+    /// Water properties.
+    pub water: Fluid,
+}
+
+/// The electric backup element in the upper part of the tank.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AuxiliaryHeater {
+    /// Element power while on.
+    pub power: Power,
+
+    /// Thermostat setpoint at the element.
+    pub setpoint: ThermodynamicTemperature,
+
+    /// Thermostat deadband below the setpoint.
+    pub deadband: Deadband,
+}
+
+/// Fixed design parameters of a [`SolarDhwSystem`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarDhwParameters {
+    /// Collector array.
+    pub collector: Collector,
+
+    /// Collector loop, pipes, and heat exchanger.
+    pub solar_loop: SolarLoop,
+
+    /// Storage tank.
+    pub storage: Storage,
+
+    /// Backup heater.
+    pub auxiliary: AuxiliaryHeater,
+
+    /// Collector pump control on the collector outlet minus the tank bottom.
+    pub pump_control: DifferentialBand,
+}
+
+/// Solver configuration for [`SolarDhwSystem`].
+#[derive(Debug, Clone, Copy)]
+pub struct SolarDhwConfig {
+    /// Absolute tolerance on the collector loop temperature balance.
+    pub temp_abs_tol: TemperatureInterval,
+
+    /// Maximum number of collector loop iterations per step.
+    pub max_iters: usize,
+}
+
+impl Default for SolarDhwConfig {
+    fn default() -> Self {
+        Self {
+            temp_abs_tol: TemperatureInterval::new::<delta_kelvin>(1e-9),
+            max_iters: 100,
+        }
+    }
+}
+
+/// Weather and demand during one time step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarDhwConditions {
+    /// Solar irradiance on the collector plane.
+    pub irradiance: HeatFluxDensity,
+
+    /// Outdoor air temperature around the collectors and pipes.
+    pub ambient_temperature: ThermodynamicTemperature,
+
+    /// Cold water supply temperature.
+    pub mains_temperature: ThermodynamicTemperature,
+
+    /// Hot water draw, replaced by mains water at the bottom of the tank.
+    pub draw: VolumeRate,
+}
+
+/// Input to a [`SolarDhwSystem`] simulation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolarDhwInput {
+    /// Uniform tank temperature at the start.
+    pub initial_temperature: ThermodynamicTemperature,
+
+    /// Duration of each step.
+    pub time_step: Time,
+
+    /// Weather and demand for each step, in order.
+    pub conditions: Vec<SolarDhwConditions>,
+}
+
+/// Energy totals over a simulation.
+///
+/// The tank loss is the balance of the other tank terms, so
+/// `solar_to_tank + auxiliary = load + tank_loss + storage_change` holds by
+/// construction. The loop terms are independent:
+/// `collected = pipe_loss + solar_to_tank` to within the loop tolerance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarDhwEnergy {
+    /// Useful heat gained by the collectors.
+    pub collected: Energy,
+
+    /// Heat lost from the supply and return pipes.
+    pub pipe_loss: Energy,
+
+    /// Heat delivered to the tank through the heat exchanger.
+    pub solar_to_tank: Energy,
+
+    /// Heat from the backup element.
+    pub auxiliary: Energy,
+
+    /// Heat carried out by hot water draws, relative to mains temperature.
+    pub load: Energy,
+
+    /// Heat lost through the tank insulation.
+    pub tank_loss: Energy,
+
+    /// Increase in stored heat.
+    pub storage_change: Energy,
+}
+
+/// Operating point during one step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarDhwStep {
+    /// Collector pump state.
+    pub pump: SwitchState,
+
+    /// Backup element state.
+    pub heater: SwitchState,
+
+    /// Collector outlet temperature while the pump runs.
+    pub collector_outlet: Option<ThermodynamicTemperature>,
+
+    /// Heat delivered to the tank through the heat exchanger.
+    pub solar_to_tank: Power,
+
+    /// Hot water delivery temperature at the top of the tank.
+    pub delivery_temperature: ThermodynamicTemperature,
+}
+
+/// Output of a [`SolarDhwSystem`] simulation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolarDhwOutput {
+    /// Share of the tank's heat input supplied by the sun,
+    /// `solar_to_tank / (solar_to_tank + auxiliary)`.
+    ///
+    /// Zero if the tank received no heat.
+    pub solar_fraction: Ratio,
+
+    /// Energy totals.
+    pub energy: SolarDhwEnergy,
+
+    /// Total time the collector pump ran.
+    pub pump_runtime: Time,
+
+    /// Tank temperatures at the end, from bottom to top.
+    pub temperatures: [ThermodynamicTemperature; NODES],
+
+    /// Operating point of each step.
+    pub steps: Vec<SolarDhwStep>,
+}
+
+/// Errors from [`SolarDhwSystem`] construction and simulation.
+#[derive(Debug, Error)]
+pub enum SolarDhwError {
+    /// A design parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// The time step or the conditions of a step are invalid.
+    #[error("invalid input at step {step}: {message}")]
+    InvalidInput {
+        /// Index of the offending step.
+        step: usize,
+
+        /// What was wrong.
+        message: &'static str,
+    },
+
+    /// The storage tank rejected its configuration or a port flow.
+    #[error(transparent)]
+    Tank(#[from] StratifiedTankError),
+
+    /// The collector loop temperature balance failed to converge.
+    #[error("collector loop failed to converge at step {step}: {message}")]
+    Convergence {
+        /// Index of the step.
+        step: usize,
+
+        /// Details about the convergence failure.
+        message: String,
+    },
+}
+
+/// A solar domestic hot water system.
+///
+/// A collector array heats a glycol loop that passes through supply and
+/// return pipes to an external counterflow heat exchanger. The exchanger's
+/// tank side draws water from the bottom of a stratified tank and returns it
+/// to the top. A differential thermostat runs both pumps when the collector
+/// outlet is warm enough relative to the tank bottom, and a setpoint
+/// thermostat switches an electric element in the upper tank. Hot water is
+/// drawn from the top and replaced with mains water at the bottom.
+///
+/// The loop is solved at steady state each step and the ten tank nodes are
+/// integrated with explicit Euler steps. Controllers see the tank at the
+/// start of each step.
+#[derive(Debug)]
+pub struct SolarDhwSystem {
+    parameters: SolarDhwParameters,
+    config: SolarDhwConfig,
+    tank: Tank,
+}
+
+fn validate_input(input: &SolarDhwInput) -> Result<(), SolarDhwError> {
+    if StrictlyPositive::check(&input.time_step).is_err() || !input.time_step.is_finite() {
+        return Err(SolarDhwError::InvalidInput {
+            step: 0,
+            message: "time step must be strictly positive and finite",
+        });
+    }
+    if !input.initial_temperature.is_finite() {
+        return Err(SolarDhwError::InvalidInput {
+            step: 0,
+            message: "initial temperature must be finite",
+        });
+    }
+    Ok(())
+}
+
+impl SolarDhwSystem {
+    /// Creates a system from its design parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolarDhwError::InvalidParameter`] if a parameter is
+    /// non-physical, or [`SolarDhwError::Tank`] if the tank rejects its
+    /// geometry or water properties.
+    pub fn new(
+        parameters: SolarDhwParameters,
+        config: SolarDhwConfig,
+    ) -> Result<Self, SolarDhwError> {
+        let SolarDhwParameters {
+            collector,
+            solar_loop,
+            storage,
+            auxiliary,
+            pump_control: _,
+        } = parameters;
+
+        collector
+            .validate()
+            .map_err(SolarDhwError::InvalidParameter)?;
+        if StrictlyPositive::check(&solar_loop.specific_heat).is_err()
+            || StrictlyPositive::check(&solar_loop.mass_flow).is_err()
+            || StrictlyPositive::check(&solar_loop.tank_flow).is_err()
+        {
+            return Err(SolarDhwError::InvalidParameter(
+                "loop specific heat and flows must be strictly positive",
+            ));
+        }
+        if NonNegative::check(&solar_loop.pipe_ua).is_err()
+            || NonNegative::check(&solar_loop.hx_ua).is_err()
+        {
+            return Err(SolarDhwError::InvalidParameter(
+                "pipe and heat exchanger UA must be non-negative",
+            ));
+        }
+        if StrictlyPositive::check(&storage.volume).is_err()
+            || StrictlyPositive::check(&storage.height).is_err()
+        {
+            return Err(SolarDhwError::InvalidParameter(
+                "tank volume and height must be strictly positive",
+            ));
+        }
+        if NonNegative::check(&storage.u_value).is_err() {
+            return Err(SolarDhwError::InvalidParameter(
+                "tank U-value must be non-negative",
+            ));
+        }
+        if NonNegative::check(&auxiliary.power).is_err() {
+            return Err(SolarDhwError::InvalidParameter(
+                "auxiliary power must be non-negative",
+            ));
+        }
+
+        let diameter: Length =
+            (storage.volume / storage.height * 4.0 / std::f64::consts::PI).sqrt();
+        let tank = StratifiedTank::new::<NODES>(
+            storage.water,
+            Geometry::VerticalCylinder {
+                diameter,
+                height: storage.height,
+            },
+            Insulation::uniform(storage.u_value),
+            [Location::point_in_node(ELEMENT_NODE)],
+            [
+                PortLocation {
+                    inlet: Location::tank_top(),
+                    outlet: Location::tank_bottom(),
+                },
+                PortLocation {
+                    inlet: Location::tank_bottom(),
+                    outlet: Location::tank_top(),
+                },
+            ],
+        )?;
+
+        Ok(Self {
+            parameters,
+            config,
+            tank,
+        })
+    }
+
+    /// Simulates the system through each step of `input.conditions`.
+    ///
+    /// Explicit Euler integration needs the flow through the tank in one step
+    /// to stay below one node's volume (a tenth of the tank).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SolarDhwError`] if the time step or a step's conditions are
+    /// invalid, or the collector loop fails to converge.
+    pub fn simulate(&self, input: &SolarDhwInput) -> Result<SolarDhwOutput, SolarDhwError> {
+        validate_input(input)?;
+        let SolarDhwInput {
+            initial_temperature,
+            time_step: dt,
+            ref conditions,
+        } = *input;
+
+        let SolarDhwParameters {
+            solar_loop,
+            storage,
+            auxiliary,
+            ..
+        } = self.parameters;
+        let water = storage.water;
+        // NODES is a small constant, so the cast is exact.
+        #[allow(clippy::cast_precision_loss)]
+        let node_volume = storage.volume / NODES as f64;
+        let room = Environment {
+            bottom: storage.room_temperature,
+            side: storage.room_temperature,
+            top: storage.room_temperature,
+        };
+        let element = AuxHeatFlow::heating(auxiliary.power)?;
+
+        let mut state = TankState {
+            temperatures: [initial_temperature; NODES],
+        };
+        let mut pump = SwitchState::Off;
+        let mut heater = SwitchState::Off;
+        let mut energy = SolarDhwEnergy {
+            collected: Energy::ZERO,
+            pipe_loss: Energy::ZERO,
+            solar_to_tank: Energy::ZERO,
+            auxiliary: Energy::ZERO,
+            load: Energy::ZERO,
+            tank_loss: Energy::ZERO,
+            storage_change: Energy::ZERO,
+        };
+        let mut pump_runtime = Time::ZERO;
+        let mut steps = Vec::with_capacity(conditions.len());
+
+        for (step, c) in conditions.iter().enumerate() {
+            self.validate_conditions(step, c, dt, node_volume)?;
+
+            let tank = self.stabilize(&state.temperatures, room);
+            let bottom = tank[0];
+            let top = tank[NODES - 1];
+
+            // Solve the loop as if the pump ran, then let the controller decide.
+            let solved = self.solve_loop(step, c, bottom)?;
+            (pump, heater) = self.control(pump, heater, solved.as_ref(), &tank);
+
+            let running = solved.filter(|_| pump == SwitchState::On);
+            let solar_port = match running {
+                Some(ref solved) => PortFlow::new(
+                    solar_loop.tank_flow,
+                    solved.hx.streams[1].outlet_temperature,
+                )?,
+                None => PortFlow::new(VolumeRate::ZERO, bottom)?,
+            };
+            let aux = match heater {
+                SwitchState::On => element,
+                SwitchState::Off => AuxHeatFlow::None,
+            };
+
+            let draw_port = PortFlow::new(c.draw, c.mains_temperature)?;
+            state = self.step_tank(&state, [solar_port, draw_port], aux, room, dt);
+
+            let solar_to_tank = running.map_or(Power::ZERO, |solved| {
+                solved.hx.streams[1].heat_flow.signed()
+            });
+            if let Some(solved) = running {
+                energy.collected += solved.collected * dt;
+                energy.pipe_loss += solved.pipe_loss * dt;
+                energy.solar_to_tank += solar_to_tank * dt;
+                pump_runtime += dt;
+            }
+            if heater == SwitchState::On {
+                energy.auxiliary += auxiliary.power * dt;
+            }
+            energy.load +=
+                c.draw * water.density * water.specific_heat * top.minus(c.mains_temperature) * dt;
+
+            steps.push(SolarDhwStep {
+                pump,
+                heater,
+                collector_outlet: running.map(|solved| solved.collector_outlet),
+                solar_to_tank,
+                delivery_temperature: top,
+            });
+        }
+
+        let temperatures = self.stabilize(&state.temperatures, room);
+        energy.storage_change = temperatures
+            .iter()
+            .map(|t| {
+                node_volume * water.density * water.specific_heat * t.minus(initial_temperature)
+            })
+            .sum();
+        energy.tank_loss =
+            energy.solar_to_tank + energy.auxiliary - energy.load - energy.storage_change;
+
+        let solar_fraction = metrics::solar_fraction(energy.solar_to_tank, energy.auxiliary);
+
+        Ok(SolarDhwOutput {
+            solar_fraction,
+            energy,
+            pump_runtime,
+            temperatures,
+            steps,
+        })
+    }
+
+    fn validate_conditions(
+        &self,
+        step: usize,
+        conditions: &SolarDhwConditions,
+        dt: Time,
+        node_volume: Volume,
+    ) -> Result<(), SolarDhwError> {
+        let invalid = |message| Err(SolarDhwError::InvalidInput { step, message });
+
+        if NonNegative::check(&conditions.irradiance).is_err() {
+            return invalid("irradiance must be non-negative");
+        }
+        if !(conditions.ambient_temperature.is_finite() && conditions.mains_temperature.is_finite())
+        {
+            return invalid("ambient and mains temperatures must be finite");
+        }
+        if NonNegative::check(&conditions.draw).is_err() {
+            return invalid("draw must be non-negative");
+        }
+        if (conditions.draw + self.parameters.solar_loop.tank_flow) * dt > node_volume {
+            return invalid("time step too long: flow per step exceeds one tank node");
+        }
+        Ok(())
+    }
+
+    /// Returns the next pump and element states for the mixed tank.
+    ///
+    /// The pump stays off when there is no loop solution to compare against.
+    fn control(
+        &self,
+        pump: SwitchState,
+        heater: SwitchState,
+        solved: Option<&LoopState>,
+        tank: &[ThermodynamicTemperature; NODES],
+    ) -> (SwitchState, SwitchState) {
+        let pump = match solved {
+            Some(solved) => differential(DifferentialThermostatInput {
+                state: pump,
+                source: solved.collector_outlet,
+                store: tank[0],
+                band: self.parameters.pump_control,
+            }),
+            None => SwitchState::Off,
+        };
+        let heater = heating(SetpointThermostatInput {
+            state: heater,
+            temperature: tank[ELEMENT_NODE],
+            setpoint: self.parameters.auxiliary.setpoint,
+            deadband: self.parameters.auxiliary.deadband,
+        });
+        (pump, heater)
+    }
+
+    /// Advances the tank over one step with the given port and element flows.
+    fn step_tank(
+        &self,
+        state: &TankState<NODES>,
+        port_flows: [PortFlow; 2],
+        aux: AuxHeatFlow,
+        environment: Environment,
+        dt: Time,
+    ) -> TankState<NODES> {
+        let output = self.tank.evaluate(&StratifiedTankInput {
+            temperatures: state.temperatures,
+            port_flows,
+            aux_heat_flows: [aux],
+            environment,
+        });
+        TankState {
+            temperatures: output.temperatures,
+        }
+        .step(
+            TankDerivative {
+                rates: output.derivatives,
+            },
+            dt,
+        )
+    }
+
+    /// Returns the tank temperatures after buoyancy mixing.
+    fn stabilize(
+        &self,
+        temperatures: &[ThermodynamicTemperature; NODES],
+        environment: Environment,
+    ) -> [ThermodynamicTemperature; NODES] {
+        let idle = PortFlow::new(VolumeRate::ZERO, temperatures[0])
+            .expect("zero flow at a finite temperature is valid");
+        self.tank
+            .evaluate(&StratifiedTankInput {
+                temperatures: *temperatures,
+                port_flows: [idle, idle],
+                aux_heat_flows: [AuxHeatFlow::None],
+                environment,
+            })
+            .temperatures
+    }
+
+    /// Solves the collector loop with the pumps running.
+    ///
+    /// Returns `None` when there is no sun to collect.
+    fn solve_loop(
+        &self,
+        step: usize,
+        conditions: &SolarDhwConditions,
+        tank_bottom: ThermodynamicTemperature,
+    ) -> Result<Option<LoopState>, SolarDhwError> {
+        let SolarDhwParameters {
+            collector,
+            solar_loop,
+            storage,
+            ..
+        } = &self.parameters;
+        let irradiance = conditions.irradiance;
+        let ambient = conditions.ambient_temperature;
+        if irradiance == HeatFluxDensity::ZERO || collector.optical_efficiency == Ratio::ZERO {
+            return Ok(None);
+        }
+
+        let convergence = |message: String| SolarDhwError::Convergence { step, message };
+        let tank_inlet = StreamInlet::new(
+            CapacitanceRate::from_quantity(
+                solar_loop.tank_flow * storage.water.density * storage.water.specific_heat,
+            )
+            .map_err(|e| convergence(e.to_string()))?,
+            tank_bottom,
+        );
+        let model = LoopModel {
+            collector,
+            irradiance,
+            ambient,
+            capacitance_rate: solar_loop.mass_flow * solar_loop.specific_heat,
+            pipe_ua: solar_loop.pipe_ua,
+            hx_ua: solar_loop.hx_ua,
+            tank_inlet,
+        };
+
+        // The bracket is known from physics without evaluation. Every part of
+        // the loop pulls the fluid toward ambient, the tank bottom, or the
+        // collector stagnation temperature, so a loop starting below all of
+        // them returns warmer and one starting above all of them returns
+        // cooler. A kelvin of margin keeps both residuals strictly signed.
+        let margin = TemperatureInterval::new::<delta_kelvin>(1.0);
+        let lower = ambient.min(tank_bottom) - margin;
+        let upper = collector
+            .stagnation(irradiance, ambient)
+            .max(tank_bottom)
+            .max(ambient)
+            + margin;
+        let bracket = Bracket::new(
+            (lower.get::<kelvin>(), Sign::Positive),
+            (upper.get::<kelvin>(), Sign::Negative),
+        )
+        .map_err(|error| convergence(error.to_string()))?;
+
+        let config = bisection::Config {
+            max_iters: self.config.max_iters,
+            x_abs_tol: 0.0,
+            x_rel_tol: 0.0,
+            residual_tol: self.config.temp_abs_tol.get::<delta_kelvin>(),
+        };
+        let solution = bisection::solve_from_bracket(
+            &model,
+            &LoopProblem,
+            bracket,
+            &config,
+            |#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+             event: &bisection::Event<'_, _, _>| {
+                #[cfg(feature = "tracing")]
+                crate::support::numerics::solve::trace_bisection(event);
+                None
+            },
+        )
+        .map_err(|error| convergence(error.to_string()))?;
+
+        if solution.status != bisection::Status::Converged {
+            return Err(convergence("iteration limit reached".to_owned()));
+        }
+
+        Ok(Some(solution.snapshot.output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        area::square_meter,
+        energy::kilowatt_hour,
+        heat_flux_density::watt_per_square_meter,
+        heat_transfer::watt_per_square_meter_kelvin,
+        length::meter,
+        mass_density::kilogram_per_cubic_meter,
+        mass_rate::kilogram_per_second,
+        power::kilowatt,
+        ratio::ratio,
+        specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+        temperature_interval::degree_celsius as delta_celsius,
+        thermal_conductance::watt_per_kelvin,
+        thermal_conductivity::watt_per_meter_kelvin,
+        thermodynamic_temperature::degree_celsius,
+        time::{hour, minute},
+        volume::liter,
+        volume_rate::liter_per_minute,
+    };
+
+    fn parameters() -> SolarDhwParameters {
+        let delta = TemperatureInterval::new::<delta_celsius>;
+        SolarDhwParameters {
+            collector: Collector {
+                area: uom::si::f64::Area::new::<square_meter>(4.0),
+                optical_efficiency: Ratio::new::<ratio>(0.75),
+                linear_loss: HeatTransfer::new::<watt_per_square_meter_kelvin>(3.5),
+                quadratic_loss: HeatTransfer::new::<watt_per_square_meter_kelvin>(0.015)
+                    / delta(1.0),
+            },
+            solar_loop: SolarLoop {
+                specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(3.8),
+                mass_flow: MassRate::new::<kilogram_per_second>(0.06),
+                pipe_ua: ThermalConductance::new::<watt_per_kelvin>(3.0),
+                hx_ua: ThermalConductance::new::<watt_per_kelvin>(400.0),
+                tank_flow: VolumeRate::new::<liter_per_minute>(3.5),
+            },
+            storage: Storage {
+                volume: Volume::new::<liter>(300.0),
+                height: Length::new::<meter>(1.6),
+                u_value: HeatTransfer::new::<watt_per_square_meter_kelvin>(0.6),
+                room_temperature: ThermodynamicTemperature::new::<degree_celsius>(20.0),
+                water: Fluid {
+                    density: uom::si::f64::MassDensity::new::<kilogram_per_cubic_meter>(990.0),
+                    specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(4.18),
+                    thermal_conductivity: uom::si::f64::ThermalConductivity::new::<
+                        watt_per_meter_kelvin,
+                    >(0.6),
+                },
+            },
+            auxiliary: AuxiliaryHeater {
+                power: Power::new::<kilowatt>(3.0),
+                setpoint: ThermodynamicTemperature::new::<degree_celsius>(50.0),
+                deadband: Deadband::new(delta(5.0)).unwrap(),
+            },
+            pump_control: DifferentialBand::new(delta(7.0), delta(2.0)).unwrap(),
+        }
+    }
+
+    /// One day of minute steps with a clear-sky half-sine of sun from 6 to 18
+    /// and draws of 10 L/min for 15 minutes at 7:00 and 19:00.
+    fn day(peak: f64) -> Vec<SolarDhwConditions> {
+        (0..24 * 60)
+            .map(|step| {
+                let clock = f64::from(step) / 60.0;
+                let sun = if (6.0..18.0).contains(&clock) {
+                    peak * (std::f64::consts::PI * (clock - 6.0) / 12.0).sin()
+                } else {
+                    0.0
+                };
+                let draw = if (7.0..7.25).contains(&clock) || (19.0..19.25).contains(&clock) {
+                    10.0
+                } else {
+                    0.0
+                };
+                SolarDhwConditions {
+                    irradiance: HeatFluxDensity::new::<watt_per_square_meter>(sun),
+                    ambient_temperature: ThermodynamicTemperature::new::<degree_celsius>(20.0),
+                    mains_temperature: ThermodynamicTemperature::new::<degree_celsius>(12.0),
+                    draw: VolumeRate::new::<liter_per_minute>(draw),
+                }
+            })
+            .collect()
+    }
+
+    fn run(peak: f64) -> SolarDhwOutput {
+        let system = SolarDhwSystem::new(parameters(), SolarDhwConfig::default()).unwrap();
+        system
+            .simulate(&SolarDhwInput {
+                initial_temperature: ThermodynamicTemperature::new::<degree_celsius>(45.0),
+                time_step: Time::new::<minute>(1.0),
+                conditions: day(peak),
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn sunny_day_covers_most_of_the_load() {
+        let output = run(900.0);
+        let energy = output.energy;
+
+        assert!(output.solar_fraction > Ratio::new::<ratio>(0.6));
+        assert!(output.pump_runtime > Time::new::<hour>(6.0));
+        assert!(output.pump_runtime < Time::new::<hour>(12.0));
+
+        // The loop balance is independent of the tank balance.
+        assert_relative_eq!(
+            energy.collected.get::<kilowatt_hour>(),
+            (energy.pipe_loss + energy.solar_to_tank).get::<kilowatt_hour>(),
+            max_relative = 1e-6
+        );
+        assert!(energy.pipe_loss > Energy::ZERO);
+
+        // Closing the tank balance leaves a small, positive insulation loss.
+        assert!(energy.tank_loss > Energy::ZERO);
+        assert!(energy.tank_loss < 0.2 * energy.load);
+    }
+
+    #[test]
+    fn cloudy_day_leans_on_the_element() {
+        let sunny = run(900.0);
+        let cloudy = run(150.0);
+
+        assert!(cloudy.solar_fraction < sunny.solar_fraction);
+        assert!(cloudy.pump_runtime < sunny.pump_runtime);
+
+        // Without the sun the lower tank is drawn down, since the element
+        // only heats the water above it.
+        assert!(cloudy.energy.storage_change < sunny.energy.storage_change);
+        assert!(cloudy.energy.auxiliary > Energy::ZERO);
+
+        // By the end of the day the element has reheated the water around
+        // it to within its deadband.
+        let element = cloudy.temperatures[ELEMENT_NODE];
+        assert!(element > ThermodynamicTemperature::new::<degree_celsius>(44.9));
+    }
+
+    #[test]
+    fn pump_waits_for_the_turn_on_difference() {
+        let output = run(900.0);
+
+        // Nothing is collected in the dark, and the pump starts only once the
+        // collector outlet clears the tank bottom by the turn-on difference.
+        for step in &output.steps[..6 * 60] {
+            assert_eq!(step.pump, SwitchState::Off);
+        }
+        let first_on = output
+            .steps
+            .iter()
+            .position(|step| step.pump == SwitchState::On)
+            .unwrap();
+        assert!(first_on > 6 * 60);
+        assert!(output.steps[first_on].collector_outlet.is_some());
+    }
+
+    #[test]
+    fn rejects_invalid_inputs() {
+        let mut bad = parameters();
+        bad.collector.optical_efficiency = Ratio::new::<ratio>(1.5);
+        assert!(matches!(
+            SolarDhwSystem::new(bad, SolarDhwConfig::default()),
+            Err(SolarDhwError::InvalidParameter(_))
+        ));
+
+        let system = SolarDhwSystem::new(parameters(), SolarDhwConfig::default()).unwrap();
+        let mut conditions = day(900.0);
+        conditions[3].draw = VolumeRate::new::<liter_per_minute>(-1.0);
+        assert!(matches!(
+            system.simulate(&SolarDhwInput {
+                initial_temperature: ThermodynamicTemperature::new::<degree_celsius>(45.0),
+                time_step: Time::new::<minute>(1.0),
+                conditions,
+            }),
+            Err(SolarDhwError::InvalidInput { step: 3, .. })
+        ));
+
+        // Ten minutes of draw at 10 L/min is more than one 30 L node.
+        assert!(matches!(
+            system.simulate(&SolarDhwInput {
+                initial_temperature: ThermodynamicTemperature::new::<degree_celsius>(45.0),
+                time_step: Time::new::<minute>(10.0),
+                conditions: day(900.0),
+            }),
+            Err(SolarDhwError::InvalidInput { .. })
+        ));
+    }
+}
@@ -0,0 +1,134 @@
+use uom::si::f64::{
+    Area, HeatFluxDensity, HeatTransfer, Power, Ratio, TemperatureInterval,
+    ThermodynamicTemperature,
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive, UnitInterval},
+    units::{QuadraticHeatTransferCoefficient, TemperatureDifference},
+};
+
+/// A solar collector array rated by its efficiency curve.
+///
+/// The useful gain per unit area is
+/// `q = η₀·G − a₁·(T_in − T_amb) − a₂·(T_in − T_amb)·|T_in − T_amb|`,
+/// with the coefficients referenced to the collector inlet temperature (the
+/// Hottel–Whillier `F_R` form). The absolute value keeps the quadratic term a
+/// loss when the inlet is colder than ambient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Collector {
+    /// Aperture area of the whole array.
+    pub area: Area,
+
+    /// Zero-loss efficiency `η₀`.
+    pub optical_efficiency: Ratio,
+
+    /// Linear heat loss coefficient `a₁`.
+    pub linear_loss: HeatTransfer,
+
+    /// Quadratic heat loss coefficient `a₂`.
+    pub quadratic_loss: QuadraticHeatTransferCoefficient,
+}
+
+impl Collector {
+    /// Checks that the rating describes a physical collector.
+    pub(crate) fn validate(&self) -> Result<(), &'static str> {
+        if StrictlyPositive::check(&self.area).is_err() {
+            return Err("collector area must be strictly positive");
+        }
+        if UnitInterval::check(&self.optical_efficiency).is_err() {
+            return Err("collector optical efficiency must be in [0, 1]");
+        }
+        if NonNegative::check(&self.linear_loss).is_err()
+            || NonNegative::check(&self.quadratic_loss).is_err()
+            || self.linear_loss.value + self.quadratic_loss.value <= 0.0
+        {
+            return Err("collector loss coefficients must be non-negative and not both zero");
+        }
+        Ok(())
+    }
+
+    /// Returns the useful heat gain with fluid entering at `inlet`.
+    pub(crate) fn gain(
+        &self,
+        irradiance: HeatFluxDensity,
+        ambient: ThermodynamicTemperature,
+        inlet: ThermodynamicTemperature,
+    ) -> Power {
+        let excess = inlet.minus(ambient);
+        let loss: HeatFluxDensity =
+            self.linear_loss * excess + self.quadratic_loss * excess * excess.abs();
+        self.area * (self.optical_efficiency * irradiance - loss)
+    }
+
+    /// Returns the inlet temperature at which the gain falls to zero.
+    pub(crate) fn stagnation(
+        &self,
+        irradiance: HeatFluxDensity,
+        ambient: ThermodynamicTemperature,
+    ) -> ThermodynamicTemperature {
+        let absorbed = (self.optical_efficiency * irradiance).value;
+        let a1 = self.linear_loss.value;
+        let a2 = self.quadratic_loss.value;
+
+        // Positive root of a₂·x² + a₁·x − η₀·G = 0.
+        let excess = if a2 > 0.0 {
+            2.0 * absorbed / (a1 + (a1 * a1 + 4.0 * a2 * absorbed).sqrt())
+        } else {
+            absorbed / a1
+        };
+        ambient.plus(TemperatureInterval::new::<
+            uom::si::temperature_interval::kelvin,
+        >(excess))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        area::square_meter, heat_flux_density::watt_per_square_meter,
+        heat_transfer::watt_per_square_meter_kelvin, power::watt, ratio::ratio,
+        temperature_interval::kelvin as delta_kelvin, thermodynamic_temperature::degree_celsius,
+    };
+
+    fn collector() -> Collector {
+        Collector {
+            area: Area::new::<square_meter>(4.0),
+            optical_efficiency: Ratio::new::<ratio>(0.75),
+            linear_loss: HeatTransfer::new::<watt_per_square_meter_kelvin>(3.5),
+            quadratic_loss: HeatTransfer::new::<watt_per_square_meter_kelvin>(0.015)
+                / TemperatureInterval::new::<delta_kelvin>(1.0),
+        }
+    }
+
+    #[test]
+    fn gain_follows_the_efficiency_curve() {
+        let g = HeatFluxDensity::new::<watt_per_square_meter>(800.0);
+        let ambient = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+        let inlet = ThermodynamicTemperature::new::<degree_celsius>(60.0);
+
+        // 4 m² × (0.75·800 − 3.5·40 − 0.015·40²) W/m²
+        assert_relative_eq!(
+            collector().gain(g, ambient, inlet).get::<watt>(),
+            4.0 * (600.0 - 140.0 - 24.0),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn stagnation_has_zero_gain() {
+        let g = HeatFluxDensity::new::<watt_per_square_meter>(800.0);
+        let ambient = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+        let stagnation = collector().stagnation(g, ambient);
+
+        assert!(stagnation > ThermodynamicTemperature::new::<degree_celsius>(100.0));
+        assert_relative_eq!(
+            collector().gain(g, ambient, stagnation).get::<watt>(),
+            0.0,
+            epsilon = 1e-9
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! Problem formulation for the closed collector loop.
+
+use std::convert::Infallible;
+
+use twine_core::{EquationProblem, Model};
+use uom::si::{
+    f64::{HeatFluxDensity, Power, ThermalConductance, ThermodynamicTemperature},
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::support::{
+    constraint::ConstraintError,
+    hx::{
+        CapacitanceRate, StreamInlet,
+        arrangement::CounterFlow,
+        functional::{self, KnownConductanceResult},
+    },
+    units::TemperatureDifference,
+};
+
+use super::collector::Collector;
+
+/// The collector loop evaluated at a candidate collector inlet temperature.
+///
+/// Fluid leaves the collector, loses heat along the supply pipe, gives heat
+/// to the tank-side stream in a counterflow exchanger, and loses more heat
+/// along the return pipe before reaching the collector again.
+pub(super) struct LoopModel<'a> {
+    pub(super) collector: &'a Collector,
+    pub(super) irradiance: HeatFluxDensity,
+    pub(super) ambient: ThermodynamicTemperature,
+    pub(super) capacitance_rate: ThermalConductance,
+    pub(super) pipe_ua: ThermalConductance,
+    pub(super) hx_ua: ThermalConductance,
+    pub(super) tank_inlet: StreamInlet,
+}
+
+/// Temperatures and heat flows around the collector loop.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct LoopState {
+    pub(super) collector_outlet: ThermodynamicTemperature,
+    pub(super) collected: Power,
+    pub(super) pipe_loss: Power,
+    pub(super) hx: KnownConductanceResult,
+
+    /// Temperature arriving back at the collector inlet.
+    pub(super) returned: ThermodynamicTemperature,
+}
+
+impl LoopModel<'_> {
+    /// Returns the outlet temperature of a pipe that loses heat to ambient.
+    fn pipe(&self, inlet: ThermodynamicTemperature) -> ThermodynamicTemperature {
+        let retained = (-(self.pipe_ua / self.capacitance_rate).value).exp();
+        self.ambient.plus(inlet.minus(self.ambient) * retained)
+    }
+}
+
+impl Model for LoopModel<'_> {
+    type Input = ThermodynamicTemperature;
+    type Output = LoopState;
+    type Error = ConstraintError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        let collected = self.collector.gain(self.irradiance, self.ambient, *input);
+        let collector_outlet = input.plus(collected / self.capacitance_rate);
+        let hx_inlet = self.pipe(collector_outlet);
+
+        let hot = StreamInlet::new(
+            CapacitanceRate::from_quantity(self.capacitance_rate)?,
+            hx_inlet,
+        );
+        let hx = functional::known_conductance_and_inlets(
+            &CounterFlow,
+            self.hx_ua,
+            [hot, self.tank_inlet],
+        )?;
+        let hx_outlet = hx.streams[0].outlet_temperature;
+        let returned = self.pipe(hx_outlet);
+
+        let pipe_loss =
+            self.capacitance_rate * (collector_outlet.minus(hx_inlet) + hx_outlet.minus(returned));
+
+        Ok(LoopState {
+            collector_outlet,
+            collected,
+            pipe_loss,
+            hx,
+            returned,
+        })
+    }
+}
+
+/// Residual is `returned - inlet` in kelvin.
+pub(super) struct LoopProblem;
+
+impl EquationProblem<1> for LoopProblem {
+    type Input = ThermodynamicTemperature;
+    type Output = LoopState;
+    type Error = Infallible;
+
+    fn input(&self, x: &[f64; 1]) -> Result<Self::Input, Self::Error> {
+        Ok(ThermodynamicTemperature::new::<kelvin>(x[0]))
+    }
+
+    fn residuals(
+        &self,
+        input: &Self::Input,
+        output: &Self::Output,
+    ) -> Result<[f64; 1], Self::Error> {
+        Ok([output.returned.get::<kelvin>() - input.get::<kelvin>()])
+    }
+}
@@ -0,0 +1,101 @@
+//! Tracking collector field for concentrating solar power.
+//!
+//! A [`SolarField`] aggregates many collector modules, such as
+//! parabolic-trough collector assemblies, into parallel rows fed by a cold
+//! header and gathered by a hot header. It accounts for row-to-row shading
+//! of single-axis trackers at low sun, heat lost from the header piping, and
+//! a defocus input that turns part of the field off the sun when the
+//! outlet would run too hot or the plant cannot take the heat.
+//!
+//! Each module is rated by the same efficiency curve as the
+//! [`Collector`](super::dhw::Collector) of a domestic hot water system,
+//! with coefficients referenced to the module inlet. The heat transfer fluid
+//! is any thermo model with a specific heat, such as
+//! [`Incompressible`](crate::support::thermo::model::Incompressible) with
+//! [`TherminolVp1`](crate::support::thermo::fluid::TherminolVp1) or
+//! [`SolarSalt`](crate::support::thermo::fluid::SolarSalt).
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::solar::{
+//!         dhw::Collector,
+//!         field::{SolarField, SolarFieldInput, SolarFieldParameters},
+//!     },
+//!     support::thermo::{fluid::TherminolVp1, model::Incompressible},
+//! };
+//! use uom::si::{
+//!     angle::degree,
+//!     area::square_meter,
+//!     f64::{
+//!         Angle, Area, HeatFluxDensity, HeatTransfer, Length, MassRate, Ratio,
+//!         TemperatureInterval, ThermalConductance, ThermodynamicTemperature,
+//!     },
+//!     heat_flux_density::watt_per_square_meter,
+//!     heat_transfer::watt_per_square_meter_kelvin,
+//!     length::meter,
+//!     mass_rate::kilogram_per_second,
+//!     power::megawatt,
+//!     ratio::ratio,
+//!     temperature_interval::kelvin as delta_kelvin,
+//!     thermal_conductance::kilowatt_per_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//! };
+//!
+//! let field = SolarField::new(
+//!     SolarFieldParameters {
+//!         collector: Collector {
+//!             area: Area::new::<square_meter>(817.5),
+//!             optical_efficiency: Ratio::new::<ratio>(0.75),
+//!             linear_loss: HeatTransfer::new::<watt_per_square_meter_kelvin>(0.05),
+//!             quadratic_loss: HeatTransfer::new::<watt_per_square_meter_kelvin>(0.0008)
+//!                 / TemperatureInterval::new::<delta_kelvin>(1.0),
+//!         },
+//!         modules_per_row: 4,
+//!         rows: 10,
+//!         aperture_width: Length::new::<meter>(5.75),
+//!         row_spacing: Length::new::<meter>(15.0),
+//!         cold_header_ua: ThermalConductance::new::<kilowatt_per_kelvin>(0.4),
+//!         hot_header_ua: ThermalConductance::new::<kilowatt_per_kelvin>(0.4),
+//!     },
+//!     Incompressible::<TherminolVp1>::new()?,
+//! )?;
+//!
+//! let output = field.evaluate(&SolarFieldInput {
+//!     fluid: TherminolVp1,
+//!     mass_flow: MassRate::new::<kilogram_per_second>(80.0),
+//!     inlet_temperature: ThermodynamicTemperature::new::<degree_celsius>(293.0),
+//!     irradiance: HeatFluxDensity::new::<watt_per_square_meter>(900.0),
+//!     ambient_temperature: ThermodynamicTemperature::new::<degree_celsius>(25.0),
+//!     tracking_angle: Angle::new::<degree>(30.0),
+//!     defocus: Ratio::new::<ratio>(0.0),
+//! })?;
+//!
+//! assert!(output.thermal_power.get::<megawatt>() > 15.0);
+//! assert!(output.outlet_temperature > ThermodynamicTemperature::new::<degree_celsius>(380.0));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    SolarField, SolarFieldError, SolarFieldInput, SolarFieldOutput, SolarFieldParameters,
+    SolarFieldThermoModel,
+};
+
+impl<Fluid, Thermo> Model for SolarField<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: SolarFieldThermoModel<Fluid>,
+{
+    type Input = SolarFieldInput<Fluid>;
+    type Output = SolarFieldOutput;
+    type Error = SolarFieldError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
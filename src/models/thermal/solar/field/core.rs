@@ -0,0 +1,543 @@
+use std::{error::Error as StdError, marker::PhantomData};
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::{
+        f64::{
+            Angle, HeatFluxDensity, Length, MassRate, Power, Ratio, ThermalConductance,
+            ThermodynamicTemperature,
+        },
+        ratio::ratio,
+    },
+};
+
+use crate::{
+    models::thermal::solar::dhw::Collector,
+    support::{
+        constraint::{Constraint, NonNegative, StrictlyPositive, UnitInterval},
+        thermo::capability::{HasCp, StateFrom, ThermoModel},
+        units::TemperatureDifference,
+    },
+};
+
+/// Required thermo model bounds for a [`SolarField`].
+#[doc(hidden)]
+pub trait SolarFieldThermoModel<Fluid>:
+    ThermoModel<Fluid = Fluid> + HasCp + StateFrom<(Fluid, ThermodynamicTemperature)>
+{
+}
+
+impl<Fluid, T> SolarFieldThermoModel<Fluid> for T where
+    T: ThermoModel<Fluid = Fluid> + HasCp + StateFrom<(Fluid, ThermodynamicTemperature)>
+{
+}
+
+/// Errors that can occur when creating or evaluating a [`SolarField`].
+#[derive(Debug, Error)]
+pub enum SolarFieldError {
+    /// A field parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating input is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// A thermodynamic model operation failed.
+    #[error("thermodynamic model failed: {context}")]
+    ThermoModelFailed {
+        /// Operation context for the thermodynamic model failure.
+        context: String,
+
+        /// Underlying thermodynamic model error.
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+}
+
+impl SolarFieldError {
+    fn thermo(context: &str, source: impl StdError + Send + Sync + 'static) -> Self {
+        Self::ThermoModelFailed {
+            context: context.to_owned(),
+            source: Box::new(source),
+        }
+    }
+}
+
+/// Layout and ratings of a [`SolarField`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarFieldParameters {
+    /// Rating of one collector module, with `area` its aperture area.
+    pub collector: Collector,
+
+    /// Modules in series along each row.
+    pub modules_per_row: usize,
+
+    /// Parallel rows, each fed from the cold header.
+    pub rows: usize,
+
+    /// Aperture width of the tracking collectors.
+    pub aperture_width: Length,
+
+    /// Distance between the axes of neighbouring rows.
+    pub row_spacing: Length,
+
+    /// Conductance to ambient of the cold header feeding the rows.
+    pub cold_header_ua: ThermalConductance,
+
+    /// Conductance to ambient of the hot header collecting the rows.
+    pub hot_header_ua: ThermalConductance,
+}
+
+/// A field of tracking collectors in parallel rows.
+///
+/// Heat transfer fluid enters the cold header, splits evenly among the
+/// rows, passes through each row's modules in series, and leaves through the
+/// hot header. Each module gains heat by its efficiency curve at its own
+/// inlet temperature, using the fluid's specific heat at that temperature:
+///
+/// ```text
+/// T_{i+1} = T_i + Q̇(G_eff, T_amb, T_i) / (ṁ_row · cp(T_i))
+/// ```
+///
+/// Both headers lose heat to ambient as insulated pipes,
+/// `T_out = T_amb + (T_in − T_amb)·exp(−UA / ṁcp)`.
+///
+/// The effective beam irradiance `G_eff` is reduced by row-to-row shading
+/// and by defocusing. A single-axis tracker at tracking angle `ω` shades the
+/// next row over `1 − L·cos ω / W` of its aperture, where `L` is the row
+/// spacing and `W` the aperture width. The first row toward the sun is never
+/// shaded, so across the field the shaded share is that fraction times
+/// `(rows − 1) / rows`.
+#[derive(Debug, Clone)]
+pub struct SolarField<Fluid, Thermo> {
+    parameters: SolarFieldParameters,
+    thermo: Thermo,
+    _fluid: PhantomData<Fluid>,
+}
+
+/// Operating conditions for a [`SolarField`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarFieldInput<Fluid> {
+    /// Heat transfer fluid.
+    pub fluid: Fluid,
+
+    /// Total mass flow through the field.
+    pub mass_flow: MassRate,
+
+    /// Fluid temperature entering the cold header.
+    pub inlet_temperature: ThermodynamicTemperature,
+
+    /// Beam irradiance on the tracked aperture plane, after incidence-angle
+    /// effects.
+    pub irradiance: HeatFluxDensity,
+
+    /// Outdoor air temperature.
+    pub ambient_temperature: ThermodynamicTemperature,
+
+    /// Rotation of the collectors from facing straight up.
+    pub tracking_angle: Angle,
+
+    /// Fraction of the modules turned off the sun, from zero (all tracking)
+    /// to one (fully stowed).
+    pub defocus: Ratio,
+}
+
+/// Operating point of a [`SolarField`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolarFieldOutput {
+    /// Fluid temperature leaving the hot header.
+    pub outlet_temperature: ThermodynamicTemperature,
+
+    /// Fluid temperature leaving each module along a row, first to last.
+    pub row_temperatures: Vec<ThermodynamicTemperature>,
+
+    /// Beam power reaching the total aperture before shading and defocus.
+    pub incident_power: Power,
+
+    /// Share of the field aperture in the shadow of a neighbouring row.
+    pub shaded_fraction: Ratio,
+
+    /// Heat gained by all modules.
+    pub collected: Power,
+
+    /// Heat lost from both headers.
+    pub header_loss: Power,
+
+    /// Heat delivered by the field, `collected − header_loss`.
+    pub thermal_power: Power,
+
+    /// Delivered heat over incident power.
+    ///
+    /// Zero when no beam irradiance reaches the field.
+    pub efficiency: Ratio,
+}
+
+impl<Fluid, Thermo> SolarField<Fluid, Thermo> {
+    /// Creates a solar field.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolarFieldError::InvalidParameter`] if the collector rating
+    /// is not physical, the field has no modules, a length is not strictly
+    /// positive, or a header conductance is negative.
+    pub fn new(parameters: SolarFieldParameters, thermo: Thermo) -> Result<Self, SolarFieldError> {
+        parameters
+            .collector
+            .validate()
+            .map_err(SolarFieldError::InvalidParameter)?;
+        if parameters.modules_per_row == 0 || parameters.rows == 0 {
+            return Err(SolarFieldError::InvalidParameter(
+                "field must have at least one row of at least one module",
+            ));
+        }
+        if StrictlyPositive::check(&parameters.aperture_width).is_err()
+            || StrictlyPositive::check(&parameters.row_spacing).is_err()
+        {
+            return Err(SolarFieldError::InvalidParameter(
+                "aperture width and row spacing must be strictly positive",
+            ));
+        }
+        if NonNegative::check(&parameters.cold_header_ua).is_err()
+            || NonNegative::check(&parameters.hot_header_ua).is_err()
+        {
+            return Err(SolarFieldError::InvalidParameter(
+                "header conductances must be non-negative",
+            ));
+        }
+
+        Ok(Self {
+            parameters,
+            thermo,
+            _fluid: PhantomData,
+        })
+    }
+
+    /// Returns the field parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &SolarFieldParameters {
+        &self.parameters
+    }
+
+    /// Returns the thermo model.
+    #[must_use]
+    pub fn thermo(&self) -> &Thermo {
+        &self.thermo
+    }
+
+    /// Share of the field aperture shaded by neighbouring rows at
+    /// `tracking_angle`.
+    #[must_use]
+    pub fn shaded_fraction(&self, tracking_angle: Angle) -> Ratio {
+        let SolarFieldParameters {
+            rows,
+            aperture_width,
+            row_spacing,
+            ..
+        } = self.parameters;
+
+        let lit = (row_spacing * tracking_angle.cos() / aperture_width).get::<ratio>();
+        let shaded_row = (1.0 - lit).clamp(0.0, 1.0);
+
+        #[allow(clippy::cast_precision_loss)]
+        let shaded_rows = (rows - 1) as f64 / rows as f64;
+        Ratio::new::<ratio>(shaded_row * shaded_rows)
+    }
+
+    /// Evaluates the field at one operating point.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolarFieldError::InvalidInput`] if the flow is not strictly
+    /// positive, the defocus fraction is outside `[0, 1]`, or the irradiance
+    /// is negative, and [`SolarFieldError::ThermoModelFailed`] if a property
+    /// evaluation fails.
+    pub fn evaluate(
+        &self,
+        input: &SolarFieldInput<Fluid>,
+    ) -> Result<SolarFieldOutput, SolarFieldError>
+    where
+        Fluid: Clone,
+        Thermo: SolarFieldThermoModel<Fluid>,
+    {
+        if StrictlyPositive::check(&input.mass_flow).is_err() || !input.mass_flow.is_finite() {
+            return Err(SolarFieldError::InvalidInput(
+                "mass flow must be strictly positive and finite",
+            ));
+        }
+        if UnitInterval::check(&input.defocus).is_err() {
+            return Err(SolarFieldError::InvalidInput(
+                "defocus fraction must be in [0, 1]",
+            ));
+        }
+        if NonNegative::check(&input.irradiance).is_err() || !input.tracking_angle.is_finite() {
+            return Err(SolarFieldError::InvalidInput(
+                "irradiance must be non-negative and tracking angle finite",
+            ));
+        }
+
+        let SolarFieldParameters {
+            collector,
+            modules_per_row,
+            rows,
+            cold_header_ua,
+            hot_header_ua,
+            ..
+        } = self.parameters;
+
+        let capacitance = |mass_flow: MassRate, temperature| {
+            let state = self
+                .thermo
+                .state_from((input.fluid.clone(), temperature))
+                .map_err(|error| SolarFieldError::thermo("state from temperature", error))?;
+            let cp = self
+                .thermo
+                .cp(&state)
+                .map_err(|error| SolarFieldError::thermo("specific heat", error))?;
+            Ok::<ThermalConductance, SolarFieldError>(mass_flow * cp)
+        };
+        let ambient = input.ambient_temperature;
+        let header = |ua: ThermalConductance, inlet| {
+            let capacitance = capacitance(input.mass_flow, inlet)?;
+            let retained = (-(ua / capacitance).get::<ratio>()).exp();
+            let outlet = ambient.plus(inlet.minus(ambient) * retained);
+            Ok::<_, SolarFieldError>((outlet, capacitance * inlet.minus(outlet)))
+        };
+
+        let shaded_fraction = self.shaded_fraction(input.tracking_angle);
+        let effective = input.irradiance
+            * (1.0 - shaded_fraction.get::<ratio>())
+            * (1.0 - input.defocus.get::<ratio>());
+
+        #[allow(clippy::cast_precision_loss)]
+        let row_flow = input.mass_flow / rows as f64;
+
+        let (row_inlet, cold_loss) = header(cold_header_ua, input.inlet_temperature)?;
+        let mut row_temperatures = Vec::with_capacity(modules_per_row);
+        let mut temperature = row_inlet;
+        let mut row_gain = Power::ZERO;
+        for _ in 0..modules_per_row {
+            let gain = collector.gain(effective, ambient, temperature);
+            temperature = temperature.plus(gain / capacitance(row_flow, temperature)?);
+            row_gain += gain;
+            row_temperatures.push(temperature);
+        }
+        let (outlet_temperature, hot_loss) = header(hot_header_ua, temperature)?;
+
+        #[allow(clippy::cast_precision_loss)]
+        let collected = row_gain * rows as f64;
+        #[allow(clippy::cast_precision_loss)]
+        let incident_power = input.irradiance * collector.area * (modules_per_row * rows) as f64;
+        let header_loss = cold_loss + hot_loss;
+        let thermal_power = collected - header_loss;
+        let efficiency = if incident_power > Power::ZERO {
+            thermal_power / incident_power
+        } else {
+            Ratio::ZERO
+        };
+
+        Ok(SolarFieldOutput {
+            outlet_temperature,
+            row_temperatures,
+            incident_power,
+            shaded_fraction,
+            collected,
+            header_loss,
+            thermal_power,
+            efficiency,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        angle::degree,
+        area::square_meter,
+        f64::{Area, HeatTransfer, TemperatureInterval},
+        heat_flux_density::watt_per_square_meter,
+        heat_transfer::watt_per_square_meter_kelvin,
+        length::meter,
+        mass_rate::kilogram_per_second,
+        power::{kilowatt, megawatt},
+        temperature_interval::kelvin as delta_kelvin,
+        thermal_conductance::kilowatt_per_kelvin,
+        thermodynamic_temperature::degree_celsius,
+    };
+
+    use crate::support::thermo::{fluid::TherminolVp1, model::Incompressible};
+
+    fn parameters() -> SolarFieldParameters {
+        SolarFieldParameters {
+            collector: Collector {
+                area: Area::new::<square_meter>(817.5),
+                optical_efficiency: Ratio::new::<ratio>(0.75),
+                linear_loss: HeatTransfer::new::<watt_per_square_meter_kelvin>(0.05),
+                quadratic_loss: HeatTransfer::new::<watt_per_square_meter_kelvin>(0.0008)
+                    / TemperatureInterval::new::<delta_kelvin>(1.0),
+            },
+            modules_per_row: 4,
+            rows: 10,
+            aperture_width: Length::new::<meter>(5.75),
+            row_spacing: Length::new::<meter>(15.0),
+            cold_header_ua: ThermalConductance::new::<kilowatt_per_kelvin>(0.4),
+            hot_header_ua: ThermalConductance::new::<kilowatt_per_kelvin>(0.4),
+        }
+    }
+
+    fn field(
+        parameters: SolarFieldParameters,
+    ) -> SolarField<TherminolVp1, Incompressible<TherminolVp1>> {
+        SolarField::new(parameters, Incompressible::new().unwrap()).unwrap()
+    }
+
+    fn input() -> SolarFieldInput<TherminolVp1> {
+        SolarFieldInput {
+            fluid: TherminolVp1,
+            mass_flow: MassRate::new::<kilogram_per_second>(80.0),
+            inlet_temperature: ThermodynamicTemperature::new::<degree_celsius>(293.0),
+            irradiance: HeatFluxDensity::new::<watt_per_square_meter>(900.0),
+            ambient_temperature: ThermodynamicTemperature::new::<degree_celsius>(25.0),
+            tracking_angle: Angle::new::<degree>(0.0),
+            defocus: Ratio::ZERO,
+        }
+    }
+
+    #[test]
+    fn energy_balance_closes() {
+        let field = field(parameters());
+        let output = field.evaluate(&input()).unwrap();
+
+        // Constant cp, so the delivered heat shows up as the temperature rise.
+        let cp = field
+            .thermo()
+            .cp(&field
+                .thermo()
+                .state_from((TherminolVp1, input().inlet_temperature))
+                .unwrap())
+            .unwrap();
+        let rise = output.outlet_temperature.minus(input().inlet_temperature);
+        assert_relative_eq!(
+            output.thermal_power.get::<kilowatt>(),
+            (input().mass_flow * cp * rise).get::<kilowatt>(),
+            max_relative = 1e-9
+        );
+
+        // About 20 MW from 29 MW of beam, heating the oil by roughly 100 K.
+        let power = output.thermal_power.get::<megawatt>();
+        assert!(power > 18.0 && power < 22.0, "power = {power}");
+        assert!(output.header_loss > Power::ZERO);
+        assert_eq!(output.row_temperatures.len(), 4);
+        assert!(output.row_temperatures.windows(2).all(|w| w[1] > w[0]));
+    }
+
+    #[test]
+    fn rows_shade_each_other_at_low_sun() {
+        let field = field(parameters());
+        assert_eq!(
+            field.shaded_fraction(Angle::new::<degree>(0.0)),
+            Ratio::ZERO
+        );
+
+        // At 70° a 15 m spacing casts 15·cos 70° ≈ 5.13 m of light on a 5.75 m
+        // aperture, and nine of the ten rows sit in a neighbour's shadow.
+        let shaded = field.shaded_fraction(Angle::new::<degree>(70.0));
+        let expected = (1.0 - 15.0 * 70.0_f64.to_radians().cos() / 5.75) * 0.9;
+        assert_relative_eq!(shaded.get::<ratio>(), expected, max_relative = 1e-12);
+
+        let low_sun = field
+            .evaluate(&SolarFieldInput {
+                tracking_angle: Angle::new::<degree>(70.0),
+                ..input()
+            })
+            .unwrap();
+        let noon = field.evaluate(&input()).unwrap();
+        assert!(low_sun.collected < noon.collected);
+        assert_eq!(low_sun.incident_power, noon.incident_power);
+
+        let single_row = SolarField::<TherminolVp1, _>::new(
+            SolarFieldParameters {
+                rows: 1,
+                ..parameters()
+            },
+            Incompressible::<TherminolVp1>::new().unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            single_row.shaded_fraction(Angle::new::<degree>(70.0)),
+            Ratio::ZERO
+        );
+    }
+
+    #[test]
+    fn defocus_sheds_collected_heat() {
+        let field = field(parameters());
+        let focused = field.evaluate(&input()).unwrap();
+        let half = field
+            .evaluate(&SolarFieldInput {
+                defocus: Ratio::new::<ratio>(0.5),
+                ..input()
+            })
+            .unwrap();
+        let stowed = field
+            .evaluate(&SolarFieldInput {
+                defocus: Ratio::new::<ratio>(1.0),
+                ..input()
+            })
+            .unwrap();
+
+        assert!(half.outlet_temperature < focused.outlet_temperature);
+        assert!(half.collected < focused.collected);
+
+        // Stowed, the field only loses heat.
+        assert!(stowed.collected < Power::ZERO);
+        assert!(stowed.outlet_temperature < input().inlet_temperature);
+        assert!(stowed.efficiency < Ratio::ZERO);
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        let no_modules = SolarFieldParameters {
+            modules_per_row: 0,
+            ..parameters()
+        };
+        assert!(matches!(
+            SolarField::<TherminolVp1, _>::new(
+                no_modules,
+                Incompressible::<TherminolVp1>::new().unwrap()
+            ),
+            Err(SolarFieldError::InvalidParameter(_))
+        ));
+        let leaky = SolarFieldParameters {
+            hot_header_ua: ThermalConductance::new::<kilowatt_per_kelvin>(-1.0),
+            ..parameters()
+        };
+        assert!(matches!(
+            SolarField::<TherminolVp1, _>::new(
+                leaky,
+                Incompressible::<TherminolVp1>::new().unwrap()
+            ),
+            Err(SolarFieldError::InvalidParameter(_))
+        ));
+
+        let field = field(parameters());
+        assert!(matches!(
+            field.evaluate(&SolarFieldInput {
+                mass_flow: MassRate::ZERO,
+                ..input()
+            }),
+            Err(SolarFieldError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            field.evaluate(&SolarFieldInput {
+                defocus: Ratio::new::<ratio>(1.5),
+                ..input()
+            }),
+            Err(SolarFieldError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,142 @@
+//! Natural-circulation (thermosiphon) solar loop.
+//!
+//! A [`Thermosiphon`] finds the flow that a collector loop without a pump
+//! settles at. Fluid densities along the loop come from a thermo model at the
+//! temperatures the flow produces, so the buoyancy head and the friction of
+//! the collector and connecting pipes are balanced self-consistently. A
+//! [`CheckValve`](crate::models::hydraulic::check_valve::CheckValve) can be
+//! fitted at the tank outlet to stop reverse circulation.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::models::thermal::solar::{
+//!     dhw::Collector,
+//!     thermosiphon::{
+//!         FlowPath, LoopPipe, Thermosiphon, ThermosiphonConfig, ThermosiphonInput,
+//!         ThermosiphonParameters,
+//!     },
+//! };
+//! # use std::convert::Infallible;
+//! # use twine_models::support::thermo::{
+//! #     PropertyError, State,
+//! #     capability::{HasCp, HasCv, HasSpeedOfSound, StateFrom, ThermoModel},
+//! # };
+//! # use uom::si::{
+//! #     f64::{MassDensity, SpecificHeatCapacity, Velocity},
+//! #     mass_density::kilogram_per_cubic_meter,
+//! #     specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+//! #     velocity::meter_per_second,
+//! # };
+//! use uom::{
+//!     ConstZero,
+//!     si::{
+//!         area::square_meter,
+//!         dynamic_viscosity::pascal_second,
+//!         f64::{
+//!             Area, DynamicViscosity, HeatFluxDensity, HeatTransfer, Length, MassRate, Pressure,
+//!             Ratio, TemperatureInterval, ThermalConductance, ThermodynamicTemperature,
+//!         },
+//!         heat_flux_density::watt_per_square_meter,
+//!         heat_transfer::watt_per_square_meter_kelvin,
+//!         length::{meter, millimeter},
+//!         pressure::kilopascal,
+//!         ratio::ratio,
+//!         temperature_interval::kelvin as delta_kelvin,
+//!         thermal_conductance::watt_per_kelvin,
+//!         thermodynamic_temperature::degree_celsius,
+//!     },
+//! };
+//! #
+//! # #[derive(Debug, Clone, Copy)]
+//! # struct Water;
+//! # struct WaterModel;
+//! # impl ThermoModel for WaterModel { type Fluid = Water; }
+//! # impl HasCp for WaterModel {
+//! #     fn cp(&self, _: &State<Water>) -> Result<SpecificHeatCapacity, PropertyError> {
+//! #         Ok(SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(4.18))
+//! #     }
+//! # }
+//! # impl HasCv for WaterModel {
+//! #     fn cv(&self, state: &State<Water>) -> Result<SpecificHeatCapacity, PropertyError> {
+//! #         self.cp(state)
+//! #     }
+//! # }
+//! # impl HasSpeedOfSound for WaterModel {
+//! #     fn speed_of_sound(&self, _: &State<Water>) -> Result<Velocity, PropertyError> {
+//! #         Ok(Velocity::new::<meter_per_second>(f64::INFINITY))
+//! #     }
+//! # }
+//! # impl StateFrom<(Water, ThermodynamicTemperature, Pressure)> for WaterModel {
+//! #     type Error = Infallible;
+//! #     fn state_from(
+//! #         &self,
+//! #         (fluid, t, _): (Water, ThermodynamicTemperature, Pressure),
+//! #     ) -> Result<State<Water>, Infallible> {
+//! #         let rho = 1000.0 - 0.4 * (t.get::<degree_celsius>() - 20.0);
+//! #         Ok(State::new(t, MassDensity::new::<kilogram_per_cubic_meter>(rho), fluid))
+//! #     }
+//! # }
+//!
+//! let pipe = |length, rise| FlowPath {
+//!     length: Length::new::<meter>(length),
+//!     diameter: Length::new::<millimeter>(22.0),
+//!     rise: Length::new::<meter>(rise),
+//!     minor_loss: Ratio::new::<ratio>(2.0),
+//! };
+//! let insulated = ThermalConductance::new::<watt_per_kelvin>(0.5);
+//!
+//! let thermosiphon = Thermosiphon::new(
+//!     ThermosiphonParameters {
+//!         collector: Collector {
+//!             area: Area::new::<square_meter>(2.0),
+//!             optical_efficiency: Ratio::new::<ratio>(0.75),
+//!             linear_loss: HeatTransfer::new::<watt_per_square_meter_kelvin>(3.5),
+//!             quadratic_loss: HeatTransfer::new::<watt_per_square_meter_kelvin>(0.015)
+//!                 / TemperatureInterval::new::<delta_kelvin>(1.0),
+//!         },
+//!         collector_path: pipe(4.0, 1.0),
+//!         riser: LoopPipe { path: pipe(2.0, 0.8), ua: insulated },
+//!         downcomer: LoopPipe { path: pipe(3.0, -0.6), ua: insulated },
+//!         viscosity: DynamicViscosity::new::<pascal_second>(6e-4),
+//!         roughness: Length::new::<millimeter>(0.0015),
+//!         pressure: Pressure::new::<kilopascal>(300.0),
+//!     },
+//!     ThermosiphonConfig::default(),
+//!     WaterModel,
+//! )?;
+//!
+//! let output = thermosiphon.solve(&ThermosiphonInput {
+//!     fluid: Water,
+//!     irradiance: HeatFluxDensity::new::<watt_per_square_meter>(900.0),
+//!     ambient_temperature: ThermodynamicTemperature::new::<degree_celsius>(25.0),
+//!     tank_temperature: ThermodynamicTemperature::new::<degree_celsius>(40.0),
+//! })?;
+//!
+//! assert!(output.mass_flow > MassRate::ZERO);
+//! assert!(output.collector_outlet_temperature > output.collector_inlet_temperature);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    FlowPath, LoopPipe, Thermosiphon, ThermosiphonConfig, ThermosiphonError, ThermosiphonInput,
+    ThermosiphonOutput, ThermosiphonParameters, ThermosiphonThermoModel,
+};
+
+impl<Fluid, Thermo> Model for Thermosiphon<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: ThermosiphonThermoModel<Fluid>,
+{
+    type Input = ThermosiphonInput<Fluid>;
+    type Output = ThermosiphonOutput;
+    type Error = ThermosiphonError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.solve(input)
+    }
+}
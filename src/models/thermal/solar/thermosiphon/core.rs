@@ -0,0 +1,914 @@
+mod friction;
+
+use std::{error::Error as StdError, marker::PhantomData};
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::{
+        acceleration::standard_gravity,
+        f64::{
+            Acceleration, DynamicViscosity, HeatFluxDensity, Length, MassDensity, MassRate, Power,
+            Pressure, Ratio, TemperatureInterval, ThermalConductance, ThermodynamicTemperature,
+        },
+        mass_rate::kilogram_per_second,
+        temperature_interval::kelvin as delta_kelvin,
+    },
+};
+
+use crate::{
+    models::{
+        hydraulic::{
+            check_valve::{CheckValve, CheckValveError, CheckValveInput, CheckValveParameters},
+            valve::ValveThermoModel,
+        },
+        thermal::solar::dhw::Collector,
+    },
+    support::{
+        constraint::{Constraint, NonNegative, StrictlyPositive},
+        numerics::solve::{self, SolveError, brent},
+        thermo::{State, capability::StateFrom},
+        units::TemperatureDifference,
+    },
+};
+
+/// Mass flow at which the search for an upper bracket starts.
+const INITIAL_UPPER_FLOW_KG_PER_S: f64 = 1e-3;
+
+/// Number of times the upper bracket may double before giving up.
+const MAX_BRACKET_DOUBLINGS: usize = 60;
+
+/// Required thermo model bounds for a [`Thermosiphon`].
+#[doc(hidden)]
+pub trait ThermosiphonThermoModel<Fluid>:
+    ValveThermoModel<Fluid> + StateFrom<(Fluid, ThermodynamicTemperature, Pressure)>
+{
+}
+
+impl<Fluid, T> ThermosiphonThermoModel<Fluid> for T where
+    T: ValveThermoModel<Fluid> + StateFrom<(Fluid, ThermodynamicTemperature, Pressure)>
+{
+}
+
+/// Hydraulic geometry of one element of the loop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlowPath {
+    /// Flow length.
+    pub length: Length,
+
+    /// Inner diameter.
+    pub diameter: Length,
+
+    /// Elevation gained from inlet to outlet; negative when the flow
+    /// descends.
+    pub rise: Length,
+
+    /// Sum of the fitting loss coefficients along the path.
+    pub minor_loss: Ratio,
+}
+
+/// A connecting pipe that loses heat to ambient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoopPipe {
+    /// Hydraulic geometry.
+    pub path: FlowPath,
+
+    /// Thermal conductance from the fluid to ambient.
+    pub ua: ThermalConductance,
+}
+
+/// Fixed design parameters of a [`Thermosiphon`].
+///
+/// Fluid leaves the bottom of the tank through the downcomer, rises through
+/// the collector and the riser, and re-enters the tank higher up. The tank
+/// closes the loop, so its descent from return port to outlet port is
+/// whatever the other elements climb.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThermosiphonParameters {
+    /// Collector array efficiency curve.
+    pub collector: Collector,
+
+    /// Hydraulic geometry of the collector's internal flow path.
+    pub collector_path: FlowPath,
+
+    /// Pipe from the collector outlet up to the tank return port.
+    pub riser: LoopPipe,
+
+    /// Pipe from the tank outlet port down to the collector inlet.
+    pub downcomer: LoopPipe,
+
+    /// Dynamic viscosity of the loop fluid.
+    ///
+    /// Thermo models do not expose transport properties, so a
+    /// representative value for the operating range is supplied here.
+    pub viscosity: DynamicViscosity,
+
+    /// Absolute roughness of the pipe walls.
+    pub roughness: Length,
+
+    /// Loop pressure at which fluid states are evaluated.
+    pub pressure: Pressure,
+}
+
+/// Solver configuration for [`Thermosiphon`].
+#[derive(Debug, Clone, Copy)]
+pub struct ThermosiphonConfig {
+    /// Relative tolerance on the circulating mass flow.
+    pub mass_flow_rel_tol: f64,
+
+    /// Maximum number of solver iterations.
+    pub max_iters: usize,
+}
+
+impl Default for ThermosiphonConfig {
+    fn default() -> Self {
+        Self {
+            mass_flow_rel_tol: 1e-9,
+            max_iters: 100,
+        }
+    }
+}
+
+/// Errors from [`Thermosiphon`] construction and evaluation.
+#[derive(Debug, Error)]
+pub enum ThermosiphonError {
+    /// A design parameter or solver setting is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// A thermodynamic model operation failed.
+    #[error("thermodynamic model failed: {context}")]
+    ThermoModelFailed {
+        /// Operation context for the thermodynamic model failure.
+        context: String,
+
+        /// Underlying thermodynamic model error.
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+
+    /// The check valve is invalid or failed to evaluate.
+    #[error("check valve failed")]
+    CheckValve(#[source] CheckValveError),
+
+    /// The flow balance failed to converge.
+    #[error("thermosiphon flow balance failed to converge: {message}")]
+    Convergence {
+        /// Details about the convergence failure.
+        message: String,
+
+        /// Iteration count when available.
+        iterations: Option<usize>,
+    },
+}
+
+impl ThermosiphonError {
+    fn thermo(context: &str, source: impl StdError + Send + Sync + 'static) -> Self {
+        Self::ThermoModelFailed {
+            context: context.to_owned(),
+            source: Box::new(source),
+        }
+    }
+}
+
+/// Operating conditions of the loop.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThermosiphonInput<Fluid> {
+    /// Fluid-specific data used to build the states.
+    pub fluid: Fluid,
+
+    /// Solar irradiance on the collector plane.
+    pub irradiance: HeatFluxDensity,
+
+    /// Outdoor air temperature around the collector and pipes.
+    pub ambient_temperature: ThermodynamicTemperature,
+
+    /// Temperature of the tank water between its ports, which also leaves
+    /// through the outlet port.
+    pub tank_temperature: ThermodynamicTemperature,
+}
+
+/// Natural-circulation operating point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThermosiphonOutput {
+    /// Circulating mass flow; zero when the loop has no forward driving head.
+    pub mass_flow: MassRate,
+
+    /// Temperature entering the collector.
+    pub collector_inlet_temperature: ThermodynamicTemperature,
+
+    /// Temperature leaving the collector.
+    pub collector_outlet_temperature: ThermodynamicTemperature,
+
+    /// Temperature returning to the tank.
+    pub return_temperature: ThermodynamicTemperature,
+
+    /// Useful heat gained by the collector.
+    pub collected: Power,
+
+    /// Heat lost from the riser and downcomer.
+    pub pipe_loss: Power,
+
+    /// Heat delivered to the tank, the balance of the collected heat and the
+    /// pipe losses.
+    pub delivered: Power,
+
+    /// Buoyancy pressure driving the flow, balanced by friction and any
+    /// check valve.
+    pub buoyancy: Pressure,
+
+    /// Number of solver iterations.
+    pub iterations: usize,
+}
+
+/// A passive solar loop driven by natural circulation.
+///
+/// Heated fluid in the collector and riser is lighter than the cooler fluid
+/// in the tank and downcomer. Around the closed loop the buoyancy pressure
+///
+/// ```text
+/// ΔP_b = −g·Σ ρ̄ᵢ·Δzᵢ
+/// ```
+///
+/// drives a flow at which it is balanced by the friction of every element.
+/// The densities come from the thermo model at the temperatures that the
+/// flow itself sets: at low flow the collector approaches its stagnation
+/// temperature, and at high flow the fluid barely warms. The balance is
+/// solved for the mass flow with Brent's method.
+///
+/// The collector outlet follows from the efficiency curve linearized about
+/// the inlet,
+///
+/// ```text
+/// T_out = T_stag + (T_in − T_stag)·exp(−UA_c / (ṁ·cp)),
+/// UA_c  = Q̇(T_in) / (T_stag − T_in)
+/// ```
+///
+/// which recovers the rated gain at high flow and stays finite as the flow
+/// stops. Pipes lose heat to ambient with the same exponential profile.
+///
+/// Reverse circulation is not modeled: without a forward driving head the
+/// flow is zero, as in a loop fitted with a check valve or heat trap. A
+/// [`CheckValve`] added with [`with_check_valve`](Self::with_check_valve)
+/// sits at the tank outlet, holds back any reverse head, and passes forward
+/// flow only once the head left over from friction exceeds its cracking
+/// pressure.
+#[derive(Debug, Clone)]
+pub struct Thermosiphon<Fluid, Thermo> {
+    parameters: ThermosiphonParameters,
+    config: ThermosiphonConfig,
+    thermo: Thermo,
+    check_valve: Option<CheckValveParameters>,
+    _fluid: PhantomData<Fluid>,
+}
+
+/// Temperatures, densities, and pressures around the loop at one flow.
+struct LoopPoint<Fluid> {
+    tank: State<Fluid>,
+    temperatures: [ThermodynamicTemperature; 4],
+    collected: Power,
+    pipe_loss: Power,
+    buoyancy: Pressure,
+    friction: Pressure,
+}
+
+impl<Fluid, Thermo> Thermosiphon<Fluid, Thermo> {
+    /// Creates a thermosiphon loop from its design parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThermosiphonError::InvalidParameter`] if a parameter is
+    /// non-physical, the tank return port sits below its outlet port, or the
+    /// solver configuration is invalid.
+    pub fn new(
+        parameters: ThermosiphonParameters,
+        config: ThermosiphonConfig,
+        thermo: Thermo,
+    ) -> Result<Self, ThermosiphonError> {
+        let ThermosiphonParameters {
+            collector,
+            collector_path,
+            riser,
+            downcomer,
+            viscosity,
+            roughness,
+            pressure,
+        } = parameters;
+
+        collector
+            .validate()
+            .map_err(ThermosiphonError::InvalidParameter)?;
+        for path in [collector_path, riser.path, downcomer.path] {
+            if StrictlyPositive::check(&path.length).is_err()
+                || StrictlyPositive::check(&path.diameter).is_err()
+            {
+                return Err(ThermosiphonError::InvalidParameter(
+                    "flow path length and diameter must be strictly positive",
+                ));
+            }
+            if NonNegative::check(&path.minor_loss).is_err() || !path.rise.is_finite() {
+                return Err(ThermosiphonError::InvalidParameter(
+                    "minor loss must be non-negative and rise finite",
+                ));
+            }
+        }
+        if NonNegative::check(&riser.ua).is_err() || NonNegative::check(&downcomer.ua).is_err() {
+            return Err(ThermosiphonError::InvalidParameter(
+                "pipe UA must be non-negative",
+            ));
+        }
+        if StrictlyPositive::check(&viscosity).is_err()
+            || NonNegative::check(&roughness).is_err()
+            || StrictlyPositive::check(&pressure).is_err()
+        {
+            return Err(ThermosiphonError::InvalidParameter(
+                "viscosity and pressure must be strictly positive and roughness non-negative",
+            ));
+        }
+        if collector_path.rise + riser.path.rise + downcomer.path.rise < Length::ZERO {
+            return Err(ThermosiphonError::InvalidParameter(
+                "tank return port must not be below the tank outlet port",
+            ));
+        }
+        if !(config.mass_flow_rel_tol.is_finite() && config.mass_flow_rel_tol > 0.0) {
+            return Err(ThermosiphonError::InvalidParameter(
+                "mass flow tolerance must be finite and strictly positive",
+            ));
+        }
+
+        Ok(Self {
+            parameters,
+            config,
+            thermo,
+            check_valve: None,
+            _fluid: PhantomData,
+        })
+    }
+
+    /// Fits a check valve at the tank outlet.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThermosiphonError::CheckValve`] if the valve parameters are
+    /// invalid.
+    pub fn with_check_valve(
+        mut self,
+        parameters: CheckValveParameters,
+    ) -> Result<Self, ThermosiphonError> {
+        CheckValve::<Fluid, _>::new(parameters, &self.thermo)
+            .map_err(ThermosiphonError::CheckValve)?;
+        self.check_valve = Some(parameters);
+        Ok(self)
+    }
+
+    /// Returns the check valve parameters, if the loop has one.
+    #[must_use]
+    pub fn check_valve(&self) -> Option<CheckValveParameters> {
+        self.check_valve
+    }
+
+    /// Returns the design parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &ThermosiphonParameters {
+        &self.parameters
+    }
+
+    /// Returns the thermo model.
+    #[must_use]
+    pub fn thermo(&self) -> &Thermo {
+        &self.thermo
+    }
+
+    /// Solves for the natural-circulation flow.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a fluid state cannot be built or the flow balance
+    /// fails to converge.
+    pub fn solve(
+        &self,
+        input: &ThermosiphonInput<Fluid>,
+    ) -> Result<ThermosiphonOutput, ThermosiphonError>
+    where
+        Fluid: Clone,
+        Thermo: ThermosiphonThermoModel<Fluid>,
+    {
+        let check_valve = self
+            .check_valve
+            .map(|parameters| CheckValve::new(parameters, &self.thermo))
+            .transpose()
+            .map_err(ThermosiphonError::CheckValve)?;
+
+        // Without a valve the head left over from friction must vanish; with
+        // one, the valve must pass the circulating flow under that head.
+        let kg_per_s = MassRate::new::<kilogram_per_second>;
+        let balance = |point: &LoopPoint<Fluid>, mass_flow: MassRate| {
+            let head = point.buoyancy - point.friction;
+            let Some(valve) = &check_valve else {
+                return Ok(head.value);
+            };
+            let passed = valve
+                .evaluate(&CheckValveInput {
+                    inlet: point.tank.clone(),
+                    pressure_difference: head,
+                })
+                .map_err(ThermosiphonError::CheckValve)?;
+            Ok((passed.mass_flow - mass_flow).value)
+        };
+        let residual = |m: f64| -> Result<f64, ThermosiphonError> {
+            let point = self.circulate(input, kg_per_s(m))?;
+            balance(&point, kg_per_s(m))
+        };
+
+        let stagnant = self.circulate(input, MassRate::ZERO)?;
+        if balance(&stagnant, MassRate::ZERO)? <= 0.0 {
+            return Ok(output(MassRate::ZERO, &stagnant, 0));
+        }
+
+        let mut upper = INITIAL_UPPER_FLOW_KG_PER_S;
+        let mut doublings = 0;
+        while residual(upper)? > 0.0 {
+            doublings += 1;
+            if doublings > MAX_BRACKET_DOUBLINGS {
+                return Err(ThermosiphonError::Convergence {
+                    message: "friction never balances buoyancy".to_owned(),
+                    iterations: None,
+                });
+            }
+            upper *= 2.0;
+        }
+
+        let solution = brent(
+            residual,
+            [0.0, upper],
+            &solve::Config {
+                max_iters: self.config.max_iters,
+                x_abs_tol: 0.0,
+                x_rel_tol: self.config.mass_flow_rel_tol,
+                residual_tol: 0.0,
+            },
+        )
+        .map_err(|error| match error {
+            SolveError::Eval(error) => error,
+            SolveError::MaxIters { iters, .. } => ThermosiphonError::Convergence {
+                message: error.to_string(),
+                iterations: Some(iters),
+            },
+            _ => ThermosiphonError::Convergence {
+                message: error.to_string(),
+                iterations: None,
+            },
+        })?;
+
+        let mass_flow = kg_per_s(solution.x);
+        let point = self.circulate(input, mass_flow)?;
+        Ok(output(mass_flow, &point, solution.iters))
+    }
+
+    /// Evaluates the loop at a candidate mass flow.
+    fn circulate(
+        &self,
+        input: &ThermosiphonInput<Fluid>,
+        mass_flow: MassRate,
+    ) -> Result<LoopPoint<Fluid>, ThermosiphonError>
+    where
+        Fluid: Clone,
+        Thermo: ThermosiphonThermoModel<Fluid>,
+    {
+        let ThermosiphonParameters {
+            collector,
+            collector_path,
+            riser,
+            downcomer,
+            viscosity,
+            roughness,
+            pressure,
+        } = &self.parameters;
+        let ThermosiphonInput {
+            fluid,
+            irradiance,
+            ambient_temperature: ambient,
+            tank_temperature,
+        } = input;
+
+        let state = |temperature, context| {
+            self.thermo
+                .state_from((fluid.clone(), temperature, *pressure))
+                .map_err(|error| ThermosiphonError::thermo(context, error))
+        };
+        let capacitance_rate = |temperature, context| -> Result<_, ThermosiphonError> {
+            let state = state(temperature, context)?;
+            let cp = self
+                .thermo
+                .cp(&state)
+                .map_err(|error| ThermosiphonError::thermo(context, error))?;
+            let rate: ThermalConductance = mass_flow * cp;
+            Ok((state, rate))
+        };
+        let approach = |inlet: ThermodynamicTemperature,
+                        target: ThermodynamicTemperature,
+                        ua: ThermalConductance,
+                        rate: ThermalConductance| {
+            let retained = if rate > ThermalConductance::ZERO {
+                (-(ua / rate).value).exp()
+            } else {
+                0.0
+            };
+            target.plus(inlet.minus(target) * retained)
+        };
+
+        // Downcomer from the tank outlet to the collector inlet.
+        let t_tank = *tank_temperature;
+        let (tank, rate_tank) = capacitance_rate(t_tank, "tank outlet")?;
+        let rho_tank = tank.density;
+        let t_collector_in = approach(t_tank, *ambient, downcomer.ua, rate_tank);
+
+        // Collector, linearized about its inlet.
+        let (collector_in, rate_collector) = capacitance_rate(t_collector_in, "collector inlet")?;
+        let rho_collector_in = collector_in.density;
+        let t_stagnation = collector.stagnation(*irradiance, *ambient);
+        let gain = collector.gain(*irradiance, *ambient, t_collector_in);
+        let headroom = t_stagnation.minus(t_collector_in);
+        let collector_ua: ThermalConductance =
+            if headroom.abs() > TemperatureInterval::new::<delta_kelvin>(1e-9) {
+                gain / headroom
+            } else {
+                let excess = t_collector_in.minus(*ambient).abs();
+                collector.area * (collector.linear_loss + 2.0 * collector.quadratic_loss * excess)
+            };
+        let t_collector_out = approach(t_collector_in, t_stagnation, collector_ua, rate_collector);
+
+        // Riser from the collector outlet to the tank return port.
+        let (collector_out, rate_riser) = capacitance_rate(t_collector_out, "collector outlet")?;
+        let rho_collector_out = collector_out.density;
+        let t_return = approach(t_collector_out, *ambient, riser.ua, rate_riser);
+        let rho_return = state(t_return, "tank return")?.density;
+
+        let mean = |a: MassDensity, b: MassDensity| (a + b) / 2.0;
+        let legs = [
+            (downcomer.path, mean(rho_tank, rho_collector_in)),
+            (*collector_path, mean(rho_collector_in, rho_collector_out)),
+            (riser.path, mean(rho_collector_out, rho_return)),
+        ];
+        let tank_rise = -(legs.iter().map(|(path, _)| path.rise).sum::<Length>());
+
+        let g = Acceleration::new::<standard_gravity>(1.0);
+        let mut head = rho_tank * tank_rise;
+        let mut friction = Pressure::ZERO;
+        for (path, density) in &legs {
+            head += *density * path.rise;
+            friction += friction::pressure_drop(path, mass_flow, *density, *viscosity, *roughness);
+        }
+
+        Ok(LoopPoint {
+            tank,
+            temperatures: [t_tank, t_collector_in, t_collector_out, t_return],
+            collected: rate_collector * t_collector_out.minus(t_collector_in),
+            pipe_loss: rate_tank * t_tank.minus(t_collector_in)
+                + rate_riser * t_collector_out.minus(t_return),
+            buoyancy: -(g * head),
+            friction,
+        })
+    }
+}
+
+/// Assembles the output at a converged (or stagnant) loop point.
+fn output<Fluid>(
+    mass_flow: MassRate,
+    point: &LoopPoint<Fluid>,
+    iterations: usize,
+) -> ThermosiphonOutput {
+    let [_, collector_in, collector_out, returned] = point.temperatures;
+    ThermosiphonOutput {
+        mass_flow,
+        collector_inlet_temperature: collector_in,
+        collector_outlet_temperature: collector_out,
+        return_temperature: returned,
+        collected: point.collected,
+        pipe_loss: point.pipe_loss,
+        delivered: point.collected - point.pipe_loss,
+        buoyancy: point.buoyancy,
+        iterations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        area::square_meter,
+        dynamic_viscosity::pascal_second,
+        f64::{Area, HeatTransfer, SpecificHeatCapacity, Velocity, VolumeRate},
+        heat_flux_density::watt_per_square_meter,
+        heat_transfer::watt_per_square_meter_kelvin,
+        length::{meter, millimeter},
+        mass_density::kilogram_per_cubic_meter,
+        power::watt,
+        pressure::{kilopascal, pascal},
+        ratio::ratio,
+        specific_heat_capacity::joule_per_kilogram_kelvin,
+        thermal_conductance::watt_per_kelvin,
+        thermodynamic_temperature::degree_celsius,
+        velocity::meter_per_second,
+        volume_rate::cubic_meter_per_hour,
+    };
+
+    use crate::{
+        models::hydraulic::valve::FlowCoefficient,
+        support::thermo::{
+            PropertyError,
+            capability::{HasCp, HasCv, HasSpeedOfSound, ThermoModel},
+        },
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Liquid;
+
+    /// Water-like liquid whose density falls 0.5 kg/m³ per kelvin.
+    #[derive(Debug, Clone, Copy)]
+    struct BuoyantLiquid;
+
+    impl ThermoModel for BuoyantLiquid {
+        type Fluid = Liquid;
+    }
+
+    impl HasCp for BuoyantLiquid {
+        fn cp(&self, _state: &State<Liquid>) -> Result<SpecificHeatCapacity, PropertyError> {
+            Ok(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+                4180.0,
+            ))
+        }
+    }
+
+    impl HasCv for BuoyantLiquid {
+        fn cv(&self, state: &State<Liquid>) -> Result<SpecificHeatCapacity, PropertyError> {
+            self.cp(state)
+        }
+    }
+
+    impl HasSpeedOfSound for BuoyantLiquid {
+        fn speed_of_sound(&self, _state: &State<Liquid>) -> Result<Velocity, PropertyError> {
+            Ok(Velocity::new::<meter_per_second>(f64::INFINITY))
+        }
+    }
+
+    impl StateFrom<(Liquid, ThermodynamicTemperature, Pressure)> for BuoyantLiquid {
+        type Error = Infallible;
+
+        fn state_from(
+            &self,
+            (fluid, temperature, _pressure): (Liquid, ThermodynamicTemperature, Pressure),
+        ) -> Result<State<Liquid>, Self::Error> {
+            let rho = 1000.0 - 0.5 * (temperature.get::<degree_celsius>() - 20.0);
+            Ok(State::new(
+                temperature,
+                MassDensity::new::<kilogram_per_cubic_meter>(rho),
+                fluid,
+            ))
+        }
+    }
+
+    fn path(length: f64, rise: f64) -> FlowPath {
+        FlowPath {
+            length: Length::new::<meter>(length),
+            diameter: Length::new::<millimeter>(22.0),
+            rise: Length::new::<meter>(rise),
+            minor_loss: Ratio::new::<ratio>(2.0),
+        }
+    }
+
+    fn parameters() -> ThermosiphonParameters {
+        ThermosiphonParameters {
+            collector: Collector {
+                area: Area::new::<square_meter>(2.0),
+                optical_efficiency: Ratio::new::<ratio>(0.75),
+                linear_loss: HeatTransfer::new::<watt_per_square_meter_kelvin>(4.0),
+                quadratic_loss: HeatTransfer::new::<watt_per_square_meter_kelvin>(0.0)
+                    / TemperatureInterval::new::<delta_kelvin>(1.0),
+            },
+            collector_path: path(4.0, 1.0),
+            riser: LoopPipe {
+                path: path(2.0, 0.8),
+                ua: ThermalConductance::new::<watt_per_kelvin>(0.5),
+            },
+            downcomer: LoopPipe {
+                path: path(3.0, -0.6),
+                ua: ThermalConductance::new::<watt_per_kelvin>(0.5),
+            },
+            viscosity: DynamicViscosity::new::<pascal_second>(6e-4),
+            roughness: Length::new::<millimeter>(0.0015),
+            pressure: Pressure::new::<kilopascal>(300.0),
+        }
+    }
+
+    fn loop_() -> Thermosiphon<Liquid, BuoyantLiquid> {
+        Thermosiphon::new(parameters(), ThermosiphonConfig::default(), BuoyantLiquid).unwrap()
+    }
+
+    fn input(irradiance: f64) -> ThermosiphonInput<Liquid> {
+        ThermosiphonInput {
+            fluid: Liquid,
+            irradiance: HeatFluxDensity::new::<watt_per_square_meter>(irradiance),
+            ambient_temperature: ThermodynamicTemperature::new::<degree_celsius>(25.0),
+            tank_temperature: ThermodynamicTemperature::new::<degree_celsius>(40.0),
+        }
+    }
+
+    #[test]
+    fn sunny_loop_circulates_and_balances_buoyancy_with_friction() {
+        let thermosiphon = loop_();
+        let output = thermosiphon.solve(&input(900.0)).unwrap();
+
+        assert!(output.mass_flow > MassRate::ZERO);
+        assert!(output.collector_outlet_temperature > output.collector_inlet_temperature);
+        assert!(output.delivered > Power::ZERO);
+
+        let point = thermosiphon
+            .circulate(&input(900.0), output.mass_flow)
+            .unwrap();
+        assert_relative_eq!(
+            point.buoyancy.value,
+            point.friction.value,
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn collector_energy_balance_holds() {
+        let output = loop_().solve(&input(900.0)).unwrap();
+        let rate =
+            output.mass_flow * SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(4180.0);
+
+        let collected: Power = rate
+            * output
+                .collector_outlet_temperature
+                .minus(output.collector_inlet_temperature);
+        assert_relative_eq!(
+            output.collected.get::<watt>(),
+            collected.get::<watt>(),
+            max_relative = 1e-12
+        );
+        assert!(output.collected < Power::new::<watt>(2.0 * 0.75 * 900.0));
+    }
+
+    #[test]
+    fn stronger_sun_drives_more_flow() {
+        let thermosiphon = loop_();
+        let weak = thermosiphon.solve(&input(400.0)).unwrap();
+        let strong = thermosiphon.solve(&input(1000.0)).unwrap();
+
+        assert!(strong.mass_flow > weak.mass_flow);
+    }
+
+    #[test]
+    fn dark_collector_does_not_circulate() {
+        let output = loop_().solve(&input(0.0)).unwrap();
+
+        assert_eq!(output.mass_flow, MassRate::ZERO);
+        assert_eq!(output.delivered, Power::ZERO);
+    }
+
+    fn check_valve(cracking_pressure: f64) -> CheckValveParameters {
+        CheckValveParameters {
+            flow_coefficient: FlowCoefficient::Kv(VolumeRate::new::<cubic_meter_per_hour>(5.0)),
+            cracking_pressure: Pressure::new::<pascal>(cracking_pressure),
+        }
+    }
+
+    #[test]
+    fn check_valve_blocks_reverse_flow() {
+        let thermosiphon = loop_().with_check_valve(check_valve(0.0)).unwrap();
+
+        // On a cold night the collector holds denser fluid than the tank,
+        // so the loop pushes backward.
+        let night = ThermosiphonInput {
+            fluid: Liquid,
+            irradiance: HeatFluxDensity::new::<watt_per_square_meter>(0.0),
+            ambient_temperature: ThermodynamicTemperature::new::<degree_celsius>(-5.0),
+            tank_temperature: ThermodynamicTemperature::new::<degree_celsius>(50.0),
+        };
+        let output = thermosiphon.solve(&night).unwrap();
+        assert!(output.buoyancy < Pressure::ZERO);
+        assert_eq!(output.mass_flow, MassRate::ZERO);
+
+        let held = CheckValve::new(check_valve(0.0), BuoyantLiquid)
+            .unwrap()
+            .evaluate(&CheckValveInput {
+                inlet: BuoyantLiquid
+                    .state_from((Liquid, night.tank_temperature, parameters().pressure))
+                    .unwrap(),
+                pressure_difference: output.buoyancy,
+            })
+            .unwrap();
+        assert!(!held.open);
+    }
+
+    #[test]
+    fn check_valve_passes_the_circulating_flow() {
+        let open = loop_().solve(&input(900.0)).unwrap();
+        let thermosiphon = loop_().with_check_valve(check_valve(5.0)).unwrap();
+        let output = thermosiphon.solve(&input(900.0)).unwrap();
+
+        // The valve's own drop slows the loop but does not stop it.
+        assert!(output.mass_flow > MassRate::ZERO);
+        assert!(output.mass_flow < open.mass_flow);
+
+        let point = thermosiphon
+            .circulate(&input(900.0), output.mass_flow)
+            .unwrap();
+        let passed = CheckValve::new(check_valve(5.0), BuoyantLiquid)
+            .unwrap()
+            .evaluate(&CheckValveInput {
+                inlet: point.tank,
+                pressure_difference: point.buoyancy - point.friction,
+            })
+            .unwrap();
+        assert!(passed.open);
+        assert_relative_eq!(
+            passed.mass_flow.get::<kilogram_per_second>(),
+            output.mass_flow.get::<kilogram_per_second>(),
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn cracking_pressure_holds_back_weak_circulation() {
+        let thermosiphon = loop_();
+        let stagnant = thermosiphon
+            .circulate(&input(400.0), MassRate::ZERO)
+            .unwrap();
+        let stiff = thermosiphon
+            .with_check_valve(check_valve(2.0 * stagnant.buoyancy.get::<pascal>()))
+            .unwrap();
+
+        assert!(loop_().solve(&input(400.0)).unwrap().mass_flow > MassRate::ZERO);
+        assert_eq!(
+            stiff.solve(&input(400.0)).unwrap().mass_flow,
+            MassRate::ZERO
+        );
+    }
+
+    #[test]
+    fn rejects_negative_cracking_pressure() {
+        assert!(matches!(
+            loop_().with_check_valve(check_valve(-1.0)),
+            Err(ThermosiphonError::CheckValve(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_return_port_below_outlet_port() {
+        let mut parameters = parameters();
+        parameters.riser.path.rise = Length::new::<meter>(-1.0);
+
+        assert!(matches!(
+            Thermosiphon::<Liquid, _>::new(
+                parameters,
+                ThermosiphonConfig::default(),
+                BuoyantLiquid
+            ),
+            Err(ThermosiphonError::InvalidParameter(_))
+        ));
+    }
+
+    #[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
+    mod coolprop_tests {
+        use super::*;
+
+        use uom::si::mass_rate::kilogram_per_hour;
+
+        use crate::support::thermo::{fluid::Water, model::CoolProp};
+
+        #[test]
+        fn water_loop_circulates_at_typical_passive_flow() {
+            let thermosiphon = Thermosiphon::new(
+                parameters(),
+                ThermosiphonConfig::default(),
+                CoolProp::<Water>::new().unwrap(),
+            )
+            .unwrap();
+            let output = thermosiphon
+                .solve(&ThermosiphonInput {
+                    fluid: Water,
+                    irradiance: HeatFluxDensity::new::<watt_per_square_meter>(900.0),
+                    ambient_temperature: ThermodynamicTemperature::new::<degree_celsius>(25.0),
+                    tank_temperature: ThermodynamicTemperature::new::<degree_celsius>(40.0),
+                })
+                .unwrap();
+
+            // Passive collectors run at a few tens of kilograms per hour
+            // per square meter.
+            let flow = output.mass_flow.get::<kilogram_per_hour>();
+            assert!((10.0..400.0).contains(&flow), "flow = {flow} kg/h");
+            let rise = output
+                .collector_outlet_temperature
+                .minus(output.collector_inlet_temperature)
+                .get::<delta_kelvin>();
+            assert!((1.0..40.0).contains(&rise), "rise = {rise} K");
+        }
+    }
+}
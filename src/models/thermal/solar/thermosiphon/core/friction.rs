@@ -0,0 +1,37 @@
+//! Pipe friction for the loop elements.
+
+use std::f64::consts::PI;
+
+use uom::{
+    ConstZero,
+    si::f64::{Area, DynamicViscosity, Length, MassDensity, MassRate, Pressure, Velocity},
+};
+
+use crate::support::friction::churchill;
+
+use super::FlowPath;
+
+/// Frictional pressure drop along a flow path.
+///
+/// Uses `ΔP = (f·L/D + K)·ρ·v²/2` with the Darcy friction factor from
+/// [`churchill`](crate::support::friction::churchill), so the drop is linear
+/// in flow while laminar and approaches quadratic once turbulent.
+pub(super) fn pressure_drop(
+    path: &FlowPath,
+    mass_flow: MassRate,
+    density: MassDensity,
+    viscosity: DynamicViscosity,
+    roughness: Length,
+) -> Pressure {
+    if mass_flow <= MassRate::ZERO {
+        return Pressure::ZERO;
+    }
+
+    let area: Area = PI / 4.0 * path.diameter * path.diameter;
+    let velocity: Velocity = mass_flow / (density * area);
+    let reynolds = (4.0 * mass_flow / (PI * path.diameter * viscosity)).value;
+    let f = churchill(reynolds, (roughness / path.diameter).value);
+
+    let loss = f * (path.length / path.diameter).value + path.minor_loss.value;
+    0.5 * loss * density * velocity * velocity
+}
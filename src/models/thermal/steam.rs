@@ -0,0 +1,4 @@
+//! Steam system components.
+
+pub mod attemperator;
+pub mod prv;
@@ -0,0 +1,488 @@
+use std::{error::Error as StdError, marker::PhantomData};
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::f64::{MassRate, Power, Pressure, Ratio, TemperatureInterval, ThermodynamicTemperature},
+};
+
+use crate::support::{
+    constraint::{Constrained, Constraint, NonNegative, StrictlyPositive, UnitInterval},
+    thermo::{
+        State,
+        capability::{HasEnthalpy, HasPressure, StateFrom, ThermoModel},
+    },
+    units::{SpecificEnthalpy, TemperatureDifference},
+};
+
+/// Required thermo model bounds for an [`Attemperator`].
+#[doc(hidden)]
+pub trait AttemperatorThermoModel<Fluid>:
+    ThermoModel<Fluid = Fluid>
+    + HasPressure
+    + HasEnthalpy
+    + StateFrom<(Fluid, ThermodynamicTemperature, Pressure)>
+    + StateFrom<(Fluid, Pressure, SpecificEnthalpy)>
+    + StateFrom<(Fluid, Pressure, Constrained<Ratio, UnitInterval>)>
+{
+}
+
+impl<Fluid, T> AttemperatorThermoModel<Fluid> for T where
+    T: ThermoModel<Fluid = Fluid>
+        + HasPressure
+        + HasEnthalpy
+        + StateFrom<(Fluid, ThermodynamicTemperature, Pressure)>
+        + StateFrom<(Fluid, Pressure, SpecificEnthalpy)>
+        + StateFrom<(Fluid, Pressure, Constrained<Ratio, UnitInterval>)>
+{
+}
+
+/// Errors that can occur when creating or evaluating an [`Attemperator`].
+#[derive(Debug, Error)]
+pub enum AttemperatorError {
+    /// A design parameter is non-physical.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// A flow or the target temperature is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// The target leaves less superheat than the station allows.
+    ///
+    /// Spraying down this far would carry unevaporated water downstream.
+    #[error("target {target:?} is below the minimum outlet temperature {minimum:?}")]
+    InsufficientSuperheat {
+        /// Requested outlet temperature.
+        target: ThermodynamicTemperature,
+
+        /// Saturation temperature plus the minimum superheat.
+        minimum: ThermodynamicTemperature,
+    },
+
+    /// A thermodynamic model operation failed.
+    #[error("thermodynamic model failed: {context}")]
+    ThermoModelFailed {
+        /// Operation context for the thermodynamic model failure.
+        context: String,
+
+        /// Underlying thermodynamic model error.
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+}
+
+impl AttemperatorError {
+    fn thermo(context: &str, source: impl StdError + Send + Sync + 'static) -> Self {
+        Self::ThermoModelFailed {
+            context: context.to_owned(),
+            source: Box::new(source),
+        }
+    }
+}
+
+/// Design parameters of an [`Attemperator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttemperatorParameters {
+    /// Smallest superheat allowed at the outlet.
+    ///
+    /// Spray needs superheat to evaporate before the next bend or turbine
+    /// inlet, so stations are typically held 10 K to 15 K above saturation.
+    pub min_superheat: TemperatureInterval,
+
+    /// Largest spray flow the control valve can pass.
+    ///
+    /// `None` leaves the spray unlimited.
+    pub spray_capacity: Option<MassRate>,
+}
+
+/// A spray attemperator holding a steam line at a target temperature.
+///
+/// Water sprayed into superheated steam evaporates and cools the mixture.
+/// Stations like this sit between superheater stages in boilers and heat
+/// recovery steam generators, and ahead of process headers and turbines
+/// that cannot take full superheat.
+///
+/// With the outlet fixed at the target temperature and the steam pressure,
+/// the adiabatic mixing balance gives the spray flow directly:
+///
+/// ```text
+/// ṁ_spray = ṁ_steam·(h_steam − h_out)⁄(h_out − h_spray)
+/// ```
+///
+/// Steam already at or below the target passes through without spray.
+/// When the balance asks for more than the spray valve can pass, the valve
+/// runs wide open and the outlet state is found from the mixed enthalpy
+/// instead, leaving the steam above target.
+///
+/// Pressure drop across the station is neglected.
+#[derive(Debug, Clone)]
+pub struct Attemperator<Fluid, Thermo> {
+    thermo: Thermo,
+    parameters: AttemperatorParameters,
+    _fluid: PhantomData<Fluid>,
+}
+
+/// Input to the attemperator model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttemperatorInput<Fluid> {
+    /// Superheated steam entering the station.
+    pub steam: State<Fluid>,
+
+    /// Steam mass flow rate.
+    pub steam_flow: MassRate,
+
+    /// Spray water, usually taken from feedwater.
+    pub spray: State<Fluid>,
+
+    /// Desired outlet temperature.
+    pub target_temperature: ThermodynamicTemperature,
+}
+
+/// Output from the attemperator model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttemperatorOutput<Fluid> {
+    /// Mixed steam leaving the station.
+    pub outlet: State<Fluid>,
+
+    /// Spray water mass flow rate.
+    pub spray_flow: MassRate,
+
+    /// Mass flow rate leaving the station, steam plus spray.
+    pub outlet_flow: MassRate,
+
+    /// Spray flow as a fraction of the entering steam flow.
+    pub spray_ratio: Ratio,
+
+    /// Heat taken up by the spray water, equal to the heat removed from
+    /// the steam.
+    pub heat_absorbed: Power,
+
+    /// Saturation temperature at the steam pressure.
+    pub saturation_temperature: ThermodynamicTemperature,
+
+    /// Superheat of the outlet steam.
+    pub superheat: TemperatureInterval,
+
+    /// Whether the spray valve was wide open short of the target.
+    pub spray_limited: bool,
+}
+
+impl<Fluid, Thermo> Attemperator<Fluid, Thermo> {
+    /// Creates an attemperator.
+    ///
+    /// `thermo` provides thermodynamic property evaluation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AttemperatorError::InvalidParameter`] if the minimum
+    /// superheat or spray capacity is negative or not finite.
+    pub fn new(
+        thermo: Thermo,
+        parameters: AttemperatorParameters,
+    ) -> Result<Self, AttemperatorError> {
+        if NonNegative::check(&parameters.min_superheat).is_err()
+            || !parameters.min_superheat.is_finite()
+        {
+            return Err(AttemperatorError::InvalidParameter(
+                "minimum superheat must be non-negative and finite",
+            ));
+        }
+        if let Some(capacity) = parameters.spray_capacity
+            && (NonNegative::check(&capacity).is_err() || !capacity.is_finite())
+        {
+            return Err(AttemperatorError::InvalidParameter(
+                "spray capacity must be non-negative and finite",
+            ));
+        }
+
+        Ok(Self {
+            thermo,
+            parameters,
+            _fluid: PhantomData,
+        })
+    }
+
+    /// Returns the design parameters.
+    #[must_use]
+    pub fn parameters(&self) -> AttemperatorParameters {
+        self.parameters
+    }
+
+    /// Returns the thermo model.
+    #[must_use]
+    pub fn thermo(&self) -> &Thermo {
+        &self.thermo
+    }
+
+    /// Evaluates the station at a steady operating point.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an input is out of range, if the target leaves
+    /// too little superheat, if the spray is no colder than the target
+    /// outlet, or if a property evaluation fails.
+    pub fn evaluate(
+        &self,
+        input: &AttemperatorInput<Fluid>,
+    ) -> Result<AttemperatorOutput<Fluid>, AttemperatorError>
+    where
+        Fluid: Clone,
+        Thermo: AttemperatorThermoModel<Fluid>,
+    {
+        if StrictlyPositive::check(&input.steam_flow).is_err() || !input.steam_flow.is_finite() {
+            return Err(AttemperatorError::InvalidInput(
+                "steam flow must be strictly positive and finite",
+            ));
+        }
+        if !input.target_temperature.is_finite() {
+            return Err(AttemperatorError::InvalidInput(
+                "target temperature must be finite",
+            ));
+        }
+
+        let fluid = input.steam.fluid.clone();
+        let pressure = self
+            .thermo
+            .pressure(&input.steam)
+            .map_err(|error| AttemperatorError::thermo("steam pressure", error))?;
+        let saturation_temperature = self
+            .thermo
+            .state_from((fluid.clone(), pressure, UnitInterval::one()))
+            .map_err(|error| AttemperatorError::thermo("saturated vapor state", error))?
+            .temperature;
+
+        let minimum = saturation_temperature.plus(self.parameters.min_superheat);
+        if input.target_temperature < minimum {
+            return Err(AttemperatorError::InsufficientSuperheat {
+                target: input.target_temperature,
+                minimum,
+            });
+        }
+
+        // Steam at or below the target needs no spray.
+        if input.steam.temperature <= input.target_temperature {
+            return Ok(AttemperatorOutput {
+                outlet: input.steam.clone(),
+                spray_flow: MassRate::ZERO,
+                outlet_flow: input.steam_flow,
+                spray_ratio: Ratio::ZERO,
+                heat_absorbed: Power::ZERO,
+                saturation_temperature,
+                superheat: input.steam.temperature.minus(saturation_temperature),
+                spray_limited: false,
+            });
+        }
+
+        let h_steam = self.enthalpy(&input.steam, "steam enthalpy")?;
+        let h_spray = self.enthalpy(&input.spray, "spray enthalpy")?;
+        let target = self
+            .thermo
+            .state_from((fluid.clone(), input.target_temperature, pressure))
+            .map_err(|error| AttemperatorError::thermo("target outlet state", error))?;
+        let h_target = self.enthalpy(&target, "target outlet enthalpy")?;
+        if h_spray >= h_target {
+            return Err(AttemperatorError::InvalidInput(
+                "spray must be colder than the target outlet",
+            ));
+        }
+
+        let required = input.steam_flow * ((h_steam - h_target) / (h_target - h_spray));
+        let (outlet, spray_flow, spray_limited) = match self.parameters.spray_capacity {
+            Some(capacity) if required > capacity => {
+                let h_mixed = (input.steam_flow * h_steam + capacity * h_spray)
+                    / (input.steam_flow + capacity);
+                let outlet = self
+                    .thermo
+                    .state_from((fluid, pressure, h_mixed))
+                    .map_err(|error| AttemperatorError::thermo("mixed outlet state", error))?;
+                (outlet, capacity, true)
+            }
+            _ => (target, required, false),
+        };
+
+        let h_outlet = self.enthalpy(&outlet, "outlet enthalpy")?;
+        Ok(AttemperatorOutput {
+            superheat: outlet.temperature.minus(saturation_temperature),
+            outlet,
+            spray_flow,
+            outlet_flow: input.steam_flow + spray_flow,
+            spray_ratio: spray_flow / input.steam_flow,
+            heat_absorbed: spray_flow * (h_outlet - h_spray),
+            saturation_temperature,
+            spray_limited,
+        })
+    }
+
+    fn enthalpy(
+        &self,
+        state: &State<Fluid>,
+        context: &str,
+    ) -> Result<SpecificEnthalpy, AttemperatorError>
+    where
+        Thermo: HasEnthalpy + ThermoModel<Fluid = Fluid>,
+    {
+        self.thermo
+            .enthalpy(state)
+            .map_err(|error| AttemperatorError::thermo(context, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        available_energy::joule_per_kilogram, mass_rate::kilogram_per_second, pressure::kilopascal,
+        temperature_interval::kelvin as delta_kelvin, thermodynamic_temperature::degree_celsius,
+    };
+
+    use crate::models::thermal::hx::discretized::core::test_support::{TestFluid, TestRefrigerant};
+
+    fn attemperator(spray_capacity: Option<f64>) -> Attemperator<TestFluid, TestRefrigerant> {
+        Attemperator::new(
+            TestRefrigerant::water_like(),
+            AttemperatorParameters {
+                min_superheat: TemperatureInterval::new::<delta_kelvin>(10.0),
+                spray_capacity: spray_capacity.map(MassRate::new::<kilogram_per_second>),
+            },
+        )
+        .unwrap()
+    }
+
+    /// Steam at 500 kPa and `steam` °C, with spray water at 40 °C.
+    fn input(thermo: &TestRefrigerant, steam: f64, target: f64) -> AttemperatorInput<TestFluid> {
+        let pressure = Pressure::new::<kilopascal>(500.0);
+        let state = |t| {
+            thermo
+                .state_from((
+                    TestFluid,
+                    ThermodynamicTemperature::new::<degree_celsius>(t),
+                    pressure,
+                ))
+                .unwrap()
+        };
+        AttemperatorInput {
+            steam: state(steam),
+            steam_flow: MassRate::new::<kilogram_per_second>(10.0),
+            spray: state(40.0),
+            target_temperature: ThermodynamicTemperature::new::<degree_celsius>(target),
+        }
+    }
+
+    #[test]
+    fn spray_balances_energy_at_target() {
+        let station = attemperator(None);
+        let input = input(station.thermo(), 350.0, 250.0);
+        let output = station.evaluate(&input).unwrap();
+
+        assert!(!output.spray_limited);
+        assert!(output.spray_flow > MassRate::ZERO);
+        assert_relative_eq!(
+            output.outlet.temperature.get::<degree_celsius>(),
+            250.0,
+            epsilon = 1e-9
+        );
+
+        let h = |state| station.thermo().enthalpy(state).unwrap();
+        let energy_in = input.steam_flow * h(&input.steam) + output.spray_flow * h(&input.spray);
+        let energy_out = output.outlet_flow * h(&output.outlet);
+        assert_relative_eq!(energy_in.value, energy_out.value, max_relative = 1e-12);
+
+        let heat_removed = input.steam_flow * (h(&input.steam) - h(&output.outlet));
+        assert_relative_eq!(
+            output.heat_absorbed.value,
+            heat_removed.value,
+            max_relative = 1e-12
+        );
+        assert!(output.superheat > TemperatureInterval::new::<delta_kelvin>(10.0));
+    }
+
+    #[test]
+    fn steam_below_target_needs_no_spray() {
+        let station = attemperator(None);
+        let input = input(station.thermo(), 250.0, 300.0);
+        let output = station.evaluate(&input).unwrap();
+
+        assert_eq!(output.spray_flow, MassRate::ZERO);
+        assert_eq!(output.outlet, input.steam);
+        assert_eq!(output.outlet_flow, input.steam_flow);
+    }
+
+    #[test]
+    fn spray_capacity_leaves_steam_above_target() {
+        let unlimited = attemperator(None);
+        let input = input(unlimited.thermo(), 350.0, 200.0);
+        let required = unlimited.evaluate(&input).unwrap().spray_flow;
+
+        let limited = attemperator(Some(0.5 * required.get::<kilogram_per_second>()));
+        let output = limited.evaluate(&input).unwrap();
+
+        assert!(output.spray_limited);
+        assert_relative_eq!(output.spray_flow.value, 0.5 * required.value);
+        assert!(output.outlet.temperature > input.target_temperature);
+        assert!(output.outlet.temperature < input.steam.temperature);
+
+        let h = |state| {
+            limited
+                .thermo()
+                .enthalpy(state)
+                .unwrap()
+                .get::<joule_per_kilogram>()
+        };
+        let mixed = (input.steam_flow.value * h(&input.steam)
+            + output.spray_flow.value * h(&input.spray))
+            / output.outlet_flow.value;
+        assert_relative_eq!(h(&output.outlet), mixed, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn rejects_target_too_close_to_saturation() {
+        let station = attemperator(None);
+
+        // Saturation at 500 kPa is about 152 °C for the test fluid.
+        let input = input(station.thermo(), 350.0, 155.0);
+        assert!(matches!(
+            station.evaluate(&input),
+            Err(AttemperatorError::InsufficientSuperheat { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_inputs() {
+        let parameters = |superheat, capacity| AttemperatorParameters {
+            min_superheat: TemperatureInterval::new::<delta_kelvin>(superheat),
+            spray_capacity: capacity,
+        };
+        assert!(matches!(
+            Attemperator::<TestFluid, _>::new(
+                TestRefrigerant::water_like(),
+                parameters(-1.0, None)
+            ),
+            Err(AttemperatorError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            Attemperator::<TestFluid, _>::new(
+                TestRefrigerant::water_like(),
+                parameters(10.0, Some(MassRate::new::<kilogram_per_second>(-1.0)))
+            ),
+            Err(AttemperatorError::InvalidParameter(_))
+        ));
+
+        let station = attemperator(None);
+        let mut no_flow = input(station.thermo(), 350.0, 250.0);
+        no_flow.steam_flow = MassRate::ZERO;
+        assert!(matches!(
+            station.evaluate(&no_flow),
+            Err(AttemperatorError::InvalidInput(_))
+        ));
+
+        let mut hot_spray = input(station.thermo(), 350.0, 250.0);
+        hot_spray.spray = hot_spray.steam;
+        assert!(matches!(
+            station.evaluate(&hot_spray),
+            Err(AttemperatorError::InvalidInput(_))
+        ));
+    }
+}
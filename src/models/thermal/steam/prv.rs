@@ -0,0 +1,79 @@
+//! Steam pressure-reducing valve with flash calculation.
+//!
+//! A [`PressureReducingValve`] drops steam or condensate from a supply
+//! header to a lower-pressure user, as in utility steam distribution and
+//! condensate return systems. It throttles at constant enthalpy, reports
+//! how much of the flow leaves as flash steam, and can trim the superheat
+//! of the reduced steam with a downstream spray
+//! [`Attemperator`](super::attemperator::Attemperator).
+//!
+//! The thermo model must construct states from pressure and enthalpy, from
+//! temperature and pressure, and saturated states from pressure and
+//! quality, such as `CoolProp`
+//! with [`Water`](crate::support::thermo::fluid::Water).
+
+mod core;
+
+use twine_core::Model;
+
+pub use core::{
+    Desuperheat, PressureReducingValve, PressureReducingValveError, PressureReducingValveInput,
+    PressureReducingValveOutput, PressureReducingValveThermoModel,
+};
+
+impl<Fluid, Thermo> Model for PressureReducingValve<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: PressureReducingValveThermoModel<Fluid>,
+{
+    type Input = PressureReducingValveInput<Fluid>;
+    type Output = PressureReducingValveOutput<Fluid>;
+    type Error = PressureReducingValveError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
+
+#[cfg(all(test, any(feature = "coolprop-static", feature = "coolprop-dylib")))]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::{MassRate, Pressure},
+        mass_rate::kilogram_per_second,
+        pressure::bar,
+        ratio::ratio,
+    };
+
+    use crate::support::{
+        constraint::UnitInterval,
+        thermo::{capability::StateFrom, fluid::Water, model::CoolProp},
+    };
+
+    #[test]
+    fn condensate_flash_matches_steam_tables() {
+        let valve = PressureReducingValve::new(CoolProp::<Water>::new().unwrap());
+        let condensate = valve
+            .thermo()
+            .state_from((Water, Pressure::new::<bar>(10.0), UnitInterval::zero()))
+            .unwrap();
+
+        let output = valve
+            .call(&PressureReducingValveInput {
+                inlet: condensate,
+                flow: MassRate::new::<kilogram_per_second>(1.0),
+                outlet_pressure: Pressure::new::<bar>(1.01325),
+                desuperheat: None,
+            })
+            .unwrap();
+
+        // Steam tables: (762.5 − 419.1)⁄2256.4 ≈ 15.2 % flashes.
+        assert_relative_eq!(
+            output.flash_fraction.get::<ratio>(),
+            0.152,
+            max_relative = 0.01
+        );
+    }
+}
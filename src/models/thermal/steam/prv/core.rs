@@ -0,0 +1,461 @@
+use std::{error::Error as StdError, marker::PhantomData};
+
+use thiserror::Error;
+use uom::si::{
+    f64::{MassRate, Pressure, Ratio, TemperatureInterval, ThermodynamicTemperature},
+    ratio::ratio,
+};
+
+use crate::{
+    models::thermal::steam::attemperator::{
+        Attemperator, AttemperatorError, AttemperatorInput, AttemperatorOutput,
+        AttemperatorParameters, AttemperatorThermoModel,
+    },
+    support::{
+        constraint::{Constraint, NonNegative, StrictlyPositive, UnitInterval},
+        thermo::State,
+        units::{SpecificEnthalpy, TemperatureDifference},
+    },
+};
+
+/// Required thermo model bounds for a [`PressureReducingValve`].
+///
+/// The same bounds as an [`Attemperator`], which the valve uses for its
+/// optional desuperheater.
+#[doc(hidden)]
+pub trait PressureReducingValveThermoModel<Fluid>: AttemperatorThermoModel<Fluid> {}
+
+impl<Fluid, T> PressureReducingValveThermoModel<Fluid> for T where T: AttemperatorThermoModel<Fluid> {}
+
+/// Errors that can occur when creating or evaluating a
+/// [`PressureReducingValve`].
+#[derive(Debug, Error)]
+pub enum PressureReducingValveError {
+    /// A flow or pressure is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// The downstream desuperheater could not be created or evaluated.
+    #[error("desuperheater failed")]
+    Desuperheater(#[source] AttemperatorError),
+
+    /// A thermodynamic model operation failed.
+    #[error("thermodynamic model failed: {context}")]
+    ThermoModelFailed {
+        /// Operation context for the thermodynamic model failure.
+        context: String,
+
+        /// Underlying thermodynamic model error.
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+}
+
+impl PressureReducingValveError {
+    fn thermo(context: &str, source: impl StdError + Send + Sync + 'static) -> Self {
+        Self::ThermoModelFailed {
+            context: context.to_owned(),
+            source: Box::new(source),
+        }
+    }
+}
+
+/// A pressure-reducing valve station on a steam or condensate line.
+///
+/// Throttling through the valve is adiabatic and does no work, so the
+/// outlet state has the inlet enthalpy at the downstream pressure. Steam
+/// leaves drier or more superheated than it entered, and hot condensate
+/// partly flashes to steam. The flash fraction is the vapor mass fraction
+/// of the outlet,
+///
+/// ```text
+/// x = (h − h_f)⁄(h_g − h_f)
+/// ```
+///
+/// with the saturated liquid and vapor enthalpies at the downstream
+/// pressure, clamped to zero for subcooled liquid and one for superheated
+/// vapor.
+///
+/// Utility steam stations often follow the valve with a spray
+/// desuperheater, since reducing pressure raises the superheat that process
+/// users see. Configure one with
+/// [`with_desuperheater`](Self::with_desuperheater) and pass its spray and
+/// target through [`PressureReducingValveInput::desuperheat`].
+#[derive(Debug, Clone)]
+pub struct PressureReducingValve<Fluid, Thermo> {
+    thermo: Thermo,
+    desuperheater: Option<AttemperatorParameters>,
+    _fluid: PhantomData<Fluid>,
+}
+
+/// Spray conditions for the desuperheater after a
+/// [`PressureReducingValve`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Desuperheat<Fluid> {
+    /// Spray water.
+    pub spray: State<Fluid>,
+
+    /// Desired temperature after the desuperheater.
+    pub target_temperature: ThermodynamicTemperature,
+}
+
+/// Input to the pressure-reducing valve model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PressureReducingValveInput<Fluid> {
+    /// Steam or condensate entering the valve.
+    pub inlet: State<Fluid>,
+
+    /// Mass flow rate through the valve.
+    pub flow: MassRate,
+
+    /// Pressure held downstream of the valve.
+    pub outlet_pressure: Pressure,
+
+    /// Spray conditions for the desuperheater, if the station has one.
+    pub desuperheat: Option<Desuperheat<Fluid>>,
+}
+
+/// Output from the pressure-reducing valve model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PressureReducingValveOutput<Fluid> {
+    /// State leaving the valve, before any desuperheating.
+    pub outlet: State<Fluid>,
+
+    /// Vapor mass fraction leaving the valve.
+    pub flash_fraction: Ratio,
+
+    /// Vapor mass flow rate leaving the valve.
+    pub flash_steam: MassRate,
+
+    /// Liquid mass flow rate leaving the valve.
+    pub liquid: MassRate,
+
+    /// Saturation temperature at the downstream pressure.
+    pub saturation_temperature: ThermodynamicTemperature,
+
+    /// Outlet temperature above saturation.
+    ///
+    /// Zero for a two-phase outlet and negative for subcooled liquid.
+    pub superheat: TemperatureInterval,
+
+    /// Result of the downstream desuperheater, if one ran.
+    pub desuperheat: Option<AttemperatorOutput<Fluid>>,
+}
+
+impl<Fluid, Thermo> PressureReducingValve<Fluid, Thermo> {
+    /// Creates a valve without a desuperheater.
+    ///
+    /// `thermo` provides thermodynamic property evaluation.
+    #[must_use]
+    pub fn new(thermo: Thermo) -> Self {
+        Self {
+            thermo,
+            desuperheater: None,
+            _fluid: PhantomData,
+        }
+    }
+
+    /// Adds a spray desuperheater downstream of the valve.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PressureReducingValveError::Desuperheater`] if the
+    /// desuperheater parameters are invalid.
+    pub fn with_desuperheater(
+        mut self,
+        parameters: AttemperatorParameters,
+    ) -> Result<Self, PressureReducingValveError> {
+        Attemperator::<Fluid, _>::new(&self.thermo, parameters)
+            .map_err(PressureReducingValveError::Desuperheater)?;
+        self.desuperheater = Some(parameters);
+        Ok(self)
+    }
+
+    /// Returns the desuperheater parameters, if the station has one.
+    #[must_use]
+    pub fn desuperheater(&self) -> Option<AttemperatorParameters> {
+        self.desuperheater
+    }
+
+    /// Returns the thermo model.
+    #[must_use]
+    pub fn thermo(&self) -> &Thermo {
+        &self.thermo
+    }
+
+    /// Evaluates the station at a steady operating point.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an input is out of range, if desuperheat is
+    /// requested from a station without a desuperheater or is missing from
+    /// one with it, if the desuperheater fails, or if a property evaluation
+    /// fails.
+    pub fn evaluate(
+        &self,
+        input: &PressureReducingValveInput<Fluid>,
+    ) -> Result<PressureReducingValveOutput<Fluid>, PressureReducingValveError>
+    where
+        Fluid: Clone,
+        Thermo: PressureReducingValveThermoModel<Fluid>,
+    {
+        if NonNegative::check(&input.flow).is_err() || !input.flow.is_finite() {
+            return Err(PressureReducingValveError::InvalidInput(
+                "flow must be non-negative and finite",
+            ));
+        }
+        if StrictlyPositive::check(&input.outlet_pressure).is_err()
+            || !input.outlet_pressure.is_finite()
+        {
+            return Err(PressureReducingValveError::InvalidInput(
+                "outlet pressure must be strictly positive and finite",
+            ));
+        }
+
+        let inlet_pressure = self
+            .thermo
+            .pressure(&input.inlet)
+            .map_err(|error| PressureReducingValveError::thermo("inlet pressure", error))?;
+        if input.outlet_pressure > inlet_pressure {
+            return Err(PressureReducingValveError::InvalidInput(
+                "outlet pressure must not exceed inlet pressure",
+            ));
+        }
+
+        let fluid = input.inlet.fluid.clone();
+        let enthalpy = self.enthalpy(&input.inlet, "inlet enthalpy")?;
+        let outlet = self
+            .thermo
+            .state_from((fluid.clone(), input.outlet_pressure, enthalpy))
+            .map_err(|error| PressureReducingValveError::thermo("outlet state", error))?;
+
+        let liquid = self
+            .thermo
+            .state_from((fluid.clone(), input.outlet_pressure, UnitInterval::zero()))
+            .map_err(|error| PressureReducingValveError::thermo("saturated liquid", error))?;
+        let vapor = self
+            .thermo
+            .state_from((fluid, input.outlet_pressure, UnitInterval::one()))
+            .map_err(|error| PressureReducingValveError::thermo("saturated vapor", error))?;
+        let h_liquid = self.enthalpy(&liquid, "saturated liquid enthalpy")?;
+        let h_vapor = self.enthalpy(&vapor, "saturated vapor enthalpy")?;
+
+        let flash = ((enthalpy - h_liquid) / (h_vapor - h_liquid))
+            .get::<ratio>()
+            .clamp(0.0, 1.0);
+        let saturation_temperature = vapor.temperature;
+
+        let desuperheat = match (self.desuperheater, &input.desuperheat) {
+            (Some(parameters), Some(desuperheat)) => Some(
+                Attemperator::new(&self.thermo, parameters)
+                    .and_then(|attemperator| {
+                        attemperator.evaluate(&AttemperatorInput {
+                            steam: outlet.clone(),
+                            steam_flow: input.flow,
+                            spray: desuperheat.spray.clone(),
+                            target_temperature: desuperheat.target_temperature,
+                        })
+                    })
+                    .map_err(PressureReducingValveError::Desuperheater)?,
+            ),
+            (None, None) => None,
+            (Some(_), None) => {
+                return Err(PressureReducingValveError::InvalidInput(
+                    "station has a desuperheater but no spray conditions were given",
+                ));
+            }
+            (None, Some(_)) => {
+                return Err(PressureReducingValveError::InvalidInput(
+                    "spray conditions were given but the station has no desuperheater",
+                ));
+            }
+        };
+
+        Ok(PressureReducingValveOutput {
+            superheat: outlet.temperature.minus(saturation_temperature),
+            outlet,
+            flash_fraction: Ratio::new::<ratio>(flash),
+            flash_steam: flash * input.flow,
+            liquid: (1.0 - flash) * input.flow,
+            saturation_temperature,
+            desuperheat,
+        })
+    }
+
+    fn enthalpy(
+        &self,
+        state: &State<Fluid>,
+        context: &str,
+    ) -> Result<SpecificEnthalpy, PressureReducingValveError>
+    where
+        Thermo: PressureReducingValveThermoModel<Fluid>,
+    {
+        self.thermo
+            .enthalpy(state)
+            .map_err(|error| PressureReducingValveError::thermo(context, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::{
+        ConstZero,
+        si::{
+            mass_rate::kilogram_per_second,
+            pressure::kilopascal,
+            temperature_interval::kelvin as delta_kelvin,
+            thermodynamic_temperature::{degree_celsius, kelvin},
+        },
+    };
+
+    use crate::{
+        models::thermal::hx::discretized::core::test_support::{TestFluid, TestRefrigerant},
+        support::thermo::capability::StateFrom,
+    };
+
+    fn valve() -> PressureReducingValve<TestFluid, TestRefrigerant> {
+        PressureReducingValve::new(TestRefrigerant::water_like())
+    }
+
+    fn saturated(thermo: &TestRefrigerant, kpa: f64, quality: f64) -> State<TestFluid> {
+        thermo
+            .state_from((
+                TestFluid,
+                Pressure::new::<kilopascal>(kpa),
+                UnitInterval::new(Ratio::new::<ratio>(quality)).unwrap(),
+            ))
+            .unwrap()
+    }
+
+    fn input(inlet: State<TestFluid>, outlet_kpa: f64) -> PressureReducingValveInput<TestFluid> {
+        PressureReducingValveInput {
+            inlet,
+            flow: MassRate::new::<kilogram_per_second>(2.0),
+            outlet_pressure: Pressure::new::<kilopascal>(outlet_kpa),
+            desuperheat: None,
+        }
+    }
+
+    #[test]
+    fn condensate_flashes_by_sensible_excess() {
+        let valve = valve();
+        let condensate = saturated(valve.thermo(), 1000.0, 0.0);
+        let output = valve.evaluate(&input(condensate, 101.325)).unwrap();
+
+        // Both phases share one specific heat, so x = cp·(T₁ − T₂)⁄h_fg.
+        let t_high = condensate.temperature.get::<kelvin>();
+        let t_low = output.saturation_temperature.get::<kelvin>();
+        assert_relative_eq!(t_low, 373.15, max_relative = 1e-9);
+        let expected = 2100.0 * (t_high - t_low) / 2.257e6;
+        assert_relative_eq!(
+            output.flash_fraction.get::<ratio>(),
+            expected,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            output.flash_steam.get::<kilogram_per_second>(),
+            2.0 * expected,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            (output.flash_steam + output.liquid).get::<kilogram_per_second>(),
+            2.0
+        );
+        assert_eq!(output.superheat, TemperatureInterval::ZERO);
+    }
+
+    #[test]
+    fn saturated_steam_leaves_superheated() {
+        let valve = valve();
+        let steam = saturated(valve.thermo(), 1000.0, 1.0);
+        let output = valve.evaluate(&input(steam, 200.0)).unwrap();
+
+        assert_relative_eq!(output.flash_fraction.get::<ratio>(), 1.0);
+        assert_eq!(output.liquid, MassRate::ZERO);
+
+        // An ideal-gas vapor throttles at constant temperature.
+        assert_relative_eq!(
+            output.outlet.temperature.get::<kelvin>(),
+            steam.temperature.get::<kelvin>(),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            output.superheat.get::<delta_kelvin>(),
+            steam.temperature.get::<kelvin>() - output.saturation_temperature.get::<kelvin>(),
+            max_relative = 1e-12
+        );
+        assert!(output.desuperheat.is_none());
+    }
+
+    #[test]
+    fn desuperheater_trims_the_reduced_steam() {
+        let valve = valve()
+            .with_desuperheater(AttemperatorParameters {
+                min_superheat: TemperatureInterval::new::<delta_kelvin>(5.0),
+                spray_capacity: None,
+            })
+            .unwrap();
+        let steam = saturated(valve.thermo(), 1000.0, 1.0);
+        let spray = valve
+            .thermo()
+            .state_from((
+                TestFluid,
+                ThermodynamicTemperature::new::<degree_celsius>(40.0),
+                Pressure::new::<kilopascal>(1000.0),
+            ))
+            .unwrap();
+        let target = ThermodynamicTemperature::new::<degree_celsius>(130.0);
+
+        let mut input = input(steam, 200.0);
+        input.desuperheat = Some(Desuperheat {
+            spray,
+            target_temperature: target,
+        });
+        let output = valve.evaluate(&input).unwrap();
+
+        let desuperheat = output.desuperheat.unwrap();
+        assert_relative_eq!(
+            desuperheat.outlet.temperature.get::<degree_celsius>(),
+            130.0,
+            epsilon = 1e-9
+        );
+        assert!(desuperheat.spray_flow > MassRate::ZERO);
+        assert_eq!(desuperheat.outlet_flow, input.flow + desuperheat.spray_flow);
+    }
+
+    #[test]
+    fn rejects_invalid_inputs() {
+        let station = valve();
+        let steam = saturated(station.thermo(), 500.0, 1.0);
+
+        assert!(matches!(
+            station.evaluate(&input(steam, 600.0)),
+            Err(PressureReducingValveError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            station.evaluate(&input(steam, 0.0)),
+            Err(PressureReducingValveError::InvalidInput(_))
+        ));
+
+        let mut unexpected_spray = input(steam, 200.0);
+        unexpected_spray.desuperheat = Some(Desuperheat {
+            spray: saturated(station.thermo(), 500.0, 0.0),
+            target_temperature: ThermodynamicTemperature::new::<degree_celsius>(130.0),
+        });
+        assert!(matches!(
+            station.evaluate(&unexpected_spray),
+            Err(PressureReducingValveError::InvalidInput(_))
+        ));
+
+        assert!(matches!(
+            valve().with_desuperheater(AttemperatorParameters {
+                min_superheat: TemperatureInterval::new::<delta_kelvin>(-1.0),
+                spray_capacity: None,
+            }),
+            Err(PressureReducingValveError::Desuperheater(_))
+        ));
+    }
+}
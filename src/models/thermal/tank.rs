@@ -1,3 +1,4 @@
 //! Thermal storage tanks.
 
 pub mod stratified;
+pub mod two_tank;
@@ -56,6 +56,9 @@
 //! assert_eq!(output.temperatures.len(), 5);
 //! ```
 //!
+//! [`StratifiedTank::builder`] assembles the same configuration field by
+//! field, defaulting to an adiabatic tank and validating the fluid properties.
+//!
 //! ## Full simulation example
 //!
 //! The `stratified_tank` example demonstrates a complete transient simulation
@@ -70,16 +73,14 @@ mod core;
 use std::convert::Infallible;
 
 use twine_core::{DerivativeOf, Model, OdeProblem, StepIntegrable};
-use uom::si::{
-    f64::{ThermodynamicTemperature, Time},
-    temperature_interval::kelvin as delta_kelvin,
-    thermodynamic_temperature::kelvin,
-};
+use uom::si::f64::{ThermodynamicTemperature, Time};
+
+use crate::support::units::TemperatureDifference;
 
 pub use core::{
     AuxHeatFlow, Environment, Fluid, Geometry, Insulation, Location, PortFlow, PortLocation,
-    StratifiedTank, StratifiedTankError, StratifiedTankInput, StratifiedTankOutput,
-    TemperatureRate, ValidatedPower,
+    StratifiedTank, StratifiedTankBuilder, StratifiedTankError, StratifiedTankInput,
+    StratifiedTankOutput, TemperatureRate, ValidatedPower,
 };
 
 impl<const N: usize, const P: usize, const Q: usize> Model for StratifiedTank<N, P, Q> {
@@ -112,10 +113,8 @@ impl<const N: usize> StepIntegrable<Time> for TankState<N> {
     fn step(&self, derivative: TankDerivative<N>, delta: Time) -> Self {
         TankState {
             temperatures: std::array::from_fn(|i| {
-                // TemperatureRate * Time = TemperatureInterval (in kelvin).
-                let delta_t = (derivative.rates[i] * delta).get::<delta_kelvin>();
-                let t_k = self.temperatures[i].get::<kelvin>();
-                ThermodynamicTemperature::new::<kelvin>(t_k + delta_t)
+                // TemperatureRate * Time = TemperatureInterval.
+                self.temperatures[i].plus(derivative.rates[i] * delta)
             }),
         }
     }
@@ -1,4 +1,5 @@
 mod aux_heat_flow;
+mod builder;
 mod buoyancy;
 mod energy_balance;
 mod environment;
@@ -17,7 +18,7 @@ use uom::{
     ConstZero,
     si::f64::{
         HeatCapacity, HeatTransfer, Ratio, TemperatureInterval, ThermalConductance,
-        ThermalConductivity, ThermodynamicTemperature, Time, Volume, VolumeRate,
+        ThermalConductivity, ThermodynamicTemperature, Volume, VolumeRate,
     },
 };
 
@@ -25,6 +26,7 @@ use geometry::NodeGeometry;
 use node::{Adjacent, Node};
 
 pub use aux_heat_flow::{AuxHeatFlow, ValidatedPower};
+pub use builder::StratifiedTankBuilder;
 pub use environment::Environment;
 pub use fluid::Fluid;
 pub use geometry::Geometry;
@@ -32,11 +34,9 @@ pub use insulation::Insulation;
 pub use location::{Location, PortLocation};
 pub use port_flow::PortFlow;
 
-/// Rate of change of temperature (K/s in SI).
-///
-/// Uses `TemperatureInterval / Time` rather than `ThermodynamicTemperature / Time`
-/// so that the type aligns with standard uom arithmetic (e.g., `Power / HeatCapacity`).
-pub type TemperatureRate = <TemperatureInterval as Div<Time>>::Output;
+pub use crate::support::units::TemperatureRate;
+
+use crate::support::units::volumetric_heat_capacity;
 
 type InverseHeatCapacity = <Ratio as Div<HeatCapacity>>::Output;
 type InverseVolume = <Ratio as Div<Volume>>::Output;
@@ -45,10 +45,18 @@ type TemperatureFlow = <VolumeRate as Mul<TemperatureInterval>>::Output;
 /// Errors that can occur when creating or using a [`StratifiedTank`].
 #[derive(Debug, Error)]
 pub enum StratifiedTankError {
+    /// A required builder field was not set.
+    #[error("missing {0}")]
+    Missing(&'static str),
+
     /// The tank geometry is invalid.
     #[error("geometry is invalid: {0}")]
     Geometry(String),
 
+    /// A fluid property is invalid.
+    #[error("fluid is invalid: {0}")]
+    Fluid(String),
+
     /// An auxiliary heat source location is invalid.
     #[error("aux[{index}] location is invalid: {context}")]
     AuxLocation {
@@ -223,7 +231,9 @@ impl<const P: usize, const Q: usize> StratifiedTank<0, P, Q> {
 
             Node {
                 inv_volume: node.volume.recip(),
-                inv_heat_capacity: (node.volume * fluid.density * fluid.specific_heat).recip(),
+                inv_heat_capacity: (node.volume
+                    * volumetric_heat_capacity(fluid.density, fluid.specific_heat))
+                .recip(),
                 ua,
                 aux_heat_weights: aux_weight_by_node[i],
                 port_inlet_weights: inlet_weight_by_node[i],
@@ -467,7 +477,7 @@ mod tests {
     }
 
     fn k_per_s(rate: TemperatureRate) -> f64 {
-        use uom::si::{temperature_interval::kelvin as delta_kelvin, time::second};
+        use uom::si::{f64::Time, temperature_interval::kelvin as delta_kelvin, time::second};
         (rate * Time::new::<second>(1.0)).get::<delta_kelvin>()
     }
 
@@ -0,0 +1,243 @@
+use super::{
+    Fluid, Geometry, Insulation, Location, PortLocation, StratifiedTank, StratifiedTankError,
+};
+
+/// Builder for a [`StratifiedTank`].
+///
+/// The fluid and geometry are required; insulation defaults to
+/// [`Insulation::Adiabatic`] and the tank starts with no ports or auxiliary
+/// sources. Setting the port or auxiliary locations changes the builder's
+/// `P` or `Q` parameter to match the array length.
+///
+/// All fields are validated at [`build`](Self::build).
+///
+/// ```
+/// use twine_models::models::thermal::tank::stratified::{
+///     Fluid, Geometry, Location, PortLocation, StratifiedTank,
+/// };
+/// use uom::si::{
+///     f64::{Length, MassDensity, SpecificHeatCapacity, ThermalConductivity},
+///     length::meter,
+///     mass_density::kilogram_per_cubic_meter,
+///     specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+///     thermal_conductivity::watt_per_meter_kelvin,
+/// };
+///
+/// let tank = StratifiedTank::builder()
+///     .fluid(Fluid {
+///         density: MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///         specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(4.186),
+///         thermal_conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(0.6),
+///     })
+///     .geometry(Geometry::VerticalCylinder {
+///         diameter: Length::new::<meter>(0.5),
+///         height: Length::new::<meter>(1.8),
+///     })
+///     .ports([PortLocation {
+///         inlet: Location::tank_bottom(),
+///         outlet: Location::tank_top(),
+///     }])
+///     .aux([Location::tank_top()])
+///     .build::<10>()?;
+/// # let _: StratifiedTank<10, 1, 1> = tank;
+/// # Ok::<(), twine_models::models::thermal::tank::stratified::StratifiedTankError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct StratifiedTankBuilder<const P: usize, const Q: usize> {
+    fluid: Option<Fluid>,
+    geometry: Option<Geometry>,
+    insulation: Insulation,
+    aux_locations: [Location; Q],
+    port_locations: [PortLocation; P],
+}
+
+impl StratifiedTank<0, 0, 0> {
+    /// Returns a builder for a stratified tank.
+    ///
+    /// See [`StratifiedTankBuilder`] for defaults and an example.
+    #[must_use]
+    pub fn builder() -> StratifiedTankBuilder<0, 0> {
+        StratifiedTankBuilder {
+            fluid: None,
+            geometry: None,
+            insulation: Insulation::Adiabatic,
+            aux_locations: [],
+            port_locations: [],
+        }
+    }
+}
+
+impl<const P: usize, const Q: usize> StratifiedTankBuilder<P, Q> {
+    /// Sets the fluid properties.
+    #[must_use]
+    pub fn fluid(self, fluid: Fluid) -> Self {
+        Self {
+            fluid: Some(fluid),
+            ..self
+        }
+    }
+
+    /// Sets the tank geometry.
+    #[must_use]
+    pub fn geometry(self, geometry: Geometry) -> Self {
+        Self {
+            geometry: Some(geometry),
+            ..self
+        }
+    }
+
+    /// Sets the tank insulation.
+    #[must_use]
+    pub fn insulation(self, insulation: Insulation) -> Self {
+        Self { insulation, ..self }
+    }
+
+    /// Sets the port pair locations, replacing any set previously.
+    #[must_use]
+    pub fn ports<const P2: usize>(
+        self,
+        port_locations: [PortLocation; P2],
+    ) -> StratifiedTankBuilder<P2, Q> {
+        StratifiedTankBuilder {
+            fluid: self.fluid,
+            geometry: self.geometry,
+            insulation: self.insulation,
+            aux_locations: self.aux_locations,
+            port_locations,
+        }
+    }
+
+    /// Sets the auxiliary heat source locations, replacing any set previously.
+    #[must_use]
+    pub fn aux<const Q2: usize>(
+        self,
+        aux_locations: [Location; Q2],
+    ) -> StratifiedTankBuilder<P, Q2> {
+        StratifiedTankBuilder {
+            fluid: self.fluid,
+            geometry: self.geometry,
+            insulation: self.insulation,
+            aux_locations,
+            port_locations: self.port_locations,
+        }
+    }
+
+    /// Validates the configuration and builds a tank with `N` nodes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StratifiedTankError::Missing`] if the fluid or geometry was
+    /// not set, [`StratifiedTankError::Fluid`] if a fluid property is
+    /// invalid, or any error from [`StratifiedTank::new`].
+    pub fn build<const N: usize>(self) -> Result<StratifiedTank<N, P, Q>, StratifiedTankError> {
+        let fluid = self.fluid.ok_or(StratifiedTankError::Missing("fluid"))?;
+        let geometry = self
+            .geometry
+            .ok_or(StratifiedTankError::Missing("geometry"))?;
+
+        fluid.validate().map_err(StratifiedTankError::Fluid)?;
+
+        StratifiedTank::new::<N>(
+            fluid,
+            geometry,
+            self.insulation,
+            self.aux_locations,
+            self.port_locations,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uom::si::{
+        f64::{Length, MassDensity, SpecificHeatCapacity, ThermalConductivity},
+        length::meter,
+        mass_density::kilogram_per_cubic_meter,
+        specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+    };
+
+    fn water() -> Fluid {
+        Fluid {
+            density: MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+            specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(4.0),
+            thermal_conductivity: ThermalConductivity::default(),
+        }
+    }
+
+    fn cylinder() -> Geometry {
+        Geometry::VerticalCylinder {
+            diameter: Length::new::<meter>(1.0),
+            height: Length::new::<meter>(2.0),
+        }
+    }
+
+    #[test]
+    fn builds_tank_without_ports_or_aux() {
+        let tank: StratifiedTank<4, 0, 0> = StratifiedTank::builder()
+            .geometry(cylinder())
+            .fluid(water())
+            .build()
+            .unwrap();
+
+        assert_eq!(tank.vols.len(), 4);
+    }
+
+    #[test]
+    fn reports_missing_fields() {
+        assert!(matches!(
+            StratifiedTank::builder().geometry(cylinder()).build::<2>(),
+            Err(StratifiedTankError::Missing("fluid"))
+        ));
+        assert!(matches!(
+            StratifiedTank::builder().fluid(water()).build::<2>(),
+            Err(StratifiedTankError::Missing("geometry"))
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_fluid() {
+        let fluid = Fluid {
+            density: MassDensity::new::<kilogram_per_cubic_meter>(-1.0),
+            ..water()
+        };
+
+        assert!(matches!(
+            StratifiedTank::builder()
+                .fluid(fluid)
+                .geometry(cylinder())
+                .build::<2>(),
+            Err(StratifiedTankError::Fluid(_))
+        ));
+    }
+
+    #[test]
+    fn location_setters_change_counts() {
+        let tank: StratifiedTank<3, 0, 2> = StratifiedTank::builder()
+            .fluid(water())
+            .geometry(cylinder())
+            .ports([PortLocation {
+                inlet: Location::tank_bottom(),
+                outlet: Location::tank_top(),
+            }])
+            .aux([Location::tank_top(), Location::tank_bottom()])
+            .ports([])
+            .build()
+            .unwrap();
+
+        assert_eq!(tank.vols.len(), 3);
+    }
+
+    #[test]
+    fn forwards_location_errors() {
+        assert!(matches!(
+            StratifiedTank::builder()
+                .fluid(water())
+                .geometry(cylinder())
+                .aux([Location::point_in_node(5)])
+                .build::<3>(),
+            Err(StratifiedTankError::AuxLocation { index: 0, .. })
+        ));
+    }
+}
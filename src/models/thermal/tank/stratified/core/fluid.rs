@@ -16,3 +16,33 @@ pub struct Fluid {
     /// Thermal conductivity, used to compute node-to-node conduction.
     pub thermal_conductivity: ThermalConductivity,
 }
+
+impl Fluid {
+    /// Checks that the fluid properties are physically meaningful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string describing the first invalid property found.
+    /// Callers wrap these into [`super::super::StratifiedTankError::Fluid`].
+    pub(super) fn validate(&self) -> Result<(), String> {
+        let Self {
+            density,
+            specific_heat,
+            thermal_conductivity,
+        } = *self;
+
+        if density.value <= 0.0 || !density.value.is_finite() {
+            return Err(format!("density must be > 0, got {density:?}"));
+        }
+        if specific_heat.value <= 0.0 || !specific_heat.value.is_finite() {
+            return Err(format!("specific heat must be > 0, got {specific_heat:?}"));
+        }
+        if thermal_conductivity.value < 0.0 || !thermal_conductivity.value.is_finite() {
+            return Err(format!(
+                "thermal conductivity must be ≥ 0, got {thermal_conductivity:?}"
+            ));
+        }
+
+        Ok(())
+    }
+}
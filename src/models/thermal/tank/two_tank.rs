@@ -0,0 +1,254 @@
+//! Two-tank sensible thermal energy storage.
+//!
+//! A [`TwoTankStorage`] holds a liquid storage medium, such as molten salt,
+//! in a cold tank and a hot tank. Charging pumps medium from the cold tank
+//! through a counterflow exchanger, where the heat transfer fluid heats it,
+//! into the hot tank. Discharging runs the other way and heats the heat
+//! transfer fluid. Each tank's mass and temperature integrate in time, and
+//! each tank loses heat to ambient.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::tank::two_tank::{
+//!         TankInventory, TwoTankInput, TwoTankOperation, TwoTankParameters, TwoTankStorage,
+//!     },
+//!     support::{
+//!         hx::{CapacitanceRate, StreamInlet},
+//!         thermo::{fluid::SolarSalt, model::Incompressible},
+//!     },
+//! };
+//! use uom::si::{
+//!     f64::{Mass, MassRate, ThermalConductance, ThermodynamicTemperature},
+//!     mass::megagram,
+//!     mass_rate::kilogram_per_second,
+//!     ratio::ratio,
+//!     thermal_conductance::{kilowatt_per_kelvin, watt_per_kelvin},
+//!     thermodynamic_temperature::degree_celsius,
+//! };
+//!
+//! let storage = TwoTankStorage::new(
+//!     TwoTankParameters {
+//!         heel: Mass::new::<megagram>(50.0),
+//!         hot_tank_ua: ThermalConductance::new::<watt_per_kelvin>(300.0),
+//!         cold_tank_ua: ThermalConductance::new::<watt_per_kelvin>(250.0),
+//!         hx_ua: ThermalConductance::new::<kilowatt_per_kelvin>(5000.0),
+//!     },
+//!     Incompressible::<SolarSalt>::new()?,
+//! )?;
+//!
+//! // Hot oil from the solar field charges the storage.
+//! let output = storage.evaluate(&TwoTankInput {
+//!     fluid: SolarSalt,
+//!     hot: TankInventory {
+//!         mass: Mass::new::<megagram>(200.0),
+//!         temperature: ThermodynamicTemperature::new::<degree_celsius>(385.0),
+//!     },
+//!     cold: TankInventory {
+//!         mass: Mass::new::<megagram>(900.0),
+//!         temperature: ThermodynamicTemperature::new::<degree_celsius>(290.0),
+//!     },
+//!     ambient_temperature: ThermodynamicTemperature::new::<degree_celsius>(20.0),
+//!     operation: TwoTankOperation::Charge {
+//!         flow: MassRate::new::<kilogram_per_second>(500.0),
+//!         htf: StreamInlet::new(
+//!             CapacitanceRate::new::<kilowatt_per_kelvin>(1900.0)?,
+//!             ThermodynamicTemperature::new::<degree_celsius>(393.0),
+//!         ),
+//!     },
+//! })?;
+//!
+//! assert!(output.derivative.hot_mass.get::<kilogram_per_second>() > 0.0);
+//! assert!(output.state_of_charge.get::<ratio>() < 0.2);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod core;
+
+use std::{convert::Infallible, marker::PhantomData};
+
+use twine_core::{DerivativeOf, Model, OdeProblem, StepIntegrable};
+use uom::si::f64::Time;
+
+pub use core::{
+    TankInventory, TwoTankDerivative, TwoTankError, TwoTankInput, TwoTankOperation, TwoTankOutput,
+    TwoTankParameters, TwoTankStorage, TwoTankThermoModel,
+};
+
+impl<Fluid, Thermo> Model for TwoTankStorage<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: TwoTankThermoModel<Fluid>,
+{
+    type Input = TwoTankInput<Fluid>;
+    type Output = TwoTankOutput;
+    type Error = TwoTankError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
+
+/// Tank inventories, used as the ODE state for time integration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwoTankState {
+    /// Hot tank inventory.
+    pub hot: TankInventory,
+
+    /// Cold tank inventory.
+    pub cold: TankInventory,
+}
+
+impl StepIntegrable<Time> for TwoTankState {
+    type Derivative = TwoTankDerivative;
+
+    fn step(&self, derivative: TwoTankDerivative, delta: Time) -> Self {
+        TwoTankState {
+            hot: TankInventory {
+                mass: self.hot.mass + derivative.hot_mass * delta,
+                temperature: self.hot.temperature + derivative.hot_temperature * delta,
+            },
+            cold: TankInventory {
+                mass: self.cold.mass + derivative.cold_mass * delta,
+                temperature: self.cold.temperature + derivative.cold_temperature * delta,
+            },
+        }
+    }
+}
+
+/// Adapts a [`TwoTankStorage`] for use with `twine_solvers::transient::euler::solve`.
+///
+/// The state is the mass and temperature of both tanks. The fluid, ambient
+/// temperature, and operation are carried forward from the base input on
+/// each step.
+#[derive(Debug, Clone, Copy)]
+pub struct TwoTankOdeProblem<Fluid> {
+    _fluid: PhantomData<Fluid>,
+}
+
+impl<Fluid> TwoTankOdeProblem<Fluid> {
+    /// Creates the adapter for a storage holding `Fluid`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            _fluid: PhantomData,
+        }
+    }
+}
+
+impl<Fluid> Default for TwoTankOdeProblem<Fluid> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Fluid: Clone> OdeProblem for TwoTankOdeProblem<Fluid> {
+    type Input = TwoTankInput<Fluid>;
+    type Output = TwoTankOutput;
+    type Delta = Time;
+    type State = TwoTankState;
+    type Error = Infallible;
+
+    fn state(&self, input: &Self::Input) -> Result<TwoTankState, Infallible> {
+        Ok(TwoTankState {
+            hot: input.hot,
+            cold: input.cold,
+        })
+    }
+
+    fn derivative(
+        &self,
+        _input: &Self::Input,
+        output: &Self::Output,
+    ) -> Result<DerivativeOf<TwoTankState, Time>, Infallible> {
+        Ok(output.derivative)
+    }
+
+    fn build_input(
+        &self,
+        base: &Self::Input,
+        state: &Self::State,
+        _delta: &Time,
+    ) -> Result<Self::Input, Infallible> {
+        Ok(TwoTankInput {
+            hot: state.hot,
+            cold: state.cold,
+            ..base.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use twine_solvers::transient::euler;
+    use uom::si::{
+        f64::{Mass, MassRate, ThermalConductance, ThermodynamicTemperature},
+        mass::megagram,
+        mass_rate::kilogram_per_second,
+        thermal_conductance::{kilowatt_per_kelvin, watt_per_kelvin},
+        thermodynamic_temperature::degree_celsius,
+        time::minute,
+    };
+
+    use crate::support::{
+        hx::{CapacitanceRate, StreamInlet},
+        thermo::{fluid::SolarSalt, model::Incompressible},
+    };
+
+    #[test]
+    fn discharge_moves_salt_to_cold_tank_and_conserves_mass() {
+        let storage = TwoTankStorage::new(
+            TwoTankParameters {
+                heel: Mass::new::<megagram>(50.0),
+                hot_tank_ua: ThermalConductance::new::<watt_per_kelvin>(300.0),
+                cold_tank_ua: ThermalConductance::new::<watt_per_kelvin>(250.0),
+                hx_ua: ThermalConductance::new::<kilowatt_per_kelvin>(5000.0),
+            },
+            Incompressible::<SolarSalt>::new().unwrap(),
+        )
+        .unwrap();
+
+        let initial = TwoTankInput {
+            fluid: SolarSalt,
+            hot: TankInventory {
+                mass: Mass::new::<megagram>(900.0),
+                temperature: ThermodynamicTemperature::new::<degree_celsius>(385.0),
+            },
+            cold: TankInventory {
+                mass: Mass::new::<megagram>(200.0),
+                temperature: ThermodynamicTemperature::new::<degree_celsius>(290.0),
+            },
+            ambient_temperature: ThermodynamicTemperature::new::<degree_celsius>(20.0),
+            operation: TwoTankOperation::Discharge {
+                flow: MassRate::new::<kilogram_per_second>(500.0),
+                htf: StreamInlet::new(
+                    CapacitanceRate::new::<kilowatt_per_kelvin>(1900.0).unwrap(),
+                    ThermodynamicTemperature::new::<degree_celsius>(290.0),
+                ),
+            },
+        };
+
+        let solution = euler::solve_unobserved(
+            &storage,
+            &TwoTankOdeProblem::new(),
+            initial,
+            Time::new::<minute>(1.0),
+            60,
+        )
+        .unwrap();
+
+        let first = solution.history.first().unwrap();
+        let last = solution.history.last().unwrap();
+        assert!(last.output.state_of_charge < first.output.state_of_charge);
+        assert!(last.input.cold.temperature > first.input.cold.temperature);
+        assert_relative_eq!(
+            (last.input.hot.mass + last.input.cold.mass).get::<megagram>(),
+            1100.0,
+            max_relative = 1e-12
+        );
+    }
+}
@@ -0,0 +1,627 @@
+use std::{error::Error as StdError, marker::PhantomData};
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::{
+        f64::{
+            Energy, HeatCapacity, Mass, MassRate, Power, Ratio, SpecificHeatCapacity,
+            ThermalConductance, ThermodynamicTemperature,
+        },
+        ratio::ratio,
+    },
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive},
+    hx::{CapacitanceRate, CounterFlow, StreamInlet, functional},
+    thermo::capability::{HasCp, HasEnthalpy, StateFrom, ThermoModel},
+    units::{SpecificEnthalpy, TemperatureDifference, TemperatureRate},
+};
+
+/// Required thermo model bounds for a [`TwoTankStorage`].
+#[doc(hidden)]
+pub trait TwoTankThermoModel<Fluid>:
+    ThermoModel<Fluid = Fluid> + HasCp + HasEnthalpy + StateFrom<(Fluid, ThermodynamicTemperature)>
+{
+}
+
+impl<Fluid, T> TwoTankThermoModel<Fluid> for T where
+    T: ThermoModel<Fluid = Fluid>
+        + HasCp
+        + HasEnthalpy
+        + StateFrom<(Fluid, ThermodynamicTemperature)>
+{
+}
+
+/// Errors that can occur when creating or evaluating a [`TwoTankStorage`].
+#[derive(Debug, Error)]
+pub enum TwoTankError {
+    /// A storage parameter is out of range.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(&'static str),
+
+    /// An operating input is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// A tank inventory is not strictly positive.
+    #[error("tank mass must be strictly positive, got {0:?}")]
+    NonPositiveMass(Mass),
+
+    /// A thermodynamic model operation failed.
+    #[error("thermodynamic model failed: {context}")]
+    ThermoModelFailed {
+        /// Operation context for the thermodynamic model failure.
+        context: String,
+
+        /// Underlying thermodynamic model error.
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+}
+
+impl TwoTankError {
+    fn thermo(context: &str, source: impl StdError + Send + Sync + 'static) -> Self {
+        Self::ThermoModelFailed {
+            context: context.to_owned(),
+            source: Box::new(source),
+        }
+    }
+}
+
+/// Sizing of a [`TwoTankStorage`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwoTankParameters {
+    /// Inventory left in a tank when its pumps can draw no more.
+    pub heel: Mass,
+
+    /// Conductance to ambient of the hot tank.
+    pub hot_tank_ua: ThermalConductance,
+
+    /// Conductance to ambient of the cold tank.
+    pub cold_tank_ua: ThermalConductance,
+
+    /// Conductance of the counterflow exchanger between the heat transfer
+    /// fluid and the storage medium.
+    pub hx_ua: ThermalConductance,
+}
+
+/// Mass and temperature of the medium in one tank.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TankInventory {
+    /// Mass of storage medium in the tank.
+    pub mass: Mass,
+
+    /// Temperature of the well-mixed tank contents.
+    pub temperature: ThermodynamicTemperature,
+}
+
+/// How the storage is being operated.
+#[derive(Debug, Clone, Copy)]
+pub enum TwoTankOperation {
+    /// No medium moves between the tanks.
+    Idle,
+
+    /// Medium is pumped from the cold tank through the exchanger into the
+    /// hot tank, heated by the heat transfer fluid.
+    Charge {
+        /// Storage medium flow through the exchanger.
+        flow: MassRate,
+
+        /// Heat transfer fluid entering the exchanger.
+        htf: StreamInlet,
+    },
+
+    /// Medium is pumped from the hot tank through the exchanger into the
+    /// cold tank, heating the heat transfer fluid.
+    Discharge {
+        /// Storage medium flow through the exchanger.
+        flow: MassRate,
+
+        /// Heat transfer fluid entering the exchanger.
+        htf: StreamInlet,
+    },
+}
+
+/// Two-tank sensible storage, such as the molten-salt storage of a
+/// concentrating solar power plant.
+///
+/// The medium moves between a cold tank and a hot tank through one
+/// counterflow exchanger with the heat transfer fluid. Each tank is well
+/// mixed, so its mass `m` and temperature `T` are the integrable state.
+/// Medium leaves a tank at the tank temperature and arrives at the
+/// exchanger outlet enthalpy `h_in`, and each tank loses
+/// `UA·(T − T_amb)` to ambient:
+///
+/// ```text
+/// dm/dt = ṁ_in − ṁ_out
+/// m·cp·dT/dt = ṁ_in·(h_in − h) − UA·(T − T_amb)
+/// ```
+///
+/// A tank cannot be drawn below its heel, so no medium moves while the
+/// source tank is at or below it. The state of charge is the hot inventory
+/// above the heel over all inventory above the two heels.
+#[derive(Debug, Clone)]
+pub struct TwoTankStorage<Fluid, Thermo> {
+    parameters: TwoTankParameters,
+    thermo: Thermo,
+    _fluid: PhantomData<Fluid>,
+}
+
+/// Input to the two-tank storage model.
+#[derive(Debug, Clone, Copy)]
+pub struct TwoTankInput<Fluid> {
+    /// Storage medium.
+    pub fluid: Fluid,
+
+    /// Hot tank inventory.
+    pub hot: TankInventory,
+
+    /// Cold tank inventory.
+    pub cold: TankInventory,
+
+    /// Air temperature around the tanks.
+    pub ambient_temperature: ThermodynamicTemperature,
+
+    /// Charging, discharging, or idle.
+    pub operation: TwoTankOperation,
+}
+
+/// Time derivatives of the two-tank state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwoTankDerivative {
+    /// Rate of change of the hot tank mass.
+    pub hot_mass: MassRate,
+
+    /// Rate of change of the hot tank temperature.
+    pub hot_temperature: TemperatureRate,
+
+    /// Rate of change of the cold tank mass.
+    pub cold_mass: MassRate,
+
+    /// Rate of change of the cold tank temperature.
+    pub cold_temperature: TemperatureRate,
+}
+
+/// Output from the two-tank storage model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwoTankOutput {
+    /// Storage medium moving between the tanks.
+    ///
+    /// Zero when idle or when the source tank is at its heel.
+    pub flow: MassRate,
+
+    /// Heat passed from the heat transfer fluid to the medium.
+    ///
+    /// Positive while charging and negative while discharging.
+    pub heat_rate: Power,
+
+    /// Heat transfer fluid temperature leaving the exchanger.
+    ///
+    /// `None` unless medium is flowing.
+    pub htf_outlet_temperature: Option<ThermodynamicTemperature>,
+
+    /// Medium temperature leaving the exchanger into the destination tank.
+    ///
+    /// `None` unless medium is flowing.
+    pub medium_outlet_temperature: Option<ThermodynamicTemperature>,
+
+    /// Heat lost from the hot tank.
+    pub hot_loss: Power,
+
+    /// Heat lost from the cold tank.
+    pub cold_loss: Power,
+
+    /// Hot inventory above the heel over all inventory above the heels.
+    pub state_of_charge: Ratio,
+
+    /// Heat the hot inventory above its heel would release in cooling to
+    /// the cold tank temperature.
+    pub stored_energy: Energy,
+
+    /// Time derivatives of the tank masses and temperatures.
+    pub derivative: TwoTankDerivative,
+}
+
+/// Properties of one tank's contents.
+struct TankProperties {
+    enthalpy: SpecificEnthalpy,
+    specific_heat: SpecificHeatCapacity,
+    thermal_mass: HeatCapacity,
+    loss: Power,
+}
+
+impl<Fluid, Thermo> TwoTankStorage<Fluid, Thermo> {
+    /// Creates a two-tank storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TwoTankError::InvalidParameter`] if the heel or a
+    /// conductance is negative or not finite.
+    pub fn new(parameters: TwoTankParameters, thermo: Thermo) -> Result<Self, TwoTankError> {
+        if NonNegative::check(&parameters.heel).is_err() || !parameters.heel.is_finite() {
+            return Err(TwoTankError::InvalidParameter(
+                "heel must be non-negative and finite",
+            ));
+        }
+        if [
+            parameters.hot_tank_ua,
+            parameters.cold_tank_ua,
+            parameters.hx_ua,
+        ]
+        .iter()
+        .any(|ua| NonNegative::check(ua).is_err() || !ua.is_finite())
+        {
+            return Err(TwoTankError::InvalidParameter(
+                "conductances must be non-negative and finite",
+            ));
+        }
+
+        Ok(Self {
+            parameters,
+            thermo,
+            _fluid: PhantomData,
+        })
+    }
+
+    /// Returns the storage parameters.
+    #[must_use]
+    pub fn parameters(&self) -> &TwoTankParameters {
+        &self.parameters
+    }
+
+    /// Returns the thermo model.
+    #[must_use]
+    pub fn thermo(&self) -> &Thermo {
+        &self.thermo
+    }
+
+    /// Evaluates the tank balances at a single point in time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a tank mass is not strictly positive, the flow is
+    /// negative, the exchanger streams are invalid, or a property
+    /// evaluation fails.
+    pub fn evaluate(&self, input: &TwoTankInput<Fluid>) -> Result<TwoTankOutput, TwoTankError>
+    where
+        Fluid: Clone,
+        Thermo: TwoTankThermoModel<Fluid>,
+    {
+        for tank in [input.hot, input.cold] {
+            if StrictlyPositive::check(&tank.mass).is_err() {
+                return Err(TwoTankError::NonPositiveMass(tank.mass));
+            }
+        }
+
+        let TwoTankParameters {
+            heel,
+            hot_tank_ua,
+            cold_tank_ua,
+            hx_ua,
+        } = self.parameters;
+        let ambient = input.ambient_temperature;
+        let hot = self.properties(&input.fluid, input.hot, hot_tank_ua, ambient)?;
+        let cold = self.properties(&input.fluid, input.cold, cold_tank_ua, ambient)?;
+
+        // Which tank feeds the exchanger, and whether it can.
+        let (flow, htf, charging) = match input.operation {
+            TwoTankOperation::Idle => (MassRate::ZERO, None, true),
+            TwoTankOperation::Charge { flow, htf } => (flow, Some(htf), true),
+            TwoTankOperation::Discharge { flow, htf } => (flow, Some(htf), false),
+        };
+        if NonNegative::check(&flow).is_err() || !flow.is_finite() {
+            return Err(TwoTankError::InvalidInput(
+                "medium flow must be non-negative and finite",
+            ));
+        }
+        let (source, source_properties) = if charging {
+            (input.cold, &cold)
+        } else {
+            (input.hot, &hot)
+        };
+        let flow = if source.mass > heel {
+            flow
+        } else {
+            MassRate::ZERO
+        };
+
+        let mut heat_rate = Power::ZERO;
+        let mut htf_outlet_temperature = None;
+        let mut medium_outlet_temperature = None;
+        if let Some(htf) = htf.filter(|_| flow > MassRate::ZERO) {
+            let medium = StreamInlet::new(
+                CapacitanceRate::from_quantity(flow * source_properties.specific_heat).map_err(
+                    |_| TwoTankError::InvalidInput("medium capacitance rate must be positive"),
+                )?,
+                source.temperature,
+            );
+            let result =
+                functional::known_conductance_and_inlets(&CounterFlow, hx_ua, [htf, medium])
+                    .map_err(|_| {
+                        TwoTankError::InvalidInput(
+                            "exchanger streams must have valid capacitance rates",
+                        )
+                    })?;
+            heat_rate = result.streams[1].heat_flow.signed();
+            htf_outlet_temperature = Some(result.streams[0].outlet_temperature);
+            medium_outlet_temperature = Some(result.streams[1].outlet_temperature);
+        }
+
+        // Medium arrives in the destination tank at the exchanger outlet
+        // enthalpy and leaves the source tank at the tank enthalpy.
+        let filling = |tank: &TankProperties| -> TemperatureRate {
+            let inflow = flow * (source_properties.enthalpy - tank.enthalpy) + heat_rate;
+            (inflow - tank.loss) / tank.thermal_mass
+        };
+        let draining =
+            |tank: &TankProperties| -> TemperatureRate { -tank.loss / tank.thermal_mass };
+
+        let derivative = if charging {
+            TwoTankDerivative {
+                hot_mass: flow,
+                hot_temperature: filling(&hot),
+                cold_mass: -flow,
+                cold_temperature: draining(&cold),
+            }
+        } else {
+            TwoTankDerivative {
+                hot_mass: -flow,
+                hot_temperature: draining(&hot),
+                cold_mass: flow,
+                cold_temperature: filling(&cold),
+            }
+        };
+
+        let usable = (input.hot.mass - heel).max(Mass::ZERO);
+        let capacity = (input.hot.mass + input.cold.mass - heel * 2.0).max(Mass::ZERO);
+        let state_of_charge = if capacity > Mass::ZERO {
+            (usable / capacity).min(Ratio::new::<ratio>(1.0))
+        } else {
+            Ratio::ZERO
+        };
+
+        Ok(TwoTankOutput {
+            flow,
+            heat_rate,
+            htf_outlet_temperature,
+            medium_outlet_temperature,
+            hot_loss: hot.loss,
+            cold_loss: cold.loss,
+            state_of_charge,
+            stored_energy: usable * (hot.enthalpy - cold.enthalpy),
+            derivative,
+        })
+    }
+
+    /// Evaluates the enthalpy, heat capacity, and ambient loss of a tank.
+    fn properties(
+        &self,
+        fluid: &Fluid,
+        tank: TankInventory,
+        ua: ThermalConductance,
+        ambient: ThermodynamicTemperature,
+    ) -> Result<TankProperties, TwoTankError>
+    where
+        Fluid: Clone,
+        Thermo: TwoTankThermoModel<Fluid>,
+    {
+        let state = self
+            .thermo
+            .state_from((fluid.clone(), tank.temperature))
+            .map_err(|error| TwoTankError::thermo("state from temperature", error))?;
+        let enthalpy = self
+            .thermo
+            .enthalpy(&state)
+            .map_err(|error| TwoTankError::thermo("enthalpy", error))?;
+        let cp = self
+            .thermo
+            .cp(&state)
+            .map_err(|error| TwoTankError::thermo("specific heat", error))?;
+
+        Ok(TankProperties {
+            enthalpy,
+            specific_heat: cp,
+            thermal_mass: tank.mass * cp,
+            loss: ua * tank.temperature.minus(ambient),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        energy::gigajoule,
+        f64::TemperatureInterval,
+        mass::megagram,
+        mass_rate::kilogram_per_second,
+        power::kilowatt,
+        temperature_interval::kelvin as delta_kelvin,
+        thermal_conductance::{kilowatt_per_kelvin, watt_per_kelvin},
+        thermodynamic_temperature::degree_celsius,
+    };
+
+    use crate::support::{
+        thermo::{
+            fluid::SolarSalt,
+            model::{Incompressible, incompressible::IncompressibleFluid},
+        },
+        units::test_utils::celsius,
+    };
+
+    fn tonnes(m: f64) -> Mass {
+        Mass::new::<megagram>(m)
+    }
+
+    fn storage() -> TwoTankStorage<SolarSalt, Incompressible<SolarSalt>> {
+        TwoTankStorage::new(
+            TwoTankParameters {
+                heel: tonnes(50.0),
+                hot_tank_ua: ThermalConductance::new::<watt_per_kelvin>(300.0),
+                cold_tank_ua: ThermalConductance::new::<watt_per_kelvin>(250.0),
+                hx_ua: ThermalConductance::new::<kilowatt_per_kelvin>(5000.0),
+            },
+            Incompressible::new().unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn input(operation: TwoTankOperation) -> TwoTankInput<SolarSalt> {
+        TwoTankInput {
+            fluid: SolarSalt,
+            hot: TankInventory {
+                mass: tonnes(550.0),
+                temperature: celsius(565.0),
+            },
+            cold: TankInventory {
+                mass: tonnes(550.0),
+                temperature: celsius(290.0),
+            },
+            ambient_temperature: celsius(20.0),
+            operation,
+        }
+    }
+
+    fn oil(temperature: f64) -> StreamInlet {
+        StreamInlet::new(
+            CapacitanceRate::new::<kilowatt_per_kelvin>(1900.0).unwrap(),
+            celsius(temperature),
+        )
+    }
+
+    fn specific_heat() -> SpecificHeatCapacity {
+        SolarSalt::parameters().cp
+    }
+
+    #[test]
+    fn idle_tanks_cool_toward_ambient() {
+        let output = storage().evaluate(&input(TwoTankOperation::Idle)).unwrap();
+
+        assert_eq!(output.flow, MassRate::ZERO);
+        assert_eq!(output.heat_rate, Power::ZERO);
+        assert!(output.htf_outlet_temperature.is_none());
+        assert_eq!(output.derivative.hot_mass, MassRate::ZERO);
+        assert_eq!(output.derivative.cold_mass, MassRate::ZERO);
+
+        // 300 W/K across 545 K.
+        assert_relative_eq!(output.hot_loss.get::<kilowatt>(), 163.5, epsilon = 1e-9);
+        let expected = -output.hot_loss / (tonnes(550.0) * specific_heat());
+        assert_relative_eq!(output.derivative.hot_temperature.value, expected.value);
+        assert!(output.derivative.cold_temperature.value < 0.0);
+    }
+
+    #[test]
+    fn charging_fills_hot_tank_with_heated_salt() {
+        let flow = MassRate::new::<kilogram_per_second>(500.0);
+        let output = storage()
+            .evaluate(&input(TwoTankOperation::Charge {
+                flow,
+                htf: oil(393.0),
+            }))
+            .unwrap();
+
+        assert_eq!(output.flow, flow);
+        assert_eq!(output.derivative.hot_mass, flow);
+        assert_eq!(output.derivative.cold_mass, -flow);
+
+        // Both streams carry the same heat.
+        let salt_out = output.medium_outlet_temperature.unwrap();
+        let oil_out = output.htf_outlet_temperature.unwrap();
+        assert!(salt_out > celsius(290.0) && salt_out < celsius(393.0));
+        assert_relative_eq!(
+            output.heat_rate.get::<kilowatt>(),
+            (flow * specific_heat() * salt_out.minus(celsius(290.0))).get::<kilowatt>(),
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            output.heat_rate.get::<kilowatt>(),
+            1900.0 * (393.0 - oil_out.get::<degree_celsius>()),
+            max_relative = 1e-9
+        );
+
+        // Salt arriving cooler than the hot tank pulls its temperature down.
+        assert!(output.derivative.hot_temperature.value < 0.0);
+    }
+
+    #[test]
+    fn discharging_heats_the_htf() {
+        let flow = MassRate::new::<kilogram_per_second>(500.0);
+        let output = storage()
+            .evaluate(&input(TwoTankOperation::Discharge {
+                flow,
+                htf: oil(290.0),
+            }))
+            .unwrap();
+
+        assert!(output.heat_rate < Power::ZERO);
+        assert!(output.htf_outlet_temperature.unwrap() > celsius(290.0));
+        assert_eq!(output.derivative.hot_mass, -flow);
+        assert_eq!(output.derivative.cold_mass, flow);
+
+        // Salt returns warmer than the cold tank and heats it.
+        assert!(output.medium_outlet_temperature.unwrap() > celsius(290.0));
+        assert!(output.derivative.cold_temperature.value > 0.0);
+    }
+
+    #[test]
+    fn heel_stops_flow() {
+        let mut at_heel = input(TwoTankOperation::Charge {
+            flow: MassRate::new::<kilogram_per_second>(500.0),
+            htf: oil(393.0),
+        });
+        at_heel.cold.mass = tonnes(50.0);
+
+        let output = storage().evaluate(&at_heel).unwrap();
+        assert_eq!(output.flow, MassRate::ZERO);
+        assert_eq!(output.heat_rate, Power::ZERO);
+        assert!(output.medium_outlet_temperature.is_none());
+        assert_relative_eq!(output.state_of_charge.get::<ratio>(), 1.0);
+    }
+
+    #[test]
+    fn state_of_charge_counts_inventory_above_heels() {
+        let output = storage().evaluate(&input(TwoTankOperation::Idle)).unwrap();
+        assert_relative_eq!(output.state_of_charge.get::<ratio>(), 0.5);
+
+        let stored =
+            tonnes(500.0) * specific_heat() * TemperatureInterval::new::<delta_kelvin>(275.0);
+        assert_relative_eq!(
+            output.stored_energy.get::<gigajoule>(),
+            stored.get::<gigajoule>(),
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_parameters_and_inputs() {
+        assert!(matches!(
+            TwoTankStorage::<SolarSalt, _>::new(
+                TwoTankParameters {
+                    hx_ua: ThermalConductance::new::<watt_per_kelvin>(-1.0),
+                    ..*storage().parameters()
+                },
+                Incompressible::<SolarSalt>::new().unwrap(),
+            ),
+            Err(TwoTankError::InvalidParameter(_))
+        ));
+
+        let mut empty = input(TwoTankOperation::Idle);
+        empty.hot.mass = Mass::ZERO;
+        assert!(matches!(
+            storage().evaluate(&empty),
+            Err(TwoTankError::NonPositiveMass(_))
+        ));
+
+        assert!(matches!(
+            storage().evaluate(&input(TwoTankOperation::Discharge {
+                flow: MassRate::new::<kilogram_per_second>(-1.0),
+                htf: oil(290.0),
+            })),
+            Err(TwoTankError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,4 @@
+//! Rigid vessels that hold a fluid inventory.
+
+pub mod control_volume;
+pub mod drum;
@@ -0,0 +1,306 @@
+//! Generic control-volume accumulator model.
+//!
+//! A [`ControlVolume`] is a rigid, well-mixed vessel whose fluid mass and
+//! internal energy change with the streams that enter and leave it and the
+//! heat added to it. The instantaneous state is recovered from density and
+//! specific internal energy, so any thermo model implementing
+//! `StateFrom<(Fluid, MassDensity, SpecificInternalEnergy)>` can be used.
+//!
+//! This is the reusable core for receivers, headers, and small plenums.
+//!
+//! ## Quick start
+//!
+//! ```
+//! use twine_models::{
+//!     models::thermal::vessel::control_volume::{ControlVolume, ControlVolumeInput, Inflow},
+//!     support::{
+//!         thermo::{
+//!             capability::{HasInternalEnergy, StateFrom},
+//!             fluid::Air,
+//!             model::PerfectGas,
+//!         },
+//!         units::SpecificEnthalpy,
+//!     },
+//! };
+//! use uom::si::{
+//!     available_energy::kilojoule_per_kilogram,
+//!     f64::{MassRate, Power, Pressure, ThermodynamicTemperature, Volume},
+//!     mass_rate::kilogram_per_second,
+//!     power::watt,
+//!     pressure::kilopascal,
+//!     thermodynamic_temperature::degree_celsius,
+//!     volume::cubic_meter,
+//! };
+//!
+//! let thermo = PerfectGas::<Air>::new().unwrap();
+//! let plenum = ControlVolume::new(thermo, Volume::new::<cubic_meter>(0.2)).unwrap();
+//!
+//! // Start from air at 20 °C and 300 kPa.
+//! let initial = plenum
+//!     .thermo()
+//!     .state_from((
+//!         Air,
+//!         ThermodynamicTemperature::new::<degree_celsius>(20.0),
+//!         Pressure::new::<kilopascal>(300.0),
+//!     ))
+//!     .unwrap();
+//! let mass = initial.density * plenum.volume();
+//! let internal_energy = mass * plenum.thermo().internal_energy(&initial).unwrap();
+//!
+//! let output = plenum
+//!     .evaluate(&ControlVolumeInput {
+//!         mass,
+//!         internal_energy,
+//!         fluid: Air,
+//!         inflows: vec![Inflow {
+//!             mass_rate: MassRate::new::<kilogram_per_second>(0.05),
+//!             enthalpy: SpecificEnthalpy::new::<kilojoule_per_kilogram>(30.0),
+//!         }],
+//!         outflows: vec![MassRate::new::<kilogram_per_second>(0.02)],
+//!         heat_rate: Power::new::<watt>(0.0),
+//!     })
+//!     .unwrap();
+//!
+//! // More mass enters than leaves, so the plenum fills.
+//! assert!(output.derivative.mass.get::<kilogram_per_second>() > 0.0);
+//! ```
+
+mod core;
+
+use std::{convert::Infallible, marker::PhantomData};
+
+use twine_core::{DerivativeOf, Model, OdeProblem, StepIntegrable};
+use uom::si::f64::{Energy, Mass, Time};
+
+pub use core::{
+    ControlVolume, ControlVolumeDerivative, ControlVolumeError, ControlVolumeInput,
+    ControlVolumeOutput, ControlVolumeThermoModel, Inflow,
+};
+
+impl<Fluid, Thermo> Model for ControlVolume<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: ControlVolumeThermoModel<Fluid>,
+{
+    type Input = ControlVolumeInput<Fluid>;
+    type Output = ControlVolumeOutput<Fluid>;
+    type Error = ControlVolumeError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
+
+/// Fluid mass and internal energy, used as the ODE state for time integration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ControlVolumeState {
+    /// Fluid mass in the volume.
+    pub mass: Mass,
+
+    /// Total internal energy of the fluid in the volume.
+    pub internal_energy: Energy,
+}
+
+impl StepIntegrable<Time> for ControlVolumeState {
+    type Derivative = ControlVolumeDerivative;
+
+    fn step(&self, derivative: ControlVolumeDerivative, delta: Time) -> Self {
+        ControlVolumeState {
+            mass: self.mass + derivative.mass * delta,
+            internal_energy: self.internal_energy + derivative.internal_energy * delta,
+        }
+    }
+}
+
+/// Adapts a [`ControlVolume`] for use with `twine_solvers::transient::euler::solve`.
+///
+/// The state is the fluid mass and internal energy. The fluid, flows, and
+/// heat input are carried forward from the base input on each step.
+///
+/// # Example
+///
+/// ```
+/// use twine_models::{
+///     models::thermal::vessel::control_volume::{
+///         ControlVolume, ControlVolumeInput, ControlVolumeOdeProblem,
+///     },
+///     support::thermo::{fluid::Air, model::PerfectGas},
+/// };
+/// use twine_solvers::transient::euler;
+/// use uom::si::{
+///     energy::kilojoule,
+///     f64::{Energy, Mass, Power, Time, Volume},
+///     mass::kilogram,
+///     power::watt,
+///     time::second,
+///     volume::cubic_meter,
+/// };
+///
+/// let plenum = ControlVolume::new(
+///     PerfectGas::<Air>::new().unwrap(),
+///     Volume::new::<cubic_meter>(0.2),
+/// ).unwrap();
+///
+/// // A closed plenum heated at 500 W.
+/// let initial = ControlVolumeInput {
+///     mass: Mass::new::<kilogram>(0.7),
+///     internal_energy: Energy::new::<kilojoule>(150.0),
+///     fluid: Air,
+///     inflows: Vec::new(),
+///     outflows: Vec::new(),
+///     heat_rate: Power::new::<watt>(500.0),
+/// };
+///
+/// let solution = euler::solve_unobserved(
+///     &plenum,
+///     &ControlVolumeOdeProblem::new(),
+///     initial,
+///     Time::new::<second>(1.0),
+///     10,
+/// ).unwrap();
+///
+/// let last = solution.history.last().unwrap();
+/// assert!((last.input.internal_energy.get::<kilojoule>() - 155.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ControlVolumeOdeProblem<Fluid> {
+    _fluid: PhantomData<Fluid>,
+}
+
+impl<Fluid> ControlVolumeOdeProblem<Fluid> {
+    /// Creates the adapter for a control volume holding `Fluid`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            _fluid: PhantomData,
+        }
+    }
+}
+
+impl<Fluid> Default for ControlVolumeOdeProblem<Fluid> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Fluid: Clone> OdeProblem for ControlVolumeOdeProblem<Fluid> {
+    type Input = ControlVolumeInput<Fluid>;
+    type Output = ControlVolumeOutput<Fluid>;
+    type Delta = Time;
+    type State = ControlVolumeState;
+    type Error = Infallible;
+
+    fn state(&self, input: &Self::Input) -> Result<ControlVolumeState, Infallible> {
+        Ok(ControlVolumeState {
+            mass: input.mass,
+            internal_energy: input.internal_energy,
+        })
+    }
+
+    fn derivative(
+        &self,
+        _input: &Self::Input,
+        output: &Self::Output,
+    ) -> Result<DerivativeOf<ControlVolumeState, Time>, Infallible> {
+        Ok(output.derivative)
+    }
+
+    fn build_input(
+        &self,
+        base: &Self::Input,
+        state: &Self::State,
+        _delta: &Time,
+    ) -> Result<Self::Input, Infallible> {
+        Ok(ControlVolumeInput {
+            mass: state.mass,
+            internal_energy: state.internal_energy,
+            ..base.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use twine_solvers::transient::euler;
+    use uom::si::{
+        available_energy::kilojoule_per_kilogram,
+        energy::kilojoule,
+        f64::{MassRate, Power, Pressure, ThermodynamicTemperature, Volume},
+        mass::kilogram,
+        mass_rate::kilogram_per_second,
+        pressure::kilopascal,
+        thermodynamic_temperature::degree_celsius,
+        time::second,
+        volume::cubic_meter,
+    };
+
+    use crate::support::{
+        thermo::{
+            capability::{HasInternalEnergy, StateFrom},
+            fluid::Air,
+            model::PerfectGas,
+        },
+        units::SpecificEnthalpy,
+    };
+
+    #[test]
+    fn filling_integrates_mass_and_energy() {
+        let plenum = ControlVolume::new(
+            PerfectGas::<Air>::new().unwrap(),
+            Volume::new::<cubic_meter>(1.0),
+        )
+        .unwrap();
+        let start = plenum
+            .thermo()
+            .state_from((
+                Air,
+                ThermodynamicTemperature::new::<degree_celsius>(20.0),
+                Pressure::new::<kilopascal>(100.0),
+            ))
+            .unwrap();
+        let mass = start.density * plenum.volume();
+        let internal_energy = mass * plenum.thermo().internal_energy(&start).unwrap();
+
+        let h_in = SpecificEnthalpy::new::<kilojoule_per_kilogram>(20.0);
+        let initial = ControlVolumeInput {
+            mass,
+            internal_energy,
+            fluid: Air,
+            inflows: vec![Inflow {
+                mass_rate: MassRate::new::<kilogram_per_second>(0.01),
+                enthalpy: h_in,
+            }],
+            outflows: Vec::new(),
+            heat_rate: Power::default(),
+        };
+
+        let solution = euler::solve_unobserved(
+            &plenum,
+            &ControlVolumeOdeProblem::new(),
+            initial,
+            Time::new::<second>(1.0),
+            100,
+        )
+        .unwrap();
+
+        // Constant inflow with no outflow: both balances are linear in time.
+        let last = solution.history.last().unwrap();
+        assert_relative_eq!(
+            last.input.mass.get::<kilogram>(),
+            mass.get::<kilogram>() + 1.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            last.input.internal_energy.get::<kilojoule>(),
+            internal_energy.get::<kilojoule>() + 20.0,
+            epsilon = 1e-9
+        );
+
+        // Adiabatic filling raises the pressure and the gas temperature.
+        assert!(last.output.pressure > Pressure::new::<kilopascal>(100.0));
+        assert!(last.output.state.temperature > start.temperature);
+    }
+}
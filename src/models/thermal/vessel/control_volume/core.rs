@@ -0,0 +1,371 @@
+use std::{error::Error as StdError, marker::PhantomData};
+
+use thiserror::Error;
+use uom::si::f64::{Energy, Mass, MassDensity, MassRate, Power, Pressure, Volume};
+
+use crate::support::{
+    constraint::{Constraint, StrictlyPositive},
+    thermo::{
+        State,
+        capability::{HasEnthalpy, HasPressure, StateFrom, ThermoModel},
+    },
+    units::{SpecificEnthalpy, SpecificInternalEnergy},
+};
+
+/// Required thermo model bounds for a [`ControlVolume`].
+#[doc(hidden)]
+pub trait ControlVolumeThermoModel<Fluid>:
+    ThermoModel<Fluid = Fluid>
+    + HasPressure
+    + HasEnthalpy
+    + StateFrom<(Fluid, MassDensity, SpecificInternalEnergy)>
+{
+}
+
+impl<Fluid, T> ControlVolumeThermoModel<Fluid> for T where
+    T: ThermoModel<Fluid = Fluid>
+        + HasPressure
+        + HasEnthalpy
+        + StateFrom<(Fluid, MassDensity, SpecificInternalEnergy)>
+{
+}
+
+/// Errors that can occur when creating or evaluating a [`ControlVolume`].
+#[derive(Debug, Error)]
+pub enum ControlVolumeError {
+    /// The vessel volume is not strictly positive.
+    #[error("volume must be strictly positive, got {0:?}")]
+    NonPositiveVolume(Volume),
+
+    /// The fluid mass in the vessel is not strictly positive.
+    #[error("mass must be strictly positive, got {0:?}")]
+    NonPositiveMass(Mass),
+
+    /// A thermodynamic model operation failed.
+    #[error("thermodynamic model failed: {context}")]
+    ThermoModelFailed {
+        /// Operation context for the thermodynamic model failure.
+        context: String,
+
+        /// Underlying thermodynamic model error.
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+}
+
+impl ControlVolumeError {
+    fn thermo(context: &str, source: impl StdError + Send + Sync + 'static) -> Self {
+        Self::ThermoModelFailed {
+            context: context.to_owned(),
+            source: Box::new(source),
+        }
+    }
+}
+
+/// A fluid stream entering a [`ControlVolume`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Inflow {
+    /// Mass flow rate into the volume.
+    pub mass_rate: MassRate,
+
+    /// Specific enthalpy of the entering fluid.
+    pub enthalpy: SpecificEnthalpy,
+}
+
+/// A rigid, well-mixed control volume holding a fluid inventory.
+///
+/// The fluid mass `m` and total internal energy `U` are the integrable
+/// state. Fluid enters at the enthalpy of each [`Inflow`] and leaves at the
+/// enthalpy of the contents, so the balances are:
+///
+/// ```text
+/// dm/dt = Σ ṁ_in − Σ ṁ_out
+/// dU/dt = Σ ṁ_in·h_in − (Σ ṁ_out)·h + Q̇
+/// ```
+///
+/// The instantaneous [`State`] is recovered from `ρ = m / V` and
+/// `u = U / m` through the thermo model.
+#[derive(Debug, Clone)]
+pub struct ControlVolume<Fluid, Thermo> {
+    thermo: Thermo,
+    volume: Volume,
+    _fluid: PhantomData<Fluid>,
+}
+
+/// Input to the control volume model.
+#[derive(Debug, Clone)]
+pub struct ControlVolumeInput<Fluid> {
+    /// Fluid mass in the volume.
+    pub mass: Mass,
+
+    /// Total internal energy of the fluid in the volume.
+    pub internal_energy: Energy,
+
+    /// Fluid-specific data used to build the state.
+    pub fluid: Fluid,
+
+    /// Streams entering the volume.
+    pub inflows: Vec<Inflow>,
+
+    /// Mass flow rates leaving the volume at the enthalpy of its contents.
+    pub outflows: Vec<MassRate>,
+
+    /// Net heat added to the fluid.
+    ///
+    /// Negative when the volume loses heat.
+    pub heat_rate: Power,
+}
+
+/// Time derivatives of the control volume state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ControlVolumeDerivative {
+    /// Rate of change of the fluid mass.
+    pub mass: MassRate,
+
+    /// Rate of change of the total internal energy.
+    pub internal_energy: Power,
+}
+
+/// Output from the control volume model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ControlVolumeOutput<Fluid> {
+    /// Instantaneous thermodynamic state of the contents.
+    pub state: State<Fluid>,
+
+    /// Pressure of the contents.
+    pub pressure: Pressure,
+
+    /// Specific enthalpy of the contents, at which all outflows leave.
+    pub enthalpy: SpecificEnthalpy,
+
+    /// Time derivatives of mass and internal energy.
+    pub derivative: ControlVolumeDerivative,
+}
+
+impl<Fluid, Thermo> ControlVolume<Fluid, Thermo> {
+    /// Creates a control volume of fixed `volume`.
+    ///
+    /// `thermo` provides thermodynamic property evaluation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ControlVolumeError::NonPositiveVolume`] if `volume` is not
+    /// strictly positive.
+    pub fn new(thermo: Thermo, volume: Volume) -> Result<Self, ControlVolumeError> {
+        if StrictlyPositive::check(&volume).is_err() {
+            return Err(ControlVolumeError::NonPositiveVolume(volume));
+        }
+
+        Ok(Self {
+            thermo,
+            volume,
+            _fluid: PhantomData,
+        })
+    }
+
+    /// Volume of the vessel.
+    #[must_use]
+    pub fn volume(&self) -> Volume {
+        self.volume
+    }
+
+    /// Returns the thermo model.
+    #[must_use]
+    pub fn thermo(&self) -> &Thermo {
+        &self.thermo
+    }
+
+    /// Recovers the state of the contents from its mass and internal energy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mass` is not strictly positive or if the thermo
+    /// model cannot build a state from `(ρ, u)`.
+    pub fn state(
+        &self,
+        fluid: Fluid,
+        mass: Mass,
+        internal_energy: Energy,
+    ) -> Result<State<Fluid>, ControlVolumeError>
+    where
+        Thermo: ControlVolumeThermoModel<Fluid>,
+    {
+        if StrictlyPositive::check(&mass).is_err() {
+            return Err(ControlVolumeError::NonPositiveMass(mass));
+        }
+
+        let density: MassDensity = mass / self.volume;
+        let specific_energy: SpecificInternalEnergy = internal_energy / mass;
+
+        self.thermo
+            .state_from((fluid, density, specific_energy))
+            .map_err(|error| ControlVolumeError::thermo("state from (ρ, u)", error))
+    }
+
+    /// Evaluates the mass and energy balances at a single point in time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the state cannot be recovered or if a property
+    /// evaluation fails.
+    pub fn evaluate(
+        &self,
+        input: &ControlVolumeInput<Fluid>,
+    ) -> Result<ControlVolumeOutput<Fluid>, ControlVolumeError>
+    where
+        Fluid: Clone,
+        Thermo: ControlVolumeThermoModel<Fluid>,
+    {
+        let state = self.state(input.fluid.clone(), input.mass, input.internal_energy)?;
+
+        let pressure = self
+            .thermo
+            .pressure(&state)
+            .map_err(|error| ControlVolumeError::thermo("pressure", error))?;
+        let enthalpy = self
+            .thermo
+            .enthalpy(&state)
+            .map_err(|error| ControlVolumeError::thermo("enthalpy", error))?;
+
+        let (mass_in, energy_in) = input.inflows.iter().fold(
+            (MassRate::default(), Power::default()),
+            |(mass, energy), inflow| {
+                (
+                    mass + inflow.mass_rate,
+                    energy + inflow.mass_rate * inflow.enthalpy,
+                )
+            },
+        );
+        let mass_out: MassRate = input.outflows.iter().copied().sum();
+
+        Ok(ControlVolumeOutput {
+            state,
+            pressure,
+            enthalpy,
+            derivative: ControlVolumeDerivative {
+                mass: mass_in - mass_out,
+                internal_energy: energy_in - mass_out * enthalpy + input.heat_rate,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::{
+        ConstZero,
+        si::{
+            available_energy::kilojoule_per_kilogram, energy::joule, f64::ThermodynamicTemperature,
+            mass::kilogram, mass_rate::kilogram_per_second, power::watt, pressure::kilopascal,
+            thermodynamic_temperature::degree_celsius, volume::cubic_meter,
+        },
+    };
+
+    use crate::support::thermo::{capability::HasInternalEnergy, fluid::Air, model::PerfectGas};
+
+    fn air_volume() -> ControlVolume<Air, PerfectGas<Air>> {
+        ControlVolume::new(PerfectGas::new().unwrap(), Volume::new::<cubic_meter>(0.5)).unwrap()
+    }
+
+    /// Mass and internal energy of air in the test volume at `t` and `p`.
+    fn inventory(
+        cv: &ControlVolume<Air, PerfectGas<Air>>,
+        t: ThermodynamicTemperature,
+        p: Pressure,
+    ) -> (Mass, Energy) {
+        let state = cv.thermo().state_from((Air, t, p)).unwrap();
+        let mass = state.density * cv.volume();
+        let u = cv.thermo().internal_energy(&state).unwrap();
+        (mass, mass * u)
+    }
+
+    fn input(mass: Mass, internal_energy: Energy) -> ControlVolumeInput<Air> {
+        ControlVolumeInput {
+            mass,
+            internal_energy,
+            fluid: Air,
+            inflows: Vec::new(),
+            outflows: Vec::new(),
+            heat_rate: Power::ZERO,
+        }
+    }
+
+    #[test]
+    fn recovers_state_from_inventory() {
+        let cv = air_volume();
+        let t = ThermodynamicTemperature::new::<degree_celsius>(40.0);
+        let p = Pressure::new::<kilopascal>(500.0);
+        let (mass, energy) = inventory(&cv, t, p);
+
+        let output = cv.evaluate(&input(mass, energy)).unwrap();
+
+        assert_relative_eq!(
+            output.state.temperature.get::<degree_celsius>(),
+            40.0,
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(output.pressure.get::<kilopascal>(), 500.0, epsilon = 1e-8);
+        assert_eq!(output.derivative.mass, MassRate::ZERO);
+        assert_eq!(output.derivative.internal_energy, Power::ZERO);
+    }
+
+    #[test]
+    fn balances_flows_and_heat() {
+        let cv = air_volume();
+        let (mass, energy) = inventory(
+            &cv,
+            ThermodynamicTemperature::new::<degree_celsius>(20.0),
+            Pressure::new::<kilopascal>(200.0),
+        );
+        let h_in = SpecificEnthalpy::new::<kilojoule_per_kilogram>(50.0);
+
+        let output = cv
+            .evaluate(&ControlVolumeInput {
+                inflows: vec![Inflow {
+                    mass_rate: MassRate::new::<kilogram_per_second>(0.3),
+                    enthalpy: h_in,
+                }],
+                outflows: vec![
+                    MassRate::new::<kilogram_per_second>(0.1),
+                    MassRate::new::<kilogram_per_second>(0.05),
+                ],
+                heat_rate: Power::new::<watt>(-200.0),
+                ..input(mass, energy)
+            })
+            .unwrap();
+
+        let h = output.enthalpy.get::<kilojoule_per_kilogram>();
+        assert_relative_eq!(
+            output.derivative.mass.get::<kilogram_per_second>(),
+            0.15,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            output.derivative.internal_energy.get::<watt>(),
+            0.3 * 50.0e3 - 0.15 * h * 1e3 - 200.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_inputs() {
+        let air = PerfectGas::<Air>::new().unwrap();
+        assert!(matches!(
+            ControlVolume::<Air, _>::new(air, Volume::ZERO),
+            Err(ControlVolumeError::NonPositiveVolume(_))
+        ));
+
+        let cv = air_volume();
+        assert!(matches!(
+            cv.evaluate(&input(Mass::ZERO, Energy::new::<joule>(1.0))),
+            Err(ControlVolumeError::NonPositiveMass(_))
+        ));
+        assert!(matches!(
+            cv.state(Air, Mass::new::<kilogram>(-1.0), Energy::ZERO),
+            Err(ControlVolumeError::NonPositiveMass(_))
+        ));
+    }
+}
@@ -0,0 +1,175 @@
+//! Saturated steam drum for drum-type boilers and evaporators.
+//!
+//! A [`Drum`] is a horizontal vessel holding boiling water under its own
+//! steam. Feedwater enters, heat boils the water, saturated steam leaves at
+//! drum pressure, and continuous blowdown bleeds off saturated liquid to
+//! control dissolved solids. Drum pressure, vapor quality, and liquid level
+//! all follow from the water mass and internal energy, which integrate in
+//! time just as in a
+//! [`ControlVolume`](super::control_volume::ControlVolume).
+//!
+//! Heat can be a fixed rate or hot gas crossing an evaporator tube bank, as
+//! in the evaporator section of a heat recovery steam generator or the
+//! boiler bank of a biomass boiler.
+//!
+//! The thermo model must construct states from density and internal energy
+//! and saturated states from pressure and quality, and must report the
+//! phase split, such as `CoolProp` with
+//! [`Water`](crate::support::thermo::fluid::Water).
+
+mod core;
+
+use std::{convert::Infallible, marker::PhantomData};
+
+use twine_core::{DerivativeOf, Model, OdeProblem};
+use uom::si::f64::Time;
+
+pub use core::{Drum, DrumError, DrumGeometry, DrumHeat, DrumInput, DrumOutput, DrumThermoModel};
+
+use super::control_volume::ControlVolumeState;
+
+impl<Fluid, Thermo> Model for Drum<Fluid, Thermo>
+where
+    Fluid: Clone,
+    Thermo: DrumThermoModel<Fluid>,
+{
+    type Input = DrumInput<Fluid>;
+    type Output = DrumOutput<Fluid>;
+    type Error = DrumError;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.evaluate(input)
+    }
+}
+
+/// Adapts a [`Drum`] for use with `twine_solvers::transient::euler::solve`.
+///
+/// The state is the water mass and internal energy, reusing
+/// [`ControlVolumeState`]. The fluid, flows, blowdown, and heat input are
+/// carried forward from the base input on each step.
+#[derive(Debug, Clone, Copy)]
+pub struct DrumOdeProblem<Fluid> {
+    _fluid: PhantomData<Fluid>,
+}
+
+impl<Fluid> DrumOdeProblem<Fluid> {
+    /// Creates the adapter for a drum holding `Fluid`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            _fluid: PhantomData,
+        }
+    }
+}
+
+impl<Fluid> Default for DrumOdeProblem<Fluid> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Fluid: Clone> OdeProblem for DrumOdeProblem<Fluid> {
+    type Input = DrumInput<Fluid>;
+    type Output = DrumOutput<Fluid>;
+    type Delta = Time;
+    type State = ControlVolumeState;
+    type Error = Infallible;
+
+    fn state(&self, input: &Self::Input) -> Result<ControlVolumeState, Infallible> {
+        Ok(ControlVolumeState {
+            mass: input.mass,
+            internal_energy: input.internal_energy,
+        })
+    }
+
+    fn derivative(
+        &self,
+        _input: &Self::Input,
+        output: &Self::Output,
+    ) -> Result<DerivativeOf<ControlVolumeState, Time>, Infallible> {
+        Ok(output.derivative)
+    }
+
+    fn build_input(
+        &self,
+        base: &Self::Input,
+        state: &Self::State,
+        _delta: &Time,
+    ) -> Result<Self::Input, Infallible> {
+        Ok(DrumInput {
+            mass: state.mass,
+            internal_energy: state.internal_energy,
+            ..base.clone()
+        })
+    }
+}
+
+#[cfg(all(test, any(feature = "coolprop-static", feature = "coolprop-dylib")))]
+mod tests {
+    use super::*;
+
+    use twine_solvers::transient::euler;
+    use uom::si::{
+        available_energy::kilojoule_per_kilogram,
+        f64::{Length, MassRate, Power, Pressure, Ratio},
+        length::meter,
+        mass_rate::kilogram_per_second,
+        power::megawatt,
+        pressure::bar,
+        ratio::percent,
+        time::second,
+    };
+
+    use crate::{
+        models::thermal::vessel::control_volume::Inflow,
+        support::{
+            thermo::{fluid::Water, model::CoolProp},
+            units::SpecificEnthalpy,
+        },
+    };
+
+    #[test]
+    fn steam_draw_without_feedwater_drops_level_and_pressure() {
+        let drum = Drum::new(
+            CoolProp::<Water>::new().unwrap(),
+            DrumGeometry {
+                diameter: Length::new::<meter>(1.5),
+                length: Length::new::<meter>(6.0),
+            },
+        )
+        .unwrap();
+        let pressure = Pressure::new::<bar>(40.0);
+        let (mass, internal_energy) = drum
+            .inventory(Water, pressure, Length::new::<meter>(0.75))
+            .unwrap();
+
+        // Feedwater is lost while the burner holds its firing rate.
+        let initial = DrumInput {
+            mass,
+            internal_energy,
+            fluid: Water,
+            feedwater: Inflow {
+                mass_rate: MassRate::default(),
+                enthalpy: SpecificEnthalpy::new::<kilojoule_per_kilogram>(800.0),
+            },
+            steam: MassRate::new::<kilogram_per_second>(5.0),
+            blowdown: Ratio::new::<percent>(2.0),
+            heat: DrumHeat::Rate(Power::new::<megawatt>(8.0)),
+        };
+
+        let solution = euler::solve_unobserved(
+            &drum,
+            &DrumOdeProblem::new(),
+            initial,
+            Time::new::<second>(1.0),
+            60,
+        )
+        .unwrap();
+
+        let first = &solution.history.first().unwrap().output;
+        let last = &solution.history.last().unwrap().output;
+        assert!(last.level < first.level);
+        assert!(last.pressure < first.pressure);
+        assert!(last.quality > first.quality);
+    }
+}
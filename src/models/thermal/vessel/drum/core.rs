@@ -0,0 +1,717 @@
+use std::{convert::Infallible, error::Error as StdError, f64::consts::PI, marker::PhantomData};
+
+use thiserror::Error;
+use uom::si::{
+    f64::{
+        Energy, Length, Mass, MassDensity, MassRate, Power, Pressure, Ratio, SpecificHeatCapacity,
+        ThermalConductance, ThermodynamicTemperature, Volume,
+    },
+    length::meter,
+    ratio::ratio,
+};
+
+use crate::{
+    models::thermal::vessel::control_volume::{ControlVolumeDerivative, Inflow},
+    support::{
+        constraint::{Constrained, Constraint, NonNegative, StrictlyPositive, UnitInterval},
+        numerics::solve::{self, brent},
+        thermo::{
+            State,
+            capability::{HasEnthalpy, HasPressure, HasTwoPhase, StateFrom, ThermoModel},
+        },
+        units::{SpecificEnthalpy, SpecificInternalEnergy, TemperatureDifference},
+    },
+};
+
+/// Required thermo model bounds for a [`Drum`].
+#[doc(hidden)]
+pub trait DrumThermoModel<Fluid>:
+    ThermoModel<Fluid = Fluid>
+    + HasPressure
+    + HasEnthalpy
+    + HasTwoPhase
+    + StateFrom<(Fluid, MassDensity, SpecificInternalEnergy)>
+    + StateFrom<(Fluid, Pressure, Constrained<Ratio, UnitInterval>)>
+{
+}
+
+impl<Fluid, T> DrumThermoModel<Fluid> for T where
+    T: ThermoModel<Fluid = Fluid>
+        + HasPressure
+        + HasEnthalpy
+        + HasTwoPhase
+        + StateFrom<(Fluid, MassDensity, SpecificInternalEnergy)>
+        + StateFrom<(Fluid, Pressure, Constrained<Ratio, UnitInterval>)>
+{
+}
+
+/// Errors that can occur when creating or evaluating a [`Drum`].
+#[derive(Debug, Error)]
+pub enum DrumError {
+    /// A drum dimension is not strictly positive and finite.
+    #[error("invalid geometry: {0}")]
+    InvalidGeometry(&'static str),
+
+    /// The water mass in the drum is not strictly positive.
+    #[error("mass must be strictly positive, got {0:?}")]
+    NonPositiveMass(Mass),
+
+    /// A flow, blowdown fraction, or gas-side value is out of range.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// The drum contents are single-phase.
+    ///
+    /// The drum has either flooded with subcooled liquid or boiled dry.
+    #[error("drum contents are not saturated at {pressure:?}")]
+    NotSaturated {
+        /// Pressure of the single-phase contents.
+        pressure: Pressure,
+    },
+
+    /// The liquid level could not be located in the drum.
+    #[error("liquid level did not converge after {iterations} iterations")]
+    LevelNotConverged {
+        /// Iterations performed before giving up.
+        iterations: usize,
+    },
+
+    /// A thermodynamic model operation failed.
+    #[error("thermodynamic model failed: {context}")]
+    ThermoModelFailed {
+        /// Operation context for the thermodynamic model failure.
+        context: String,
+
+        /// Underlying thermodynamic model error.
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+}
+
+impl DrumError {
+    fn thermo(context: &str, source: impl StdError + Send + Sync + 'static) -> Self {
+        Self::ThermoModelFailed {
+            context: context.to_owned(),
+            source: Box::new(source),
+        }
+    }
+}
+
+/// Shell of a horizontal cylindrical steam drum.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrumGeometry {
+    /// Inside diameter of the shell.
+    pub diameter: Length,
+
+    /// Inside length of the shell, ignoring the heads.
+    pub length: Length,
+}
+
+/// Heat delivered to the water in a [`Drum`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DrumHeat {
+    /// A known heat rate, such as furnace radiation to the waterwalls.
+    ///
+    /// Negative when the drum loses heat.
+    Rate(Power),
+
+    /// Hot gas crossing an evaporator tube bank that boils drum water.
+    ///
+    /// The water side stays at the saturation temperature, so the bank
+    /// behaves as an exchanger with a capacity ratio of zero:
+    /// `ε = 1 − exp(−UA / C_gas)`.
+    Gas {
+        /// Gas mass flow rate.
+        mass_rate: MassRate,
+
+        /// Gas specific heat.
+        specific_heat: SpecificHeatCapacity,
+
+        /// Gas temperature entering the tube bank.
+        inlet_temperature: ThermodynamicTemperature,
+
+        /// Conductance of the tube bank.
+        ua: ThermalConductance,
+    },
+}
+
+/// A horizontal drum holding saturated water and steam.
+///
+/// The water mass `m` and total internal energy `U` are the integrable
+/// state, as in a [`ControlVolume`](super::super::control_volume::ControlVolume).
+/// The contents must stay inside the vapor dome. Steam leaves as saturated
+/// vapor at drum pressure and continuous blowdown leaves as saturated
+/// liquid, so the balances are:
+///
+/// ```text
+/// dm/dt = ṁ_fw − ṁ_steam − ṁ_bd
+/// dU/dt = ṁ_fw·h_fw − ṁ_steam·h_g − ṁ_bd·h_f + Q̇
+/// ```
+///
+/// The blowdown rate is a fraction of the steam flow. The liquid level is
+/// the depth of the liquid share of the drum volume, `m(1 − x)/ρ_f`,
+/// lying in the bottom of the shell.
+#[derive(Debug, Clone)]
+pub struct Drum<Fluid, Thermo> {
+    thermo: Thermo,
+    geometry: DrumGeometry,
+    _fluid: PhantomData<Fluid>,
+}
+
+/// Input to the drum model.
+#[derive(Debug, Clone)]
+pub struct DrumInput<Fluid> {
+    /// Water mass in the drum.
+    pub mass: Mass,
+
+    /// Total internal energy of the water in the drum.
+    pub internal_energy: Energy,
+
+    /// Fluid-specific data used to build the state.
+    pub fluid: Fluid,
+
+    /// Feedwater entering the drum.
+    pub feedwater: Inflow,
+
+    /// Saturated steam drawn from the drum.
+    pub steam: MassRate,
+
+    /// Continuous blowdown as a fraction of the steam flow.
+    pub blowdown: Ratio,
+
+    /// Heat delivered to the water.
+    pub heat: DrumHeat,
+}
+
+/// Output from the drum model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrumOutput<Fluid> {
+    /// Instantaneous thermodynamic state of the mixed contents.
+    pub state: State<Fluid>,
+
+    /// Drum pressure.
+    pub pressure: Pressure,
+
+    /// Vapor mass fraction of the contents.
+    pub quality: Ratio,
+
+    /// Volume occupied by saturated liquid.
+    pub liquid_volume: Volume,
+
+    /// Liquid depth measured from the bottom of the shell.
+    pub level: Length,
+
+    /// Specific enthalpy of the saturated steam leaving the drum.
+    pub steam_enthalpy: SpecificEnthalpy,
+
+    /// Blowdown mass flow rate.
+    pub blowdown: MassRate,
+
+    /// Specific enthalpy of the saturated liquid blown down.
+    pub blowdown_enthalpy: SpecificEnthalpy,
+
+    /// Heat delivered to the water.
+    pub heat_rate: Power,
+
+    /// Gas temperature leaving the evaporator tube bank.
+    ///
+    /// `None` unless the heat comes from [`DrumHeat::Gas`].
+    pub gas_outlet_temperature: Option<ThermodynamicTemperature>,
+
+    /// Steam flow the heat would raise if the drum were held at this
+    /// pressure and level.
+    ///
+    /// Feedwater then replaces the steam and blowdown, so
+    /// `Q̇ = ṁ_steam·(h_g − h_fw) + ṁ_bd·(h_f − h_fw)`.
+    pub steady_steam: MassRate,
+
+    /// Time derivatives of mass and internal energy.
+    pub derivative: ControlVolumeDerivative,
+}
+
+impl<Fluid, Thermo> Drum<Fluid, Thermo> {
+    /// Creates a drum with the given shell.
+    ///
+    /// `thermo` provides thermodynamic property evaluation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrumError::InvalidGeometry`] if the diameter or length is
+    /// not strictly positive and finite.
+    pub fn new(thermo: Thermo, geometry: DrumGeometry) -> Result<Self, DrumError> {
+        if StrictlyPositive::check(&geometry.diameter).is_err() || !geometry.diameter.is_finite() {
+            return Err(DrumError::InvalidGeometry(
+                "diameter must be strictly positive and finite",
+            ));
+        }
+        if StrictlyPositive::check(&geometry.length).is_err() || !geometry.length.is_finite() {
+            return Err(DrumError::InvalidGeometry(
+                "length must be strictly positive and finite",
+            ));
+        }
+
+        Ok(Self {
+            thermo,
+            geometry,
+            _fluid: PhantomData,
+        })
+    }
+
+    /// Shell dimensions.
+    #[must_use]
+    pub fn geometry(&self) -> DrumGeometry {
+        self.geometry
+    }
+
+    /// Inside volume of the shell.
+    #[must_use]
+    pub fn volume(&self) -> Volume {
+        PI / 4.0 * self.geometry.diameter * self.geometry.diameter * self.geometry.length
+    }
+
+    /// Returns the thermo model.
+    #[must_use]
+    pub fn thermo(&self) -> &Thermo {
+        &self.thermo
+    }
+
+    /// Liquid depth that fills `liquid_volume` of the shell.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrumError::LevelNotConverged`] if the depth cannot be
+    /// resolved.
+    pub fn level(&self, liquid_volume: Volume) -> Result<Length, DrumError> {
+        let diameter = self.geometry.diameter.get::<meter>();
+        let full = self.volume();
+        if liquid_volume <= Volume::default() {
+            return Ok(Length::default());
+        }
+        if liquid_volume >= full {
+            return Ok(self.geometry.diameter);
+        }
+
+        let target = (liquid_volume / full).get::<ratio>();
+        let solution = brent(
+            |h| Ok::<_, Infallible>(liquid_share(diameter, h) - target),
+            [0.0, diameter],
+            &solve::Config {
+                max_iters: 100,
+                x_abs_tol: 1e-12 * diameter,
+                x_rel_tol: 0.0,
+                residual_tol: 0.0,
+            },
+        )
+        .map_err(|error| DrumError::LevelNotConverged {
+            iterations: match error {
+                solve::SolveError::MaxIters { iters, .. } => iters,
+                _ => 0,
+            },
+        })?;
+
+        Ok(Length::new::<meter>(solution.x))
+    }
+
+    /// Volume below a liquid depth of `level`.
+    ///
+    /// Depths outside the shell are clamped to empty or full.
+    #[must_use]
+    pub fn liquid_volume(&self, level: Length) -> Volume {
+        let diameter = self.geometry.diameter.get::<meter>();
+        let depth = level.get::<meter>().clamp(0.0, diameter);
+        liquid_share(diameter, depth) * self.volume()
+    }
+
+    /// Water mass and internal energy of a saturated drum.
+    ///
+    /// Useful for building an initial [`DrumInput`] from a drum pressure and
+    /// a liquid level. The internal energy is `H − pV` of the saturated
+    /// liquid and vapor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a saturation state cannot be evaluated.
+    pub fn inventory(
+        &self,
+        fluid: Fluid,
+        pressure: Pressure,
+        level: Length,
+    ) -> Result<(Mass, Energy), DrumError>
+    where
+        Fluid: Clone,
+        Thermo: DrumThermoModel<Fluid>,
+    {
+        let (liquid, h_liquid) = self.saturated(fluid.clone(), pressure, UnitInterval::zero())?;
+        let (vapor, h_vapor) = self.saturated(fluid, pressure, UnitInterval::one())?;
+
+        let liquid_volume = self.liquid_volume(level);
+        let liquid_mass: Mass = liquid.density * liquid_volume;
+        let vapor_mass: Mass = vapor.density * (self.volume() - liquid_volume);
+        let enthalpy: Energy = liquid_mass * h_liquid + vapor_mass * h_vapor;
+
+        Ok((
+            liquid_mass + vapor_mass,
+            enthalpy - pressure * self.volume(),
+        ))
+    }
+
+    /// Saturated state and enthalpy at `pressure`.
+    fn saturated(
+        &self,
+        fluid: Fluid,
+        pressure: Pressure,
+        quality: Constrained<Ratio, UnitInterval>,
+    ) -> Result<(State<Fluid>, SpecificEnthalpy), DrumError>
+    where
+        Thermo: DrumThermoModel<Fluid>,
+    {
+        let state = self
+            .thermo
+            .state_from((fluid, pressure, quality))
+            .map_err(|error| DrumError::thermo("saturation state", error))?;
+        let enthalpy = self
+            .thermo
+            .enthalpy(&state)
+            .map_err(|error| DrumError::thermo("saturation enthalpy", error))?;
+        Ok((state, enthalpy))
+    }
+
+    /// Evaluates the drum balances at a single point in time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an input is out of range, if the contents are
+    /// not saturated, or if a property evaluation fails.
+    pub fn evaluate(&self, input: &DrumInput<Fluid>) -> Result<DrumOutput<Fluid>, DrumError>
+    where
+        Fluid: Clone,
+        Thermo: DrumThermoModel<Fluid>,
+    {
+        if StrictlyPositive::check(&input.mass).is_err() {
+            return Err(DrumError::NonPositiveMass(input.mass));
+        }
+        if NonNegative::check(&input.feedwater.mass_rate).is_err()
+            || NonNegative::check(&input.steam).is_err()
+        {
+            return Err(DrumError::InvalidInput(
+                "feedwater and steam flows must be non-negative",
+            ));
+        }
+        if NonNegative::check(&input.blowdown).is_err() || !input.blowdown.is_finite() {
+            return Err(DrumError::InvalidInput(
+                "blowdown fraction must be non-negative and finite",
+            ));
+        }
+
+        let density: MassDensity = input.mass / self.volume();
+        let specific_energy: SpecificInternalEnergy = input.internal_energy / input.mass;
+        let state = self
+            .thermo
+            .state_from((input.fluid.clone(), density, specific_energy))
+            .map_err(|error| DrumError::thermo("state from (ρ, u)", error))?;
+        let pressure = self
+            .thermo
+            .pressure(&state)
+            .map_err(|error| DrumError::thermo("pressure", error))?;
+        let Some(two_phase) = self
+            .thermo
+            .two_phase(&state)
+            .map_err(|error| DrumError::thermo("two-phase split", error))?
+        else {
+            return Err(DrumError::NotSaturated { pressure });
+        };
+
+        let (_, h_liquid) = self.saturated(input.fluid.clone(), pressure, UnitInterval::zero())?;
+        let (_, h_vapor) = self.saturated(input.fluid.clone(), pressure, UnitInterval::one())?;
+
+        let (heat_rate, gas_outlet_temperature) = match input.heat {
+            DrumHeat::Rate(heat_rate) => (heat_rate, None),
+            DrumHeat::Gas {
+                mass_rate,
+                specific_heat,
+                inlet_temperature,
+                ua,
+            } => {
+                if StrictlyPositive::check(&mass_rate).is_err()
+                    || StrictlyPositive::check(&specific_heat).is_err()
+                {
+                    return Err(DrumError::InvalidInput(
+                        "gas mass rate and specific heat must be strictly positive",
+                    ));
+                }
+                if NonNegative::check(&ua).is_err() || !ua.is_finite() {
+                    return Err(DrumError::InvalidInput(
+                        "tube bank conductance must be non-negative and finite",
+                    ));
+                }
+
+                let capacitance: ThermalConductance = mass_rate * specific_heat;
+                let effectiveness = 1.0 - (-(ua / capacitance).get::<ratio>()).exp();
+                let heat_rate: Power =
+                    effectiveness * capacitance * inlet_temperature.minus(state.temperature);
+                (heat_rate, Some(inlet_temperature - heat_rate / capacitance))
+            }
+        };
+
+        let quality = two_phase.quality;
+        let liquid_volume: Volume =
+            input.mass * (1.0 - quality.get::<ratio>()) / two_phase.liquid_density;
+        let level = self.level(liquid_volume)?;
+
+        let blowdown = input.steam * input.blowdown.get::<ratio>();
+        let h_feed = input.feedwater.enthalpy;
+        let steady_steam =
+            heat_rate / ((h_vapor - h_feed) + input.blowdown.get::<ratio>() * (h_liquid - h_feed));
+
+        Ok(DrumOutput {
+            state,
+            pressure,
+            quality,
+            liquid_volume,
+            level,
+            steam_enthalpy: h_vapor,
+            blowdown,
+            blowdown_enthalpy: h_liquid,
+            heat_rate,
+            gas_outlet_temperature,
+            steady_steam,
+            derivative: ControlVolumeDerivative {
+                mass: input.feedwater.mass_rate - input.steam - blowdown,
+                internal_energy: input.feedwater.mass_rate * h_feed
+                    - input.steam * h_vapor
+                    - blowdown * h_liquid
+                    + heat_rate,
+            },
+        })
+    }
+}
+
+/// Share of a circle's area below a chord at depth `h`.
+fn liquid_share(diameter: f64, h: f64) -> f64 {
+    let radius = diameter / 2.0;
+    let offset = radius - h;
+    let half_chord = (h * (diameter - h)).max(0.0).sqrt();
+    let segment = radius * radius * (offset / radius).clamp(-1.0, 1.0).acos() - offset * half_chord;
+    segment / (PI * radius * radius)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::volume::cubic_meter;
+
+    fn drum() -> Drum<(), ()> {
+        Drum::new(
+            (),
+            DrumGeometry {
+                diameter: Length::new::<meter>(1.2),
+                length: Length::new::<meter>(5.0),
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn level_inverts_liquid_volume() {
+        let drum = drum();
+        let volume = drum.volume().get::<cubic_meter>();
+        assert_relative_eq!(volume, PI * 0.36 * 5.0, max_relative = 1e-12);
+
+        let half = drum.liquid_volume(Length::new::<meter>(0.6));
+        assert_relative_eq!(
+            half.get::<cubic_meter>(),
+            volume / 2.0,
+            max_relative = 1e-12
+        );
+
+        for depth in [0.05, 0.3, 0.6, 0.9, 1.15] {
+            let level = drum
+                .level(drum.liquid_volume(Length::new::<meter>(depth)))
+                .unwrap();
+            assert_relative_eq!(level.get::<meter>(), depth, epsilon = 1e-9);
+        }
+
+        assert_eq!(drum.level(Volume::default()).unwrap(), Length::default());
+        assert_eq!(
+            drum.level(drum.volume()).unwrap(),
+            Length::new::<meter>(1.2)
+        );
+        assert_eq!(drum.liquid_volume(Length::new::<meter>(2.0)), drum.volume());
+    }
+
+    #[test]
+    fn rejects_invalid_geometry() {
+        let geometry = |diameter, length| DrumGeometry {
+            diameter: Length::new::<meter>(diameter),
+            length: Length::new::<meter>(length),
+        };
+        assert!(matches!(
+            Drum::<(), ()>::new((), geometry(0.0, 5.0)),
+            Err(DrumError::InvalidGeometry(_))
+        ));
+        assert!(matches!(
+            Drum::<(), ()>::new((), geometry(1.0, f64::INFINITY)),
+            Err(DrumError::InvalidGeometry(_))
+        ));
+    }
+
+    #[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
+    mod coolprop_tests {
+        use super::*;
+
+        use uom::si::{
+            available_energy::kilojoule_per_kilogram, mass::kilogram,
+            mass_rate::kilogram_per_second, power::megawatt, pressure::bar, ratio::percent,
+            specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+            thermal_conductance::kilowatt_per_kelvin, thermodynamic_temperature::degree_celsius,
+        };
+
+        use crate::support::thermo::{
+            capability::HasInternalEnergy, fluid::Water, model::CoolProp,
+        };
+
+        fn water_drum() -> Drum<Water, CoolProp<Water>> {
+            Drum::new(
+                CoolProp::new().unwrap(),
+                DrumGeometry {
+                    diameter: Length::new::<meter>(1.2),
+                    length: Length::new::<meter>(5.0),
+                },
+            )
+            .unwrap()
+        }
+
+        fn input(drum: &Drum<Water, CoolProp<Water>>, heat: DrumHeat) -> DrumInput<Water> {
+            let (mass, internal_energy) = drum
+                .inventory(Water, Pressure::new::<bar>(40.0), Length::new::<meter>(0.6))
+                .unwrap();
+            DrumInput {
+                mass,
+                internal_energy,
+                fluid: Water,
+                feedwater: Inflow {
+                    mass_rate: MassRate::default(),
+                    enthalpy: SpecificEnthalpy::new::<kilojoule_per_kilogram>(650.0),
+                },
+                steam: MassRate::default(),
+                blowdown: Ratio::new::<percent>(3.0),
+                heat,
+            }
+        }
+
+        #[test]
+        fn recovers_pressure_and_level_from_inventory() {
+            let drum = water_drum();
+            let output = drum
+                .evaluate(&input(&drum, DrumHeat::Rate(Power::default())))
+                .unwrap();
+
+            assert_relative_eq!(output.pressure.get::<bar>(), 40.0, max_relative = 1e-6);
+            assert_relative_eq!(output.level.get::<meter>(), 0.6, epsilon = 1e-6);
+            assert_relative_eq!(
+                output.state.temperature.get::<degree_celsius>(),
+                250.4,
+                epsilon = 0.1
+            );
+            assert!(output.steam_enthalpy > output.blowdown_enthalpy);
+        }
+
+        #[test]
+        fn steady_steam_holds_the_drum() {
+            let drum = water_drum();
+            let heat = DrumHeat::Rate(Power::new::<megawatt>(10.0));
+            let probe = drum.evaluate(&input(&drum, heat)).unwrap();
+
+            let steam = probe.steady_steam;
+            let steady = drum
+                .evaluate(&DrumInput {
+                    feedwater: Inflow {
+                        mass_rate: steam * 1.03,
+                        ..input(&drum, heat).feedwater
+                    },
+                    steam,
+                    ..input(&drum, heat)
+                })
+                .unwrap();
+
+            assert_relative_eq!(
+                steady.blowdown.get::<kilogram_per_second>(),
+                0.03 * steam.get::<kilogram_per_second>(),
+                max_relative = 1e-12
+            );
+            assert!(steady.derivative.mass.get::<kilogram_per_second>().abs() < 1e-12);
+            assert!(steady.derivative.internal_energy.get::<megawatt>().abs() < 1e-9);
+
+            // About 5 kg/s of steam from 10 MW at 40 bar.
+            let rate = steam.get::<kilogram_per_second>();
+            assert!(rate > 4.5 && rate < 5.0, "steam = {rate}");
+        }
+
+        #[test]
+        fn gas_side_tube_bank_approaches_saturation() {
+            let drum = water_drum();
+            let gas = |ua| DrumHeat::Gas {
+                mass_rate: MassRate::new::<kilogram_per_second>(20.0),
+                specific_heat: SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(1.1),
+                inlet_temperature: ThermodynamicTemperature::new::<degree_celsius>(500.0),
+                ua: ThermalConductance::new::<kilowatt_per_kelvin>(ua),
+            };
+
+            let output = drum.evaluate(&input(&drum, gas(44.0))).unwrap();
+            let t_sat = output.state.temperature.get::<degree_celsius>();
+            let t_out = output
+                .gas_outlet_temperature
+                .unwrap()
+                .get::<degree_celsius>();
+
+            // NTU = 2, so the gas closes 1 − e⁻² of its approach.
+            let effectiveness = 1.0 - (-2.0_f64).exp();
+            assert_relative_eq!(
+                t_out,
+                500.0 - effectiveness * (500.0 - t_sat),
+                epsilon = 1e-9
+            );
+            assert_relative_eq!(
+                output.heat_rate.get::<megawatt>(),
+                22.0e-3 * (500.0 - t_out),
+                max_relative = 1e-9
+            );
+            assert!(output.derivative.internal_energy > Power::default());
+
+            let none = drum.evaluate(&input(&drum, gas(0.0))).unwrap();
+            assert_eq!(none.heat_rate, Power::default());
+        }
+
+        #[test]
+        fn flooded_drum_is_not_saturated() {
+            let drum = water_drum();
+            let liquid = drum
+                .thermo()
+                .state_from((
+                    Water,
+                    ThermodynamicTemperature::new::<degree_celsius>(200.0),
+                    Pressure::new::<bar>(40.0),
+                ))
+                .unwrap();
+            let mass = liquid.density * drum.volume();
+            let flooded = DrumInput {
+                mass,
+                internal_energy: mass * drum.thermo().internal_energy(&liquid).unwrap(),
+                ..input(&drum, DrumHeat::Rate(Power::default()))
+            };
+            assert!(matches!(
+                drum.evaluate(&flooded),
+                Err(DrumError::NotSaturated { .. })
+            ));
+            assert!(matches!(
+                drum.evaluate(&DrumInput {
+                    mass: Mass::new::<kilogram>(0.0),
+                    ..input(&drum, DrumHeat::Rate(Power::default()))
+                }),
+                Err(DrumError::NonPositiveMass(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Python bindings.
+//!
+//! Exposes the thermo capability calls, the effectiveness-NTU functional
+//! solvers, and the discretized heat exchanger `solve` and `given_ua` entry
+//! points as the `twine_models` Python module. Thermo models and flow
+//! arrangements are chosen at runtime through the [`factory`] dispatch
+//! types, so the same fluids and arrangements are available as in a
+//! declarative config.
+//!
+//! Every quantity crosses the boundary as a plain float in SI base units
+//! (kelvin, pascal, kilogram per second, watt, watt per kelvin, joule per
+//! kilogram). Invalid inputs raise `ValueError`; property and solver
+//! failures raise `RuntimeError` with the full error chain as the message.
+//!
+//! ```python
+//! import twine_models as tm
+//!
+//! air = tm.ThermoModel.perfect_gas("air")
+//! cold = air.state_from_tp(400.0, 200e3)
+//! hot = air.state_from_tp(600.0, 100e3)
+//!
+//! result = tm.discretized_given_ua(
+//!     air, tm.Arrangement.counter_flow(), 20, cold, hot, (1.0, 1.0), 500.0
+//! )
+//! print(result.q_dot, result.top[-1].temperature)
+//! ```
+//!
+//! Requires the `python` feature. Build the extension module with
+//! [maturin](https://www.maturin.rs), which reads its settings from
+//! `pyproject.toml` and builds the crate as a `cdylib`.
+//!
+//! [`factory`]: crate::models::factory
+
+mod discretized;
+mod hx;
+mod thermo;
+
+use std::{error::Error as StdError, fmt::Write};
+
+use pyo3::{
+    exceptions::{PyRuntimeError, PyValueError},
+    prelude::*,
+};
+
+/// The `twine_models` Python module.
+#[pymodule]
+fn twine_models(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<thermo::PyState>()?;
+    module.add_class::<thermo::PyThermo>()?;
+    module.add_class::<hx::PyArrangement>()?;
+    module.add_class::<hx::PyStream>()?;
+    module.add_class::<hx::PyKnownConductanceResult>()?;
+    module.add_class::<hx::PyKnownConditionsResult>()?;
+    module.add_class::<discretized::PyGiven>()?;
+    module.add_class::<discretized::PyDiscretizedResults>()?;
+    module.add_function(wrap_pyfunction!(hx::known_conductance_and_inlets, module)?)?;
+    module.add_function(wrap_pyfunction!(hx::known_conditions_and_inlets, module)?)?;
+    module.add_function(wrap_pyfunction!(discretized::discretized_solve, module)?)?;
+    module.add_function(wrap_pyfunction!(discretized::discretized_given_ua, module)?)?;
+    Ok(())
+}
+
+/// Raises a `ValueError` for an input the Rust API rejected.
+fn value_error(error: &(dyn StdError + 'static)) -> PyErr {
+    PyValueError::new_err(error_chain(error))
+}
+
+/// Raises a `RuntimeError` for a property or solver failure.
+fn runtime_error(error: &(dyn StdError + 'static)) -> PyErr {
+    PyRuntimeError::new_err(error_chain(error))
+}
+
+/// Joins an error and its sources into one message.
+///
+/// Python tracebacks don't walk Rust source chains, so the causes are
+/// folded into the exception message instead.
+fn error_chain(error: &(dyn StdError + 'static)) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        let _ = write!(message, ": {cause}");
+        source = cause.source();
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::models::thermal::hx::discretized::KnownBuildError;
+    use crate::support::constraint::ConstraintError;
+
+    #[test]
+    fn error_chain_includes_sources() {
+        let error = KnownBuildError::MassFlow {
+            stream: "top",
+            source: ConstraintError::Zero,
+        };
+        let message = error_chain(&error);
+
+        assert!(message.starts_with("top mass flow rate is invalid: "));
+        assert!(message.ends_with(&ConstraintError::Zero.to_string()));
+    }
+}
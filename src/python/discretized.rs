@@ -0,0 +1,336 @@
+use pyo3::{exceptions::PyValueError, prelude::*};
+use uom::si::{
+    f64::{
+        MassRate, Power, Pressure, TemperatureInterval, ThermalConductance,
+        ThermodynamicTemperature,
+    },
+    mass_rate::kilogram_per_second,
+    power::watt,
+    pressure::pascal,
+    temperature_interval::kelvin as delta_kelvin,
+    thermal_conductance::watt_per_kelvin,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::{
+    models::{
+        factory::{ArrangementConfig, with_gas},
+        thermal::hx::discretized::core::{
+            DiscretizedArrangement, DiscretizedHx, DiscretizedHxThermoModel, Given, GivenUaConfig,
+            GivenUaError, HeatTransferRate, Known, Results,
+        },
+    },
+    support::{
+        hx::{CounterFlow, ParallelFlow},
+        thermo::State,
+    },
+};
+
+use super::{
+    hx::PyArrangement,
+    runtime_error,
+    thermo::{PyState, PyThermo, requires_gas},
+    value_error,
+};
+
+/// Calls `$solve::<Arrangement, _, _, N>($args)` with the arrangement type
+/// and node count selected at runtime.
+///
+/// Segment counts match those supported by the recuperator models.
+macro_rules! dispatch {
+    ($arrangement:expr, $segments:expr, $solve:ident($($arg:expr),* $(,)?)) => {
+        match $arrangement.0.config() {
+            ArrangementConfig::CounterFlow => {
+                dispatch!(@nodes CounterFlow, $segments, $solve($($arg),*))
+            }
+            ArrangementConfig::ParallelFlow => {
+                dispatch!(@nodes ParallelFlow, $segments, $solve($($arg),*))
+            }
+            _ => Err(PyValueError::new_err(
+                "discretized heat exchangers support counter flow and parallel flow",
+            )),
+        }
+    };
+    (@nodes $arrangement:ty, $segments:expr, $solve:ident($($arg:expr),*)) => {
+        match $segments {
+            1 => $solve::<$arrangement, _, _, 2>($($arg),*),
+            5 => $solve::<$arrangement, _, _, 6>($($arg),*),
+            10 => $solve::<$arrangement, _, _, 11>($($arg),*),
+            20 => $solve::<$arrangement, _, _, 21>($($arg),*),
+            50 => $solve::<$arrangement, _, _, 51>($($arg),*),
+            100 => $solve::<$arrangement, _, _, 101>($($arg),*),
+            segments => Err(PyValueError::new_err(format!(
+                "unsupported segment count {segments}; supported values are 1, 5, 10, 20, 50, 100"
+            ))),
+        }
+    };
+}
+
+/// The extra condition that closes a discretized solve.
+#[pyclass(name = "Given", module = "twine_models", frozen)]
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PyGiven(Given);
+
+#[pymethods]
+impl PyGiven {
+    /// Top stream outlet temperature in K.
+    #[staticmethod]
+    fn top_outlet_temperature(temperature: f64) -> Self {
+        Self(Given::TopOutletTemp(
+            ThermodynamicTemperature::new::<kelvin>(temperature),
+        ))
+    }
+
+    /// Bottom stream outlet temperature in K.
+    #[staticmethod]
+    fn bottom_outlet_temperature(temperature: f64) -> Self {
+        Self(Given::BottomOutletTemp(ThermodynamicTemperature::new::<
+            kelvin,
+        >(temperature)))
+    }
+
+    /// Heat transfer rate in W, positive from the top to the bottom stream.
+    #[staticmethod]
+    fn heat_transfer_rate(q_dot: f64) -> PyResult<Self> {
+        HeatTransferRate::from_signed_top_to_bottom(Power::new::<watt>(q_dot))
+            .map(|q_dot| Self(Given::HeatTransferRate(q_dot)))
+            .map_err(|error| value_error(&error))
+    }
+}
+
+/// Node states and performance metrics of a discretized solve.
+///
+/// Node lists run from left (0) to right; the top stream always enters at
+/// node 0. `q_dot` is in W, positive from the top to the bottom stream.
+#[pyclass(name = "DiscretizedResults", module = "twine_models", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub(super) struct PyDiscretizedResults {
+    top: Vec<PyState>,
+    bottom: Vec<PyState>,
+    q_dot: f64,
+    ua: f64,
+    min_delta_t: f64,
+    min_delta_t_node: usize,
+    iterations: Option<usize>,
+}
+
+impl PyDiscretizedResults {
+    fn new<TopFluid, BottomFluid, const N: usize>(
+        results: &Results<TopFluid, BottomFluid, N>,
+        iterations: Option<usize>,
+    ) -> Self {
+        Self {
+            top: results.top.iter().map(PyState::from_state).collect(),
+            bottom: results.bottom.iter().map(PyState::from_state).collect(),
+            q_dot: results.q_dot.signed_top_to_bottom().get::<watt>(),
+            ua: results.ua.get::<watt_per_kelvin>(),
+            min_delta_t: results.min_delta_t.value.get::<delta_kelvin>(),
+            min_delta_t_node: results.min_delta_t.node,
+            iterations,
+        }
+    }
+}
+
+/// Solves a discretized heat exchanger closed by `given`.
+///
+/// Both streams use `thermo`, which must be a perfect gas model.
+/// `mass_flows` and `pressure_drops` are `(top, bottom)` pairs in kg/s and Pa.
+#[pyfunction]
+#[pyo3(signature = (
+    thermo, arrangement, segments, top_inlet, bottom_inlet, mass_flows, given,
+    pressure_drops = (0.0, 0.0),
+))]
+#[allow(clippy::too_many_arguments)]
+pub(super) fn discretized_solve(
+    thermo: PyThermo,
+    arrangement: PyArrangement,
+    segments: usize,
+    top_inlet: PyState,
+    bottom_inlet: PyState,
+    mass_flows: (f64, f64),
+    given: PyGiven,
+    pressure_drops: (f64, f64),
+) -> PyResult<PyDiscretizedResults> {
+    with_gas!(&thermo.0, |model, fluid| {
+        let known = known(
+            model,
+            [top_inlet.to_state(fluid), bottom_inlet.to_state(fluid)],
+            mass_flows,
+            pressure_drops,
+        )?;
+        dispatch!(arrangement, segments, solve(&known, given.0, model))
+    }, else Err(requires_gas("discretized_solve")))
+}
+
+/// Solves a discretized heat exchanger for the target conductance `ua` (W/K).
+///
+/// Arguments match [`discretized_solve`]. Unset tolerances and the
+/// iteration limit keep the core solver defaults.
+#[pyfunction]
+#[pyo3(signature = (
+    thermo, arrangement, segments, top_inlet, bottom_inlet, mass_flows, ua,
+    pressure_drops = (0.0, 0.0), *, max_iters = None, temp_tol = None, ua_tol = None,
+))]
+#[allow(clippy::too_many_arguments)]
+pub(super) fn discretized_given_ua(
+    thermo: PyThermo,
+    arrangement: PyArrangement,
+    segments: usize,
+    top_inlet: PyState,
+    bottom_inlet: PyState,
+    mass_flows: (f64, f64),
+    ua: f64,
+    pressure_drops: (f64, f64),
+    max_iters: Option<usize>,
+    temp_tol: Option<f64>,
+    ua_tol: Option<f64>,
+) -> PyResult<PyDiscretizedResults> {
+    let defaults = GivenUaConfig::default();
+    let config = GivenUaConfig {
+        max_iters: max_iters.unwrap_or(defaults.max_iters),
+        temp_tol: temp_tol.map_or(defaults.temp_tol, TemperatureInterval::new::<delta_kelvin>),
+        ua_tol: ua_tol.map_or(defaults.ua_tol, ThermalConductance::new::<watt_per_kelvin>),
+        ..defaults
+    };
+    let ua = ThermalConductance::new::<watt_per_kelvin>(ua);
+
+    with_gas!(&thermo.0, |model, fluid| {
+        let known = known(
+            model,
+            [top_inlet.to_state(fluid), bottom_inlet.to_state(fluid)],
+            mass_flows,
+            pressure_drops,
+        )?;
+        dispatch!(arrangement, segments, given_ua(&known, ua, config, model))
+    }, else Err(requires_gas("discretized_given_ua")))
+}
+
+fn known<Fluid>(
+    thermo: &impl DiscretizedHxThermoModel<Fluid>,
+    [top, bottom]: [State<Fluid>; 2],
+    mass_flows: (f64, f64),
+    pressure_drops: (f64, f64),
+) -> PyResult<Known<Fluid, Fluid>> {
+    Known::builder()
+        .top_inlet(top)
+        .bottom_inlet(bottom)
+        .mass_flows(
+            MassRate::new::<kilogram_per_second>(mass_flows.0),
+            MassRate::new::<kilogram_per_second>(mass_flows.1),
+        )
+        .top_pressure_drop(Pressure::new::<pascal>(pressure_drops.0))
+        .bottom_pressure_drop(Pressure::new::<pascal>(pressure_drops.1))
+        .build_checked(thermo, thermo)
+        .map_err(|error| value_error(&error))
+}
+
+fn solve<Arrangement, Fluid, Model, const N: usize>(
+    known: &Known<Fluid, Fluid>,
+    given: Given,
+    thermo: &Model,
+) -> PyResult<PyDiscretizedResults>
+where
+    Arrangement: DiscretizedArrangement + Default,
+    Fluid: Clone,
+    Model: DiscretizedHxThermoModel<Fluid>,
+{
+    DiscretizedHx::<Arrangement, N>::solve_same(known, given, thermo)
+        .map(|results| PyDiscretizedResults::new(&results, None))
+        .map_err(|error| runtime_error(&error))
+}
+
+fn given_ua<Arrangement, Fluid, Model, const N: usize>(
+    known: &Known<Fluid, Fluid>,
+    ua: ThermalConductance,
+    config: GivenUaConfig,
+    thermo: &Model,
+) -> PyResult<PyDiscretizedResults>
+where
+    Arrangement: DiscretizedArrangement + Default,
+    Fluid: Clone,
+    Model: DiscretizedHxThermoModel<Fluid>,
+{
+    DiscretizedHx::<Arrangement, N>::given_ua_same(known, ua, config, thermo)
+        .map(|solution| PyDiscretizedResults::new(&solution.results, Some(solution.iterations)))
+        .map_err(|error| match error {
+            GivenUaError::NegativeUa(_)
+            | GivenUaError::InvalidInletTemperature { .. }
+            | GivenUaError::EqualInletTemperatures => value_error(&error),
+            _ => runtime_error(&error),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    fn air() -> (PyThermo, PyState, PyState) {
+        let thermo = PyThermo::perfect_gas("air").unwrap();
+        let cold = PyState::new(400.0, 2.0).unwrap();
+        let hot = PyState::new(600.0, 1.0).unwrap();
+        (thermo, cold, hot)
+    }
+
+    #[test]
+    fn given_ua_matches_target_and_closes_with_solve() {
+        let (thermo, cold, hot) = air();
+        let counter_flow = PyArrangement::counter_flow().unwrap();
+
+        let result = discretized_given_ua(
+            thermo,
+            counter_flow,
+            10,
+            cold,
+            hot,
+            (1.0, 1.0),
+            500.0,
+            (0.0, 0.0),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_relative_eq!(result.ua, 500.0, max_relative = 1e-9);
+        assert_eq!(result.top.len(), 11);
+        assert!(result.q_dot < 0.0, "heat flows from the hot bottom stream");
+
+        let outlet = result.top.last().unwrap().temperature;
+        let solved = discretized_solve(
+            thermo,
+            counter_flow,
+            10,
+            cold,
+            hot,
+            (1.0, 1.0),
+            PyGiven::top_outlet_temperature(outlet),
+            (0.0, 0.0),
+        )
+        .unwrap();
+        assert_relative_eq!(solved.ua, 500.0, max_relative = 1e-6);
+        assert_eq!(solved.iterations, None);
+    }
+
+    #[test]
+    fn rejects_unsupported_inputs() {
+        let (thermo, cold, hot) = air();
+        let given = PyGiven::heat_transfer_rate(0.0).unwrap();
+        let solve = |arrangement, segments| {
+            discretized_solve(
+                thermo,
+                arrangement,
+                segments,
+                cold,
+                hot,
+                (1.0, 1.0),
+                given,
+                (0.0, 0.0),
+            )
+        };
+
+        assert!(solve(PyArrangement::parallel_flow().unwrap(), 5).is_ok());
+        assert!(solve(PyArrangement::parallel_flow().unwrap(), 7).is_err());
+        assert!(solve(PyArrangement::cross_flow(true, false).unwrap(), 5).is_err());
+    }
+}
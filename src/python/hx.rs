@@ -0,0 +1,275 @@
+use pyo3::{exceptions::PyValueError, prelude::*};
+use uom::si::{
+    f64::{ThermalConductance, ThermodynamicTemperature},
+    power::watt,
+    ratio::ratio,
+    thermal_conductance::watt_per_kelvin,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::{
+    models::factory::{AnyArrangement, ArrangementConfig, Mixing},
+    support::hx::{
+        CapacitanceRate, CounterFlow, CrossFlow, Effectiveness, Mixed, Ntu, NtuRelation,
+        ParallelFlow, ShellAndTube, Stream, StreamInlet, Unmixed,
+        functional::{self, KnownConditionsResult, KnownConductanceResult},
+    },
+};
+
+use super::value_error;
+
+/// A heat exchanger flow arrangement chosen at runtime.
+#[pyclass(name = "Arrangement", module = "twine_models", frozen)]
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PyArrangement(pub(super) AnyArrangement);
+
+#[pymethods]
+impl PyArrangement {
+    #[staticmethod]
+    pub(super) fn counter_flow() -> PyResult<Self> {
+        Self::build(ArrangementConfig::CounterFlow)
+    }
+
+    #[staticmethod]
+    pub(super) fn parallel_flow() -> PyResult<Self> {
+        Self::build(ArrangementConfig::ParallelFlow)
+    }
+
+    /// Cross flow, with the mixing of each stream in inlet order.
+    #[staticmethod]
+    pub(super) fn cross_flow(first_mixed: bool, second_mixed: bool) -> PyResult<Self> {
+        let mixing = |mixed| {
+            if mixed {
+                Mixing::Mixed
+            } else {
+                Mixing::Unmixed
+            }
+        };
+        Self::build(ArrangementConfig::CrossFlow {
+            first: mixing(first_mixed),
+            second: mixing(second_mixed),
+        })
+    }
+
+    #[staticmethod]
+    fn shell_and_tube(shell_passes: u16, tube_passes: u16) -> PyResult<Self> {
+        Self::build(ArrangementConfig::ShellAndTube {
+            shell_passes,
+            tube_passes,
+        })
+    }
+
+    // pyo3 only binds methods that borrow the instance.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn __repr__(&self) -> String {
+        format!("Arrangement({:?})", self.0.config())
+    }
+}
+
+impl PyArrangement {
+    fn build(config: ArrangementConfig) -> PyResult<Self> {
+        config
+            .build()
+            .map(Self)
+            .map_err(|error| value_error(&error))
+    }
+
+    /// Raises `ValueError` if the arrangement has no closed-form NTU relation.
+    ///
+    /// Cross flow with both streams mixed or both unmixed can only be solved
+    /// in the forward direction.
+    fn check_invertible(self) -> PyResult<()> {
+        match self.0.config() {
+            ArrangementConfig::CrossFlow { first, second } if first == second => {
+                Err(PyValueError::new_err(
+                    "cross flow with matching stream mixing has no closed-form NTU relation",
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl NtuRelation for PyArrangement {
+    fn ntu(&self, effectiveness: Effectiveness, rates: [CapacitanceRate; 2]) -> Ntu {
+        match self.0.config() {
+            ArrangementConfig::CounterFlow => CounterFlow.ntu(effectiveness, rates),
+            ArrangementConfig::ParallelFlow => ParallelFlow.ntu(effectiveness, rates),
+            ArrangementConfig::CrossFlow {
+                first: Mixing::Mixed,
+                second: Mixing::Unmixed,
+            } => CrossFlow::<Mixed, Unmixed>::new().ntu(effectiveness, rates),
+            ArrangementConfig::CrossFlow {
+                first: Mixing::Unmixed,
+                second: Mixing::Mixed,
+            } => CrossFlow::<Unmixed, Mixed>::new().ntu(effectiveness, rates),
+            ArrangementConfig::CrossFlow { .. } => unreachable!("rejected by check_invertible"),
+            // The NTU depends only on the shell pass count.
+            ArrangementConfig::ShellAndTube { shell_passes, .. } => match shell_passes {
+                1 => ShellAndTube::<1, 2>::new().map(|a| a.ntu(effectiveness, rates)),
+                2 => ShellAndTube::<2, 4>::new().map(|a| a.ntu(effectiveness, rates)),
+                3 => ShellAndTube::<3, 6>::new().map(|a| a.ntu(effectiveness, rates)),
+                4 => ShellAndTube::<4, 8>::new().map(|a| a.ntu(effectiveness, rates)),
+                _ => unreachable!("validated at build"),
+            }
+            .expect("pass counts are valid"),
+        }
+    }
+}
+
+/// A fully resolved stream.
+///
+/// `heat_flow` is signed in W, positive when the stream is heated.
+#[pyclass(name = "Stream", module = "twine_models", frozen, get_all)]
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PyStream {
+    capacitance_rate: f64,
+    inlet_temperature: f64,
+    outlet_temperature: f64,
+    heat_flow: f64,
+}
+
+impl From<Stream> for PyStream {
+    fn from(stream: Stream) -> Self {
+        Self {
+            capacitance_rate: stream.capacitance_rate.get::<watt_per_kelvin>(),
+            inlet_temperature: stream.inlet_temperature.get::<kelvin>(),
+            outlet_temperature: stream.outlet_temperature.get::<kelvin>(),
+            heat_flow: stream.heat_flow.signed().get::<watt>(),
+        }
+    }
+}
+
+/// Result of [`known_conductance_and_inlets`].
+#[pyclass(
+    name = "KnownConductanceResult",
+    module = "twine_models",
+    frozen,
+    get_all
+)]
+#[derive(Debug, Clone)]
+pub(super) struct PyKnownConductanceResult {
+    streams: Vec<PyStream>,
+    effectiveness: f64,
+}
+
+impl From<KnownConductanceResult> for PyKnownConductanceResult {
+    fn from(result: KnownConductanceResult) -> Self {
+        Self {
+            streams: result.streams.map(PyStream::from).to_vec(),
+            effectiveness: result.effectiveness.get::<ratio>(),
+        }
+    }
+}
+
+/// Result of [`known_conditions_and_inlets`].
+#[pyclass(
+    name = "KnownConditionsResult",
+    module = "twine_models",
+    frozen,
+    get_all
+)]
+#[derive(Debug, Clone)]
+pub(super) struct PyKnownConditionsResult {
+    streams: Vec<PyStream>,
+    ua: f64,
+    ntu: f64,
+}
+
+impl From<KnownConditionsResult> for PyKnownConditionsResult {
+    fn from(result: KnownConditionsResult) -> Self {
+        Self {
+            streams: result.streams.map(PyStream::from).to_vec(),
+            ua: result.ua.get::<watt_per_kelvin>(),
+            ntu: result.ntu.get::<ratio>(),
+        }
+    }
+}
+
+/// Resolves both streams given the conductance `ua` (W/K) and the
+/// `(capacitance_rate, temperature)` of each inlet (W/K, K).
+#[pyfunction]
+pub(super) fn known_conductance_and_inlets(
+    arrangement: PyArrangement,
+    ua: f64,
+    inlets: [(f64, f64); 2],
+) -> PyResult<PyKnownConductanceResult> {
+    let inlets = [stream_inlet(inlets[0])?, stream_inlet(inlets[1])?];
+    functional::known_conductance_and_inlets(
+        &arrangement.0,
+        ThermalConductance::new::<watt_per_kelvin>(ua),
+        inlets,
+    )
+    .map(PyKnownConductanceResult::from)
+    .map_err(|error| value_error(&error))
+}
+
+/// Finds the conductance that takes `stream` from its inlet to its outlet
+/// temperature against `inlet`.
+///
+/// `inlet` is `(capacitance_rate, temperature)` and `stream` is
+/// `(capacitance_rate, inlet_temperature, outlet_temperature)`, in W/K and K.
+#[pyfunction]
+pub(super) fn known_conditions_and_inlets(
+    arrangement: PyArrangement,
+    inlet: (f64, f64),
+    stream: (f64, f64, f64),
+) -> PyResult<PyKnownConditionsResult> {
+    let inlet = stream_inlet(inlet)?;
+    let (capacitance_rate, inlet_temperature, outlet_temperature) = stream;
+    let stream = Stream::new_from_outlet_temperature(
+        capacitance_rate_from(capacitance_rate)?,
+        ThermodynamicTemperature::new::<kelvin>(inlet_temperature),
+        ThermodynamicTemperature::new::<kelvin>(outlet_temperature),
+    );
+
+    arrangement.check_invertible()?;
+    functional::known_conditions_and_inlets(&arrangement, (inlet, stream))
+        .map(PyKnownConditionsResult::from)
+        .map_err(|error| value_error(&error))
+}
+
+fn stream_inlet((capacitance_rate, temperature): (f64, f64)) -> PyResult<StreamInlet> {
+    Ok(StreamInlet::new(
+        capacitance_rate_from(capacitance_rate)?,
+        ThermodynamicTemperature::new::<kelvin>(temperature),
+    ))
+}
+
+fn capacitance_rate_from(value: f64) -> PyResult<CapacitanceRate> {
+    CapacitanceRate::new::<watt_per_kelvin>(value).map_err(|error| value_error(&error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn forward_and_inverse_solves_agree() {
+        let arrangement = PyArrangement::counter_flow().unwrap();
+        let forward =
+            known_conductance_and_inlets(arrangement, 3000.0, [(3000.0, 323.15), (6000.0, 353.15)])
+                .unwrap();
+        let cold = &forward.streams[0];
+
+        let inverse = known_conditions_and_inlets(
+            arrangement,
+            (6000.0, 353.15),
+            (3000.0, 323.15, cold.outlet_temperature),
+        )
+        .unwrap();
+
+        assert_relative_eq!(inverse.ua, 3000.0, max_relative = 1e-9);
+        assert!(cold.heat_flow > 0.0);
+    }
+
+    #[test]
+    fn unsupported_cross_flow_inverse_is_an_error() {
+        let arrangement = PyArrangement::cross_flow(true, true).unwrap();
+        let result =
+            known_conditions_and_inlets(arrangement, (1000.0, 350.0), (1000.0, 300.0, 320.0));
+        assert!(result.is_err());
+    }
+}
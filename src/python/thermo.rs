@@ -0,0 +1,240 @@
+use pyo3::{exceptions::PyValueError, prelude::*};
+use serde::{Deserialize, de::IntoDeserializer};
+use uom::si::{
+    available_energy::joule_per_kilogram,
+    f64::{MassDensity, Pressure, ThermodynamicTemperature},
+    mass_density::kilogram_per_cubic_meter,
+    pressure::pascal,
+    specific_heat_capacity::joule_per_kilogram_kelvin,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::{
+    models::factory::{AnyThermo, Gas, Liquid, ThermoConfig, with_gas, with_thermo},
+    support::{
+        thermo::{
+            State,
+            capability::{
+                HasCp, HasCv, HasEnthalpy, HasEntropy, HasInternalEnergy, HasPressure, StateFrom,
+            },
+            fluid::Water,
+        },
+        units::SpecificEnthalpy,
+    },
+};
+
+use super::{runtime_error, value_error};
+
+/// A thermodynamic state: temperature in K and density in kg/m³.
+#[pyclass(name = "State", module = "twine_models", frozen)]
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PyState {
+    /// Temperature in K.
+    #[pyo3(get)]
+    pub(super) temperature: f64,
+
+    /// Density in kg/m³.
+    #[pyo3(get)]
+    pub(super) density: f64,
+}
+
+#[pymethods]
+impl PyState {
+    #[new]
+    pub(super) fn new(temperature: f64, density: f64) -> PyResult<Self> {
+        let state = State::try_new(
+            ThermodynamicTemperature::new::<kelvin>(temperature),
+            MassDensity::new::<kilogram_per_cubic_meter>(density),
+            (),
+        )
+        .map_err(|error| value_error(&error))?;
+        Ok(Self::from_state(&state))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "State(temperature={}, density={})",
+            self.temperature, self.density
+        )
+    }
+}
+
+impl PyState {
+    pub(super) fn from_state<Fluid>(state: &State<Fluid>) -> Self {
+        Self {
+            temperature: state.temperature.get::<kelvin>(),
+            density: state.density.get::<kilogram_per_cubic_meter>(),
+        }
+    }
+
+    pub(super) fn to_state<Fluid>(self, fluid: Fluid) -> State<Fluid> {
+        State::new(
+            ThermodynamicTemperature::new::<kelvin>(self.temperature),
+            MassDensity::new::<kilogram_per_cubic_meter>(self.density),
+            fluid,
+        )
+    }
+}
+
+/// A thermo model chosen at runtime.
+#[pyclass(name = "ThermoModel", module = "twine_models", frozen)]
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PyThermo(pub(super) AnyThermo);
+
+#[pymethods]
+impl PyThermo {
+    /// Perfect gas model of `fluid` (`"air"` or `"carbon_dioxide"`).
+    #[staticmethod]
+    pub(super) fn perfect_gas(fluid: &str) -> PyResult<Self> {
+        let fluid = Gas::deserialize(fluid.into_deserializer())
+            .map_err(|error: serde::de::value::Error| value_error(&error))?;
+        Self::build(ThermoConfig::PerfectGas { fluid })
+    }
+
+    /// Incompressible model of `fluid` (`"water"`).
+    #[staticmethod]
+    fn incompressible(fluid: &str) -> PyResult<Self> {
+        let fluid = Liquid::deserialize(fluid.into_deserializer())
+            .map_err(|error: serde::de::value::Error| value_error(&error))?;
+        Self::build(ThermoConfig::Incompressible { fluid })
+    }
+
+    /// State at `temperature` (K) and `pressure` (Pa).
+    fn state_from_tp(&self, temperature: f64, pressure: f64) -> PyResult<PyState> {
+        let t = ThermodynamicTemperature::new::<kelvin>(temperature);
+        let p = Pressure::new::<pascal>(pressure);
+        with_gas!(&self.0, |model, fluid| model
+            .state_from((fluid, t, p))
+            .map(|state| PyState::from_state(&state))
+            .map_err(|error| runtime_error(&error)),
+            else Err(requires_gas("state_from_tp")))
+    }
+
+    /// State at `pressure` (Pa) and specific `enthalpy` (J/kg).
+    fn state_from_ph(&self, pressure: f64, enthalpy: f64) -> PyResult<PyState> {
+        let p = Pressure::new::<pascal>(pressure);
+        let h = SpecificEnthalpy::new::<joule_per_kilogram>(enthalpy);
+        with_gas!(&self.0, |model, fluid| model
+            .state_from((fluid, p, h))
+            .map(|state| PyState::from_state(&state))
+            .map_err(|error| runtime_error(&error)),
+            else Err(requires_gas("state_from_ph")))
+    }
+
+    /// State at `temperature` (K) for an incompressible model.
+    fn state_from_t(&self, temperature: f64) -> PyResult<PyState> {
+        let t = ThermodynamicTemperature::new::<kelvin>(temperature);
+        match &self.0 {
+            AnyThermo::IncompressibleWater(model) => model
+                .state_from((Water, t))
+                .map(|state| PyState::from_state(&state))
+                .map_err(|error| runtime_error(&error)),
+            _ => Err(PyValueError::new_err(
+                "state_from_t requires an incompressible model",
+            )),
+        }
+    }
+
+    /// Pressure in Pa.
+    fn pressure(&self, state: PyState) -> PyResult<f64> {
+        with_gas!(&self.0, |model, fluid| model
+            .pressure(&state.to_state(fluid))
+            .map(|p| p.get::<pascal>())
+            .map_err(|error| runtime_error(&error)),
+            else Err(requires_gas("pressure")))
+    }
+
+    /// Specific internal energy in J/kg.
+    fn internal_energy(&self, state: PyState) -> PyResult<f64> {
+        with_thermo!(&self.0, |model, fluid| model
+            .internal_energy(&state.to_state(fluid))
+            .map(|u| u.get::<joule_per_kilogram>())
+            .map_err(|error| runtime_error(&error)))
+    }
+
+    /// Specific enthalpy in J/kg.
+    fn enthalpy(&self, state: PyState) -> PyResult<f64> {
+        with_thermo!(&self.0, |model, fluid| model
+            .enthalpy(&state.to_state(fluid))
+            .map(|h| h.get::<joule_per_kilogram>())
+            .map_err(|error| runtime_error(&error)))
+    }
+
+    /// Specific entropy in J/(kg·K).
+    fn entropy(&self, state: PyState) -> PyResult<f64> {
+        with_thermo!(&self.0, |model, fluid| model
+            .entropy(&state.to_state(fluid))
+            .map(|s| s.get::<joule_per_kilogram_kelvin>())
+            .map_err(|error| runtime_error(&error)))
+    }
+
+    /// Isobaric specific heat in J/(kg·K).
+    fn cp(&self, state: PyState) -> PyResult<f64> {
+        with_thermo!(&self.0, |model, fluid| model
+            .cp(&state.to_state(fluid))
+            .map(|cp| cp.get::<joule_per_kilogram_kelvin>())
+            .map_err(|error| runtime_error(&error)))
+    }
+
+    /// Isochoric specific heat in J/(kg·K).
+    fn cv(&self, state: PyState) -> PyResult<f64> {
+        with_thermo!(&self.0, |model, fluid| model
+            .cv(&state.to_state(fluid))
+            .map(|cv| cv.get::<joule_per_kilogram_kelvin>())
+            .map_err(|error| runtime_error(&error)))
+    }
+
+    fn __repr__(&self) -> String {
+        match self.0 {
+            AnyThermo::PerfectGasAir(_) => "ThermoModel.perfect_gas('air')",
+            AnyThermo::PerfectGasCarbonDioxide(_) => "ThermoModel.perfect_gas('carbon_dioxide')",
+            AnyThermo::IncompressibleWater(_) => "ThermoModel.incompressible('water')",
+        }
+        .to_owned()
+    }
+}
+
+/// Raises `ValueError` for a call that needs a pressure relation.
+pub(super) fn requires_gas(operation: &str) -> PyErr {
+    PyValueError::new_err(format!("{operation} requires a perfect gas model"))
+}
+
+impl PyThermo {
+    fn build(config: ThermoConfig) -> PyResult<Self> {
+        config
+            .build()
+            .map(Self)
+            .map_err(|error| value_error(&error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn perfect_gas_round_trips_through_floats() {
+        let air = PyThermo::perfect_gas("air").unwrap();
+        let state = air.state_from_tp(300.0, 101_325.0).unwrap();
+
+        assert_relative_eq!(air.pressure(state).unwrap(), 101_325.0, epsilon = 1e-6);
+
+        let h = air.enthalpy(state).unwrap();
+        let back = air.state_from_ph(101_325.0, h).unwrap();
+        assert_relative_eq!(back.temperature, 300.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn rejects_unknown_fluids_and_unsupported_calls() {
+        assert!(PyThermo::perfect_gas("steam").is_err());
+        assert!(PyThermo::incompressible("air").is_err());
+
+        let water = PyThermo::incompressible("water").unwrap();
+        let state = water.state_from_t(330.0).unwrap();
+        assert!(water.cp(state).unwrap() > 4000.0);
+        assert!(water.pressure(state).is_err());
+        assert!(water.state_from_tp(330.0, 101_325.0).is_err());
+    }
+}
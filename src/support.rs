@@ -1,11 +1,43 @@
 //! Supporting utilities for models.
 //!
 //! Each exported module is a self-contained utility with its own APIs and design.
+//!
+//! Without the `std` feature only [`constraint`], [`hx`], [`thermo`], and
+//! [`units`] are available.
 
 pub mod constraint;
+#[cfg(feature = "std")]
 pub mod control;
+#[cfg(feature = "std")]
+pub mod convection;
+#[cfg(feature = "std")]
+pub mod disinfection;
+#[cfg(feature = "std")]
+pub mod exergy;
+#[cfg(feature = "std")]
+pub(crate) mod friction;
 pub mod hx;
+#[cfg(feature = "std")]
+pub mod insulation;
+#[cfg(feature = "std")]
+pub mod metrics;
+#[cfg(feature = "std")]
+pub mod numerics;
+#[cfg(feature = "std")]
+pub mod port;
+#[cfg(feature = "std")]
+pub mod psychrometrics;
+#[cfg(feature = "std")]
+pub mod radiation;
+#[cfg(feature = "std")]
 pub mod schedule;
+#[cfg(feature = "std")]
+pub mod sensitivity;
+#[cfg(feature = "std")]
+pub mod testing;
+#[cfg(feature = "std")]
+pub mod thermal_network;
 pub mod thermo;
+#[cfg(feature = "std")]
 pub mod turbomachinery;
 pub mod units;
@@ -11,9 +11,13 @@
 //!
 //! The following marker types are available:
 //!
+//! - [`AboveAbsoluteZero`]: Thermodynamic temperature above 0 K
+//! - [`Finite`]: Neither infinite nor `NaN`
+//! - [`InRange<MIN, MAX>`]: Integer in the closed range `MIN..=MAX`
 //! - [`NonNegative`]: Zero or greater
 //! - [`NonPositive`]: Zero or less
 //! - [`NonZero`]: Not equal to zero
+//! - [`NotNan`]: Any value other than `NaN`
 //! - [`StrictlyNegative`]: Less than zero
 //! - [`StrictlyPositive`]: Greater than zero
 //! - [`UnitInterval`]: Closed unit interval `0 ≤ x ≤ 1`
@@ -27,33 +31,124 @@
 //! For convenience, each marker also provides an associated `new()`
 //! constructor (e.g., `StrictlyPositive::new(5.0)`).
 //!
+//! # Conversions
+//!
+//! Values convert between compatible constraints. Widening, such as
+//! [`StrictlyPositive`] to [`NonNegative`] or [`UnitIntervalOpen`] to
+//! [`UnitInterval`], uses [`From`] and never fails. Narrowing uses
+//! [`TryFrom`] and re-checks the value.
+//!
+//! # Clamping
+//!
+//! Solvers and controllers often produce values that violate a constraint by
+//! floating-point noise alone. As an explicit alternative to `new()`, the
+//! [`NonNegative`], [`NonPositive`], and [`UnitInterval`] markers and
+//! [`Bounded`] provide `clamped()` constructors that snap out-of-range
+//! values to the nearest bound (e.g., `UnitInterval::clamped(1.2)` is `1.0`).
+//!
+//! # Arithmetic
+//!
+//! Constrained values can be scaled by an `f64` with `*` and `/`; the result
+//! is re-checked and returned as a [`ConstraintResult`]. Subtracting two
+//! values with the same constraint yields the unconstrained inner type.
+//! Operations that always preserve a constraint return a constrained value
+//! directly, such as dividing a [`NonNegative`] value by a
+//! [`StrictlyPositive`] one or multiplying two [`UnitInterval`] values, so
+//! iterators of unit-interval values can be combined with `product()`.
+//! Multiplying or dividing two [`StrictlyPositive`] values is re-checked,
+//! since the result can underflow to zero, and their `product()` collects
+//! into a [`ConstraintResult`].
+//!
+//! # Combining constraints
+//!
+//! The [`And`] and [`Or`] combinators compose existing markers without
+//! defining new types, e.g., `Constrained::<f64, And<NonNegative, NonZero>>`.
+//!
+//! # Runtime bounds
+//!
+//! When the valid range is only known at runtime, use [`Bounded<T>`],
+//! which stores its `[min, max]` interval alongside the value
+//! (e.g., `Bounded::new(0.8, 0.4, 0.95)`).
+//!
+//! # Fractions that sum to one
+//!
+//! [`Weights`] holds a fixed-size or dynamically sized set of non-negative
+//! fractions validated to sum to one, for flow splits and compositions.
+//!
 //! See the documentation and tests for each constraint for usage patterns.
 //!
+//! # Approximate equality
+//!
+//! With the `approx` feature enabled, [`Constrained<T, C>`] implements the
+//! `approx` comparison traits whenever `T` does, delegating to the inner value.
+//!
+//! # Property testing
+//!
+//! With the `proptest` feature enabled, `Constrained<f64, C>` implements
+//! `proptest::arbitrary::Arbitrary` for every provided marker, so
+//! `any::<Constrained<f64, StrictlyPositive>>()` generates valid values
+//! directly.
+//!
+//! # Serialization
+//!
+//! With the `serde` feature enabled, [`Constrained<T, C>`] serializes as its
+//! inner value. Deserialization re-validates through [`Constraint::check`],
+//! so out-of-range values in configuration files are rejected with an error
+//! naming the constraint.
+//!
 //! # Extending
 //!
 //! You can define custom numeric invariants by implementing [`Constraint<T>`]
 //! for your own zero-sized marker types.
 
+mod above_absolute_zero;
+#[cfg(feature = "approx")]
+mod approx_eq;
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod bounded;
+mod combinator;
+mod conversion;
+mod finite;
+mod in_range;
 mod non_negative;
 mod non_positive;
 mod non_zero;
+mod not_nan;
+#[cfg(feature = "serde")]
+mod serialization;
 mod strictly_negative;
 mod strictly_positive;
 mod unit_interval;
+mod weights;
+
+use core::{
+    fmt::{self, Debug, Display},
+    iter::{Product, Sum},
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Sub},
+};
 
-use std::{iter::Sum, marker::PhantomData, ops::Add};
+use alloc::{format, string::String};
+use num_traits::{One, Zero};
 
-use num_traits::Zero;
 use thiserror::Error;
 
+pub use above_absolute_zero::AboveAbsoluteZero;
+pub use bounded::Bounded;
+pub use combinator::{And, Or};
+pub use finite::{Finite, IsFinite};
+pub use in_range::InRange;
 pub use non_negative::NonNegative;
 pub use non_positive::NonPositive;
 pub use non_zero::NonZero;
+pub use not_nan::NotNan;
 pub use strictly_negative::StrictlyNegative;
 pub use strictly_positive::StrictlyPositive;
 pub use unit_interval::{
     UnitBounds, UnitInterval, UnitIntervalLowerOpen, UnitIntervalOpen, UnitIntervalUpperOpen,
 };
+pub use weights::Weights;
 
 /// A trait for enforcing numeric invariants at construction time.
 ///
@@ -83,10 +178,85 @@ pub enum ConstraintError {
     Zero,
     #[error("value is not a number")]
     NotANumber,
+    #[error("value must be finite")]
+    Infinite,
+    #[error("values must sum to one")]
+    NotNormalized,
     #[error("value is below the minimum allowed")]
     BelowMinimum,
     #[error("value is above the maximum allowed")]
     AboveMaximum,
+    #[error("minimum bound must not exceed maximum bound")]
+    InvalidBounds,
+}
+
+impl ConstraintError {
+    /// Attaches the constraint name and offending value to this error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use twine_models::support::constraint::ConstraintError;
+    ///
+    /// let err = ConstraintError::Negative.with_context("NonNegative", &-2.5);
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "NonNegative constraint violated by -2.5: value must not be negative",
+    /// );
+    /// assert_eq!(err.kind(), ConstraintError::Negative);
+    /// ```
+    #[must_use]
+    pub fn with_context<T: Debug + ?Sized>(
+        self,
+        constraint: &'static str,
+        value: &T,
+    ) -> ConstraintViolation {
+        ConstraintViolation {
+            constraint,
+            value: format!("{value:?}"),
+            kind: self,
+        }
+    }
+}
+
+/// A [`ConstraintError`] annotated with the constraint name and offending
+/// value.
+///
+/// Created by [`ConstraintError::with_context`] or
+/// [`Constrained::new_with_context`]. Converts into the underlying
+/// [`ConstraintError`] for callers that only need the kind of violation.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{constraint} constraint violated by {value}: {kind}")]
+pub struct ConstraintViolation {
+    constraint: &'static str,
+    value: String,
+    kind: ConstraintError,
+}
+
+impl ConstraintViolation {
+    /// Returns the name of the violated constraint (e.g., `"NonNegative"`).
+    #[must_use]
+    pub fn constraint(&self) -> &'static str {
+        self.constraint
+    }
+
+    /// Returns the debug representation of the offending value.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the underlying violation.
+    #[must_use]
+    pub fn kind(&self) -> ConstraintError {
+        self.kind
+    }
+}
+
+impl From<ConstraintViolation> for ConstraintError {
+    fn from(violation: ConstraintViolation) -> Self {
+        violation.kind
+    }
 }
 
 /// A result type alias to use with [`Constraint`].
@@ -129,6 +299,120 @@ impl<T, C: Constraint<T>> Constrained<T, C> {
     pub fn into_inner(self) -> T {
         self.value
     }
+
+    /// Applies a function to the inner value and re-checks the constraint.
+    ///
+    /// The closure may change the inner type, as long as the same constraint
+    /// applies to the result (e.g., mapping a positive `Power` to a positive
+    /// `ThermalConductance`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mapped value does not satisfy the constraint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use twine_models::support::constraint::{ConstraintError, NonNegative};
+    ///
+    /// let x = NonNegative::new(4.0).unwrap();
+    /// assert_eq!(x.map(f64::sqrt).unwrap().into_inner(), 2.0);
+    /// assert_eq!(x.map(|v| v - 5.0), Err(ConstraintError::Negative));
+    /// ```
+    pub fn map<U, F>(self, f: F) -> Result<Constrained<U, C>, ConstraintError>
+    where
+        C: Constraint<U>,
+        F: FnOnce(T) -> U,
+    {
+        Constrained::new(f(self.value))
+    }
+
+    /// Applies a fallible function to the inner value and re-checks the constraint.
+    ///
+    /// The closure's error type must be convertible from [`ConstraintError`]
+    /// so both failure modes can be reported through a single error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the closure's error if it fails, or the converted
+    /// [`ConstraintError`] if the mapped value violates the constraint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use twine_models::support::constraint::{ConstraintError, StrictlyPositive};
+    ///
+    /// let x = StrictlyPositive::new(8.0_f64).unwrap();
+    /// let halved = x.try_map(|v| {
+    ///     if v.is_finite() { Ok(v / 2.0) } else { Err(ConstraintError::Infinite) }
+    /// });
+    /// assert_eq!(halved.unwrap().into_inner(), 4.0);
+    /// ```
+    pub fn try_map<U, E, F>(self, f: F) -> Result<Constrained<U, C>, E>
+    where
+        C: Constraint<U>,
+        E: From<ConstraintError>,
+        F: FnOnce(T) -> Result<U, E>,
+    {
+        Ok(Constrained::new(f(self.value)?)?)
+    }
+}
+
+impl<T: Debug, C: Constraint<T>> Constrained<T, C> {
+    /// Constructs a new constrained value, attaching context on failure.
+    ///
+    /// Behaves like [`Constrained::new`], but a violation is reported as a
+    /// [`ConstraintViolation`] naming the constraint and the offending value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value does not satisfy the constraint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use twine_models::support::constraint::{Constrained, ConstraintError, UnitInterval};
+    ///
+    /// let err = Constrained::<f64, UnitInterval>::new_with_context(1.2).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "UnitInterval constraint violated by 1.2: value is above the maximum allowed",
+    /// );
+    /// assert_eq!(err.kind(), ConstraintError::AboveMaximum);
+    /// ```
+    pub fn new_with_context(value: T) -> Result<Self, ConstraintViolation> {
+        C::check(&value).map_err(|err| err.with_context(constraint_name::<C>(), &value))?;
+        Ok(Self {
+            value,
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Formats the inner value, honoring any formatter options.
+///
+/// # Example
+///
+/// ```
+/// use twine_models::support::constraint::UnitInterval;
+///
+/// let x = UnitInterval::new(0.125).unwrap();
+/// assert_eq!(format!("{x}"), "0.125");
+/// assert_eq!(format!("{x:.2}"), "0.12");
+/// ```
+impl<T: Display, C: Constraint<T>> Display for Constrained<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
+
+/// Returns the name of a constraint marker without its module path.
+///
+/// Generic parameters are dropped, so `And<NonNegative, NonZero>` becomes `And`.
+fn constraint_name<C>() -> &'static str {
+    let name = core::any::type_name::<C>();
+    let base = name.split('<').next().unwrap_or(name);
+    base.rsplit("::").next().unwrap_or(base)
 }
 
 /// Returns a reference to the inner unconstrained value.
@@ -150,3 +434,96 @@ where
         iter.fold(Self::zero(), |a, b| a + b)
     }
 }
+
+/// Multiplies constrained values for which multiplication is valid.
+///
+/// Applies to all constraints that are preserved under multiplication,
+/// such as [`UnitInterval`].
+impl<T, C> Product for Constrained<T, C>
+where
+    C: Constraint<T>,
+    Constrained<T, C>: Mul<Output = Self> + One,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |a, b| a * b)
+    }
+}
+
+/// Scales a constrained value by a raw `f64` factor.
+///
+/// Because an arbitrary factor may violate the constraint (for example,
+/// scaling a non-negative value by `-1.0`), the result is re-checked and
+/// returned as a [`ConstraintResult`].
+///
+/// # Example
+///
+/// ```
+/// use twine_models::support::constraint::{ConstraintError, NonNegative};
+///
+/// let x = NonNegative::new(4.0).unwrap();
+/// assert_eq!((x * 0.5).unwrap().into_inner(), 2.0);
+/// assert_eq!(x * -1.0, Err(ConstraintError::Negative));
+/// ```
+impl<T, C> Mul<f64> for Constrained<T, C>
+where
+    T: Mul<f64, Output = T>,
+    C: Constraint<T>,
+{
+    type Output = ConstraintResult<Self>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.value * rhs)
+    }
+}
+
+/// Divides a constrained value by a raw `f64` divisor.
+///
+/// The result is re-checked against the constraint and returned as a
+/// [`ConstraintResult`].
+///
+/// # Example
+///
+/// ```
+/// use twine_models::support::constraint::{ConstraintError, UnitInterval};
+///
+/// let x = UnitInterval::new(0.8).unwrap();
+/// assert_eq!((x / 2.0).unwrap().into_inner(), 0.4);
+/// assert_eq!(x / 0.5, Err(ConstraintError::AboveMaximum));
+/// ```
+impl<T, C> Div<f64> for Constrained<T, C>
+where
+    T: Div<f64, Output = T>,
+    C: Constraint<T>,
+{
+    type Output = ConstraintResult<Self>;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.value / rhs)
+    }
+}
+
+/// Subtracts two values with the same constraint, returning an unconstrained `T`.
+///
+/// No constraint is generally preserved under subtraction, so the difference
+/// is returned as the raw inner type.
+///
+/// # Example
+///
+/// ```
+/// use twine_models::support::constraint::StrictlyPositive;
+///
+/// let a = StrictlyPositive::new(3).unwrap();
+/// let b = StrictlyPositive::new(5).unwrap();
+/// assert_eq!(a - b, -2);
+/// ```
+impl<T, C> Sub for Constrained<T, C>
+where
+    T: Sub<Output = T>,
+    C: Constraint<T>,
+{
+    type Output = T;
+
+    fn sub(self, rhs: Self) -> T {
+        self.value - rhs.value
+    }
+}
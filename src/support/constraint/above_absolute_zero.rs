@@ -0,0 +1,153 @@
+use core::{cmp::Ordering, marker::PhantomData};
+
+use uom::{ConstZero, si::f64::ThermodynamicTemperature};
+
+use super::{Constrained, Constraint, ConstraintError};
+
+/// Marker type enforcing that a temperature is above absolute zero: `T > 0 K`.
+///
+/// Use this type with [`Constrained<ThermodynamicTemperature, AboveAbsoluteZero>`]
+/// to reject non-physical absolute temperatures at the boundary of a model,
+/// before they reach property evaluation.
+///
+/// For a stricter practical floor (e.g., the lower limit of a property
+/// correlation), use [`AboveAbsoluteZero::with_floor`].
+///
+/// # Examples
+///
+/// ```
+/// use twine_models::support::constraint::{AboveAbsoluteZero, ConstraintError};
+/// use uom::si::{
+///     f64::ThermodynamicTemperature,
+///     thermodynamic_temperature::{degree_celsius, kelvin},
+/// };
+///
+/// let t = AboveAbsoluteZero::new(ThermodynamicTemperature::new::<degree_celsius>(20.0)).unwrap();
+/// assert_eq!(t.into_inner().get::<degree_celsius>(), 20.0);
+///
+/// // Error cases:
+/// let zero = ThermodynamicTemperature::new::<kelvin>(0.0);
+/// assert_eq!(AboveAbsoluteZero::new(zero), Err(ConstraintError::BelowMinimum));
+///
+/// let below = ThermodynamicTemperature::new::<degree_celsius>(-300.0);
+/// assert!(AboveAbsoluteZero::new(below).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AboveAbsoluteZero;
+
+impl AboveAbsoluteZero {
+    /// Constructs a temperature constrained to be above absolute zero.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConstraintError::BelowMinimum`] if the temperature is at or below 0 K.
+    /// - [`ConstraintError::NotANumber`] if the temperature is `NaN`.
+    pub fn new(
+        temperature: ThermodynamicTemperature,
+    ) -> Result<Constrained<ThermodynamicTemperature, AboveAbsoluteZero>, ConstraintError> {
+        Constrained::new(temperature)
+    }
+
+    /// Constructs a temperature that is at or above a practical lower bound.
+    ///
+    /// The floor itself must be above absolute zero, so a value that passes
+    /// this check also satisfies [`AboveAbsoluteZero`].
+    ///
+    /// # Errors
+    ///
+    /// - [`ConstraintError::InvalidBounds`] if `floor` is not above absolute zero.
+    /// - [`ConstraintError::BelowMinimum`] if the temperature is below `floor`.
+    /// - [`ConstraintError::NotANumber`] if either temperature is `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use twine_models::support::constraint::{AboveAbsoluteZero, ConstraintError};
+    /// use uom::si::{f64::ThermodynamicTemperature, thermodynamic_temperature::kelvin};
+    ///
+    /// let floor = ThermodynamicTemperature::new::<kelvin>(200.0);
+    /// let t = ThermodynamicTemperature::new::<kelvin>(150.0);
+    /// assert_eq!(
+    ///     AboveAbsoluteZero::with_floor(t, floor),
+    ///     Err(ConstraintError::BelowMinimum),
+    /// );
+    /// ```
+    pub fn with_floor(
+        temperature: ThermodynamicTemperature,
+        floor: ThermodynamicTemperature,
+    ) -> Result<Constrained<ThermodynamicTemperature, AboveAbsoluteZero>, ConstraintError> {
+        match Self::check(&floor) {
+            Ok(()) => {}
+            Err(ConstraintError::BelowMinimum) => return Err(ConstraintError::InvalidBounds),
+            Err(err) => return Err(err),
+        }
+        match temperature.partial_cmp(&floor) {
+            Some(Ordering::Less) => Err(ConstraintError::BelowMinimum),
+            Some(_) => Ok(Constrained {
+                value: temperature,
+                _marker: PhantomData,
+            }),
+            None => Err(ConstraintError::NotANumber),
+        }
+    }
+}
+
+impl Constraint<ThermodynamicTemperature> for AboveAbsoluteZero {
+    fn check(value: &ThermodynamicTemperature) -> Result<(), ConstraintError> {
+        match value.partial_cmp(&ThermodynamicTemperature::ZERO) {
+            Some(Ordering::Greater) => Ok(()),
+            Some(_) => Err(ConstraintError::BelowMinimum),
+            None => Err(ConstraintError::NotANumber),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+
+    fn k(value: f64) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<kelvin>(value)
+    }
+
+    #[test]
+    fn absolute_zero_bound() {
+        assert!(AboveAbsoluteZero::new(k(1e-9)).is_ok());
+        assert!(
+            AboveAbsoluteZero::new(ThermodynamicTemperature::new::<degree_celsius>(-273.0)).is_ok()
+        );
+        assert_eq!(
+            AboveAbsoluteZero::new(k(0.0)),
+            Err(ConstraintError::BelowMinimum)
+        );
+        assert_eq!(
+            AboveAbsoluteZero::new(k(-5.0)),
+            Err(ConstraintError::BelowMinimum)
+        );
+        assert_eq!(
+            AboveAbsoluteZero::new(k(f64::NAN)),
+            Err(ConstraintError::NotANumber)
+        );
+    }
+
+    #[test]
+    fn practical_floor() {
+        let floor = k(273.16);
+        assert!(AboveAbsoluteZero::with_floor(k(300.0), floor).is_ok());
+        assert!(AboveAbsoluteZero::with_floor(floor, floor).is_ok());
+        assert_eq!(
+            AboveAbsoluteZero::with_floor(k(250.0), floor),
+            Err(ConstraintError::BelowMinimum)
+        );
+        assert_eq!(
+            AboveAbsoluteZero::with_floor(k(300.0), k(0.0)),
+            Err(ConstraintError::InvalidBounds)
+        );
+        assert_eq!(
+            AboveAbsoluteZero::with_floor(k(f64::NAN), floor),
+            Err(ConstraintError::NotANumber)
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! Approximate equality for [`Constrained`] values.
+//!
+//! Comparisons delegate to the inner values, so constrained floats can be
+//! used directly with `assert_relative_eq!` and friends.
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use super::{Constrained, Constraint};
+
+impl<T, C> AbsDiffEq for Constrained<T, C>
+where
+    T: AbsDiffEq,
+    C: Constraint<T> + PartialEq,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.value.abs_diff_eq(&other.value, epsilon)
+    }
+}
+
+impl<T, C> RelativeEq for Constrained<T, C>
+where
+    T: RelativeEq,
+    C: Constraint<T> + PartialEq,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.value.relative_eq(&other.value, epsilon, max_relative)
+    }
+}
+
+impl<T, C> UlpsEq for Constrained<T, C>
+where
+    T: UlpsEq,
+    C: Constraint<T> + PartialEq,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.value.ulps_eq(&other.value, epsilon, max_ulps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+
+    use crate::support::constraint::{NonNegative, UnitInterval};
+
+    #[test]
+    fn compares_inner_values() {
+        let a = UnitInterval::new(0.1 + 0.2).unwrap();
+        let b = UnitInterval::new(0.3).unwrap();
+        assert_relative_eq!(a, b);
+        assert_ulps_eq!(a, b);
+
+        let c = NonNegative::new(1.0).unwrap();
+        let d = NonNegative::new(1.05).unwrap();
+        assert_abs_diff_eq!(c, d, epsilon = 0.1);
+        assert_relative_ne!(c, d);
+    }
+}
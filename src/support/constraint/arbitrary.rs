@@ -0,0 +1,104 @@
+//! Proptest support for constrained `f64` values.
+//!
+//! Each marker's [`Arbitrary`] implementation generates values spanning the
+//! full valid range of the constraint, so property tests exercise the same
+//! edge cases (zero, tiny, and huge magnitudes) that callers can construct.
+
+use core::marker::PhantomData;
+
+use proptest::{
+    arbitrary::Arbitrary,
+    prelude::{BoxedStrategy, Just, Strategy, prop_oneof},
+};
+
+use super::{
+    Constrained, Finite, NonNegative, NonPositive, NonZero, NotNan, StrictlyNegative,
+    StrictlyPositive, UnitInterval, UnitIntervalLowerOpen, UnitIntervalOpen, UnitIntervalUpperOpen,
+};
+
+/// Implements `Arbitrary` for `Constrained<f64, $marker>` from a raw strategy.
+macro_rules! impl_arbitrary {
+    ($marker:ty, $strategy:expr) => {
+        impl Arbitrary for Constrained<f64, $marker> {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with((): ()) -> Self::Strategy {
+                $strategy
+                    .prop_map(|value| Self {
+                        value,
+                        _marker: PhantomData,
+                    })
+                    .boxed()
+            }
+        }
+    };
+}
+
+impl_arbitrary!(NonNegative, 0.0..=f64::MAX);
+impl_arbitrary!(NonPositive, -f64::MAX..=0.0);
+impl_arbitrary!(StrictlyPositive, f64::MIN_POSITIVE..=f64::MAX);
+impl_arbitrary!(StrictlyNegative, -f64::MAX..=-f64::MIN_POSITIVE);
+impl_arbitrary!(
+    NonZero,
+    prop_oneof![f64::MIN_POSITIVE..=f64::MAX, -f64::MAX..=-f64::MIN_POSITIVE]
+);
+impl_arbitrary!(UnitInterval, 0.0..=1.0);
+impl_arbitrary!(UnitIntervalOpen, f64::MIN_POSITIVE..1.0);
+impl_arbitrary!(UnitIntervalLowerOpen, f64::MIN_POSITIVE..=1.0);
+impl_arbitrary!(UnitIntervalUpperOpen, 0.0..1.0);
+impl_arbitrary!(Finite, -f64::MAX..=f64::MAX);
+impl_arbitrary!(
+    NotNan,
+    prop_oneof![
+        8 => -f64::MAX..=f64::MAX,
+        1 => Just(f64::INFINITY),
+        1 => Just(f64::NEG_INFINITY),
+    ]
+);
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::support::constraint::*;
+
+    proptest! {
+        #[test]
+        fn sign_constraints_hold(
+            a in any::<Constrained<f64, NonNegative>>(),
+            b in any::<Constrained<f64, NonPositive>>(),
+            c in any::<Constrained<f64, StrictlyPositive>>(),
+            d in any::<Constrained<f64, StrictlyNegative>>(),
+            e in any::<Constrained<f64, NonZero>>(),
+        ) {
+            prop_assert!(NonNegative::check(a.as_ref()).is_ok());
+            prop_assert!(NonPositive::check(b.as_ref()).is_ok());
+            prop_assert!(StrictlyPositive::check(c.as_ref()).is_ok());
+            prop_assert!(StrictlyNegative::check(d.as_ref()).is_ok());
+            prop_assert!(NonZero::check(e.as_ref()).is_ok());
+        }
+
+        #[test]
+        fn unit_interval_constraints_hold(
+            a in any::<Constrained<f64, UnitInterval>>(),
+            b in any::<Constrained<f64, UnitIntervalOpen>>(),
+            c in any::<Constrained<f64, UnitIntervalLowerOpen>>(),
+            d in any::<Constrained<f64, UnitIntervalUpperOpen>>(),
+        ) {
+            prop_assert!(UnitInterval::check(a.as_ref()).is_ok());
+            prop_assert!(UnitIntervalOpen::check(b.as_ref()).is_ok());
+            prop_assert!(UnitIntervalLowerOpen::check(c.as_ref()).is_ok());
+            prop_assert!(UnitIntervalUpperOpen::check(d.as_ref()).is_ok());
+        }
+
+        #[test]
+        fn nan_constraints_hold(
+            a in any::<Constrained<f64, Finite>>(),
+            b in any::<Constrained<f64, NotNan>>(),
+        ) {
+            prop_assert!(Finite::check(a.as_ref()).is_ok());
+            prop_assert!(NotNan::check(b.as_ref()).is_ok());
+        }
+    }
+}
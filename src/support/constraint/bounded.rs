@@ -0,0 +1,243 @@
+use core::cmp::Ordering;
+
+use crate::support::constraint::ConstraintError;
+
+/// A value constrained to a closed interval `min ≤ x ≤ max` chosen at runtime.
+///
+/// Unlike the marker types used with [`Constrained`](super::Constrained),
+/// whose bounds are fixed at compile time, `Bounded` stores its interval
+/// alongside the value. This makes it suitable for model parameters whose
+/// valid range depends on context, such as an efficiency between `0.4` and
+/// `0.95` or a temperature between 250 K and 2000 K.
+///
+/// Works with any `T: PartialOrd`, including `uom` quantities.
+///
+/// # Examples
+///
+/// ```
+/// use twine_models::support::constraint::{Bounded, ConstraintError};
+///
+/// let eta = Bounded::new(0.8, 0.4, 0.95).unwrap();
+/// assert_eq!(eta.into_inner(), 0.8);
+/// assert_eq!(eta.bounds(), (0.4, 0.95));
+///
+/// assert_eq!(Bounded::new(0.3, 0.4, 0.95), Err(ConstraintError::BelowMinimum));
+/// assert_eq!(Bounded::new(0.99, 0.4, 0.95), Err(ConstraintError::AboveMaximum));
+/// assert_eq!(Bounded::new(0.5, 0.9, 0.1), Err(ConstraintError::InvalidBounds));
+/// ```
+///
+/// Using with `uom::si::f64::ThermodynamicTemperature`:
+///
+/// ```
+/// use twine_models::support::constraint::Bounded;
+/// use uom::si::{f64::ThermodynamicTemperature, thermodynamic_temperature::kelvin};
+///
+/// let t = |k| ThermodynamicTemperature::new::<kelvin>(k);
+///
+/// let t_inlet = Bounded::new(t(800.0), t(250.0), t(2000.0)).unwrap();
+/// assert_eq!(t_inlet.max(), t(2000.0));
+///
+/// // Reuse the same interval for another value.
+/// assert!(t_inlet.with_value(t(2100.0)).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounded<T> {
+    value: T,
+    min: T,
+    max: T,
+}
+
+impl<T: PartialOrd> Bounded<T> {
+    /// Constructs a bounded value if `min ≤ value ≤ max`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConstraintError::InvalidBounds`] if `min > max`.
+    /// - [`ConstraintError::BelowMinimum`] if `value < min`.
+    /// - [`ConstraintError::AboveMaximum`] if `value > max`.
+    /// - [`ConstraintError::NotANumber`] if any comparison is undefined (e.g., NaN).
+    pub fn new(value: T, min: T, max: T) -> Result<Self, ConstraintError> {
+        Self::check(&value, &min, &max)?;
+        Ok(Self { value, min, max })
+    }
+
+    /// Constructs a bounded value, clamping `value` into `[min, max]`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConstraintError::InvalidBounds`] if `min > max`.
+    /// - [`ConstraintError::NotANumber`] if any comparison is undefined (e.g., NaN).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use twine_models::support::constraint::Bounded;
+    ///
+    /// assert_eq!(Bounded::clamped(1.2, 0.0, 1.0).unwrap().into_inner(), 1.0);
+    /// assert_eq!(Bounded::clamped(7, 0, 10).unwrap().into_inner(), 7);
+    /// ```
+    pub fn clamped(value: T, min: T, max: T) -> Result<Self, ConstraintError>
+    where
+        T: Clone,
+    {
+        match Self::check(&value, &min, &max) {
+            Ok(()) => Ok(Self { value, min, max }),
+            Err(ConstraintError::BelowMinimum) => Ok(Self {
+                value: min.clone(),
+                min,
+                max,
+            }),
+            Err(ConstraintError::AboveMaximum) => Ok(Self {
+                value: max.clone(),
+                min,
+                max,
+            }),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Checks that `min ≤ value ≤ max` without constructing a wrapper.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Bounded::new`].
+    pub fn check(value: &T, min: &T, max: &T) -> Result<(), ConstraintError> {
+        match min.partial_cmp(max) {
+            None => return Err(ConstraintError::NotANumber),
+            Some(Ordering::Greater) => return Err(ConstraintError::InvalidBounds),
+            Some(_) => {}
+        }
+
+        match (value.partial_cmp(min), value.partial_cmp(max)) {
+            (None, _) | (_, None) => Err(ConstraintError::NotANumber),
+            (Some(Ordering::Less), _) => Err(ConstraintError::BelowMinimum),
+            (_, Some(Ordering::Greater)) => Err(ConstraintError::AboveMaximum),
+            _ => Ok(()),
+        }
+    }
+
+    /// Constructs a new bounded value that shares this value's interval.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` lies outside the interval.
+    pub fn with_value(&self, value: T) -> Result<Self, ConstraintError>
+    where
+        T: Clone,
+    {
+        Self::new(value, self.min.clone(), self.max.clone())
+    }
+}
+
+impl<T> Bounded<T> {
+    /// Consumes the wrapper and returns the inner value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Returns the lower bound.
+    pub fn min(&self) -> T
+    where
+        T: Copy,
+    {
+        self.min
+    }
+
+    /// Returns the upper bound.
+    pub fn max(&self) -> T
+    where
+        T: Copy,
+    {
+        self.max
+    }
+
+    /// Returns the `(min, max)` interval.
+    pub fn bounds(&self) -> (T, T)
+    where
+        T: Copy,
+    {
+        (self.min, self.max)
+    }
+}
+
+/// Returns a reference to the inner unconstrained value.
+impl<T> AsRef<T> for Bounded<T> {
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uom::si::{
+        f64::{Ratio, ThermodynamicTemperature},
+        ratio::ratio,
+        thermodynamic_temperature::kelvin,
+    };
+
+    #[test]
+    fn integers() {
+        assert!(Bounded::new(5, 0, 10).is_ok());
+        assert!(Bounded::new(0, 0, 10).is_ok());
+        assert!(Bounded::new(10, 0, 10).is_ok());
+        assert!(Bounded::new(3, 3, 3).is_ok());
+        assert_eq!(Bounded::new(-1, 0, 10), Err(ConstraintError::BelowMinimum));
+        assert_eq!(Bounded::new(11, 0, 10), Err(ConstraintError::AboveMaximum));
+        assert_eq!(Bounded::new(5, 10, 0), Err(ConstraintError::InvalidBounds));
+    }
+
+    #[test]
+    fn floats_nan() {
+        assert_eq!(
+            Bounded::new(f64::NAN, 0.0, 1.0),
+            Err(ConstraintError::NotANumber)
+        );
+        assert_eq!(
+            Bounded::new(0.5, f64::NAN, 1.0),
+            Err(ConstraintError::NotANumber)
+        );
+        assert_eq!(
+            Bounded::new(0.5, 0.0, f64::NAN),
+            Err(ConstraintError::NotANumber)
+        );
+    }
+
+    #[test]
+    fn uom_quantities() {
+        let r = |v| Ratio::new::<ratio>(v);
+        let eta = Bounded::new(r(0.8), r(0.4), r(0.95)).unwrap();
+        assert_eq!(eta.bounds(), (r(0.4), r(0.95)));
+        assert_eq!(*eta.as_ref(), r(0.8));
+
+        let t = |v| ThermodynamicTemperature::new::<kelvin>(v);
+        assert_eq!(
+            Bounded::new(t(200.0), t(250.0), t(2000.0)),
+            Err(ConstraintError::BelowMinimum)
+        );
+    }
+
+    #[test]
+    fn with_value_reuses_interval() {
+        let a = Bounded::new(1.0, 0.0, 2.0).unwrap();
+        let b = a.with_value(1.5).unwrap();
+        assert_eq!(b.bounds(), (0.0, 2.0));
+        assert_eq!(a.with_value(3.0), Err(ConstraintError::AboveMaximum));
+    }
+
+    #[test]
+    fn clamped() {
+        assert_eq!(Bounded::clamped(-1, 0, 10).unwrap().into_inner(), 0);
+        assert_eq!(Bounded::clamped(11, 0, 10).unwrap().into_inner(), 10);
+        assert_eq!(Bounded::clamped(4, 0, 10).unwrap().into_inner(), 4);
+        assert_eq!(
+            Bounded::clamped(5, 10, 0),
+            Err(ConstraintError::InvalidBounds)
+        );
+        assert_eq!(
+            Bounded::clamped(f64::NAN, 0.0, 1.0),
+            Err(ConstraintError::NotANumber)
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use core::marker::PhantomData;
+
+use super::{Constraint, ConstraintError};
+
+/// Marker type enforcing that a value satisfies both `C1` and `C2`.
+///
+/// Constraints are checked in order, and the first violation is returned.
+/// Combinators nest, so `And<A, And<B, C>>` requires all three.
+///
+/// # Examples
+///
+/// ```
+/// use twine_models::support::constraint::{
+///     And, Constrained, ConstraintError, NonNegative, NonZero,
+/// };
+///
+/// type Positive = And<NonNegative, NonZero>;
+///
+/// let x = Constrained::<f64, Positive>::new(2.0).unwrap();
+/// assert_eq!(x.into_inner(), 2.0);
+///
+/// assert_eq!(
+///     Constrained::<f64, Positive>::new(-1.0),
+///     Err(ConstraintError::Negative),
+/// );
+/// assert_eq!(
+///     Constrained::<f64, Positive>::new(0.0),
+///     Err(ConstraintError::Zero),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct And<C1, C2>(PhantomData<(C1, C2)>);
+
+impl<T, C1, C2> Constraint<T> for And<C1, C2>
+where
+    C1: Constraint<T>,
+    C2: Constraint<T>,
+{
+    fn check(value: &T) -> Result<(), ConstraintError> {
+        C1::check(value)?;
+        C2::check(value)
+    }
+}
+
+/// Marker type enforcing that a value satisfies `C1` or `C2` (or both).
+///
+/// `C1` is checked first; `C2` is only checked if `C1` fails.
+/// When both fail, the error from `C2` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use twine_models::support::constraint::{
+///     Constrained, Or, StrictlyNegative, UnitInterval,
+/// };
+///
+/// // Either a fraction in [0, 1] or a negative sentinel value.
+/// type FractionOrSentinel = Or<UnitInterval, StrictlyNegative>;
+///
+/// assert!(Constrained::<f64, FractionOrSentinel>::new(0.5).is_ok());
+/// assert!(Constrained::<f64, FractionOrSentinel>::new(-1.0).is_ok());
+/// assert!(Constrained::<f64, FractionOrSentinel>::new(2.0).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Or<C1, C2>(PhantomData<(C1, C2)>);
+
+impl<T, C1, C2> Constraint<T> for Or<C1, C2>
+where
+    C1: Constraint<T>,
+    C2: Constraint<T>,
+{
+    fn check(value: &T) -> Result<(), ConstraintError> {
+        C1::check(value).or_else(|_| C2::check(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uom::si::{f64::MassRate, mass_rate::kilogram_per_second};
+
+    use crate::support::constraint::{
+        Constrained, NonNegative, NonPositive, NonZero, StrictlyPositive, UnitInterval,
+    };
+
+    #[test]
+    fn and_requires_both() {
+        type C = And<NonNegative, UnitInterval>;
+        assert!(Constrained::<f64, C>::new(0.0).is_ok());
+        assert!(Constrained::<f64, C>::new(1.0).is_ok());
+        assert_eq!(
+            Constrained::<f64, C>::new(-0.5),
+            Err(ConstraintError::Negative)
+        );
+        assert_eq!(
+            Constrained::<f64, C>::new(1.5),
+            Err(ConstraintError::AboveMaximum)
+        );
+    }
+
+    #[test]
+    fn or_requires_either() {
+        type C = Or<StrictlyPositive, NonPositive>;
+        assert!(Constrained::<i32, C>::new(5).is_ok());
+        assert!(Constrained::<i32, C>::new(-5).is_ok());
+        assert!(Constrained::<i32, C>::new(0).is_ok());
+        assert_eq!(
+            Constrained::<f64, C>::new(f64::NAN),
+            Err(ConstraintError::NotANumber)
+        );
+    }
+
+    #[test]
+    fn nested_combinators() {
+        type C = And<NonZero, And<NonNegative, UnitInterval>>;
+        assert!(Constrained::<f64, C>::new(0.25).is_ok());
+        assert_eq!(Constrained::<f64, C>::new(0.0), Err(ConstraintError::Zero));
+        assert_eq!(
+            Constrained::<f64, C>::new(2.0),
+            Err(ConstraintError::AboveMaximum)
+        );
+    }
+
+    #[test]
+    fn mass_rates() {
+        type C = And<NonNegative, NonZero>;
+        let m = Constrained::<MassRate, C>::new(MassRate::new::<kilogram_per_second>(1.0));
+        assert!(m.is_ok());
+        assert!(
+            Constrained::<MassRate, C>::new(MassRate::new::<kilogram_per_second>(0.0)).is_err()
+        );
+    }
+}
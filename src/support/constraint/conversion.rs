@@ -0,0 +1,122 @@
+//! Conversions between compatible constraints.
+//!
+//! A value satisfying a narrower constraint always satisfies a wider one,
+//! so widening is an infallible [`From`] conversion that skips re-validation.
+//! The reverse direction is a fallible [`TryFrom`] conversion that re-runs
+//! the narrower constraint's check.
+
+use core::marker::PhantomData;
+
+use num_traits::Zero;
+
+use super::{
+    Constrained, ConstraintError, Finite, IsFinite, NonNegative, NonPositive, NonZero, NotNan,
+    StrictlyNegative, StrictlyPositive, UnitBounds, UnitInterval, UnitIntervalLowerOpen,
+    UnitIntervalOpen, UnitIntervalUpperOpen,
+};
+
+/// Implements `From<Constrained<T, $narrow>>` for `Constrained<T, $wide>` and
+/// the reverse `TryFrom`, for all `T` satisfying the given bounds.
+macro_rules! impl_widening {
+    ($narrow:ty => $wide:ty where T: $($bound:tt)+) => {
+        impl<T: $($bound)+> From<Constrained<T, $narrow>> for Constrained<T, $wide> {
+            fn from(value: Constrained<T, $narrow>) -> Self {
+                Self {
+                    value: value.value,
+                    _marker: PhantomData,
+                }
+            }
+        }
+
+        impl<T: $($bound)+> TryFrom<Constrained<T, $wide>> for Constrained<T, $narrow> {
+            type Error = ConstraintError;
+
+            fn try_from(value: Constrained<T, $wide>) -> Result<Self, Self::Error> {
+                Self::new(value.value)
+            }
+        }
+    };
+}
+
+// Sign constraints.
+impl_widening!(StrictlyPositive => NonNegative where T: PartialOrd + Zero);
+impl_widening!(StrictlyPositive => NonZero where T: PartialOrd + Zero);
+impl_widening!(StrictlyNegative => NonPositive where T: PartialOrd + Zero);
+impl_widening!(StrictlyNegative => NonZero where T: PartialOrd + Zero);
+
+// Unit intervals.
+impl_widening!(UnitIntervalOpen => UnitInterval where T: UnitBounds);
+impl_widening!(UnitIntervalOpen => UnitIntervalLowerOpen where T: UnitBounds);
+impl_widening!(UnitIntervalOpen => UnitIntervalUpperOpen where T: UnitBounds);
+impl_widening!(UnitIntervalLowerOpen => UnitInterval where T: UnitBounds);
+impl_widening!(UnitIntervalUpperOpen => UnitInterval where T: UnitBounds);
+
+// Unit intervals into sign constraints.
+impl_widening!(UnitInterval => NonNegative where T: UnitBounds + Zero);
+impl_widening!(UnitIntervalUpperOpen => NonNegative where T: UnitBounds + Zero);
+impl_widening!(UnitIntervalLowerOpen => NonNegative where T: UnitBounds + Zero);
+impl_widening!(UnitIntervalOpen => NonNegative where T: UnitBounds + Zero);
+impl_widening!(UnitIntervalLowerOpen => StrictlyPositive where T: UnitBounds + Zero);
+impl_widening!(UnitIntervalOpen => StrictlyPositive where T: UnitBounds + Zero);
+
+// Finiteness.
+impl_widening!(Finite => NotNan where T: IsFinite + PartialOrd);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uom::si::{
+        f64::{Power, Ratio},
+        power::watt,
+        ratio::ratio,
+    };
+
+    fn needs_non_negative(value: Constrained<Power, NonNegative>) -> Power {
+        value.into_inner()
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn widening_is_infallible() {
+        let power = StrictlyPositive::new(Power::new::<watt>(10.0)).unwrap();
+        assert_eq!(needs_non_negative(power.into()), Power::new::<watt>(10.0));
+
+        let open = UnitIntervalOpen::new(Ratio::new::<ratio>(0.5)).unwrap();
+        let closed: Constrained<Ratio, UnitInterval> = open.into();
+        assert_eq!(closed.into_inner(), Ratio::new::<ratio>(0.5));
+
+        let fraction = UnitIntervalLowerOpen::new(1.0).unwrap();
+        let positive = Constrained::<f64, StrictlyPositive>::from(fraction);
+        assert_eq!(positive.into_inner(), 1.0);
+
+        let finite = Finite::new(-2.0).unwrap();
+        assert_eq!(Constrained::<f64, NotNan>::from(finite).into_inner(), -2.0);
+    }
+
+    #[test]
+    fn narrowing_revalidates() {
+        let zero = NonNegative::zero::<f64>();
+        assert_eq!(
+            Constrained::<f64, StrictlyPositive>::try_from(zero),
+            Err(ConstraintError::Zero)
+        );
+
+        let one = UnitInterval::one::<f64>();
+        assert_eq!(
+            Constrained::<f64, UnitIntervalUpperOpen>::try_from(one),
+            Err(ConstraintError::AboveMaximum)
+        );
+        assert!(Constrained::<f64, UnitIntervalLowerOpen>::try_from(one).is_ok());
+
+        let large = NonNegative::new(2.0).unwrap();
+        assert_eq!(
+            Constrained::<f64, UnitInterval>::try_from(large),
+            Err(ConstraintError::AboveMaximum)
+        );
+
+        let negative = NonZero::new(-3).unwrap();
+        let narrowed: Constrained<i32, StrictlyNegative> = negative.try_into().unwrap();
+        assert_eq!(narrowed.into_inner(), -3);
+    }
+}
@@ -0,0 +1,137 @@
+use uom::si::{Dimension, Quantity, Units};
+
+use super::{Constrained, Constraint, ConstraintError};
+
+/// Reports whether a value is finite (neither infinite nor `NaN`).
+///
+/// Implement this trait for your type `T` if you want to use it with
+/// `Constrained<T, Finite>`.
+/// We provide implementations for `f32`, `f64`, and all `uom` quantities
+/// with `f64` storage.
+pub trait IsFinite {
+    /// Returns `true` if the value is neither infinite nor `NaN`.
+    fn is_finite(&self) -> bool;
+
+    /// Returns `true` if the value is `NaN`.
+    ///
+    /// Used to distinguish [`ConstraintError::NotANumber`] from
+    /// [`ConstraintError::Infinite`].
+    fn is_nan(&self) -> bool;
+}
+
+impl IsFinite for f32 {
+    fn is_finite(&self) -> bool {
+        f32::is_finite(*self)
+    }
+    fn is_nan(&self) -> bool {
+        f32::is_nan(*self)
+    }
+}
+
+impl IsFinite for f64 {
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+    fn is_nan(&self) -> bool {
+        f64::is_nan(*self)
+    }
+}
+
+impl<D, U> IsFinite for Quantity<D, U, f64>
+where
+    D: Dimension + ?Sized,
+    U: Units<f64> + ?Sized,
+{
+    fn is_finite(&self) -> bool {
+        self.value.is_finite()
+    }
+    fn is_nan(&self) -> bool {
+        self.value.is_nan()
+    }
+}
+
+/// Marker type enforcing that a value is finite (neither infinite nor `NaN`).
+///
+/// Use this type with [`Constrained<T, Finite>`] for inputs that may take any
+/// sign but must be a real number, such as temperatures or signed heat rates
+/// passed to a solver. Requires `T: IsFinite`.
+///
+/// # Examples
+///
+/// ```
+/// use twine_models::support::constraint::{Constrained, ConstraintError, Finite};
+///
+/// let x = Finite::new(-3.5).unwrap();
+/// assert_eq!(x.into_inner(), -3.5);
+///
+/// assert_eq!(Finite::new(f64::INFINITY), Err(ConstraintError::Infinite));
+/// assert_eq!(Finite::new(f64::NAN), Err(ConstraintError::NotANumber));
+///
+/// // Combine with other markers for stricter checks:
+/// use twine_models::support::constraint::{And, StrictlyPositive};
+/// assert!(Constrained::<f64, And<Finite, StrictlyPositive>>::new(2.0).is_ok());
+/// assert!(Constrained::<f64, And<Finite, StrictlyPositive>>::new(f64::INFINITY).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Finite;
+
+impl Finite {
+    /// Constructs a [`Constrained<T, Finite>`] if the value is finite.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConstraintError::Infinite`] if the value is positive or negative infinity.
+    /// - [`ConstraintError::NotANumber`] if the value is `NaN`.
+    pub fn new<T: IsFinite>(value: T) -> Result<Constrained<T, Finite>, ConstraintError> {
+        Constrained::<T, Finite>::new(value)
+    }
+}
+
+impl<T: IsFinite> Constraint<T> for Finite {
+    fn check(value: &T) -> Result<(), ConstraintError> {
+        if value.is_finite() {
+            Ok(())
+        } else if value.is_nan() {
+            Err(ConstraintError::NotANumber)
+        } else {
+            Err(ConstraintError::Infinite)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uom::si::{
+        f64::{Power, ThermodynamicTemperature},
+        power::watt,
+        thermodynamic_temperature::degree_celsius,
+    };
+
+    #[test]
+    fn floats() {
+        assert!(Finite::new(0.0).is_ok());
+        assert!(Finite::new(-1e300).is_ok());
+        assert!(Finite::new(1.0_f32).is_ok());
+        assert_eq!(
+            Finite::new(f64::NEG_INFINITY),
+            Err(ConstraintError::Infinite)
+        );
+        assert_eq!(Finite::new(f32::INFINITY), Err(ConstraintError::Infinite));
+        assert_eq!(Finite::new(f32::NAN), Err(ConstraintError::NotANumber));
+    }
+
+    #[test]
+    fn quantities() {
+        let t = ThermodynamicTemperature::new::<degree_celsius>(-40.0);
+        assert!(Finite::new(t).is_ok());
+
+        let q = Power::new::<watt>(f64::INFINITY);
+        assert_eq!(Finite::new(q), Err(ConstraintError::Infinite));
+        assert_eq!(
+            Finite::new(Power::new::<watt>(f64::NAN)),
+            Err(ConstraintError::NotANumber)
+        );
+    }
+}
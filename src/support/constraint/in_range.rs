@@ -0,0 +1,123 @@
+use super::{Constrained, Constraint, ConstraintError};
+
+/// Marker type enforcing that an integer lies in the closed range `MIN..=MAX`.
+///
+/// Use this type with [`Constrained<T, InRange<MIN, MAX>>`] for discrete
+/// counts such as node counts, pass counts, or tube rows. Works with any
+/// primitive integer type; the comparison is done in `i128` so unsigned and
+/// signed values are handled uniformly.
+///
+/// A range with `MIN > MAX` is rejected at compile time when a value is
+/// constructed:
+///
+/// ```compile_fail
+/// use twine_models::support::constraint::InRange;
+///
+/// let _ = InRange::<5, 1>::new(3);
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use twine_models::support::constraint::{Constrained, ConstraintError, InRange};
+///
+/// type PassCount = Constrained<usize, InRange<1, 8>>;
+///
+/// let passes = PassCount::new(2).unwrap();
+/// assert_eq!(passes.into_inner(), 2);
+///
+/// // Associated constructor:
+/// let rows = InRange::<1, 100>::new(12_u32).unwrap();
+/// assert_eq!(rows.into_inner(), 12);
+///
+/// // Error cases:
+/// assert_eq!(PassCount::new(0), Err(ConstraintError::BelowMinimum));
+/// assert_eq!(PassCount::new(9), Err(ConstraintError::AboveMaximum));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InRange<const MIN: i64, const MAX: i64>;
+
+impl<const MIN: i64, const MAX: i64> InRange<MIN, MAX> {
+    /// The smallest allowed value.
+    pub const MIN: i64 = MIN;
+
+    /// The largest allowed value.
+    pub const MAX: i64 = MAX;
+
+    const VALID_RANGE: () = assert!(MIN <= MAX, "InRange requires MIN <= MAX");
+
+    /// Constructs a [`Constrained<T, InRange<MIN, MAX>>`] if `MIN ≤ value ≤ MAX`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConstraintError::BelowMinimum`] if the value is less than `MIN`.
+    /// - [`ConstraintError::AboveMaximum`] if the value is greater than `MAX`.
+    pub fn new<T: Copy + TryInto<i128>>(
+        value: T,
+    ) -> Result<Constrained<T, InRange<MIN, MAX>>, ConstraintError> {
+        Constrained::new(value)
+    }
+}
+
+impl<T, const MIN: i64, const MAX: i64> Constraint<T> for InRange<MIN, MAX>
+where
+    T: Copy + TryInto<i128>,
+{
+    fn check(value: &T) -> Result<(), ConstraintError> {
+        let () = Self::VALID_RANGE;
+
+        // Only `u128` values beyond `i128::MAX` fail to convert, and those
+        // are above any `i64` bound.
+        let Ok(value) = (*value).try_into() else {
+            return Err(ConstraintError::AboveMaximum);
+        };
+
+        if value < i128::from(MIN) {
+            Err(ConstraintError::BelowMinimum)
+        } else if value > i128::from(MAX) {
+            Err(ConstraintError::AboveMaximum)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signed_integers() {
+        type Offset = InRange<-3, 3>;
+
+        assert!(Offset::new(-3_i32).is_ok());
+        assert!(Offset::new(0_i8).is_ok());
+        assert!(Offset::new(3_i64).is_ok());
+        assert_eq!(Offset::new(-4_i16), Err(ConstraintError::BelowMinimum));
+        assert_eq!(Offset::new(4_i32), Err(ConstraintError::AboveMaximum));
+    }
+
+    #[test]
+    fn unsigned_integers() {
+        type NodeCount = InRange<2, 1000>;
+
+        assert!(NodeCount::new(2_usize).is_ok());
+        assert_eq!(NodeCount::new(1_u8), Err(ConstraintError::BelowMinimum));
+        assert_eq!(NodeCount::new(1001_u64), Err(ConstraintError::AboveMaximum));
+        assert_eq!(
+            NodeCount::new(u128::MAX),
+            Err(ConstraintError::AboveMaximum)
+        );
+        assert_eq!(
+            NodeCount::new(usize::MAX),
+            Err(ConstraintError::AboveMaximum)
+        );
+    }
+
+    #[test]
+    fn single_value_range() {
+        assert_eq!(InRange::<5, 5>::new(5).unwrap().into_inner(), 5);
+        assert!(InRange::<5, 5>::new(4).is_err());
+        assert_eq!(InRange::<5, 5>::MIN, InRange::<5, 5>::MAX);
+    }
+}
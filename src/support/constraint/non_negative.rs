@@ -1,8 +1,12 @@
-use std::{cmp::Ordering, marker::PhantomData, ops::Add};
+use core::{
+    cmp::Ordering,
+    marker::PhantomData,
+    ops::{Add, Div},
+};
 
 use num_traits::Zero;
 
-use super::{Constrained, Constraint, ConstraintError};
+use super::{Constrained, Constraint, ConstraintError, StrictlyPositive};
 
 /// Marker type enforcing that a value is non-negative (zero or greater).
 ///
@@ -52,6 +56,39 @@ impl NonNegative {
     pub fn zero<T: PartialOrd + Zero>() -> Constrained<T, NonNegative> {
         Constrained::<T, NonNegative>::zero()
     }
+
+    /// Constructs a [`Constrained<T, NonNegative>`], clamping negative values to zero.
+    ///
+    /// Use this when small negative values are expected from floating-point
+    /// noise (e.g., a solver returning `-1e-15` for a quantity that is
+    /// physically zero). Any negative value is clamped, regardless of size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConstraintError::NotANumber`] if the value is not comparable
+    /// to zero (e.g., `NaN`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use twine_models::support::constraint::NonNegative;
+    ///
+    /// assert_eq!(NonNegative::clamped(-0.001).unwrap().into_inner(), 0.0);
+    /// assert_eq!(NonNegative::clamped(2.5).unwrap().into_inner(), 2.5);
+    /// assert!(NonNegative::clamped(f64::NAN).is_err());
+    /// ```
+    pub fn clamped<T: PartialOrd + Zero>(
+        value: T,
+    ) -> Result<Constrained<T, NonNegative>, ConstraintError> {
+        match value.partial_cmp(&T::zero()) {
+            Some(Ordering::Less) => Ok(Self::zero()),
+            Some(_) => Ok(Constrained {
+                value,
+                _marker: PhantomData,
+            }),
+            None => Err(ConstraintError::NotANumber),
+        }
+    }
 }
 
 impl<T: PartialOrd + Zero> Constraint<T> for NonNegative {
@@ -109,11 +146,48 @@ where
     }
 }
 
+/// Divides a `NonNegative` value by a `StrictlyPositive` one, preserving
+/// non-negativity.
+///
+/// The operands may have different types, so dividing a non-negative `Power`
+/// by a positive `CapacitanceRate` quantity yields a non-negative
+/// `TemperatureInterval`.
+/// The invariant is checked in debug builds.
+///
+/// # Panics
+///
+/// Panics in debug builds if the quotient is unexpectedly negative.
+impl<T, U> Div<Constrained<U, StrictlyPositive>> for Constrained<T, NonNegative>
+where
+    T: Div<U> + PartialOrd + Zero,
+    U: PartialOrd + Zero,
+    <T as Div<U>>::Output: PartialOrd + Zero,
+{
+    type Output = Constrained<<T as Div<U>>::Output, NonNegative>;
+
+    fn div(self, rhs: Constrained<U, StrictlyPositive>) -> Self::Output {
+        let value = self.value / rhs.value;
+        debug_assert!(
+            value >= <T as Div<U>>::Output::zero(),
+            "Division produced a negative value, violating NonNegative bound invariant"
+        );
+        Constrained {
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use uom::si::{f64::MassRate, mass_rate::kilogram_per_second};
+    use uom::si::{
+        f64::{Mass, MassRate, Time},
+        mass::kilogram,
+        mass_rate::kilogram_per_second,
+        time::second,
+    };
 
     #[test]
     fn integers() {
@@ -151,4 +225,57 @@ mod tests {
         let mass_rate = MassRate::new::<kilogram_per_second>(-2.0);
         assert!(NonNegative::new(mass_rate).is_err());
     }
+
+    #[test]
+    fn arithmetic() {
+        let mass = NonNegative::new(Mass::new::<kilogram>(6.0)).unwrap();
+        let dt = StrictlyPositive::new(Time::new::<second>(2.0)).unwrap();
+
+        let m_dot: Constrained<MassRate, NonNegative> = mass / dt;
+        assert_eq!(
+            m_dot.into_inner(),
+            MassRate::new::<kilogram_per_second>(3.0)
+        );
+        assert_eq!(
+            (NonNegative::zero::<Mass>() / dt).into_inner(),
+            MassRate::new::<kilogram_per_second>(0.0)
+        );
+
+        let doubled = (m_dot * 2.0).unwrap();
+        assert_eq!(doubled - m_dot, MassRate::new::<kilogram_per_second>(3.0));
+        assert_eq!(
+            (m_dot / 3.0).unwrap().into_inner(),
+            MassRate::new::<kilogram_per_second>(1.0)
+        );
+        assert_eq!(m_dot * -1.0, Err(ConstraintError::Negative));
+    }
+
+    #[test]
+    fn error_context() {
+        let err = Constrained::<MassRate, NonNegative>::new_with_context(MassRate::new::<
+            kilogram_per_second,
+        >(-0.5))
+        .unwrap_err();
+
+        assert_eq!(err.kind(), ConstraintError::Negative);
+        let message = err.to_string();
+        assert!(message.starts_with("NonNegative constraint violated by -0.5"));
+        assert!(message.ends_with("value must not be negative"));
+    }
+
+    #[test]
+    fn clamped() {
+        assert_eq!(NonNegative::clamped(-3).unwrap().into_inner(), 0);
+        assert_eq!(NonNegative::clamped(3).unwrap().into_inner(), 3);
+
+        let tiny = MassRate::new::<kilogram_per_second>(-1e-15);
+        assert_eq!(
+            NonNegative::clamped(tiny).unwrap().into_inner(),
+            MassRate::new::<kilogram_per_second>(0.0)
+        );
+        assert_eq!(
+            NonNegative::clamped(f64::NAN),
+            Err(ConstraintError::NotANumber)
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use std::{cmp::Ordering, marker::PhantomData, ops::Add};
+use core::{cmp::Ordering, marker::PhantomData, ops::Add};
 
 use num_traits::Zero;
 
@@ -52,6 +52,36 @@ impl NonPositive {
     pub fn zero<T: PartialOrd + Zero>() -> Constrained<T, NonPositive> {
         Constrained::<T, NonPositive>::zero()
     }
+
+    /// Constructs a [`Constrained<T, NonPositive>`], clamping positive values to zero.
+    ///
+    /// Any positive value is clamped, regardless of size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConstraintError::NotANumber`] if the value is not comparable
+    /// to zero (e.g., `NaN`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use twine_models::support::constraint::NonPositive;
+    ///
+    /// assert_eq!(NonPositive::clamped(1e-12).unwrap().into_inner(), 0.0);
+    /// assert_eq!(NonPositive::clamped(-4.0).unwrap().into_inner(), -4.0);
+    /// ```
+    pub fn clamped<T: PartialOrd + Zero>(
+        value: T,
+    ) -> Result<Constrained<T, NonPositive>, ConstraintError> {
+        match value.partial_cmp(&T::zero()) {
+            Some(Ordering::Greater) => Ok(Self::zero()),
+            Some(_) => Ok(Constrained {
+                value,
+                _marker: PhantomData,
+            }),
+            None => Err(ConstraintError::NotANumber),
+        }
+    }
 }
 
 impl<T: PartialOrd + Zero> Constraint<T> for NonPositive {
@@ -151,4 +181,14 @@ mod tests {
         let pos_mass_rate = Power::new::<watt>(2.0);
         assert!(NonPositive::new(pos_mass_rate).is_err());
     }
+
+    #[test]
+    fn clamped() {
+        assert_eq!(NonPositive::clamped(5).unwrap().into_inner(), 0);
+        assert_eq!(NonPositive::clamped(-5).unwrap().into_inner(), -5);
+        assert_eq!(
+            NonPositive::clamped(f64::NAN),
+            Err(ConstraintError::NotANumber)
+        );
+    }
 }
@@ -0,0 +1,71 @@
+use super::{Constrained, Constraint, ConstraintError};
+
+/// Marker type enforcing that a value is not `NaN`.
+///
+/// A value is considered `NaN` if it is not comparable to itself, so this
+/// marker works with any `PartialOrd` type, including `uom` quantities.
+/// Infinite values are accepted; use [`Finite`](super::Finite) to reject them.
+///
+/// # Examples
+///
+/// ```
+/// use twine_models::support::constraint::{Constrained, NotNan};
+///
+/// let x = Constrained::<_, NotNan>::new(f64::INFINITY).unwrap();
+/// assert_eq!(x.into_inner(), f64::INFINITY);
+///
+/// let y = NotNan::new(-2.0).unwrap();
+/// assert_eq!(y.into_inner(), -2.0);
+///
+/// assert!(NotNan::new(f64::NAN).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NotNan;
+
+impl NotNan {
+    /// Constructs a [`Constrained<T, NotNan>`] if the value is not `NaN`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConstraintError::NotANumber`] if the value is not comparable
+    /// to itself.
+    pub fn new<T: PartialOrd>(value: T) -> Result<Constrained<T, NotNan>, ConstraintError> {
+        Constrained::<T, NotNan>::new(value)
+    }
+}
+
+impl<T: PartialOrd> Constraint<T> for NotNan {
+    fn check(value: &T) -> Result<(), ConstraintError> {
+        if value.partial_cmp(value).is_some() {
+            Ok(())
+        } else {
+            Err(ConstraintError::NotANumber)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uom::si::{f64::MassRate, mass_rate::kilogram_per_second};
+
+    #[test]
+    fn integers() {
+        assert_eq!(NotNan::new(-7).unwrap().into_inner(), -7);
+    }
+
+    #[test]
+    fn floats() {
+        assert!(NotNan::new(0.0).is_ok());
+        assert!(NotNan::new(f64::NEG_INFINITY).is_ok());
+        assert_eq!(NotNan::new(f64::NAN), Err(ConstraintError::NotANumber));
+    }
+
+    #[test]
+    fn quantities() {
+        let m = MassRate::new::<kilogram_per_second>(f64::NAN);
+        assert_eq!(NotNan::new(m), Err(ConstraintError::NotANumber));
+        assert!(NotNan::new(MassRate::new::<kilogram_per_second>(1.0)).is_ok());
+    }
+}
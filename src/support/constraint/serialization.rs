@@ -0,0 +1,88 @@
+//! Serde support for [`Constrained`] values.
+//!
+//! A constrained value serializes as its inner value. Deserialization reads
+//! the inner value and re-validates it through [`Constraint::check`], so
+//! invalid data is rejected instead of producing a value that breaks the
+//! constraint.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use super::{Constrained, Constraint, constraint_name};
+
+impl<T, C> Serialize for Constrained<T, C>
+where
+    T: Serialize,
+    C: Constraint<T>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de, T, C> Deserialize<'de> for Constrained<T, C>
+where
+    T: Deserialize<'de>,
+    C: Constraint<T>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = T::deserialize(deserializer)?;
+        Self::new(value).map_err(|err| {
+            de::Error::custom(format_args!(
+                "invalid {} value: {err}",
+                constraint_name::<C>()
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uom::si::{f64::Power, power::watt};
+
+    use crate::support::constraint::{NonNegative, StrictlyPositive, UnitInterval};
+
+    #[test]
+    fn round_trips_through_json() {
+        let x = UnitInterval::new(0.25).unwrap();
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!(json, "0.25");
+
+        let back: Constrained<f64, UnitInterval> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, x);
+    }
+
+    #[test]
+    fn round_trips_quantities() {
+        let power = StrictlyPositive::new(Power::new::<watt>(1500.0)).unwrap();
+        let json = serde_json::to_string(&power).unwrap();
+
+        let back: Constrained<Power, StrictlyPositive> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, power);
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        let err = serde_json::from_str::<Constrained<f64, NonNegative>>("-1.0").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid NonNegative value: value must not be negative"
+        );
+
+        let err = serde_json::from_str::<Constrained<f64, UnitInterval>>("1.5").unwrap_err();
+        assert!(err.to_string().contains("UnitInterval"));
+    }
+
+    #[test]
+    fn rejects_invalid_fields_in_structs() {
+        #[derive(Debug, serde::Deserialize)]
+        #[allow(dead_code)]
+        struct Params {
+            ua: Constrained<f64, StrictlyPositive>,
+        }
+
+        let err = serde_json::from_str::<Params>(r#"{"ua": 0.0}"#).unwrap_err();
+        assert!(err.to_string().contains("value must not be zero"));
+    }
+}
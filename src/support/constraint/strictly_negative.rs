@@ -1,4 +1,4 @@
-use std::{cmp::Ordering, marker::PhantomData, ops::Add};
+use core::{cmp::Ordering, marker::PhantomData, ops::Add};
 
 use num_traits::Zero;
 
@@ -52,7 +52,7 @@ impl<T: PartialOrd + Zero> Constraint<T> for StrictlyNegative {
         match value.partial_cmp(&T::zero()) {
             Some(Ordering::Less) => Ok(()),
             Some(Ordering::Equal) => Err(ConstraintError::Zero),
-            Some(Ordering::Greater) => Err(ConstraintError::Negative),
+            Some(Ordering::Greater) => Err(ConstraintError::Positive),
             None => Err(ConstraintError::NotANumber),
         }
     }
@@ -100,7 +100,7 @@ mod tests {
         assert_eq!(y.as_ref(), &-42);
 
         assert!(StrictlyNegative::new(0).is_err());
-        assert!(StrictlyNegative::new(2).is_err());
+        assert_eq!(StrictlyNegative::new(2), Err(ConstraintError::Positive));
     }
 
     #[test]
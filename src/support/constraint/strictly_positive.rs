@@ -1,8 +1,13 @@
-use std::{cmp::Ordering, marker::PhantomData, ops::Add};
+use core::{
+    cmp::Ordering,
+    iter::Product,
+    marker::PhantomData,
+    ops::{Add, Div, Mul},
+};
 
-use num_traits::Zero;
+use num_traits::{One, Zero};
 
-use super::{Constrained, Constraint, ConstraintError};
+use super::{Constrained, Constraint, ConstraintError, ConstraintResult};
 
 /// Marker type enforcing that a value is strictly positive (greater than zero).
 ///
@@ -87,11 +92,77 @@ where
     }
 }
 
+/// Multiplies two `StrictlyPositive` values.
+///
+/// The operands may have different types, so multiplying a positive
+/// `MassRate` by a positive `SpecificHeatCapacity` yields a positive
+/// `ThermalConductance`.
+///
+/// The product of two positive values can still leave the bound, through
+/// floating-point underflow or integer overflow, so it is re-checked and
+/// returned as a [`ConstraintResult`].
+impl<T, U> Mul<Constrained<U, StrictlyPositive>> for Constrained<T, StrictlyPositive>
+where
+    T: Mul<U> + PartialOrd + Zero,
+    U: PartialOrd + Zero,
+    <T as Mul<U>>::Output: PartialOrd + Zero,
+{
+    type Output = ConstraintResult<Constrained<<T as Mul<U>>::Output, StrictlyPositive>>;
+
+    fn mul(self, rhs: Constrained<U, StrictlyPositive>) -> Self::Output {
+        Constrained::new(self.value * rhs.value)
+    }
+}
+
+/// Multiplies an iterator of `StrictlyPositive` values into a checked result.
+///
+/// The empty product is one. Fails as soon as a partial product leaves the
+/// bound.
+impl<T> Product<Constrained<T, StrictlyPositive>>
+    for ConstraintResult<Constrained<T, StrictlyPositive>>
+where
+    T: Mul<Output = T> + PartialOrd + Zero + One,
+{
+    fn product<I: Iterator<Item = Constrained<T, StrictlyPositive>>>(mut iter: I) -> Self {
+        iter.try_fold(Constrained::new(T::one())?, |product, factor| {
+            product * factor
+        })
+    }
+}
+
+/// Divides two `StrictlyPositive` values.
+///
+/// The operands may have different types, so dividing a positive `Power` by a
+/// positive `ThermalConductance` yields a positive `TemperatureInterval`.
+///
+/// The quotient of two positive values can still reach zero, through
+/// integer truncation (`1 / 2`) or floating-point underflow, so it is
+/// re-checked and returned as a [`ConstraintResult`].
+impl<T, U> Div<Constrained<U, StrictlyPositive>> for Constrained<T, StrictlyPositive>
+where
+    T: Div<U> + PartialOrd + Zero,
+    U: PartialOrd + Zero,
+    <T as Div<U>>::Output: PartialOrd + Zero,
+{
+    type Output = ConstraintResult<Constrained<<T as Div<U>>::Output, StrictlyPositive>>;
+
+    fn div(self, rhs: Constrained<U, StrictlyPositive>) -> Self::Output {
+        Constrained::new(self.value / rhs.value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use uom::si::{f64::MassRate, mass_rate::kilogram_per_second};
+    use uom::si::{
+        f64::{MassRate, Power, SpecificHeatCapacity, TemperatureInterval, ThermalConductance},
+        mass_rate::kilogram_per_second,
+        power::watt,
+        specific_heat_capacity::joule_per_kilogram_kelvin,
+        temperature_interval::kelvin,
+        thermal_conductance::watt_per_kelvin,
+    };
 
     #[test]
     fn integers() {
@@ -125,4 +196,98 @@ mod tests {
         let mass_rate = MassRate::new::<kilogram_per_second>(-2.0);
         assert!(StrictlyPositive::new(mass_rate).is_err());
     }
+
+    #[test]
+    fn arithmetic() {
+        let q = StrictlyPositive::new(Power::new::<watt>(100.0)).unwrap();
+        let c = StrictlyPositive::new(ThermalConductance::new::<watt_per_kelvin>(20.0)).unwrap();
+
+        let dt = (q / c).unwrap();
+        assert_eq!(dt.into_inner(), TemperatureInterval::new::<kelvin>(5.0));
+
+        let one = StrictlyPositive::new(1).unwrap();
+        let two = StrictlyPositive::new(2).unwrap();
+        assert_eq!(one / two, Err(ConstraintError::Zero));
+
+        let tiny = StrictlyPositive::new(f64::MIN_POSITIVE).unwrap();
+        let huge = StrictlyPositive::new(f64::MAX).unwrap();
+        assert_eq!(tiny / huge, Err(ConstraintError::Zero));
+
+        let half = (q * 0.5).unwrap();
+        assert_eq!(half.into_inner(), Power::new::<watt>(50.0));
+        assert!((q * 0.0).is_err());
+        assert!((q / -2.0).is_err());
+
+        assert_eq!(half - q, Power::new::<watt>(-50.0));
+    }
+
+    #[test]
+    fn map_revalidates() {
+        #[derive(Debug, PartialEq)]
+        enum ModelError {
+            Constraint(ConstraintError),
+            Overloaded,
+        }
+        impl From<ConstraintError> for ModelError {
+            fn from(err: ConstraintError) -> Self {
+                Self::Constraint(err)
+            }
+        }
+
+        let q = StrictlyPositive::new(Power::new::<watt>(100.0)).unwrap();
+        let dt = TemperatureInterval::new::<kelvin>(4.0);
+
+        let ua = q.map(|q| q / dt).unwrap();
+        assert_eq!(
+            ua.into_inner(),
+            ThermalConductance::new::<watt_per_kelvin>(25.0)
+        );
+
+        assert_eq!(q.map(|q| -q), Err(ConstraintError::Negative));
+
+        let limit = Power::new::<watt>(50.0);
+        let checked = |q: Power| {
+            if q > limit {
+                Err(ModelError::Overloaded)
+            } else {
+                Ok(q - limit)
+            }
+        };
+        assert_eq!(q.try_map(checked), Err(ModelError::Overloaded));
+        let half = (q * 0.5).unwrap();
+        assert_eq!(
+            half.try_map(checked),
+            Err(ModelError::Constraint(ConstraintError::Zero))
+        );
+    }
+
+    #[test]
+    fn products() {
+        let m_dot = StrictlyPositive::new(MassRate::new::<kilogram_per_second>(2.0)).unwrap();
+        let cp = StrictlyPositive::new(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+            4000.0,
+        ))
+        .unwrap();
+        let c = (m_dot * cp).unwrap();
+        assert_eq!(
+            c.into_inner(),
+            ThermalConductance::new::<watt_per_kelvin>(8000.0)
+        );
+
+        let tiny = StrictlyPositive::new(f64::MIN_POSITIVE).unwrap();
+        assert_eq!(tiny * tiny, Err(ConstraintError::Zero));
+
+        let factors = [2, 3, 4].map(|x| StrictlyPositive::new(x).unwrap());
+        let product: ConstraintResult<Constrained<i32, StrictlyPositive>> =
+            factors.into_iter().product();
+        assert_eq!(product.unwrap().into_inner(), 24);
+
+        let underflow: ConstraintResult<Constrained<f64, StrictlyPositive>> =
+            [tiny, tiny].into_iter().product();
+        assert_eq!(underflow, Err(ConstraintError::Zero));
+
+        let empty: ConstraintResult<Constrained<f64, StrictlyPositive>> =
+            core::iter::empty().product();
+        assert_eq!(empty, StrictlyPositive::new(1.0));
+    }
 }
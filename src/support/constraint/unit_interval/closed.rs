@@ -1,4 +1,6 @@
-use std::{cmp::Ordering, marker::PhantomData};
+use core::{cmp::Ordering, marker::PhantomData, ops::Mul};
+
+use num_traits::One;
 
 use crate::support::constraint::{Constrained, Constraint, ConstraintError, UnitBounds};
 
@@ -87,6 +89,40 @@ impl UnitInterval {
             _marker: PhantomData,
         }
     }
+
+    /// Constructs `Constrained<T, UnitInterval>`, clamping the value into `[0, 1]`.
+    ///
+    /// Values below zero become zero and values above one become one.
+    /// Use this for fractions that may drift slightly outside the interval
+    /// from floating-point noise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConstraintError::NotANumber`] if comparison is undefined (e.g., NaN).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use twine_models::support::constraint::UnitInterval;
+    ///
+    /// assert_eq!(UnitInterval::clamped(1.2).unwrap().into_inner(), 1.0);
+    /// assert_eq!(UnitInterval::clamped(-0.01).unwrap().into_inner(), 0.0);
+    /// assert_eq!(UnitInterval::clamped(0.3).unwrap().into_inner(), 0.3);
+    /// assert!(UnitInterval::clamped(f64::NAN).is_err());
+    /// ```
+    pub fn clamped<T: UnitBounds>(
+        value: T,
+    ) -> Result<Constrained<T, UnitInterval>, ConstraintError> {
+        match (value.partial_cmp(&T::zero()), value.partial_cmp(&T::one())) {
+            (None, _) | (_, None) => Err(ConstraintError::NotANumber),
+            (Some(Ordering::Less), _) => Ok(Self::zero()),
+            (_, Some(Ordering::Greater)) => Ok(Self::one()),
+            _ => Ok(Constrained {
+                value,
+                _marker: PhantomData,
+            }),
+        }
+    }
 }
 
 impl<T: UnitBounds> Constraint<T> for UnitInterval {
@@ -100,10 +136,48 @@ impl<T: UnitBounds> Constraint<T> for UnitInterval {
     }
 }
 
+/// Multiplies two `Constrained<T, UnitInterval>` values.
+///
+/// The product of two values in `[0, 1]` is also in `[0, 1]`, so cascaded
+/// efficiencies (e.g., collector × piping × heat exchanger) stay typed.
+/// The invariant is checked in debug builds.
+///
+/// # Panics
+///
+/// Panics in debug builds if the product is unexpectedly outside `[0, 1]`.
+impl<T> Mul for Constrained<T, UnitInterval>
+where
+    T: UnitBounds + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let value = self.value * rhs.value;
+        debug_assert!(
+            value >= T::zero() && value <= T::one(),
+            "Multiplication produced a value outside [0, 1], violating UnitInterval bound invariant"
+        );
+        Self {
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> One for Constrained<T, UnitInterval>
+where
+    T: UnitBounds + Mul<Output = T>,
+{
+    fn one() -> Self {
+        UnitInterval::one()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::support::constraint::*;
 
+    use approx::assert_relative_eq;
     use uom::si::{
         f64::Ratio,
         ratio::{percent, ratio},
@@ -186,4 +260,40 @@ mod tests {
             Err(ConstraintError::AboveMaximum)
         ));
     }
+
+    #[test]
+    fn clamped() {
+        let over = UnitInterval::clamped(Ratio::new::<ratio>(1.0 + 1e-12)).unwrap();
+        assert_eq!(over.into_inner(), Ratio::new::<ratio>(1.0));
+
+        let under = UnitInterval::clamped(Ratio::new::<percent>(-5.0)).unwrap();
+        assert_eq!(under.into_inner(), Ratio::new::<ratio>(0.0));
+
+        let inside = UnitInterval::clamped(Ratio::new::<percent>(40.0)).unwrap();
+        assert_eq!(inside.into_inner(), Ratio::new::<percent>(40.0));
+
+        assert_eq!(
+            UnitInterval::clamped(f64::NAN),
+            Err(ConstraintError::NotANumber)
+        );
+    }
+
+    #[test]
+    fn products() {
+        let collector = UnitInterval::new(Ratio::new::<percent>(60.0)).unwrap();
+        let piping = UnitInterval::new(Ratio::new::<percent>(95.0)).unwrap();
+        let hx = UnitInterval::new(Ratio::new::<percent>(80.0)).unwrap();
+
+        let overall = collector * piping * hx;
+        assert_relative_eq!(overall.into_inner().get::<ratio>(), 0.456);
+
+        let product: Constrained<f64, UnitInterval> = [0.5, 0.5, 0.5]
+            .into_iter()
+            .map(|x| UnitInterval::new(x).unwrap())
+            .product();
+        assert_relative_eq!(product.into_inner(), 0.125);
+
+        let empty: Constrained<f64, UnitInterval> = core::iter::empty().product();
+        assert_eq!(empty, UnitInterval::one());
+    }
 }
@@ -1,4 +1,4 @@
-use std::{cmp::Ordering, marker::PhantomData};
+use core::{cmp::Ordering, marker::PhantomData};
 
 use crate::support::constraint::{Constrained, Constraint, ConstraintError};
 
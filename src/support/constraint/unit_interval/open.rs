@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 use crate::support::constraint::{Constrained, Constraint, ConstraintError};
 
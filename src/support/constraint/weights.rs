@@ -0,0 +1,237 @@
+use core::ops::Mul;
+
+use super::ConstraintError;
+
+/// Non-negative fractions that sum to one.
+///
+/// `Weights` validates a convex combination: every entry is a finite,
+/// non-negative fraction and the entries sum to one within
+/// [`Weights::DEFAULT_TOLERANCE`]. Use it for flow splits, mixture
+/// compositions, or any other set of fractions that must account for a
+/// whole.
+///
+/// The storage `S` can be a fixed-size array (`[f64; N]`), a `Vec<f64>`, or
+/// any other type that derefs to a slice of `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use twine_models::support::constraint::{ConstraintError, Weights};
+///
+/// // Fixed-size weights:
+/// let split = Weights::new([0.25, 0.75]).unwrap();
+/// assert_eq!(split.as_slice(), &[0.25, 0.75]);
+///
+/// // Dynamically sized weights built from raw proportions:
+/// let composition = Weights::normalized(vec![2.0, 1.0, 1.0]).unwrap();
+/// assert_eq!(composition.as_slice(), &[0.5, 0.25, 0.25]);
+///
+/// // Error cases:
+/// assert_eq!(Weights::new([0.5, 0.6]), Err(ConstraintError::NotNormalized));
+/// assert_eq!(Weights::new([1.5, -0.5]), Err(ConstraintError::Negative));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Weights<S> {
+    values: S,
+}
+
+impl<S: AsRef<[f64]>> Weights<S> {
+    /// Tolerance on the sum used by [`Weights::new`].
+    pub const DEFAULT_TOLERANCE: f64 = 1e-9;
+
+    /// Constructs weights if the entries are non-negative and sum to one.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConstraintError::NotANumber`] if any entry is `NaN`.
+    /// - [`ConstraintError::Infinite`] if any entry is infinite.
+    /// - [`ConstraintError::Negative`] if any entry is negative.
+    /// - [`ConstraintError::NotNormalized`] if the entries do not sum to one
+    ///   within [`Weights::DEFAULT_TOLERANCE`] (including when there are no entries).
+    pub fn new(values: S) -> Result<Self, ConstraintError> {
+        Self::with_tolerance(values, Self::DEFAULT_TOLERANCE)
+    }
+
+    /// Constructs weights, allowing the sum to differ from one by `tolerance`.
+    ///
+    /// The entries are stored as given; use [`Weights::normalized`] to rescale
+    /// them to sum to exactly one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Weights::new`].
+    pub fn with_tolerance(values: S, tolerance: f64) -> Result<Self, ConstraintError> {
+        let sum = checked_sum(values.as_ref())?;
+        if (sum - 1.0).abs() <= tolerance {
+            Ok(Self { values })
+        } else {
+            Err(ConstraintError::NotNormalized)
+        }
+    }
+
+    /// Returns the weights as a slice.
+    #[must_use]
+    pub fn as_slice(&self) -> &[f64] {
+        self.values.as_ref()
+    }
+
+    /// Returns the weight at `index`, or `None` if out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<f64> {
+        self.as_slice().get(index).copied()
+    }
+
+    /// Returns an iterator over the weights.
+    pub fn iter(&self) -> impl Iterator<Item = f64> + '_ {
+        self.as_slice().iter().copied()
+    }
+
+    /// Splits `total` into parts proportional to the weights.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use twine_models::support::constraint::Weights;
+    /// use uom::si::{f64::MassRate, mass_rate::kilogram_per_second};
+    ///
+    /// let split = Weights::new([0.4, 0.6]).unwrap();
+    /// let total = MassRate::new::<kilogram_per_second>(5.0);
+    /// let parts: Vec<_> = split.split(total).collect();
+    /// assert_eq!(parts[0], MassRate::new::<kilogram_per_second>(2.0));
+    /// ```
+    pub fn split<Q>(&self, total: Q) -> impl Iterator<Item = Q> + '_
+    where
+        Q: Mul<f64, Output = Q> + Copy + 'static,
+    {
+        self.iter().map(move |weight| total * weight)
+    }
+
+    /// Consumes the wrapper and returns the underlying storage.
+    pub fn into_inner(self) -> S {
+        self.values
+    }
+}
+
+impl<S: AsRef<[f64]> + AsMut<[f64]>> Weights<S> {
+    /// Constructs weights by rescaling non-negative proportions to sum to one.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConstraintError::NotANumber`] if any entry is `NaN`.
+    /// - [`ConstraintError::Infinite`] if any entry (or their sum) is infinite.
+    /// - [`ConstraintError::Negative`] if any entry is negative.
+    /// - [`ConstraintError::Zero`] if the entries sum to zero (including when
+    ///   there are no entries).
+    pub fn normalized(mut values: S) -> Result<Self, ConstraintError> {
+        let sum = checked_sum(values.as_ref())?;
+        if sum == 0.0 {
+            return Err(ConstraintError::Zero);
+        }
+        if !sum.is_finite() {
+            return Err(ConstraintError::Infinite);
+        }
+        for value in values.as_mut() {
+            *value /= sum;
+        }
+        Ok(Self { values })
+    }
+}
+
+impl<S: AsRef<[f64]>> AsRef<[f64]> for Weights<S> {
+    fn as_ref(&self) -> &[f64] {
+        self.as_slice()
+    }
+}
+
+/// Validates each entry and returns their sum.
+fn checked_sum(values: &[f64]) -> Result<f64, ConstraintError> {
+    let mut sum = 0.0;
+    for &value in values {
+        if value.is_nan() {
+            return Err(ConstraintError::NotANumber);
+        }
+        if value.is_infinite() {
+            return Err(ConstraintError::Infinite);
+        }
+        if value < 0.0 {
+            return Err(ConstraintError::Negative);
+        }
+        sum += value;
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{f64::Power, power::watt};
+
+    #[test]
+    fn arrays_and_vecs() {
+        assert!(Weights::new([1.0]).is_ok());
+        assert!(Weights::new([0.0, 1.0, 0.0]).is_ok());
+        assert!(Weights::new(vec![0.1, 0.2, 0.7]).is_ok());
+        assert!(Weights::new([0.1 + 0.2, 0.7]).is_ok());
+
+        let weights = Weights::new(vec![0.5, 0.5]).unwrap();
+        assert_eq!(weights.get(1), Some(0.5));
+        assert_eq!(weights.get(2), None);
+        assert_eq!(weights.into_inner(), vec![0.5, 0.5]);
+    }
+
+    #[test]
+    fn invalid_entries() {
+        assert_eq!(
+            Weights::new([f64::NAN, 1.0]),
+            Err(ConstraintError::NotANumber)
+        );
+        assert_eq!(
+            Weights::new([f64::INFINITY, 1.0]),
+            Err(ConstraintError::Infinite)
+        );
+        assert_eq!(Weights::new([-0.1, 1.1]), Err(ConstraintError::Negative));
+        assert_eq!(
+            Weights::new(Vec::<f64>::new()),
+            Err(ConstraintError::NotNormalized)
+        );
+    }
+
+    #[test]
+    fn tolerance() {
+        assert_eq!(
+            Weights::new([0.5, 0.5001]),
+            Err(ConstraintError::NotNormalized)
+        );
+        assert!(Weights::with_tolerance([0.5, 0.5001], 1e-3).is_ok());
+    }
+
+    #[test]
+    fn normalization() {
+        let weights = Weights::normalized([1.0, 3.0]).unwrap();
+        assert_relative_eq!(weights.as_slice()[0], 0.25);
+        assert_relative_eq!(weights.iter().sum::<f64>(), 1.0);
+
+        assert_eq!(Weights::normalized([0.0, 0.0]), Err(ConstraintError::Zero));
+        assert_eq!(
+            Weights::normalized([1.0, -1.0]),
+            Err(ConstraintError::Negative)
+        );
+        assert_eq!(
+            Weights::normalized([f64::MAX, f64::MAX]),
+            Err(ConstraintError::Infinite)
+        );
+    }
+
+    #[test]
+    fn split_quantities() {
+        let weights = Weights::new([0.2, 0.3, 0.5]).unwrap();
+        let parts: Vec<Power> = weights.split(Power::new::<watt>(1000.0)).collect();
+
+        assert_relative_eq!(parts[0].get::<watt>(), 200.0);
+        assert_relative_eq!(parts[2].get::<watt>(), 500.0);
+        let total: Power = parts.into_iter().sum();
+        assert_relative_eq!(total.get::<watt>(), 1000.0);
+    }
+}
@@ -1,8 +1,13 @@
 //! Thermostat controllers for temperature regulation.
 //!
 //! This module provides setpoint-based thermostat logic with hysteresis
-//! (deadband) to prevent rapid cycling between on/off states.
+//! (deadband) to prevent rapid cycling between on/off states, and a
+//! differential thermostat that compares two temperatures.
 
+pub mod differential;
 pub mod setpoint;
 
+pub use differential::{
+    DifferentialBand, DifferentialThermostatInput, InvalidDifferentialBand, differential,
+};
 pub use setpoint::{Deadband, InvalidDeadband, SetpointThermostatInput, cooling, heating};
@@ -0,0 +1,222 @@
+//! Differential thermostat for transfer pumps.
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::f64::{TemperatureInterval, ThermodynamicTemperature},
+};
+
+use crate::support::{control::SwitchState, units::TemperatureDifference};
+
+/// Turn-on and turn-off temperature differences for a differential thermostat.
+///
+/// Validated at construction — the turn-off difference is non-negative and
+/// no larger than the turn-on difference, so the controller cannot cycle on
+/// a single measurement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DifferentialBand {
+    on: TemperatureInterval,
+    off: TemperatureInterval,
+}
+
+/// Error returned when constructing an invalid [`DifferentialBand`].
+#[derive(Debug, Error)]
+#[error("differential band requires 0 ≤ off ≤ on, got on = {on:?}, off = {off:?}")]
+pub struct InvalidDifferentialBand {
+    pub on: TemperatureInterval,
+    pub off: TemperatureInterval,
+}
+
+impl DifferentialBand {
+    /// Constructs a `DifferentialBand` if `0 ≤ off ≤ on`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDifferentialBand`] if either value is NaN, `off` is
+    /// negative, or `off` exceeds `on`.
+    pub fn new(
+        on: TemperatureInterval,
+        off: TemperatureInterval,
+    ) -> Result<Self, InvalidDifferentialBand> {
+        if off >= TemperatureInterval::ZERO && off <= on {
+            Ok(Self { on, off })
+        } else {
+            Err(InvalidDifferentialBand { on, off })
+        }
+    }
+
+    /// Returns the difference at or above which the controller turns on.
+    #[must_use]
+    pub fn on(self) -> TemperatureInterval {
+        self.on
+    }
+
+    /// Returns the difference at or below which the controller turns off.
+    #[must_use]
+    pub fn off(self) -> TemperatureInterval {
+        self.off
+    }
+}
+
+/// Controls a pump that moves heat from a hot source to a cooler store.
+///
+/// Turns on when `source - store` rises to the band's turn-on difference or
+/// above, and off when it falls to the turn-off difference or below. This is
+/// the usual control for solar collector loops, where the source is the
+/// collector outlet and the store is the bottom of the tank.
+///
+/// # Examples
+///
+/// ```
+/// use twine_models::support::control::{
+///     SwitchState,
+///     thermostat::differential::{DifferentialBand, DifferentialThermostatInput, differential},
+/// };
+/// use uom::si::{
+///     f64::{TemperatureInterval, ThermodynamicTemperature},
+///     temperature_interval::degree_celsius as delta_celsius,
+///     thermodynamic_temperature::degree_celsius,
+/// };
+///
+/// let band = DifferentialBand::new(
+///     TemperatureInterval::new::<delta_celsius>(7.0),
+///     TemperatureInterval::new::<delta_celsius>(2.0),
+/// ).unwrap();
+///
+/// let input = DifferentialThermostatInput {
+///     state: SwitchState::On,
+///     source: ThermodynamicTemperature::new::<degree_celsius>(45.0),
+///     store: ThermodynamicTemperature::new::<degree_celsius>(41.0),
+///     band,
+/// };
+///
+/// // A 4 °C difference keeps a running pump on but would not start it.
+/// assert_eq!(differential(input), SwitchState::On);
+/// assert_eq!(differential(input.with_state(SwitchState::Off)), SwitchState::Off);
+/// ```
+#[must_use]
+pub fn differential(input: DifferentialThermostatInput) -> SwitchState {
+    let DifferentialThermostatInput {
+        state,
+        source,
+        store,
+        band,
+    } = input;
+
+    let difference = source.minus(store);
+    match state {
+        SwitchState::Off => {
+            if difference >= band.on() {
+                SwitchState::On
+            } else {
+                SwitchState::Off
+            }
+        }
+        SwitchState::On => {
+            if difference <= band.off() {
+                SwitchState::Off
+            } else {
+                SwitchState::On
+            }
+        }
+    }
+}
+
+/// Input to the [`differential`] thermostat function.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DifferentialThermostatInput {
+    /// The current on/off state of the controlled pump.
+    pub state: SwitchState,
+
+    /// Temperature of the heat source, such as a collector outlet.
+    pub source: ThermodynamicTemperature,
+
+    /// Temperature of the heat store, such as the bottom of a tank.
+    pub store: ThermodynamicTemperature,
+
+    /// Turn-on and turn-off differences.
+    pub band: DifferentialBand,
+}
+
+impl DifferentialThermostatInput {
+    /// Returns `self` with the given state, keeping other fields unchanged.
+    #[must_use]
+    pub fn with_state(self, state: SwitchState) -> Self {
+        Self { state, ..self }
+    }
+
+    /// Returns `self` with the given source temperature, keeping other fields unchanged.
+    #[must_use]
+    pub fn with_source(self, source: ThermodynamicTemperature) -> Self {
+        Self { source, ..self }
+    }
+
+    /// Returns `self` with the given store temperature, keeping other fields unchanged.
+    #[must_use]
+    pub fn with_store(self, store: ThermodynamicTemperature) -> Self {
+        Self { store, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uom::si::{
+        temperature_interval::degree_celsius as delta_celsius,
+        thermodynamic_temperature::degree_celsius,
+    };
+
+    /// Store temperature (°C) for all tests.
+    const STORE: f64 = 40.0;
+
+    fn band() -> DifferentialBand {
+        DifferentialBand::new(
+            TemperatureInterval::new::<delta_celsius>(7.0),
+            TemperatureInterval::new::<delta_celsius>(2.0),
+        )
+        .unwrap()
+    }
+
+    fn test_input(state: SwitchState, source: f64) -> DifferentialThermostatInput {
+        DifferentialThermostatInput {
+            state,
+            source: ThermodynamicTemperature::new::<degree_celsius>(source),
+            store: ThermodynamicTemperature::new::<degree_celsius>(STORE),
+            band: band(),
+        }
+    }
+
+    #[test]
+    fn band_rejects_off_above_on_and_negative_off() {
+        let delta = TemperatureInterval::new::<delta_celsius>;
+        assert!(DifferentialBand::new(delta(2.0), delta(3.0)).is_err());
+        assert!(DifferentialBand::new(delta(2.0), delta(-1.0)).is_err());
+        assert!(DifferentialBand::new(delta(f64::NAN), delta(1.0)).is_err());
+        assert!(DifferentialBand::new(delta(2.0), delta(2.0)).is_ok());
+    }
+
+    #[test]
+    fn turns_on_at_or_above_on_difference() {
+        assert_eq!(
+            differential(test_input(SwitchState::Off, STORE + 7.0)),
+            SwitchState::On
+        );
+        assert_eq!(
+            differential(test_input(SwitchState::Off, STORE + 6.9)),
+            SwitchState::Off
+        );
+    }
+
+    #[test]
+    fn turns_off_at_or_below_off_difference() {
+        assert_eq!(
+            differential(test_input(SwitchState::On, STORE + 2.0)),
+            SwitchState::Off
+        );
+        assert_eq!(
+            differential(test_input(SwitchState::On, STORE + 2.1)),
+            SwitchState::On
+        );
+    }
+}
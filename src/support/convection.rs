@@ -0,0 +1,206 @@
+//! External convection from surfaces to their surroundings.
+//!
+//! This module provides average heat transfer coefficients for surfaces
+//! losing heat to ambient air, ready to multiply by an area in a UA
+//! assembly:
+//!
+//! - [`FilmProperties`]: fluid properties at the film temperature, with
+//!   [`FilmProperties::air`] for dry air at atmospheric pressure.
+//! - [`natural`]: buoyancy-driven convection from vertical plates,
+//!   horizontal plates, and horizontal cylinders.
+//! - [`forced`]: wind-driven convection over flat plates and across
+//!   cylinders.
+//! - [`combined`]: blends natural and forced coefficients for mixed
+//!   convection.
+//! - [`horizontal_pipe`] and [`vertical_tank`]: complete outdoor or
+//!   mechanical-room coefficients for the common component shapes.
+//!
+//! Coefficients depend on the surface temperature, so a model that solves
+//! for its own surface temperature should re-evaluate them as it iterates.
+//!
+//! # Example
+//!
+//! The ambient conductance of an uninsulated tank in a light breeze:
+//!
+//! ```
+//! use twine_models::support::{
+//!     constraint::ConstraintResult,
+//!     convection::{FilmProperties, vertical_tank},
+//!     units::TemperatureDifference,
+//! };
+//! use uom::si::{
+//!     f64::{Length, ThermodynamicTemperature, Velocity},
+//!     length::meter,
+//!     thermal_conductance::watt_per_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//!     velocity::meter_per_second,
+//! };
+//!
+//! fn main() -> ConstraintResult<()> {
+//!     let surface = ThermodynamicTemperature::new::<degree_celsius>(55.0);
+//!     let ambient = ThermodynamicTemperature::new::<degree_celsius>(15.0);
+//!     let film = FilmProperties::air(surface.mean(ambient));
+//!
+//!     let tank = vertical_tank(
+//!         Length::new::<meter>(0.6),
+//!         Length::new::<meter>(1.5),
+//!         surface,
+//!         ambient,
+//!         Velocity::new::<meter_per_second>(1.0),
+//!         &film,
+//!     )?;
+//!
+//!     let ua = tank.conductance.get::<watt_per_kelvin>();
+//!     assert!((10.0..60.0).contains(&ua));
+//!     Ok(())
+//! }
+//! ```
+
+pub mod forced;
+pub mod natural;
+
+mod film;
+
+pub use film::FilmProperties;
+
+use core::f64::consts::PI;
+
+use uom::si::{
+    f64::{Area, HeatTransfer, Length, ThermalConductance, ThermodynamicTemperature, Velocity},
+    heat_transfer::watt_per_square_meter_kelvin,
+};
+
+use crate::support::constraint::ConstraintResult;
+
+use natural::Facing;
+
+/// Average coefficients over the exposed surfaces of a vertical tank.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TankConvection {
+    /// Coefficient over the cylindrical side wall.
+    pub side: HeatTransfer,
+
+    /// Coefficient over the top.
+    pub top: HeatTransfer,
+
+    /// Total conductance `h_side·A_side + h_top·A_top` to ambient.
+    pub conductance: ThermalConductance,
+}
+
+/// Blends natural and forced coefficients for mixed convection.
+///
+/// Uses Churchill's cube rule `h³ = h_N³ + h_F³`, which recovers either
+/// mode when it dominates and suits assisting and transverse flows.
+#[must_use]
+pub fn combined(natural: HeatTransfer, forced: HeatTransfer) -> HeatTransfer {
+    let h = (natural.value.powi(3) + forced.value.powi(3)).cbrt();
+    HeatTransfer::new::<watt_per_square_meter_kelvin>(h)
+}
+
+/// Returns the coefficient for a horizontal pipe in wind.
+///
+/// Combines natural convection from a horizontal cylinder with crossflow
+/// over it, so still air gives the free-convection limit.
+///
+/// # Errors
+///
+/// Returns `Err` if the outer diameter is not strictly positive or the wind
+/// speed is negative.
+pub fn horizontal_pipe(
+    outer_diameter: Length,
+    surface: ThermodynamicTemperature,
+    ambient: ThermodynamicTemperature,
+    wind_speed: Velocity,
+    film: &FilmProperties,
+) -> ConstraintResult<HeatTransfer> {
+    Ok(combined(
+        natural::horizontal_cylinder(outer_diameter, surface, ambient, film)?,
+        forced::cylinder_crossflow(outer_diameter, wind_speed, film)?,
+    ))
+}
+
+/// Returns the coefficients for a vertical cylindrical tank in wind.
+///
+/// The side wall combines natural convection from a vertical plate of the
+/// tank's height with crossflow over the cylinder. The top combines natural
+/// convection from an upward-facing disk with wind parallel to it. The
+/// bottom is assumed to sit on a floor or pad and is excluded.
+///
+/// # Errors
+///
+/// Returns `Err` if the diameter or height is not strictly positive or the
+/// wind speed is negative.
+pub fn vertical_tank(
+    diameter: Length,
+    height: Length,
+    surface: ThermodynamicTemperature,
+    ambient: ThermodynamicTemperature,
+    wind_speed: Velocity,
+    film: &FilmProperties,
+) -> ConstraintResult<TankConvection> {
+    let side = combined(
+        natural::vertical_plate(height, surface, ambient, film)?,
+        forced::cylinder_crossflow(diameter, wind_speed, film)?,
+    );
+    let top = combined(
+        natural::horizontal_plate(diameter / 4.0, Facing::Up, surface, ambient, film)?,
+        forced::parallel_plate(diameter, wind_speed, film)?,
+    );
+
+    let side_area: Area = PI * diameter * height;
+    let top_area: Area = PI / 4.0 * diameter * diameter;
+    Ok(TankConvection {
+        side,
+        top,
+        conductance: side * side_area + top * top_area,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        length::meter, thermodynamic_temperature::degree_celsius, velocity::meter_per_second,
+    };
+
+    use crate::support::units::TemperatureDifference;
+
+    fn h(value: f64) -> HeatTransfer {
+        HeatTransfer::new::<watt_per_square_meter_kelvin>(value)
+    }
+
+    #[test]
+    fn combined_recovers_dominant_mode() {
+        assert_relative_eq!(combined(h(3.0), h(0.0)).value, 3.0);
+        assert_relative_eq!(combined(h(3.0), h(4.0)).value, 91.0_f64.cbrt());
+        assert!(combined(h(0.5), h(20.0)).value < 20.001);
+    }
+
+    #[test]
+    fn wind_increases_pipe_losses() {
+        let surface = ThermodynamicTemperature::new::<degree_celsius>(70.0);
+        let ambient = ThermodynamicTemperature::new::<degree_celsius>(5.0);
+        let film = FilmProperties::air(surface.mean(ambient));
+        let diameter = Length::new::<meter>(0.03);
+        let pipe = |v| {
+            horizontal_pipe(
+                diameter,
+                surface,
+                ambient,
+                Velocity::new::<meter_per_second>(v),
+                &film,
+            )
+            .unwrap()
+        };
+
+        let still = pipe(0.0);
+        let windy = pipe(5.0);
+        assert!(windy > still * 3.0);
+
+        // Still air is close to pure free convection.
+        let natural = natural::horizontal_cylinder(diameter, surface, ambient, &film).unwrap();
+        assert_relative_eq!(still.value, natural.value, max_relative = 1e-3);
+    }
+}
@@ -0,0 +1,94 @@
+use uom::si::{
+    dynamic_viscosity::pascal_second,
+    f64::{
+        DynamicViscosity, MassDensity, Ratio, TemperatureCoefficient, ThermalConductivity,
+        ThermodynamicTemperature,
+    },
+    mass_density::kilogram_per_cubic_meter,
+    ratio::ratio,
+    temperature_coefficient::per_kelvin,
+    thermal_conductivity::watt_per_meter_kelvin,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::support::units::{KinematicViscosity, ThermalDiffusivity};
+
+/// Properties of the surrounding fluid at the film temperature.
+///
+/// External correlations evaluate properties at the film temperature, the
+/// mean of the surface and free-stream temperatures.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FilmProperties {
+    /// Thermal conductivity.
+    pub conductivity: ThermalConductivity,
+
+    /// Kinematic viscosity `ν`.
+    pub kinematic_viscosity: KinematicViscosity,
+
+    /// Prandtl number `ν / α`.
+    pub prandtl: Ratio,
+
+    /// Volumetric thermal expansion coefficient `β`, which drives natural
+    /// convection.
+    pub expansion_coefficient: TemperatureCoefficient,
+}
+
+impl FilmProperties {
+    /// Returns the properties of dry air at one standard atmosphere.
+    ///
+    /// Viscosity and conductivity follow Sutherland's law, density the ideal
+    /// gas law, and `β = 1/T`. The fits are within a few percent of
+    /// tabulated values from 250 K to 600 K, which covers outdoor and
+    /// mechanical-room surroundings.
+    #[must_use]
+    pub fn air(film_temperature: ThermodynamicTemperature) -> Self {
+        let t = film_temperature.get::<kelvin>();
+        let sutherland =
+            |reference: f64, s: f64| reference * (t / 273.15).powf(1.5) * (273.15 + s) / (t + s);
+
+        let viscosity = DynamicViscosity::new::<pascal_second>(sutherland(1.716e-5, 110.4));
+        let conductivity =
+            ThermalConductivity::new::<watt_per_meter_kelvin>(sutherland(0.0241, 194.0));
+        let density = MassDensity::new::<kilogram_per_cubic_meter>(101_325.0 / (287.05 * t));
+        let prandtl = 1006.0 * viscosity.value / conductivity.value;
+
+        Self {
+            conductivity,
+            kinematic_viscosity: viscosity / density,
+            prandtl: Ratio::new::<ratio>(prandtl),
+            expansion_coefficient: TemperatureCoefficient::new::<per_kelvin>(1.0 / t),
+        }
+    }
+
+    /// Returns the thermal diffusivity `α = ν / Pr`.
+    #[must_use]
+    pub fn thermal_diffusivity(&self) -> ThermalDiffusivity {
+        self.kinematic_viscosity / self.prandtl
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::diffusion_coefficient::square_meter_per_second;
+
+    #[test]
+    fn air_at_300_kelvin_matches_tables() {
+        // Incropera Table A.4: k = 0.0263 W/m·K, ν = 15.89e-6 m²/s, Pr = 0.707.
+        let air = FilmProperties::air(ThermodynamicTemperature::new::<kelvin>(300.0));
+
+        assert_relative_eq!(
+            air.conductivity.get::<watt_per_meter_kelvin>(),
+            0.0263,
+            max_relative = 0.02
+        );
+        assert_relative_eq!(
+            air.kinematic_viscosity.get::<square_meter_per_second>(),
+            15.89e-6,
+            max_relative = 0.02
+        );
+        assert_relative_eq!(air.prandtl.get::<ratio>(), 0.707, max_relative = 0.02);
+    }
+}
@@ -0,0 +1,132 @@
+//! Forced (wind-driven) convection from surfaces in a cross or parallel flow.
+//!
+//! Each function returns the average coefficient over the surface for the
+//! given free-stream speed. The Reynolds number `Re = V·L / ν` sets the
+//! strength of the flow.
+
+use uom::si::f64::{HeatTransfer, Length, Velocity};
+
+use crate::support::constraint::{Constraint, ConstraintResult, NonNegative, StrictlyPositive};
+
+use super::FilmProperties;
+
+/// Reynolds number at which the boundary layer on a flat plate turns
+/// turbulent.
+const PLATE_TRANSITION_REYNOLDS: f64 = 5e5;
+
+/// Returns the coefficient for a flat plate in flow parallel to its surface.
+///
+/// Laminar flow follows `Nu = 0.664·Re^(1/2)·Pr^(1/3)`. Past the transition
+/// at `Re = 5×10⁵` the mixed laminar-turbulent average
+/// `Nu = (0.037·Re^(4/5) − 871)·Pr^(1/3)` applies.
+///
+/// # Errors
+///
+/// Returns `Err` if the length is not strictly positive or the speed is
+/// negative.
+pub fn parallel_plate(
+    length: Length,
+    speed: Velocity,
+    film: &FilmProperties,
+) -> ConstraintResult<HeatTransfer> {
+    StrictlyPositive::check(&length)?;
+    NonNegative::check(&speed)?;
+
+    let re = (speed * length / film.kinematic_viscosity).value;
+    let pr_third = film.prandtl.value.cbrt();
+    let nu = if re <= PLATE_TRANSITION_REYNOLDS {
+        0.664 * re.sqrt() * pr_third
+    } else {
+        (0.037 * re.powf(0.8) - 871.0) * pr_third
+    };
+    Ok(nu * film.conductivity / length)
+}
+
+/// Returns the coefficient for a cylinder in crossflow, such as a pipe or a
+/// tank wall in wind.
+///
+/// Uses the Churchill–Bernstein correlation,
+///
+/// ```text
+/// Nu = 0.3 + 0.62·Re^(1/2)·Pr^(1/3) / [1 + (0.4/Pr)^(2/3)]^(1/4)
+///          · [1 + (Re/282000)^(5/8)]^(4/5)
+/// ```
+///
+/// valid for all `Re·Pr > 0.2`.
+///
+/// # Errors
+///
+/// Returns `Err` if the diameter is not strictly positive or the speed is
+/// negative.
+pub fn cylinder_crossflow(
+    diameter: Length,
+    speed: Velocity,
+    film: &FilmProperties,
+) -> ConstraintResult<HeatTransfer> {
+    StrictlyPositive::check(&diameter)?;
+    NonNegative::check(&speed)?;
+
+    let re = (speed * diameter / film.kinematic_viscosity).value;
+    let pr = film.prandtl.value;
+    let nu = 0.3
+        + 0.62 * re.sqrt() * pr.cbrt() / (1.0 + (0.4 / pr).powf(2.0 / 3.0)).powf(0.25)
+            * (1.0 + (re / 282_000.0).powf(5.0 / 8.0)).powf(0.8);
+    Ok(nu * film.conductivity / diameter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::ThermodynamicTemperature, heat_transfer::watt_per_square_meter_kelvin, length::meter,
+        thermodynamic_temperature::kelvin, velocity::meter_per_second,
+    };
+
+    fn air() -> FilmProperties {
+        FilmProperties::air(ThermodynamicTemperature::new::<kelvin>(300.0))
+    }
+
+    #[test]
+    fn plate_coefficient_grows_with_speed() {
+        let film = air();
+        let length = Length::new::<meter>(2.0);
+        let h = |v| {
+            parallel_plate(length, Velocity::new::<meter_per_second>(v), &film)
+                .unwrap()
+                .get::<watt_per_square_meter_kelvin>()
+        };
+
+        assert!(h(2.0) < h(3.0));
+        assert_relative_eq!(h(0.0), 0.0);
+    }
+
+    #[test]
+    fn pipe_in_breeze_matches_hand_calculation() {
+        // 60 mm pipe in 3 m/s wind: Re ≈ 1.13×10⁴, Nu ≈ 56, h ≈ 25 W/m²·K.
+        let h = cylinder_crossflow(
+            Length::new::<meter>(0.06),
+            Velocity::new::<meter_per_second>(3.0),
+            &air(),
+        )
+        .unwrap();
+        assert_relative_eq!(
+            h.get::<watt_per_square_meter_kelvin>(),
+            25.0,
+            max_relative = 0.1
+        );
+    }
+
+    #[test]
+    fn rejects_negative_speed() {
+        assert!(
+            parallel_plate(
+                Length::new::<meter>(1.0),
+                Velocity::new::<meter_per_second>(-1.0),
+                &air()
+            )
+            .is_err()
+        );
+    }
+}
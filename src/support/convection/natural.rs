@@ -0,0 +1,204 @@
+//! Natural (free) convection from surfaces in quiescent surroundings.
+//!
+//! Each function returns the average coefficient over the surface for the
+//! given surface and ambient temperatures. The Rayleigh number
+//! `Ra = g·β·|T_s − T_∞|·L³ / (ν·α)` sets the strength of the buoyant flow,
+//! so the coefficient falls to its conduction limit as the temperatures
+//! approach each other.
+
+use uom::si::{
+    acceleration::standard_gravity,
+    f64::{Acceleration, HeatTransfer, Length, ThermodynamicTemperature},
+};
+
+use crate::support::{
+    constraint::{Constraint, ConstraintResult, StrictlyPositive},
+    units::TemperatureDifference,
+};
+
+use super::FilmProperties;
+
+/// Which face of a horizontal plate is exposed to the surroundings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Facing {
+    /// The upper face, such as a tank lid or a roof.
+    Up,
+
+    /// The lower face, such as the underside of a raised floor.
+    Down,
+}
+
+/// Returns the coefficient for a vertical plate of the given height.
+///
+/// Uses the Churchill–Chu correlation over the full Rayleigh range,
+///
+/// ```text
+/// Nu = {0.825 + 0.387·Ra^(1/6) / [1 + (0.492/Pr)^(9/16)]^(8/27)}²
+/// ```
+///
+/// which also suits vertical cylinders, such as tank walls, whose diameter
+/// is large compared with their boundary layer.
+///
+/// # Errors
+///
+/// Returns `Err` if the height is not strictly positive.
+pub fn vertical_plate(
+    height: Length,
+    surface: ThermodynamicTemperature,
+    ambient: ThermodynamicTemperature,
+    film: &FilmProperties,
+) -> ConstraintResult<HeatTransfer> {
+    StrictlyPositive::check(&height)?;
+
+    let ra = rayleigh(height, surface, ambient, film);
+    let pr = film.prandtl.value;
+    let nu = (0.825
+        + 0.387 * ra.powf(1.0 / 6.0) / (1.0 + (0.492 / pr).powf(9.0 / 16.0)).powf(8.0 / 27.0))
+    .powi(2);
+    Ok(nu * film.conductivity / height)
+}
+
+/// Returns the coefficient for one face of a horizontal plate.
+///
+/// The characteristic length is the plate area divided by its perimeter.
+/// When the buoyant flow rises freely off the face (a hot face up or a cold
+/// face down) the correlations of Lloyd and Moran apply,
+/// `Nu = 0.54·Ra^(1/4)` below `Ra = 10⁷` and `Nu = 0.15·Ra^(1/3)` above it.
+/// Otherwise the flow is stably stratified and `Nu = 0.52·Ra^(1/5)`.
+///
+/// # Errors
+///
+/// Returns `Err` if the characteristic length is not strictly positive.
+pub fn horizontal_plate(
+    characteristic_length: Length,
+    facing: Facing,
+    surface: ThermodynamicTemperature,
+    ambient: ThermodynamicTemperature,
+    film: &FilmProperties,
+) -> ConstraintResult<HeatTransfer> {
+    StrictlyPositive::check(&characteristic_length)?;
+
+    let ra = rayleigh(characteristic_length, surface, ambient, film);
+    let heated = surface > ambient;
+    let unstable = matches!((facing, heated), (Facing::Up, true) | (Facing::Down, false));
+    let nu = if !unstable {
+        0.52 * ra.powf(0.2)
+    } else if ra < 1e7 {
+        0.54 * ra.powf(0.25)
+    } else {
+        0.15 * ra.cbrt()
+    };
+    Ok(nu * film.conductivity / characteristic_length)
+}
+
+/// Returns the coefficient for a long horizontal cylinder, such as a pipe.
+///
+/// Uses the Churchill–Chu correlation,
+///
+/// ```text
+/// Nu = {0.60 + 0.387·Ra^(1/6) / [1 + (0.559/Pr)^(9/16)]^(8/27)}²
+/// ```
+///
+/// with the outer diameter as the characteristic length.
+///
+/// # Errors
+///
+/// Returns `Err` if the diameter is not strictly positive.
+pub fn horizontal_cylinder(
+    diameter: Length,
+    surface: ThermodynamicTemperature,
+    ambient: ThermodynamicTemperature,
+    film: &FilmProperties,
+) -> ConstraintResult<HeatTransfer> {
+    StrictlyPositive::check(&diameter)?;
+
+    let ra = rayleigh(diameter, surface, ambient, film);
+    let pr = film.prandtl.value;
+    let nu = (0.60
+        + 0.387 * ra.powf(1.0 / 6.0) / (1.0 + (0.559 / pr).powf(9.0 / 16.0)).powf(8.0 / 27.0))
+    .powi(2);
+    Ok(nu * film.conductivity / diameter)
+}
+
+/// Rayleigh number for a surface of characteristic length `length`.
+fn rayleigh(
+    length: Length,
+    surface: ThermodynamicTemperature,
+    ambient: ThermodynamicTemperature,
+    film: &FilmProperties,
+) -> f64 {
+    let g = Acceleration::new::<standard_gravity>(1.0);
+    let delta = surface.minus(ambient).abs();
+    (g * film.expansion_coefficient * delta * length * length * length
+        / (film.kinematic_viscosity * film.thermal_diffusivity()))
+    .value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{heat_transfer::watt_per_square_meter_kelvin, length::meter};
+
+    use crate::support::units::test_utils::celsius;
+
+    fn air(surface: f64, ambient: f64) -> FilmProperties {
+        FilmProperties::air(celsius(0.5 * (surface + ambient)))
+    }
+
+    #[test]
+    fn warm_wall_has_a_few_watts_per_square_meter_kelvin() {
+        let h = vertical_plate(
+            Length::new::<meter>(1.5),
+            celsius(60.0),
+            celsius(20.0),
+            &air(60.0, 20.0),
+        )
+        .unwrap();
+        assert_relative_eq!(
+            h.get::<watt_per_square_meter_kelvin>(),
+            4.5,
+            max_relative = 0.2
+        );
+    }
+
+    #[test]
+    fn hot_face_up_convects_more_than_hot_face_down() {
+        let film = air(70.0, 20.0);
+        let length = Length::new::<meter>(0.25);
+        let up = horizontal_plate(length, Facing::Up, celsius(70.0), celsius(20.0), &film).unwrap();
+        let down =
+            horizontal_plate(length, Facing::Down, celsius(70.0), celsius(20.0), &film).unwrap();
+
+        assert!(up > down);
+
+        // A cold face down behaves like a hot face up.
+        let cold_down =
+            horizontal_plate(length, Facing::Down, celsius(20.0), celsius(70.0), &film).unwrap();
+        assert_eq!(up, cold_down);
+    }
+
+    #[test]
+    fn isothermal_cylinder_falls_to_conduction_limit() {
+        let film = air(20.0, 20.0);
+        let diameter = Length::new::<meter>(0.05);
+        let h = horizontal_cylinder(diameter, celsius(20.0), celsius(20.0), &film).unwrap();
+
+        assert_relative_eq!((h * diameter / film.conductivity).value, 0.36);
+    }
+
+    #[test]
+    fn rejects_non_positive_length() {
+        let film = air(40.0, 20.0);
+        assert!(
+            vertical_plate(
+                Length::new::<meter>(0.0),
+                celsius(40.0),
+                celsius(20.0),
+                &film
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,432 @@
+//! Thermal disinfection compliance for hot water storage.
+//!
+//! Guidance on Legionella control asks that stored hot water be raised to a
+//! disinfection temperature and held there for a while at regular
+//! intervals, such as 60 °C for an hour every week. A [`HoldRequirement`]
+//! states such a rule, and [`HoldRequirement::check`] tests a simulated
+//! temperature series against it, reporting every qualifying hold and every
+//! stretch of time during which the rule was overdue.
+//!
+//! The series can come from any model: the outlet or top-node temperature
+//! of a stratified tank, or the delivery temperature of a solar hot water
+//! system. Samples are joined by straight lines, so a hold starts and ends
+//! where the series crosses the set temperature rather than at the nearest
+//! sample.
+//!
+//! # Example
+//!
+//! A tank that idles at 55 °C and is boosted to 65 °C for two hours every
+//! Sunday:
+//!
+//! ```
+//! use twine_models::support::disinfection::HoldRequirement;
+//! use uom::si::{
+//!     f64::{ThermodynamicTemperature, Time},
+//!     thermodynamic_temperature::degree_celsius,
+//!     time::{day, hour},
+//! };
+//!
+//! let requirement = HoldRequirement::new(
+//!     ThermodynamicTemperature::new::<degree_celsius>(60.0),
+//!     Time::new::<hour>(1.0),
+//!     Time::new::<day>(7.0),
+//! )?;
+//!
+//! // Four weeks of hourly samples.
+//! let samples = (0..=28 * 24).map(|h| {
+//!     let boosted = h % (7 * 24) >= 6 * 24 + 2 && h % (7 * 24) < 6 * 24 + 5;
+//!     let celsius = if boosted { 65.0 } else { 55.0 };
+//!     (
+//!         Time::new::<hour>(f64::from(h)),
+//!         ThermodynamicTemperature::new::<degree_celsius>(celsius),
+//!     )
+//! });
+//!
+//! let report = requirement.check(samples)?;
+//! assert!(report.is_compliant());
+//! assert_eq!(report.holds.len(), 4);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::f64::{ThermodynamicTemperature, Time},
+};
+
+use crate::support::{
+    constraint::{Constraint, StrictlyPositive},
+    units::TemperatureDifference,
+};
+
+/// Errors from stating or checking a [`HoldRequirement`].
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum DisinfectionError {
+    /// The requirement is out of range.
+    #[error("invalid requirement: {0}")]
+    InvalidRequirement(&'static str),
+
+    /// The temperature series cannot be checked.
+    #[error("invalid samples: {0}")]
+    InvalidSamples(&'static str),
+}
+
+/// A rule that stored water reach `temperature` and stay at or above it for
+/// `duration` without a break, at least once in every `period`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HoldRequirement {
+    temperature: ThermodynamicTemperature,
+    duration: Time,
+    period: Time,
+}
+
+/// A continuous stretch at or above the set temperature long enough to
+/// satisfy the requirement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hold {
+    /// When the series rose to the set temperature.
+    pub start: Time,
+
+    /// When it fell below again, or the series ended.
+    pub end: Time,
+}
+
+impl Hold {
+    /// Returns how long the hold lasted.
+    #[must_use]
+    pub fn duration(&self) -> Time {
+        self.end - self.start
+    }
+}
+
+/// A stretch of time during which the requirement was overdue.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Violation {
+    /// When a full period had passed since the requirement was last met,
+    /// or since the series began.
+    pub start: Time,
+
+    /// When a hold next met the requirement, or the series ended.
+    pub end: Time,
+
+    /// Longest continuous stretch at or above the set temperature since the
+    /// requirement was last met, which fell short of the required duration.
+    pub longest_hold: Time,
+}
+
+/// The outcome of checking a temperature series against a
+/// [`HoldRequirement`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisinfectionReport {
+    /// Every hold that met the required duration, in time order.
+    pub holds: Vec<Hold>,
+
+    /// Every stretch during which the requirement was overdue, in time
+    /// order.
+    pub violations: Vec<Violation>,
+
+    /// Total time at or above the set temperature, including stretches too
+    /// short to count as holds.
+    pub time_above: Time,
+}
+
+impl DisinfectionReport {
+    /// Returns whether the requirement was met throughout the series.
+    #[must_use]
+    pub fn is_compliant(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl HoldRequirement {
+    /// States a requirement to hold at `temperature` for `duration` once
+    /// in every `period`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DisinfectionError::InvalidRequirement`] if the temperature
+    /// is not finite, the duration is not strictly positive and finite, or
+    /// the period is not finite and at least as long as the duration.
+    pub fn new(
+        temperature: ThermodynamicTemperature,
+        duration: Time,
+        period: Time,
+    ) -> Result<Self, DisinfectionError> {
+        if !temperature.is_finite() {
+            return Err(DisinfectionError::InvalidRequirement(
+                "temperature must be finite",
+            ));
+        }
+        if StrictlyPositive::check(&duration).is_err() || !duration.is_finite() {
+            return Err(DisinfectionError::InvalidRequirement(
+                "duration must be strictly positive and finite",
+            ));
+        }
+        if !period.is_finite() || period < duration {
+            return Err(DisinfectionError::InvalidRequirement(
+                "period must be finite and at least the duration",
+            ));
+        }
+        Ok(Self {
+            temperature,
+            duration,
+            period,
+        })
+    }
+
+    /// Returns the disinfection temperature.
+    #[must_use]
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    /// Returns how long the temperature must be held.
+    #[must_use]
+    pub fn duration(&self) -> Time {
+        self.duration
+    }
+
+    /// Returns the longest allowed time between holds.
+    #[must_use]
+    pub fn period(&self) -> Time {
+        self.period
+    }
+
+    /// Checks a series of `(time, temperature)` samples against the
+    /// requirement.
+    ///
+    /// The requirement is met from the moment a hold reaches the required
+    /// duration until the hold ends, and falls overdue a full period after
+    /// that. The first period of the series is a grace period, since the
+    /// history before it is unknown.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DisinfectionError::InvalidSamples`] if there are fewer than
+    /// two samples, any sample is not finite, or the times do not strictly
+    /// increase.
+    pub fn check(
+        &self,
+        samples: impl IntoIterator<Item = (Time, ThermodynamicTemperature)>,
+    ) -> Result<DisinfectionReport, DisinfectionError> {
+        let samples: Vec<_> = samples.into_iter().collect();
+        if samples.len() < 2 {
+            return Err(DisinfectionError::InvalidSamples(
+                "series must have at least two samples",
+            ));
+        }
+        if samples
+            .iter()
+            .any(|(time, temperature)| !time.is_finite() || !temperature.is_finite())
+        {
+            return Err(DisinfectionError::InvalidSamples("samples must be finite"));
+        }
+        if samples.windows(2).any(|pair| pair[1].0 <= pair[0].0) {
+            return Err(DisinfectionError::InvalidSamples(
+                "sample times must strictly increase",
+            ));
+        }
+
+        let stretches = self.stretches_above(&samples);
+        let first = samples[0].0;
+        let last = samples[samples.len() - 1].0;
+
+        let mut holds = Vec::new();
+        let mut violations = Vec::new();
+        let mut met_until = first;
+        let mut longest_short = Time::ZERO;
+        for stretch in &stretches {
+            if stretch.duration() < self.duration {
+                longest_short = longest_short.max(stretch.duration());
+                continue;
+            }
+            let met_from = stretch.start + self.duration;
+            if met_from - met_until > self.period {
+                violations.push(Violation {
+                    start: met_until + self.period,
+                    end: met_from,
+                    longest_hold: longest_short,
+                });
+            }
+            holds.push(*stretch);
+            met_until = stretch.end;
+            longest_short = Time::ZERO;
+        }
+        if last - met_until > self.period {
+            violations.push(Violation {
+                start: met_until + self.period,
+                end: last,
+                longest_hold: longest_short,
+            });
+        }
+
+        Ok(DisinfectionReport {
+            holds,
+            violations,
+            time_above: stretches.iter().map(Hold::duration).sum(),
+        })
+    }
+
+    /// Finds every continuous stretch at or above the set temperature,
+    /// interpolating where the series crosses it.
+    fn stretches_above(&self, samples: &[(Time, ThermodynamicTemperature)]) -> Vec<Hold> {
+        let set = self.temperature;
+        let crossing = |(t0, a): (Time, ThermodynamicTemperature),
+                        (t1, b): (Time, ThermodynamicTemperature)| {
+            t0 + (t1 - t0) * (set.minus(a) / b.minus(a))
+        };
+
+        let mut stretches = Vec::new();
+        let mut start = (samples[0].1 >= set).then_some(samples[0].0);
+        for pair in samples.windows(2) {
+            let (before, after) = (pair[0], pair[1]);
+            match (start, after.1 >= set) {
+                (None, true) => start = Some(crossing(before, after)),
+                (Some(began), false) => {
+                    stretches.push(Hold {
+                        start: began,
+                        end: crossing(before, after),
+                    });
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(began) = start {
+            stretches.push(Hold {
+                start: began,
+                end: samples[samples.len() - 1].0,
+            });
+        }
+        stretches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        thermodynamic_temperature::degree_celsius,
+        time::{day, hour, minute},
+    };
+
+    fn weekly() -> HoldRequirement {
+        HoldRequirement::new(
+            ThermodynamicTemperature::new::<degree_celsius>(60.0),
+            Time::new::<hour>(1.0),
+            Time::new::<day>(7.0),
+        )
+        .unwrap()
+    }
+
+    /// Samples every quarter hour for `days`, at 55 °C except for boosts
+    /// to 65 °C given as `(start, length)` in hours.
+    #[allow(clippy::cast_precision_loss)]
+    fn series(days: usize, boosts: &[(f64, f64)]) -> Vec<(Time, ThermodynamicTemperature)> {
+        (0..=days * 96)
+            .map(|i| {
+                let hours = i as f64 / 4.0;
+                let boosted = boosts
+                    .iter()
+                    .any(|&(start, length)| hours >= start && hours < start + length);
+                (
+                    Time::new::<hour>(hours),
+                    ThermodynamicTemperature::new::<degree_celsius>(if boosted {
+                        65.0
+                    } else {
+                        55.0
+                    }),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn crossings_are_interpolated() {
+        // A ramp from 50 °C up to 70 °C and back, two hours each way, is at
+        // or above 60 °C for the middle two hours.
+        let samples = [(0.0, 50.0), (2.0, 70.0), (4.0, 50.0)].map(|(h, c)| {
+            (
+                Time::new::<hour>(h),
+                ThermodynamicTemperature::new::<degree_celsius>(c),
+            )
+        });
+        let report = weekly().check(samples).unwrap();
+        assert_eq!(report.holds.len(), 1);
+        assert_relative_eq!(report.holds[0].start.get::<hour>(), 1.0);
+        assert_relative_eq!(report.holds[0].end.get::<hour>(), 3.0);
+        assert_relative_eq!(report.time_above.get::<hour>(), 2.0);
+        assert!(report.is_compliant());
+    }
+
+    #[test]
+    fn regular_boosts_comply() {
+        let boosts: Vec<_> = (0..3)
+            .map(|week| (f64::from(week) * 168.0 + 100.0, 2.0))
+            .collect();
+        let report = weekly().check(series(21, &boosts)).unwrap();
+        assert_eq!(report.holds.len(), 3);
+        assert!(report.is_compliant());
+    }
+
+    #[test]
+    fn short_boosts_do_not_count() {
+        let boosts: Vec<_> = (0..3)
+            .map(|week| (f64::from(week) * 168.0 + 100.0, 0.5))
+            .collect();
+        let report = weekly().check(series(21, &boosts)).unwrap();
+        assert!(report.holds.is_empty());
+        assert_eq!(report.violations.len(), 1);
+
+        // Overdue from the end of the first week until the series ends.
+        let violation = report.violations[0];
+        assert_relative_eq!(violation.start.get::<day>(), 7.0);
+        assert_relative_eq!(violation.end.get::<day>(), 21.0);
+        assert_relative_eq!(violation.longest_hold.get::<minute>(), 30.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn late_boost_leaves_a_gap() {
+        // Held on day 1, then not again until day 10. Each boost crosses
+        // 60 °C an eighth of an hour before its first and last samples.
+        let report = weekly()
+            .check(series(14, &[(24.0, 2.0), (240.0, 2.0)]))
+            .unwrap();
+        assert_eq!(report.holds.len(), 2);
+        assert_eq!(report.violations.len(), 1);
+        let violation = report.violations[0];
+        assert_relative_eq!(violation.start.get::<hour>(), 25.875 + 168.0);
+        assert_relative_eq!(violation.end.get::<hour>(), 240.875);
+        assert_eq!(violation.longest_hold, Time::ZERO);
+    }
+
+    #[test]
+    fn rejects_invalid_requirements_and_samples() {
+        assert!(matches!(
+            HoldRequirement::new(
+                ThermodynamicTemperature::new::<degree_celsius>(60.0),
+                Time::new::<hour>(2.0),
+                Time::new::<hour>(1.0),
+            ),
+            Err(DisinfectionError::InvalidRequirement(_))
+        ));
+
+        let sample = |h: f64| {
+            (
+                Time::new::<hour>(h),
+                ThermodynamicTemperature::new::<degree_celsius>(60.0),
+            )
+        };
+        assert!(matches!(
+            weekly().check([sample(0.0)]),
+            Err(DisinfectionError::InvalidSamples(_))
+        ));
+        assert!(matches!(
+            weekly().check([sample(1.0), sample(1.0)]),
+            Err(DisinfectionError::InvalidSamples(_))
+        ));
+    }
+}
@@ -0,0 +1,321 @@
+//! Exergy (second-law) accounting.
+//!
+//! Energy balances say where energy goes; exergy balances say where the
+//! ability to do work is destroyed. This module provides the pieces for a
+//! cycle-level exergy breakdown:
+//!
+//! - [`DeadState`] and [`flow_exergy`]: specific flow exergy
+//!   `ψ = (h − h₀) − T₀·(s − s₀)` of a state relative to the environment.
+//! - [`ExergyBalance`]: the fuel, product, and loss of one component, from
+//!   which its exergy destruction and second-law efficiency follow.
+//! - [`ExergyAudit`]: balances for every component of a cycle, reduced to an
+//!   [`ExergyReport`] of where exergy is destroyed.
+//!
+//! Component results build their own balances: see
+//! [`CompressionResult::exergy_balance`], [`ExpansionResult::exergy_balance`],
+//! the recuperator outputs' `exergy_balance`, and [`ExergyBalance::mixer`].
+//!
+//! ## Fuel and product
+//!
+//! Balances follow the fuel–product convention. Exergy a component consumes
+//! is fuel; exergy it delivers is product; exergy it rejects to the
+//! environment is loss. Whatever remains was destroyed:
+//!
+//! ```text
+//! Ėx_D = Ėx_F − Ėx_P − Ėx_L        η_II = Ėx_P / Ėx_F
+//! ```
+//!
+//! A stream whose exergy rises through a component contributes product, and
+//! one whose exergy falls contributes fuel. Work in is fuel and work out is
+//! product. Heat crossing the boundary at temperature `T_b` carries exergy
+//! `Q̇·(1 − T₀/T_b)`, which is fuel when it enters and loss when it leaves,
+//! unless delivering heat is the component's purpose.
+//!
+//! ## Example
+//!
+//! An air compressor followed by an aftercooler that rejects heat to the
+//! environment:
+//!
+//! ```
+//! use twine_models::support::{
+//!     exergy::{DeadState, ExergyAudit},
+//!     thermo::{capability::StateFrom, fluid::Air, model::PerfectGas},
+//!     turbomachinery::{IsentropicEfficiency, compressor},
+//! };
+//! use uom::si::{
+//!     f64::{MassRate, Pressure, ThermodynamicTemperature},
+//!     mass_rate::kilogram_per_second,
+//!     power::kilowatt,
+//!     pressure::kilopascal,
+//!     thermodynamic_temperature::kelvin,
+//! };
+//!
+//! let air = PerfectGas::<Air>::new()?;
+//! let t0 = ThermodynamicTemperature::new::<kelvin>(298.15);
+//! let p0 = Pressure::new::<kilopascal>(101.325);
+//! let ambient = air.state_from((Air, t0, p0))?;
+//! let dead = DeadState::new(&air, &ambient)?;
+//! let m_dot = MassRate::new::<kilogram_per_second>(1.0);
+//!
+//! let inlet = ambient.clone();
+//! let compressed = compressor::isentropic(
+//!     &inlet,
+//!     Pressure::new::<kilopascal>(400.0),
+//!     IsentropicEfficiency::new(0.8)?,
+//!     &air,
+//! )?;
+//!
+//! let audit = ExergyAudit::new()
+//!     .with(compressed.exergy_balance(&inlet, m_dot, &air, &dead)?)
+//!     .with(dead.cooler("aftercooler", &air, &compressed.outlet, m_dot)?);
+//!
+//! let report = audit.report();
+//! let compressor = &report.components[0];
+//! assert!(compressor.efficiency.unwrap() > 0.8 && compressor.efficiency.unwrap() < 1.0);
+//!
+//! // All exergy the compressor added is destroyed or lost by the time the
+//! // air is back at the dead state.
+//! let work = compressed.work.quantity() * m_dot;
+//! let accounted = report.total_destroyed + report.total_loss;
+//! assert!((accounted - work).get::<kilowatt>().abs() < 1e-9);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`CompressionResult::exergy_balance`]: crate::support::turbomachinery::compressor::CompressionResult::exergy_balance
+//! [`ExpansionResult::exergy_balance`]: crate::support::turbomachinery::turbine::ExpansionResult::exergy_balance
+
+mod audit;
+
+use uom::{
+    ConstZero,
+    si::{
+        f64::{MassRate, Pressure, TemperatureInterval, ThermodynamicTemperature},
+        temperature_interval::kelvin as delta_kelvin,
+        thermodynamic_temperature::kelvin,
+    },
+};
+
+use crate::support::{
+    port::FluidPort,
+    thermo::{
+        PropertyError, State,
+        capability::{HasEnthalpy, HasEntropy, HasPressure},
+    },
+    units::{SpecificEnthalpy, SpecificEntropy},
+};
+
+pub use audit::{ComponentExergy, ExergyAudit, ExergyBalance, ExergyReport};
+
+/// The environment that exergy is measured against.
+///
+/// Holds the dead-state temperature and pressure and one fluid's enthalpy
+/// and entropy there. Cycles with several fluids use one dead state per
+/// fluid, all at the same `T₀` and `p₀`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeadState {
+    /// Environment temperature `T₀`.
+    pub temperature: ThermodynamicTemperature,
+
+    /// Environment pressure `p₀`.
+    pub pressure: Pressure,
+
+    /// Specific enthalpy of the fluid at the dead state.
+    pub enthalpy: SpecificEnthalpy,
+
+    /// Specific entropy of the fluid at the dead state.
+    pub entropy: SpecificEntropy,
+}
+
+impl DeadState {
+    /// Takes the dead state to be `state`, the fluid in equilibrium with
+    /// the environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PropertyError`] if a property cannot be evaluated.
+    pub fn new<Fluid>(
+        thermo: &(impl HasPressure<Fluid = Fluid> + HasEnthalpy + HasEntropy),
+        state: &State<Fluid>,
+    ) -> Result<Self, PropertyError> {
+        Ok(Self {
+            temperature: state.temperature,
+            pressure: thermo.pressure(state)?,
+            enthalpy: thermo.enthalpy(state)?,
+            entropy: thermo.entropy(state)?,
+        })
+    }
+
+    /// Builds the balance of a cooler that returns a stream to the dead
+    /// state, rejecting its heat to the environment.
+    ///
+    /// The stream's whole flow exergy is fuel, none of it is product, and
+    /// the heat leaves at `T₀`, so it carries no exergy: everything is
+    /// destroyed. Useful for closing an open cycle's audit.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PropertyError`] if the inlet properties cannot be
+    /// evaluated.
+    pub fn cooler<Fluid>(
+        &self,
+        component: impl Into<String>,
+        thermo: &(impl HasEnthalpy<Fluid = Fluid> + HasEntropy),
+        inlet: &State<Fluid>,
+        mass_flow: MassRate,
+    ) -> Result<ExergyBalance, PropertyError> {
+        let psi = flow_exergy(thermo, inlet, self)?;
+        Ok(ExergyBalance::new(component, self.temperature).stream(
+            mass_flow,
+            psi,
+            SpecificEnthalpy::ZERO,
+        ))
+    }
+}
+
+/// Returns the specific flow exergy `(h − h₀) − T₀·(s − s₀)` of `state`.
+///
+/// Kinetic and potential exergy are neglected.
+///
+/// # Errors
+///
+/// Returns a [`PropertyError`] if the enthalpy or entropy cannot be
+/// evaluated.
+pub fn flow_exergy<Fluid>(
+    thermo: &(impl HasEnthalpy<Fluid = Fluid> + HasEntropy),
+    state: &State<Fluid>,
+    dead: &DeadState,
+) -> Result<SpecificEnthalpy, PropertyError> {
+    let h = thermo.enthalpy(state)?;
+    let s = thermo.entropy(state)?;
+    Ok(h - dead.enthalpy - absolute(dead.temperature) * (s - dead.entropy))
+}
+
+impl ExergyBalance {
+    /// Builds the balance of an adiabatic mixer, or of a throttle when
+    /// there is one inlet.
+    ///
+    /// Each inlet is followed to the outlet state: inlets that gain exergy
+    /// on mixing contribute product and inlets that lose it contribute fuel.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PropertyError`] if a property cannot be evaluated.
+    pub fn mixer<Fluid>(
+        component: impl Into<String>,
+        thermo: &(impl HasEnthalpy<Fluid = Fluid> + HasEntropy),
+        inlets: &[FluidPort<Fluid>],
+        outlet: &State<Fluid>,
+        dead: &DeadState,
+    ) -> Result<Self, PropertyError> {
+        let psi_out = flow_exergy(thermo, outlet, dead)?;
+        inlets
+            .iter()
+            .try_fold(Self::new(component, dead.temperature), |balance, inlet| {
+                let psi_in = flow_exergy(thermo, &inlet.state, dead)?;
+                Ok(balance.stream(inlet.mass_flow, psi_in, psi_out))
+            })
+    }
+}
+
+/// Treats an absolute temperature as an interval above absolute zero, so it
+/// can multiply an entropy.
+fn absolute(temperature: ThermodynamicTemperature) -> TemperatureInterval {
+    TemperatureInterval::new::<delta_kelvin>(temperature.get::<kelvin>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        available_energy::kilojoule_per_kilogram, f64::Power, mass_rate::kilogram_per_second,
+        power::kilowatt, pressure::kilopascal,
+    };
+
+    use crate::support::thermo::{capability::StateFrom, fluid::Air, model::PerfectGas};
+
+    fn setup() -> (PerfectGas<Air>, DeadState) {
+        let air = PerfectGas::<Air>::new().unwrap();
+        let dead = DeadState::new(&air, &state(&air, 300.0, 100.0)).unwrap();
+        (air, dead)
+    }
+
+    fn state(air: &PerfectGas<Air>, t: f64, p: f64) -> State<Air> {
+        air.state_from((
+            Air,
+            ThermodynamicTemperature::new::<kelvin>(t),
+            Pressure::new::<kilopascal>(p),
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn flow_exergy_matches_the_perfect_gas_expression() {
+        let (air, dead) = setup();
+
+        assert_relative_eq!(
+            flow_exergy(&air, &state(&air, 300.0, 100.0), &dead)
+                .unwrap()
+                .get::<kilojoule_per_kilogram>(),
+            0.0,
+            epsilon = 1e-9
+        );
+
+        // ψ = cp·(T − T₀ − T₀·ln(T/T₀)) + R·T₀·ln(p/p₀).
+        let cp = 1.005;
+        let r = 0.287_053;
+        let expected = cp * (600.0 - 300.0 - 300.0 * 2.0_f64.ln()) + r * 300.0 * 5.0_f64.ln();
+        let psi = flow_exergy(&air, &state(&air, 600.0, 500.0), &dead).unwrap();
+        assert_relative_eq!(
+            psi.get::<kilojoule_per_kilogram>(),
+            expected,
+            max_relative = 1e-3
+        );
+    }
+
+    #[test]
+    fn mixing_destroys_exergy() {
+        let (air, dead) = setup();
+        let m_dot = MassRate::new::<kilogram_per_second>(1.0);
+        let hot = FluidPort::new(state(&air, 500.0, 100.0), m_dot);
+        let cold = FluidPort::new(state(&air, 300.0, 100.0), m_dot);
+        let outlet = state(&air, 400.0, 100.0);
+
+        let balance = ExergyBalance::mixer("mixer", &air, &[hot, cold], &outlet, &dead).unwrap();
+        assert!(balance.fuel() > balance.product());
+        assert!(balance.destroyed().get::<kilowatt>() > 1.0);
+        assert_relative_eq!(balance.loss().get::<kilowatt>(), 0.0);
+    }
+
+    #[test]
+    fn recuperator_destroys_exergy_across_its_temperature_difference() {
+        use twine_core::Model;
+        use uom::si::{f64::ThermalConductance, thermal_conductance::watt_per_kelvin};
+
+        use crate::models::thermal::hx::discretized::{
+            Inlets, MassFlows, PressureDrops, RecuperatorGivenUa, RecuperatorGivenUaConfig,
+            RecuperatorGivenUaInput,
+        };
+
+        let (air, dead) = setup();
+        let m_dot = MassRate::new::<kilogram_per_second>(1.0);
+        let input = RecuperatorGivenUaInput {
+            inlets: Inlets {
+                top: state(&air, 350.0, 400.0),
+                bottom: state(&air, 700.0, 100.0),
+            },
+            mass_flows: MassFlows::new_unchecked(m_dot, m_dot),
+            pressure_drops: PressureDrops::default(),
+            ua: ThermalConductance::new::<watt_per_kelvin>(2000.0),
+        };
+        let recuperator =
+            RecuperatorGivenUa::new(air, 10, RecuperatorGivenUaConfig::default()).unwrap();
+        let output = recuperator.call(&input).unwrap();
+
+        let balance = output.exergy_balance(&input, &air, &dead).unwrap();
+        let efficiency = balance.efficiency().unwrap();
+        assert!(efficiency > 0.5 && efficiency < 1.0);
+        assert!(balance.destroyed() > Power::ZERO);
+    }
+}
@@ -0,0 +1,407 @@
+use std::fmt;
+
+use uom::{
+    ConstZero,
+    si::{
+        f64::{MassRate, Power, ThermodynamicTemperature},
+        thermodynamic_temperature::kelvin,
+    },
+};
+
+use crate::support::units::{SpecificEnthalpy, display::pretty};
+
+/// Exergy fuel, product, and loss of one component.
+///
+/// Add every stream, work, and heat term crossing the component boundary;
+/// the [module documentation](super) explains how each is classified.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExergyBalance {
+    component: String,
+    dead_temperature: ThermodynamicTemperature,
+    fuel: Power,
+    product: Power,
+    loss: Power,
+}
+
+impl ExergyBalance {
+    /// Starts an empty balance for the named component, with environment
+    /// temperature `dead_temperature`.
+    #[must_use]
+    pub fn new(component: impl Into<String>, dead_temperature: ThermodynamicTemperature) -> Self {
+        Self {
+            component: component.into(),
+            dead_temperature,
+            fuel: Power::ZERO,
+            product: Power::ZERO,
+            loss: Power::ZERO,
+        }
+    }
+
+    /// Renames the component.
+    #[must_use]
+    pub fn named(mut self, component: impl Into<String>) -> Self {
+        self.component = component.into();
+        self
+    }
+
+    /// Adds a stream passing through the component, entering with specific
+    /// flow exergy `psi_in` and leaving with `psi_out`.
+    #[must_use]
+    pub fn stream(
+        mut self,
+        m_dot: MassRate,
+        psi_in: SpecificEnthalpy,
+        psi_out: SpecificEnthalpy,
+    ) -> Self {
+        let gain = m_dot * (psi_out - psi_in);
+        if gain >= Power::ZERO {
+            self.product += gain;
+        } else {
+            self.fuel -= gain;
+        }
+        self
+    }
+
+    /// Adds work done on the component (negative for work extracted).
+    #[must_use]
+    pub fn work_in(mut self, w_dot: Power) -> Self {
+        if w_dot >= Power::ZERO {
+            self.fuel += w_dot;
+        } else {
+            self.product -= w_dot;
+        }
+        self
+    }
+
+    /// Adds heat transferred into the component across a boundary at
+    /// `boundary_temperature` (negative for heat lost).
+    #[must_use]
+    pub fn heat_in(mut self, q_dot: Power, boundary_temperature: ThermodynamicTemperature) -> Self {
+        let exergy = q_dot * self.carnot(boundary_temperature);
+        if exergy >= Power::ZERO {
+            self.fuel += exergy;
+        } else {
+            self.loss -= exergy;
+        }
+        self
+    }
+
+    /// Adds heat delivered by the component as its product, such as a
+    /// heater's output, across a boundary at `boundary_temperature`.
+    ///
+    /// Unlike heat lost through [`heat_in`](Self::heat_in), its exergy
+    /// `Q̇·(1 − T₀/T_b)` counts as product rather than loss.
+    #[must_use]
+    pub fn heat_delivered(
+        mut self,
+        q_dot: Power,
+        boundary_temperature: ThermodynamicTemperature,
+    ) -> Self {
+        self.product += q_dot * self.carnot(boundary_temperature);
+        self
+    }
+
+    /// Returns the component name.
+    #[must_use]
+    pub fn component(&self) -> &str {
+        &self.component
+    }
+
+    /// Returns the exergy consumed.
+    #[must_use]
+    pub fn fuel(&self) -> Power {
+        self.fuel
+    }
+
+    /// Returns the exergy delivered.
+    #[must_use]
+    pub fn product(&self) -> Power {
+        self.product
+    }
+
+    /// Returns the exergy rejected to the environment.
+    #[must_use]
+    pub fn loss(&self) -> Power {
+        self.loss
+    }
+
+    /// Returns the exergy destroyed, `fuel − product − loss`.
+    ///
+    /// Negative destruction means the inputs violate the second law, which
+    /// usually points to a property or sign error upstream.
+    #[must_use]
+    pub fn destroyed(&self) -> Power {
+        self.fuel - self.product - self.loss
+    }
+
+    /// Returns the second-law efficiency `product / fuel`, or `None` for a
+    /// component that consumes no exergy.
+    #[must_use]
+    pub fn efficiency(&self) -> Option<f64> {
+        (self.fuel > Power::ZERO).then(|| (self.product / self.fuel).value)
+    }
+
+    /// Exergy per unit heat crossing a boundary at `temperature`.
+    fn carnot(&self, temperature: ThermodynamicTemperature) -> f64 {
+        1.0 - self.dead_temperature.get::<kelvin>() / temperature.get::<kelvin>()
+    }
+}
+
+/// Exergy balances for the components of a cycle.
+///
+/// ```
+/// use twine_models::support::exergy::{ExergyAudit, ExergyBalance};
+/// use uom::si::{
+///     f64::{Power, ThermodynamicTemperature},
+///     power::kilowatt,
+///     thermodynamic_temperature::kelvin,
+/// };
+///
+/// let t0 = ThermodynamicTemperature::new::<kelvin>(300.0);
+/// let kw = Power::new::<kilowatt>;
+///
+/// // A motor turns 10 kW of electricity into 9.5 kW of shaft work, and a
+/// // resistance heater turns 5 kW into heat delivered at 600 K.
+/// let report = ExergyAudit::new()
+///     .with(ExergyBalance::new("motor", t0).work_in(kw(10.0)).work_in(-kw(9.5)))
+///     .with(
+///         ExergyBalance::new("heater", t0)
+///             .work_in(kw(5.0))
+///             .heat_delivered(kw(5.0), ThermodynamicTemperature::new::<kelvin>(600.0)),
+///     )
+///     .report();
+///
+/// // Heat at 600 K is worth half its energy, so the heater destroys 2.5 kW.
+/// assert!((report.total_destroyed - kw(3.0)).get::<kilowatt>().abs() < 1e-12);
+/// assert_eq!(report.components[1].efficiency, Some(0.5));
+/// println!("{report}");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExergyAudit {
+    balances: Vec<ExergyBalance>,
+}
+
+impl ExergyAudit {
+    /// Creates an empty audit.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a component balance.
+    #[must_use]
+    pub fn with(mut self, balance: ExergyBalance) -> Self {
+        self.balances.push(balance);
+        self
+    }
+
+    /// Adds several component balances.
+    #[must_use]
+    pub fn with_all(mut self, balances: impl IntoIterator<Item = ExergyBalance>) -> Self {
+        self.balances.extend(balances);
+        self
+    }
+
+    /// Returns the component balances.
+    #[must_use]
+    pub fn balances(&self) -> &[ExergyBalance] {
+        &self.balances
+    }
+
+    /// Summarizes destruction and efficiency by component.
+    #[must_use]
+    pub fn report(&self) -> ExergyReport {
+        let total_destroyed: Power = self.balances.iter().map(ExergyBalance::destroyed).sum();
+        let total_loss: Power = self.balances.iter().map(ExergyBalance::loss).sum();
+
+        let components = self
+            .balances
+            .iter()
+            .map(|balance| {
+                let destroyed = balance.destroyed();
+                ComponentExergy {
+                    component: balance.component.clone(),
+                    fuel: balance.fuel,
+                    product: balance.product,
+                    loss: balance.loss,
+                    destroyed,
+                    efficiency: balance.efficiency(),
+                    share: (total_destroyed > Power::ZERO)
+                        .then(|| (destroyed / total_destroyed).value),
+                }
+            })
+            .collect();
+
+        ExergyReport {
+            components,
+            total_destroyed,
+            total_loss,
+        }
+    }
+}
+
+/// One component's line in an [`ExergyReport`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentExergy {
+    /// Name of the component.
+    pub component: String,
+
+    /// Exergy consumed.
+    pub fuel: Power,
+
+    /// Exergy delivered.
+    pub product: Power,
+
+    /// Exergy rejected to the environment.
+    pub loss: Power,
+
+    /// Exergy destroyed.
+    pub destroyed: Power,
+
+    /// Second-law efficiency, `None` if the component consumes no exergy.
+    pub efficiency: Option<f64>,
+
+    /// Fraction of the cycle's total destruction, `None` if nothing is
+    /// destroyed.
+    pub share: Option<f64>,
+}
+
+/// Where a cycle destroys and loses exergy.
+///
+/// Displays as a table with one row per component.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExergyReport {
+    /// Components in the order they were added to the audit.
+    pub components: Vec<ComponentExergy>,
+
+    /// Exergy destroyed across all components.
+    pub total_destroyed: Power,
+
+    /// Exergy lost to the environment across all components.
+    pub total_loss: Power,
+}
+
+impl ExergyReport {
+    /// Returns the components whose destruction is below `-tolerance`.
+    ///
+    /// A second-law violation means a component's inputs are inconsistent.
+    pub fn violations(&self, tolerance: Power) -> impl Iterator<Item = &ComponentExergy> {
+        self.components
+            .iter()
+            .filter(move |component| component.destroyed < -tolerance)
+    }
+}
+
+impl fmt::Display for ExergyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let percent = |value: Option<f64>| {
+            value.map_or_else(|| "-".to_owned(), |v| format!("{:.1}%", 100.0 * v))
+        };
+        let width = self
+            .components
+            .iter()
+            .map(|component| component.component.len())
+            .chain(["component".len()])
+            .max()
+            .unwrap_or_default();
+
+        writeln!(
+            f,
+            "{:width$}  {:>12}  {:>12}  {:>12}  {:>8}  {:>8}",
+            "component", "destroyed", "loss", "fuel", "η_II", "share"
+        )?;
+        for component in &self.components {
+            writeln!(
+                f,
+                "{:width$}  {:>12}  {:>12}  {:>12}  {:>8}  {:>8}",
+                component.component,
+                pretty(component.destroyed).to_string(),
+                pretty(component.loss).to_string(),
+                pretty(component.fuel).to_string(),
+                percent(component.efficiency),
+                percent(component.share),
+            )?;
+        }
+        write!(
+            f,
+            "{:width$}  {:>12}  {:>12}",
+            "total",
+            pretty(self.total_destroyed).to_string(),
+            pretty(self.total_loss).to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        available_energy::kilojoule_per_kilogram, mass_rate::kilogram_per_second, power::kilowatt,
+    };
+
+    fn t0() -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<kelvin>(300.0)
+    }
+
+    fn kw(value: f64) -> Power {
+        Power::new::<kilowatt>(value)
+    }
+
+    #[test]
+    fn classifies_terms_by_direction() {
+        let psi = SpecificEnthalpy::new::<kilojoule_per_kilogram>;
+        let m_dot = MassRate::new::<kilogram_per_second>(2.0);
+
+        // A counterflow exchanger: the hot stream gives up 40 kW of exergy,
+        // the cold stream gains 30 kW.
+        let hx = ExergyBalance::new("hx", t0())
+            .stream(m_dot, psi(50.0), psi(30.0))
+            .stream(m_dot, psi(5.0), psi(20.0));
+        assert_relative_eq!(hx.fuel().get::<kilowatt>(), 40.0);
+        assert_relative_eq!(hx.product().get::<kilowatt>(), 30.0);
+        assert_relative_eq!(hx.destroyed().get::<kilowatt>(), 10.0);
+        assert_relative_eq!(hx.efficiency().unwrap(), 0.75);
+
+        // Heat entering from a 600 K source is worth half its energy.
+        let boiler = ExergyBalance::new("boiler", t0())
+            .heat_in(kw(100.0), ThermodynamicTemperature::new::<kelvin>(600.0));
+        assert_relative_eq!(boiler.fuel().get::<kilowatt>(), 50.0);
+        assert_eq!(boiler.named("furnace").component(), "furnace");
+
+        let idle = ExergyBalance::new("idle", t0());
+        assert!(idle.efficiency().is_none());
+    }
+
+    #[test]
+    fn report_shares_and_violations() {
+        let report = ExergyAudit::new()
+            .with(
+                ExergyBalance::new("a", t0())
+                    .work_in(kw(4.0))
+                    .work_in(-kw(3.0)),
+            )
+            .with(
+                ExergyBalance::new("b", t0())
+                    .work_in(kw(6.0))
+                    .work_in(-kw(3.0)),
+            )
+            .with(
+                ExergyBalance::new("bad", t0())
+                    .work_in(kw(1.0))
+                    .work_in(-kw(1.5)),
+            )
+            .report();
+
+        assert_relative_eq!(report.total_destroyed.get::<kilowatt>(), 3.5);
+        assert_relative_eq!(report.components[1].share.unwrap(), 3.0 / 3.5);
+        let violations: Vec<_> = report.violations(kw(1e-9)).collect();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].component, "bad");
+
+        let table = report.to_string();
+        assert!(table.starts_with("component"));
+        assert_eq!(table.lines().count(), 5);
+    }
+}
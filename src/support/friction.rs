@@ -0,0 +1,32 @@
+//! Friction factors for internal flow.
+
+/// Darcy friction factor from the Churchill (1977) correlation.
+///
+/// A single expression spans laminar, transitional, and turbulent flow, so
+/// a pressure drop built on it stays continuous as the flow speeds up or
+/// reverses through zero.
+pub(crate) fn churchill(reynolds: f64, relative_roughness: f64) -> f64 {
+    let a = (-2.457 * ((7.0 / reynolds).powf(0.9) + 0.27 * relative_roughness).ln()).powi(16);
+    let b = (37_530.0 / reynolds).powi(16);
+    8.0 * ((8.0 / reynolds).powi(12) + (a + b).powf(-1.5)).powf(1.0 / 12.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn laminar_limit_is_64_over_re() {
+        assert_relative_eq!(churchill(100.0, 0.0), 0.64, max_relative = 1e-6);
+        assert_relative_eq!(churchill(1000.0, 0.0), 0.064, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn turbulent_smooth_pipe_matches_blasius() {
+        // Blasius gives 0.316·Re^(−1/4) for smooth pipes near Re = 10⁴.
+        let blasius = 0.316 * 1e4_f64.powf(-0.25);
+        assert_relative_eq!(churchill(1e4, 0.0), blasius, max_relative = 0.03);
+    }
+}
@@ -16,6 +16,27 @@
 //! - **Flow arrangements**: [`CounterFlow`], [`ParallelFlow`], [`CrossFlow`], [`ShellAndTube`]
 //! - **Analysis functions**: [`functional::known_conductance_and_inlets`],
 //!   [`functional::known_conditions_and_inlets`]
+//! - **Unit-free relations**: [`RawEffectivenessRelation`], [`RawNtuRelation`]
+//!   evaluate the same correlations over any float type, including `f32`
+//! - **Uncertainty propagation**: [`functional::known_conductance_and_inlets_uncertain`]
+//!   carries [`Uncertain`](crate::support::units::Uncertain) inputs through to the results
+//!
+//! With the `approx` feature enabled, the core types implement `AbsDiffEq`
+//! and `RelativeEq`, comparing values in SI base units. With the `proptest`
+//! feature enabled, [`CapacitanceRate`], [`Effectiveness`], and [`Ntu`]
+//! implement `Arbitrary` over practical engineering ranges.
+//!
+//! The toolkit is available without the `std` feature, so the raw relations
+//! also run on embedded targets:
+//!
+//! ```
+//! use twine_models::support::hx::{CounterFlow, RawEffectivenessRelation, RawNtuRelation};
+//!
+//! // Capacitance rates in W/K; any consistent unit works.
+//! let effectiveness = CounterFlow.effectiveness_raw(2.0_f32, [500.0, 1000.0]);
+//! let ntu = CounterFlow.ntu_raw(effectiveness, [500.0, 1000.0]);
+//! assert!((ntu - 2.0).abs() < 1e-4);
+//! ```
 //!
 //! # Example
 //!
@@ -58,6 +79,10 @@
 //! }
 //! ```
 
+#[cfg(feature = "approx")]
+mod approx_eq;
+#[cfg(feature = "proptest")]
+mod arbitrary;
 pub mod arrangement;
 mod capacitance_rate;
 mod capacity_ratio;
@@ -69,6 +94,9 @@ mod stream;
 pub use arrangement::{CounterFlow, CrossFlow, Mixed, ParallelFlow, ShellAndTube, Unmixed};
 pub use capacitance_rate::CapacitanceRate;
 pub use capacity_ratio::CapacityRatio;
-pub use effectiveness_ntu::{Effectiveness, EffectivenessRelation, Ntu, NtuRelation};
+pub use effectiveness_ntu::{
+    Effectiveness, EffectivenessRelation, Ntu, NtuRelation, RawEffectivenessRelation,
+    RawNtuRelation,
+};
 pub use flow::HeatFlow;
 pub use stream::{Stream, StreamInlet};
@@ -0,0 +1,84 @@
+//! Approximate equality for heat exchanger types.
+//!
+//! Each type is compared by its value in SI base units (or as a plain ratio),
+//! so the epsilon is an `f64` in those units. For example, a
+//! [`CapacitanceRate`] epsilon is in W/K and a [`HeatFlow`] epsilon is in W.
+
+use approx::{AbsDiffEq, RelativeEq};
+
+use super::{CapacitanceRate, Effectiveness, HeatFlow, Ntu};
+
+/// Implements `AbsDiffEq` and `RelativeEq` by comparing `$value(self)`.
+macro_rules! impl_approx_via_f64 {
+    ($ty:ty, $value:expr) => {
+        impl AbsDiffEq for $ty {
+            type Epsilon = f64;
+
+            fn default_epsilon() -> f64 {
+                f64::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+                let value: fn(&$ty) -> f64 = $value;
+                value(self).abs_diff_eq(&value(other), epsilon)
+            }
+        }
+
+        impl RelativeEq for $ty {
+            fn default_max_relative() -> f64 {
+                f64::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+                let value: fn(&$ty) -> f64 = $value;
+                value(self).relative_eq(&value(other), epsilon, max_relative)
+            }
+        }
+    };
+}
+
+impl_approx_via_f64!(CapacitanceRate, |c| c.value);
+impl_approx_via_f64!(Effectiveness, |e| e.value);
+impl_approx_via_f64!(Ntu, |n| n.value);
+impl_approx_via_f64!(HeatFlow, |q| q.signed().value);
+
+#[cfg(test)]
+mod tests {
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne};
+    use uom::si::{f64::Power, power::watt, thermal_conductance::watt_per_kelvin};
+
+    use super::*;
+
+    #[test]
+    fn capacitance_rate() {
+        let a = CapacitanceRate::new::<watt_per_kelvin>(1000.0).unwrap();
+        let b = CapacitanceRate::new::<watt_per_kelvin>(1000.0 + 1e-10).unwrap();
+        assert_relative_eq!(a, b, max_relative = 1e-12);
+        assert_relative_ne!(a, (a * 1.01).unwrap(), max_relative = 1e-3);
+    }
+
+    #[test]
+    fn effectiveness_and_ntu() {
+        assert_relative_eq!(
+            Effectiveness::new(0.1 + 0.2).unwrap(),
+            Effectiveness::new(0.3).unwrap()
+        );
+        assert_abs_diff_eq!(
+            Ntu::new(2.0).unwrap(),
+            Ntu::new(2.001).unwrap(),
+            epsilon = 1e-2
+        );
+    }
+
+    #[test]
+    fn heat_flow_compares_signed_values() {
+        let tiny_in = HeatFlow::incoming(Power::new::<watt>(1e-9)).unwrap();
+        let tiny_out = HeatFlow::outgoing(Power::new::<watt>(1e-9)).unwrap();
+        assert_abs_diff_eq!(tiny_in, HeatFlow::None, epsilon = 1e-6);
+        assert_abs_diff_eq!(tiny_in, tiny_out, epsilon = 1e-6);
+
+        let q = HeatFlow::from_signed(Power::new::<watt>(-500.0)).unwrap();
+        assert_relative_eq!(q, HeatFlow::outgoing(Power::new::<watt>(500.0)).unwrap());
+        assert_relative_ne!(q, HeatFlow::incoming(Power::new::<watt>(500.0)).unwrap());
+    }
+}
@@ -0,0 +1,92 @@
+//! Proptest support for heat exchanger types.
+//!
+//! Unlike the constraint markers, these strategies cover practical
+//! engineering ranges rather than every representable value:
+//!
+//! - [`Effectiveness`]: the full interval `[0, 1]`
+//! - [`Ntu`]: `[0, 100]`, well past where effectiveness saturates
+//! - [`CapacitanceRate`]: `[1e-3, 1e9]` W/K, from small lab flows
+//!   to large power plant streams
+
+use proptest::{
+    arbitrary::Arbitrary,
+    prelude::{BoxedStrategy, Strategy},
+};
+use uom::si::thermal_conductance::watt_per_kelvin;
+
+use super::{CapacitanceRate, Effectiveness, Ntu};
+
+impl Arbitrary for Effectiveness {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        (0.0..=1.0)
+            .prop_filter_map("valid effectiveness", |value| Self::new(value).ok())
+            .boxed()
+    }
+}
+
+impl Arbitrary for Ntu {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        (0.0..=100.0)
+            .prop_filter_map("valid NTU", |value| Self::new(value).ok())
+            .boxed()
+    }
+}
+
+impl Arbitrary for CapacitanceRate {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        (-3.0..=9.0_f64)
+            .prop_filter_map("valid capacitance rate", |exponent| {
+                Self::new::<watt_per_kelvin>(10.0_f64.powf(exponent)).ok()
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use uom::si::ratio::ratio;
+
+    use super::*;
+    use crate::support::hx::{CounterFlow, EffectivenessRelation, ParallelFlow};
+
+    proptest! {
+        #[test]
+        fn effectiveness_is_monotonic_in_ntu(
+            ntu in any::<Ntu>(),
+            capacitance_rates in any::<[CapacitanceRate; 2]>(),
+        ) {
+            let bigger = Ntu::new(ntu.get::<ratio>() + 0.1).unwrap();
+            for (lower, higher) in [
+                (
+                    CounterFlow.effectiveness(ntu, capacitance_rates),
+                    CounterFlow.effectiveness(bigger, capacitance_rates),
+                ),
+                (
+                    ParallelFlow.effectiveness(ntu, capacitance_rates),
+                    ParallelFlow.effectiveness(bigger, capacitance_rates),
+                ),
+            ] {
+                prop_assert!(higher.get::<ratio>() >= lower.get::<ratio>() - 1e-12);
+            }
+        }
+
+        #[test]
+        fn generated_values_are_in_range(
+            effectiveness in any::<Effectiveness>(),
+            capacitance_rate in any::<CapacitanceRate>(),
+        ) {
+            prop_assert!((0.0..=1.0).contains(&effectiveness.get::<ratio>()));
+            prop_assert!(capacitance_rate.get::<watt_per_kelvin>() > 0.0);
+        }
+    }
+}
@@ -1,40 +1,59 @@
 //! Counter-flow effectiveness-NTU relationships.
 
+use num_traits::Float;
+
 use crate::support::hx::{
     CapacitanceRate, Effectiveness, Ntu,
-    effectiveness_ntu::{EffectivenessRelation, NtuRelation, effectiveness_via, ntu_via},
+    effectiveness_ntu::{
+        EffectivenessRelation, NtuRelation, RawEffectivenessRelation, RawNtuRelation,
+        effectiveness_via, ntu_via, typed_effectiveness, typed_ntu,
+    },
 };
 
 /// Counter-flow heat exchanger arrangement.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct CounterFlow;
 
-impl EffectivenessRelation for CounterFlow {
-    fn effectiveness(&self, ntu: Ntu, capacitance_rates: [CapacitanceRate; 2]) -> Effectiveness {
+impl RawEffectivenessRelation for CounterFlow {
+    fn effectiveness_raw<F: Float>(&self, ntu: F, capacitance_rates: [F; 2]) -> F {
         effectiveness_via(ntu, capacitance_rates, |ntu, cr| {
-            if cr < 1. {
-                (1. - (-ntu * (1. - cr)).exp()) / (1. - cr * (-ntu * (1. - cr)).exp())
+            let one = F::one();
+            if cr < one {
+                (one - (-ntu * (one - cr)).exp()) / (one - cr * (-ntu * (one - cr)).exp())
             } else {
                 // cr == 1
-                ntu / (1. + ntu)
+                ntu / (one + ntu)
             }
         })
     }
 }
 
-impl NtuRelation for CounterFlow {
-    fn ntu(&self, effectiveness: Effectiveness, capacitance_rates: [CapacitanceRate; 2]) -> Ntu {
+impl RawNtuRelation for CounterFlow {
+    fn ntu_raw<F: Float>(&self, effectiveness: F, capacitance_rates: [F; 2]) -> F {
         ntu_via(effectiveness, capacitance_rates, |eff, cr| {
-            if cr < 1. {
-                (((1. - eff * cr) / (1. - eff)).ln()) / (1. - cr)
+            let one = F::one();
+            if cr < one {
+                (((one - eff * cr) / (one - eff)).ln()) / (one - cr)
             } else {
                 // cr == 1
-                eff / (1. - eff)
+                eff / (one - eff)
             }
         })
     }
 }
 
+impl EffectivenessRelation for CounterFlow {
+    fn effectiveness(&self, ntu: Ntu, capacitance_rates: [CapacitanceRate; 2]) -> Effectiveness {
+        typed_effectiveness(self, ntu, capacitance_rates)
+    }
+}
+
+impl NtuRelation for CounterFlow {
+    fn ntu(&self, effectiveness: Effectiveness, capacitance_rates: [CapacitanceRate; 2]) -> Ntu {
+        typed_ntu(self, effectiveness, capacitance_rates)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::support::constraint::ConstraintResult;
@@ -73,4 +92,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn raw_f32_matches_typed() -> ConstraintResult<()> {
+        let rates = [
+            CapacitanceRate::new::<watt_per_kelvin>(1.)?,
+            CapacitanceRate::new::<watt_per_kelvin>(2.)?,
+        ];
+        let typed = CounterFlow.effectiveness(Ntu::new(1.5)?, rates);
+        let raw = CounterFlow.effectiveness_raw(1.5_f32, [1., 2.]);
+
+        assert_relative_eq!(f64::from(raw), typed.get::<ratio>(), max_relative = 1e-6);
+        assert_relative_eq!(CounterFlow.ntu_raw(raw, [1., 2.]), 1.5, max_relative = 1e-5);
+        Ok(())
+    }
 }
@@ -1,10 +1,15 @@
 //! Cross-flow effectiveness-NTU relationships.
 
-use std::marker::PhantomData;
+use core::marker::PhantomData;
+
+use num_traits::Float;
 
 use crate::support::hx::{
     CapacitanceRate, Effectiveness, Ntu,
-    effectiveness_ntu::{EffectivenessRelation, NtuRelation, effectiveness_via, ntu_via},
+    effectiveness_ntu::{
+        EffectivenessRelation, NtuRelation, RawEffectivenessRelation, RawNtuRelation,
+        effectiveness_via, lit, ntu_via, typed_effectiveness, typed_ntu,
+    },
 };
 
 /// Cross-flow heat exchanger arrangement.
@@ -24,8 +29,10 @@ impl<T: MixState, U: MixState> CrossFlow<T, U> {
 }
 
 /// Marker type for a cross-flow stream that is mixed across the flow channel.
+#[derive(Debug, Clone, Copy, Default)]
 pub struct Mixed;
 /// Marker type for a cross-flow stream that remains unmixed across the flow channel.
+#[derive(Debug, Clone, Copy, Default)]
 pub struct Unmixed;
 
 /// Trait for marker types representing the mixing state of a cross-flow stream.
@@ -33,61 +40,84 @@ pub trait MixState {}
 impl MixState for Mixed {}
 impl MixState for Unmixed {}
 
-impl EffectivenessRelation for CrossFlow<Unmixed, Unmixed> {
-    fn effectiveness(&self, ntu: Ntu, capacitance_rates: [CapacitanceRate; 2]) -> Effectiveness {
+impl RawEffectivenessRelation for CrossFlow<Unmixed, Unmixed> {
+    fn effectiveness_raw<F: Float>(&self, ntu: F, capacitance_rates: [F; 2]) -> F {
         effectiveness_via(ntu, capacitance_rates, |ntu, cr| {
-            1. - ((ntu.powf(0.22) / cr) * ((-cr * ntu.powf(0.78)).exp() - 1.)).exp()
+            F::one()
+                - ((ntu.powf(lit(0.22)) / cr) * ((-cr * ntu.powf(lit(0.78))).exp() - F::one()))
+                    .exp()
         })
     }
 }
 
-impl EffectivenessRelation for CrossFlow<Mixed, Mixed> {
-    fn effectiveness(&self, ntu: Ntu, capacitance_rates: [CapacitanceRate; 2]) -> Effectiveness {
+impl RawEffectivenessRelation for CrossFlow<Mixed, Mixed> {
+    fn effectiveness_raw<F: Float>(&self, ntu: F, capacitance_rates: [F; 2]) -> F {
         effectiveness_via(ntu, capacitance_rates, |ntu, cr| {
-            1. / (1. / (1. - (-ntu).exp()) + cr / (1. - (-cr * ntu).exp()) - 1. / ntu)
+            let one = F::one();
+            one / (one / (one - (-ntu).exp()) + cr / (one - (-cr * ntu).exp()) - one / ntu)
         })
     }
 }
 
-impl EffectivenessRelation for CrossFlow<Mixed, Unmixed> {
-    fn effectiveness(&self, ntu: Ntu, capacitance_rates: [CapacitanceRate; 2]) -> Effectiveness {
+impl RawEffectivenessRelation for CrossFlow<Mixed, Unmixed> {
+    fn effectiveness_raw<F: Float>(&self, ntu: F, capacitance_rates: [F; 2]) -> F {
+        let one = F::one();
         if capacitance_rates[0] >= capacitance_rates[1] {
             effectiveness_via(ntu, capacitance_rates, |ntu, cr| {
-                (1. - (cr * ((-ntu).exp() - 1.)).exp()) / cr
+                (one - (cr * ((-ntu).exp() - one)).exp()) / cr
             })
         } else {
             effectiveness_via(ntu, capacitance_rates, |ntu, cr| {
-                1. - (-((1. - (-cr * ntu).exp()) / cr)).exp()
+                one - (-((one - (-cr * ntu).exp()) / cr)).exp()
             })
         }
     }
 }
 
-impl EffectivenessRelation for CrossFlow<Unmixed, Mixed> {
-    fn effectiveness(&self, ntu: Ntu, capacitance_rates: [CapacitanceRate; 2]) -> Effectiveness {
+impl RawEffectivenessRelation for CrossFlow<Unmixed, Mixed> {
+    fn effectiveness_raw<F: Float>(&self, ntu: F, capacitance_rates: [F; 2]) -> F {
         CrossFlow::<Mixed, Unmixed>::new()
-            .effectiveness(ntu, [capacitance_rates[1], capacitance_rates[0]])
+            .effectiveness_raw(ntu, [capacitance_rates[1], capacitance_rates[0]])
     }
 }
 
-impl NtuRelation for CrossFlow<Mixed, Unmixed> {
-    fn ntu(&self, effectiveness: Effectiveness, capacitance_rates: [CapacitanceRate; 2]) -> Ntu {
+impl RawNtuRelation for CrossFlow<Mixed, Unmixed> {
+    fn ntu_raw<F: Float>(&self, effectiveness: F, capacitance_rates: [F; 2]) -> F {
+        let one = F::one();
         if capacitance_rates[0] >= capacitance_rates[1] {
             ntu_via(effectiveness, capacitance_rates, |eff, cr| {
-                -(1. + (1. - eff * cr).ln() / cr).ln()
+                -(one + (one - eff * cr).ln() / cr).ln()
             })
         } else {
             ntu_via(effectiveness, capacitance_rates, |eff, cr| {
-                -(cr * (1. - eff).ln() + 1.).ln() / cr
+                -(cr * (one - eff).ln() + one).ln() / cr
             })
         }
     }
 }
 
-impl NtuRelation for CrossFlow<Unmixed, Mixed> {
-    fn ntu(&self, effectiveness: Effectiveness, capacitance_rates: [CapacitanceRate; 2]) -> Ntu {
+impl RawNtuRelation for CrossFlow<Unmixed, Mixed> {
+    fn ntu_raw<F: Float>(&self, effectiveness: F, capacitance_rates: [F; 2]) -> F {
         CrossFlow::<Mixed, Unmixed>::new()
-            .ntu(effectiveness, [capacitance_rates[1], capacitance_rates[0]])
+            .ntu_raw(effectiveness, [capacitance_rates[1], capacitance_rates[0]])
+    }
+}
+
+impl<T: MixState, U: MixState> EffectivenessRelation for CrossFlow<T, U>
+where
+    Self: RawEffectivenessRelation,
+{
+    fn effectiveness(&self, ntu: Ntu, capacitance_rates: [CapacitanceRate; 2]) -> Effectiveness {
+        typed_effectiveness(self, ntu, capacitance_rates)
+    }
+}
+
+impl<T: MixState, U: MixState> NtuRelation for CrossFlow<T, U>
+where
+    Self: RawNtuRelation,
+{
+    fn ntu(&self, effectiveness: Effectiveness, capacitance_rates: [CapacitanceRate; 2]) -> Ntu {
+        typed_ntu(self, effectiveness, capacitance_rates)
     }
 }
 
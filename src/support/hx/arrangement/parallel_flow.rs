@@ -1,30 +1,47 @@
 //! Parallel-flow effectiveness-NTU relationships.
 
+use num_traits::Float;
+
 use crate::support::hx::{
     CapacitanceRate, Effectiveness, Ntu,
-    effectiveness_ntu::{EffectivenessRelation, NtuRelation, effectiveness_via, ntu_via},
+    effectiveness_ntu::{
+        EffectivenessRelation, NtuRelation, RawEffectivenessRelation, RawNtuRelation,
+        effectiveness_via, ntu_via, typed_effectiveness, typed_ntu,
+    },
 };
 
 /// Parallel-flow heat exchanger arrangement.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct ParallelFlow;
 
-impl EffectivenessRelation for ParallelFlow {
-    fn effectiveness(&self, ntu: Ntu, capacitance_rates: [CapacitanceRate; 2]) -> Effectiveness {
+impl RawEffectivenessRelation for ParallelFlow {
+    fn effectiveness_raw<F: Float>(&self, ntu: F, capacitance_rates: [F; 2]) -> F {
         effectiveness_via(ntu, capacitance_rates, |ntu, cr| {
-            (1. - (-ntu * (1. + cr)).exp()) / (1. + cr)
+            (F::one() - (-ntu * (F::one() + cr)).exp()) / (F::one() + cr)
         })
     }
 }
 
-impl NtuRelation for ParallelFlow {
-    fn ntu(&self, effectiveness: Effectiveness, capacitance_rates: [CapacitanceRate; 2]) -> Ntu {
+impl RawNtuRelation for ParallelFlow {
+    fn ntu_raw<F: Float>(&self, effectiveness: F, capacitance_rates: [F; 2]) -> F {
         ntu_via(effectiveness, capacitance_rates, |eff, cr| {
-            -(1. - eff * (1. + cr)).ln() / (1. + cr)
+            -(F::one() - eff * (F::one() + cr)).ln() / (F::one() + cr)
         })
     }
 }
 
+impl EffectivenessRelation for ParallelFlow {
+    fn effectiveness(&self, ntu: Ntu, capacitance_rates: [CapacitanceRate; 2]) -> Effectiveness {
+        typed_effectiveness(self, ntu, capacitance_rates)
+    }
+}
+
+impl NtuRelation for ParallelFlow {
+    fn ntu(&self, effectiveness: Effectiveness, capacitance_rates: [CapacitanceRate; 2]) -> Ntu {
+        typed_ntu(self, effectiveness, capacitance_rates)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::support::constraint::ConstraintResult;
@@ -1,12 +1,16 @@
 //! Shell-and-tube effectiveness-NTU relationships.
 
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
+use num_traits::Float;
 use thiserror::Error;
 
 use crate::support::hx::{
     CapacitanceRate, Effectiveness, Ntu,
-    effectiveness_ntu::{EffectivenessRelation, NtuRelation, effectiveness_via, ntu_via},
+    effectiveness_ntu::{
+        EffectivenessRelation, NtuRelation, RawEffectivenessRelation, RawNtuRelation,
+        effectiveness_via, lit, ntu_via, typed_effectiveness, typed_ntu,
+    },
 };
 
 /// Shell-and-tube heat exchanger arrangement.
@@ -50,49 +54,71 @@ impl<const S: u16, const T: u16> ShellAndTube<S, T> {
     }
 }
 
-impl<const S: u16, const T: u16> EffectivenessRelation for ShellAndTube<S, T> {
-    fn effectiveness(&self, ntu: Ntu, capacitance_rates: [CapacitanceRate; 2]) -> Effectiveness {
-        let eff_1: fn(f64, f64) -> f64 = |ntu_1, cr| {
-            2. / (1.
-                + cr
-                + (1. + cr.powi(2)).sqrt() * (1. + (-ntu_1 * (1. + cr.powi(2)).sqrt()).exp())
-                    / (1. - (-ntu_1 * (1. + cr.powi(2)).sqrt()).exp()))
+impl<const S: u16, const T: u16> Default for ShellAndTube<S, T> {
+    /// Returns the arrangement for these pass counts.
+    ///
+    /// Unsupported pass counts are rejected at compile time.
+    fn default() -> Self {
+        const {
+            assert!(
+                Self::validate().is_ok(),
+                "shell-and-tube pass counts are not supported"
+            );
+        }
+
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<const S: u16, const T: u16> RawEffectivenessRelation for ShellAndTube<S, T> {
+    fn effectiveness_raw<F: Float>(&self, ntu: F, capacitance_rates: [F; 2]) -> F {
+        let one = F::one();
+        let eff_1 = |ntu_1: F, cr: F| {
+            let root = (one + cr.powi(2)).sqrt();
+            lit::<F>(2.)
+                / (one + cr + root * (one + (-ntu_1 * root).exp()) / (one - (-ntu_1 * root).exp()))
         };
 
         if S == 1 {
             effectiveness_via(ntu, capacitance_rates, eff_1)
         } else {
+            let shells: F = lit(f64::from(S));
             effectiveness_via(ntu, capacitance_rates, |ntu_1, cr| {
                 let eff_1 = eff_1(ntu_1, cr);
 
-                if cr < 1. {
-                    (((1. - eff_1 * cr) / (1. - eff_1)).powi(S.into()) - 1.)
-                        / (((1. - eff_1 * cr) / (1. - eff_1)).powi(S.into()) - cr)
+                if cr < one {
+                    (((one - eff_1 * cr) / (one - eff_1)).powi(S.into()) - one)
+                        / (((one - eff_1 * cr) / (one - eff_1)).powi(S.into()) - cr)
                 } else {
                     // cr == 1
-                    (f64::from(S) * eff_1) / (1. + eff_1 * (f64::from(S) - 1.))
+                    (shells * eff_1) / (one + eff_1 * (shells - one))
                 }
             })
         }
     }
 }
 
-impl<const S: u16, const T: u16> NtuRelation for ShellAndTube<S, T> {
-    fn ntu(&self, effectiveness: Effectiveness, capacitance_rates: [CapacitanceRate; 2]) -> Ntu {
-        let ntu_1: fn(f64, f64) -> f64 = |eff_1, cr| {
-            let e = (2. - eff_1 * (1. + cr)) / (eff_1 * (1. + cr.powi(2)).sqrt());
-            ((e + 1.) / (e - 1.)).ln() / (1. + cr.powi(2)).sqrt()
+impl<const S: u16, const T: u16> RawNtuRelation for ShellAndTube<S, T> {
+    fn ntu_raw<F: Float>(&self, effectiveness: F, capacitance_rates: [F; 2]) -> F {
+        let one = F::one();
+        let ntu_1 = |eff_1: F, cr: F| {
+            let root = (one + cr.powi(2)).sqrt();
+            let e = (lit::<F>(2.) - eff_1 * (one + cr)) / (eff_1 * root);
+            ((e + one) / (e - one)).ln() / root
         };
 
         if S == 1 {
             ntu_via(effectiveness, capacitance_rates, ntu_1)
         } else {
+            let shells: F = lit(f64::from(S));
             ntu_via(effectiveness, capacitance_rates, |eff, cr| {
-                let eff_1 = if cr < 1. {
-                    let f = ((eff * cr - 1.) / (eff - 1.)).powf(1.0 / f64::from(S));
-                    (f - 1.) / (f - cr)
+                let eff_1 = if cr < one {
+                    let f = ((eff * cr - one) / (eff - one)).powf(one / shells);
+                    (f - one) / (f - cr)
                 } else {
-                    eff / (f64::from(S) - eff * (f64::from(S) - 1.))
+                    eff / (shells - eff * (shells - one))
                 };
                 ntu_1(eff_1, cr)
             })
@@ -100,6 +126,18 @@ impl<const S: u16, const T: u16> NtuRelation for ShellAndTube<S, T> {
     }
 }
 
+impl<const S: u16, const T: u16> EffectivenessRelation for ShellAndTube<S, T> {
+    fn effectiveness(&self, ntu: Ntu, capacitance_rates: [CapacitanceRate; 2]) -> Effectiveness {
+        typed_effectiveness(self, ntu, capacitance_rates)
+    }
+}
+
+impl<const S: u16, const T: u16> NtuRelation for ShellAndTube<S, T> {
+    fn ntu(&self, effectiveness: Effectiveness, capacitance_rates: [CapacitanceRate; 2]) -> Ntu {
+        typed_ntu(self, effectiveness, capacitance_rates)
+    }
+}
+
 /// Errors returned when constructing a [`ShellAndTube`] arrangement.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
 pub enum ShellAndTubeConfigError {
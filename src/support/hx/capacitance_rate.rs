@@ -1,4 +1,4 @@
-use std::ops::Deref;
+use core::ops::{Deref, Div, Mul};
 
 use crate::support::constraint::{Constrained, ConstraintResult, StrictlyPositive};
 use uom::si::f64::{MassRate, SpecificHeatCapacity, ThermalConductance};
@@ -54,6 +54,24 @@ impl Deref for CapacitanceRate {
     }
 }
 
+/// Scales a capacitance rate, re-checking that the result is strictly positive.
+impl Mul<f64> for CapacitanceRate {
+    type Output = ConstraintResult<Self>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Ok(Self((self.0 * rhs)?))
+    }
+}
+
+/// Divides a capacitance rate, re-checking that the result is strictly positive.
+impl Div<f64> for CapacitanceRate {
+    type Output = ConstraintResult<Self>;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Ok(Self((self.0 / rhs)?))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -75,4 +93,15 @@ mod tests {
         assert_relative_eq!(capacitance_rate.get::<watt_per_kelvin>(), 40000.);
         Ok(())
     }
+
+    #[test]
+    fn scaling() -> ConstraintResult<()> {
+        let capacitance_rate = CapacitanceRate::new::<watt_per_kelvin>(100.)?;
+
+        assert_relative_eq!((capacitance_rate * 2.)?.get::<watt_per_kelvin>(), 200.);
+        assert_relative_eq!((capacitance_rate / 4.)?.get::<watt_per_kelvin>(), 25.);
+        assert!((capacitance_rate * 0.).is_err());
+        assert!((capacitance_rate / -1.).is_err());
+        Ok(())
+    }
 }
@@ -1,4 +1,6 @@
-use std::ops::Deref;
+use core::ops::Deref;
+
+use num_traits::Float;
 
 use crate::support::constraint::{Constrained, ConstraintResult, NonNegative, UnitInterval};
 use uom::si::{
@@ -6,19 +8,19 @@ use uom::si::{
     ratio::ratio,
 };
 
-use super::{CapacitanceRate, CapacityRatio};
+use super::CapacitanceRate;
 
 /// Trait for computing heat exchanger effectiveness from NTU.
 pub trait EffectivenessRelation {
     /// Calculate the effectiveness for an arrangement given the [NTU](Ntu) and
-    /// [capacity ratio](CapacityRatio).
+    /// [capacity ratio](super::CapacityRatio).
     fn effectiveness(&self, ntu: Ntu, capacitance_rates: [CapacitanceRate; 2]) -> Effectiveness;
 }
 
 /// Trait for computing NTU from heat exchanger effectiveness.
 pub trait NtuRelation {
     /// Calculate the [NTU](Ntu) for an arrangement given the
-    /// [effectiveness](Effectiveness) and [capacity ratio](CapacityRatio).
+    /// [effectiveness](Effectiveness) and [capacity ratio](super::CapacityRatio).
     fn ntu(&self, effectiveness: Effectiveness, capacitance_rates: [CapacitanceRate; 2]) -> Ntu;
 }
 
@@ -28,7 +30,7 @@ pub trait NtuRelation {
 /// the maximum possible amount of heat transferred in the heat exchanger.
 ///
 /// The effectiveness must be in the interval [0, 1].
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct Effectiveness(Constrained<Ratio, UnitInterval>);
 
 impl Effectiveness {
@@ -115,37 +117,96 @@ impl Deref for Ntu {
     }
 }
 
+/// Trait for computing heat exchanger effectiveness from NTU in any float
+/// type.
+///
+/// This is the unit-free form of [`EffectivenessRelation`], for callers that
+/// work in `f32` or keep values outside `uom`. Every provided arrangement
+/// implements both traits with the same relation.
+pub trait RawEffectivenessRelation {
+    /// Calculate the effectiveness for an arrangement given the NTU and the
+    /// capacitance rates of both streams.
+    ///
+    /// The capacitance rates may be in any unit, as long as both use the same
+    /// one. They must be positive, and the NTU must be non-negative.
+    fn effectiveness_raw<F: Float>(&self, ntu: F, capacitance_rates: [F; 2]) -> F;
+}
+
+/// Trait for computing NTU from heat exchanger effectiveness in any float
+/// type.
+///
+/// This is the unit-free form of [`NtuRelation`].
+pub trait RawNtuRelation {
+    /// Calculate the NTU for an arrangement given the effectiveness and the
+    /// capacitance rates of both streams.
+    ///
+    /// The capacitance rates may be in any unit, as long as both use the same
+    /// one. They must be positive, and the effectiveness must be achievable
+    /// by the arrangement.
+    fn ntu_raw<F: Float>(&self, effectiveness: F, capacitance_rates: [F; 2]) -> F;
+}
+
+/// Converts an `f64` literal into the float type of a raw relation.
 #[inline]
-pub(crate) fn effectiveness_via(
+pub(crate) fn lit<F: Float>(value: f64) -> F {
+    F::from(value).expect("literal should be representable in any float type")
+}
+
+/// Returns the capacity ratio `C_min / C_max` of two capacitance rates.
+#[inline]
+pub(crate) fn capacity_ratio<F: Float>(capacitance_rates: [F; 2]) -> F {
+    let [first, second] = capacitance_rates;
+    first.min(second) / first.max(second)
+}
+
+#[inline]
+pub(crate) fn effectiveness_via<F: Float>(
+    ntu: F,
+    capacitance_rates: [F; 2],
+    fn_raw: impl Fn(F, F) -> F,
+) -> F {
+    let cr = capacity_ratio(capacitance_rates);
+    if cr.is_zero() {
+        return F::one() - (-ntu).exp();
+    }
+    fn_raw(ntu, cr)
+}
+
+#[inline]
+pub(crate) fn ntu_via<F: Float>(
+    effectiveness: F,
+    capacitance_rates: [F; 2],
+    fn_raw: impl Fn(F, F) -> F,
+) -> F {
+    let cr = capacity_ratio(capacitance_rates);
+    if cr.is_zero() {
+        return -(F::one() - effectiveness).ln();
+    }
+    fn_raw(effectiveness, cr)
+}
+
+/// Evaluates a raw relation with the typed inputs of [`EffectivenessRelation`].
+#[inline]
+pub(crate) fn typed_effectiveness(
+    arrangement: &impl RawEffectivenessRelation,
     ntu: Ntu,
     capacitance_rates: [CapacitanceRate; 2],
-    fn_raw: impl Fn(f64, f64) -> f64,
 ) -> Effectiveness {
-    let cr = CapacityRatio::from_capacitance_rates(capacitance_rates).get::<ratio>();
-    let ntu = ntu.get::<ratio>();
-    if cr == 0.0 {
-        return {
-            Effectiveness::new(1. - (-ntu).exp())
-                .expect("ntu should always yield valid effectiveness")
-        };
-    }
-    Effectiveness::new(fn_raw(ntu, cr)).expect("ntu should always yield valid effectiveness")
+    let rates = capacitance_rates.map(|rate| rate.value);
+    Effectiveness::new(arrangement.effectiveness_raw(ntu.get::<ratio>(), rates))
+        .expect("ntu should always yield valid effectiveness")
 }
 
+/// Evaluates a raw relation with the typed inputs of [`NtuRelation`].
 #[inline]
-pub(crate) fn ntu_via(
+pub(crate) fn typed_ntu(
+    arrangement: &impl RawNtuRelation,
     effectiveness: Effectiveness,
     capacitance_rates: [CapacitanceRate; 2],
-    fn_raw: impl Fn(f64, f64) -> f64,
 ) -> Ntu {
-    let cr = CapacityRatio::from_capacitance_rates(capacitance_rates).get::<ratio>();
-    let eff = effectiveness.get::<ratio>();
-    if cr == 0.0 {
-        return {
-            Ntu::new(-(1. - eff).ln()).expect("effectiveness should always yield valid ntu")
-        };
-    }
-    Ntu::new(fn_raw(eff, cr)).expect("effectiveness should always yield valid ntu")
+    let rates = capacitance_rates.map(|rate| rate.value);
+    Ntu::new(arrangement.ntu_raw(effectiveness.get::<ratio>(), rates))
+        .expect("effectiveness should always yield valid ntu")
 }
 
 #[cfg(test)]
@@ -155,6 +216,13 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn capacity_ratio_of_rates() {
+        assert_relative_eq!(capacity_ratio([10., 20.]), 0.5);
+        assert_relative_eq!(capacity_ratio([20_f32, 10.]), 0.5);
+        assert_relative_eq!(capacity_ratio([1., f64::INFINITY]), 0.);
+    }
+
     #[test]
     fn ntu_from_conductance_and_capacitance_rates() -> ConstraintResult<()> {
         let ua = ThermalConductance::new::<watt_per_kelvin>(10.);
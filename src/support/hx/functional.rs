@@ -1,5 +1,7 @@
 //! Functional helpers for common heat exchanger calculations.
 
+mod uncertain;
+
 use crate::support::{
     constraint::{ConstraintError, ConstraintResult},
     units::TemperatureDifference,
@@ -9,6 +11,10 @@ use uom::{
     si::f64::{Power, ThermalConductance},
 };
 
+pub use uncertain::{
+    UncertainKnownConductanceResult, UncertainStreamInlet, known_conductance_and_inlets_uncertain,
+};
+
 use super::{
     Effectiveness, HeatFlow, Ntu, StreamInlet,
     effectiveness_ntu::{EffectivenessRelation, NtuRelation},
@@ -145,15 +151,15 @@ fn calculate_max_heat_flow(inlets: [StreamInlet; 2]) -> ConstraintResult<[Stream
             .partial_cmp(&Power::ZERO)
             .expect("heat flow should not be NaN")
         {
-            std::cmp::Ordering::Less => [
+            core::cmp::Ordering::Less => [
                 inlets[0].with_heat_flow(HeatFlow::incoming(max_heat_flow.abs())?),
                 inlets[1].with_heat_flow(HeatFlow::outgoing(max_heat_flow.abs())?),
             ],
-            std::cmp::Ordering::Equal => [
+            core::cmp::Ordering::Equal => [
                 inlets[0].with_heat_flow(HeatFlow::None),
                 inlets[1].with_heat_flow(HeatFlow::None),
             ],
-            std::cmp::Ordering::Greater => [
+            core::cmp::Ordering::Greater => [
                 inlets[0].with_heat_flow(HeatFlow::outgoing(max_heat_flow)?),
                 inlets[1].with_heat_flow(HeatFlow::incoming(max_heat_flow)?),
             ],
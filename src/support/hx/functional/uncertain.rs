@@ -0,0 +1,272 @@
+//! First-order uncertainty propagation through heat exchanger calculations.
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float as _;
+use uom::si::{
+    f64::{Power, ThermalConductance, ThermodynamicTemperature},
+    ratio::ratio,
+};
+
+use crate::support::{
+    constraint::ConstraintResult,
+    hx::{CapacitanceRate, StreamInlet, effectiveness_ntu::EffectivenessRelation},
+    units::{Uncertain, UncertainValue},
+};
+
+use super::{KnownConductanceResult, known_conductance_and_inlets};
+
+/// Inlet state whose capacitance rate and temperature carry uncertainty.
+#[derive(Debug, Clone, Copy)]
+pub struct UncertainStreamInlet {
+    /// Capacitance rate of the stream.
+    pub capacitance_rate: Uncertain<ThermalConductance>,
+    /// Temperature at the exchanger inlet.
+    pub temperature: Uncertain<ThermodynamicTemperature>,
+}
+
+/// Result returned from [`known_conductance_and_inlets_uncertain`].
+#[derive(Debug, Clone, Copy)]
+pub struct UncertainKnownConductanceResult {
+    /// Result evaluated at the nominal inputs.
+    pub nominal: KnownConductanceResult,
+    /// Magnitude of the heat transfer rate between the streams.
+    pub heat_rate: Uncertain<Power>,
+    /// Outlet temperature of each stream (same order as the inputs).
+    pub outlet_temperatures: [Uncertain<ThermodynamicTemperature>; 2],
+    /// Overall effectiveness, as a fraction.
+    pub effectiveness: Uncertain<f64>,
+}
+
+/// Number of uncertain inputs: UA, then capacitance rate and temperature for each inlet.
+const INPUTS: usize = 5;
+
+/// Number of propagated outputs: heat rate, two outlet temperatures, effectiveness.
+const OUTPUTS: usize = 4;
+
+/// Analyze a heat exchanger with uncertain conductance and inlet conditions.
+///
+/// Behaves like [`known_conductance_and_inlets`], and additionally propagates
+/// the input uncertainties to the heat rate, outlet temperatures, and
+/// effectiveness. Propagation is first order with uncorrelated inputs: each
+/// sensitivity is estimated by perturbing one input by its standard
+/// uncertainty, and the contributions are combined in quadrature.
+///
+/// # Errors
+///
+/// Returns `Err` if the nominal inputs violate a constraint, or if a
+/// perturbed input does (for example, when a capacitance rate's uncertainty
+/// exceeds its value so that no valid perturbation exists in either direction).
+///
+/// # Example
+///
+/// ```
+/// use twine_models::support::{
+///     hx::{
+///         arrangement::CounterFlow,
+///         functional::{UncertainStreamInlet, known_conductance_and_inlets_uncertain},
+///     },
+///     units::Uncertain,
+/// };
+/// use uom::si::{
+///     f64::{ThermalConductance, ThermodynamicTemperature},
+///     power::kilowatt,
+///     thermal_conductance::kilowatt_per_kelvin,
+///     thermodynamic_temperature::{degree_celsius, kelvin},
+/// };
+///
+/// let inlet = |c_kw: f64, t_c: f64| UncertainStreamInlet {
+///     capacitance_rate: Uncertain::with_relative(
+///         ThermalConductance::new::<kilowatt_per_kelvin>(c_kw),
+///         0.02,
+///     ).unwrap(),
+///     temperature: Uncertain::new(
+///         ThermodynamicTemperature::new::<degree_celsius>(t_c),
+///         ThermodynamicTemperature::new::<kelvin>(0.2),
+///     ).unwrap(),
+/// };
+///
+/// let result = known_conductance_and_inlets_uncertain(
+///     &CounterFlow,
+///     Uncertain::with_relative(ThermalConductance::new::<kilowatt_per_kelvin>(3.0), 0.1)?,
+///     [inlet(3.0, 50.0), inlet(6.0, 80.0)],
+/// )?;
+///
+/// assert!(result.heat_rate.value.get::<kilowatt>() > 0.0);
+/// assert!(result.heat_rate.std_dev.get::<kilowatt>() > 0.0);
+/// # Ok::<(), twine_models::support::constraint::ConstraintError>(())
+/// ```
+pub fn known_conductance_and_inlets_uncertain(
+    arrangement: &impl EffectivenessRelation,
+    ua: Uncertain<ThermalConductance>,
+    inlets: [UncertainStreamInlet; 2],
+) -> ConstraintResult<UncertainKnownConductanceResult> {
+    let values = [
+        ua.value.base_value(),
+        inlets[0].capacitance_rate.value.base_value(),
+        inlets[0].temperature.value.base_value(),
+        inlets[1].capacitance_rate.value.base_value(),
+        inlets[1].temperature.value.base_value(),
+    ];
+    let std_devs = [
+        ua.std_dev.base_value(),
+        inlets[0].capacitance_rate.std_dev.base_value(),
+        inlets[0].temperature.std_dev.base_value(),
+        inlets[1].capacitance_rate.std_dev.base_value(),
+        inlets[1].temperature.std_dev.base_value(),
+    ];
+
+    let evaluate = |x: [f64; INPUTS]| -> ConstraintResult<KnownConductanceResult> {
+        let inlet = |c: f64, t: f64| -> ConstraintResult<StreamInlet> {
+            Ok(StreamInlet::new(
+                CapacitanceRate::from_quantity(ThermalConductance::from_base_value(c))?,
+                ThermodynamicTemperature::from_base_value(t),
+            ))
+        };
+        known_conductance_and_inlets(
+            arrangement,
+            ThermalConductance::from_base_value(x[0]),
+            [inlet(x[1], x[2])?, inlet(x[3], x[4])?],
+        )
+    };
+
+    let nominal = evaluate(values)?;
+    let nominal_outputs = outputs(&nominal);
+
+    let mut variance = [0.0; OUTPUTS];
+    for (i, &sigma) in std_devs.iter().enumerate() {
+        if sigma == 0.0 {
+            continue;
+        }
+        let perturbed = |sign: f64| {
+            let mut x = values;
+            x[i] += sign * sigma;
+            evaluate(x).map(|result| outputs(&result))
+        };
+
+        // Central difference when both perturbations are valid, otherwise one-sided.
+        let change = match (perturbed(1.0), perturbed(-1.0)) {
+            (Ok(up), Ok(down)) => array_map(up, down, |u, d| 0.5 * (u - d)),
+            (Ok(up), Err(_)) => array_map(up, nominal_outputs, |u, n| u - n),
+            (Err(_), Ok(down)) => array_map(nominal_outputs, down, |n, d| n - d),
+            (Err(error), Err(_)) => return Err(error),
+        };
+        for (var, delta) in variance.iter_mut().zip(change) {
+            *var += delta * delta;
+        }
+    }
+
+    let uncertain = |k: usize| (nominal_outputs[k], variance[k].sqrt());
+
+    Ok(UncertainKnownConductanceResult {
+        nominal,
+        heat_rate: from_pair(uncertain(0)),
+        outlet_temperatures: [from_pair(uncertain(1)), from_pair(uncertain(2))],
+        effectiveness: from_pair(uncertain(3)),
+    })
+}
+
+fn outputs(result: &KnownConductanceResult) -> [f64; OUTPUTS] {
+    [
+        result.streams[0].heat_flow.signed().abs().base_value(),
+        result.streams[0].outlet_temperature.base_value(),
+        result.streams[1].outlet_temperature.base_value(),
+        result.effectiveness.get::<ratio>(),
+    ]
+}
+
+fn array_map(a: [f64; OUTPUTS], b: [f64; OUTPUTS], f: impl Fn(f64, f64) -> f64) -> [f64; OUTPUTS] {
+    core::array::from_fn(|k| f(a[k], b[k]))
+}
+
+fn from_pair<T: UncertainValue>((value, std_dev): (f64, f64)) -> Uncertain<T> {
+    Uncertain {
+        value: T::from_base_value(value),
+        std_dev: T::from_base_value(std_dev),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        power::watt, thermal_conductance::watt_per_kelvin, thermodynamic_temperature::kelvin,
+    };
+
+    use crate::support::hx::arrangement::CounterFlow;
+
+    fn inlet(c: f64, sigma_c: f64, t: f64, sigma_t: f64) -> UncertainStreamInlet {
+        UncertainStreamInlet {
+            capacitance_rate: Uncertain::new(
+                ThermalConductance::new::<watt_per_kelvin>(c),
+                ThermalConductance::new::<watt_per_kelvin>(sigma_c),
+            )
+            .unwrap(),
+            temperature: Uncertain::new(
+                ThermodynamicTemperature::new::<kelvin>(t),
+                ThermodynamicTemperature::new::<kelvin>(sigma_t),
+            )
+            .unwrap(),
+        }
+    }
+
+    #[test]
+    fn exact_inputs_have_no_uncertainty() {
+        let ua = Uncertain::exact(ThermalConductance::new::<watt_per_kelvin>(1000.0));
+        let result = known_conductance_and_inlets_uncertain(
+            &CounterFlow,
+            ua,
+            [
+                inlet(1000.0, 0.0, 300.0, 0.0),
+                inlet(2000.0, 0.0, 350.0, 0.0),
+            ],
+        )
+        .unwrap();
+
+        assert_relative_eq!(result.heat_rate.std_dev.get::<watt>(), 0.0);
+        assert_relative_eq!(result.effectiveness.std_dev, 0.0);
+    }
+
+    #[test]
+    fn inlet_temperature_uncertainty_propagates_linearly() {
+        // With exact UA and capacitance rates, effectiveness is fixed and the
+        // heat rate is ε·C_min·(T_hot − T_cold), so σ_Q = ε·C_min·σ_ΔT.
+        let ua = Uncertain::exact(ThermalConductance::new::<watt_per_kelvin>(1000.0));
+        let result = known_conductance_and_inlets_uncertain(
+            &CounterFlow,
+            ua,
+            [
+                inlet(1000.0, 0.0, 300.0, 0.3),
+                inlet(2000.0, 0.0, 350.0, 0.4),
+            ],
+        )
+        .unwrap();
+
+        let effectiveness = result.effectiveness.value;
+        assert_relative_eq!(result.effectiveness.std_dev, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(
+            result.heat_rate.std_dev.get::<watt>(),
+            effectiveness * 1000.0 * 0.5,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn one_sided_difference_near_constraint_boundary() {
+        // The capacitance rate uncertainty equals its value, so only the
+        // upward perturbation is valid.
+        let ua = Uncertain::exact(ThermalConductance::new::<watt_per_kelvin>(1000.0));
+        let result = known_conductance_and_inlets_uncertain(
+            &CounterFlow,
+            ua,
+            [
+                inlet(1000.0, 1000.0, 300.0, 0.0),
+                inlet(2000.0, 0.0, 350.0, 0.0),
+            ],
+        )
+        .unwrap();
+
+        assert!(result.heat_rate.std_dev.get::<watt>() > 0.0);
+    }
+}
@@ -110,10 +110,10 @@ impl Stream {
                 .partial_cmp(&outlet_temperature)
                 .expect("temperatures to be comparable")
             {
-                std::cmp::Ordering::Less => HeatFlow::incoming(heat_rate_magnitude)
+                core::cmp::Ordering::Less => HeatFlow::incoming(heat_rate_magnitude)
                     .expect("heat rate magnitude should always be positive"),
-                std::cmp::Ordering::Equal => HeatFlow::None,
-                std::cmp::Ordering::Greater => HeatFlow::outgoing(heat_rate_magnitude)
+                core::cmp::Ordering::Equal => HeatFlow::None,
+                core::cmp::Ordering::Greater => HeatFlow::outgoing(heat_rate_magnitude)
                     .expect("heat rate magnitude should always be positive"),
             },
         }
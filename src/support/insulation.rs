@@ -0,0 +1,122 @@
+//! Composite conduction through layered insulation.
+//!
+//! Ambient losses from pipes and tanks pass through surface films and one
+//! or more layers of wall and insulation in series. This module adds up
+//! those resistances once so every model derives its UA the same way:
+//!
+//! - [`PlanarWall`]: flat layers, such as a tank jacket or lid, reduced to a
+//!   U-value per unit area.
+//! - [`CylindricalWall`]: concentric layers around a pipe, reduced to a UA
+//!   per unit length.
+//!
+//! Both are assembled with a builder, inside to outside: an optional inner
+//! film, any number of conducting layers, then an optional outer film. A
+//! film left unset contributes no resistance.
+//!
+//! # Example
+//!
+//! A copper pipe with 19 mm of elastomeric foam, losing heat to still air:
+//!
+//! ```
+//! use twine_models::support::insulation::{CylindricalWall, InsulationError};
+//! use uom::si::{
+//!     f64::{HeatTransfer, Length, ThermalConductivity},
+//!     heat_transfer::watt_per_square_meter_kelvin,
+//!     length::{meter, millimeter},
+//!     thermal_conductance::watt_per_kelvin,
+//!     thermal_conductivity::watt_per_meter_kelvin,
+//! };
+//!
+//! let pipe = CylindricalWall::builder(Length::new::<millimeter>(20.0))
+//!     .inner_film(HeatTransfer::new::<watt_per_square_meter_kelvin>(2000.0))
+//!     .layer(
+//!         Length::new::<millimeter>(1.0),
+//!         ThermalConductivity::new::<watt_per_meter_kelvin>(390.0),
+//!     )
+//!     .layer(
+//!         Length::new::<millimeter>(19.0),
+//!         ThermalConductivity::new::<watt_per_meter_kelvin>(0.035),
+//!     )
+//!     .outer_film(HeatTransfer::new::<watt_per_square_meter_kelvin>(8.0))
+//!     .build()?;
+//!
+//! // About 0.2 W/K per meter, dominated by the foam.
+//! let ua = pipe.ua(Length::new::<meter>(10.0)).get::<watt_per_kelvin>();
+//! assert!((1.5..2.5).contains(&ua));
+//! # Ok::<(), InsulationError>(())
+//! ```
+
+mod cylindrical;
+mod planar;
+
+use thiserror::Error;
+use uom::si::f64::{HeatTransfer, Length, ThermalConductivity};
+
+use crate::support::constraint::{
+    And, Constrained, ConstraintError, Finite, NonNegative, StrictlyPositive,
+};
+
+pub use cylindrical::{CylindricalWall, CylindricalWallBuilder};
+pub use planar::{PlanarWall, PlanarWallBuilder};
+
+/// Errors from building a layered wall.
+#[derive(Debug, Error)]
+pub enum InsulationError {
+    /// A dimension, conductivity, or film coefficient is out of range.
+    #[error("invalid {field}")]
+    InvalidParameter {
+        /// Name of the invalid field.
+        field: &'static str,
+
+        /// Violated constraint.
+        #[source]
+        source: ConstraintError,
+    },
+
+    /// The wall has neither films nor layers, so it offers no resistance.
+    #[error("wall has no films or layers")]
+    Empty,
+}
+
+/// A conducting layer of uniform thickness and conductivity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Layer {
+    thickness: Length,
+    conductivity: ThermalConductivity,
+}
+
+/// Films and layers collected by a builder, inside to outside.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Stack {
+    inner_film: Option<HeatTransfer>,
+    layers: Vec<Layer>,
+    outer_film: Option<HeatTransfer>,
+}
+
+impl Stack {
+    /// Checks every film and layer and that there is at least one.
+    fn validate(&self) -> Result<(), InsulationError> {
+        let invalid = |field| move |source| InsulationError::InvalidParameter { field, source };
+
+        if let Some(h) = self.inner_film {
+            Constrained::<HeatTransfer, And<StrictlyPositive, Finite>>::new(h)
+                .map_err(invalid("inner film coefficient"))?;
+        }
+        if let Some(h) = self.outer_film {
+            Constrained::<HeatTransfer, And<StrictlyPositive, Finite>>::new(h)
+                .map_err(invalid("outer film coefficient"))?;
+        }
+        for layer in &self.layers {
+            Constrained::<Length, And<NonNegative, Finite>>::new(layer.thickness)
+                .map_err(invalid("layer thickness"))?;
+            Constrained::<ThermalConductivity, And<StrictlyPositive, Finite>>::new(
+                layer.conductivity,
+            )
+            .map_err(invalid("layer conductivity"))?;
+        }
+        if self.inner_film.is_none() && self.outer_film.is_none() && self.layers.is_empty() {
+            return Err(InsulationError::Empty);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+use core::f64::consts::PI;
+
+use uom::si::{
+    f64::{HeatTransfer, Length, ThermalConductance, ThermalConductivity},
+    thermal_conductivity::watt_per_meter_kelvin,
+};
+
+use crate::support::{
+    constraint::{And, Constrained, Finite, StrictlyPositive},
+    units::LinearThermalConductance,
+};
+
+use super::{InsulationError, Layer, Stack};
+
+/// Concentric layers around a pipe, reduced to a UA per unit length.
+///
+/// ```text
+/// 1/UA′ = 1/(h_i·π·D_i) + Σ ln(r_{j+1}/r_j)/(2π·kⱼ) + 1/(h_o·π·D_o)
+/// ```
+///
+/// Each layer adds its thickness to the radius, so the outer film acts on
+/// the outer diameter of the last layer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CylindricalWall {
+    ua_per_length: LinearThermalConductance,
+    inner_diameter: Length,
+    outer_diameter: Length,
+}
+
+impl CylindricalWall {
+    /// Returns a builder for a wall starting at `inner_diameter`.
+    #[must_use]
+    pub fn builder(inner_diameter: Length) -> CylindricalWallBuilder {
+        CylindricalWallBuilder {
+            inner_diameter,
+            stack: Stack::default(),
+        }
+    }
+
+    /// Returns the conductance per unit length.
+    #[must_use]
+    pub fn ua_per_length(&self) -> LinearThermalConductance {
+        self.ua_per_length
+    }
+
+    /// Returns the conductance of `length` of pipe.
+    #[must_use]
+    pub fn ua(&self, length: Length) -> ThermalConductance {
+        self.ua_per_length * length
+    }
+
+    /// Returns the diameter of the inner face.
+    #[must_use]
+    pub fn inner_diameter(&self) -> Length {
+        self.inner_diameter
+    }
+
+    /// Returns the diameter of the outer face.
+    #[must_use]
+    pub fn outer_diameter(&self) -> Length {
+        self.outer_diameter
+    }
+}
+
+/// Builder for [`CylindricalWall`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CylindricalWallBuilder {
+    inner_diameter: Length,
+    stack: Stack,
+}
+
+impl CylindricalWallBuilder {
+    /// Sets the film coefficient on the inner face.
+    #[must_use]
+    pub fn inner_film(mut self, h: HeatTransfer) -> Self {
+        self.stack.inner_film = Some(h);
+        self
+    }
+
+    /// Adds a layer around those already added.
+    #[must_use]
+    pub fn layer(mut self, thickness: Length, conductivity: ThermalConductivity) -> Self {
+        self.stack.layers.push(Layer {
+            thickness,
+            conductivity,
+        });
+        self
+    }
+
+    /// Sets the film coefficient on the outer face.
+    #[must_use]
+    pub fn outer_film(mut self, h: HeatTransfer) -> Self {
+        self.stack.outer_film = Some(h);
+        self
+    }
+
+    /// Validates the layers and returns the wall.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsulationError::InvalidParameter`] if the inner diameter,
+    /// a film coefficient, or a conductivity is not strictly positive and
+    /// finite or a thickness is negative, or [`InsulationError::Empty`] if
+    /// nothing was added.
+    pub fn build(self) -> Result<CylindricalWall, InsulationError> {
+        Constrained::<Length, And<StrictlyPositive, Finite>>::new(self.inner_diameter).map_err(
+            |source| InsulationError::InvalidParameter {
+                field: "inner diameter",
+                source,
+            },
+        )?;
+        self.stack.validate()?;
+        let Stack {
+            inner_film,
+            layers,
+            outer_film,
+        } = &self.stack;
+
+        let film = |h: Option<HeatTransfer>, diameter: Length| {
+            h.map_or(0.0, |h| 1.0 / (h * PI * diameter).value)
+        };
+        let mut diameter = self.inner_diameter;
+        let mut resistance = film(*inner_film, diameter);
+        for layer in layers {
+            let outer = diameter + 2.0 * layer.thickness;
+            resistance += (outer / diameter).value.ln() / (2.0 * PI * layer.conductivity.value);
+            diameter = outer;
+        }
+        resistance += film(*outer_film, diameter);
+
+        Ok(CylindricalWall {
+            ua_per_length: LinearThermalConductance::new::<watt_per_meter_kelvin>(1.0 / resistance),
+            inner_diameter: self.inner_diameter,
+            outer_diameter: diameter,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        heat_transfer::watt_per_square_meter_kelvin,
+        length::{meter, millimeter},
+    };
+
+    use crate::support::insulation::PlanarWall;
+
+    fn mm(value: f64) -> Length {
+        Length::new::<millimeter>(value)
+    }
+
+    fn k(value: f64) -> ThermalConductivity {
+        ThermalConductivity::new::<watt_per_meter_kelvin>(value)
+    }
+
+    #[test]
+    fn insulated_pipe_matches_hand_calculation() {
+        // r: 10 → 30 mm of k = 0.04, outer film 10 W/m²·K on D = 60 mm.
+        let wall = CylindricalWall::builder(mm(20.0))
+            .layer(mm(20.0), k(0.04))
+            .outer_film(HeatTransfer::new::<watt_per_square_meter_kelvin>(10.0))
+            .build()
+            .unwrap();
+
+        let resistance = 3.0_f64.ln() / (2.0 * PI * 0.04) + 1.0 / (10.0 * PI * 0.06);
+        assert_relative_eq!(
+            wall.ua_per_length().value,
+            1.0 / resistance,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(wall.outer_diameter().get::<millimeter>(), 60.0);
+    }
+
+    #[test]
+    fn thin_layers_on_a_large_pipe_approach_a_flat_wall() {
+        let h = HeatTransfer::new::<watt_per_square_meter_kelvin>(15.0);
+        let diameter = Length::new::<meter>(100.0);
+        let pipe = CylindricalWall::builder(diameter)
+            .inner_film(h)
+            .layer(mm(5.0), k(0.05))
+            .outer_film(h)
+            .build()
+            .unwrap();
+        let flat = PlanarWall::builder()
+            .inner_film(h)
+            .layer(mm(5.0), k(0.05))
+            .outer_film(h)
+            .build()
+            .unwrap();
+
+        let per_area = (pipe.ua_per_length() / (PI * diameter)).value;
+        assert_relative_eq!(per_area, flat.u_value().value, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn rejects_non_positive_inner_diameter() {
+        assert!(matches!(
+            CylindricalWall::builder(mm(0.0))
+                .layer(mm(10.0), k(0.04))
+                .build(),
+            Err(InsulationError::InvalidParameter {
+                field: "inner diameter",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,168 @@
+use uom::si::{
+    f64::{Area, HeatTransfer, Length, ThermalConductance, ThermalConductivity},
+    heat_transfer::watt_per_square_meter_kelvin,
+};
+
+use super::{InsulationError, Layer, Stack};
+
+/// Flat layers in series, reduced to an overall U-value.
+///
+/// ```text
+/// 1/U = 1/h_i + Σ tⱼ/kⱼ + 1/h_o
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlanarWall {
+    u_value: HeatTransfer,
+    thickness: Length,
+}
+
+impl PlanarWall {
+    /// Returns a builder for a flat wall.
+    #[must_use]
+    pub fn builder() -> PlanarWallBuilder {
+        PlanarWallBuilder {
+            stack: Stack::default(),
+        }
+    }
+
+    /// Returns the overall heat transfer coefficient through the wall.
+    #[must_use]
+    pub fn u_value(&self) -> HeatTransfer {
+        self.u_value
+    }
+
+    /// Returns the conductance through `area` of wall.
+    #[must_use]
+    pub fn ua(&self, area: Area) -> ThermalConductance {
+        self.u_value * area
+    }
+
+    /// Returns the combined thickness of the layers.
+    #[must_use]
+    pub fn thickness(&self) -> Length {
+        self.thickness
+    }
+}
+
+/// Builder for [`PlanarWall`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanarWallBuilder {
+    stack: Stack,
+}
+
+impl PlanarWallBuilder {
+    /// Sets the film coefficient on the inner face.
+    #[must_use]
+    pub fn inner_film(mut self, h: HeatTransfer) -> Self {
+        self.stack.inner_film = Some(h);
+        self
+    }
+
+    /// Adds a layer outside those already added.
+    #[must_use]
+    pub fn layer(mut self, thickness: Length, conductivity: ThermalConductivity) -> Self {
+        self.stack.layers.push(Layer {
+            thickness,
+            conductivity,
+        });
+        self
+    }
+
+    /// Sets the film coefficient on the outer face.
+    #[must_use]
+    pub fn outer_film(mut self, h: HeatTransfer) -> Self {
+        self.stack.outer_film = Some(h);
+        self
+    }
+
+    /// Validates the layers and returns the wall.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsulationError::InvalidParameter`] if a film coefficient or
+    /// conductivity is not strictly positive and finite or a thickness is
+    /// negative, or [`InsulationError::Empty`] if nothing was added.
+    pub fn build(self) -> Result<PlanarWall, InsulationError> {
+        let Stack {
+            inner_film,
+            layers,
+            outer_film,
+        } = &self.stack;
+        self.stack.validate()?;
+
+        let film = |h: Option<HeatTransfer>| h.map_or(0.0, |h| 1.0 / h.value);
+        let conduction: f64 = layers
+            .iter()
+            .map(|layer| (layer.thickness / layer.conductivity).value)
+            .sum();
+        let resistance = film(*inner_film) + conduction + film(*outer_film);
+
+        Ok(PlanarWall {
+            u_value: HeatTransfer::new::<watt_per_square_meter_kelvin>(1.0 / resistance),
+            thickness: layers.iter().map(|layer| layer.thickness).sum(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        area::square_meter, length::millimeter, thermal_conductance::watt_per_kelvin,
+        thermal_conductivity::watt_per_meter_kelvin,
+    };
+
+    fn h(value: f64) -> HeatTransfer {
+        HeatTransfer::new::<watt_per_square_meter_kelvin>(value)
+    }
+
+    fn k(value: f64) -> ThermalConductivity {
+        ThermalConductivity::new::<watt_per_meter_kelvin>(value)
+    }
+
+    #[test]
+    fn resistances_add_in_series() {
+        // 1/U = 1/10 + 0.05/0.04 + 0.002/50 + 1/25 = 1.39004 m²·K/W.
+        let wall = PlanarWall::builder()
+            .inner_film(h(10.0))
+            .layer(Length::new::<millimeter>(50.0), k(0.04))
+            .layer(Length::new::<millimeter>(2.0), k(50.0))
+            .outer_film(h(25.0))
+            .build()
+            .unwrap();
+
+        assert_relative_eq!(wall.u_value().value, 1.0 / 1.390_04, max_relative = 1e-12);
+        assert_relative_eq!(
+            wall.ua(Area::new::<square_meter>(3.0))
+                .get::<watt_per_kelvin>(),
+            3.0 / 1.390_04,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(wall.thickness().get::<millimeter>(), 52.0);
+    }
+
+    #[test]
+    fn film_alone_gives_its_coefficient() {
+        let wall = PlanarWall::builder().outer_film(h(8.0)).build().unwrap();
+        assert_relative_eq!(wall.u_value().value, 8.0);
+    }
+
+    #[test]
+    fn rejects_empty_and_invalid_walls() {
+        assert!(matches!(
+            PlanarWall::builder().build(),
+            Err(InsulationError::Empty)
+        ));
+        assert!(matches!(
+            PlanarWall::builder()
+                .layer(Length::new::<millimeter>(10.0), k(0.0))
+                .build(),
+            Err(InsulationError::InvalidParameter {
+                field: "layer conductivity",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,59 @@
+//! Seasonal performance metrics.
+//!
+//! Annual and seasonal figures of merit are ratios of energy totals, but
+//! each has its own conventions for which energy goes where. This module
+//! keeps those definitions in one place:
+//!
+//! - [`SeasonalTotals`]: delivered and consumed energy accumulated from a
+//!   simulated time series, reduced to a seasonal performance factor such
+//!   as SCOP or SEER.
+//! - **Bin method**: [`bin_totals`] evaluates a unit at the outdoor
+//!   temperature of each [`Bin`] and weights the result by the bin hours.
+//!   [`LoadLine`] gives the building load at each bin, and [`bins`] holds
+//!   the standard bin tables.
+//! - **Solar fraction**: [`solar_fraction`] and [`solar_fraction_of_load`]
+//!   for the two common definitions.
+//!
+//! Performance factors here are dimensionless, as in EN 14825. US SEER and
+//! HSPF ratings in Btu/W·h are 3.412 times the dimensionless value.
+//!
+//! # Example
+//!
+//! SCOP of a heat pump whose COP falls linearly with outdoor temperature,
+//! over the EN 14825 average heating season:
+//!
+//! ```
+//! use std::convert::Infallible;
+//!
+//! use twine_models::support::metrics::{LoadLine, OperatingPoint, bin_totals, bins};
+//! use uom::si::{
+//!     f64::{Power, ThermodynamicTemperature},
+//!     power::kilowatt,
+//!     ratio::ratio,
+//!     thermodynamic_temperature::degree_celsius,
+//! };
+//!
+//! let load = LoadLine::new(
+//!     Power::new::<kilowatt>(8.0),
+//!     ThermodynamicTemperature::new::<degree_celsius>(-10.0),
+//! );
+//!
+//! let totals = bin_totals(&bins::en14825_average_heating(), |outdoor| {
+//!     let delivered = load.load(outdoor);
+//!     let cop = 3.5 + 0.08 * (outdoor.get::<degree_celsius>() - 7.0);
+//!     Ok::<_, Infallible>(OperatingPoint::new(delivered, delivered / cop))
+//! })?;
+//!
+//! let scop = totals.performance_factor().unwrap().get::<ratio>();
+//! assert!(scop > 3.0 && scop < 3.5);
+//! # Ok::<(), Infallible>(())
+//! ```
+
+pub mod bins;
+
+mod seasonal;
+mod solar;
+
+pub use bins::Bin;
+pub use seasonal::{LoadLine, OperatingPoint, SeasonalTotals, bin_totals};
+pub use solar::{solar_fraction, solar_fraction_of_load};
@@ -0,0 +1,88 @@
+//! Standard outdoor temperature bins.
+//!
+//! The EN 14825 tables give the hours per season at each whole-degree
+//! outdoor temperature for the reference climates used in SCOP and SEER
+//! ratings.
+
+use uom::si::{
+    f64::{ThermodynamicTemperature, Time},
+    thermodynamic_temperature::degree_celsius,
+    time::hour,
+};
+
+/// Hours in a season spent at one outdoor temperature.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bin {
+    /// Outdoor temperature at the center of the bin.
+    pub temperature: ThermodynamicTemperature,
+
+    /// Time spent in the bin over the season.
+    pub hours: Time,
+}
+
+impl Bin {
+    /// Creates a bin from a temperature in °C and a duration in hours.
+    #[must_use]
+    pub fn new(celsius: f64, hours: f64) -> Self {
+        Self {
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(celsius),
+            hours: Time::new::<hour>(hours),
+        }
+    }
+}
+
+/// EN 14825 average heating season, −10 °C to 15 °C (4910 h).
+///
+/// The design temperature of the average climate is −10 °C.
+#[must_use]
+pub fn en14825_average_heating() -> Vec<Bin> {
+    const HOURS: [f64; 26] = [
+        1.0, 25.0, 23.0, 24.0, 27.0, 68.0, 91.0, 89.0, 165.0, 173.0, 240.0, 280.0, 320.0, 357.0,
+        356.0, 303.0, 330.0, 326.0, 348.0, 335.0, 315.0, 215.0, 169.0, 151.0, 105.0, 74.0,
+    ];
+    from_hours(-10, &HOURS)
+}
+
+/// EN 14825 cooling season, 17 °C to 39 °C (2602 h).
+///
+/// The design temperature for cooling is 35 °C.
+#[must_use]
+pub fn en14825_cooling() -> Vec<Bin> {
+    const HOURS: [f64; 23] = [
+        205.0, 227.0, 225.0, 225.0, 216.0, 215.0, 218.0, 197.0, 178.0, 158.0, 137.0, 109.0, 88.0,
+        63.0, 39.0, 31.0, 24.0, 17.0, 13.0, 9.0, 4.0, 3.0, 1.0,
+    ];
+    from_hours(17, &HOURS)
+}
+
+/// Builds consecutive one-degree bins starting at `first` °C.
+fn from_hours(first: i32, hours: &[f64]) -> Vec<Bin> {
+    (first..)
+        .zip(hours)
+        .map(|(t, &h)| Bin::new(f64::from(t), h))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    fn total_hours(bins: &[Bin]) -> f64 {
+        bins.iter().map(|bin| bin.hours.get::<hour>()).sum()
+    }
+
+    #[test]
+    fn tables_match_standard_totals() {
+        let heating = en14825_average_heating();
+        assert_eq!(heating.len(), 26);
+        assert_relative_eq!(total_hours(&heating), 4910.0);
+        assert_relative_eq!(heating[25].temperature.get::<degree_celsius>(), 15.0);
+
+        let cooling = en14825_cooling();
+        assert_relative_eq!(total_hours(&cooling), 2602.0);
+        assert_relative_eq!(cooling[22].temperature.get::<degree_celsius>(), 39.0);
+    }
+}
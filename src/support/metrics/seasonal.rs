@@ -0,0 +1,212 @@
+use uom::{
+    ConstZero,
+    si::{
+        f64::{Energy, Power, Ratio, ThermodynamicTemperature, Time},
+        thermodynamic_temperature::degree_celsius,
+    },
+};
+
+use crate::support::units::TemperatureDifference;
+
+use super::Bin;
+
+/// Outdoor temperature at which the EN 14825 load lines reach zero.
+const BALANCE_POINT: f64 = 16.0;
+
+/// Useful output and purchased input of a unit at one instant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OperatingPoint {
+    /// Heating or cooling delivered to the load.
+    pub delivered: Power,
+
+    /// Power consumed to deliver it, including backup heat and auxiliaries.
+    pub consumed: Power,
+}
+
+impl OperatingPoint {
+    /// Creates an operating point.
+    #[must_use]
+    pub fn new(delivered: Power, consumed: Power) -> Self {
+        Self {
+            delivered,
+            consumed,
+        }
+    }
+}
+
+/// Energy delivered and consumed over a season.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeasonalTotals {
+    /// Heating or cooling delivered to the load.
+    pub delivered: Energy,
+
+    /// Energy consumed to deliver it.
+    pub consumed: Energy,
+
+    /// Time covered by the totals.
+    pub duration: Time,
+}
+
+impl Default for SeasonalTotals {
+    fn default() -> Self {
+        Self {
+            delivered: Energy::ZERO,
+            consumed: Energy::ZERO,
+            duration: Time::ZERO,
+        }
+    }
+}
+
+impl SeasonalTotals {
+    /// Accumulates totals from a time series of steps and their operating
+    /// points.
+    pub fn from_series(series: impl IntoIterator<Item = (Time, OperatingPoint)>) -> Self {
+        let mut totals = Self::default();
+        for (duration, point) in series {
+            totals.record(duration, point);
+        }
+        totals
+    }
+
+    /// Adds a step of `duration` at `point`.
+    pub fn record(&mut self, duration: Time, point: OperatingPoint) {
+        self.delivered += point.delivered * duration;
+        self.consumed += point.consumed * duration;
+        self.duration += duration;
+    }
+
+    /// Returns the seasonal performance factor, delivered over consumed
+    /// energy: SCOP for heating, SEER for cooling.
+    ///
+    /// Returns `None` if no energy was consumed.
+    #[must_use]
+    pub fn performance_factor(&self) -> Option<Ratio> {
+        (self.consumed > Energy::ZERO).then(|| self.delivered / self.consumed)
+    }
+
+    /// Returns the mean delivered power over the covered time, or `None` if
+    /// no time was covered.
+    #[must_use]
+    pub fn mean_delivered(&self) -> Option<Power> {
+        (self.duration > Time::ZERO).then(|| self.delivered / self.duration)
+    }
+}
+
+/// A building load that varies linearly with outdoor temperature.
+///
+/// The load equals the design load at the design temperature and falls to
+/// zero at the 16 °C balance point used by EN 14825, staying zero beyond
+/// it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoadLine {
+    design_load: Power,
+    design_temperature: ThermodynamicTemperature,
+}
+
+impl LoadLine {
+    /// Creates a load line from the load at the design temperature.
+    ///
+    /// A design temperature below the balance point gives a heating load
+    /// line and one above it a cooling load line. EN 14825 uses 35 °C for
+    /// cooling and −10 °C for the average heating climate.
+    #[must_use]
+    pub fn new(design_load: Power, design_temperature: ThermodynamicTemperature) -> Self {
+        Self {
+            design_load,
+            design_temperature,
+        }
+    }
+
+    /// Returns the load at `outdoor` temperature.
+    #[must_use]
+    pub fn load(&self, outdoor: ThermodynamicTemperature) -> Power {
+        let balance = ThermodynamicTemperature::new::<degree_celsius>(BALANCE_POINT);
+        let fraction: Ratio = outdoor.minus(balance) / self.design_temperature.minus(balance);
+        self.design_load * fraction.max(Ratio::ZERO)
+    }
+}
+
+/// Weights operating points evaluated at each bin temperature by the bin
+/// hours.
+///
+/// `evaluate` receives the outdoor temperature of each bin and returns the
+/// unit's operating point there. Bins with no hours are skipped.
+///
+/// # Errors
+///
+/// Returns the first error from `evaluate`.
+pub fn bin_totals<E>(
+    bins: &[Bin],
+    mut evaluate: impl FnMut(ThermodynamicTemperature) -> Result<OperatingPoint, E>,
+) -> Result<SeasonalTotals, E> {
+    let mut totals = SeasonalTotals::default();
+    for bin in bins.iter().filter(|bin| bin.hours > Time::ZERO) {
+        totals.record(bin.hours, evaluate(bin.temperature)?);
+    }
+    Ok(totals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{energy::kilowatt_hour, power::kilowatt, ratio::ratio, time::hour};
+
+    use crate::support::{metrics::bins, units::test_utils::celsius};
+
+    fn kw(value: f64) -> Power {
+        Power::new::<kilowatt>(value)
+    }
+
+    #[test]
+    fn series_totals_weight_by_duration() {
+        let totals = SeasonalTotals::from_series([
+            (
+                Time::new::<hour>(1.0),
+                OperatingPoint::new(kw(6.0), kw(2.0)),
+            ),
+            (
+                Time::new::<hour>(3.0),
+                OperatingPoint::new(kw(2.0), kw(0.5)),
+            ),
+        ]);
+
+        assert_relative_eq!(totals.delivered.get::<kilowatt_hour>(), 12.0);
+        assert_relative_eq!(totals.consumed.get::<kilowatt_hour>(), 3.5);
+        assert_relative_eq!(
+            totals.performance_factor().unwrap().get::<ratio>(),
+            12.0 / 3.5
+        );
+        assert_relative_eq!(totals.mean_delivered().unwrap().get::<kilowatt>(), 3.0);
+        assert!(SeasonalTotals::default().performance_factor().is_none());
+    }
+
+    #[test]
+    fn load_lines_meet_design_and_balance_points() {
+        let heating = LoadLine::new(kw(8.0), celsius(-10.0));
+        assert_relative_eq!(heating.load(celsius(-10.0)).get::<kilowatt>(), 8.0);
+        assert_relative_eq!(heating.load(celsius(3.0)).get::<kilowatt>(), 4.0);
+        assert_relative_eq!(heating.load(celsius(20.0)).get::<kilowatt>(), 0.0);
+
+        let cooling = LoadLine::new(kw(5.0), celsius(35.0));
+        assert_relative_eq!(cooling.load(celsius(35.0)).get::<kilowatt>(), 5.0);
+        assert_relative_eq!(cooling.load(celsius(12.0)).get::<kilowatt>(), 0.0);
+    }
+
+    #[test]
+    fn constant_cop_gives_that_cop_seasonally() {
+        let load = LoadLine::new(kw(8.0), celsius(-10.0));
+        let totals = bin_totals(&bins::en14825_average_heating(), |t| {
+            Ok::<_, ()>(OperatingPoint::new(load.load(t), load.load(t) / 3.0))
+        })
+        .unwrap();
+
+        assert_relative_eq!(
+            totals.performance_factor().unwrap().get::<ratio>(),
+            3.0,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(totals.duration.get::<hour>(), 4910.0);
+    }
+}
@@ -0,0 +1,65 @@
+use uom::{
+    ConstZero,
+    si::{
+        f64::{Energy, Ratio},
+        ratio::ratio,
+    },
+};
+
+/// Returns the share of the heat supplied to a store that came from the
+/// solar loop, `Q_solar / (Q_solar + Q_aux)`.
+///
+/// Storage and distribution losses are charged to both sources in
+/// proportion. Returns zero if nothing was supplied.
+#[must_use]
+pub fn solar_fraction(solar: Energy, auxiliary: Energy) -> Ratio {
+    let supplied = solar + auxiliary;
+    if supplied > Energy::ZERO {
+        (solar / supplied).max(Ratio::ZERO)
+    } else {
+        Ratio::ZERO
+    }
+}
+
+/// Returns the share of a load not met by auxiliary heat,
+/// `1 − Q_aux / Q_load`.
+///
+/// Losses are charged to the solar system, so this is the fraction of the
+/// auxiliary energy a solar system saves against a lossless conventional
+/// one. It is floored at zero, for systems whose losses exceed their
+/// solar gain, and is zero if there was no load.
+#[must_use]
+pub fn solar_fraction_of_load(load: Energy, auxiliary: Energy) -> Ratio {
+    if load > Energy::ZERO {
+        (Ratio::new::<ratio>(1.0) - auxiliary / load).max(Ratio::ZERO)
+    } else {
+        Ratio::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::energy::kilowatt_hour;
+
+    fn kwh(value: f64) -> Energy {
+        Energy::new::<kilowatt_hour>(value)
+    }
+
+    #[test]
+    fn definitions_differ_by_loss_allocation() {
+        // 100 kWh load, 20 kWh of store losses, 80 kWh solar, 40 kWh backup.
+        assert_relative_eq!(
+            solar_fraction(kwh(80.0), kwh(40.0)).get::<ratio>(),
+            2.0 / 3.0
+        );
+        assert_relative_eq!(
+            solar_fraction_of_load(kwh(100.0), kwh(40.0)).get::<ratio>(),
+            0.6
+        );
+        assert_eq!(solar_fraction(Energy::ZERO, Energy::ZERO), Ratio::ZERO);
+        assert_eq!(solar_fraction_of_load(kwh(10.0), kwh(20.0)), Ratio::ZERO);
+    }
+}
@@ -0,0 +1,14 @@
+//! Numerical building blocks shared across models.
+//!
+//! - [`interp`]: 1-D and 2-D interpolation over tabulated data.
+//! - [`performance_map`]: gridded equipment data over named input axes.
+//! - [`solve`]: closure-based root finding and fixed-point iteration.
+
+pub mod interp;
+pub mod performance_map;
+pub mod solve;
+
+pub(crate) mod linear;
+pub(crate) mod special;
+
+mod grid;
@@ -0,0 +1,139 @@
+use super::interp::{Extrapolation, InterpError};
+
+/// A validated, strictly increasing grid.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Grid(Vec<f64>);
+
+/// Position of a query within a grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct Position {
+    /// Index of the lower point of the enclosing (or nearest edge) segment.
+    pub(super) index: usize,
+
+    /// Fractional position within the segment.
+    ///
+    /// In `[0, 1]` inside the grid or when clamped, and outside that range
+    /// when extrapolating linearly.
+    pub(super) fraction: f64,
+}
+
+impl Grid {
+    pub(super) fn new(points: Vec<f64>) -> Result<Self, InterpError> {
+        if points.len() < 2 {
+            return Err(InterpError::TooFewPoints(points.len()));
+        }
+        if !points.iter().all(|p| p.is_finite()) {
+            return Err(InterpError::NonFinite);
+        }
+        if let Some(i) = points.windows(2).position(|w| w[1] <= w[0]) {
+            return Err(InterpError::NotIncreasing(i + 1));
+        }
+        Ok(Self(points))
+    }
+
+    pub(super) fn points(&self) -> &[f64] {
+        &self.0
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the width of segment `index`.
+    pub(super) fn width(&self, index: usize) -> f64 {
+        self.0[index + 1] - self.0[index]
+    }
+
+    /// Locates `x` within the grid according to `policy`.
+    pub(super) fn locate(&self, x: f64, policy: Extrapolation) -> Result<Position, InterpError> {
+        if x.is_nan() {
+            return Err(InterpError::NanQuery);
+        }
+
+        let points = &self.0;
+        let (min, max) = (points[0], points[points.len() - 1]);
+        if (x < min || x > max) && policy == Extrapolation::Error {
+            return Err(InterpError::OutOfRange { value: x, min, max });
+        }
+
+        // Index of the segment whose lower point is the last one at or below `x`,
+        // limited to the first and last segments.
+        let index = points
+            .partition_point(|&p| p <= x)
+            .saturating_sub(1)
+            .min(points.len() - 2);
+
+        let fraction = (x - points[index]) / self.width(index);
+        let fraction = match policy {
+            Extrapolation::Clamp => fraction.clamp(0.0, 1.0),
+            Extrapolation::Error | Extrapolation::Linear => fraction,
+        };
+
+        Ok(Position { index, fraction })
+    }
+}
+
+/// Checks that `values` has `expected` finite entries.
+pub(super) fn check_values(values: &[f64], expected: usize) -> Result<(), InterpError> {
+    if values.len() != expected {
+        return Err(InterpError::LengthMismatch {
+            expected,
+            actual: values.len(),
+        });
+    }
+    if !values.iter().all(|v| v.is_finite()) {
+        return Err(InterpError::NonFinite);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn rejects_invalid_grids() {
+        assert_eq!(Grid::new(vec![1.0]), Err(InterpError::TooFewPoints(1)));
+        assert_eq!(
+            Grid::new(vec![0.0, 1.0, 1.0]),
+            Err(InterpError::NotIncreasing(2))
+        );
+        assert_eq!(
+            Grid::new(vec![0.0, f64::INFINITY]),
+            Err(InterpError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn locates_queries() {
+        let grid = Grid::new(vec![0.0, 1.0, 3.0]).unwrap();
+
+        let p = grid.locate(2.0, Extrapolation::Error).unwrap();
+        assert_eq!(p.index, 1);
+        assert_relative_eq!(p.fraction, 0.5);
+
+        // The last grid point belongs to the last segment.
+        let p = grid.locate(3.0, Extrapolation::Error).unwrap();
+        assert_eq!(p.index, 1);
+        assert_relative_eq!(p.fraction, 1.0);
+
+        let p = grid.locate(-1.0, Extrapolation::Linear).unwrap();
+        assert_eq!(p.index, 0);
+        assert_relative_eq!(p.fraction, -1.0);
+
+        let p = grid.locate(5.0, Extrapolation::Clamp).unwrap();
+        assert_eq!(p.index, 1);
+        assert_relative_eq!(p.fraction, 1.0);
+
+        assert!(matches!(
+            grid.locate(5.0, Extrapolation::Error),
+            Err(InterpError::OutOfRange { .. })
+        ));
+        assert_eq!(
+            grid.locate(f64::NAN, Extrapolation::Clamp),
+            Err(InterpError::NanQuery)
+        );
+    }
+}
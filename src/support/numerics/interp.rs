@@ -0,0 +1,81 @@
+//! Interpolation over tabulated data.
+//!
+//! Interpolators are built from a strictly increasing grid and the values at
+//! each grid point, and validate both at construction:
+//!
+//! - [`Linear1d`]: piecewise-linear interpolation in one dimension.
+//! - [`CubicSpline1d`]: natural cubic spline interpolation in one dimension.
+//! - [`Bilinear2d`]: bilinear interpolation over a rectangular 2-D grid.
+//!
+//! Queries outside the grid follow an [`Extrapolation`] policy, which defaults
+//! to [`Extrapolation::Error`].
+//!
+//! Interpolators work on raw `f64` values. Convert quantities to a fixed unit
+//! before building a table and when querying it.
+//!
+//! # Example
+//!
+//! ```
+//! use twine_models::support::numerics::interp::{Extrapolation, Linear1d};
+//!
+//! let table = Linear1d::new(vec![0.0, 1.0, 2.0], vec![10.0, 20.0, 40.0])?;
+//! assert_eq!(table.eval(1.5)?, 30.0);
+//! assert!(table.eval(3.0).is_err());
+//!
+//! let clamped = table.with_extrapolation(Extrapolation::Clamp);
+//! assert_eq!(clamped.eval(3.0)?, 40.0);
+//! # Ok::<(), twine_models::support::numerics::interp::InterpError>(())
+//! ```
+
+mod bilinear;
+mod linear;
+mod spline;
+
+use thiserror::Error;
+
+pub use bilinear::Bilinear2d;
+pub use linear::Linear1d;
+pub use spline::CubicSpline1d;
+
+/// How an interpolator handles queries outside its grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Extrapolation {
+    /// Return [`InterpError::OutOfRange`].
+    #[default]
+    Error,
+
+    /// Use the value at the nearest edge of the grid.
+    Clamp,
+
+    /// Extend the edge segment (or edge slope) linearly.
+    Linear,
+}
+
+/// Errors from building or querying an interpolator.
+#[derive(Debug, Clone, PartialEq, Error)]
+#[non_exhaustive]
+pub enum InterpError {
+    /// The grid has fewer than two points.
+    #[error("grid must have at least 2 points, got {0}")]
+    TooFewPoints(usize),
+
+    /// The number of values doesn't match the grid.
+    #[error("expected {expected} values, got {actual}")]
+    LengthMismatch { expected: usize, actual: usize },
+
+    /// The grid is not strictly increasing at the given index.
+    #[error("grid must be strictly increasing, but point {0} is not above its predecessor")]
+    NotIncreasing(usize),
+
+    /// A grid point or value is NaN or infinite.
+    #[error("grid points and values must be finite")]
+    NonFinite,
+
+    /// The query is NaN.
+    #[error("query must not be NaN")]
+    NanQuery,
+
+    /// The query is outside the grid and the policy is [`Extrapolation::Error`].
+    #[error("query {value} is outside the grid range [{min}, {max}]")]
+    OutOfRange { value: f64, min: f64, max: f64 },
+}
@@ -0,0 +1,131 @@
+use crate::support::numerics::grid::{Grid, check_values};
+
+use super::{Extrapolation, InterpError};
+
+/// Bilinear interpolation over a rectangular 2-D grid.
+///
+/// Values are stored in row-major order: `values[i * y.len() + j]` is the
+/// value at `(x[i], y[j])`. The extrapolation policy applies to both axes.
+///
+/// # Example
+///
+/// ```
+/// use twine_models::support::numerics::interp::Bilinear2d;
+///
+/// // z = x + 10·y
+/// let table = Bilinear2d::new(
+///     vec![0.0, 1.0],
+///     vec![0.0, 1.0, 2.0],
+///     vec![
+///         0.0, 10.0, 20.0, // x = 0
+///         1.0, 11.0, 21.0, // x = 1
+///     ],
+/// )?;
+///
+/// assert!((table.eval(0.5, 1.5)? - 15.5).abs() < 1e-12);
+/// # Ok::<(), twine_models::support::numerics::interp::InterpError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bilinear2d {
+    x: Grid,
+    y: Grid,
+    values: Vec<f64>,
+    extrapolation: Extrapolation,
+}
+
+impl Bilinear2d {
+    /// Creates an interpolator over the grid `x × y`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either axis has fewer than two points or is not
+    /// strictly increasing, if `values` does not have `x.len() * y.len()`
+    /// entries, or if any value is not finite.
+    pub fn new(x: Vec<f64>, y: Vec<f64>, values: Vec<f64>) -> Result<Self, InterpError> {
+        let x = Grid::new(x)?;
+        let y = Grid::new(y)?;
+        check_values(&values, x.len() * y.len())?;
+        Ok(Self {
+            x,
+            y,
+            values,
+            extrapolation: Extrapolation::default(),
+        })
+    }
+
+    /// Sets how queries outside the grid are handled.
+    #[must_use]
+    pub fn with_extrapolation(self, extrapolation: Extrapolation) -> Self {
+        Self {
+            extrapolation,
+            ..self
+        }
+    }
+
+    /// Evaluates the interpolant at `(x, y)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either coordinate is NaN, or if the point is
+    /// outside the grid and the extrapolation policy is
+    /// [`Extrapolation::Error`].
+    pub fn eval(&self, x: f64, y: f64) -> Result<f64, InterpError> {
+        let px = self.x.locate(x, self.extrapolation)?;
+        let py = self.y.locate(y, self.extrapolation)?;
+
+        let at = |i: usize, j: usize| self.values[i * self.y.len() + j];
+        let (i, j) = (px.index, py.index);
+        let (tx, ty) = (px.fraction, py.fraction);
+
+        let lower = at(i, j) + tx * (at(i + 1, j) - at(i, j));
+        let upper = at(i, j + 1) + tx * (at(i + 1, j + 1) - at(i, j + 1));
+        Ok(lower + ty * (upper - lower))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    fn table() -> Bilinear2d {
+        // z = x·y on a non-uniform grid; bilinear interpolation is exact for it.
+        let x = vec![0.0, 1.0, 3.0];
+        let y = vec![0.0, 2.0];
+        let values = x
+            .iter()
+            .flat_map(|&xi| y.iter().map(move |&yj| xi * yj))
+            .collect();
+        Bilinear2d::new(x, y, values).unwrap()
+    }
+
+    #[test]
+    fn interpolates_inside_grid() {
+        let table = table();
+        assert_relative_eq!(table.eval(2.0, 1.0).unwrap(), 2.0);
+        assert_relative_eq!(table.eval(3.0, 2.0).unwrap(), 6.0);
+        assert!(table.eval(4.0, 1.0).is_err());
+        assert!(table.eval(1.0, -1.0).is_err());
+    }
+
+    #[test]
+    fn extrapolation_policies_apply_to_both_axes() {
+        let clamped = table().with_extrapolation(Extrapolation::Clamp);
+        assert_relative_eq!(clamped.eval(5.0, 3.0).unwrap(), 6.0);
+
+        let linear = table().with_extrapolation(Extrapolation::Linear);
+        assert_relative_eq!(linear.eval(4.0, 3.0).unwrap(), 12.0);
+    }
+
+    #[test]
+    fn rejects_wrong_value_count() {
+        assert_eq!(
+            Bilinear2d::new(vec![0.0, 1.0], vec![0.0, 1.0], vec![0.0; 3]),
+            Err(InterpError::LengthMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+}
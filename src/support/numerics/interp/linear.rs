@@ -0,0 +1,102 @@
+use crate::support::numerics::grid::{Grid, check_values};
+
+use super::{Extrapolation, InterpError};
+
+/// Piecewise-linear interpolation over a 1-D grid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Linear1d {
+    x: Grid,
+    y: Vec<f64>,
+    extrapolation: Extrapolation,
+}
+
+impl Linear1d {
+    /// Creates an interpolator through the points `(x[i], y[i])`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` has fewer than two points or is not strictly
+    /// increasing, if `y` has a different length, or if any value is not finite.
+    pub fn new(x: Vec<f64>, y: Vec<f64>) -> Result<Self, InterpError> {
+        let x = Grid::new(x)?;
+        check_values(&y, x.len())?;
+        Ok(Self {
+            x,
+            y,
+            extrapolation: Extrapolation::default(),
+        })
+    }
+
+    /// Sets how queries outside the grid are handled.
+    #[must_use]
+    pub fn with_extrapolation(self, extrapolation: Extrapolation) -> Self {
+        Self {
+            extrapolation,
+            ..self
+        }
+    }
+
+    /// Returns the grid points.
+    #[must_use]
+    pub fn x(&self) -> &[f64] {
+        self.x.points()
+    }
+
+    /// Returns the values at the grid points.
+    #[must_use]
+    pub fn y(&self) -> &[f64] {
+        &self.y
+    }
+
+    /// Evaluates the interpolant at `x`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` is NaN, or if it is outside the grid and the
+    /// extrapolation policy is [`Extrapolation::Error`].
+    pub fn eval(&self, x: f64) -> Result<f64, InterpError> {
+        let p = self.x.locate(x, self.extrapolation)?;
+        let (y0, y1) = (self.y[p.index], self.y[p.index + 1]);
+        Ok(y0 + p.fraction * (y1 - y0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn interpolates_and_extrapolates() {
+        let table = Linear1d::new(vec![0.0, 1.0, 3.0], vec![0.0, 2.0, 3.0]).unwrap();
+
+        assert_relative_eq!(table.eval(0.5).unwrap(), 1.0);
+        assert_relative_eq!(table.eval(1.0).unwrap(), 2.0);
+        assert_relative_eq!(table.eval(2.0).unwrap(), 2.5);
+        assert!(table.eval(-0.1).is_err());
+
+        let clamped = table.clone().with_extrapolation(Extrapolation::Clamp);
+        assert_relative_eq!(clamped.eval(-5.0).unwrap(), 0.0);
+        assert_relative_eq!(clamped.eval(5.0).unwrap(), 3.0);
+
+        let linear = table.with_extrapolation(Extrapolation::Linear);
+        assert_relative_eq!(linear.eval(-1.0).unwrap(), -2.0);
+        assert_relative_eq!(linear.eval(5.0).unwrap(), 4.0);
+    }
+
+    #[test]
+    fn rejects_mismatched_values() {
+        assert_eq!(
+            Linear1d::new(vec![0.0, 1.0], vec![1.0]),
+            Err(InterpError::LengthMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            Linear1d::new(vec![0.0, 1.0], vec![1.0, f64::NAN]),
+            Err(InterpError::NonFinite)
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use crate::support::numerics::grid::{Grid, check_values};
+
+use super::{Extrapolation, InterpError};
+
+/// Natural cubic spline interpolation over a 1-D grid.
+///
+/// The spline passes through every point, has continuous first and second
+/// derivatives, and has zero curvature at both ends. With
+/// [`Extrapolation::Linear`], queries outside the grid follow the tangent at
+/// the nearest end.
+///
+/// # Example
+///
+/// ```
+/// use twine_models::support::numerics::interp::CubicSpline1d;
+///
+/// let x: Vec<f64> = (0..=8).map(|i| f64::from(i) * 0.25).collect();
+/// let y = x.iter().map(|x| x.sin()).collect();
+/// let spline = CubicSpline1d::new(x, y)?;
+///
+/// assert!((spline.eval(0.8)? - 0.8_f64.sin()).abs() < 1e-3);
+/// # Ok::<(), twine_models::support::numerics::interp::InterpError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicSpline1d {
+    x: Grid,
+    y: Vec<f64>,
+    /// Second derivative of the spline at each grid point.
+    curvature: Vec<f64>,
+    extrapolation: Extrapolation,
+}
+
+impl CubicSpline1d {
+    /// Creates a natural cubic spline through the points `(x[i], y[i])`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` has fewer than two points or is not strictly
+    /// increasing, if `y` has a different length, or if any value is not finite.
+    pub fn new(x: Vec<f64>, y: Vec<f64>) -> Result<Self, InterpError> {
+        let x = Grid::new(x)?;
+        check_values(&y, x.len())?;
+        let curvature = natural_curvature(&x, &y);
+        Ok(Self {
+            x,
+            y,
+            curvature,
+            extrapolation: Extrapolation::default(),
+        })
+    }
+
+    /// Sets how queries outside the grid are handled.
+    #[must_use]
+    pub fn with_extrapolation(self, extrapolation: Extrapolation) -> Self {
+        Self {
+            extrapolation,
+            ..self
+        }
+    }
+
+    /// Evaluates the spline at `x`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `x` is NaN, or if it is outside the grid and the
+    /// extrapolation policy is [`Extrapolation::Error`].
+    pub fn eval(&self, x: f64) -> Result<f64, InterpError> {
+        let position = self.x.locate(x, self.extrapolation)?;
+        let (i, h) = (position.index, self.x.width(position.index));
+        let t = position.fraction;
+
+        // Beyond the ends the cubic would diverge, so extrapolate along the
+        // end tangent instead.
+        if t < 0.0 {
+            return Ok(self.y[0] + t * h * self.slope_at_start());
+        }
+        if t > 1.0 {
+            let last = self.y.len() - 1;
+            return Ok(self.y[last] + (t - 1.0) * h * self.slope_at_end());
+        }
+
+        let (m0, m1) = (self.curvature[i], self.curvature[i + 1]);
+        let cubic = |u: f64| u * u * u - u;
+        Ok((1.0 - t) * self.y[i]
+            + t * self.y[i + 1]
+            + h * h / 6.0 * (cubic(1.0 - t) * m0 + cubic(t) * m1))
+    }
+
+    fn slope_at_start(&self) -> f64 {
+        let h = self.x.width(0);
+        (self.y[1] - self.y[0]) / h - h * (2.0 * self.curvature[0] + self.curvature[1]) / 6.0
+    }
+
+    fn slope_at_end(&self) -> f64 {
+        let n = self.y.len() - 1;
+        let h = self.x.width(n - 1);
+        (self.y[n] - self.y[n - 1]) / h
+            + h * (self.curvature[n - 1] + 2.0 * self.curvature[n]) / 6.0
+    }
+}
+
+/// Solves for the second derivatives of a natural cubic spline.
+///
+/// Uses the Thomas algorithm on the tridiagonal system for the interior
+/// points, with zero curvature at both ends.
+fn natural_curvature(x: &Grid, y: &[f64]) -> Vec<f64> {
+    let n = y.len();
+    let mut curvature = vec![0.0; n];
+    if n < 3 {
+        return curvature;
+    }
+
+    // Forward sweep over interior rows 1..n-1.
+    let mut upper = vec![0.0; n];
+    let mut rhs = vec![0.0; n];
+    for i in 1..n - 1 {
+        let (h0, h1) = (x.width(i - 1), x.width(i));
+        let slope_change = (y[i + 1] - y[i]) / h1 - (y[i] - y[i - 1]) / h0;
+        let diag = 2.0 * (h0 + h1) - h0 * upper[i - 1];
+        upper[i] = h1 / diag;
+        rhs[i] = (6.0 * slope_change - h0 * rhs[i - 1]) / diag;
+    }
+
+    // Back substitution.
+    for i in (1..n - 1).rev() {
+        curvature[i] = rhs[i] - upper[i] * curvature[i + 1];
+    }
+    curvature
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn passes_through_points() {
+        let x = vec![0.0, 1.0, 2.5, 4.0];
+        let y = vec![1.0, -1.0, 2.0, 0.5];
+        let spline = CubicSpline1d::new(x.clone(), y.clone()).unwrap();
+        for (xi, yi) in x.into_iter().zip(y) {
+            assert_relative_eq!(spline.eval(xi).unwrap(), yi, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn reproduces_linear_data() {
+        let spline = CubicSpline1d::new(vec![0.0, 1.0, 3.0, 4.0], vec![1.0, 3.0, 7.0, 9.0])
+            .unwrap()
+            .with_extrapolation(Extrapolation::Linear);
+        assert_relative_eq!(spline.eval(2.0).unwrap(), 5.0, epsilon = 1e-12);
+        assert_relative_eq!(spline.eval(-1.0).unwrap(), -1.0, epsilon = 1e-12);
+        assert_relative_eq!(spline.eval(6.0).unwrap(), 13.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn matches_known_natural_spline() {
+        // Natural spline through (0,0), (1,1), (2,0) has curvature -3 at x=1,
+        // giving 11/16 at x=0.5.
+        let spline = CubicSpline1d::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 0.0]).unwrap();
+        assert_relative_eq!(spline.eval(0.5).unwrap(), 11.0 / 16.0, epsilon = 1e-12);
+        assert_relative_eq!(spline.eval(1.5).unwrap(), 11.0 / 16.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn clamps_to_end_values() {
+        let spline = CubicSpline1d::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 0.0])
+            .unwrap()
+            .with_extrapolation(Extrapolation::Clamp);
+        assert_relative_eq!(spline.eval(-3.0).unwrap(), 0.0);
+        assert_relative_eq!(spline.eval(3.0).unwrap(), 0.0);
+    }
+}
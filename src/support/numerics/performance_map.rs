@@ -0,0 +1,378 @@
+//! Gridded equipment performance data over named input axes.
+//!
+//! A [`PerformanceMap`] stores one output (for example, capacity or power)
+//! tabulated over `D` input [`Axis`]es (for example, evaporating and
+//! condensing temperature). It supports multilinear interpolation and
+//! inversion along one axis, which is how map-based equipment models find,
+//! say, the condensing temperature that delivers a required capacity.
+//!
+//! Axis and output units are descriptive labels: values are stored and
+//! queried as raw `f64` in those units.
+//!
+//! # Example
+//!
+//! ```
+//! use twine_models::support::numerics::performance_map::{Axis, PerformanceMap};
+//!
+//! // Compressor capacity (kW) over evaporating and condensing temperature (°C).
+//! let map = PerformanceMap::new(
+//!     [
+//!         Axis::new("evaporating temperature", "°C", vec![-10.0, 0.0, 10.0])?,
+//!         Axis::new("condensing temperature", "°C", vec![30.0, 50.0])?,
+//!     ],
+//!     vec![
+//!         8.0, 6.5, // -10 °C
+//!         11.0, 9.0, // 0 °C
+//!         15.0, 12.5, // 10 °C
+//!     ],
+//! )?
+//! .with_output("capacity", "kW");
+//!
+//! assert!((map.eval([5.0, 40.0])? - 11.875).abs() < 1e-12);
+//!
+//! // Evaporating temperature that gives 10.75 kW at 50 °C condensing.
+//! let t_evap = map.invert(0, [0.0, 50.0], 10.75)?;
+//! assert!((t_evap - 5.0).abs() < 1e-12);
+//! # Ok::<(), twine_models::support::numerics::performance_map::PerformanceMapError>(())
+//! ```
+
+use thiserror::Error;
+
+use super::{
+    grid::{Grid, check_values},
+    interp::{Extrapolation, InterpError},
+};
+
+/// A named, strictly increasing input axis of a [`PerformanceMap`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Axis {
+    name: String,
+    unit: String,
+    grid: Grid,
+}
+
+impl Axis {
+    /// Creates an axis with the given name, unit label, and grid points.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are fewer than two points, if the points are
+    /// not strictly increasing, or if any point is not finite.
+    pub fn new(
+        name: impl Into<String>,
+        unit: impl Into<String>,
+        points: Vec<f64>,
+    ) -> Result<Self, PerformanceMapError> {
+        let name = name.into();
+        let grid = Grid::new(points).map_err(|source| PerformanceMapError::Axis {
+            axis: name.clone(),
+            source,
+        })?;
+        Ok(Self {
+            name,
+            unit: unit.into(),
+            grid,
+        })
+    }
+
+    /// Returns the axis name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the axis unit label.
+    #[must_use]
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+
+    /// Returns the grid points.
+    #[must_use]
+    pub fn points(&self) -> &[f64] {
+        self.grid.points()
+    }
+}
+
+/// Errors from building or querying a [`PerformanceMap`].
+#[derive(Debug, Clone, PartialEq, Error)]
+#[non_exhaustive]
+pub enum PerformanceMapError {
+    /// An axis is invalid, or a query is outside it.
+    #[error("axis `{axis}`: {source}")]
+    Axis {
+        axis: String,
+        #[source]
+        source: InterpError,
+    },
+
+    /// The tabulated values don't match the grid.
+    #[error("invalid map values: {0}")]
+    Values(#[source] InterpError),
+
+    /// The requested axis index doesn't exist.
+    #[error("axis index {index} is out of range for a map with {dimensions} axes")]
+    NoSuchAxis { index: usize, dimensions: usize },
+
+    /// The target output is not reached anywhere along the inversion axis.
+    #[error("target {target} is not reached along axis `{axis}`")]
+    TargetNotBracketed { axis: String, target: f64 },
+}
+
+/// Tabulated output over `D` named input axes.
+///
+/// Values are stored in row-major order, with the last axis varying fastest.
+/// The extrapolation policy applies to every axis and defaults to
+/// [`Extrapolation::Error`].
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerformanceMap<const D: usize> {
+    axes: [Axis; D],
+    values: Vec<f64>,
+    output_name: String,
+    output_unit: String,
+    extrapolation: Extrapolation,
+}
+
+impl<const D: usize> PerformanceMap<D> {
+    /// Creates a map from its axes and row-major values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `values` does not have one finite entry per grid point.
+    pub fn new(axes: [Axis; D], values: Vec<f64>) -> Result<Self, PerformanceMapError> {
+        const { assert!(D > 0, "a performance map needs at least one axis") };
+
+        let expected = axes.iter().map(|axis| axis.grid.len()).product();
+        check_values(&values, expected).map_err(PerformanceMapError::Values)?;
+        Ok(Self {
+            axes,
+            values,
+            output_name: String::from("output"),
+            output_unit: String::new(),
+            extrapolation: Extrapolation::default(),
+        })
+    }
+
+    /// Sets the output name and unit label.
+    #[must_use]
+    pub fn with_output(self, name: impl Into<String>, unit: impl Into<String>) -> Self {
+        Self {
+            output_name: name.into(),
+            output_unit: unit.into(),
+            ..self
+        }
+    }
+
+    /// Sets how queries outside the grid are handled.
+    #[must_use]
+    pub fn with_extrapolation(self, extrapolation: Extrapolation) -> Self {
+        Self {
+            extrapolation,
+            ..self
+        }
+    }
+
+    /// Returns the input axes.
+    #[must_use]
+    pub fn axes(&self) -> &[Axis; D] {
+        &self.axes
+    }
+
+    /// Returns the index of the axis with the given name.
+    #[must_use]
+    pub fn axis_index(&self, name: &str) -> Option<usize> {
+        self.axes.iter().position(|axis| axis.name == name)
+    }
+
+    /// Returns the output name.
+    #[must_use]
+    pub fn output_name(&self) -> &str {
+        &self.output_name
+    }
+
+    /// Returns the output unit label.
+    #[must_use]
+    pub fn output_unit(&self) -> &str {
+        &self.output_unit
+    }
+
+    /// Interpolates the output at `point`, given in axis order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any coordinate is NaN, or if the point is outside
+    /// the grid and the extrapolation policy is [`Extrapolation::Error`].
+    pub fn eval(&self, point: [f64; D]) -> Result<f64, PerformanceMapError> {
+        let mut positions = [(0, 0.0); D];
+        for (k, axis) in self.axes.iter().enumerate() {
+            let position = axis
+                .grid
+                .locate(point[k], self.extrapolation)
+                .map_err(|source| PerformanceMapError::Axis {
+                    axis: axis.name.clone(),
+                    source,
+                })?;
+            positions[k] = (position.index, position.fraction);
+        }
+
+        // Weighted sum over the 2^D corners of the enclosing cell.
+        let mut result = 0.0;
+        for corner in 0..1_usize << D {
+            let mut weight = 1.0;
+            let mut offset = 0;
+            for (k, &(index, fraction)) in positions.iter().enumerate() {
+                let upper = corner >> k & 1 == 1;
+                weight *= if upper { fraction } else { 1.0 - fraction };
+                offset = offset * self.axes[k].grid.len() + index + usize::from(upper);
+            }
+            result += weight * self.values[offset];
+        }
+        Ok(result)
+    }
+
+    /// Finds the coordinate along `axis` at which the output equals `target`.
+    ///
+    /// The other coordinates are taken from `point`; its entry for `axis` is
+    /// ignored. Multilinear interpolation is piecewise linear along a single
+    /// axis, so the solution is exact. If the target is reached more than
+    /// once, the lowest coordinate is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `axis` is out of range, if the other coordinates
+    /// can't be evaluated, or if `target` is not reached within the axis range.
+    pub fn invert(
+        &self,
+        axis: usize,
+        point: [f64; D],
+        target: f64,
+    ) -> Result<f64, PerformanceMapError> {
+        let grid = &self
+            .axes
+            .get(axis)
+            .ok_or(PerformanceMapError::NoSuchAxis {
+                index: axis,
+                dimensions: D,
+            })?
+            .grid;
+
+        let mut along = Vec::with_capacity(grid.len());
+        for &x in grid.points() {
+            let mut query = point;
+            query[axis] = x;
+            along.push(self.eval(query)? - target);
+        }
+
+        along
+            .windows(2)
+            .enumerate()
+            .find(|(_, pair)| pair[0] == 0.0 || pair[0].signum() != pair[1].signum())
+            .map(|(i, pair)| {
+                let fraction = pair[0] / (pair[0] - pair[1]);
+                grid.points()[i] + fraction * grid.width(i)
+            })
+            .or_else(|| (along.last() == Some(&0.0)).then(|| grid.points()[grid.len() - 1]))
+            .ok_or_else(|| PerformanceMapError::TargetNotBracketed {
+                axis: self.axes[axis].name.clone(),
+                target,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    /// `z = x + 2y + 3w + xyw` over a 3-D grid, which multilinear
+    /// interpolation reproduces exactly.
+    fn map_3d() -> PerformanceMap<3> {
+        let f = |x: f64, y: f64, w: f64| x + 2.0 * y + 3.0 * w + x * y * w;
+        let xs = vec![0.0, 1.0, 2.0];
+        let ys = vec![0.0, 0.5, 2.0];
+        let ws = vec![-1.0, 1.0];
+
+        let mut values = Vec::new();
+        for &x in &xs {
+            for &y in &ys {
+                for &w in &ws {
+                    values.push(f(x, y, w));
+                }
+            }
+        }
+
+        PerformanceMap::new(
+            [
+                Axis::new("x", "-", xs).unwrap(),
+                Axis::new("y", "-", ys).unwrap(),
+                Axis::new("w", "-", ws).unwrap(),
+            ],
+            values,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn multilinear_eval_is_exact_for_multilinear_data() {
+        let map = map_3d();
+        let (x, y, w) = (1.5, 1.25, 0.2);
+        assert_relative_eq!(
+            map.eval([x, y, w]).unwrap(),
+            x + 2.0 * y + 3.0 * w + x * y * w,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(map.eval([2.0, 2.0, 1.0]).unwrap(), 13.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn inversion_recovers_coordinate() {
+        let map = map_3d();
+        let point = [1.5, 1.25, 0.2];
+        let target = map.eval(point).unwrap();
+
+        for axis in 0..3 {
+            let recovered = map.invert(axis, point, target).unwrap();
+            assert_relative_eq!(recovered, point[axis], epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn inversion_reports_unreachable_targets() {
+        let map = map_3d();
+        assert!(matches!(
+            map.invert(0, [0.0, 0.0, 0.0], 100.0),
+            Err(PerformanceMapError::TargetNotBracketed { .. })
+        ));
+        assert!(matches!(
+            map.invert(3, [0.0, 0.0, 0.0], 0.0),
+            Err(PerformanceMapError::NoSuchAxis { index: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn errors_name_the_axis() {
+        let map = map_3d();
+        let err = map.eval([0.0, 5.0, 0.0]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "axis `y`: query 5 is outside the grid range [0, 2]"
+        );
+        assert_eq!(map.axis_index("w"), Some(2));
+        assert_eq!(map.axis_index("missing"), None);
+
+        let clamped = map.with_extrapolation(Extrapolation::Clamp);
+        assert_relative_eq!(
+            clamped.eval([0.0, 5.0, 0.0]).unwrap(),
+            clamped.eval([0.0, 2.0, 0.0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_value_count() {
+        let result = PerformanceMap::new([Axis::new("x", "-", vec![0.0, 1.0]).unwrap()], vec![1.0]);
+        assert!(matches!(result, Err(PerformanceMapError::Values(_))));
+    }
+}
@@ -0,0 +1,181 @@
+//! Scalar root finding and fixed-point iteration.
+//!
+//! These helpers work directly on closures, for the small inner solves that
+//! don't warrant wrapping a model in a `twine_solvers` problem:
+//!
+//! - [`brent`]: bracketed root finding with guaranteed convergence.
+//! - [`secant`]: unbracketed root finding from two starting points.
+//! - [`fixed_point`]: damped successive substitution, e.g. for tear streams.
+//!
+//! Closures return `Result` so model failures propagate as
+//! [`SolveError::Eval`]. Use [`Infallible`](std::convert::Infallible) as the
+//! error type for functions that can't fail.
+//!
+//! With the `tracing` feature enabled, each solver runs inside a `debug`
+//! span and emits a `trace` event per iteration.
+//!
+//! # Example
+//!
+//! ```
+//! use std::convert::Infallible;
+//!
+//! use twine_models::support::numerics::solve::{Config, brent};
+//!
+//! let solution = brent(
+//!     |x| Ok::<_, Infallible>(x * x - 2.0),
+//!     [0.0, 2.0],
+//!     &Config::default(),
+//! )?;
+//! assert!((solution.x - 2.0_f64.sqrt()).abs() < 1e-12);
+//! # Ok::<(), twine_models::support::numerics::solve::SolveError<Infallible>>(())
+//! ```
+
+mod brent;
+mod fixed_point;
+mod secant;
+
+use thiserror::Error;
+
+pub use brent::brent;
+pub use fixed_point::fixed_point;
+pub use secant::secant;
+
+/// Iteration limits and convergence tolerances.
+///
+/// A solve converges when either the step in `x` falls below
+/// `x_abs_tol + x_rel_tol * |x|`, or the residual magnitude falls below
+/// `residual_tol`. For [`fixed_point`] the residual is the largest change
+/// `|g(x) - x|` across components.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    pub max_iters: usize,
+    pub x_abs_tol: f64,
+    pub x_rel_tol: f64,
+    pub residual_tol: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_iters: 100,
+            x_abs_tol: 1e-12,
+            x_rel_tol: 1e-12,
+            residual_tol: 1e-12,
+        }
+    }
+}
+
+impl Config {
+    fn validate<E>(&self) -> Result<(), SolveError<E>> {
+        let valid = |tol: f64| tol.is_finite() && tol >= 0.0;
+        if !valid(self.x_abs_tol) {
+            return Err(SolveError::InvalidConfig(
+                "x_abs_tol must be finite and non-negative",
+            ));
+        }
+        if !valid(self.x_rel_tol) {
+            return Err(SolveError::InvalidConfig(
+                "x_rel_tol must be finite and non-negative",
+            ));
+        }
+        if !valid(self.residual_tol) {
+            return Err(SolveError::InvalidConfig(
+                "residual_tol must be finite and non-negative",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns whether a step of size `step` near `x` is within tolerance.
+    fn step_converged(&self, step: f64, x: f64) -> bool {
+        step.abs() <= self.x_abs_tol + self.x_rel_tol * x.abs()
+    }
+}
+
+/// A converged solution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Solution<X> {
+    /// The solution.
+    pub x: X,
+
+    /// Residual at the solution.
+    pub residual: f64,
+
+    /// Number of iterations performed.
+    pub iters: usize,
+}
+
+/// Errors from the solvers in this module.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum SolveError<E> {
+    /// A tolerance or parameter is invalid.
+    #[error("invalid solver configuration: {0}")]
+    InvalidConfig(&'static str),
+
+    /// The function failed to evaluate.
+    #[error("function evaluation failed")]
+    Eval(#[source] E),
+
+    /// The function returned a non-finite value.
+    #[error("function value is not finite at x={x}")]
+    NonFinite { x: f64 },
+
+    /// The residuals at the bracket ends have the same sign.
+    #[error("bracket does not contain a root: f({a})={fa}, f({b})={fb}")]
+    NotBracketed { a: f64, b: f64, fa: f64, fb: f64 },
+
+    /// The secant through the last two points is horizontal.
+    #[error("secant is flat near x={x}")]
+    FlatSecant { x: f64 },
+
+    /// The solver hit the iteration limit before converging.
+    #[error("failed to converge in {iters} iterations (residual={residual})")]
+    MaxIters { iters: usize, residual: f64 },
+}
+
+/// Records a `twine_solvers` bisection event as a `trace` event.
+///
+/// Models that drive [`bisection`](twine_solvers::equation::bisection) call
+/// this from their observer so their iterations are traced the same way as
+/// the closure solvers in this module.
+#[cfg(feature = "tracing")]
+pub(crate) fn trace_bisection<M, P>(event: &twine_solvers::equation::bisection::Event<'_, M, P>)
+where
+    M: twine_core::Model,
+    M::Error: std::fmt::Display,
+    P: twine_core::EquationProblem<1, Input = M::Input, Output = M::Output>,
+    P::Error: std::fmt::Display,
+{
+    use twine_solvers::equation::bisection::Event;
+
+    match event {
+        Event::Evaluated { point, bracket, .. } => tracing::trace!(
+            x = point.x,
+            residual = point.residual,
+            bracket = ?bracket.as_array(),
+        ),
+        Event::ModelFailed { x, error, bracket } => tracing::trace!(
+            x,
+            %error,
+            bracket = ?bracket.as_array(),
+            "model failed"
+        ),
+        Event::ProblemFailed { x, error, bracket } => tracing::trace!(
+            x,
+            %error,
+            bracket = ?bracket.as_array(),
+            "problem failed"
+        ),
+    }
+}
+
+/// Evaluates `f(x)`, mapping failures and non-finite values to errors.
+fn eval<E>(f: &mut impl FnMut(f64) -> Result<f64, E>, x: f64) -> Result<f64, SolveError<E>> {
+    let value = f(x).map_err(SolveError::Eval)?;
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(SolveError::NonFinite { x })
+    }
+}
@@ -0,0 +1,213 @@
+use super::{Config, Solution, SolveError, eval};
+
+/// Finds a root of `f` within `bracket` using Brent's method.
+///
+/// Brent's method combines bisection with secant and inverse quadratic
+/// interpolation steps. It never leaves the bracket, so it converges whenever
+/// `f` is continuous and changes sign across it, typically much faster than
+/// plain bisection.
+///
+/// # Errors
+///
+/// Returns an error if the configuration is invalid, if `f` fails or returns
+/// a non-finite value, if `f` has the same sign at both ends of the bracket,
+/// or if the iteration limit is reached.
+// Variable names follow the standard presentation of the algorithm.
+#[allow(clippy::many_single_char_names, clippy::float_cmp)]
+pub fn brent<E>(
+    mut f: impl FnMut(f64) -> Result<f64, E>,
+    bracket: [f64; 2],
+    config: &Config,
+) -> Result<Solution<f64>, SolveError<E>> {
+    config.validate()?;
+
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("brent", ?bracket).entered();
+
+    let [mut a, mut b] = bracket;
+    let mut fa = eval(&mut f, a)?;
+    let mut fb = eval(&mut f, b)?;
+
+    if fa.abs() <= config.residual_tol {
+        return Ok(Solution {
+            x: a,
+            residual: fa,
+            iters: 0,
+        });
+    }
+    if fb.abs() <= config.residual_tol {
+        return Ok(Solution {
+            x: b,
+            residual: fb,
+            iters: 0,
+        });
+    }
+    if fa.signum() == fb.signum() {
+        return Err(SolveError::NotBracketed { a, b, fa, fb });
+    }
+
+    // `b` is the best estimate, `c` is the other end of the current bracket,
+    // and `a` is the previous value of `b`.
+    let (mut c, mut fc) = (a, fa);
+    let mut step = b - a;
+    let mut previous_step = step;
+
+    for iter in 1..=config.max_iters {
+        if fb.signum() == fc.signum() {
+            (c, fc) = (a, fa);
+            step = b - a;
+            previous_step = step;
+        }
+        if fc.abs() < fb.abs() {
+            (a, fa) = (b, fb);
+            (b, fb) = (c, fc);
+            (c, fc) = (a, fa);
+        }
+
+        let tol =
+            2.0 * f64::EPSILON * b.abs() + 0.5 * (config.x_abs_tol + config.x_rel_tol * b.abs());
+        let midpoint = 0.5 * (c - b);
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(iter, x = b, residual = fb, bracket = ?[b.min(c), b.max(c)]);
+
+        if midpoint.abs() <= tol || fb.abs() <= config.residual_tol {
+            return Ok(Solution {
+                x: b,
+                residual: fb,
+                iters: iter,
+            });
+        }
+
+        if previous_step.abs() >= tol && fa.abs() > fb.abs() {
+            // Attempt interpolation: secant when only two distinct points are
+            // available, inverse quadratic otherwise.
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * midpoint * s, 1.0 - s)
+            } else {
+                let qa = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * midpoint * qa * (qa - r) - (b - a) * (r - 1.0)),
+                    (qa - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            } else {
+                p = -p;
+            }
+
+            // Accept the interpolation only if it stays well inside the
+            // bracket and is shrinking fast enough; otherwise bisect.
+            if 2.0 * p < (3.0 * midpoint * q - (tol * q).abs()).min((previous_step * q).abs()) {
+                previous_step = step;
+                step = p / q;
+            } else {
+                step = midpoint;
+                previous_step = midpoint;
+            }
+        } else {
+            step = midpoint;
+            previous_step = midpoint;
+        }
+
+        (a, fa) = (b, fb);
+        b += if step.abs() > tol {
+            step
+        } else {
+            tol.copysign(midpoint)
+        };
+        fb = eval(&mut f, b)?;
+    }
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        iters = config.max_iters,
+        x = b,
+        residual = fb,
+        "brent hit iteration limit"
+    );
+
+    Err(SolveError::MaxIters {
+        iters: config.max_iters,
+        residual: fb,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+
+    #[allow(clippy::unnecessary_wraps)]
+    fn ok(value: f64) -> Result<f64, Infallible> {
+        Ok(value)
+    }
+
+    #[test]
+    fn finds_roots_of_smooth_functions() {
+        let config = Config::default();
+
+        let cubic = brent(|x| ok(x * x * x - 2.0 * x - 5.0), [2.0, 3.0], &config).unwrap();
+        assert_relative_eq!(cubic.x, 2.094_551_481_542_327, epsilon = 1e-12);
+        assert!(cubic.iters < 15);
+
+        let cosine = brent(|x| ok(x.cos() - x), [0.0, 1.0], &config).unwrap();
+        assert_relative_eq!(cosine.x, 0.739_085_133_215_160_6, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn handles_reversed_bracket_and_exact_endpoint() {
+        let config = Config::default();
+        let reversed = brent(|x| ok(x - 1.0), [3.0, -2.0], &config).unwrap();
+        assert_relative_eq!(reversed.x, 1.0, epsilon = 1e-12);
+
+        let endpoint = brent(|x| ok(x - 1.0), [1.0, 2.0], &config).unwrap();
+        assert_relative_eq!(endpoint.x, 1.0);
+        assert_eq!(endpoint.iters, 0);
+    }
+
+    #[test]
+    fn converges_on_badly_scaled_functions() {
+        // A flat-bottomed root where interpolation steps make slow progress,
+        // so convergence relies on the bisection fallback.
+        let config = Config {
+            max_iters: 200,
+            residual_tol: 0.0,
+            ..Config::default()
+        };
+        let solution = brent(|x| ok((x - 0.3).powi(9)), [0.0, 1.0], &config).unwrap();
+        assert_relative_eq!(solution.x, 0.3, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn reports_errors() {
+        let config = Config::default();
+        assert!(matches!(
+            brent(|x| ok(x * x + 1.0), [-1.0, 1.0], &config),
+            Err(SolveError::NotBracketed { .. })
+        ));
+        assert!(matches!(
+            brent(|x| ok(1.0 / x), [0.0, 1.0], &config),
+            Err(SolveError::NonFinite { x }) if x == 0.0
+        ));
+        assert!(matches!(
+            brent(|_| Err::<f64, _>("model failed"), [0.0, 1.0], &config),
+            Err(SolveError::Eval("model failed"))
+        ));
+
+        let limited = Config {
+            max_iters: 2,
+            ..config
+        };
+        assert!(matches!(
+            brent(|x| ok(x.cos() - x), [0.0, 1.0], &limited),
+            Err(SolveError::MaxIters { iters: 2, .. })
+        ));
+    }
+}
@@ -0,0 +1,143 @@
+use super::{Config, Solution, SolveError};
+
+/// Solves `x = g(x)` by damped successive substitution.
+///
+/// Each iteration updates `x ← x + damping · (g(x) − x)`. A damping of `1.0`
+/// is plain substitution; smaller values trade speed for stability when the
+/// undamped iteration oscillates or diverges, as is common when closing a
+/// tear stream in a recycle loop.
+///
+/// Converges when every component's change `|g(x) − x|` is within the `x`
+/// tolerances, or when the largest change is within `residual_tol`. The
+/// returned residual is that largest change.
+///
+/// # Errors
+///
+/// Returns an error if the configuration is invalid, if `damping` is not in
+/// `(0, 1]`, if `g` fails or returns a non-finite value, or if the iteration
+/// limit is reached.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::Infallible;
+///
+/// use twine_models::support::numerics::solve::{Config, fixed_point};
+///
+/// // x = cos(x), y = x / 2
+/// let solution = fixed_point(
+///     |[x, _]| Ok::<_, Infallible>([x.cos(), x / 2.0]),
+///     [1.0, 0.0],
+///     0.8,
+///     &Config::default(),
+/// )?;
+/// assert!((solution.x[0] - 0.739_085_133).abs() < 1e-9);
+/// # Ok::<(), twine_models::support::numerics::solve::SolveError<Infallible>>(())
+/// ```
+pub fn fixed_point<const N: usize, E>(
+    mut g: impl FnMut([f64; N]) -> Result<[f64; N], E>,
+    x0: [f64; N],
+    damping: f64,
+    config: &Config,
+) -> Result<Solution<[f64; N]>, SolveError<E>> {
+    config.validate()?;
+    if !(damping > 0.0 && damping <= 1.0) {
+        return Err(SolveError::InvalidConfig("damping must be in (0, 1]"));
+    }
+
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("fixed_point", n = N, damping).entered();
+
+    let mut x = x0;
+    let mut residual = f64::INFINITY;
+
+    for iter in 1..=config.max_iters {
+        let gx = g(x).map_err(SolveError::Eval)?;
+        if let Some(i) = gx.iter().position(|v| !v.is_finite()) {
+            return Err(SolveError::NonFinite { x: x[i] });
+        }
+
+        let mut converged = true;
+        residual = 0.0;
+        for i in 0..N {
+            let change = gx[i] - x[i];
+            residual = f64::max(residual, change.abs());
+            converged &= config.step_converged(change, gx[i]);
+            x[i] += damping * change;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(iter, residual, converged);
+
+        if converged || residual <= config.residual_tol {
+            return Ok(Solution {
+                x,
+                residual,
+                iters: iter,
+            });
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        iters = config.max_iters,
+        residual,
+        "fixed_point hit iteration limit"
+    );
+
+    Err(SolveError::MaxIters {
+        iters: config.max_iters,
+        residual,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn solves_scalar_fixed_point() {
+        let solution = fixed_point(
+            |[x]| Ok::<_, Infallible>([x.cos()]),
+            [0.0],
+            1.0,
+            &Config::default(),
+        )
+        .unwrap();
+        assert_relative_eq!(solution.x[0], 0.739_085_133_215_160_6, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn damping_stabilizes_an_oscillating_iteration() {
+        // g(x) = 3 − 2x has fixed point 1 and slope −2, so plain
+        // substitution diverges, while damping 0.5 converges in one step.
+        let g = |[x]: [f64; 1]| Ok::<_, Infallible>([3.0 - 2.0 * x]);
+        let config = Config {
+            max_iters: 50,
+            ..Config::default()
+        };
+
+        assert!(matches!(
+            fixed_point(g, [0.0], 1.0, &config),
+            Err(SolveError::NonFinite { .. } | SolveError::MaxIters { .. })
+        ));
+
+        let damped = fixed_point(g, [0.0], 0.3, &config).unwrap();
+        assert_relative_eq!(damped.x[0], 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn rejects_invalid_damping() {
+        let g = |x: [f64; 1]| Ok::<_, Infallible>(x);
+        for damping in [0.0, 1.5, f64::NAN] {
+            assert!(matches!(
+                fixed_point(g, [0.0], damping, &Config::default()),
+                Err(SolveError::InvalidConfig(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use super::{Config, Solution, SolveError, eval};
+
+/// Finds a root of `f` with the secant method, starting from `x0` and `x1`.
+///
+/// The secant method converges superlinearly near a simple root and needs no
+/// bracket or derivative, but it can diverge from a poor starting guess. Prefer
+/// [`brent`](super::brent) when a bracket is known.
+///
+/// # Errors
+///
+/// Returns an error if the configuration is invalid, if `f` fails or returns
+/// a non-finite value, if two successive residuals are equal, or if the
+/// iteration limit is reached.
+pub fn secant<E>(
+    mut f: impl FnMut(f64) -> Result<f64, E>,
+    x0: f64,
+    x1: f64,
+    config: &Config,
+) -> Result<Solution<f64>, SolveError<E>> {
+    config.validate()?;
+
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("secant", x0, x1).entered();
+
+    let (mut x_prev, mut x) = (x0, x1);
+    let mut f_prev = eval(&mut f, x_prev)?;
+    let mut fx = eval(&mut f, x)?;
+
+    for iter in 1..=config.max_iters {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(iter, x, residual = fx);
+
+        if fx.abs() <= config.residual_tol {
+            return Ok(Solution {
+                x,
+                residual: fx,
+                iters: iter - 1,
+            });
+        }
+
+        let slope_denominator = fx - f_prev;
+        if slope_denominator == 0.0 {
+            return Err(SolveError::FlatSecant { x });
+        }
+
+        let step = -fx * (x - x_prev) / slope_denominator;
+        (x_prev, f_prev) = (x, fx);
+        x += step;
+        fx = eval(&mut f, x)?;
+
+        if config.step_converged(step, x) {
+            return Ok(Solution {
+                x,
+                residual: fx,
+                iters: iter,
+            });
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        iters = config.max_iters,
+        x,
+        residual = fx,
+        "secant hit iteration limit"
+    );
+
+    Err(SolveError::MaxIters {
+        iters: config.max_iters,
+        residual: fx,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+
+    #[allow(clippy::unnecessary_wraps)]
+    fn ok(value: f64) -> Result<f64, Infallible> {
+        Ok(value)
+    }
+
+    #[test]
+    fn finds_root_from_nearby_guesses() {
+        let solution = secant(|x| ok(x * x - 2.0), 1.0, 2.0, &Config::default()).unwrap();
+        assert_relative_eq!(solution.x, 2.0_f64.sqrt(), epsilon = 1e-12);
+        assert!(solution.iters < 10);
+    }
+
+    #[test]
+    fn returns_immediately_at_root() {
+        let solution = secant(|x| ok(x - 2.0), 0.0, 2.0, &Config::default()).unwrap();
+        assert_relative_eq!(solution.x, 2.0);
+        assert_eq!(solution.iters, 0);
+    }
+
+    #[test]
+    fn reports_flat_secant() {
+        assert!(matches!(
+            secant(|x| ok(x * x - 1.0), -2.0, 2.0, &Config::default()),
+            Err(SolveError::FlatSecant { .. })
+        ));
+    }
+
+    #[test]
+    fn reports_invalid_config() {
+        let config = Config {
+            x_rel_tol: -1.0,
+            ..Config::default()
+        };
+        assert!(matches!(
+            secant(ok, 0.0, 1.0, &config),
+            Err(SolveError::InvalidConfig(_))
+        ));
+    }
+}
@@ -0,0 +1,347 @@
+//! Connector types shared by models that exchange fluid or heat.
+//!
+//! A [`FluidPort`] is what crosses a fluid connection: a thermodynamic state
+//! and a mass flow rate, plus an optional pressure for fluids whose state
+//! does not fix it (an incompressible liquid, for example). A [`HeatPort`]
+//! is what crosses a thermal connection: a temperature and a heat flow.
+//!
+//! Models that speak these types can be wired together directly. When two
+//! ports are joined, [`connect_fluid`] and [`connect_heat`] check that the
+//! connection conserves mass and energy, which catches sign and units slips
+//! at the seam between two models:
+//!
+//! ```
+//! use twine_models::support::{
+//!     port::{ConnectionTolerance, HeatPort, connect_heat},
+//! };
+//! use uom::si::{
+//!     f64::{Power, ThermodynamicTemperature},
+//!     power::watt,
+//!     thermodynamic_temperature::kelvin,
+//! };
+//!
+//! // A wall loses 150 W to a coolant that gains 150 W at the same surface.
+//! let t = ThermodynamicTemperature::new::<kelvin>(320.0);
+//! let wall = HeatPort::new(t, Power::new::<watt>(-150.0));
+//! let coolant = HeatPort::new(t, Power::new::<watt>(150.0));
+//! assert!(connect_heat(&wall, &coolant, &ConnectionTolerance::default()).is_ok());
+//!
+//! // Forgetting the sign flip is reported.
+//! assert!(connect_heat(&wall, &wall, &ConnectionTolerance::default()).is_err());
+//! ```
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::{
+        f64::{
+            MassDensity, MassRate, Power, Pressure, TemperatureInterval, ThermodynamicTemperature,
+        },
+        mass_density::kilogram_per_cubic_meter,
+        mass_rate::kilogram_per_second,
+        power::watt,
+        pressure::pascal,
+        temperature_interval::kelvin as delta_kelvin,
+        thermodynamic_temperature::kelvin,
+    },
+};
+
+use crate::support::thermo::State;
+
+/// Fluid crossing a connection.
+///
+/// `mass_flow` is positive in the nominal flow direction, from the
+/// upstream model's outlet into the downstream model's inlet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FluidPort<Fluid> {
+    /// Thermodynamic state of the fluid.
+    pub state: State<Fluid>,
+
+    /// Mass flow rate.
+    pub mass_flow: MassRate,
+
+    /// Pressure at the port, for fluids whose state does not determine it.
+    ///
+    /// `None` when the pressure, if needed, comes from the thermo model.
+    pub pressure: Option<Pressure>,
+}
+
+impl<Fluid> FluidPort<Fluid> {
+    /// Creates a port without a pressure reference.
+    #[must_use]
+    pub fn new(state: State<Fluid>, mass_flow: MassRate) -> Self {
+        Self {
+            state,
+            mass_flow,
+            pressure: None,
+        }
+    }
+
+    /// Sets the pressure reference.
+    #[must_use]
+    pub fn with_pressure(mut self, pressure: Pressure) -> Self {
+        self.pressure = Some(pressure);
+        self
+    }
+}
+
+/// Heat crossing a thermal connection.
+///
+/// `heat_flow` is positive into the model that owns the port, so the two
+/// sides of a connection carry equal and opposite heat flows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeatPort {
+    /// Temperature at the connection.
+    pub temperature: ThermodynamicTemperature,
+
+    /// Heat flow into the owning model.
+    pub heat_flow: Power,
+}
+
+impl HeatPort {
+    /// Creates a heat port.
+    #[must_use]
+    pub fn new(temperature: ThermodynamicTemperature, heat_flow: Power) -> Self {
+        Self {
+            temperature,
+            heat_flow,
+        }
+    }
+}
+
+/// Allowed mismatch across a connection.
+///
+/// Two values agree when they differ by at most
+/// `absolute + relative · max(|a|, |b|)`. Density and pressure use the
+/// relative part only.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConnectionTolerance {
+    /// Relative allowance.
+    pub relative: f64,
+
+    /// Absolute allowance on mass flow.
+    pub mass_flow: MassRate,
+
+    /// Absolute allowance on temperature.
+    pub temperature: TemperatureInterval,
+
+    /// Absolute allowance on heat flow.
+    pub heat_flow: Power,
+}
+
+impl ConnectionTolerance {
+    /// A purely relative tolerance.
+    #[must_use]
+    pub fn relative(relative: f64) -> Self {
+        Self {
+            relative,
+            mass_flow: MassRate::ZERO,
+            temperature: TemperatureInterval::ZERO,
+            heat_flow: Power::ZERO,
+        }
+    }
+
+    fn agree(&self, a: f64, b: f64, absolute: f64) -> bool {
+        (a - b).abs() <= absolute + self.relative * a.abs().max(b.abs())
+    }
+}
+
+impl Default for ConnectionTolerance {
+    /// A relative tolerance of `1e-9`.
+    fn default() -> Self {
+        Self::relative(1e-9)
+    }
+}
+
+/// A connection that does not conserve mass or energy.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ConnectionError {
+    /// Different mass flows leave and enter.
+    #[error("mass flow mismatch: {outlet:?} leaves, {inlet:?} enters")]
+    MassFlow { outlet: MassRate, inlet: MassRate },
+
+    /// The two sides report different temperatures.
+    #[error("temperature mismatch: {outlet:?} vs {inlet:?}")]
+    Temperature {
+        outlet: ThermodynamicTemperature,
+        inlet: ThermodynamicTemperature,
+    },
+
+    /// The two sides report different densities.
+    #[error("density mismatch: {outlet:?} vs {inlet:?}")]
+    Density {
+        outlet: MassDensity,
+        inlet: MassDensity,
+    },
+
+    /// The two sides carry different pressure references.
+    #[error("pressure mismatch: {outlet:?} vs {inlet:?}")]
+    Pressure { outlet: Pressure, inlet: Pressure },
+
+    /// The heat flows are not equal and opposite.
+    #[error("heat flows do not balance: {first:?} + {second:?} != 0")]
+    HeatFlow { first: Power, second: Power },
+}
+
+/// Checks that the fluid leaving `outlet` is the fluid entering `inlet`.
+///
+/// Mass flow, temperature, and density must agree, as must the pressure
+/// references when both ports carry one. Equal mass flow at the same state
+/// also means equal enthalpy flow, so the connection conserves energy.
+///
+/// # Errors
+///
+/// Returns the first [`ConnectionError`] found.
+pub fn connect_fluid<Fluid>(
+    outlet: &FluidPort<Fluid>,
+    inlet: &FluidPort<Fluid>,
+    tolerance: &ConnectionTolerance,
+) -> Result<(), ConnectionError> {
+    if !tolerance.agree(
+        outlet.mass_flow.get::<kilogram_per_second>(),
+        inlet.mass_flow.get::<kilogram_per_second>(),
+        tolerance.mass_flow.get::<kilogram_per_second>(),
+    ) {
+        return Err(ConnectionError::MassFlow {
+            outlet: outlet.mass_flow,
+            inlet: inlet.mass_flow,
+        });
+    }
+
+    check_temperature(outlet.state.temperature, inlet.state.temperature, tolerance)?;
+
+    if !tolerance.agree(
+        outlet.state.density.get::<kilogram_per_cubic_meter>(),
+        inlet.state.density.get::<kilogram_per_cubic_meter>(),
+        0.0,
+    ) {
+        return Err(ConnectionError::Density {
+            outlet: outlet.state.density,
+            inlet: inlet.state.density,
+        });
+    }
+
+    if let (Some(p_out), Some(p_in)) = (outlet.pressure, inlet.pressure)
+        && !tolerance.agree(p_out.get::<pascal>(), p_in.get::<pascal>(), 0.0)
+    {
+        return Err(ConnectionError::Pressure {
+            outlet: p_out,
+            inlet: p_in,
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks that two heat ports meet at one temperature and exchange equal and
+/// opposite heat flows.
+///
+/// # Errors
+///
+/// Returns the first [`ConnectionError`] found.
+pub fn connect_heat(
+    first: &HeatPort,
+    second: &HeatPort,
+    tolerance: &ConnectionTolerance,
+) -> Result<(), ConnectionError> {
+    check_temperature(first.temperature, second.temperature, tolerance)?;
+
+    if !tolerance.agree(
+        first.heat_flow.get::<watt>(),
+        -second.heat_flow.get::<watt>(),
+        tolerance.heat_flow.get::<watt>(),
+    ) {
+        return Err(ConnectionError::HeatFlow {
+            first: first.heat_flow,
+            second: second.heat_flow,
+        });
+    }
+
+    Ok(())
+}
+
+fn check_temperature(
+    outlet: ThermodynamicTemperature,
+    inlet: ThermodynamicTemperature,
+    tolerance: &ConnectionTolerance,
+) -> Result<(), ConnectionError> {
+    if tolerance.agree(
+        outlet.get::<kelvin>(),
+        inlet.get::<kelvin>(),
+        tolerance.temperature.get::<delta_kelvin>(),
+    ) {
+        Ok(())
+    } else {
+        Err(ConnectionError::Temperature { outlet, inlet })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uom::si::pressure::kilopascal;
+
+    use crate::support::thermo::fluid::Water;
+
+    fn port(t: f64, m_dot: f64) -> FluidPort<Water> {
+        FluidPort::new(
+            State::new(
+                ThermodynamicTemperature::new::<kelvin>(t),
+                MassDensity::new::<kilogram_per_cubic_meter>(990.0),
+                Water,
+            ),
+            MassRate::new::<kilogram_per_second>(m_dot),
+        )
+    }
+
+    #[test]
+    fn accepts_matching_fluid_ports() {
+        let outlet = port(330.0, 0.5).with_pressure(Pressure::new::<kilopascal>(300.0));
+        let inlet = port(330.0, 0.5 * (1.0 + 1e-12));
+        assert!(connect_fluid(&outlet, &inlet, &ConnectionTolerance::default()).is_ok());
+    }
+
+    #[test]
+    fn reports_the_quantity_that_does_not_match() {
+        let tolerance = ConnectionTolerance::default();
+
+        assert!(matches!(
+            connect_fluid(&port(330.0, 0.5), &port(330.0, 0.4), &tolerance),
+            Err(ConnectionError::MassFlow { .. })
+        ));
+        assert!(matches!(
+            connect_fluid(&port(330.0, 0.5), &port(331.0, 0.5), &tolerance),
+            Err(ConnectionError::Temperature { .. })
+        ));
+
+        let outlet = port(330.0, 0.5).with_pressure(Pressure::new::<kilopascal>(300.0));
+        let inlet = port(330.0, 0.5).with_pressure(Pressure::new::<kilopascal>(250.0));
+        assert!(matches!(
+            connect_fluid(&outlet, &inlet, &tolerance),
+            Err(ConnectionError::Pressure { .. })
+        ));
+    }
+
+    #[test]
+    fn absolute_allowance_covers_values_near_zero() {
+        let tolerance = ConnectionTolerance {
+            mass_flow: MassRate::new::<kilogram_per_second>(1e-9),
+            ..ConnectionTolerance::default()
+        };
+        assert!(connect_fluid(&port(330.0, 0.0), &port(330.0, 1e-10), &tolerance).is_ok());
+
+        let t = ThermodynamicTemperature::new::<kelvin>(300.0);
+        let tolerance = ConnectionTolerance {
+            heat_flow: Power::new::<watt>(1e-6),
+            ..ConnectionTolerance::default()
+        };
+        let first = HeatPort::new(t, Power::new::<watt>(1e-7));
+        let second = HeatPort::new(t, Power::ZERO);
+        assert!(connect_heat(&first, &second, &tolerance).is_ok());
+        assert!(matches!(
+            connect_heat(&first, &second, &ConnectionTolerance::default()),
+            Err(ConnectionError::HeatFlow { .. })
+        ));
+    }
+}
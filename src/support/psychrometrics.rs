@@ -0,0 +1,99 @@
+//! Moist-air states and psychrometric chart operations.
+//!
+//! Air-side models (coils, humidifiers, evaporative coolers, ventilation)
+//! all move a moist-air state around the psychrometric chart. This module
+//! gives them a shared representation and the basic processes:
+//!
+//! - [`MoistAir`]: a validated state built from any common pair of
+//!   properties (relative humidity, dew point, wet bulb, enthalpy) with the
+//!   derived properties available as methods.
+//! - **Processes**: [`sensible_heating`] and [`sensible_to_temperature`] at
+//!   constant humidity ratio, [`adiabatic_saturation`] and
+//!   [`evaporative_cooling`] at constant wet-bulb temperature, and [`mix`]
+//!   for the adiabatic mixing of two streams.
+//! - [`saturation_pressure`] of water vapor over liquid or ice.
+//!
+//! Property relations follow ASHRAE Fundamentals, treating dry air and
+//! water vapor as ideal gases. They cover −100 °C to 200 °C.
+//!
+//! # Example
+//!
+//! Heating outdoor winter air, then mixing it with return air:
+//!
+//! ```
+//! use twine_models::support::psychrometrics::{MoistAir, PsychrometricError, mix, sensible_heating};
+//! use uom::si::{
+//!     f64::{MassRate, Power, Pressure, Ratio, ThermodynamicTemperature},
+//!     mass_rate::kilogram_per_second,
+//!     power::kilowatt,
+//!     pressure::kilopascal,
+//!     ratio::percent,
+//!     thermodynamic_temperature::degree_celsius,
+//! };
+//!
+//! let p = Pressure::new::<kilopascal>(101.325);
+//! let flow = MassRate::new::<kilogram_per_second>(1.0);
+//!
+//! let outdoor = MoistAir::from_relative_humidity(
+//!     ThermodynamicTemperature::new::<degree_celsius>(0.0),
+//!     Ratio::new::<percent>(80.0),
+//!     p,
+//! )?;
+//! let heated = sensible_heating(&outdoor, Power::new::<kilowatt>(15.0), flow)?;
+//! assert!(heated.relative_humidity() < outdoor.relative_humidity());
+//!
+//! let indoor = MoistAir::from_relative_humidity(
+//!     ThermodynamicTemperature::new::<degree_celsius>(22.0),
+//!     Ratio::new::<percent>(40.0),
+//!     p,
+//! )?;
+//! let supply = mix([(&heated, flow), (&indoor, 3.0 * flow)])?;
+//! assert!(supply.temperature() > heated.temperature());
+//! assert!(supply.temperature() < indoor.temperature());
+//! # Ok::<(), PsychrometricError>(())
+//! ```
+
+mod process;
+mod saturation;
+mod state;
+
+use thiserror::Error;
+use uom::si::f64::{Ratio, ThermodynamicTemperature};
+
+pub use process::{
+    adiabatic_saturation, evaporative_cooling, mix, sensible_heating, sensible_to_temperature,
+};
+pub use saturation::saturation_pressure;
+pub use state::MoistAir;
+
+/// Errors from building or processing moist-air states.
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum PsychrometricError {
+    /// An input is non-physical, such as a negative humidity ratio.
+    #[error("invalid input: {0}")]
+    InvalidInput(&'static str),
+
+    /// A temperature lies outside the −100 °C to 200 °C correlation range.
+    #[error("temperature out of range: {0:?}")]
+    OutOfRange(ThermodynamicTemperature),
+
+    /// The air would hold more water vapor than it can at its temperature.
+    #[error("supersaturated: humidity ratio {humidity_ratio:?} exceeds {saturation:?}")]
+    Supersaturated {
+        /// Requested humidity ratio.
+        humidity_ratio: Ratio,
+
+        /// Humidity ratio at saturation.
+        saturation: Ratio,
+    },
+
+    /// A sensible process would cool the air below its dew point.
+    #[error("condensation: {temperature:?} is below the dew point {dew_point:?}")]
+    Condensation {
+        /// Temperature the process would reach.
+        temperature: ThermodynamicTemperature,
+
+        /// Dew point of the incoming air.
+        dew_point: ThermodynamicTemperature,
+    },
+}
@@ -0,0 +1,241 @@
+use uom::{
+    ConstZero,
+    si::{
+        f64::{MassRate, Power, Ratio, ThermodynamicTemperature},
+        thermodynamic_temperature::kelvin,
+    },
+};
+
+use super::{PsychrometricError, state::MoistAir};
+
+/// Heats or cools air without adding or removing moisture.
+///
+/// Positive `heat` warms the air; negative cools it. The humidity ratio is
+/// unchanged, so the temperature rises by `Q / (ṁ_da · c_p,humid)` where
+/// `ṁ_da` is the dry-air mass flow.
+///
+/// # Errors
+///
+/// Returns [`PsychrometricError::InvalidInput`] if the dry-air flow is not
+/// strictly positive, or [`PsychrometricError::Condensation`] if cooling
+/// would take the air below its dew point. Latent cooling belongs to the
+/// caller, which knows how the condensate leaves.
+pub fn sensible_heating(
+    air: &MoistAir,
+    heat: Power,
+    dry_air_flow: MassRate,
+) -> Result<MoistAir, PsychrometricError> {
+    if dry_air_flow.is_nan() || dry_air_flow <= MassRate::ZERO {
+        return Err(PsychrometricError::InvalidInput(
+            "dry air flow must be strictly positive",
+        ));
+    }
+    let temperature = air.temperature() + heat / (dry_air_flow * air.humid_specific_heat());
+    sensible_to_temperature(air, temperature)
+}
+
+/// Heats or cools air to `temperature` without changing its humidity ratio.
+///
+/// # Errors
+///
+/// Returns [`PsychrometricError::Condensation`] if `temperature` is below
+/// the dew point, or any error from [`MoistAir::new`].
+pub fn sensible_to_temperature(
+    air: &MoistAir,
+    temperature: ThermodynamicTemperature,
+) -> Result<MoistAir, PsychrometricError> {
+    if let Some(dew_point) = air.dew_point()
+        && temperature < dew_point
+    {
+        return Err(PsychrometricError::Condensation {
+            temperature,
+            dew_point,
+        });
+    }
+    MoistAir::new(temperature, air.humidity_ratio(), air.pressure())
+}
+
+/// Returns the state reached by adiabatic saturation: saturated air at the
+/// thermodynamic wet-bulb temperature.
+///
+/// # Errors
+///
+/// Returns any error from [`MoistAir::saturated`].
+pub fn adiabatic_saturation(air: &MoistAir) -> Result<MoistAir, PsychrometricError> {
+    MoistAir::saturated(air.wet_bulb(), air.pressure())
+}
+
+/// Cools air by evaporating water into it, as in a direct evaporative
+/// cooler with the given saturation effectiveness.
+///
+/// The leaving dry bulb is `T₁ − ε·(T₁ − T_wb)` and the process holds the
+/// wet bulb constant, so `ε = 1` gives [`adiabatic_saturation`]. Enthalpy
+/// rises only by that of the evaporated water.
+///
+/// # Errors
+///
+/// Returns [`PsychrometricError::InvalidInput`] if the effectiveness lies
+/// outside `[0, 1]`, or any error from [`MoistAir::from_wet_bulb`].
+pub fn evaporative_cooling(
+    air: &MoistAir,
+    effectiveness: Ratio,
+) -> Result<MoistAir, PsychrometricError> {
+    let e = effectiveness.value;
+    if !(0.0..=1.0).contains(&e) {
+        return Err(PsychrometricError::InvalidInput(
+            "effectiveness must be in [0, 1]",
+        ));
+    }
+
+    // The wet bulb is constant along the process, so it fixes the leaving
+    // humidity ratio once the dry bulb is known.
+    let wet_bulb = air.wet_bulb();
+    let dry_bulb = air.temperature().get::<kelvin>();
+    let depression = dry_bulb - wet_bulb.get::<kelvin>();
+    let temperature = ThermodynamicTemperature::new::<kelvin>(dry_bulb - e * depression);
+    MoistAir::from_wet_bulb(temperature, wet_bulb.min(temperature), air.pressure())
+}
+
+/// Mixes two moist-air streams adiabatically.
+///
+/// Each stream is paired with its dry-air mass flow. The mixed humidity
+/// ratio and enthalpy are the flow-weighted averages of the inlets.
+///
+/// # Errors
+///
+/// Returns [`PsychrometricError::InvalidInput`] if a flow is negative, both
+/// flows are zero, or the streams are at different pressures, and
+/// [`PsychrometricError::Supersaturated`] if the mixture would fog.
+pub fn mix(streams: [(&MoistAir, MassRate); 2]) -> Result<MoistAir, PsychrometricError> {
+    let [(a, flow_a), (b, flow_b)] = streams;
+    if flow_a.is_nan() || flow_b.is_nan() || flow_a < MassRate::ZERO || flow_b < MassRate::ZERO {
+        return Err(PsychrometricError::InvalidInput(
+            "dry air flows must be non-negative",
+        ));
+    }
+    let total = flow_a + flow_b;
+    if total == MassRate::ZERO {
+        return Err(PsychrometricError::InvalidInput(
+            "total dry air flow must be positive",
+        ));
+    }
+    if (a.pressure() - b.pressure()).abs().value > 1e-9 * a.pressure().value {
+        return Err(PsychrometricError::InvalidInput(
+            "streams must be at the same pressure",
+        ));
+    }
+
+    let fraction_a: Ratio = flow_a / total;
+    let fraction_b: Ratio = flow_b / total;
+    let humidity_ratio = fraction_a * a.humidity_ratio() + fraction_b * b.humidity_ratio();
+    let enthalpy = fraction_a * a.enthalpy() + fraction_b * b.enthalpy();
+    MoistAir::from_enthalpy(enthalpy, humidity_ratio, a.pressure())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        available_energy::kilojoule_per_kilogram, mass_rate::kilogram_per_second, power::kilowatt,
+        pressure::kilopascal, ratio::ratio, thermodynamic_temperature::degree_celsius,
+    };
+
+    use uom::si::f64::Pressure;
+
+    use crate::support::units::test_utils::celsius;
+
+    fn air(t: f64, rh: f64) -> MoistAir {
+        MoistAir::from_relative_humidity(
+            celsius(t),
+            Ratio::new::<ratio>(rh),
+            Pressure::new::<kilopascal>(101.325),
+        )
+        .unwrap()
+    }
+
+    fn flow(value: f64) -> MassRate {
+        MassRate::new::<kilogram_per_second>(value)
+    }
+
+    #[test]
+    fn sensible_heating_conserves_energy_and_moisture() {
+        let inlet = air(10.0, 0.6);
+        let heat = Power::new::<kilowatt>(5.0);
+        let outlet = sensible_heating(&inlet, heat, flow(0.5)).unwrap();
+
+        assert_eq!(outlet.humidity_ratio(), inlet.humidity_ratio());
+        let delta_h = (outlet.enthalpy() - inlet.enthalpy()).get::<kilojoule_per_kilogram>();
+        assert_relative_eq!(delta_h * 0.5, 5.0, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn sensible_cooling_stops_at_the_dew_point() {
+        let inlet = air(25.0, 0.5);
+        let dew_point = inlet.dew_point().unwrap();
+
+        assert!(sensible_to_temperature(&inlet, celsius(15.0)).is_ok());
+        assert!(matches!(
+            sensible_to_temperature(&inlet, celsius(10.0)),
+            Err(PsychrometricError::Condensation { dew_point: d, .. }) if d == dew_point
+        ));
+    }
+
+    #[test]
+    fn evaporative_cooling_holds_the_wet_bulb() {
+        let inlet = air(35.0, 0.2);
+        let half = evaporative_cooling(&inlet, Ratio::new::<ratio>(0.5)).unwrap();
+        let full = evaporative_cooling(&inlet, Ratio::new::<ratio>(1.0)).unwrap();
+        let saturated = adiabatic_saturation(&inlet).unwrap();
+
+        assert_relative_eq!(
+            half.wet_bulb().get::<degree_celsius>(),
+            inlet.wet_bulb().get::<degree_celsius>(),
+            epsilon = 1e-6
+        );
+        let h = inlet.enthalpy().get::<kilojoule_per_kilogram>();
+        assert_relative_eq!(
+            half.enthalpy().get::<kilojoule_per_kilogram>(),
+            h,
+            max_relative = 1e-2
+        );
+        assert_relative_eq!(
+            full.temperature().get::<degree_celsius>(),
+            saturated.temperature().get::<degree_celsius>(),
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(full.relative_humidity().value, 1.0, max_relative = 1e-6);
+        assert!(half.humidity_ratio() > inlet.humidity_ratio());
+        assert!(half.temperature() < inlet.temperature());
+    }
+
+    #[test]
+    fn mixing_weights_by_dry_air_flow() {
+        let cold = air(0.0, 0.8);
+        let warm = air(22.0, 0.4);
+        let mixed = mix([(&cold, flow(1.0)), (&warm, flow(3.0))]).unwrap();
+
+        assert_relative_eq!(
+            mixed.humidity_ratio().value,
+            0.25 * cold.humidity_ratio().value + 0.75 * warm.humidity_ratio().value,
+            max_relative = 1e-12
+        );
+        // Mixing is nearly linear in dry bulb on the chart.
+        assert_relative_eq!(
+            mixed.temperature().get::<degree_celsius>(),
+            16.5,
+            epsilon = 0.1
+        );
+    }
+
+    #[test]
+    fn mixing_near_saturated_streams_can_fog() {
+        let cold = air(-10.0, 1.0);
+        let warm = air(30.0, 1.0);
+        assert!(matches!(
+            mix([(&cold, flow(1.0)), (&warm, flow(1.0))]),
+            Err(PsychrometricError::Supersaturated { .. })
+        ));
+    }
+}
@@ -0,0 +1,91 @@
+use uom::si::{
+    f64::{Pressure, ThermodynamicTemperature},
+    pressure::pascal,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::support::numerics::solve::{Config, brent};
+
+/// Lowest temperature covered by the saturation correlations, K.
+pub(super) const MIN_KELVIN: f64 = 173.15;
+
+/// Highest temperature covered by the saturation correlations, K.
+pub(super) const MAX_KELVIN: f64 = 473.15;
+
+/// Returns the saturation pressure of water vapor.
+///
+/// Uses the Hyland–Wexler correlations adopted by ASHRAE: over ice below
+/// 0 °C and over liquid water above it. They cover −100 °C to 200 °C and
+/// agree with the IAPWS formulations to better than 0.1 %.
+#[must_use]
+pub fn saturation_pressure(temperature: ThermodynamicTemperature) -> Pressure {
+    Pressure::new::<pascal>(ln_saturation_pressure_pa(temperature.get::<kelvin>()).exp())
+}
+
+/// Natural log of the saturation pressure in Pa at `t` kelvin.
+fn ln_saturation_pressure_pa(t: f64) -> f64 {
+    if t < 273.15 {
+        -5.674_535_9e3 / t + 6.392_524_7 - 9.677_843e-3 * t
+            + 6.221_570_1e-7 * t * t
+            + 2.074_782_5e-9 * t.powi(3)
+            - 9.484_024e-13 * t.powi(4)
+            + 4.163_501_9 * t.ln()
+    } else {
+        -5.800_220_6e3 / t + 1.391_499_3 - 4.864_023_9e-2 * t + 4.176_476_8e-5 * t * t
+            - 1.445_209_3e-8 * t.powi(3)
+            + 6.545_967_3 * t.ln()
+    }
+}
+
+/// Returns the temperature at which water vapor at `vapor_pressure`
+/// saturates, or `None` if it lies below the range of the correlations.
+pub(super) fn saturation_temperature(vapor_pressure: Pressure) -> Option<ThermodynamicTemperature> {
+    let target = vapor_pressure.get::<pascal>().ln();
+    if target.is_nan() || target < ln_saturation_pressure_pa(MIN_KELVIN) {
+        return None;
+    }
+
+    let solution = brent(
+        |t| Ok::<_, core::convert::Infallible>(ln_saturation_pressure_pa(t) - target),
+        [MIN_KELVIN, MAX_KELVIN],
+        &Config::default(),
+    )
+    .ok()?;
+    Some(ThermodynamicTemperature::new::<kelvin>(solution.x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{pressure::kilopascal, thermodynamic_temperature::degree_celsius};
+
+    use crate::support::units::test_utils::celsius;
+
+    #[test]
+    fn saturation_pressure_matches_ashrae_tables() {
+        // ASHRAE Fundamentals Table 3: −20, 0.01, 25, and 100 °C.
+        for (t, p_kpa) in [
+            (-20.0, 0.103_26),
+            (0.01, 0.611_65),
+            (25.0, 3.169_9),
+            (100.0, 101.418),
+        ] {
+            assert_relative_eq!(
+                saturation_pressure(celsius(t)).get::<kilopascal>(),
+                p_kpa,
+                max_relative = 1e-3
+            );
+        }
+    }
+
+    #[test]
+    fn saturation_temperature_inverts_saturation_pressure() {
+        for t in [-40.0, -5.0, 10.0, 60.0] {
+            let back = saturation_temperature(saturation_pressure(celsius(t))).unwrap();
+            assert_relative_eq!(back.get::<degree_celsius>(), t, epsilon = 1e-9);
+        }
+        assert!(saturation_temperature(Pressure::new::<pascal>(0.0)).is_none());
+    }
+}
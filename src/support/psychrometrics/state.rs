@@ -0,0 +1,446 @@
+use uom::si::{
+    available_energy::kilojoule_per_kilogram,
+    f64::{Pressure, Ratio, SpecificHeatCapacity, SpecificVolume, ThermodynamicTemperature},
+    pressure::kilopascal,
+    ratio::ratio,
+    specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+    specific_volume::cubic_meter_per_kilogram,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+use crate::support::{
+    numerics::solve::{Config, brent},
+    units::SpecificEnthalpy,
+};
+
+use super::{
+    PsychrometricError,
+    saturation::{MAX_KELVIN, MIN_KELVIN, saturation_pressure, saturation_temperature},
+};
+
+/// Ratio of the molar masses of water and dry air.
+const MOLAR_MASS_RATIO: f64 = 0.621_945;
+
+/// Relative tolerance on the saturation humidity ratio, so states computed
+/// at saturation are not rejected for round-off.
+const SATURATION_TOLERANCE: f64 = 1e-9;
+
+/// A moist-air state: dry air carrying water vapor at a total pressure.
+///
+/// Quantities follow the ASHRAE Fundamentals conventions. The humidity ratio
+/// is the mass of water vapor per mass of dry air, and specific enthalpy and
+/// volume are per mass of dry air. States are never supersaturated; processes
+/// that would produce fog or condensate report it instead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoistAir {
+    temperature: ThermodynamicTemperature,
+    humidity_ratio: Ratio,
+    pressure: Pressure,
+}
+
+impl MoistAir {
+    /// Creates a state from its dry-bulb temperature, humidity ratio, and
+    /// total pressure.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PsychrometricError::InvalidInput`] if the humidity ratio is
+    /// negative or the pressure is not strictly positive,
+    /// [`PsychrometricError::OutOfRange`] if the temperature lies outside
+    /// −100 °C to 200 °C, or [`PsychrometricError::Supersaturated`] if the
+    /// air holds more water than it can at that temperature.
+    pub fn new(
+        temperature: ThermodynamicTemperature,
+        humidity_ratio: Ratio,
+        pressure: Pressure,
+    ) -> Result<Self, PsychrometricError> {
+        check_conditions(temperature, pressure)?;
+        if humidity_ratio.is_nan() || humidity_ratio.value < 0.0 {
+            return Err(PsychrometricError::InvalidInput(
+                "humidity ratio must be non-negative",
+            ));
+        }
+
+        let saturation = saturation_humidity_ratio(temperature, pressure)?;
+        if humidity_ratio.value > saturation.value * (1.0 + SATURATION_TOLERANCE) {
+            return Err(PsychrometricError::Supersaturated {
+                humidity_ratio,
+                saturation,
+            });
+        }
+
+        Ok(Self {
+            temperature,
+            humidity_ratio: humidity_ratio.min(saturation),
+            pressure,
+        })
+    }
+
+    /// Creates a state from its dry-bulb temperature and relative humidity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PsychrometricError::InvalidInput`] if the relative humidity
+    /// lies outside `[0, 1]`, or any error from [`MoistAir::new`].
+    pub fn from_relative_humidity(
+        temperature: ThermodynamicTemperature,
+        relative_humidity: Ratio,
+        pressure: Pressure,
+    ) -> Result<Self, PsychrometricError> {
+        let rh = relative_humidity.get::<ratio>();
+        if !(0.0..=1.0).contains(&rh) {
+            return Err(PsychrometricError::InvalidInput(
+                "relative humidity must be in [0, 1]",
+            ));
+        }
+        check_conditions(temperature, pressure)?;
+
+        let vapor_pressure = rh * saturation_pressure(temperature);
+        Self::new(
+            temperature,
+            humidity_ratio_from_vapor_pressure(vapor_pressure, pressure)?,
+            pressure,
+        )
+    }
+
+    /// Creates saturated air at the given temperature.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`MoistAir::new`].
+    pub fn saturated(
+        temperature: ThermodynamicTemperature,
+        pressure: Pressure,
+    ) -> Result<Self, PsychrometricError> {
+        Self::from_relative_humidity(temperature, Ratio::new::<ratio>(1.0), pressure)
+    }
+
+    /// Creates a state from its dry-bulb temperature and dew point.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PsychrometricError::Supersaturated`] if the dew point is
+    /// above the dry-bulb temperature, or any error from [`MoistAir::new`].
+    pub fn from_dew_point(
+        temperature: ThermodynamicTemperature,
+        dew_point: ThermodynamicTemperature,
+        pressure: Pressure,
+    ) -> Result<Self, PsychrometricError> {
+        check_conditions(dew_point, pressure)?;
+        Self::new(
+            temperature,
+            saturation_humidity_ratio(dew_point, pressure)?,
+            pressure,
+        )
+    }
+
+    /// Creates a state from its dry-bulb and thermodynamic wet-bulb
+    /// temperatures.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PsychrometricError::InvalidInput`] if the wet bulb is above
+    /// the dry bulb or so far below it that the air would hold negative
+    /// moisture, or any error from [`MoistAir::new`].
+    pub fn from_wet_bulb(
+        temperature: ThermodynamicTemperature,
+        wet_bulb: ThermodynamicTemperature,
+        pressure: Pressure,
+    ) -> Result<Self, PsychrometricError> {
+        check_conditions(temperature, pressure)?;
+        check_conditions(wet_bulb, pressure)?;
+        if wet_bulb > temperature {
+            return Err(PsychrometricError::InvalidInput(
+                "wet bulb must not exceed dry bulb",
+            ));
+        }
+
+        let humidity_ratio = wet_bulb_humidity_ratio(temperature, wet_bulb, pressure)?;
+        if humidity_ratio < 0.0 {
+            return Err(PsychrometricError::InvalidInput(
+                "wet bulb is too low for the dry bulb",
+            ));
+        }
+        Self::new(temperature, Ratio::new::<ratio>(humidity_ratio), pressure)
+    }
+
+    /// Creates a state from its specific enthalpy and humidity ratio.
+    ///
+    /// Inverts `h = 1.006·t + W·(2501 + 1.86·t)` kJ/kg for the dry-bulb
+    /// temperature `t` in °C.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`MoistAir::new`].
+    pub fn from_enthalpy(
+        enthalpy: SpecificEnthalpy,
+        humidity_ratio: Ratio,
+        pressure: Pressure,
+    ) -> Result<Self, PsychrometricError> {
+        let h = enthalpy.get::<kilojoule_per_kilogram>();
+        let w = humidity_ratio.get::<ratio>();
+        let t = (h - 2501.0 * w) / (1.006 + 1.86 * w);
+        Self::new(
+            ThermodynamicTemperature::new::<degree_celsius>(t),
+            humidity_ratio,
+            pressure,
+        )
+    }
+
+    /// Returns the dry-bulb temperature.
+    #[must_use]
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    /// Returns the humidity ratio, in mass of vapor per mass of dry air.
+    #[must_use]
+    pub fn humidity_ratio(&self) -> Ratio {
+        self.humidity_ratio
+    }
+
+    /// Returns the total pressure.
+    #[must_use]
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+
+    /// Returns the partial pressure of the water vapor.
+    #[must_use]
+    pub fn vapor_pressure(&self) -> Pressure {
+        let w = self.humidity_ratio.get::<ratio>();
+        self.pressure * w / (MOLAR_MASS_RATIO + w)
+    }
+
+    /// Returns the relative humidity, the ratio of the vapor pressure to
+    /// its saturation value at the dry-bulb temperature.
+    #[must_use]
+    pub fn relative_humidity(&self) -> Ratio {
+        self.vapor_pressure() / saturation_pressure(self.temperature)
+    }
+
+    /// Returns the dew point, the temperature at which the air saturates
+    /// when cooled at constant humidity ratio.
+    ///
+    /// Below 0 °C this is the frost point. Returns `None` if the dew point
+    /// lies below −100 °C, including for perfectly dry air.
+    #[must_use]
+    pub fn dew_point(&self) -> Option<ThermodynamicTemperature> {
+        saturation_temperature(self.vapor_pressure())
+            .map(|dew_point| dew_point.min(self.temperature))
+    }
+
+    /// Returns the thermodynamic wet-bulb temperature, the temperature at
+    /// which adiabatic evaporation would saturate the air.
+    #[must_use]
+    pub fn wet_bulb(&self) -> ThermodynamicTemperature {
+        let target = self.humidity_ratio.value;
+        let residual = |t: f64| {
+            wet_bulb_humidity_ratio(
+                self.temperature,
+                ThermodynamicTemperature::new::<kelvin>(t),
+                self.pressure,
+            )
+            .map(|w| w - target)
+        };
+
+        // The residual is non-negative at the dry bulb and strongly
+        // negative at the bottom of the correlation range.
+        let upper = self.temperature.get::<kelvin>();
+        match brent(residual, [MIN_KELVIN, upper], &Config::default()) {
+            Ok(solution) => ThermodynamicTemperature::new::<kelvin>(solution.x),
+            Err(_) => self.temperature,
+        }
+    }
+
+    /// Returns the specific enthalpy per mass of dry air,
+    /// `h = 1.006·t + W·(2501 + 1.86·t)` kJ/kg with `t` in °C.
+    ///
+    /// The reference is dry air and saturated liquid water at 0 °C.
+    #[must_use]
+    pub fn enthalpy(&self) -> SpecificEnthalpy {
+        let t = self.temperature.get::<degree_celsius>();
+        let w = self.humidity_ratio.get::<ratio>();
+        SpecificEnthalpy::new::<kilojoule_per_kilogram>(1.006 * t + w * (2501.0 + 1.86 * t))
+    }
+
+    /// Returns the humid specific heat `1.006 + 1.86·W` kJ/kg·K, per mass of
+    /// dry air.
+    #[must_use]
+    pub fn humid_specific_heat(&self) -> SpecificHeatCapacity {
+        SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(
+            1.006 + 1.86 * self.humidity_ratio.get::<ratio>(),
+        )
+    }
+
+    /// Returns the specific volume per mass of dry air.
+    #[must_use]
+    pub fn specific_volume(&self) -> SpecificVolume {
+        let t = self.temperature.get::<kelvin>();
+        let w = self.humidity_ratio.get::<ratio>();
+        SpecificVolume::new::<cubic_meter_per_kilogram>(
+            0.287_042 * t * (1.0 + 1.607_858 * w) / self.pressure.get::<kilopascal>(),
+        )
+    }
+
+    /// Returns whether moisture condenses on a surface at
+    /// `surface_temperature`, that is, whether the surface is below the dew
+    /// point.
+    #[must_use]
+    pub fn condenses_on(&self, surface_temperature: ThermodynamicTemperature) -> bool {
+        self.dew_point()
+            .is_some_and(|dew_point| surface_temperature < dew_point)
+    }
+}
+
+/// Checks that a temperature and pressure are within the correlations.
+pub(super) fn check_conditions(
+    temperature: ThermodynamicTemperature,
+    pressure: Pressure,
+) -> Result<(), PsychrometricError> {
+    let t = temperature.get::<kelvin>();
+    if t.is_nan() || !(MIN_KELVIN..=MAX_KELVIN).contains(&t) {
+        return Err(PsychrometricError::OutOfRange(temperature));
+    }
+    if pressure.is_nan() || pressure.value <= 0.0 {
+        return Err(PsychrometricError::InvalidInput(
+            "pressure must be strictly positive",
+        ));
+    }
+    Ok(())
+}
+
+/// Humidity ratio of air saturated at `temperature`.
+fn saturation_humidity_ratio(
+    temperature: ThermodynamicTemperature,
+    pressure: Pressure,
+) -> Result<Ratio, PsychrometricError> {
+    humidity_ratio_from_vapor_pressure(saturation_pressure(temperature), pressure)
+}
+
+/// Humidity ratio `W = 0.621945·p_w / (p − p_w)`.
+fn humidity_ratio_from_vapor_pressure(
+    vapor_pressure: Pressure,
+    pressure: Pressure,
+) -> Result<Ratio, PsychrometricError> {
+    if vapor_pressure >= pressure {
+        return Err(PsychrometricError::InvalidInput(
+            "vapor pressure must be below the total pressure",
+        ));
+    }
+    Ok(MOLAR_MASS_RATIO * vapor_pressure / (pressure - vapor_pressure))
+}
+
+/// Humidity ratio of air with the given dry and wet bulbs (ASHRAE
+/// Fundamentals, equations 33 and 35).
+fn wet_bulb_humidity_ratio(
+    temperature: ThermodynamicTemperature,
+    wet_bulb: ThermodynamicTemperature,
+    pressure: Pressure,
+) -> Result<f64, PsychrometricError> {
+    let t = temperature.get::<degree_celsius>();
+    let t_wb = wet_bulb.get::<degree_celsius>();
+    let w_s = saturation_humidity_ratio(wet_bulb, pressure)?.get::<ratio>();
+
+    Ok(if t_wb >= 0.0 {
+        ((2501.0 - 2.326 * t_wb) * w_s - 1.006 * (t - t_wb)) / (2501.0 + 1.86 * t - 4.186 * t_wb)
+    } else {
+        ((2830.0 - 0.24 * t_wb) * w_s - 1.006 * (t - t_wb)) / (2830.0 + 1.86 * t - 2.1 * t_wb)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::pressure::pascal;
+
+    use crate::support::units::test_utils::celsius;
+
+    fn atm() -> Pressure {
+        Pressure::new::<pascal>(101_325.0)
+    }
+
+    #[test]
+    fn ashrae_example_state() {
+        // ASHRAE Fundamentals Example 2: 40 °C dry bulb, 20 °C wet bulb. The
+        // handbook's 0.0065 includes the enhancement factor omitted here.
+        let air = MoistAir::from_wet_bulb(celsius(40.0), celsius(20.0), atm()).unwrap();
+
+        assert_relative_eq!(air.humidity_ratio().value, 0.006_5, max_relative = 2e-2);
+        assert_relative_eq!(
+            air.enthalpy().get::<kilojoule_per_kilogram>(),
+            56.9,
+            max_relative = 1e-2
+        );
+        assert_relative_eq!(
+            air.dew_point().unwrap().get::<degree_celsius>(),
+            7.7,
+            epsilon = 0.3
+        );
+        assert_relative_eq!(air.relative_humidity().value, 0.14, epsilon = 0.01);
+        assert_relative_eq!(
+            air.specific_volume().get::<cubic_meter_per_kilogram>(),
+            0.896,
+            max_relative = 2e-3
+        );
+    }
+
+    #[test]
+    fn constructors_agree() {
+        let air = MoistAir::from_relative_humidity(celsius(25.0), Ratio::new::<ratio>(0.5), atm())
+            .unwrap();
+
+        let by_dew_point =
+            MoistAir::from_dew_point(celsius(25.0), air.dew_point().unwrap(), atm()).unwrap();
+        let by_wet_bulb = MoistAir::from_wet_bulb(celsius(25.0), air.wet_bulb(), atm()).unwrap();
+        let by_enthalpy =
+            MoistAir::from_enthalpy(air.enthalpy(), air.humidity_ratio(), atm()).unwrap();
+
+        for other in [by_dew_point, by_wet_bulb, by_enthalpy] {
+            assert_relative_eq!(
+                other.humidity_ratio().value,
+                air.humidity_ratio().value,
+                max_relative = 1e-9
+            );
+            assert_relative_eq!(
+                other.temperature().get::<kelvin>(),
+                air.temperature().get::<kelvin>(),
+                max_relative = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn saturated_air_has_equal_bulbs() {
+        let air = MoistAir::saturated(celsius(15.0), atm()).unwrap();
+        assert_relative_eq!(air.relative_humidity().value, 1.0, max_relative = 1e-12);
+        assert_relative_eq!(air.wet_bulb().get::<degree_celsius>(), 15.0, epsilon = 1e-9);
+        assert!(!air.condenses_on(celsius(15.0)));
+        assert!(air.condenses_on(celsius(14.0)));
+    }
+
+    #[test]
+    fn rejects_supersaturated_and_out_of_range_states() {
+        assert!(matches!(
+            MoistAir::new(celsius(20.0), Ratio::new::<ratio>(0.02), atm()),
+            Err(PsychrometricError::Supersaturated { .. })
+        ));
+        assert!(matches!(
+            MoistAir::from_dew_point(celsius(20.0), celsius(25.0), atm()),
+            Err(PsychrometricError::Supersaturated { .. })
+        ));
+        assert!(matches!(
+            MoistAir::new(celsius(250.0), Ratio::new::<ratio>(0.0), atm()),
+            Err(PsychrometricError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn dry_air_has_no_dew_point() {
+        let air = MoistAir::new(celsius(20.0), Ratio::new::<ratio>(0.0), atm()).unwrap();
+        assert!(air.dew_point().is_none());
+        assert!(!air.condenses_on(celsius(-90.0)));
+    }
+}
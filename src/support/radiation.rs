@@ -0,0 +1,67 @@
+//! Thermal radiation between surfaces.
+//!
+//! This module provides the pieces for long-wave radiation terms in
+//! component models:
+//!
+//! - **Surface properties**: [`Emissivity`] and [`ViewFactor`], both
+//!   constrained to `[0, 1]`, and the black-body [`emissive_power`].
+//! - **Gray-body exchange**: [`gray_exchange`] between two diffuse gray
+//!   surfaces, [`exchange_with_surroundings`] for a surface enclosed by much
+//!   larger surroundings, and [`radiative_coefficient`] for the linearized
+//!   coefficient `h_r` that sits alongside a convection coefficient.
+//! - **View factors** ([`view_factor`]): closed-form results for common
+//!   geometries and the reciprocity relation.
+//! - **Sky temperature** ([`sky`]): effective sky temperatures for exchange
+//!   between outdoor surfaces and the night sky.
+//!
+//! Temperatures are absolute and all surfaces are diffuse and gray, so the
+//! absorptivity equals the emissivity.
+//!
+//! # Example
+//!
+//! A flat-plate collector absorber radiating to its glazing, which in turn
+//! radiates to the sky:
+//!
+//! ```
+//! use twine_models::support::{
+//!     constraint::ConstraintResult,
+//!     radiation::{Emissivity, GraySurface, ViewFactor, gray_exchange, sky},
+//! };
+//! use uom::si::{
+//!     area::square_meter,
+//!     f64::{Area, ThermodynamicTemperature},
+//!     power::watt,
+//!     thermodynamic_temperature::degree_celsius,
+//! };
+//!
+//! fn main() -> ConstraintResult<()> {
+//!     let area = Area::new::<square_meter>(2.0);
+//!     let absorber = GraySurface::new(area, Emissivity::new(0.10)?)?;
+//!     let glazing = GraySurface::new(area, Emissivity::new(0.88)?)?;
+//!
+//!     // Closely spaced parallel plates see only each other.
+//!     let internal = gray_exchange(
+//!         [absorber, glazing],
+//!         ViewFactor::new(1.0)?,
+//!         [
+//!             ThermodynamicTemperature::new::<degree_celsius>(80.0),
+//!             ThermodynamicTemperature::new::<degree_celsius>(35.0),
+//!         ],
+//!     );
+//!     assert!(internal.get::<watt>() > 0.0);
+//!
+//!     // The glazing sees a sky well below the air temperature.
+//!     let t_sky = sky::swinbank_temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0));
+//!     assert!(t_sky < ThermodynamicTemperature::new::<degree_celsius>(10.0));
+//!     Ok(())
+//! }
+//! ```
+
+pub mod sky;
+pub mod view_factor;
+
+mod exchange;
+mod surface;
+
+pub use exchange::{GraySurface, exchange_with_surroundings, gray_exchange, radiative_coefficient};
+pub use surface::{Emissivity, STEFAN_BOLTZMANN, ViewFactor, emissive_power};
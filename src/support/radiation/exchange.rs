@@ -0,0 +1,199 @@
+use uom::si::{
+    f64::{Area, HeatTransfer, Power, ThermodynamicTemperature},
+    heat_transfer::watt_per_square_meter_kelvin,
+    power::watt,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::support::constraint::{Constrained, ConstraintResult, StrictlyPositive};
+
+use super::{Emissivity, STEFAN_BOLTZMANN, ViewFactor};
+
+/// A diffuse gray surface taking part in radiative exchange.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GraySurface {
+    area: Constrained<Area, StrictlyPositive>,
+    emissivity: Emissivity,
+}
+
+impl GraySurface {
+    /// Creates a surface from its area and emissivity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the area is not strictly positive.
+    pub fn new(area: Area, emissivity: Emissivity) -> ConstraintResult<Self> {
+        Ok(Self {
+            area: StrictlyPositive::new(area)?,
+            emissivity,
+        })
+    }
+
+    /// Returns the surface area.
+    #[must_use]
+    pub fn area(&self) -> Area {
+        self.area.into_inner()
+    }
+
+    /// Returns the surface emissivity.
+    #[must_use]
+    pub fn emissivity(&self) -> Emissivity {
+        self.emissivity
+    }
+}
+
+/// Returns the net radiation from the first surface to the second.
+///
+/// The two surfaces exchange through the series network of surface and
+/// space resistances,
+///
+/// ```text
+/// Q̇₁₂ = σ·(T₁⁴ − T₂⁴) / [ (1 − ε₁)/(ε₁·A₁) + 1/(A₁·F₁₂) + (1 − ε₂)/(ε₂·A₂) ]
+/// ```
+///
+/// where `view_factor` is `F₁₂`, seen from the first surface. The result is
+/// exact for a two-surface enclosure and treats any radiation that misses
+/// the second surface as lost otherwise. A black surface has no surface
+/// resistance, and a surface with zero emissivity or a zero view factor
+/// exchanges nothing.
+#[must_use]
+pub fn gray_exchange(
+    surfaces: [GraySurface; 2],
+    view_factor: ViewFactor,
+    temperatures: [ThermodynamicTemperature; 2],
+) -> Power {
+    let surface_resistance = |surface: &GraySurface| {
+        let eps = surface.emissivity.value;
+        (1.0 - eps) / (eps * surface.area().value)
+    };
+    let [first, second] = &surfaces;
+    let space_resistance = 1.0 / (first.area().value * view_factor.value);
+    let resistance = surface_resistance(first) + space_resistance + surface_resistance(second);
+
+    let [t1, t2] = temperatures.map(|t| t.get::<kelvin>());
+    Power::new::<watt>(STEFAN_BOLTZMANN * (t1.powi(4) - t2.powi(4)) / resistance)
+}
+
+/// Returns the net radiation from a surface to surroundings that enclose it.
+///
+/// When the surroundings are much larger than the surface, their surface
+/// resistance vanishes and the exchange reduces to
+/// `Q̇ = ε·σ·A·(T⁴ − T_surr⁴)`.
+#[must_use]
+pub fn exchange_with_surroundings(
+    surface: GraySurface,
+    temperature: ThermodynamicTemperature,
+    surroundings: ThermodynamicTemperature,
+) -> Power {
+    let t = temperature.get::<kelvin>();
+    let t_surr = surroundings.get::<kelvin>();
+    Power::new::<watt>(
+        surface.emissivity.value
+            * STEFAN_BOLTZMANN
+            * surface.area().value
+            * (t.powi(4) - t_surr.powi(4)),
+    )
+}
+
+/// Returns the linearized radiative heat transfer coefficient.
+///
+/// The coefficient `h_r = ε·σ·(T₁ + T₂)·(T₁² + T₂²)` reproduces the exchange
+/// exactly as `q = h_r·(T₁ − T₂)`, so it can be added to a convection
+/// coefficient acting across the same temperature difference. For a surface
+/// in large surroundings `emissivity` is the surface emissivity; for other
+/// pairs pass the effective emissivity of the exchange.
+#[must_use]
+pub fn radiative_coefficient(
+    emissivity: Emissivity,
+    temperatures: [ThermodynamicTemperature; 2],
+) -> HeatTransfer {
+    let [t1, t2] = temperatures.map(|t| t.get::<kelvin>());
+    HeatTransfer::new::<watt_per_square_meter_kelvin>(
+        emissivity.value * STEFAN_BOLTZMANN * (t1 + t2) * (t1 * t1 + t2 * t2),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{area::square_meter, thermodynamic_temperature::degree_celsius};
+
+    fn surface(area: f64, emissivity: f64) -> GraySurface {
+        GraySurface::new(
+            Area::new::<square_meter>(area),
+            Emissivity::new(emissivity).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn kelvins(t1: f64, t2: f64) -> [ThermodynamicTemperature; 2] {
+        [
+            ThermodynamicTemperature::new::<kelvin>(t1),
+            ThermodynamicTemperature::new::<kelvin>(t2),
+        ]
+    }
+
+    #[test]
+    fn infinite_parallel_plates() {
+        // q = σ·(T₁⁴ − T₂⁴) / (1/ε₁ + 1/ε₂ − 1) per unit area.
+        let q = gray_exchange(
+            [surface(1.0, 0.8), surface(1.0, 0.5)],
+            ViewFactor::new(1.0).unwrap(),
+            kelvins(400.0, 300.0),
+        );
+        let expected = STEFAN_BOLTZMANN * (400.0_f64.powi(4) - 300.0_f64.powi(4))
+            / (1.0 / 0.8 + 1.0 / 0.5 - 1.0);
+        assert_relative_eq!(q.get::<watt>(), expected, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn small_body_matches_very_large_enclosure() {
+        let small = surface(0.1, 0.6);
+        let t = kelvins(350.0, 290.0);
+
+        let enclosed = gray_exchange([small, surface(1e9, 0.3)], ViewFactor::new(1.0).unwrap(), t);
+        let surroundings = exchange_with_surroundings(small, t[0], t[1]);
+        assert_relative_eq!(
+            enclosed.get::<watt>(),
+            surroundings.get::<watt>(),
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn nonparticipating_surfaces_exchange_nothing() {
+        let t = kelvins(400.0, 300.0);
+        let q = gray_exchange(
+            [surface(1.0, 0.0), surface(1.0, 0.9)],
+            ViewFactor::new(1.0).unwrap(),
+            t,
+        );
+        assert_relative_eq!(q.get::<watt>(), 0.0);
+
+        let q = gray_exchange(
+            [surface(1.0, 0.9), surface(1.0, 0.9)],
+            ViewFactor::new(0.0).unwrap(),
+            t,
+        );
+        assert_relative_eq!(q.get::<watt>(), 0.0);
+    }
+
+    #[test]
+    fn linearized_coefficient_reproduces_exchange() {
+        let pipe = surface(2.0, 0.9);
+        let t = [
+            ThermodynamicTemperature::new::<degree_celsius>(60.0),
+            ThermodynamicTemperature::new::<degree_celsius>(-5.0),
+        ];
+
+        let h_r = radiative_coefficient(pipe.emissivity(), t);
+        let linear = h_r.get::<watt_per_square_meter_kelvin>() * 2.0 * 65.0;
+        assert_relative_eq!(
+            exchange_with_surroundings(pipe, t[0], t[1]).get::<watt>(),
+            linear,
+            max_relative = 1e-12
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! Effective sky temperature for long-wave exchange with the atmosphere.
+//!
+//! The sky radiates like a black body at an effective temperature below the
+//! air temperature, because the atmosphere is transparent across part of the
+//! long-wave spectrum. An outdoor surface then exchanges with the sky through
+//! [`exchange_with_surroundings`](super::exchange_with_surroundings) at that
+//! temperature, weighted by its [view factor](super::view_factor) to the sky.
+
+use uom::si::{
+    f64::ThermodynamicTemperature,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+use super::Emissivity;
+
+/// Returns the clear-sky emissivity from the dew point, after Berdahl and
+/// Martin (1984).
+///
+/// `ε_sky = 0.711 + 0.56·(T_dp/100) + 0.73·(T_dp/100)²` with the dew point in
+/// °C, fitted over dew points from −20 °C to 30 °C. Humid air makes the sky
+/// look warmer. The result is capped at one.
+///
+/// # Panics
+///
+/// Panics if `dew_point` is NaN.
+#[must_use]
+pub fn clear_sky_emissivity(dew_point: ThermodynamicTemperature) -> Emissivity {
+    let t = dew_point.get::<degree_celsius>() / 100.0;
+    let emissivity = 0.711 + 0.56 * t + 0.73 * t * t;
+    Emissivity::new(emissivity.clamp(0.0, 1.0)).expect("clamped value is in the unit interval")
+}
+
+/// Returns the effective sky temperature `ε_sky^¼·T_air` for a given sky
+/// emissivity.
+#[must_use]
+pub fn temperature(
+    air_temperature: ThermodynamicTemperature,
+    sky_emissivity: Emissivity,
+) -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<kelvin>(
+        sky_emissivity.value.powf(0.25) * air_temperature.get::<kelvin>(),
+    )
+}
+
+/// Returns the clear-sky temperature from the air temperature alone, after
+/// Swinbank (1963): `T_sky = 0.0552·T_air^1.5` in kelvin.
+///
+/// Use this when no humidity data is available; [`clear_sky_emissivity`]
+/// is more accurate when the dew point is known.
+#[must_use]
+pub fn swinbank_temperature(air_temperature: ThermodynamicTemperature) -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<kelvin>(0.0552 * air_temperature.get::<kelvin>().powf(1.5))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use crate::support::units::test_utils::celsius;
+
+    #[test]
+    fn humid_sky_is_warmer_than_dry_sky() {
+        let air = celsius(25.0);
+        let dry = temperature(air, clear_sky_emissivity(celsius(0.0)));
+        let humid = temperature(air, clear_sky_emissivity(celsius(20.0)));
+
+        assert_relative_eq!(dry.get::<kelvin>(), 0.711_f64.powf(0.25) * 298.15);
+        assert!(humid > dry);
+        assert!(humid < air);
+    }
+
+    #[test]
+    fn swinbank_sky_is_about_twenty_kelvin_below_mild_air() {
+        let air = celsius(20.0);
+        let depression = air.get::<kelvin>() - swinbank_temperature(air).get::<kelvin>();
+        assert!(
+            (15.0..25.0).contains(&depression),
+            "depression = {depression} K"
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use core::ops::Deref;
+
+use uom::si::{
+    f64::{HeatFluxDensity, Ratio, ThermodynamicTemperature},
+    heat_flux_density::watt_per_square_meter,
+    ratio::ratio,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::support::constraint::{Constrained, ConstraintResult, UnitInterval};
+
+/// Stefan–Boltzmann constant `σ`, W/(m²·K⁴), from the 2018 CODATA values.
+pub const STEFAN_BOLTZMANN: f64 = 5.670_374_419e-8;
+
+/// The hemispherical emissivity of a diffuse gray surface.
+///
+/// The emissivity must be in the interval [0, 1].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Emissivity(Constrained<Ratio, UnitInterval>);
+
+impl Emissivity {
+    /// Create an [`Emissivity`] from a scalar value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the value lies outside the interval [0, 1].
+    pub fn new(value: f64) -> ConstraintResult<Self> {
+        Self::from_quantity(Ratio::new::<ratio>(value))
+    }
+
+    /// Create an [`Emissivity`] from a ratio quantity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the quantity lies outside the interval [0, 1].
+    pub fn from_quantity(quantity: Ratio) -> ConstraintResult<Self> {
+        Ok(Self(UnitInterval::new(quantity)?))
+    }
+}
+
+impl Deref for Emissivity {
+    type Target = Ratio;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+/// The fraction of radiation leaving one surface that strikes another.
+///
+/// The view factor must be in the interval [0, 1].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ViewFactor(Constrained<Ratio, UnitInterval>);
+
+impl ViewFactor {
+    /// Create a [`ViewFactor`] from a scalar value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the value lies outside the interval [0, 1].
+    pub fn new(value: f64) -> ConstraintResult<Self> {
+        Self::from_quantity(Ratio::new::<ratio>(value))
+    }
+
+    /// Create a [`ViewFactor`] from a ratio quantity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the quantity lies outside the interval [0, 1].
+    pub fn from_quantity(quantity: Ratio) -> ConstraintResult<Self> {
+        Ok(Self(UnitInterval::new(quantity)?))
+    }
+
+    /// Creates a view factor from a closed-form result, clamping round-off
+    /// just outside [0, 1].
+    pub(super) fn clamped(value: f64) -> Self {
+        Self::new(value.clamp(0.0, 1.0)).expect("clamped value is in the unit interval")
+    }
+}
+
+impl Deref for ViewFactor {
+    type Target = Ratio;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+/// Returns the emissive power `ε·σ·T⁴` of a gray surface.
+#[must_use]
+pub fn emissive_power(
+    emissivity: Emissivity,
+    temperature: ThermodynamicTemperature,
+) -> HeatFluxDensity {
+    let t = temperature.get::<kelvin>();
+    HeatFluxDensity::new::<watt_per_square_meter>(emissivity.value * STEFAN_BOLTZMANN * t.powi(4))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn black_body_at_room_temperature() {
+        let power = emissive_power(
+            Emissivity::new(1.0).unwrap(),
+            ThermodynamicTemperature::new::<kelvin>(300.0),
+        );
+        assert_relative_eq!(
+            power.get::<watt_per_square_meter>(),
+            459.3,
+            max_relative = 1e-4
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn properties_outside_unit_interval_are_rejected() {
+        assert!(Emissivity::new(1.1).is_err());
+        assert!(Emissivity::new(-0.1).is_err());
+        assert!(ViewFactor::new(f64::NAN).is_err());
+        assert_eq!(ViewFactor::clamped(1.0 + 1e-15).value, 1.0);
+    }
+}
@@ -0,0 +1,222 @@
+//! Closed-form view factors for common geometries.
+//!
+//! Each function returns the view factor from the first named surface to the
+//! second. Use [`reciprocal`] for the reverse direction, since
+//! `A₁·F₁₂ = A₂·F₂₁`.
+
+use core::f64::consts::PI;
+
+use uom::si::{
+    angle::radian,
+    f64::{Angle, Area, Length},
+};
+
+use crate::support::constraint::{Constraint, ConstraintResult, StrictlyPositive};
+
+use super::ViewFactor;
+
+/// Returns `F₂₁` from `F₁₂` and the two surface areas.
+///
+/// # Errors
+///
+/// Returns `Err` if an area is not strictly positive or the result exceeds
+/// one, which means the areas and view factor are inconsistent.
+pub fn reciprocal(
+    view_factor: ViewFactor,
+    from_area: Area,
+    to_area: Area,
+) -> ConstraintResult<ViewFactor> {
+    StrictlyPositive::check(&from_area)?;
+    StrictlyPositive::check(&to_area)?;
+    ViewFactor::from_quantity(*view_factor * from_area / to_area)
+}
+
+/// Returns the view factor between two coaxial parallel disks.
+///
+/// ```text
+/// S   = 1 + (1 + R₂²) / R₁²,   Rᵢ = rᵢ / L
+/// F₁₂ = ½·[S − √(S² − 4·(R₂/R₁)²)]
+/// ```
+///
+/// # Errors
+///
+/// Returns `Err` if a radius or the separation is not strictly positive.
+pub fn coaxial_disks(
+    from_radius: Length,
+    to_radius: Length,
+    separation: Length,
+) -> ConstraintResult<ViewFactor> {
+    for length in [from_radius, to_radius, separation] {
+        StrictlyPositive::check(&length)?;
+    }
+
+    let r1 = (from_radius / separation).value;
+    let r2 = (to_radius / separation).value;
+    let s = 1.0 + (1.0 + r2 * r2) / (r1 * r1);
+    let ratio = r2 / r1;
+    Ok(ViewFactor::clamped(
+        0.5 * (s - (s * s - 4.0 * ratio * ratio).sqrt()),
+    ))
+}
+
+/// Returns the view factor between two identical, directly opposed parallel
+/// rectangles of size `width × length` a distance `separation` apart.
+///
+/// # Errors
+///
+/// Returns `Err` if a dimension is not strictly positive.
+pub fn aligned_rectangles(
+    width: Length,
+    length: Length,
+    separation: Length,
+) -> ConstraintResult<ViewFactor> {
+    for length in [width, length, separation] {
+        StrictlyPositive::check(&length)?;
+    }
+
+    let x = (width / separation).value;
+    let y = (length / separation).value;
+    let (x2, y2) = (x * x, y * y);
+    let (root_x, root_y) = ((1.0 + x2).sqrt(), (1.0 + y2).sqrt());
+
+    let sum = 0.5 * ((1.0 + x2) * (1.0 + y2) / (1.0 + x2 + y2)).ln()
+        + x * root_y * (x / root_y).atan()
+        + y * root_x * (y / root_x).atan()
+        - x * x.atan()
+        - y * y.atan();
+    Ok(ViewFactor::clamped(2.0 / (PI * x * y) * sum))
+}
+
+/// Returns the view factor between two perpendicular rectangles that share
+/// an edge, such as a wall and the floor in front of it.
+///
+/// Both rectangles span the shared edge of length `common_edge`; each
+/// extends `from_width` or `to_width` away from it.
+///
+/// # Errors
+///
+/// Returns `Err` if a dimension is not strictly positive.
+pub fn perpendicular_rectangles(
+    common_edge: Length,
+    from_width: Length,
+    to_width: Length,
+) -> ConstraintResult<ViewFactor> {
+    for length in [common_edge, from_width, to_width] {
+        StrictlyPositive::check(&length)?;
+    }
+
+    let w = (from_width / common_edge).value;
+    let h = (to_width / common_edge).value;
+    let (w2, h2) = (w * w, h * h);
+    let diagonal = (w2 + h2).sqrt();
+
+    let log_terms = ((1.0 + w2) * (1.0 + h2) / (1.0 + w2 + h2)).ln()
+        + w2 * (w2 * (1.0 + w2 + h2) / ((1.0 + w2) * (w2 + h2))).ln()
+        + h2 * (h2 * (1.0 + h2 + w2) / ((1.0 + h2) * (h2 + w2))).ln();
+    let sum = w * (1.0 / w).atan() + h * (1.0 / h).atan() - diagonal * (1.0 / diagonal).atan()
+        + 0.25 * log_terms;
+    Ok(ViewFactor::clamped(sum / (PI * w)))
+}
+
+/// Returns the view factor from a plane tilted `tilt` above horizontal to
+/// the sky dome, `(1 + cos β) / 2`.
+///
+/// # Errors
+///
+/// Returns `Err` if the tilt is not a number.
+pub fn tilted_surface_to_sky(tilt: Angle) -> ConstraintResult<ViewFactor> {
+    ViewFactor::new(0.5 * (1.0 + tilt.get::<radian>().cos()))
+}
+
+/// Returns the view factor from a plane tilted `tilt` above horizontal to
+/// the ground, `(1 − cos β) / 2`.
+///
+/// # Errors
+///
+/// Returns `Err` if the tilt is not a number.
+pub fn tilted_surface_to_ground(tilt: Angle) -> ConstraintResult<ViewFactor> {
+    ViewFactor::new(0.5 * (1.0 - tilt.get::<radian>().cos()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{angle::degree, area::square_meter, length::meter};
+
+    fn m(value: f64) -> Length {
+        Length::new::<meter>(value)
+    }
+
+    #[test]
+    fn equal_disks_match_tabulated_value() {
+        // Equal disks one radius apart: F = 0.382.
+        let f = coaxial_disks(m(1.0), m(1.0), m(1.0)).unwrap();
+        assert_relative_eq!(f.value, 0.381_966, max_relative = 1e-5);
+    }
+
+    #[test]
+    fn aligned_squares_match_tabulated_value() {
+        // Unit squares one side apart: F = 0.1998.
+        let f = aligned_rectangles(m(1.0), m(1.0), m(1.0)).unwrap();
+        assert_relative_eq!(f.value, 0.199_82, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn perpendicular_squares_match_tabulated_value() {
+        // Two squares sharing an edge: F = 0.2000.
+        let f = perpendicular_rectangles(m(1.0), m(1.0), m(1.0)).unwrap();
+        assert_relative_eq!(f.value, 0.200_04, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn close_plates_see_only_each_other() {
+        let disks = coaxial_disks(m(1.0), m(1.0), m(1e-4)).unwrap();
+        let plates = aligned_rectangles(m(1.0), m(1.0), m(1e-4)).unwrap();
+        assert_relative_eq!(disks.value, 1.0, epsilon = 1e-3);
+        assert_relative_eq!(plates.value, 1.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn reciprocity_scales_by_area() {
+        let f = perpendicular_rectangles(m(2.0), m(1.0), m(3.0)).unwrap();
+        let back = reciprocal(
+            f,
+            Area::new::<square_meter>(2.0),
+            Area::new::<square_meter>(6.0),
+        )
+        .unwrap();
+        let direct = perpendicular_rectangles(m(2.0), m(3.0), m(1.0)).unwrap();
+        assert_relative_eq!(back.value, direct.value, max_relative = 1e-12);
+
+        assert!(
+            reciprocal(
+                ViewFactor::new(1.0).unwrap(),
+                Area::new::<square_meter>(2.0),
+                Area::new::<square_meter>(1.0),
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn tilted_surface_splits_between_sky_and_ground() {
+        let tilt = Angle::new::<degree>(40.0);
+        let sky = tilted_surface_to_sky(tilt).unwrap();
+        let ground = tilted_surface_to_ground(tilt).unwrap();
+        assert_relative_eq!(sky.value + ground.value, 1.0);
+        assert_relative_eq!(
+            tilted_surface_to_sky(Angle::new::<degree>(0.0))
+                .unwrap()
+                .value,
+            1.0
+        );
+    }
+
+    #[test]
+    fn rejects_degenerate_geometry() {
+        assert!(coaxial_disks(m(1.0), m(1.0), m(0.0)).is_err());
+        assert!(aligned_rectangles(m(-1.0), m(1.0), m(1.0)).is_err());
+    }
+}
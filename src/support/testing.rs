@@ -0,0 +1,89 @@
+//! Testing utilities for model authors.
+//!
+//! - [`GoldenTable`] and [`GoldenFile`]: golden-file regression tests.
+//! - [`Balance`] and [`ConservationCheck`]: mass and energy balance closure.
+//! - [`RecuperatorScenario`]: canonical solver workloads for benchmarks.
+//! - [`ReferenceData`] and [`validate`]: thermo model acceptance against
+//!   reference property data.
+//!
+//! ## Golden files
+//!
+//! Physics regressions in solver refactors are easy to miss with a handful of
+//! hand-written asserts. This module provides a golden-file harness: run a
+//! model over a canonical sweep of inputs, record the outputs as a table, and
+//! compare that table against a file checked into the repository, with
+//! per-column tolerances.
+//!
+//! ### Workflow
+//!
+//! 1. Build a [`GoldenTable`] with [`GoldenTable::sweep`] (or by pushing rows).
+//! 2. Compare it against a stored file with [`GoldenFile::check`].
+//! 3. When a change in output is intended, rerun the test with the
+//!    `TWINE_UPDATE_GOLDEN` environment variable set to rewrite the file,
+//!    and review the diff like any other change.
+//!
+//! Golden files are plain CSV: a header of column names followed by one row
+//! per input case, with numbers written in shortest round-trip form.
+//!
+//! ### Example
+//!
+//! ```no_run
+//! use twine_models::{
+//!     models::thermal::battery::lumped::{BatteryPack, BatteryPackInput, BatteryPackParameters},
+//!     support::testing::{GoldenFile, GoldenTable, Tolerance},
+//! };
+//! # fn pack() -> BatteryPack { unimplemented!() }
+//! # fn canonical_inputs() -> Vec<BatteryPackInput> { unimplemented!() }
+//!
+//! let table = GoldenTable::sweep(
+//!     &pack(),
+//!     canonical_inputs(),
+//!     ["heat_generation_w", "derivative_k_per_s"],
+//!     |_input, output| vec![output.heat_generation.value, output.derivative.value],
+//! )?;
+//!
+//! GoldenFile::new("tests/golden/battery_pack.csv")
+//!     .default_tolerance(Tolerance::relative(1e-12))
+//!     .tolerance("derivative_k_per_s", Tolerance::absolute(1e-15))
+//!     .check(&table)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+//!
+//! ## Conservation checks
+//!
+//! A [`Balance`] collects the streams, heat, work, and storage terms crossing
+//! one component's boundary and reports its mass and energy residuals. A
+//! [`ConservationCheck`] runs a set of balances, for example every component
+//! of a resolved system, and lists each one that fails to close.
+//!
+//! ## Benchmark scenarios
+//!
+//! Solver and thermo backend changes can shift performance without changing
+//! any result. [`RecuperatorScenario`] provides fixed discretized heat
+//! exchanger solves at each of the [`HX_SEGMENTS`] counts, with a perfect gas
+//! model and, when a `CoolProp` feature is enabled, real-fluid CO₂. The
+//! crate's `benches/` suite times these scenarios, and downstream crates can
+//! time the same workloads in their own harness.
+//!
+//! ## Reference property data
+//!
+//! [`ReferenceData`] bundles single-phase state points for air, carbon
+//! dioxide, and water, generated with `CoolProp` from the same reference
+//! equations of state used by the NIST Chemistry `WebBook` (Lemmon et al. for
+//! air, Span–Wagner for CO₂, IAPWS-95 for water). [`validate`] checks any
+//! thermo model against them at a [`ValidationTier`], giving new property
+//! backends a common acceptance gate.
+
+mod balance;
+mod bench;
+mod golden;
+mod reference;
+
+pub use balance::{Balance, BalanceTolerance, ConservationCheck, ConservationError, Imbalance};
+pub use bench::{HX_SEGMENTS, RecuperatorScenario};
+pub use golden::{GoldenError, GoldenFile, GoldenTable, Mismatch, Tolerance, UPDATE_ENV_VAR};
+pub use reference::{
+    PropertyTolerances, REFERENCE_COLUMNS, ReferenceData, ReferenceFluid, ReferencePoint,
+    ValidationError, ValidationTier, validate,
+};
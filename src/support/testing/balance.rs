@@ -0,0 +1,394 @@
+use std::fmt;
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::f64::{MassRate, Power},
+};
+
+use crate::support::units::{SpecificEnthalpy, display::pretty};
+
+/// Mass and energy flows across one component's boundary.
+///
+/// Add every stream, heat, and work term crossing the boundary, plus any
+/// storage terms, then [`check`](Self::check) that the balances close:
+///
+/// ```text
+/// Σ ṁ_in − Σ ṁ_out − dm/dt = 0
+/// Σ ṁ_in·h_in − Σ ṁ_out·h_out + Q̇_in + Ẇ_in − dE/dt = 0
+/// ```
+///
+/// Heat and work are positive into the component.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Balance {
+    component: String,
+    mass: Terms<MassRate>,
+    energy: Terms<Power>,
+}
+
+/// Running sum and magnitude of the terms in one balance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Terms<Q> {
+    residual: Q,
+    scale: Q,
+}
+
+impl<Q> Terms<Q>
+where
+    Q: ConstZero + Copy + std::ops::Add<Output = Q> + std::ops::Neg<Output = Q> + PartialOrd,
+{
+    fn new() -> Self {
+        Self {
+            residual: Q::ZERO,
+            scale: Q::ZERO,
+        }
+    }
+
+    fn add(&mut self, term: Q) {
+        self.residual = self.residual + term;
+        self.scale = self.scale + if term < Q::ZERO { -term } else { term };
+    }
+}
+
+/// Allowed imbalance when checking a [`Balance`].
+///
+/// A balance closes when each residual is within
+/// `absolute + relative * scale`, where `scale` is the sum of the magnitudes
+/// of that balance's terms.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BalanceTolerance {
+    /// Relative allowance, as a fraction of the summed term magnitudes.
+    pub relative: f64,
+
+    /// Absolute allowance on the mass balance.
+    pub mass: MassRate,
+
+    /// Absolute allowance on the energy balance.
+    pub energy: Power,
+}
+
+impl BalanceTolerance {
+    /// A purely relative tolerance.
+    #[must_use]
+    pub fn relative(relative: f64) -> Self {
+        Self {
+            relative,
+            mass: MassRate::ZERO,
+            energy: Power::ZERO,
+        }
+    }
+}
+
+impl Default for BalanceTolerance {
+    /// A relative tolerance of `1e-9`.
+    fn default() -> Self {
+        Self::relative(1e-9)
+    }
+}
+
+/// A component whose mass or energy balance does not close.
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error(
+    "{component} does not conserve {}: mass residual {}, energy residual {}",
+    self.violated(),
+    pretty(*mass),
+    pretty(*energy)
+)]
+pub struct Imbalance {
+    /// Name of the component.
+    pub component: String,
+
+    /// Mass residual (net accumulation not accounted for).
+    pub mass: MassRate,
+
+    /// Energy residual (net accumulation not accounted for).
+    pub energy: Power,
+
+    /// Whether the mass residual exceeded tolerance.
+    pub mass_violated: bool,
+
+    /// Whether the energy residual exceeded tolerance.
+    pub energy_violated: bool,
+}
+
+impl Imbalance {
+    fn violated(&self) -> &'static str {
+        match (self.mass_violated, self.energy_violated) {
+            (true, true) => "mass or energy",
+            (true, false) => "mass",
+            _ => "energy",
+        }
+    }
+}
+
+impl Balance {
+    /// Starts an empty balance for the named component.
+    #[must_use]
+    pub fn new(component: impl Into<String>) -> Self {
+        Self {
+            component: component.into(),
+            mass: Terms::new(),
+            energy: Terms::new(),
+        }
+    }
+
+    /// Adds a stream entering the component with specific enthalpy `h`.
+    #[must_use]
+    pub fn inflow(mut self, m_dot: MassRate, h: SpecificEnthalpy) -> Self {
+        self.mass.add(m_dot);
+        self.energy.add(m_dot * h);
+        self
+    }
+
+    /// Adds a stream leaving the component with specific enthalpy `h`.
+    #[must_use]
+    pub fn outflow(mut self, m_dot: MassRate, h: SpecificEnthalpy) -> Self {
+        self.mass.add(-m_dot);
+        self.energy.add(-(m_dot * h));
+        self
+    }
+
+    /// Adds heat transferred into the component (negative for heat lost).
+    #[must_use]
+    pub fn heat_in(mut self, q_dot: Power) -> Self {
+        self.energy.add(q_dot);
+        self
+    }
+
+    /// Adds work done on the component (negative for work extracted).
+    #[must_use]
+    pub fn work_in(mut self, w_dot: Power) -> Self {
+        self.energy.add(w_dot);
+        self
+    }
+
+    /// Adds the rates of change of stored mass and energy.
+    #[must_use]
+    pub fn accumulation(mut self, mass: MassRate, energy: Power) -> Self {
+        self.mass.add(-mass);
+        self.energy.add(-energy);
+        self
+    }
+
+    /// Returns the component name.
+    #[must_use]
+    pub fn component(&self) -> &str {
+        &self.component
+    }
+
+    /// Returns the mass residual, zero when mass is conserved.
+    #[must_use]
+    pub fn mass_residual(&self) -> MassRate {
+        self.mass.residual
+    }
+
+    /// Returns the energy residual, zero when energy is conserved.
+    #[must_use]
+    pub fn energy_residual(&self) -> Power {
+        self.energy.residual
+    }
+
+    /// Checks that both balances close within `tolerance`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Imbalance`] if either residual exceeds its allowance or
+    /// is NaN.
+    pub fn check(&self, tolerance: BalanceTolerance) -> Result<(), Imbalance> {
+        let within = |residual: f64, scale: f64, absolute: f64| {
+            residual.abs() <= absolute + tolerance.relative * scale
+        };
+
+        let mass_violated = !within(
+            self.mass.residual.value,
+            self.mass.scale.value,
+            tolerance.mass.value,
+        );
+        let energy_violated = !within(
+            self.energy.residual.value,
+            self.energy.scale.value,
+            tolerance.energy.value,
+        );
+
+        if mass_violated || energy_violated {
+            Err(Imbalance {
+                component: self.component.clone(),
+                mass: self.mass.residual,
+                energy: self.energy.residual,
+                mass_violated,
+                energy_violated,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Checks the balances of several components and reports every one that
+/// fails to close.
+///
+/// ```
+/// use twine_models::support::{
+///     testing::{Balance, BalanceTolerance, ConservationCheck},
+///     units::SpecificEnthalpy,
+/// };
+/// use uom::si::{
+///     available_energy::kilojoule_per_kilogram, f64::{MassRate, Power},
+///     mass_rate::kilogram_per_second, power::kilowatt,
+/// };
+///
+/// let m_dot = MassRate::new::<kilogram_per_second>(2.0);
+/// let h = |kj| SpecificEnthalpy::new::<kilojoule_per_kilogram>(kj);
+///
+/// let heater = Balance::new("heater")
+///     .inflow(m_dot, h(100.0))
+///     .outflow(m_dot, h(150.0))
+///     .heat_in(Power::new::<kilowatt>(100.0));
+/// let pump = Balance::new("pump")
+///     .inflow(m_dot, h(150.0))
+///     .outflow(m_dot, h(150.5))
+///     .work_in(Power::new::<kilowatt>(0.5));
+///
+/// let error = ConservationCheck::new()
+///     .with(heater)
+///     .with(pump)
+///     .check(BalanceTolerance::default())
+///     .unwrap_err();
+/// assert_eq!(error.imbalances.len(), 1);
+/// assert_eq!(error.imbalances[0].component, "pump");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConservationCheck {
+    balances: Vec<Balance>,
+}
+
+/// One or more components with balances that do not close.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub struct ConservationError {
+    /// Every failing component, in the order they were added.
+    pub imbalances: Vec<Imbalance>,
+}
+
+impl fmt::Display for ConservationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} component(s) do not conserve", self.imbalances.len())?;
+        for imbalance in &self.imbalances {
+            write!(f, "\n  {imbalance}")?;
+        }
+        Ok(())
+    }
+}
+
+impl ConservationCheck {
+    /// Creates an empty check.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a component balance.
+    #[must_use]
+    pub fn with(mut self, balance: Balance) -> Self {
+        self.balances.push(balance);
+        self
+    }
+
+    /// Adds several component balances.
+    #[must_use]
+    pub fn with_all(mut self, balances: impl IntoIterator<Item = Balance>) -> Self {
+        self.balances.extend(balances);
+        self
+    }
+
+    /// Returns the component balances.
+    #[must_use]
+    pub fn balances(&self) -> &[Balance] {
+        &self.balances
+    }
+
+    /// Checks every balance against `tolerance`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConservationError`] listing every component that fails.
+    pub fn check(&self, tolerance: BalanceTolerance) -> Result<(), ConservationError> {
+        let imbalances: Vec<Imbalance> = self
+            .balances
+            .iter()
+            .filter_map(|balance| balance.check(tolerance).err())
+            .collect();
+
+        if imbalances.is_empty() {
+            Ok(())
+        } else {
+            Err(ConservationError { imbalances })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        available_energy::kilojoule_per_kilogram, mass_rate::kilogram_per_second, power::kilowatt,
+    };
+
+    fn kg_per_s(value: f64) -> MassRate {
+        MassRate::new::<kilogram_per_second>(value)
+    }
+
+    fn kj_per_kg(value: f64) -> SpecificEnthalpy {
+        SpecificEnthalpy::new::<kilojoule_per_kilogram>(value)
+    }
+
+    #[test]
+    fn closed_balance_passes() {
+        let balance = Balance::new("mixer")
+            .inflow(kg_per_s(1.0), kj_per_kg(100.0))
+            .inflow(kg_per_s(3.0), kj_per_kg(200.0))
+            .outflow(kg_per_s(4.0), kj_per_kg(175.0));
+
+        assert!(balance.check(BalanceTolerance::relative(1e-12)).is_ok());
+        assert_relative_eq!(balance.energy_residual().get::<kilowatt>(), 0.0);
+    }
+
+    #[test]
+    fn reports_which_balance_fails() {
+        let balance = Balance::new("tank")
+            .inflow(kg_per_s(1.0), kj_per_kg(100.0))
+            .outflow(kg_per_s(0.5), kj_per_kg(100.0));
+
+        let error = balance.check(BalanceTolerance::default()).unwrap_err();
+        assert!(error.mass_violated && error.energy_violated);
+        assert_relative_eq!(error.mass.get::<kilogram_per_second>(), 0.5);
+
+        let stored = balance.accumulation(kg_per_s(0.5), Power::new::<kilowatt>(50.0));
+        assert!(stored.check(BalanceTolerance::default()).is_ok());
+    }
+
+    #[test]
+    fn absolute_tolerance_covers_small_residuals() {
+        let balance = Balance::new("pipe")
+            .inflow(kg_per_s(1.0), kj_per_kg(100.0))
+            .outflow(kg_per_s(1.0), kj_per_kg(100.0))
+            .heat_in(Power::new::<kilowatt>(-0.001));
+
+        assert!(balance.check(BalanceTolerance::default()).is_err());
+        assert!(
+            balance
+                .check(BalanceTolerance {
+                    energy: Power::new::<kilowatt>(0.01),
+                    ..BalanceTolerance::default()
+                })
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn nan_residual_fails() {
+        let balance = Balance::new("broken").inflow(kg_per_s(f64::NAN), kj_per_kg(1.0));
+        assert!(balance.check(BalanceTolerance::default()).is_err());
+    }
+}
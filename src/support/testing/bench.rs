@@ -0,0 +1,232 @@
+use twine_core::Model;
+use uom::si::{
+    f64::{MassRate, Pressure, ThermalConductance, ThermodynamicTemperature},
+    mass_rate::kilogram_per_second,
+    pressure::kilopascal,
+    thermal_conductance::kilowatt_per_kelvin,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::{
+    models::thermal::hx::discretized::{
+        Known, RecuperatorGivenUa, RecuperatorGivenUaConfig, RecuperatorGivenUaError,
+        RecuperatorGivenUaInput, RecuperatorGivenUaOutput,
+    },
+    support::thermo::{
+        State,
+        capability::{StateFrom, ThermoModel},
+        fluid::Air,
+        model::PerfectGas,
+    },
+};
+
+#[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
+use crate::support::thermo::{fluid::CarbonDioxide, model::CoolProp};
+
+/// Segment counts of the canonical discretized heat exchanger scenarios.
+pub const HX_SEGMENTS: [usize; 3] = [5, 20, 100];
+
+/// A fixed recuperator solve used as a benchmark workload.
+///
+/// Each scenario pairs a [`RecuperatorGivenUa`] with one input, chosen so
+/// the solve exercises the full bisection loop. The inputs are part of the
+/// crate's API: they only change when the scenario itself is deliberately
+/// revised, so timings stay comparable across releases.
+///
+/// ```
+/// use std::hint::black_box;
+///
+/// use twine_models::support::testing::{HX_SEGMENTS, RecuperatorScenario};
+///
+/// for segments in HX_SEGMENTS {
+///     let scenario = RecuperatorScenario::perfect_gas(segments)?;
+///     let output = black_box(&scenario).run()?;
+///     assert!(output.iterations > 0);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct RecuperatorScenario<Fluid, Thermo> {
+    name: String,
+    model: RecuperatorGivenUa<Fluid, Thermo>,
+    input: RecuperatorGivenUaInput<Fluid>,
+}
+
+impl<Fluid, Thermo> RecuperatorScenario<Fluid, Thermo> {
+    /// Returns a short, stable identifier such as `perfect_gas_air/20`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the model under test.
+    #[must_use]
+    pub fn model(&self) -> &RecuperatorGivenUa<Fluid, Thermo> {
+        &self.model
+    }
+
+    /// Returns the input solved on each run.
+    #[must_use]
+    pub fn input(&self) -> &RecuperatorGivenUaInput<Fluid> {
+        &self.input
+    }
+
+    /// Solves the scenario once.
+    ///
+    /// # Errors
+    ///
+    /// Returns the model's error if the solve fails.
+    pub fn run(&self) -> Result<RecuperatorGivenUaOutput<Fluid>, RecuperatorGivenUaError>
+    where
+        RecuperatorGivenUa<Fluid, Thermo>: Model<
+                Input = RecuperatorGivenUaInput<Fluid>,
+                Output = RecuperatorGivenUaOutput<Fluid>,
+                Error = RecuperatorGivenUaError,
+            >,
+    {
+        self.model.call(&self.input)
+    }
+}
+
+impl RecuperatorScenario<Air, PerfectGas<Air>> {
+    /// Air recuperator with a perfect gas model.
+    ///
+    /// Compressor discharge at 450 K and 400 kPa is heated by turbine exhaust
+    /// at 800 K and 110 kPa, both at 1 kg/s, with a UA of 2 kW/K.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RecuperatorGivenUaError::UnsupportedSegments`] if `segments`
+    /// is not a supported segment count.
+    pub fn perfect_gas(segments: usize) -> Result<Self, RecuperatorGivenUaError> {
+        let thermo =
+            PerfectGas::<Air>::new().map_err(|error| thermo_failed("perfect gas", error))?;
+        let known = brayton_streams(&thermo, Air, (450.0, 400.0), (800.0, 110.0))?;
+
+        Self::from_parts(
+            format!("perfect_gas_air/{segments}"),
+            thermo,
+            segments,
+            known,
+            2.0,
+        )
+    }
+}
+
+#[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
+impl RecuperatorScenario<CarbonDioxide, CoolProp<CarbonDioxide>> {
+    /// Supercritical CO₂ recuperator with `CoolProp` properties.
+    ///
+    /// Compressor discharge at 80 °C and 200 bar is heated by turbine exhaust
+    /// at 400 °C and 80 bar, both at 1 kg/s, with a UA of 2000 kW/K. The cold
+    /// stream passes near the critical point, where properties vary sharply.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RecuperatorGivenUaError::UnsupportedSegments`] if `segments`
+    /// is not a supported segment count, or
+    /// [`RecuperatorGivenUaError::ThermoModelFailed`] if `CoolProp` fails.
+    pub fn coolprop_co2(segments: usize) -> Result<Self, RecuperatorGivenUaError> {
+        let thermo = CoolProp::<CarbonDioxide>::new()
+            .map_err(|error| thermo_failed("CoolProp construction", error))?;
+        let known = brayton_streams(
+            &thermo,
+            CarbonDioxide,
+            (353.15, 20_000.0),
+            (673.15, 8_000.0),
+        )?;
+
+        Self::from_parts(
+            format!("coolprop_co2/{segments}"),
+            thermo,
+            segments,
+            known,
+            2000.0,
+        )
+    }
+}
+
+impl<Fluid, Thermo> RecuperatorScenario<Fluid, Thermo> {
+    fn from_parts(
+        name: String,
+        thermo: Thermo,
+        segments: usize,
+        known: Known<Fluid, Fluid>,
+        ua_kw_per_k: f64,
+    ) -> Result<Self, RecuperatorGivenUaError> {
+        Ok(Self {
+            name,
+            model: RecuperatorGivenUa::new(thermo, segments, RecuperatorGivenUaConfig::default())?,
+            input: RecuperatorGivenUaInput::new(
+                known,
+                ThermalConductance::new::<kilowatt_per_kelvin>(ua_kw_per_k),
+            ),
+        })
+    }
+}
+
+/// Builds balanced 1 kg/s cold (top) and hot (bottom) streams from
+/// `(temperature in K, pressure in kPa)` pairs.
+fn brayton_streams<Fluid, Thermo>(
+    thermo: &Thermo,
+    fluid: Fluid,
+    cold: (f64, f64),
+    hot: (f64, f64),
+) -> Result<Known<Fluid, Fluid>, RecuperatorGivenUaError>
+where
+    Fluid: Clone,
+    Thermo: StateFrom<(Fluid, ThermodynamicTemperature, Pressure)> + ThermoModel<Fluid = Fluid>,
+{
+    let state = |(t, p): (f64, f64)| -> Result<State<Fluid>, RecuperatorGivenUaError> {
+        thermo
+            .state_from((
+                fluid.clone(),
+                ThermodynamicTemperature::new::<kelvin>(t),
+                Pressure::new::<kilopascal>(p),
+            ))
+            .map_err(|error| thermo_failed("inlet state", error))
+    };
+    let m_dot = MassRate::new::<kilogram_per_second>(1.0);
+
+    Known::builder()
+        .top_inlet(state(cold)?)
+        .bottom_inlet(state(hot)?)
+        .mass_flows(m_dot, m_dot)
+        .build()
+        .map_err(|error| thermo_failed("scenario inputs", error))
+}
+
+fn thermo_failed(
+    context: &str,
+    error: impl std::error::Error + Send + Sync + 'static,
+) -> RecuperatorGivenUaError {
+    RecuperatorGivenUaError::ThermoModelFailed {
+        context: context.to_owned(),
+        source: Box::new(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn perfect_gas_scenarios_converge() {
+        for segments in HX_SEGMENTS {
+            let scenario = RecuperatorScenario::perfect_gas(segments).unwrap();
+            let output = scenario.run().unwrap();
+
+            assert_eq!(scenario.name(), format!("perfect_gas_air/{segments}"));
+            assert!(output.iterations > 1);
+            assert!(output.top_outlet.temperature > scenario.input().inlets.top.temperature);
+        }
+    }
+
+    #[test]
+    fn rejects_unsupported_segments() {
+        assert!(matches!(
+            RecuperatorScenario::perfect_gas(3),
+            Err(RecuperatorGivenUaError::UnsupportedSegments(3))
+        ));
+    }
+}
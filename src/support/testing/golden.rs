@@ -0,0 +1,357 @@
+mod table;
+mod tolerance;
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Write as _},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use thiserror::Error;
+
+pub use table::GoldenTable;
+pub use tolerance::Tolerance;
+
+/// Environment variable that switches [`GoldenFile::check`] to rewrite the
+/// stored file instead of comparing against it.
+pub const UPDATE_ENV_VAR: &str = "TWINE_UPDATE_GOLDEN";
+
+/// Maximum number of mismatches listed in a [`GoldenError::Mismatch`] message.
+const MAX_REPORTED: usize = 10;
+
+/// A stored golden file and the tolerances used to compare against it.
+#[derive(Debug, Clone)]
+pub struct GoldenFile {
+    path: PathBuf,
+    default_tolerance: Tolerance,
+    tolerances: HashMap<String, Tolerance>,
+}
+
+/// A single value that differs from its golden counterpart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    /// Zero-based row index.
+    pub row: usize,
+
+    /// Column name.
+    pub column: String,
+
+    /// Value stored in the golden file.
+    pub expected: f64,
+
+    /// Freshly computed value.
+    pub actual: f64,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "row {} `{}`: expected {}, got {}",
+            self.row, self.column, self.expected, self.actual
+        )
+    }
+}
+
+/// Errors from reading, writing, or comparing golden files.
+#[derive(Debug, Error)]
+pub enum GoldenError {
+    /// The golden file does not exist.
+    #[error("golden file {} not found; rerun with {UPDATE_ENV_VAR}=1 to create it", .path.display())]
+    Missing {
+        /// Path of the missing file.
+        path: PathBuf,
+    },
+
+    /// The golden file could not be read or written.
+    #[error("failed to access golden file {}", .path.display())]
+    Io {
+        /// Path of the file.
+        path: PathBuf,
+
+        /// Underlying I/O error.
+        #[source]
+        source: io::Error,
+    },
+
+    /// The golden file is not a valid table.
+    #[error("golden file line {line}: {message}")]
+    Parse {
+        /// One-based line number.
+        line: usize,
+
+        /// Description of the problem.
+        message: String,
+    },
+
+    /// The computed table has different columns than the golden file.
+    #[error("golden columns differ: expected {expected:?}, got {actual:?}")]
+    Columns {
+        /// Columns in the golden file.
+        expected: Vec<String>,
+
+        /// Columns in the computed table.
+        actual: Vec<String>,
+    },
+
+    /// The computed table has a different number of rows than the golden file.
+    #[error("golden row count differs: expected {expected}, got {actual}")]
+    RowCount {
+        /// Rows in the golden file.
+        expected: usize,
+
+        /// Rows in the computed table.
+        actual: usize,
+    },
+
+    /// One or more values are outside tolerance.
+    #[error("{} value(s) differ from {}:{}", .mismatches.len(), .path.display(), summarize(.mismatches))]
+    Mismatch {
+        /// Path of the golden file.
+        path: PathBuf,
+
+        /// Every out-of-tolerance value, in row-major order.
+        mismatches: Vec<Mismatch>,
+    },
+}
+
+pub(super) fn summarize(mismatches: &[Mismatch]) -> String {
+    let mut summary = String::new();
+    for mismatch in mismatches.iter().take(MAX_REPORTED) {
+        write!(summary, "\n  {mismatch}").expect("writing to a String cannot fail");
+    }
+    if mismatches.len() > MAX_REPORTED {
+        let remaining = mismatches.len() - MAX_REPORTED;
+        write!(summary, "\n  ... and {remaining} more").expect("writing to a String cannot fail");
+    }
+    summary
+}
+
+impl GoldenFile {
+    /// Refers to the golden file at `path`.
+    ///
+    /// Relative paths resolve against the working directory, which for
+    /// `cargo test` is the package root.
+    #[must_use]
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_owned(),
+            default_tolerance: Tolerance::default(),
+            tolerances: HashMap::new(),
+        }
+    }
+
+    /// Sets the tolerance for columns without their own.
+    #[must_use]
+    pub fn default_tolerance(mut self, tolerance: Tolerance) -> Self {
+        self.default_tolerance = tolerance;
+        self
+    }
+
+    /// Sets the tolerance for a single column.
+    #[must_use]
+    pub fn tolerance(mut self, column: impl Into<String>, tolerance: Tolerance) -> Self {
+        self.tolerances.insert(column.into(), tolerance);
+        self
+    }
+
+    /// Returns the path of the golden file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Compares `actual` against the golden file.
+    ///
+    /// If the [`UPDATE_ENV_VAR`] environment variable is set, the file is
+    /// rewritten from `actual` instead and the check passes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GoldenError`] if the file is missing or unreadable, if its
+    /// shape differs from `actual`, or if any value is out of tolerance.
+    pub fn check(&self, actual: &GoldenTable) -> Result<(), GoldenError> {
+        if std::env::var_os(UPDATE_ENV_VAR).is_some() {
+            return self.write(actual);
+        }
+
+        let expected = self.read()?;
+        self.compare(&expected, actual)
+    }
+
+    /// Writes `table` to the golden file, creating parent directories.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GoldenError::Io`] if the file cannot be written.
+    pub fn write(&self, table: &GoldenTable) -> Result<(), GoldenError> {
+        let io_error = |source| GoldenError::Io {
+            path: self.path.clone(),
+            source,
+        };
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        fs::write(&self.path, table.to_csv()).map_err(io_error)
+    }
+
+    /// Reads the golden file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GoldenError::Missing`] if the file does not exist, or
+    /// another [`GoldenError`] if it cannot be read or parsed.
+    pub fn read(&self) -> Result<GoldenTable, GoldenError> {
+        let csv = fs::read_to_string(&self.path).map_err(|source| {
+            if source.kind() == io::ErrorKind::NotFound {
+                GoldenError::Missing {
+                    path: self.path.clone(),
+                }
+            } else {
+                GoldenError::Io {
+                    path: self.path.clone(),
+                    source,
+                }
+            }
+        })?;
+        GoldenTable::from_csv(&csv)
+    }
+
+    /// Compares two tables using this file's tolerances.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GoldenError`] if the shapes differ or any value is out of
+    /// tolerance.
+    pub fn compare(&self, expected: &GoldenTable, actual: &GoldenTable) -> Result<(), GoldenError> {
+        if expected.columns() != actual.columns() {
+            return Err(GoldenError::Columns {
+                expected: expected.columns().to_vec(),
+                actual: actual.columns().to_vec(),
+            });
+        }
+        if expected.rows().len() != actual.rows().len() {
+            return Err(GoldenError::RowCount {
+                expected: expected.rows().len(),
+                actual: actual.rows().len(),
+            });
+        }
+
+        let tolerances: Vec<Tolerance> = expected
+            .columns()
+            .iter()
+            .map(|column| {
+                self.tolerances
+                    .get(column)
+                    .copied()
+                    .unwrap_or(self.default_tolerance)
+            })
+            .collect();
+
+        let mismatches: Vec<Mismatch> = expected
+            .rows()
+            .iter()
+            .zip(actual.rows())
+            .enumerate()
+            .flat_map(|(row, (expected_row, actual_row))| {
+                expected_row
+                    .iter()
+                    .zip(actual_row)
+                    .zip(&tolerances)
+                    .zip(expected.columns())
+                    .filter(|(((expected, actual), tolerance), _)| {
+                        !tolerance.matches(**expected, **actual)
+                    })
+                    .map(move |(((&expected, &actual), _), column)| Mismatch {
+                        row,
+                        column: column.clone(),
+                        expected,
+                        actual,
+                    })
+            })
+            .collect();
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(GoldenError::Mismatch {
+                path: self.path.clone(),
+                mismatches,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(rows: &[[f64; 2]]) -> GoldenTable {
+        let mut table = GoldenTable::new(["q_dot", "ua"]);
+        for row in rows {
+            table.push(*row);
+        }
+        table
+    }
+
+    #[test]
+    fn applies_per_column_tolerances() {
+        let golden = GoldenFile::new("unused.csv")
+            .default_tolerance(Tolerance::EXACT)
+            .tolerance("ua", Tolerance::relative(1e-3));
+
+        let expected = table(&[[1000.0, 50.0], [2000.0, 60.0]]);
+        let close = table(&[[1000.0, 50.04], [2000.0, 60.0]]);
+        let far = table(&[[1000.5, 50.1], [2000.0, 60.0]]);
+
+        assert!(golden.compare(&expected, &close).is_ok());
+
+        let Err(GoldenError::Mismatch { mismatches, .. }) = golden.compare(&expected, &far) else {
+            panic!("expected a mismatch");
+        };
+        assert_eq!(
+            mismatches
+                .iter()
+                .map(|m| m.column.as_str())
+                .collect::<Vec<_>>(),
+            ["q_dot", "ua"]
+        );
+        assert_eq!(mismatches[0].row, 0);
+    }
+
+    #[test]
+    fn rejects_shape_changes() {
+        let golden = GoldenFile::new("unused.csv");
+        let expected = table(&[[1.0, 2.0]]);
+
+        assert!(matches!(
+            golden.compare(&expected, &table(&[])),
+            Err(GoldenError::RowCount {
+                expected: 1,
+                actual: 0
+            })
+        ));
+        assert!(matches!(
+            golden.compare(&expected, &GoldenTable::new(["q_dot"])),
+            Err(GoldenError::Columns { .. })
+        ));
+    }
+
+    #[test]
+    fn writes_and_reads_back() {
+        let path = std::env::temp_dir().join(format!(
+            "twine-golden-{}/nested/table.csv",
+            std::process::id()
+        ));
+        let golden = GoldenFile::new(&path);
+        let expected = table(&[[0.1, 1.0 / 3.0]]);
+
+        assert!(matches!(golden.read(), Err(GoldenError::Missing { .. })));
+        golden.write(&expected).unwrap();
+        assert_eq!(golden.read().unwrap(), expected);
+
+        fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,201 @@
+use std::fmt::Write as _;
+
+use twine_core::Model;
+
+use super::GoldenError;
+
+/// A table of model outputs: named columns and one row per input case.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoldenTable {
+    columns: Vec<String>,
+    rows: Vec<Vec<f64>>,
+}
+
+impl GoldenTable {
+    /// Creates an empty table with the given column names.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a column name is empty, repeated, or contains a comma or
+    /// line break.
+    #[must_use]
+    pub fn new<S: Into<String>>(columns: impl IntoIterator<Item = S>) -> Self {
+        let columns: Vec<String> = columns.into_iter().map(Into::into).collect();
+        for (index, column) in columns.iter().enumerate() {
+            assert!(
+                !column.is_empty() && !column.contains([',', '\n', '\r']),
+                "invalid golden column name `{column}`"
+            );
+            assert!(
+                !columns[..index].contains(column),
+                "duplicate golden column name `{column}`"
+            );
+        }
+
+        Self {
+            columns,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Runs `model` over `inputs` and records one row per input.
+    ///
+    /// `row` maps each input and its output to the values of `columns`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the model's error if any call fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` returns a different number of values than there are
+    /// columns, or for an invalid column name (see [`GoldenTable::new`]).
+    pub fn sweep<M, S>(
+        model: &M,
+        inputs: impl IntoIterator<Item = M::Input>,
+        columns: impl IntoIterator<Item = S>,
+        row: impl Fn(&M::Input, &M::Output) -> Vec<f64>,
+    ) -> Result<Self, M::Error>
+    where
+        M: Model,
+        S: Into<String>,
+    {
+        let mut table = Self::new(columns);
+        for input in inputs {
+            let output = model.call(&input)?;
+            table.push(row(&input, &output));
+        }
+        Ok(table)
+    }
+
+    /// Appends a row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` has a different length than the column list.
+    pub fn push(&mut self, values: impl IntoIterator<Item = f64>) {
+        let values: Vec<f64> = values.into_iter().collect();
+        assert_eq!(
+            values.len(),
+            self.columns.len(),
+            "golden row has {} values for {} columns",
+            values.len(),
+            self.columns.len()
+        );
+        self.rows.push(values);
+    }
+
+    /// Returns the column names.
+    #[must_use]
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Returns the rows in insertion order.
+    #[must_use]
+    pub fn rows(&self) -> &[Vec<f64>] {
+        &self.rows
+    }
+
+    /// Writes the table as CSV.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut csv = self.columns.join(",");
+        csv.push('\n');
+        for row in &self.rows {
+            for (index, value) in row.iter().enumerate() {
+                if index > 0 {
+                    csv.push(',');
+                }
+                // `Display` for f64 writes the shortest string that parses back
+                // to the same value.
+                write!(csv, "{value}").expect("writing to a String cannot fail");
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Reads a table written by [`GoldenTable::to_csv`].
+    ///
+    /// Blank lines and lines starting with `#` are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GoldenError::Parse`] if the header is missing, a row has the
+    /// wrong number of columns, or a value is not a number.
+    pub fn from_csv(csv: &str) -> Result<Self, GoldenError> {
+        let mut lines = csv
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+
+        let Some((_, header)) = lines.next() else {
+            return Err(GoldenError::Parse {
+                line: 1,
+                message: "missing header".into(),
+            });
+        };
+        let columns: Vec<String> = header.split(',').map(|c| c.trim().to_owned()).collect();
+
+        let mut rows = Vec::new();
+        for (line, text) in lines {
+            let error = |message| GoldenError::Parse { line, message };
+
+            let row = text
+                .split(',')
+                .map(|value| {
+                    let value = value.trim();
+                    value
+                        .parse::<f64>()
+                        .map_err(|_| error(format!("invalid value `{value}`")))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if row.len() != columns.len() {
+                return Err(error(format!(
+                    "expected {} columns, found {}",
+                    columns.len(),
+                    row.len()
+                )));
+            }
+            rows.push(row);
+        }
+
+        Ok(Self { columns, rows })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_round_trips_exactly() {
+        let mut table = GoldenTable::new(["a", "b"]);
+        table.push([0.1 + 0.2, -1.0e-300]);
+        table.push([f64::NAN, f64::INFINITY]);
+
+        let parsed = GoldenTable::from_csv(&table.to_csv()).unwrap();
+
+        assert_eq!(parsed.columns(), ["a", "b"]);
+        assert_eq!(parsed.rows()[0], [0.1 + 0.2, -1.0e-300]);
+        assert!(parsed.rows()[1][0].is_nan());
+        assert_eq!(parsed.rows()[1][1..], [f64::INFINITY]);
+    }
+
+    #[test]
+    fn reports_malformed_rows() {
+        let error = GoldenTable::from_csv("a,b\n# comment\n1,2\n3\n").unwrap_err();
+        assert!(matches!(error, GoldenError::Parse { line: 4, .. }));
+
+        let error = GoldenTable::from_csv("a\nx\n").unwrap_err();
+        assert!(matches!(error, GoldenError::Parse { line: 2, .. }));
+    }
+
+    #[test]
+    #[should_panic(expected = "golden row has 1 values for 2 columns")]
+    fn push_rejects_wrong_length() {
+        GoldenTable::new(["a", "b"]).push([1.0]);
+    }
+}
@@ -0,0 +1,89 @@
+/// Allowed difference between a golden value and a freshly computed one.
+///
+/// Values `expected` and `actual` match when
+/// `|actual - expected| <= absolute + relative * |expected|`.
+/// Two NaN values always match, and infinities match only themselves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// Absolute allowance, in the column's units.
+    pub absolute: f64,
+
+    /// Relative allowance, as a fraction of the expected magnitude.
+    pub relative: f64,
+}
+
+impl Tolerance {
+    /// Requires bit-for-bit agreement of the written value.
+    pub const EXACT: Self = Self {
+        absolute: 0.0,
+        relative: 0.0,
+    };
+
+    /// An absolute tolerance.
+    #[must_use]
+    pub const fn absolute(absolute: f64) -> Self {
+        Self {
+            absolute,
+            relative: 0.0,
+        }
+    }
+
+    /// A relative tolerance.
+    #[must_use]
+    pub const fn relative(relative: f64) -> Self {
+        Self {
+            absolute: 0.0,
+            relative,
+        }
+    }
+
+    /// Returns whether `actual` is within tolerance of `expected`.
+    #[must_use]
+    pub fn matches(&self, expected: f64, actual: f64) -> bool {
+        if expected.is_nan() || actual.is_nan() {
+            return expected.is_nan() && actual.is_nan();
+        }
+        if expected.is_infinite() || actual.is_infinite() {
+            #[allow(clippy::float_cmp)]
+            return expected == actual;
+        }
+
+        (actual - expected).abs() <= self.absolute + self.relative * expected.abs()
+    }
+}
+
+impl Default for Tolerance {
+    /// A relative tolerance of `1e-12`, loose enough to absorb last-digit
+    /// differences between platforms.
+    fn default() -> Self {
+        Self::relative(1e-12)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combines_absolute_and_relative_allowances() {
+        let tol = Tolerance {
+            absolute: 0.1,
+            relative: 0.01,
+        };
+
+        assert!(tol.matches(100.0, 101.1));
+        assert!(!tol.matches(100.0, 101.2));
+        assert!(tol.matches(0.0, -0.1));
+    }
+
+    #[test]
+    fn handles_non_finite_values() {
+        let tol = Tolerance::absolute(1.0);
+
+        assert!(tol.matches(f64::NAN, f64::NAN));
+        assert!(!tol.matches(f64::NAN, 0.0));
+        assert!(tol.matches(f64::INFINITY, f64::INFINITY));
+        assert!(!tol.matches(f64::INFINITY, f64::NEG_INFINITY));
+        assert!(!tol.matches(1e300, f64::INFINITY));
+    }
+}
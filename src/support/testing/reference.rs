@@ -0,0 +1,474 @@
+use std::error::Error as StdError;
+
+use thiserror::Error;
+use uom::si::{
+    available_energy::joule_per_kilogram,
+    f64::{MassDensity, Pressure, SpecificHeatCapacity, ThermodynamicTemperature},
+    mass_density::kilogram_per_cubic_meter,
+    pressure::pascal,
+    specific_heat_capacity::joule_per_kilogram_kelvin,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::support::{
+    thermo::{
+        State,
+        capability::{HasCp, HasEnthalpy, HasEntropy, StateFrom},
+        fluid::{Air, CarbonDioxide, Water},
+    },
+    units::{SpecificEnthalpy, SpecificEntropy},
+};
+
+use super::{GoldenError, GoldenTable, Mismatch, Tolerance, golden::summarize};
+
+/// Column names of a reference data file, in order.
+pub const REFERENCE_COLUMNS: [&str; 6] = [
+    "temperature_k",
+    "pressure_pa",
+    "density_kg_per_m3",
+    "enthalpy_j_per_kg",
+    "entropy_j_per_kg_k",
+    "cp_j_per_kg_k",
+];
+
+/// A fluid with bundled reference property data.
+pub trait ReferenceFluid {
+    /// Bundled reference data, in the format read by [`ReferenceData::from_csv`].
+    const REFERENCE_CSV: &'static str;
+}
+
+impl ReferenceFluid for Air {
+    const REFERENCE_CSV: &'static str = include_str!("reference/air.csv");
+}
+
+impl ReferenceFluid for CarbonDioxide {
+    const REFERENCE_CSV: &'static str = include_str!("reference/carbon_dioxide.csv");
+}
+
+impl ReferenceFluid for Water {
+    const REFERENCE_CSV: &'static str = include_str!("reference/water.csv");
+}
+
+/// One reference state point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReferencePoint {
+    pub temperature: ThermodynamicTemperature,
+    pub pressure: Pressure,
+    pub density: MassDensity,
+    pub enthalpy: SpecificEnthalpy,
+    pub entropy: SpecificEntropy,
+    pub cp: SpecificHeatCapacity,
+}
+
+/// A set of single-phase reference state points for one fluid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceData {
+    points: Vec<ReferencePoint>,
+}
+
+impl ReferenceData {
+    /// Returns the bundled reference data for `F`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bundled CSV for `F` is malformed.
+    #[must_use]
+    pub fn of<F: ReferenceFluid>() -> Self {
+        Self::from_csv(F::REFERENCE_CSV).expect("bundled reference data is valid")
+    }
+
+    /// Reads reference data from CSV with the [`REFERENCE_COLUMNS`] header.
+    ///
+    /// Blank lines and lines starting with `#` are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::Data`] if the table is malformed, its
+    /// columns differ from [`REFERENCE_COLUMNS`], or it has no points.
+    pub fn from_csv(csv: &str) -> Result<Self, ValidationError> {
+        let table = GoldenTable::from_csv(csv)?;
+        if table.columns() != REFERENCE_COLUMNS {
+            return Err(GoldenError::Columns {
+                expected: REFERENCE_COLUMNS.map(str::to_owned).to_vec(),
+                actual: table.columns().to_vec(),
+            }
+            .into());
+        }
+        if table.rows().is_empty() {
+            return Err(GoldenError::RowCount {
+                expected: 1,
+                actual: 0,
+            }
+            .into());
+        }
+
+        let points = table
+            .rows()
+            .iter()
+            .map(|row| ReferencePoint {
+                temperature: ThermodynamicTemperature::new::<kelvin>(row[0]),
+                pressure: Pressure::new::<pascal>(row[1]),
+                density: MassDensity::new::<kilogram_per_cubic_meter>(row[2]),
+                enthalpy: SpecificEnthalpy::new::<joule_per_kilogram>(row[3]),
+                entropy: SpecificEntropy::new::<joule_per_kilogram_kelvin>(row[4]),
+                cp: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(row[5]),
+            })
+            .collect();
+
+        Ok(Self { points })
+    }
+
+    /// Returns the reference points.
+    #[must_use]
+    pub fn points(&self) -> &[ReferencePoint] {
+        &self.points
+    }
+
+    /// Keeps only the points for which `keep` returns `true`.
+    ///
+    /// Use this to restrict validation to the range a model is meant to
+    /// cover, such as low pressures for an ideal-gas model.
+    #[must_use]
+    pub fn filter(mut self, keep: impl FnMut(&ReferencePoint) -> bool) -> Self {
+        self.points.retain(keep);
+        self
+    }
+}
+
+/// Predefined accuracy tiers for [`validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationTier {
+    /// Agreement expected of an independent implementation of the same
+    /// reference equation of state.
+    Reference,
+
+    /// Agreement expected of an engineering formulation of the reference
+    /// data, such as IAPWS-IF97 for water.
+    Engineering,
+
+    /// Agreement expected of a simplified model, such as a cubic equation
+    /// of state or an ideal gas within its range.
+    Screening,
+}
+
+/// Allowed differences per property.
+///
+/// Density and `cp` are compared directly. Enthalpy and entropy are
+/// compared as differences from the first reference point, so models with
+/// different reference states compare equally.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PropertyTolerances {
+    /// Density tolerance, in kg/m³.
+    pub density: Tolerance,
+
+    /// Enthalpy difference tolerance, in J/kg.
+    pub enthalpy: Tolerance,
+
+    /// Entropy difference tolerance, in J/(kg·K).
+    pub entropy: Tolerance,
+
+    /// Isobaric specific heat tolerance, in J/(kg·K).
+    pub cp: Tolerance,
+}
+
+impl From<ValidationTier> for PropertyTolerances {
+    fn from(tier: ValidationTier) -> Self {
+        let (relative, enthalpy, entropy, cp) = match tier {
+            ValidationTier::Reference => (1e-6, 1.0, 1e-3, 1e-5),
+            ValidationTier::Engineering => (5e-3, 1e3, 1.0, 2e-2),
+            ValidationTier::Screening => (5e-2, 1e4, 10.0, 0.15),
+        };
+        Self {
+            density: Tolerance::relative(relative),
+            enthalpy: Tolerance {
+                absolute: enthalpy,
+                relative,
+            },
+            entropy: Tolerance {
+                absolute: entropy,
+                relative,
+            },
+            cp: Tolerance::relative(cp),
+        }
+    }
+}
+
+/// Errors from reading reference data or validating a model against it.
+#[derive(Debug, Error)]
+pub enum ValidationError {
+    /// The reference data is malformed.
+    #[error("invalid reference data")]
+    Data(#[from] GoldenError),
+
+    /// The model failed to evaluate a reference point.
+    #[error("model failed at reference point {point}: {context}")]
+    Model {
+        /// Zero-based index of the reference point.
+        point: usize,
+
+        /// Operation that failed.
+        context: &'static str,
+
+        /// Underlying model error.
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+
+    /// One or more properties are outside tolerance.
+    ///
+    /// Each [`Mismatch`] row is a reference point index and each column one
+    /// of `density`, `enthalpy`, `entropy`, or `cp`, in SI units.
+    #[error("{} value(s) differ from the reference data:{}", .mismatches.len(), summarize(.mismatches))]
+    Mismatch {
+        /// Every out-of-tolerance value, in point-major order.
+        mismatches: Vec<Mismatch>,
+    },
+}
+
+/// Checks a thermodynamic model against reference data.
+///
+/// Each point's state is built from its temperature and pressure, then the
+/// model's density, enthalpy, entropy, and `cp` are compared with the
+/// reference values. `tolerances` is usually a [`ValidationTier`].
+///
+/// This is the acceptance gate for a new property backend: a faithful
+/// implementation of a reference equation of state should pass
+/// [`ValidationTier::Reference`] over all bundled points.
+///
+/// # Example
+///
+/// ```
+/// use twine_models::support::{
+///     testing::{ReferenceData, ValidationTier, validate},
+///     thermo::{fluid::Air, model::PerfectGas},
+/// };
+/// use uom::si::{
+///     f64::{Pressure, ThermodynamicTemperature},
+///     pressure::kilopascal,
+///     thermodynamic_temperature::kelvin,
+/// };
+///
+/// // A perfect gas is only meant for low pressures and moderate temperatures.
+/// let data = ReferenceData::of::<Air>().filter(|point| {
+///     point.pressure < Pressure::new::<kilopascal>(200.0)
+///         && point.temperature <= ThermodynamicTemperature::new::<kelvin>(1000.0)
+/// });
+///
+/// let thermo = PerfectGas::<Air>::new()?;
+/// validate(&thermo, &Air, &data, ValidationTier::Screening)?;
+/// assert!(validate(&thermo, &Air, &data, ValidationTier::Reference).is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ValidationError::Model`] if the model cannot evaluate a point,
+/// or [`ValidationError::Mismatch`] listing every out-of-tolerance value.
+pub fn validate<Fluid, Thermo>(
+    thermo: &Thermo,
+    fluid: &Fluid,
+    data: &ReferenceData,
+    tolerances: impl Into<PropertyTolerances>,
+) -> Result<(), ValidationError>
+where
+    Fluid: Clone,
+    Thermo: StateFrom<(Fluid, ThermodynamicTemperature, Pressure), Fluid = Fluid>
+        + HasEnthalpy
+        + HasEntropy
+        + HasCp,
+{
+    let tolerances = tolerances.into();
+
+    let evaluated = data
+        .points
+        .iter()
+        .enumerate()
+        .map(|(point, reference)| evaluate(thermo, fluid, point, reference))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (Some(first), Some(reference_first)) = (evaluated.first(), data.points.first()) else {
+        return Ok(());
+    };
+
+    let mut mismatches = Vec::new();
+    for (row, (actual, expected)) in evaluated.iter().zip(&data.points).enumerate() {
+        let comparisons = [
+            (
+                "density",
+                tolerances.density,
+                expected.density.get::<kilogram_per_cubic_meter>(),
+                actual.density.get::<kilogram_per_cubic_meter>(),
+            ),
+            (
+                "enthalpy",
+                tolerances.enthalpy,
+                (expected.enthalpy - reference_first.enthalpy).get::<joule_per_kilogram>(),
+                (actual.enthalpy - first.enthalpy).get::<joule_per_kilogram>(),
+            ),
+            (
+                "entropy",
+                tolerances.entropy,
+                (expected.entropy - reference_first.entropy).get::<joule_per_kilogram_kelvin>(),
+                (actual.entropy - first.entropy).get::<joule_per_kilogram_kelvin>(),
+            ),
+            (
+                "cp",
+                tolerances.cp,
+                expected.cp.get::<joule_per_kilogram_kelvin>(),
+                actual.cp.get::<joule_per_kilogram_kelvin>(),
+            ),
+        ];
+
+        for (column, tolerance, expected, actual) in comparisons {
+            if !tolerance.matches(expected, actual) {
+                mismatches.push(Mismatch {
+                    row,
+                    column: column.to_owned(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::Mismatch { mismatches })
+    }
+}
+
+fn evaluate<Fluid, Thermo>(
+    thermo: &Thermo,
+    fluid: &Fluid,
+    point: usize,
+    reference: &ReferencePoint,
+) -> Result<ReferencePoint, ValidationError>
+where
+    Fluid: Clone,
+    Thermo: StateFrom<(Fluid, ThermodynamicTemperature, Pressure), Fluid = Fluid>
+        + HasEnthalpy
+        + HasEntropy
+        + HasCp,
+{
+    let failed = |context, source: Box<dyn StdError + Send + Sync>| ValidationError::Model {
+        point,
+        context,
+        source,
+    };
+
+    let state: State<Fluid> = thermo
+        .state_from((fluid.clone(), reference.temperature, reference.pressure))
+        .map_err(|error| failed("state_from(T, P)", Box::new(error)))?;
+
+    Ok(ReferencePoint {
+        temperature: state.temperature,
+        pressure: reference.pressure,
+        density: state.density,
+        enthalpy: thermo
+            .enthalpy(&state)
+            .map_err(|error| failed("enthalpy", Box::new(error)))?,
+        entropy: thermo
+            .entropy(&state)
+            .map_err(|error| failed("entropy", Box::new(error)))?,
+        cp: thermo
+            .cp(&state)
+            .map_err(|error| failed("cp", Box::new(error)))?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uom::si::pressure::kilopascal;
+
+    use crate::support::thermo::model::PerfectGas;
+
+    #[test]
+    fn bundled_data_is_single_phase_and_complete() {
+        for data in [
+            ReferenceData::of::<Air>(),
+            ReferenceData::of::<CarbonDioxide>(),
+            ReferenceData::of::<Water>(),
+        ] {
+            assert!(data.points().len() >= 15);
+            for point in data.points() {
+                assert!(point.density.get::<kilogram_per_cubic_meter>() > 0.0);
+                assert!(point.cp.get::<joule_per_kilogram_kelvin>() > 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn perfect_gas_passes_screening_only_within_its_range() {
+        let thermo = PerfectGas::<Air>::new().unwrap();
+        let in_range = |point: &ReferencePoint| {
+            point.pressure < Pressure::new::<kilopascal>(200.0)
+                && point.temperature <= ThermodynamicTemperature::new::<kelvin>(1000.0)
+        };
+        let all = ReferenceData::of::<Air>();
+
+        validate(
+            &thermo,
+            &Air,
+            &all.clone().filter(in_range),
+            ValidationTier::Screening,
+        )
+        .unwrap();
+        assert!(
+            validate(
+                &thermo,
+                &Air,
+                &all.clone().filter(in_range),
+                ValidationTier::Reference
+            )
+            .is_err()
+        );
+
+        let Err(ValidationError::Mismatch { mismatches }) =
+            validate(&thermo, &Air, &all, ValidationTier::Screening)
+        else {
+            panic!("a perfect gas should miss dense and hot air");
+        };
+        assert!(mismatches.iter().any(|m| m.column == "density"));
+        assert!(mismatches.iter().any(|m| m.column == "cp"));
+        assert!(mismatches.iter().all(|m| !in_range(&all.points()[m.row])));
+    }
+
+    #[test]
+    fn rejects_malformed_data() {
+        assert!(matches!(
+            ReferenceData::from_csv("temperature_k,pressure_pa\n300,101325\n"),
+            Err(ValidationError::Data(GoldenError::Columns { .. }))
+        ));
+        assert!(matches!(
+            ReferenceData::from_csv(&REFERENCE_COLUMNS.join(",")),
+            Err(ValidationError::Data(GoldenError::RowCount { .. }))
+        ));
+    }
+
+    #[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
+    #[test]
+    fn coolprop_passes_reference_tier() {
+        use crate::support::thermo::model::CoolProp;
+
+        let co2 = CoolProp::<CarbonDioxide>::new().unwrap();
+        validate(
+            &co2,
+            &CarbonDioxide,
+            &ReferenceData::of::<CarbonDioxide>(),
+            ValidationTier::Reference,
+        )
+        .unwrap();
+
+        let water = CoolProp::<Water>::new().unwrap();
+        validate(
+            &water,
+            &Water,
+            &ReferenceData::of::<Water>(),
+            ValidationTier::Reference,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,730 @@
+//! Lumped thermal resistance–capacitance networks.
+//!
+//! Many component models reduce to a handful of temperature nodes joined by
+//! conductances: a battery pack and its coolant, a chip, its case, and a
+//! cold plate, or a zone's air, walls, and the outdoors. A
+//! [`ThermalNetwork`] lets such a model declare its nodes and links and have
+//! the network assembled and solved for it:
+//!
+//! - **Nodes**: free nodes, added with [`ThermalNetwork::add_node`], carry a
+//!   heat capacity (zero for a massless junction) and an optional heat
+//!   source; boundary nodes, added with [`ThermalNetwork::add_boundary`],
+//!   hold a set temperature.
+//! - **Links**: [`ThermalNetwork::connect`] joins two nodes through a
+//!   conductance, the inverse of a thermal resistance.
+//! - **Solutions**: [`ThermalNetwork::steady_state`] solves for the free
+//!   node temperatures with capacitances ignored, and
+//!   [`ThermalNetwork::step`] advances a transient by one backward Euler
+//!   step. Both return a [`NetworkSolution`] with every node temperature
+//!   and link heat flow.
+//!
+//! Backward Euler is unconditionally stable, so the step size is set by the
+//! accuracy needed rather than by the fastest node, and massless nodes are
+//! solved as algebraic junctions within the same step. The network is
+//! assembled into a dense matrix, which suits networks of up to a few
+//! hundred nodes.
+//!
+//! # Example
+//!
+//! A chip dissipating 40 W through its case and a heat sink to room air,
+//! first at steady state and then warming up from room temperature:
+//!
+//! ```
+//! use twine_models::support::thermal_network::{ThermalNetwork, ThermalNetworkError};
+//! use uom::si::{
+//!     f64::{HeatCapacity, Power, ThermalConductance, ThermodynamicTemperature, Time},
+//!     heat_capacity::joule_per_kelvin,
+//!     power::watt,
+//!     thermal_conductance::watt_per_kelvin,
+//!     thermodynamic_temperature::degree_celsius,
+//!     time::second,
+//! };
+//!
+//! let room = ThermodynamicTemperature::new::<degree_celsius>(25.0);
+//!
+//! let mut network = ThermalNetwork::new();
+//! let chip = network.add_node(HeatCapacity::new::<joule_per_kelvin>(5.0))?;
+//! let sink = network.add_node(HeatCapacity::new::<joule_per_kelvin>(200.0))?;
+//! let air = network.add_boundary(room)?;
+//! network.connect(chip, sink, ThermalConductance::new::<watt_per_kelvin>(4.0))?;
+//! let convection = network.connect(sink, air, ThermalConductance::new::<watt_per_kelvin>(2.0))?;
+//! network.set_heat(chip, Power::new::<watt>(40.0))?;
+//!
+//! // 40 W through 0.25 K/W and then 0.5 K/W.
+//! let steady = network.steady_state()?;
+//! assert!((steady.temperature(chip).get::<degree_celsius>() - 55.0).abs() < 1e-9);
+//! assert!((steady.heat_flow(convection).get::<watt>() - 40.0).abs() < 1e-9);
+//!
+//! let mut state = network.uniform(room);
+//! for _ in 0..60 {
+//!     state = network.step(&state, Time::new::<second>(1.0))?;
+//! }
+//! assert!(state.temperature(sink) > room);
+//! assert!(state.temperature(sink) < steady.temperature(sink));
+//! # Ok::<(), ThermalNetworkError>(())
+//! ```
+
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::{
+        f64::{HeatCapacity, Power, ThermalConductance, ThermodynamicTemperature, Time},
+        heat_capacity::joule_per_kelvin,
+        power::watt,
+        thermal_conductance::watt_per_kelvin,
+        thermodynamic_temperature::kelvin,
+        time::second,
+    },
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive},
+    numerics::linear::solve_linear,
+};
+
+/// Handle to a node of a [`ThermalNetwork`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+/// Handle to a link of a [`ThermalNetwork`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LinkId(usize);
+
+/// Errors from building or solving a [`ThermalNetwork`].
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ThermalNetworkError {
+    /// A value passed to the network is out of range.
+    #[error("invalid value: {0}")]
+    InvalidValue(&'static str),
+
+    /// The node does not belong to this network.
+    #[error("unknown node: {0:?}")]
+    UnknownNode(NodeId),
+
+    /// The link does not belong to this network.
+    #[error("unknown link: {0:?}")]
+    UnknownLink(LinkId),
+
+    /// The operation needs a free node but was given a boundary, or the
+    /// reverse.
+    #[error("wrong node kind: {0:?}")]
+    WrongNodeKind(NodeId),
+
+    /// The solution passed to [`ThermalNetwork::step`] was not produced by
+    /// this network.
+    #[error("solution has {found} nodes, network has {expected}")]
+    MismatchedSolution { expected: usize, found: usize },
+
+    /// The temperatures are not fixed: some nodes have neither a path to a
+    /// boundary nor, in a transient step, any heat capacity.
+    #[error("network is singular: some nodes have no path to a boundary")]
+    Singular,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Node {
+    Free {
+        capacitance: HeatCapacity,
+        heat: Power,
+    },
+    Boundary {
+        temperature: ThermodynamicTemperature,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Link {
+    nodes: [NodeId; 2],
+    conductance: ThermalConductance,
+}
+
+/// A network of temperature nodes joined by thermal conductances.
+///
+/// Free nodes have unknown temperatures; boundary nodes have set ones.
+/// Heat sources are positive into their node. Link heat flows are positive
+/// from the first node passed to [`connect`](Self::connect) to the second.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThermalNetwork {
+    nodes: Vec<Node>,
+    links: Vec<Link>,
+}
+
+/// Node temperatures and link heat flows of a [`ThermalNetwork`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkSolution {
+    temperatures: Vec<ThermodynamicTemperature>,
+    heat_flows: Vec<Power>,
+    outflows: Vec<Power>,
+}
+
+impl NetworkSolution {
+    /// Returns the temperature of `node`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` does not belong to the network that produced this
+    /// solution.
+    #[must_use]
+    pub fn temperature(&self, node: NodeId) -> ThermodynamicTemperature {
+        self.temperatures[node.0]
+    }
+
+    /// Returns every node temperature, in the order the nodes were added.
+    #[must_use]
+    pub fn temperatures(&self) -> &[ThermodynamicTemperature] {
+        &self.temperatures
+    }
+
+    /// Returns the heat flow through `link`, from its first node to its
+    /// second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `link` does not belong to the network that produced this
+    /// solution.
+    #[must_use]
+    pub fn heat_flow(&self, link: LinkId) -> Power {
+        self.heat_flows[link.0]
+    }
+
+    /// Returns the net heat conducted out of `node` through its links.
+    ///
+    /// For a boundary this is the heat it supplies to the network. For a
+    /// free node it equals the node's source less the rate its stored heat
+    /// rises, so at steady state it equals the source.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` does not belong to the network that produced this
+    /// solution.
+    #[must_use]
+    pub fn net_outflow(&self, node: NodeId) -> Power {
+        self.outflows[node.0]
+    }
+}
+
+impl ThermalNetwork {
+    /// Creates an empty network.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a free node with the given heat capacity and no heat source.
+    ///
+    /// A zero capacity makes a massless junction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThermalNetworkError::InvalidValue`] if the capacity is
+    /// negative or not finite.
+    pub fn add_node(&mut self, capacitance: HeatCapacity) -> Result<NodeId, ThermalNetworkError> {
+        if NonNegative::check(&capacitance).is_err() || !capacitance.is_finite() {
+            return Err(ThermalNetworkError::InvalidValue(
+                "heat capacity must be non-negative and finite",
+            ));
+        }
+        self.nodes.push(Node::Free {
+            capacitance,
+            heat: Power::ZERO,
+        });
+        Ok(NodeId(self.nodes.len() - 1))
+    }
+
+    /// Adds a boundary node held at `temperature`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThermalNetworkError::InvalidValue`] if the temperature is
+    /// not strictly positive and finite.
+    pub fn add_boundary(
+        &mut self,
+        temperature: ThermodynamicTemperature,
+    ) -> Result<NodeId, ThermalNetworkError> {
+        check_temperature(temperature)?;
+        self.nodes.push(Node::Boundary { temperature });
+        Ok(NodeId(self.nodes.len() - 1))
+    }
+
+    /// Joins two distinct nodes through a conductance.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ThermalNetworkError`] if either node is unknown, the
+    /// nodes are the same, or the conductance is negative or not finite.
+    pub fn connect(
+        &mut self,
+        from: NodeId,
+        to: NodeId,
+        conductance: ThermalConductance,
+    ) -> Result<LinkId, ThermalNetworkError> {
+        self.node(from)?;
+        self.node(to)?;
+        if from == to {
+            return Err(ThermalNetworkError::InvalidValue(
+                "a link must join two distinct nodes",
+            ));
+        }
+        check_conductance(conductance)?;
+        self.links.push(Link {
+            nodes: [from, to],
+            conductance,
+        });
+        Ok(LinkId(self.links.len() - 1))
+    }
+
+    /// Sets the heat source of a free node, positive into the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ThermalNetworkError`] if the node is unknown or a
+    /// boundary, or the heat is not finite.
+    pub fn set_heat(&mut self, node: NodeId, heat: Power) -> Result<(), ThermalNetworkError> {
+        if !heat.is_finite() {
+            return Err(ThermalNetworkError::InvalidValue("heat must be finite"));
+        }
+        match self.node_mut(node)? {
+            Node::Free { heat: source, .. } => {
+                *source = heat;
+                Ok(())
+            }
+            Node::Boundary { .. } => Err(ThermalNetworkError::WrongNodeKind(node)),
+        }
+    }
+
+    /// Sets the temperature of a boundary node.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ThermalNetworkError`] if the node is unknown or free, or
+    /// the temperature is not strictly positive and finite.
+    pub fn set_boundary(
+        &mut self,
+        node: NodeId,
+        temperature: ThermodynamicTemperature,
+    ) -> Result<(), ThermalNetworkError> {
+        check_temperature(temperature)?;
+        match self.node_mut(node)? {
+            Node::Boundary { temperature: set } => {
+                *set = temperature;
+                Ok(())
+            }
+            Node::Free { .. } => Err(ThermalNetworkError::WrongNodeKind(node)),
+        }
+    }
+
+    /// Sets the conductance of a link.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ThermalNetworkError`] if the link is unknown or the
+    /// conductance is negative or not finite.
+    pub fn set_conductance(
+        &mut self,
+        link: LinkId,
+        conductance: ThermalConductance,
+    ) -> Result<(), ThermalNetworkError> {
+        check_conductance(conductance)?;
+        let link = self
+            .links
+            .get_mut(link.0)
+            .ok_or(ThermalNetworkError::UnknownLink(link))?;
+        link.conductance = conductance;
+        Ok(())
+    }
+
+    /// Returns the number of nodes, free and boundary.
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of links.
+    #[must_use]
+    pub fn link_count(&self) -> usize {
+        self.links.len()
+    }
+
+    /// Returns a solution with every free node at `temperature` and every
+    /// boundary at its set temperature, as a starting point for
+    /// [`step`](Self::step).
+    #[must_use]
+    pub fn uniform(&self, temperature: ThermodynamicTemperature) -> NetworkSolution {
+        let temperatures = self
+            .nodes
+            .iter()
+            .map(|node| match *node {
+                Node::Free { .. } => temperature,
+                Node::Boundary { temperature: set } => set,
+            })
+            .collect();
+        self.solution(temperatures)
+    }
+
+    /// Solves for the steady-state temperatures, ignoring heat capacities.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThermalNetworkError::Singular`] if some free nodes have no
+    /// path to a boundary.
+    pub fn steady_state(&self) -> Result<NetworkSolution, ThermalNetworkError> {
+        self.solve(|_| (0.0, 0.0))
+    }
+
+    /// Advances `previous` by one backward Euler step of length `dt`.
+    ///
+    /// Sources and boundary temperatures are those currently set, taken to
+    /// hold over the step.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ThermalNetworkError`] if the step is not strictly positive
+    /// and finite, `previous` has the wrong number of nodes, or some massless
+    /// nodes have no path to a boundary or a node with capacity.
+    pub fn step(
+        &self,
+        previous: &NetworkSolution,
+        dt: Time,
+    ) -> Result<NetworkSolution, ThermalNetworkError> {
+        if StrictlyPositive::check(&dt).is_err() || !dt.is_finite() {
+            return Err(ThermalNetworkError::InvalidValue(
+                "time step must be strictly positive and finite",
+            ));
+        }
+        if previous.temperatures.len() != self.nodes.len() {
+            return Err(ThermalNetworkError::MismatchedSolution {
+                expected: self.nodes.len(),
+                found: previous.temperatures.len(),
+            });
+        }
+
+        let dt = dt.get::<second>();
+        self.solve(|i| match self.nodes[i] {
+            Node::Free { capacitance, .. } => {
+                let c_over_dt = capacitance.get::<joule_per_kelvin>() / dt;
+                (
+                    c_over_dt,
+                    c_over_dt * previous.temperatures[i].get::<kelvin>(),
+                )
+            }
+            Node::Boundary { .. } => (0.0, 0.0),
+        })
+    }
+
+    /// Assembles and solves `(S + G)·T = s + q + G_b·T_b` over the free
+    /// nodes, where `storage(i)` gives node `i`'s diagonal term `S` and
+    /// right-hand term `s`.
+    fn solve(
+        &self,
+        storage: impl Fn(usize) -> (f64, f64),
+    ) -> Result<NetworkSolution, ThermalNetworkError> {
+        // Position of each free node among the unknowns.
+        let mut unknowns = vec![None; self.nodes.len()];
+        let mut count = 0;
+        for (i, node) in self.nodes.iter().enumerate() {
+            if matches!(node, Node::Free { .. }) {
+                unknowns[i] = Some(count);
+                count += 1;
+            }
+        }
+
+        let mut matrix = vec![vec![0.0; count]; count];
+        let mut sources = vec![0.0; count];
+        for (i, node) in self.nodes.iter().enumerate() {
+            if let (Some(row), Node::Free { heat, .. }) = (unknowns[i], node) {
+                let (diagonal, rhs) = storage(i);
+                matrix[row][row] += diagonal;
+                sources[row] += rhs + heat.get::<watt>();
+            }
+        }
+        for link in &self.links {
+            let conductance = link.conductance.get::<watt_per_kelvin>();
+            let [from, to] = link.nodes.map(|node| node.0);
+            for (this, other) in [(from, to), (to, from)] {
+                let Some(row) = unknowns[this] else { continue };
+                matrix[row][row] += conductance;
+                match (unknowns[other], self.nodes[other]) {
+                    (Some(col), _) => matrix[row][col] -= conductance,
+                    (None, Node::Boundary { temperature }) => {
+                        sources[row] += conductance * temperature.get::<kelvin>();
+                    }
+                    (None, Node::Free { .. }) => unreachable!("every free node is an unknown"),
+                }
+            }
+        }
+
+        let solved = if count == 0 {
+            Vec::new()
+        } else {
+            solve_linear(matrix, sources).ok_or(ThermalNetworkError::Singular)?
+        };
+        let temperatures = self
+            .nodes
+            .iter()
+            .zip(&unknowns)
+            .map(|(node, unknown)| match (node, unknown) {
+                (_, Some(index)) => ThermodynamicTemperature::new::<kelvin>(solved[*index]),
+                (Node::Boundary { temperature }, None) => *temperature,
+                (Node::Free { .. }, None) => unreachable!("every free node is an unknown"),
+            })
+            .collect();
+        Ok(self.solution(temperatures))
+    }
+
+    fn solution(&self, temperatures: Vec<ThermodynamicTemperature>) -> NetworkSolution {
+        let mut outflows = vec![Power::ZERO; self.nodes.len()];
+        let heat_flows = self
+            .links
+            .iter()
+            .map(|link| {
+                let [from, to] = link.nodes;
+                let flow = Power::new::<watt>(
+                    link.conductance.get::<watt_per_kelvin>()
+                        * (temperatures[from.0].get::<kelvin>()
+                            - temperatures[to.0].get::<kelvin>()),
+                );
+                outflows[from.0] += flow;
+                outflows[to.0] -= flow;
+                flow
+            })
+            .collect();
+        NetworkSolution {
+            temperatures,
+            heat_flows,
+            outflows,
+        }
+    }
+
+    fn node(&self, node: NodeId) -> Result<&Node, ThermalNetworkError> {
+        self.nodes
+            .get(node.0)
+            .ok_or(ThermalNetworkError::UnknownNode(node))
+    }
+
+    fn node_mut(&mut self, node: NodeId) -> Result<&mut Node, ThermalNetworkError> {
+        self.nodes
+            .get_mut(node.0)
+            .ok_or(ThermalNetworkError::UnknownNode(node))
+    }
+}
+
+fn check_temperature(temperature: ThermodynamicTemperature) -> Result<(), ThermalNetworkError> {
+    let t = temperature.get::<kelvin>();
+    if t > 0.0 && t.is_finite() {
+        Ok(())
+    } else {
+        Err(ThermalNetworkError::InvalidValue(
+            "temperature must be strictly positive and finite",
+        ))
+    }
+}
+
+fn check_conductance(conductance: ThermalConductance) -> Result<(), ThermalNetworkError> {
+    if NonNegative::check(&conductance).is_err() || !conductance.is_finite() {
+        return Err(ThermalNetworkError::InvalidValue(
+            "conductance must be non-negative and finite",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+
+    use crate::support::units::test_utils::celsius;
+
+    fn ua(g: f64) -> ThermalConductance {
+        ThermalConductance::new::<watt_per_kelvin>(g)
+    }
+
+    fn capacity(c: f64) -> HeatCapacity {
+        HeatCapacity::new::<joule_per_kelvin>(c)
+    }
+
+    #[test]
+    fn series_wall_between_two_boundaries() {
+        let mut network = ThermalNetwork::new();
+        let inside = network.add_boundary(celsius(20.0)).unwrap();
+        let outside = network.add_boundary(celsius(0.0)).unwrap();
+        let surface = network.add_node(HeatCapacity::ZERO).unwrap();
+        let to_surface = network.connect(inside, surface, ua(10.0)).unwrap();
+        let to_outside = network.connect(surface, outside, ua(30.0)).unwrap();
+
+        // 20 K over 0.1 + 1/30 K/W.
+        let solution = network.steady_state().unwrap();
+        assert_relative_eq!(
+            solution.heat_flow(to_surface).get::<watt>(),
+            150.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            solution.heat_flow(to_outside).get::<watt>(),
+            150.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            solution.temperature(surface).get::<degree_celsius>(),
+            5.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            solution.net_outflow(inside).get::<watt>(),
+            150.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            solution.net_outflow(outside).get::<watt>(),
+            -150.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn source_splits_between_parallel_paths() {
+        let mut network = ThermalNetwork::new();
+        let ambient = network.add_boundary(celsius(25.0)).unwrap();
+        let core = network.add_node(capacity(1000.0)).unwrap();
+        let shell = network.add_node(capacity(500.0)).unwrap();
+        let direct = network.connect(core, ambient, ua(1.0)).unwrap();
+        network.connect(core, shell, ua(4.0)).unwrap();
+        let through_shell = network.connect(shell, ambient, ua(4.0)).unwrap();
+        network.set_heat(core, Power::new::<watt>(60.0)).unwrap();
+
+        // The shell path is 0.5 K/W against 1 K/W direct.
+        let solution = network.steady_state().unwrap();
+        assert_relative_eq!(
+            solution.heat_flow(direct).get::<watt>(),
+            20.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            solution.heat_flow(through_shell).get::<watt>(),
+            40.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            solution.net_outflow(core).get::<watt>(),
+            60.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn single_node_relaxes_exponentially() {
+        let mut network = ThermalNetwork::new();
+        let ambient = network.add_boundary(celsius(20.0)).unwrap();
+        let mass = network.add_node(capacity(1000.0)).unwrap();
+        network.connect(mass, ambient, ua(10.0)).unwrap();
+
+        // τ = C/G = 100 s; step to t = τ with steps of τ/1000.
+        let mut state = network.uniform(celsius(80.0));
+        let dt = Time::new::<second>(0.1);
+        for _ in 0..1000 {
+            state = network.step(&state, dt).unwrap();
+        }
+        let expected = 20.0 + 60.0 * (-1.0_f64).exp();
+        assert_relative_eq!(
+            state.temperature(mass).get::<degree_celsius>(),
+            expected,
+            max_relative = 1e-3
+        );
+
+        // A very long step lands on the steady state.
+        let settled = network.step(&state, Time::new::<second>(1e9)).unwrap();
+        assert_relative_eq!(
+            settled.temperature(mass).get::<degree_celsius>(),
+            20.0,
+            epsilon = 1e-4
+        );
+    }
+
+    #[test]
+    fn transient_step_conserves_energy() {
+        let mut network = ThermalNetwork::new();
+        let coolant = network.add_boundary(celsius(30.0)).unwrap();
+        let cells = network.add_node(capacity(20_000.0)).unwrap();
+        let junction = network.add_node(HeatCapacity::ZERO).unwrap();
+        let plate = network.add_node(capacity(3_000.0)).unwrap();
+        network.connect(cells, junction, ua(50.0)).unwrap();
+        network.connect(junction, plate, ua(80.0)).unwrap();
+        network.connect(plate, coolant, ua(40.0)).unwrap();
+        network.set_heat(cells, Power::new::<watt>(500.0)).unwrap();
+
+        let before = network.uniform(celsius(30.0));
+        let dt = 10.0;
+        let after = network.step(&before, Time::new::<second>(dt)).unwrap();
+
+        let stored: f64 = [(cells, 20_000.0), (plate, 3_000.0)]
+            .iter()
+            .map(|&(node, c)| {
+                c * (after.temperature(node).get::<kelvin>()
+                    - before.temperature(node).get::<kelvin>())
+                    / dt
+            })
+            .sum();
+        let delivered = after.net_outflow(coolant).get::<watt>();
+        assert_relative_eq!(stored - delivered, 500.0, max_relative = 1e-9);
+
+        // The massless junction passes its heat straight through.
+        assert_relative_eq!(
+            after.net_outflow(junction).get::<watt>(),
+            0.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn floating_nodes_are_singular_at_steady_state() {
+        let mut network = ThermalNetwork::new();
+        let a = network.add_node(capacity(100.0)).unwrap();
+        let b = network.add_node(capacity(100.0)).unwrap();
+        network.connect(a, b, ua(1.0)).unwrap();
+
+        assert_eq!(network.steady_state(), Err(ThermalNetworkError::Singular));
+
+        // With capacity the transient is still well posed.
+        let state = network.uniform(celsius(50.0));
+        assert!(network.step(&state, Time::new::<second>(1.0)).is_ok());
+    }
+
+    #[test]
+    fn rejects_invalid_construction() {
+        let mut network = ThermalNetwork::new();
+        let boundary = network.add_boundary(celsius(20.0)).unwrap();
+        let node = network.add_node(capacity(10.0)).unwrap();
+
+        assert!(matches!(
+            network.add_node(capacity(-1.0)),
+            Err(ThermalNetworkError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            network.connect(node, node, ua(1.0)),
+            Err(ThermalNetworkError::InvalidValue(_))
+        ));
+        assert_eq!(
+            network.connect(node, NodeId(9), ua(1.0)),
+            Err(ThermalNetworkError::UnknownNode(NodeId(9)))
+        );
+        assert_eq!(
+            network.set_heat(boundary, Power::new::<watt>(1.0)),
+            Err(ThermalNetworkError::WrongNodeKind(boundary))
+        );
+        assert_eq!(
+            network.set_boundary(node, celsius(30.0)),
+            Err(ThermalNetworkError::WrongNodeKind(node))
+        );
+
+        let other = ThermalNetwork::new().uniform(celsius(20.0));
+        assert_eq!(
+            network.step(&other, Time::new::<second>(1.0)),
+            Err(ThermalNetworkError::MismatchedSolution {
+                expected: 2,
+                found: 0
+            })
+        );
+    }
+}
@@ -8,6 +8,8 @@ mod state;
 pub mod capability;
 pub mod fluid;
 pub mod model;
+#[cfg(feature = "std")]
+pub mod table;
 
 pub use error::PropertyError;
 pub use state::{State, StateDerivative};
@@ -1,7 +1,39 @@
 //! Capability traits used to query and construct thermodynamic states.
 //!
-//! All traits in this module have blanket impls for `&T`, so borrowed models
-//! satisfy the same bounds as owned ones.
+//! All traits in this module have blanket impls for `&T`, `Box<T>`, `Rc<T>`,
+//! and `Arc<T>`, so borrowed and shared models satisfy the same bounds as
+//! owned ones:
+//!
+//! ```
+//! use std::sync::Arc;
+//!
+//! use twine_models::support::thermo::{
+//!     capability::{HasPressure, StateFrom},
+//!     fluid::Air,
+//!     model::PerfectGas,
+//! };
+//! use uom::si::{
+//!     f64::{Pressure, ThermodynamicTemperature},
+//!     pressure::kilopascal,
+//!     thermodynamic_temperature::kelvin,
+//! };
+//!
+//! fn round_trip<T>(thermo: T) -> Pressure
+//! where
+//!     T: HasPressure<Fluid = Air> + StateFrom<(Air, ThermodynamicTemperature, Pressure)>,
+//! {
+//!     let t = ThermodynamicTemperature::new::<kelvin>(300.0);
+//!     let p = Pressure::new::<kilopascal>(200.0);
+//!     let state = thermo.state_from((Air, t, p)).unwrap();
+//!     thermo.pressure(&state).unwrap()
+//! }
+//!
+//! // One model shared by several components.
+//! let shared = Arc::new(PerfectGas::<Air>::new().unwrap());
+//! let p = round_trip(Arc::clone(&shared));
+//! assert!((p.get::<kilopascal>() - 200.0).abs() < 1e-9);
+//! assert_eq!(round_trip(&shared), p);
+//! ```
 
 mod base;
 mod properties;
@@ -1,3 +1,5 @@
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+
 pub trait ThermoModel {
     type Fluid;
 }
@@ -5,3 +7,15 @@ pub trait ThermoModel {
 impl<T: ThermoModel> ThermoModel for &T {
     type Fluid = T::Fluid;
 }
+
+impl<T: ThermoModel> ThermoModel for Box<T> {
+    type Fluid = T::Fluid;
+}
+
+impl<T: ThermoModel> ThermoModel for Rc<T> {
+    type Fluid = T::Fluid;
+}
+
+impl<T: ThermoModel> ThermoModel for Arc<T> {
+    type Fluid = T::Fluid;
+}
@@ -1,4 +1,9 @@
-use uom::si::f64::{Pressure, SpecificHeatCapacity};
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+
+use uom::si::f64::{
+    DynamicViscosity, MassDensity, Pressure, Ratio, SpecificHeatCapacity, ThermalConductivity,
+    Velocity,
+};
 
 use crate::support::thermo::{PropertyError, State};
 use crate::support::units::{SpecificEnthalpy, SpecificEntropy, SpecificInternalEnergy};
@@ -66,6 +71,67 @@ pub trait HasCv: ThermoModel {
     fn cv(&self, state: &State<Self::Fluid>) -> Result<SpecificHeatCapacity, PropertyError>;
 }
 
+pub trait HasSpeedOfSound: ThermoModel {
+    /// Returns the thermodynamic speed of sound for the given state.
+    ///
+    /// Models that treat the fluid as incompressible report an infinite
+    /// speed of sound.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PropertyError`] if the speed of sound cannot be calculated.
+    fn speed_of_sound(&self, state: &State<Self::Fluid>) -> Result<Velocity, PropertyError>;
+}
+
+pub trait HasViscosity: ThermoModel {
+    /// Returns the dynamic viscosity for the given state.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PropertyError`] if the viscosity cannot be calculated,
+    /// including models that were not given transport properties.
+    fn viscosity(&self, state: &State<Self::Fluid>) -> Result<DynamicViscosity, PropertyError>;
+}
+
+pub trait HasThermalConductivity: ThermoModel {
+    /// Returns the thermal conductivity for the given state.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PropertyError`] if the thermal conductivity cannot be
+    /// calculated, including models that were not given transport
+    /// properties.
+    fn thermal_conductivity(
+        &self,
+        state: &State<Self::Fluid>,
+    ) -> Result<ThermalConductivity, PropertyError>;
+}
+
+/// Phase split of a state inside the vapor dome.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwoPhase {
+    /// Vapor mass fraction, from zero (saturated liquid) to one (saturated vapor).
+    pub quality: Ratio,
+
+    /// Density of the saturated liquid phase.
+    pub liquid_density: MassDensity,
+
+    /// Density of the saturated vapor phase.
+    pub vapor_density: MassDensity,
+}
+
+pub trait HasTwoPhase: ThermoModel {
+    /// Returns the phase split for the given state.
+    ///
+    /// Returns `None` if the state is single-phase, including models that
+    /// never form two phases.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PropertyError`] if the phase split cannot be calculated.
+    fn two_phase(&self, state: &State<Self::Fluid>) -> Result<Option<TwoPhase>, PropertyError>;
+}
+
 impl<T: HasPressure> HasPressure for &T {
     fn pressure(&self, state: &State<Self::Fluid>) -> Result<Pressure, PropertyError> {
         T::pressure(self, state)
@@ -104,3 +170,123 @@ impl<T: HasCv> HasCv for &T {
         T::cv(self, state)
     }
 }
+
+impl<T: HasSpeedOfSound> HasSpeedOfSound for &T {
+    fn speed_of_sound(&self, state: &State<Self::Fluid>) -> Result<Velocity, PropertyError> {
+        T::speed_of_sound(self, state)
+    }
+}
+
+impl<T: HasViscosity> HasViscosity for &T {
+    fn viscosity(&self, state: &State<Self::Fluid>) -> Result<DynamicViscosity, PropertyError> {
+        T::viscosity(self, state)
+    }
+}
+
+impl<T: HasThermalConductivity> HasThermalConductivity for &T {
+    fn thermal_conductivity(
+        &self,
+        state: &State<Self::Fluid>,
+    ) -> Result<ThermalConductivity, PropertyError> {
+        T::thermal_conductivity(self, state)
+    }
+}
+
+impl<T: HasTwoPhase> HasTwoPhase for &T {
+    fn two_phase(&self, state: &State<Self::Fluid>) -> Result<Option<TwoPhase>, PropertyError> {
+        T::two_phase(self, state)
+    }
+}
+
+/// Forwards every property capability through owning smart pointers, so a
+/// model shared as `Arc<T>` (or held as `Box<T>` or `Rc<T>`) satisfies the
+/// same bounds as `T`.
+macro_rules! impl_for_pointers {
+    ($($pointer:ident),*) => {$(
+        impl<T: HasPressure> HasPressure for $pointer<T> {
+            fn pressure(&self, state: &State<Self::Fluid>) -> Result<Pressure, PropertyError> {
+                T::pressure(self, state)
+            }
+        }
+
+        impl<T: HasInternalEnergy> HasInternalEnergy for $pointer<T> {
+            fn internal_energy(
+                &self,
+                state: &State<Self::Fluid>,
+            ) -> Result<SpecificInternalEnergy, PropertyError> {
+                T::internal_energy(self, state)
+            }
+        }
+
+        impl<T: HasEnthalpy> HasEnthalpy for $pointer<T> {
+            fn enthalpy(
+                &self,
+                state: &State<Self::Fluid>,
+            ) -> Result<SpecificEnthalpy, PropertyError> {
+                T::enthalpy(self, state)
+            }
+        }
+
+        impl<T: HasEntropy> HasEntropy for $pointer<T> {
+            fn entropy(&self, state: &State<Self::Fluid>) -> Result<SpecificEntropy, PropertyError> {
+                T::entropy(self, state)
+            }
+        }
+
+        impl<T: HasCp> HasCp for $pointer<T> {
+            fn cp(
+                &self,
+                state: &State<Self::Fluid>,
+            ) -> Result<SpecificHeatCapacity, PropertyError> {
+                T::cp(self, state)
+            }
+        }
+
+        impl<T: HasCv> HasCv for $pointer<T> {
+            fn cv(
+                &self,
+                state: &State<Self::Fluid>,
+            ) -> Result<SpecificHeatCapacity, PropertyError> {
+                T::cv(self, state)
+            }
+        }
+
+        impl<T: HasSpeedOfSound> HasSpeedOfSound for $pointer<T> {
+            fn speed_of_sound(
+                &self,
+                state: &State<Self::Fluid>,
+            ) -> Result<Velocity, PropertyError> {
+                T::speed_of_sound(self, state)
+            }
+        }
+
+        impl<T: HasViscosity> HasViscosity for $pointer<T> {
+            fn viscosity(
+                &self,
+                state: &State<Self::Fluid>,
+            ) -> Result<DynamicViscosity, PropertyError> {
+                T::viscosity(self, state)
+            }
+        }
+
+        impl<T: HasThermalConductivity> HasThermalConductivity for $pointer<T> {
+            fn thermal_conductivity(
+                &self,
+                state: &State<Self::Fluid>,
+            ) -> Result<ThermalConductivity, PropertyError> {
+                T::thermal_conductivity(self, state)
+            }
+        }
+
+        impl<T: HasTwoPhase> HasTwoPhase for $pointer<T> {
+            fn two_phase(
+                &self,
+                state: &State<Self::Fluid>,
+            ) -> Result<Option<TwoPhase>, PropertyError> {
+                T::two_phase(self, state)
+            }
+        }
+    )*};
+}
+
+impl_for_pointers!(Box, Rc, Arc);
@@ -1,3 +1,5 @@
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+
 use crate::support::thermo::State;
 
 use super::ThermoModel;
@@ -21,10 +23,15 @@ use super::ThermoModel;
 /// - `(Fluid, Pressure, MassDensity)` (pressure + density)
 /// - `(Fluid, Pressure, SpecificEnthalpy)` (pressure + enthalpy)
 /// - `(Fluid, Pressure, SpecificEntropy)` (pressure + entropy)
+/// - `(Fluid, MassDensity, SpecificInternalEnergy)` (density + internal energy)
+/// - `(Fluid, ThermodynamicTemperature, Constrained<Ratio, UnitInterval>)`
+///   (saturation temperature + vapor quality)
+/// - `(Fluid, Pressure, Constrained<Ratio, UnitInterval>)`
+///   (saturation pressure + vapor quality)
 /// - `(Fluid, ThermodynamicTemperature)` (e.g. for an incompressible liquid)
 ///
 pub trait StateFrom<Input>: ThermoModel {
-    type Error: std::error::Error + Send + Sync + 'static;
+    type Error: core::error::Error + Send + Sync + 'static;
 
     /// Create a thermodynamic state from the provided input.
     ///
@@ -49,3 +56,25 @@ where
         T::state_from(self, input)
     }
 }
+
+/// Blanket impls for owning smart pointers.
+///
+/// Long-lived simulations often share one model across many components
+/// through `Arc<T>`; these impls let the shared handle be passed wherever a
+/// `StateFrom` bound is required.
+macro_rules! impl_for_pointers {
+    ($($pointer:ident),*) => {$(
+        impl<T, Input> StateFrom<Input> for $pointer<T>
+        where
+            T: StateFrom<Input>,
+        {
+            type Error = T::Error;
+
+            fn state_from(&self, input: Input) -> Result<State<Self::Fluid>, Self::Error> {
+                T::state_from(self, input)
+            }
+        }
+    )*};
+}
+
+impl_for_pointers!(Box, Rc, Arc);
@@ -6,11 +6,31 @@
 //! for external property libraries like [`CoolProp`](crate::model::CoolProp)).
 //!
 //! Some fluids are simple unit-like types, while others carry state-defining data.
+//!
+//! Heat transfer liquids for concentrating solar power loops ([`SolarSalt`],
+//! [`Hitec`], [`TherminolVp1`], and [`Therminol66`]) carry their own
+//! temperature-dependent property correlations, so they can be modeled
+//! without an external property library.
+//!
+//! [`MoistAir`] carries its humidity ratio, so the `HumidAir` model can
+//! describe air-side streams that gain or lose water.
 
 mod air;
+mod ammonia;
 mod carbon_dioxide;
+mod hitec;
+mod moist_air;
+mod solar_salt;
+mod therminol_66;
+mod therminol_vp1;
 mod water;
 
 pub use air::Air;
+pub use ammonia::Ammonia;
 pub use carbon_dioxide::CarbonDioxide;
+pub use hitec::Hitec;
+pub use moist_air::MoistAir;
+pub use solar_salt::SolarSalt;
+pub use therminol_66::Therminol66;
+pub use therminol_vp1::TherminolVp1;
 pub use water::Water;
@@ -1,10 +1,19 @@
-use twine_core::StepIntegrable;
 use uom::si::{
-    f64::{SpecificHeatCapacity, Time},
+    dynamic_viscosity::millipascal_second,
+    f64::{DynamicViscosity, SpecificHeatCapacity, ThermalConductivity},
     specific_heat_capacity::joule_per_kilogram_kelvin,
+    thermal_conductivity::watt_per_meter_kelvin,
 };
 
-use crate::support::thermo::model::perfect_gas::{PerfectGasFluid, PerfectGasParameters};
+#[cfg(feature = "std")]
+use twine_core::StepIntegrable;
+#[cfg(feature = "std")]
+use uom::si::f64::Time;
+
+use crate::support::thermo::model::{
+    perfect_gas::{PerfectGasFluid, PerfectGasParameters},
+    transport::TransportProperties,
+};
 use crate::support::units::SpecificGasConstant;
 
 /// Canonical identifier for dry air.
@@ -17,9 +26,15 @@ impl PerfectGasFluid for Air {
             SpecificGasConstant::new::<joule_per_kilogram_kelvin>(287.053),
             SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(1005.0),
         )
+        // Transport properties at 25 °C and 1 atm.
+        .with_transport(TransportProperties::new(
+            DynamicViscosity::new::<millipascal_second>(0.01849),
+            ThermalConductivity::new::<watt_per_meter_kelvin>(0.0259),
+        ))
     }
 }
 
+#[cfg(feature = "std")]
 impl StepIntegrable<Time> for Air {
     type Derivative = ();
 
@@ -0,0 +1,26 @@
+#[cfg(feature = "std")]
+use twine_core::StepIntegrable;
+#[cfg(feature = "std")]
+use uom::si::f64::Time;
+
+#[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
+use crate::support::thermo::model::coolprop::CoolPropFluid;
+
+/// Canonical identifier for ammonia (R-717).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Ammonia;
+
+#[cfg(feature = "std")]
+impl StepIntegrable<Time> for Ammonia {
+    type Derivative = ();
+
+    fn step(&self, (): (), _: Time) -> Self {
+        *self
+    }
+}
+
+#[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
+impl CoolPropFluid for Ammonia {
+    const BACKEND: &'static str = "HEOS";
+    const NAME: &'static str = "Ammonia";
+}
@@ -1,11 +1,20 @@
-use twine_core::StepIntegrable;
 use uom::si::{
-    f64::{SpecificHeatCapacity, Time},
+    dynamic_viscosity::millipascal_second,
+    f64::{DynamicViscosity, SpecificHeatCapacity, ThermalConductivity},
     specific_heat_capacity::joule_per_kilogram_kelvin,
+    thermal_conductivity::watt_per_meter_kelvin,
 };
 
+#[cfg(feature = "std")]
+use twine_core::StepIntegrable;
+#[cfg(feature = "std")]
+use uom::si::f64::Time;
+
 use crate::support::{
-    thermo::model::perfect_gas::{PerfectGasFluid, PerfectGasParameters},
+    thermo::model::{
+        perfect_gas::{PerfectGasFluid, PerfectGasParameters},
+        transport::TransportProperties,
+    },
     units::SpecificGasConstant,
 };
 
@@ -22,9 +31,15 @@ impl PerfectGasFluid for CarbonDioxide {
             SpecificGasConstant::new::<joule_per_kilogram_kelvin>(188.92),
             SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(844.0),
         )
+        // Transport properties at 25 °C and 1 atm.
+        .with_transport(TransportProperties::new(
+            DynamicViscosity::new::<millipascal_second>(0.01493),
+            ThermalConductivity::new::<watt_per_meter_kelvin>(0.01662),
+        ))
     }
 }
 
+#[cfg(feature = "std")]
 impl StepIntegrable<Time> for CarbonDioxide {
     type Derivative = ();
 
@@ -0,0 +1,122 @@
+use core::ops::RangeInclusive;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float as _;
+use uom::si::{
+    dynamic_viscosity::millipascal_second,
+    f64::{
+        DynamicViscosity, MassDensity, SpecificHeatCapacity, ThermalConductivity,
+        ThermodynamicTemperature,
+    },
+    mass_density::kilogram_per_cubic_meter,
+    specific_heat_capacity::joule_per_kilogram_kelvin,
+    thermal_conductivity::watt_per_meter_kelvin,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+#[cfg(feature = "std")]
+use twine_core::StepIntegrable;
+#[cfg(feature = "std")]
+use uom::si::f64::Time;
+
+use crate::support::thermo::model::{
+    incompressible::{IncompressibleFluid, IncompressibleParameters},
+    transport::TransportProperties,
+};
+
+/// Canonical identifier for Hitec, a ternary salt of 53 % `KNO₃`, 40 % `NaNO₂`,
+/// and 7 % `NaNO₃` by mass.
+///
+/// Density is the supplier's linear fit and specific heat is its constant
+/// value. Viscosity follows an Arrhenius fit to the supplier's tabulated
+/// data. Thermal conductivity is nearly flat over the liquid range and is
+/// taken as constant. The fits hold over
+/// [`temperature_range`](Self::temperature_range), from just above
+/// freezing to the rated maximum, and are extrapolated outside it.
+///
+/// The [`Incompressible`](crate::support::thermo::model::Incompressible)
+/// model uses constant properties evaluated at 300 °C.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Hitec;
+
+impl Hitec {
+    /// Temperatures over which the correlations were fitted.
+    #[must_use]
+    pub fn temperature_range() -> RangeInclusive<ThermodynamicTemperature> {
+        ThermodynamicTemperature::new::<degree_celsius>(150.0)
+            ..=ThermodynamicTemperature::new::<degree_celsius>(535.0)
+    }
+
+    /// Density, `ρ = 2080 − 0.733·T` kg/m³ with `T` in °C.
+    #[must_use]
+    pub fn density(temperature: ThermodynamicTemperature) -> MassDensity {
+        let t = temperature.get::<degree_celsius>();
+        MassDensity::new::<kilogram_per_cubic_meter>(2080.0 - 0.733 * t)
+    }
+
+    /// Specific heat, a constant 1560 J/(kg·K).
+    #[must_use]
+    pub fn specific_heat(_temperature: ThermodynamicTemperature) -> SpecificHeatCapacity {
+        SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(1560.0)
+    }
+
+    /// Dynamic viscosity, `μ = 0.0916·exp(2051 / T)` mPa·s with `T` in K.
+    #[must_use]
+    pub fn viscosity(temperature: ThermodynamicTemperature) -> DynamicViscosity {
+        let t = temperature.get::<kelvin>();
+        DynamicViscosity::new::<millipascal_second>(0.0916 * (2051.0 / t).exp())
+    }
+
+    /// Thermal conductivity, a constant 0.48 W/(m·K).
+    #[must_use]
+    pub fn thermal_conductivity(_temperature: ThermodynamicTemperature) -> ThermalConductivity {
+        ThermalConductivity::new::<watt_per_meter_kelvin>(0.48)
+    }
+}
+
+impl IncompressibleFluid for Hitec {
+    fn parameters() -> IncompressibleParameters {
+        let design = ThermodynamicTemperature::new::<degree_celsius>(300.0);
+        IncompressibleParameters::new(Self::specific_heat(design), Self::density(design))
+            .with_transport(TransportProperties::new(
+                Self::viscosity(design),
+                Self::thermal_conductivity(design),
+            ))
+    }
+}
+
+#[cfg(feature = "std")]
+impl StepIntegrable<Time> for Hitec {
+    type Derivative = ();
+
+    fn step(&self, (): (), _: Time) -> Self {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use crate::support::units::test_utils::celsius;
+
+    #[test]
+    fn matches_published_values() {
+        assert_relative_eq!(
+            Hitec::density(celsius(300.0)).get::<kilogram_per_cubic_meter>(),
+            1860.1,
+            epsilon = 0.1
+        );
+
+        // Tabulated viscosities, within a few percent.
+        for (t, mu) in [(200.0, 7.0), (300.0, 3.3), (500.0, 1.3)] {
+            assert_relative_eq!(
+                Hitec::viscosity(celsius(t)).get::<millipascal_second>(),
+                mu,
+                max_relative = 0.03
+            );
+        }
+    }
+}
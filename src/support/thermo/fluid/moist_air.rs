@@ -0,0 +1,28 @@
+use uom::si::{f64::Ratio, ratio::ratio};
+
+/// Canonical identifier for moist air: dry air carrying water vapor.
+///
+/// Unlike the pure fluids, moist air carries its composition as the humidity
+/// ratio, the mass of water vapor per mass of dry air. The default is dry
+/// air.
+///
+/// This is the fluid of the `HumidAir` thermo model. For psychrometric chart
+/// work on a per-dry-air basis, see `support::psychrometrics::MoistAir`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoistAir {
+    pub humidity_ratio: Ratio,
+}
+
+impl MoistAir {
+    /// Creates moist air with the given humidity ratio.
+    #[must_use]
+    pub fn new(humidity_ratio: Ratio) -> Self {
+        Self { humidity_ratio }
+    }
+}
+
+impl Default for MoistAir {
+    fn default() -> Self {
+        Self::new(Ratio::new::<ratio>(0.0))
+    }
+}
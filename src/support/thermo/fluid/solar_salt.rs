@@ -0,0 +1,195 @@
+use core::ops::RangeInclusive;
+
+use uom::si::{
+    dynamic_viscosity::millipascal_second,
+    f64::{
+        DynamicViscosity, MassDensity, SpecificHeatCapacity, ThermalConductivity,
+        ThermodynamicTemperature,
+    },
+    mass_density::kilogram_per_cubic_meter,
+    specific_heat_capacity::joule_per_kilogram_kelvin,
+    thermal_conductivity::watt_per_meter_kelvin,
+    thermodynamic_temperature::degree_celsius,
+};
+
+#[cfg(feature = "std")]
+use twine_core::StepIntegrable;
+#[cfg(feature = "std")]
+use uom::si::f64::Time;
+
+use crate::support::thermo::model::{
+    incompressible::{IncompressibleFluid, IncompressibleParameters},
+    polynomial_incompressible::{
+        Polynomial, PolynomialIncompressibleFluid, PolynomialIncompressibleParameters, ViscosityFit,
+    },
+    transport::TransportProperties,
+};
+
+/// Canonical identifier for solar salt, 60 % `NaNO₃` and 40 % `KNO₃` by mass.
+///
+/// The correlations are the fits published with the Solar Two plant data
+/// (Zavoico, SAND2001-2100). They hold over
+/// [`temperature_range`](Self::temperature_range), from just above freezing
+/// to the onset of nitrite formation, and are extrapolated outside it.
+///
+/// The [`Incompressible`](crate::support::thermo::model::Incompressible)
+/// model uses constant properties evaluated at 450 °C, midway between
+/// typical cold- and hot-tank temperatures. The
+/// [`PolynomialIncompressible`](crate::support::thermo::model::PolynomialIncompressible)
+/// model uses the full correlations, limited to the fitted range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SolarSalt;
+
+impl SolarSalt {
+    /// Temperatures over which the correlations were fitted.
+    #[must_use]
+    pub fn temperature_range() -> RangeInclusive<ThermodynamicTemperature> {
+        ThermodynamicTemperature::new::<degree_celsius>(260.0)
+            ..=ThermodynamicTemperature::new::<degree_celsius>(600.0)
+    }
+
+    /// Density, `ρ = 2090 − 0.636·T` kg/m³ with `T` in °C.
+    #[must_use]
+    pub fn density(temperature: ThermodynamicTemperature) -> MassDensity {
+        let t = temperature.get::<degree_celsius>();
+        MassDensity::new::<kilogram_per_cubic_meter>(2090.0 - 0.636 * t)
+    }
+
+    /// Specific heat, `cp = 1443 + 0.172·T` J/(kg·K) with `T` in °C.
+    #[must_use]
+    pub fn specific_heat(temperature: ThermodynamicTemperature) -> SpecificHeatCapacity {
+        let t = temperature.get::<degree_celsius>();
+        SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(1443.0 + 0.172 * t)
+    }
+
+    /// Dynamic viscosity, a cubic in `T` (°C) giving mPa·s.
+    #[must_use]
+    pub fn viscosity(temperature: ThermodynamicTemperature) -> DynamicViscosity {
+        let t = temperature.get::<degree_celsius>();
+        let mu = 22.714 + t * (-0.120 + t * (2.281e-4 - t * 1.474e-7));
+        DynamicViscosity::new::<millipascal_second>(mu)
+    }
+
+    /// Thermal conductivity, `k = 0.443 + 1.9e-4·T` W/(m·K) with `T` in °C.
+    #[must_use]
+    pub fn thermal_conductivity(temperature: ThermodynamicTemperature) -> ThermalConductivity {
+        let t = temperature.get::<degree_celsius>();
+        ThermalConductivity::new::<watt_per_meter_kelvin>(0.443 + 1.9e-4 * t)
+    }
+}
+
+impl IncompressibleFluid for SolarSalt {
+    fn parameters() -> IncompressibleParameters {
+        let design = ThermodynamicTemperature::new::<degree_celsius>(450.0);
+        IncompressibleParameters::new(Self::specific_heat(design), Self::density(design))
+            .with_transport(TransportProperties::new(
+                Self::viscosity(design),
+                Self::thermal_conductivity(design),
+            ))
+    }
+}
+
+impl PolynomialIncompressibleFluid for SolarSalt {
+    fn parameters() -> PolynomialIncompressibleParameters {
+        // The correlations are in °C, so a 0 °C base keeps their coefficients.
+        PolynomialIncompressibleParameters::new(
+            ThermodynamicTemperature::new::<degree_celsius>(0.0),
+            Polynomial::new([1443.0, 0.172]),
+            Polynomial::new([2090.0, -0.636]),
+        )
+        .with_transport(
+            ViscosityFit::Polynomial(Polynomial::new([
+                22.714e-3, -0.120e-3, 2.281e-7, -1.474e-10,
+            ])),
+            Polynomial::new([0.443, 1.9e-4]),
+        )
+        .with_temperature_range(Self::temperature_range())
+    }
+}
+
+#[cfg(feature = "std")]
+impl StepIntegrable<Time> for SolarSalt {
+    type Derivative = ();
+
+    fn step(&self, (): (), _: Time) -> Self {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use crate::support::units::test_utils::celsius;
+
+    #[test]
+    fn matches_published_values() {
+        // Tabulated values at the cold- and hot-tank temperatures.
+        assert_relative_eq!(
+            SolarSalt::density(celsius(290.0)).get::<kilogram_per_cubic_meter>(),
+            1905.6,
+            epsilon = 0.1
+        );
+        assert_relative_eq!(
+            SolarSalt::density(celsius(565.0)).get::<kilogram_per_cubic_meter>(),
+            1730.7,
+            epsilon = 0.1
+        );
+        assert_relative_eq!(
+            SolarSalt::specific_heat(celsius(565.0)).get::<joule_per_kilogram_kelvin>(),
+            1540.2,
+            epsilon = 0.1
+        );
+        assert_relative_eq!(
+            SolarSalt::viscosity(celsius(290.0)).get::<millipascal_second>(),
+            3.50,
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            SolarSalt::viscosity(celsius(565.0)).get::<millipascal_second>(),
+            1.14,
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            SolarSalt::thermal_conductivity(celsius(565.0)).get::<watt_per_meter_kelvin>(),
+            0.550,
+            epsilon = 1e-3
+        );
+    }
+
+    #[test]
+    fn polynomial_model_matches_correlations() {
+        use crate::support::thermo::{
+            capability::{HasCp, HasThermalConductivity, HasViscosity, StateFrom},
+            model::PolynomialIncompressible,
+        };
+
+        let salt = PolynomialIncompressible::<SolarSalt>::new().unwrap();
+        for t in [290.0, 450.0, 565.0] {
+            let state = salt.state_from(celsius(t)).unwrap();
+            assert_relative_eq!(
+                state.density.value,
+                SolarSalt::density(celsius(t)).value,
+                max_relative = 1e-12
+            );
+            assert_relative_eq!(
+                salt.cp(&state).unwrap().value,
+                SolarSalt::specific_heat(celsius(t)).value,
+                max_relative = 1e-12
+            );
+            assert_relative_eq!(
+                salt.viscosity(&state).unwrap().value,
+                SolarSalt::viscosity(celsius(t)).value,
+                max_relative = 1e-12
+            );
+            assert_relative_eq!(
+                salt.thermal_conductivity(&state).unwrap().value,
+                SolarSalt::thermal_conductivity(celsius(t)).value,
+                max_relative = 1e-12
+            );
+        }
+        assert!(salt.state_from(celsius(200.0)).is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use core::ops::RangeInclusive;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float as _;
+use uom::si::{
+    diffusion_coefficient::square_millimeter_per_second,
+    f64::{
+        DiffusionCoefficient, DynamicViscosity, MassDensity, SpecificHeatCapacity,
+        ThermalConductivity, ThermodynamicTemperature,
+    },
+    mass_density::kilogram_per_cubic_meter,
+    specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+    thermal_conductivity::watt_per_meter_kelvin,
+    thermodynamic_temperature::degree_celsius,
+};
+
+#[cfg(feature = "std")]
+use twine_core::StepIntegrable;
+#[cfg(feature = "std")]
+use uom::si::f64::Time;
+
+use crate::support::thermo::model::{
+    incompressible::{IncompressibleFluid, IncompressibleParameters},
+    transport::TransportProperties,
+};
+
+/// Canonical identifier for Therminol 66, a modified terphenyl heat transfer
+/// oil for unpressurized loops.
+///
+/// The correlations are the supplier's liquid-phase fits, valid over
+/// [`temperature_range`](Self::temperature_range).
+///
+/// The [`Incompressible`](crate::support::thermo::model::Incompressible)
+/// model uses constant properties evaluated at 250 °C.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Therminol66;
+
+impl Therminol66 {
+    /// Temperatures over which the correlations were fitted.
+    #[must_use]
+    pub fn temperature_range() -> RangeInclusive<ThermodynamicTemperature> {
+        ThermodynamicTemperature::new::<degree_celsius>(0.0)
+            ..=ThermodynamicTemperature::new::<degree_celsius>(345.0)
+    }
+
+    /// Density, a quadratic in `T` (°C) giving kg/m³.
+    #[must_use]
+    pub fn density(temperature: ThermodynamicTemperature) -> MassDensity {
+        let t = temperature.get::<degree_celsius>();
+        let rho = 1020.62 - t * (0.614_254 + t * 3.21e-4);
+        MassDensity::new::<kilogram_per_cubic_meter>(rho)
+    }
+
+    /// Specific heat, a quadratic in `T` (°C) giving kJ/(kg·K).
+    #[must_use]
+    pub fn specific_heat(temperature: ThermodynamicTemperature) -> SpecificHeatCapacity {
+        let t = temperature.get::<degree_celsius>();
+        let cp = 1.496_005 + t * (3.313e-3 + t * 8.970_785e-7);
+        SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(cp)
+    }
+
+    /// Dynamic viscosity, from the kinematic viscosity fit
+    /// `ν = exp(586.375 / (T + 62.5) − 2.2809)` mm²/s with `T` in °C.
+    #[must_use]
+    pub fn viscosity(temperature: ThermodynamicTemperature) -> DynamicViscosity {
+        let t = temperature.get::<degree_celsius>();
+        let nu = (586.375 / (t + 62.5) - 2.2809).exp();
+        Self::density(temperature) * DiffusionCoefficient::new::<square_millimeter_per_second>(nu)
+    }
+
+    /// Thermal conductivity, a quadratic in `T` (°C) giving W/(m·K).
+    #[must_use]
+    pub fn thermal_conductivity(temperature: ThermodynamicTemperature) -> ThermalConductivity {
+        let t = temperature.get::<degree_celsius>();
+        let k = 0.118_294 - t * (3.3e-5 + t * 1.5e-7);
+        ThermalConductivity::new::<watt_per_meter_kelvin>(k)
+    }
+}
+
+impl IncompressibleFluid for Therminol66 {
+    fn parameters() -> IncompressibleParameters {
+        let design = ThermodynamicTemperature::new::<degree_celsius>(250.0);
+        IncompressibleParameters::new(Self::specific_heat(design), Self::density(design))
+            .with_transport(TransportProperties::new(
+                Self::viscosity(design),
+                Self::thermal_conductivity(design),
+            ))
+    }
+}
+
+#[cfg(feature = "std")]
+impl StepIntegrable<Time> for Therminol66 {
+    type Derivative = ();
+
+    fn step(&self, (): (), _: Time) -> Self {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::dynamic_viscosity::millipascal_second;
+
+    use crate::support::units::test_utils::celsius;
+
+    #[test]
+    fn matches_published_values() {
+        // Supplier table at 100, 200, and 300 °C.
+        for (t, rho, cp, k) in [
+            (100.0, 955.0, 1.84, 0.1135),
+            (200.0, 885.0, 2.19, 0.1057),
+            (300.0, 808.0, 2.57, 0.0949),
+        ] {
+            let t = celsius(t);
+            assert_relative_eq!(
+                Therminol66::density(t).get::<kilogram_per_cubic_meter>(),
+                rho,
+                max_relative = 0.005
+            );
+            assert_relative_eq!(
+                Therminol66::specific_heat(t).get::<kilojoule_per_kilogram_kelvin>(),
+                cp,
+                max_relative = 0.005
+            );
+            assert_relative_eq!(
+                Therminol66::thermal_conductivity(t).get::<watt_per_meter_kelvin>(),
+                k,
+                max_relative = 0.005
+            );
+        }
+        assert_relative_eq!(
+            Therminol66::viscosity(celsius(300.0)).get::<millipascal_second>(),
+            0.416,
+            max_relative = 0.01
+        );
+    }
+}
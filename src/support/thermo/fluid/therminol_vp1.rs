@@ -0,0 +1,142 @@
+use core::ops::RangeInclusive;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float as _;
+use uom::si::{
+    diffusion_coefficient::square_millimeter_per_second,
+    f64::{
+        DiffusionCoefficient, DynamicViscosity, MassDensity, SpecificHeatCapacity,
+        ThermalConductivity, ThermodynamicTemperature,
+    },
+    mass_density::kilogram_per_cubic_meter,
+    specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+    thermal_conductivity::watt_per_meter_kelvin,
+    thermodynamic_temperature::degree_celsius,
+};
+
+#[cfg(feature = "std")]
+use twine_core::StepIntegrable;
+#[cfg(feature = "std")]
+use uom::si::f64::Time;
+
+use crate::support::thermo::model::{
+    incompressible::{IncompressibleFluid, IncompressibleParameters},
+    transport::TransportProperties,
+};
+
+/// Canonical identifier for Therminol VP-1, the eutectic of biphenyl and
+/// diphenyl oxide used in most parabolic-trough plants.
+///
+/// The correlations are the supplier's liquid-phase fits, valid over
+/// [`temperature_range`](Self::temperature_range). Above the 257 °C normal
+/// boiling point the liquid must be kept pressurized.
+///
+/// The [`Incompressible`](crate::support::thermo::model::Incompressible)
+/// model uses constant properties evaluated at 300 °C.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TherminolVp1;
+
+impl TherminolVp1 {
+    /// Temperatures over which the correlations were fitted.
+    #[must_use]
+    pub fn temperature_range() -> RangeInclusive<ThermodynamicTemperature> {
+        ThermodynamicTemperature::new::<degree_celsius>(12.0)
+            ..=ThermodynamicTemperature::new::<degree_celsius>(400.0)
+    }
+
+    /// Density, a cubic in `T` (°C) giving kg/m³.
+    #[must_use]
+    pub fn density(temperature: ThermodynamicTemperature) -> MassDensity {
+        let t = temperature.get::<degree_celsius>();
+        let rho = 1083.25 + t * (-0.907_97 + t * (7.8116e-4 - t * 2.367e-6));
+        MassDensity::new::<kilogram_per_cubic_meter>(rho)
+    }
+
+    /// Specific heat, a quartic in `T` (°C) giving kJ/(kg·K).
+    #[must_use]
+    pub fn specific_heat(temperature: ThermodynamicTemperature) -> SpecificHeatCapacity {
+        let t = temperature.get::<degree_celsius>();
+        let cp = 1.498 + t * (2.414e-3 + t * (5.9591e-6 + t * (-2.9879e-8 + t * 4.4172e-11)));
+        SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(cp)
+    }
+
+    /// Dynamic viscosity, from the kinematic viscosity fit
+    /// `ν = exp(544.149 / (T + 114.43) − 2.59578)` mm²/s with `T` in °C.
+    #[must_use]
+    pub fn viscosity(temperature: ThermodynamicTemperature) -> DynamicViscosity {
+        let t = temperature.get::<degree_celsius>();
+        let nu = (544.149 / (t + 114.43) - 2.595_78).exp();
+        Self::density(temperature) * DiffusionCoefficient::new::<square_millimeter_per_second>(nu)
+    }
+
+    /// Thermal conductivity, a quartic in `T` (°C) giving W/(m·K).
+    #[must_use]
+    pub fn thermal_conductivity(temperature: ThermodynamicTemperature) -> ThermalConductivity {
+        let t = temperature.get::<degree_celsius>();
+        let k =
+            0.137_743 + t * (-8.19477e-5 + t * (-1.92257e-7 + t * (2.5034e-11 - t * 7.2974e-15)));
+        ThermalConductivity::new::<watt_per_meter_kelvin>(k)
+    }
+}
+
+impl IncompressibleFluid for TherminolVp1 {
+    fn parameters() -> IncompressibleParameters {
+        let design = ThermodynamicTemperature::new::<degree_celsius>(300.0);
+        IncompressibleParameters::new(Self::specific_heat(design), Self::density(design))
+            .with_transport(TransportProperties::new(
+                Self::viscosity(design),
+                Self::thermal_conductivity(design),
+            ))
+    }
+}
+
+#[cfg(feature = "std")]
+impl StepIntegrable<Time> for TherminolVp1 {
+    type Derivative = ();
+
+    fn step(&self, (): (), _: Time) -> Self {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::dynamic_viscosity::millipascal_second;
+
+    use crate::support::units::test_utils::celsius;
+
+    #[test]
+    fn matches_published_values() {
+        // Supplier table at 25, 300, and 400 °C.
+        for (t, rho, cp, k) in [
+            (25.0, 1060.0, 1.56, 0.1356),
+            (300.0, 815.0, 2.32, 0.0965),
+            (400.0, 694.0, 2.63, 0.0757),
+        ] {
+            let t = celsius(t);
+            assert_relative_eq!(
+                TherminolVp1::density(t).get::<kilogram_per_cubic_meter>(),
+                rho,
+                max_relative = 0.005
+            );
+            assert_relative_eq!(
+                TherminolVp1::specific_heat(t).get::<kilojoule_per_kilogram_kelvin>(),
+                cp,
+                max_relative = 0.005
+            );
+            assert_relative_eq!(
+                TherminolVp1::thermal_conductivity(t).get::<watt_per_meter_kelvin>(),
+                k,
+                max_relative = 0.005
+            );
+        }
+        assert_relative_eq!(
+            TherminolVp1::viscosity(celsius(300.0)).get::<millipascal_second>(),
+            0.227,
+            max_relative = 0.01
+        );
+    }
+}
@@ -1,12 +1,19 @@
-use twine_core::StepIntegrable;
 use uom::si::{
-    f64::{MassDensity, SpecificHeatCapacity, Time},
+    dynamic_viscosity::millipascal_second,
+    f64::{DynamicViscosity, MassDensity, SpecificHeatCapacity, ThermalConductivity},
     mass_density::kilogram_per_cubic_meter,
     specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+    thermal_conductivity::watt_per_meter_kelvin,
 };
 
-use crate::support::thermo::model::incompressible::{
-    IncompressibleFluid, IncompressibleParameters,
+#[cfg(feature = "std")]
+use twine_core::StepIntegrable;
+#[cfg(feature = "std")]
+use uom::si::f64::Time;
+
+use crate::support::thermo::model::{
+    incompressible::{IncompressibleFluid, IncompressibleParameters},
+    transport::TransportProperties,
 };
 
 #[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
@@ -22,9 +29,15 @@ impl IncompressibleFluid for Water {
             SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(4.184),
             MassDensity::new::<kilogram_per_cubic_meter>(997.047),
         )
+        // Transport properties at 25 °C and 1 atm.
+        .with_transport(TransportProperties::new(
+            DynamicViscosity::new::<millipascal_second>(0.8900),
+            ThermalConductivity::new::<watt_per_meter_kelvin>(0.6065),
+        ))
     }
 }
 
+#[cfg(feature = "std")]
 impl StepIntegrable<Time> for Water {
     type Derivative = ();
 
@@ -1,15 +1,27 @@
 //! Thermodynamic property models.
 
+#[cfg(feature = "std")]
+pub mod freeze_guard;
+#[cfg(feature = "std")]
+pub mod humid_air;
 pub mod incompressible;
 pub mod perfect_gas;
+pub mod polynomial_incompressible;
+pub mod transport;
 
 pub(crate) mod ideal_gas_eos;
 
 #[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
 pub mod coolprop;
 
+#[cfg(feature = "std")]
+pub use freeze_guard::FreezeGuard;
+#[cfg(feature = "std")]
+pub use humid_air::HumidAir;
 pub use incompressible::Incompressible;
 pub use perfect_gas::PerfectGas;
+pub use polynomial_incompressible::PolynomialIncompressible;
+pub use transport::TransportProperties;
 
 #[cfg(any(feature = "coolprop-static", feature = "coolprop-dylib"))]
 pub use coolprop::CoolProp;
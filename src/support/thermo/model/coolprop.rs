@@ -19,19 +19,27 @@ use std::{
 
 use uom::si::{
     available_energy::joule_per_kilogram,
-    f64::{MassDensity, MolarMass, Pressure, SpecificHeatCapacity, ThermodynamicTemperature},
+    dynamic_viscosity::pascal_second,
+    f64::{
+        DynamicViscosity, MassDensity, MolarMass, Pressure, Ratio, SpecificHeatCapacity,
+        ThermalConductivity, ThermodynamicTemperature, Velocity,
+    },
     mass_density::kilogram_per_cubic_meter,
     molar_mass::kilogram_per_mole,
     pressure::pascal,
+    ratio::ratio,
     specific_heat_capacity::joule_per_kilogram_kelvin,
+    thermal_conductivity::watt_per_meter_kelvin,
     thermodynamic_temperature::kelvin,
+    velocity::meter_per_second,
 };
 
+use crate::support::constraint::{Constrained, UnitInterval};
 use crate::support::thermo::{
     PropertyError, State,
     capability::{
-        HasCp, HasCv, HasEnthalpy, HasEntropy, HasInternalEnergy, HasPressure, StateFrom,
-        ThermoModel,
+        HasCp, HasCv, HasEnthalpy, HasEntropy, HasInternalEnergy, HasPressure, HasSpeedOfSound,
+        HasThermalConductivity, HasTwoPhase, HasViscosity, StateFrom, ThermoModel, TwoPhase,
     },
 };
 use crate::support::units::{SpecificEnthalpy, SpecificEntropy, SpecificInternalEnergy};
@@ -51,6 +59,11 @@ pub trait CoolPropFluid: Default + Send + Sync + 'static {
 }
 
 /// A fluid property model backed by `CoolProp`.
+///
+/// Property queries update the underlying `AbstractState` from the queried
+/// [`State`], and the update is skipped when the handle already holds that
+/// state. Evaluating several properties of one state back to back costs a
+/// single flash.
 pub struct CoolProp<F: CoolPropFluid> {
     state: Mutex<AbstractState>,
     _f: PhantomData<F>,
@@ -87,6 +100,9 @@ impl<F: CoolPropFluid> CoolProp<F> {
     }
 
     /// Locks the underlying `AbstractState` and updates it from `state`.
+    ///
+    /// The flash is skipped when the handle already holds `state`, so
+    /// consecutive property queries on the same state share one update.
     fn lock_with_state(
         &self,
         state: &State<F>,
@@ -166,6 +182,70 @@ impl<F: CoolPropFluid> HasCv for CoolProp<F> {
     }
 }
 
+impl<F: CoolPropFluid> HasSpeedOfSound for CoolProp<F> {
+    fn speed_of_sound(&self, state: &State<Self::Fluid>) -> Result<Velocity, PropertyError> {
+        let abstract_state = self.lock_with_state(state)?;
+        let speed = abstract_state
+            .keyed_output(OutputParam::SPEED_OF_SOUND)
+            .map_err(CoolPropError::from)?;
+        Ok(Velocity::new::<meter_per_second>(speed))
+    }
+}
+
+impl<F: CoolPropFluid> HasViscosity for CoolProp<F> {
+    fn viscosity(&self, state: &State<Self::Fluid>) -> Result<DynamicViscosity, PropertyError> {
+        let abstract_state = self.lock_with_state(state)?;
+        let viscosity = abstract_state
+            .keyed_output(OutputParam::VISCOSITY)
+            .map_err(CoolPropError::from)?;
+        Ok(DynamicViscosity::new::<pascal_second>(viscosity))
+    }
+}
+
+impl<F: CoolPropFluid> HasThermalConductivity for CoolProp<F> {
+    fn thermal_conductivity(
+        &self,
+        state: &State<Self::Fluid>,
+    ) -> Result<ThermalConductivity, PropertyError> {
+        let abstract_state = self.lock_with_state(state)?;
+        let conductivity = abstract_state
+            .keyed_output(OutputParam::CONDUCTIVITY)
+            .map_err(CoolPropError::from)?;
+        Ok(ThermalConductivity::new::<watt_per_meter_kelvin>(
+            conductivity,
+        ))
+    }
+}
+
+impl<F: CoolPropFluid> HasTwoPhase for CoolProp<F> {
+    /// Reads the quality of the state, then flashes the saturated liquid and
+    /// vapor at the state's temperature to get the phase densities.
+    fn two_phase(&self, state: &State<Self::Fluid>) -> Result<Option<TwoPhase>, PropertyError> {
+        let mut abstract_state = self.lock_with_state(state)?;
+        let quality = abstract_state
+            .keyed_output(OutputParam::Q)
+            .map_err(CoolPropError::from)?;
+        if !(0.0..=1.0).contains(&quality) {
+            return Ok(None);
+        }
+
+        let t = state.temperature.get::<kelvin>();
+        let mut saturated_density = |q: f64| -> Result<MassDensity, CoolPropError> {
+            abstract_state.update(InputPair::QT, q, t)?;
+            let density = abstract_state.keyed_output(OutputParam::DMASS)?;
+            Ok(MassDensity::new::<kilogram_per_cubic_meter>(density))
+        };
+        let liquid_density = saturated_density(0.0)?;
+        let vapor_density = saturated_density(1.0)?;
+
+        Ok(Some(TwoPhase {
+            quality: Ratio::new::<ratio>(quality),
+            liquid_density,
+            vapor_density,
+        }))
+    }
+}
+
 impl<F: CoolPropFluid> StateFrom<(F, ThermodynamicTemperature, MassDensity)> for CoolProp<F> {
     type Error = CoolPropError;
 
@@ -213,6 +293,67 @@ impl<F: CoolPropFluid> StateFrom<(F, ThermodynamicTemperature, Pressure)> for Co
     }
 }
 
+impl<F: CoolPropFluid>
+    StateFrom<(
+        F,
+        ThermodynamicTemperature,
+        Constrained<Ratio, UnitInterval>,
+    )> for CoolProp<F>
+{
+    type Error = CoolPropError;
+
+    /// Flashes the saturated state at the given temperature and vapor quality.
+    fn state_from(
+        &self,
+        (fluid, temperature, quality): (
+            F,
+            ThermodynamicTemperature,
+            Constrained<Ratio, UnitInterval>,
+        ),
+    ) -> Result<State<F>, Self::Error> {
+        let mut abstract_state = self.state.lock()?;
+        abstract_state.update(
+            InputPair::QT,
+            quality.into_inner().get::<ratio>(),
+            temperature.get::<kelvin>(),
+        )?;
+
+        let density = abstract_state.keyed_output(OutputParam::DMASS)?;
+
+        Ok(State {
+            temperature,
+            density: MassDensity::new::<kilogram_per_cubic_meter>(density),
+            fluid,
+        })
+    }
+}
+
+impl<F: CoolPropFluid> StateFrom<(F, Pressure, Constrained<Ratio, UnitInterval>)> for CoolProp<F> {
+    type Error = CoolPropError;
+
+    /// Flashes the saturated state at the given pressure and vapor quality.
+    fn state_from(
+        &self,
+        (fluid, pressure, quality): (F, Pressure, Constrained<Ratio, UnitInterval>),
+    ) -> Result<State<F>, Self::Error> {
+        let mut abstract_state = self.state.lock()?;
+        abstract_state.update(
+            InputPair::PQ,
+            pressure.get::<pascal>(),
+            quality.into_inner().get::<ratio>(),
+        )?;
+
+        let temperature = abstract_state.keyed_output(OutputParam::T)?;
+        let density = abstract_state.keyed_output(OutputParam::DMASS)?;
+
+        Ok(State {
+            temperature: ThermodynamicTemperature::new::<kelvin>(temperature),
+            density: MassDensity::new::<kilogram_per_cubic_meter>(density),
+            fluid,
+        })
+    }
+}
+
 impl<F: CoolPropFluid> StateFrom<(F, Pressure, SpecificEnthalpy)> for CoolProp<F> {
     type Error = CoolPropError;
 
@@ -288,6 +429,30 @@ impl<F: CoolPropFluid> StateFrom<(F, SpecificEnthalpy, SpecificEntropy)> for Coo
     }
 }
 
+impl<F: CoolPropFluid> StateFrom<(F, MassDensity, SpecificInternalEnergy)> for CoolProp<F> {
+    type Error = CoolPropError;
+
+    fn state_from(
+        &self,
+        (fluid, density, internal_energy): (F, MassDensity, SpecificInternalEnergy),
+    ) -> Result<State<F>, Self::Error> {
+        let mut abstract_state = self.state.lock()?;
+        abstract_state.update(
+            InputPair::DMASS_UMASS,
+            density.get::<kilogram_per_cubic_meter>(),
+            internal_energy.get::<joule_per_kilogram>(),
+        )?;
+
+        let temperature = abstract_state.keyed_output(OutputParam::T)?;
+
+        Ok(State {
+            temperature: ThermodynamicTemperature::new::<kelvin>(temperature),
+            density,
+            fluid,
+        })
+    }
+}
+
 // Static assertion: `CoolProp<F>` must be `Send + Sync` for any `CoolPropFluid`.
 // Thread safety is provided by `COOLPROP_LOCK` in `wrapper.rs`, which serializes
 // all CoolProp FFI calls. The local `Mutex<AbstractState>` provides interior
@@ -315,7 +480,7 @@ mod tests {
         f64::{MassDensity, ThermodynamicTemperature},
         mass_density::kilogram_per_cubic_meter,
         molar_mass::gram_per_mole,
-        pressure::megapascal,
+        pressure::{kilopascal, megapascal},
         specific_heat_capacity::{joule_per_kilogram_kelvin, kilojoule_per_kilogram_kelvin},
         thermodynamic_temperature::{degree_celsius, kelvin},
     };
@@ -491,6 +656,148 @@ mod tests {
         );
     }
 
+    #[test]
+    fn water_speed_of_sound_matches_expected() {
+        let model = water_model();
+        let speed = model.speed_of_sound(&water_state()).unwrap();
+        assert_relative_eq!(speed.get::<meter_per_second>(), 1507.7369, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn water_transport_properties_match_iapws() {
+        // IAPWS 2008 and 2011 give 890.0 μPa·s and 0.6065 W/(m·K) at 25 °C
+        // and 0.1 MPa.
+        let model = water_model();
+        let state = model
+            .state_from((
+                Water,
+                ThermodynamicTemperature::new::<degree_celsius>(25.0),
+                Pressure::new::<kilopascal>(100.0),
+            ))
+            .unwrap();
+        assert_relative_eq!(
+            model.viscosity(&state).unwrap().get::<pascal_second>(),
+            890.0e-6,
+            max_relative = 1e-3
+        );
+        assert_relative_eq!(
+            model
+                .thermal_conductivity(&state)
+                .unwrap()
+                .get::<watt_per_meter_kelvin>(),
+            0.6065,
+            max_relative = 1e-3
+        );
+    }
+
+    #[test]
+    fn repeated_queries_share_one_flash() {
+        let model = co2_model();
+        let state = co2_state();
+
+        let enthalpy = model.enthalpy(&state).unwrap();
+        model.pressure(&state).unwrap();
+        model.entropy(&state).unwrap();
+        model.cp(&state).unwrap();
+        assert_eq!(model.state.lock().unwrap().flashes, 1);
+
+        // A failed update must not leave the previous state marked as current.
+        let other = State::new(
+            state.temperature,
+            MassDensity::new::<kilogram_per_cubic_meter>(-1.0),
+            CarbonDioxide,
+        );
+        assert!(model.enthalpy(&other).is_err());
+        assert_eq!(model.enthalpy(&state).unwrap(), enthalpy);
+        assert_eq!(model.state.lock().unwrap().flashes, 3);
+    }
+
+    #[test]
+    fn water_two_phase_reports_saturated_densities() {
+        let model = water_model();
+        assert_eq!(model.two_phase(&water_state()).unwrap(), None);
+
+        let state = State::new(
+            ThermodynamicTemperature::new::<degree_celsius>(100.0),
+            MassDensity::new::<kilogram_per_cubic_meter>(2.0),
+            Water,
+        );
+        let enthalpy = model.enthalpy(&state).unwrap();
+        let two_phase = model
+            .two_phase(&state)
+            .unwrap()
+            .expect("state is two-phase");
+
+        assert_relative_eq!(
+            two_phase.liquid_density.get::<kilogram_per_cubic_meter>(),
+            958.35,
+            max_relative = 1e-4
+        );
+        assert_relative_eq!(
+            two_phase.vapor_density.get::<kilogram_per_cubic_meter>(),
+            0.5976,
+            max_relative = 1e-3
+        );
+
+        // The phase volumes add up to the state's specific volume.
+        let x = two_phase.quality.get::<ratio>();
+        let specific_volume = x / two_phase.vapor_density + (1.0 - x) / two_phase.liquid_density;
+        assert_relative_eq!(
+            (state.density * specific_volume).get::<ratio>(),
+            1.0,
+            max_relative = 1e-9
+        );
+
+        // The saturation flashes must not leave a stale state behind.
+        assert_eq!(model.enthalpy(&state).unwrap(), enthalpy);
+    }
+
+    #[test]
+    fn water_state_from_temperature_quality_is_saturated() {
+        let model = water_model();
+        let t = ThermodynamicTemperature::new::<degree_celsius>(100.0);
+        let quality = |x| Constrained::new(Ratio::new::<ratio>(x)).unwrap();
+
+        let liquid = model.state_from((Water, t, quality(0.0))).unwrap();
+        let mixture = model.state_from((Water, t, quality(0.5))).unwrap();
+
+        assert_relative_eq!(
+            model.pressure(&mixture).unwrap().get::<kilopascal>(),
+            101.42,
+            max_relative = 1e-3
+        );
+        assert_relative_eq!(
+            liquid.density.get::<kilogram_per_cubic_meter>(),
+            958.35,
+            max_relative = 1e-4
+        );
+        let two_phase = model
+            .two_phase(&mixture)
+            .unwrap()
+            .expect("state is two-phase");
+        assert_relative_eq!(two_phase.quality.get::<ratio>(), 0.5, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn water_state_from_pressure_quality_is_saturated() {
+        let model = water_model();
+        let p = Pressure::new::<kilopascal>(101.325);
+        let quality = |x| Constrained::new(Ratio::new::<ratio>(x)).unwrap();
+
+        let vapor = model.state_from((Water, p, quality(1.0))).unwrap();
+
+        assert_relative_eq!(
+            vapor.temperature.get::<degree_celsius>(),
+            99.97,
+            max_relative = 1e-3
+        );
+        assert_relative_eq!(
+            vapor.density.get::<kilogram_per_cubic_meter>(),
+            0.5977,
+            max_relative = 1e-3
+        );
+    }
+
     #[test]
     fn co2_state_from_temperature_pressure_roundtrips_from_temperature_density() {
         let model = co2_model();
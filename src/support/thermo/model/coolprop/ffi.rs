@@ -64,6 +64,12 @@ unsafe extern "C" {
 pub struct InputPair(c_long);
 
 impl InputPair {
+    /// Vapor quality (kg/kg) + temperature (K).
+    pub const QT: Self = Self(1);
+
+    /// Pressure (Pa) + vapor quality (kg/kg).
+    pub const PQ: Self = Self(2);
+
     /// Mass density (kg/m³) + temperature (K).
     pub const DMASS_T: Self = Self(10);
 
@@ -79,6 +85,9 @@ impl InputPair {
     /// Mass enthalpy (J/kg) + mass entropy (J/kg/K).
     pub const HMASS_SMASS: Self = Self(26);
 
+    /// Mass density (kg/m³) + mass internal energy (J/kg).
+    pub const DMASS_UMASS: Self = Self(34);
+
     /// Returns the raw `c_long` value.
     pub const fn as_c_long(self) -> c_long {
         self.0
@@ -107,6 +116,9 @@ impl OutputParam {
     /// Pressure (Pa).
     pub const P: Self = Self(20);
 
+    /// Vapor quality (kg/kg); outside `0..=1` for single-phase states.
+    pub const Q: Self = Self(21);
+
     /// Mass-based density (kg/m³).
     pub const DMASS: Self = Self(39);
 
@@ -125,6 +137,15 @@ impl OutputParam {
     /// Mass-based internal energy (J/kg).
     pub const UMASS: Self = Self(45);
 
+    /// Dynamic viscosity (Pa·s).
+    pub const VISCOSITY: Self = Self(51);
+
+    /// Thermal conductivity (W/m/K).
+    pub const CONDUCTIVITY: Self = Self(52);
+
+    /// Speed of sound (m/s).
+    pub const SPEED_OF_SOUND: Self = Self(55);
+
     /// Returns the raw `c_long` value.
     pub const fn as_c_long(self) -> c_long {
         self.0
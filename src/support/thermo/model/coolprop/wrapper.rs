@@ -32,8 +32,23 @@ static COOLPROP_LOCK: Mutex<()> = Mutex::new(());
 /// Calls `AbstractState_factory` on construction and `AbstractState_free` on drop.
 /// All methods return `Result`, converting `CoolProp` error strings
 /// into [`WrapperError`].
+///
+/// The inputs of the last successful [`update`](Self::update) are remembered,
+/// so repeating an update with bit-identical inputs skips the flash. Querying
+/// several properties of one state therefore costs a single flash.
 pub struct AbstractState {
     handle: c_long,
+    current: Option<UpdateInputs>,
+    #[cfg(test)]
+    pub(super) flashes: usize,
+}
+
+/// Inputs of a successful `update`, compared bit for bit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct UpdateInputs {
+    pair: InputPair,
+    v1: u64,
+    v2: u64,
 }
 
 /// Errors returned by [`AbstractState`] methods.
@@ -80,18 +95,47 @@ impl AbstractState {
         };
 
         if errcode != 0 {
-            return Err(WrapperError::CoolProp(read_message(&buf)));
+            let message = read_message(&buf);
+            #[cfg(feature = "tracing")]
+            tracing::debug!(backend, fluid, %message, "CoolProp factory failed");
+            return Err(WrapperError::CoolProp(message));
         }
 
-        Ok(Self { handle })
+        #[cfg(feature = "tracing")]
+        tracing::debug!(backend, fluid, handle, "CoolProp state created");
+
+        Ok(Self {
+            handle,
+            current: None,
+            #[cfg(test)]
+            flashes: 0,
+        })
     }
 
     /// Update the thermodynamic state from an input pair and two values.
     ///
+    /// Does nothing if the handle already holds the state from an update with
+    /// the same pair and bit-identical values.
+    ///
     /// # Errors
     ///
     /// Returns [`WrapperError::CoolProp`] if `CoolProp` rejects the state.
     pub fn update(&mut self, pair: InputPair, v1: f64, v2: f64) -> Result<(), WrapperError> {
+        let inputs = UpdateInputs {
+            pair,
+            v1: v1.to_bits(),
+            v2: v2.to_bits(),
+        };
+        if self.current == Some(inputs) {
+            return Ok(());
+        }
+        // A failed update leaves the handle in an unspecified state.
+        self.current = None;
+        #[cfg(test)]
+        {
+            self.flashes += 1;
+        }
+
         let mut errcode: c_long = 0;
         let mut buf = [0u8; MSG_BUF_LEN];
 
@@ -114,9 +158,29 @@ impl AbstractState {
         }
 
         if errcode != 0 {
-            return Err(WrapperError::CoolProp(read_message(&buf)));
+            let message = read_message(&buf);
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                handle = self.handle,
+                pair = pair.as_c_long(),
+                v1,
+                v2,
+                %message,
+                "CoolProp update failed"
+            );
+            return Err(WrapperError::CoolProp(message));
         }
 
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            handle = self.handle,
+            pair = pair.as_c_long(),
+            v1,
+            v2,
+            "CoolProp update"
+        );
+
+        self.current = Some(inputs);
         Ok(())
     }
 
@@ -149,9 +213,25 @@ impl AbstractState {
         };
 
         if errcode != 0 {
-            return Err(WrapperError::CoolProp(read_message(&buf)));
+            let message = read_message(&buf);
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                handle = self.handle,
+                param = param.as_c_long(),
+                %message,
+                "CoolProp keyed output failed"
+            );
+            return Err(WrapperError::CoolProp(message));
         }
 
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            handle = self.handle,
+            param = param.as_c_long(),
+            value,
+            "CoolProp keyed output"
+        );
+
         Ok(value)
     }
 }
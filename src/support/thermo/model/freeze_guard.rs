@@ -0,0 +1,397 @@
+//! Freeze protection for liquid loops.
+//!
+//! `FreezeGuard` wraps any liquid property model and watches every state it
+//! builds or evaluates for temperatures below the fluid's freezing point.
+//! Property models for water and brines happily extrapolate into the ice
+//! region, so without a guard a glycol loop sized too lean simply reports
+//! numbers. With one, the simulation either stops with a structured error or
+//! keeps running and records how far it strayed.
+//!
+//! # Policies
+//!
+//! - [`FreezePolicy::Reject`]: building a frozen state fails with
+//!   [`FreezeGuardError::BelowFreezingPoint`], and property queries on one
+//!   fail with [`PropertyError::OutOfDomain`]. The error propagates through
+//!   the wrapping model's thermo-model error like any other property
+//!   failure.
+//! - [`FreezePolicy::Record`]: frozen states pass through unchanged, and the
+//!   guard tallies them in a [`FreezeReport`] for review after the run. With
+//!   the `tracing` feature each violation is also logged as a warning.
+//!
+//! # Freezing points
+//!
+//! [`Brine::freezing_point`] interpolates the ASHRAE freezing-point tables
+//! for aqueous ethylene and propylene glycol. Any other fluid's freezing
+//! point can be passed to [`FreezeGuard::new`] directly.
+//!
+//! # Example
+//!
+//! ```
+//! use twine_models::support::thermo::{
+//!     capability::StateFrom,
+//!     fluid::Water,
+//!     model::{
+//!         Incompressible,
+//!         freeze_guard::{Brine, FreezeGuard, FreezeGuardError, FreezePolicy},
+//!     },
+//! };
+//! use uom::si::{
+//!     f64::{Ratio, ThermodynamicTemperature},
+//!     ratio::percent,
+//!     thermodynamic_temperature::degree_celsius,
+//! };
+//!
+//! // A 30 % propylene glycol loop freezes near −13 °C.
+//! let freezing_point = Brine::PropyleneGlycol.freezing_point(Ratio::new::<percent>(30.0))?;
+//! let loop_fluid = FreezeGuard::new(
+//!     Incompressible::<Water>::new()?,
+//!     freezing_point,
+//!     FreezePolicy::Reject,
+//! );
+//!
+//! let cold_night = ThermodynamicTemperature::new::<degree_celsius>(-18.0);
+//! assert!(matches!(
+//!     loop_fluid.state_from((Water, cold_night)),
+//!     Err(FreezeGuardError::BelowFreezingPoint { .. })
+//! ));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::sync::{Mutex, PoisonError};
+
+use thiserror::Error;
+use uom::si::{
+    f64::{
+        DynamicViscosity, Pressure, Ratio, SpecificHeatCapacity, ThermalConductivity,
+        ThermodynamicTemperature, Velocity,
+    },
+    ratio::ratio,
+    thermodynamic_temperature::degree_celsius,
+};
+
+use crate::support::{
+    constraint::{Constraint, ConstraintError, UnitInterval},
+    thermo::{
+        PropertyError, State,
+        capability::{
+            HasCp, HasCv, HasEnthalpy, HasEntropy, HasInternalEnergy, HasPressure, HasSpeedOfSound,
+            HasThermalConductivity, HasTwoPhase, HasViscosity, StateFrom, ThermoModel, TwoPhase,
+        },
+    },
+    units::{SpecificEnthalpy, SpecificEntropy, SpecificInternalEnergy},
+};
+
+/// Glycol mass fractions of the freezing-point tables, in percent.
+const GLYCOL_FRACTIONS: [f64; 7] = [0.0, 10.0, 20.0, 30.0, 40.0, 50.0, 60.0];
+
+/// Freezing points of aqueous ethylene glycol, °C (ASHRAE Handbook).
+const ETHYLENE_GLYCOL_FREEZING: [f64; 7] = [0.0, -3.2, -7.8, -14.1, -22.3, -33.8, -48.3];
+
+/// Freezing points of aqueous propylene glycol, °C (ASHRAE Handbook).
+const PROPYLENE_GLYCOL_FREEZING: [f64; 7] = [0.0, -3.3, -7.1, -12.7, -21.1, -33.5, -51.1];
+
+/// Aqueous glycol brines with tabulated freezing points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Brine {
+    /// Ethylene glycol in water.
+    EthyleneGlycol,
+
+    /// Propylene glycol in water.
+    PropyleneGlycol,
+}
+
+impl Brine {
+    /// Returns the freezing point of the brine at a glycol mass fraction.
+    ///
+    /// Interpolates linearly in the ASHRAE tables, which cover mass
+    /// fractions up to 60 %. Richer mixtures are not used for freeze
+    /// protection because their freezing point rises again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConstraintError::AboveMaximum`] if the mass fraction exceeds
+    /// 60 %, or another [`ConstraintError`] if it is negative or not a
+    /// number.
+    pub fn freezing_point(
+        self,
+        mass_fraction: Ratio,
+    ) -> Result<ThermodynamicTemperature, ConstraintError> {
+        UnitInterval::check(&mass_fraction)?;
+        let percent = 100.0 * mass_fraction.get::<ratio>();
+        if percent > GLYCOL_FRACTIONS[GLYCOL_FRACTIONS.len() - 1] {
+            return Err(ConstraintError::AboveMaximum);
+        }
+
+        let table = match self {
+            Self::EthyleneGlycol => &ETHYLENE_GLYCOL_FREEZING,
+            Self::PropyleneGlycol => &PROPYLENE_GLYCOL_FREEZING,
+        };
+        let upper = GLYCOL_FRACTIONS
+            .iter()
+            .position(|&x| x >= percent)
+            .unwrap_or(GLYCOL_FRACTIONS.len() - 1)
+            .max(1);
+        let (x0, x1) = (GLYCOL_FRACTIONS[upper - 1], GLYCOL_FRACTIONS[upper]);
+        let (t0, t1) = (table[upper - 1], table[upper]);
+        let celsius = t0 + (t1 - t0) * (percent - x0) / (x1 - x0);
+
+        Ok(ThermodynamicTemperature::new::<degree_celsius>(celsius))
+    }
+}
+
+/// What a [`FreezeGuard`] does when it meets a frozen state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FreezePolicy {
+    /// Fail the state construction or property query.
+    Reject,
+
+    /// Let the state through and record it in the [`FreezeReport`].
+    Record,
+}
+
+/// Errors from building a state through a [`FreezeGuard`].
+#[derive(Debug, Error)]
+pub enum FreezeGuardError<E> {
+    /// The wrapped model failed to build the state.
+    #[error(transparent)]
+    Model(E),
+
+    /// The state is colder than the fluid's freezing point.
+    #[error("temperature {temperature:?} is below the freezing point {freezing_point:?}")]
+    BelowFreezingPoint {
+        /// Temperature of the rejected state.
+        temperature: ThermodynamicTemperature,
+
+        /// Freezing point of the fluid.
+        freezing_point: ThermodynamicTemperature,
+    },
+}
+
+/// Frozen states seen by a [`FreezeGuard`] under [`FreezePolicy::Record`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FreezeReport {
+    /// Number of frozen states built or evaluated.
+    pub violations: usize,
+
+    /// Coldest temperature among them.
+    pub coldest: Option<ThermodynamicTemperature>,
+}
+
+/// A property model wrapper that guards against frozen liquid states.
+///
+/// Every state passed to `state_from` or a property query is compared with
+/// the freezing point; see the [module documentation](self) for the
+/// policies. All capabilities of the wrapped model are forwarded, so the
+/// guard drops in wherever the bare model fits.
+#[derive(Debug)]
+pub struct FreezeGuard<Model> {
+    model: Model,
+    freezing_point: ThermodynamicTemperature,
+    policy: FreezePolicy,
+    report: Mutex<FreezeReport>,
+}
+
+impl<Model> FreezeGuard<Model> {
+    /// Wraps `model`, guarding states colder than `freezing_point`.
+    #[must_use]
+    pub fn new(
+        model: Model,
+        freezing_point: ThermodynamicTemperature,
+        policy: FreezePolicy,
+    ) -> Self {
+        Self {
+            model,
+            freezing_point,
+            policy,
+            report: Mutex::new(FreezeReport::default()),
+        }
+    }
+
+    /// Returns the wrapped model.
+    #[must_use]
+    pub fn model(&self) -> &Model {
+        &self.model
+    }
+
+    /// Returns the guarded freezing point.
+    #[must_use]
+    pub fn freezing_point(&self) -> ThermodynamicTemperature {
+        self.freezing_point
+    }
+
+    /// Returns the frozen states recorded so far.
+    #[must_use]
+    pub fn report(&self) -> FreezeReport {
+        *self.report.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Clears the recorded frozen states, for example between runs.
+    pub fn reset(&self) {
+        *self.report.lock().unwrap_or_else(PoisonError::into_inner) = FreezeReport::default();
+    }
+
+    /// Checks a temperature, recording it under [`FreezePolicy::Record`].
+    ///
+    /// Returns `Err` with the freezing point only under
+    /// [`FreezePolicy::Reject`].
+    fn check(&self, temperature: ThermodynamicTemperature) -> Result<(), ThermodynamicTemperature> {
+        if temperature >= self.freezing_point || temperature.is_nan() {
+            return Ok(());
+        }
+
+        match self.policy {
+            FreezePolicy::Reject => Err(self.freezing_point),
+            FreezePolicy::Record => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    temperature = ?temperature,
+                    freezing_point = ?self.freezing_point,
+                    "state below freezing point"
+                );
+                let mut report = self.report.lock().unwrap_or_else(PoisonError::into_inner);
+                report.violations += 1;
+                report.coldest = Some(match report.coldest {
+                    Some(coldest) if coldest <= temperature => coldest,
+                    _ => temperature,
+                });
+                Ok(())
+            }
+        }
+    }
+
+    /// Guards a state passed to a property query.
+    fn guard<F>(&self, state: &State<F>) -> Result<(), PropertyError> {
+        self.check(state.temperature)
+            .map_err(|freezing_point| PropertyError::OutOfDomain {
+                context: format!(
+                    "temperature {:?} is below the freezing point {freezing_point:?}",
+                    state.temperature
+                ),
+            })
+    }
+}
+
+impl<Model: ThermoModel> ThermoModel for FreezeGuard<Model> {
+    type Fluid = Model::Fluid;
+}
+
+impl<Model, Input> StateFrom<Input> for FreezeGuard<Model>
+where
+    Model: StateFrom<Input>,
+{
+    type Error = FreezeGuardError<Model::Error>;
+
+    fn state_from(&self, input: Input) -> Result<State<Self::Fluid>, Self::Error> {
+        let state = self
+            .model
+            .state_from(input)
+            .map_err(FreezeGuardError::Model)?;
+        self.check(state.temperature).map_err(|freezing_point| {
+            FreezeGuardError::BelowFreezingPoint {
+                temperature: state.temperature,
+                freezing_point,
+            }
+        })?;
+        Ok(state)
+    }
+}
+
+/// Forwards a property capability after guarding the queried state.
+macro_rules! guarded_property {
+    ($capability:ident, $method:ident, $output:ty) => {
+        impl<Model: $capability> $capability for FreezeGuard<Model> {
+            fn $method(&self, state: &State<Self::Fluid>) -> Result<$output, PropertyError> {
+                self.guard(state)?;
+                self.model.$method(state)
+            }
+        }
+    };
+}
+
+guarded_property!(HasPressure, pressure, Pressure);
+guarded_property!(HasInternalEnergy, internal_energy, SpecificInternalEnergy);
+guarded_property!(HasEnthalpy, enthalpy, SpecificEnthalpy);
+guarded_property!(HasEntropy, entropy, SpecificEntropy);
+guarded_property!(HasCp, cp, SpecificHeatCapacity);
+guarded_property!(HasCv, cv, SpecificHeatCapacity);
+guarded_property!(HasSpeedOfSound, speed_of_sound, Velocity);
+guarded_property!(HasViscosity, viscosity, DynamicViscosity);
+guarded_property!(
+    HasThermalConductivity,
+    thermal_conductivity,
+    ThermalConductivity
+);
+guarded_property!(HasTwoPhase, two_phase, Option<TwoPhase>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::ratio::percent;
+
+    use crate::support::{
+        thermo::{fluid::Water, model::Incompressible},
+        units::test_utils::celsius,
+    };
+
+    fn guard(policy: FreezePolicy) -> FreezeGuard<Incompressible<Water>> {
+        FreezeGuard::new(Incompressible::new().unwrap(), celsius(0.0), policy)
+    }
+
+    #[test]
+    fn brine_tables_interpolate_between_entries() {
+        let pg = |percent_glycol| {
+            Brine::PropyleneGlycol
+                .freezing_point(Ratio::new::<percent>(percent_glycol))
+                .unwrap()
+                .get::<degree_celsius>()
+        };
+        assert_relative_eq!(pg(0.0), 0.0);
+        assert_relative_eq!(pg(30.0), -12.7, max_relative = 1e-12);
+        assert_relative_eq!(pg(35.0), -16.9, max_relative = 1e-12);
+
+        let eg = Brine::EthyleneGlycol
+            .freezing_point(Ratio::new::<percent>(60.0))
+            .unwrap();
+        assert_relative_eq!(eg.get::<degree_celsius>(), -48.3, max_relative = 1e-12);
+
+        assert_eq!(
+            Brine::EthyleneGlycol.freezing_point(Ratio::new::<percent>(70.0)),
+            Err(ConstraintError::AboveMaximum)
+        );
+    }
+
+    #[test]
+    fn reject_policy_fails_frozen_states_and_queries() {
+        let guard = guard(FreezePolicy::Reject);
+        assert!(guard.state_from((Water, celsius(5.0))).is_ok());
+        assert!(matches!(
+            guard.state_from((Water, celsius(-2.0))),
+            Err(FreezeGuardError::BelowFreezingPoint { .. })
+        ));
+
+        let frozen = guard.model().state_from((Water, celsius(-2.0))).unwrap();
+        assert!(matches!(
+            guard.cp(&frozen),
+            Err(PropertyError::OutOfDomain { .. })
+        ));
+        assert_eq!(guard.report(), FreezeReport::default());
+    }
+
+    #[test]
+    fn record_policy_tallies_frozen_states() {
+        let guard = guard(FreezePolicy::Record);
+        for t in [3.0, -1.0, -4.0, -2.0] {
+            let state = guard.state_from((Water, celsius(t))).unwrap();
+            guard.enthalpy(&state).unwrap();
+        }
+
+        let report = guard.report();
+        assert_eq!(report.violations, 6);
+        assert_relative_eq!(report.coldest.unwrap().get::<degree_celsius>(), -4.0);
+
+        guard.reset();
+        assert_eq!(guard.report(), FreezeReport::default());
+    }
+}
@@ -0,0 +1,489 @@
+//! Humid air property model.
+//!
+//! `HumidAir` treats moist air as an ideal-gas mixture of dry air and water
+//! vapor, with the composition carried by the [`MoistAir`] fluid's humidity
+//! ratio. It applies the ASHRAE Fundamentals relations of
+//! [`support::psychrometrics`](crate::support::psychrometrics) through the
+//! thermo capability traits, so air-side component models (cooling coils,
+//! evaporative coolers, ventilation) can use the same generic machinery as
+//! any other fluid.
+//!
+//! # Mass Basis
+//!
+//! Psychrometric charts report enthalpy and volume per mass of dry air.
+//! Thermo models report properties per mass of the fluid itself, so every
+//! property here is per mass of moist air: the chart enthalpy divided by
+//! `1 + W`. Multiply by the moist-air mass flow, not the dry-air flow, in
+//! energy balances.
+//!
+//! # Reference State
+//!
+//! Enthalpy and entropy are zero for dry air at 0 °C and 1 atm and for
+//! saturated liquid water at 0 °C, matching the psychrometric tables.
+//!
+//! # States
+//!
+//! States can be built from the dry-bulb temperature and total pressure
+//! together with the humidity ratio (through the fluid), the
+//! [`RelativeHumidity`], or the thermodynamic [`WetBulb`] temperature.
+//! Supersaturated states and temperatures outside −100 °C to 200 °C are
+//! rejected with a [`PsychrometricError`].
+//!
+//! # Example
+//!
+//! ```
+//! use twine_models::support::thermo::{
+//!     capability::{HasEnthalpy, StateFrom},
+//!     model::humid_air::{HumidAir, RelativeHumidity, WetBulb},
+//! };
+//! use uom::si::{
+//!     f64::{Pressure, Ratio, ThermodynamicTemperature},
+//!     pressure::atmosphere,
+//!     ratio::percent,
+//!     thermodynamic_temperature::degree_celsius,
+//! };
+//!
+//! let p = Pressure::new::<atmosphere>(1.0);
+//! let dry_bulb = ThermodynamicTemperature::new::<degree_celsius>(30.0);
+//!
+//! // A warm summer day entering a cooling coil.
+//! let inlet = HumidAir.state_from((dry_bulb, p, RelativeHumidity(Ratio::new::<percent>(50.0))))?;
+//!
+//! // The same air described by its wet bulb, near 22 °C.
+//! let wet_bulb = HumidAir.psychrometric(&inlet)?.wet_bulb();
+//! let same = HumidAir.state_from((dry_bulb, p, WetBulb(wet_bulb)))?;
+//!
+//! let difference = HumidAir.enthalpy(&inlet)? - HumidAir.enthalpy(&same)?;
+//! assert!(difference.value.abs() < 1.0);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use uom::si::{
+    available_energy::joule_per_kilogram,
+    f64::{MassDensity, Pressure, Ratio, SpecificHeatCapacity, ThermodynamicTemperature},
+    mass_density::kilogram_per_cubic_meter,
+    pressure::pascal,
+    ratio::ratio,
+    specific_heat_capacity::joule_per_kilogram_kelvin,
+    specific_volume::cubic_meter_per_kilogram,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+use crate::support::{
+    psychrometrics::{self, PsychrometricError, saturation_pressure},
+    thermo::{
+        PropertyError, State,
+        capability::{
+            HasCp, HasCv, HasEnthalpy, HasEntropy, HasInternalEnergy, HasPressure, StateFrom,
+            ThermoModel,
+        },
+        fluid::MoistAir,
+    },
+    units::{SpecificEnthalpy, SpecificEntropy, SpecificInternalEnergy},
+};
+
+/// Gas constant of dry air, J/kg·K.
+const DRY_AIR_GAS_CONSTANT: f64 = 287.042;
+
+/// Ratio of the molar masses of water and dry air.
+const MOLAR_MASS_RATIO: f64 = 0.621_945;
+
+/// Ratio of the gas constants of water vapor and dry air, as used in the
+/// psychrometric specific volume.
+const GAS_CONSTANT_RATIO: f64 = 1.607_858;
+
+/// Specific heat of dry air, J/kg·K.
+const DRY_AIR_CP: f64 = 1006.0;
+
+/// Specific heat of water vapor, J/kg·K.
+const VAPOR_CP: f64 = 1860.0;
+
+/// Enthalpy of saturated vapor at 0 °C relative to the liquid, J/kg.
+const VAPOR_ENTHALPY_AT_ZERO: f64 = 2_501_000.0;
+
+/// Reference temperature, K.
+const T_REF: f64 = 273.15;
+
+/// Reference pressure of dry air, Pa.
+const P_REF: f64 = 101_325.0;
+
+/// Relative humidity input for building a [`HumidAir`] state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelativeHumidity(pub Ratio);
+
+/// Thermodynamic wet-bulb temperature input for building a [`HumidAir`]
+/// state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WetBulb(pub ThermodynamicTemperature);
+
+/// Ideal-gas mixture model of dry air and water vapor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HumidAir;
+
+impl ThermoModel for HumidAir {
+    type Fluid = MoistAir;
+}
+
+impl HumidAir {
+    /// Returns the moist-air state as a psychrometric state, for chart
+    /// properties such as the dew point or relative humidity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PsychrometricError`] if the state is supersaturated or out
+    /// of range.
+    pub fn psychrometric(
+        &self,
+        state: &State<MoistAir>,
+    ) -> Result<psychrometrics::MoistAir, PsychrometricError> {
+        // A non-physical humidity ratio is rejected by the psychrometric state.
+        let w = state.fluid.humidity_ratio.get::<ratio>().max(0.0);
+        let pressure = Pressure::new::<pascal>(
+            state.density.get::<kilogram_per_cubic_meter>()
+                * gas_constant(w)
+                * state.temperature.get::<kelvin>(),
+        );
+        psychrometrics::MoistAir::new(state.temperature, state.fluid.humidity_ratio, pressure)
+    }
+}
+
+/// Returns the humidity ratio of a state, rejecting non-physical values.
+fn humidity_ratio(state: &State<MoistAir>) -> Result<f64, PropertyError> {
+    let w = state.fluid.humidity_ratio.get::<ratio>();
+    if w.is_finite() && w >= 0.0 {
+        Ok(w)
+    } else {
+        Err(PropertyError::InvalidState {
+            context: format!("humidity ratio must be non-negative, got {w}"),
+        })
+    }
+}
+
+/// Returns the gas constant of the mixture, per mass of moist air, J/kg·K.
+fn gas_constant(w: f64) -> f64 {
+    DRY_AIR_GAS_CONSTANT * (1.0 + GAS_CONSTANT_RATIO * w) / (1.0 + w)
+}
+
+/// Converts a validated psychrometric state into a thermo state.
+fn state_from_psychrometric(air: &psychrometrics::MoistAir) -> State<MoistAir> {
+    let w = air.humidity_ratio().get::<ratio>();
+    let volume = air.specific_volume().get::<cubic_meter_per_kilogram>();
+
+    State {
+        temperature: air.temperature(),
+        density: MassDensity::new::<kilogram_per_cubic_meter>((1.0 + w) / volume),
+        fluid: MoistAir::new(air.humidity_ratio()),
+    }
+}
+
+impl HasPressure for HumidAir {
+    /// Computes pressure with `p = ρ·R·T`, where the mixture gas constant is
+    /// `R = R_da·(1 + 1.607858·W)⁄(1 + W)`.
+    fn pressure(&self, state: &State<MoistAir>) -> Result<Pressure, PropertyError> {
+        let w = humidity_ratio(state)?;
+        let rho = state.density.get::<kilogram_per_cubic_meter>();
+        let t = state.temperature.get::<kelvin>();
+
+        Ok(Pressure::new::<pascal>(rho * gas_constant(w) * t))
+    }
+}
+
+impl HasEnthalpy for HumidAir {
+    /// Computes enthalpy per mass of moist air with
+    /// `h = [1006·t + W·(2,501,000 + 1860·t)]⁄(1 + W)` J/kg, `t` in °C.
+    fn enthalpy(&self, state: &State<MoistAir>) -> Result<SpecificEnthalpy, PropertyError> {
+        let w = humidity_ratio(state)?;
+        let t = state.temperature.get::<degree_celsius>();
+        let h = DRY_AIR_CP * t + w * (VAPOR_ENTHALPY_AT_ZERO + VAPOR_CP * t);
+
+        Ok(SpecificEnthalpy::new::<joule_per_kilogram>(h / (1.0 + w)))
+    }
+}
+
+impl HasInternalEnergy for HumidAir {
+    /// Computes internal energy with `u = h − R·T`.
+    fn internal_energy(
+        &self,
+        state: &State<MoistAir>,
+    ) -> Result<SpecificInternalEnergy, PropertyError> {
+        let w = humidity_ratio(state)?;
+        let h = self.enthalpy(state)?;
+        let pv = SpecificInternalEnergy::new::<joule_per_kilogram>(
+            gas_constant(w) * state.temperature.get::<kelvin>(),
+        );
+
+        Ok(h - pv)
+    }
+}
+
+impl HasEntropy for HumidAir {
+    /// Computes entropy per mass of moist air from the partial pressures of
+    /// dry air and vapor, `s = (s_da + W·s_v)⁄(1 + W)`, with
+    /// `s_da = cp_da·ln(T⁄T₀) − R_da·ln(p_da⁄p₀)` and
+    /// `s_v = h_g0⁄T₀ + cp_v·ln(T⁄T₀) − R_v·ln(p_v⁄p_sat(T₀))`.
+    fn entropy(&self, state: &State<MoistAir>) -> Result<SpecificEntropy, PropertyError> {
+        let w = humidity_ratio(state)?;
+        let t = state.temperature.get::<kelvin>();
+        let p = self.pressure(state)?.get::<pascal>();
+        if !(t > 0.0 && p > 0.0) {
+            return Err(PropertyError::InvalidState {
+                context: format!("temperature and pressure must be positive, got {t} K, {p} Pa"),
+            });
+        }
+
+        let vapor_fraction = w / (MOLAR_MASS_RATIO + w);
+        let p_vapor = vapor_fraction * p;
+        let p_dry = p - p_vapor;
+
+        let s_dry = DRY_AIR_CP * (t / T_REF).ln() - DRY_AIR_GAS_CONSTANT * (p_dry / P_REF).ln();
+
+        // Dry air carries no vapor term, and `W·ln(p_v)` vanishes as W → 0.
+        let s_vapor = if w > 0.0 {
+            let r_vapor = DRY_AIR_GAS_CONSTANT * GAS_CONSTANT_RATIO;
+            let p_vapor_ref =
+                saturation_pressure(ThermodynamicTemperature::new::<kelvin>(T_REF)).get::<pascal>();
+            VAPOR_ENTHALPY_AT_ZERO / T_REF + VAPOR_CP * (t / T_REF).ln()
+                - r_vapor * (p_vapor / p_vapor_ref).ln()
+        } else {
+            0.0
+        };
+
+        Ok(SpecificEntropy::new::<joule_per_kilogram_kelvin>(
+            (s_dry + w * s_vapor) / (1.0 + w),
+        ))
+    }
+}
+
+impl HasCp for HumidAir {
+    /// Computes `cp = (1006 + 1860·W)⁄(1 + W)` J/kg·K.
+    fn cp(&self, state: &State<MoistAir>) -> Result<SpecificHeatCapacity, PropertyError> {
+        let w = humidity_ratio(state)?;
+
+        Ok(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+            (DRY_AIR_CP + VAPOR_CP * w) / (1.0 + w),
+        ))
+    }
+}
+
+impl HasCv for HumidAir {
+    /// Computes `cv = cp − R` with the mixture gas constant.
+    fn cv(&self, state: &State<MoistAir>) -> Result<SpecificHeatCapacity, PropertyError> {
+        let w = humidity_ratio(state)?;
+
+        Ok(self.cp(state)?
+            - SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(gas_constant(w)))
+    }
+}
+
+impl StateFrom<(MoistAir, ThermodynamicTemperature, Pressure)> for HumidAir {
+    type Error = PsychrometricError;
+
+    fn state_from(
+        &self,
+        (fluid, temperature, pressure): (MoistAir, ThermodynamicTemperature, Pressure),
+    ) -> Result<State<MoistAir>, Self::Error> {
+        let air = psychrometrics::MoistAir::new(temperature, fluid.humidity_ratio, pressure)?;
+        Ok(state_from_psychrometric(&air))
+    }
+}
+
+impl StateFrom<(ThermodynamicTemperature, Pressure, RelativeHumidity)> for HumidAir {
+    type Error = PsychrometricError;
+
+    fn state_from(
+        &self,
+        (temperature, pressure, RelativeHumidity(relative_humidity)): (
+            ThermodynamicTemperature,
+            Pressure,
+            RelativeHumidity,
+        ),
+    ) -> Result<State<MoistAir>, Self::Error> {
+        let air = psychrometrics::MoistAir::from_relative_humidity(
+            temperature,
+            relative_humidity,
+            pressure,
+        )?;
+        Ok(state_from_psychrometric(&air))
+    }
+}
+
+impl StateFrom<(ThermodynamicTemperature, Pressure, WetBulb)> for HumidAir {
+    type Error = PsychrometricError;
+
+    fn state_from(
+        &self,
+        (temperature, pressure, WetBulb(wet_bulb)): (ThermodynamicTemperature, Pressure, WetBulb),
+    ) -> Result<State<MoistAir>, Self::Error> {
+        let air = psychrometrics::MoistAir::from_wet_bulb(temperature, wet_bulb, pressure)?;
+        Ok(state_from_psychrometric(&air))
+    }
+}
+
+impl StateFrom<(MoistAir, Pressure, SpecificEnthalpy)> for HumidAir {
+    type Error = PsychrometricError;
+
+    /// Inverts the enthalpy for the dry-bulb temperature at fixed humidity
+    /// ratio.
+    fn state_from(
+        &self,
+        (fluid, pressure, enthalpy): (MoistAir, Pressure, SpecificEnthalpy),
+    ) -> Result<State<MoistAir>, Self::Error> {
+        let w = fluid.humidity_ratio.get::<ratio>();
+        let air = psychrometrics::MoistAir::from_enthalpy(
+            enthalpy * (1.0 + w),
+            fluid.humidity_ratio,
+            pressure,
+        )?;
+        Ok(state_from_psychrometric(&air))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{available_energy::kilojoule_per_kilogram, pressure::kilopascal, ratio::percent};
+
+    use crate::support::units::test_utils::celsius;
+
+    fn atmosphere() -> Pressure {
+        Pressure::new::<kilopascal>(101.325)
+    }
+
+    #[test]
+    fn dry_air_is_zero_at_reference() -> Result<(), Box<dyn std::error::Error>> {
+        let state = HumidAir.state_from((MoistAir::default(), celsius(0.0), atmosphere()))?;
+
+        assert_relative_eq!(HumidAir.enthalpy(&state)?.get::<joule_per_kilogram>(), 0.0);
+        assert_relative_eq!(
+            HumidAir.entropy(&state)?.get::<joule_per_kilogram_kelvin>(),
+            0.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            HumidAir.cp(&state)?.get::<joule_per_kilogram_kelvin>(),
+            DRY_AIR_CP
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn saturated_air_matches_ashrae_table() -> Result<(), Box<dyn std::error::Error>> {
+        // ASHRAE Fundamentals, saturated air at 20 °C and 101.325 kPa:
+        // W = 0.014758 and h = 57.555 kJ/kg of dry air. The table includes
+        // the enhancement factor that the ideal-gas relations omit, worth
+        // about 0.4 % in W.
+        let state = HumidAir.state_from((
+            celsius(20.0),
+            atmosphere(),
+            RelativeHumidity(Ratio::new::<percent>(100.0)),
+        ))?;
+        let w = state.fluid.humidity_ratio.get::<ratio>();
+        assert_relative_eq!(w, 0.014_758, max_relative = 5e-3);
+
+        let h_dry_basis = HumidAir.enthalpy(&state)?.get::<kilojoule_per_kilogram>() * (1.0 + w);
+        assert_relative_eq!(h_dry_basis, 57.555, max_relative = 5e-3);
+        Ok(())
+    }
+
+    #[test]
+    fn agrees_with_psychrometric_state() -> Result<(), Box<dyn std::error::Error>> {
+        let state = HumidAir.state_from((
+            celsius(30.0),
+            atmosphere(),
+            RelativeHumidity(Ratio::new::<percent>(60.0)),
+        ))?;
+        let air = HumidAir.psychrometric(&state)?;
+        let w = air.humidity_ratio().get::<ratio>();
+
+        assert_relative_eq!(
+            HumidAir.pressure(&state)?.get::<kilopascal>(),
+            101.325,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            air.relative_humidity().get::<percent>(),
+            60.0,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            HumidAir.enthalpy(&state)?.get::<joule_per_kilogram>() * (1.0 + w),
+            air.enthalpy().get::<joule_per_kilogram>(),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            (1.0 + w) / state.density.get::<kilogram_per_cubic_meter>(),
+            air.specific_volume().get::<cubic_meter_per_kilogram>(),
+            max_relative = 1e-12
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn wet_bulb_roundtrip() -> Result<(), Box<dyn std::error::Error>> {
+        let wet_bulb = celsius(20.0);
+        let state = HumidAir.state_from((celsius(32.0), atmosphere(), WetBulb(wet_bulb)))?;
+
+        let recovered = HumidAir.psychrometric(&state)?.wet_bulb();
+        assert_relative_eq!(recovered.get::<degree_celsius>(), 20.0, epsilon = 1e-6);
+        Ok(())
+    }
+
+    #[test]
+    fn enthalpy_roundtrip() -> Result<(), Box<dyn std::error::Error>> {
+        let fluid = MoistAir::new(Ratio::new::<ratio>(0.01));
+        let state = HumidAir.state_from((fluid, celsius(25.0), atmosphere()))?;
+        let h = HumidAir.enthalpy(&state)?;
+
+        let recovered = HumidAir.state_from((fluid, atmosphere(), h))?;
+        assert_relative_eq!(
+            recovered.temperature.get::<degree_celsius>(),
+            25.0,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            recovered.density.get::<kilogram_per_cubic_meter>(),
+            state.density.get::<kilogram_per_cubic_meter>(),
+            max_relative = 1e-12
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn entropy_satisfies_gibbs_relation() -> Result<(), Box<dyn std::error::Error>> {
+        // At constant pressure and composition, dh = T·ds.
+        let fluid = MoistAir::new(Ratio::new::<ratio>(0.008));
+        let cold = HumidAir.state_from((fluid, celsius(24.9), atmosphere()))?;
+        let warm = HumidAir.state_from((fluid, celsius(25.1), atmosphere()))?;
+
+        let dh =
+            (HumidAir.enthalpy(&warm)? - HumidAir.enthalpy(&cold)?).get::<joule_per_kilogram>();
+        let ds = (HumidAir.entropy(&warm)? - HumidAir.entropy(&cold)?)
+            .get::<joule_per_kilogram_kelvin>();
+        assert_relative_eq!(dh / ds, celsius(25.0).get::<kelvin>(), max_relative = 1e-6);
+
+        let cp = HumidAir.cp(&cold)?.get::<joule_per_kilogram_kelvin>();
+        assert_relative_eq!(dh / 0.2, cp, max_relative = 1e-9);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_supersaturated_and_invalid_states() {
+        let wet = MoistAir::new(Ratio::new::<ratio>(0.05));
+        assert!(matches!(
+            HumidAir.state_from((wet, celsius(20.0), atmosphere())),
+            Err(PsychrometricError::Supersaturated { .. })
+        ));
+
+        let state = State {
+            temperature: celsius(20.0),
+            density: MassDensity::new::<kilogram_per_cubic_meter>(1.2),
+            fluid: MoistAir::new(Ratio::new::<ratio>(-0.01)),
+        };
+        assert!(matches!(
+            HumidAir.enthalpy(&state),
+            Err(PropertyError::InvalidState { .. })
+        ));
+    }
+}
@@ -14,27 +14,38 @@
 //! Use this model when pressure/density variation is negligible and you only
 //! need sensible heat effects (e.g. many water heating/storage problems).
 //!
-//! If you need temperature/pressure dependent properties (or phase change),
-//! use [`super::CoolProp`] (when enabled) instead.
+//! If you need temperature dependent properties, use
+//! [`super::PolynomialIncompressible`]. For pressure dependence or phase
+//! change, use [`super::CoolProp`] (when enabled) instead.
 //!
 //! # Reference State
 //!
 //! Enthalpy and entropy are reported relative to a configurable reference state
 //! (`T_ref`, `ρ_ref`, `h_ref`, `s_ref`).
 
-use std::{convert::Infallible, marker::PhantomData};
+use core::{convert::Infallible, marker::PhantomData};
 
+use num_traits::Float;
 use thiserror::Error;
 use uom::{
     ConstZero,
     si::{
-        f64::{MassDensity, SpecificHeatCapacity, ThermodynamicTemperature},
+        dynamic_viscosity::pascal_second,
+        f64::{
+            DynamicViscosity, MassDensity, SpecificHeatCapacity, ThermalConductivity,
+            ThermodynamicTemperature, Velocity,
+        },
         mass_density::kilogram_per_cubic_meter,
+        ratio::ratio,
         specific_heat_capacity::joule_per_kilogram_kelvin,
+        thermal_conductivity::watt_per_meter_kelvin,
         thermodynamic_temperature::{degree_celsius, kelvin},
+        velocity::meter_per_second,
     },
 };
 
+use super::transport::TransportProperties;
+
 use crate::support::units::{
     SpecificEnthalpy, SpecificEntropy, SpecificInternalEnergy, TemperatureDifference,
 };
@@ -43,7 +54,8 @@ use crate::support::{
     thermo::{
         PropertyError, State,
         capability::{
-            HasCp, HasCv, HasEnthalpy, HasEntropy, HasInternalEnergy, StateFrom, ThermoModel,
+            HasCp, HasCv, HasEnthalpy, HasEntropy, HasInternalEnergy, HasSpeedOfSound,
+            HasThermalConductivity, HasTwoPhase, HasViscosity, StateFrom, ThermoModel, TwoPhase,
         },
     },
 };
@@ -56,6 +68,10 @@ pub enum IncompressibleParametersError {
     ReferenceTemperature { t_ref: ThermodynamicTemperature },
     #[error("invalid reference density: {rho_ref:?}")]
     ReferenceDensity { rho_ref: MassDensity },
+    #[error("invalid viscosity: {viscosity:?}")]
+    Viscosity { viscosity: DynamicViscosity },
+    #[error("invalid thermal conductivity: {conductivity:?}")]
+    Conductivity { conductivity: ThermalConductivity },
 }
 
 /// Reference values used to define enthalpy/entropy offsets for an [`Incompressible`] model.
@@ -87,6 +103,7 @@ impl IncompressibleReference {
 pub struct IncompressibleParameters {
     pub cp: SpecificHeatCapacity,
     pub reference: IncompressibleReference,
+    pub transport: Option<TransportProperties>,
 }
 
 impl IncompressibleParameters {
@@ -95,6 +112,7 @@ impl IncompressibleParameters {
         Self {
             cp,
             reference: IncompressibleReference::standard(reference_density),
+            transport: None,
         }
     }
 
@@ -103,6 +121,14 @@ impl IncompressibleParameters {
         self.reference = reference;
         self
     }
+
+    /// Adds constant transport properties, enabling [`HasViscosity`] and
+    /// [`HasThermalConductivity`] on the model.
+    #[must_use]
+    pub fn with_transport(mut self, transport: TransportProperties) -> Self {
+        self.transport = Some(transport);
+        self
+    }
 }
 
 /// Fluid constants required by the [`Incompressible`] model.
@@ -119,6 +145,7 @@ pub struct Incompressible<Fluid> {
     rho_ref: MassDensity,
     h_ref: SpecificEnthalpy,
     s_ref: SpecificEntropy,
+    transport: Option<TransportProperties>,
     _marker: PhantomData<Fluid>,
 }
 
@@ -152,12 +179,24 @@ impl<Fluid> Incompressible<Fluid> {
             return Err(IncompressibleParametersError::ReferenceDensity { rho_ref });
         }
 
+        if let Some(transport) = parameters.transport {
+            let viscosity = transport.viscosity;
+            if StrictlyPositive::check(&viscosity.get::<pascal_second>()).is_err() {
+                return Err(IncompressibleParametersError::Viscosity { viscosity });
+            }
+            let conductivity = transport.conductivity;
+            if StrictlyPositive::check(&conductivity.get::<watt_per_meter_kelvin>()).is_err() {
+                return Err(IncompressibleParametersError::Conductivity { conductivity });
+            }
+        }
+
         Ok(Self {
             cp,
             t_ref,
             rho_ref,
             h_ref: parameters.reference.enthalpy,
             s_ref: parameters.reference.entropy,
+            transport: parameters.transport,
             _marker: PhantomData,
         })
     }
@@ -180,6 +219,73 @@ impl<Fluid> Incompressible<Fluid> {
             fluid,
         }
     }
+
+    /// Returns the unit-free form of this model in the float type `F`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a model constant cannot be represented in `F`, which does
+    /// not happen for `f32` or `f64`.
+    #[must_use]
+    pub fn raw<F: Float>(&self) -> RawIncompressible<F> {
+        let float = |value: f64| F::from(value).expect("f64 constants convert to any float type");
+        RawIncompressible {
+            cp: float(self.cp.value),
+            t_ref: float(self.t_ref.get::<kelvin>()),
+            rho_ref: float(self.rho_ref.value),
+            h_ref: float(self.h_ref.value),
+            s_ref: float(self.s_ref.value),
+        }
+    }
+}
+
+/// Unit-free incompressible liquid relations in any float type.
+///
+/// This is the raw form of an [`Incompressible`] model, from
+/// [`Incompressible::raw`], for callers that work in `f32` or keep values
+/// outside `uom`. Every value is in SI base units: K, kg/m³, J/kg, and
+/// J/(kg·K). The relations match the typed model's to rounding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RawIncompressible<F> {
+    cp: F,
+    t_ref: F,
+    rho_ref: F,
+    h_ref: F,
+    s_ref: F,
+}
+
+impl<F: Float> RawIncompressible<F> {
+    /// Returns the constant specific heat.
+    #[must_use]
+    pub fn cp(&self) -> F {
+        self.cp
+    }
+
+    /// Returns the constant reference density.
+    #[must_use]
+    pub fn reference_density(&self) -> F {
+        self.rho_ref
+    }
+
+    /// Computes enthalpy using `h = h₀ + c·(T − T₀)`.
+    ///
+    /// Internal energy is equal to enthalpy.
+    #[must_use]
+    pub fn enthalpy(&self, temperature: F) -> F {
+        self.h_ref + self.cp * (temperature - self.t_ref)
+    }
+
+    /// Computes entropy with `s = s₀ + c·ln(T/T₀)`.
+    #[must_use]
+    pub fn entropy(&self, temperature: F) -> F {
+        self.s_ref + self.cp * (temperature / self.t_ref).ln()
+    }
+
+    /// Inverts `h = h₀ + c·(T − T₀)` for temperature.
+    #[must_use]
+    pub fn temperature_from_enthalpy(&self, enthalpy: F) -> F {
+        self.t_ref + (enthalpy - self.h_ref) / self.cp
+    }
 }
 
 impl<Fluid> HasInternalEnergy for Incompressible<Fluid> {
@@ -210,7 +316,7 @@ impl<Fluid> HasEntropy for Incompressible<Fluid> {
         let t_ref = self.t_ref;
         let s_ref = self.s_ref;
 
-        Ok(s_ref + c * (state.temperature / t_ref).ln())
+        Ok(s_ref + c * (state.temperature / t_ref).get::<ratio>().ln())
     }
 }
 
@@ -228,6 +334,49 @@ impl<Fluid> HasCv for Incompressible<Fluid> {
     }
 }
 
+impl<Fluid> HasSpeedOfSound for Incompressible<Fluid> {
+    /// Returns an infinite speed of sound; density does not respond to pressure.
+    fn speed_of_sound(&self, _state: &State<Fluid>) -> Result<Velocity, PropertyError> {
+        Ok(Velocity::new::<meter_per_second>(f64::INFINITY))
+    }
+}
+
+impl<Fluid> Incompressible<Fluid> {
+    /// Returns the transport properties, or an error if none were given.
+    fn transport(&self) -> Result<TransportProperties, PropertyError> {
+        self.transport.ok_or_else(|| PropertyError::Undefined {
+            context:
+                "no transport properties; set them with `IncompressibleParameters::with_transport`"
+                    .into(),
+        })
+    }
+}
+
+impl<Fluid> HasViscosity for Incompressible<Fluid> {
+    /// Returns the constant viscosity from the model's transport properties.
+    fn viscosity(&self, _state: &State<Fluid>) -> Result<DynamicViscosity, PropertyError> {
+        self.transport().map(|transport| transport.viscosity)
+    }
+}
+
+impl<Fluid> HasThermalConductivity for Incompressible<Fluid> {
+    /// Returns the constant thermal conductivity from the model's transport
+    /// properties.
+    fn thermal_conductivity(
+        &self,
+        _state: &State<Fluid>,
+    ) -> Result<ThermalConductivity, PropertyError> {
+        self.transport().map(|transport| transport.conductivity)
+    }
+}
+
+impl<Fluid> HasTwoPhase for Incompressible<Fluid> {
+    /// Returns `None`; the model describes a single-phase liquid.
+    fn two_phase(&self, _state: &State<Fluid>) -> Result<Option<TwoPhase>, PropertyError> {
+        Ok(None)
+    }
+}
+
 /// Enables state creation from temperature alone.
 ///
 /// The returned state uses the fluid's reference density.
@@ -265,10 +414,12 @@ mod tests {
 
     use approx::assert_relative_eq;
     use uom::si::{
+        dynamic_viscosity::pascal_second,
         f64::{MassDensity, SpecificHeatCapacity, ThermodynamicTemperature},
         mass_density::kilogram_per_cubic_meter,
         specific_heat_capacity::kilojoule_per_kilogram_kelvin,
-        thermodynamic_temperature::degree_celsius,
+        thermal_conductivity::watt_per_meter_kelvin,
+        thermodynamic_temperature::{degree_celsius, kelvin},
     };
 
     use crate::support::thermo::capability::HasCp;
@@ -342,4 +493,72 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn transport_properties_are_opt_in() {
+        #[derive(Debug, Clone, Copy, Default)]
+        struct ViscousLiquid;
+
+        impl IncompressibleFluid for ViscousLiquid {
+            fn parameters() -> IncompressibleParameters {
+                MockLiquid::parameters().with_transport(TransportProperties::new(
+                    DynamicViscosity::new::<pascal_second>(2e-3),
+                    ThermalConductivity::new::<watt_per_meter_kelvin>(0.5),
+                ))
+            }
+        }
+
+        let state = mock_liquid_model().reference_state(MockLiquid);
+        assert!(matches!(
+            mock_liquid_model().thermal_conductivity(&state),
+            Err(PropertyError::Undefined { .. })
+        ));
+
+        let thermo = Incompressible::<ViscousLiquid>::new().unwrap();
+        let state = thermo.reference_state(ViscousLiquid);
+        assert_relative_eq!(
+            thermo.viscosity(&state).unwrap().get::<pascal_second>(),
+            2e-3
+        );
+        assert_relative_eq!(
+            thermo
+                .thermal_conductivity(&state)
+                .unwrap()
+                .get::<watt_per_meter_kelvin>(),
+            0.5
+        );
+    }
+
+    #[test]
+    fn raw_matches_typed() -> Result<(), PropertyError> {
+        let thermo = mock_liquid_model();
+        let state: State<MockLiquid> = thermo
+            .state_from((
+                MockLiquid,
+                ThermodynamicTemperature::new::<degree_celsius>(60.0),
+            ))
+            .unwrap();
+        let t = state.temperature.get::<kelvin>();
+
+        let raw = thermo.raw::<f64>();
+        assert_relative_eq!(raw.enthalpy(t), thermo.enthalpy(&state)?.value);
+        assert_relative_eq!(raw.entropy(t), thermo.entropy(&state)?.value);
+        assert_relative_eq!(raw.reference_density(), 1.0);
+
+        // Single precision agrees to its own rounding.
+        #[allow(clippy::cast_possible_truncation)]
+        let t = t as f32;
+        let raw = thermo.raw::<f32>();
+        assert_relative_eq!(
+            f64::from(raw.enthalpy(t)),
+            thermo.enthalpy(&state)?.value,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(
+            raw.temperature_from_enthalpy(raw.enthalpy(t)),
+            t,
+            max_relative = 1e-6
+        );
+        Ok(())
+    }
 }
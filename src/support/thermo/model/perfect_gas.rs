@@ -21,17 +21,25 @@
 //! Enthalpy and entropy are reported relative to a configurable reference state
 //! (`T_ref`, `p_ref`, `h_ref`, `s_ref`).
 
-use std::{convert::Infallible, marker::PhantomData};
+use core::{convert::Infallible, marker::PhantomData};
 
+use num_traits::Float;
 use thiserror::Error;
 use uom::{
     ConstZero,
     si::{
-        f64::{MassDensity, Pressure, SpecificHeatCapacity, ThermodynamicTemperature},
+        available_energy::joule_per_kilogram,
+        dynamic_viscosity::pascal_second,
+        f64::{
+            DynamicViscosity, MassDensity, Pressure, SpecificHeatCapacity, ThermalConductivity,
+            ThermodynamicTemperature, Velocity,
+        },
         pressure::{atmosphere, pascal},
         ratio::ratio,
         specific_heat_capacity::joule_per_kilogram_kelvin,
+        thermal_conductivity::watt_per_meter_kelvin,
         thermodynamic_temperature::{degree_celsius, kelvin},
+        velocity::meter_per_second,
     },
 };
 
@@ -44,13 +52,13 @@ use crate::support::{
     thermo::{
         PropertyError, State,
         capability::{
-            HasCp, HasCv, HasEnthalpy, HasEntropy, HasInternalEnergy, HasPressure, StateFrom,
-            ThermoModel,
+            HasCp, HasCv, HasEnthalpy, HasEntropy, HasInternalEnergy, HasPressure, HasSpeedOfSound,
+            HasThermalConductivity, HasTwoPhase, HasViscosity, StateFrom, ThermoModel, TwoPhase,
         },
     },
 };
 
-use super::ideal_gas_eos;
+use super::{ideal_gas_eos, transport::TransportProperties};
 
 #[derive(Debug, Error, Clone, PartialEq)]
 pub enum PerfectGasParametersError {
@@ -68,6 +76,10 @@ pub enum PerfectGasParametersError {
         cp: SpecificHeatCapacity,
         cv: SpecificHeatCapacity,
     },
+    #[error("invalid viscosity: {viscosity:?}")]
+    Viscosity { viscosity: DynamicViscosity },
+    #[error("invalid thermal conductivity: {conductivity:?}")]
+    Conductivity { conductivity: ThermalConductivity },
 }
 
 /// Reference values used to define enthalpy/entropy offsets for a [`PerfectGas`] model.
@@ -100,6 +112,7 @@ pub struct PerfectGasParameters {
     pub gas_constant: SpecificGasConstant,
     pub cp: SpecificHeatCapacity,
     pub reference: PerfectGasReference,
+    pub transport: Option<TransportProperties>,
 }
 
 impl PerfectGasParameters {
@@ -109,6 +122,7 @@ impl PerfectGasParameters {
             gas_constant,
             cp,
             reference: PerfectGasReference::standard(),
+            transport: None,
         }
     }
 
@@ -117,6 +131,14 @@ impl PerfectGasParameters {
         self.reference = reference;
         self
     }
+
+    /// Adds constant transport properties, enabling [`HasViscosity`] and
+    /// [`HasThermalConductivity`] on the model.
+    #[must_use]
+    pub fn with_transport(mut self, transport: TransportProperties) -> Self {
+        self.transport = Some(transport);
+        self
+    }
 }
 
 /// Fluid constants required by the [`PerfectGas`] model.
@@ -135,6 +157,7 @@ pub struct PerfectGas<Fluid> {
     p_ref: Pressure,
     h_ref: SpecificEnthalpy,
     s_ref: SpecificEntropy,
+    transport: Option<TransportProperties>,
     _marker: PhantomData<Fluid>,
 }
 
@@ -185,6 +208,17 @@ impl<Fluid: PerfectGasFluid> PerfectGas<Fluid> {
             });
         }
 
+        if let Some(transport) = parameters.transport {
+            let viscosity = transport.viscosity;
+            if StrictlyPositive::check(&viscosity.get::<pascal_second>()).is_err() {
+                return Err(PerfectGasParametersError::Viscosity { viscosity });
+            }
+            let conductivity = transport.conductivity;
+            if StrictlyPositive::check(&conductivity.get::<watt_per_meter_kelvin>()).is_err() {
+                return Err(PerfectGasParametersError::Conductivity { conductivity });
+            }
+        }
+
         Ok(Self {
             r: gas_constant,
             cp,
@@ -193,6 +227,7 @@ impl<Fluid: PerfectGasFluid> PerfectGas<Fluid> {
             p_ref: reference_pressure,
             h_ref: parameters.reference.enthalpy,
             s_ref: parameters.reference.entropy,
+            transport: parameters.transport,
             _marker: PhantomData,
         })
     }
@@ -212,6 +247,115 @@ impl<Fluid: PerfectGasFluid> PerfectGas<Fluid> {
     }
 }
 
+impl<Fluid> PerfectGas<Fluid> {
+    /// Returns the unit-free form of this model in the float type `F`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a model constant cannot be represented in `F`, which does
+    /// not happen for `f32` or `f64`.
+    #[must_use]
+    pub fn raw<F: Float>(&self) -> RawPerfectGas<F> {
+        let float = |value: f64| F::from(value).expect("f64 constants convert to any float type");
+        RawPerfectGas {
+            r: float(self.r.value),
+            cp: float(self.cp.value),
+            cv: float(self.cv.value),
+            t_ref: float(self.t_ref.get::<kelvin>()),
+            p_ref: float(self.p_ref.get::<pascal>()),
+            h_ref: float(self.h_ref.value),
+            s_ref: float(self.s_ref.value),
+        }
+    }
+}
+
+/// Unit-free perfect gas relations in any float type.
+///
+/// This is the raw form of a [`PerfectGas`] model, from [`PerfectGas::raw`],
+/// for callers that work in `f32` or keep values outside `uom`. Every value is
+/// in SI base units: K, Pa, kg/m³, J/kg, and J/(kg·K). The relations match
+/// the typed model's to rounding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RawPerfectGas<F> {
+    r: F,
+    cp: F,
+    cv: F,
+    t_ref: F,
+    p_ref: F,
+    h_ref: F,
+    s_ref: F,
+}
+
+impl<F: Float> RawPerfectGas<F> {
+    /// Returns the specific gas constant `R`.
+    #[must_use]
+    pub fn gas_constant(&self) -> F {
+        self.r
+    }
+
+    /// Returns the constant `cp`.
+    #[must_use]
+    pub fn cp(&self) -> F {
+        self.cp
+    }
+
+    /// Returns the constant `cv`.
+    #[must_use]
+    pub fn cv(&self) -> F {
+        self.cv
+    }
+
+    /// Computes pressure with `P = ρ·R·T`.
+    #[must_use]
+    pub fn pressure(&self, temperature: F, density: F) -> F {
+        density * self.r * temperature
+    }
+
+    /// Computes density with `ρ = P⁄(R·T)`.
+    #[must_use]
+    pub fn density(&self, temperature: F, pressure: F) -> F {
+        pressure / (self.r * temperature)
+    }
+
+    /// Computes enthalpy with `h = h₀ + cp·(T − T₀)`.
+    #[must_use]
+    pub fn enthalpy(&self, temperature: F) -> F {
+        self.h_ref + self.cp * (temperature - self.t_ref)
+    }
+
+    /// Computes internal energy with `u = h − R·T`.
+    #[must_use]
+    pub fn internal_energy(&self, temperature: F) -> F {
+        self.enthalpy(temperature) - self.r * temperature
+    }
+
+    /// Computes entropy with `s = s₀ + cp·ln(T⁄T₀) − R·ln(p⁄p₀)`.
+    #[must_use]
+    pub fn entropy(&self, temperature: F, pressure: F) -> F {
+        self.s_ref + self.cp * (temperature / self.t_ref).ln()
+            - self.r * (pressure / self.p_ref).ln()
+    }
+
+    /// Computes the speed of sound with `a = √(γ·R·T)`, where `γ = cp⁄cv`.
+    #[must_use]
+    pub fn speed_of_sound(&self, temperature: F) -> F {
+        (self.cp / self.cv * self.r * temperature).sqrt()
+    }
+
+    /// Inverts `h = h₀ + cp·(T − T₀)` for temperature.
+    #[must_use]
+    pub fn temperature_from_enthalpy(&self, enthalpy: F) -> F {
+        self.t_ref + (enthalpy - self.h_ref) / self.cp
+    }
+
+    /// Inverts `s = s₀ + cp·ln(T⁄T₀) − R·ln(p⁄p₀)` for temperature.
+    #[must_use]
+    pub fn temperature_from_entropy(&self, pressure: F, entropy: F) -> F {
+        let exponent = (entropy - self.s_ref + self.r * (pressure / self.p_ref).ln()) / self.cp;
+        self.t_ref * exponent.exp()
+    }
+}
+
 impl<Fluid> HasPressure for PerfectGas<Fluid> {
     /// Computes pressure with `P = ρ·R·T`.
     fn pressure(&self, state: &State<Fluid>) -> Result<Pressure, PropertyError> {
@@ -255,7 +399,8 @@ impl<Fluid> HasEntropy for PerfectGas<Fluid> {
 
         let p = self.pressure(state)?;
 
-        Ok(s_ref + cp * (state.temperature / t_ref).ln() - r * (p / p_ref).ln())
+        Ok(s_ref + cp * (state.temperature / t_ref).get::<ratio>().ln()
+            - r * (p / p_ref).get::<ratio>().ln())
     }
 }
 
@@ -273,6 +418,55 @@ impl<Fluid> HasCv for PerfectGas<Fluid> {
     }
 }
 
+impl<Fluid> HasSpeedOfSound for PerfectGas<Fluid> {
+    /// Computes the speed of sound with `a = √(γ·R·T)`, where `γ = cp⁄cv`.
+    fn speed_of_sound(&self, state: &State<Fluid>) -> Result<Velocity, PropertyError> {
+        let gamma = (self.cp / self.cv).get::<ratio>();
+        let a_squared: SpecificInternalEnergy = gamma * self.r * state.temperature;
+
+        // Take the root on the raw value; `Quantity::sqrt` requires `std`.
+        Ok(Velocity::new::<meter_per_second>(
+            a_squared.get::<joule_per_kilogram>().sqrt(),
+        ))
+    }
+}
+
+impl<Fluid> PerfectGas<Fluid> {
+    /// Returns the transport properties, or an error if none were given.
+    fn transport(&self) -> Result<TransportProperties, PropertyError> {
+        self.transport.ok_or_else(|| PropertyError::Undefined {
+            context:
+                "no transport properties; set them with `PerfectGasParameters::with_transport`"
+                    .into(),
+        })
+    }
+}
+
+impl<Fluid> HasViscosity for PerfectGas<Fluid> {
+    /// Returns the constant viscosity from the model's transport properties.
+    fn viscosity(&self, _state: &State<Fluid>) -> Result<DynamicViscosity, PropertyError> {
+        self.transport().map(|transport| transport.viscosity)
+    }
+}
+
+impl<Fluid> HasThermalConductivity for PerfectGas<Fluid> {
+    /// Returns the constant thermal conductivity from the model's transport
+    /// properties.
+    fn thermal_conductivity(
+        &self,
+        _state: &State<Fluid>,
+    ) -> Result<ThermalConductivity, PropertyError> {
+        self.transport().map(|transport| transport.conductivity)
+    }
+}
+
+impl<Fluid> HasTwoPhase for PerfectGas<Fluid> {
+    /// Returns `None`; the model describes a single-phase gas.
+    fn two_phase(&self, _state: &State<Fluid>) -> Result<Option<TwoPhase>, PropertyError> {
+        Ok(None)
+    }
+}
+
 impl<Fluid> StateFrom<(Fluid, ThermodynamicTemperature, MassDensity)> for PerfectGas<Fluid> {
     type Error = Infallible;
 
@@ -344,6 +538,25 @@ impl<Fluid> StateFrom<(Fluid, Pressure, SpecificEnthalpy)> for PerfectGas<Fluid>
     }
 }
 
+impl<Fluid> StateFrom<(Fluid, MassDensity, SpecificInternalEnergy)> for PerfectGas<Fluid> {
+    type Error = Infallible;
+
+    /// Inverts `u = u₀ + cv·(T − T₀)`, where `u₀ = h₀ − R·T₀`.
+    fn state_from(
+        &self,
+        (fluid, density, internal_energy): (Fluid, MassDensity, SpecificInternalEnergy),
+    ) -> Result<State<Fluid>, Self::Error> {
+        let u_ref = self.h_ref - self.r * self.t_ref;
+        let temperature = self.t_ref + (internal_energy - u_ref) / self.cv;
+
+        Ok(State {
+            temperature,
+            density,
+            fluid,
+        })
+    }
+}
+
 impl<Fluid> StateFrom<(Fluid, Pressure, SpecificEntropy)> for PerfectGas<Fluid> {
     type Error = Infallible;
 
@@ -357,7 +570,7 @@ impl<Fluid> StateFrom<(Fluid, Pressure, SpecificEntropy)> for PerfectGas<Fluid>
         let p_ref = self.p_ref;
         let s_ref = self.s_ref;
 
-        let exponent = ((entropy - s_ref) + r * (pressure / p_ref).ln()) / cp;
+        let exponent = ((entropy - s_ref) + r * (pressure / p_ref).get::<ratio>().ln()) / cp;
         let temperature = t_ref * exponent.get::<ratio>().exp();
         let density = ideal_gas_eos::density(temperature, pressure, r);
 
@@ -384,7 +597,7 @@ impl<Fluid> StateFrom<(Fluid, SpecificEnthalpy, SpecificEntropy)> for PerfectGas
         let s_ref = self.s_ref;
 
         let temperature = t_ref + (enthalpy - h_ref) / cp;
-        let exponent = (cp * (temperature / t_ref).ln() + s_ref - entropy) / r;
+        let exponent = (cp * (temperature / t_ref).get::<ratio>().ln() + s_ref - entropy) / r;
         let pressure = p_ref * exponent.get::<ratio>().exp();
         let density = ideal_gas_eos::density(temperature, pressure, r);
 
@@ -402,10 +615,13 @@ mod tests {
 
     use approx::assert_relative_eq;
     use uom::si::{
+        dynamic_viscosity::pascal_second,
         mass_density::pound_per_cubic_foot,
         pressure::{atmosphere, kilopascal, pascal, psi},
         specific_heat_capacity::joule_per_kilogram_kelvin,
+        thermal_conductivity::watt_per_meter_kelvin,
         thermodynamic_temperature::{degree_celsius, kelvin},
+        velocity::meter_per_second,
     };
 
     use crate::support::thermo::fluid::CarbonDioxide;
@@ -439,6 +655,24 @@ mod tests {
         assert_eq!(h_ref, SpecificEnthalpy::ZERO);
     }
 
+    #[test]
+    fn speed_of_sound_matches_ideal_gas_relation() -> Result<(), PropertyError> {
+        let thermo = mock_gas_model();
+
+        // γ = 1000 / 600, so a² = γ·R·T = (5/3)·400·300 = 200,000 m²/s².
+        let state = thermo
+            .state_from((
+                MockGas,
+                ThermodynamicTemperature::new::<kelvin>(300.0),
+                Pressure::new::<kilopascal>(100.0),
+            ))
+            .unwrap();
+        let a = thermo.speed_of_sound(&state)?;
+        assert_relative_eq!(a.get::<meter_per_second>(), 200_000.0_f64.sqrt());
+
+        Ok(())
+    }
+
     #[test]
     fn increase_temperature_at_constant_density() -> Result<(), PropertyError> {
         let thermo = mock_gas_model();
@@ -556,6 +790,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn state_from_density_internal_energy_roundtrip() -> Result<(), PropertyError> {
+        let thermo = mock_gas_model();
+
+        let temp_in = ThermodynamicTemperature::new::<degree_celsius>(60.0);
+        let pres_in = Pressure::new::<kilopascal>(300.0);
+        let state_in: State<MockGas> = thermo.state_from((MockGas, temp_in, pres_in)).unwrap();
+
+        let u = thermo.internal_energy(&state_in)?;
+        let state_out: State<MockGas> = thermo.state_from((MockGas, state_in.density, u)).unwrap();
+
+        assert_relative_eq!(
+            state_out.temperature.get::<kelvin>(),
+            temp_in.get::<kelvin>(),
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            thermo.pressure(&state_out)?.get::<pascal>(),
+            pres_in.get::<pascal>(),
+            epsilon = 1e-8
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn carbon_dioxide_parameters_smoke_test() {
         let thermo = PerfectGas::<CarbonDioxide>::new().unwrap();
@@ -563,4 +822,93 @@ mod tests {
         let state = thermo.reference_state(CarbonDioxide);
         assert_relative_eq!(thermo.pressure(&state).unwrap().get::<atmosphere>(), 1.0);
     }
+
+    #[test]
+    fn transport_properties_are_opt_in() {
+        let state = mock_gas_model().reference_state(MockGas);
+        assert!(matches!(
+            mock_gas_model().viscosity(&state),
+            Err(PropertyError::Undefined { .. })
+        ));
+
+        let thermo = PerfectGas::<CarbonDioxide>::new().unwrap();
+        let state = thermo.reference_state(CarbonDioxide);
+        assert_relative_eq!(
+            thermo.viscosity(&state).unwrap().get::<pascal_second>(),
+            14.93e-6
+        );
+        assert_relative_eq!(
+            thermo
+                .thermal_conductivity(&state)
+                .unwrap()
+                .get::<watt_per_meter_kelvin>(),
+            0.01662
+        );
+    }
+
+    #[test]
+    fn rejects_nonpositive_viscosity() {
+        #[derive(Debug, Clone, Copy, Default)]
+        struct Inviscid;
+
+        impl PerfectGasFluid for Inviscid {
+            fn parameters() -> PerfectGasParameters {
+                MockGas::parameters().with_transport(TransportProperties::new(
+                    DynamicViscosity::new::<pascal_second>(0.0),
+                    ThermalConductivity::new::<watt_per_meter_kelvin>(0.025),
+                ))
+            }
+        }
+
+        assert!(matches!(
+            PerfectGas::<Inviscid>::new(),
+            Err(PerfectGasParametersError::Viscosity { .. })
+        ));
+    }
+
+    #[test]
+    fn raw_matches_typed() -> Result<(), PropertyError> {
+        let thermo = mock_gas_model();
+        let state = thermo
+            .state_from((
+                MockGas,
+                ThermodynamicTemperature::new::<kelvin>(350.0),
+                Pressure::new::<kilopascal>(250.0),
+            ))
+            .unwrap();
+
+        let raw = thermo.raw::<f64>();
+        assert_relative_eq!(raw.enthalpy(350.0), thermo.enthalpy(&state)?.value);
+        assert_relative_eq!(
+            raw.internal_energy(350.0),
+            thermo.internal_energy(&state)?.value
+        );
+        assert_relative_eq!(
+            raw.entropy(350.0, 250e3),
+            thermo.entropy(&state)?.value,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            raw.speed_of_sound(350.0),
+            thermo.speed_of_sound(&state)?.get::<meter_per_second>()
+        );
+        assert_relative_eq!(raw.density(350.0, 250e3), state.density.value);
+
+        // Single precision agrees to its own rounding.
+        let raw = thermo.raw::<f32>();
+        let h = raw.enthalpy(350.0);
+        let s = raw.entropy(350.0, 250e3);
+        assert_relative_eq!(raw.temperature_from_enthalpy(h), 350.0, max_relative = 1e-6);
+        assert_relative_eq!(
+            raw.temperature_from_entropy(250e3, s),
+            350.0,
+            max_relative = 1e-5
+        );
+        assert_relative_eq!(
+            f64::from(raw.pressure(350.0, raw.density(350.0, 250e3))),
+            250e3,
+            max_relative = 1e-6
+        );
+        Ok(())
+    }
 }
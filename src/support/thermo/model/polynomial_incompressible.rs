@@ -0,0 +1,762 @@
+//! Incompressible liquid model with temperature-dependent properties.
+//!
+//! `PolynomialIncompressible` extends the [`Incompressible`](super::Incompressible)
+//! approximation with properties that vary with temperature. Specific heat,
+//! density, and thermal conductivity are polynomials in the temperature
+//! offset from a base temperature, and viscosity is a polynomial or the
+//! exponential of one, following the fits of the `INCOMP` backend in `CoolProp`.
+//! Glycol mixtures, brines, thermal oils, and molten salts can then be
+//! modeled accurately without the `coolprop` feature.
+//!
+//! # Assumptions
+//!
+//! - Density depends on temperature only (`ρ = ρ(T)`)
+//! - Pressure effects are not modeled: `cv = cp` and `u = h`
+//!
+//! # Fits
+//!
+//! Every fit is evaluated at `x = T − T_base` in kelvin and returns SI
+//! units: J/(kg·K) for `cp`, kg/m³ for `ρ`, W/(m·K) for `k`, and Pa·s for
+//! `μ` (or its natural log for [`ViscosityFit::ExpPolynomial`]).
+//! Enthalpy and entropy integrate the `cp` fit exactly.
+//!
+//! An optional temperature range rejects states outside the span of the
+//! fitted data with [`PropertyError::OutOfDomain`]. Without one, the fits
+//! are extrapolated.
+//!
+//! # Reference State
+//!
+//! Enthalpy and entropy are reported relative to a configurable reference
+//! state (`T_ref`, `h_ref`, `s_ref`).
+//!
+//! # Example
+//!
+//! ```
+//! use twine_models::support::thermo::{
+//!     capability::{HasCp, StateFrom},
+//!     fluid::SolarSalt,
+//!     model::PolynomialIncompressible,
+//! };
+//! use uom::si::{f64::ThermodynamicTemperature, thermodynamic_temperature::degree_celsius};
+//!
+//! let salt = PolynomialIncompressible::<SolarSalt>::new()?;
+//!
+//! let cold = salt.state_from(ThermodynamicTemperature::new::<degree_celsius>(290.0))?;
+//! let hot = salt.state_from(ThermodynamicTemperature::new::<degree_celsius>(565.0))?;
+//!
+//! // Hot salt is lighter and holds more heat per kelvin.
+//! assert!(hot.density < cold.density);
+//! assert!(salt.cp(&hot)? > salt.cp(&cold)?);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use alloc::{format, vec::Vec};
+use core::{marker::PhantomData, ops::RangeInclusive};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float as _;
+use thiserror::Error;
+use uom::{
+    ConstZero,
+    si::{
+        available_energy::joule_per_kilogram,
+        dynamic_viscosity::pascal_second,
+        f64::{
+            DynamicViscosity, MassDensity, SpecificHeatCapacity, ThermalConductivity,
+            ThermodynamicTemperature, Velocity,
+        },
+        mass_density::kilogram_per_cubic_meter,
+        specific_heat_capacity::joule_per_kilogram_kelvin,
+        thermal_conductivity::watt_per_meter_kelvin,
+        thermodynamic_temperature::{degree_celsius, kelvin},
+        velocity::meter_per_second,
+    },
+};
+
+use crate::support::{
+    thermo::{
+        PropertyError, State,
+        capability::{
+            HasCp, HasCv, HasEnthalpy, HasEntropy, HasInternalEnergy, HasSpeedOfSound,
+            HasThermalConductivity, HasTwoPhase, HasViscosity, StateFrom, ThermoModel, TwoPhase,
+        },
+    },
+    units::{SpecificEnthalpy, SpecificEntropy, SpecificInternalEnergy},
+};
+
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum PolynomialIncompressibleParametersError {
+    #[error("{property} fit has no coefficients")]
+    EmptyFit { property: &'static str },
+    #[error("{property} fit has a non-finite coefficient")]
+    NonFiniteCoefficient { property: &'static str },
+    #[error("invalid base temperature: {t_base:?}")]
+    BaseTemperature { t_base: ThermodynamicTemperature },
+    #[error("invalid reference temperature: {t_ref:?}")]
+    ReferenceTemperature { t_ref: ThermodynamicTemperature },
+    #[error("invalid temperature range: {range:?}")]
+    TemperatureRange {
+        range: RangeInclusive<ThermodynamicTemperature>,
+    },
+    #[error("invalid cp at the reference temperature: {cp:?}")]
+    Cp { cp: SpecificHeatCapacity },
+    #[error("invalid density at the reference temperature: {density:?}")]
+    Density { density: MassDensity },
+}
+
+/// A polynomial `c₀ + c₁·x + c₂·x² + …` in the temperature offset `x`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    coefficients: Vec<f64>,
+}
+
+impl Polynomial {
+    /// Creates a polynomial from its coefficients, lowest order first.
+    #[must_use]
+    pub fn new(coefficients: impl Into<Vec<f64>>) -> Self {
+        Self {
+            coefficients: coefficients.into(),
+        }
+    }
+
+    /// Creates a polynomial that always evaluates to `value`.
+    #[must_use]
+    pub fn constant(value: f64) -> Self {
+        Self::new([value])
+    }
+
+    /// Returns the coefficients, lowest order first.
+    #[must_use]
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Evaluates the polynomial at `x` with Horner's method.
+    #[must_use]
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |sum, &c| sum * x + c)
+    }
+
+    /// Evaluates the antiderivative that vanishes at zero.
+    // Polynomial degrees are tiny, so the casts are exact.
+    #[allow(clippy::cast_precision_loss)]
+    fn antiderivative(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .fold(0.0, |sum, (i, &c)| (sum + c / (i + 1) as f64) * x)
+    }
+
+    /// Divides by `x − root`, returning the quotient and the remainder.
+    fn deflate(&self, root: f64) -> (Self, f64) {
+        let mut quotient = Vec::with_capacity(self.coefficients.len());
+        let mut carry = 0.0;
+        for &c in self.coefficients.iter().rev() {
+            quotient.push(carry);
+            carry = carry * root + c;
+        }
+        quotient.reverse();
+        quotient.pop();
+        (Self::new(quotient), carry)
+    }
+
+    /// Checks that the fit has finite coefficients.
+    fn check(&self, property: &'static str) -> Result<(), PolynomialIncompressibleParametersError> {
+        if self.coefficients.is_empty() {
+            return Err(PolynomialIncompressibleParametersError::EmptyFit { property });
+        }
+        if self.coefficients.iter().any(|c| !c.is_finite()) {
+            return Err(PolynomialIncompressibleParametersError::NonFiniteCoefficient { property });
+        }
+        Ok(())
+    }
+}
+
+/// Form of a viscosity fit.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ViscosityFit {
+    /// `μ = p(x)` in Pa·s.
+    Polynomial(Polynomial),
+
+    /// `μ = exp(p(x))` in Pa·s, suited to the steep fall of liquid
+    /// viscosity with temperature.
+    ExpPolynomial(Polynomial),
+}
+
+impl ViscosityFit {
+    fn evaluate(&self, x: f64) -> f64 {
+        match self {
+            Self::Polynomial(fit) => fit.evaluate(x),
+            Self::ExpPolynomial(fit) => fit.evaluate(x).exp(),
+        }
+    }
+
+    fn polynomial(&self) -> &Polynomial {
+        match self {
+            Self::Polynomial(fit) | Self::ExpPolynomial(fit) => fit,
+        }
+    }
+}
+
+/// Temperature-dependent transport property fits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolynomialTransport {
+    pub viscosity: ViscosityFit,
+    pub conductivity: Polynomial,
+}
+
+/// Reference values used to define enthalpy/entropy offsets for a
+/// [`PolynomialIncompressible`] model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolynomialIncompressibleReference {
+    pub temperature: ThermodynamicTemperature,
+    pub enthalpy: SpecificEnthalpy,
+    pub entropy: SpecificEntropy,
+}
+
+impl PolynomialIncompressibleReference {
+    /// Returns a reference with `T_ref = 25°C`, `h_ref = 0`, and `s_ref = 0`.
+    #[must_use]
+    pub fn standard() -> Self {
+        Self {
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(25.0),
+            enthalpy: SpecificEnthalpy::ZERO,
+            entropy: SpecificEntropy::ZERO,
+        }
+    }
+}
+
+/// Property fits for the [`PolynomialIncompressible`] model.
+///
+/// These values are typically provided by a fluid's
+/// [`PolynomialIncompressibleFluid`] implementation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolynomialIncompressibleParameters {
+    pub base_temperature: ThermodynamicTemperature,
+    pub cp: Polynomial,
+    pub density: Polynomial,
+    pub reference: PolynomialIncompressibleReference,
+    pub transport: Option<PolynomialTransport>,
+    pub temperature_range: Option<RangeInclusive<ThermodynamicTemperature>>,
+}
+
+impl PolynomialIncompressibleParameters {
+    #[must_use]
+    pub fn new(
+        base_temperature: ThermodynamicTemperature,
+        cp: Polynomial,
+        density: Polynomial,
+    ) -> Self {
+        Self {
+            base_temperature,
+            cp,
+            density,
+            reference: PolynomialIncompressibleReference::standard(),
+            transport: None,
+            temperature_range: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_reference(mut self, reference: PolynomialIncompressibleReference) -> Self {
+        self.reference = reference;
+        self
+    }
+
+    /// Adds transport property fits, enabling [`HasViscosity`] and
+    /// [`HasThermalConductivity`] on the model.
+    #[must_use]
+    pub fn with_transport(mut self, viscosity: ViscosityFit, conductivity: Polynomial) -> Self {
+        self.transport = Some(PolynomialTransport {
+            viscosity,
+            conductivity,
+        });
+        self
+    }
+
+    /// Restricts the model to the temperatures the fits cover.
+    #[must_use]
+    pub fn with_temperature_range(
+        mut self,
+        range: RangeInclusive<ThermodynamicTemperature>,
+    ) -> Self {
+        self.temperature_range = Some(range);
+        self
+    }
+}
+
+/// Fluid property fits required by the [`PolynomialIncompressible`] model.
+pub trait PolynomialIncompressibleFluid {
+    /// Returns the property fits for use with [`PolynomialIncompressible`].
+    fn parameters() -> PolynomialIncompressibleParameters;
+}
+
+/// Incompressible liquid model with polynomial temperature-dependent
+/// properties.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolynomialIncompressible<Fluid> {
+    t_base: f64,
+    cp: Polynomial,
+    /// `cp⁄T = cp_over_t.0 + cp_over_t.1⁄T`, for integrating entropy.
+    cp_over_t: (Polynomial, f64),
+    density: Polynomial,
+    t_ref: ThermodynamicTemperature,
+    h_ref: SpecificEnthalpy,
+    s_ref: SpecificEntropy,
+    transport: Option<PolynomialTransport>,
+    range: Option<RangeInclusive<ThermodynamicTemperature>>,
+    _marker: PhantomData<Fluid>,
+}
+
+impl<Fluid> ThermoModel for PolynomialIncompressible<Fluid> {
+    type Fluid = Fluid;
+}
+
+impl<Fluid> PolynomialIncompressible<Fluid> {
+    /// Creates a model using the fits defined by `Fluid`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolynomialIncompressibleParametersError`] if a fit is empty
+    /// or non-finite, a temperature is not strictly positive, the range is
+    /// empty, or `cp` or `ρ` is not strictly positive at the reference
+    /// temperature.
+    pub fn new() -> Result<Self, PolynomialIncompressibleParametersError>
+    where
+        Fluid: PolynomialIncompressibleFluid,
+    {
+        let parameters = Fluid::parameters();
+
+        parameters.cp.check("cp")?;
+        parameters.density.check("density")?;
+        if let Some(transport) = &parameters.transport {
+            transport.viscosity.polynomial().check("viscosity")?;
+            transport.conductivity.check("thermal conductivity")?;
+        }
+
+        let t_base = parameters.base_temperature;
+        if !(t_base.get::<kelvin>() > 0.0 && t_base.is_finite()) {
+            return Err(PolynomialIncompressibleParametersError::BaseTemperature { t_base });
+        }
+
+        let t_ref = parameters.reference.temperature;
+        if !(t_ref.get::<kelvin>() > 0.0 && t_ref.is_finite()) {
+            return Err(PolynomialIncompressibleParametersError::ReferenceTemperature { t_ref });
+        }
+
+        if let Some(range) = &parameters.temperature_range
+            && !(range.start().get::<kelvin>() > 0.0
+                && range.start() <= range.end()
+                && range.end().is_finite())
+        {
+            return Err(PolynomialIncompressibleParametersError::TemperatureRange {
+                range: range.clone(),
+            });
+        }
+
+        let t_base = t_base.get::<kelvin>();
+        let x_ref = t_ref.get::<kelvin>() - t_base;
+
+        let cp = parameters.cp.evaluate(x_ref);
+        if cp <= 0.0 {
+            return Err(PolynomialIncompressibleParametersError::Cp {
+                cp: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(cp),
+            });
+        }
+
+        let density = parameters.density.evaluate(x_ref);
+        if density <= 0.0 {
+            return Err(PolynomialIncompressibleParametersError::Density {
+                density: MassDensity::new::<kilogram_per_cubic_meter>(density),
+            });
+        }
+
+        // Writing cp(x) = (x + T_base)·q(x) + r gives cp⁄T = q(x) + r⁄T.
+        let cp_over_t = parameters.cp.deflate(-t_base);
+
+        Ok(Self {
+            t_base,
+            cp: parameters.cp,
+            cp_over_t,
+            density: parameters.density,
+            t_ref,
+            h_ref: parameters.reference.enthalpy,
+            s_ref: parameters.reference.entropy,
+            transport: parameters.transport,
+            range: parameters.temperature_range,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the density at `temperature`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PropertyError::OutOfDomain`] if the temperature is outside
+    /// the model's range or the density fit is not positive there.
+    pub fn density(
+        &self,
+        temperature: ThermodynamicTemperature,
+    ) -> Result<MassDensity, PropertyError> {
+        let x = self.offset(temperature)?;
+        let density = self.density.evaluate(x);
+        if density > 0.0 {
+            Ok(MassDensity::new::<kilogram_per_cubic_meter>(density))
+        } else {
+            Err(PropertyError::OutOfDomain {
+                context: format!("density fit is not positive at {temperature:?}"),
+            })
+        }
+    }
+
+    /// Creates a state at the reference temperature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PropertyError::OutOfDomain`] if the reference temperature
+    /// is outside the model's range.
+    pub fn reference_state(&self, fluid: Fluid) -> Result<State<Fluid>, PropertyError> {
+        self.state_from((fluid, self.t_ref))
+    }
+
+    /// Returns the temperature offset `T − T_base` in kelvin, checking the
+    /// model's range.
+    fn offset(&self, temperature: ThermodynamicTemperature) -> Result<f64, PropertyError> {
+        if let Some(range) = &self.range
+            && !range.contains(&temperature)
+        {
+            return Err(PropertyError::OutOfDomain {
+                context: format!("{temperature:?} is outside the fitted range {range:?}"),
+            });
+        }
+        Ok(temperature.get::<kelvin>() - self.t_base)
+    }
+
+    /// Returns the transport fits, or an error if none were given.
+    fn transport(&self) -> Result<&PolynomialTransport, PropertyError> {
+        self.transport.as_ref().ok_or_else(|| PropertyError::Undefined {
+            context: "no transport properties; set them with `PolynomialIncompressibleParameters::with_transport`".into(),
+        })
+    }
+}
+
+impl<Fluid> HasInternalEnergy for PolynomialIncompressible<Fluid> {
+    /// Computes internal energy, which is equal to enthalpy for incompressible fluids.
+    fn internal_energy(
+        &self,
+        state: &State<Fluid>,
+    ) -> Result<SpecificInternalEnergy, PropertyError> {
+        self.enthalpy(state)
+    }
+}
+
+impl<Fluid> HasEnthalpy for PolynomialIncompressible<Fluid> {
+    /// Computes enthalpy with `h = h₀ + ∫ cp dT` from `T₀` to `T`.
+    fn enthalpy(&self, state: &State<Fluid>) -> Result<SpecificEnthalpy, PropertyError> {
+        let x = self.offset(state.temperature)?;
+        let x_ref = self.t_ref.get::<kelvin>() - self.t_base;
+        let delta = self.cp.antiderivative(x) - self.cp.antiderivative(x_ref);
+
+        Ok(self.h_ref + SpecificEnthalpy::new::<joule_per_kilogram>(delta))
+    }
+}
+
+impl<Fluid> HasEntropy for PolynomialIncompressible<Fluid> {
+    /// Computes entropy with `s = s₀ + ∫ cp⁄T dT` from `T₀` to `T`.
+    fn entropy(&self, state: &State<Fluid>) -> Result<SpecificEntropy, PropertyError> {
+        let x = self.offset(state.temperature)?;
+        let t = state.temperature.get::<kelvin>();
+        if t <= 0.0 {
+            return Err(PropertyError::InvalidState {
+                context: format!("temperature must be positive, got {t} K"),
+            });
+        }
+
+        let t_ref = self.t_ref.get::<kelvin>();
+        let (quotient, remainder) = &self.cp_over_t;
+        let delta = quotient.antiderivative(x) - quotient.antiderivative(t_ref - self.t_base)
+            + remainder * (t / t_ref).ln();
+
+        Ok(self.s_ref + SpecificEntropy::new::<joule_per_kilogram_kelvin>(delta))
+    }
+}
+
+impl<Fluid> HasCp for PolynomialIncompressible<Fluid> {
+    /// Evaluates the `cp` fit.
+    fn cp(&self, state: &State<Fluid>) -> Result<SpecificHeatCapacity, PropertyError> {
+        let x = self.offset(state.temperature)?;
+        Ok(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+            self.cp.evaluate(x),
+        ))
+    }
+}
+
+impl<Fluid> HasCv for PolynomialIncompressible<Fluid> {
+    /// Evaluates the `cp` fit; the two heat capacities coincide.
+    fn cv(&self, state: &State<Fluid>) -> Result<SpecificHeatCapacity, PropertyError> {
+        self.cp(state)
+    }
+}
+
+impl<Fluid> HasSpeedOfSound for PolynomialIncompressible<Fluid> {
+    /// Returns an infinite speed of sound; density does not respond to pressure.
+    fn speed_of_sound(&self, _state: &State<Fluid>) -> Result<Velocity, PropertyError> {
+        Ok(Velocity::new::<meter_per_second>(f64::INFINITY))
+    }
+}
+
+impl<Fluid> HasViscosity for PolynomialIncompressible<Fluid> {
+    /// Evaluates the viscosity fit.
+    fn viscosity(&self, state: &State<Fluid>) -> Result<DynamicViscosity, PropertyError> {
+        let x = self.offset(state.temperature)?;
+        let transport = self.transport()?;
+        Ok(DynamicViscosity::new::<pascal_second>(
+            transport.viscosity.evaluate(x),
+        ))
+    }
+}
+
+impl<Fluid> HasThermalConductivity for PolynomialIncompressible<Fluid> {
+    /// Evaluates the thermal conductivity fit.
+    fn thermal_conductivity(
+        &self,
+        state: &State<Fluid>,
+    ) -> Result<ThermalConductivity, PropertyError> {
+        let x = self.offset(state.temperature)?;
+        let transport = self.transport()?;
+        Ok(ThermalConductivity::new::<watt_per_meter_kelvin>(
+            transport.conductivity.evaluate(x),
+        ))
+    }
+}
+
+impl<Fluid> HasTwoPhase for PolynomialIncompressible<Fluid> {
+    /// Returns `None`; the model describes a single-phase liquid.
+    fn two_phase(&self, _state: &State<Fluid>) -> Result<Option<TwoPhase>, PropertyError> {
+        Ok(None)
+    }
+}
+
+/// Enables state creation from temperature alone.
+///
+/// The returned state uses the density fit at that temperature.
+impl<Fluid> StateFrom<(Fluid, ThermodynamicTemperature)> for PolynomialIncompressible<Fluid> {
+    type Error = PropertyError;
+
+    fn state_from(
+        &self,
+        (fluid, temperature): (Fluid, ThermodynamicTemperature),
+    ) -> Result<State<Fluid>, Self::Error> {
+        let density = self.density(temperature)?;
+
+        Ok(State {
+            temperature,
+            density,
+            fluid,
+        })
+    }
+}
+
+impl<Fluid: Default> StateFrom<ThermodynamicTemperature> for PolynomialIncompressible<Fluid> {
+    type Error = PropertyError;
+
+    fn state_from(
+        &self,
+        temperature: ThermodynamicTemperature,
+    ) -> Result<State<Fluid>, Self::Error> {
+        self.state_from((Fluid::default(), temperature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use crate::support::units::test_utils::celsius;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    struct MockGlycol;
+
+    /// A glycol-like liquid with a base temperature of 0 °C.
+    impl PolynomialIncompressibleFluid for MockGlycol {
+        fn parameters() -> PolynomialIncompressibleParameters {
+            PolynomialIncompressibleParameters::new(
+                ThermodynamicTemperature::new::<degree_celsius>(0.0),
+                Polynomial::new([3600.0, 3.5, -0.004]),
+                Polynomial::new([1040.0, -0.4, -0.002]),
+            )
+            .with_transport(
+                ViscosityFit::ExpPolynomial(Polynomial::new([-5.2, -0.035, 1.2e-4])),
+                Polynomial::new([0.42, 8.0e-4]),
+            )
+            .with_temperature_range(
+                ThermodynamicTemperature::new::<degree_celsius>(-20.0)
+                    ..=ThermodynamicTemperature::new::<degree_celsius>(100.0),
+            )
+        }
+    }
+
+    fn model() -> PolynomialIncompressible<MockGlycol> {
+        PolynomialIncompressible::new().expect("mock glycol fits must be valid")
+    }
+
+    #[test]
+    fn polynomial_helpers() {
+        let p = Polynomial::new([1.0, -2.0, 3.0]);
+        assert_relative_eq!(p.evaluate(2.0), 9.0);
+        assert_relative_eq!(p.antiderivative(2.0), 2.0 - 4.0 + 8.0);
+
+        // 3x² − 2x + 1 = (x + 5)·(3x − 17) + 86.
+        let (quotient, remainder) = p.deflate(-5.0);
+        assert_eq!(quotient.coefficients(), &[-17.0, 3.0]);
+        assert_relative_eq!(remainder, 86.0);
+    }
+
+    #[test]
+    fn properties_follow_the_fits() -> Result<(), PropertyError> {
+        let thermo = model();
+        let state = thermo.state_from(celsius(40.0))?;
+
+        assert_relative_eq!(
+            state.density.get::<kilogram_per_cubic_meter>(),
+            1040.0 - 16.0 - 3.2
+        );
+        assert_relative_eq!(
+            thermo.cp(&state)?.get::<joule_per_kilogram_kelvin>(),
+            3600.0 + 140.0 - 6.4
+        );
+        assert_eq!(thermo.cv(&state)?, thermo.cp(&state)?);
+        assert_relative_eq!(
+            thermo.viscosity(&state)?.get::<pascal_second>(),
+            (-5.2 - 1.4 + 0.192_f64).exp()
+        );
+        assert_relative_eq!(
+            thermo
+                .thermal_conductivity(&state)?
+                .get::<watt_per_meter_kelvin>(),
+            0.452
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn enthalpy_and_entropy_integrate_cp() -> Result<(), PropertyError> {
+        let thermo = model();
+        let reference = thermo.reference_state(MockGlycol)?;
+        assert_eq!(thermo.enthalpy(&reference)?, SpecificEnthalpy::ZERO);
+        assert_relative_eq!(thermo.entropy(&reference)?.value, 0.0, epsilon = 1e-9);
+
+        // Trapezoidal integration over fine steps agrees with the exact form.
+        let steps = 10_000;
+        let (t_a, t_b) = (25.0, 85.0);
+        let dt = (t_b - t_a) / f64::from(steps);
+        let (mut h, mut s) = (0.0, 0.0);
+        for i in 0..steps {
+            let lo = thermo.state_from(celsius(t_a + dt * f64::from(i)))?;
+            let hi = thermo.state_from(celsius(t_a + dt * f64::from(i + 1)))?;
+            let (cp_lo, cp_hi) = (thermo.cp(&lo)?.value, thermo.cp(&hi)?.value);
+            h += 0.5 * (cp_lo + cp_hi) * dt;
+            s += 0.5 * (cp_lo / lo.temperature.value + cp_hi / hi.temperature.value) * dt;
+        }
+
+        let state = thermo.state_from(celsius(t_b))?;
+        assert_relative_eq!(
+            thermo.enthalpy(&state)?.get::<joule_per_kilogram>(),
+            h,
+            max_relative = 1e-8
+        );
+        assert_relative_eq!(
+            thermo.entropy(&state)?.get::<joule_per_kilogram_kelvin>(),
+            s,
+            max_relative = 1e-8
+        );
+        assert_eq!(thermo.internal_energy(&state)?, thermo.enthalpy(&state)?);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_temperatures_outside_the_range() {
+        let thermo = model();
+        assert!(matches!(
+            thermo.state_from(celsius(-30.0)),
+            Err(PropertyError::OutOfDomain { .. })
+        ));
+
+        let state = State::new(
+            celsius(120.0),
+            MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+            MockGlycol,
+        );
+        assert!(matches!(
+            thermo.enthalpy(&state),
+            Err(PropertyError::OutOfDomain { .. })
+        ));
+    }
+
+    #[test]
+    fn transport_properties_are_opt_in() {
+        #[derive(Debug, Clone, Copy, Default)]
+        struct BareLiquid;
+
+        impl PolynomialIncompressibleFluid for BareLiquid {
+            fn parameters() -> PolynomialIncompressibleParameters {
+                PolynomialIncompressibleParameters::new(
+                    ThermodynamicTemperature::new::<degree_celsius>(0.0),
+                    Polynomial::constant(4000.0),
+                    Polynomial::constant(1000.0),
+                )
+            }
+        }
+
+        let thermo = PolynomialIncompressible::<BareLiquid>::new().unwrap();
+        let state = thermo.reference_state(BareLiquid).unwrap();
+        assert!(matches!(
+            thermo.viscosity(&state),
+            Err(PropertyError::Undefined { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_fits() {
+        #[derive(Debug, Clone, Copy, Default)]
+        struct EmptyCp;
+
+        impl PolynomialIncompressibleFluid for EmptyCp {
+            fn parameters() -> PolynomialIncompressibleParameters {
+                PolynomialIncompressibleParameters::new(
+                    ThermodynamicTemperature::new::<degree_celsius>(0.0),
+                    Polynomial::new([]),
+                    Polynomial::constant(1000.0),
+                )
+            }
+        }
+
+        #[derive(Debug, Clone, Copy, Default)]
+        struct NegativeDensity;
+
+        impl PolynomialIncompressibleFluid for NegativeDensity {
+            fn parameters() -> PolynomialIncompressibleParameters {
+                PolynomialIncompressibleParameters::new(
+                    ThermodynamicTemperature::new::<degree_celsius>(0.0),
+                    Polynomial::constant(4000.0),
+                    Polynomial::new([100.0, -10.0]),
+                )
+            }
+        }
+
+        assert!(matches!(
+            PolynomialIncompressible::<EmptyCp>::new(),
+            Err(PolynomialIncompressibleParametersError::EmptyFit { property: "cp" })
+        ));
+        assert!(matches!(
+            PolynomialIncompressible::<NegativeDensity>::new(),
+            Err(PolynomialIncompressibleParametersError::Density { .. })
+        ));
+    }
+}
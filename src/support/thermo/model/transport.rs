@@ -0,0 +1,29 @@
+//! Constant transport properties for the simple property models.
+
+use uom::si::f64::{DynamicViscosity, ThermalConductivity};
+
+/// Viscosity and thermal conductivity held constant over all states.
+///
+/// [`PerfectGas`](super::PerfectGas) and [`Incompressible`](super::Incompressible)
+/// report these through [`HasViscosity`] and [`HasThermalConductivity`] when
+/// their parameters include them, which is accurate enough for heat transfer
+/// correlations over a modest temperature range. Values are usually taken
+/// at the mean temperature of the application.
+///
+/// [`HasViscosity`]: crate::support::thermo::capability::HasViscosity
+/// [`HasThermalConductivity`]: crate::support::thermo::capability::HasThermalConductivity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransportProperties {
+    pub viscosity: DynamicViscosity,
+    pub conductivity: ThermalConductivity,
+}
+
+impl TransportProperties {
+    #[must_use]
+    pub fn new(viscosity: DynamicViscosity, conductivity: ThermalConductivity) -> Self {
+        Self {
+            viscosity,
+            conductivity,
+        }
+    }
+}
@@ -1,8 +1,13 @@
-use std::ops::Div;
+use core::ops::Div;
 
+#[cfg(feature = "std")]
 use twine_core::StepIntegrable;
 use uom::si::f64::{MassDensity, TemperatureInterval, ThermodynamicTemperature, Time};
 
+use crate::support::constraint::{
+    AboveAbsoluteZero, Constrained, ConstraintViolation, StrictlyPositive,
+};
+
 /// The thermodynamic state of a fluid.
 ///
 /// A `State<Fluid>` captures the thermodynamic state of a specific fluid,
@@ -50,6 +55,45 @@ impl<Fluid> State<Fluid> {
         }
     }
 
+    /// Creates a new state, checking that it is physically meaningful.
+    ///
+    /// The temperature must be above absolute zero and the density must be
+    /// strictly positive. Use this at model boundaries so invalid states fail
+    /// early instead of deep inside property evaluation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConstraintViolation`] naming the offending field's constraint
+    /// and value if either check fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use twine_models::support::thermo::{State, fluid::Air};
+    /// use uom::si::{
+    ///     f64::{MassDensity, ThermodynamicTemperature},
+    ///     mass_density::kilogram_per_cubic_meter,
+    ///     thermodynamic_temperature::kelvin,
+    /// };
+    ///
+    /// let density = MassDensity::new::<kilogram_per_cubic_meter>(1.2);
+    /// assert!(State::try_new(ThermodynamicTemperature::new::<kelvin>(300.0), density, Air).is_ok());
+    /// assert!(State::try_new(ThermodynamicTemperature::new::<kelvin>(-1.0), density, Air).is_err());
+    /// ```
+    pub fn try_new(
+        temperature: ThermodynamicTemperature,
+        density: MassDensity,
+        fluid: Fluid,
+    ) -> Result<Self, ConstraintViolation> {
+        let temperature = Constrained::<_, AboveAbsoluteZero>::new_with_context(temperature)?;
+        let density = Constrained::<_, StrictlyPositive>::new_with_context(density)?;
+        Ok(Self::new(
+            temperature.into_inner(),
+            density.into_inner(),
+            fluid,
+        ))
+    }
+
     /// Returns a new state with the given temperature, keeping other fields unchanged.
     #[must_use]
     pub fn with_temperature(self, temperature: ThermodynamicTemperature) -> Self {
@@ -116,6 +160,7 @@ pub struct StateDerivative<FluidDerivative> {
     pub fluid: FluidDerivative,
 }
 
+#[cfg(feature = "std")]
 impl<Fluid> StepIntegrable<Time> for State<Fluid>
 where
     Fluid: StepIntegrable<Time>,
@@ -131,7 +176,7 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
@@ -144,7 +189,7 @@ mod tests {
         time::second,
     };
 
-    use crate::support::thermo::fluid::Air;
+    use crate::support::{constraint::ConstraintError, thermo::fluid::Air};
 
     #[test]
     fn step_advances_temperature_and_density() {
@@ -205,4 +250,27 @@ mod tests {
         assert_eq!(Water.step((), dt), Water);
         assert_eq!(CarbonDioxide.step((), dt), CarbonDioxide);
     }
+
+    #[test]
+    fn try_new_rejects_non_physical_states() {
+        let temperature = ThermodynamicTemperature::new::<kelvin>(300.0);
+        let density = MassDensity::new::<kilogram_per_cubic_meter>(1.2);
+        assert_eq!(
+            State::try_new(temperature, density, Air).unwrap(),
+            State::new(temperature, density, Air)
+        );
+
+        let err = State::try_new(ThermodynamicTemperature::new::<kelvin>(-10.0), density, Air)
+            .unwrap_err();
+        assert_eq!(err.kind(), ConstraintError::BelowMinimum);
+        assert!(err.to_string().starts_with("AboveAbsoluteZero"));
+
+        let err = State::try_new(
+            temperature,
+            MassDensity::new::<kilogram_per_cubic_meter>(0.0),
+            Air,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ConstraintError::Zero);
+    }
 }
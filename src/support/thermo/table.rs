@@ -0,0 +1,548 @@
+//! Property tables sampled from a thermo model.
+//!
+//! Validating a model against EES, REFPROP, or published tables means
+//! evaluating it over the same temperature and pressure grid and lining the
+//! results up column by column. [`PropertyTable`] does the sampling and
+//! writes the result to CSV, or to Parquet with the `parquet` feature.
+//!
+//! Each table carries ordered key/value metadata describing where it came
+//! from: the model type by default, the properties at a reference state when
+//! recorded with [`PropertyTable::with_reference`], and any notes added with
+//! [`PropertyTable::with_metadata`]. The reference state matters when
+//! comparing enthalpy and entropy, since tools differ in where they put zero.
+//!
+//! ```
+//! use twine_models::support::thermo::{
+//!     fluid::Air,
+//!     model::PerfectGas,
+//!     table::{PropertyGrid, PropertyTable},
+//! };
+//! use uom::si::{
+//!     f64::{Pressure, ThermodynamicTemperature},
+//!     pressure::kilopascal,
+//!     thermodynamic_temperature::kelvin,
+//! };
+//!
+//! let air = PerfectGas::<Air>::new()?;
+//! let grid = PropertyGrid::new(
+//!     [300.0, 400.0, 500.0].map(ThermodynamicTemperature::new::<kelvin>),
+//!     [100.0, 1000.0].map(Pressure::new::<kilopascal>),
+//! )?;
+//!
+//! let table = PropertyTable::sample(&air, Air, &grid)?
+//!     .with_reference(&air, &air.reference_state(Air))?
+//!     .with_metadata("source", "twine-models");
+//!
+//! assert_eq!(table.rows().len(), 6);
+//! let csv = table.to_csv();
+//! assert!(csv.starts_with("# model: "));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+#[cfg(feature = "parquet")]
+mod parquet;
+
+use std::{error::Error as StdError, fmt::Write as _, io};
+
+use thiserror::Error;
+use uom::si::{
+    available_energy::joule_per_kilogram,
+    f64::{MassDensity, Pressure, SpecificHeatCapacity, ThermodynamicTemperature},
+    mass_density::kilogram_per_cubic_meter,
+    pressure::pascal,
+    specific_heat_capacity::joule_per_kilogram_kelvin,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::support::units::{SpecificEnthalpy, SpecificEntropy, SpecificInternalEnergy};
+
+use super::{
+    PropertyError, State,
+    capability::{HasCp, HasCv, HasEnthalpy, HasEntropy, HasInternalEnergy, StateFrom},
+};
+
+/// Column names, in SI base units, in the order they are written.
+pub const COLUMNS: [&str; 8] = [
+    "temperature_k",
+    "pressure_pa",
+    "density_kg_per_m3",
+    "internal_energy_j_per_kg",
+    "enthalpy_j_per_kg",
+    "entropy_j_per_kg_k",
+    "cp_j_per_kg_k",
+    "cv_j_per_kg_k",
+];
+
+/// Errors from building a grid or sampling a property table.
+#[derive(Debug, Error)]
+pub enum PropertyTableError {
+    /// The grid has no temperatures or no pressures.
+    #[error("property grid needs at least one temperature and one pressure")]
+    EmptyGrid,
+
+    /// A grid temperature is not finite and positive.
+    #[error("invalid grid temperature: {0:?}")]
+    Temperature(ThermodynamicTemperature),
+
+    /// A grid pressure is not finite and positive.
+    #[error("invalid grid pressure: {0:?}")]
+    Pressure(Pressure),
+
+    /// The model could not construct the state at a grid point.
+    #[error("failed to build state at T = {temperature:?}, p = {pressure:?}")]
+    State {
+        temperature: ThermodynamicTemperature,
+        pressure: Pressure,
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+
+    /// A property evaluation failed at a grid point.
+    #[error("failed to evaluate properties at T = {temperature:?}, p = {pressure:?}")]
+    Property {
+        temperature: ThermodynamicTemperature,
+        pressure: Pressure,
+        #[source]
+        source: PropertyError,
+    },
+
+    /// A property evaluation failed at the reference state.
+    #[error("failed to evaluate properties at the reference state")]
+    Reference(#[source] PropertyError),
+
+    /// The Parquet file could not be written.
+    #[cfg(feature = "parquet")]
+    #[error("failed to write Parquet table")]
+    Parquet(#[from] ::parquet::errors::ParquetError),
+}
+
+/// Temperatures and pressures to sample, as a full tensor product.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyGrid {
+    temperatures: Vec<ThermodynamicTemperature>,
+    pressures: Vec<Pressure>,
+}
+
+impl PropertyGrid {
+    /// Creates a grid from explicit temperatures and pressures.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PropertyTableError`] if either list is empty or holds a
+    /// value that is not finite and positive.
+    pub fn new(
+        temperatures: impl IntoIterator<Item = ThermodynamicTemperature>,
+        pressures: impl IntoIterator<Item = Pressure>,
+    ) -> Result<Self, PropertyTableError> {
+        let temperatures: Vec<_> = temperatures.into_iter().collect();
+        let pressures: Vec<_> = pressures.into_iter().collect();
+
+        if temperatures.is_empty() || pressures.is_empty() {
+            return Err(PropertyTableError::EmptyGrid);
+        }
+        if let Some(&t) = temperatures
+            .iter()
+            .find(|t| !is_positive(t.get::<kelvin>()))
+        {
+            return Err(PropertyTableError::Temperature(t));
+        }
+        if let Some(&p) = pressures.iter().find(|p| !is_positive(p.get::<pascal>())) {
+            return Err(PropertyTableError::Pressure(p));
+        }
+
+        Ok(Self {
+            temperatures,
+            pressures,
+        })
+    }
+
+    /// Returns the grid temperatures.
+    #[must_use]
+    pub fn temperatures(&self) -> &[ThermodynamicTemperature] {
+        &self.temperatures
+    }
+
+    /// Returns the grid pressures.
+    #[must_use]
+    pub fn pressures(&self) -> &[Pressure] {
+        &self.pressures
+    }
+
+    /// Returns the grid points in table order: pressure-major, so each
+    /// pressure's isobar is contiguous.
+    pub fn points(&self) -> impl Iterator<Item = (ThermodynamicTemperature, Pressure)> + '_ {
+        self.pressures
+            .iter()
+            .flat_map(|&p| self.temperatures.iter().map(move |&t| (t, p)))
+    }
+}
+
+fn is_positive(value: f64) -> bool {
+    value.is_finite() && value > 0.0
+}
+
+/// Properties at one grid point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PropertyRow {
+    pub temperature: ThermodynamicTemperature,
+    pub pressure: Pressure,
+    pub density: MassDensity,
+    pub internal_energy: SpecificInternalEnergy,
+    pub enthalpy: SpecificEnthalpy,
+    pub entropy: SpecificEntropy,
+    pub cp: SpecificHeatCapacity,
+    pub cv: SpecificHeatCapacity,
+}
+
+impl PropertyRow {
+    /// Returns the values in SI base units, in [`COLUMNS`] order.
+    #[must_use]
+    pub fn values(&self) -> [f64; 8] {
+        [
+            self.temperature.get::<kelvin>(),
+            self.pressure.get::<pascal>(),
+            self.density.get::<kilogram_per_cubic_meter>(),
+            self.internal_energy.get::<joule_per_kilogram>(),
+            self.enthalpy.get::<joule_per_kilogram>(),
+            self.entropy.get::<joule_per_kilogram_kelvin>(),
+            self.cp.get::<joule_per_kilogram_kelvin>(),
+            self.cv.get::<joule_per_kilogram_kelvin>(),
+        ]
+    }
+}
+
+/// A property table and the metadata describing its source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyTable {
+    metadata: Vec<(String, String)>,
+    rows: Vec<PropertyRow>,
+}
+
+impl PropertyTable {
+    /// Samples `model` at every point of `grid` from temperature and pressure.
+    ///
+    /// The `model` metadata entry is set to the model's type name.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PropertyTableError`] for the first grid point where the
+    /// state or a property cannot be evaluated.
+    pub fn sample<M, Fluid>(
+        model: &M,
+        fluid: Fluid,
+        grid: &PropertyGrid,
+    ) -> Result<Self, PropertyTableError>
+    where
+        M: StateFrom<(Fluid, ThermodynamicTemperature, Pressure)>
+            + HasInternalEnergy
+            + HasEnthalpy
+            + HasEntropy
+            + HasCp
+            + HasCv,
+        Fluid: Clone,
+    {
+        Self::sample_with(model, grid, |t, p| model.state_from((fluid.clone(), t, p)))
+    }
+
+    /// Samples `model` at every point of `grid`, building each state with
+    /// `state_at`.
+    ///
+    /// Use this for models without a temperature-pressure constructor. For
+    /// an incompressible liquid, for example, `state_at` can ignore the
+    /// pressure, which is then recorded as given.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PropertyTableError`] for the first grid point where
+    /// `state_at` or a property evaluation fails.
+    pub fn sample_with<M, E>(
+        model: &M,
+        grid: &PropertyGrid,
+        state_at: impl Fn(ThermodynamicTemperature, Pressure) -> Result<State<M::Fluid>, E>,
+    ) -> Result<Self, PropertyTableError>
+    where
+        M: HasInternalEnergy + HasEnthalpy + HasEntropy + HasCp + HasCv,
+        E: StdError + Send + Sync + 'static,
+    {
+        let rows = grid
+            .points()
+            .map(|(temperature, pressure)| {
+                let state = state_at(temperature, pressure).map_err(|source| {
+                    PropertyTableError::State {
+                        temperature,
+                        pressure,
+                        source: Box::new(source),
+                    }
+                })?;
+                evaluate(model, &state, pressure).map_err(|source| PropertyTableError::Property {
+                    temperature,
+                    pressure,
+                    source,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            metadata: vec![("model".into(), std::any::type_name::<M>().into())],
+            rows,
+        })
+    }
+
+    /// Records the properties of `model` at `state` as the reference state.
+    ///
+    /// Adds `reference_temperature_k`, `reference_density_kg_per_m3`,
+    /// `reference_enthalpy_j_per_kg`, and `reference_entropy_j_per_kg_k`
+    /// entries. For the analytic models, pass `model.reference_state(fluid)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PropertyTableError::Reference`] if enthalpy or entropy
+    /// cannot be evaluated at `state`.
+    pub fn with_reference<M>(
+        self,
+        model: &M,
+        state: &State<M::Fluid>,
+    ) -> Result<Self, PropertyTableError>
+    where
+        M: HasEnthalpy + HasEntropy,
+    {
+        let enthalpy = model
+            .enthalpy(state)
+            .map_err(PropertyTableError::Reference)?;
+        let entropy = model
+            .entropy(state)
+            .map_err(PropertyTableError::Reference)?;
+
+        Ok(self
+            .with_metadata(
+                "reference_temperature_k",
+                state.temperature.get::<kelvin>().to_string(),
+            )
+            .with_metadata(
+                "reference_density_kg_per_m3",
+                state.density.get::<kilogram_per_cubic_meter>().to_string(),
+            )
+            .with_metadata(
+                "reference_enthalpy_j_per_kg",
+                enthalpy.get::<joule_per_kilogram>().to_string(),
+            )
+            .with_metadata(
+                "reference_entropy_j_per_kg_k",
+                entropy.get::<joule_per_kilogram_kelvin>().to_string(),
+            ))
+    }
+
+    /// Sets a metadata entry, replacing any existing value for `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is empty or contains `:`, or if `key` or `value`
+    /// contains a line break.
+    #[must_use]
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        let key = key.into();
+        let value = value.into();
+        assert!(
+            !key.is_empty() && !key.contains([':', '\n', '\r']),
+            "invalid property table metadata key `{key}`"
+        );
+        assert!(
+            !value.contains(['\n', '\r']),
+            "property table metadata value for `{key}` contains a line break"
+        );
+
+        match self.metadata.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, existing)) => *existing = value,
+            None => self.metadata.push((key, value)),
+        }
+        self
+    }
+
+    /// Returns the metadata entries in insertion order.
+    #[must_use]
+    pub fn metadata(&self) -> &[(String, String)] {
+        &self.metadata
+    }
+
+    /// Returns the rows in grid order (see [`PropertyGrid::points`]).
+    #[must_use]
+    pub fn rows(&self) -> &[PropertyRow] {
+        &self.rows
+    }
+
+    /// Writes the table as CSV.
+    ///
+    /// Metadata comes first as `# key: value` comment lines, followed by a
+    /// header of [`COLUMNS`] and one row per grid point. Numbers are written
+    /// in shortest round-trip form.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for (key, value) in &self.metadata {
+            writeln!(csv, "# {key}: {value}").expect("writing to a String cannot fail");
+        }
+        csv.push_str(&COLUMNS.join(","));
+        csv.push('\n');
+        for row in &self.rows {
+            for (index, value) in row.values().iter().enumerate() {
+                if index > 0 {
+                    csv.push(',');
+                }
+                write!(csv, "{value}").expect("writing to a String cannot fail");
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Writes the table as CSV to `writer` (see [`PropertyTable::to_csv`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `writer`.
+    pub fn write_csv(&self, mut writer: impl io::Write) -> io::Result<()> {
+        writer.write_all(self.to_csv().as_bytes())
+    }
+}
+
+fn evaluate<M>(
+    model: &M,
+    state: &State<M::Fluid>,
+    pressure: Pressure,
+) -> Result<PropertyRow, PropertyError>
+where
+    M: HasInternalEnergy + HasEnthalpy + HasEntropy + HasCp + HasCv,
+{
+    Ok(PropertyRow {
+        temperature: state.temperature,
+        pressure,
+        density: state.density,
+        internal_energy: model.internal_energy(state)?,
+        enthalpy: model.enthalpy(state)?,
+        entropy: model.entropy(state)?,
+        cp: model.cp(state)?,
+        cv: model.cv(state)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::pressure::kilopascal;
+
+    use crate::support::thermo::{
+        capability::HasPressure,
+        fluid::{Air, Water},
+        model::{Incompressible, PerfectGas},
+    };
+
+    fn grid() -> PropertyGrid {
+        PropertyGrid::new(
+            [300.0, 350.0, 400.0].map(ThermodynamicTemperature::new::<kelvin>),
+            [100.0, 500.0].map(Pressure::new::<kilopascal>),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn samples_every_grid_point_pressure_major() {
+        let air = PerfectGas::<Air>::new().unwrap();
+        let table = PropertyTable::sample(&air, Air, &grid()).unwrap();
+
+        let rows = table.rows();
+        assert_eq!(rows.len(), 6);
+        assert_relative_eq!(rows[2].temperature.get::<kelvin>(), 400.0);
+        assert_relative_eq!(rows[3].pressure.get::<kilopascal>(), 500.0);
+
+        for row in rows {
+            let state = State::new(row.temperature, row.density, Air);
+            assert_relative_eq!(
+                air.pressure(&state).unwrap().get::<pascal>(),
+                row.pressure.get::<pascal>(),
+                max_relative = 1e-12
+            );
+            assert_relative_eq!(
+                air.enthalpy(&state).unwrap().get::<joule_per_kilogram>(),
+                row.enthalpy.get::<joule_per_kilogram>(),
+            );
+        }
+    }
+
+    #[test]
+    fn csv_starts_with_metadata_and_round_trips_values() {
+        let air = PerfectGas::<Air>::new().unwrap();
+        let table = PropertyTable::sample(&air, Air, &grid())
+            .unwrap()
+            .with_reference(&air, &air.reference_state(Air))
+            .unwrap()
+            .with_metadata("source", "test")
+            .with_metadata("source", "unit test");
+
+        let csv = table.to_csv();
+        let mut lines = csv.lines();
+        assert!(lines.next().unwrap().starts_with("# model: "));
+        assert!(
+            lines
+                .next()
+                .unwrap()
+                .starts_with("# reference_temperature_k: 273.15")
+        );
+        assert!(
+            lines
+                .nth(2)
+                .unwrap()
+                .starts_with("# reference_entropy_j_per_kg_k: ")
+        );
+        assert_eq!(lines.next().unwrap(), "# source: unit test");
+        assert_eq!(lines.next().unwrap(), COLUMNS.join(","));
+
+        let first: Vec<f64> = lines
+            .next()
+            .unwrap()
+            .split(',')
+            .map(|value| value.parse().unwrap())
+            .collect();
+        assert_eq!(first, table.rows()[0].values());
+    }
+
+    #[test]
+    fn samples_incompressible_liquids_through_a_closure() {
+        let water = Incompressible::<Water>::new().unwrap();
+        let table =
+            PropertyTable::sample_with(&water, &grid(), |t, _| water.state_from((Water, t)))
+                .unwrap();
+
+        assert_eq!(table.rows()[0].density, water.reference_density());
+        assert_eq!(table.rows()[0].cp, table.rows()[5].cp);
+    }
+
+    #[test]
+    fn rejects_invalid_grids() {
+        let t = ThermodynamicTemperature::new::<kelvin>(300.0);
+        let p = Pressure::new::<pascal>(101_325.0);
+
+        assert!(matches!(
+            PropertyGrid::new([], [p]),
+            Err(PropertyTableError::EmptyGrid)
+        ));
+        assert!(matches!(
+            PropertyGrid::new([t, ThermodynamicTemperature::new::<kelvin>(f64::NAN)], [p]),
+            Err(PropertyTableError::Temperature(_))
+        ));
+        assert!(matches!(
+            PropertyGrid::new([t], [Pressure::new::<pascal>(0.0)]),
+            Err(PropertyTableError::Pressure(_))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid property table metadata key")]
+    fn rejects_metadata_keys_that_break_the_csv_header() {
+        let air = PerfectGas::<Air>::new().unwrap();
+        let _ = PropertyTable::sample(&air, Air, &grid())
+            .unwrap()
+            .with_metadata("a: b", "c");
+    }
+}
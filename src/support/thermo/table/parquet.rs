@@ -0,0 +1,112 @@
+use std::{fmt::Write as _, io, sync::Arc};
+
+use parquet::{
+    data_type::DoubleType,
+    file::{metadata::KeyValue, properties::WriterProperties, writer::SerializedFileWriter},
+    schema::parser::parse_message_type,
+};
+
+use super::{COLUMNS, PropertyTable, PropertyTableError};
+
+impl PropertyTable {
+    /// Writes the table as a Parquet file to `writer`.
+    ///
+    /// Each of [`COLUMNS`] becomes a required `DOUBLE` column in a single row
+    /// group, and the metadata is stored as file key/value metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PropertyTableError::Parquet`] if encoding or writing fails.
+    pub fn write_parquet(&self, writer: impl io::Write + Send) -> Result<(), PropertyTableError> {
+        let mut message = String::from("message property_table {");
+        for column in COLUMNS {
+            write!(message, " REQUIRED DOUBLE {column};").expect("writing to a String cannot fail");
+        }
+        message.push_str(" }");
+        let schema = Arc::new(parse_message_type(&message)?);
+
+        let metadata = self
+            .metadata
+            .iter()
+            .map(|(key, value)| KeyValue::new(key.clone(), value.clone()))
+            .collect();
+        let properties = WriterProperties::builder()
+            .set_key_value_metadata(Some(metadata))
+            .build();
+
+        let mut file = SerializedFileWriter::new(writer, schema, Arc::new(properties))?;
+        let mut row_group = file.next_row_group()?;
+        let mut index = 0;
+        while let Some(mut column) = row_group.next_column()? {
+            let values: Vec<f64> = self.rows.iter().map(|row| row.values()[index]).collect();
+            column
+                .typed::<DoubleType>()
+                .write_batch(&values, None, None)?;
+            column.close()?;
+            index += 1;
+        }
+        row_group.close()?;
+        file.close()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use parquet::{
+        file::reader::{FileReader, SerializedFileReader},
+        record::RowAccessor,
+    };
+    use uom::si::{
+        f64::{Pressure, ThermodynamicTemperature},
+        pressure::kilopascal,
+        thermodynamic_temperature::kelvin,
+    };
+
+    use crate::support::thermo::{
+        fluid::Air,
+        model::PerfectGas,
+        table::{PropertyGrid, PropertyTable},
+    };
+
+    #[test]
+    fn parquet_round_trips_rows_and_metadata() {
+        let air = PerfectGas::<Air>::new().unwrap();
+        let grid = PropertyGrid::new(
+            [300.0, 400.0].map(ThermodynamicTemperature::new::<kelvin>),
+            [100.0, 200.0, 300.0].map(Pressure::new::<kilopascal>),
+        )
+        .unwrap();
+        let table = PropertyTable::sample(&air, Air, &grid)
+            .unwrap()
+            .with_metadata("source", "unit test");
+
+        let path = std::env::temp_dir().join(format!(
+            "twine_property_table_{}.parquet",
+            std::process::id()
+        ));
+        table
+            .write_parquet(std::fs::File::create(&path).unwrap())
+            .unwrap();
+
+        let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+        let metadata = reader.metadata().file_metadata();
+        assert_eq!(metadata.num_rows(), 6);
+        let entries = metadata.key_value_metadata().unwrap();
+        assert!(
+            entries
+                .iter()
+                .any(|kv| kv.key == "source" && kv.value.as_deref() == Some("unit test"))
+        );
+
+        for (row, expected) in reader.get_row_iter(None).unwrap().zip(table.rows()) {
+            let row = row.unwrap();
+            for (index, value) in expected.values().into_iter().enumerate() {
+                assert_relative_eq!(row.get_double(index).unwrap(), value);
+            }
+        }
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
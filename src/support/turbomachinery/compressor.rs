@@ -1,7 +1,14 @@
 //! Compressor models.
+//!
+//! - [`isentropic`]: outlet state and specific work at a target pressure,
+//!   for a given mass flow.
+//! - [`positive_displacement`]: mass flow set by the swept volume, speed,
+//!   and clearance volumetric efficiency, with optional shell heat loss.
 
+mod displacement;
 mod isentropic;
 mod types;
 
+pub use displacement::{Displacement, DisplacementResult, ShellHeatLoss, positive_displacement};
 pub use isentropic::isentropic;
 pub use types::{CompressionError, CompressionResult};
@@ -0,0 +1,373 @@
+//! Positive-displacement compressor model.
+//!
+//! Reciprocating, scroll, and rotary compressors move a fixed volume of
+//! suction gas per revolution, less what re-expands from the clearance
+//! volume. Given the inlet state, discharge pressure, and machine geometry,
+//! the model:
+//! 1. computes the clearance volumetric efficiency
+//!    `η_v = 1 + C − C·(p_out/p_in)^(1/n)`,
+//! 2. sets the mass flow from the displaced volume and suction density,
+//!    `ṁ = η_v·ρ_in·V_s·N`,
+//! 3. compresses adiabatically with an isentropic efficiency (see
+//!    [`isentropic`](super::isentropic)), and
+//! 4. optionally removes shell heat loss to ambient from the discharge gas.
+
+use uom::{
+    ConstZero,
+    si::{
+        f64::{
+            Frequency, MassRate, Power, Pressure, Ratio, ThermalConductance,
+            ThermodynamicTemperature, Volume, VolumeRate,
+        },
+        ratio::ratio,
+    },
+};
+
+use crate::support::{
+    constraint::{Constraint, NonNegative, StrictlyPositive},
+    thermo::{
+        State,
+        capability::{HasEnthalpy, HasEntropy, HasPressure, StateFrom, ThermoModel},
+    },
+    turbomachinery::{
+        IsentropicEfficiency,
+        compressor::{CompressionError, CompressionResult, isentropic},
+    },
+    units::{SpecificEnthalpy, SpecificEntropy, TemperatureDifference},
+};
+
+/// Geometry and speed of a positive-displacement compressor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Displacement {
+    /// Volume swept per revolution.
+    pub swept_volume: Volume,
+
+    /// Shaft speed, in revolutions per unit time.
+    pub speed: Frequency,
+
+    /// Clearance volume as a fraction of the swept volume.
+    ///
+    /// A few percent for reciprocating machines; zero neglects re-expansion.
+    pub clearance: Ratio,
+
+    /// Polytropic exponent of the clearance gas re-expansion.
+    ///
+    /// The suction gas's isentropic exponent is a reasonable default.
+    pub expansion_exponent: Ratio,
+}
+
+impl Displacement {
+    /// Returns the clearance volumetric efficiency at `pressure_ratio`,
+    /// `η_v = 1 + C − C·r^(1/n)`, floored at zero.
+    #[must_use]
+    pub fn volumetric_efficiency(&self, pressure_ratio: Ratio) -> Ratio {
+        let c = self.clearance.get::<ratio>();
+        let n = self.expansion_exponent.get::<ratio>();
+        let r = pressure_ratio.get::<ratio>();
+        Ratio::new::<ratio>((1.0 + c - c * r.powf(1.0 / n)).max(0.0))
+    }
+
+    /// Returns the swept volume rate `V_s·N`.
+    #[must_use]
+    pub fn volume_rate(&self) -> VolumeRate {
+        self.swept_volume * self.speed
+    }
+
+    fn validate<Fluid>(&self) -> Result<(), CompressionError<Fluid>> {
+        if StrictlyPositive::check(&self.swept_volume).is_err() {
+            return Err(CompressionError::InvalidDisplacement(
+                "swept volume must be strictly positive",
+            ));
+        }
+        if StrictlyPositive::check(&self.speed).is_err() {
+            return Err(CompressionError::InvalidDisplacement(
+                "speed must be strictly positive",
+            ));
+        }
+        if NonNegative::check(&self.clearance).is_err() {
+            return Err(CompressionError::InvalidDisplacement(
+                "clearance must be non-negative",
+            ));
+        }
+        if StrictlyPositive::check(&self.expansion_exponent).is_err() {
+            return Err(CompressionError::InvalidDisplacement(
+                "expansion exponent must be strictly positive",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Heat lost from the compressor shell to its surroundings.
+///
+/// The shell is taken to sit at the adiabatic discharge temperature, so the
+/// loss is `UA·(T_discharge − T_ambient)`. It comes out of the discharge gas
+/// and does not change the shaft power.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShellHeatLoss {
+    /// Conductance from the shell to ambient.
+    pub ua: ThermalConductance,
+
+    /// Temperature of the surroundings.
+    pub ambient: ThermodynamicTemperature,
+}
+
+/// Result of a positive-displacement compressor calculation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisplacementResult<Fluid> {
+    /// Adiabatic compression, before any shell heat loss.
+    pub compression: CompressionResult<Fluid>,
+
+    /// Discharge state after shell heat loss.
+    ///
+    /// Equals the adiabatic outlet when there is no shell loss.
+    pub outlet: State<Fluid>,
+
+    /// Refrigerant mass flow set by the displacement.
+    pub mass_flow: MassRate,
+
+    /// Clearance volumetric efficiency.
+    pub volumetric_efficiency: Ratio,
+
+    /// Shaft power, `ṁ·w`.
+    pub power: Power,
+
+    /// Heat lost from the shell, negative if the surroundings are warmer.
+    pub shell_heat_loss: Power,
+}
+
+/// Computes the mass flow, discharge state, and power of a
+/// positive-displacement compressor.
+///
+/// The refrigerant flow emerges from the displacement and suction density
+/// rather than being an input. `eta` is the isentropic efficiency of the
+/// adiabatic compression, and `shell` adds an optional loss to ambient.
+///
+/// # Errors
+///
+/// Returns [`CompressionError::InvalidDisplacement`] if the geometry or
+/// speed is out of range, [`CompressionError::ZeroVolumetricEfficiency`] if
+/// the pressure ratio is so high that the clearance gas fills the cylinder,
+/// or any error from [`isentropic`].
+pub fn positive_displacement<Fluid, Model>(
+    inlet: &State<Fluid>,
+    p_out: Pressure,
+    eta: IsentropicEfficiency,
+    displacement: &Displacement,
+    shell: Option<&ShellHeatLoss>,
+    thermo: &Model,
+) -> Result<DisplacementResult<Fluid>, CompressionError<Fluid>>
+where
+    Fluid: Clone,
+    Model: ThermoModel<Fluid = Fluid>
+        + HasPressure
+        + HasEnthalpy
+        + HasEntropy
+        + StateFrom<(Fluid, Pressure, SpecificEnthalpy)>
+        + StateFrom<(Fluid, Pressure, SpecificEntropy)>,
+{
+    displacement.validate()?;
+
+    let compression = isentropic(inlet, p_out, eta, thermo)?;
+
+    let p_in = thermo
+        .pressure(inlet)
+        .map_err(CompressionError::inlet_pressure_failed)?;
+    let pressure_ratio: Ratio = p_out / p_in;
+    let volumetric_efficiency = displacement.volumetric_efficiency(pressure_ratio);
+    if volumetric_efficiency <= Ratio::ZERO {
+        return Err(CompressionError::ZeroVolumetricEfficiency { pressure_ratio });
+    }
+
+    let mass_flow: MassRate = volumetric_efficiency * inlet.density * displacement.volume_rate();
+    let power: Power = mass_flow * compression.work.quantity();
+
+    let (outlet, shell_heat_loss) = match shell {
+        None => (compression.outlet.clone(), Power::ZERO),
+        Some(shell) => {
+            let loss = shell.ua * compression.outlet.temperature.minus(shell.ambient);
+            let h_in = thermo
+                .enthalpy(inlet)
+                .map_err(CompressionError::inlet_enthalpy_failed)?;
+            let h_out = h_in + compression.work.quantity() - loss / mass_flow;
+            let outlet = thermo
+                .state_from((inlet.fluid.clone(), p_out, h_out))
+                .map_err(|source| {
+                    CompressionError::outlet_state_from_pressure_enthalpy_failed(
+                        p_out, h_out, source,
+                    )
+                })?;
+            (outlet, loss)
+        }
+    };
+
+    Ok(DisplacementResult {
+        compression,
+        outlet,
+        mass_flow,
+        volumetric_efficiency,
+        power,
+        shell_heat_loss,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::ThermodynamicTemperature, frequency::hertz, mass_rate::kilogram_per_second,
+        power::watt, pressure::kilopascal, thermal_conductance::watt_per_kelvin,
+        thermodynamic_temperature::kelvin, volume::cubic_centimeter,
+    };
+
+    use crate::support::turbomachinery::test_utils::{MockGas, mock_gas_model};
+
+    fn displacement(clearance: f64) -> Displacement {
+        Displacement {
+            swept_volume: Volume::new::<cubic_centimeter>(30.0),
+            speed: Frequency::new::<hertz>(50.0),
+            clearance: Ratio::new::<ratio>(clearance),
+            expansion_exponent: Ratio::new::<ratio>(1.4),
+        }
+    }
+
+    fn inlet() -> State<MockGas> {
+        mock_gas_model()
+            .state_from((
+                MockGas,
+                ThermodynamicTemperature::new::<kelvin>(300.0),
+                Pressure::new::<kilopascal>(100.0),
+            ))
+            .unwrap()
+    }
+
+    #[test]
+    fn mass_flow_follows_displacement_and_suction_density() {
+        let thermo = mock_gas_model();
+        let eta = IsentropicEfficiency::new(0.7).unwrap();
+        let result = positive_displacement(
+            &inlet(),
+            Pressure::new::<kilopascal>(400.0),
+            eta,
+            &displacement(0.05),
+            None,
+            &thermo,
+        )
+        .unwrap();
+
+        // η_v = 1.05 − 0.05·4^(1/1.4), ρ = p / (R·T).
+        let eta_v = 1.05 - 0.05 * 4.0_f64.powf(1.0 / 1.4);
+        let rho = 100_000.0 / (2000.0 / 7.0 * 300.0);
+        let m_dot = eta_v * rho * 30e-6 * 50.0;
+        assert_relative_eq!(
+            result.volumetric_efficiency.get::<ratio>(),
+            eta_v,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            result.mass_flow.get::<kilogram_per_second>(),
+            m_dot,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            result.power.get::<watt>(),
+            m_dot * result.compression.work.quantity().value,
+            max_relative = 1e-12
+        );
+        assert_eq!(result.outlet, result.compression.outlet);
+        assert_eq!(result.shell_heat_loss, Power::ZERO);
+    }
+
+    #[test]
+    fn zero_clearance_displaces_the_full_volume() {
+        let thermo = mock_gas_model();
+        let result = positive_displacement(
+            &inlet(),
+            Pressure::new::<kilopascal>(1000.0),
+            IsentropicEfficiency::new(1.0).unwrap(),
+            &displacement(0.0),
+            None,
+            &thermo,
+        )
+        .unwrap();
+        assert_relative_eq!(result.volumetric_efficiency.get::<ratio>(), 1.0);
+    }
+
+    #[test]
+    fn shell_loss_cools_the_discharge_without_changing_power() {
+        let thermo = mock_gas_model();
+        let eta = IsentropicEfficiency::new(0.7).unwrap();
+        let p_out = Pressure::new::<kilopascal>(400.0);
+        let shell = ShellHeatLoss {
+            ua: ThermalConductance::new::<watt_per_kelvin>(2.0),
+            ambient: ThermodynamicTemperature::new::<kelvin>(300.0),
+        };
+
+        let adiabatic =
+            positive_displacement(&inlet(), p_out, eta, &displacement(0.05), None, &thermo)
+                .unwrap();
+        let lossy = positive_displacement(
+            &inlet(),
+            p_out,
+            eta,
+            &displacement(0.05),
+            Some(&shell),
+            &thermo,
+        )
+        .unwrap();
+
+        let t_adiabatic = adiabatic.outlet.temperature.get::<kelvin>();
+        assert_relative_eq!(
+            lossy.shell_heat_loss.get::<watt>(),
+            2.0 * (t_adiabatic - 300.0),
+            max_relative = 1e-12
+        );
+        assert_eq!(lossy.power, adiabatic.power);
+
+        // cp = 1000 J/kg·K, so the discharge cools by Q / (ṁ·cp).
+        let drop = lossy.shell_heat_loss.get::<watt>()
+            / (lossy.mass_flow.get::<kilogram_per_second>() * 1000.0);
+        assert_relative_eq!(
+            lossy.outlet.temperature.get::<kelvin>(),
+            t_adiabatic - drop,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn excessive_pressure_ratio_stops_the_flow() {
+        let thermo = mock_gas_model();
+        let err = positive_displacement(
+            &inlet(),
+            Pressure::new::<kilopascal>(10_000.0),
+            IsentropicEfficiency::new(0.7).unwrap(),
+            &displacement(0.1),
+            None,
+            &thermo,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            CompressionError::ZeroVolumetricEfficiency { .. }
+        ));
+    }
+
+    #[test]
+    fn invalid_geometry_is_rejected() {
+        let thermo = mock_gas_model();
+        let err = positive_displacement(
+            &inlet(),
+            Pressure::new::<kilopascal>(400.0),
+            IsentropicEfficiency::new(0.7).unwrap(),
+            &displacement(-0.01),
+            None,
+            &thermo,
+        )
+        .unwrap_err();
+        assert!(matches!(err, CompressionError::InvalidDisplacement(_)));
+    }
+}
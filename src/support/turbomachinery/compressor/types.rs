@@ -1,12 +1,16 @@
 use std::error::Error as StdError;
 
 use thiserror::Error;
-use uom::si::f64::Pressure;
+use uom::si::f64::{MassRate, Pressure, Ratio};
 
 use crate::support::{
-    thermo::{PropertyError, State},
+    exergy::{DeadState, ExergyBalance, flow_exergy},
+    thermo::{
+        PropertyError, State,
+        capability::{HasEnthalpy, HasEntropy},
+    },
     turbomachinery::work::CompressionWork,
-    units::{SpecificEnthalpy, SpecificEntropy},
+    units::{SpecificEnthalpy, SpecificEntropy, display::pretty},
 };
 
 /// Result of a compressor calculation.
@@ -22,12 +26,42 @@ pub struct CompressionResult<Fluid> {
     pub work: CompressionWork,
 }
 
+impl<Fluid> CompressionResult<Fluid> {
+    /// Returns the exergy balance of the compressor for `inlet` at `m_dot`.
+    ///
+    /// Shaft work is fuel and the stream's exergy rise is product.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PropertyError`] if an enthalpy or entropy cannot be
+    /// evaluated.
+    pub fn exergy_balance(
+        &self,
+        inlet: &State<Fluid>,
+        m_dot: MassRate,
+        thermo: &(impl HasEnthalpy<Fluid = Fluid> + HasEntropy),
+        dead: &DeadState,
+    ) -> Result<ExergyBalance, PropertyError> {
+        Ok(ExergyBalance::new("compressor", dead.temperature)
+            .stream(
+                m_dot,
+                flow_exergy(thermo, inlet, dead)?,
+                flow_exergy(thermo, &self.outlet, dead)?,
+            )
+            .work_in(self.work.quantity() * m_dot))
+    }
+}
+
 /// Errors that may occur when calling a compressor model.
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum CompressionError<Fluid> {
     /// The requested outlet pressure is less than the inlet pressure.
-    #[error("outlet pressure must not be less than inlet (p_in={p_in:?}, p_out={p_out:?})")]
+    #[error(
+        "outlet pressure must not be less than inlet (p_in={}, p_out={})",
+        pretty(*p_in),
+        pretty(*p_out)
+    )]
     OutletPressureLessThanInlet { p_in: Pressure, p_out: Pressure },
 
     /// The computed target work is negative.
@@ -37,12 +71,21 @@ pub enum CompressionError<Fluid> {
     ///
     /// Includes the outlet state returned by the thermodynamic model so callers can
     /// decide how to recover without recomputing it.
-    #[error("computed compression work is non-physical (raw_work={raw_work:?})")]
+    #[error("computed compression work is non-physical (raw_work={})", pretty(*raw_work))]
     NonPhysicalWork {
         outlet: State<Fluid>,
         raw_work: SpecificEnthalpy,
     },
 
+    /// A positive-displacement geometry or speed is out of range.
+    #[error("invalid displacement: {0}")]
+    InvalidDisplacement(&'static str),
+
+    /// The pressure ratio is so high that re-expanding clearance gas fills
+    /// the cylinder and no fresh suction gas is drawn in.
+    #[error("volumetric efficiency is zero at pressure ratio {}", pressure_ratio.value)]
+    ZeroVolumetricEfficiency { pressure_ratio: Ratio },
+
     /// A thermodynamic model operation failed.
     ///
     /// This failure can be from property evaluation or state construction.
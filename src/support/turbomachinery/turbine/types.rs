@@ -1,12 +1,16 @@
 use std::error::Error as StdError;
 
 use thiserror::Error;
-use uom::si::f64::Pressure;
+use uom::si::f64::{MassRate, Pressure};
 
 use crate::support::{
-    thermo::{PropertyError, State},
+    exergy::{DeadState, ExergyBalance, flow_exergy},
+    thermo::{
+        PropertyError, State,
+        capability::{HasEnthalpy, HasEntropy},
+    },
     turbomachinery::work::ExpansionWork,
-    units::{SpecificEnthalpy, SpecificEntropy},
+    units::{SpecificEnthalpy, SpecificEntropy, display::pretty},
 };
 
 /// Result of a turbine calculation.
@@ -22,12 +26,42 @@ pub struct ExpansionResult<Fluid> {
     pub work: ExpansionWork,
 }
 
+impl<Fluid> ExpansionResult<Fluid> {
+    /// Returns the exergy balance of the turbine for `inlet` at `m_dot`.
+    ///
+    /// The stream's exergy drop is fuel and shaft work is product.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PropertyError`] if an enthalpy or entropy cannot be
+    /// evaluated.
+    pub fn exergy_balance(
+        &self,
+        inlet: &State<Fluid>,
+        m_dot: MassRate,
+        thermo: &(impl HasEnthalpy<Fluid = Fluid> + HasEntropy),
+        dead: &DeadState,
+    ) -> Result<ExergyBalance, PropertyError> {
+        Ok(ExergyBalance::new("turbine", dead.temperature)
+            .stream(
+                m_dot,
+                flow_exergy(thermo, inlet, dead)?,
+                flow_exergy(thermo, &self.outlet, dead)?,
+            )
+            .work_in(-(self.work.quantity() * m_dot)))
+    }
+}
+
 /// Errors that may occur when calling a turbine model.
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum ExpansionError<Fluid> {
     /// The requested outlet pressure is greater than the inlet pressure.
-    #[error("outlet pressure must not be greater than inlet (p_in={p_in:?}, p_out={p_out:?})")]
+    #[error(
+        "outlet pressure must not be greater than inlet (p_in={}, p_out={})",
+        pretty(*p_in),
+        pretty(*p_out)
+    )]
     OutletPressureGreaterThanInlet { p_in: Pressure, p_out: Pressure },
 
     /// The computed target work is negative.
@@ -37,7 +71,7 @@ pub enum ExpansionError<Fluid> {
     ///
     /// Includes the outlet state returned by the thermodynamic model so callers can
     /// decide how to recover without recomputing it.
-    #[error("computed expansion work is non-physical (raw_work={raw_work:?})")]
+    #[error("computed expansion work is non-physical (raw_work={})", pretty(*raw_work))]
     NonPhysicalWork {
         outlet: State<Fluid>,
         raw_work: SpecificEnthalpy,
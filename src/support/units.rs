@@ -19,13 +19,67 @@
 //! // delta_t is a TemperatureInterval, not a ThermodynamicTemperature
 //! ```
 //!
+//! The same trait provides [`plus`](TemperatureDifference::plus) and
+//! [`mean`](TemperatureDifference::mean), and [`mean_temperature`],
+//! [`max_temperature`], and [`min_temperature`] reduce collections of
+//! temperatures without manual kelvin conversions.
+//!
 //! This extension trait is currently needed due to limitations in [`uom`].
 //! See [`TemperatureDifference`] for details.
+//!
+//! ## Gas constants
+//!
+//! [`specific_gas_constant`] converts a molar mass to a [`SpecificGasConstant`]
+//! using the [`universal_gas_constant`], and [`molar_mass`] goes the other way,
+//! so perfect-gas parameters can be written in terms of a gas's molar mass:
+//!
+//! ```
+//! use twine_models::support::units::specific_gas_constant;
+//! use uom::si::{f64::MolarMass, molar_mass::gram_per_mole};
+//!
+//! let r_argon = specific_gas_constant(MolarMass::new::<gram_per_mole>(39.948));
+//! ```
+//!
+//! ## Display
+//!
+//! The [`display`] module formats quantities for diagnostics with automatic
+//! SI prefixes and fixed significant digits, e.g. `101.3 kPa` instead of the
+//! raw base-SI [`Debug`] output.
+//!
+//! ## Uncertainty
+//!
+//! [`Uncertain`] pairs a value with its standard uncertainty and propagates
+//! it to first order through arithmetic, so measurement uncertainty rides
+//! along with the units. See
+//! [`known_conductance_and_inlets_uncertain`](crate::support::hx::functional::known_conductance_and_inlets_uncertain)
+//! for uncertainty propagation through a heat exchanger calculation.
+//!
+//! ## Derived thermal properties
+//!
+//! [`volumetric_heat_capacity`] and [`thermal_diffusivity`] compute `ρ·cp`
+//! and `k / (ρ·cp)` from a material's density, specific heat, and
+//! conductivity, returning the [`VolumetricHeatCapacity`] and
+//! [`ThermalDiffusivity`] aliases.
+
+pub mod display;
 
+mod gas_constant;
 mod quantities;
 mod temperature_difference;
+mod thermal_properties;
+mod uncertain;
 
+pub use gas_constant::{molar_mass, specific_gas_constant, universal_gas_constant};
 pub use quantities::{
+    KinematicViscosity, LinearThermalConductance, QuadraticHeatTransferCoefficient,
     SpecificEnthalpy, SpecificEntropy, SpecificGasConstant, SpecificInternalEnergy,
+    TemperatureRate, ThermalDiffusivity, VolumetricHeatCapacity,
 };
-pub use temperature_difference::TemperatureDifference;
+pub use temperature_difference::{
+    TemperatureDifference, max_temperature, mean_temperature, min_temperature,
+};
+pub use thermal_properties::{thermal_diffusivity, volumetric_heat_capacity};
+pub use uncertain::{Uncertain, UncertainValue};
+
+#[cfg(test)]
+pub(crate) mod test_utils;
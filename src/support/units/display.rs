@@ -0,0 +1,246 @@
+//! Human-readable formatting of quantities for diagnostics.
+//!
+//! The [`Debug`] output of a [`uom`] quantity is the raw base-SI value followed
+//! by the dimension exponents, which is hard to read in error messages.
+//! [`pretty`] wraps a quantity so that its [`Display`] output uses a sensible
+//! SI prefix and a fixed number of significant digits:
+//!
+//! ```
+//! use twine_models::support::units::display::pretty;
+//! use uom::si::{f64::{Power, Pressure}, power::watt, pressure::pascal};
+//!
+//! assert_eq!(pretty(Pressure::new::<pascal>(101_325.0)).to_string(), "101.3 kPa");
+//! assert_eq!(pretty(Power::new::<watt>(2.5e6)).to_string(), "2.500 MW");
+//! assert_eq!(
+//!     pretty(Power::new::<watt>(2.5e6)).with_significant_digits(2).to_string(),
+//!     "2.5 MW",
+//! );
+//! ```
+//!
+//! Quantities whose units don't take prefixes (temperatures, mass flows,
+//! densities) are shown in their base unit, falling back to scientific
+//! notation for very large or small magnitudes.
+
+use core::fmt::{self, Display};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float as _;
+use uom::si::{
+    f64::{
+        Area, Energy, Length, MassDensity, MassRate, Power, Pressure, SpecificHeatCapacity,
+        TemperatureInterval, ThermalConductance, ThermodynamicTemperature, Time, Volume,
+        VolumeRate,
+    },
+    thermodynamic_temperature::kelvin,
+};
+
+use super::SpecificEnthalpy;
+
+/// Default number of significant digits used by [`pretty`].
+pub const DEFAULT_SIGNIFICANT_DIGITS: usize = 4;
+
+/// SI prefixes from nano to giga, indexed by `exponent / 3 + 3`.
+const PREFIXES: [&str; 7] = ["n", "µ", "m", "", "k", "M", "G"];
+
+/// A quantity that can be formatted by [`pretty`].
+pub trait PrettyUnit: Copy {
+    /// Symbol of the base SI unit, e.g. `"Pa"` or `"W/K"`.
+    const SYMBOL: &'static str;
+
+    /// Whether the unit takes SI prefixes (`kPa`, `MW`).
+    const PREFIXED: bool;
+
+    /// Returns the value in the base SI unit.
+    fn base_value(self) -> f64;
+}
+
+macro_rules! impl_pretty_unit {
+    ($($quantity:ty => $symbol:literal, $prefixed:literal;)+) => {
+        $(
+            impl PrettyUnit for $quantity {
+                const SYMBOL: &'static str = $symbol;
+                const PREFIXED: bool = $prefixed;
+
+                fn base_value(self) -> f64 {
+                    self.value
+                }
+            }
+        )+
+    };
+}
+
+impl_pretty_unit! {
+    Area => "m²", false;
+    Energy => "J", true;
+    Length => "m", true;
+    MassDensity => "kg/m³", false;
+    MassRate => "kg/s", false;
+    Power => "W", true;
+    Pressure => "Pa", true;
+    SpecificEnthalpy => "J/kg", true;
+    SpecificHeatCapacity => "J/kg·K", true;
+    TemperatureInterval => "K", false;
+    ThermalConductance => "W/K", true;
+    Time => "s", false;
+    Volume => "m³", false;
+    VolumeRate => "m³/s", false;
+}
+
+impl PrettyUnit for ThermodynamicTemperature {
+    const SYMBOL: &'static str = "K";
+    const PREFIXED: bool = false;
+
+    fn base_value(self) -> f64 {
+        self.get::<kelvin>()
+    }
+}
+
+/// Wraps a quantity for human-readable [`Display`] output.
+///
+/// See the [module documentation](self) for examples.
+#[must_use]
+pub fn pretty<Q: PrettyUnit>(quantity: Q) -> Pretty<Q> {
+    Pretty {
+        quantity,
+        significant_digits: DEFAULT_SIGNIFICANT_DIGITS,
+    }
+}
+
+/// Display adapter returned by [`pretty`].
+#[derive(Debug, Clone, Copy)]
+pub struct Pretty<Q> {
+    quantity: Q,
+    significant_digits: usize,
+}
+
+impl<Q> Pretty<Q> {
+    /// Sets the number of significant digits, which must be at least one.
+    #[must_use]
+    pub fn with_significant_digits(self, digits: usize) -> Self {
+        Self {
+            significant_digits: digits.max(1),
+            ..self
+        }
+    }
+}
+
+impl<Q: PrettyUnit> Display for Pretty<Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.quantity.base_value();
+        let digits = self.significant_digits;
+
+        if !value.is_finite() || value == 0.0 {
+            return write!(f, "{value} {}", Q::SYMBOL);
+        }
+
+        if Q::PREFIXED {
+            let (mantissa, prefix) = scale(value, digits);
+            write!(
+                f,
+                "{mantissa:.*} {prefix}{}",
+                decimals(mantissa, digits),
+                Q::SYMBOL
+            )
+        } else if (1e-3..1e6).contains(&value.abs()) {
+            write!(f, "{value:.*} {}", decimals(value, digits), Q::SYMBOL)
+        } else {
+            write!(f, "{value:.*e} {}", digits - 1, Q::SYMBOL)
+        }
+    }
+}
+
+/// Splits a nonzero finite value into a mantissa in `[1, 1000)` and an SI prefix.
+///
+/// Values outside the supported prefix range keep the nearest prefix, so their
+/// mantissa falls outside `[1, 1000)`.
+fn scale(value: f64, digits: usize) -> (f64, &'static str) {
+    let mut index = magnitude(value).div_euclid(3) + 3;
+    let mut index_clamped = index.clamp(0, 6);
+
+    // Rounding to the requested digits can carry into the next prefix
+    // (999.96 → 1000), so re-check after rounding.
+    let rounded = round_to(value / 10f64.powi(3 * (index_clamped - 3)), digits);
+    if rounded.abs() >= 1000.0 && index_clamped < 6 {
+        index += 1;
+        index_clamped = index.clamp(0, 6);
+    }
+
+    let mantissa = value / 10f64.powi(3 * (index_clamped - 3));
+    #[allow(clippy::cast_sign_loss)]
+    let prefix = PREFIXES[index_clamped as usize];
+    (mantissa, prefix)
+}
+
+/// Returns the decimal exponent of the leading digit of a nonzero value.
+#[allow(clippy::cast_possible_truncation)]
+fn magnitude(value: f64) -> i32 {
+    value.abs().log10().floor() as i32
+}
+
+/// Returns the number of decimal places that shows `digits` significant digits.
+fn decimals(value: f64, digits: usize) -> usize {
+    let leading = magnitude(round_to(value, digits));
+    let places = i32::try_from(digits).unwrap_or(i32::MAX) - 1 - leading;
+    usize::try_from(places).unwrap_or(0)
+}
+
+/// Rounds a nonzero value to the given number of significant digits.
+fn round_to(value: f64, digits: usize) -> f64 {
+    let digits = i32::try_from(digits).unwrap_or(i32::MAX);
+    let factor = 10f64.powi(digits - 1 - magnitude(value));
+    (value * factor).round() / factor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uom::si::{
+        mass_rate::kilogram_per_second, power::watt, pressure::pascal,
+        temperature_interval::kelvin as delta_kelvin, thermal_conductance::watt_per_kelvin,
+        thermodynamic_temperature::degree_celsius,
+    };
+
+    #[test]
+    fn prefixed_units_scale() {
+        let p = |pa| pretty(Pressure::new::<pascal>(pa)).to_string();
+        assert_eq!(p(12.0), "12.00 Pa");
+        assert_eq!(p(1500.0), "1.500 kPa");
+        assert_eq!(p(-2.0e7), "-20.00 MPa");
+        assert_eq!(p(0.0025), "2.500 mPa");
+        assert_eq!(p(0.0), "0 Pa");
+
+        let ua = pretty(ThermalConductance::new::<watt_per_kelvin>(12_346.0));
+        assert_eq!(ua.to_string(), "12.35 kW/K");
+    }
+
+    #[test]
+    fn rounding_carries_into_next_prefix() {
+        assert_eq!(
+            pretty(Power::new::<watt>(999_960.0)).to_string(),
+            "1.000 MW"
+        );
+        assert_eq!(pretty(Power::new::<watt>(999.96)).to_string(), "1.000 kW");
+    }
+
+    #[test]
+    fn unprefixed_units_use_base_unit() {
+        let t = ThermodynamicTemperature::new::<degree_celsius>(25.0);
+        assert_eq!(pretty(t).with_significant_digits(5).to_string(), "298.15 K");
+
+        let dt = TemperatureInterval::new::<delta_kelvin>(-0.5);
+        assert_eq!(pretty(dt).to_string(), "-0.5000 K");
+
+        let m = MassRate::new::<kilogram_per_second>(2.5e-5);
+        assert_eq!(pretty(m).to_string(), "2.500e-5 kg/s");
+    }
+
+    #[test]
+    fn non_finite_values() {
+        assert_eq!(pretty(Power::new::<watt>(f64::NAN)).to_string(), "NaN W");
+        assert_eq!(
+            pretty(Power::new::<watt>(f64::INFINITY)).to_string(),
+            "inf W"
+        );
+    }
+}
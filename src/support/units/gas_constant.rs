@@ -0,0 +1,84 @@
+use uom::si::{
+    f64::{MolarHeatCapacity, MolarMass},
+    molar_heat_capacity::joule_per_kelvin_mole,
+};
+
+use super::SpecificGasConstant;
+
+/// Universal (molar) gas constant, J/mol·K, from the 2018 CODATA values.
+const UNIVERSAL_GAS_CONSTANT_SI: f64 = 8.314_462_618_153_24;
+
+/// Returns the universal gas constant `R̄ ≈ 8.314 J/mol·K`.
+#[must_use]
+pub fn universal_gas_constant() -> MolarHeatCapacity {
+    MolarHeatCapacity::new::<joule_per_kelvin_mole>(UNIVERSAL_GAS_CONSTANT_SI)
+}
+
+/// Returns the specific gas constant `R = R̄ / M` for a gas with molar mass `M`.
+///
+/// # Example
+///
+/// ```
+/// use twine_models::support::units::specific_gas_constant;
+/// use uom::si::{
+///     f64::MolarMass, molar_mass::gram_per_mole,
+///     specific_heat_capacity::joule_per_kilogram_kelvin,
+/// };
+///
+/// // Carbon dioxide.
+/// let r = specific_gas_constant(MolarMass::new::<gram_per_mole>(44.0095));
+/// assert!((r.get::<joule_per_kilogram_kelvin>() - 188.92).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn specific_gas_constant(molar_mass: MolarMass) -> SpecificGasConstant {
+    universal_gas_constant() / molar_mass
+}
+
+/// Returns the molar mass `M = R̄ / R` of a gas with specific gas constant `R`.
+///
+/// This is the inverse of [`specific_gas_constant`].
+///
+/// # Example
+///
+/// ```
+/// use twine_models::support::units::{SpecificGasConstant, molar_mass};
+/// use uom::si::{
+///     molar_mass::gram_per_mole, specific_heat_capacity::joule_per_kilogram_kelvin,
+/// };
+///
+/// // Dry air.
+/// let m = molar_mass(SpecificGasConstant::new::<joule_per_kilogram_kelvin>(287.053));
+/// assert!((m.get::<gram_per_mole>() - 28.965).abs() < 0.001);
+/// ```
+#[must_use]
+pub fn molar_mass(gas_constant: SpecificGasConstant) -> MolarMass {
+    universal_gas_constant() / gas_constant
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{molar_mass::gram_per_mole, specific_heat_capacity::joule_per_kilogram_kelvin};
+
+    #[test]
+    fn nitrogen_gas_constant() {
+        let r = specific_gas_constant(MolarMass::new::<gram_per_mole>(28.0134));
+        assert_relative_eq!(
+            r.get::<joule_per_kilogram_kelvin>(),
+            296.80,
+            max_relative = 1e-4
+        );
+    }
+
+    #[test]
+    fn molar_mass_round_trips() {
+        let m = MolarMass::new::<gram_per_mole>(18.015);
+        assert_relative_eq!(
+            molar_mass(specific_gas_constant(m)).get::<gram_per_mole>(),
+            18.015,
+            epsilon = 1e-12
+        );
+    }
+}
@@ -1,8 +1,22 @@
+use core::ops::Div;
+
 use uom::{
-    si::{ISQ, Quantity, SI},
-    typenum::{N1, N2, P2, Z0},
+    si::{
+        ISQ, Quantity, SI,
+        f64::{DiffusionCoefficient, TemperatureInterval, Time},
+    },
+    typenum::{N1, N2, N3, P1, P2, Z0},
 };
 
+/// Heat transfer coefficient per kelvin of temperature difference, W/m²·K² in SI.
+///
+/// The quadratic loss coefficient `a₂` in solar collector efficiency curves
+/// has these units. Build one by dividing a [`HeatTransfer`] by a
+/// [`TemperatureInterval`].
+///
+/// [`HeatTransfer`]: uom::si::f64::HeatTransfer
+pub type QuadraticHeatTransferCoefficient = Quantity<ISQ<Z0, P1, N3, Z0, N2, Z0, Z0>, SI<f64>, f64>;
+
 /// Specific gas constant, J/kg·K in SI.
 pub type SpecificGasConstant = Quantity<ISQ<P2, Z0, N2, Z0, N1, Z0, Z0>, SI<f64>, f64>;
 
@@ -14,3 +28,30 @@ pub type SpecificEntropy = Quantity<ISQ<P2, Z0, N2, Z0, N1, Z0, Z0>, SI<f64>, f6
 
 /// Specific internal energy, J/kg in SI.
 pub type SpecificInternalEnergy = Quantity<ISQ<P2, Z0, N2, Z0, Z0, Z0, Z0>, SI<f64>, f64>;
+
+/// Thermal diffusivity, m²/s in SI.
+///
+/// Shares its dimension with uom's [`DiffusionCoefficient`].
+pub type ThermalDiffusivity = DiffusionCoefficient;
+
+/// Volumetric heat capacity, J/m³·K in SI.
+pub type VolumetricHeatCapacity = uom::si::f64::VolumetricHeatCapacity;
+
+/// Thermal conductance per unit length, W/m·K in SI.
+///
+/// The UA of a pipe or other long cylindrical wall per meter of length.
+/// Shares its dimension with uom's [`ThermalConductivity`].
+///
+/// [`ThermalConductivity`]: uom::si::f64::ThermalConductivity
+pub type LinearThermalConductance = uom::si::f64::ThermalConductivity;
+
+/// Kinematic viscosity `ν = μ / ρ`, m²/s in SI.
+///
+/// Shares its dimension with uom's [`DiffusionCoefficient`].
+pub type KinematicViscosity = DiffusionCoefficient;
+
+/// Rate of change of temperature, K/s in SI.
+///
+/// Uses `TemperatureInterval / Time` rather than `ThermodynamicTemperature / Time`
+/// so that the type aligns with standard uom arithmetic (e.g., `Power / HeatCapacity`).
+pub type TemperatureRate = <TemperatureInterval as Div<Time>>::Output;
@@ -8,7 +8,12 @@ use uom::si::{
 ///
 /// This trait provides a [`minus`](Self::minus) method that subtracts two
 /// [`ThermodynamicTemperature`] values (absolute temperatures) and returns a
-/// [`TemperatureInterval`] (temperature difference).
+/// [`TemperatureInterval`] (temperature difference), along with
+/// [`plus`](Self::plus) for offsetting a temperature by an interval and
+/// [`mean`](Self::mean) for averaging two temperatures.
+///
+/// For extremes and averages over many temperatures, see [`max_temperature`],
+/// [`min_temperature`], and [`mean_temperature`].
 ///
 /// For background on this distinction and why this extension is needed:
 /// [#380](https://github.com/iliekturtles/uom/issues/380),
@@ -20,6 +25,29 @@ use uom::si::{
 pub trait TemperatureDifference {
     /// Returns the temperature difference `self - other`.
     fn minus(self, other: Self) -> TemperatureInterval;
+
+    /// Returns the temperature `self + delta`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use twine_models::support::units::TemperatureDifference;
+    /// use uom::si::{
+    ///     f64::{TemperatureInterval, ThermodynamicTemperature},
+    ///     temperature_interval::kelvin as delta_kelvin,
+    ///     thermodynamic_temperature::degree_celsius,
+    /// };
+    ///
+    /// let t = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+    /// let warmer = t.plus(TemperatureInterval::new::<delta_kelvin>(5.0));
+    /// assert!((warmer.get::<degree_celsius>() - 25.0).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    fn plus(self, delta: TemperatureInterval) -> Self;
+
+    /// Returns the arithmetic mean of `self` and `other`.
+    #[must_use]
+    fn mean(self, other: Self) -> Self;
 }
 
 impl TemperatureDifference for ThermodynamicTemperature {
@@ -28,6 +56,69 @@ impl TemperatureDifference for ThermodynamicTemperature {
             self.get::<abs_kelvin>() - other.get::<abs_kelvin>(),
         )
     }
+
+    fn plus(self, delta: TemperatureInterval) -> Self {
+        ThermodynamicTemperature::new::<abs_kelvin>(
+            self.get::<abs_kelvin>() + delta.get::<delta_kelvin>(),
+        )
+    }
+
+    fn mean(self, other: Self) -> Self {
+        ThermodynamicTemperature::new::<abs_kelvin>(
+            0.5 * (self.get::<abs_kelvin>() + other.get::<abs_kelvin>()),
+        )
+    }
+}
+
+/// Returns the arithmetic mean of the given temperatures.
+///
+/// Returns `None` if the iterator is empty.
+///
+/// # Example
+///
+/// ```
+/// use twine_models::support::units::mean_temperature;
+/// use uom::si::{f64::ThermodynamicTemperature, thermodynamic_temperature::kelvin};
+///
+/// let nodes = [300.0, 310.0, 320.0].map(ThermodynamicTemperature::new::<kelvin>);
+/// let mean = mean_temperature(nodes).unwrap();
+/// assert!((mean.get::<kelvin>() - 310.0).abs() < 1e-12);
+/// ```
+pub fn mean_temperature(
+    temperatures: impl IntoIterator<Item = ThermodynamicTemperature>,
+) -> Option<ThermodynamicTemperature> {
+    let (sum, count) = temperatures
+        .into_iter()
+        .fold((0.0, 0_u32), |(sum, count), t| {
+            (sum + t.get::<abs_kelvin>(), count + 1)
+        });
+    (count > 0).then(|| ThermodynamicTemperature::new::<abs_kelvin>(sum / f64::from(count)))
+}
+
+/// Returns the highest of the given temperatures.
+///
+/// `NaN` values are ignored. Returns `None` if the iterator is empty or
+/// contains only `NaN` values.
+pub fn max_temperature(
+    temperatures: impl IntoIterator<Item = ThermodynamicTemperature>,
+) -> Option<ThermodynamicTemperature> {
+    temperatures
+        .into_iter()
+        .filter(|t| !t.is_nan())
+        .reduce(ThermodynamicTemperature::max)
+}
+
+/// Returns the lowest of the given temperatures.
+///
+/// `NaN` values are ignored. Returns `None` if the iterator is empty or
+/// contains only `NaN` values.
+pub fn min_temperature(
+    temperatures: impl IntoIterator<Item = ThermodynamicTemperature>,
+) -> Option<ThermodynamicTemperature> {
+    temperatures
+        .into_iter()
+        .filter(|t| !t.is_nan())
+        .reduce(ThermodynamicTemperature::min)
 }
 
 #[cfg(test)]
@@ -61,4 +152,43 @@ mod tests {
             epsilon = 1e-12
         );
     }
+
+    #[test]
+    fn offset_and_average_temperatures() {
+        let t1 = ThermodynamicTemperature::new::<degree_celsius>(10.0);
+        let t2 = ThermodynamicTemperature::new::<degree_celsius>(30.0);
+
+        assert_relative_eq!(
+            t1.plus(TemperatureInterval::new::<delta_celsius>(-15.0))
+                .get::<degree_celsius>(),
+            -5.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(t1.mean(t2).get::<degree_celsius>(), 20.0, epsilon = 1e-12);
+        assert_relative_eq!(t2.mean(t1).get::<abs_kelvin>(), 293.15, epsilon = 1e-12);
+
+        // A temperature plus its difference from another recovers the other.
+        assert_relative_eq!(
+            t1.plus(t2.minus(t1)).get::<abs_kelvin>(),
+            t2.get::<abs_kelvin>(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn temperature_extremes_and_mean() {
+        let temps =
+            [305.0, 295.0, f64::NAN, 300.0].map(ThermodynamicTemperature::new::<abs_kelvin>);
+
+        assert_relative_eq!(max_temperature(temps).unwrap().get::<abs_kelvin>(), 305.0);
+        assert_relative_eq!(min_temperature(temps).unwrap().get::<abs_kelvin>(), 295.0);
+        assert!(mean_temperature(temps).unwrap().is_nan());
+
+        let valid = [305.0, 295.0].map(ThermodynamicTemperature::new::<abs_kelvin>);
+        assert_relative_eq!(mean_temperature(valid).unwrap().get::<abs_kelvin>(), 300.0);
+
+        assert!(max_temperature([]).is_none());
+        assert!(min_temperature([ThermodynamicTemperature::new::<abs_kelvin>(f64::NAN)]).is_none());
+        assert!(mean_temperature([]).is_none());
+    }
 }
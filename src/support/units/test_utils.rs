@@ -0,0 +1,8 @@
+//! Shared fixtures for unit tests.
+
+use uom::si::{f64::ThermodynamicTemperature, thermodynamic_temperature::degree_celsius};
+
+/// Builds a temperature from a value in degrees Celsius.
+pub(crate) fn celsius(value: f64) -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<degree_celsius>(value)
+}
@@ -0,0 +1,93 @@
+use uom::si::f64::{MassDensity, SpecificHeatCapacity, ThermalConductivity};
+
+use super::{ThermalDiffusivity, VolumetricHeatCapacity};
+
+/// Returns the volumetric heat capacity `ρ·cp`.
+///
+/// # Example
+///
+/// ```
+/// use twine_models::support::units::volumetric_heat_capacity;
+/// use uom::si::{
+///     f64::{MassDensity, SpecificHeatCapacity},
+///     mass_density::kilogram_per_cubic_meter,
+///     specific_heat_capacity::kilojoule_per_kilogram_kelvin,
+///     volumetric_heat_capacity::joule_per_cubic_meter_kelvin,
+/// };
+///
+/// let water = volumetric_heat_capacity(
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     SpecificHeatCapacity::new::<kilojoule_per_kilogram_kelvin>(4.18),
+/// );
+/// assert!((water.get::<joule_per_cubic_meter_kelvin>() - 4.18e6).abs() < 1e-6);
+/// ```
+#[must_use]
+pub fn volumetric_heat_capacity(
+    density: MassDensity,
+    specific_heat: SpecificHeatCapacity,
+) -> VolumetricHeatCapacity {
+    density * specific_heat
+}
+
+/// Returns the thermal diffusivity `α = k / (ρ·cp)`.
+///
+/// # Example
+///
+/// ```
+/// use twine_models::support::units::thermal_diffusivity;
+/// use uom::si::{
+///     diffusion_coefficient::square_meter_per_second,
+///     f64::{MassDensity, SpecificHeatCapacity, ThermalConductivity},
+///     mass_density::kilogram_per_cubic_meter,
+///     specific_heat_capacity::joule_per_kilogram_kelvin,
+///     thermal_conductivity::watt_per_meter_kelvin,
+/// };
+///
+/// // A typical saturated soil.
+/// let alpha = thermal_diffusivity(
+///     ThermalConductivity::new::<watt_per_meter_kelvin>(2.0),
+///     MassDensity::new::<kilogram_per_cubic_meter>(2000.0),
+///     SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(1000.0),
+/// );
+/// assert!((alpha.get::<square_meter_per_second>() - 1.0e-6).abs() < 1e-18);
+/// ```
+#[must_use]
+pub fn thermal_diffusivity(
+    conductivity: ThermalConductivity,
+    density: MassDensity,
+    specific_heat: SpecificHeatCapacity,
+) -> ThermalDiffusivity {
+    conductivity / volumetric_heat_capacity(density, specific_heat)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        diffusion_coefficient::square_millimeter_per_second,
+        mass_density::kilogram_per_cubic_meter, specific_heat_capacity::joule_per_kilogram_kelvin,
+        thermal_conductivity::watt_per_meter_kelvin,
+        volumetric_heat_capacity::joule_per_cubic_meter_kelvin,
+    };
+
+    #[test]
+    fn water_properties() {
+        let density = MassDensity::new::<kilogram_per_cubic_meter>(998.0);
+        let cp = SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(4182.0);
+        let k = ThermalConductivity::new::<watt_per_meter_kelvin>(0.598);
+
+        assert_relative_eq!(
+            volumetric_heat_capacity(density, cp).get::<joule_per_cubic_meter_kelvin>(),
+            998.0 * 4182.0,
+        );
+
+        // Water at 20 °C has a diffusivity of about 0.143 mm²/s.
+        assert_relative_eq!(
+            thermal_diffusivity(k, density, cp).get::<square_millimeter_per_second>(),
+            0.143,
+            max_relative = 1e-2
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use core::{
+    fmt::{self, Display},
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float as _;
+use uom::si::{Dimension, Quantity, Units};
+
+use crate::support::constraint::{Constraint, ConstraintError, NonNegative};
+
+use super::display::{PrettyUnit, pretty};
+
+/// A value that can carry an uncertainty.
+///
+/// Uncertainty arithmetic is done on the value in SI base units, so this is
+/// implemented for `f64` and every `f64`-backed [`uom`] quantity.
+pub trait UncertainValue: Copy {
+    /// Returns the value in SI base units.
+    fn base_value(self) -> f64;
+
+    /// Builds the value from SI base units.
+    fn from_base_value(value: f64) -> Self;
+}
+
+impl UncertainValue for f64 {
+    fn base_value(self) -> f64 {
+        self
+    }
+
+    fn from_base_value(value: f64) -> Self {
+        value
+    }
+}
+
+impl<D, U> UncertainValue for Quantity<D, U, f64>
+where
+    D: Dimension + ?Sized,
+    U: Units<f64> + ?Sized,
+{
+    fn base_value(self) -> f64 {
+        self.value
+    }
+
+    fn from_base_value(value: f64) -> Self {
+        Quantity {
+            dimension: PhantomData,
+            units: PhantomData,
+            value,
+        }
+    }
+}
+
+/// A value paired with its standard uncertainty (one standard deviation).
+///
+/// Arithmetic propagates uncertainty to first order, assuming the operands
+/// are uncorrelated:
+///
+/// - `a + b` and `a - b` combine absolute uncertainties in quadrature.
+/// - `a * b` and `a / b` combine relative uncertainties in quadrature.
+///
+/// Because operands are treated as independent, `x - x` reports a nonzero
+/// uncertainty; rearrange expressions so each measured input appears once.
+///
+/// The standard deviation has the same type as the value. For
+/// `ThermodynamicTemperature` this means the uncertainty is an absolute
+/// temperature in kelvin, so construct it with `kelvin` rather than a unit
+/// with an offset such as `degree_celsius`.
+///
+/// # Example
+///
+/// ```
+/// use twine_models::support::units::Uncertain;
+/// use uom::si::{
+///     f64::{Area, Length},
+///     area::square_meter,
+///     length::meter,
+/// };
+///
+/// let width = Uncertain::new(Length::new::<meter>(2.0), Length::new::<meter>(0.01))?;
+/// let height = Uncertain::new(Length::new::<meter>(3.0), Length::new::<meter>(0.02))?;
+///
+/// let area: Uncertain<Area> = width * height;
+/// assert!((area.value.get::<square_meter>() - 6.0).abs() < 1e-12);
+/// assert!((area.relative() - (0.01_f64 / 2.0).hypot(0.02 / 3.0)).abs() < 1e-12);
+/// # Ok::<(), twine_models::support::constraint::ConstraintError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Uncertain<T> {
+    /// Best estimate of the value.
+    pub value: T,
+
+    /// Standard uncertainty of the value.
+    pub std_dev: T,
+}
+
+impl<T: UncertainValue> Uncertain<T> {
+    /// Creates an uncertain value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `std_dev` is negative or NaN.
+    pub fn new(value: T, std_dev: T) -> Result<Self, ConstraintError> {
+        NonNegative::check(&std_dev.base_value())?;
+        Ok(Self { value, std_dev })
+    }
+
+    /// Creates an uncertain value from a relative standard uncertainty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `relative` is negative or NaN.
+    pub fn with_relative(value: T, relative: f64) -> Result<Self, ConstraintError> {
+        NonNegative::check(&relative)?;
+        Ok(Self {
+            value,
+            std_dev: T::from_base_value(relative * value.base_value().abs()),
+        })
+    }
+
+    /// Creates a value with no uncertainty.
+    #[must_use]
+    pub fn exact(value: T) -> Self {
+        Self {
+            value,
+            std_dev: T::from_base_value(0.0),
+        }
+    }
+
+    /// Returns the relative standard uncertainty, `σ / |value|`.
+    ///
+    /// Returns infinity (or NaN for an exact zero) when the value is zero.
+    #[must_use]
+    pub fn relative(&self) -> f64 {
+        self.std_dev.base_value() / self.value.base_value().abs()
+    }
+
+    fn from_base(value: f64, std_dev: f64) -> Self {
+        Self {
+            value: T::from_base_value(value),
+            std_dev: T::from_base_value(std_dev),
+        }
+    }
+}
+
+impl<T> Add for Uncertain<T>
+where
+    T: UncertainValue + Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            value: self.value + rhs.value,
+            std_dev: T::from_base_value(self.std_dev.base_value().hypot(rhs.std_dev.base_value())),
+        }
+    }
+}
+
+impl<T> Sub for Uncertain<T>
+where
+    T: UncertainValue + Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            value: self.value - rhs.value,
+            std_dev: T::from_base_value(self.std_dev.base_value().hypot(rhs.std_dev.base_value())),
+        }
+    }
+}
+
+impl<T, U> Mul<Uncertain<U>> for Uncertain<T>
+where
+    T: UncertainValue + Mul<U>,
+    U: UncertainValue,
+    <T as Mul<U>>::Output: UncertainValue,
+{
+    type Output = Uncertain<<T as Mul<U>>::Output>;
+
+    fn mul(self, rhs: Uncertain<U>) -> Self::Output {
+        let (a, sa) = (self.value.base_value(), self.std_dev.base_value());
+        let (b, sb) = (rhs.value.base_value(), rhs.std_dev.base_value());
+        Uncertain::from_base(a * b, (b * sa).hypot(a * sb))
+    }
+}
+
+impl<T, U> Div<Uncertain<U>> for Uncertain<T>
+where
+    T: UncertainValue + Div<U>,
+    U: UncertainValue,
+    <T as Div<U>>::Output: UncertainValue,
+{
+    type Output = Uncertain<<T as Div<U>>::Output>;
+
+    fn div(self, rhs: Uncertain<U>) -> Self::Output {
+        let (a, sa) = (self.value.base_value(), self.std_dev.base_value());
+        let (b, sb) = (rhs.value.base_value(), rhs.std_dev.base_value());
+        Uncertain::from_base(a / b, (sa / b).hypot(a * sb / (b * b)))
+    }
+}
+
+impl<T: UncertainValue> Mul<f64> for Uncertain<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Self::from_base(
+            self.value.base_value() * rhs,
+            self.std_dev.base_value() * rhs.abs(),
+        )
+    }
+}
+
+impl<T: UncertainValue> Div<f64> for Uncertain<T> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Self::from_base(
+            self.value.base_value() / rhs,
+            self.std_dev.base_value() / rhs.abs(),
+        )
+    }
+}
+
+impl<T: UncertainValue + Neg<Output = T>> Neg for Uncertain<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            value: -self.value,
+            std_dev: self.std_dev,
+        }
+    }
+}
+
+/// Formats as `value ± std_dev` using [`pretty`] for each part.
+impl<T: PrettyUnit> Display for Uncertain<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ± {}", pretty(self.value), pretty(self.std_dev))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use uom::si::{
+        f64::{MassRate, Power, SpecificHeatCapacity, TemperatureInterval, ThermalConductance},
+        mass_rate::kilogram_per_second,
+        power::watt,
+        specific_heat_capacity::joule_per_kilogram_kelvin,
+        temperature_interval::kelvin as delta_kelvin,
+        thermal_conductance::watt_per_kelvin,
+    };
+
+    #[test]
+    fn sums_add_in_quadrature() {
+        let a = Uncertain::new(Power::new::<watt>(100.0), Power::new::<watt>(3.0)).unwrap();
+        let b = Uncertain::new(Power::new::<watt>(50.0), Power::new::<watt>(4.0)).unwrap();
+
+        let sum = a + b;
+        assert_relative_eq!(sum.value.get::<watt>(), 150.0);
+        assert_relative_eq!(sum.std_dev.get::<watt>(), 5.0);
+
+        let diff = a - b;
+        assert_relative_eq!(diff.value.get::<watt>(), 50.0);
+        assert_relative_eq!(diff.std_dev.get::<watt>(), 5.0);
+    }
+
+    #[test]
+    fn products_and_quotients_combine_relative_uncertainty() {
+        let m = Uncertain::with_relative(MassRate::new::<kilogram_per_second>(2.0), 0.03).unwrap();
+        let cp = Uncertain::with_relative(
+            SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(4000.0),
+            0.04,
+        )
+        .unwrap();
+
+        let c: Uncertain<ThermalConductance> = m * cp;
+        assert_relative_eq!(c.value.get::<watt_per_kelvin>(), 8000.0);
+        assert_relative_eq!(c.relative(), 0.05, epsilon = 1e-12);
+
+        let dt = Uncertain::new(
+            TemperatureInterval::new::<delta_kelvin>(10.0),
+            TemperatureInterval::new::<delta_kelvin>(0.0),
+        )
+        .unwrap();
+        let q: Uncertain<Power> = c * dt;
+        let back: Uncertain<ThermalConductance> = q / dt;
+        assert_relative_eq!(back.value.get::<watt_per_kelvin>(), 8000.0);
+        assert_relative_eq!(back.relative(), 0.05, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn scaling_and_negation() {
+        let x = Uncertain::new(2.0, 0.1).unwrap();
+        let y = -x * -3.0;
+        assert_relative_eq!(y.value, 6.0);
+        assert_relative_eq!(y.std_dev, 0.3, epsilon = 1e-12);
+        assert_relative_eq!((x / 4.0).std_dev, 0.025);
+        assert_relative_eq!(Uncertain::exact(5.0).std_dev, 0.0);
+    }
+
+    #[test]
+    fn rejects_negative_uncertainty() {
+        assert!(Uncertain::new(1.0, -0.1).is_err());
+        assert!(Uncertain::with_relative(1.0, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn displays_with_units() {
+        let q = Uncertain::new(Power::new::<watt>(12_500.0), Power::new::<watt>(250.0)).unwrap();
+        assert_eq!(q.to_string(), "12.50 kW ± 250.0 W");
+    }
+}